@@ -6,8 +6,8 @@
 // Runs tools/scrape.py with that venv’s interpreter.
 // Finally, asks Cargo to re-compile if the generated file changed.
 //
-// Note: The generated/colors.rs file is included in the published package.
-// If you need to regenerate it, set PIGMENT_REGEN=1 before building.
+// Note: The generated/*.rs files are included in the published package.
+// If you need to regenerate them, set PIGMENT_REGEN=1 before building.
 
 use std::{
     env,
@@ -21,7 +21,7 @@ fn main() {
     let regen = env::var_os("PIGMENT_REGEN").is_some();
     let _table = Path::new("generated/colors.rs");
     if !regen {
-        println!("cargo:rerun-if-changed=generated/colors.rs");
+        watch_generated();
         return;
     }
 
@@ -75,8 +75,23 @@ fn main() {
     let py = bin("python");
     run(Command::new(py).arg("tools/scrape.py"));
 
-    // tell Cargo to watch the generated file
-    println!("cargo:rerun-if-changed=generated/colors.rs");
+    // tell Cargo to watch the generated files
+    watch_generated();
+}
+
+// Helper: ask Cargo to rebuild when any generated table changes
+fn watch_generated() {
+    for table in [
+        "colors",
+        "css",
+        "x11",
+        "lab",
+        "locale_de",
+        "locale_fr",
+        "locale_ja",
+    ] {
+        println!("cargo:rerun-if-changed=generated/{table}.rs");
+    }
 }
 
 // Helper: run a command and panic on failure
@@ -13,7 +13,7 @@ fn main() {
         let azure = color("Azure").unwrap();
         
         // Convert to color::Rgba8
-        let color_rs: Rgba8 = azure.into();
+        let color_rs: Rgba8 = azure.clone().into();
         
         println!("Azure color:");
         println!("  Pigment RGB: {:?}", azure.rgb());
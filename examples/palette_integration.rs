@@ -13,7 +13,7 @@ fn main() {
         let azure = color("Azure").unwrap();
 
         // Convert to palette's Srgb<u8>
-        let palette_color: Srgb<u8> = azure.into();
+        let palette_color: Srgb<u8> = azure.clone().into();
 
         println!("Azure color:");
         println!("  Pigment RGB: {:?}", azure.rgb());
@@ -25,7 +25,7 @@ fn main() {
         for color_name in colors {
             if let Some(c) = color(color_name) {
                 // Convert to ratatui Color
-                let rt_color: ratatui::style::Color = c.into();
+                let rt_color: ratatui::style::Color = c.clone().into();
                 
                 // Create a style with the color
                 let style = Style::default().fg(rt_color);
@@ -13,7 +13,7 @@ fn main() {
         let azure = color("Azure").unwrap();
         
         // Convert to rgb::Rgb<u8>
-        let rgb_color: Rgb<u8> = azure.into();
+        let rgb_color: Rgb<u8> = azure.clone().into();
         
         println!("Azure color:");
         println!("  Pigment RGB: {:?}", azure.rgb());
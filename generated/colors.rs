@@ -1,899 +1,4556 @@
-///  ***  AUTO-GENERATED  – DO NOT EDIT BY HAND  ***
-use phf::{phf_map};
+//  ***  AUTO-GENERATED  – DO NOT EDIT BY HAND  ***
 
-pub static COLORS: phf::Map<&'static str, crate::Color> = phf_map! {
-    "absolutezero" => crate::Color{ name:"Absolute Zero", hex:"#0048BA", rgb:(0,72,186) },
-    "acidgreen" => crate::Color{ name:"Acid green", hex:"#B0BF1A", rgb:(176,191,26) },
-    "aero" => crate::Color{ name:"Aero", hex:"#7CB9E8", rgb:(124,185,232) },
-    "africanviolet" => crate::Color{ name:"African violet", hex:"#B284BE", rgb:(178,132,190) },
-    "airsuperiorityblue" => crate::Color{ name:"Air superiority blue", hex:"#72A0C1", rgb:(114,160,193) },
-    "aliceblue" => crate::Color{ name:"Alice blue", hex:"#F0F8FF", rgb:(240,248,255) },
-    "alizarin" => crate::Color{ name:"Alizarin", hex:"#DB2D43", rgb:(219,45,67) },
-    "alloyorange" => crate::Color{ name:"Alloy orange", hex:"#C46210", rgb:(196,98,16) },
-    "almond" => crate::Color{ name:"Almond", hex:"#EED9C4", rgb:(238,217,196) },
-    "amaranthdeeppurple" => crate::Color{ name:"Amaranth deep purple", hex:"#9F2B68", rgb:(159,43,104) },
-    "amaranthpink" => crate::Color{ name:"Amaranth pink", hex:"#F19CBB", rgb:(241,156,187) },
-    "amaranthpurple" => crate::Color{ name:"Amaranth purple", hex:"#AB274F", rgb:(171,39,79) },
-    "amazon" => crate::Color{ name:"Amazon", hex:"#3B7A57", rgb:(59,122,87) },
-    "amber" => crate::Color{ name:"Amber", hex:"#FFBF00", rgb:(255,191,0) },
-    "amethyst" => crate::Color{ name:"Amethyst", hex:"#9966CC", rgb:(153,102,204) },
-    "androidgreen" => crate::Color{ name:"Android green", hex:"#3DDC84", rgb:(61,220,132) },
-    "antiquebrass" => crate::Color{ name:"Antique brass", hex:"#C88A65", rgb:(200,138,101) },
-    "antiquebronze" => crate::Color{ name:"Antique bronze", hex:"#665D1E", rgb:(102,93,30) },
-    "antiquefuchsia" => crate::Color{ name:"Antique fuchsia", hex:"#915C83", rgb:(145,92,131) },
-    "antiqueruby" => crate::Color{ name:"Antique ruby", hex:"#841B2D", rgb:(132,27,45) },
-    "antiquewhite" => crate::Color{ name:"Antique white", hex:"#FAEBD7", rgb:(250,235,215) },
-    "apricot" => crate::Color{ name:"Apricot", hex:"#FBCEB1", rgb:(251,206,177) },
-    "aqua" => crate::Color{ name:"Aqua", hex:"#00FFFF", rgb:(0,255,255) },
-    "aquamarine" => crate::Color{ name:"Aquamarine", hex:"#7FFFD4", rgb:(127,255,212) },
-    "arcticlime" => crate::Color{ name:"Arctic lime", hex:"#D0FF14", rgb:(208,255,20) },
-    "artichokegreen" => crate::Color{ name:"Artichoke green", hex:"#4B6F44", rgb:(75,111,68) },
-    "arylideyellow" => crate::Color{ name:"Arylide yellow", hex:"#E9D66B", rgb:(233,214,107) },
-    "ashgray" => crate::Color{ name:"Ash gray", hex:"#B2BEB5", rgb:(178,190,181) },
-    "atomictangerine" => crate::Color{ name:"Atomic tangerine", hex:"#FF9966", rgb:(255,153,102) },
-    "aureolin" => crate::Color{ name:"Aureolin", hex:"#FDEE00", rgb:(253,238,0) },
-    "azure" => crate::Color{ name:"Azure", hex:"#007FFF", rgb:(0,127,255) },
-    "azurex11webcolor" => crate::Color{ name:"Azure (X11/web color)", hex:"#F0FFFF", rgb:(240,255,255) },
-    "babyblue" => crate::Color{ name:"Baby blue", hex:"#89CFF0", rgb:(137,207,240) },
-    "babyblueeyes" => crate::Color{ name:"Baby blue eyes", hex:"#A1CAF1", rgb:(161,202,241) },
-    "babypink" => crate::Color{ name:"Baby pink", hex:"#F4C2C2", rgb:(244,194,194) },
-    "babypowder" => crate::Color{ name:"Baby powder", hex:"#FEFEFA", rgb:(254,254,250) },
-    "bakermillerpink" => crate::Color{ name:"Baker-Miller pink", hex:"#FF91AF", rgb:(255,145,175) },
-    "bananamania" => crate::Color{ name:"Banana Mania", hex:"#FAE7B5", rgb:(250,231,181) },
-    "barbiepink" => crate::Color{ name:"Barbie Pink", hex:"#DA1884", rgb:(218,24,132) },
-    "barnred" => crate::Color{ name:"Barn red", hex:"#7C0A02", rgb:(124,10,2) },
-    "battleshipgrey" => crate::Color{ name:"Battleship grey", hex:"#848482", rgb:(132,132,130) },
-    "beaublue" => crate::Color{ name:"Beau blue", hex:"#BCD4E6", rgb:(188,212,230) },
-    "beaver" => crate::Color{ name:"Beaver", hex:"#9F8170", rgb:(159,129,112) },
-    "beige" => crate::Color{ name:"Beige", hex:"#F5F5DC", rgb:(245,245,220) },
-    "bdazzledblue" => crate::Color{ name:"B'dazzled blue", hex:"#2E5894", rgb:(46,88,148) },
-    "bigdiporuby" => crate::Color{ name:"Big dip o’ruby", hex:"#9C2542", rgb:(156,37,66) },
-    "bisque" => crate::Color{ name:"Bisque", hex:"#FFE4C4", rgb:(255,228,196) },
-    "bistre" => crate::Color{ name:"Bistre", hex:"#3D2B1F", rgb:(61,43,31) },
-    "bistrebrown" => crate::Color{ name:"Bistre brown", hex:"#967117", rgb:(150,113,23) },
-    "bitterlemon" => crate::Color{ name:"Bitter lemon", hex:"#CAE00D", rgb:(202,224,13) },
-    "black" => crate::Color{ name:"Black", hex:"#000000", rgb:(0,0,0) },
-    "blackbean" => crate::Color{ name:"Black bean", hex:"#3D0C02", rgb:(61,12,2) },
-    "blackcoral" => crate::Color{ name:"Black coral", hex:"#54626F", rgb:(84,98,111) },
-    "blackolive" => crate::Color{ name:"Black olive", hex:"#3B3C36", rgb:(59,60,54) },
-    "blackshadows" => crate::Color{ name:"Black Shadows", hex:"#BFAFB2", rgb:(191,175,178) },
-    "blanchedalmond" => crate::Color{ name:"Blanched almond", hex:"#FFEBCD", rgb:(255,235,205) },
-    "blastoffbronze" => crate::Color{ name:"Blast-off bronze", hex:"#A57164", rgb:(165,113,100) },
-    "bleudefrance" => crate::Color{ name:"Bleu de France", hex:"#318CE7", rgb:(49,140,231) },
-    "blizzardblue" => crate::Color{ name:"Blizzard blue", hex:"#ACE5EE", rgb:(172,229,238) },
-    "bloodred" => crate::Color{ name:"Blood red", hex:"#660000", rgb:(102,0,0) },
-    "blue" => crate::Color{ name:"Blue", hex:"#0000FF", rgb:(0,0,255) },
-    "bluecrayola" => crate::Color{ name:"Blue (Crayola)", hex:"#1F75FE", rgb:(31,117,254) },
-    "bluemunsell" => crate::Color{ name:"Blue (Munsell)", hex:"#0093AF", rgb:(0,147,175) },
-    "bluencs" => crate::Color{ name:"Blue (NCS)", hex:"#0087BD", rgb:(0,135,189) },
-    "bluepantone" => crate::Color{ name:"Blue (Pantone)", hex:"#0018A8", rgb:(0,24,168) },
-    "bluepigment" => crate::Color{ name:"Blue (pigment)", hex:"#333399", rgb:(51,51,153) },
-    "bluebell" => crate::Color{ name:"Blue bell", hex:"#A2A2D0", rgb:(162,162,208) },
-    "bluegraycrayola" => crate::Color{ name:"Blue-gray(Crayola)", hex:"#6699CC", rgb:(102,153,204) },
-    "bluejeans" => crate::Color{ name:"Blue jeans", hex:"#5DADEC", rgb:(93,173,236) },
-    "bluesapphire" => crate::Color{ name:"Blue sapphire", hex:"#126180", rgb:(18,97,128) },
-    "blueviolet" => crate::Color{ name:"Blue-violet", hex:"#8A2BE2", rgb:(138,43,226) },
-    "blueyonder" => crate::Color{ name:"Blue yonder", hex:"#5072A7", rgb:(80,114,167) },
-    "bluetiful" => crate::Color{ name:"Bluetiful", hex:"#3C69E7", rgb:(60,105,231) },
-    "blush" => crate::Color{ name:"Blush", hex:"#DE5D83", rgb:(222,93,131) },
-    "bole" => crate::Color{ name:"Bole", hex:"#79443B", rgb:(121,68,59) },
-    "bone" => crate::Color{ name:"Bone", hex:"#E3DAC9", rgb:(227,218,201) },
-    "brickred" => crate::Color{ name:"Brick red", hex:"#CB4154", rgb:(203,65,84) },
-    "brightlilac" => crate::Color{ name:"Bright lilac", hex:"#D891EF", rgb:(216,145,239) },
-    "brightyellowcrayola" => crate::Color{ name:"Bright yellow (Crayola)", hex:"#FFAA1D", rgb:(255,170,29) },
-    "britishracinggreen" => crate::Color{ name:"British racing green", hex:"#004225", rgb:(0,66,37) },
-    "bronze" => crate::Color{ name:"Bronze", hex:"#CD7F32", rgb:(205,127,50) },
-    "brown" => crate::Color{ name:"Brown", hex:"#964B00", rgb:(150,75,0) },
-    "brownsugar" => crate::Color{ name:"Brown sugar", hex:"#AF6E4D", rgb:(175,110,77) },
-    "budgreen" => crate::Color{ name:"Bud green", hex:"#7BB661", rgb:(123,182,97) },
-    "buff" => crate::Color{ name:"Buff", hex:"#FFC680", rgb:(255,198,128) },
-    "burgundy" => crate::Color{ name:"Burgundy", hex:"#800020", rgb:(128,0,32) },
-    "burlywood" => crate::Color{ name:"Burlywood", hex:"#DEB887", rgb:(222,184,135) },
-    "burnishedbrown" => crate::Color{ name:"Burnished brown", hex:"#A17A74", rgb:(161,122,116) },
-    "burntorange" => crate::Color{ name:"Burnt orange", hex:"#CC5500", rgb:(204,85,0) },
-    "burntsienna" => crate::Color{ name:"Burnt sienna", hex:"#E97451", rgb:(233,116,81) },
-    "burntumber" => crate::Color{ name:"Burnt umber", hex:"#8A3324", rgb:(138,51,36) },
-    "byzantine" => crate::Color{ name:"Byzantine", hex:"#BD33A4", rgb:(189,51,164) },
-    "byzantium" => crate::Color{ name:"Byzantium", hex:"#702963", rgb:(112,41,99) },
-    "cadetblue" => crate::Color{ name:"Cadet blue", hex:"#5F9EA0", rgb:(95,158,160) },
-    "cadetgrey" => crate::Color{ name:"Cadet grey", hex:"#91A3B0", rgb:(145,163,176) },
-    "cadmiumgreen" => crate::Color{ name:"Cadmium green", hex:"#006B3C", rgb:(0,107,60) },
-    "cadmiumorange" => crate::Color{ name:"Cadmium orange", hex:"#ED872D", rgb:(237,135,45) },
-    "cafaulait" => crate::Color{ name:"Café au lait", hex:"#A67B5B", rgb:(166,123,91) },
-    "cafnoir" => crate::Color{ name:"Café noir", hex:"#4B3621", rgb:(75,54,33) },
-    "cambridgeblue" => crate::Color{ name:"Cambridge blue", hex:"#A3C1AD", rgb:(163,193,173) },
-    "camel" => crate::Color{ name:"Camel", hex:"#C19A6B", rgb:(193,154,107) },
-    "cameopink" => crate::Color{ name:"Cameo pink", hex:"#EFBBCC", rgb:(239,187,204) },
-    "canary" => crate::Color{ name:"Canary", hex:"#FFFF99", rgb:(255,255,153) },
-    "canaryyellow" => crate::Color{ name:"Canary yellow", hex:"#FFEF00", rgb:(255,239,0) },
-    "candypink" => crate::Color{ name:"Candy pink", hex:"#E4717A", rgb:(228,113,122) },
-    "cardinal" => crate::Color{ name:"Cardinal", hex:"#C41E3A", rgb:(196,30,58) },
-    "caribbeangreen" => crate::Color{ name:"Caribbean green", hex:"#00CC99", rgb:(0,204,153) },
-    "carmine" => crate::Color{ name:"Carmine", hex:"#960018", rgb:(150,0,24) },
-    "carminemp" => crate::Color{ name:"Carmine (M&P)", hex:"#D70040", rgb:(215,0,64) },
-    "carnationpink" => crate::Color{ name:"Carnation pink", hex:"#FFA6C9", rgb:(255,166,201) },
-    "carnelian" => crate::Color{ name:"Carnelian", hex:"#B31B1B", rgb:(179,27,27) },
-    "carolinablue" => crate::Color{ name:"Carolina blue", hex:"#56A0D3", rgb:(86,160,211) },
-    "carrotorange" => crate::Color{ name:"Carrot orange", hex:"#ED9121", rgb:(237,145,33) },
-    "catawba" => crate::Color{ name:"Catawba", hex:"#703642", rgb:(112,54,66) },
-    "cedarchest" => crate::Color{ name:"Cedar Chest", hex:"#C95A49", rgb:(201,90,73) },
-    "celadon" => crate::Color{ name:"Celadon", hex:"#ACE1AF", rgb:(172,225,175) },
-    "celeste" => crate::Color{ name:"Celeste", hex:"#B2FFFF", rgb:(178,255,255) },
-    "cerise" => crate::Color{ name:"Cerise", hex:"#DE3163", rgb:(222,49,99) },
-    "cerulean" => crate::Color{ name:"Cerulean", hex:"#007BA7", rgb:(0,123,167) },
-    "ceruleanblue" => crate::Color{ name:"Cerulean blue", hex:"#2A52BE", rgb:(42,82,190) },
-    "ceruleanfrost" => crate::Color{ name:"Cerulean frost", hex:"#6D9BC3", rgb:(109,155,195) },
-    "ceruleancrayola" => crate::Color{ name:"Cerulean (Crayola)", hex:"#1DACD6", rgb:(29,172,214) },
-    "ceruleanrgb" => crate::Color{ name:"Cerulean (RGB)", hex:"#0040FF", rgb:(0,64,255) },
-    "champagne" => crate::Color{ name:"Champagne", hex:"#F7E7CE", rgb:(247,231,206) },
-    "champagnepink" => crate::Color{ name:"Champagne pink", hex:"#F1DDCF", rgb:(241,221,207) },
-    "charcoal" => crate::Color{ name:"Charcoal", hex:"#36454F", rgb:(54,69,79) },
-    "charmpink" => crate::Color{ name:"Charm pink", hex:"#E68FAC", rgb:(230,143,172) },
-    "chartreuseweb" => crate::Color{ name:"Chartreuse (web)", hex:"#80FF00", rgb:(128,255,0) },
-    "cherryblossompink" => crate::Color{ name:"Cherry blossom pink", hex:"#FFB7C5", rgb:(255,183,197) },
-    "chestnut" => crate::Color{ name:"Chestnut", hex:"#954535", rgb:(149,69,53) },
-    "chilired" => crate::Color{ name:"Chili red", hex:"#E23D28", rgb:(226,61,40) },
-    "chinapink" => crate::Color{ name:"China pink", hex:"#DE6FA1", rgb:(222,111,161) },
-    "chinesered" => crate::Color{ name:"Chinese red", hex:"#AA381E", rgb:(170,56,30) },
-    "chineseviolet" => crate::Color{ name:"Chinese violet", hex:"#856088", rgb:(133,96,136) },
-    "chineseyellow" => crate::Color{ name:"Chinese yellow", hex:"#FFB200", rgb:(255,178,0) },
-    "chocolatetraditional" => crate::Color{ name:"Chocolate (traditional)", hex:"#7B3F00", rgb:(123,63,0) },
-    "chocolateweb" => crate::Color{ name:"Chocolate (web)", hex:"#D2691E", rgb:(210,105,30) },
-    "cinereous" => crate::Color{ name:"Cinereous", hex:"#98817B", rgb:(152,129,123) },
-    "cinnabar" => crate::Color{ name:"Cinnabar", hex:"#E34234", rgb:(227,66,52) },
-    "cinnamonsatin" => crate::Color{ name:"Cinnamon Satin", hex:"#CD607E", rgb:(205,96,126) },
-    "citrine" => crate::Color{ name:"Citrine", hex:"#E4D00A", rgb:(228,208,10) },
-    "citron" => crate::Color{ name:"Citron", hex:"#9FA91F", rgb:(159,169,31) },
-    "claret" => crate::Color{ name:"Claret", hex:"#7F1734", rgb:(127,23,52) },
-    "coffee" => crate::Color{ name:"Coffee", hex:"#6F4E37", rgb:(111,78,55) },
-    "columbiablue" => crate::Color{ name:"Columbia Blue", hex:"#B9D9EB", rgb:(185,217,235) },
-    "congopink" => crate::Color{ name:"Congo pink", hex:"#F88379", rgb:(248,131,121) },
-    "coolgrey" => crate::Color{ name:"Cool grey", hex:"#8C92AC", rgb:(140,146,172) },
-    "copper" => crate::Color{ name:"Copper", hex:"#B87333", rgb:(184,115,51) },
-    "coppercrayola" => crate::Color{ name:"Copper (Crayola)", hex:"#DA8A67", rgb:(218,138,103) },
-    "copperpenny" => crate::Color{ name:"Copper penny", hex:"#AD6F69", rgb:(173,111,105) },
-    "copperred" => crate::Color{ name:"Copper red", hex:"#CB6D51", rgb:(203,109,81) },
-    "copperrose" => crate::Color{ name:"Copper rose", hex:"#996666", rgb:(153,102,102) },
-    "coquelicot" => crate::Color{ name:"Coquelicot", hex:"#FF3800", rgb:(255,56,0) },
-    "coral" => crate::Color{ name:"Coral", hex:"#FF7F50", rgb:(255,127,80) },
-    "coralpink" => crate::Color{ name:"Coral pink", hex:"#F88379", rgb:(248,131,121) },
-    "cordovan" => crate::Color{ name:"Cordovan", hex:"#893F45", rgb:(137,63,69) },
-    "corn" => crate::Color{ name:"Corn", hex:"#FBEC5D", rgb:(251,236,93) },
-    "cornflowerblue" => crate::Color{ name:"Cornflower blue", hex:"#6495ED", rgb:(100,149,237) },
-    "cornsilk" => crate::Color{ name:"Cornsilk", hex:"#FFF8DC", rgb:(255,248,220) },
-    "cosmiccobalt" => crate::Color{ name:"Cosmic cobalt", hex:"#2E2D88", rgb:(46,45,136) },
-    "cosmiclatte" => crate::Color{ name:"Cosmic latte", hex:"#FFF8E7", rgb:(255,248,231) },
-    "coyotebrown" => crate::Color{ name:"Coyote brown", hex:"#81613C", rgb:(129,97,60) },
-    "cottoncandy" => crate::Color{ name:"Cotton candy", hex:"#FFBCD9", rgb:(255,188,217) },
-    "cream" => crate::Color{ name:"Cream", hex:"#FFFDD0", rgb:(255,253,208) },
-    "crimson" => crate::Color{ name:"Crimson", hex:"#DC143C", rgb:(220,20,60) },
-    "crimsonua" => crate::Color{ name:"Crimson (UA)", hex:"#9E1B32", rgb:(158,27,50) },
-    "culturedpearl" => crate::Color{ name:"Cultured Pearl", hex:"#F5F5F5", rgb:(245,245,245) },
-    "cyan" => crate::Color{ name:"Cyan", hex:"#00FFFF", rgb:(0,255,255) },
-    "cyanprocess" => crate::Color{ name:"Cyan (process)", hex:"#00B7EB", rgb:(0,183,235) },
-    "cybergrape" => crate::Color{ name:"Cyber grape", hex:"#58427C", rgb:(88,66,124) },
-    "cyberyellow" => crate::Color{ name:"Cyber yellow", hex:"#FFD300", rgb:(255,211,0) },
-    "cyclamen" => crate::Color{ name:"Cyclamen", hex:"#F56FA1", rgb:(245,111,161) },
-    "dandelion" => crate::Color{ name:"Dandelion", hex:"#FED85D", rgb:(254,216,93) },
-    "darkbrown" => crate::Color{ name:"Dark brown", hex:"#654321", rgb:(101,67,33) },
-    "darkbyzantium" => crate::Color{ name:"Dark byzantium", hex:"#5D3954", rgb:(93,57,84) },
-    "darkcyan" => crate::Color{ name:"Dark cyan", hex:"#008B8B", rgb:(0,139,139) },
-    "darkelectricblue" => crate::Color{ name:"Dark electric blue", hex:"#536878", rgb:(83,104,120) },
-    "darkgoldenrod" => crate::Color{ name:"Dark goldenrod", hex:"#B8860B", rgb:(184,134,11) },
-    "darkgreenx11" => crate::Color{ name:"Dark green (X11)", hex:"#006400", rgb:(0,100,0) },
-    "darkjunglegreen" => crate::Color{ name:"Dark jungle green", hex:"#1A2421", rgb:(26,36,33) },
-    "darkkhaki" => crate::Color{ name:"Dark khaki", hex:"#BDB76B", rgb:(189,183,107) },
-    "darklava" => crate::Color{ name:"Dark lava", hex:"#483C32", rgb:(72,60,50) },
-    "darkliverhorses" => crate::Color{ name:"Dark liver (horses)", hex:"#543D37", rgb:(84,61,55) },
-    "darkmagenta" => crate::Color{ name:"Dark magenta", hex:"#8B008B", rgb:(139,0,139) },
-    "darkolivegreen" => crate::Color{ name:"Dark olive green", hex:"#556B2F", rgb:(85,107,47) },
-    "darkorange" => crate::Color{ name:"Dark orange", hex:"#FF8C00", rgb:(255,140,0) },
-    "darkorchid" => crate::Color{ name:"Dark orchid", hex:"#9932CC", rgb:(153,50,204) },
-    "darkpurple" => crate::Color{ name:"Dark purple", hex:"#301934", rgb:(48,25,52) },
-    "darkred" => crate::Color{ name:"Dark red", hex:"#8B0000", rgb:(139,0,0) },
-    "darksalmon" => crate::Color{ name:"Dark salmon", hex:"#E9967A", rgb:(233,150,122) },
-    "darkseagreen" => crate::Color{ name:"Dark sea green", hex:"#8FBC8F", rgb:(143,188,143) },
-    "darksienna" => crate::Color{ name:"Dark sienna", hex:"#3C1414", rgb:(60,20,20) },
-    "darkskyblue" => crate::Color{ name:"Dark sky blue", hex:"#8CBED6", rgb:(140,190,214) },
-    "darkslateblue" => crate::Color{ name:"Dark slate blue", hex:"#483D8B", rgb:(72,61,139) },
-    "darkslategray" => crate::Color{ name:"Dark slate gray", hex:"#2F4F4F", rgb:(47,79,79) },
-    "darkspringgreen" => crate::Color{ name:"Dark spring green", hex:"#177245", rgb:(23,114,69) },
-    "darkturquoise" => crate::Color{ name:"Dark turquoise", hex:"#00CED1", rgb:(0,206,209) },
-    "darkviolet" => crate::Color{ name:"Dark violet", hex:"#9400D3", rgb:(148,0,211) },
-    "davysgrey" => crate::Color{ name:"Davy's grey", hex:"#555555", rgb:(85,85,85) },
-    "deepcerise" => crate::Color{ name:"Deep cerise", hex:"#DA3287", rgb:(218,50,135) },
-    "deepchampagne" => crate::Color{ name:"Deep champagne", hex:"#FAD6A5", rgb:(250,214,165) },
-    "deepchestnut" => crate::Color{ name:"Deep chestnut", hex:"#B94E48", rgb:(185,78,72) },
-    "deepjunglegreen" => crate::Color{ name:"Deep jungle green", hex:"#004B49", rgb:(0,75,73) },
-    "deeppink" => crate::Color{ name:"Deep pink", hex:"#FF1493", rgb:(255,20,147) },
-    "deepsaffron" => crate::Color{ name:"Deep saffron", hex:"#FF9933", rgb:(255,153,51) },
-    "deepskyblue" => crate::Color{ name:"Deep sky blue", hex:"#00BFFF", rgb:(0,191,255) },
-    "deepspacesparkle" => crate::Color{ name:"Deep Space Sparkle", hex:"#4A646C", rgb:(74,100,108) },
-    "deeptaupe" => crate::Color{ name:"Deep taupe", hex:"#7E5E60", rgb:(126,94,96) },
-    "denim" => crate::Color{ name:"Denim", hex:"#1560BD", rgb:(21,96,189) },
-    "denimblue" => crate::Color{ name:"Denim blue", hex:"#2243B6", rgb:(34,67,182) },
-    "desert" => crate::Color{ name:"Desert", hex:"#C19A6B", rgb:(193,154,107) },
-    "desertsand" => crate::Color{ name:"Desert sand", hex:"#EDC9AF", rgb:(237,201,175) },
-    "dimgray" => crate::Color{ name:"Dim gray", hex:"#696969", rgb:(105,105,105) },
-    "dodgerblue" => crate::Color{ name:"Dodger blue", hex:"#1E90FF", rgb:(30,144,255) },
-    "drabdarkbrown" => crate::Color{ name:"Drab dark brown", hex:"#4A412A", rgb:(74,65,42) },
-    "dukeblue" => crate::Color{ name:"Duke blue", hex:"#00009C", rgb:(0,0,156) },
-    "dutchwhite" => crate::Color{ name:"Dutch white", hex:"#EFDFBB", rgb:(239,223,187) },
-    "ebony" => crate::Color{ name:"Ebony", hex:"#555D50", rgb:(85,93,80) },
-    "ecru" => crate::Color{ name:"Ecru", hex:"#C2B280", rgb:(194,178,128) },
-    "eerieblack" => crate::Color{ name:"Eerie black", hex:"#1B1B1B", rgb:(27,27,27) },
-    "eggplant" => crate::Color{ name:"Eggplant", hex:"#614051", rgb:(97,64,81) },
-    "eggshell" => crate::Color{ name:"Eggshell", hex:"#F0EAD6", rgb:(240,234,214) },
-    "electriclime" => crate::Color{ name:"Electric lime", hex:"#CCFF00", rgb:(204,255,0) },
-    "electricpurple" => crate::Color{ name:"Electric purple", hex:"#BF00FF", rgb:(191,0,255) },
-    "electricviolet" => crate::Color{ name:"Electric violet", hex:"#8F00FF", rgb:(143,0,255) },
-    "emerald" => crate::Color{ name:"Emerald", hex:"#50C878", rgb:(80,200,120) },
-    "eminence" => crate::Color{ name:"Eminence", hex:"#6C3082", rgb:(108,48,130) },
-    "englishlavender" => crate::Color{ name:"English lavender", hex:"#B48395", rgb:(180,131,149) },
-    "englishred" => crate::Color{ name:"English red", hex:"#AB4B52", rgb:(171,75,82) },
-    "englishvermillion" => crate::Color{ name:"English vermillion", hex:"#CC474B", rgb:(204,71,75) },
-    "englishviolet" => crate::Color{ name:"English violet", hex:"#563C5C", rgb:(86,60,92) },
-    "erin" => crate::Color{ name:"Erin", hex:"#00FF40", rgb:(0,255,64) },
-    "etonblue" => crate::Color{ name:"Eton blue", hex:"#96C8A2", rgb:(150,200,162) },
-    "fallow" => crate::Color{ name:"Fallow", hex:"#C19A6B", rgb:(193,154,107) },
-    "falured" => crate::Color{ name:"Falu red", hex:"#801818", rgb:(128,24,24) },
-    "fandango" => crate::Color{ name:"Fandango", hex:"#B53389", rgb:(181,51,137) },
-    "fandangopink" => crate::Color{ name:"Fandango pink", hex:"#DE5285", rgb:(222,82,133) },
-    "fawn" => crate::Color{ name:"Fawn", hex:"#E5AA70", rgb:(229,170,112) },
-    "ferngreen" => crate::Color{ name:"Fern green", hex:"#4F7942", rgb:(79,121,66) },
-    "fielddrab" => crate::Color{ name:"Field drab", hex:"#6C541E", rgb:(108,84,30) },
-    "fieryrose" => crate::Color{ name:"Fiery rose", hex:"#FF5470", rgb:(255,84,112) },
-    "finn" => crate::Color{ name:"Finn", hex:"#683068", rgb:(104,48,104) },
-    "firebrick" => crate::Color{ name:"Firebrick", hex:"#B22222", rgb:(178,34,34) },
-    "fireenginered" => crate::Color{ name:"Fire engine red", hex:"#CE2029", rgb:(206,32,41) },
-    "flame" => crate::Color{ name:"Flame", hex:"#E25822", rgb:(226,88,34) },
-    "flax" => crate::Color{ name:"Flax", hex:"#EEDC82", rgb:(238,220,130) },
-    "flirt" => crate::Color{ name:"Flirt", hex:"#A2006D", rgb:(162,0,109) },
-    "floralwhite" => crate::Color{ name:"Floral white", hex:"#FFFAF0", rgb:(255,250,240) },
-    "forestgreenweb" => crate::Color{ name:"Forest green (web)", hex:"#228B22", rgb:(34,139,34) },
-    "frenchbeige" => crate::Color{ name:"French beige", hex:"#A67B5B", rgb:(166,123,91) },
-    "frenchbistre" => crate::Color{ name:"French bistre", hex:"#856D4D", rgb:(133,109,77) },
-    "frenchblue" => crate::Color{ name:"French blue", hex:"#0072BB", rgb:(0,114,187) },
-    "frenchfuchsia" => crate::Color{ name:"French fuchsia", hex:"#FD3F92", rgb:(253,63,146) },
-    "frenchlilac" => crate::Color{ name:"French lilac", hex:"#86608E", rgb:(134,96,142) },
-    "frenchlime" => crate::Color{ name:"French lime", hex:"#9EFD38", rgb:(158,253,56) },
-    "frenchmauve" => crate::Color{ name:"French mauve", hex:"#D473D4", rgb:(212,115,212) },
-    "frenchpink" => crate::Color{ name:"French pink", hex:"#FD6C9E", rgb:(253,108,158) },
-    "frenchraspberry" => crate::Color{ name:"French raspberry", hex:"#C72C48", rgb:(199,44,72) },
-    "frenchskyblue" => crate::Color{ name:"French sky blue", hex:"#77B5FE", rgb:(119,181,254) },
-    "frenchviolet" => crate::Color{ name:"French violet", hex:"#8806CE", rgb:(136,6,206) },
-    "frostbite" => crate::Color{ name:"Frostbite", hex:"#E936A7", rgb:(233,54,167) },
-    "fuchsia" => crate::Color{ name:"Fuchsia", hex:"#FF00FF", rgb:(255,0,255) },
-    "fuchsiacrayola" => crate::Color{ name:"Fuchsia (Crayola)", hex:"#C154C1", rgb:(193,84,193) },
-    "fulvous" => crate::Color{ name:"Fulvous", hex:"#E48400", rgb:(228,132,0) },
-    "fuzzywuzzy" => crate::Color{ name:"Fuzzy Wuzzy", hex:"#87421F", rgb:(135,66,31) },
-    "gainsboro" => crate::Color{ name:"Gainsboro", hex:"#DCDCDC", rgb:(220,220,220) },
-    "gamboge" => crate::Color{ name:"Gamboge", hex:"#E49B0F", rgb:(228,155,15) },
-    "genericviridian" => crate::Color{ name:"Generic viridian", hex:"#007F66", rgb:(0,127,102) },
-    "ghostwhite" => crate::Color{ name:"Ghost white", hex:"#F8F8FF", rgb:(248,248,255) },
-    "glaucous" => crate::Color{ name:"Glaucous", hex:"#6082B6", rgb:(96,130,182) },
-    "glossygrape" => crate::Color{ name:"Glossy grape", hex:"#AB92B3", rgb:(171,146,179) },
-    "gogreen" => crate::Color{ name:"GO green", hex:"#00AB66", rgb:(0,171,102) },
-    "goldmetallic" => crate::Color{ name:"Gold (metallic)", hex:"#D4AF37", rgb:(212,175,55) },
-    "goldwebgolden" => crate::Color{ name:"Gold (web) (Golden)", hex:"#FFD700", rgb:(255,215,0) },
-    "goldcrayola" => crate::Color{ name:"Gold (Crayola)", hex:"#E6BE8A", rgb:(230,190,138) },
-    "goldfusion" => crate::Color{ name:"Gold Fusion", hex:"#85754E", rgb:(133,117,78) },
-    "goldenbrown" => crate::Color{ name:"Golden brown", hex:"#996515", rgb:(153,101,21) },
-    "goldenpoppy" => crate::Color{ name:"Golden poppy", hex:"#FCC200", rgb:(252,194,0) },
-    "goldenyellow" => crate::Color{ name:"Golden yellow", hex:"#FFDF00", rgb:(255,223,0) },
-    "goldenrod" => crate::Color{ name:"Goldenrod", hex:"#DAA520", rgb:(218,165,32) },
-    "gothamgreen" => crate::Color{ name:"Gotham green", hex:"#00573F", rgb:(0,87,63) },
-    "granitegray" => crate::Color{ name:"Granite gray", hex:"#676767", rgb:(103,103,103) },
-    "grannysmithapple" => crate::Color{ name:"Granny Smith apple", hex:"#A8E4A0", rgb:(168,228,160) },
-    "grayweb" => crate::Color{ name:"Gray (web)", hex:"#808080", rgb:(128,128,128) },
-    "grayx11gray" => crate::Color{ name:"Gray (X11 gray)", hex:"#BEBEBE", rgb:(190,190,190) },
-    "green" => crate::Color{ name:"Green", hex:"#00FF00", rgb:(0,255,0) },
-    "greencrayola" => crate::Color{ name:"Green (Crayola)", hex:"#1CAC78", rgb:(28,172,120) },
-    "greenweb" => crate::Color{ name:"Green (web)", hex:"#008000", rgb:(0,128,0) },
-    "greenmunsell" => crate::Color{ name:"Green (Munsell)", hex:"#00A877", rgb:(0,168,119) },
-    "greenncs" => crate::Color{ name:"Green (NCS)", hex:"#009F6B", rgb:(0,159,107) },
-    "greenpantone" => crate::Color{ name:"Green (Pantone)", hex:"#00AD43", rgb:(0,173,67) },
-    "greenpigment" => crate::Color{ name:"Green (pigment)", hex:"#00A550", rgb:(0,165,80) },
-    "greenblue" => crate::Color{ name:"Green-blue", hex:"#1164B4", rgb:(17,100,180) },
-    "greenlizard" => crate::Color{ name:"Green Lizard", hex:"#A7F432", rgb:(167,244,50) },
-    "greensheen" => crate::Color{ name:"Green Sheen", hex:"#6EAEA1", rgb:(110,174,161) },
-    "gunmetal" => crate::Color{ name:"Gunmetal", hex:"#2A3439", rgb:(42,52,57) },
-    "hansayellow" => crate::Color{ name:"Hansa yellow", hex:"#E9D66B", rgb:(233,214,107) },
-    "harlequin" => crate::Color{ name:"Harlequin", hex:"#3FFF00", rgb:(63,255,0) },
-    "harvestgold" => crate::Color{ name:"Harvest gold", hex:"#DA9100", rgb:(218,145,0) },
-    "heatwave" => crate::Color{ name:"Heat Wave", hex:"#FF7A00", rgb:(255,122,0) },
-    "heliotrope" => crate::Color{ name:"Heliotrope", hex:"#DF73FF", rgb:(223,115,255) },
-    "heliotropegray" => crate::Color{ name:"Heliotrope gray", hex:"#AA98A9", rgb:(170,152,169) },
-    "hollywoodcerise" => crate::Color{ name:"Hollywood cerise", hex:"#F400A1", rgb:(244,0,161) },
-    "honolulublue" => crate::Color{ name:"Honolulu blue", hex:"#006DB0", rgb:(0,109,176) },
-    "hookersgreen" => crate::Color{ name:"Hooker's green", hex:"#49796B", rgb:(73,121,107) },
-    "hotmagentabrokenanchor" => crate::Color{ name:"Hot magenta[broken anchor]", hex:"#FF1DCE", rgb:(255,29,206) },
-    "hotpink" => crate::Color{ name:"Hot pink", hex:"#FF69B4", rgb:(255,105,180) },
-    "huntergreen" => crate::Color{ name:"Hunter green", hex:"#355E3B", rgb:(53,94,59) },
-    "iceberg" => crate::Color{ name:"Iceberg", hex:"#71A6D2", rgb:(113,166,210) },
-    "illuminatingemerald" => crate::Color{ name:"Illuminating emerald", hex:"#319177", rgb:(49,145,119) },
-    "imperialred" => crate::Color{ name:"Imperial red", hex:"#ED2939", rgb:(237,41,57) },
-    "inchworm" => crate::Color{ name:"Inchworm", hex:"#B2EC5D", rgb:(178,236,93) },
-    "independence" => crate::Color{ name:"Independence", hex:"#4C516D", rgb:(76,81,109) },
-    "indiagreen" => crate::Color{ name:"India green", hex:"#138808", rgb:(19,136,8) },
-    "indianred" => crate::Color{ name:"Indian red", hex:"#CD5C5C", rgb:(205,92,92) },
-    "indianyellow" => crate::Color{ name:"Indian yellow", hex:"#E3A857", rgb:(227,168,87) },
-    "indigo" => crate::Color{ name:"Indigo", hex:"#6A5DFF", rgb:(106,93,255) },
-    "indigodye" => crate::Color{ name:"Indigo dye", hex:"#00416A", rgb:(0,65,106) },
-    "internationalkleinblue" => crate::Color{ name:"International Klein Blue", hex:"#130A8F", rgb:(19,10,143) },
-    "internationalorangeengineering" => crate::Color{ name:"International orange (engineering)", hex:"#BA160C", rgb:(186,22,12) },
-    "internationalorangegoldengatebridge" => crate::Color{ name:"International orange (Golden Gate Bridge)", hex:"#C0362C", rgb:(192,54,44) },
-    "irresistible" => crate::Color{ name:"Irresistible", hex:"#B3446C", rgb:(179,68,108) },
-    "isabelline" => crate::Color{ name:"Isabelline", hex:"#F4F0EC", rgb:(244,240,236) },
-    "italianskyblue" => crate::Color{ name:"Italian sky blue", hex:"#B2FFFF", rgb:(178,255,255) },
-    "ivory" => crate::Color{ name:"Ivory", hex:"#FFFFF0", rgb:(255,255,240) },
-    "japanesecarmine" => crate::Color{ name:"Japanese carmine", hex:"#9D2933", rgb:(157,41,51) },
-    "japaneseviolet" => crate::Color{ name:"Japanese violet", hex:"#5B3256", rgb:(91,50,86) },
-    "jasmine" => crate::Color{ name:"Jasmine", hex:"#F8DE7E", rgb:(248,222,126) },
-    "jazzberryjam" => crate::Color{ name:"Jazzberry jam", hex:"#A50B5E", rgb:(165,11,94) },
-    "jet" => crate::Color{ name:"Jet", hex:"#343434", rgb:(52,52,52) },
-    "jonquil" => crate::Color{ name:"Jonquil", hex:"#F4CA16", rgb:(244,202,22) },
-    "junebud" => crate::Color{ name:"June bud", hex:"#BDDA57", rgb:(189,218,87) },
-    "junglegreen" => crate::Color{ name:"Jungle green", hex:"#29AB87", rgb:(41,171,135) },
-    "kellygreen" => crate::Color{ name:"Kelly green", hex:"#4CBB17", rgb:(76,187,23) },
-    "keppel" => crate::Color{ name:"Keppel", hex:"#3AB09E", rgb:(58,176,158) },
-    "keylime" => crate::Color{ name:"Key lime", hex:"#E8F48C", rgb:(232,244,140) },
-    "khakiweb" => crate::Color{ name:"Khaki (web)", hex:"#C3B091", rgb:(195,176,145) },
-    "khakix11lightkhaki" => crate::Color{ name:"Khaki (X11) (Light khaki)", hex:"#F0E68C", rgb:(240,230,140) },
-    "kobe" => crate::Color{ name:"Kobe", hex:"#882D17", rgb:(136,45,23) },
-    "kobi" => crate::Color{ name:"Kobi", hex:"#E79FC4", rgb:(231,159,196) },
-    "kobicha" => crate::Color{ name:"Kobicha", hex:"#6B4423", rgb:(107,68,35) },
-    "ksupurple" => crate::Color{ name:"KSU purple", hex:"#512888", rgb:(81,40,136) },
-    "languidlavender" => crate::Color{ name:"Languid lavender", hex:"#D6CADD", rgb:(214,202,221) },
-    "lapislazuli" => crate::Color{ name:"Lapis lazuli", hex:"#26619C", rgb:(38,97,156) },
-    "laserlemon" => crate::Color{ name:"Laser lemon", hex:"#FFFF66", rgb:(255,255,102) },
-    "laurelgreen" => crate::Color{ name:"Laurel green", hex:"#A9BA9D", rgb:(169,186,157) },
-    "lava" => crate::Color{ name:"Lava", hex:"#CF1020", rgb:(207,16,32) },
-    "lavenderfloral" => crate::Color{ name:"Lavender (floral)", hex:"#B57EDC", rgb:(181,126,220) },
-    "lavenderweb" => crate::Color{ name:"Lavender (web)", hex:"#E6E6FA", rgb:(230,230,250) },
-    "lavenderblue" => crate::Color{ name:"Lavender blue", hex:"#CCCCFF", rgb:(204,204,255) },
-    "lavenderblush" => crate::Color{ name:"Lavender blush", hex:"#FFF0F5", rgb:(255,240,245) },
-    "lavendergray" => crate::Color{ name:"Lavender gray", hex:"#C4C3D0", rgb:(196,195,208) },
-    "lawngreen" => crate::Color{ name:"Lawn green", hex:"#7CFC00", rgb:(124,252,0) },
-    "lemon" => crate::Color{ name:"Lemon", hex:"#FFF700", rgb:(255,247,0) },
-    "lemonchiffon" => crate::Color{ name:"Lemon chiffon", hex:"#FFFACD", rgb:(255,250,205) },
-    "lemoncurry" => crate::Color{ name:"Lemon curry", hex:"#CCA01D", rgb:(204,160,29) },
-    "lemonglacier" => crate::Color{ name:"Lemon glacier", hex:"#FDFF00", rgb:(253,255,0) },
-    "lemonmeringue" => crate::Color{ name:"Lemon meringue", hex:"#F6EABE", rgb:(246,234,190) },
-    "lemonyellow" => crate::Color{ name:"Lemon yellow", hex:"#FFF44F", rgb:(255,244,79) },
-    "lemonyellowcrayola" => crate::Color{ name:"Lemon yellow (Crayola)", hex:"#FFFF9F", rgb:(255,255,159) },
-    "liberty" => crate::Color{ name:"Liberty", hex:"#545AA7", rgb:(84,90,167) },
-    "lightblue" => crate::Color{ name:"Light blue", hex:"#ADD8E6", rgb:(173,216,230) },
-    "lightcoral" => crate::Color{ name:"Light coral", hex:"#F08080", rgb:(240,128,128) },
-    "lightcornflowerblue" => crate::Color{ name:"Light cornflower blue", hex:"#93CCEA", rgb:(147,204,234) },
-    "lightcyan" => crate::Color{ name:"Light cyan", hex:"#E0FFFF", rgb:(224,255,255) },
-    "lightfrenchbeige" => crate::Color{ name:"Light French beige", hex:"#C8AD7F", rgb:(200,173,127) },
-    "lightgoldenrodyellow" => crate::Color{ name:"Light goldenrod yellow", hex:"#FAFAD2", rgb:(250,250,210) },
-    "lightgray" => crate::Color{ name:"Light gray", hex:"#D3D3D3", rgb:(211,211,211) },
-    "lightgreen" => crate::Color{ name:"Light green", hex:"#90EE90", rgb:(144,238,144) },
-    "lightorange" => crate::Color{ name:"Light orange", hex:"#FED8B1", rgb:(254,216,177) },
-    "lightperiwinkle" => crate::Color{ name:"Light periwinkle", hex:"#C5CBE1", rgb:(197,203,225) },
-    "lightpink" => crate::Color{ name:"Light pink", hex:"#FFB6C1", rgb:(255,182,193) },
-    "lightpurple" => crate::Color{ name:"Light purple", hex:"#D8BFD8", rgb:(216,191,216) },
-    "lightsalmon" => crate::Color{ name:"Light salmon", hex:"#FFA07A", rgb:(255,160,122) },
-    "lightseagreen" => crate::Color{ name:"Light sea green", hex:"#20B2AA", rgb:(32,178,170) },
-    "lightskyblue" => crate::Color{ name:"Light sky blue", hex:"#87CEFA", rgb:(135,206,250) },
-    "lightslategray" => crate::Color{ name:"Light slate gray", hex:"#778899", rgb:(119,136,153) },
-    "lightsteelblue" => crate::Color{ name:"Light steel blue", hex:"#B0C4DE", rgb:(176,196,222) },
-    "lightyellow" => crate::Color{ name:"Light yellow", hex:"#FFFFE0", rgb:(255,255,224) },
-    "lilac" => crate::Color{ name:"Lilac", hex:"#C8A2C8", rgb:(200,162,200) },
-    "lilacluster" => crate::Color{ name:"Lilac Luster", hex:"#AE98AA", rgb:(174,152,170) },
-    "limecolorwheel" => crate::Color{ name:"Lime (color wheel)", hex:"#BFFF00", rgb:(191,255,0) },
-    "limewebx11green" => crate::Color{ name:"Lime (web) (X11 green)", hex:"#00FF00", rgb:(0,255,0) },
-    "limegreen" => crate::Color{ name:"Lime green", hex:"#32CD32", rgb:(50,205,50) },
-    "lincolngreen" => crate::Color{ name:"Lincoln green", hex:"#195905", rgb:(25,89,5) },
-    "linen" => crate::Color{ name:"Linen", hex:"#FAF0E6", rgb:(250,240,230) },
-    "lion" => crate::Color{ name:"Lion", hex:"#DECC9C", rgb:(222,204,156) },
-    "liseranpurple" => crate::Color{ name:"Liseran purple", hex:"#DE6FA1", rgb:(222,111,161) },
-    "littleboyblue" => crate::Color{ name:"Little boy blue", hex:"#6CA0DC", rgb:(108,160,220) },
-    "liver" => crate::Color{ name:"Liver", hex:"#674C47", rgb:(103,76,71) },
-    "liverdogs" => crate::Color{ name:"Liver (dogs)", hex:"#B86D29", rgb:(184,109,41) },
-    "liverorgan" => crate::Color{ name:"Liver (organ)", hex:"#6C2E1F", rgb:(108,46,31) },
-    "liverchestnut" => crate::Color{ name:"Liver chestnut", hex:"#987456", rgb:(152,116,86) },
-    "livid" => crate::Color{ name:"Livid", hex:"#6699CC", rgb:(102,153,204) },
-    "macaroniandcheese" => crate::Color{ name:"Macaroni and Cheese", hex:"#FFBD88", rgb:(255,189,136) },
-    "madderlake" => crate::Color{ name:"Madder Lake", hex:"#CC3336", rgb:(204,51,54) },
-    "magenta" => crate::Color{ name:"Magenta", hex:"#FF00FF", rgb:(255,0,255) },
-    "magentacrayolabrokenanchor" => crate::Color{ name:"Magenta (Crayola)[broken anchor]", hex:"#F653A6", rgb:(246,83,166) },
-    "magentadye" => crate::Color{ name:"Magenta (dye)", hex:"#CA1F7B", rgb:(202,31,123) },
-    "magentapantone" => crate::Color{ name:"Magenta (Pantone)", hex:"#D0417E", rgb:(208,65,126) },
-    "magentaprocess" => crate::Color{ name:"Magenta (process)", hex:"#FF0090", rgb:(255,0,144) },
-    "magentahaze" => crate::Color{ name:"Magenta haze", hex:"#9F4576", rgb:(159,69,118) },
-    "magicmint" => crate::Color{ name:"Magic mint", hex:"#AAF0D1", rgb:(170,240,209) },
-    "magnolia" => crate::Color{ name:"Magnolia", hex:"#F2E8D7", rgb:(242,232,215) },
-    "mahogany" => crate::Color{ name:"Mahogany", hex:"#C04000", rgb:(192,64,0) },
-    "maize" => crate::Color{ name:"Maize", hex:"#FBEC5D", rgb:(251,236,93) },
-    "maizecrayola" => crate::Color{ name:"Maize (Crayola)", hex:"#F2C649", rgb:(242,198,73) },
-    "majorelleblue" => crate::Color{ name:"Majorelle blue", hex:"#6050DC", rgb:(96,80,220) },
-    "malachite" => crate::Color{ name:"Malachite", hex:"#0BDA51", rgb:(11,218,81) },
-    "manatee" => crate::Color{ name:"Manatee", hex:"#979AAA", rgb:(151,154,170) },
-    "mandarin" => crate::Color{ name:"Mandarin", hex:"#F37A48", rgb:(243,122,72) },
-    "mango" => crate::Color{ name:"Mango", hex:"#FDBE02", rgb:(253,190,2) },
-    "mangotango" => crate::Color{ name:"Mango Tango", hex:"#FF8243", rgb:(255,130,67) },
-    "mantis" => crate::Color{ name:"Mantis", hex:"#74C365", rgb:(116,195,101) },
-    "mardigras" => crate::Color{ name:"Mardi Gras", hex:"#880085", rgb:(136,0,133) },
-    "marigold" => crate::Color{ name:"Marigold", hex:"#EAA221", rgb:(234,162,33) },
-    "marooncrayola" => crate::Color{ name:"Maroon (Crayola)", hex:"#C32148", rgb:(195,33,72) },
-    "maroonweb" => crate::Color{ name:"Maroon (web)", hex:"#800000", rgb:(128,0,0) },
-    "maroonx11" => crate::Color{ name:"Maroon (X11)", hex:"#B03060", rgb:(176,48,96) },
-    "mauve" => crate::Color{ name:"Mauve", hex:"#E0B0FF", rgb:(224,176,255) },
-    "mauvetaupe" => crate::Color{ name:"Mauve taupe", hex:"#915F6D", rgb:(145,95,109) },
-    "mauvelous" => crate::Color{ name:"Mauvelous", hex:"#EF98AA", rgb:(239,152,170) },
-    "maximumblue" => crate::Color{ name:"Maximum blue", hex:"#47ABCC", rgb:(71,171,204) },
-    "maximumbluegreen" => crate::Color{ name:"Maximum blue green", hex:"#30BFBF", rgb:(48,191,191) },
-    "maximumbluepurple" => crate::Color{ name:"Maximum blue purple", hex:"#ACACE6", rgb:(172,172,230) },
-    "maximumgreen" => crate::Color{ name:"Maximum green", hex:"#5E8C31", rgb:(94,140,49) },
-    "maximumgreenyellow" => crate::Color{ name:"Maximum green yellow", hex:"#D9E650", rgb:(217,230,80) },
-    "maximumpurple" => crate::Color{ name:"Maximum purple", hex:"#733380", rgb:(115,51,128) },
-    "maximumred" => crate::Color{ name:"Maximum red", hex:"#D92121", rgb:(217,33,33) },
-    "maximumredpurple" => crate::Color{ name:"Maximum red purple", hex:"#A63A79", rgb:(166,58,121) },
-    "maximumyellow" => crate::Color{ name:"Maximum yellow", hex:"#FAFA37", rgb:(250,250,55) },
-    "maximumyellowred" => crate::Color{ name:"Maximum yellow red", hex:"#F2BA49", rgb:(242,186,73) },
-    "maygreen" => crate::Color{ name:"May green", hex:"#4C9141", rgb:(76,145,65) },
-    "mayablue" => crate::Color{ name:"Maya blue", hex:"#73C2FB", rgb:(115,194,251) },
-    "mediumaquamarine" => crate::Color{ name:"Medium aquamarine", hex:"#66DDAA", rgb:(102,221,170) },
-    "mediumblue" => crate::Color{ name:"Medium blue", hex:"#0000CD", rgb:(0,0,205) },
-    "mediumcandyapplered" => crate::Color{ name:"Medium candy apple red", hex:"#E2062C", rgb:(226,6,44) },
-    "mediumcarmine" => crate::Color{ name:"Medium carmine", hex:"#AF4035", rgb:(175,64,53) },
-    "mediumchampagne" => crate::Color{ name:"Medium champagne", hex:"#F3E5AB", rgb:(243,229,171) },
-    "mediumorchid" => crate::Color{ name:"Medium orchid", hex:"#BA55D3", rgb:(186,85,211) },
-    "mediumpurple" => crate::Color{ name:"Medium purple", hex:"#9370DB", rgb:(147,112,219) },
-    "mediumseagreen" => crate::Color{ name:"Medium sea green", hex:"#3CB371", rgb:(60,179,113) },
-    "mediumslateblue" => crate::Color{ name:"Medium slate blue", hex:"#7B68EE", rgb:(123,104,238) },
-    "mediumspringgreen" => crate::Color{ name:"Medium spring green", hex:"#00FA9A", rgb:(0,250,154) },
-    "mediumturquoise" => crate::Color{ name:"Medium turquoise", hex:"#48D1CC", rgb:(72,209,204) },
-    "mediumvioletred" => crate::Color{ name:"Medium violet-red", hex:"#C71585", rgb:(199,21,133) },
-    "mellowapricot" => crate::Color{ name:"Mellow apricot", hex:"#F8B878", rgb:(248,184,120) },
-    "mellowyellow" => crate::Color{ name:"Mellow yellow", hex:"#F8DE7E", rgb:(248,222,126) },
-    "melon" => crate::Color{ name:"Melon", hex:"#FEBAAD", rgb:(254,186,173) },
-    "metallicgold" => crate::Color{ name:"Metallic gold", hex:"#D3AF37", rgb:(211,175,55) },
-    "metallicseaweed" => crate::Color{ name:"Metallic Seaweed", hex:"#0A7E8C", rgb:(10,126,140) },
-    "metallicsunburst" => crate::Color{ name:"Metallic Sunburst", hex:"#9C7C38", rgb:(156,124,56) },
-    "mexicanpink" => crate::Color{ name:"Mexican pink", hex:"#E4007C", rgb:(228,0,124) },
-    "middleblue" => crate::Color{ name:"Middle blue", hex:"#7ED4E6", rgb:(126,212,230) },
-    "middlebluegreen" => crate::Color{ name:"Middle blue green", hex:"#8DD9CC", rgb:(141,217,204) },
-    "middlebluepurple" => crate::Color{ name:"Middle blue purple", hex:"#8B72BE", rgb:(139,114,190) },
-    "middlegrey" => crate::Color{ name:"Middle grey", hex:"#8B8680", rgb:(139,134,128) },
-    "middlegreen" => crate::Color{ name:"Middle green", hex:"#4D8C57", rgb:(77,140,87) },
-    "middlegreenyellow" => crate::Color{ name:"Middle green yellow", hex:"#ACBF60", rgb:(172,191,96) },
-    "middlepurple" => crate::Color{ name:"Middle purple", hex:"#D982B5", rgb:(217,130,181) },
-    "middlered" => crate::Color{ name:"Middle red", hex:"#E58E73", rgb:(229,142,115) },
-    "middleredpurple" => crate::Color{ name:"Middle red purple", hex:"#A55353", rgb:(165,83,83) },
-    "middleyellow" => crate::Color{ name:"Middle yellow", hex:"#FFEB00", rgb:(255,235,0) },
-    "middleyellowred" => crate::Color{ name:"Middle yellow red", hex:"#ECB176", rgb:(236,177,118) },
-    "midnight" => crate::Color{ name:"Midnight", hex:"#702670", rgb:(112,38,112) },
-    "midnightblue" => crate::Color{ name:"Midnight blue", hex:"#191970", rgb:(25,25,112) },
-    "midnightgreeneaglegreen" => crate::Color{ name:"Midnight green (eagle green)", hex:"#004953", rgb:(0,73,83) },
-    "mikadoyellow" => crate::Color{ name:"Mikado yellow", hex:"#FFC40C", rgb:(255,196,12) },
-    "mimipink" => crate::Color{ name:"Mimi pink", hex:"#FFDAE9", rgb:(255,218,233) },
-    "mindaro" => crate::Color{ name:"Mindaro", hex:"#E3F988", rgb:(227,249,136) },
-    "ming" => crate::Color{ name:"Ming", hex:"#36747D", rgb:(54,116,125) },
-    "minionyellow" => crate::Color{ name:"Minion yellow", hex:"#F5E050", rgb:(245,224,80) },
-    "mint" => crate::Color{ name:"Mint", hex:"#3EB489", rgb:(62,180,137) },
-    "mintcream" => crate::Color{ name:"Mint cream", hex:"#F5FFFA", rgb:(245,255,250) },
-    "mintgreen" => crate::Color{ name:"Mint green", hex:"#98FF98", rgb:(152,255,152) },
-    "mistymoss" => crate::Color{ name:"Misty moss", hex:"#BBB477", rgb:(187,180,119) },
-    "mistyrose" => crate::Color{ name:"Misty rose", hex:"#FFE4E1", rgb:(255,228,225) },
-    "moccasin" => crate::Color{ name:"Moccasin", hex:"#FFE4B5", rgb:(255,228,181) },
-    "modebeige" => crate::Color{ name:"Mode beige", hex:"#967117", rgb:(150,113,23) },
-    "monalisa" => crate::Color{ name:"Mona Lisa", hex:"#FF948E", rgb:(255,148,142) },
-    "morningblue" => crate::Color{ name:"Morning blue", hex:"#8DA399", rgb:(141,163,153) },
-    "mossgreen" => crate::Color{ name:"Moss green", hex:"#8A9A5B", rgb:(138,154,91) },
-    "mountainmeadow" => crate::Color{ name:"Mountain Meadow", hex:"#30BA8F", rgb:(48,186,143) },
-    "mountbattenpink" => crate::Color{ name:"Mountbatten pink", hex:"#997A8D", rgb:(153,122,141) },
-    "msugreen" => crate::Color{ name:"MSU green", hex:"#18453B", rgb:(24,69,59) },
-    "mulberry" => crate::Color{ name:"Mulberry", hex:"#C54B8C", rgb:(197,75,140) },
-    "mulberrycrayola" => crate::Color{ name:"Mulberry (Crayola)", hex:"#C8509B", rgb:(200,80,155) },
-    "mustard" => crate::Color{ name:"Mustard", hex:"#FFDB58", rgb:(255,219,88) },
-    "myrtlegreen" => crate::Color{ name:"Myrtle green", hex:"#317873", rgb:(49,120,115) },
-    "mystic" => crate::Color{ name:"Mystic", hex:"#D65282", rgb:(214,82,130) },
-    "mysticmaroon" => crate::Color{ name:"Mystic maroon", hex:"#AD4379", rgb:(173,67,121) },
-    "nadeshikopink" => crate::Color{ name:"Nadeshiko pink", hex:"#F6ADC6", rgb:(246,173,198) },
-    "naplesyellow" => crate::Color{ name:"Naples yellow", hex:"#FADA5E", rgb:(250,218,94) },
-    "navajowhite" => crate::Color{ name:"Navajo white", hex:"#FFDEAD", rgb:(255,222,173) },
-    "navyblue" => crate::Color{ name:"Navy blue", hex:"#000080", rgb:(0,0,128) },
-    "navybluecrayola" => crate::Color{ name:"Navy blue (Crayola)", hex:"#1974D2", rgb:(25,116,210) },
-    "neonblue" => crate::Color{ name:"Neon blue", hex:"#4666FF", rgb:(70,102,255) },
-    "neongreen" => crate::Color{ name:"Neon green", hex:"#39FF14", rgb:(57,255,20) },
-    "neonfuchsia" => crate::Color{ name:"Neon fuchsia", hex:"#FE4164", rgb:(254,65,100) },
-    "newcar" => crate::Color{ name:"New Car", hex:"#214FC6", rgb:(33,79,198) },
-    "newyorkpink" => crate::Color{ name:"New York pink", hex:"#D7837F", rgb:(215,131,127) },
-    "nickel" => crate::Color{ name:"Nickel", hex:"#727472", rgb:(114,116,114) },
-    "nonphotoblue" => crate::Color{ name:"Non-photo blue", hex:"#A4DDED", rgb:(164,221,237) },
-    "nyanza" => crate::Color{ name:"Nyanza", hex:"#E9FFDB", rgb:(233,255,219) },
-    "ochre" => crate::Color{ name:"Ochre", hex:"#CC7722", rgb:(204,119,34) },
-    "oldburgundy" => crate::Color{ name:"Old burgundy", hex:"#43302E", rgb:(67,48,46) },
-    "oldgold" => crate::Color{ name:"Old gold", hex:"#CFB53B", rgb:(207,181,59) },
-    "oldlace" => crate::Color{ name:"Old lace", hex:"#FDF5E6", rgb:(253,245,230) },
-    "oldlavender" => crate::Color{ name:"Old lavender", hex:"#796878", rgb:(121,104,120) },
-    "oldmauve" => crate::Color{ name:"Old mauve", hex:"#673147", rgb:(103,49,71) },
-    "oldrose" => crate::Color{ name:"Old rose", hex:"#C08081", rgb:(192,128,129) },
-    "oldsilver" => crate::Color{ name:"Old silver", hex:"#848482", rgb:(132,132,130) },
-    "olive" => crate::Color{ name:"Olive", hex:"#808000", rgb:(128,128,0) },
-    "olivedrab3" => crate::Color{ name:"Olive Drab (#3)", hex:"#6B8E23", rgb:(107,142,35) },
-    "olivedrab7" => crate::Color{ name:"Olive Drab #7", hex:"#3C341F", rgb:(60,52,31) },
-    "olivegreen" => crate::Color{ name:"Olive green", hex:"#B5B35C", rgb:(181,179,92) },
-    "olivine" => crate::Color{ name:"Olivine", hex:"#9AB973", rgb:(154,185,115) },
-    "onyx" => crate::Color{ name:"Onyx", hex:"#353839", rgb:(53,56,57) },
-    "opal" => crate::Color{ name:"Opal", hex:"#A8C3BC", rgb:(168,195,188) },
-    "operamauve" => crate::Color{ name:"Opera mauve", hex:"#B784A7", rgb:(183,132,167) },
-    "orange" => crate::Color{ name:"Orange", hex:"#FF7F00", rgb:(255,127,0) },
-    "orangecrayola" => crate::Color{ name:"Orange (Crayola)", hex:"#FF7538", rgb:(255,117,56) },
-    "orangepantone" => crate::Color{ name:"Orange (Pantone)", hex:"#FF5800", rgb:(255,88,0) },
-    "orangeweb" => crate::Color{ name:"Orange (web)", hex:"#FFA500", rgb:(255,165,0) },
-    "orangepeel" => crate::Color{ name:"Orange peel", hex:"#FF9F00", rgb:(255,159,0) },
-    "orangered" => crate::Color{ name:"Orange-red", hex:"#FF681F", rgb:(255,104,31) },
-    "orangeredcrayola" => crate::Color{ name:"Orange-red (Crayola)", hex:"#FF5349", rgb:(255,83,73) },
-    "orangesoda" => crate::Color{ name:"Orange soda", hex:"#FA5B3D", rgb:(250,91,61) },
-    "orangeyellow" => crate::Color{ name:"Orange-yellow", hex:"#F5BD1F", rgb:(245,189,31) },
-    "orangeyellowcrayola" => crate::Color{ name:"Orange-yellow (Crayola)", hex:"#F8D568", rgb:(248,213,104) },
-    "orchid" => crate::Color{ name:"Orchid", hex:"#DA70D6", rgb:(218,112,214) },
-    "orchidpink" => crate::Color{ name:"Orchid pink", hex:"#F2BDCD", rgb:(242,189,205) },
-    "orchidcrayola" => crate::Color{ name:"Orchid (Crayola)", hex:"#E29CD2", rgb:(226,156,210) },
-    "outerspacecrayola" => crate::Color{ name:"Outer space (Crayola)", hex:"#2D383A", rgb:(45,56,58) },
-    "outrageousorange" => crate::Color{ name:"Outrageous Orange", hex:"#FF6E4A", rgb:(255,110,74) },
-    "oxblood" => crate::Color{ name:"Oxblood", hex:"#4A0000", rgb:(74,0,0) },
-    "oxfordblue" => crate::Color{ name:"Oxford blue", hex:"#002147", rgb:(0,33,71) },
-    "oucrimsonred" => crate::Color{ name:"OU Crimson red", hex:"#841617", rgb:(132,22,23) },
-    "pacificblue" => crate::Color{ name:"Pacific blue", hex:"#1CA9C9", rgb:(28,169,201) },
-    "pakistangreen" => crate::Color{ name:"Pakistan green", hex:"#006600", rgb:(0,102,0) },
-    "palatinatepurple" => crate::Color{ name:"Palatinate purple", hex:"#682860", rgb:(104,40,96) },
-    "paleaqua" => crate::Color{ name:"Pale aqua", hex:"#BED3E5", rgb:(190,211,229) },
-    "palecerulean" => crate::Color{ name:"Pale cerulean", hex:"#9BC4E2", rgb:(155,196,226) },
-    "paledogwood" => crate::Color{ name:"Pale Dogwood", hex:"#ED7A9B", rgb:(237,122,155) },
-    "palepink" => crate::Color{ name:"Pale pink", hex:"#FADADD", rgb:(250,218,221) },
-    "palepurplepantone" => crate::Color{ name:"Pale purple (Pantone)", hex:"#FAE6FA", rgb:(250,230,250) },
-    "palespringbud" => crate::Color{ name:"Pale spring bud", hex:"#ECEBBD", rgb:(236,235,189) },
-    "pansypurple" => crate::Color{ name:"Pansy purple", hex:"#78184A", rgb:(120,24,74) },
-    "paoloveronesegreen" => crate::Color{ name:"Paolo Veronese green", hex:"#009B7D", rgb:(0,155,125) },
-    "papayawhip" => crate::Color{ name:"Papaya whip", hex:"#FFEFD5", rgb:(255,239,213) },
-    "paradisepink" => crate::Color{ name:"Paradise pink", hex:"#E63E62", rgb:(230,62,98) },
-    "parchment" => crate::Color{ name:"Parchment", hex:"#F1E9D2", rgb:(241,233,210) },
-    "parisgreen" => crate::Color{ name:"Paris Green", hex:"#50C878", rgb:(80,200,120) },
-    "pastelpink" => crate::Color{ name:"Pastel pink", hex:"#DEA5A4", rgb:(222,165,164) },
-    "patriarch" => crate::Color{ name:"Patriarch", hex:"#800080", rgb:(128,0,128) },
-    "paua" => crate::Color{ name:"Paua", hex:"#1F005E", rgb:(31,0,94) },
-    "paynesgrey" => crate::Color{ name:"Payne's grey", hex:"#536878", rgb:(83,104,120) },
-    "peach" => crate::Color{ name:"Peach", hex:"#FFE5B4", rgb:(255,229,180) },
-    "peachcrayola" => crate::Color{ name:"Peach (Crayola)", hex:"#FFCBA4", rgb:(255,203,164) },
-    "peachpuff" => crate::Color{ name:"Peach puff", hex:"#FFDAB9", rgb:(255,218,185) },
-    "pear" => crate::Color{ name:"Pear", hex:"#D1E231", rgb:(209,226,49) },
-    "pearlypurple" => crate::Color{ name:"Pearly purple", hex:"#B768A2", rgb:(183,104,162) },
-    "periwinkle" => crate::Color{ name:"Periwinkle", hex:"#CCCCFF", rgb:(204,204,255) },
-    "periwinklecrayola" => crate::Color{ name:"Periwinkle (Crayola)", hex:"#C3CDE6", rgb:(195,205,230) },
-    "permanentgeraniumlake" => crate::Color{ name:"Permanent Geranium Lake", hex:"#E12C2C", rgb:(225,44,44) },
-    "persianblue" => crate::Color{ name:"Persian blue", hex:"#1C39BB", rgb:(28,57,187) },
-    "persiangreen" => crate::Color{ name:"Persian green", hex:"#00A693", rgb:(0,166,147) },
-    "persianindigo" => crate::Color{ name:"Persian indigo", hex:"#32127A", rgb:(50,18,122) },
-    "persianorange" => crate::Color{ name:"Persian orange", hex:"#D99058", rgb:(217,144,88) },
-    "persianpink" => crate::Color{ name:"Persian pink", hex:"#F77FBE", rgb:(247,127,190) },
-    "persianplum" => crate::Color{ name:"Persian plum", hex:"#701C1C", rgb:(112,28,28) },
-    "persianred" => crate::Color{ name:"Persian red", hex:"#CC3333", rgb:(204,51,51) },
-    "persianrose" => crate::Color{ name:"Persian rose", hex:"#FE28A2", rgb:(254,40,162) },
-    "persimmon" => crate::Color{ name:"Persimmon", hex:"#EC5800", rgb:(236,88,0) },
-    "petunia" => crate::Color{ name:"Petunia", hex:"#470659", rgb:(71,6,89) },
-    "pewterblue" => crate::Color{ name:"Pewter Blue", hex:"#8BA8B7", rgb:(139,168,183) },
-    "phlox" => crate::Color{ name:"Phlox", hex:"#DF00FF", rgb:(223,0,255) },
-    "phthaloblue" => crate::Color{ name:"Phthalo blue", hex:"#000F89", rgb:(0,15,137) },
-    "phthalogreen" => crate::Color{ name:"Phthalo green", hex:"#123524", rgb:(18,53,36) },
-    "picoteeblue" => crate::Color{ name:"Picotee blue", hex:"#2E2787", rgb:(46,39,135) },
-    "pictorialcarmine" => crate::Color{ name:"Pictorial carmine", hex:"#C30B4E", rgb:(195,11,78) },
-    "piggypink" => crate::Color{ name:"Piggy pink", hex:"#FDDDE6", rgb:(253,221,230) },
-    "pinegreen" => crate::Color{ name:"Pine green", hex:"#01796F", rgb:(1,121,111) },
-    "pinegreen2" => crate::Color{ name:"Pine green", hex:"#2A2F23", rgb:(42,47,35) },
-    "pink" => crate::Color{ name:"Pink", hex:"#FFC0CB", rgb:(255,192,203) },
-    "pinkpantone" => crate::Color{ name:"Pink (Pantone)", hex:"#D74894", rgb:(215,72,148) },
-    "pinklace" => crate::Color{ name:"Pink lace", hex:"#FFDDF4", rgb:(255,221,244) },
-    "pinklavender" => crate::Color{ name:"Pink lavender", hex:"#D8B2D1", rgb:(216,178,209) },
-    "pinksherbet" => crate::Color{ name:"Pink Sherbet", hex:"#F78FA7", rgb:(247,143,167) },
-    "pistachio" => crate::Color{ name:"Pistachio", hex:"#93C572", rgb:(147,197,114) },
-    "platinum" => crate::Color{ name:"Platinum", hex:"#E5E4E2", rgb:(229,228,226) },
-    "plum" => crate::Color{ name:"Plum", hex:"#8E4585", rgb:(142,69,133) },
-    "plumweb" => crate::Color{ name:"Plum (web)", hex:"#DDA0DD", rgb:(221,160,221) },
-    "plumppurple" => crate::Color{ name:"Plump Purple", hex:"#5946B2", rgb:(89,70,178) },
-    "polishedpine" => crate::Color{ name:"Polished Pine", hex:"#5DA493", rgb:(93,164,147) },
-    "pompandpower" => crate::Color{ name:"Pomp and Power", hex:"#86608E", rgb:(134,96,142) },
-    "popstar" => crate::Color{ name:"Popstar", hex:"#BE4F62", rgb:(190,79,98) },
-    "portlandorange" => crate::Color{ name:"Portland Orange", hex:"#FF5A36", rgb:(255,90,54) },
-    "powderblue" => crate::Color{ name:"Powder blue", hex:"#B0E0E6", rgb:(176,224,230) },
-    "prairiegold" => crate::Color{ name:"Prairie gold", hex:"#E1CA7A", rgb:(225,202,122) },
-    "princetonorange" => crate::Color{ name:"Princeton orange", hex:"#F58025", rgb:(245,128,37) },
-    "prune" => crate::Color{ name:"Prune", hex:"#701C1C", rgb:(112,28,28) },
-    "prussianblue" => crate::Color{ name:"Prussian blue", hex:"#003153", rgb:(0,49,83) },
-    "psychedelicpurple" => crate::Color{ name:"Psychedelic purple", hex:"#DF00FF", rgb:(223,0,255) },
-    "puce" => crate::Color{ name:"Puce", hex:"#CC8899", rgb:(204,136,153) },
-    "pullmanbrownupsbrown" => crate::Color{ name:"Pullman Brown (UPS Brown)", hex:"#644117", rgb:(100,65,23) },
-    "pumpkin" => crate::Color{ name:"Pumpkin", hex:"#FF7518", rgb:(255,117,24) },
-    "purple" => crate::Color{ name:"Purple", hex:"#6A0DAD", rgb:(106,13,173) },
-    "purpleweb" => crate::Color{ name:"Purple (web)", hex:"#800080", rgb:(128,0,128) },
-    "purplemunsell" => crate::Color{ name:"Purple (Munsell)", hex:"#9F00C5", rgb:(159,0,197) },
-    "purplex11" => crate::Color{ name:"Purple (X11)", hex:"#A020F0", rgb:(160,32,240) },
-    "purplemountainmajesty" => crate::Color{ name:"Purple mountain majesty", hex:"#9678B6", rgb:(150,120,182) },
-    "purplenavy" => crate::Color{ name:"Purple navy", hex:"#4E5180", rgb:(78,81,128) },
-    "purplepizzazzbrokenanchor" => crate::Color{ name:"Purple pizzazz[broken anchor]", hex:"#FE4EDA", rgb:(254,78,218) },
-    "purpleplum" => crate::Color{ name:"Purple Plum", hex:"#9C51B6", rgb:(156,81,182) },
-    "queenblue" => crate::Color{ name:"Queen blue", hex:"#436B95", rgb:(67,107,149) },
-    "queenpink" => crate::Color{ name:"Queen pink", hex:"#E8CCD7", rgb:(232,204,215) },
-    "quicksilver" => crate::Color{ name:"Quick Silver", hex:"#A6A6A6", rgb:(166,166,166) },
-    "quinacridonemagenta" => crate::Color{ name:"Quinacridone magenta", hex:"#8E3A59", rgb:(142,58,89) },
-    "radicalred" => crate::Color{ name:"Radical Red", hex:"#FF355E", rgb:(255,53,94) },
-    "raisinblack" => crate::Color{ name:"Raisin black", hex:"#242124", rgb:(36,33,36) },
-    "rajah" => crate::Color{ name:"Rajah", hex:"#FBAB60", rgb:(251,171,96) },
-    "raspberry" => crate::Color{ name:"Raspberry", hex:"#E30B5D", rgb:(227,11,93) },
-    "raspberryglac" => crate::Color{ name:"Raspberry glacé", hex:"#915F6D", rgb:(145,95,109) },
-    "raspberryrose" => crate::Color{ name:"Raspberry rose", hex:"#B3446C", rgb:(179,68,108) },
-    "rawsienna" => crate::Color{ name:"Raw sienna", hex:"#D68A59", rgb:(214,138,89) },
-    "rawumber" => crate::Color{ name:"Raw umber", hex:"#826644", rgb:(130,102,68) },
-    "razzledazzlerose" => crate::Color{ name:"Razzle dazzle rose", hex:"#FF33CC", rgb:(255,51,204) },
-    "razzmatazz" => crate::Color{ name:"Razzmatazz", hex:"#E3256B", rgb:(227,37,107) },
-    "razzmicberry" => crate::Color{ name:"Razzmic Berry", hex:"#8D4E85", rgb:(141,78,133) },
-    "rebeccapurple" => crate::Color{ name:"Rebecca Purple", hex:"#663399", rgb:(102,51,153) },
-    "red" => crate::Color{ name:"Red", hex:"#FF0000", rgb:(255,0,0) },
-    "redcrayola" => crate::Color{ name:"Red (Crayola)", hex:"#EE204D", rgb:(238,32,77) },
-    "redmunsell" => crate::Color{ name:"Red (Munsell)", hex:"#F2003C", rgb:(242,0,60) },
-    "redncs" => crate::Color{ name:"Red (NCS)", hex:"#C40233", rgb:(196,2,51) },
-    "redpantone" => crate::Color{ name:"Red (Pantone)", hex:"#ED2939", rgb:(237,41,57) },
-    "redpigment" => crate::Color{ name:"Red (pigment)", hex:"#ED1C24", rgb:(237,28,36) },
-    "redryb" => crate::Color{ name:"Red (RYB)", hex:"#FE2712", rgb:(254,39,18) },
-    "redorange" => crate::Color{ name:"Red-orange", hex:"#FF5349", rgb:(255,83,73) },
-    "redochre2" => crate::Color{ name:"Red ochre[2]", hex:"#913831", rgb:(145,56,49) },
-    "redorangecrayola" => crate::Color{ name:"Red-orange (Crayola)", hex:"#FF681F", rgb:(255,104,31) },
-    "redorangecolorwheel" => crate::Color{ name:"Red-orange (Color wheel)", hex:"#FF4500", rgb:(255,69,0) },
-    "redpurple" => crate::Color{ name:"Red-purple", hex:"#E40078", rgb:(228,0,120) },
-    "redsalsa" => crate::Color{ name:"Red Salsa", hex:"#FD3A4A", rgb:(253,58,74) },
-    "redviolet" => crate::Color{ name:"Red-violet", hex:"#C71585", rgb:(199,21,133) },
-    "redvioletcrayola" => crate::Color{ name:"Red-violet (Crayola)", hex:"#C0448F", rgb:(192,68,143) },
-    "redvioletcolorwheel" => crate::Color{ name:"Red-violet (Color wheel)", hex:"#922B3E", rgb:(146,43,62) },
-    "redwood" => crate::Color{ name:"Redwood", hex:"#A45A52", rgb:(164,90,82) },
-    "resolutionblue" => crate::Color{ name:"Resolution blue", hex:"#002387", rgb:(0,35,135) },
-    "rhythm" => crate::Color{ name:"Rhythm", hex:"#777696", rgb:(119,118,150) },
-    "richblack" => crate::Color{ name:"Rich black", hex:"#004040", rgb:(0,64,64) },
-    "richblackfogra29" => crate::Color{ name:"Rich black (FOGRA29)", hex:"#010B13", rgb:(1,11,19) },
-    "richblackfogra39" => crate::Color{ name:"Rich black (FOGRA39)", hex:"#010203", rgb:(1,2,3) },
-    "riflegreen" => crate::Color{ name:"Rifle green", hex:"#444C38", rgb:(68,76,56) },
-    "robineggblue" => crate::Color{ name:"Robin egg blue", hex:"#00CCCC", rgb:(0,204,204) },
-    "rocketmetallic" => crate::Color{ name:"Rocket metallic", hex:"#8A7F80", rgb:(138,127,128) },
-    "rojospanishred" => crate::Color{ name:"Rojo Spanish red", hex:"#A91101", rgb:(169,17,1) },
-    "romansilver" => crate::Color{ name:"Roman silver", hex:"#838996", rgb:(131,137,150) },
-    "rose" => crate::Color{ name:"Rose", hex:"#FF007F", rgb:(255,0,127) },
-    "rosebonbon" => crate::Color{ name:"Rose bonbon", hex:"#F9429E", rgb:(249,66,158) },
-    "rosedust" => crate::Color{ name:"Rose Dust", hex:"#9E5E6F", rgb:(158,94,111) },
-    "roseebony" => crate::Color{ name:"Rose ebony", hex:"#674846", rgb:(103,72,70) },
-    "rosemadder" => crate::Color{ name:"Rose madder", hex:"#E32636", rgb:(227,38,54) },
-    "rosepink" => crate::Color{ name:"Rose pink", hex:"#FF66CC", rgb:(255,102,204) },
-    "rosepompadour" => crate::Color{ name:"Rose Pompadour", hex:"#ED7A9B", rgb:(237,122,155) },
-    "rosered" => crate::Color{ name:"Rose red", hex:"#C21E56", rgb:(194,30,86) },
-    "rosetaupe" => crate::Color{ name:"Rose taupe", hex:"#905D5D", rgb:(144,93,93) },
-    "rosevale" => crate::Color{ name:"Rose vale", hex:"#AB4E52", rgb:(171,78,82) },
-    "rosewood" => crate::Color{ name:"Rosewood", hex:"#65000B", rgb:(101,0,11) },
-    "rossocorsa" => crate::Color{ name:"Rosso corsa", hex:"#D40000", rgb:(212,0,0) },
-    "rosybrown" => crate::Color{ name:"Rosy brown", hex:"#BC8F8F", rgb:(188,143,143) },
-    "royalbluedark" => crate::Color{ name:"Royal blue (dark)", hex:"#002366", rgb:(0,35,102) },
-    "royalbluelight" => crate::Color{ name:"Royal blue (light)", hex:"#4169E1", rgb:(65,105,225) },
-    "royalpurple" => crate::Color{ name:"Royal purple", hex:"#7851A9", rgb:(120,81,169) },
-    "royalyellow" => crate::Color{ name:"Royal yellow", hex:"#FADA5E", rgb:(250,218,94) },
-    "ruber" => crate::Color{ name:"Ruber", hex:"#CE4676", rgb:(206,70,118) },
-    "rubinered" => crate::Color{ name:"Rubine red", hex:"#D10056", rgb:(209,0,86) },
-    "ruby" => crate::Color{ name:"Ruby", hex:"#E0115F", rgb:(224,17,95) },
-    "rubyred" => crate::Color{ name:"Ruby red", hex:"#9B111E", rgb:(155,17,30) },
-    "rufous" => crate::Color{ name:"Rufous", hex:"#A81C07", rgb:(168,28,7) },
-    "russet" => crate::Color{ name:"Russet", hex:"#80461B", rgb:(128,70,27) },
-    "russiangreen" => crate::Color{ name:"Russian green", hex:"#679267", rgb:(103,146,103) },
-    "russianviolet" => crate::Color{ name:"Russian violet", hex:"#32174D", rgb:(50,23,77) },
-    "rust" => crate::Color{ name:"Rust", hex:"#B7410E", rgb:(183,65,14) },
-    "rustyred" => crate::Color{ name:"Rusty red", hex:"#DA2C43", rgb:(218,44,67) },
-    "sacramentostategreen" => crate::Color{ name:"Sacramento State green", hex:"#043927", rgb:(4,57,39) },
-    "saddlebrown" => crate::Color{ name:"Saddle brown", hex:"#8B4513", rgb:(139,69,19) },
-    "safetyorange" => crate::Color{ name:"Safety orange", hex:"#FF7800", rgb:(255,120,0) },
-    "safetyorangeblazeorange" => crate::Color{ name:"Safety orange (blaze orange)", hex:"#FF6700", rgb:(255,103,0) },
-    "safetyyellow" => crate::Color{ name:"Safety yellow", hex:"#EED202", rgb:(238,210,2) },
-    "saffron" => crate::Color{ name:"Saffron", hex:"#F4C430", rgb:(244,196,48) },
-    "sage" => crate::Color{ name:"Sage", hex:"#BCB88A", rgb:(188,184,138) },
-    "stpatricksblue" => crate::Color{ name:"St. Patrick's blue", hex:"#23297A", rgb:(35,41,122) },
-    "salmon" => crate::Color{ name:"Salmon", hex:"#FA8072", rgb:(250,128,114) },
-    "salmonpink" => crate::Color{ name:"Salmon pink", hex:"#FF91A4", rgb:(255,145,164) },
-    "sand" => crate::Color{ name:"Sand", hex:"#C2B280", rgb:(194,178,128) },
-    "sanddune" => crate::Color{ name:"Sand dune", hex:"#967117", rgb:(150,113,23) },
-    "sandybrown" => crate::Color{ name:"Sandy brown", hex:"#F4A460", rgb:(244,164,96) },
-    "sapgreen" => crate::Color{ name:"Sap green", hex:"#507D2A", rgb:(80,125,42) },
-    "sapphire" => crate::Color{ name:"Sapphire", hex:"#0F52BA", rgb:(15,82,186) },
-    "sapphireblue" => crate::Color{ name:"Sapphire blue", hex:"#0067A5", rgb:(0,103,165) },
-    "sapphirecrayola" => crate::Color{ name:"Sapphire (Crayola)", hex:"#2D5DA1", rgb:(45,93,161) },
-    "satinsheengold" => crate::Color{ name:"Satin sheen gold", hex:"#CBA135", rgb:(203,161,53) },
-    "scarlet" => crate::Color{ name:"Scarlet", hex:"#FF2400", rgb:(255,36,0) },
-    "schausspink" => crate::Color{ name:"Schauss pink", hex:"#FF91AF", rgb:(255,145,175) },
-    "schoolbusyellow" => crate::Color{ name:"School bus yellow", hex:"#FFD800", rgb:(255,216,0) },
-    "screamingreen" => crate::Color{ name:"Screamin' Green", hex:"#66FF66", rgb:(102,255,102) },
-    "seagreen" => crate::Color{ name:"Sea green", hex:"#2E8B57", rgb:(46,139,87) },
-    "seagreencrayola" => crate::Color{ name:"Sea green (Crayola)", hex:"#00FFCD", rgb:(0,255,205) },
-    "seance" => crate::Color{ name:"Seance", hex:"#612086", rgb:(97,32,134) },
-    "sealbrown" => crate::Color{ name:"Seal brown", hex:"#59260B", rgb:(89,38,11) },
-    "seashell" => crate::Color{ name:"Seashell", hex:"#FFF5EE", rgb:(255,245,238) },
-    "secret" => crate::Color{ name:"Secret", hex:"#764374", rgb:(118,67,116) },
-    "selectiveyellow" => crate::Color{ name:"Selective yellow", hex:"#FFBA00", rgb:(255,186,0) },
-    "sepia" => crate::Color{ name:"Sepia", hex:"#704214", rgb:(112,66,20) },
-    "shadow" => crate::Color{ name:"Shadow", hex:"#8A795D", rgb:(138,121,93) },
-    "shadowblue" => crate::Color{ name:"Shadow blue", hex:"#778BA5", rgb:(119,139,165) },
-    "shamrockgreen" => crate::Color{ name:"Shamrock green", hex:"#009E60", rgb:(0,158,96) },
-    "sheengreen" => crate::Color{ name:"Sheen green", hex:"#8FD400", rgb:(143,212,0) },
-    "shimmeringblush" => crate::Color{ name:"Shimmering Blush", hex:"#D98695", rgb:(217,134,149) },
-    "shinyshamrock" => crate::Color{ name:"Shiny Shamrock", hex:"#5FA778", rgb:(95,167,120) },
-    "shockingpink" => crate::Color{ name:"Shocking pink", hex:"#FC0FC0", rgb:(252,15,192) },
-    "shockingpinkcrayola" => crate::Color{ name:"Shocking pink (Crayola)", hex:"#FF6FFF", rgb:(255,111,255) },
-    "sienna" => crate::Color{ name:"Sienna", hex:"#882D17", rgb:(136,45,23) },
-    "silver" => crate::Color{ name:"Silver", hex:"#C0C0C0", rgb:(192,192,192) },
-    "silvercrayola" => crate::Color{ name:"Silver (Crayola)", hex:"#C9C0BB", rgb:(201,192,187) },
-    "silvermetallic" => crate::Color{ name:"Silver (Metallic)", hex:"#AAA9AD", rgb:(170,169,173) },
-    "silverchalice" => crate::Color{ name:"Silver chalice", hex:"#ACACAC", rgb:(172,172,172) },
-    "silverpink" => crate::Color{ name:"Silver pink", hex:"#C4AEAD", rgb:(196,174,173) },
-    "silversand" => crate::Color{ name:"Silver sand", hex:"#BFC1C2", rgb:(191,193,194) },
-    "sinopia" => crate::Color{ name:"Sinopia", hex:"#CB410B", rgb:(203,65,11) },
-    "sizzlingred" => crate::Color{ name:"Sizzling Red", hex:"#FF3855", rgb:(255,56,85) },
-    "sizzlingsunrise" => crate::Color{ name:"Sizzling Sunrise", hex:"#FFDB00", rgb:(255,219,0) },
-    "skobeloff" => crate::Color{ name:"Skobeloff", hex:"#007474", rgb:(0,116,116) },
-    "skincolor" => crate::Color{ name:"Skin color", hex:"#FFDEAD", rgb:(255,222,173) },
-    "skyblue" => crate::Color{ name:"Sky blue", hex:"#87CEEB", rgb:(135,206,235) },
-    "skybluecrayola" => crate::Color{ name:"Sky blue (Crayola)", hex:"#76D7EA", rgb:(118,215,234) },
-    "skymagenta" => crate::Color{ name:"Sky magenta", hex:"#CF71AF", rgb:(207,113,175) },
-    "slateblue" => crate::Color{ name:"Slate blue", hex:"#6A5ACD", rgb:(106,90,205) },
-    "slategray" => crate::Color{ name:"Slate gray", hex:"#708090", rgb:(112,128,144) },
-    "slimygreen" => crate::Color{ name:"Slimy green", hex:"#299617", rgb:(41,150,23) },
-    "smitten" => crate::Color{ name:"Smitten", hex:"#C84186", rgb:(200,65,134) },
-    "smokyblack" => crate::Color{ name:"Smoky black", hex:"#100C08", rgb:(16,12,8) },
-    "snow" => crate::Color{ name:"Snow", hex:"#FFFAFA", rgb:(255,250,250) },
-    "solidpink" => crate::Color{ name:"Solid pink", hex:"#893843", rgb:(137,56,67) },
-    "sonicsilver" => crate::Color{ name:"Sonic silver", hex:"#757575", rgb:(117,117,117) },
-    "spacecadet" => crate::Color{ name:"Space cadet", hex:"#1D2951", rgb:(29,41,81) },
-    "spanishbistre" => crate::Color{ name:"Spanish bistre", hex:"#807532", rgb:(128,117,50) },
-    "spanishblue" => crate::Color{ name:"Spanish blue", hex:"#0070B8", rgb:(0,112,184) },
-    "spanishcarmine" => crate::Color{ name:"Spanish carmine", hex:"#D10047", rgb:(209,0,71) },
-    "spanishgray" => crate::Color{ name:"Spanish gray", hex:"#989898", rgb:(152,152,152) },
-    "spanishgreen" => crate::Color{ name:"Spanish green", hex:"#009150", rgb:(0,145,80) },
-    "spanishorange" => crate::Color{ name:"Spanish orange", hex:"#E86100", rgb:(232,97,0) },
-    "spanishpink" => crate::Color{ name:"Spanish pink", hex:"#F7BFBE", rgb:(247,191,190) },
-    "spanishred" => crate::Color{ name:"Spanish red", hex:"#E60026", rgb:(230,0,38) },
-    "spanishskyblue" => crate::Color{ name:"Spanish sky blue", hex:"#00FFFE", rgb:(0,255,254) },
-    "spanishviolet" => crate::Color{ name:"Spanish violet", hex:"#4C2882", rgb:(76,40,130) },
-    "spanishviridian" => crate::Color{ name:"Spanish viridian", hex:"#007F5C", rgb:(0,127,92) },
-    "springbud" => crate::Color{ name:"Spring bud", hex:"#A7FC00", rgb:(167,252,0) },
-    "springfrost" => crate::Color{ name:"Spring Frost", hex:"#87FF2A", rgb:(135,255,42) },
-    "springgreen" => crate::Color{ name:"Spring green", hex:"#00FF7F", rgb:(0,255,127) },
-    "springgreencrayola" => crate::Color{ name:"Spring green (Crayola)", hex:"#ECEBBD", rgb:(236,235,189) },
-    "starcommandblue" => crate::Color{ name:"Star command blue", hex:"#007BB8", rgb:(0,123,184) },
-    "steelblue" => crate::Color{ name:"Steel blue", hex:"#4682B4", rgb:(70,130,180) },
-    "steelpink" => crate::Color{ name:"Steel pink", hex:"#CC33CC", rgb:(204,51,204) },
-    "stildegrainyellow" => crate::Color{ name:"Stil de grain yellow", hex:"#FADA5E", rgb:(250,218,94) },
-    "straw" => crate::Color{ name:"Straw", hex:"#E4D96F", rgb:(228,217,111) },
-    "strawberry" => crate::Color{ name:"Strawberry", hex:"#FA5053", rgb:(250,80,83) },
-    "strawberryblonde" => crate::Color{ name:"Strawberry Blonde", hex:"#FF9361", rgb:(255,147,97) },
-    "stronglimegreen" => crate::Color{ name:"Strong Lime Green", hex:"#33CC33", rgb:(51,204,51) },
-    "sugarplum" => crate::Color{ name:"Sugar Plum", hex:"#914E75", rgb:(145,78,117) },
-    "sunglow" => crate::Color{ name:"Sunglow", hex:"#FFCC33", rgb:(255,204,51) },
-    "sunray" => crate::Color{ name:"Sunray", hex:"#E3AB57", rgb:(227,171,87) },
-    "sunset" => crate::Color{ name:"Sunset", hex:"#FAD6A5", rgb:(250,214,165) },
-    "superpink" => crate::Color{ name:"Super pink", hex:"#CF6BA9", rgb:(207,107,169) },
-    "sweetbrown" => crate::Color{ name:"Sweet Brown", hex:"#A83731", rgb:(168,55,49) },
-    "syracuseorange" => crate::Color{ name:"Syracuse Orange", hex:"#D44500", rgb:(212,69,0) },
-    "tan" => crate::Color{ name:"Tan", hex:"#D2B48C", rgb:(210,180,140) },
-    "tancrayola" => crate::Color{ name:"Tan (Crayola)", hex:"#D99A6C", rgb:(217,154,108) },
-    "tangerine" => crate::Color{ name:"Tangerine", hex:"#F28500", rgb:(242,133,0) },
-    "tangopink" => crate::Color{ name:"Tango pink", hex:"#E4717A", rgb:(228,113,122) },
-    "tartorange" => crate::Color{ name:"Tart Orange", hex:"#FB4D46", rgb:(251,77,70) },
-    "taupe" => crate::Color{ name:"Taupe", hex:"#483C32", rgb:(72,60,50) },
-    "taupegray" => crate::Color{ name:"Taupe gray", hex:"#8B8589", rgb:(139,133,137) },
-    "teagreen" => crate::Color{ name:"Tea green", hex:"#D0F0C0", rgb:(208,240,192) },
-    "tearose" => crate::Color{ name:"Tea rose", hex:"#F4C2C2", rgb:(244,194,194) },
-    "teal" => crate::Color{ name:"Teal", hex:"#008080", rgb:(0,128,128) },
-    "tealblue" => crate::Color{ name:"Teal blue", hex:"#367588", rgb:(54,117,136) },
-    "technobotanica" => crate::Color{ name:"Technobotanica", hex:"#00FFBF", rgb:(0,255,191) },
-    "telemagenta" => crate::Color{ name:"Telemagenta", hex:"#CF3476", rgb:(207,52,118) },
-    "tenntawny" => crate::Color{ name:"Tenné(tawny)", hex:"#CD5700", rgb:(205,87,0) },
-    "terracotta" => crate::Color{ name:"Terra cotta", hex:"#E2725B", rgb:(226,114,91) },
-    "thistle" => crate::Color{ name:"Thistle", hex:"#D8BFD8", rgb:(216,191,216) },
-    "thulianpink" => crate::Color{ name:"Thulian pink", hex:"#DE6FA1", rgb:(222,111,161) },
-    "ticklemepink" => crate::Color{ name:"Tickle Me Pink", hex:"#FC89AC", rgb:(252,137,172) },
-    "tiffanyblue" => crate::Color{ name:"Tiffany Blue", hex:"#0ABAB5", rgb:(10,186,181) },
-    "timberwolf" => crate::Color{ name:"Timberwolf", hex:"#DBD7D2", rgb:(219,215,210) },
-    "titaniumyellow" => crate::Color{ name:"Titanium yellow", hex:"#EEE600", rgb:(238,230,0) },
-    "tomato" => crate::Color{ name:"Tomato", hex:"#FF6347", rgb:(255,99,71) },
-    "tourmaline" => crate::Color{ name:"Tourmaline", hex:"#86A1A9", rgb:(134,161,169) },
-    "tropicalrainforest" => crate::Color{ name:"Tropical rainforest", hex:"#00755E", rgb:(0,117,94) },
-    "trueblue" => crate::Color{ name:"True Blue", hex:"#2D68C4", rgb:(45,104,196) },
-    "trypanblue" => crate::Color{ name:"Trypan Blue", hex:"#1C05B3", rgb:(28,5,179) },
-    "tuftsblue" => crate::Color{ name:"Tufts blue", hex:"#3E8EDE", rgb:(62,142,222) },
-    "tumbleweed" => crate::Color{ name:"Tumbleweed", hex:"#DEAA88", rgb:(222,170,136) },
-    "turquoise" => crate::Color{ name:"Turquoise", hex:"#40E0D0", rgb:(64,224,208) },
-    "turquoiseblue" => crate::Color{ name:"Turquoise blue", hex:"#00FFEF", rgb:(0,255,239) },
-    "turquoisegreen" => crate::Color{ name:"Turquoise green", hex:"#A0D6B4", rgb:(160,214,180) },
-    "turtlegreen" => crate::Color{ name:"Turtle green", hex:"#8A9A5B", rgb:(138,154,91) },
-    "tuscan" => crate::Color{ name:"Tuscan", hex:"#FAD6A5", rgb:(250,214,165) },
-    "tuscanbrown" => crate::Color{ name:"Tuscan brown", hex:"#6F4E37", rgb:(111,78,55) },
-    "tuscanred" => crate::Color{ name:"Tuscan red", hex:"#7C4848", rgb:(124,72,72) },
-    "tuscantan" => crate::Color{ name:"Tuscan tan", hex:"#A67B5B", rgb:(166,123,91) },
-    "tuscany" => crate::Color{ name:"Tuscany", hex:"#C09999", rgb:(192,153,153) },
-    "twilightlavender" => crate::Color{ name:"Twilight lavender", hex:"#8A496B", rgb:(138,73,107) },
-    "tyrianpurple" => crate::Color{ name:"Tyrian purple", hex:"#66023C", rgb:(102,2,60) },
-    "uablue" => crate::Color{ name:"UA blue", hex:"#0033AA", rgb:(0,51,170) },
-    "uared" => crate::Color{ name:"UA red", hex:"#D9004C", rgb:(217,0,76) },
-    "ultramarine" => crate::Color{ name:"Ultramarine", hex:"#3F00FF", rgb:(63,0,255) },
-    "ultramarineblue" => crate::Color{ name:"Ultramarine blue", hex:"#4166F5", rgb:(65,102,245) },
-    "ultrapink" => crate::Color{ name:"Ultra pink", hex:"#FF6FFF", rgb:(255,111,255) },
-    "ultrared" => crate::Color{ name:"Ultra red", hex:"#FC6C85", rgb:(252,108,133) },
-    "umber" => crate::Color{ name:"Umber", hex:"#635147", rgb:(99,81,71) },
-    "unbleachedsilk" => crate::Color{ name:"Unbleached silk", hex:"#FFDDCA", rgb:(255,221,202) },
-    "unitednationsblue" => crate::Color{ name:"United Nations blue", hex:"#009EDB", rgb:(0,158,219) },
-    "universityofpennsylvaniared" => crate::Color{ name:"University of Pennsylvania red", hex:"#A50021", rgb:(165,0,33) },
-    "unmellowyellow" => crate::Color{ name:"Unmellow yellow", hex:"#FFFF66", rgb:(255,255,102) },
-    "upforestgreen" => crate::Color{ name:"UP Forest green", hex:"#014421", rgb:(1,68,33) },
-    "upmaroon" => crate::Color{ name:"UP maroon", hex:"#7B1113", rgb:(123,17,19) },
-    "upsdellred" => crate::Color{ name:"Upsdell red", hex:"#AE2029", rgb:(174,32,41) },
-    "uranianblue" => crate::Color{ name:"Uranian blue", hex:"#AFDBF5", rgb:(175,219,245) },
-    "usafablue" => crate::Color{ name:"USAFA blue", hex:"#004F98", rgb:(0,79,152) },
-    "vandykebrown" => crate::Color{ name:"Van Dyke brown", hex:"#664228", rgb:(102,66,40) },
-    "vanilla" => crate::Color{ name:"Vanilla", hex:"#F3E5AB", rgb:(243,229,171) },
-    "vanillaice" => crate::Color{ name:"Vanilla ice", hex:"#F38FA9", rgb:(243,143,169) },
-    "vantgblue" => crate::Color{ name:"Vantg blue", hex:"#5271FF", rgb:(82,113,255) },
-    "vegasgold" => crate::Color{ name:"Vegas gold", hex:"#C5B358", rgb:(197,179,88) },
-    "venetianred" => crate::Color{ name:"Venetian red", hex:"#C80815", rgb:(200,8,21) },
-    "verdigris" => crate::Color{ name:"Verdigris", hex:"#43B3AE", rgb:(67,179,174) },
-    "vermilion" => crate::Color{ name:"Vermilion", hex:"#E34234", rgb:(227,66,52) },
-    "vermilion2" => crate::Color{ name:"Vermilion", hex:"#D9381E", rgb:(217,56,30) },
-    "veronica" => crate::Color{ name:"Veronica", hex:"#A020F0", rgb:(160,32,240) },
-    "violet" => crate::Color{ name:"Violet", hex:"#8F00FF", rgb:(143,0,255) },
-    "violetcolorwheel" => crate::Color{ name:"Violet (color wheel)", hex:"#7F00FF", rgb:(127,0,255) },
-    "violetcrayola" => crate::Color{ name:"Violet (crayola)", hex:"#963D7F", rgb:(150,61,127) },
-    "violetryb" => crate::Color{ name:"Violet (RYB)", hex:"#8601AF", rgb:(134,1,175) },
-    "violetweb" => crate::Color{ name:"Violet (web)", hex:"#EE82EE", rgb:(238,130,238) },
-    "violetblue" => crate::Color{ name:"Violet-blue", hex:"#324AB2", rgb:(50,74,178) },
-    "violetbluecrayola" => crate::Color{ name:"Violet-blue (Crayola)", hex:"#766EC8", rgb:(118,110,200) },
-    "violetred" => crate::Color{ name:"Violet-red", hex:"#F75394", rgb:(247,83,148) },
-    "violetredperbang" => crate::Color{ name:"Violet-red(PerBang)", hex:"#F0599C", rgb:(240,89,156) },
-    "viridian" => crate::Color{ name:"Viridian", hex:"#40826D", rgb:(64,130,109) },
-    "viridiangreen" => crate::Color{ name:"Viridian green", hex:"#009698", rgb:(0,150,152) },
-    "vividburgundy" => crate::Color{ name:"Vivid burgundy", hex:"#9F1D35", rgb:(159,29,53) },
-    "vividskyblue" => crate::Color{ name:"Vivid sky blue", hex:"#00CCFF", rgb:(0,204,255) },
-    "vividtangerine" => crate::Color{ name:"Vivid tangerine", hex:"#FFA089", rgb:(255,160,137) },
-    "vividviolet" => crate::Color{ name:"Vivid violet", hex:"#9F00FF", rgb:(159,0,255) },
-    "volt" => crate::Color{ name:"Volt", hex:"#CEFF00", rgb:(206,255,0) },
-    "warmblack" => crate::Color{ name:"Warm black", hex:"#004242", rgb:(0,66,66) },
-    "weezyblue" => crate::Color{ name:"Weezy Blue", hex:"#189BCC", rgb:(24,155,204) },
-    "wheat" => crate::Color{ name:"Wheat", hex:"#F5DEB3", rgb:(245,222,179) },
-    "white" => crate::Color{ name:"White", hex:"#FFFFFF", rgb:(255,255,255) },
-    "wildblueyonder" => crate::Color{ name:"Wild blue yonder", hex:"#A2ADD0", rgb:(162,173,208) },
-    "wildorchid" => crate::Color{ name:"Wild orchid", hex:"#D470A2", rgb:(212,112,162) },
-    "wildstrawberry" => crate::Color{ name:"Wild Strawberry", hex:"#FF43A4", rgb:(255,67,164) },
-    "wildwatermelon" => crate::Color{ name:"Wild watermelon", hex:"#FC6C85", rgb:(252,108,133) },
-    "windsortan" => crate::Color{ name:"Windsor tan", hex:"#A75502", rgb:(167,85,2) },
-    "wine" => crate::Color{ name:"Wine", hex:"#722F37", rgb:(114,47,55) },
-    "winedregs" => crate::Color{ name:"Wine dregs", hex:"#673147", rgb:(103,49,71) },
-    "wintersky" => crate::Color{ name:"Winter Sky", hex:"#FF007C", rgb:(255,0,124) },
-    "wintergreendream" => crate::Color{ name:"Wintergreen Dream", hex:"#56887D", rgb:(86,136,125) },
-    "wisteria" => crate::Color{ name:"Wisteria", hex:"#C9A0DC", rgb:(201,160,220) },
-    "woodbrown" => crate::Color{ name:"Wood brown", hex:"#C19A6B", rgb:(193,154,107) },
-    "xanadu" => crate::Color{ name:"Xanadu", hex:"#738678", rgb:(115,134,120) },
-    "xanthic" => crate::Color{ name:"Xanthic", hex:"#EEED09", rgb:(238,237,9) },
-    "xanthous" => crate::Color{ name:"Xanthous", hex:"#F1B42F", rgb:(241,180,47) },
-    "yaleblue" => crate::Color{ name:"Yale Blue", hex:"#00356B", rgb:(0,53,107) },
-    "yellow" => crate::Color{ name:"Yellow", hex:"#FFFF00", rgb:(255,255,0) },
-    "yellowcrayola" => crate::Color{ name:"Yellow (Crayola)", hex:"#FCE883", rgb:(252,232,131) },
-    "yellowmunsell" => crate::Color{ name:"Yellow (Munsell)", hex:"#EFCC00", rgb:(239,204,0) },
-    "yellowncs" => crate::Color{ name:"Yellow (NCS)", hex:"#FFD300", rgb:(255,211,0) },
-    "yellowpantone" => crate::Color{ name:"Yellow (Pantone)", hex:"#FEDF00", rgb:(254,223,0) },
-    "yellowprocess" => crate::Color{ name:"Yellow (process)", hex:"#FFEF00", rgb:(255,239,0) },
-    "yellowryb" => crate::Color{ name:"Yellow (RYB)", hex:"#FEFE33", rgb:(254,254,51) },
-    "yellowgreen" => crate::Color{ name:"Yellow-green", hex:"#9ACD32", rgb:(154,205,50) },
-    "yellowgreencrayola" => crate::Color{ name:"Yellow-green (Crayola)", hex:"#C5E384", rgb:(197,227,132) },
-    "yellowgreencolorwheel" => crate::Color{ name:"Yellow-green (Color Wheel)", hex:"#30B21A", rgb:(48,178,26) },
-    "yelloworange" => crate::Color{ name:"Yellow Orange", hex:"#FFAE42", rgb:(255,174,66) },
-    "yelloworangecolorwheel" => crate::Color{ name:"Yellow Orange (Color Wheel)", hex:"#FF9505", rgb:(255,149,5) },
-    "yellowsunshine" => crate::Color{ name:"Yellow Sunshine", hex:"#FFF700", rgb:(255,247,0) },
-    "yinmnblue" => crate::Color{ name:"YInMn Blue", hex:"#2E5090", rgb:(46,80,144) },
-    "zaffre" => crate::Color{ name:"Zaffre", hex:"#0014A8", rgb:(0,20,168) },
-    "zinnwalditebrown" => crate::Color{ name:"Zinnwaldite brown", hex:"#2C1608", rgb:(44,22,8) },
-    "zomp" => crate::Color{ name:"Zomp", hex:"#39A78E", rgb:(57,167,142) },
+/// Colors from Wikipedia's "List of colors" pages.
+pub static COLORS: phf::Map<&'static str, crate::Color> = phf::phf_map! {
+    "absolutezero" => crate::Color{ name:std::borrow::Cow::Borrowed("Absolute Zero"), hex:std::borrow::Cow::Borrowed("#0048BA"), rgb:(0,72,186), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "acidgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Acid green"), hex:std::borrow::Cow::Borrowed("#B0BF1A"), rgb:(176,191,26), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "aero" => crate::Color{ name:std::borrow::Cow::Borrowed("Aero"), hex:std::borrow::Cow::Borrowed("#7CB9E8"), rgb:(124,185,232), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "africanviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("African violet"), hex:std::borrow::Cow::Borrowed("#B284BE"), rgb:(178,132,190), aliases:&["africanvoilet"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "airsuperiorityblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Air superiority blue"), hex:std::borrow::Cow::Borrowed("#72A0C1"), rgb:(114,160,193), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "aliceblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Alice blue"), hex:std::borrow::Cow::Borrowed("#F0F8FF"), rgb:(240,248,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "alizarin" => crate::Color{ name:std::borrow::Cow::Borrowed("Alizarin"), hex:std::borrow::Cow::Borrowed("#DB2D43"), rgb:(219,45,67), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "alloyorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Alloy orange"), hex:std::borrow::Cow::Borrowed("#C46210"), rgb:(196,98,16), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "almond" => crate::Color{ name:std::borrow::Cow::Borrowed("Almond"), hex:std::borrow::Cow::Borrowed("#EED9C4"), rgb:(238,217,196), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "amaranthdeeppurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Amaranth deep purple"), hex:std::borrow::Cow::Borrowed("#9F2B68"), rgb:(159,43,104), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "amaranthpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Amaranth pink"), hex:std::borrow::Cow::Borrowed("#F19CBB"), rgb:(241,156,187), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "amaranthpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Amaranth purple"), hex:std::borrow::Cow::Borrowed("#AB274F"), rgb:(171,39,79), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "amazon" => crate::Color{ name:std::borrow::Cow::Borrowed("Amazon"), hex:std::borrow::Cow::Borrowed("#3B7A57"), rgb:(59,122,87), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "amber" => crate::Color{ name:std::borrow::Cow::Borrowed("Amber"), hex:std::borrow::Cow::Borrowed("#FFBF00"), rgb:(255,191,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "amethyst" => crate::Color{ name:std::borrow::Cow::Borrowed("Amethyst"), hex:std::borrow::Cow::Borrowed("#9966CC"), rgb:(153,102,204), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "androidgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Android green"), hex:std::borrow::Cow::Borrowed("#3DDC84"), rgb:(61,220,132), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "antiquebrass" => crate::Color{ name:std::borrow::Cow::Borrowed("Antique brass"), hex:std::borrow::Cow::Borrowed("#C88A65"), rgb:(200,138,101), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "antiquebronze" => crate::Color{ name:std::borrow::Cow::Borrowed("Antique bronze"), hex:std::borrow::Cow::Borrowed("#665D1E"), rgb:(102,93,30), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "antiquefuchsia" => crate::Color{ name:std::borrow::Cow::Borrowed("Antique fuchsia"), hex:std::borrow::Cow::Borrowed("#915C83"), rgb:(145,92,131), aliases:&["antiquefuschia", "antiquefushia"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "antiqueruby" => crate::Color{ name:std::borrow::Cow::Borrowed("Antique ruby"), hex:std::borrow::Cow::Borrowed("#841B2D"), rgb:(132,27,45), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "antiquewhite" => crate::Color{ name:std::borrow::Cow::Borrowed("Antique white"), hex:std::borrow::Cow::Borrowed("#FAEBD7"), rgb:(250,235,215), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "apricot" => crate::Color{ name:std::borrow::Cow::Borrowed("Apricot"), hex:std::borrow::Cow::Borrowed("#FBCEB1"), rgb:(251,206,177), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "aqua" => crate::Color{ name:std::borrow::Cow::Borrowed("Aqua"), hex:std::borrow::Cow::Borrowed("#00FFFF"), rgb:(0,255,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "aquamarine" => crate::Color{ name:std::borrow::Cow::Borrowed("Aquamarine"), hex:std::borrow::Cow::Borrowed("#7FFFD4"), rgb:(127,255,212), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "arcticlime" => crate::Color{ name:std::borrow::Cow::Borrowed("Arctic lime"), hex:std::borrow::Cow::Borrowed("#D0FF14"), rgb:(208,255,20), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "artichokegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Artichoke green"), hex:std::borrow::Cow::Borrowed("#4B6F44"), rgb:(75,111,68), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "arylideyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Arylide yellow"), hex:std::borrow::Cow::Borrowed("#E9D66B"), rgb:(233,214,107), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "ashgray" => crate::Color{ name:std::borrow::Cow::Borrowed("Ash gray"), hex:std::borrow::Cow::Borrowed("#B2BEB5"), rgb:(178,190,181), aliases:&["ashgrey"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "atomictangerine" => crate::Color{ name:std::borrow::Cow::Borrowed("Atomic tangerine"), hex:std::borrow::Cow::Borrowed("#FF9966"), rgb:(255,153,102), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "aureolin" => crate::Color{ name:std::borrow::Cow::Borrowed("Aureolin"), hex:std::borrow::Cow::Borrowed("#FDEE00"), rgb:(253,238,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "azure" => crate::Color{ name:std::borrow::Cow::Borrowed("Azure"), hex:std::borrow::Cow::Borrowed("#007FFF"), rgb:(0,127,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "azurex11webcolor" => crate::Color{ name:std::borrow::Cow::Borrowed("Azure (X11/web color)"), hex:std::borrow::Cow::Borrowed("#F0FFFF"), rgb:(240,255,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("X11/web color") } },
+    "babyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Baby blue"), hex:std::borrow::Cow::Borrowed("#89CFF0"), rgb:(137,207,240), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "babyblueeyes" => crate::Color{ name:std::borrow::Cow::Borrowed("Baby blue eyes"), hex:std::borrow::Cow::Borrowed("#A1CAF1"), rgb:(161,202,241), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "babypink" => crate::Color{ name:std::borrow::Cow::Borrowed("Baby pink"), hex:std::borrow::Cow::Borrowed("#F4C2C2"), rgb:(244,194,194), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "babypowder" => crate::Color{ name:std::borrow::Cow::Borrowed("Baby powder"), hex:std::borrow::Cow::Borrowed("#FEFEFA"), rgb:(254,254,250), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bakermillerpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Baker-Miller pink"), hex:std::borrow::Cow::Borrowed("#FF91AF"), rgb:(255,145,175), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bananamania" => crate::Color{ name:std::borrow::Cow::Borrowed("Banana Mania"), hex:std::borrow::Cow::Borrowed("#FAE7B5"), rgb:(250,231,181), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "barbiepink" => crate::Color{ name:std::borrow::Cow::Borrowed("Barbie Pink"), hex:std::borrow::Cow::Borrowed("#DA1884"), rgb:(218,24,132), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "barnred" => crate::Color{ name:std::borrow::Cow::Borrowed("Barn red"), hex:std::borrow::Cow::Borrowed("#7C0A02"), rgb:(124,10,2), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "battleshipgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("Battleship grey"), hex:std::borrow::Cow::Borrowed("#848482"), rgb:(132,132,130), aliases:&["battleshipgray"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "beaublue" => crate::Color{ name:std::borrow::Cow::Borrowed("Beau blue"), hex:std::borrow::Cow::Borrowed("#BCD4E6"), rgb:(188,212,230), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "beaver" => crate::Color{ name:std::borrow::Cow::Borrowed("Beaver"), hex:std::borrow::Cow::Borrowed("#9F8170"), rgb:(159,129,112), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "beige" => crate::Color{ name:std::borrow::Cow::Borrowed("Beige"), hex:std::borrow::Cow::Borrowed("#F5F5DC"), rgb:(245,245,220), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bdazzledblue" => crate::Color{ name:std::borrow::Cow::Borrowed("B'dazzled blue"), hex:std::borrow::Cow::Borrowed("#2E5894"), rgb:(46,88,148), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bigdiporuby" => crate::Color{ name:std::borrow::Cow::Borrowed("Big dip o’ruby"), hex:std::borrow::Cow::Borrowed("#9C2542"), rgb:(156,37,66), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bisque" => crate::Color{ name:std::borrow::Cow::Borrowed("Bisque"), hex:std::borrow::Cow::Borrowed("#FFE4C4"), rgb:(255,228,196), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bistre" => crate::Color{ name:std::borrow::Cow::Borrowed("Bistre"), hex:std::borrow::Cow::Borrowed("#3D2B1F"), rgb:(61,43,31), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bistrebrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Bistre brown"), hex:std::borrow::Cow::Borrowed("#967117"), rgb:(150,113,23), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bitterlemon" => crate::Color{ name:std::borrow::Cow::Borrowed("Bitter lemon"), hex:std::borrow::Cow::Borrowed("#CAE00D"), rgb:(202,224,13), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "black" => crate::Color{ name:std::borrow::Cow::Borrowed("Black"), hex:std::borrow::Cow::Borrowed("#000000"), rgb:(0,0,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blackbean" => crate::Color{ name:std::borrow::Cow::Borrowed("Black bean"), hex:std::borrow::Cow::Borrowed("#3D0C02"), rgb:(61,12,2), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blackcoral" => crate::Color{ name:std::borrow::Cow::Borrowed("Black coral"), hex:std::borrow::Cow::Borrowed("#54626F"), rgb:(84,98,111), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blackolive" => crate::Color{ name:std::borrow::Cow::Borrowed("Black olive"), hex:std::borrow::Cow::Borrowed("#3B3C36"), rgb:(59,60,54), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blackshadows" => crate::Color{ name:std::borrow::Cow::Borrowed("Black Shadows"), hex:std::borrow::Cow::Borrowed("#BFAFB2"), rgb:(191,175,178), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blanchedalmond" => crate::Color{ name:std::borrow::Cow::Borrowed("Blanched almond"), hex:std::borrow::Cow::Borrowed("#FFEBCD"), rgb:(255,235,205), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blastoffbronze" => crate::Color{ name:std::borrow::Cow::Borrowed("Blast-off bronze"), hex:std::borrow::Cow::Borrowed("#A57164"), rgb:(165,113,100), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bleudefrance" => crate::Color{ name:std::borrow::Cow::Borrowed("Bleu de France"), hex:std::borrow::Cow::Borrowed("#318CE7"), rgb:(49,140,231), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blizzardblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Blizzard blue"), hex:std::borrow::Cow::Borrowed("#ACE5EE"), rgb:(172,229,238), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bloodred" => crate::Color{ name:std::borrow::Cow::Borrowed("Blood red"), hex:std::borrow::Cow::Borrowed("#660000"), rgb:(102,0,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blue" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue"), hex:std::borrow::Cow::Borrowed("#0000FF"), rgb:(0,0,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bluecrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue (Crayola)"), hex:std::borrow::Cow::Borrowed("#1F75FE"), rgb:(31,117,254), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("Crayola") } },
+    "bluemunsell" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue (Munsell)"), hex:std::borrow::Cow::Borrowed("#0093AF"), rgb:(0,147,175), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("Munsell") } },
+    "bluencs" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue (NCS)"), hex:std::borrow::Cow::Borrowed("#0087BD"), rgb:(0,135,189), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("NCS") } },
+    "bluepantone" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue (Pantone)"), hex:std::borrow::Cow::Borrowed("#0018A8"), rgb:(0,24,168), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("Pantone") } },
+    "bluepigment" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue (pigment)"), hex:std::borrow::Cow::Borrowed("#333399"), rgb:(51,51,153), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("pigment") } },
+    "bluebell" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue bell"), hex:std::borrow::Cow::Borrowed("#A2A2D0"), rgb:(162,162,208), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bluegraycrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue-gray(Crayola)"), hex:std::borrow::Cow::Borrowed("#6699CC"), rgb:(102,153,204), aliases:&["bluegreycrayola"], source:crate::Source{ list:"List of colors: A–F", variant:Some("Crayola") } },
+    "bluejeans" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue jeans"), hex:std::borrow::Cow::Borrowed("#5DADEC"), rgb:(93,173,236), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bluesapphire" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue sapphire"), hex:std::borrow::Cow::Borrowed("#126180"), rgb:(18,97,128), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blueviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue-violet"), hex:std::borrow::Cow::Borrowed("#8A2BE2"), rgb:(138,43,226), aliases:&["bluevoilet"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blueyonder" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue yonder"), hex:std::borrow::Cow::Borrowed("#5072A7"), rgb:(80,114,167), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bluetiful" => crate::Color{ name:std::borrow::Cow::Borrowed("Bluetiful"), hex:std::borrow::Cow::Borrowed("#3C69E7"), rgb:(60,105,231), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blush" => crate::Color{ name:std::borrow::Cow::Borrowed("Blush"), hex:std::borrow::Cow::Borrowed("#DE5D83"), rgb:(222,93,131), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bole" => crate::Color{ name:std::borrow::Cow::Borrowed("Bole"), hex:std::borrow::Cow::Borrowed("#79443B"), rgb:(121,68,59), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bone" => crate::Color{ name:std::borrow::Cow::Borrowed("Bone"), hex:std::borrow::Cow::Borrowed("#E3DAC9"), rgb:(227,218,201), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "brickred" => crate::Color{ name:std::borrow::Cow::Borrowed("Brick red"), hex:std::borrow::Cow::Borrowed("#CB4154"), rgb:(203,65,84), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "brightlilac" => crate::Color{ name:std::borrow::Cow::Borrowed("Bright lilac"), hex:std::borrow::Cow::Borrowed("#D891EF"), rgb:(216,145,239), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "brightyellowcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Bright yellow (Crayola)"), hex:std::borrow::Cow::Borrowed("#FFAA1D"), rgb:(255,170,29), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("Crayola") } },
+    "britishracinggreen" => crate::Color{ name:std::borrow::Cow::Borrowed("British racing green"), hex:std::borrow::Cow::Borrowed("#004225"), rgb:(0,66,37), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bronze" => crate::Color{ name:std::borrow::Cow::Borrowed("Bronze"), hex:std::borrow::Cow::Borrowed("#CD7F32"), rgb:(205,127,50), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "brown" => crate::Color{ name:std::borrow::Cow::Borrowed("Brown"), hex:std::borrow::Cow::Borrowed("#964B00"), rgb:(150,75,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "brownsugar" => crate::Color{ name:std::borrow::Cow::Borrowed("Brown sugar"), hex:std::borrow::Cow::Borrowed("#AF6E4D"), rgb:(175,110,77), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "budgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Bud green"), hex:std::borrow::Cow::Borrowed("#7BB661"), rgb:(123,182,97), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "buff" => crate::Color{ name:std::borrow::Cow::Borrowed("Buff"), hex:std::borrow::Cow::Borrowed("#FFC680"), rgb:(255,198,128), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "burgundy" => crate::Color{ name:std::borrow::Cow::Borrowed("Burgundy"), hex:std::borrow::Cow::Borrowed("#800020"), rgb:(128,0,32), aliases:&["burgandy"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "burlywood" => crate::Color{ name:std::borrow::Cow::Borrowed("Burlywood"), hex:std::borrow::Cow::Borrowed("#DEB887"), rgb:(222,184,135), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "burnishedbrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Burnished brown"), hex:std::borrow::Cow::Borrowed("#A17A74"), rgb:(161,122,116), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "burntorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Burnt orange"), hex:std::borrow::Cow::Borrowed("#CC5500"), rgb:(204,85,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "burntsienna" => crate::Color{ name:std::borrow::Cow::Borrowed("Burnt sienna"), hex:std::borrow::Cow::Borrowed("#E97451"), rgb:(233,116,81), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "burntumber" => crate::Color{ name:std::borrow::Cow::Borrowed("Burnt umber"), hex:std::borrow::Cow::Borrowed("#8A3324"), rgb:(138,51,36), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "byzantine" => crate::Color{ name:std::borrow::Cow::Borrowed("Byzantine"), hex:std::borrow::Cow::Borrowed("#BD33A4"), rgb:(189,51,164), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "byzantium" => crate::Color{ name:std::borrow::Cow::Borrowed("Byzantium"), hex:std::borrow::Cow::Borrowed("#702963"), rgb:(112,41,99), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cadetblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Cadet blue"), hex:std::borrow::Cow::Borrowed("#5F9EA0"), rgb:(95,158,160), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cadetgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("Cadet grey"), hex:std::borrow::Cow::Borrowed("#91A3B0"), rgb:(145,163,176), aliases:&["cadetgray"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cadmiumgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Cadmium green"), hex:std::borrow::Cow::Borrowed("#006B3C"), rgb:(0,107,60), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cadmiumorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Cadmium orange"), hex:std::borrow::Cow::Borrowed("#ED872D"), rgb:(237,135,45), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cafeaulait" => crate::Color{ name:std::borrow::Cow::Borrowed("Café au lait"), hex:std::borrow::Cow::Borrowed("#A67B5B"), rgb:(166,123,91), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cafenoir" => crate::Color{ name:std::borrow::Cow::Borrowed("Café noir"), hex:std::borrow::Cow::Borrowed("#4B3621"), rgb:(75,54,33), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cambridgeblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Cambridge blue"), hex:std::borrow::Cow::Borrowed("#A3C1AD"), rgb:(163,193,173), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "camel" => crate::Color{ name:std::borrow::Cow::Borrowed("Camel"), hex:std::borrow::Cow::Borrowed("#C19A6B"), rgb:(193,154,107), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cameopink" => crate::Color{ name:std::borrow::Cow::Borrowed("Cameo pink"), hex:std::borrow::Cow::Borrowed("#EFBBCC"), rgb:(239,187,204), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "canary" => crate::Color{ name:std::borrow::Cow::Borrowed("Canary"), hex:std::borrow::Cow::Borrowed("#FFFF99"), rgb:(255,255,153), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "canaryyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Canary yellow"), hex:std::borrow::Cow::Borrowed("#FFEF00"), rgb:(255,239,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "candypink" => crate::Color{ name:std::borrow::Cow::Borrowed("Candy pink"), hex:std::borrow::Cow::Borrowed("#E4717A"), rgb:(228,113,122), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cardinal" => crate::Color{ name:std::borrow::Cow::Borrowed("Cardinal"), hex:std::borrow::Cow::Borrowed("#C41E3A"), rgb:(196,30,58), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "caribbeangreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Caribbean green"), hex:std::borrow::Cow::Borrowed("#00CC99"), rgb:(0,204,153), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "carmine" => crate::Color{ name:std::borrow::Cow::Borrowed("Carmine"), hex:std::borrow::Cow::Borrowed("#960018"), rgb:(150,0,24), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "carminemp" => crate::Color{ name:std::borrow::Cow::Borrowed("Carmine (M&P)"), hex:std::borrow::Cow::Borrowed("#D70040"), rgb:(215,0,64), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("M&P") } },
+    "carnationpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Carnation pink"), hex:std::borrow::Cow::Borrowed("#FFA6C9"), rgb:(255,166,201), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "carnelian" => crate::Color{ name:std::borrow::Cow::Borrowed("Carnelian"), hex:std::borrow::Cow::Borrowed("#B31B1B"), rgb:(179,27,27), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "carolinablue" => crate::Color{ name:std::borrow::Cow::Borrowed("Carolina blue"), hex:std::borrow::Cow::Borrowed("#56A0D3"), rgb:(86,160,211), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "carrotorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Carrot orange"), hex:std::borrow::Cow::Borrowed("#ED9121"), rgb:(237,145,33), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "catawba" => crate::Color{ name:std::borrow::Cow::Borrowed("Catawba"), hex:std::borrow::Cow::Borrowed("#703642"), rgb:(112,54,66), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cedarchest" => crate::Color{ name:std::borrow::Cow::Borrowed("Cedar Chest"), hex:std::borrow::Cow::Borrowed("#C95A49"), rgb:(201,90,73), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "celadon" => crate::Color{ name:std::borrow::Cow::Borrowed("Celadon"), hex:std::borrow::Cow::Borrowed("#ACE1AF"), rgb:(172,225,175), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "celeste" => crate::Color{ name:std::borrow::Cow::Borrowed("Celeste"), hex:std::borrow::Cow::Borrowed("#B2FFFF"), rgb:(178,255,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cerise" => crate::Color{ name:std::borrow::Cow::Borrowed("Cerise"), hex:std::borrow::Cow::Borrowed("#DE3163"), rgb:(222,49,99), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cerulean" => crate::Color{ name:std::borrow::Cow::Borrowed("Cerulean"), hex:std::borrow::Cow::Borrowed("#007BA7"), rgb:(0,123,167), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "ceruleanblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Cerulean blue"), hex:std::borrow::Cow::Borrowed("#2A52BE"), rgb:(42,82,190), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "ceruleanfrost" => crate::Color{ name:std::borrow::Cow::Borrowed("Cerulean frost"), hex:std::borrow::Cow::Borrowed("#6D9BC3"), rgb:(109,155,195), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "ceruleancrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Cerulean (Crayola)"), hex:std::borrow::Cow::Borrowed("#1DACD6"), rgb:(29,172,214), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("Crayola") } },
+    "ceruleanrgb" => crate::Color{ name:std::borrow::Cow::Borrowed("Cerulean (RGB)"), hex:std::borrow::Cow::Borrowed("#0040FF"), rgb:(0,64,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("RGB") } },
+    "champagne" => crate::Color{ name:std::borrow::Cow::Borrowed("Champagne"), hex:std::borrow::Cow::Borrowed("#F7E7CE"), rgb:(247,231,206), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "champagnepink" => crate::Color{ name:std::borrow::Cow::Borrowed("Champagne pink"), hex:std::borrow::Cow::Borrowed("#F1DDCF"), rgb:(241,221,207), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "charcoal" => crate::Color{ name:std::borrow::Cow::Borrowed("Charcoal"), hex:std::borrow::Cow::Borrowed("#36454F"), rgb:(54,69,79), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "charmpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Charm pink"), hex:std::borrow::Cow::Borrowed("#E68FAC"), rgb:(230,143,172), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "chartreuseweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Chartreuse (web)"), hex:std::borrow::Cow::Borrowed("#80FF00"), rgb:(128,255,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("web") } },
+    "cherryblossompink" => crate::Color{ name:std::borrow::Cow::Borrowed("Cherry blossom pink"), hex:std::borrow::Cow::Borrowed("#FFB7C5"), rgb:(255,183,197), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "chestnut" => crate::Color{ name:std::borrow::Cow::Borrowed("Chestnut"), hex:std::borrow::Cow::Borrowed("#954535"), rgb:(149,69,53), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "chilired" => crate::Color{ name:std::borrow::Cow::Borrowed("Chili red"), hex:std::borrow::Cow::Borrowed("#E23D28"), rgb:(226,61,40), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "chinapink" => crate::Color{ name:std::borrow::Cow::Borrowed("China pink"), hex:std::borrow::Cow::Borrowed("#DE6FA1"), rgb:(222,111,161), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "chinesered" => crate::Color{ name:std::borrow::Cow::Borrowed("Chinese red"), hex:std::borrow::Cow::Borrowed("#AA381E"), rgb:(170,56,30), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "chineseviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("Chinese violet"), hex:std::borrow::Cow::Borrowed("#856088"), rgb:(133,96,136), aliases:&["chinesevoilet"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "chineseyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Chinese yellow"), hex:std::borrow::Cow::Borrowed("#FFB200"), rgb:(255,178,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "chocolatetraditional" => crate::Color{ name:std::borrow::Cow::Borrowed("Chocolate (traditional)"), hex:std::borrow::Cow::Borrowed("#7B3F00"), rgb:(123,63,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("traditional") } },
+    "chocolateweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Chocolate (web)"), hex:std::borrow::Cow::Borrowed("#D2691E"), rgb:(210,105,30), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("web") } },
+    "cinereous" => crate::Color{ name:std::borrow::Cow::Borrowed("Cinereous"), hex:std::borrow::Cow::Borrowed("#98817B"), rgb:(152,129,123), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cinnabar" => crate::Color{ name:std::borrow::Cow::Borrowed("Cinnabar"), hex:std::borrow::Cow::Borrowed("#E34234"), rgb:(227,66,52), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cinnamonsatin" => crate::Color{ name:std::borrow::Cow::Borrowed("Cinnamon Satin"), hex:std::borrow::Cow::Borrowed("#CD607E"), rgb:(205,96,126), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "citrine" => crate::Color{ name:std::borrow::Cow::Borrowed("Citrine"), hex:std::borrow::Cow::Borrowed("#E4D00A"), rgb:(228,208,10), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "citron" => crate::Color{ name:std::borrow::Cow::Borrowed("Citron"), hex:std::borrow::Cow::Borrowed("#9FA91F"), rgb:(159,169,31), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "claret" => crate::Color{ name:std::borrow::Cow::Borrowed("Claret"), hex:std::borrow::Cow::Borrowed("#7F1734"), rgb:(127,23,52), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "coffee" => crate::Color{ name:std::borrow::Cow::Borrowed("Coffee"), hex:std::borrow::Cow::Borrowed("#6F4E37"), rgb:(111,78,55), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "columbiablue" => crate::Color{ name:std::borrow::Cow::Borrowed("Columbia Blue"), hex:std::borrow::Cow::Borrowed("#B9D9EB"), rgb:(185,217,235), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "congopink" => crate::Color{ name:std::borrow::Cow::Borrowed("Congo pink"), hex:std::borrow::Cow::Borrowed("#F88379"), rgb:(248,131,121), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "coolgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("Cool grey"), hex:std::borrow::Cow::Borrowed("#8C92AC"), rgb:(140,146,172), aliases:&["coolgray"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "copper" => crate::Color{ name:std::borrow::Cow::Borrowed("Copper"), hex:std::borrow::Cow::Borrowed("#B87333"), rgb:(184,115,51), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "coppercrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Copper (Crayola)"), hex:std::borrow::Cow::Borrowed("#DA8A67"), rgb:(218,138,103), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("Crayola") } },
+    "copperpenny" => crate::Color{ name:std::borrow::Cow::Borrowed("Copper penny"), hex:std::borrow::Cow::Borrowed("#AD6F69"), rgb:(173,111,105), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "copperred" => crate::Color{ name:std::borrow::Cow::Borrowed("Copper red"), hex:std::borrow::Cow::Borrowed("#CB6D51"), rgb:(203,109,81), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "copperrose" => crate::Color{ name:std::borrow::Cow::Borrowed("Copper rose"), hex:std::borrow::Cow::Borrowed("#996666"), rgb:(153,102,102), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "coquelicot" => crate::Color{ name:std::borrow::Cow::Borrowed("Coquelicot"), hex:std::borrow::Cow::Borrowed("#FF3800"), rgb:(255,56,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "coral" => crate::Color{ name:std::borrow::Cow::Borrowed("Coral"), hex:std::borrow::Cow::Borrowed("#FF7F50"), rgb:(255,127,80), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "coralpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Coral pink"), hex:std::borrow::Cow::Borrowed("#F88379"), rgb:(248,131,121), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cordovan" => crate::Color{ name:std::borrow::Cow::Borrowed("Cordovan"), hex:std::borrow::Cow::Borrowed("#893F45"), rgb:(137,63,69), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "corn" => crate::Color{ name:std::borrow::Cow::Borrowed("Corn"), hex:std::borrow::Cow::Borrowed("#FBEC5D"), rgb:(251,236,93), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cornflowerblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Cornflower blue"), hex:std::borrow::Cow::Borrowed("#6495ED"), rgb:(100,149,237), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cornsilk" => crate::Color{ name:std::borrow::Cow::Borrowed("Cornsilk"), hex:std::borrow::Cow::Borrowed("#FFF8DC"), rgb:(255,248,220), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cosmiccobalt" => crate::Color{ name:std::borrow::Cow::Borrowed("Cosmic cobalt"), hex:std::borrow::Cow::Borrowed("#2E2D88"), rgb:(46,45,136), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cosmiclatte" => crate::Color{ name:std::borrow::Cow::Borrowed("Cosmic latte"), hex:std::borrow::Cow::Borrowed("#FFF8E7"), rgb:(255,248,231), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "coyotebrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Coyote brown"), hex:std::borrow::Cow::Borrowed("#81613C"), rgb:(129,97,60), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cottoncandy" => crate::Color{ name:std::borrow::Cow::Borrowed("Cotton candy"), hex:std::borrow::Cow::Borrowed("#FFBCD9"), rgb:(255,188,217), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cream" => crate::Color{ name:std::borrow::Cow::Borrowed("Cream"), hex:std::borrow::Cow::Borrowed("#FFFDD0"), rgb:(255,253,208), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "crimson" => crate::Color{ name:std::borrow::Cow::Borrowed("Crimson"), hex:std::borrow::Cow::Borrowed("#DC143C"), rgb:(220,20,60), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "crimsonua" => crate::Color{ name:std::borrow::Cow::Borrowed("Crimson (UA)"), hex:std::borrow::Cow::Borrowed("#9E1B32"), rgb:(158,27,50), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("UA") } },
+    "culturedpearl" => crate::Color{ name:std::borrow::Cow::Borrowed("Cultured Pearl"), hex:std::borrow::Cow::Borrowed("#F5F5F5"), rgb:(245,245,245), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cyan" => crate::Color{ name:std::borrow::Cow::Borrowed("Cyan"), hex:std::borrow::Cow::Borrowed("#00FFFF"), rgb:(0,255,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cyanprocess" => crate::Color{ name:std::borrow::Cow::Borrowed("Cyan (process)"), hex:std::borrow::Cow::Borrowed("#00B7EB"), rgb:(0,183,235), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("process") } },
+    "cybergrape" => crate::Color{ name:std::borrow::Cow::Borrowed("Cyber grape"), hex:std::borrow::Cow::Borrowed("#58427C"), rgb:(88,66,124), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cyberyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Cyber yellow"), hex:std::borrow::Cow::Borrowed("#FFD300"), rgb:(255,211,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cyclamen" => crate::Color{ name:std::borrow::Cow::Borrowed("Cyclamen"), hex:std::borrow::Cow::Borrowed("#F56FA1"), rgb:(245,111,161), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "dandelion" => crate::Color{ name:std::borrow::Cow::Borrowed("Dandelion"), hex:std::borrow::Cow::Borrowed("#FED85D"), rgb:(254,216,93), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkbrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark brown"), hex:std::borrow::Cow::Borrowed("#654321"), rgb:(101,67,33), aliases:&["dkbrown"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkbyzantium" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark byzantium"), hex:std::borrow::Cow::Borrowed("#5D3954"), rgb:(93,57,84), aliases:&["dkbyzantium"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkcyan" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark cyan"), hex:std::borrow::Cow::Borrowed("#008B8B"), rgb:(0,139,139), aliases:&["dkcyan"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkelectricblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark electric blue"), hex:std::borrow::Cow::Borrowed("#536878"), rgb:(83,104,120), aliases:&["dkelectricblue"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkgoldenrod" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark goldenrod"), hex:std::borrow::Cow::Borrowed("#B8860B"), rgb:(184,134,11), aliases:&["dkgoldenrod"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkgreenx11" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark green (X11)"), hex:std::borrow::Cow::Borrowed("#006400"), rgb:(0,100,0), aliases:&["dkgreenx11"], source:crate::Source{ list:"List of colors: A–F", variant:Some("X11") } },
+    "darkjunglegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark jungle green"), hex:std::borrow::Cow::Borrowed("#1A2421"), rgb:(26,36,33), aliases:&["dkjunglegreen"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkkhaki" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark khaki"), hex:std::borrow::Cow::Borrowed("#BDB76B"), rgb:(189,183,107), aliases:&["dkkhaki"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darklava" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark lava"), hex:std::borrow::Cow::Borrowed("#483C32"), rgb:(72,60,50), aliases:&["dklava"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkliverhorses" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark liver (horses)"), hex:std::borrow::Cow::Borrowed("#543D37"), rgb:(84,61,55), aliases:&["dkliverhorses"], source:crate::Source{ list:"List of colors: A–F", variant:Some("horses") } },
+    "darkmagenta" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark magenta"), hex:std::borrow::Cow::Borrowed("#8B008B"), rgb:(139,0,139), aliases:&["darkmegenta", "dkmagenta", "dkmegenta"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkolivegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark olive green"), hex:std::borrow::Cow::Borrowed("#556B2F"), rgb:(85,107,47), aliases:&["dkolivegreen"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark orange"), hex:std::borrow::Cow::Borrowed("#FF8C00"), rgb:(255,140,0), aliases:&["dkorange"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkorchid" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark orchid"), hex:std::borrow::Cow::Borrowed("#9932CC"), rgb:(153,50,204), aliases:&["dkorchid"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark purple"), hex:std::borrow::Cow::Borrowed("#301934"), rgb:(48,25,52), aliases:&["dkpurple"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkred" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark red"), hex:std::borrow::Cow::Borrowed("#8B0000"), rgb:(139,0,0), aliases:&["dkred"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darksalmon" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark salmon"), hex:std::borrow::Cow::Borrowed("#E9967A"), rgb:(233,150,122), aliases:&["dksalmon"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkseagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark sea green"), hex:std::borrow::Cow::Borrowed("#8FBC8F"), rgb:(143,188,143), aliases:&["dkseagreen"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darksienna" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark sienna"), hex:std::borrow::Cow::Borrowed("#3C1414"), rgb:(60,20,20), aliases:&["dksienna"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark sky blue"), hex:std::borrow::Cow::Borrowed("#8CBED6"), rgb:(140,190,214), aliases:&["dkskyblue"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkslateblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark slate blue"), hex:std::borrow::Cow::Borrowed("#483D8B"), rgb:(72,61,139), aliases:&["dkslateblue"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkslategray" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark slate gray"), hex:std::borrow::Cow::Borrowed("#2F4F4F"), rgb:(47,79,79), aliases:&["darkslategrey", "dkslategray", "dkslategrey"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkspringgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark spring green"), hex:std::borrow::Cow::Borrowed("#177245"), rgb:(23,114,69), aliases:&["dkspringgreen"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkturquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark turquoise"), hex:std::borrow::Cow::Borrowed("#00CED1"), rgb:(0,206,209), aliases:&["darkturqoise", "darkturquise", "dkturquoise", "dkturqoise", "dkturquise"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark violet"), hex:std::borrow::Cow::Borrowed("#9400D3"), rgb:(148,0,211), aliases:&["darkvoilet", "dkviolet", "dkvoilet"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "davysgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("Davy's grey"), hex:std::borrow::Cow::Borrowed("#555555"), rgb:(85,85,85), aliases:&["davysgray"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "deepcerise" => crate::Color{ name:std::borrow::Cow::Borrowed("Deep cerise"), hex:std::borrow::Cow::Borrowed("#DA3287"), rgb:(218,50,135), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "deepchampagne" => crate::Color{ name:std::borrow::Cow::Borrowed("Deep champagne"), hex:std::borrow::Cow::Borrowed("#FAD6A5"), rgb:(250,214,165), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "deepchestnut" => crate::Color{ name:std::borrow::Cow::Borrowed("Deep chestnut"), hex:std::borrow::Cow::Borrowed("#B94E48"), rgb:(185,78,72), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "deepjunglegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Deep jungle green"), hex:std::borrow::Cow::Borrowed("#004B49"), rgb:(0,75,73), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "deeppink" => crate::Color{ name:std::borrow::Cow::Borrowed("Deep pink"), hex:std::borrow::Cow::Borrowed("#FF1493"), rgb:(255,20,147), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "deepsaffron" => crate::Color{ name:std::borrow::Cow::Borrowed("Deep saffron"), hex:std::borrow::Cow::Borrowed("#FF9933"), rgb:(255,153,51), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "deepskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Deep sky blue"), hex:std::borrow::Cow::Borrowed("#00BFFF"), rgb:(0,191,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "deepspacesparkle" => crate::Color{ name:std::borrow::Cow::Borrowed("Deep Space Sparkle"), hex:std::borrow::Cow::Borrowed("#4A646C"), rgb:(74,100,108), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "deeptaupe" => crate::Color{ name:std::borrow::Cow::Borrowed("Deep taupe"), hex:std::borrow::Cow::Borrowed("#7E5E60"), rgb:(126,94,96), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "denim" => crate::Color{ name:std::borrow::Cow::Borrowed("Denim"), hex:std::borrow::Cow::Borrowed("#1560BD"), rgb:(21,96,189), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "denimblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Denim blue"), hex:std::borrow::Cow::Borrowed("#2243B6"), rgb:(34,67,182), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "desert" => crate::Color{ name:std::borrow::Cow::Borrowed("Desert"), hex:std::borrow::Cow::Borrowed("#C19A6B"), rgb:(193,154,107), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "desertsand" => crate::Color{ name:std::borrow::Cow::Borrowed("Desert sand"), hex:std::borrow::Cow::Borrowed("#EDC9AF"), rgb:(237,201,175), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "dimgray" => crate::Color{ name:std::borrow::Cow::Borrowed("Dim gray"), hex:std::borrow::Cow::Borrowed("#696969"), rgb:(105,105,105), aliases:&["dimgrey"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "dodgerblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Dodger blue"), hex:std::borrow::Cow::Borrowed("#1E90FF"), rgb:(30,144,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "drabdarkbrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Drab dark brown"), hex:std::borrow::Cow::Borrowed("#4A412A"), rgb:(74,65,42), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "dukeblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Duke blue"), hex:std::borrow::Cow::Borrowed("#00009C"), rgb:(0,0,156), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "dutchwhite" => crate::Color{ name:std::borrow::Cow::Borrowed("Dutch white"), hex:std::borrow::Cow::Borrowed("#EFDFBB"), rgb:(239,223,187), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "ebony" => crate::Color{ name:std::borrow::Cow::Borrowed("Ebony"), hex:std::borrow::Cow::Borrowed("#555D50"), rgb:(85,93,80), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "ecru" => crate::Color{ name:std::borrow::Cow::Borrowed("Ecru"), hex:std::borrow::Cow::Borrowed("#C2B280"), rgb:(194,178,128), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "eerieblack" => crate::Color{ name:std::borrow::Cow::Borrowed("Eerie black"), hex:std::borrow::Cow::Borrowed("#1B1B1B"), rgb:(27,27,27), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "eggplant" => crate::Color{ name:std::borrow::Cow::Borrowed("Eggplant"), hex:std::borrow::Cow::Borrowed("#614051"), rgb:(97,64,81), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "eggshell" => crate::Color{ name:std::borrow::Cow::Borrowed("Eggshell"), hex:std::borrow::Cow::Borrowed("#F0EAD6"), rgb:(240,234,214), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "electriclime" => crate::Color{ name:std::borrow::Cow::Borrowed("Electric lime"), hex:std::borrow::Cow::Borrowed("#CCFF00"), rgb:(204,255,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "electricpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Electric purple"), hex:std::borrow::Cow::Borrowed("#BF00FF"), rgb:(191,0,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "electricviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("Electric violet"), hex:std::borrow::Cow::Borrowed("#8F00FF"), rgb:(143,0,255), aliases:&["electricvoilet"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "emerald" => crate::Color{ name:std::borrow::Cow::Borrowed("Emerald"), hex:std::borrow::Cow::Borrowed("#50C878"), rgb:(80,200,120), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "eminence" => crate::Color{ name:std::borrow::Cow::Borrowed("Eminence"), hex:std::borrow::Cow::Borrowed("#6C3082"), rgb:(108,48,130), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "englishlavender" => crate::Color{ name:std::borrow::Cow::Borrowed("English lavender"), hex:std::borrow::Cow::Borrowed("#B48395"), rgb:(180,131,149), aliases:&["englishlavendar"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "englishred" => crate::Color{ name:std::borrow::Cow::Borrowed("English red"), hex:std::borrow::Cow::Borrowed("#AB4B52"), rgb:(171,75,82), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "englishvermillion" => crate::Color{ name:std::borrow::Cow::Borrowed("English vermillion"), hex:std::borrow::Cow::Borrowed("#CC474B"), rgb:(204,71,75), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "englishviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("English violet"), hex:std::borrow::Cow::Borrowed("#563C5C"), rgb:(86,60,92), aliases:&["englishvoilet"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "erin" => crate::Color{ name:std::borrow::Cow::Borrowed("Erin"), hex:std::borrow::Cow::Borrowed("#00FF40"), rgb:(0,255,64), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "etonblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Eton blue"), hex:std::borrow::Cow::Borrowed("#96C8A2"), rgb:(150,200,162), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "fallow" => crate::Color{ name:std::borrow::Cow::Borrowed("Fallow"), hex:std::borrow::Cow::Borrowed("#C19A6B"), rgb:(193,154,107), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "falured" => crate::Color{ name:std::borrow::Cow::Borrowed("Falu red"), hex:std::borrow::Cow::Borrowed("#801818"), rgb:(128,24,24), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "fandango" => crate::Color{ name:std::borrow::Cow::Borrowed("Fandango"), hex:std::borrow::Cow::Borrowed("#B53389"), rgb:(181,51,137), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "fandangopink" => crate::Color{ name:std::borrow::Cow::Borrowed("Fandango pink"), hex:std::borrow::Cow::Borrowed("#DE5285"), rgb:(222,82,133), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "fawn" => crate::Color{ name:std::borrow::Cow::Borrowed("Fawn"), hex:std::borrow::Cow::Borrowed("#E5AA70"), rgb:(229,170,112), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "ferngreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Fern green"), hex:std::borrow::Cow::Borrowed("#4F7942"), rgb:(79,121,66), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "fielddrab" => crate::Color{ name:std::borrow::Cow::Borrowed("Field drab"), hex:std::borrow::Cow::Borrowed("#6C541E"), rgb:(108,84,30), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "fieryrose" => crate::Color{ name:std::borrow::Cow::Borrowed("Fiery rose"), hex:std::borrow::Cow::Borrowed("#FF5470"), rgb:(255,84,112), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "finn" => crate::Color{ name:std::borrow::Cow::Borrowed("Finn"), hex:std::borrow::Cow::Borrowed("#683068"), rgb:(104,48,104), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "firebrick" => crate::Color{ name:std::borrow::Cow::Borrowed("Firebrick"), hex:std::borrow::Cow::Borrowed("#B22222"), rgb:(178,34,34), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "fireenginered" => crate::Color{ name:std::borrow::Cow::Borrowed("Fire engine red"), hex:std::borrow::Cow::Borrowed("#CE2029"), rgb:(206,32,41), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "flame" => crate::Color{ name:std::borrow::Cow::Borrowed("Flame"), hex:std::borrow::Cow::Borrowed("#E25822"), rgb:(226,88,34), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "flax" => crate::Color{ name:std::borrow::Cow::Borrowed("Flax"), hex:std::borrow::Cow::Borrowed("#EEDC82"), rgb:(238,220,130), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "flirt" => crate::Color{ name:std::borrow::Cow::Borrowed("Flirt"), hex:std::borrow::Cow::Borrowed("#A2006D"), rgb:(162,0,109), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "floralwhite" => crate::Color{ name:std::borrow::Cow::Borrowed("Floral white"), hex:std::borrow::Cow::Borrowed("#FFFAF0"), rgb:(255,250,240), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "forestgreenweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Forest green (web)"), hex:std::borrow::Cow::Borrowed("#228B22"), rgb:(34,139,34), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("web") } },
+    "frenchbeige" => crate::Color{ name:std::borrow::Cow::Borrowed("French beige"), hex:std::borrow::Cow::Borrowed("#A67B5B"), rgb:(166,123,91), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frenchbistre" => crate::Color{ name:std::borrow::Cow::Borrowed("French bistre"), hex:std::borrow::Cow::Borrowed("#856D4D"), rgb:(133,109,77), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frenchblue" => crate::Color{ name:std::borrow::Cow::Borrowed("French blue"), hex:std::borrow::Cow::Borrowed("#0072BB"), rgb:(0,114,187), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frenchfuchsia" => crate::Color{ name:std::borrow::Cow::Borrowed("French fuchsia"), hex:std::borrow::Cow::Borrowed("#FD3F92"), rgb:(253,63,146), aliases:&["frenchfuschia", "frenchfushia"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frenchlilac" => crate::Color{ name:std::borrow::Cow::Borrowed("French lilac"), hex:std::borrow::Cow::Borrowed("#86608E"), rgb:(134,96,142), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frenchlime" => crate::Color{ name:std::borrow::Cow::Borrowed("French lime"), hex:std::borrow::Cow::Borrowed("#9EFD38"), rgb:(158,253,56), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frenchmauve" => crate::Color{ name:std::borrow::Cow::Borrowed("French mauve"), hex:std::borrow::Cow::Borrowed("#D473D4"), rgb:(212,115,212), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frenchpink" => crate::Color{ name:std::borrow::Cow::Borrowed("French pink"), hex:std::borrow::Cow::Borrowed("#FD6C9E"), rgb:(253,108,158), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frenchraspberry" => crate::Color{ name:std::borrow::Cow::Borrowed("French raspberry"), hex:std::borrow::Cow::Borrowed("#C72C48"), rgb:(199,44,72), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frenchskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("French sky blue"), hex:std::borrow::Cow::Borrowed("#77B5FE"), rgb:(119,181,254), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frenchviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("French violet"), hex:std::borrow::Cow::Borrowed("#8806CE"), rgb:(136,6,206), aliases:&["frenchvoilet"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frostbite" => crate::Color{ name:std::borrow::Cow::Borrowed("Frostbite"), hex:std::borrow::Cow::Borrowed("#E936A7"), rgb:(233,54,167), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "fuchsia" => crate::Color{ name:std::borrow::Cow::Borrowed("Fuchsia"), hex:std::borrow::Cow::Borrowed("#FF00FF"), rgb:(255,0,255), aliases:&["fuschia", "fushia"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "fuchsiacrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Fuchsia (Crayola)"), hex:std::borrow::Cow::Borrowed("#C154C1"), rgb:(193,84,193), aliases:&["fuschiacrayola", "fushiacrayola"], source:crate::Source{ list:"List of colors: A–F", variant:Some("Crayola") } },
+    "fulvous" => crate::Color{ name:std::borrow::Cow::Borrowed("Fulvous"), hex:std::borrow::Cow::Borrowed("#E48400"), rgb:(228,132,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "fuzzywuzzy" => crate::Color{ name:std::borrow::Cow::Borrowed("Fuzzy Wuzzy"), hex:std::borrow::Cow::Borrowed("#87421F"), rgb:(135,66,31), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "gainsboro" => crate::Color{ name:std::borrow::Cow::Borrowed("Gainsboro"), hex:std::borrow::Cow::Borrowed("#DCDCDC"), rgb:(220,220,220), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "gamboge" => crate::Color{ name:std::borrow::Cow::Borrowed("Gamboge"), hex:std::borrow::Cow::Borrowed("#E49B0F"), rgb:(228,155,15), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "genericviridian" => crate::Color{ name:std::borrow::Cow::Borrowed("Generic viridian"), hex:std::borrow::Cow::Borrowed("#007F66"), rgb:(0,127,102), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "ghostwhite" => crate::Color{ name:std::borrow::Cow::Borrowed("Ghost white"), hex:std::borrow::Cow::Borrowed("#F8F8FF"), rgb:(248,248,255), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "glaucous" => crate::Color{ name:std::borrow::Cow::Borrowed("Glaucous"), hex:std::borrow::Cow::Borrowed("#6082B6"), rgb:(96,130,182), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "glossygrape" => crate::Color{ name:std::borrow::Cow::Borrowed("Glossy grape"), hex:std::borrow::Cow::Borrowed("#AB92B3"), rgb:(171,146,179), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "gogreen" => crate::Color{ name:std::borrow::Cow::Borrowed("GO green"), hex:std::borrow::Cow::Borrowed("#00AB66"), rgb:(0,171,102), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "goldmetallic" => crate::Color{ name:std::borrow::Cow::Borrowed("Gold (metallic)"), hex:std::borrow::Cow::Borrowed("#D4AF37"), rgb:(212,175,55), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("metallic") } },
+    "goldwebgolden" => crate::Color{ name:std::borrow::Cow::Borrowed("Gold (web) (Golden)"), hex:std::borrow::Cow::Borrowed("#FFD700"), rgb:(255,215,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("web, Golden") } },
+    "goldcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Gold (Crayola)"), hex:std::borrow::Cow::Borrowed("#E6BE8A"), rgb:(230,190,138), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("Crayola") } },
+    "goldfusion" => crate::Color{ name:std::borrow::Cow::Borrowed("Gold Fusion"), hex:std::borrow::Cow::Borrowed("#85754E"), rgb:(133,117,78), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "goldenbrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Golden brown"), hex:std::borrow::Cow::Borrowed("#996515"), rgb:(153,101,21), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "goldenpoppy" => crate::Color{ name:std::borrow::Cow::Borrowed("Golden poppy"), hex:std::borrow::Cow::Borrowed("#FCC200"), rgb:(252,194,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "goldenyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Golden yellow"), hex:std::borrow::Cow::Borrowed("#FFDF00"), rgb:(255,223,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "goldenrod" => crate::Color{ name:std::borrow::Cow::Borrowed("Goldenrod"), hex:std::borrow::Cow::Borrowed("#DAA520"), rgb:(218,165,32), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "gothamgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Gotham green"), hex:std::borrow::Cow::Borrowed("#00573F"), rgb:(0,87,63), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "granitegray" => crate::Color{ name:std::borrow::Cow::Borrowed("Granite gray"), hex:std::borrow::Cow::Borrowed("#676767"), rgb:(103,103,103), aliases:&["granitegrey"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "grannysmithapple" => crate::Color{ name:std::borrow::Cow::Borrowed("Granny Smith apple"), hex:std::borrow::Cow::Borrowed("#A8E4A0"), rgb:(168,228,160), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "grayweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Gray (web)"), hex:std::borrow::Cow::Borrowed("#808080"), rgb:(128,128,128), aliases:&["greyweb"], source:crate::Source{ list:"List of colors: G–M", variant:Some("web") } },
+    "grayx11gray" => crate::Color{ name:std::borrow::Cow::Borrowed("Gray (X11 gray)"), hex:std::borrow::Cow::Borrowed("#BEBEBE"), rgb:(190,190,190), aliases:&["greyx11grey"], source:crate::Source{ list:"List of colors: G–M", variant:Some("X11 gray") } },
+    "green" => crate::Color{ name:std::borrow::Cow::Borrowed("Green"), hex:std::borrow::Cow::Borrowed("#00FF00"), rgb:(0,255,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "greencrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Green (Crayola)"), hex:std::borrow::Cow::Borrowed("#1CAC78"), rgb:(28,172,120), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("Crayola") } },
+    "greenweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Green (web)"), hex:std::borrow::Cow::Borrowed("#008000"), rgb:(0,128,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("web") } },
+    "greenmunsell" => crate::Color{ name:std::borrow::Cow::Borrowed("Green (Munsell)"), hex:std::borrow::Cow::Borrowed("#00A877"), rgb:(0,168,119), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("Munsell") } },
+    "greenncs" => crate::Color{ name:std::borrow::Cow::Borrowed("Green (NCS)"), hex:std::borrow::Cow::Borrowed("#009F6B"), rgb:(0,159,107), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("NCS") } },
+    "greenpantone" => crate::Color{ name:std::borrow::Cow::Borrowed("Green (Pantone)"), hex:std::borrow::Cow::Borrowed("#00AD43"), rgb:(0,173,67), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("Pantone") } },
+    "greenpigment" => crate::Color{ name:std::borrow::Cow::Borrowed("Green (pigment)"), hex:std::borrow::Cow::Borrowed("#00A550"), rgb:(0,165,80), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("pigment") } },
+    "greenblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Green-blue"), hex:std::borrow::Cow::Borrowed("#1164B4"), rgb:(17,100,180), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "greenlizard" => crate::Color{ name:std::borrow::Cow::Borrowed("Green Lizard"), hex:std::borrow::Cow::Borrowed("#A7F432"), rgb:(167,244,50), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "greensheen" => crate::Color{ name:std::borrow::Cow::Borrowed("Green Sheen"), hex:std::borrow::Cow::Borrowed("#6EAEA1"), rgb:(110,174,161), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "gunmetal" => crate::Color{ name:std::borrow::Cow::Borrowed("Gunmetal"), hex:std::borrow::Cow::Borrowed("#2A3439"), rgb:(42,52,57), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "hansayellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Hansa yellow"), hex:std::borrow::Cow::Borrowed("#E9D66B"), rgb:(233,214,107), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "harlequin" => crate::Color{ name:std::borrow::Cow::Borrowed("Harlequin"), hex:std::borrow::Cow::Borrowed("#3FFF00"), rgb:(63,255,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "harvestgold" => crate::Color{ name:std::borrow::Cow::Borrowed("Harvest gold"), hex:std::borrow::Cow::Borrowed("#DA9100"), rgb:(218,145,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "heatwave" => crate::Color{ name:std::borrow::Cow::Borrowed("Heat Wave"), hex:std::borrow::Cow::Borrowed("#FF7A00"), rgb:(255,122,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "heliotrope" => crate::Color{ name:std::borrow::Cow::Borrowed("Heliotrope"), hex:std::borrow::Cow::Borrowed("#DF73FF"), rgb:(223,115,255), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "heliotropegray" => crate::Color{ name:std::borrow::Cow::Borrowed("Heliotrope gray"), hex:std::borrow::Cow::Borrowed("#AA98A9"), rgb:(170,152,169), aliases:&["heliotropegrey"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "hollywoodcerise" => crate::Color{ name:std::borrow::Cow::Borrowed("Hollywood cerise"), hex:std::borrow::Cow::Borrowed("#F400A1"), rgb:(244,0,161), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "honolulublue" => crate::Color{ name:std::borrow::Cow::Borrowed("Honolulu blue"), hex:std::borrow::Cow::Borrowed("#006DB0"), rgb:(0,109,176), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "hookersgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Hooker's green"), hex:std::borrow::Cow::Borrowed("#49796B"), rgb:(73,121,107), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "hotmagentabrokenanchor" => crate::Color{ name:std::borrow::Cow::Borrowed("Hot magenta[broken anchor]"), hex:std::borrow::Cow::Borrowed("#FF1DCE"), rgb:(255,29,206), aliases:&["hotmegentabrokenanchor"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "hotpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Hot pink"), hex:std::borrow::Cow::Borrowed("#FF69B4"), rgb:(255,105,180), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "huntergreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Hunter green"), hex:std::borrow::Cow::Borrowed("#355E3B"), rgb:(53,94,59), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "iceberg" => crate::Color{ name:std::borrow::Cow::Borrowed("Iceberg"), hex:std::borrow::Cow::Borrowed("#71A6D2"), rgb:(113,166,210), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "illuminatingemerald" => crate::Color{ name:std::borrow::Cow::Borrowed("Illuminating emerald"), hex:std::borrow::Cow::Borrowed("#319177"), rgb:(49,145,119), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "imperialred" => crate::Color{ name:std::borrow::Cow::Borrowed("Imperial red"), hex:std::borrow::Cow::Borrowed("#ED2939"), rgb:(237,41,57), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "inchworm" => crate::Color{ name:std::borrow::Cow::Borrowed("Inchworm"), hex:std::borrow::Cow::Borrowed("#B2EC5D"), rgb:(178,236,93), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "independence" => crate::Color{ name:std::borrow::Cow::Borrowed("Independence"), hex:std::borrow::Cow::Borrowed("#4C516D"), rgb:(76,81,109), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "indiagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("India green"), hex:std::borrow::Cow::Borrowed("#138808"), rgb:(19,136,8), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "indianred" => crate::Color{ name:std::borrow::Cow::Borrowed("Indian red"), hex:std::borrow::Cow::Borrowed("#CD5C5C"), rgb:(205,92,92), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "indianyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Indian yellow"), hex:std::borrow::Cow::Borrowed("#E3A857"), rgb:(227,168,87), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "indigo" => crate::Color{ name:std::borrow::Cow::Borrowed("Indigo"), hex:std::borrow::Cow::Borrowed("#6A5DFF"), rgb:(106,93,255), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "indigodye" => crate::Color{ name:std::borrow::Cow::Borrowed("Indigo dye"), hex:std::borrow::Cow::Borrowed("#00416A"), rgb:(0,65,106), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "internationalkleinblue" => crate::Color{ name:std::borrow::Cow::Borrowed("International Klein Blue"), hex:std::borrow::Cow::Borrowed("#130A8F"), rgb:(19,10,143), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "internationalorangeengineering" => crate::Color{ name:std::borrow::Cow::Borrowed("International orange (engineering)"), hex:std::borrow::Cow::Borrowed("#BA160C"), rgb:(186,22,12), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("engineering") } },
+    "internationalorangegoldengatebridge" => crate::Color{ name:std::borrow::Cow::Borrowed("International orange (Golden Gate Bridge)"), hex:std::borrow::Cow::Borrowed("#C0362C"), rgb:(192,54,44), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("Golden Gate Bridge") } },
+    "irresistible" => crate::Color{ name:std::borrow::Cow::Borrowed("Irresistible"), hex:std::borrow::Cow::Borrowed("#B3446C"), rgb:(179,68,108), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "isabelline" => crate::Color{ name:std::borrow::Cow::Borrowed("Isabelline"), hex:std::borrow::Cow::Borrowed("#F4F0EC"), rgb:(244,240,236), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "italianskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Italian sky blue"), hex:std::borrow::Cow::Borrowed("#B2FFFF"), rgb:(178,255,255), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "ivory" => crate::Color{ name:std::borrow::Cow::Borrowed("Ivory"), hex:std::borrow::Cow::Borrowed("#FFFFF0"), rgb:(255,255,240), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "japanesecarmine" => crate::Color{ name:std::borrow::Cow::Borrowed("Japanese carmine"), hex:std::borrow::Cow::Borrowed("#9D2933"), rgb:(157,41,51), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "japaneseviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("Japanese violet"), hex:std::borrow::Cow::Borrowed("#5B3256"), rgb:(91,50,86), aliases:&["japanesevoilet"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "jasmine" => crate::Color{ name:std::borrow::Cow::Borrowed("Jasmine"), hex:std::borrow::Cow::Borrowed("#F8DE7E"), rgb:(248,222,126), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "jazzberryjam" => crate::Color{ name:std::borrow::Cow::Borrowed("Jazzberry jam"), hex:std::borrow::Cow::Borrowed("#A50B5E"), rgb:(165,11,94), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "jet" => crate::Color{ name:std::borrow::Cow::Borrowed("Jet"), hex:std::borrow::Cow::Borrowed("#343434"), rgb:(52,52,52), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "jonquil" => crate::Color{ name:std::borrow::Cow::Borrowed("Jonquil"), hex:std::borrow::Cow::Borrowed("#F4CA16"), rgb:(244,202,22), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "junebud" => crate::Color{ name:std::borrow::Cow::Borrowed("June bud"), hex:std::borrow::Cow::Borrowed("#BDDA57"), rgb:(189,218,87), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "junglegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Jungle green"), hex:std::borrow::Cow::Borrowed("#29AB87"), rgb:(41,171,135), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "kellygreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Kelly green"), hex:std::borrow::Cow::Borrowed("#4CBB17"), rgb:(76,187,23), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "keppel" => crate::Color{ name:std::borrow::Cow::Borrowed("Keppel"), hex:std::borrow::Cow::Borrowed("#3AB09E"), rgb:(58,176,158), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "keylime" => crate::Color{ name:std::borrow::Cow::Borrowed("Key lime"), hex:std::borrow::Cow::Borrowed("#E8F48C"), rgb:(232,244,140), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "khakiweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Khaki (web)"), hex:std::borrow::Cow::Borrowed("#C3B091"), rgb:(195,176,145), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("web") } },
+    "khakix11lightkhaki" => crate::Color{ name:std::borrow::Cow::Borrowed("Khaki (X11) (Light khaki)"), hex:std::borrow::Cow::Borrowed("#F0E68C"), rgb:(240,230,140), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("X11, Light khaki") } },
+    "kobe" => crate::Color{ name:std::borrow::Cow::Borrowed("Kobe"), hex:std::borrow::Cow::Borrowed("#882D17"), rgb:(136,45,23), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "kobi" => crate::Color{ name:std::borrow::Cow::Borrowed("Kobi"), hex:std::borrow::Cow::Borrowed("#E79FC4"), rgb:(231,159,196), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "kobicha" => crate::Color{ name:std::borrow::Cow::Borrowed("Kobicha"), hex:std::borrow::Cow::Borrowed("#6B4423"), rgb:(107,68,35), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "ksupurple" => crate::Color{ name:std::borrow::Cow::Borrowed("KSU purple"), hex:std::borrow::Cow::Borrowed("#512888"), rgb:(81,40,136), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "languidlavender" => crate::Color{ name:std::borrow::Cow::Borrowed("Languid lavender"), hex:std::borrow::Cow::Borrowed("#D6CADD"), rgb:(214,202,221), aliases:&["languidlavendar"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lapislazuli" => crate::Color{ name:std::borrow::Cow::Borrowed("Lapis lazuli"), hex:std::borrow::Cow::Borrowed("#26619C"), rgb:(38,97,156), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "laserlemon" => crate::Color{ name:std::borrow::Cow::Borrowed("Laser lemon"), hex:std::borrow::Cow::Borrowed("#FFFF66"), rgb:(255,255,102), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "laurelgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Laurel green"), hex:std::borrow::Cow::Borrowed("#A9BA9D"), rgb:(169,186,157), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lava" => crate::Color{ name:std::borrow::Cow::Borrowed("Lava"), hex:std::borrow::Cow::Borrowed("#CF1020"), rgb:(207,16,32), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lavenderfloral" => crate::Color{ name:std::borrow::Cow::Borrowed("Lavender (floral)"), hex:std::borrow::Cow::Borrowed("#B57EDC"), rgb:(181,126,220), aliases:&["lavendarfloral"], source:crate::Source{ list:"List of colors: G–M", variant:Some("floral") } },
+    "lavenderweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Lavender (web)"), hex:std::borrow::Cow::Borrowed("#E6E6FA"), rgb:(230,230,250), aliases:&["lavendarweb"], source:crate::Source{ list:"List of colors: G–M", variant:Some("web") } },
+    "lavenderblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Lavender blue"), hex:std::borrow::Cow::Borrowed("#CCCCFF"), rgb:(204,204,255), aliases:&["lavendarblue"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lavenderblush" => crate::Color{ name:std::borrow::Cow::Borrowed("Lavender blush"), hex:std::borrow::Cow::Borrowed("#FFF0F5"), rgb:(255,240,245), aliases:&["lavendarblush"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lavendergray" => crate::Color{ name:std::borrow::Cow::Borrowed("Lavender gray"), hex:std::borrow::Cow::Borrowed("#C4C3D0"), rgb:(196,195,208), aliases:&["lavendargray", "lavendergrey", "lavendargrey"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lawngreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Lawn green"), hex:std::borrow::Cow::Borrowed("#7CFC00"), rgb:(124,252,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lemon" => crate::Color{ name:std::borrow::Cow::Borrowed("Lemon"), hex:std::borrow::Cow::Borrowed("#FFF700"), rgb:(255,247,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lemonchiffon" => crate::Color{ name:std::borrow::Cow::Borrowed("Lemon chiffon"), hex:std::borrow::Cow::Borrowed("#FFFACD"), rgb:(255,250,205), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lemoncurry" => crate::Color{ name:std::borrow::Cow::Borrowed("Lemon curry"), hex:std::borrow::Cow::Borrowed("#CCA01D"), rgb:(204,160,29), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lemonglacier" => crate::Color{ name:std::borrow::Cow::Borrowed("Lemon glacier"), hex:std::borrow::Cow::Borrowed("#FDFF00"), rgb:(253,255,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lemonmeringue" => crate::Color{ name:std::borrow::Cow::Borrowed("Lemon meringue"), hex:std::borrow::Cow::Borrowed("#F6EABE"), rgb:(246,234,190), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lemonyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Lemon yellow"), hex:std::borrow::Cow::Borrowed("#FFF44F"), rgb:(255,244,79), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lemonyellowcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Lemon yellow (Crayola)"), hex:std::borrow::Cow::Borrowed("#FFFF9F"), rgb:(255,255,159), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("Crayola") } },
+    "liberty" => crate::Color{ name:std::borrow::Cow::Borrowed("Liberty"), hex:std::borrow::Cow::Borrowed("#545AA7"), rgb:(84,90,167), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Light blue"), hex:std::borrow::Cow::Borrowed("#ADD8E6"), rgb:(173,216,230), aliases:&["ltblue"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightcoral" => crate::Color{ name:std::borrow::Cow::Borrowed("Light coral"), hex:std::borrow::Cow::Borrowed("#F08080"), rgb:(240,128,128), aliases:&["ltcoral"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightcornflowerblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Light cornflower blue"), hex:std::borrow::Cow::Borrowed("#93CCEA"), rgb:(147,204,234), aliases:&["ltcornflowerblue"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightcyan" => crate::Color{ name:std::borrow::Cow::Borrowed("Light cyan"), hex:std::borrow::Cow::Borrowed("#E0FFFF"), rgb:(224,255,255), aliases:&["ltcyan"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightfrenchbeige" => crate::Color{ name:std::borrow::Cow::Borrowed("Light French beige"), hex:std::borrow::Cow::Borrowed("#C8AD7F"), rgb:(200,173,127), aliases:&["ltfrenchbeige"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightgoldenrodyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Light goldenrod yellow"), hex:std::borrow::Cow::Borrowed("#FAFAD2"), rgb:(250,250,210), aliases:&["ltgoldenrodyellow"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightgray" => crate::Color{ name:std::borrow::Cow::Borrowed("Light gray"), hex:std::borrow::Cow::Borrowed("#D3D3D3"), rgb:(211,211,211), aliases:&["lightgrey", "ltgray", "ltgrey"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Light green"), hex:std::borrow::Cow::Borrowed("#90EE90"), rgb:(144,238,144), aliases:&["ltgreen"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Light orange"), hex:std::borrow::Cow::Borrowed("#FED8B1"), rgb:(254,216,177), aliases:&["ltorange"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightperiwinkle" => crate::Color{ name:std::borrow::Cow::Borrowed("Light periwinkle"), hex:std::borrow::Cow::Borrowed("#C5CBE1"), rgb:(197,203,225), aliases:&["ltperiwinkle"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Light pink"), hex:std::borrow::Cow::Borrowed("#FFB6C1"), rgb:(255,182,193), aliases:&["ltpink"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Light purple"), hex:std::borrow::Cow::Borrowed("#D8BFD8"), rgb:(216,191,216), aliases:&["ltpurple"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightsalmon" => crate::Color{ name:std::borrow::Cow::Borrowed("Light salmon"), hex:std::borrow::Cow::Borrowed("#FFA07A"), rgb:(255,160,122), aliases:&["ltsalmon"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightseagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Light sea green"), hex:std::borrow::Cow::Borrowed("#20B2AA"), rgb:(32,178,170), aliases:&["ltseagreen"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Light sky blue"), hex:std::borrow::Cow::Borrowed("#87CEFA"), rgb:(135,206,250), aliases:&["ltskyblue"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightslategray" => crate::Color{ name:std::borrow::Cow::Borrowed("Light slate gray"), hex:std::borrow::Cow::Borrowed("#778899"), rgb:(119,136,153), aliases:&["lightslategrey", "ltslategray", "ltslategrey"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightsteelblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Light steel blue"), hex:std::borrow::Cow::Borrowed("#B0C4DE"), rgb:(176,196,222), aliases:&["ltsteelblue"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Light yellow"), hex:std::borrow::Cow::Borrowed("#FFFFE0"), rgb:(255,255,224), aliases:&["ltyellow"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lilac" => crate::Color{ name:std::borrow::Cow::Borrowed("Lilac"), hex:std::borrow::Cow::Borrowed("#C8A2C8"), rgb:(200,162,200), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lilacluster" => crate::Color{ name:std::borrow::Cow::Borrowed("Lilac Luster"), hex:std::borrow::Cow::Borrowed("#AE98AA"), rgb:(174,152,170), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "limecolorwheel" => crate::Color{ name:std::borrow::Cow::Borrowed("Lime (color wheel)"), hex:std::borrow::Cow::Borrowed("#BFFF00"), rgb:(191,255,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("color wheel") } },
+    "limewebx11green" => crate::Color{ name:std::borrow::Cow::Borrowed("Lime (web) (X11 green)"), hex:std::borrow::Cow::Borrowed("#00FF00"), rgb:(0,255,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("web, X11 green") } },
+    "limegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Lime green"), hex:std::borrow::Cow::Borrowed("#32CD32"), rgb:(50,205,50), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lincolngreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Lincoln green"), hex:std::borrow::Cow::Borrowed("#195905"), rgb:(25,89,5), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "linen" => crate::Color{ name:std::borrow::Cow::Borrowed("Linen"), hex:std::borrow::Cow::Borrowed("#FAF0E6"), rgb:(250,240,230), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lion" => crate::Color{ name:std::borrow::Cow::Borrowed("Lion"), hex:std::borrow::Cow::Borrowed("#DECC9C"), rgb:(222,204,156), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "liseranpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Liseran purple"), hex:std::borrow::Cow::Borrowed("#DE6FA1"), rgb:(222,111,161), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "littleboyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Little boy blue"), hex:std::borrow::Cow::Borrowed("#6CA0DC"), rgb:(108,160,220), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "liver" => crate::Color{ name:std::borrow::Cow::Borrowed("Liver"), hex:std::borrow::Cow::Borrowed("#674C47"), rgb:(103,76,71), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "liverdogs" => crate::Color{ name:std::borrow::Cow::Borrowed("Liver (dogs)"), hex:std::borrow::Cow::Borrowed("#B86D29"), rgb:(184,109,41), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("dogs") } },
+    "liverorgan" => crate::Color{ name:std::borrow::Cow::Borrowed("Liver (organ)"), hex:std::borrow::Cow::Borrowed("#6C2E1F"), rgb:(108,46,31), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("organ") } },
+    "liverchestnut" => crate::Color{ name:std::borrow::Cow::Borrowed("Liver chestnut"), hex:std::borrow::Cow::Borrowed("#987456"), rgb:(152,116,86), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "livid" => crate::Color{ name:std::borrow::Cow::Borrowed("Livid"), hex:std::borrow::Cow::Borrowed("#6699CC"), rgb:(102,153,204), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "macaroniandcheese" => crate::Color{ name:std::borrow::Cow::Borrowed("Macaroni and Cheese"), hex:std::borrow::Cow::Borrowed("#FFBD88"), rgb:(255,189,136), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "madderlake" => crate::Color{ name:std::borrow::Cow::Borrowed("Madder Lake"), hex:std::borrow::Cow::Borrowed("#CC3336"), rgb:(204,51,54), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "magenta" => crate::Color{ name:std::borrow::Cow::Borrowed("Magenta"), hex:std::borrow::Cow::Borrowed("#FF00FF"), rgb:(255,0,255), aliases:&["megenta"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "magentacrayolabrokenanchor" => crate::Color{ name:std::borrow::Cow::Borrowed("Magenta (Crayola)[broken anchor]"), hex:std::borrow::Cow::Borrowed("#F653A6"), rgb:(246,83,166), aliases:&["megentacrayolabrokenanchor"], source:crate::Source{ list:"List of colors: G–M", variant:Some("Crayola") } },
+    "magentadye" => crate::Color{ name:std::borrow::Cow::Borrowed("Magenta (dye)"), hex:std::borrow::Cow::Borrowed("#CA1F7B"), rgb:(202,31,123), aliases:&["megentadye"], source:crate::Source{ list:"List of colors: G–M", variant:Some("dye") } },
+    "magentapantone" => crate::Color{ name:std::borrow::Cow::Borrowed("Magenta (Pantone)"), hex:std::borrow::Cow::Borrowed("#D0417E"), rgb:(208,65,126), aliases:&["megentapantone"], source:crate::Source{ list:"List of colors: G–M", variant:Some("Pantone") } },
+    "magentaprocess" => crate::Color{ name:std::borrow::Cow::Borrowed("Magenta (process)"), hex:std::borrow::Cow::Borrowed("#FF0090"), rgb:(255,0,144), aliases:&["megentaprocess"], source:crate::Source{ list:"List of colors: G–M", variant:Some("process") } },
+    "magentahaze" => crate::Color{ name:std::borrow::Cow::Borrowed("Magenta haze"), hex:std::borrow::Cow::Borrowed("#9F4576"), rgb:(159,69,118), aliases:&["megentahaze"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "magicmint" => crate::Color{ name:std::borrow::Cow::Borrowed("Magic mint"), hex:std::borrow::Cow::Borrowed("#AAF0D1"), rgb:(170,240,209), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "magnolia" => crate::Color{ name:std::borrow::Cow::Borrowed("Magnolia"), hex:std::borrow::Cow::Borrowed("#F2E8D7"), rgb:(242,232,215), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mahogany" => crate::Color{ name:std::borrow::Cow::Borrowed("Mahogany"), hex:std::borrow::Cow::Borrowed("#C04000"), rgb:(192,64,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maize" => crate::Color{ name:std::borrow::Cow::Borrowed("Maize"), hex:std::borrow::Cow::Borrowed("#FBEC5D"), rgb:(251,236,93), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maizecrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Maize (Crayola)"), hex:std::borrow::Cow::Borrowed("#F2C649"), rgb:(242,198,73), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("Crayola") } },
+    "majorelleblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Majorelle blue"), hex:std::borrow::Cow::Borrowed("#6050DC"), rgb:(96,80,220), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "malachite" => crate::Color{ name:std::borrow::Cow::Borrowed("Malachite"), hex:std::borrow::Cow::Borrowed("#0BDA51"), rgb:(11,218,81), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "manatee" => crate::Color{ name:std::borrow::Cow::Borrowed("Manatee"), hex:std::borrow::Cow::Borrowed("#979AAA"), rgb:(151,154,170), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mandarin" => crate::Color{ name:std::borrow::Cow::Borrowed("Mandarin"), hex:std::borrow::Cow::Borrowed("#F37A48"), rgb:(243,122,72), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mango" => crate::Color{ name:std::borrow::Cow::Borrowed("Mango"), hex:std::borrow::Cow::Borrowed("#FDBE02"), rgb:(253,190,2), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mangotango" => crate::Color{ name:std::borrow::Cow::Borrowed("Mango Tango"), hex:std::borrow::Cow::Borrowed("#FF8243"), rgb:(255,130,67), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mantis" => crate::Color{ name:std::borrow::Cow::Borrowed("Mantis"), hex:std::borrow::Cow::Borrowed("#74C365"), rgb:(116,195,101), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mardigras" => crate::Color{ name:std::borrow::Cow::Borrowed("Mardi Gras"), hex:std::borrow::Cow::Borrowed("#880085"), rgb:(136,0,133), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "marigold" => crate::Color{ name:std::borrow::Cow::Borrowed("Marigold"), hex:std::borrow::Cow::Borrowed("#EAA221"), rgb:(234,162,33), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "marooncrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Maroon (Crayola)"), hex:std::borrow::Cow::Borrowed("#C32148"), rgb:(195,33,72), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("Crayola") } },
+    "maroonweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Maroon (web)"), hex:std::borrow::Cow::Borrowed("#800000"), rgb:(128,0,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("web") } },
+    "maroonx11" => crate::Color{ name:std::borrow::Cow::Borrowed("Maroon (X11)"), hex:std::borrow::Cow::Borrowed("#B03060"), rgb:(176,48,96), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("X11") } },
+    "mauve" => crate::Color{ name:std::borrow::Cow::Borrowed("Mauve"), hex:std::borrow::Cow::Borrowed("#E0B0FF"), rgb:(224,176,255), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mauvetaupe" => crate::Color{ name:std::borrow::Cow::Borrowed("Mauve taupe"), hex:std::borrow::Cow::Borrowed("#915F6D"), rgb:(145,95,109), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mauvelous" => crate::Color{ name:std::borrow::Cow::Borrowed("Mauvelous"), hex:std::borrow::Cow::Borrowed("#EF98AA"), rgb:(239,152,170), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maximumblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Maximum blue"), hex:std::borrow::Cow::Borrowed("#47ABCC"), rgb:(71,171,204), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maximumbluegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Maximum blue green"), hex:std::borrow::Cow::Borrowed("#30BFBF"), rgb:(48,191,191), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maximumbluepurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Maximum blue purple"), hex:std::borrow::Cow::Borrowed("#ACACE6"), rgb:(172,172,230), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maximumgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Maximum green"), hex:std::borrow::Cow::Borrowed("#5E8C31"), rgb:(94,140,49), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maximumgreenyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Maximum green yellow"), hex:std::borrow::Cow::Borrowed("#D9E650"), rgb:(217,230,80), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maximumpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Maximum purple"), hex:std::borrow::Cow::Borrowed("#733380"), rgb:(115,51,128), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maximumred" => crate::Color{ name:std::borrow::Cow::Borrowed("Maximum red"), hex:std::borrow::Cow::Borrowed("#D92121"), rgb:(217,33,33), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maximumredpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Maximum red purple"), hex:std::borrow::Cow::Borrowed("#A63A79"), rgb:(166,58,121), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maximumyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Maximum yellow"), hex:std::borrow::Cow::Borrowed("#FAFA37"), rgb:(250,250,55), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maximumyellowred" => crate::Color{ name:std::borrow::Cow::Borrowed("Maximum yellow red"), hex:std::borrow::Cow::Borrowed("#F2BA49"), rgb:(242,186,73), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maygreen" => crate::Color{ name:std::borrow::Cow::Borrowed("May green"), hex:std::borrow::Cow::Borrowed("#4C9141"), rgb:(76,145,65), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mayablue" => crate::Color{ name:std::borrow::Cow::Borrowed("Maya blue"), hex:std::borrow::Cow::Borrowed("#73C2FB"), rgb:(115,194,251), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumaquamarine" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium aquamarine"), hex:std::borrow::Cow::Borrowed("#66DDAA"), rgb:(102,221,170), aliases:&["medaquamarine"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium blue"), hex:std::borrow::Cow::Borrowed("#0000CD"), rgb:(0,0,205), aliases:&["medblue"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumcandyapplered" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium candy apple red"), hex:std::borrow::Cow::Borrowed("#E2062C"), rgb:(226,6,44), aliases:&["medcandyapplered"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumcarmine" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium carmine"), hex:std::borrow::Cow::Borrowed("#AF4035"), rgb:(175,64,53), aliases:&["medcarmine"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumchampagne" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium champagne"), hex:std::borrow::Cow::Borrowed("#F3E5AB"), rgb:(243,229,171), aliases:&["medchampagne"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumorchid" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium orchid"), hex:std::borrow::Cow::Borrowed("#BA55D3"), rgb:(186,85,211), aliases:&["medorchid"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium purple"), hex:std::borrow::Cow::Borrowed("#9370DB"), rgb:(147,112,219), aliases:&["medpurple"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumseagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium sea green"), hex:std::borrow::Cow::Borrowed("#3CB371"), rgb:(60,179,113), aliases:&["medseagreen"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumslateblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium slate blue"), hex:std::borrow::Cow::Borrowed("#7B68EE"), rgb:(123,104,238), aliases:&["medslateblue"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumspringgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium spring green"), hex:std::borrow::Cow::Borrowed("#00FA9A"), rgb:(0,250,154), aliases:&["medspringgreen"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumturquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium turquoise"), hex:std::borrow::Cow::Borrowed("#48D1CC"), rgb:(72,209,204), aliases:&["mediumturqoise", "mediumturquise", "medturquoise", "medturqoise", "medturquise"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumvioletred" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium violet-red"), hex:std::borrow::Cow::Borrowed("#C71585"), rgb:(199,21,133), aliases:&["mediumvoiletred", "medvioletred", "medvoiletred"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mellowapricot" => crate::Color{ name:std::borrow::Cow::Borrowed("Mellow apricot"), hex:std::borrow::Cow::Borrowed("#F8B878"), rgb:(248,184,120), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mellowyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Mellow yellow"), hex:std::borrow::Cow::Borrowed("#F8DE7E"), rgb:(248,222,126), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "melon" => crate::Color{ name:std::borrow::Cow::Borrowed("Melon"), hex:std::borrow::Cow::Borrowed("#FEBAAD"), rgb:(254,186,173), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "metallicgold" => crate::Color{ name:std::borrow::Cow::Borrowed("Metallic gold"), hex:std::borrow::Cow::Borrowed("#D3AF37"), rgb:(211,175,55), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "metallicseaweed" => crate::Color{ name:std::borrow::Cow::Borrowed("Metallic Seaweed"), hex:std::borrow::Cow::Borrowed("#0A7E8C"), rgb:(10,126,140), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "metallicsunburst" => crate::Color{ name:std::borrow::Cow::Borrowed("Metallic Sunburst"), hex:std::borrow::Cow::Borrowed("#9C7C38"), rgb:(156,124,56), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mexicanpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Mexican pink"), hex:std::borrow::Cow::Borrowed("#E4007C"), rgb:(228,0,124), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middleblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle blue"), hex:std::borrow::Cow::Borrowed("#7ED4E6"), rgb:(126,212,230), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middlebluegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle blue green"), hex:std::borrow::Cow::Borrowed("#8DD9CC"), rgb:(141,217,204), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middlebluepurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle blue purple"), hex:std::borrow::Cow::Borrowed("#8B72BE"), rgb:(139,114,190), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middlegrey" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle grey"), hex:std::borrow::Cow::Borrowed("#8B8680"), rgb:(139,134,128), aliases:&["middlegray"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middlegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle green"), hex:std::borrow::Cow::Borrowed("#4D8C57"), rgb:(77,140,87), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middlegreenyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle green yellow"), hex:std::borrow::Cow::Borrowed("#ACBF60"), rgb:(172,191,96), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middlepurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle purple"), hex:std::borrow::Cow::Borrowed("#D982B5"), rgb:(217,130,181), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middlered" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle red"), hex:std::borrow::Cow::Borrowed("#E58E73"), rgb:(229,142,115), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middleredpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle red purple"), hex:std::borrow::Cow::Borrowed("#A55353"), rgb:(165,83,83), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middleyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle yellow"), hex:std::borrow::Cow::Borrowed("#FFEB00"), rgb:(255,235,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middleyellowred" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle yellow red"), hex:std::borrow::Cow::Borrowed("#ECB176"), rgb:(236,177,118), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "midnight" => crate::Color{ name:std::borrow::Cow::Borrowed("Midnight"), hex:std::borrow::Cow::Borrowed("#702670"), rgb:(112,38,112), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "midnightblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Midnight blue"), hex:std::borrow::Cow::Borrowed("#191970"), rgb:(25,25,112), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "midnightgreeneaglegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Midnight green (eagle green)"), hex:std::borrow::Cow::Borrowed("#004953"), rgb:(0,73,83), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("eagle green") } },
+    "mikadoyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Mikado yellow"), hex:std::borrow::Cow::Borrowed("#FFC40C"), rgb:(255,196,12), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mimipink" => crate::Color{ name:std::borrow::Cow::Borrowed("Mimi pink"), hex:std::borrow::Cow::Borrowed("#FFDAE9"), rgb:(255,218,233), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mindaro" => crate::Color{ name:std::borrow::Cow::Borrowed("Mindaro"), hex:std::borrow::Cow::Borrowed("#E3F988"), rgb:(227,249,136), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "ming" => crate::Color{ name:std::borrow::Cow::Borrowed("Ming"), hex:std::borrow::Cow::Borrowed("#36747D"), rgb:(54,116,125), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "minionyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Minion yellow"), hex:std::borrow::Cow::Borrowed("#F5E050"), rgb:(245,224,80), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mint" => crate::Color{ name:std::borrow::Cow::Borrowed("Mint"), hex:std::borrow::Cow::Borrowed("#3EB489"), rgb:(62,180,137), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mintcream" => crate::Color{ name:std::borrow::Cow::Borrowed("Mint cream"), hex:std::borrow::Cow::Borrowed("#F5FFFA"), rgb:(245,255,250), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mintgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Mint green"), hex:std::borrow::Cow::Borrowed("#98FF98"), rgb:(152,255,152), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mistymoss" => crate::Color{ name:std::borrow::Cow::Borrowed("Misty moss"), hex:std::borrow::Cow::Borrowed("#BBB477"), rgb:(187,180,119), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mistyrose" => crate::Color{ name:std::borrow::Cow::Borrowed("Misty rose"), hex:std::borrow::Cow::Borrowed("#FFE4E1"), rgb:(255,228,225), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "moccasin" => crate::Color{ name:std::borrow::Cow::Borrowed("Moccasin"), hex:std::borrow::Cow::Borrowed("#FFE4B5"), rgb:(255,228,181), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "modebeige" => crate::Color{ name:std::borrow::Cow::Borrowed("Mode beige"), hex:std::borrow::Cow::Borrowed("#967117"), rgb:(150,113,23), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "monalisa" => crate::Color{ name:std::borrow::Cow::Borrowed("Mona Lisa"), hex:std::borrow::Cow::Borrowed("#FF948E"), rgb:(255,148,142), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "morningblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Morning blue"), hex:std::borrow::Cow::Borrowed("#8DA399"), rgb:(141,163,153), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mossgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Moss green"), hex:std::borrow::Cow::Borrowed("#8A9A5B"), rgb:(138,154,91), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mountainmeadow" => crate::Color{ name:std::borrow::Cow::Borrowed("Mountain Meadow"), hex:std::borrow::Cow::Borrowed("#30BA8F"), rgb:(48,186,143), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mountbattenpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Mountbatten pink"), hex:std::borrow::Cow::Borrowed("#997A8D"), rgb:(153,122,141), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "msugreen" => crate::Color{ name:std::borrow::Cow::Borrowed("MSU green"), hex:std::borrow::Cow::Borrowed("#18453B"), rgb:(24,69,59), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mulberry" => crate::Color{ name:std::borrow::Cow::Borrowed("Mulberry"), hex:std::borrow::Cow::Borrowed("#C54B8C"), rgb:(197,75,140), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mulberrycrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Mulberry (Crayola)"), hex:std::borrow::Cow::Borrowed("#C8509B"), rgb:(200,80,155), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("Crayola") } },
+    "mustard" => crate::Color{ name:std::borrow::Cow::Borrowed("Mustard"), hex:std::borrow::Cow::Borrowed("#FFDB58"), rgb:(255,219,88), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "myrtlegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Myrtle green"), hex:std::borrow::Cow::Borrowed("#317873"), rgb:(49,120,115), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mystic" => crate::Color{ name:std::borrow::Cow::Borrowed("Mystic"), hex:std::borrow::Cow::Borrowed("#D65282"), rgb:(214,82,130), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mysticmaroon" => crate::Color{ name:std::borrow::Cow::Borrowed("Mystic maroon"), hex:std::borrow::Cow::Borrowed("#AD4379"), rgb:(173,67,121), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "nadeshikopink" => crate::Color{ name:std::borrow::Cow::Borrowed("Nadeshiko pink"), hex:std::borrow::Cow::Borrowed("#F6ADC6"), rgb:(246,173,198), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "naplesyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Naples yellow"), hex:std::borrow::Cow::Borrowed("#FADA5E"), rgb:(250,218,94), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "navajowhite" => crate::Color{ name:std::borrow::Cow::Borrowed("Navajo white"), hex:std::borrow::Cow::Borrowed("#FFDEAD"), rgb:(255,222,173), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "navyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Navy blue"), hex:std::borrow::Cow::Borrowed("#000080"), rgb:(0,0,128), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "navybluecrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Navy blue (Crayola)"), hex:std::borrow::Cow::Borrowed("#1974D2"), rgb:(25,116,210), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "neonblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Neon blue"), hex:std::borrow::Cow::Borrowed("#4666FF"), rgb:(70,102,255), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "neongreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Neon green"), hex:std::borrow::Cow::Borrowed("#39FF14"), rgb:(57,255,20), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "neonfuchsia" => crate::Color{ name:std::borrow::Cow::Borrowed("Neon fuchsia"), hex:std::borrow::Cow::Borrowed("#FE4164"), rgb:(254,65,100), aliases:&["neonfuschia", "neonfushia"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "newcar" => crate::Color{ name:std::borrow::Cow::Borrowed("New Car"), hex:std::borrow::Cow::Borrowed("#214FC6"), rgb:(33,79,198), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "newyorkpink" => crate::Color{ name:std::borrow::Cow::Borrowed("New York pink"), hex:std::borrow::Cow::Borrowed("#D7837F"), rgb:(215,131,127), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "nickel" => crate::Color{ name:std::borrow::Cow::Borrowed("Nickel"), hex:std::borrow::Cow::Borrowed("#727472"), rgb:(114,116,114), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "nonphotoblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Non-photo blue"), hex:std::borrow::Cow::Borrowed("#A4DDED"), rgb:(164,221,237), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "nyanza" => crate::Color{ name:std::borrow::Cow::Borrowed("Nyanza"), hex:std::borrow::Cow::Borrowed("#E9FFDB"), rgb:(233,255,219), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "ochre" => crate::Color{ name:std::borrow::Cow::Borrowed("Ochre"), hex:std::borrow::Cow::Borrowed("#CC7722"), rgb:(204,119,34), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "oldburgundy" => crate::Color{ name:std::borrow::Cow::Borrowed("Old burgundy"), hex:std::borrow::Cow::Borrowed("#43302E"), rgb:(67,48,46), aliases:&["oldburgandy"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "oldgold" => crate::Color{ name:std::borrow::Cow::Borrowed("Old gold"), hex:std::borrow::Cow::Borrowed("#CFB53B"), rgb:(207,181,59), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "oldlace" => crate::Color{ name:std::borrow::Cow::Borrowed("Old lace"), hex:std::borrow::Cow::Borrowed("#FDF5E6"), rgb:(253,245,230), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "oldlavender" => crate::Color{ name:std::borrow::Cow::Borrowed("Old lavender"), hex:std::borrow::Cow::Borrowed("#796878"), rgb:(121,104,120), aliases:&["oldlavendar"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "oldmauve" => crate::Color{ name:std::borrow::Cow::Borrowed("Old mauve"), hex:std::borrow::Cow::Borrowed("#673147"), rgb:(103,49,71), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "oldrose" => crate::Color{ name:std::borrow::Cow::Borrowed("Old rose"), hex:std::borrow::Cow::Borrowed("#C08081"), rgb:(192,128,129), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "oldsilver" => crate::Color{ name:std::borrow::Cow::Borrowed("Old silver"), hex:std::borrow::Cow::Borrowed("#848482"), rgb:(132,132,130), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "olive" => crate::Color{ name:std::borrow::Cow::Borrowed("Olive"), hex:std::borrow::Cow::Borrowed("#808000"), rgb:(128,128,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "olivedrab3" => crate::Color{ name:std::borrow::Cow::Borrowed("Olive Drab (#3)"), hex:std::borrow::Cow::Borrowed("#6B8E23"), rgb:(107,142,35), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("#3") } },
+    "olivedrab7" => crate::Color{ name:std::borrow::Cow::Borrowed("Olive Drab #7"), hex:std::borrow::Cow::Borrowed("#3C341F"), rgb:(60,52,31), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("#7") } },
+    "olivegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Olive green"), hex:std::borrow::Cow::Borrowed("#B5B35C"), rgb:(181,179,92), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "olivine" => crate::Color{ name:std::borrow::Cow::Borrowed("Olivine"), hex:std::borrow::Cow::Borrowed("#9AB973"), rgb:(154,185,115), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "onyx" => crate::Color{ name:std::borrow::Cow::Borrowed("Onyx"), hex:std::borrow::Cow::Borrowed("#353839"), rgb:(53,56,57), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "opal" => crate::Color{ name:std::borrow::Cow::Borrowed("Opal"), hex:std::borrow::Cow::Borrowed("#A8C3BC"), rgb:(168,195,188), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "operamauve" => crate::Color{ name:std::borrow::Cow::Borrowed("Opera mauve"), hex:std::borrow::Cow::Borrowed("#B784A7"), rgb:(183,132,167), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "orange" => crate::Color{ name:std::borrow::Cow::Borrowed("Orange"), hex:std::borrow::Cow::Borrowed("#FF7F00"), rgb:(255,127,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "orangecrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Orange (Crayola)"), hex:std::borrow::Cow::Borrowed("#FF7538"), rgb:(255,117,56), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "orangepantone" => crate::Color{ name:std::borrow::Cow::Borrowed("Orange (Pantone)"), hex:std::borrow::Cow::Borrowed("#FF5800"), rgb:(255,88,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Pantone") } },
+    "orangeweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Orange (web)"), hex:std::borrow::Cow::Borrowed("#FFA500"), rgb:(255,165,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("web") } },
+    "orangepeel" => crate::Color{ name:std::borrow::Cow::Borrowed("Orange peel"), hex:std::borrow::Cow::Borrowed("#FF9F00"), rgb:(255,159,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "orangered" => crate::Color{ name:std::borrow::Cow::Borrowed("Orange-red"), hex:std::borrow::Cow::Borrowed("#FF681F"), rgb:(255,104,31), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "orangeredcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Orange-red (Crayola)"), hex:std::borrow::Cow::Borrowed("#FF5349"), rgb:(255,83,73), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "orangesoda" => crate::Color{ name:std::borrow::Cow::Borrowed("Orange soda"), hex:std::borrow::Cow::Borrowed("#FA5B3D"), rgb:(250,91,61), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "orangeyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Orange-yellow"), hex:std::borrow::Cow::Borrowed("#F5BD1F"), rgb:(245,189,31), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "orangeyellowcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Orange-yellow (Crayola)"), hex:std::borrow::Cow::Borrowed("#F8D568"), rgb:(248,213,104), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "orchid" => crate::Color{ name:std::borrow::Cow::Borrowed("Orchid"), hex:std::borrow::Cow::Borrowed("#DA70D6"), rgb:(218,112,214), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "orchidpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Orchid pink"), hex:std::borrow::Cow::Borrowed("#F2BDCD"), rgb:(242,189,205), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "orchidcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Orchid (Crayola)"), hex:std::borrow::Cow::Borrowed("#E29CD2"), rgb:(226,156,210), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "outerspacecrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Outer space (Crayola)"), hex:std::borrow::Cow::Borrowed("#2D383A"), rgb:(45,56,58), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "outrageousorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Outrageous Orange"), hex:std::borrow::Cow::Borrowed("#FF6E4A"), rgb:(255,110,74), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "oxblood" => crate::Color{ name:std::borrow::Cow::Borrowed("Oxblood"), hex:std::borrow::Cow::Borrowed("#4A0000"), rgb:(74,0,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "oxfordblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Oxford blue"), hex:std::borrow::Cow::Borrowed("#002147"), rgb:(0,33,71), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "oucrimsonred" => crate::Color{ name:std::borrow::Cow::Borrowed("OU Crimson red"), hex:std::borrow::Cow::Borrowed("#841617"), rgb:(132,22,23), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pacificblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Pacific blue"), hex:std::borrow::Cow::Borrowed("#1CA9C9"), rgb:(28,169,201), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pakistangreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Pakistan green"), hex:std::borrow::Cow::Borrowed("#006600"), rgb:(0,102,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "palatinatepurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Palatinate purple"), hex:std::borrow::Cow::Borrowed("#682860"), rgb:(104,40,96), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "paleaqua" => crate::Color{ name:std::borrow::Cow::Borrowed("Pale aqua"), hex:std::borrow::Cow::Borrowed("#BED3E5"), rgb:(190,211,229), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "palecerulean" => crate::Color{ name:std::borrow::Cow::Borrowed("Pale cerulean"), hex:std::borrow::Cow::Borrowed("#9BC4E2"), rgb:(155,196,226), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "paledogwood" => crate::Color{ name:std::borrow::Cow::Borrowed("Pale Dogwood"), hex:std::borrow::Cow::Borrowed("#ED7A9B"), rgb:(237,122,155), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "palepink" => crate::Color{ name:std::borrow::Cow::Borrowed("Pale pink"), hex:std::borrow::Cow::Borrowed("#FADADD"), rgb:(250,218,221), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "palepurplepantone" => crate::Color{ name:std::borrow::Cow::Borrowed("Pale purple (Pantone)"), hex:std::borrow::Cow::Borrowed("#FAE6FA"), rgb:(250,230,250), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Pantone") } },
+    "palespringbud" => crate::Color{ name:std::borrow::Cow::Borrowed("Pale spring bud"), hex:std::borrow::Cow::Borrowed("#ECEBBD"), rgb:(236,235,189), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pansypurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Pansy purple"), hex:std::borrow::Cow::Borrowed("#78184A"), rgb:(120,24,74), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "paoloveronesegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Paolo Veronese green"), hex:std::borrow::Cow::Borrowed("#009B7D"), rgb:(0,155,125), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "papayawhip" => crate::Color{ name:std::borrow::Cow::Borrowed("Papaya whip"), hex:std::borrow::Cow::Borrowed("#FFEFD5"), rgb:(255,239,213), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "paradisepink" => crate::Color{ name:std::borrow::Cow::Borrowed("Paradise pink"), hex:std::borrow::Cow::Borrowed("#E63E62"), rgb:(230,62,98), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "parchment" => crate::Color{ name:std::borrow::Cow::Borrowed("Parchment"), hex:std::borrow::Cow::Borrowed("#F1E9D2"), rgb:(241,233,210), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "parisgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Paris Green"), hex:std::borrow::Cow::Borrowed("#50C878"), rgb:(80,200,120), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pastelpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Pastel pink"), hex:std::borrow::Cow::Borrowed("#DEA5A4"), rgb:(222,165,164), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "patriarch" => crate::Color{ name:std::borrow::Cow::Borrowed("Patriarch"), hex:std::borrow::Cow::Borrowed("#800080"), rgb:(128,0,128), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "paua" => crate::Color{ name:std::borrow::Cow::Borrowed("Paua"), hex:std::borrow::Cow::Borrowed("#1F005E"), rgb:(31,0,94), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "paynesgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("Payne's grey"), hex:std::borrow::Cow::Borrowed("#536878"), rgb:(83,104,120), aliases:&["paynesgray"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "peach" => crate::Color{ name:std::borrow::Cow::Borrowed("Peach"), hex:std::borrow::Cow::Borrowed("#FFE5B4"), rgb:(255,229,180), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "peachcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Peach (Crayola)"), hex:std::borrow::Cow::Borrowed("#FFCBA4"), rgb:(255,203,164), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "peachpuff" => crate::Color{ name:std::borrow::Cow::Borrowed("Peach puff"), hex:std::borrow::Cow::Borrowed("#FFDAB9"), rgb:(255,218,185), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pear" => crate::Color{ name:std::borrow::Cow::Borrowed("Pear"), hex:std::borrow::Cow::Borrowed("#D1E231"), rgb:(209,226,49), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pearlypurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Pearly purple"), hex:std::borrow::Cow::Borrowed("#B768A2"), rgb:(183,104,162), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "periwinkle" => crate::Color{ name:std::borrow::Cow::Borrowed("Periwinkle"), hex:std::borrow::Cow::Borrowed("#CCCCFF"), rgb:(204,204,255), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "periwinklecrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Periwinkle (Crayola)"), hex:std::borrow::Cow::Borrowed("#C3CDE6"), rgb:(195,205,230), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "permanentgeraniumlake" => crate::Color{ name:std::borrow::Cow::Borrowed("Permanent Geranium Lake"), hex:std::borrow::Cow::Borrowed("#E12C2C"), rgb:(225,44,44), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "persianblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Persian blue"), hex:std::borrow::Cow::Borrowed("#1C39BB"), rgb:(28,57,187), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "persiangreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Persian green"), hex:std::borrow::Cow::Borrowed("#00A693"), rgb:(0,166,147), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "persianindigo" => crate::Color{ name:std::borrow::Cow::Borrowed("Persian indigo"), hex:std::borrow::Cow::Borrowed("#32127A"), rgb:(50,18,122), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "persianorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Persian orange"), hex:std::borrow::Cow::Borrowed("#D99058"), rgb:(217,144,88), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "persianpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Persian pink"), hex:std::borrow::Cow::Borrowed("#F77FBE"), rgb:(247,127,190), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "persianplum" => crate::Color{ name:std::borrow::Cow::Borrowed("Persian plum"), hex:std::borrow::Cow::Borrowed("#701C1C"), rgb:(112,28,28), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "persianred" => crate::Color{ name:std::borrow::Cow::Borrowed("Persian red"), hex:std::borrow::Cow::Borrowed("#CC3333"), rgb:(204,51,51), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "persianrose" => crate::Color{ name:std::borrow::Cow::Borrowed("Persian rose"), hex:std::borrow::Cow::Borrowed("#FE28A2"), rgb:(254,40,162), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "persimmon" => crate::Color{ name:std::borrow::Cow::Borrowed("Persimmon"), hex:std::borrow::Cow::Borrowed("#EC5800"), rgb:(236,88,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "petunia" => crate::Color{ name:std::borrow::Cow::Borrowed("Petunia"), hex:std::borrow::Cow::Borrowed("#470659"), rgb:(71,6,89), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pewterblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Pewter Blue"), hex:std::borrow::Cow::Borrowed("#8BA8B7"), rgb:(139,168,183), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "phlox" => crate::Color{ name:std::borrow::Cow::Borrowed("Phlox"), hex:std::borrow::Cow::Borrowed("#DF00FF"), rgb:(223,0,255), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "phthaloblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Phthalo blue"), hex:std::borrow::Cow::Borrowed("#000F89"), rgb:(0,15,137), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "phthalogreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Phthalo green"), hex:std::borrow::Cow::Borrowed("#123524"), rgb:(18,53,36), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "picoteeblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Picotee blue"), hex:std::borrow::Cow::Borrowed("#2E2787"), rgb:(46,39,135), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pictorialcarmine" => crate::Color{ name:std::borrow::Cow::Borrowed("Pictorial carmine"), hex:std::borrow::Cow::Borrowed("#C30B4E"), rgb:(195,11,78), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "piggypink" => crate::Color{ name:std::borrow::Cow::Borrowed("Piggy pink"), hex:std::borrow::Cow::Borrowed("#FDDDE6"), rgb:(253,221,230), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pinegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Pine green"), hex:std::borrow::Cow::Borrowed("#01796F"), rgb:(1,121,111), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pinegreen2" => crate::Color{ name:std::borrow::Cow::Borrowed("Pine green"), hex:std::borrow::Cow::Borrowed("#2A2F23"), rgb:(42,47,35), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pink" => crate::Color{ name:std::borrow::Cow::Borrowed("Pink"), hex:std::borrow::Cow::Borrowed("#FFC0CB"), rgb:(255,192,203), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pinkpantone" => crate::Color{ name:std::borrow::Cow::Borrowed("Pink (Pantone)"), hex:std::borrow::Cow::Borrowed("#D74894"), rgb:(215,72,148), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Pantone") } },
+    "pinklace" => crate::Color{ name:std::borrow::Cow::Borrowed("Pink lace"), hex:std::borrow::Cow::Borrowed("#FFDDF4"), rgb:(255,221,244), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pinklavender" => crate::Color{ name:std::borrow::Cow::Borrowed("Pink lavender"), hex:std::borrow::Cow::Borrowed("#D8B2D1"), rgb:(216,178,209), aliases:&["pinklavendar"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pinksherbet" => crate::Color{ name:std::borrow::Cow::Borrowed("Pink Sherbet"), hex:std::borrow::Cow::Borrowed("#F78FA7"), rgb:(247,143,167), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pistachio" => crate::Color{ name:std::borrow::Cow::Borrowed("Pistachio"), hex:std::borrow::Cow::Borrowed("#93C572"), rgb:(147,197,114), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "platinum" => crate::Color{ name:std::borrow::Cow::Borrowed("Platinum"), hex:std::borrow::Cow::Borrowed("#E5E4E2"), rgb:(229,228,226), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "plum" => crate::Color{ name:std::borrow::Cow::Borrowed("Plum"), hex:std::borrow::Cow::Borrowed("#8E4585"), rgb:(142,69,133), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "plumweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Plum (web)"), hex:std::borrow::Cow::Borrowed("#DDA0DD"), rgb:(221,160,221), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("web") } },
+    "plumppurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Plump Purple"), hex:std::borrow::Cow::Borrowed("#5946B2"), rgb:(89,70,178), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "polishedpine" => crate::Color{ name:std::borrow::Cow::Borrowed("Polished Pine"), hex:std::borrow::Cow::Borrowed("#5DA493"), rgb:(93,164,147), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pompandpower" => crate::Color{ name:std::borrow::Cow::Borrowed("Pomp and Power"), hex:std::borrow::Cow::Borrowed("#86608E"), rgb:(134,96,142), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "popstar" => crate::Color{ name:std::borrow::Cow::Borrowed("Popstar"), hex:std::borrow::Cow::Borrowed("#BE4F62"), rgb:(190,79,98), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "portlandorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Portland Orange"), hex:std::borrow::Cow::Borrowed("#FF5A36"), rgb:(255,90,54), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "powderblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Powder blue"), hex:std::borrow::Cow::Borrowed("#B0E0E6"), rgb:(176,224,230), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "prairiegold" => crate::Color{ name:std::borrow::Cow::Borrowed("Prairie gold"), hex:std::borrow::Cow::Borrowed("#E1CA7A"), rgb:(225,202,122), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "princetonorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Princeton orange"), hex:std::borrow::Cow::Borrowed("#F58025"), rgb:(245,128,37), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "prune" => crate::Color{ name:std::borrow::Cow::Borrowed("Prune"), hex:std::borrow::Cow::Borrowed("#701C1C"), rgb:(112,28,28), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "prussianblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Prussian blue"), hex:std::borrow::Cow::Borrowed("#003153"), rgb:(0,49,83), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "psychedelicpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Psychedelic purple"), hex:std::borrow::Cow::Borrowed("#DF00FF"), rgb:(223,0,255), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "puce" => crate::Color{ name:std::borrow::Cow::Borrowed("Puce"), hex:std::borrow::Cow::Borrowed("#CC8899"), rgb:(204,136,153), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pullmanbrownupsbrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Pullman Brown (UPS Brown)"), hex:std::borrow::Cow::Borrowed("#644117"), rgb:(100,65,23), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("UPS Brown") } },
+    "pumpkin" => crate::Color{ name:std::borrow::Cow::Borrowed("Pumpkin"), hex:std::borrow::Cow::Borrowed("#FF7518"), rgb:(255,117,24), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "purple" => crate::Color{ name:std::borrow::Cow::Borrowed("Purple"), hex:std::borrow::Cow::Borrowed("#6A0DAD"), rgb:(106,13,173), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "purpleweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Purple (web)"), hex:std::borrow::Cow::Borrowed("#800080"), rgb:(128,0,128), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("web") } },
+    "purplemunsell" => crate::Color{ name:std::borrow::Cow::Borrowed("Purple (Munsell)"), hex:std::borrow::Cow::Borrowed("#9F00C5"), rgb:(159,0,197), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Munsell") } },
+    "purplex11" => crate::Color{ name:std::borrow::Cow::Borrowed("Purple (X11)"), hex:std::borrow::Cow::Borrowed("#A020F0"), rgb:(160,32,240), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("X11") } },
+    "purplemountainmajesty" => crate::Color{ name:std::borrow::Cow::Borrowed("Purple mountain majesty"), hex:std::borrow::Cow::Borrowed("#9678B6"), rgb:(150,120,182), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "purplenavy" => crate::Color{ name:std::borrow::Cow::Borrowed("Purple navy"), hex:std::borrow::Cow::Borrowed("#4E5180"), rgb:(78,81,128), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "purplepizzazzbrokenanchor" => crate::Color{ name:std::borrow::Cow::Borrowed("Purple pizzazz[broken anchor]"), hex:std::borrow::Cow::Borrowed("#FE4EDA"), rgb:(254,78,218), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "purpleplum" => crate::Color{ name:std::borrow::Cow::Borrowed("Purple Plum"), hex:std::borrow::Cow::Borrowed("#9C51B6"), rgb:(156,81,182), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "queenblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Queen blue"), hex:std::borrow::Cow::Borrowed("#436B95"), rgb:(67,107,149), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "queenpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Queen pink"), hex:std::borrow::Cow::Borrowed("#E8CCD7"), rgb:(232,204,215), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "quicksilver" => crate::Color{ name:std::borrow::Cow::Borrowed("Quick Silver"), hex:std::borrow::Cow::Borrowed("#A6A6A6"), rgb:(166,166,166), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "quinacridonemagenta" => crate::Color{ name:std::borrow::Cow::Borrowed("Quinacridone magenta"), hex:std::borrow::Cow::Borrowed("#8E3A59"), rgb:(142,58,89), aliases:&["quinacridonemegenta"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "radicalred" => crate::Color{ name:std::borrow::Cow::Borrowed("Radical Red"), hex:std::borrow::Cow::Borrowed("#FF355E"), rgb:(255,53,94), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "raisinblack" => crate::Color{ name:std::borrow::Cow::Borrowed("Raisin black"), hex:std::borrow::Cow::Borrowed("#242124"), rgb:(36,33,36), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rajah" => crate::Color{ name:std::borrow::Cow::Borrowed("Rajah"), hex:std::borrow::Cow::Borrowed("#FBAB60"), rgb:(251,171,96), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "raspberry" => crate::Color{ name:std::borrow::Cow::Borrowed("Raspberry"), hex:std::borrow::Cow::Borrowed("#E30B5D"), rgb:(227,11,93), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "raspberryglace" => crate::Color{ name:std::borrow::Cow::Borrowed("Raspberry glacé"), hex:std::borrow::Cow::Borrowed("#915F6D"), rgb:(145,95,109), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "raspberryrose" => crate::Color{ name:std::borrow::Cow::Borrowed("Raspberry rose"), hex:std::borrow::Cow::Borrowed("#B3446C"), rgb:(179,68,108), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rawsienna" => crate::Color{ name:std::borrow::Cow::Borrowed("Raw sienna"), hex:std::borrow::Cow::Borrowed("#D68A59"), rgb:(214,138,89), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rawumber" => crate::Color{ name:std::borrow::Cow::Borrowed("Raw umber"), hex:std::borrow::Cow::Borrowed("#826644"), rgb:(130,102,68), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "razzledazzlerose" => crate::Color{ name:std::borrow::Cow::Borrowed("Razzle dazzle rose"), hex:std::borrow::Cow::Borrowed("#FF33CC"), rgb:(255,51,204), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "razzmatazz" => crate::Color{ name:std::borrow::Cow::Borrowed("Razzmatazz"), hex:std::borrow::Cow::Borrowed("#E3256B"), rgb:(227,37,107), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "razzmicberry" => crate::Color{ name:std::borrow::Cow::Borrowed("Razzmic Berry"), hex:std::borrow::Cow::Borrowed("#8D4E85"), rgb:(141,78,133), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rebeccapurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Rebecca Purple"), hex:std::borrow::Cow::Borrowed("#663399"), rgb:(102,51,153), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "red" => crate::Color{ name:std::borrow::Cow::Borrowed("Red"), hex:std::borrow::Cow::Borrowed("#FF0000"), rgb:(255,0,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "redcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Red (Crayola)"), hex:std::borrow::Cow::Borrowed("#EE204D"), rgb:(238,32,77), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "redmunsell" => crate::Color{ name:std::borrow::Cow::Borrowed("Red (Munsell)"), hex:std::borrow::Cow::Borrowed("#F2003C"), rgb:(242,0,60), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Munsell") } },
+    "redncs" => crate::Color{ name:std::borrow::Cow::Borrowed("Red (NCS)"), hex:std::borrow::Cow::Borrowed("#C40233"), rgb:(196,2,51), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("NCS") } },
+    "redpantone" => crate::Color{ name:std::borrow::Cow::Borrowed("Red (Pantone)"), hex:std::borrow::Cow::Borrowed("#ED2939"), rgb:(237,41,57), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Pantone") } },
+    "redpigment" => crate::Color{ name:std::borrow::Cow::Borrowed("Red (pigment)"), hex:std::borrow::Cow::Borrowed("#ED1C24"), rgb:(237,28,36), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("pigment") } },
+    "redryb" => crate::Color{ name:std::borrow::Cow::Borrowed("Red (RYB)"), hex:std::borrow::Cow::Borrowed("#FE2712"), rgb:(254,39,18), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("RYB") } },
+    "redorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Red-orange"), hex:std::borrow::Cow::Borrowed("#FF5349"), rgb:(255,83,73), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "redochre2" => crate::Color{ name:std::borrow::Cow::Borrowed("Red ochre[2]"), hex:std::borrow::Cow::Borrowed("#913831"), rgb:(145,56,49), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "redorangecrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Red-orange (Crayola)"), hex:std::borrow::Cow::Borrowed("#FF681F"), rgb:(255,104,31), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "redorangecolorwheel" => crate::Color{ name:std::borrow::Cow::Borrowed("Red-orange (Color wheel)"), hex:std::borrow::Cow::Borrowed("#FF4500"), rgb:(255,69,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Color wheel") } },
+    "redpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Red-purple"), hex:std::borrow::Cow::Borrowed("#E40078"), rgb:(228,0,120), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "redsalsa" => crate::Color{ name:std::borrow::Cow::Borrowed("Red Salsa"), hex:std::borrow::Cow::Borrowed("#FD3A4A"), rgb:(253,58,74), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "redviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("Red-violet"), hex:std::borrow::Cow::Borrowed("#C71585"), rgb:(199,21,133), aliases:&["redvoilet"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "redvioletcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Red-violet (Crayola)"), hex:std::borrow::Cow::Borrowed("#C0448F"), rgb:(192,68,143), aliases:&["redvoiletcrayola"], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "redvioletcolorwheel" => crate::Color{ name:std::borrow::Cow::Borrowed("Red-violet (Color wheel)"), hex:std::borrow::Cow::Borrowed("#922B3E"), rgb:(146,43,62), aliases:&["redvoiletcolorwheel"], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Color wheel") } },
+    "redwood" => crate::Color{ name:std::borrow::Cow::Borrowed("Redwood"), hex:std::borrow::Cow::Borrowed("#A45A52"), rgb:(164,90,82), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "resolutionblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Resolution blue"), hex:std::borrow::Cow::Borrowed("#002387"), rgb:(0,35,135), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rhythm" => crate::Color{ name:std::borrow::Cow::Borrowed("Rhythm"), hex:std::borrow::Cow::Borrowed("#777696"), rgb:(119,118,150), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "richblack" => crate::Color{ name:std::borrow::Cow::Borrowed("Rich black"), hex:std::borrow::Cow::Borrowed("#004040"), rgb:(0,64,64), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "richblackfogra29" => crate::Color{ name:std::borrow::Cow::Borrowed("Rich black (FOGRA29)"), hex:std::borrow::Cow::Borrowed("#010B13"), rgb:(1,11,19), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("FOGRA29") } },
+    "richblackfogra39" => crate::Color{ name:std::borrow::Cow::Borrowed("Rich black (FOGRA39)"), hex:std::borrow::Cow::Borrowed("#010203"), rgb:(1,2,3), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("FOGRA39") } },
+    "riflegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Rifle green"), hex:std::borrow::Cow::Borrowed("#444C38"), rgb:(68,76,56), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "robineggblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Robin egg blue"), hex:std::borrow::Cow::Borrowed("#00CCCC"), rgb:(0,204,204), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rocketmetallic" => crate::Color{ name:std::borrow::Cow::Borrowed("Rocket metallic"), hex:std::borrow::Cow::Borrowed("#8A7F80"), rgb:(138,127,128), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rojospanishred" => crate::Color{ name:std::borrow::Cow::Borrowed("Rojo Spanish red"), hex:std::borrow::Cow::Borrowed("#A91101"), rgb:(169,17,1), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "romansilver" => crate::Color{ name:std::borrow::Cow::Borrowed("Roman silver"), hex:std::borrow::Cow::Borrowed("#838996"), rgb:(131,137,150), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rose" => crate::Color{ name:std::borrow::Cow::Borrowed("Rose"), hex:std::borrow::Cow::Borrowed("#FF007F"), rgb:(255,0,127), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rosebonbon" => crate::Color{ name:std::borrow::Cow::Borrowed("Rose bonbon"), hex:std::borrow::Cow::Borrowed("#F9429E"), rgb:(249,66,158), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rosedust" => crate::Color{ name:std::borrow::Cow::Borrowed("Rose Dust"), hex:std::borrow::Cow::Borrowed("#9E5E6F"), rgb:(158,94,111), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "roseebony" => crate::Color{ name:std::borrow::Cow::Borrowed("Rose ebony"), hex:std::borrow::Cow::Borrowed("#674846"), rgb:(103,72,70), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rosemadder" => crate::Color{ name:std::borrow::Cow::Borrowed("Rose madder"), hex:std::borrow::Cow::Borrowed("#E32636"), rgb:(227,38,54), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rosepink" => crate::Color{ name:std::borrow::Cow::Borrowed("Rose pink"), hex:std::borrow::Cow::Borrowed("#FF66CC"), rgb:(255,102,204), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rosepompadour" => crate::Color{ name:std::borrow::Cow::Borrowed("Rose Pompadour"), hex:std::borrow::Cow::Borrowed("#ED7A9B"), rgb:(237,122,155), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rosered" => crate::Color{ name:std::borrow::Cow::Borrowed("Rose red"), hex:std::borrow::Cow::Borrowed("#C21E56"), rgb:(194,30,86), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rosetaupe" => crate::Color{ name:std::borrow::Cow::Borrowed("Rose taupe"), hex:std::borrow::Cow::Borrowed("#905D5D"), rgb:(144,93,93), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rosevale" => crate::Color{ name:std::borrow::Cow::Borrowed("Rose vale"), hex:std::borrow::Cow::Borrowed("#AB4E52"), rgb:(171,78,82), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rosewood" => crate::Color{ name:std::borrow::Cow::Borrowed("Rosewood"), hex:std::borrow::Cow::Borrowed("#65000B"), rgb:(101,0,11), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rossocorsa" => crate::Color{ name:std::borrow::Cow::Borrowed("Rosso corsa"), hex:std::borrow::Cow::Borrowed("#D40000"), rgb:(212,0,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rosybrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Rosy brown"), hex:std::borrow::Cow::Borrowed("#BC8F8F"), rgb:(188,143,143), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "royalbluedark" => crate::Color{ name:std::borrow::Cow::Borrowed("Royal blue (dark)"), hex:std::borrow::Cow::Borrowed("#002366"), rgb:(0,35,102), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("dark") } },
+    "royalbluelight" => crate::Color{ name:std::borrow::Cow::Borrowed("Royal blue (light)"), hex:std::borrow::Cow::Borrowed("#4169E1"), rgb:(65,105,225), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("light") } },
+    "royalpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Royal purple"), hex:std::borrow::Cow::Borrowed("#7851A9"), rgb:(120,81,169), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "royalyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Royal yellow"), hex:std::borrow::Cow::Borrowed("#FADA5E"), rgb:(250,218,94), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "ruber" => crate::Color{ name:std::borrow::Cow::Borrowed("Ruber"), hex:std::borrow::Cow::Borrowed("#CE4676"), rgb:(206,70,118), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rubinered" => crate::Color{ name:std::borrow::Cow::Borrowed("Rubine red"), hex:std::borrow::Cow::Borrowed("#D10056"), rgb:(209,0,86), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "ruby" => crate::Color{ name:std::borrow::Cow::Borrowed("Ruby"), hex:std::borrow::Cow::Borrowed("#E0115F"), rgb:(224,17,95), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rubyred" => crate::Color{ name:std::borrow::Cow::Borrowed("Ruby red"), hex:std::borrow::Cow::Borrowed("#9B111E"), rgb:(155,17,30), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rufous" => crate::Color{ name:std::borrow::Cow::Borrowed("Rufous"), hex:std::borrow::Cow::Borrowed("#A81C07"), rgb:(168,28,7), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "russet" => crate::Color{ name:std::borrow::Cow::Borrowed("Russet"), hex:std::borrow::Cow::Borrowed("#80461B"), rgb:(128,70,27), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "russiangreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Russian green"), hex:std::borrow::Cow::Borrowed("#679267"), rgb:(103,146,103), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "russianviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("Russian violet"), hex:std::borrow::Cow::Borrowed("#32174D"), rgb:(50,23,77), aliases:&["russianvoilet"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rust" => crate::Color{ name:std::borrow::Cow::Borrowed("Rust"), hex:std::borrow::Cow::Borrowed("#B7410E"), rgb:(183,65,14), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rustyred" => crate::Color{ name:std::borrow::Cow::Borrowed("Rusty red"), hex:std::borrow::Cow::Borrowed("#DA2C43"), rgb:(218,44,67), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sacramentostategreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Sacramento State green"), hex:std::borrow::Cow::Borrowed("#043927"), rgb:(4,57,39), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "saddlebrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Saddle brown"), hex:std::borrow::Cow::Borrowed("#8B4513"), rgb:(139,69,19), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "safetyorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Safety orange"), hex:std::borrow::Cow::Borrowed("#FF7800"), rgb:(255,120,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "safetyorangeblazeorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Safety orange (blaze orange)"), hex:std::borrow::Cow::Borrowed("#FF6700"), rgb:(255,103,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("blaze orange") } },
+    "safetyyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Safety yellow"), hex:std::borrow::Cow::Borrowed("#EED202"), rgb:(238,210,2), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "saffron" => crate::Color{ name:std::borrow::Cow::Borrowed("Saffron"), hex:std::borrow::Cow::Borrowed("#F4C430"), rgb:(244,196,48), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sage" => crate::Color{ name:std::borrow::Cow::Borrowed("Sage"), hex:std::borrow::Cow::Borrowed("#BCB88A"), rgb:(188,184,138), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "stpatricksblue" => crate::Color{ name:std::borrow::Cow::Borrowed("St. Patrick's blue"), hex:std::borrow::Cow::Borrowed("#23297A"), rgb:(35,41,122), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "salmon" => crate::Color{ name:std::borrow::Cow::Borrowed("Salmon"), hex:std::borrow::Cow::Borrowed("#FA8072"), rgb:(250,128,114), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "salmonpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Salmon pink"), hex:std::borrow::Cow::Borrowed("#FF91A4"), rgb:(255,145,164), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sand" => crate::Color{ name:std::borrow::Cow::Borrowed("Sand"), hex:std::borrow::Cow::Borrowed("#C2B280"), rgb:(194,178,128), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sanddune" => crate::Color{ name:std::borrow::Cow::Borrowed("Sand dune"), hex:std::borrow::Cow::Borrowed("#967117"), rgb:(150,113,23), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sandybrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Sandy brown"), hex:std::borrow::Cow::Borrowed("#F4A460"), rgb:(244,164,96), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sapgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Sap green"), hex:std::borrow::Cow::Borrowed("#507D2A"), rgb:(80,125,42), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sapphire" => crate::Color{ name:std::borrow::Cow::Borrowed("Sapphire"), hex:std::borrow::Cow::Borrowed("#0F52BA"), rgb:(15,82,186), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sapphireblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Sapphire blue"), hex:std::borrow::Cow::Borrowed("#0067A5"), rgb:(0,103,165), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sapphirecrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Sapphire (Crayola)"), hex:std::borrow::Cow::Borrowed("#2D5DA1"), rgb:(45,93,161), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "satinsheengold" => crate::Color{ name:std::borrow::Cow::Borrowed("Satin sheen gold"), hex:std::borrow::Cow::Borrowed("#CBA135"), rgb:(203,161,53), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "scarlet" => crate::Color{ name:std::borrow::Cow::Borrowed("Scarlet"), hex:std::borrow::Cow::Borrowed("#FF2400"), rgb:(255,36,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "schausspink" => crate::Color{ name:std::borrow::Cow::Borrowed("Schauss pink"), hex:std::borrow::Cow::Borrowed("#FF91AF"), rgb:(255,145,175), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "schoolbusyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("School bus yellow"), hex:std::borrow::Cow::Borrowed("#FFD800"), rgb:(255,216,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "screamingreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Screamin' Green"), hex:std::borrow::Cow::Borrowed("#66FF66"), rgb:(102,255,102), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "seagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Sea green"), hex:std::borrow::Cow::Borrowed("#2E8B57"), rgb:(46,139,87), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "seagreencrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Sea green (Crayola)"), hex:std::borrow::Cow::Borrowed("#00FFCD"), rgb:(0,255,205), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "seance" => crate::Color{ name:std::borrow::Cow::Borrowed("Seance"), hex:std::borrow::Cow::Borrowed("#612086"), rgb:(97,32,134), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sealbrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Seal brown"), hex:std::borrow::Cow::Borrowed("#59260B"), rgb:(89,38,11), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "seashell" => crate::Color{ name:std::borrow::Cow::Borrowed("Seashell"), hex:std::borrow::Cow::Borrowed("#FFF5EE"), rgb:(255,245,238), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "secret" => crate::Color{ name:std::borrow::Cow::Borrowed("Secret"), hex:std::borrow::Cow::Borrowed("#764374"), rgb:(118,67,116), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "selectiveyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Selective yellow"), hex:std::borrow::Cow::Borrowed("#FFBA00"), rgb:(255,186,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sepia" => crate::Color{ name:std::borrow::Cow::Borrowed("Sepia"), hex:std::borrow::Cow::Borrowed("#704214"), rgb:(112,66,20), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "shadow" => crate::Color{ name:std::borrow::Cow::Borrowed("Shadow"), hex:std::borrow::Cow::Borrowed("#8A795D"), rgb:(138,121,93), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "shadowblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Shadow blue"), hex:std::borrow::Cow::Borrowed("#778BA5"), rgb:(119,139,165), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "shamrockgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Shamrock green"), hex:std::borrow::Cow::Borrowed("#009E60"), rgb:(0,158,96), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sheengreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Sheen green"), hex:std::borrow::Cow::Borrowed("#8FD400"), rgb:(143,212,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "shimmeringblush" => crate::Color{ name:std::borrow::Cow::Borrowed("Shimmering Blush"), hex:std::borrow::Cow::Borrowed("#D98695"), rgb:(217,134,149), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "shinyshamrock" => crate::Color{ name:std::borrow::Cow::Borrowed("Shiny Shamrock"), hex:std::borrow::Cow::Borrowed("#5FA778"), rgb:(95,167,120), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "shockingpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Shocking pink"), hex:std::borrow::Cow::Borrowed("#FC0FC0"), rgb:(252,15,192), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "shockingpinkcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Shocking pink (Crayola)"), hex:std::borrow::Cow::Borrowed("#FF6FFF"), rgb:(255,111,255), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "sienna" => crate::Color{ name:std::borrow::Cow::Borrowed("Sienna"), hex:std::borrow::Cow::Borrowed("#882D17"), rgb:(136,45,23), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "silver" => crate::Color{ name:std::borrow::Cow::Borrowed("Silver"), hex:std::borrow::Cow::Borrowed("#C0C0C0"), rgb:(192,192,192), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "silvercrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Silver (Crayola)"), hex:std::borrow::Cow::Borrowed("#C9C0BB"), rgb:(201,192,187), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "silvermetallic" => crate::Color{ name:std::borrow::Cow::Borrowed("Silver (Metallic)"), hex:std::borrow::Cow::Borrowed("#AAA9AD"), rgb:(170,169,173), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Metallic") } },
+    "silverchalice" => crate::Color{ name:std::borrow::Cow::Borrowed("Silver chalice"), hex:std::borrow::Cow::Borrowed("#ACACAC"), rgb:(172,172,172), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "silverpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Silver pink"), hex:std::borrow::Cow::Borrowed("#C4AEAD"), rgb:(196,174,173), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "silversand" => crate::Color{ name:std::borrow::Cow::Borrowed("Silver sand"), hex:std::borrow::Cow::Borrowed("#BFC1C2"), rgb:(191,193,194), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sinopia" => crate::Color{ name:std::borrow::Cow::Borrowed("Sinopia"), hex:std::borrow::Cow::Borrowed("#CB410B"), rgb:(203,65,11), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sizzlingred" => crate::Color{ name:std::borrow::Cow::Borrowed("Sizzling Red"), hex:std::borrow::Cow::Borrowed("#FF3855"), rgb:(255,56,85), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sizzlingsunrise" => crate::Color{ name:std::borrow::Cow::Borrowed("Sizzling Sunrise"), hex:std::borrow::Cow::Borrowed("#FFDB00"), rgb:(255,219,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "skobeloff" => crate::Color{ name:std::borrow::Cow::Borrowed("Skobeloff"), hex:std::borrow::Cow::Borrowed("#007474"), rgb:(0,116,116), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "skincolor" => crate::Color{ name:std::borrow::Cow::Borrowed("Skin color"), hex:std::borrow::Cow::Borrowed("#FFDEAD"), rgb:(255,222,173), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "skyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Sky blue"), hex:std::borrow::Cow::Borrowed("#87CEEB"), rgb:(135,206,235), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "skybluecrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Sky blue (Crayola)"), hex:std::borrow::Cow::Borrowed("#76D7EA"), rgb:(118,215,234), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "skymagenta" => crate::Color{ name:std::borrow::Cow::Borrowed("Sky magenta"), hex:std::borrow::Cow::Borrowed("#CF71AF"), rgb:(207,113,175), aliases:&["skymegenta"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "slateblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Slate blue"), hex:std::borrow::Cow::Borrowed("#6A5ACD"), rgb:(106,90,205), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "slategray" => crate::Color{ name:std::borrow::Cow::Borrowed("Slate gray"), hex:std::borrow::Cow::Borrowed("#708090"), rgb:(112,128,144), aliases:&["slategrey"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "slimygreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Slimy green"), hex:std::borrow::Cow::Borrowed("#299617"), rgb:(41,150,23), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "smitten" => crate::Color{ name:std::borrow::Cow::Borrowed("Smitten"), hex:std::borrow::Cow::Borrowed("#C84186"), rgb:(200,65,134), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "smokyblack" => crate::Color{ name:std::borrow::Cow::Borrowed("Smoky black"), hex:std::borrow::Cow::Borrowed("#100C08"), rgb:(16,12,8), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "snow" => crate::Color{ name:std::borrow::Cow::Borrowed("Snow"), hex:std::borrow::Cow::Borrowed("#FFFAFA"), rgb:(255,250,250), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "solidpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Solid pink"), hex:std::borrow::Cow::Borrowed("#893843"), rgb:(137,56,67), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sonicsilver" => crate::Color{ name:std::borrow::Cow::Borrowed("Sonic silver"), hex:std::borrow::Cow::Borrowed("#757575"), rgb:(117,117,117), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spacecadet" => crate::Color{ name:std::borrow::Cow::Borrowed("Space cadet"), hex:std::borrow::Cow::Borrowed("#1D2951"), rgb:(29,41,81), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishbistre" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish bistre"), hex:std::borrow::Cow::Borrowed("#807532"), rgb:(128,117,50), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish blue"), hex:std::borrow::Cow::Borrowed("#0070B8"), rgb:(0,112,184), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishcarmine" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish carmine"), hex:std::borrow::Cow::Borrowed("#D10047"), rgb:(209,0,71), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishgray" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish gray"), hex:std::borrow::Cow::Borrowed("#989898"), rgb:(152,152,152), aliases:&["spanishgrey"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish green"), hex:std::borrow::Cow::Borrowed("#009150"), rgb:(0,145,80), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish orange"), hex:std::borrow::Cow::Borrowed("#E86100"), rgb:(232,97,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish pink"), hex:std::borrow::Cow::Borrowed("#F7BFBE"), rgb:(247,191,190), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishred" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish red"), hex:std::borrow::Cow::Borrowed("#E60026"), rgb:(230,0,38), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish sky blue"), hex:std::borrow::Cow::Borrowed("#00FFFE"), rgb:(0,255,254), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish violet"), hex:std::borrow::Cow::Borrowed("#4C2882"), rgb:(76,40,130), aliases:&["spanishvoilet"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishviridian" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish viridian"), hex:std::borrow::Cow::Borrowed("#007F5C"), rgb:(0,127,92), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "springbud" => crate::Color{ name:std::borrow::Cow::Borrowed("Spring bud"), hex:std::borrow::Cow::Borrowed("#A7FC00"), rgb:(167,252,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "springfrost" => crate::Color{ name:std::borrow::Cow::Borrowed("Spring Frost"), hex:std::borrow::Cow::Borrowed("#87FF2A"), rgb:(135,255,42), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "springgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Spring green"), hex:std::borrow::Cow::Borrowed("#00FF7F"), rgb:(0,255,127), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "springgreencrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Spring green (Crayola)"), hex:std::borrow::Cow::Borrowed("#ECEBBD"), rgb:(236,235,189), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "starcommandblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Star command blue"), hex:std::borrow::Cow::Borrowed("#007BB8"), rgb:(0,123,184), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "steelblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Steel blue"), hex:std::borrow::Cow::Borrowed("#4682B4"), rgb:(70,130,180), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "steelpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Steel pink"), hex:std::borrow::Cow::Borrowed("#CC33CC"), rgb:(204,51,204), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "stildegrainyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Stil de grain yellow"), hex:std::borrow::Cow::Borrowed("#FADA5E"), rgb:(250,218,94), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "straw" => crate::Color{ name:std::borrow::Cow::Borrowed("Straw"), hex:std::borrow::Cow::Borrowed("#E4D96F"), rgb:(228,217,111), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "strawberry" => crate::Color{ name:std::borrow::Cow::Borrowed("Strawberry"), hex:std::borrow::Cow::Borrowed("#FA5053"), rgb:(250,80,83), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "strawberryblonde" => crate::Color{ name:std::borrow::Cow::Borrowed("Strawberry Blonde"), hex:std::borrow::Cow::Borrowed("#FF9361"), rgb:(255,147,97), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "stronglimegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Strong Lime Green"), hex:std::borrow::Cow::Borrowed("#33CC33"), rgb:(51,204,51), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sugarplum" => crate::Color{ name:std::borrow::Cow::Borrowed("Sugar Plum"), hex:std::borrow::Cow::Borrowed("#914E75"), rgb:(145,78,117), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sunglow" => crate::Color{ name:std::borrow::Cow::Borrowed("Sunglow"), hex:std::borrow::Cow::Borrowed("#FFCC33"), rgb:(255,204,51), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sunray" => crate::Color{ name:std::borrow::Cow::Borrowed("Sunray"), hex:std::borrow::Cow::Borrowed("#E3AB57"), rgb:(227,171,87), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sunset" => crate::Color{ name:std::borrow::Cow::Borrowed("Sunset"), hex:std::borrow::Cow::Borrowed("#FAD6A5"), rgb:(250,214,165), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "superpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Super pink"), hex:std::borrow::Cow::Borrowed("#CF6BA9"), rgb:(207,107,169), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sweetbrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Sweet Brown"), hex:std::borrow::Cow::Borrowed("#A83731"), rgb:(168,55,49), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "syracuseorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Syracuse Orange"), hex:std::borrow::Cow::Borrowed("#D44500"), rgb:(212,69,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tan" => crate::Color{ name:std::borrow::Cow::Borrowed("Tan"), hex:std::borrow::Cow::Borrowed("#D2B48C"), rgb:(210,180,140), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tancrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Tan (Crayola)"), hex:std::borrow::Cow::Borrowed("#D99A6C"), rgb:(217,154,108), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "tangerine" => crate::Color{ name:std::borrow::Cow::Borrowed("Tangerine"), hex:std::borrow::Cow::Borrowed("#F28500"), rgb:(242,133,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tangopink" => crate::Color{ name:std::borrow::Cow::Borrowed("Tango pink"), hex:std::borrow::Cow::Borrowed("#E4717A"), rgb:(228,113,122), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tartorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Tart Orange"), hex:std::borrow::Cow::Borrowed("#FB4D46"), rgb:(251,77,70), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "taupe" => crate::Color{ name:std::borrow::Cow::Borrowed("Taupe"), hex:std::borrow::Cow::Borrowed("#483C32"), rgb:(72,60,50), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "taupegray" => crate::Color{ name:std::borrow::Cow::Borrowed("Taupe gray"), hex:std::borrow::Cow::Borrowed("#8B8589"), rgb:(139,133,137), aliases:&["taupegrey"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "teagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Tea green"), hex:std::borrow::Cow::Borrowed("#D0F0C0"), rgb:(208,240,192), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tearose" => crate::Color{ name:std::borrow::Cow::Borrowed("Tea rose"), hex:std::borrow::Cow::Borrowed("#F4C2C2"), rgb:(244,194,194), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "teal" => crate::Color{ name:std::borrow::Cow::Borrowed("Teal"), hex:std::borrow::Cow::Borrowed("#008080"), rgb:(0,128,128), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tealblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Teal blue"), hex:std::borrow::Cow::Borrowed("#367588"), rgb:(54,117,136), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "technobotanica" => crate::Color{ name:std::borrow::Cow::Borrowed("Technobotanica"), hex:std::borrow::Cow::Borrowed("#00FFBF"), rgb:(0,255,191), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "telemagenta" => crate::Color{ name:std::borrow::Cow::Borrowed("Telemagenta"), hex:std::borrow::Cow::Borrowed("#CF3476"), rgb:(207,52,118), aliases:&["telemegenta"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tennetawny" => crate::Color{ name:std::borrow::Cow::Borrowed("Tenné(tawny)"), hex:std::borrow::Cow::Borrowed("#CD5700"), rgb:(205,87,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("tawny") } },
+    "terracotta" => crate::Color{ name:std::borrow::Cow::Borrowed("Terra cotta"), hex:std::borrow::Cow::Borrowed("#E2725B"), rgb:(226,114,91), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "thistle" => crate::Color{ name:std::borrow::Cow::Borrowed("Thistle"), hex:std::borrow::Cow::Borrowed("#D8BFD8"), rgb:(216,191,216), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "thulianpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Thulian pink"), hex:std::borrow::Cow::Borrowed("#DE6FA1"), rgb:(222,111,161), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "ticklemepink" => crate::Color{ name:std::borrow::Cow::Borrowed("Tickle Me Pink"), hex:std::borrow::Cow::Borrowed("#FC89AC"), rgb:(252,137,172), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tiffanyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Tiffany Blue"), hex:std::borrow::Cow::Borrowed("#0ABAB5"), rgb:(10,186,181), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "timberwolf" => crate::Color{ name:std::borrow::Cow::Borrowed("Timberwolf"), hex:std::borrow::Cow::Borrowed("#DBD7D2"), rgb:(219,215,210), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "titaniumyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Titanium yellow"), hex:std::borrow::Cow::Borrowed("#EEE600"), rgb:(238,230,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tomato" => crate::Color{ name:std::borrow::Cow::Borrowed("Tomato"), hex:std::borrow::Cow::Borrowed("#FF6347"), rgb:(255,99,71), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tourmaline" => crate::Color{ name:std::borrow::Cow::Borrowed("Tourmaline"), hex:std::borrow::Cow::Borrowed("#86A1A9"), rgb:(134,161,169), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tropicalrainforest" => crate::Color{ name:std::borrow::Cow::Borrowed("Tropical rainforest"), hex:std::borrow::Cow::Borrowed("#00755E"), rgb:(0,117,94), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "trueblue" => crate::Color{ name:std::borrow::Cow::Borrowed("True Blue"), hex:std::borrow::Cow::Borrowed("#2D68C4"), rgb:(45,104,196), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "trypanblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Trypan Blue"), hex:std::borrow::Cow::Borrowed("#1C05B3"), rgb:(28,5,179), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tuftsblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Tufts blue"), hex:std::borrow::Cow::Borrowed("#3E8EDE"), rgb:(62,142,222), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tumbleweed" => crate::Color{ name:std::borrow::Cow::Borrowed("Tumbleweed"), hex:std::borrow::Cow::Borrowed("#DEAA88"), rgb:(222,170,136), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "turquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("Turquoise"), hex:std::borrow::Cow::Borrowed("#40E0D0"), rgb:(64,224,208), aliases:&["turqoise", "turquise"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "turquoiseblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Turquoise blue"), hex:std::borrow::Cow::Borrowed("#00FFEF"), rgb:(0,255,239), aliases:&["turqoiseblue", "turquiseblue"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "turquoisegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Turquoise green"), hex:std::borrow::Cow::Borrowed("#A0D6B4"), rgb:(160,214,180), aliases:&["turqoisegreen", "turquisegreen"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "turtlegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Turtle green"), hex:std::borrow::Cow::Borrowed("#8A9A5B"), rgb:(138,154,91), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tuscan" => crate::Color{ name:std::borrow::Cow::Borrowed("Tuscan"), hex:std::borrow::Cow::Borrowed("#FAD6A5"), rgb:(250,214,165), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tuscanbrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Tuscan brown"), hex:std::borrow::Cow::Borrowed("#6F4E37"), rgb:(111,78,55), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tuscanred" => crate::Color{ name:std::borrow::Cow::Borrowed("Tuscan red"), hex:std::borrow::Cow::Borrowed("#7C4848"), rgb:(124,72,72), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tuscantan" => crate::Color{ name:std::borrow::Cow::Borrowed("Tuscan tan"), hex:std::borrow::Cow::Borrowed("#A67B5B"), rgb:(166,123,91), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tuscany" => crate::Color{ name:std::borrow::Cow::Borrowed("Tuscany"), hex:std::borrow::Cow::Borrowed("#C09999"), rgb:(192,153,153), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "twilightlavender" => crate::Color{ name:std::borrow::Cow::Borrowed("Twilight lavender"), hex:std::borrow::Cow::Borrowed("#8A496B"), rgb:(138,73,107), aliases:&["twilightlavendar"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tyrianpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Tyrian purple"), hex:std::borrow::Cow::Borrowed("#66023C"), rgb:(102,2,60), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "uablue" => crate::Color{ name:std::borrow::Cow::Borrowed("UA blue"), hex:std::borrow::Cow::Borrowed("#0033AA"), rgb:(0,51,170), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "uared" => crate::Color{ name:std::borrow::Cow::Borrowed("UA red"), hex:std::borrow::Cow::Borrowed("#D9004C"), rgb:(217,0,76), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "ultramarine" => crate::Color{ name:std::borrow::Cow::Borrowed("Ultramarine"), hex:std::borrow::Cow::Borrowed("#3F00FF"), rgb:(63,0,255), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "ultramarineblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Ultramarine blue"), hex:std::borrow::Cow::Borrowed("#4166F5"), rgb:(65,102,245), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "ultrapink" => crate::Color{ name:std::borrow::Cow::Borrowed("Ultra pink"), hex:std::borrow::Cow::Borrowed("#FF6FFF"), rgb:(255,111,255), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "ultrared" => crate::Color{ name:std::borrow::Cow::Borrowed("Ultra red"), hex:std::borrow::Cow::Borrowed("#FC6C85"), rgb:(252,108,133), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "umber" => crate::Color{ name:std::borrow::Cow::Borrowed("Umber"), hex:std::borrow::Cow::Borrowed("#635147"), rgb:(99,81,71), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "unbleachedsilk" => crate::Color{ name:std::borrow::Cow::Borrowed("Unbleached silk"), hex:std::borrow::Cow::Borrowed("#FFDDCA"), rgb:(255,221,202), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "unitednationsblue" => crate::Color{ name:std::borrow::Cow::Borrowed("United Nations blue"), hex:std::borrow::Cow::Borrowed("#009EDB"), rgb:(0,158,219), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "universityofpennsylvaniared" => crate::Color{ name:std::borrow::Cow::Borrowed("University of Pennsylvania red"), hex:std::borrow::Cow::Borrowed("#A50021"), rgb:(165,0,33), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "unmellowyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Unmellow yellow"), hex:std::borrow::Cow::Borrowed("#FFFF66"), rgb:(255,255,102), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "upforestgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("UP Forest green"), hex:std::borrow::Cow::Borrowed("#014421"), rgb:(1,68,33), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "upmaroon" => crate::Color{ name:std::borrow::Cow::Borrowed("UP maroon"), hex:std::borrow::Cow::Borrowed("#7B1113"), rgb:(123,17,19), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "upsdellred" => crate::Color{ name:std::borrow::Cow::Borrowed("Upsdell red"), hex:std::borrow::Cow::Borrowed("#AE2029"), rgb:(174,32,41), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "uranianblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Uranian blue"), hex:std::borrow::Cow::Borrowed("#AFDBF5"), rgb:(175,219,245), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "usafablue" => crate::Color{ name:std::borrow::Cow::Borrowed("USAFA blue"), hex:std::borrow::Cow::Borrowed("#004F98"), rgb:(0,79,152), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vandykebrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Van Dyke brown"), hex:std::borrow::Cow::Borrowed("#664228"), rgb:(102,66,40), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vanilla" => crate::Color{ name:std::borrow::Cow::Borrowed("Vanilla"), hex:std::borrow::Cow::Borrowed("#F3E5AB"), rgb:(243,229,171), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vanillaice" => crate::Color{ name:std::borrow::Cow::Borrowed("Vanilla ice"), hex:std::borrow::Cow::Borrowed("#F38FA9"), rgb:(243,143,169), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vantgblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Vantg blue"), hex:std::borrow::Cow::Borrowed("#5271FF"), rgb:(82,113,255), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vegasgold" => crate::Color{ name:std::borrow::Cow::Borrowed("Vegas gold"), hex:std::borrow::Cow::Borrowed("#C5B358"), rgb:(197,179,88), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "venetianred" => crate::Color{ name:std::borrow::Cow::Borrowed("Venetian red"), hex:std::borrow::Cow::Borrowed("#C80815"), rgb:(200,8,21), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "verdigris" => crate::Color{ name:std::borrow::Cow::Borrowed("Verdigris"), hex:std::borrow::Cow::Borrowed("#43B3AE"), rgb:(67,179,174), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vermilion" => crate::Color{ name:std::borrow::Cow::Borrowed("Vermilion"), hex:std::borrow::Cow::Borrowed("#E34234"), rgb:(227,66,52), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vermilion2" => crate::Color{ name:std::borrow::Cow::Borrowed("Vermilion"), hex:std::borrow::Cow::Borrowed("#D9381E"), rgb:(217,56,30), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "veronica" => crate::Color{ name:std::borrow::Cow::Borrowed("Veronica"), hex:std::borrow::Cow::Borrowed("#A020F0"), rgb:(160,32,240), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "violet" => crate::Color{ name:std::borrow::Cow::Borrowed("Violet"), hex:std::borrow::Cow::Borrowed("#8F00FF"), rgb:(143,0,255), aliases:&["voilet"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "violetcolorwheel" => crate::Color{ name:std::borrow::Cow::Borrowed("Violet (color wheel)"), hex:std::borrow::Cow::Borrowed("#7F00FF"), rgb:(127,0,255), aliases:&["voiletcolorwheel"], source:crate::Source{ list:"List of colors: N–Z", variant:Some("color wheel") } },
+    "violetcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Violet (crayola)"), hex:std::borrow::Cow::Borrowed("#963D7F"), rgb:(150,61,127), aliases:&["voiletcrayola"], source:crate::Source{ list:"List of colors: N–Z", variant:Some("crayola") } },
+    "violetryb" => crate::Color{ name:std::borrow::Cow::Borrowed("Violet (RYB)"), hex:std::borrow::Cow::Borrowed("#8601AF"), rgb:(134,1,175), aliases:&["voiletryb"], source:crate::Source{ list:"List of colors: N–Z", variant:Some("RYB") } },
+    "violetweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Violet (web)"), hex:std::borrow::Cow::Borrowed("#EE82EE"), rgb:(238,130,238), aliases:&["voiletweb"], source:crate::Source{ list:"List of colors: N–Z", variant:Some("web") } },
+    "violetblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Violet-blue"), hex:std::borrow::Cow::Borrowed("#324AB2"), rgb:(50,74,178), aliases:&["voiletblue"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "violetbluecrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Violet-blue (Crayola)"), hex:std::borrow::Cow::Borrowed("#766EC8"), rgb:(118,110,200), aliases:&["voiletbluecrayola"], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "violetred" => crate::Color{ name:std::borrow::Cow::Borrowed("Violet-red"), hex:std::borrow::Cow::Borrowed("#F75394"), rgb:(247,83,148), aliases:&["voiletred"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "violetredperbang" => crate::Color{ name:std::borrow::Cow::Borrowed("Violet-red(PerBang)"), hex:std::borrow::Cow::Borrowed("#F0599C"), rgb:(240,89,156), aliases:&["voiletredperbang"], source:crate::Source{ list:"List of colors: N–Z", variant:Some("PerBang") } },
+    "viridian" => crate::Color{ name:std::borrow::Cow::Borrowed("Viridian"), hex:std::borrow::Cow::Borrowed("#40826D"), rgb:(64,130,109), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "viridiangreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Viridian green"), hex:std::borrow::Cow::Borrowed("#009698"), rgb:(0,150,152), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vividburgundy" => crate::Color{ name:std::borrow::Cow::Borrowed("Vivid burgundy"), hex:std::borrow::Cow::Borrowed("#9F1D35"), rgb:(159,29,53), aliases:&["vividburgandy"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vividskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Vivid sky blue"), hex:std::borrow::Cow::Borrowed("#00CCFF"), rgb:(0,204,255), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vividtangerine" => crate::Color{ name:std::borrow::Cow::Borrowed("Vivid tangerine"), hex:std::borrow::Cow::Borrowed("#FFA089"), rgb:(255,160,137), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vividviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("Vivid violet"), hex:std::borrow::Cow::Borrowed("#9F00FF"), rgb:(159,0,255), aliases:&["vividvoilet"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "volt" => crate::Color{ name:std::borrow::Cow::Borrowed("Volt"), hex:std::borrow::Cow::Borrowed("#CEFF00"), rgb:(206,255,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "warmblack" => crate::Color{ name:std::borrow::Cow::Borrowed("Warm black"), hex:std::borrow::Cow::Borrowed("#004242"), rgb:(0,66,66), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "weezyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Weezy Blue"), hex:std::borrow::Cow::Borrowed("#189BCC"), rgb:(24,155,204), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "wheat" => crate::Color{ name:std::borrow::Cow::Borrowed("Wheat"), hex:std::borrow::Cow::Borrowed("#F5DEB3"), rgb:(245,222,179), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "white" => crate::Color{ name:std::borrow::Cow::Borrowed("White"), hex:std::borrow::Cow::Borrowed("#FFFFFF"), rgb:(255,255,255), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "wildblueyonder" => crate::Color{ name:std::borrow::Cow::Borrowed("Wild blue yonder"), hex:std::borrow::Cow::Borrowed("#A2ADD0"), rgb:(162,173,208), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "wildorchid" => crate::Color{ name:std::borrow::Cow::Borrowed("Wild orchid"), hex:std::borrow::Cow::Borrowed("#D470A2"), rgb:(212,112,162), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "wildstrawberry" => crate::Color{ name:std::borrow::Cow::Borrowed("Wild Strawberry"), hex:std::borrow::Cow::Borrowed("#FF43A4"), rgb:(255,67,164), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "wildwatermelon" => crate::Color{ name:std::borrow::Cow::Borrowed("Wild watermelon"), hex:std::borrow::Cow::Borrowed("#FC6C85"), rgb:(252,108,133), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "windsortan" => crate::Color{ name:std::borrow::Cow::Borrowed("Windsor tan"), hex:std::borrow::Cow::Borrowed("#A75502"), rgb:(167,85,2), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "wine" => crate::Color{ name:std::borrow::Cow::Borrowed("Wine"), hex:std::borrow::Cow::Borrowed("#722F37"), rgb:(114,47,55), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "winedregs" => crate::Color{ name:std::borrow::Cow::Borrowed("Wine dregs"), hex:std::borrow::Cow::Borrowed("#673147"), rgb:(103,49,71), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "wintersky" => crate::Color{ name:std::borrow::Cow::Borrowed("Winter Sky"), hex:std::borrow::Cow::Borrowed("#FF007C"), rgb:(255,0,124), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "wintergreendream" => crate::Color{ name:std::borrow::Cow::Borrowed("Wintergreen Dream"), hex:std::borrow::Cow::Borrowed("#56887D"), rgb:(86,136,125), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "wisteria" => crate::Color{ name:std::borrow::Cow::Borrowed("Wisteria"), hex:std::borrow::Cow::Borrowed("#C9A0DC"), rgb:(201,160,220), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "woodbrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Wood brown"), hex:std::borrow::Cow::Borrowed("#C19A6B"), rgb:(193,154,107), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "xanadu" => crate::Color{ name:std::borrow::Cow::Borrowed("Xanadu"), hex:std::borrow::Cow::Borrowed("#738678"), rgb:(115,134,120), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "xanthic" => crate::Color{ name:std::borrow::Cow::Borrowed("Xanthic"), hex:std::borrow::Cow::Borrowed("#EEED09"), rgb:(238,237,9), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "xanthous" => crate::Color{ name:std::borrow::Cow::Borrowed("Xanthous"), hex:std::borrow::Cow::Borrowed("#F1B42F"), rgb:(241,180,47), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "yaleblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Yale Blue"), hex:std::borrow::Cow::Borrowed("#00356B"), rgb:(0,53,107), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "yellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow"), hex:std::borrow::Cow::Borrowed("#FFFF00"), rgb:(255,255,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "yellowcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow (Crayola)"), hex:std::borrow::Cow::Borrowed("#FCE883"), rgb:(252,232,131), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "yellowmunsell" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow (Munsell)"), hex:std::borrow::Cow::Borrowed("#EFCC00"), rgb:(239,204,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Munsell") } },
+    "yellowncs" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow (NCS)"), hex:std::borrow::Cow::Borrowed("#FFD300"), rgb:(255,211,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("NCS") } },
+    "yellowpantone" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow (Pantone)"), hex:std::borrow::Cow::Borrowed("#FEDF00"), rgb:(254,223,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Pantone") } },
+    "yellowprocess" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow (process)"), hex:std::borrow::Cow::Borrowed("#FFEF00"), rgb:(255,239,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("process") } },
+    "yellowryb" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow (RYB)"), hex:std::borrow::Cow::Borrowed("#FEFE33"), rgb:(254,254,51), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("RYB") } },
+    "yellowgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow-green"), hex:std::borrow::Cow::Borrowed("#9ACD32"), rgb:(154,205,50), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "yellowgreencrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow-green (Crayola)"), hex:std::borrow::Cow::Borrowed("#C5E384"), rgb:(197,227,132), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "yellowgreencolorwheel" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow-green (Color Wheel)"), hex:std::borrow::Cow::Borrowed("#30B21A"), rgb:(48,178,26), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Color Wheel") } },
+    "yelloworange" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow Orange"), hex:std::borrow::Cow::Borrowed("#FFAE42"), rgb:(255,174,66), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "yelloworangecolorwheel" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow Orange (Color Wheel)"), hex:std::borrow::Cow::Borrowed("#FF9505"), rgb:(255,149,5), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Color Wheel") } },
+    "yellowsunshine" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow Sunshine"), hex:std::borrow::Cow::Borrowed("#FFF700"), rgb:(255,247,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "yinmnblue" => crate::Color{ name:std::borrow::Cow::Borrowed("YInMn Blue"), hex:std::borrow::Cow::Borrowed("#2E5090"), rgb:(46,80,144), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "zaffre" => crate::Color{ name:std::borrow::Cow::Borrowed("Zaffre"), hex:std::borrow::Cow::Borrowed("#0014A8"), rgb:(0,20,168), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "zinnwalditebrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Zinnwaldite brown"), hex:std::borrow::Cow::Borrowed("#2C1608"), rgb:(44,22,8), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "zomp" => crate::Color{ name:std::borrow::Cow::Borrowed("Zomp"), hex:std::borrow::Cow::Borrowed("#39A78E"), rgb:(57,167,142), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
 };
+
+/// Alternate spellings of `COLORS` keys, mapped to the key they stand for.
+pub(crate) static COLORS_ALIASES: phf::Map<&'static str, &'static str> = phf::phf_map! {
+    "africanvoilet" => "africanviolet",
+    "antiquefuschia" => "antiquefuchsia",
+    "antiquefushia" => "antiquefuchsia",
+    "ashgrey" => "ashgray",
+    "battleshipgray" => "battleshipgrey",
+    "bluegreycrayola" => "bluegraycrayola",
+    "bluevoilet" => "blueviolet",
+    "burgandy" => "burgundy",
+    "cadetgray" => "cadetgrey",
+    "chinesevoilet" => "chineseviolet",
+    "coolgray" => "coolgrey",
+    "dkbrown" => "darkbrown",
+    "dkbyzantium" => "darkbyzantium",
+    "dkcyan" => "darkcyan",
+    "dkelectricblue" => "darkelectricblue",
+    "dkgoldenrod" => "darkgoldenrod",
+    "dkgreenx11" => "darkgreenx11",
+    "dkjunglegreen" => "darkjunglegreen",
+    "dkkhaki" => "darkkhaki",
+    "dklava" => "darklava",
+    "dkliverhorses" => "darkliverhorses",
+    "darkmegenta" => "darkmagenta",
+    "dkmagenta" => "darkmagenta",
+    "dkmegenta" => "darkmagenta",
+    "dkolivegreen" => "darkolivegreen",
+    "dkorange" => "darkorange",
+    "dkorchid" => "darkorchid",
+    "dkpurple" => "darkpurple",
+    "dkred" => "darkred",
+    "dksalmon" => "darksalmon",
+    "dkseagreen" => "darkseagreen",
+    "dksienna" => "darksienna",
+    "dkskyblue" => "darkskyblue",
+    "dkslateblue" => "darkslateblue",
+    "darkslategrey" => "darkslategray",
+    "dkslategray" => "darkslategray",
+    "dkslategrey" => "darkslategray",
+    "dkspringgreen" => "darkspringgreen",
+    "darkturqoise" => "darkturquoise",
+    "darkturquise" => "darkturquoise",
+    "dkturquoise" => "darkturquoise",
+    "dkturqoise" => "darkturquoise",
+    "dkturquise" => "darkturquoise",
+    "darkvoilet" => "darkviolet",
+    "dkviolet" => "darkviolet",
+    "dkvoilet" => "darkviolet",
+    "davysgray" => "davysgrey",
+    "dimgrey" => "dimgray",
+    "electricvoilet" => "electricviolet",
+    "englishlavendar" => "englishlavender",
+    "englishvoilet" => "englishviolet",
+    "frenchfuschia" => "frenchfuchsia",
+    "frenchfushia" => "frenchfuchsia",
+    "frenchvoilet" => "frenchviolet",
+    "fuschia" => "fuchsia",
+    "fushia" => "fuchsia",
+    "fuschiacrayola" => "fuchsiacrayola",
+    "fushiacrayola" => "fuchsiacrayola",
+    "granitegrey" => "granitegray",
+    "greyweb" => "grayweb",
+    "greyx11grey" => "grayx11gray",
+    "heliotropegrey" => "heliotropegray",
+    "hotmegentabrokenanchor" => "hotmagentabrokenanchor",
+    "japanesevoilet" => "japaneseviolet",
+    "languidlavendar" => "languidlavender",
+    "lavendarfloral" => "lavenderfloral",
+    "lavendarweb" => "lavenderweb",
+    "lavendarblue" => "lavenderblue",
+    "lavendarblush" => "lavenderblush",
+    "lavendargray" => "lavendergray",
+    "lavendergrey" => "lavendergray",
+    "lavendargrey" => "lavendergray",
+    "ltblue" => "lightblue",
+    "ltcoral" => "lightcoral",
+    "ltcornflowerblue" => "lightcornflowerblue",
+    "ltcyan" => "lightcyan",
+    "ltfrenchbeige" => "lightfrenchbeige",
+    "ltgoldenrodyellow" => "lightgoldenrodyellow",
+    "lightgrey" => "lightgray",
+    "ltgray" => "lightgray",
+    "ltgrey" => "lightgray",
+    "ltgreen" => "lightgreen",
+    "ltorange" => "lightorange",
+    "ltperiwinkle" => "lightperiwinkle",
+    "ltpink" => "lightpink",
+    "ltpurple" => "lightpurple",
+    "ltsalmon" => "lightsalmon",
+    "ltseagreen" => "lightseagreen",
+    "ltskyblue" => "lightskyblue",
+    "lightslategrey" => "lightslategray",
+    "ltslategray" => "lightslategray",
+    "ltslategrey" => "lightslategray",
+    "ltsteelblue" => "lightsteelblue",
+    "ltyellow" => "lightyellow",
+    "megenta" => "magenta",
+    "megentacrayolabrokenanchor" => "magentacrayolabrokenanchor",
+    "megentadye" => "magentadye",
+    "megentapantone" => "magentapantone",
+    "megentaprocess" => "magentaprocess",
+    "megentahaze" => "magentahaze",
+    "medaquamarine" => "mediumaquamarine",
+    "medblue" => "mediumblue",
+    "medcandyapplered" => "mediumcandyapplered",
+    "medcarmine" => "mediumcarmine",
+    "medchampagne" => "mediumchampagne",
+    "medorchid" => "mediumorchid",
+    "medpurple" => "mediumpurple",
+    "medseagreen" => "mediumseagreen",
+    "medslateblue" => "mediumslateblue",
+    "medspringgreen" => "mediumspringgreen",
+    "mediumturqoise" => "mediumturquoise",
+    "mediumturquise" => "mediumturquoise",
+    "medturquoise" => "mediumturquoise",
+    "medturqoise" => "mediumturquoise",
+    "medturquise" => "mediumturquoise",
+    "mediumvoiletred" => "mediumvioletred",
+    "medvioletred" => "mediumvioletred",
+    "medvoiletred" => "mediumvioletred",
+    "middlegray" => "middlegrey",
+    "neonfuschia" => "neonfuchsia",
+    "neonfushia" => "neonfuchsia",
+    "oldburgandy" => "oldburgundy",
+    "oldlavendar" => "oldlavender",
+    "paynesgray" => "paynesgrey",
+    "pinklavendar" => "pinklavender",
+    "quinacridonemegenta" => "quinacridonemagenta",
+    "redvoilet" => "redviolet",
+    "redvoiletcrayola" => "redvioletcrayola",
+    "redvoiletcolorwheel" => "redvioletcolorwheel",
+    "russianvoilet" => "russianviolet",
+    "skymegenta" => "skymagenta",
+    "slategrey" => "slategray",
+    "spanishgrey" => "spanishgray",
+    "spanishvoilet" => "spanishviolet",
+    "taupegrey" => "taupegray",
+    "telemegenta" => "telemagenta",
+    "turqoise" => "turquoise",
+    "turquise" => "turquoise",
+    "turqoiseblue" => "turquoiseblue",
+    "turquiseblue" => "turquoiseblue",
+    "turqoisegreen" => "turquoisegreen",
+    "turquisegreen" => "turquoisegreen",
+    "twilightlavendar" => "twilightlavender",
+    "voilet" => "violet",
+    "voiletcolorwheel" => "violetcolorwheel",
+    "voiletcrayola" => "violetcrayola",
+    "voiletryb" => "violetryb",
+    "voiletweb" => "violetweb",
+    "voiletblue" => "violetblue",
+    "voiletbluecrayola" => "violetbluecrayola",
+    "voiletred" => "violetred",
+    "voiletredperbang" => "violetredperbang",
+    "vividburgandy" => "vividburgundy",
+    "vividvoilet" => "vividviolet",
+};
+
+/// `COLORS` keys sharing a base name, for names that aren't simply their own key.
+pub(crate) static COLORS_VARIANTS: phf::Map<&'static str, &'static [&'static str]> = phf::phf_map! {
+    "azure" => &["azure", "azurex11webcolor"],
+    "blue" => &["blue", "bluecrayola", "bluemunsell", "bluencs", "bluepantone", "bluepigment"],
+    "bluegray" => &["bluegraycrayola"],
+    "brightyellow" => &["brightyellowcrayola"],
+    "carmine" => &["carmine", "carminemp"],
+    "cerulean" => &["cerulean", "ceruleancrayola", "ceruleanrgb"],
+    "chartreuse" => &["chartreuseweb"],
+    "chocolate" => &["chocolatetraditional", "chocolateweb"],
+    "copper" => &["copper", "coppercrayola"],
+    "crimson" => &["crimson", "crimsonua"],
+    "cyan" => &["cyan", "cyanprocess"],
+    "darkgreen" => &["darkgreenx11"],
+    "darkliver" => &["darkliverhorses"],
+    "forestgreen" => &["forestgreenweb"],
+    "fuchsia" => &["fuchsia", "fuchsiacrayola"],
+    "gold" => &["goldmetallic", "goldwebgolden", "goldcrayola"],
+    "gray" => &["grayweb", "grayx11gray"],
+    "green" => &["green", "greencrayola", "greenweb", "greenmunsell", "greenncs", "greenpantone", "greenpigment"],
+    "hotmagenta" => &["hotmagentabrokenanchor"],
+    "internationalorange" => &["internationalorangeengineering", "internationalorangegoldengatebridge"],
+    "khaki" => &["khakiweb", "khakix11lightkhaki"],
+    "lavender" => &["lavenderfloral", "lavenderweb"],
+    "lemonyellow" => &["lemonyellow", "lemonyellowcrayola"],
+    "lime" => &["limecolorwheel", "limewebx11green"],
+    "liver" => &["liver", "liverdogs", "liverorgan"],
+    "magenta" => &["magenta", "magentacrayolabrokenanchor", "magentadye", "magentapantone", "magentaprocess"],
+    "maize" => &["maize", "maizecrayola"],
+    "maroon" => &["marooncrayola", "maroonweb", "maroonx11"],
+    "midnightgreen" => &["midnightgreeneaglegreen"],
+    "mulberry" => &["mulberry", "mulberrycrayola"],
+    "navyblue" => &["navyblue", "navybluecrayola"],
+    "olivedrab" => &["olivedrab3", "olivedrab7"],
+    "orange" => &["orange", "orangecrayola", "orangepantone", "orangeweb"],
+    "orangered" => &["orangered", "orangeredcrayola"],
+    "orangeyellow" => &["orangeyellow", "orangeyellowcrayola"],
+    "orchid" => &["orchid", "orchidcrayola"],
+    "outerspace" => &["outerspacecrayola"],
+    "palepurple" => &["palepurplepantone"],
+    "peach" => &["peach", "peachcrayola"],
+    "periwinkle" => &["periwinkle", "periwinklecrayola"],
+    "pinegreen" => &["pinegreen", "pinegreen2"],
+    "pink" => &["pink", "pinkpantone"],
+    "plum" => &["plum", "plumweb"],
+    "pullmanbrown" => &["pullmanbrownupsbrown"],
+    "purple" => &["purple", "purpleweb", "purplemunsell", "purplex11"],
+    "purplepizzazz" => &["purplepizzazzbrokenanchor"],
+    "red" => &["red", "redcrayola", "redmunsell", "redncs", "redpantone", "redpigment", "redryb"],
+    "redorange" => &["redorange", "redorangecrayola", "redorangecolorwheel"],
+    "redochre" => &["redochre2"],
+    "redviolet" => &["redviolet", "redvioletcrayola", "redvioletcolorwheel"],
+    "richblack" => &["richblack", "richblackfogra29", "richblackfogra39"],
+    "royalblue" => &["royalbluedark", "royalbluelight"],
+    "safetyorange" => &["safetyorange", "safetyorangeblazeorange"],
+    "sapphire" => &["sapphire", "sapphirecrayola"],
+    "seagreen" => &["seagreen", "seagreencrayola"],
+    "shockingpink" => &["shockingpink", "shockingpinkcrayola"],
+    "silver" => &["silver", "silvercrayola", "silvermetallic"],
+    "skyblue" => &["skyblue", "skybluecrayola"],
+    "springgreen" => &["springgreen", "springgreencrayola"],
+    "tan" => &["tan", "tancrayola"],
+    "tenne" => &["tennetawny"],
+    "vermilion" => &["vermilion", "vermilion2"],
+    "violet" => &["violet", "violetcolorwheel", "violetcrayola", "violetryb", "violetweb"],
+    "violetblue" => &["violetblue", "violetbluecrayola"],
+    "violetred" => &["violetred", "violetredperbang"],
+    "yellow" => &["yellow", "yellowcrayola", "yellowmunsell", "yellowncs", "yellowpantone", "yellowprocess", "yellowryb"],
+    "yellowgreen" => &["yellowgreen", "yellowgreencrayola", "yellowgreencolorwheel"],
+    "yelloworange" => &["yelloworange", "yelloworangecolorwheel"],
+};
+
+/// `COLORS` keys ordered by display name, case-insensitively.
+pub(crate) static COLORS_BY_NAME: [&str; 894] = [
+    "absolutezero",
+    "acidgreen",
+    "aero",
+    "africanviolet",
+    "airsuperiorityblue",
+    "aliceblue",
+    "alizarin",
+    "alloyorange",
+    "almond",
+    "amaranthdeeppurple",
+    "amaranthpink",
+    "amaranthpurple",
+    "amazon",
+    "amber",
+    "amethyst",
+    "androidgreen",
+    "antiquebrass",
+    "antiquebronze",
+    "antiquefuchsia",
+    "antiqueruby",
+    "antiquewhite",
+    "apricot",
+    "aqua",
+    "aquamarine",
+    "arcticlime",
+    "artichokegreen",
+    "arylideyellow",
+    "ashgray",
+    "atomictangerine",
+    "aureolin",
+    "azure",
+    "azurex11webcolor",
+    "bdazzledblue",
+    "babyblue",
+    "babyblueeyes",
+    "babypink",
+    "babypowder",
+    "bakermillerpink",
+    "bananamania",
+    "barbiepink",
+    "barnred",
+    "battleshipgrey",
+    "beaublue",
+    "beaver",
+    "beige",
+    "bigdiporuby",
+    "bisque",
+    "bistre",
+    "bistrebrown",
+    "bitterlemon",
+    "black",
+    "blackbean",
+    "blackcoral",
+    "blackolive",
+    "blackshadows",
+    "blanchedalmond",
+    "blastoffbronze",
+    "bleudefrance",
+    "blizzardblue",
+    "bloodred",
+    "blue",
+    "bluecrayola",
+    "bluemunsell",
+    "bluencs",
+    "bluepantone",
+    "bluepigment",
+    "bluebell",
+    "bluejeans",
+    "bluesapphire",
+    "blueyonder",
+    "bluegraycrayola",
+    "blueviolet",
+    "bluetiful",
+    "blush",
+    "bole",
+    "bone",
+    "brickred",
+    "brightlilac",
+    "brightyellowcrayola",
+    "britishracinggreen",
+    "bronze",
+    "brown",
+    "brownsugar",
+    "budgreen",
+    "buff",
+    "burgundy",
+    "burlywood",
+    "burnishedbrown",
+    "burntorange",
+    "burntsienna",
+    "burntumber",
+    "byzantine",
+    "byzantium",
+    "cadetblue",
+    "cadetgrey",
+    "cadmiumgreen",
+    "cadmiumorange",
+    "cafeaulait",
+    "cafenoir",
+    "cambridgeblue",
+    "camel",
+    "cameopink",
+    "canary",
+    "canaryyellow",
+    "candypink",
+    "cardinal",
+    "caribbeangreen",
+    "carmine",
+    "carminemp",
+    "carnationpink",
+    "carnelian",
+    "carolinablue",
+    "carrotorange",
+    "catawba",
+    "cedarchest",
+    "celadon",
+    "celeste",
+    "cerise",
+    "cerulean",
+    "ceruleancrayola",
+    "ceruleanrgb",
+    "ceruleanblue",
+    "ceruleanfrost",
+    "champagne",
+    "champagnepink",
+    "charcoal",
+    "charmpink",
+    "chartreuseweb",
+    "cherryblossompink",
+    "chestnut",
+    "chilired",
+    "chinapink",
+    "chinesered",
+    "chineseviolet",
+    "chineseyellow",
+    "chocolatetraditional",
+    "chocolateweb",
+    "cinereous",
+    "cinnabar",
+    "cinnamonsatin",
+    "citrine",
+    "citron",
+    "claret",
+    "coffee",
+    "columbiablue",
+    "congopink",
+    "coolgrey",
+    "copper",
+    "coppercrayola",
+    "copperpenny",
+    "copperred",
+    "copperrose",
+    "coquelicot",
+    "coral",
+    "coralpink",
+    "cordovan",
+    "corn",
+    "cornflowerblue",
+    "cornsilk",
+    "cosmiccobalt",
+    "cosmiclatte",
+    "cottoncandy",
+    "coyotebrown",
+    "cream",
+    "crimson",
+    "crimsonua",
+    "culturedpearl",
+    "cyan",
+    "cyanprocess",
+    "cybergrape",
+    "cyberyellow",
+    "cyclamen",
+    "dandelion",
+    "darkbrown",
+    "darkbyzantium",
+    "darkcyan",
+    "darkelectricblue",
+    "darkgoldenrod",
+    "darkgreenx11",
+    "darkjunglegreen",
+    "darkkhaki",
+    "darklava",
+    "darkliverhorses",
+    "darkmagenta",
+    "darkolivegreen",
+    "darkorange",
+    "darkorchid",
+    "darkpurple",
+    "darkred",
+    "darksalmon",
+    "darkseagreen",
+    "darksienna",
+    "darkskyblue",
+    "darkslateblue",
+    "darkslategray",
+    "darkspringgreen",
+    "darkturquoise",
+    "darkviolet",
+    "davysgrey",
+    "deepcerise",
+    "deepchampagne",
+    "deepchestnut",
+    "deepjunglegreen",
+    "deeppink",
+    "deepsaffron",
+    "deepskyblue",
+    "deepspacesparkle",
+    "deeptaupe",
+    "denim",
+    "denimblue",
+    "desert",
+    "desertsand",
+    "dimgray",
+    "dodgerblue",
+    "drabdarkbrown",
+    "dukeblue",
+    "dutchwhite",
+    "ebony",
+    "ecru",
+    "eerieblack",
+    "eggplant",
+    "eggshell",
+    "electriclime",
+    "electricpurple",
+    "electricviolet",
+    "emerald",
+    "eminence",
+    "englishlavender",
+    "englishred",
+    "englishvermillion",
+    "englishviolet",
+    "erin",
+    "etonblue",
+    "fallow",
+    "falured",
+    "fandango",
+    "fandangopink",
+    "fawn",
+    "ferngreen",
+    "fielddrab",
+    "fieryrose",
+    "finn",
+    "fireenginered",
+    "firebrick",
+    "flame",
+    "flax",
+    "flirt",
+    "floralwhite",
+    "forestgreenweb",
+    "frenchbeige",
+    "frenchbistre",
+    "frenchblue",
+    "frenchfuchsia",
+    "frenchlilac",
+    "frenchlime",
+    "frenchmauve",
+    "frenchpink",
+    "frenchraspberry",
+    "frenchskyblue",
+    "frenchviolet",
+    "frostbite",
+    "fuchsia",
+    "fuchsiacrayola",
+    "fulvous",
+    "fuzzywuzzy",
+    "gainsboro",
+    "gamboge",
+    "genericviridian",
+    "ghostwhite",
+    "glaucous",
+    "glossygrape",
+    "gogreen",
+    "goldcrayola",
+    "goldmetallic",
+    "goldwebgolden",
+    "goldfusion",
+    "goldenbrown",
+    "goldenpoppy",
+    "goldenyellow",
+    "goldenrod",
+    "gothamgreen",
+    "granitegray",
+    "grannysmithapple",
+    "grayweb",
+    "grayx11gray",
+    "green",
+    "greencrayola",
+    "greenmunsell",
+    "greenncs",
+    "greenpantone",
+    "greenpigment",
+    "greenweb",
+    "greenlizard",
+    "greensheen",
+    "greenblue",
+    "gunmetal",
+    "hansayellow",
+    "harlequin",
+    "harvestgold",
+    "heatwave",
+    "heliotrope",
+    "heliotropegray",
+    "hollywoodcerise",
+    "honolulublue",
+    "hookersgreen",
+    "hotmagentabrokenanchor",
+    "hotpink",
+    "huntergreen",
+    "iceberg",
+    "illuminatingemerald",
+    "imperialred",
+    "inchworm",
+    "independence",
+    "indiagreen",
+    "indianred",
+    "indianyellow",
+    "indigo",
+    "indigodye",
+    "internationalkleinblue",
+    "internationalorangeengineering",
+    "internationalorangegoldengatebridge",
+    "irresistible",
+    "isabelline",
+    "italianskyblue",
+    "ivory",
+    "japanesecarmine",
+    "japaneseviolet",
+    "jasmine",
+    "jazzberryjam",
+    "jet",
+    "jonquil",
+    "junebud",
+    "junglegreen",
+    "kellygreen",
+    "keppel",
+    "keylime",
+    "khakiweb",
+    "khakix11lightkhaki",
+    "kobe",
+    "kobi",
+    "kobicha",
+    "ksupurple",
+    "languidlavender",
+    "lapislazuli",
+    "laserlemon",
+    "laurelgreen",
+    "lava",
+    "lavenderfloral",
+    "lavenderweb",
+    "lavenderblue",
+    "lavenderblush",
+    "lavendergray",
+    "lawngreen",
+    "lemon",
+    "lemonchiffon",
+    "lemoncurry",
+    "lemonglacier",
+    "lemonmeringue",
+    "lemonyellow",
+    "lemonyellowcrayola",
+    "liberty",
+    "lightblue",
+    "lightcoral",
+    "lightcornflowerblue",
+    "lightcyan",
+    "lightfrenchbeige",
+    "lightgoldenrodyellow",
+    "lightgray",
+    "lightgreen",
+    "lightorange",
+    "lightperiwinkle",
+    "lightpink",
+    "lightpurple",
+    "lightsalmon",
+    "lightseagreen",
+    "lightskyblue",
+    "lightslategray",
+    "lightsteelblue",
+    "lightyellow",
+    "lilac",
+    "lilacluster",
+    "limecolorwheel",
+    "limewebx11green",
+    "limegreen",
+    "lincolngreen",
+    "linen",
+    "lion",
+    "liseranpurple",
+    "littleboyblue",
+    "liver",
+    "liverdogs",
+    "liverorgan",
+    "liverchestnut",
+    "livid",
+    "macaroniandcheese",
+    "madderlake",
+    "magenta",
+    "magentacrayolabrokenanchor",
+    "magentadye",
+    "magentapantone",
+    "magentaprocess",
+    "magentahaze",
+    "magicmint",
+    "magnolia",
+    "mahogany",
+    "maize",
+    "maizecrayola",
+    "majorelleblue",
+    "malachite",
+    "manatee",
+    "mandarin",
+    "mango",
+    "mangotango",
+    "mantis",
+    "mardigras",
+    "marigold",
+    "marooncrayola",
+    "maroonweb",
+    "maroonx11",
+    "mauve",
+    "mauvetaupe",
+    "mauvelous",
+    "maximumblue",
+    "maximumbluegreen",
+    "maximumbluepurple",
+    "maximumgreen",
+    "maximumgreenyellow",
+    "maximumpurple",
+    "maximumred",
+    "maximumredpurple",
+    "maximumyellow",
+    "maximumyellowred",
+    "maygreen",
+    "mayablue",
+    "mediumaquamarine",
+    "mediumblue",
+    "mediumcandyapplered",
+    "mediumcarmine",
+    "mediumchampagne",
+    "mediumorchid",
+    "mediumpurple",
+    "mediumseagreen",
+    "mediumslateblue",
+    "mediumspringgreen",
+    "mediumturquoise",
+    "mediumvioletred",
+    "mellowapricot",
+    "mellowyellow",
+    "melon",
+    "metallicgold",
+    "metallicseaweed",
+    "metallicsunburst",
+    "mexicanpink",
+    "middleblue",
+    "middlebluegreen",
+    "middlebluepurple",
+    "middlegreen",
+    "middlegreenyellow",
+    "middlegrey",
+    "middlepurple",
+    "middlered",
+    "middleredpurple",
+    "middleyellow",
+    "middleyellowred",
+    "midnight",
+    "midnightblue",
+    "midnightgreeneaglegreen",
+    "mikadoyellow",
+    "mimipink",
+    "mindaro",
+    "ming",
+    "minionyellow",
+    "mint",
+    "mintcream",
+    "mintgreen",
+    "mistymoss",
+    "mistyrose",
+    "moccasin",
+    "modebeige",
+    "monalisa",
+    "morningblue",
+    "mossgreen",
+    "mountainmeadow",
+    "mountbattenpink",
+    "msugreen",
+    "mulberry",
+    "mulberrycrayola",
+    "mustard",
+    "myrtlegreen",
+    "mystic",
+    "mysticmaroon",
+    "nadeshikopink",
+    "naplesyellow",
+    "navajowhite",
+    "navyblue",
+    "navybluecrayola",
+    "neonblue",
+    "neonfuchsia",
+    "neongreen",
+    "newcar",
+    "newyorkpink",
+    "nickel",
+    "nonphotoblue",
+    "nyanza",
+    "ochre",
+    "oldburgundy",
+    "oldgold",
+    "oldlace",
+    "oldlavender",
+    "oldmauve",
+    "oldrose",
+    "oldsilver",
+    "olive",
+    "olivedrab7",
+    "olivedrab3",
+    "olivegreen",
+    "olivine",
+    "onyx",
+    "opal",
+    "operamauve",
+    "orange",
+    "orangecrayola",
+    "orangepantone",
+    "orangeweb",
+    "orangepeel",
+    "orangesoda",
+    "orangered",
+    "orangeredcrayola",
+    "orangeyellow",
+    "orangeyellowcrayola",
+    "orchid",
+    "orchidcrayola",
+    "orchidpink",
+    "oucrimsonred",
+    "outerspacecrayola",
+    "outrageousorange",
+    "oxblood",
+    "oxfordblue",
+    "pacificblue",
+    "pakistangreen",
+    "palatinatepurple",
+    "paleaqua",
+    "palecerulean",
+    "paledogwood",
+    "palepink",
+    "palepurplepantone",
+    "palespringbud",
+    "pansypurple",
+    "paoloveronesegreen",
+    "papayawhip",
+    "paradisepink",
+    "parchment",
+    "parisgreen",
+    "pastelpink",
+    "patriarch",
+    "paua",
+    "paynesgrey",
+    "peach",
+    "peachcrayola",
+    "peachpuff",
+    "pear",
+    "pearlypurple",
+    "periwinkle",
+    "periwinklecrayola",
+    "permanentgeraniumlake",
+    "persianblue",
+    "persiangreen",
+    "persianindigo",
+    "persianorange",
+    "persianpink",
+    "persianplum",
+    "persianred",
+    "persianrose",
+    "persimmon",
+    "petunia",
+    "pewterblue",
+    "phlox",
+    "phthaloblue",
+    "phthalogreen",
+    "picoteeblue",
+    "pictorialcarmine",
+    "piggypink",
+    "pinegreen",
+    "pinegreen2",
+    "pink",
+    "pinkpantone",
+    "pinklace",
+    "pinklavender",
+    "pinksherbet",
+    "pistachio",
+    "platinum",
+    "plum",
+    "plumweb",
+    "plumppurple",
+    "polishedpine",
+    "pompandpower",
+    "popstar",
+    "portlandorange",
+    "powderblue",
+    "prairiegold",
+    "princetonorange",
+    "prune",
+    "prussianblue",
+    "psychedelicpurple",
+    "puce",
+    "pullmanbrownupsbrown",
+    "pumpkin",
+    "purple",
+    "purplemunsell",
+    "purpleweb",
+    "purplex11",
+    "purplemountainmajesty",
+    "purplenavy",
+    "purplepizzazzbrokenanchor",
+    "purpleplum",
+    "queenblue",
+    "queenpink",
+    "quicksilver",
+    "quinacridonemagenta",
+    "radicalred",
+    "raisinblack",
+    "rajah",
+    "raspberry",
+    "raspberryglace",
+    "raspberryrose",
+    "rawsienna",
+    "rawumber",
+    "razzledazzlerose",
+    "razzmatazz",
+    "razzmicberry",
+    "rebeccapurple",
+    "red",
+    "redcrayola",
+    "redmunsell",
+    "redncs",
+    "redpantone",
+    "redpigment",
+    "redryb",
+    "redochre2",
+    "redsalsa",
+    "redorange",
+    "redorangecolorwheel",
+    "redorangecrayola",
+    "redpurple",
+    "redviolet",
+    "redvioletcolorwheel",
+    "redvioletcrayola",
+    "redwood",
+    "resolutionblue",
+    "rhythm",
+    "richblack",
+    "richblackfogra29",
+    "richblackfogra39",
+    "riflegreen",
+    "robineggblue",
+    "rocketmetallic",
+    "rojospanishred",
+    "romansilver",
+    "rose",
+    "rosebonbon",
+    "rosedust",
+    "roseebony",
+    "rosemadder",
+    "rosepink",
+    "rosepompadour",
+    "rosered",
+    "rosetaupe",
+    "rosevale",
+    "rosewood",
+    "rossocorsa",
+    "rosybrown",
+    "royalbluedark",
+    "royalbluelight",
+    "royalpurple",
+    "royalyellow",
+    "ruber",
+    "rubinered",
+    "ruby",
+    "rubyred",
+    "rufous",
+    "russet",
+    "russiangreen",
+    "russianviolet",
+    "rust",
+    "rustyred",
+    "sacramentostategreen",
+    "saddlebrown",
+    "safetyorange",
+    "safetyorangeblazeorange",
+    "safetyyellow",
+    "saffron",
+    "sage",
+    "salmon",
+    "salmonpink",
+    "sand",
+    "sanddune",
+    "sandybrown",
+    "sapgreen",
+    "sapphire",
+    "sapphirecrayola",
+    "sapphireblue",
+    "satinsheengold",
+    "scarlet",
+    "schausspink",
+    "schoolbusyellow",
+    "screamingreen",
+    "seagreen",
+    "seagreencrayola",
+    "sealbrown",
+    "seance",
+    "seashell",
+    "secret",
+    "selectiveyellow",
+    "sepia",
+    "shadow",
+    "shadowblue",
+    "shamrockgreen",
+    "sheengreen",
+    "shimmeringblush",
+    "shinyshamrock",
+    "shockingpink",
+    "shockingpinkcrayola",
+    "sienna",
+    "silver",
+    "silvercrayola",
+    "silvermetallic",
+    "silverchalice",
+    "silverpink",
+    "silversand",
+    "sinopia",
+    "sizzlingred",
+    "sizzlingsunrise",
+    "skincolor",
+    "skobeloff",
+    "skyblue",
+    "skybluecrayola",
+    "skymagenta",
+    "slateblue",
+    "slategray",
+    "slimygreen",
+    "smitten",
+    "smokyblack",
+    "snow",
+    "solidpink",
+    "sonicsilver",
+    "spacecadet",
+    "spanishbistre",
+    "spanishblue",
+    "spanishcarmine",
+    "spanishgray",
+    "spanishgreen",
+    "spanishorange",
+    "spanishpink",
+    "spanishred",
+    "spanishskyblue",
+    "spanishviolet",
+    "spanishviridian",
+    "springbud",
+    "springfrost",
+    "springgreen",
+    "springgreencrayola",
+    "stpatricksblue",
+    "starcommandblue",
+    "steelblue",
+    "steelpink",
+    "stildegrainyellow",
+    "straw",
+    "strawberry",
+    "strawberryblonde",
+    "stronglimegreen",
+    "sugarplum",
+    "sunglow",
+    "sunray",
+    "sunset",
+    "superpink",
+    "sweetbrown",
+    "syracuseorange",
+    "tan",
+    "tancrayola",
+    "tangerine",
+    "tangopink",
+    "tartorange",
+    "taupe",
+    "taupegray",
+    "teagreen",
+    "tearose",
+    "teal",
+    "tealblue",
+    "technobotanica",
+    "telemagenta",
+    "tennetawny",
+    "terracotta",
+    "thistle",
+    "thulianpink",
+    "ticklemepink",
+    "tiffanyblue",
+    "timberwolf",
+    "titaniumyellow",
+    "tomato",
+    "tourmaline",
+    "tropicalrainforest",
+    "trueblue",
+    "trypanblue",
+    "tuftsblue",
+    "tumbleweed",
+    "turquoise",
+    "turquoiseblue",
+    "turquoisegreen",
+    "turtlegreen",
+    "tuscan",
+    "tuscanbrown",
+    "tuscanred",
+    "tuscantan",
+    "tuscany",
+    "twilightlavender",
+    "tyrianpurple",
+    "uablue",
+    "uared",
+    "ultrapink",
+    "ultrared",
+    "ultramarine",
+    "ultramarineblue",
+    "umber",
+    "unbleachedsilk",
+    "unitednationsblue",
+    "universityofpennsylvaniared",
+    "unmellowyellow",
+    "upforestgreen",
+    "upmaroon",
+    "upsdellred",
+    "uranianblue",
+    "usafablue",
+    "vandykebrown",
+    "vanilla",
+    "vanillaice",
+    "vantgblue",
+    "vegasgold",
+    "venetianred",
+    "verdigris",
+    "vermilion",
+    "vermilion2",
+    "veronica",
+    "violet",
+    "violetcolorwheel",
+    "violetcrayola",
+    "violetryb",
+    "violetweb",
+    "violetblue",
+    "violetbluecrayola",
+    "violetred",
+    "violetredperbang",
+    "viridian",
+    "viridiangreen",
+    "vividburgundy",
+    "vividskyblue",
+    "vividtangerine",
+    "vividviolet",
+    "volt",
+    "warmblack",
+    "weezyblue",
+    "wheat",
+    "white",
+    "wildblueyonder",
+    "wildorchid",
+    "wildstrawberry",
+    "wildwatermelon",
+    "windsortan",
+    "wine",
+    "winedregs",
+    "wintersky",
+    "wintergreendream",
+    "wisteria",
+    "woodbrown",
+    "xanadu",
+    "xanthic",
+    "xanthous",
+    "yaleblue",
+    "yellow",
+    "yellowcrayola",
+    "yellowmunsell",
+    "yellowncs",
+    "yellowpantone",
+    "yellowprocess",
+    "yellowryb",
+    "yelloworange",
+    "yelloworangecolorwheel",
+    "yellowsunshine",
+    "yellowgreen",
+    "yellowgreencolorwheel",
+    "yellowgreencrayola",
+    "yinmnblue",
+    "zaffre",
+    "zinnwalditebrown",
+    "zomp",
+];
+
+/// `COLORS` keys in sorted order, for prefix search.
+pub(crate) static COLORS_KEYS: [&str; 894] = [
+    "absolutezero",
+    "acidgreen",
+    "aero",
+    "africanviolet",
+    "airsuperiorityblue",
+    "aliceblue",
+    "alizarin",
+    "alloyorange",
+    "almond",
+    "amaranthdeeppurple",
+    "amaranthpink",
+    "amaranthpurple",
+    "amazon",
+    "amber",
+    "amethyst",
+    "androidgreen",
+    "antiquebrass",
+    "antiquebronze",
+    "antiquefuchsia",
+    "antiqueruby",
+    "antiquewhite",
+    "apricot",
+    "aqua",
+    "aquamarine",
+    "arcticlime",
+    "artichokegreen",
+    "arylideyellow",
+    "ashgray",
+    "atomictangerine",
+    "aureolin",
+    "azure",
+    "azurex11webcolor",
+    "babyblue",
+    "babyblueeyes",
+    "babypink",
+    "babypowder",
+    "bakermillerpink",
+    "bananamania",
+    "barbiepink",
+    "barnred",
+    "battleshipgrey",
+    "bdazzledblue",
+    "beaublue",
+    "beaver",
+    "beige",
+    "bigdiporuby",
+    "bisque",
+    "bistre",
+    "bistrebrown",
+    "bitterlemon",
+    "black",
+    "blackbean",
+    "blackcoral",
+    "blackolive",
+    "blackshadows",
+    "blanchedalmond",
+    "blastoffbronze",
+    "bleudefrance",
+    "blizzardblue",
+    "bloodred",
+    "blue",
+    "bluebell",
+    "bluecrayola",
+    "bluegraycrayola",
+    "bluejeans",
+    "bluemunsell",
+    "bluencs",
+    "bluepantone",
+    "bluepigment",
+    "bluesapphire",
+    "bluetiful",
+    "blueviolet",
+    "blueyonder",
+    "blush",
+    "bole",
+    "bone",
+    "brickred",
+    "brightlilac",
+    "brightyellowcrayola",
+    "britishracinggreen",
+    "bronze",
+    "brown",
+    "brownsugar",
+    "budgreen",
+    "buff",
+    "burgundy",
+    "burlywood",
+    "burnishedbrown",
+    "burntorange",
+    "burntsienna",
+    "burntumber",
+    "byzantine",
+    "byzantium",
+    "cadetblue",
+    "cadetgrey",
+    "cadmiumgreen",
+    "cadmiumorange",
+    "cafeaulait",
+    "cafenoir",
+    "cambridgeblue",
+    "camel",
+    "cameopink",
+    "canary",
+    "canaryyellow",
+    "candypink",
+    "cardinal",
+    "caribbeangreen",
+    "carmine",
+    "carminemp",
+    "carnationpink",
+    "carnelian",
+    "carolinablue",
+    "carrotorange",
+    "catawba",
+    "cedarchest",
+    "celadon",
+    "celeste",
+    "cerise",
+    "cerulean",
+    "ceruleanblue",
+    "ceruleancrayola",
+    "ceruleanfrost",
+    "ceruleanrgb",
+    "champagne",
+    "champagnepink",
+    "charcoal",
+    "charmpink",
+    "chartreuseweb",
+    "cherryblossompink",
+    "chestnut",
+    "chilired",
+    "chinapink",
+    "chinesered",
+    "chineseviolet",
+    "chineseyellow",
+    "chocolatetraditional",
+    "chocolateweb",
+    "cinereous",
+    "cinnabar",
+    "cinnamonsatin",
+    "citrine",
+    "citron",
+    "claret",
+    "coffee",
+    "columbiablue",
+    "congopink",
+    "coolgrey",
+    "copper",
+    "coppercrayola",
+    "copperpenny",
+    "copperred",
+    "copperrose",
+    "coquelicot",
+    "coral",
+    "coralpink",
+    "cordovan",
+    "corn",
+    "cornflowerblue",
+    "cornsilk",
+    "cosmiccobalt",
+    "cosmiclatte",
+    "cottoncandy",
+    "coyotebrown",
+    "cream",
+    "crimson",
+    "crimsonua",
+    "culturedpearl",
+    "cyan",
+    "cyanprocess",
+    "cybergrape",
+    "cyberyellow",
+    "cyclamen",
+    "dandelion",
+    "darkbrown",
+    "darkbyzantium",
+    "darkcyan",
+    "darkelectricblue",
+    "darkgoldenrod",
+    "darkgreenx11",
+    "darkjunglegreen",
+    "darkkhaki",
+    "darklava",
+    "darkliverhorses",
+    "darkmagenta",
+    "darkolivegreen",
+    "darkorange",
+    "darkorchid",
+    "darkpurple",
+    "darkred",
+    "darksalmon",
+    "darkseagreen",
+    "darksienna",
+    "darkskyblue",
+    "darkslateblue",
+    "darkslategray",
+    "darkspringgreen",
+    "darkturquoise",
+    "darkviolet",
+    "davysgrey",
+    "deepcerise",
+    "deepchampagne",
+    "deepchestnut",
+    "deepjunglegreen",
+    "deeppink",
+    "deepsaffron",
+    "deepskyblue",
+    "deepspacesparkle",
+    "deeptaupe",
+    "denim",
+    "denimblue",
+    "desert",
+    "desertsand",
+    "dimgray",
+    "dodgerblue",
+    "drabdarkbrown",
+    "dukeblue",
+    "dutchwhite",
+    "ebony",
+    "ecru",
+    "eerieblack",
+    "eggplant",
+    "eggshell",
+    "electriclime",
+    "electricpurple",
+    "electricviolet",
+    "emerald",
+    "eminence",
+    "englishlavender",
+    "englishred",
+    "englishvermillion",
+    "englishviolet",
+    "erin",
+    "etonblue",
+    "fallow",
+    "falured",
+    "fandango",
+    "fandangopink",
+    "fawn",
+    "ferngreen",
+    "fielddrab",
+    "fieryrose",
+    "finn",
+    "firebrick",
+    "fireenginered",
+    "flame",
+    "flax",
+    "flirt",
+    "floralwhite",
+    "forestgreenweb",
+    "frenchbeige",
+    "frenchbistre",
+    "frenchblue",
+    "frenchfuchsia",
+    "frenchlilac",
+    "frenchlime",
+    "frenchmauve",
+    "frenchpink",
+    "frenchraspberry",
+    "frenchskyblue",
+    "frenchviolet",
+    "frostbite",
+    "fuchsia",
+    "fuchsiacrayola",
+    "fulvous",
+    "fuzzywuzzy",
+    "gainsboro",
+    "gamboge",
+    "genericviridian",
+    "ghostwhite",
+    "glaucous",
+    "glossygrape",
+    "gogreen",
+    "goldcrayola",
+    "goldenbrown",
+    "goldenpoppy",
+    "goldenrod",
+    "goldenyellow",
+    "goldfusion",
+    "goldmetallic",
+    "goldwebgolden",
+    "gothamgreen",
+    "granitegray",
+    "grannysmithapple",
+    "grayweb",
+    "grayx11gray",
+    "green",
+    "greenblue",
+    "greencrayola",
+    "greenlizard",
+    "greenmunsell",
+    "greenncs",
+    "greenpantone",
+    "greenpigment",
+    "greensheen",
+    "greenweb",
+    "gunmetal",
+    "hansayellow",
+    "harlequin",
+    "harvestgold",
+    "heatwave",
+    "heliotrope",
+    "heliotropegray",
+    "hollywoodcerise",
+    "honolulublue",
+    "hookersgreen",
+    "hotmagentabrokenanchor",
+    "hotpink",
+    "huntergreen",
+    "iceberg",
+    "illuminatingemerald",
+    "imperialred",
+    "inchworm",
+    "independence",
+    "indiagreen",
+    "indianred",
+    "indianyellow",
+    "indigo",
+    "indigodye",
+    "internationalkleinblue",
+    "internationalorangeengineering",
+    "internationalorangegoldengatebridge",
+    "irresistible",
+    "isabelline",
+    "italianskyblue",
+    "ivory",
+    "japanesecarmine",
+    "japaneseviolet",
+    "jasmine",
+    "jazzberryjam",
+    "jet",
+    "jonquil",
+    "junebud",
+    "junglegreen",
+    "kellygreen",
+    "keppel",
+    "keylime",
+    "khakiweb",
+    "khakix11lightkhaki",
+    "kobe",
+    "kobi",
+    "kobicha",
+    "ksupurple",
+    "languidlavender",
+    "lapislazuli",
+    "laserlemon",
+    "laurelgreen",
+    "lava",
+    "lavenderblue",
+    "lavenderblush",
+    "lavenderfloral",
+    "lavendergray",
+    "lavenderweb",
+    "lawngreen",
+    "lemon",
+    "lemonchiffon",
+    "lemoncurry",
+    "lemonglacier",
+    "lemonmeringue",
+    "lemonyellow",
+    "lemonyellowcrayola",
+    "liberty",
+    "lightblue",
+    "lightcoral",
+    "lightcornflowerblue",
+    "lightcyan",
+    "lightfrenchbeige",
+    "lightgoldenrodyellow",
+    "lightgray",
+    "lightgreen",
+    "lightorange",
+    "lightperiwinkle",
+    "lightpink",
+    "lightpurple",
+    "lightsalmon",
+    "lightseagreen",
+    "lightskyblue",
+    "lightslategray",
+    "lightsteelblue",
+    "lightyellow",
+    "lilac",
+    "lilacluster",
+    "limecolorwheel",
+    "limegreen",
+    "limewebx11green",
+    "lincolngreen",
+    "linen",
+    "lion",
+    "liseranpurple",
+    "littleboyblue",
+    "liver",
+    "liverchestnut",
+    "liverdogs",
+    "liverorgan",
+    "livid",
+    "macaroniandcheese",
+    "madderlake",
+    "magenta",
+    "magentacrayolabrokenanchor",
+    "magentadye",
+    "magentahaze",
+    "magentapantone",
+    "magentaprocess",
+    "magicmint",
+    "magnolia",
+    "mahogany",
+    "maize",
+    "maizecrayola",
+    "majorelleblue",
+    "malachite",
+    "manatee",
+    "mandarin",
+    "mango",
+    "mangotango",
+    "mantis",
+    "mardigras",
+    "marigold",
+    "marooncrayola",
+    "maroonweb",
+    "maroonx11",
+    "mauve",
+    "mauvelous",
+    "mauvetaupe",
+    "maximumblue",
+    "maximumbluegreen",
+    "maximumbluepurple",
+    "maximumgreen",
+    "maximumgreenyellow",
+    "maximumpurple",
+    "maximumred",
+    "maximumredpurple",
+    "maximumyellow",
+    "maximumyellowred",
+    "mayablue",
+    "maygreen",
+    "mediumaquamarine",
+    "mediumblue",
+    "mediumcandyapplered",
+    "mediumcarmine",
+    "mediumchampagne",
+    "mediumorchid",
+    "mediumpurple",
+    "mediumseagreen",
+    "mediumslateblue",
+    "mediumspringgreen",
+    "mediumturquoise",
+    "mediumvioletred",
+    "mellowapricot",
+    "mellowyellow",
+    "melon",
+    "metallicgold",
+    "metallicseaweed",
+    "metallicsunburst",
+    "mexicanpink",
+    "middleblue",
+    "middlebluegreen",
+    "middlebluepurple",
+    "middlegreen",
+    "middlegreenyellow",
+    "middlegrey",
+    "middlepurple",
+    "middlered",
+    "middleredpurple",
+    "middleyellow",
+    "middleyellowred",
+    "midnight",
+    "midnightblue",
+    "midnightgreeneaglegreen",
+    "mikadoyellow",
+    "mimipink",
+    "mindaro",
+    "ming",
+    "minionyellow",
+    "mint",
+    "mintcream",
+    "mintgreen",
+    "mistymoss",
+    "mistyrose",
+    "moccasin",
+    "modebeige",
+    "monalisa",
+    "morningblue",
+    "mossgreen",
+    "mountainmeadow",
+    "mountbattenpink",
+    "msugreen",
+    "mulberry",
+    "mulberrycrayola",
+    "mustard",
+    "myrtlegreen",
+    "mystic",
+    "mysticmaroon",
+    "nadeshikopink",
+    "naplesyellow",
+    "navajowhite",
+    "navyblue",
+    "navybluecrayola",
+    "neonblue",
+    "neonfuchsia",
+    "neongreen",
+    "newcar",
+    "newyorkpink",
+    "nickel",
+    "nonphotoblue",
+    "nyanza",
+    "ochre",
+    "oldburgundy",
+    "oldgold",
+    "oldlace",
+    "oldlavender",
+    "oldmauve",
+    "oldrose",
+    "oldsilver",
+    "olive",
+    "olivedrab3",
+    "olivedrab7",
+    "olivegreen",
+    "olivine",
+    "onyx",
+    "opal",
+    "operamauve",
+    "orange",
+    "orangecrayola",
+    "orangepantone",
+    "orangepeel",
+    "orangered",
+    "orangeredcrayola",
+    "orangesoda",
+    "orangeweb",
+    "orangeyellow",
+    "orangeyellowcrayola",
+    "orchid",
+    "orchidcrayola",
+    "orchidpink",
+    "oucrimsonred",
+    "outerspacecrayola",
+    "outrageousorange",
+    "oxblood",
+    "oxfordblue",
+    "pacificblue",
+    "pakistangreen",
+    "palatinatepurple",
+    "paleaqua",
+    "palecerulean",
+    "paledogwood",
+    "palepink",
+    "palepurplepantone",
+    "palespringbud",
+    "pansypurple",
+    "paoloveronesegreen",
+    "papayawhip",
+    "paradisepink",
+    "parchment",
+    "parisgreen",
+    "pastelpink",
+    "patriarch",
+    "paua",
+    "paynesgrey",
+    "peach",
+    "peachcrayola",
+    "peachpuff",
+    "pear",
+    "pearlypurple",
+    "periwinkle",
+    "periwinklecrayola",
+    "permanentgeraniumlake",
+    "persianblue",
+    "persiangreen",
+    "persianindigo",
+    "persianorange",
+    "persianpink",
+    "persianplum",
+    "persianred",
+    "persianrose",
+    "persimmon",
+    "petunia",
+    "pewterblue",
+    "phlox",
+    "phthaloblue",
+    "phthalogreen",
+    "picoteeblue",
+    "pictorialcarmine",
+    "piggypink",
+    "pinegreen",
+    "pinegreen2",
+    "pink",
+    "pinklace",
+    "pinklavender",
+    "pinkpantone",
+    "pinksherbet",
+    "pistachio",
+    "platinum",
+    "plum",
+    "plumppurple",
+    "plumweb",
+    "polishedpine",
+    "pompandpower",
+    "popstar",
+    "portlandorange",
+    "powderblue",
+    "prairiegold",
+    "princetonorange",
+    "prune",
+    "prussianblue",
+    "psychedelicpurple",
+    "puce",
+    "pullmanbrownupsbrown",
+    "pumpkin",
+    "purple",
+    "purplemountainmajesty",
+    "purplemunsell",
+    "purplenavy",
+    "purplepizzazzbrokenanchor",
+    "purpleplum",
+    "purpleweb",
+    "purplex11",
+    "queenblue",
+    "queenpink",
+    "quicksilver",
+    "quinacridonemagenta",
+    "radicalred",
+    "raisinblack",
+    "rajah",
+    "raspberry",
+    "raspberryglace",
+    "raspberryrose",
+    "rawsienna",
+    "rawumber",
+    "razzledazzlerose",
+    "razzmatazz",
+    "razzmicberry",
+    "rebeccapurple",
+    "red",
+    "redcrayola",
+    "redmunsell",
+    "redncs",
+    "redochre2",
+    "redorange",
+    "redorangecolorwheel",
+    "redorangecrayola",
+    "redpantone",
+    "redpigment",
+    "redpurple",
+    "redryb",
+    "redsalsa",
+    "redviolet",
+    "redvioletcolorwheel",
+    "redvioletcrayola",
+    "redwood",
+    "resolutionblue",
+    "rhythm",
+    "richblack",
+    "richblackfogra29",
+    "richblackfogra39",
+    "riflegreen",
+    "robineggblue",
+    "rocketmetallic",
+    "rojospanishred",
+    "romansilver",
+    "rose",
+    "rosebonbon",
+    "rosedust",
+    "roseebony",
+    "rosemadder",
+    "rosepink",
+    "rosepompadour",
+    "rosered",
+    "rosetaupe",
+    "rosevale",
+    "rosewood",
+    "rossocorsa",
+    "rosybrown",
+    "royalbluedark",
+    "royalbluelight",
+    "royalpurple",
+    "royalyellow",
+    "ruber",
+    "rubinered",
+    "ruby",
+    "rubyred",
+    "rufous",
+    "russet",
+    "russiangreen",
+    "russianviolet",
+    "rust",
+    "rustyred",
+    "sacramentostategreen",
+    "saddlebrown",
+    "safetyorange",
+    "safetyorangeblazeorange",
+    "safetyyellow",
+    "saffron",
+    "sage",
+    "salmon",
+    "salmonpink",
+    "sand",
+    "sanddune",
+    "sandybrown",
+    "sapgreen",
+    "sapphire",
+    "sapphireblue",
+    "sapphirecrayola",
+    "satinsheengold",
+    "scarlet",
+    "schausspink",
+    "schoolbusyellow",
+    "screamingreen",
+    "seagreen",
+    "seagreencrayola",
+    "sealbrown",
+    "seance",
+    "seashell",
+    "secret",
+    "selectiveyellow",
+    "sepia",
+    "shadow",
+    "shadowblue",
+    "shamrockgreen",
+    "sheengreen",
+    "shimmeringblush",
+    "shinyshamrock",
+    "shockingpink",
+    "shockingpinkcrayola",
+    "sienna",
+    "silver",
+    "silverchalice",
+    "silvercrayola",
+    "silvermetallic",
+    "silverpink",
+    "silversand",
+    "sinopia",
+    "sizzlingred",
+    "sizzlingsunrise",
+    "skincolor",
+    "skobeloff",
+    "skyblue",
+    "skybluecrayola",
+    "skymagenta",
+    "slateblue",
+    "slategray",
+    "slimygreen",
+    "smitten",
+    "smokyblack",
+    "snow",
+    "solidpink",
+    "sonicsilver",
+    "spacecadet",
+    "spanishbistre",
+    "spanishblue",
+    "spanishcarmine",
+    "spanishgray",
+    "spanishgreen",
+    "spanishorange",
+    "spanishpink",
+    "spanishred",
+    "spanishskyblue",
+    "spanishviolet",
+    "spanishviridian",
+    "springbud",
+    "springfrost",
+    "springgreen",
+    "springgreencrayola",
+    "starcommandblue",
+    "steelblue",
+    "steelpink",
+    "stildegrainyellow",
+    "stpatricksblue",
+    "straw",
+    "strawberry",
+    "strawberryblonde",
+    "stronglimegreen",
+    "sugarplum",
+    "sunglow",
+    "sunray",
+    "sunset",
+    "superpink",
+    "sweetbrown",
+    "syracuseorange",
+    "tan",
+    "tancrayola",
+    "tangerine",
+    "tangopink",
+    "tartorange",
+    "taupe",
+    "taupegray",
+    "teagreen",
+    "teal",
+    "tealblue",
+    "tearose",
+    "technobotanica",
+    "telemagenta",
+    "tennetawny",
+    "terracotta",
+    "thistle",
+    "thulianpink",
+    "ticklemepink",
+    "tiffanyblue",
+    "timberwolf",
+    "titaniumyellow",
+    "tomato",
+    "tourmaline",
+    "tropicalrainforest",
+    "trueblue",
+    "trypanblue",
+    "tuftsblue",
+    "tumbleweed",
+    "turquoise",
+    "turquoiseblue",
+    "turquoisegreen",
+    "turtlegreen",
+    "tuscan",
+    "tuscanbrown",
+    "tuscanred",
+    "tuscantan",
+    "tuscany",
+    "twilightlavender",
+    "tyrianpurple",
+    "uablue",
+    "uared",
+    "ultramarine",
+    "ultramarineblue",
+    "ultrapink",
+    "ultrared",
+    "umber",
+    "unbleachedsilk",
+    "unitednationsblue",
+    "universityofpennsylvaniared",
+    "unmellowyellow",
+    "upforestgreen",
+    "upmaroon",
+    "upsdellred",
+    "uranianblue",
+    "usafablue",
+    "vandykebrown",
+    "vanilla",
+    "vanillaice",
+    "vantgblue",
+    "vegasgold",
+    "venetianred",
+    "verdigris",
+    "vermilion",
+    "vermilion2",
+    "veronica",
+    "violet",
+    "violetblue",
+    "violetbluecrayola",
+    "violetcolorwheel",
+    "violetcrayola",
+    "violetred",
+    "violetredperbang",
+    "violetryb",
+    "violetweb",
+    "viridian",
+    "viridiangreen",
+    "vividburgundy",
+    "vividskyblue",
+    "vividtangerine",
+    "vividviolet",
+    "volt",
+    "warmblack",
+    "weezyblue",
+    "wheat",
+    "white",
+    "wildblueyonder",
+    "wildorchid",
+    "wildstrawberry",
+    "wildwatermelon",
+    "windsortan",
+    "wine",
+    "winedregs",
+    "wintergreendream",
+    "wintersky",
+    "wisteria",
+    "woodbrown",
+    "xanadu",
+    "xanthic",
+    "xanthous",
+    "yaleblue",
+    "yellow",
+    "yellowcrayola",
+    "yellowgreen",
+    "yellowgreencolorwheel",
+    "yellowgreencrayola",
+    "yellowmunsell",
+    "yellowncs",
+    "yelloworange",
+    "yelloworangecolorwheel",
+    "yellowpantone",
+    "yellowprocess",
+    "yellowryb",
+    "yellowsunshine",
+    "yinmnblue",
+    "zaffre",
+    "zinnwalditebrown",
+    "zomp",
+];
+
+/// Every word of every display name, sorted, with the keys (in display-name order) using it.
+pub(crate) static COLORS_WORDS: [(&str, &[&str]); 661] = [
+    ("2", &["redochre2"]),
+    ("3", &["olivedrab3"]),
+    ("7", &["olivedrab7"]),
+    ("absolute", &["absolutezero"]),
+    ("acid", &["acidgreen"]),
+    ("aero", &["aero"]),
+    ("african", &["africanviolet"]),
+    ("air", &["airsuperiorityblue"]),
+    ("alice", &["aliceblue"]),
+    ("alizarin", &["alizarin"]),
+    ("alloy", &["alloyorange"]),
+    ("almond", &["almond", "blanchedalmond"]),
+    ("amaranth", &["amaranthdeeppurple", "amaranthpink", "amaranthpurple"]),
+    ("amazon", &["amazon"]),
+    ("amber", &["amber"]),
+    ("amethyst", &["amethyst"]),
+    ("anchor", &["hotmagentabrokenanchor", "magentacrayolabrokenanchor", "purplepizzazzbrokenanchor"]),
+    ("and", &["macaroniandcheese", "pompandpower"]),
+    ("android", &["androidgreen"]),
+    ("antique", &["antiquebrass", "antiquebronze", "antiquefuchsia", "antiqueruby", "antiquewhite"]),
+    ("apple", &["grannysmithapple", "mediumcandyapplered"]),
+    ("apricot", &["apricot", "mellowapricot"]),
+    ("aqua", &["aqua", "paleaqua"]),
+    ("aquamarine", &["aquamarine", "mediumaquamarine"]),
+    ("arctic", &["arcticlime"]),
+    ("artichoke", &["artichokegreen"]),
+    ("arylide", &["arylideyellow"]),
+    ("ash", &["ashgray"]),
+    ("atomic", &["atomictangerine"]),
+    ("au", &["cafeaulait"]),
+    ("aureolin", &["aureolin"]),
+    ("azure", &["azure", "azurex11webcolor"]),
+    ("b", &["bdazzledblue"]),
+    ("baby", &["babyblue", "babyblueeyes", "babypink", "babypowder"]),
+    ("baker", &["bakermillerpink"]),
+    ("banana", &["bananamania"]),
+    ("barbie", &["barbiepink"]),
+    ("barn", &["barnred"]),
+    ("battleship", &["battleshipgrey"]),
+    ("bean", &["blackbean"]),
+    ("beau", &["beaublue"]),
+    ("beaver", &["beaver"]),
+    ("beige", &["beige", "frenchbeige", "lightfrenchbeige", "modebeige"]),
+    ("bell", &["bluebell"]),
+    ("berry", &["razzmicberry"]),
+    ("big", &["bigdiporuby"]),
+    ("bisque", &["bisque"]),
+    ("bistre", &["bistre", "bistrebrown", "frenchbistre", "spanishbistre"]),
+    ("bitter", &["bitterlemon"]),
+    ("black", &["black", "blackbean", "blackcoral", "blackolive", "blackshadows", "eerieblack", "raisinblack", "richblack", "richblackfogra29", "richblackfogra39", "smokyblack", "warmblack"]),
+    ("blanched", &["blanchedalmond"]),
+    ("blast", &["blastoffbronze"]),
+    ("blaze", &["safetyorangeblazeorange"]),
+    ("bleu", &["bleudefrance"]),
+    ("blizzard", &["blizzardblue"]),
+    ("blonde", &["strawberryblonde"]),
+    ("blood", &["bloodred"]),
+    ("blossom", &["cherryblossompink"]),
+    ("blue", &["airsuperiorityblue", "aliceblue", "bdazzledblue", "babyblue", "babyblueeyes", "beaublue", "blizzardblue", "blue", "bluecrayola", "bluemunsell", "bluencs", "bluepantone", "bluepigment", "bluebell", "bluejeans", "bluesapphire", "blueyonder", "bluegraycrayola", "blueviolet", "cadetblue", "cambridgeblue", "carolinablue", "ceruleanblue", "columbiablue", "cornflowerblue", "darkelectricblue", "darkskyblue", "darkslateblue", "deepskyblue", "denimblue", "dodgerblue", "dukeblue", "etonblue", "frenchblue", "frenchskyblue", "greenblue", "honolulublue", "internationalkleinblue", "italianskyblue", "lavenderblue", "lightblue", "lightcornflowerblue", "lightskyblue", "lightsteelblue", "littleboyblue", "majorelleblue", "maximumblue", "maximumbluegreen", "maximumbluepurple", "mayablue", "mediumblue", "mediumslateblue", "middleblue", "middlebluegreen", "middlebluepurple", "midnightblue", "morningblue", "navyblue", "navybluecrayola", "neonblue", "nonphotoblue", "oxfordblue", "pacificblue", "persianblue", "pewterblue", "phthaloblue", "picoteeblue", "powderblue", "prussianblue", "queenblue", "resolutionblue", "robineggblue", "royalbluedark", "royalbluelight", "sapphireblue", "shadowblue", "skyblue", "skybluecrayola", "slateblue", "spanishblue", "spanishskyblue", "stpatricksblue", "starcommandblue", "steelblue", "tealblue", "tiffanyblue", "trueblue", "trypanblue", "tuftsblue", "turquoiseblue", "uablue", "ultramarineblue", "unitednationsblue", "uranianblue", "usafablue", "vantgblue", "violetblue", "violetbluecrayola", "vividskyblue", "weezyblue", "wildblueyonder", "yaleblue", "yinmnblue"]),
+    ("bluetiful", &["bluetiful"]),
+    ("blush", &["blush", "lavenderblush", "shimmeringblush"]),
+    ("bole", &["bole"]),
+    ("bonbon", &["rosebonbon"]),
+    ("bone", &["bone"]),
+    ("boy", &["littleboyblue"]),
+    ("brass", &["antiquebrass"]),
+    ("brick", &["brickred"]),
+    ("bridge", &["internationalorangegoldengatebridge"]),
+    ("bright", &["brightlilac", "brightyellowcrayola"]),
+    ("british", &["britishracinggreen"]),
+    ("broken", &["hotmagentabrokenanchor", "magentacrayolabrokenanchor", "purplepizzazzbrokenanchor"]),
+    ("bronze", &["antiquebronze", "blastoffbronze", "bronze"]),
+    ("brown", &["bistrebrown", "brown", "brownsugar", "burnishedbrown", "coyotebrown", "darkbrown", "drabdarkbrown", "goldenbrown", "pullmanbrownupsbrown", "rosybrown", "saddlebrown", "sandybrown", "sealbrown", "sweetbrown", "tuscanbrown", "vandykebrown", "woodbrown", "zinnwalditebrown"]),
+    ("bud", &["budgreen", "junebud", "palespringbud", "springbud"]),
+    ("buff", &["buff"]),
+    ("burgundy", &["burgundy", "oldburgundy", "vividburgundy"]),
+    ("burlywood", &["burlywood"]),
+    ("burnished", &["burnishedbrown"]),
+    ("burnt", &["burntorange", "burntsienna", "burntumber"]),
+    ("bus", &["schoolbusyellow"]),
+    ("byzantine", &["byzantine"]),
+    ("byzantium", &["byzantium", "darkbyzantium"]),
+    ("cadet", &["cadetblue", "cadetgrey", "spacecadet"]),
+    ("cadmium", &["cadmiumgreen", "cadmiumorange"]),
+    ("cafe", &["cafeaulait", "cafenoir"]),
+    ("cambridge", &["cambridgeblue"]),
+    ("camel", &["camel"]),
+    ("cameo", &["cameopink"]),
+    ("canary", &["canary", "canaryyellow"]),
+    ("candy", &["candypink", "cottoncandy", "mediumcandyapplered"]),
+    ("car", &["newcar"]),
+    ("cardinal", &["cardinal"]),
+    ("caribbean", &["caribbeangreen"]),
+    ("carmine", &["carmine", "carminemp", "japanesecarmine", "mediumcarmine", "pictorialcarmine", "spanishcarmine"]),
+    ("carnation", &["carnationpink"]),
+    ("carnelian", &["carnelian"]),
+    ("carolina", &["carolinablue"]),
+    ("carrot", &["carrotorange"]),
+    ("catawba", &["catawba"]),
+    ("cedar", &["cedarchest"]),
+    ("celadon", &["celadon"]),
+    ("celeste", &["celeste"]),
+    ("cerise", &["cerise", "deepcerise", "hollywoodcerise"]),
+    ("cerulean", &["cerulean", "ceruleancrayola", "ceruleanrgb", "ceruleanblue", "ceruleanfrost", "palecerulean"]),
+    ("chalice", &["silverchalice"]),
+    ("champagne", &["champagne", "champagnepink", "deepchampagne", "mediumchampagne"]),
+    ("charcoal", &["charcoal"]),
+    ("charm", &["charmpink"]),
+    ("chartreuse", &["chartreuseweb"]),
+    ("cheese", &["macaroniandcheese"]),
+    ("cherry", &["cherryblossompink"]),
+    ("chest", &["cedarchest"]),
+    ("chestnut", &["chestnut", "deepchestnut", "liverchestnut"]),
+    ("chiffon", &["lemonchiffon"]),
+    ("chili", &["chilired"]),
+    ("china", &["chinapink"]),
+    ("chinese", &["chinesered", "chineseviolet", "chineseyellow"]),
+    ("chocolate", &["chocolatetraditional", "chocolateweb"]),
+    ("cinereous", &["cinereous"]),
+    ("cinnabar", &["cinnabar"]),
+    ("cinnamon", &["cinnamonsatin"]),
+    ("citrine", &["citrine"]),
+    ("citron", &["citron"]),
+    ("claret", &["claret"]),
+    ("cobalt", &["cosmiccobalt"]),
+    ("coffee", &["coffee"]),
+    ("color", &["azurex11webcolor", "limecolorwheel", "redorangecolorwheel", "redvioletcolorwheel", "skincolor", "violetcolorwheel", "yelloworangecolorwheel", "yellowgreencolorwheel"]),
+    ("columbia", &["columbiablue"]),
+    ("command", &["starcommandblue"]),
+    ("congo", &["congopink"]),
+    ("cool", &["coolgrey"]),
+    ("copper", &["copper", "coppercrayola", "copperpenny", "copperred", "copperrose"]),
+    ("coquelicot", &["coquelicot"]),
+    ("coral", &["blackcoral", "coral", "coralpink", "lightcoral"]),
+    ("cordovan", &["cordovan"]),
+    ("corn", &["corn"]),
+    ("cornflower", &["cornflowerblue", "lightcornflowerblue"]),
+    ("cornsilk", &["cornsilk"]),
+    ("corsa", &["rossocorsa"]),
+    ("cosmic", &["cosmiccobalt", "cosmiclatte"]),
+    ("cotta", &["terracotta"]),
+    ("cotton", &["cottoncandy"]),
+    ("coyote", &["coyotebrown"]),
+    ("crayola", &["bluecrayola", "bluegraycrayola", "brightyellowcrayola", "ceruleancrayola", "coppercrayola", "fuchsiacrayola", "goldcrayola", "greencrayola", "lemonyellowcrayola", "magentacrayolabrokenanchor", "maizecrayola", "marooncrayola", "mulberrycrayola", "navybluecrayola", "orangecrayola", "orangeredcrayola", "orangeyellowcrayola", "orchidcrayola", "outerspacecrayola", "peachcrayola", "periwinklecrayola", "redcrayola", "redorangecrayola", "redvioletcrayola", "sapphirecrayola", "seagreencrayola", "shockingpinkcrayola", "silvercrayola", "skybluecrayola", "springgreencrayola", "tancrayola", "violetcrayola", "violetbluecrayola", "yellowcrayola", "yellowgreencrayola"]),
+    ("cream", &["cream", "mintcream"]),
+    ("crimson", &["crimson", "crimsonua", "oucrimsonred"]),
+    ("cultured", &["culturedpearl"]),
+    ("curry", &["lemoncurry"]),
+    ("cyan", &["cyan", "cyanprocess", "darkcyan", "lightcyan"]),
+    ("cyber", &["cybergrape", "cyberyellow"]),
+    ("cyclamen", &["cyclamen"]),
+    ("dandelion", &["dandelion"]),
+    ("dark", &["darkbrown", "darkbyzantium", "darkcyan", "darkelectricblue", "darkgoldenrod", "darkgreenx11", "darkjunglegreen", "darkkhaki", "darklava", "darkliverhorses", "darkmagenta", "darkolivegreen", "darkorange", "darkorchid", "darkpurple", "darkred", "darksalmon", "darkseagreen", "darksienna", "darkskyblue", "darkslateblue", "darkslategray", "darkspringgreen", "darkturquoise", "darkviolet", "drabdarkbrown", "royalbluedark"]),
+    ("davy", &["davysgrey"]),
+    ("dazzle", &["razzledazzlerose"]),
+    ("dazzled", &["bdazzledblue"]),
+    ("de", &["bleudefrance", "stildegrainyellow"]),
+    ("deep", &["amaranthdeeppurple", "deepcerise", "deepchampagne", "deepchestnut", "deepjunglegreen", "deeppink", "deepsaffron", "deepskyblue", "deepspacesparkle", "deeptaupe"]),
+    ("denim", &["denim", "denimblue"]),
+    ("desert", &["desert", "desertsand"]),
+    ("dim", &["dimgray"]),
+    ("dip", &["bigdiporuby"]),
+    ("dodger", &["dodgerblue"]),
+    ("dogs", &["liverdogs"]),
+    ("dogwood", &["paledogwood"]),
+    ("drab", &["drabdarkbrown", "fielddrab", "olivedrab7", "olivedrab3"]),
+    ("dream", &["wintergreendream"]),
+    ("dregs", &["winedregs"]),
+    ("duke", &["dukeblue"]),
+    ("dune", &["sanddune"]),
+    ("dust", &["rosedust"]),
+    ("dutch", &["dutchwhite"]),
+    ("dye", &["indigodye", "magentadye"]),
+    ("dyke", &["vandykebrown"]),
+    ("eagle", &["midnightgreeneaglegreen"]),
+    ("ebony", &["ebony", "roseebony"]),
+    ("ecru", &["ecru"]),
+    ("eerie", &["eerieblack"]),
+    ("egg", &["robineggblue"]),
+    ("eggplant", &["eggplant"]),
+    ("eggshell", &["eggshell"]),
+    ("electric", &["darkelectricblue", "electriclime", "electricpurple", "electricviolet"]),
+    ("emerald", &["emerald", "illuminatingemerald"]),
+    ("eminence", &["eminence"]),
+    ("engine", &["fireenginered"]),
+    ("engineering", &["internationalorangeengineering"]),
+    ("english", &["englishlavender", "englishred", "englishvermillion", "englishviolet"]),
+    ("erin", &["erin"]),
+    ("eton", &["etonblue"]),
+    ("eyes", &["babyblueeyes"]),
+    ("fallow", &["fallow"]),
+    ("falu", &["falured"]),
+    ("fandango", &["fandango", "fandangopink"]),
+    ("fawn", &["fawn"]),
+    ("fern", &["ferngreen"]),
+    ("field", &["fielddrab"]),
+    ("fiery", &["fieryrose"]),
+    ("finn", &["finn"]),
+    ("fire", &["fireenginered"]),
+    ("firebrick", &["firebrick"]),
+    ("flame", &["flame"]),
+    ("flax", &["flax"]),
+    ("flirt", &["flirt"]),
+    ("floral", &["floralwhite", "lavenderfloral"]),
+    ("fogra29", &["richblackfogra29"]),
+    ("fogra39", &["richblackfogra39"]),
+    ("forest", &["forestgreenweb", "upforestgreen"]),
+    ("france", &["bleudefrance"]),
+    ("french", &["frenchbeige", "frenchbistre", "frenchblue", "frenchfuchsia", "frenchlilac", "frenchlime", "frenchmauve", "frenchpink", "frenchraspberry", "frenchskyblue", "frenchviolet", "lightfrenchbeige"]),
+    ("frost", &["ceruleanfrost", "springfrost"]),
+    ("frostbite", &["frostbite"]),
+    ("fuchsia", &["antiquefuchsia", "frenchfuchsia", "fuchsia", "fuchsiacrayola", "neonfuchsia"]),
+    ("fulvous", &["fulvous"]),
+    ("fusion", &["goldfusion"]),
+    ("fuzzy", &["fuzzywuzzy"]),
+    ("gainsboro", &["gainsboro"]),
+    ("gamboge", &["gamboge"]),
+    ("gate", &["internationalorangegoldengatebridge"]),
+    ("generic", &["genericviridian"]),
+    ("geranium", &["permanentgeraniumlake"]),
+    ("ghost", &["ghostwhite"]),
+    ("glace", &["raspberryglace"]),
+    ("glacier", &["lemonglacier"]),
+    ("glaucous", &["glaucous"]),
+    ("glossy", &["glossygrape"]),
+    ("go", &["gogreen"]),
+    ("gold", &["goldcrayola", "goldmetallic", "goldwebgolden", "goldfusion", "harvestgold", "metallicgold", "oldgold", "prairiegold", "satinsheengold", "vegasgold"]),
+    ("golden", &["goldwebgolden", "goldenbrown", "goldenpoppy", "goldenyellow", "internationalorangegoldengatebridge"]),
+    ("goldenrod", &["darkgoldenrod", "goldenrod", "lightgoldenrodyellow"]),
+    ("gotham", &["gothamgreen"]),
+    ("grain", &["stildegrainyellow"]),
+    ("granite", &["granitegray"]),
+    ("granny", &["grannysmithapple"]),
+    ("grape", &["cybergrape", "glossygrape"]),
+    ("gras", &["mardigras"]),
+    ("gray", &["ashgray", "bluegraycrayola", "darkslategray", "dimgray", "granitegray", "grayweb", "grayx11gray", "heliotropegray", "lavendergray", "lightgray", "lightslategray", "slategray", "spanishgray", "taupegray"]),
+    ("green", &["acidgreen", "androidgreen", "artichokegreen", "britishracinggreen", "budgreen", "cadmiumgreen", "caribbeangreen", "darkgreenx11", "darkjunglegreen", "darkolivegreen", "darkseagreen", "darkspringgreen", "deepjunglegreen", "ferngreen", "forestgreenweb", "gogreen", "gothamgreen", "green", "greencrayola", "greenmunsell", "greenncs", "greenpantone", "greenpigment", "greenweb", "greenlizard", "greensheen", "greenblue", "hookersgreen", "huntergreen", "indiagreen", "junglegreen", "kellygreen", "laurelgreen", "lawngreen", "lightgreen", "lightseagreen", "limewebx11green", "limegreen", "lincolngreen", "maximumbluegreen", "maximumgreen", "maximumgreenyellow", "maygreen", "mediumseagreen", "mediumspringgreen", "middlebluegreen", "middlegreen", "middlegreenyellow", "midnightgreeneaglegreen", "mintgreen", "mossgreen", "msugreen", "myrtlegreen", "neongreen", "olivegreen", "pakistangreen", "paoloveronesegreen", "parisgreen", "persiangreen", "phthalogreen", "pinegreen", "pinegreen2", "riflegreen", "russiangreen", "sacramentostategreen", "sapgreen", "screamingreen", "seagreen", "seagreencrayola", "shamrockgreen", "sheengreen", "slimygreen", "spanishgreen", "springgreen", "springgreencrayola", "stronglimegreen", "teagreen", "turquoisegreen", "turtlegreen", "upforestgreen", "viridiangreen", "yellowgreen", "yellowgreencolorwheel", "yellowgreencrayola"]),
+    ("grey", &["battleshipgrey", "cadetgrey", "coolgrey", "davysgrey", "middlegrey", "paynesgrey"]),
+    ("gunmetal", &["gunmetal"]),
+    ("hansa", &["hansayellow"]),
+    ("harlequin", &["harlequin"]),
+    ("harvest", &["harvestgold"]),
+    ("haze", &["magentahaze"]),
+    ("heat", &["heatwave"]),
+    ("heliotrope", &["heliotrope", "heliotropegray"]),
+    ("hollywood", &["hollywoodcerise"]),
+    ("honolulu", &["honolulublue"]),
+    ("hooker", &["hookersgreen"]),
+    ("horses", &["darkliverhorses"]),
+    ("hot", &["hotmagentabrokenanchor", "hotpink"]),
+    ("hunter", &["huntergreen"]),
+    ("ice", &["vanillaice"]),
+    ("iceberg", &["iceberg"]),
+    ("illuminating", &["illuminatingemerald"]),
+    ("imperial", &["imperialred"]),
+    ("inchworm", &["inchworm"]),
+    ("independence", &["independence"]),
+    ("india", &["indiagreen"]),
+    ("indian", &["indianred", "indianyellow"]),
+    ("indigo", &["indigo", "indigodye", "persianindigo"]),
+    ("international", &["internationalkleinblue", "internationalorangeengineering", "internationalorangegoldengatebridge"]),
+    ("irresistible", &["irresistible"]),
+    ("isabelline", &["isabelline"]),
+    ("italian", &["italianskyblue"]),
+    ("ivory", &["ivory"]),
+    ("jam", &["jazzberryjam"]),
+    ("japanese", &["japanesecarmine", "japaneseviolet"]),
+    ("jasmine", &["jasmine"]),
+    ("jazzberry", &["jazzberryjam"]),
+    ("jeans", &["bluejeans"]),
+    ("jet", &["jet"]),
+    ("jonquil", &["jonquil"]),
+    ("june", &["junebud"]),
+    ("jungle", &["darkjunglegreen", "deepjunglegreen", "junglegreen"]),
+    ("kelly", &["kellygreen"]),
+    ("keppel", &["keppel"]),
+    ("key", &["keylime"]),
+    ("khaki", &["darkkhaki", "khakiweb", "khakix11lightkhaki"]),
+    ("klein", &["internationalkleinblue"]),
+    ("kobe", &["kobe"]),
+    ("kobi", &["kobi"]),
+    ("kobicha", &["kobicha"]),
+    ("ksu", &["ksupurple"]),
+    ("lace", &["oldlace", "pinklace"]),
+    ("lait", &["cafeaulait"]),
+    ("lake", &["madderlake", "permanentgeraniumlake"]),
+    ("languid", &["languidlavender"]),
+    ("lapis", &["lapislazuli"]),
+    ("laser", &["laserlemon"]),
+    ("latte", &["cosmiclatte"]),
+    ("laurel", &["laurelgreen"]),
+    ("lava", &["darklava", "lava"]),
+    ("lavender", &["englishlavender", "languidlavender", "lavenderfloral", "lavenderweb", "lavenderblue", "lavenderblush", "lavendergray", "oldlavender", "pinklavender", "twilightlavender"]),
+    ("lawn", &["lawngreen"]),
+    ("lazuli", &["lapislazuli"]),
+    ("lemon", &["bitterlemon", "laserlemon", "lemon", "lemonchiffon", "lemoncurry", "lemonglacier", "lemonmeringue", "lemonyellow", "lemonyellowcrayola"]),
+    ("liberty", &["liberty"]),
+    ("light", &["khakix11lightkhaki", "lightblue", "lightcoral", "lightcornflowerblue", "lightcyan", "lightfrenchbeige", "lightgoldenrodyellow", "lightgray", "lightgreen", "lightorange", "lightperiwinkle", "lightpink", "lightpurple", "lightsalmon", "lightseagreen", "lightskyblue", "lightslategray", "lightsteelblue", "lightyellow", "royalbluelight"]),
+    ("lilac", &["brightlilac", "frenchlilac", "lilac", "lilacluster"]),
+    ("lime", &["arcticlime", "electriclime", "frenchlime", "keylime", "limecolorwheel", "limewebx11green", "limegreen", "stronglimegreen"]),
+    ("lincoln", &["lincolngreen"]),
+    ("linen", &["linen"]),
+    ("lion", &["lion"]),
+    ("lisa", &["monalisa"]),
+    ("liseran", &["liseranpurple"]),
+    ("little", &["littleboyblue"]),
+    ("liver", &["darkliverhorses", "liver", "liverdogs", "liverorgan", "liverchestnut"]),
+    ("livid", &["livid"]),
+    ("lizard", &["greenlizard"]),
+    ("luster", &["lilacluster"]),
+    ("m", &["carminemp"]),
+    ("macaroni", &["macaroniandcheese"]),
+    ("madder", &["madderlake", "rosemadder"]),
+    ("magenta", &["darkmagenta", "hotmagentabrokenanchor", "magenta", "magentacrayolabrokenanchor", "magentadye", "magentapantone", "magentaprocess", "magentahaze", "quinacridonemagenta", "skymagenta"]),
+    ("magic", &["magicmint"]),
+    ("magnolia", &["magnolia"]),
+    ("mahogany", &["mahogany"]),
+    ("maize", &["maize", "maizecrayola"]),
+    ("majesty", &["purplemountainmajesty"]),
+    ("majorelle", &["majorelleblue"]),
+    ("malachite", &["malachite"]),
+    ("manatee", &["manatee"]),
+    ("mandarin", &["mandarin"]),
+    ("mango", &["mango", "mangotango"]),
+    ("mania", &["bananamania"]),
+    ("mantis", &["mantis"]),
+    ("mardi", &["mardigras"]),
+    ("marigold", &["marigold"]),
+    ("maroon", &["marooncrayola", "maroonweb", "maroonx11", "mysticmaroon", "upmaroon"]),
+    ("mauve", &["frenchmauve", "mauve", "mauvetaupe", "oldmauve", "operamauve"]),
+    ("mauvelous", &["mauvelous"]),
+    ("maximum", &["maximumblue", "maximumbluegreen", "maximumbluepurple", "maximumgreen", "maximumgreenyellow", "maximumpurple", "maximumred", "maximumredpurple", "maximumyellow", "maximumyellowred"]),
+    ("may", &["maygreen"]),
+    ("maya", &["mayablue"]),
+    ("me", &["ticklemepink"]),
+    ("meadow", &["mountainmeadow"]),
+    ("medium", &["mediumaquamarine", "mediumblue", "mediumcandyapplered", "mediumcarmine", "mediumchampagne", "mediumorchid", "mediumpurple", "mediumseagreen", "mediumslateblue", "mediumspringgreen", "mediumturquoise", "mediumvioletred"]),
+    ("mellow", &["mellowapricot", "mellowyellow"]),
+    ("melon", &["melon"]),
+    ("meringue", &["lemonmeringue"]),
+    ("metallic", &["goldmetallic", "metallicgold", "metallicseaweed", "metallicsunburst", "rocketmetallic", "silvermetallic"]),
+    ("mexican", &["mexicanpink"]),
+    ("middle", &["middleblue", "middlebluegreen", "middlebluepurple", "middlegreen", "middlegreenyellow", "middlegrey", "middlepurple", "middlered", "middleredpurple", "middleyellow", "middleyellowred"]),
+    ("midnight", &["midnight", "midnightblue", "midnightgreeneaglegreen"]),
+    ("mikado", &["mikadoyellow"]),
+    ("miller", &["bakermillerpink"]),
+    ("mimi", &["mimipink"]),
+    ("mindaro", &["mindaro"]),
+    ("ming", &["ming"]),
+    ("minion", &["minionyellow"]),
+    ("mint", &["magicmint", "mint", "mintcream", "mintgreen"]),
+    ("misty", &["mistymoss", "mistyrose"]),
+    ("moccasin", &["moccasin"]),
+    ("mode", &["modebeige"]),
+    ("mona", &["monalisa"]),
+    ("morning", &["morningblue"]),
+    ("moss", &["mistymoss", "mossgreen"]),
+    ("mountain", &["mountainmeadow", "purplemountainmajesty"]),
+    ("mountbatten", &["mountbattenpink"]),
+    ("msu", &["msugreen"]),
+    ("mulberry", &["mulberry", "mulberrycrayola"]),
+    ("munsell", &["bluemunsell", "greenmunsell", "purplemunsell", "redmunsell", "yellowmunsell"]),
+    ("mustard", &["mustard"]),
+    ("myrtle", &["myrtlegreen"]),
+    ("mystic", &["mystic", "mysticmaroon"]),
+    ("nadeshiko", &["nadeshikopink"]),
+    ("naples", &["naplesyellow"]),
+    ("nations", &["unitednationsblue"]),
+    ("navajo", &["navajowhite"]),
+    ("navy", &["navyblue", "navybluecrayola", "purplenavy"]),
+    ("ncs", &["bluencs", "greenncs", "redncs", "yellowncs"]),
+    ("neon", &["neonblue", "neonfuchsia", "neongreen"]),
+    ("new", &["newcar", "newyorkpink"]),
+    ("nickel", &["nickel"]),
+    ("noir", &["cafenoir"]),
+    ("non", &["nonphotoblue"]),
+    ("nyanza", &["nyanza"]),
+    ("o", &["bigdiporuby"]),
+    ("ochre", &["ochre", "redochre2"]),
+    ("of", &["universityofpennsylvaniared"]),
+    ("off", &["blastoffbronze"]),
+    ("old", &["oldburgundy", "oldgold", "oldlace", "oldlavender", "oldmauve", "oldrose", "oldsilver"]),
+    ("olive", &["blackolive", "darkolivegreen", "olive", "olivedrab7", "olivedrab3", "olivegreen"]),
+    ("olivine", &["olivine"]),
+    ("onyx", &["onyx"]),
+    ("opal", &["opal"]),
+    ("opera", &["operamauve"]),
+    ("orange", &["alloyorange", "burntorange", "cadmiumorange", "carrotorange", "darkorange", "internationalorangeengineering", "internationalorangegoldengatebridge", "lightorange", "orange", "orangecrayola", "orangepantone", "orangeweb", "orangepeel", "orangesoda", "orangered", "orangeredcrayola", "orangeyellow", "orangeyellowcrayola", "outrageousorange", "persianorange", "portlandorange", "princetonorange", "redorange", "redorangecolorwheel", "redorangecrayola", "safetyorange", "safetyorangeblazeorange", "spanishorange", "syracuseorange", "tartorange", "yelloworange", "yelloworangecolorwheel"]),
+    ("orchid", &["darkorchid", "mediumorchid", "orchid", "orchidcrayola", "orchidpink", "wildorchid"]),
+    ("organ", &["liverorgan"]),
+    ("ou", &["oucrimsonred"]),
+    ("outer", &["outerspacecrayola"]),
+    ("outrageous", &["outrageousorange"]),
+    ("oxblood", &["oxblood"]),
+    ("oxford", &["oxfordblue"]),
+    ("p", &["carminemp"]),
+    ("pacific", &["pacificblue"]),
+    ("pakistan", &["pakistangreen"]),
+    ("palatinate", &["palatinatepurple"]),
+    ("pale", &["paleaqua", "palecerulean", "paledogwood", "palepink", "palepurplepantone", "palespringbud"]),
+    ("pansy", &["pansypurple"]),
+    ("pantone", &["bluepantone", "greenpantone", "magentapantone", "orangepantone", "palepurplepantone", "pinkpantone", "redpantone", "yellowpantone"]),
+    ("paolo", &["paoloveronesegreen"]),
+    ("papaya", &["papayawhip"]),
+    ("paradise", &["paradisepink"]),
+    ("parchment", &["parchment"]),
+    ("paris", &["parisgreen"]),
+    ("pastel", &["pastelpink"]),
+    ("patriarch", &["patriarch"]),
+    ("patrick", &["stpatricksblue"]),
+    ("paua", &["paua"]),
+    ("payne", &["paynesgrey"]),
+    ("peach", &["peach", "peachcrayola", "peachpuff"]),
+    ("pear", &["pear"]),
+    ("pearl", &["culturedpearl"]),
+    ("pearly", &["pearlypurple"]),
+    ("peel", &["orangepeel"]),
+    ("pennsylvania", &["universityofpennsylvaniared"]),
+    ("penny", &["copperpenny"]),
+    ("perbang", &["violetredperbang"]),
+    ("periwinkle", &["lightperiwinkle", "periwinkle", "periwinklecrayola"]),
+    ("permanent", &["permanentgeraniumlake"]),
+    ("persian", &["persianblue", "persiangreen", "persianindigo", "persianorange", "persianpink", "persianplum", "persianred", "persianrose"]),
+    ("persimmon", &["persimmon"]),
+    ("petunia", &["petunia"]),
+    ("pewter", &["pewterblue"]),
+    ("phlox", &["phlox"]),
+    ("photo", &["nonphotoblue"]),
+    ("phthalo", &["phthaloblue", "phthalogreen"]),
+    ("picotee", &["picoteeblue"]),
+    ("pictorial", &["pictorialcarmine"]),
+    ("piggy", &["piggypink"]),
+    ("pigment", &["bluepigment", "greenpigment", "redpigment"]),
+    ("pine", &["pinegreen", "pinegreen2", "polishedpine"]),
+    ("pink", &["amaranthpink", "babypink", "bakermillerpink", "barbiepink", "cameopink", "candypink", "carnationpink", "champagnepink", "charmpink", "cherryblossompink", "chinapink", "congopink", "coralpink", "deeppink", "fandangopink", "frenchpink", "hotpink", "lightpink", "mexicanpink", "mimipink", "mountbattenpink", "nadeshikopink", "newyorkpink", "orchidpink", "palepink", "paradisepink", "pastelpink", "persianpink", "piggypink", "pink", "pinkpantone", "pinklace", "pinklavender", "pinksherbet", "queenpink", "rosepink", "salmonpink", "schausspink", "shockingpink", "shockingpinkcrayola", "silverpink", "solidpink", "spanishpink", "steelpink", "superpink", "tangopink", "thulianpink", "ticklemepink", "ultrapink"]),
+    ("pistachio", &["pistachio"]),
+    ("pizzazz", &["purplepizzazzbrokenanchor"]),
+    ("platinum", &["platinum"]),
+    ("plum", &["persianplum", "plum", "plumweb", "purpleplum", "sugarplum"]),
+    ("plump", &["plumppurple"]),
+    ("polished", &["polishedpine"]),
+    ("pomp", &["pompandpower"]),
+    ("pompadour", &["rosepompadour"]),
+    ("poppy", &["goldenpoppy"]),
+    ("popstar", &["popstar"]),
+    ("portland", &["portlandorange"]),
+    ("powder", &["babypowder", "powderblue"]),
+    ("power", &["pompandpower"]),
+    ("prairie", &["prairiegold"]),
+    ("princeton", &["princetonorange"]),
+    ("process", &["cyanprocess", "magentaprocess", "yellowprocess"]),
+    ("prune", &["prune"]),
+    ("prussian", &["prussianblue"]),
+    ("psychedelic", &["psychedelicpurple"]),
+    ("puce", &["puce"]),
+    ("puff", &["peachpuff"]),
+    ("pullman", &["pullmanbrownupsbrown"]),
+    ("pumpkin", &["pumpkin"]),
+    ("purple", &["amaranthdeeppurple", "amaranthpurple", "darkpurple", "electricpurple", "ksupurple", "lightpurple", "liseranpurple", "maximumbluepurple", "maximumpurple", "maximumredpurple", "mediumpurple", "middlebluepurple", "middlepurple", "middleredpurple", "palatinatepurple", "palepurplepantone", "pansypurple", "pearlypurple", "plumppurple", "psychedelicpurple", "purple", "purplemunsell", "purpleweb", "purplex11", "purplemountainmajesty", "purplenavy", "purplepizzazzbrokenanchor", "purpleplum", "rebeccapurple", "redpurple", "royalpurple", "tyrianpurple"]),
+    ("queen", &["queenblue", "queenpink"]),
+    ("quick", &["quicksilver"]),
+    ("quinacridone", &["quinacridonemagenta"]),
+    ("racing", &["britishracinggreen"]),
+    ("radical", &["radicalred"]),
+    ("rainforest", &["tropicalrainforest"]),
+    ("raisin", &["raisinblack"]),
+    ("rajah", &["rajah"]),
+    ("raspberry", &["frenchraspberry", "raspberry", "raspberryglace", "raspberryrose"]),
+    ("raw", &["rawsienna", "rawumber"]),
+    ("razzle", &["razzledazzlerose"]),
+    ("razzmatazz", &["razzmatazz"]),
+    ("razzmic", &["razzmicberry"]),
+    ("rebecca", &["rebeccapurple"]),
+    ("red", &["barnred", "bloodred", "brickred", "chilired", "chinesered", "copperred", "darkred", "englishred", "falured", "fireenginered", "imperialred", "indianred", "maximumred", "maximumredpurple", "maximumyellowred", "mediumcandyapplered", "mediumvioletred", "middlered", "middleredpurple", "middleyellowred", "orangered", "orangeredcrayola", "oucrimsonred", "persianred", "radicalred", "red", "redcrayola", "redmunsell", "redncs", "redpantone", "redpigment", "redryb", "redochre2", "redsalsa", "redorange", "redorangecolorwheel", "redorangecrayola", "redpurple", "redviolet", "redvioletcolorwheel", "redvioletcrayola", "rojospanishred", "rosered", "rubinered", "rubyred", "rustyred", "sizzlingred", "spanishred", "tuscanred", "uared", "ultrared", "universityofpennsylvaniared", "upsdellred", "venetianred", "violetred", "violetredperbang"]),
+    ("redwood", &["redwood"]),
+    ("resolution", &["resolutionblue"]),
+    ("rgb", &["ceruleanrgb"]),
+    ("rhythm", &["rhythm"]),
+    ("rich", &["richblack", "richblackfogra29", "richblackfogra39"]),
+    ("rifle", &["riflegreen"]),
+    ("robin", &["robineggblue"]),
+    ("rocket", &["rocketmetallic"]),
+    ("rojo", &["rojospanishred"]),
+    ("roman", &["romansilver"]),
+    ("rose", &["copperrose", "fieryrose", "mistyrose", "oldrose", "persianrose", "raspberryrose", "razzledazzlerose", "rose", "rosebonbon", "rosedust", "roseebony", "rosemadder", "rosepink", "rosepompadour", "rosered", "rosetaupe", "rosevale", "tearose"]),
+    ("rosewood", &["rosewood"]),
+    ("rosso", &["rossocorsa"]),
+    ("rosy", &["rosybrown"]),
+    ("royal", &["royalbluedark", "royalbluelight", "royalpurple", "royalyellow"]),
+    ("ruber", &["ruber"]),
+    ("rubine", &["rubinered"]),
+    ("ruby", &["antiqueruby", "bigdiporuby", "ruby", "rubyred"]),
+    ("rufous", &["rufous"]),
+    ("russet", &["russet"]),
+    ("russian", &["russiangreen", "russianviolet"]),
+    ("rust", &["rust"]),
+    ("rusty", &["rustyred"]),
+    ("ryb", &["redryb", "violetryb", "yellowryb"]),
+    ("s", &["davysgrey", "hookersgreen", "paynesgrey", "stpatricksblue"]),
+    ("sacramento", &["sacramentostategreen"]),
+    ("saddle", &["saddlebrown"]),
+    ("safety", &["safetyorange", "safetyorangeblazeorange", "safetyyellow"]),
+    ("saffron", &["deepsaffron", "saffron"]),
+    ("sage", &["sage"]),
+    ("salmon", &["darksalmon", "lightsalmon", "salmon", "salmonpink"]),
+    ("salsa", &["redsalsa"]),
+    ("sand", &["desertsand", "sand", "sanddune", "silversand"]),
+    ("sandy", &["sandybrown"]),
+    ("sap", &["sapgreen"]),
+    ("sapphire", &["bluesapphire", "sapphire", "sapphirecrayola", "sapphireblue"]),
+    ("satin", &["cinnamonsatin", "satinsheengold"]),
+    ("scarlet", &["scarlet"]),
+    ("schauss", &["schausspink"]),
+    ("school", &["schoolbusyellow"]),
+    ("screamin", &["screamingreen"]),
+    ("sea", &["darkseagreen", "lightseagreen", "mediumseagreen", "seagreen", "seagreencrayola"]),
+    ("seal", &["sealbrown"]),
+    ("seance", &["seance"]),
+    ("seashell", &["seashell"]),
+    ("seaweed", &["metallicseaweed"]),
+    ("secret", &["secret"]),
+    ("selective", &["selectiveyellow"]),
+    ("sepia", &["sepia"]),
+    ("shadow", &["shadow", "shadowblue"]),
+    ("shadows", &["blackshadows"]),
+    ("shamrock", &["shamrockgreen", "shinyshamrock"]),
+    ("sheen", &["greensheen", "satinsheengold", "sheengreen"]),
+    ("sherbet", &["pinksherbet"]),
+    ("shimmering", &["shimmeringblush"]),
+    ("shiny", &["shinyshamrock"]),
+    ("shocking", &["shockingpink", "shockingpinkcrayola"]),
+    ("sienna", &["burntsienna", "darksienna", "rawsienna", "sienna"]),
+    ("silk", &["unbleachedsilk"]),
+    ("silver", &["oldsilver", "quicksilver", "romansilver", "silver", "silvercrayola", "silvermetallic", "silverchalice", "silverpink", "silversand", "sonicsilver"]),
+    ("sinopia", &["sinopia"]),
+    ("sizzling", &["sizzlingred", "sizzlingsunrise"]),
+    ("skin", &["skincolor"]),
+    ("skobeloff", &["skobeloff"]),
+    ("sky", &["darkskyblue", "deepskyblue", "frenchskyblue", "italianskyblue", "lightskyblue", "skyblue", "skybluecrayola", "skymagenta", "spanishskyblue", "vividskyblue", "wintersky"]),
+    ("slate", &["darkslateblue", "darkslategray", "lightslategray", "mediumslateblue", "slateblue", "slategray"]),
+    ("slimy", &["slimygreen"]),
+    ("smith", &["grannysmithapple"]),
+    ("smitten", &["smitten"]),
+    ("smoky", &["smokyblack"]),
+    ("snow", &["snow"]),
+    ("soda", &["orangesoda"]),
+    ("solid", &["solidpink"]),
+    ("sonic", &["sonicsilver"]),
+    ("space", &["deepspacesparkle", "outerspacecrayola", "spacecadet"]),
+    ("spanish", &["rojospanishred", "spanishbistre", "spanishblue", "spanishcarmine", "spanishgray", "spanishgreen", "spanishorange", "spanishpink", "spanishred", "spanishskyblue", "spanishviolet", "spanishviridian"]),
+    ("sparkle", &["deepspacesparkle"]),
+    ("spring", &["darkspringgreen", "mediumspringgreen", "palespringbud", "springbud", "springfrost", "springgreen", "springgreencrayola"]),
+    ("st", &["stpatricksblue"]),
+    ("star", &["starcommandblue"]),
+    ("state", &["sacramentostategreen"]),
+    ("steel", &["lightsteelblue", "steelblue", "steelpink"]),
+    ("stil", &["stildegrainyellow"]),
+    ("straw", &["straw"]),
+    ("strawberry", &["strawberry", "strawberryblonde", "wildstrawberry"]),
+    ("strong", &["stronglimegreen"]),
+    ("sugar", &["brownsugar", "sugarplum"]),
+    ("sunburst", &["metallicsunburst"]),
+    ("sunglow", &["sunglow"]),
+    ("sunray", &["sunray"]),
+    ("sunrise", &["sizzlingsunrise"]),
+    ("sunset", &["sunset"]),
+    ("sunshine", &["yellowsunshine"]),
+    ("super", &["superpink"]),
+    ("superiority", &["airsuperiorityblue"]),
+    ("sweet", &["sweetbrown"]),
+    ("syracuse", &["syracuseorange"]),
+    ("tan", &["tan", "tancrayola", "tuscantan", "windsortan"]),
+    ("tangerine", &["atomictangerine", "tangerine", "vividtangerine"]),
+    ("tango", &["mangotango", "tangopink"]),
+    ("tart", &["tartorange"]),
+    ("taupe", &["deeptaupe", "mauvetaupe", "rosetaupe", "taupe", "taupegray"]),
+    ("tawny", &["tennetawny"]),
+    ("tea", &["teagreen", "tearose"]),
+    ("teal", &["teal", "tealblue"]),
+    ("technobotanica", &["technobotanica"]),
+    ("telemagenta", &["telemagenta"]),
+    ("tenne", &["tennetawny"]),
+    ("terra", &["terracotta"]),
+    ("thistle", &["thistle"]),
+    ("thulian", &["thulianpink"]),
+    ("tickle", &["ticklemepink"]),
+    ("tiffany", &["tiffanyblue"]),
+    ("timberwolf", &["timberwolf"]),
+    ("titanium", &["titaniumyellow"]),
+    ("tomato", &["tomato"]),
+    ("tourmaline", &["tourmaline"]),
+    ("traditional", &["chocolatetraditional"]),
+    ("tropical", &["tropicalrainforest"]),
+    ("true", &["trueblue"]),
+    ("trypan", &["trypanblue"]),
+    ("tufts", &["tuftsblue"]),
+    ("tumbleweed", &["tumbleweed"]),
+    ("turquoise", &["darkturquoise", "mediumturquoise", "turquoise", "turquoiseblue", "turquoisegreen"]),
+    ("turtle", &["turtlegreen"]),
+    ("tuscan", &["tuscan", "tuscanbrown", "tuscanred", "tuscantan"]),
+    ("tuscany", &["tuscany"]),
+    ("twilight", &["twilightlavender"]),
+    ("tyrian", &["tyrianpurple"]),
+    ("ua", &["crimsonua", "uablue", "uared"]),
+    ("ultra", &["ultrapink", "ultrared"]),
+    ("ultramarine", &["ultramarine", "ultramarineblue"]),
+    ("umber", &["burntumber", "rawumber", "umber"]),
+    ("unbleached", &["unbleachedsilk"]),
+    ("united", &["unitednationsblue"]),
+    ("university", &["universityofpennsylvaniared"]),
+    ("unmellow", &["unmellowyellow"]),
+    ("up", &["upforestgreen", "upmaroon"]),
+    ("ups", &["pullmanbrownupsbrown"]),
+    ("upsdell", &["upsdellred"]),
+    ("uranian", &["uranianblue"]),
+    ("usafa", &["usafablue"]),
+    ("vale", &["rosevale"]),
+    ("van", &["vandykebrown"]),
+    ("vanilla", &["vanilla", "vanillaice"]),
+    ("vantg", &["vantgblue"]),
+    ("vegas", &["vegasgold"]),
+    ("venetian", &["venetianred"]),
+    ("verdigris", &["verdigris"]),
+    ("vermilion", &["vermilion", "vermilion2"]),
+    ("vermillion", &["englishvermillion"]),
+    ("veronese", &["paoloveronesegreen"]),
+    ("veronica", &["veronica"]),
+    ("violet", &["africanviolet", "blueviolet", "chineseviolet", "darkviolet", "electricviolet", "englishviolet", "frenchviolet", "japaneseviolet", "mediumvioletred", "redviolet", "redvioletcolorwheel", "redvioletcrayola", "russianviolet", "spanishviolet", "violet", "violetcolorwheel", "violetcrayola", "violetryb", "violetweb", "violetblue", "violetbluecrayola", "violetred", "violetredperbang", "vividviolet"]),
+    ("viridian", &["genericviridian", "spanishviridian", "viridian", "viridiangreen"]),
+    ("vivid", &["vividburgundy", "vividskyblue", "vividtangerine", "vividviolet"]),
+    ("volt", &["volt"]),
+    ("warm", &["warmblack"]),
+    ("watermelon", &["wildwatermelon"]),
+    ("wave", &["heatwave"]),
+    ("web", &["azurex11webcolor", "chartreuseweb", "chocolateweb", "forestgreenweb", "goldwebgolden", "grayweb", "greenweb", "khakiweb", "lavenderweb", "limewebx11green", "maroonweb", "orangeweb", "plumweb", "purpleweb", "violetweb"]),
+    ("weezy", &["weezyblue"]),
+    ("wheat", &["wheat"]),
+    ("wheel", &["limecolorwheel", "redorangecolorwheel", "redvioletcolorwheel", "violetcolorwheel", "yelloworangecolorwheel", "yellowgreencolorwheel"]),
+    ("whip", &["papayawhip"]),
+    ("white", &["antiquewhite", "dutchwhite", "floralwhite", "ghostwhite", "navajowhite", "white"]),
+    ("wild", &["wildblueyonder", "wildorchid", "wildstrawberry", "wildwatermelon"]),
+    ("windsor", &["windsortan"]),
+    ("wine", &["wine", "winedregs"]),
+    ("winter", &["wintersky"]),
+    ("wintergreen", &["wintergreendream"]),
+    ("wisteria", &["wisteria"]),
+    ("wood", &["woodbrown"]),
+    ("wuzzy", &["fuzzywuzzy"]),
+    ("x11", &["azurex11webcolor", "darkgreenx11", "grayx11gray", "khakix11lightkhaki", "limewebx11green", "maroonx11", "purplex11"]),
+    ("xanadu", &["xanadu"]),
+    ("xanthic", &["xanthic"]),
+    ("xanthous", &["xanthous"]),
+    ("yale", &["yaleblue"]),
+    ("yellow", &["arylideyellow", "brightyellowcrayola", "canaryyellow", "chineseyellow", "cyberyellow", "goldenyellow", "hansayellow", "indianyellow", "lemonyellow", "lemonyellowcrayola", "lightgoldenrodyellow", "lightyellow", "maximumgreenyellow", "maximumyellow", "maximumyellowred", "mellowyellow", "middlegreenyellow", "middleyellow", "middleyellowred", "mikadoyellow", "minionyellow", "naplesyellow", "orangeyellow", "orangeyellowcrayola", "royalyellow", "safetyyellow", "schoolbusyellow", "selectiveyellow", "stildegrainyellow", "titaniumyellow", "unmellowyellow", "yellow", "yellowcrayola", "yellowmunsell", "yellowncs", "yellowpantone", "yellowprocess", "yellowryb", "yelloworange", "yelloworangecolorwheel", "yellowsunshine", "yellowgreen", "yellowgreencolorwheel", "yellowgreencrayola"]),
+    ("yinmn", &["yinmnblue"]),
+    ("yonder", &["blueyonder", "wildblueyonder"]),
+    ("york", &["newyorkpink"]),
+    ("zaffre", &["zaffre"]),
+    ("zero", &["absolutezero"]),
+    ("zinnwaldite", &["zinnwalditebrown"]),
+    ("zomp", &["zomp"]),
+];
+
+/// `COLORS` keys of each hue family, in `Family` order, each in display-name order.
+pub(crate) static COLORS_FAMILIES: [&[&str]; 10] = [
+    &["alizarin", "amaranthpurple", "bigdiporuby", "blastoffbronze", "brickred", "burnishedbrown", "burntsienna", "candypink", "cardinal", "carmine", "carminemp", "carnelian", "catawba", "cedarchest", "chilired", "chinesered", "cinnabar", "claret", "congopink", "copperpenny", "copperred", "copperrose", "coquelicot", "coralpink", "crimson", "darksalmon", "deepchestnut", "englishred", "englishvermillion", "fireenginered", "firebrick", "flame", "frenchraspberry", "imperialred", "indianred", "internationalorangeengineering", "internationalorangegoldengatebridge", "lava", "lightcoral", "madderlake", "mahogany", "maximumred", "mediumcandyapplered", "mediumcarmine", "middlered", "middleredpurple", "monalisa", "neonfuchsia", "newyorkpink", "oldrose", "orangepantone", "orangesoda", "orangeredcrayola", "outrageousorange", "pastelpink", "permanentgeraniumlake", "persianred", "portlandorange", "radicalred", "red", "redcrayola", "redmunsell", "redncs", "redpantone", "redpigment", "redryb", "redsalsa", "redorange", "redorangecolorwheel", "redvioletcolorwheel", "redwood", "rojospanishred", "rosemadder", "rosetaupe", "rosevale", "rossocorsa", "rosybrown", "rufous", "rust", "rustyred", "salmon", "scarlet", "sinopia", "sizzlingred", "solidpink", "spanishred", "strawberry", "sweetbrown", "syracuseorange", "tangopink", "tartorange", "terracotta", "tomato", "tuscany", "universityofpennsylvaniared", "upsdellred", "venetianred", "vermilion", "vermilion2"],
+    &["almond", "antiquebrass", "apricot", "atomictangerine", "bisque", "brightyellowcrayola", "bronze", "buff", "burlywood", "cadmiumorange", "camel", "carrotorange", "coppercrayola", "coral", "darkorange", "deepsaffron", "desert", "desertsand", "fallow", "fawn", "fulvous", "gamboge", "goldcrayola", "harvestgold", "heatwave", "indianyellow", "lightorange", "lightsalmon", "macaroniandcheese", "mandarin", "mangotango", "mellowapricot", "middleyellowred", "orange", "orangecrayola", "orangeweb", "orangepeel", "orangered", "peachcrayola", "peachpuff", "persianorange", "princetonorange", "pumpkin", "rajah", "rawsienna", "redorangecrayola", "safetyorange", "safetyorangeblazeorange", "sandybrown", "spanishorange", "strawberryblonde", "tan", "tancrayola", "tangerine", "tumbleweed", "unbleachedsilk", "woodbrown", "yelloworange", "yelloworangecolorwheel"],
+    &["acidgreen", "amber", "antiquebronze", "arylideyellow", "aureolin", "bananamania", "bitterlemon", "blanchedalmond", "canary", "canaryyellow", "champagne", "chineseyellow", "citrine", "citron", "corn", "cornsilk", "cream", "cyberyellow", "dandelion", "darkgoldenrod", "darkkhaki", "deepchampagne", "dutchwhite", "ecru", "flax", "goldmetallic", "goldwebgolden", "goldenpoppy", "goldenyellow", "goldenrod", "hansayellow", "jasmine", "jonquil", "keylime", "khakiweb", "khakix11lightkhaki", "laserlemon", "lemon", "lemonchiffon", "lemoncurry", "lemonglacier", "lemonmeringue", "lemonyellow", "lemonyellowcrayola", "lightfrenchbeige", "lightgoldenrodyellow", "lightyellow", "lion", "maize", "maizecrayola", "mango", "marigold", "maximumgreenyellow", "maximumyellow", "maximumyellowred", "mediumchampagne", "mellowyellow", "metallicgold", "middlegreenyellow", "middleyellow", "mikadoyellow", "mindaro", "minionyellow", "mistymoss", "moccasin", "mustard", "naplesyellow", "navajowhite", "oldgold", "olive", "olivegreen", "orangeyellow", "orangeyellowcrayola", "palespringbud", "papayawhip", "peach", "pear", "prairiegold", "royalyellow", "safetyyellow", "saffron", "sage", "sand", "satinsheengold", "schoolbusyellow", "selectiveyellow", "sizzlingsunrise", "skincolor", "spanishbistre", "springgreencrayola", "stildegrainyellow", "straw", "sunglow", "sunray", "sunset", "titaniumyellow", "tuscan", "unmellowyellow", "vanilla", "vegasgold", "wheat", "xanthic", "xanthous", "yellow", "yellowcrayola", "yellowmunsell", "yellowncs", "yellowpantone", "yellowprocess", "yellowryb", "yellowsunshine"],
+    &["amazon", "androidgreen", "aquamarine", "arcticlime", "artichokegreen", "britishracinggreen", "budgreen", "cadmiumgreen", "cambridgeblue", "caribbeangreen", "celadon", "chartreuseweb", "darkgreenx11", "darkolivegreen", "darkseagreen", "darkspringgreen", "electriclime", "emerald", "erin", "etonblue", "ferngreen", "forestgreenweb", "frenchlime", "genericviridian", "gogreen", "gothamgreen", "grannysmithapple", "green", "greencrayola", "greenmunsell", "greenncs", "greenpantone", "greenpigment", "greenweb", "greenlizard", "harlequin", "hookersgreen", "huntergreen", "illuminatingemerald", "inchworm", "indiagreen", "junebud", "junglegreen", "kellygreen", "laurelgreen", "lawngreen", "lightgreen", "limecolorwheel", "limewebx11green", "limegreen", "lincolngreen", "magicmint", "malachite", "mantis", "maximumgreen", "maygreen", "mediumaquamarine", "mediumseagreen", "mediumspringgreen", "middlegreen", "mint", "mintgreen", "mossgreen", "mountainmeadow", "neongreen", "nyanza", "olivedrab3", "olivine", "pakistangreen", "paoloveronesegreen", "parisgreen", "phthalogreen", "pistachio", "russiangreen", "sacramentostategreen", "sapgreen", "screamingreen", "seagreen", "seagreencrayola", "shamrockgreen", "sheengreen", "shinyshamrock", "slimygreen", "spanishgreen", "spanishviridian", "springbud", "springfrost", "springgreen", "stronglimegreen", "teagreen", "technobotanica", "tropicalrainforest", "turquoisegreen", "turtlegreen", "upforestgreen", "viridian", "volt", "yellowgreen", "yellowgreencolorwheel", "yellowgreencrayola", "zomp"],
+    &["aqua", "babyblue", "blizzardblue", "bluemunsell", "cadetblue", "celeste", "ceruleancrayola", "cyan", "cyanprocess", "darkcyan", "darkskyblue", "darkslategray", "darkturquoise", "deepjunglegreen", "greensheen", "italianskyblue", "keppel", "lightblue", "lightseagreen", "maximumblue", "maximumbluegreen", "mediumturquoise", "metallicseaweed", "middleblue", "middlebluegreen", "midnightgreeneaglegreen", "ming", "msugreen", "myrtlegreen", "nonphotoblue", "pacificblue", "persiangreen", "pinegreen", "polishedpine", "powderblue", "richblack", "robineggblue", "skobeloff", "skyblue", "skybluecrayola", "spanishskyblue", "teal", "tealblue", "tiffanyblue", "turquoise", "turquoiseblue", "verdigris", "viridiangreen", "vividskyblue", "warmblack", "wintergreendream"],
+    &["absolutezero", "aero", "airsuperiorityblue", "azure", "bdazzledblue", "babyblueeyes", "beaublue", "bleudefrance", "blue", "bluecrayola", "bluencs", "bluepantone", "bluepigment", "bluebell", "bluejeans", "bluesapphire", "blueyonder", "bluegraycrayola", "bluetiful", "carolinablue", "cerulean", "ceruleanrgb", "ceruleanblue", "ceruleanfrost", "columbiablue", "coolgrey", "cornflowerblue", "cosmiccobalt", "darkelectricblue", "darkslateblue", "deepskyblue", "denim", "denimblue", "dodgerblue", "dukeblue", "frenchblue", "frenchskyblue", "glaucous", "greenblue", "honolulublue", "iceberg", "independence", "indigo", "indigodye", "internationalkleinblue", "lapislazuli", "lavenderblue", "liberty", "lightcornflowerblue", "lightskyblue", "lightsteelblue", "littleboyblue", "livid", "majorelleblue", "maximumbluepurple", "mayablue", "mediumblue", "mediumslateblue", "midnightblue", "navyblue", "navybluecrayola", "neonblue", "newcar", "oxfordblue", "palecerulean", "paua", "paynesgrey", "periwinkle", "periwinklecrayola", "persianblue", "persianindigo", "pewterblue", "phthaloblue", "picoteeblue", "plumppurple", "prussianblue", "purplenavy", "queenblue", "resolutionblue", "rhythm", "royalbluedark", "royalbluelight", "sapphire", "sapphirecrayola", "sapphireblue", "shadowblue", "slateblue", "spacecadet", "spanishblue", "stpatricksblue", "starcommandblue", "steelblue", "trueblue", "trypanblue", "tuftsblue", "uablue", "ultramarine", "ultramarineblue", "unitednationsblue", "uranianblue", "usafablue", "vantgblue", "violetblue", "violetbluecrayola", "weezyblue", "wildblueyonder", "yaleblue", "yinmnblue", "zaffre"],
+    &["africanviolet", "amaranthdeeppurple", "amethyst", "antiquefuchsia", "blueviolet", "brightlilac", "byzantine", "byzantium", "chineseviolet", "cybergrape", "darkbyzantium", "darkmagenta", "darkorchid", "darkpurple", "darkviolet", "eggplant", "electricpurple", "electricviolet", "eminence", "englishviolet", "finn", "flirt", "frenchlilac", "frenchmauve", "frenchviolet", "fuchsia", "fuchsiacrayola", "glossygrape", "heliotrope", "japaneseviolet", "jazzberryjam", "ksupurple", "lavenderfloral", "lightpurple", "lilac", "lilacluster", "magenta", "mardigras", "mauve", "maximumpurple", "mediumorchid", "mediumpurple", "middlebluepurple", "midnight", "oldmauve", "operamauve", "orchid", "orchidcrayola", "palatinatepurple", "pansypurple", "patriarch", "pearlypurple", "petunia", "phlox", "pinklace", "pinklavender", "plum", "plumweb", "pompandpower", "psychedelicpurple", "purple", "purplemunsell", "purpleweb", "purplex11", "purplemountainmajesty", "purplepizzazzbrokenanchor", "purpleplum", "quinacridonemagenta", "razzmicberry", "rebeccapurple", "royalpurple", "russianviolet", "seance", "secret", "shockingpinkcrayola", "spanishviolet", "steelpink", "thistle", "twilightlavender", "tyrianpurple", "ultrapink", "veronica", "violet", "violetcolorwheel", "violetcrayola", "violetryb", "violetweb", "vividviolet", "winedregs", "wisteria"],
+    &["amaranthpink", "babypink", "bakermillerpink", "barbiepink", "blush", "cameopink", "carnationpink", "cerise", "charmpink", "cherryblossompink", "chinapink", "cinnamonsatin", "cottoncandy", "cyclamen", "deepcerise", "deeppink", "deeptaupe", "englishlavender", "fandango", "fandangopink", "fieryrose", "frenchfuchsia", "frenchpink", "frostbite", "hollywoodcerise", "hotmagentabrokenanchor", "hotpink", "irresistible", "kobi", "lightpink", "liseranpurple", "magentacrayolabrokenanchor", "magentadye", "magentapantone", "magentaprocess", "magentahaze", "marooncrayola", "maroonx11", "mauvetaupe", "mauvelous", "maximumredpurple", "mediumvioletred", "melon", "mexicanpink", "middlepurple", "mimipink", "mountbattenpink", "mulberry", "mulberrycrayola", "mystic", "mysticmaroon", "nadeshikopink", "orchidpink", "paledogwood", "palepink", "paradisepink", "persianpink", "persianrose", "pictorialcarmine", "piggypink", "pink", "pinkpantone", "pinksherbet", "popstar", "puce", "raspberry", "raspberryglace", "raspberryrose", "razzledazzlerose", "razzmatazz", "redpurple", "redviolet", "redvioletcrayola", "rose", "rosebonbon", "rosedust", "rosepink", "rosepompadour", "rosered", "ruber", "rubinered", "ruby", "salmonpink", "schausspink", "shimmeringblush", "shockingpink", "skymagenta", "smitten", "spanishcarmine", "spanishpink", "sugarplum", "superpink", "tearose", "telemagenta", "thulianpink", "ticklemepink", "uared", "ultrared", "vanillaice", "violetred", "violetredperbang", "vividtangerine", "wildorchid", "wildstrawberry", "wildwatermelon", "wintersky"],
+    &["alloyorange", "antiqueruby", "barnred", "beaver", "bistrebrown", "blackbean", "bloodred", "bole", "brown", "brownsugar", "burgundy", "burntorange", "burntumber", "cafeaulait", "cafenoir", "chestnut", "chocolatetraditional", "chocolateweb", "coffee", "copper", "cordovan", "coyotebrown", "crimsonua", "darkbrown", "darkred", "darksienna", "drabdarkbrown", "falured", "fielddrab", "frenchbeige", "frenchbistre", "fuzzywuzzy", "goldfusion", "goldenbrown", "japanesecarmine", "kobe", "kobicha", "liver", "liverdogs", "liverorgan", "liverchestnut", "maroonweb", "metallicsunburst", "modebeige", "ochre", "olivedrab7", "oucrimsonred", "oxblood", "persianplum", "persimmon", "prune", "pullmanbrownupsbrown", "rawumber", "redochre2", "roseebony", "rosewood", "rubyred", "russet", "saddlebrown", "sanddune", "sealbrown", "sepia", "shadow", "sienna", "tennetawny", "tuscanbrown", "tuscanred", "tuscantan", "upmaroon", "vandykebrown", "vividburgundy", "windsortan", "wine", "zinnwalditebrown"],
+    &["aliceblue", "antiquewhite", "ashgray", "azurex11webcolor", "babypowder", "battleshipgrey", "beige", "bistre", "black", "blackcoral", "blackolive", "blackshadows", "bone", "cadetgrey", "champagnepink", "charcoal", "cinereous", "cosmiclatte", "culturedpearl", "darkjunglegreen", "darklava", "darkliverhorses", "davysgrey", "deepspacesparkle", "dimgray", "ebony", "eerieblack", "eggshell", "floralwhite", "gainsboro", "ghostwhite", "granitegray", "grayweb", "grayx11gray", "gunmetal", "heliotropegray", "isabelline", "ivory", "jet", "languidlavender", "lavenderweb", "lavenderblush", "lavendergray", "lightcyan", "lightgray", "lightperiwinkle", "lightslategray", "linen", "magnolia", "manatee", "middlegrey", "mintcream", "mistyrose", "morningblue", "nickel", "oldburgundy", "oldlace", "oldlavender", "oldsilver", "onyx", "opal", "outerspacecrayola", "paleaqua", "palepurplepantone", "parchment", "pinegreen2", "platinum", "queenpink", "quicksilver", "raisinblack", "richblackfogra29", "richblackfogra39", "riflegreen", "rocketmetallic", "romansilver", "seashell", "silver", "silvercrayola", "silvermetallic", "silverchalice", "silverpink", "silversand", "slategray", "smokyblack", "snow", "sonicsilver", "spanishgray", "taupe", "taupegray", "timberwolf", "tourmaline", "umber", "white", "xanadu"],
+];
+
+/// `COLORS_FAMILIES` split by `Lightness` and then `Chroma` band, each in display-name order.
+pub(crate) static COLORS_BANDS: [[[&[&str]; 3]; 3]; 10] = [
+    [
+        [&[], &["catawba", "claret"], &["carmine"]],
+        [&["blastoffbronze", "burnishedbrown", "copperrose", "oldrose", "rosetaupe", "rosybrown", "tuscany"], &["bigdiporuby", "burntsienna", "candypink", "cedarchest", "chinesered", "congopink", "copperpenny", "copperred", "coralpink", "deepchestnut", "englishred", "indianred", "lightcoral", "mediumcarmine", "middlered", "middleredpurple", "newyorkpink", "redvioletcolorwheel", "redwood", "rosevale", "salmon", "solidpink", "sweetbrown", "tangopink", "terracotta"], &["alizarin", "amaranthpurple", "brickred", "cardinal", "carminemp", "carnelian", "chilired", "cinnabar", "coquelicot", "crimson", "englishvermillion", "fireenginered", "firebrick", "flame", "frenchraspberry", "imperialred", "internationalorangeengineering", "internationalorangegoldengatebridge", "lava", "madderlake", "mahogany", "maximumred", "mediumcandyapplered", "neonfuchsia", "orangepantone", "orangesoda", "orangeredcrayola", "outrageousorange", "permanentgeraniumlake", "persianred", "portlandorange", "radicalred", "red", "redcrayola", "redmunsell", "redncs", "redpantone", "redpigment", "redryb", "redsalsa", "redorange", "redorangecolorwheel", "rojospanishred", "rosemadder", "rossocorsa", "rufous", "rust", "rustyred", "scarlet", "sinopia", "sizzlingred", "spanishred", "strawberry", "syracuseorange", "tartorange", "tomato", "universityofpennsylvaniared", "upsdellred", "venetianred", "vermilion", "vermilion2"]],
+        [&["pastelpink"], &["darksalmon", "monalisa"], &[]],
+    ],
+    [
+        [&[], &[], &[]],
+        [&["camel", "desert", "fallow", "woodbrown"], &["antiquebrass", "bronze", "cadmiumorange", "carrotorange", "coppercrayola", "gamboge", "harvestgold", "persianorange", "rawsienna", "tancrayola"], &["coral", "fulvous", "heatwave", "mandarin", "mangotango", "orange", "orangecrayola", "orangered", "princetonorange", "pumpkin", "redorangecrayola", "safetyorange", "safetyorangeblazeorange", "spanishorange", "tangerine"]],
+        [&["almond", "apricot", "bisque", "burlywood", "desertsand", "lightorange", "peachcrayola", "peachpuff", "tan", "tumbleweed", "unbleachedsilk"], &["atomictangerine", "buff", "fawn", "goldcrayola", "indianyellow", "lightsalmon", "macaroniandcheese", "mellowapricot", "middleyellowred", "rajah", "sandybrown", "strawberryblonde", "yelloworange"], &["brightyellowcrayola", "darkorange", "deepsaffron", "orangeweb", "orangepeel", "yelloworangecolorwheel"]],
+    ],
+    [
+        [&[], &[], &[]],
+        [&[], &["antiquebronze", "citron", "darkgoldenrod", "lemoncurry", "olive", "olivegreen", "satinsheengold", "spanishbistre"], &[]],
+        [&["bananamania", "blanchedalmond", "champagne", "cornsilk", "cream", "deepchampagne", "dutchwhite", "ecru", "khakiweb", "lemonchiffon", "lemonmeringue", "lightfrenchbeige", "lightgoldenrodyellow", "lightyellow", "lion", "mediumchampagne", "moccasin", "navajowhite", "palespringbud", "papayawhip", "peach", "sage", "sand", "skincolor", "springgreencrayola", "sunset", "tuscan", "vanilla", "wheat"], &["arylideyellow", "canary", "dandelion", "darkkhaki", "flax", "goldmetallic", "goldenrod", "hansayellow", "jasmine", "keylime", "khakix11lightkhaki", "lemonyellowcrayola", "maizecrayola", "marigold", "maximumyellowred", "mellowyellow", "metallicgold", "middlegreenyellow", "mindaro", "minionyellow", "mistymoss", "mustard", "naplesyellow", "oldgold", "orangeyellowcrayola", "prairiegold", "royalyellow", "saffron", "stildegrainyellow", "straw", "sunray", "vegasgold", "xanthous", "yellowcrayola"], &["acidgreen", "amber", "aureolin", "bitterlemon", "canaryyellow", "chineseyellow", "citrine", "corn", "cyberyellow", "goldwebgolden", "goldenpoppy", "goldenyellow", "jonquil", "laserlemon", "lemon", "lemonglacier", "lemonyellow", "maize", "mango", "maximumgreenyellow", "maximumyellow", "middleyellow", "mikadoyellow", "orangeyellow", "pear", "safetyyellow", "schoolbusyellow", "selectiveyellow", "sizzlingsunrise", "sunglow", "titaniumyellow", "unmellowyellow", "xanthic", "yellow", "yellowmunsell", "yellowncs", "yellowpantone", "yellowprocess", "yellowryb", "yellowsunshine"]],
+    ],
+    [
+        [&["britishracinggreen", "huntergreen", "phthalogreen", "sacramentostategreen"], &["darkgreenx11", "gothamgreen", "lincolngreen", "pakistangreen", "upforestgreen"], &[]],
+        [&["artichokegreen", "hookersgreen", "russiangreen", "viridian"], &["amazon", "budgreen", "cadmiumgreen", "caribbeangreen", "darkolivegreen", "darkspringgreen", "emerald", "ferngreen", "genericviridian", "gogreen", "greencrayola", "greenmunsell", "greenncs", "illuminatingemerald", "junglegreen", "mantis", "maximumgreen", "maygreen", "mediumseagreen", "middlegreen", "mint", "mossgreen", "mountainmeadow", "olivedrab3", "olivine", "paoloveronesegreen", "parisgreen", "sapgreen", "seagreen", "shamrockgreen", "shinyshamrock", "spanishgreen", "spanishviridian", "tropicalrainforest", "turtlegreen", "zomp"], &["forestgreenweb", "greenpantone", "greenpigment", "greenweb", "indiagreen", "kellygreen", "limegreen", "slimygreen", "stronglimegreen", "yellowgreencolorwheel"]],
+        [&["cambridgeblue", "darkseagreen", "etonblue", "laurelgreen", "nyanza", "teagreen", "turquoisegreen"], &["aquamarine", "celadon", "grannysmithapple", "junebud", "lightgreen", "magicmint", "mediumaquamarine", "pistachio", "yellowgreencrayola"], &["androidgreen", "arcticlime", "chartreuseweb", "electriclime", "erin", "frenchlime", "green", "greenlizard", "harlequin", "inchworm", "lawngreen", "limecolorwheel", "limewebx11green", "malachite", "mediumspringgreen", "mintgreen", "neongreen", "screamingreen", "seagreencrayola", "sheengreen", "springbud", "springfrost", "springgreen", "technobotanica", "volt", "yellowgreen"]],
+    ],
+    [
+        [&["darkslategray", "deepjunglegreen", "midnightgreeneaglegreen", "msugreen", "richblack", "warmblack"], &[], &[]],
+        [&["cadetblue", "greensheen", "ming", "myrtlegreen", "polishedpine", "tealblue", "wintergreendream"], &["bluemunsell", "ceruleancrayola", "cyanprocess", "darkcyan", "keppel", "lightseagreen", "maximumblue", "maximumbluegreen", "metallicseaweed", "pacificblue", "persiangreen", "pinegreen", "skobeloff", "teal", "tiffanyblue", "verdigris", "viridiangreen"], &[]],
+        [&["blizzardblue", "celeste", "darkskyblue", "italianskyblue", "lightblue", "middlebluegreen", "nonphotoblue", "powderblue"], &["aqua", "babyblue", "cyan", "darkturquoise", "mediumturquoise", "middleblue", "robineggblue", "skyblue", "skybluecrayola", "spanishskyblue", "turquoise", "turquoiseblue", "vividskyblue"], &[]],
+    ],
+    [
+        [&["independence", "prussianblue", "spacecadet"], &["cosmiccobalt", "darkslateblue", "indigodye", "midnightblue", "oxfordblue", "paua", "persianindigo", "picoteeblue", "royalbluedark", "stpatricksblue", "usafablue", "yaleblue", "yinmnblue"], &["absolutezero", "bluepantone", "bluepigment", "denimblue", "dukeblue", "internationalkleinblue", "mediumblue", "navyblue", "persianblue", "phthaloblue", "resolutionblue", "trypanblue", "uablue", "zaffre"]],
+        [&["airsuperiorityblue", "bluebell", "ceruleanfrost", "coolgrey", "darkelectricblue", "paynesgrey", "pewterblue", "purplenavy", "rhythm", "shadowblue"], &["bdazzledblue", "bluencs", "bluejeans", "bluesapphire", "blueyonder", "bluegraycrayola", "carolinablue", "cerulean", "cornflowerblue", "frenchblue", "glaucous", "greenblue", "honolulublue", "iceberg", "lapislazuli", "liberty", "littleboyblue", "livid", "queenblue", "sapphirecrayola", "sapphireblue", "spanishblue", "starcommandblue", "steelblue", "trueblue", "tuftsblue", "unitednationsblue", "violetbluecrayola", "weezyblue"], &["azure", "bleudefrance", "blue", "bluecrayola", "bluetiful", "ceruleanrgb", "ceruleanblue", "denim", "dodgerblue", "indigo", "majorelleblue", "mediumslateblue", "navybluecrayola", "neonblue", "newcar", "plumppurple", "royalbluelight", "sapphire", "slateblue", "ultramarine", "ultramarineblue", "vantgblue", "violetblue"]],
+        [&["babyblueeyes", "beaublue", "columbiablue", "lavenderblue", "lightcornflowerblue", "lightsteelblue", "palecerulean", "periwinkle", "periwinklecrayola", "uranianblue", "wildblueyonder"], &["aero", "deepskyblue", "frenchskyblue", "lightskyblue", "maximumbluepurple", "mayablue"], &[]],
+    ],
+    [
+        [&["darkbyzantium", "darkpurple", "eggplant", "englishviolet", "japaneseviolet"], &["byzantium", "cybergrape", "eminence", "finn", "ksupurple", "maximumpurple", "midnight", "oldmauve", "palatinatepurple", "pansypurple", "petunia", "russianviolet", "spanishviolet", "tyrianpurple", "winedregs"], &["darkmagenta", "mardigras", "patriarch", "purple", "purpleweb", "rebeccapurple", "seance"]],
+        [&["chineseviolet", "glossygrape", "lilacluster", "operamauve"], &["africanviolet", "amethyst", "antiquefuchsia", "frenchlilac", "lavenderfloral", "mediumpurple", "middlebluepurple", "pearlypurple", "plum", "pompandpower", "purplemountainmajesty", "quinacridonemagenta", "razzmicberry", "royalpurple", "secret", "twilightlavender", "violetcrayola"], &["amaranthdeeppurple", "blueviolet", "byzantine", "darkorchid", "darkviolet", "electricpurple", "electricviolet", "flirt", "frenchmauve", "frenchviolet", "fuchsia", "fuchsiacrayola", "heliotrope", "jazzberryjam", "magenta", "mediumorchid", "orchid", "phlox", "psychedelicpurple", "purplemunsell", "purplex11", "purplepizzazzbrokenanchor", "purpleplum", "steelpink", "veronica", "violet", "violetcolorwheel", "violetryb", "vividviolet"]],
+        [&["lightpurple", "lilac", "pinklace", "pinklavender", "thistle"], &["brightlilac", "mauve", "orchidcrayola", "plumweb", "wisteria"], &["shockingpinkcrayola", "ultrapink", "violetweb"]],
+    ],
+    [
+        [&[], &[], &[]],
+        [&["deeptaupe", "englishlavender", "mauvetaupe", "mountbattenpink", "raspberryglace"], &["charmpink", "chinapink", "cinnamonsatin", "irresistible", "liseranpurple", "magentahaze", "maximumredpurple", "middlepurple", "mysticmaroon", "paledogwood", "popstar", "puce", "raspberryrose", "rosedust", "rosepompadour", "shimmeringblush", "skymagenta", "sugarplum", "superpink", "thulianpink", "wildorchid"], &["barbiepink", "blush", "cerise", "cyclamen", "deepcerise", "deeppink", "fandango", "fandangopink", "fieryrose", "frenchfuchsia", "frenchpink", "frostbite", "hollywoodcerise", "hotmagentabrokenanchor", "hotpink", "magentacrayolabrokenanchor", "magentadye", "magentapantone", "magentaprocess", "marooncrayola", "maroonx11", "mediumvioletred", "mexicanpink", "mulberry", "mulberrycrayola", "mystic", "paradisepink", "persianpink", "persianrose", "pictorialcarmine", "pinkpantone", "raspberry", "razzledazzlerose", "razzmatazz", "redpurple", "redviolet", "redvioletcrayola", "rose", "rosebonbon", "rosepink", "rosered", "ruber", "rubinered", "ruby", "shockingpink", "smitten", "spanishcarmine", "telemagenta", "uared", "ultrared", "violetred", "violetredperbang", "wildstrawberry", "wildwatermelon", "wintersky"]],
+        [&["babypink", "cameopink", "mimipink", "orchidpink", "palepink", "piggypink", "pink", "spanishpink", "tearose"], &["amaranthpink", "bakermillerpink", "carnationpink", "cherryblossompink", "cottoncandy", "kobi", "lightpink", "mauvelous", "melon", "nadeshikopink", "pinksherbet", "salmonpink", "schausspink", "ticklemepink", "vanillaice", "vividtangerine"], &[]],
+    ],
+    [
+        [&["blackbean", "bole", "cafenoir", "darkbrown", "darksienna", "drabdarkbrown", "kobicha", "liver", "olivedrab7", "pullmanbrownupsbrown", "roseebony", "vandykebrown", "zinnwalditebrown"], &["antiqueruby", "barnred", "bloodred", "burgundy", "burntumber", "chocolatetraditional", "falured", "kobe", "liverorgan", "maroonweb", "oucrimsonred", "oxblood", "persianplum", "prune", "rosewood", "sealbrown", "sepia", "sienna", "upmaroon", "wine"], &["darkred", "rubyred"]],
+        [&["beaver", "cafeaulait", "coffee", "coyotebrown", "fielddrab", "frenchbeige", "frenchbistre", "goldfusion", "liverchestnut", "rawumber", "shadow", "tuscanbrown", "tuscanred", "tuscantan"], &["alloyorange", "bistrebrown", "brown", "brownsugar", "chestnut", "chocolateweb", "copper", "cordovan", "fuzzywuzzy", "goldenbrown", "japanesecarmine", "liverdogs", "metallicsunburst", "modebeige", "ochre", "redochre2", "russet", "saddlebrown", "sanddune", "windsortan"], &["burntorange", "crimsonua", "persimmon", "tennetawny", "vividburgundy"]],
+        [&[], &[], &[]],
+    ],
+    [
+        [&["bistre", "black", "blackolive", "charcoal", "darkjunglegreen", "darklava", "darkliverhorses", "davysgrey", "eerieblack", "gunmetal", "jet", "oldburgundy", "onyx", "outerspacecrayola", "pinegreen2", "raisinblack", "richblackfogra29", "richblackfogra39", "riflegreen", "smokyblack", "taupe"], &[], &[]],
+        [&["battleshipgrey", "blackcoral", "cadetgrey", "cinereous", "deepspacesparkle", "dimgray", "ebony", "granitegray", "grayweb", "heliotropegray", "lightslategray", "manatee", "middlegrey", "morningblue", "nickel", "oldlavender", "oldsilver", "quicksilver", "rocketmetallic", "romansilver", "silvermetallic", "silverchalice", "slategray", "sonicsilver", "spanishgray", "taupegray", "tourmaline", "umber", "xanadu"], &[], &[]],
+        [&["aliceblue", "antiquewhite", "ashgray", "azurex11webcolor", "babypowder", "beige", "blackshadows", "bone", "champagnepink", "cosmiclatte", "culturedpearl", "eggshell", "floralwhite", "gainsboro", "ghostwhite", "grayx11gray", "isabelline", "ivory", "languidlavender", "lavenderweb", "lavenderblush", "lavendergray", "lightcyan", "lightgray", "lightperiwinkle", "linen", "magnolia", "mintcream", "mistyrose", "oldlace", "opal", "paleaqua", "palepurplepantone", "parchment", "platinum", "queenpink", "seashell", "silver", "silvercrayola", "silverpink", "silversand", "snow", "timberwolf", "white"], &[], &[]],
+    ],
+];
+
+/// `COLORS` keys laid out as a k-d tree over OKLab coordinates.
+pub(crate) static COLORS_NEAREST: [crate::nearest::Node; 894] = [
+    crate::nearest::Node{ lab:[0.65825135,-0.044853345,0.07610483], key:"turtlegreen" },
+    crate::nearest::Node{ lab:[0.59897864,0.057168227,0.039448358], key:"blastoffbronze" },
+    crate::nearest::Node{ lab:[0.51802874,-0.09083574,-0.0076601785], key:"pinegreen" },
+    crate::nearest::Node{ lab:[0.48552307,-0.026028452,-0.02085409], key:"deepspacesparkle" },
+    crate::nearest::Node{ lab:[0.4398917,-0.016073884,-0.111626536], key:"yinmnblue" },
+    crate::nearest::Node{ lab:[0.2852834,-0.019172631,-0.12275449], key:"royalbluedark" },
+    crate::nearest::Node{ lab:[0.38345328,-0.02753398,-0.26427624], key:"mediumblue" },
+    crate::nearest::Node{ lab:[0.32184708,-0.019507008,-0.1674787], key:"resolutionblue" },
+    crate::nearest::Node{ lab:[0.34797248,-0.021986865,-0.21633586], key:"bluepantone" },
+    crate::nearest::Node{ lab:[0.34408522,-0.02220885,-0.21893746], key:"zaffre" },
+    crate::nearest::Node{ lab:[0.3131322,-0.022484554,-0.21581091], key:"dukeblue" },
+    crate::nearest::Node{ lab:[0.27114984,-0.019469997,-0.18687664], key:"navyblue" },
+    crate::nearest::Node{ lab:[0.29710805,-0.019077092,-0.18805243], key:"phthaloblue" },
+    crate::nearest::Node{ lab:[0.444041,-0.030220311,-0.18785344], key:"absolutezero" },
+    crate::nearest::Node{ lab:[0.46653616,-0.03058691,-0.17361465], key:"sapphire" },
+    crate::nearest::Node{ lab:[0.45201373,-0.032456983,-0.31152815], key:"blue" },
+    crate::nearest::Node{ lab:[0.43062758,-0.0378965,-0.13125166], key:"usafablue" },
+    crate::nearest::Node{ lab:[0.47494522,-0.019576382,-0.19130133], key:"newcar" },
+    crate::nearest::Node{ lab:[0.38865826,-0.023929207,-0.19322073], key:"uablue" },
+    crate::nearest::Node{ lab:[0.37153354,-0.055529796,-0.032468844], key:"midnightgreeneaglegreen" },
+    crate::nearest::Node{ lab:[0.30350265,-0.0317874,-0.07113183], key:"prussianblue" },
+    crate::nearest::Node{ lab:[0.34323105,-0.05665324,-0.014935588], key:"warmblack" },
+    crate::nearest::Node{ lab:[0.36260617,-0.03859134,-0.082649484], key:"indigodye" },
+    crate::nearest::Node{ lab:[0.33635125,-0.05551767,-0.014636217], key:"richblack" },
+    crate::nearest::Node{ lab:[0.25046423,-0.02171748,-0.077873394], key:"oxfordblue" },
+    crate::nearest::Node{ lab:[0.33242992,-0.028868334,-0.10314908], key:"yaleblue" },
+    crate::nearest::Node{ lab:[0.48421347,-0.036113318,-0.10696092], key:"lapislazuli" },
+    crate::nearest::Node{ lab:[0.37295556,-0.06275077,-0.012993567], key:"deepjunglegreen" },
+    crate::nearest::Node{ lab:[0.46294564,-0.054965604,-0.06683501], key:"bluesapphire" },
+    crate::nearest::Node{ lab:[0.40296292,-0.03630598,-0.01024461], key:"darkslategray" },
+    crate::nearest::Node{ lab:[0.4610331,-0.023472438,-0.10630949], key:"bdazzledblue" },
+    crate::nearest::Node{ lab:[0.4806092,-0.026286589,-0.11876569], key:"sapphirecrayola" },
+    crate::nearest::Node{ lab:[0.24107769,0.034536637,-0.13743797], key:"paua" },
+    crate::nearest::Node{ lab:[0.3909911,0.016645685,-0.16077578], key:"bluepigment" },
+    crate::nearest::Node{ lab:[0.3610752,0.016851597,-0.14619601], key:"cosmiccobalt" },
+    crate::nearest::Node{ lab:[0.30774397,-0.0041636038,-0.19408944], key:"internationalkleinblue" },
+    crate::nearest::Node{ lab:[0.3578636,-0.0030052206,-0.23251452], key:"trypanblue" },
+    crate::nearest::Node{ lab:[0.28811887,0.006927588,-0.14345874], key:"midnightblue" },
+    crate::nearest::Node{ lab:[0.31199443,0.04633266,-0.1509656], key:"persianindigo" },
+    crate::nearest::Node{ lab:[0.34994093,0.021825057,-0.15157267], key:"picoteeblue" },
+    crate::nearest::Node{ lab:[0.45494774,-0.0033475277,-0.16732341], key:"violetblue" },
+    crate::nearest::Node{ lab:[0.43802926,-0.012482282,-0.18508701], key:"denimblue" },
+    crate::nearest::Node{ lab:[0.42302522,-0.012576002,-0.20427106], key:"persianblue" },
+    crate::nearest::Node{ lab:[0.4771607,-0.015532671,-0.17523603], key:"ceruleanblue" },
+    crate::nearest::Node{ lab:[0.47495806,0.04643322,-0.1575407], key:"plumppurple" },
+    crate::nearest::Node{ lab:[0.47320974,0.018847827,-0.3002088], key:"ultramarine" },
+    crate::nearest::Node{ lab:[0.3813769,-0.013836242,-0.021753376], key:"charcoal" },
+    crate::nearest::Node{ lab:[0.33287644,0.0075079906,-0.13535666], key:"stpatricksblue" },
+    crate::nearest::Node{ lab:[0.14188524,-0.015076642,-0.022130191], key:"richblackfogra29" },
+    crate::nearest::Node{ lab:[0.29335928,-0.0014218224,-0.07447428], key:"spacecadet" },
+    crate::nearest::Node{ lab:[0.3177972,-0.0105383415,-0.012357463], key:"gunmetal" },
+    crate::nearest::Node{ lab:[0.25741762,0.045345835,-0.03606172], key:"darkpurple" },
+    crate::nearest::Node{ lab:[0.27447447,0.053369313,-0.080149785], key:"russianviolet" },
+    crate::nearest::Node{ lab:[0.3981867,0.047390956,-0.039854538], key:"englishviolet" },
+    crate::nearest::Node{ lab:[0.45343462,0.013392697,-0.075289175], key:"purplenavy" },
+    crate::nearest::Node{ lab:[0.4143428,0.034491476,-0.11997692], key:"darkslateblue" },
+    crate::nearest::Node{ lab:[0.44197366,0.00524548,-0.046222944], key:"independence" },
+    crate::nearest::Node{ lab:[0.414942,0.050991345,-0.011420752], key:"eggplant" },
+    crate::nearest::Node{ lab:[0.42956173,0.04768349,-0.082961634], key:"cybergrape" },
+    crate::nearest::Node{ lab:[0.50108993,-0.030174272,-0.278721], key:"ceruleanrgb" },
+    crate::nearest::Node{ lab:[0.58913153,-0.071183756,-0.10405663], key:"bluencs" },
+    crate::nearest::Node{ lab:[0.5572282,-0.062885724,-0.11385769], key:"starcommandblue" },
+    crate::nearest::Node{ lab:[0.4972232,-0.052467912,-0.11504004], key:"sapphireblue" },
+    crate::nearest::Node{ lab:[0.53087467,-0.05426367,-0.12961079], key:"spanishblue" },
+    crate::nearest::Node{ lab:[0.53751355,-0.05498865,-0.13104953], key:"frenchblue" },
+    crate::nearest::Node{ lab:[0.518656,-0.054106902,-0.12238164], key:"honolulublue" },
+    crate::nearest::Node{ lab:[0.5020138,-0.042558204,-0.14011306], key:"greenblue" },
+    crate::nearest::Node{ lab:[0.5001138,-0.036728613,-0.15806355], key:"denim" },
+    crate::nearest::Node{ lab:[0.6349673,-0.046411768,-0.13590764], key:"tuftsblue" },
+    crate::nearest::Node{ lab:[0.65200555,-0.05493326,-0.18200985], key:"dodgerblue" },
+    crate::nearest::Node{ lab:[0.6130819,-0.050091464,-0.20595838], key:"azure" },
+    crate::nearest::Node{ lab:[0.6319969,-0.049637515,-0.15294132], key:"bleudefrance" },
+    crate::nearest::Node{ lab:[0.5600732,-0.045571312,-0.15918685], key:"navybluecrayola" },
+    crate::nearest::Node{ lab:[0.5953018,-0.037794735,-0.21589367], key:"bluecrayola" },
+    crate::nearest::Node{ lab:[0.54783934,-0.0685352,-0.08896469], key:"cerulean" },
+    crate::nearest::Node{ lab:[0.52646476,-0.07022214,-0.0110018095], key:"myrtlegreen" },
+    crate::nearest::Node{ lab:[0.5431226,-0.089647055,-0.023633804], key:"teal" },
+    crate::nearest::Node{ lab:[0.5435608,-0.08046707,-0.043971732], key:"metallicseaweed" },
+    crate::nearest::Node{ lab:[0.5060897,-0.083534464,-0.022022331], key:"skobeloff" },
+    crate::nearest::Node{ lab:[0.5218066,-0.057446405,-0.030506535], key:"ming" },
+    crate::nearest::Node{ lab:[0.528952,-0.053914614,-0.045816984], key:"tealblue" },
+    crate::nearest::Node{ lab:[0.6472885,-0.079240024,-0.09684141], key:"weezyblue" },
+    crate::nearest::Node{ lab:[0.61018914,-0.09967032,-0.029419594], key:"viridiangreen" },
+    crate::nearest::Node{ lab:[0.6119837,-0.086897485,-0.06696618], key:"bluemunsell" },
+    crate::nearest::Node{ lab:[0.57652205,-0.095159926,-0.025087172], key:"darkcyan" },
+    crate::nearest::Node{ lab:[0.65768147,-0.061722323,-0.020410107], key:"cadetblue" },
+    crate::nearest::Node{ lab:[0.5880009,-0.040817074,-0.090565845], key:"steelblue" },
+    crate::nearest::Node{ lab:[0.5496273,-0.017551322,-0.090355046], key:"blueyonder" },
+    crate::nearest::Node{ lab:[0.56970316,-0.008748506,-0.21757187], key:"ultramarineblue" },
+    crate::nearest::Node{ lab:[0.50046796,0.016253447,-0.120216206], key:"liberty" },
+    crate::nearest::Node{ lab:[0.5598482,-0.011820611,-0.18786201], key:"royalbluelight" },
+    crate::nearest::Node{ lab:[0.5622477,-0.015460981,-0.1969046], key:"bluetiful" },
+    crate::nearest::Node{ lab:[0.5289219,-0.029368643,-0.15368661], key:"trueblue" },
+    crate::nearest::Node{ lab:[0.5435672,0.045877244,-0.16496573], key:"slateblue" },
+    crate::nearest::Node{ lab:[0.53033704,0.04266501,-0.20016871], key:"majorelleblue" },
+    crate::nearest::Node{ lab:[0.5864626,0.040664844,-0.2274512], key:"indigo" },
+    crate::nearest::Node{ lab:[0.6056804,-0.001415847,-0.21313308], key:"vantgblue" },
+    crate::nearest::Node{ lab:[0.58007413,-0.0044031376,-0.22903168], key:"neonblue" },
+    crate::nearest::Node{ lab:[0.5866375,0.036019497,-0.1298641], key:"violetbluecrayola" },
+    crate::nearest::Node{ lab:[0.60704064,0.054143667,-0.10164556], key:"middlebluepurple" },
+    crate::nearest::Node{ lab:[0.6044729,0.051809892,-0.18683998], key:"mediumslateblue" },
+    crate::nearest::Node{ lab:[0.59250414,-0.01141009,-0.028742729], key:"slategray" },
+    crate::nearest::Node{ lab:[0.48913512,-0.01084922,-0.02483063], key:"blackcoral" },
+    crate::nearest::Node{ lab:[0.5062731,-0.01725702,-0.03145141], key:"darkelectricblue" },
+    crate::nearest::Node{ lab:[0.51676697,-0.026658848,-0.07632402], key:"queenblue" },
+    crate::nearest::Node{ lab:[0.5062731,-0.01725702,-0.03145141], key:"paynesgrey" },
+    crate::nearest::Node{ lab:[0.53885436,0.02720747,-0.017094225], key:"oldlavender" },
+    crate::nearest::Node{ lab:[0.5784959,0.013615323,-0.046870608], key:"rhythm" },
+    crate::nearest::Node{ lab:[0.6294332,-0.0014821948,-0.020678453], key:"romansilver" },
+    crate::nearest::Node{ lab:[0.6302811,-0.011736662,-0.044097234], key:"shadowblue" },
+    crate::nearest::Node{ lab:[0.6025983,-0.017434837,-0.08702596], key:"glaucous" },
+    crate::nearest::Node{ lab:[0.61902016,-0.011989233,-0.030206343], key:"lightslategray" },
+    crate::nearest::Node{ lab:[0.6151462,0.044277,-0.015028676], key:"mountbattenpink" },
+    crate::nearest::Node{ lab:[0.6247793,0.056816958,-0.078307614], key:"purplemountainmajesty" },
+    crate::nearest::Node{ lab:[0.52738965,-0.0795097,0.033300303], key:"amazon" },
+    crate::nearest::Node{ lab:[0.32503685,2.6312758e-11,1.2115394e-08], key:"jet" },
+    crate::nearest::Node{ lab:[0.40521407,-0.08120031,0.01937932], key:"gothamgreen" },
+    crate::nearest::Node{ lab:[0.30646956,-0.059831064,0.016817749], key:"sacramentostategreen" },
+    crate::nearest::Node{ lab:[0.082309134,-0.0039260634,-0.007010753], key:"richblackfogra39" },
+    crate::nearest::Node{ lab:[0.29755905,-0.048239984,0.017845135], key:"phthalogreen" },
+    crate::nearest::Node{ lab:[0.24958095,-0.015185025,0.0013979138], key:"darkjunglegreen" },
+    crate::nearest::Node{ lab:[0.2962453,-0.01305195,0.017888166], key:"pinegreen2" },
+    crate::nearest::Node{ lab:[0.22212875,1.7982005e-11,8.279606e-09], key:"eerieblack" },
+    crate::nearest::Node{ lab:[0.0,0.0,0.0], key:"black" },
+    crate::nearest::Node{ lab:[0.3314289,-0.01311514,-0.0075227194], key:"outerspacecrayola" },
+    crate::nearest::Node{ lab:[0.5012615,-0.0957087,0.012173908], key:"tropicalrainforest" },
+    crate::nearest::Node{ lab:[0.35595354,-0.05229121,0.0035747373], key:"msugreen" },
+    crate::nearest::Node{ lab:[0.467214,-0.015665704,0.016632438], key:"ebony" },
+    crate::nearest::Node{ lab:[0.35314614,-0.0042595845,0.009302186], key:"blackolive" },
+    crate::nearest::Node{ lab:[0.33815894,-0.0034603637,-0.0028636598], key:"onyx" },
+    crate::nearest::Node{ lab:[0.44212538,-0.119347766,0.09159419], key:"pakistangreen" },
+    crate::nearest::Node{ lab:[0.3335927,-0.07312978,0.0310697], key:"britishracinggreen" },
+    crate::nearest::Node{ lab:[0.40746307,-0.09783534,0.08179743], key:"lincolngreen" },
+    crate::nearest::Node{ lab:[0.3393065,-0.077049494,0.038993586], key:"upforestgreen" },
+    crate::nearest::Node{ lab:[0.43601784,-0.11769909,0.0903289], key:"darkgreenx11" },
+    crate::nearest::Node{ lab:[0.44148088,-0.062085684,0.03930367], key:"huntergreen" },
+    crate::nearest::Node{ lab:[0.40317076,-0.019738507,0.027571265], key:"riflegreen" },
+    crate::nearest::Node{ lab:[0.5025247,-0.06008194,0.04963196], key:"artichokegreen" },
+    crate::nearest::Node{ lab:[0.46377486,-0.10342975,0.04732856], key:"cadmiumgreen" },
+    crate::nearest::Node{ lab:[0.4892612,-0.0999982,0.044448875], key:"darkspringgreen" },
+    crate::nearest::Node{ lab:[0.51975185,-0.14030233,0.107675895], key:"greenweb" },
+    crate::nearest::Node{ lab:[0.47386557,-0.015976302,0.08134933], key:"antiquebronze" },
+    crate::nearest::Node{ lab:[0.4955208,-0.05289934,0.072315395], key:"darkolivegreen" },
+    crate::nearest::Node{ lab:[0.30676293,0.01945686,0.02755986], key:"bistre" },
+    crate::nearest::Node{ lab:[0.43510556,0.03947364,0.016864384], key:"roseebony" },
+    crate::nearest::Node{ lab:[0.36572334,0.011185313,0.020846242], key:"taupe" },
+    crate::nearest::Node{ lab:[0.15796071,0.004105644,0.010066425], key:"smokyblack" },
+    crate::nearest::Node{ lab:[0.25227332,0.005876713,-0.0039959615], key:"raisinblack" },
+    crate::nearest::Node{ lab:[0.36572334,0.011185313,0.020846242], key:"darklava" },
+    crate::nearest::Node{ lab:[0.38370585,0.027974896,0.019667504], key:"darkliverhorses" },
+    crate::nearest::Node{ lab:[0.33040577,0.025451811,0.012161781], key:"oldburgundy" },
+    crate::nearest::Node{ lab:[0.45020232,0.018337728,0.022400396], key:"umber" },
+    crate::nearest::Node{ lab:[0.5137937,4.1593312e-11,1.91511e-08], key:"granitegray" },
+    crate::nearest::Node{ lab:[0.4495332,3.639089e-11,1.6755859e-08], key:"davysgrey" },
+    crate::nearest::Node{ lab:[0.52080655,4.216083e-11,1.9412495e-08], key:"dimgray" },
+    crate::nearest::Node{ lab:[0.4438403,0.03259182,0.019237798], key:"liver" },
+    crate::nearest::Node{ lab:[0.515365,0.04066686,0.0104827555], key:"deeptaupe" },
+    crate::nearest::Node{ lab:[0.41432676,0.03572349,0.052545823], key:"vandykebrown" },
+    crate::nearest::Node{ lab:[0.22775845,0.026612895,0.03369998], key:"zinnwalditebrown" },
+    crate::nearest::Node{ lab:[0.37808514,0.00063998747,0.038168896], key:"drabdarkbrown" },
+    crate::nearest::Node{ lab:[0.3276122,0.00034479235,0.03571493], key:"olivedrab7" },
+    crate::nearest::Node{ lab:[0.35120362,0.018561773,0.040717855], key:"cafenoir" },
+    crate::nearest::Node{ lab:[0.40718612,0.028374806,0.06867024], key:"pullmanbrownupsbrown" },
+    crate::nearest::Node{ lab:[0.41400516,0.029658241,0.060763396], key:"darkbrown" },
+    crate::nearest::Node{ lab:[0.45392776,0.03204077,0.046858396], key:"tuscanbrown" },
+    crate::nearest::Node{ lab:[0.5165877,0.023432001,0.062624946], key:"coyotebrown" },
+    crate::nearest::Node{ lab:[0.45392776,0.03204077,0.046858396], key:"coffee" },
+    crate::nearest::Node{ lab:[0.4596131,0.007395991,0.077135384], key:"fielddrab" },
+    crate::nearest::Node{ lab:[0.42558694,0.041795943,0.07563908], key:"sepia" },
+    crate::nearest::Node{ lab:[0.42440858,0.036613084,0.061594125], key:"kobicha" },
+    crate::nearest::Node{ lab:[0.5806646,-0.04281223,0.1191162], key:"olive" },
+    crate::nearest::Node{ lab:[0.6154014,-0.13472831,0.056894], key:"shamrockgreen" },
+    crate::nearest::Node{ lab:[0.58785,-0.056760583,0.0012374569], key:"wintergreendream" },
+    crate::nearest::Node{ lab:[0.58340377,-0.08773143,0.055298593], key:"middlegreen" },
+    crate::nearest::Node{ lab:[0.5287483,-0.10681185,0.027390629], key:"spanishviridian" },
+    crate::nearest::Node{ lab:[0.5315672,-0.10163518,0.013262868], key:"genericviridian" },
+    crate::nearest::Node{ lab:[0.5685264,-0.107546546,0.05026605], key:"seagreen" },
+    crate::nearest::Node{ lab:[0.55673677,-0.07479696,0.012646176], key:"viridian" },
+    crate::nearest::Node{ lab:[0.53736436,-0.056823704,0.0064525916], key:"hookersgreen" },
+    crate::nearest::Node{ lab:[0.6501063,-0.117277466,-0.0017412826], key:"persiangreen" },
+    crate::nearest::Node{ lab:[0.6475634,-0.1328872,0.038606852], key:"greenmunsell" },
+    crate::nearest::Node{ lab:[0.61443317,-0.11749915,0.015380972], key:"paoloveronesegreen" },
+    crate::nearest::Node{ lab:[0.62052613,-0.13029069,0.044132046], key:"greenncs" },
+    crate::nearest::Node{ lab:[0.6166685,-0.06469219,0.04608136], key:"russiangreen" },
+    crate::nearest::Node{ lab:[0.59352696,-0.09676951,0.014446005], key:"illuminatingemerald" },
+    crate::nearest::Node{ lab:[0.5895765,-0.14405198,0.11533459], key:"slimygreen" },
+    crate::nearest::Node{ lab:[0.5870827,-0.088048816,0.09758879], key:"maximumgreen" },
+    crate::nearest::Node{ lab:[0.5578046,-0.1346051,0.10182646], key:"forestgreenweb" },
+    crate::nearest::Node{ lab:[0.5763808,-0.13023515,0.06276425], key:"spanishgreen" },
+    crate::nearest::Node{ lab:[0.5452053,-0.14199805,0.11067057], key:"indiagreen" },
+    crate::nearest::Node{ lab:[0.53863937,-0.08484943,0.09008631], key:"sapgreen" },
+    crate::nearest::Node{ lab:[0.5308536,-0.07091865,0.06253602], key:"ferngreen" },
+    crate::nearest::Node{ lab:[0.59227365,-0.10432899,0.084491976], key:"maygreen" },
+    crate::nearest::Node{ lab:[0.63166857,-0.14850532,0.08209099], key:"greenpigment" },
+    crate::nearest::Node{ lab:[0.65187,-0.14391176,0.063087925], key:"gogreen" },
+    crate::nearest::Node{ lab:[0.6517998,-0.16072145,0.10161455], key:"greenpantone" },
+    crate::nearest::Node{ lab:[0.59948385,-0.081376776,0.11069002], key:"olivedrab3" },
+    crate::nearest::Node{ lab:[0.65825135,-0.044853345,0.07610483], key:"mossgreen" },
+    crate::nearest::Node{ lab:[0.54984975,0.014987691,0.053097285], key:"frenchbistre" },
+    crate::nearest::Node{ lab:[0.6127261,-0.0008421151,0.0028453534], key:"battleshipgrey" },
+    crate::nearest::Node{ lab:[0.5998708,4.8561544e-11,2.235953e-08], key:"grayweb" },
+    crate::nearest::Node{ lab:[0.5565899,-0.0032007808,0.0021979334], key:"nickel" },
+    crate::nearest::Node{ lab:[0.56240505,4.5528192e-11,2.0963034e-08], key:"sonicsilver" },
+    crate::nearest::Node{ lab:[0.600552,-0.027264245,0.013613609], key:"xanadu" },
+    crate::nearest::Node{ lab:[0.58434325,0.007900103,0.044875946], key:"shadow" },
+    crate::nearest::Node{ lab:[0.60661703,0.013225741,0.0026215918], key:"rocketmetallic" },
+    crate::nearest::Node{ lab:[0.6227219,0.024410238,0.017088247], key:"cinereous" },
+    crate::nearest::Node{ lab:[0.6127261,-0.0008421151,0.0028453534], key:"oldsilver" },
+    crate::nearest::Node{ lab:[0.62318724,0.008548593,-0.003530075], key:"taupegray" },
+    crate::nearest::Node{ lab:[0.6225857,0.0032093322,0.010241239], key:"middlegrey" },
+    crate::nearest::Node{ lab:[0.6268912,0.028066466,0.034806788], key:"beaver" },
+    crate::nearest::Node{ lab:[0.6159505,0.043846063,0.023871617], key:"burnishedbrown" },
+    crate::nearest::Node{ lab:[0.5706673,0.011667329,0.10903065], key:"sanddune" },
+    crate::nearest::Node{ lab:[0.5706673,0.011667329,0.10903065], key:"modebeige" },
+    crate::nearest::Node{ lab:[0.55817705,-0.015493545,0.087216645], key:"spanishbistre" },
+    crate::nearest::Node{ lab:[0.56748897,0.0019030448,0.058612257], key:"goldfusion" },
+    crate::nearest::Node{ lab:[0.5706673,0.011667329,0.10903065], key:"bistrebrown" },
+    crate::nearest::Node{ lab:[0.54973835,0.03504063,0.104863055], key:"goldenbrown" },
+    crate::nearest::Node{ lab:[0.5298028,0.01938623,0.057372816], key:"rawumber" },
+    crate::nearest::Node{ lab:[0.6176574,0.03760138,0.059379533], key:"cafeaulait" },
+    crate::nearest::Node{ lab:[0.60325426,0.010078094,0.09422343], key:"metallicsunburst" },
+    crate::nearest::Node{ lab:[0.5869905,0.030348035,0.054652587], key:"liverchestnut" },
+    crate::nearest::Node{ lab:[0.65206957,0.01937692,0.13077164], key:"darkgoldenrod" },
+    crate::nearest::Node{ lab:[0.6176574,0.03760138,0.059379533], key:"tuscantan" },
+    crate::nearest::Node{ lab:[0.6176574,0.03760138,0.059379533], key:"frenchbeige" },
+    crate::nearest::Node{ lab:[0.55067515,0.2175604,0.034856066], key:"rubinered" },
+    crate::nearest::Node{ lab:[0.5416511,0.060808484,-0.04414741], key:"chineseviolet" },
+    crate::nearest::Node{ lab:[0.52935565,0.11738072,-0.26854175], key:"violetcolorwheel" },
+    crate::nearest::Node{ lab:[0.46241993,0.08391852,-0.051565595], key:"secret" },
+    crate::nearest::Node{ lab:[0.4067351,0.09379674,-0.060027637], key:"finn" },
+    crate::nearest::Node{ lab:[0.39094284,0.10446695,-0.053939547], key:"palatinatepurple" },
+    crate::nearest::Node{ lab:[0.37617615,0.06594622,-0.12741888], key:"spanishviolet" },
+    crate::nearest::Node{ lab:[0.38696495,0.07249728,-0.13293596], key:"ksupurple" },
+    crate::nearest::Node{ lab:[0.2982503,0.100242205,-0.09361181], key:"petunia" },
+    crate::nearest::Node{ lab:[0.40618414,0.1131309,-0.051716957], key:"byzantium" },
+    crate::nearest::Node{ lab:[0.39621332,0.10467277,-0.12499041], key:"seance" },
+    crate::nearest::Node{ lab:[0.4277668,0.10124331,-0.098065645], key:"eminence" },
+    crate::nearest::Node{ lab:[0.5176225,0.07373137,-0.11666214], key:"royalpurple" },
+    crate::nearest::Node{ lab:[0.4402718,0.08817676,-0.13386434], key:"rebeccapurple" },
+    crate::nearest::Node{ lab:[0.5181426,0.09897636,-0.052930396], key:"razzmicberry" },
+    crate::nearest::Node{ lab:[0.50553507,0.11410801,-0.060364846], key:"plum" },
+    crate::nearest::Node{ lab:[0.44016927,0.10622621,-0.08728785], key:"maximumpurple" },
+    crate::nearest::Node{ lab:[0.41208646,0.08186605,0.0119350385], key:"catawba" },
+    crate::nearest::Node{ lab:[0.39177614,0.08174951,-0.0059852907], key:"oldmauve" },
+    crate::nearest::Node{ lab:[0.3973361,0.059441973,-0.025935315], key:"darkbyzantium" },
+    crate::nearest::Node{ lab:[0.3826312,0.06971168,-0.037755158], key:"japaneseviolet" },
+    crate::nearest::Node{ lab:[0.2548405,0.058515843,0.024430417], key:"darksienna" },
+    crate::nearest::Node{ lab:[0.40077865,0.091534294,0.02467889], key:"wine" },
+    crate::nearest::Node{ lab:[0.39177614,0.08174951,-0.0059852907], key:"winedregs" },
+    crate::nearest::Node{ lab:[0.46554133,0.09683965,0.029292548], key:"cordovan" },
+    crate::nearest::Node{ lab:[0.53392476,0.063379966,0.022473192], key:"rosetaupe" },
+    crate::nearest::Node{ lab:[0.4951845,0.095909074,-0.01928946], key:"twilightlavender" },
+    crate::nearest::Node{ lab:[0.46297687,0.067019776,0.024600165], key:"tuscanred" },
+    crate::nearest::Node{ lab:[0.4544233,0.10746113,0.027459968], key:"solidpink" },
+    crate::nearest::Node{ lab:[0.516651,0.09923692,-0.025897818], key:"sugarplum" },
+    crate::nearest::Node{ lab:[0.470976,0.19360247,-0.039208665], key:"flirt" },
+    crate::nearest::Node{ lab:[0.46559456,0.1606632,-0.16384731], key:"violetryb" },
+    crate::nearest::Node{ lab:[0.42091367,0.16470431,-0.10147178], key:"purpleweb" },
+    crate::nearest::Node{ lab:[0.42091367,0.16470431,-0.10147178], key:"patriarch" },
+    crate::nearest::Node{ lab:[0.42849085,0.11846329,-0.18124227], key:"purple" },
+    crate::nearest::Node{ lab:[0.4096806,0.118739024,-0.07487456], key:"midnight" },
+    crate::nearest::Node{ lab:[0.43781623,0.17240374,-0.10146111], key:"mardigras" },
+    crate::nearest::Node{ lab:[0.44679788,0.17483285,-0.10771182], key:"darkmagenta" },
+    crate::nearest::Node{ lab:[0.49584103,0.15090184,-0.20225547], key:"frenchviolet" },
+    crate::nearest::Node{ lab:[0.54111457,0.15060078,-0.1701666], key:"darkorchid" },
+    crate::nearest::Node{ lab:[0.53376496,0.13031808,-0.21370523], key:"blueviolet" },
+    crate::nearest::Node{ lab:[0.50293416,0.13382334,-0.051940832], key:"violetcrayola" },
+    crate::nearest::Node{ lab:[0.5195411,0.18530074,-0.1725538], key:"purplemunsell" },
+    crate::nearest::Node{ lab:[0.51490974,0.16689838,-0.20023905], key:"darkviolet" },
+    crate::nearest::Node{ lab:[0.47384584,0.18773521,-0.011031611], key:"jazzberryjam" },
+    crate::nearest::Node{ lab:[0.39509264,0.13574535,0.025694022], key:"claret" },
+    crate::nearest::Node{ lab:[0.47167856,0.11842778,-0.00086675846], key:"quinacridonemagenta" },
+    crate::nearest::Node{ lab:[0.33543,0.13539821,-0.015278784], key:"tyrianpurple" },
+    crate::nearest::Node{ lab:[0.4515809,0.13349308,0.033071913], key:"redvioletcolorwheel" },
+    crate::nearest::Node{ lab:[0.46428013,0.1512787,0.03159574], key:"bigdiporuby" },
+    crate::nearest::Node{ lab:[0.39002565,0.13681537,-0.014432625], key:"pansypurple" },
+    crate::nearest::Node{ lab:[0.49612674,0.16682085,0.024403075], key:"amaranthpurple" },
+    crate::nearest::Node{ lab:[0.5230566,0.12972657,-0.025658568], key:"magentahaze" },
+    crate::nearest::Node{ lab:[0.52086556,0.15351781,-0.032913964], key:"maximumredpurple" },
+    crate::nearest::Node{ lab:[0.48706886,0.16062917,-0.02075928], key:"amaranthdeeppurple" },
+    crate::nearest::Node{ lab:[0.53301275,0.19484068,0.028302046], key:"rosered" },
+    crate::nearest::Node{ lab:[0.51770574,0.16756217,0.0069583473], key:"maroonx11" },
+    crate::nearest::Node{ lab:[0.625444,0.17114109,0.002332848], key:"mystic" },
+    crate::nearest::Node{ lab:[0.6112648,0.16741587,-0.044911876], key:"mulberrycrayola" },
+    crate::nearest::Node{ lab:[0.5666131,0.12066933,-0.113862045], key:"purpleplum" },
+    crate::nearest::Node{ lab:[0.54763174,0.14169216,-0.25796884], key:"violet" },
+    crate::nearest::Node{ lab:[0.54533833,0.06382957,-0.052701373], key:"frenchlilac" },
+    crate::nearest::Node{ lab:[0.54763174,0.14169216,-0.25796884], key:"electricviolet" },
+    crate::nearest::Node{ lab:[0.54533833,0.06382957,-0.052701373], key:"pompandpower" },
+    crate::nearest::Node{ lab:[0.565501,0.16262309,-0.22063299], key:"veronica" },
+    crate::nearest::Node{ lab:[0.565501,0.16262309,-0.22063299], key:"purplex11" },
+    crate::nearest::Node{ lab:[0.62557954,0.15330534,-0.132207], key:"mediumorchid" },
+    crate::nearest::Node{ lab:[0.6076205,0.09024343,-0.12734418], key:"amethyst" },
+    crate::nearest::Node{ lab:[0.62691426,0.07093668,-0.14121489], key:"mediumpurple" },
+    crate::nearest::Node{ lab:[0.62484664,0.11550581,-0.047335852], key:"pearlypurple" },
+    crate::nearest::Node{ lab:[0.6237304,0.16134061,-0.10252075], key:"fuchsiacrayola" },
+    crate::nearest::Node{ lab:[0.5671712,0.16477573,-0.24661], key:"vividviolet" },
+    crate::nearest::Node{ lab:[0.5586189,0.08573352,0.0046147397], key:"rosedust" },
+    crate::nearest::Node{ lab:[0.54237807,0.14838313,-0.021899726], key:"mysticmaroon" },
+    crate::nearest::Node{ lab:[0.54362655,0.06767031,0.0015843842], key:"mauvetaupe" },
+    crate::nearest::Node{ lab:[0.54605293,0.080591016,-0.034178115], key:"antiquefuchsia" },
+    crate::nearest::Node{ lab:[0.54362655,0.06767031,0.0015843842], key:"raspberryglace" },
+    crate::nearest::Node{ lab:[0.54833454,0.14877672,0.0023458095], key:"raspberryrose" },
+    crate::nearest::Node{ lab:[0.54833454,0.14877672,0.0023458095], key:"irresistible" },
+    crate::nearest::Node{ lab:[0.6294865,0.14044964,0.011458373], key:"cinnamonsatin" },
+    crate::nearest::Node{ lab:[0.57655615,0.14006111,0.030461883], key:"popstar" },
+    crate::nearest::Node{ lab:[0.5643525,0.0624514,0.021916663], key:"copperrose" },
+    crate::nearest::Node{ lab:[0.6060415,0.072160415,0.03475517], key:"copperpenny" },
+    crate::nearest::Node{ lab:[0.65114945,0.16391937,0.012765149], key:"blush" },
+    crate::nearest::Node{ lab:[0.5951153,0.16716394,-0.028155832], key:"mulberry" },
+    crate::nearest::Node{ lab:[0.587096,0.23022005,-0.021486588], key:"barbiepink" },
+    crate::nearest::Node{ lab:[0.6092981,0.20670143,-0.22209285], key:"electricpurple" },
+    crate::nearest::Node{ lab:[0.5691313,0.19205646,-0.08404446], key:"byzantine" },
+    crate::nearest::Node{ lab:[0.58076805,0.17215285,-0.040650208], key:"redvioletcrayola" },
+    crate::nearest::Node{ lab:[0.54399014,0.18028972,-0.049848933], key:"fandango" },
+    crate::nearest::Node{ lab:[0.58639574,0.18116602,-0.023143014], key:"smitten" },
+    crate::nearest::Node{ lab:[0.55336696,0.21807168,-0.03968207], key:"redviolet" },
+    crate::nearest::Node{ lab:[0.55336696,0.21807168,-0.03968207], key:"mediumvioletred" },
+    crate::nearest::Node{ lab:[0.6544557,0.24305098,-0.19600451], key:"phlox" },
+    crate::nearest::Node{ lab:[0.64173555,0.22941428,-0.051787503], key:"frostbite" },
+    crate::nearest::Node{ lab:[0.6131073,0.20863299,-0.13000724], key:"steelpink" },
+    crate::nearest::Node{ lab:[0.6218636,0.19049318,-0.028923895], key:"pinkpantone" },
+    crate::nearest::Node{ lab:[0.63741416,0.2618854,-0.045464214], key:"hollywoodcerise" },
+    crate::nearest::Node{ lab:[0.6544557,0.24305098,-0.19600451], key:"psychedelicpurple" },
+    crate::nearest::Node{ lab:[0.60096836,0.22103779,0.02475591], key:"razzmatazz" },
+    crate::nearest::Node{ lab:[0.5591887,0.21219687,-0.019550174], key:"magentadye" },
+    crate::nearest::Node{ lab:[0.5801425,0.19695488,-0.0007371235], key:"telemagenta" },
+    crate::nearest::Node{ lab:[0.5962308,0.18602851,-0.005565246], key:"magentapantone" },
+    crate::nearest::Node{ lab:[0.5958121,0.17536923,0.007286676], key:"ruber" },
+    crate::nearest::Node{ lab:[0.5948825,0.24100037,0.0012714873], key:"redpurple" },
+    crate::nearest::Node{ lab:[0.59615153,0.2422193,-0.00456126], key:"mexicanpink" },
+    crate::nearest::Node{ lab:[0.64506364,0.25977802,0.012628676], key:"rose" },
+    crate::nearest::Node{ lab:[0.6383903,0.17973304,0.003331822], key:"fandangopink" },
+    crate::nearest::Node{ lab:[0.60249317,0.21295787,-0.018159619], key:"deepcerise" },
+    crate::nearest::Node{ lab:[0.6442221,0.25879532,0.016827684], key:"wintersky" },
+    crate::nearest::Node{ lab:[0.65027076,0.2649197,-0.011569724], key:"magentaprocess" },
+    crate::nearest::Node{ lab:[0.654935,0.26096505,-0.0139288595], key:"deeppink" },
+    crate::nearest::Node{ lab:[0.5474139,0.21187654,0.05464594], key:"spanishcarmine" },
+    crate::nearest::Node{ lab:[0.50316536,0.12762032,0.08662559], key:"chinesered" },
+    crate::nearest::Node{ lab:[0.39257485,0.124400415,0.06195262], key:"falured" },
+    crate::nearest::Node{ lab:[0.38426822,0.07590022,0.052840367], key:"liverorgan" },
+    crate::nearest::Node{ lab:[0.36609405,0.10653839,0.049997386], key:"persianplum" },
+    crate::nearest::Node{ lab:[0.2569091,0.09199598,0.05148624], key:"oxblood" },
+    crate::nearest::Node{ lab:[0.24152951,0.06453652,0.045546647], key:"blackbean" },
+    crate::nearest::Node{ lab:[0.33629897,0.059254173,0.059607234], key:"sealbrown" },
+    crate::nearest::Node{ lab:[0.31928962,0.117446855,0.05426743], key:"rosewood" },
+    crate::nearest::Node{ lab:[0.36609405,0.10653839,0.049997386], key:"prune" },
+    crate::nearest::Node{ lab:[0.46353638,0.10851562,0.05605875], key:"redochre2" },
+    crate::nearest::Node{ lab:[0.5420161,0.10101044,0.03935302], key:"middleredpurple" },
+    crate::nearest::Node{ lab:[0.44820476,0.064785026,0.038219366], key:"bole" },
+    crate::nearest::Node{ lab:[0.49000335,0.09375322,0.060424317], key:"chestnut" },
+    crate::nearest::Node{ lab:[0.5427934,0.115341954,0.040644564], key:"rosevale" },
+    crate::nearest::Node{ lab:[0.5383891,0.1200822,0.03875357], key:"englishred" },
+    crate::nearest::Node{ lab:[0.44285735,0.1036415,0.06470267], key:"burntumber" },
+    crate::nearest::Node{ lab:[0.32043204,0.114742756,0.06421664], key:"bloodred" },
+    crate::nearest::Node{ lab:[0.42986467,0.105995625,0.07335905], key:"sienna" },
+    crate::nearest::Node{ lab:[0.42986467,0.105995625,0.07335905], key:"kobe" },
+    crate::nearest::Node{ lab:[0.43490916,0.05731574,0.088258676], key:"chocolatetraditional" },
+    crate::nearest::Node{ lab:[0.3733034,0.12632202,0.07355378], key:"barnred" },
+    crate::nearest::Node{ lab:[0.37672463,0.12488483,0.06229811], key:"upmaroon" },
+    crate::nearest::Node{ lab:[0.46062005,0.074275814,0.07534047], key:"fuzzywuzzy" },
+    crate::nearest::Node{ lab:[0.4707835,0.07080872,0.08696012], key:"saddlebrown" },
+    crate::nearest::Node{ lab:[0.45687208,0.057495005,0.078043036], key:"russet" },
+    crate::nearest::Node{ lab:[0.4967423,0.07136499,0.10074232], key:"brown" },
+    crate::nearest::Node{ lab:[0.5388764,0.076233365,0.10867899], key:"windsortan" },
+    crate::nearest::Node{ lab:[0.53509915,0.12540387,0.10340163], key:"rust" },
+    crate::nearest::Node{ lab:[0.4557328,0.16979656,0.07024997], key:"universityofpennsylvaniared" },
+    crate::nearest::Node{ lab:[0.46042374,0.15645063,0.048543185], key:"vividburgundy" },
+    crate::nearest::Node{ lab:[0.4417714,0.15386587,0.06949228], key:"rubyred" },
+    crate::nearest::Node{ lab:[0.3799746,0.14435318,0.048641592], key:"burgundy" },
+    crate::nearest::Node{ lab:[0.40630656,0.13294236,0.04059843], key:"antiqueruby" },
+    crate::nearest::Node{ lab:[0.39871502,0.12943153,0.06454199], key:"oucrimsonred" },
+    crate::nearest::Node{ lab:[0.42434382,0.15669264,0.07014515], key:"carmine" },
+    crate::nearest::Node{ lab:[0.45655653,0.15621136,0.051157773], key:"crimsonua" },
+    crate::nearest::Node{ lab:[0.53334314,0.1879744,0.04876054], key:"marooncrayola" },
+    crate::nearest::Node{ lab:[0.5308306,0.18553981,0.06584733], key:"cardinal" },
+    crate::nearest::Node{ lab:[0.4666701,0.1415133,0.053767174], key:"japanesecarmine" },
+    crate::nearest::Node{ lab:[0.500819,0.13323906,0.06772583], key:"sweetbrown" },
+    crate::nearest::Node{ lab:[0.51965606,0.19565533,0.070051745], key:"redncs" },
+    crate::nearest::Node{ lab:[0.52516824,0.20236064,0.0366942], key:"pictorialcarmine" },
+    crate::nearest::Node{ lab:[0.49677107,0.1603672,0.081059314], key:"firebrick" },
+    crate::nearest::Node{ lab:[0.46733496,0.15830497,0.09332969], key:"rojospanishred" },
+    crate::nearest::Node{ lab:[0.39985687,0.14318381,0.08013389], key:"darkred" },
+    crate::nearest::Node{ lab:[0.3766921,0.13488878,0.07549152], key:"maroonweb" },
+    crate::nearest::Node{ lab:[0.47179124,0.15114202,0.09183609], key:"rufous" },
+    crate::nearest::Node{ lab:[0.4933732,0.1647346,0.085735574], key:"carnelian" },
+    crate::nearest::Node{ lab:[0.48870045,0.16116302,0.072035216], key:"upsdellred" },
+    crate::nearest::Node{ lab:[0.5258339,0.18784505,0.09718028], key:"venetianred" },
+    crate::nearest::Node{ lab:[0.54039615,0.15499839,0.08437138], key:"internationalorangegoldengatebridge" },
+    crate::nearest::Node{ lab:[0.5241671,0.129172,0.07077586], key:"mediumcarmine" },
+    crate::nearest::Node{ lab:[0.5031413,0.17027196,0.096513115], key:"internationalorangeengineering" },
+    crate::nearest::Node{ lab:[0.5462857,0.19561817,0.109479174], key:"rossocorsa" },
+    crate::nearest::Node{ lab:[0.5422226,0.19254248,0.09413338], key:"lava" },
+    crate::nearest::Node{ lab:[0.5481551,0.18155994,0.054443844], key:"frenchraspberry" },
+    crate::nearest::Node{ lab:[0.60535246,0.17588018,0.104889214], key:"chilired" },
+    crate::nearest::Node{ lab:[0.60088897,0.064687744,0.070020996], key:"brownsugar" },
+    crate::nearest::Node{ lab:[0.5769047,0.16625935,0.049606152], key:"brickred" },
+    crate::nearest::Node{ lab:[0.5619146,0.12609582,0.060914993], key:"deepchestnut" },
+    crate::nearest::Node{ lab:[0.5519774,0.0877183,0.045064848], key:"redwood" },
+    crate::nearest::Node{ lab:[0.58349097,0.15591414,0.06402495], key:"englishvermillion" },
+    crate::nearest::Node{ lab:[0.5593959,0.17093518,0.082863495], key:"persianred" },
+    crate::nearest::Node{ lab:[0.5597858,0.17195657,0.07990361], key:"madderlake" },
+    crate::nearest::Node{ lab:[0.61544085,0.1334389,0.05453257], key:"indianred" },
+    crate::nearest::Node{ lab:[0.63647336,0.100233085,0.07662549], key:"copperred" },
+    crate::nearest::Node{ lab:[0.603224,0.124929294,0.074023925], key:"cedarchest" },
+    crate::nearest::Node{ lab:[0.6179532,0.0578433,0.10181905], key:"copper" },
+    crate::nearest::Node{ lab:[0.61289644,0.17466073,0.097392306], key:"vermilion" },
+    crate::nearest::Node{ lab:[0.61289644,0.17466073,0.097392306], key:"cinnabar" },
+    crate::nearest::Node{ lab:[0.60265964,0.09162328,0.11796342], key:"alloyorange" },
+    crate::nearest::Node{ lab:[0.5685533,0.1449517,0.11148068], key:"sinopia" },
+    crate::nearest::Node{ lab:[0.5948405,0.118258566,0.12027619], key:"burntorange" },
+    crate::nearest::Node{ lab:[0.5485056,0.13415419,0.11062811], key:"mahogany" },
+    crate::nearest::Node{ lab:[0.59917116,0.11648875,0.121180944], key:"tennetawny" },
+    crate::nearest::Node{ lab:[0.58779675,0.14764543,0.11850935], key:"syracuseorange" },
+    crate::nearest::Node{ lab:[0.5845523,0.17098334,0.10631744], key:"vermilion2" },
+    crate::nearest::Node{ lab:[0.6542429,0.13125299,0.13227418], key:"spanishorange" },
+    crate::nearest::Node{ lab:[0.6343984,0.09907391,0.11919316], key:"chocolateweb" },
+    crate::nearest::Node{ lab:[0.6061719,0.06484345,0.106216796], key:"liverdogs" },
+    crate::nearest::Node{ lab:[0.64956224,0.071006596,0.12109882], key:"ochre" },
+    crate::nearest::Node{ lab:[0.648952,0.1473968,0.13101345], key:"persimmon" },
+    crate::nearest::Node{ lab:[0.63446414,0.14165314,0.116478436], key:"flame" },
+    crate::nearest::Node{ lab:[0.65297973,0.21102782,0.08721516], key:"redsalsa" },
+    crate::nearest::Node{ lab:[0.5878832,0.22826861,0.039510995], key:"raspberry" },
+    crate::nearest::Node{ lab:[0.55749923,0.21295086,0.0669858], key:"carminemp" },
+    crate::nearest::Node{ lab:[0.583409,0.19416402,0.072637744], key:"alizarin" },
+    crate::nearest::Node{ lab:[0.58093977,0.19426128,0.07180953], key:"rustyred" },
+    crate::nearest::Node{ lab:[0.5711893,0.20843765,0.07622524], key:"crimson" },
+    crate::nearest::Node{ lab:[0.5634484,0.21867189,0.053760085], key:"uared" },
+    crate::nearest::Node{ lab:[0.58436847,0.22521165,0.035304293], key:"ruby" },
+    crate::nearest::Node{ lab:[0.6115422,0.2205732,0.06972633], key:"redcrayola" },
+    crate::nearest::Node{ lab:[0.6216562,0.19801016,0.04693353], key:"paradisepink" },
+    crate::nearest::Node{ lab:[0.59789073,0.2049457,0.035737816], key:"cerise" },
+    crate::nearest::Node{ lab:[0.6566428,0.21818313,0.075901814], key:"sizzlingred" },
+    crate::nearest::Node{ lab:[0.6075652,0.2282293,0.087175235], key:"redmunsell" },
+    crate::nearest::Node{ lab:[0.65618056,0.22400112,0.064596444], key:"radicalred" },
+    crate::nearest::Node{ lab:[0.60301495,0.20914295,0.1065032], key:"redpigment" },
+    crate::nearest::Node{ lab:[0.59144425,0.2010608,0.08883291], key:"rosemadder" },
+    crate::nearest::Node{ lab:[0.5904966,0.19200198,0.09763226], key:"permanentgeraniumlake" },
+    crate::nearest::Node{ lab:[0.5482022,0.18572602,0.08868106], key:"fireenginered" },
+    crate::nearest::Node{ lab:[0.5683348,0.1910856,0.10008968], key:"maximumred" },
+    crate::nearest::Node{ lab:[0.5826792,0.2140876,0.09982712], key:"spanishred" },
+    crate::nearest::Node{ lab:[0.5769067,0.21161732,0.09355546], key:"mediumcandyapplered" },
+    crate::nearest::Node{ lab:[0.63802433,0.21208858,0.12350716], key:"redryb" },
+    crate::nearest::Node{ lab:[0.61150044,0.20717202,0.09175999], key:"redpantone" },
+    crate::nearest::Node{ lab:[0.61150044,0.20717202,0.09175999], key:"imperialred" },
+    crate::nearest::Node{ lab:[0.64976555,0.19891308,0.13060151], key:"coquelicot" },
+    crate::nearest::Node{ lab:[0.63788545,0.21288706,0.12801369], key:"scarlet" },
+    crate::nearest::Node{ lab:[0.6279554,0.22486307,0.1258463], key:"red" },
+    crate::nearest::Node{ lab:[0.8434354,0.0024134587,0.1466686], key:"maizecrayola" },
+    crate::nearest::Node{ lab:[0.9778096,-0.012259016,0.056911167], key:"lemonchiffon" },
+    crate::nearest::Node{ lab:[0.81362313,-0.011055388,-0.04135573], key:"lightsteelblue" },
+    crate::nearest::Node{ lab:[0.7228985,-0.052397456,-0.10935344], key:"bluejeans" },
+    crate::nearest::Node{ lab:[0.7025225,-0.09848188,-0.01905639], key:"verdigris" },
+    crate::nearest::Node{ lab:[0.733372,-0.111287944,-0.029683698], key:"maximumbluegreen" },
+    crate::nearest::Node{ lab:[0.6934368,-0.089559786,-0.08776085], key:"ceruleancrayola" },
+    crate::nearest::Node{ lab:[0.68028927,-0.09203423,-0.07344979], key:"pacificblue" },
+    crate::nearest::Node{ lab:[0.7271483,-0.09551729,-0.10340074], key:"cyanprocess" },
+    crate::nearest::Node{ lab:[0.7130614,-0.11916665,-0.02391171], key:"tiffanyblue" },
+    crate::nearest::Node{ lab:[0.6958523,-0.07548052,-0.0708166], key:"maximumblue" },
+    crate::nearest::Node{ lab:[0.66012114,-0.08028149,-0.11484271], key:"unitednationsblue" },
+    crate::nearest::Node{ lab:[0.7855614,-0.10589181,-0.103225954], key:"vividskyblue" },
+    crate::nearest::Node{ lab:[0.76526356,-0.12631334,-0.0333002], key:"robineggblue" },
+    crate::nearest::Node{ lab:[0.7719288,-0.12593356,-0.03764616], key:"darkturquoise" },
+    crate::nearest::Node{ lab:[0.7868021,-0.11386521,-0.023286536], key:"mediumturquoise" },
+    crate::nearest::Node{ lab:[0.78544587,-0.053845476,-0.099283025], key:"mayablue" },
+    crate::nearest::Node{ lab:[0.75535,-0.0952156,-0.12030096], key:"deepskyblue" },
+    crate::nearest::Node{ lab:[0.691201,-0.11281922,-0.01779147], key:"lightseagreen" },
+    crate::nearest::Node{ lab:[0.6594793,-0.10550399,0.009653714], key:"zomp" },
+    crate::nearest::Node{ lab:[0.664073,-0.1197322,0.022456335], key:"junglegreen" },
+    crate::nearest::Node{ lab:[0.6873873,-0.10703216,-0.0015293075], key:"keppel" },
+    crate::nearest::Node{ lab:[0.6607896,-0.13185017,0.042578448], key:"greencrayola" },
+    crate::nearest::Node{ lab:[0.6681455,-0.091143936,0.044290643], key:"shinyshamrock" },
+    crate::nearest::Node{ lab:[0.66698045,-0.07652271,0.004523079], key:"polishedpine" },
+    crate::nearest::Node{ lab:[0.7037532,-0.06878816,0.00012153621], key:"greensheen" },
+    crate::nearest::Node{ lab:[0.69266343,-0.119237795,0.03182192], key:"mint" },
+    crate::nearest::Node{ lab:[0.70622337,-0.12848857,0.029459594], key:"mountainmeadow" },
+    crate::nearest::Node{ lab:[0.7499994,-0.14987393,0.034840956], key:"caribbeangreen" },
+    crate::nearest::Node{ lab:[0.7508648,-0.06507693,0.046025883], key:"darkseagreen" },
+    crate::nearest::Node{ lab:[0.7871764,-0.066141725,0.035492998], key:"etonblue" },
+    crate::nearest::Node{ lab:[0.71474874,-0.02469291,-0.029934056], key:"pewterblue" },
+    crate::nearest::Node{ lab:[0.69366586,-0.031026315,-0.1001605], key:"littleboyblue" },
+    crate::nearest::Node{ lab:[0.66757596,-0.03303534,-0.08785054], key:"livid" },
+    crate::nearest::Node{ lab:[0.66757596,-0.03303534,-0.08785054], key:"bluegraycrayola" },
+    crate::nearest::Node{ lab:[0.6789742,-0.05159262,-0.092394896], key:"carolinablue" },
+    crate::nearest::Node{ lab:[0.68508023,-0.03455203,-0.060433626], key:"airsuperiorityblue" },
+    crate::nearest::Node{ lab:[0.671872,-0.0321679,-0.070735864], key:"ceruleanfrost" },
+    crate::nearest::Node{ lab:[0.674622,-0.021289019,-0.1397445], key:"cornflowerblue" },
+    crate::nearest::Node{ lab:[0.76067,-0.03547555,-0.118509814], key:"frenchskyblue" },
+    crate::nearest::Node{ lab:[0.7050169,-0.036936592,-0.07737939], key:"iceberg" },
+    crate::nearest::Node{ lab:[0.7619825,-0.04263658,-0.08215846], key:"aero" },
+    crate::nearest::Node{ lab:[0.7746906,-0.040988285,-0.04741452], key:"darkskyblue" },
+    crate::nearest::Node{ lab:[0.75087583,0.00127862,-0.051928114], key:"wildblueyonder" },
+    crate::nearest::Node{ lab:[0.80110127,-0.030285722,-0.05297335], key:"palecerulean" },
+    crate::nearest::Node{ lab:[0.76780564,-0.0299782,0.032927066], key:"laurelgreen" },
+    crate::nearest::Node{ lab:[0.6796803,5.5022154e-11,2.533434e-08], key:"spanishgray" },
+    crate::nearest::Node{ lab:[0.69105965,-0.025575008,-0.019716434], key:"tourmaline" },
+    crate::nearest::Node{ lab:[0.70565987,-0.014236823,-0.023932874], key:"cadetgrey" },
+    crate::nearest::Node{ lab:[0.6954205,-0.0278135,0.006738201], key:"morningblue" },
+    crate::nearest::Node{ lab:[0.7444284,6.0263516e-11,2.7747756e-08], key:"silverchalice" },
+    crate::nearest::Node{ lab:[0.72515714,5.87036e-11,2.7029442e-08], key:"quicksilver" },
+    crate::nearest::Node{ lab:[0.80963826,-0.0017398313,-0.0019868773], key:"silversand" },
+    crate::nearest::Node{ lab:[0.78956074,-0.016317919,0.008143105], key:"ashgray" },
+    crate::nearest::Node{ lab:[0.79496133,-0.030834874,0.0010199478], key:"opal" },
+    crate::nearest::Node{ lab:[0.7829122,-0.03929016,0.01741479], key:"cambridgeblue" },
+    crate::nearest::Node{ lab:[0.80779624,6.539341e-11,3.010972e-08], key:"silver" },
+    crate::nearest::Node{ lab:[0.8015167,6.4884986e-11,2.987566e-08], key:"grayx11gray" },
+    crate::nearest::Node{ lab:[0.8688809,-0.03411867,-0.047426894], key:"uranianblue" },
+    crate::nearest::Node{ lab:[0.9502116,-0.07232947,-0.020683033], key:"celeste" },
+    crate::nearest::Node{ lab:[0.8562331,-0.037679408,-0.031231297], key:"lightblue" },
+    crate::nearest::Node{ lab:[0.81990635,-0.05486208,-0.063845955], key:"babyblue" },
+    crate::nearest::Node{ lab:[0.8266558,-0.080890864,-0.05015651], key:"skybluecrayola" },
+    crate::nearest::Node{ lab:[0.81481725,-0.057156272,-0.05868004], key:"skyblue" },
+    crate::nearest::Node{ lab:[0.8219718,-0.073177464,-0.046766132], key:"middleblue" },
+    crate::nearest::Node{ lab:[0.81649417,-0.044827566,-0.05680809], key:"lightcornflowerblue" },
+    crate::nearest::Node{ lab:[0.8206188,-0.051824216,-0.07905587], key:"lightskyblue" },
+    crate::nearest::Node{ lab:[0.8859068,-0.052159514,-0.027962636], key:"blizzardblue" },
+    crate::nearest::Node{ lab:[0.9053992,-0.14944394,-0.039398156], key:"cyan" },
+    crate::nearest::Node{ lab:[0.9053992,-0.14944394,-0.039398156], key:"aqua" },
+    crate::nearest::Node{ lab:[0.9050652,-0.14986442,-0.038085785], key:"spanishskyblue" },
+    crate::nearest::Node{ lab:[0.8750832,-0.045226052,-0.021795848], key:"powderblue" },
+    crate::nearest::Node{ lab:[0.86381805,-0.049678788,-0.037397824], key:"nonphotoblue" },
+    crate::nearest::Node{ lab:[0.89055794,-0.17143585,0.025142089], key:"seagreencrayola" },
+    crate::nearest::Node{ lab:[0.83201855,-0.07770111,-0.0032345878], key:"middlebluegreen" },
+    crate::nearest::Node{ lab:[0.8151864,-0.12546168,0.03844431], key:"mediumaquamarine" },
+    crate::nearest::Node{ lab:[0.8223342,-0.13022855,-0.011597303], key:"turquoise" },
+    crate::nearest::Node{ lab:[0.8870849,-0.1778066,0.042535026], key:"technobotanica" },
+    crate::nearest::Node{ lab:[0.85987616,-0.07284497,0.048754267], key:"celadon" },
+    crate::nearest::Node{ lab:[0.8292749,-0.06747754,0.028956167], key:"turquoisegreen" },
+    crate::nearest::Node{ lab:[0.9502116,-0.07232947,-0.020683033], key:"italianskyblue" },
+    crate::nearest::Node{ lab:[0.90020925,-0.07939158,0.02075705], key:"magicmint" },
+    crate::nearest::Node{ lab:[0.90023583,-0.1562836,-0.018469727], key:"turquoiseblue" },
+    crate::nearest::Node{ lab:[0.91499454,-0.12798631,0.024896637], key:"aquamarine" },
+    crate::nearest::Node{ lab:[0.91969395,-0.051175892,0.050747965], key:"teagreen" },
+    crate::nearest::Node{ lab:[0.9745583,-0.03594397,0.03831461], key:"nyanza" },
+    crate::nearest::Node{ lab:[0.9190806,0.0002754717,0.0028923708], key:"platinum" },
+    crate::nearest::Node{ lab:[0.86772346,-0.02561598,-0.033479523], key:"columbiablue" },
+    crate::nearest::Node{ lab:[0.8485028,-0.0011151101,-0.036492337], key:"periwinklecrayola" },
+    crate::nearest::Node{ lab:[0.8577632,-0.01780857,-0.031273633], key:"beaublue" },
+    crate::nearest::Node{ lab:[0.8231182,-0.027018836,-0.064911544], key:"babyblueeyes" },
+    crate::nearest::Node{ lab:[0.85677326,-0.014817796,-0.030396534], key:"paleaqua" },
+    crate::nearest::Node{ lab:[0.8668631,7.01752e-11,3.2311373e-08], key:"lightgray" },
+    crate::nearest::Node{ lab:[0.84430397,0.0019523985,-0.03122727], key:"lightperiwinkle" },
+    crate::nearest::Node{ lab:[0.8944904,7.2411466e-11,3.3341152e-08], key:"gainsboro" },
+    crate::nearest::Node{ lab:[0.9778582,-0.030687958,-0.009171604], key:"lightcyan" },
+    crate::nearest::Node{ lab:[0.97514284,-0.005500548,-0.011404169], key:"aliceblue" },
+    crate::nearest::Node{ lab:[0.98895067,-0.015041059,-0.0045705023], key:"azurex11webcolor" },
+    crate::nearest::Node{ lab:[1.0,8.0952856e-11,3.7273907e-08], key:"white" },
+    crate::nearest::Node{ lab:[0.97015077,7.8536455e-11,3.616131e-08], key:"culturedpearl" },
+    crate::nearest::Node{ lab:[0.98001367,0.0007264414,0.023535365], key:"cosmiclatte" },
+    crate::nearest::Node{ lab:[0.9364041,-0.0014792025,0.027220368], key:"eggshell" },
+    crate::nearest::Node{ lab:[0.9773007,-0.003531187,0.03708985], key:"cornsilk" },
+    crate::nearest::Node{ lab:[0.9635736,-0.009584834,0.031351846], key:"beige" },
+    crate::nearest::Node{ lab:[0.9750065,-0.015435947,0.049487032], key:"lightgoldenrodyellow" },
+    crate::nearest::Node{ lab:[0.9342337,-0.0005681052,0.031605452], key:"parchment" },
+    crate::nearest::Node{ lab:[0.8809152,0.002270903,0.0077844053], key:"timberwolf" },
+    crate::nearest::Node{ lab:[0.9959763,-0.0056533646,0.018783517], key:"ivory" },
+    crate::nearest::Node{ lab:[0.99200743,-0.011841024,0.038462907], key:"lightyellow" },
+    crate::nearest::Node{ lab:[0.991172,-0.011939205,0.003240988], key:"mintcream" },
+    crate::nearest::Node{ lab:[0.98440266,-0.015316982,0.055935122], key:"cream" },
+    crate::nearest::Node{ lab:[0.98623276,0.0013425166,0.014156463], key:"floralwhite" },
+    crate::nearest::Node{ lab:[0.99592274,-0.0014921264,0.005039103], key:"babypowder" },
+    crate::nearest::Node{ lab:[0.88175315,-0.18977894,0.18217745], key:"lawngreen" },
+    crate::nearest::Node{ lab:[0.7671261,-0.07245521,0.1526088], key:"acidgreen" },
+    crate::nearest::Node{ lab:[0.7394971,-0.18077563,0.13696559], key:"stronglimegreen" },
+    crate::nearest::Node{ lab:[0.76856756,-0.08565976,0.08907123], key:"pistachio" },
+    crate::nearest::Node{ lab:[0.68404204,-0.13052309,0.060866643], key:"mediumseagreen" },
+    crate::nearest::Node{ lab:[0.74513996,-0.1386491,0.075160146], key:"parisgreen" },
+    crate::nearest::Node{ lab:[0.74513996,-0.1386491,0.075160146], key:"emerald" },
+    crate::nearest::Node{ lab:[0.6681099,-0.16496858,0.13199042], key:"yellowgreencolorwheel" },
+    crate::nearest::Node{ lab:[0.74419236,-0.11621824,0.095758386], key:"mantis" },
+    crate::nearest::Node{ lab:[0.71561813,-0.095898986,0.09009619], key:"budgreen" },
+    crate::nearest::Node{ lab:[0.79303676,-0.16201735,0.07902775], key:"androidgreen" },
+    crate::nearest::Node{ lab:[0.8749299,-0.20581263,0.11397103], key:"springgreen" },
+    crate::nearest::Node{ lab:[0.86680585,-0.19017537,0.081097126], key:"mediumspringgreen" },
+    crate::nearest::Node{ lab:[0.774386,-0.19197132,0.12536094], key:"malachite" },
+    crate::nearest::Node{ lab:[0.86800325,-0.126184,0.09138165], key:"lightgreen" },
+    crate::nearest::Node{ lab:[0.8609429,-0.086786166,0.06866777], key:"grannysmithapple" },
+    crate::nearest::Node{ lab:[0.86643964,-0.23388757,0.17949848], key:"green" },
+    crate::nearest::Node{ lab:[0.78485227,-0.10964247,0.14744212], key:"yellowgreen" },
+    crate::nearest::Node{ lab:[0.6998742,-0.15910152,0.1399122], key:"kellygreen" },
+    crate::nearest::Node{ lab:[0.74187446,-0.18220717,0.13812841], key:"limegreen" },
+    crate::nearest::Node{ lab:[0.79333663,-0.13411617,0.16353215], key:"sheengreen" },
+    crate::nearest::Node{ lab:[0.86048573,-0.088260636,0.17524326], key:"bitterlemon" },
+    crate::nearest::Node{ lab:[0.84146273,-0.08088439,0.1373768], key:"junebud" },
+    crate::nearest::Node{ lab:[0.8721586,-0.22311376,0.18055767], key:"harlequin" },
+    crate::nearest::Node{ lab:[0.87143195,-0.22389737,0.1779002], key:"neongreen" },
+    crate::nearest::Node{ lab:[0.86849844,-0.22611961,0.16185355], key:"erin" },
+    crate::nearest::Node{ lab:[0.86643964,-0.23388757,0.17949848], key:"limewebx11green" },
+    crate::nearest::Node{ lab:[0.87127584,-0.07927928,0.16766147], key:"pear" },
+    crate::nearest::Node{ lab:[0.8752925,-0.11292277,0.14272448], key:"inchworm" },
+    crate::nearest::Node{ lab:[0.87046516,-0.021160008,0.12771575], key:"arylideyellow" },
+    crate::nearest::Node{ lab:[0.76747406,-0.02457125,0.094910465], key:"darkkhaki" },
+    crate::nearest::Node{ lab:[0.76352763,-0.01646215,0.11264559], key:"vegasgold" },
+    crate::nearest::Node{ lab:[0.74568194,-0.062101237,0.07925277], key:"olivine" },
+    crate::nearest::Node{ lab:[0.76135266,-0.017523883,0.07899401], key:"mistymoss" },
+    crate::nearest::Node{ lab:[0.7498437,-0.033294104,0.10494136], key:"olivegreen" },
+    crate::nearest::Node{ lab:[0.7650061,-0.0029296859,0.06904687], key:"sand" },
+    crate::nearest::Node{ lab:[0.7650061,-0.0029296859,0.06904687], key:"ecru" },
+    crate::nearest::Node{ lab:[0.77433413,-0.01458821,0.060391244], key:"sage" },
+    crate::nearest::Node{ lab:[0.76957154,-0.059050318,0.10775094], key:"middlegreenyellow" },
+    crate::nearest::Node{ lab:[0.8726998,-0.06886237,0.104780324], key:"yellowgreencrayola" },
+    crate::nearest::Node{ lab:[0.8727635,-0.029674098,0.12492052], key:"straw" },
+    crate::nearest::Node{ lab:[0.8409967,-0.006969507,0.10316134], key:"prairiegold" },
+    crate::nearest::Node{ lab:[0.848379,0.00010763084,0.06616671], key:"lion" },
+    crate::nearest::Node{ lab:[0.8495819,-0.019866327,0.17382948], key:"yellowmunsell" },
+    crate::nearest::Node{ lab:[0.7655302,-0.0037478162,0.13842352], key:"metallicgold" },
+    crate::nearest::Node{ lab:[0.77422416,-0.016499305,0.13833281], key:"oldgold" },
+    crate::nearest::Node{ lab:[0.7036615,-0.060952857,0.13672987], key:"citron" },
+    crate::nearest::Node{ lab:[0.8476691,-0.0378065,0.17242062], key:"citrine" },
+    crate::nearest::Node{ lab:[0.83987963,0.002247191,0.15978712], key:"saffron" },
+    crate::nearest::Node{ lab:[0.7665278,-0.0025638866,0.13865352], key:"goldmetallic" },
+    crate::nearest::Node{ lab:[0.87888414,-0.011234227,0.17972594], key:"yellowncs" },
+    crate::nearest::Node{ lab:[0.86080337,-0.029440835,0.1759889], key:"safetyyellow" },
+    crate::nearest::Node{ lab:[0.87046516,-0.021160008,0.12771575], key:"hansayellow" },
+    crate::nearest::Node{ lab:[0.87888414,-0.011234227,0.17972594], key:"cyberyellow" },
+    crate::nearest::Node{ lab:[0.85075617,-0.009873688,0.17078537], key:"jonquil" },
+    crate::nearest::Node{ lab:[0.88170266,-0.003920474,0.13340665], key:"orangeyellowcrayola" },
+    crate::nearest::Node{ lab:[0.94796807,-0.0472757,0.17040591], key:"lemonyellow" },
+    crate::nearest::Node{ lab:[0.9017266,-0.15614162,0.1859131], key:"springbud" },
+    crate::nearest::Node{ lab:[0.9019865,-0.05707731,0.18493222], key:"titaniumyellow" },
+    crate::nearest::Node{ lab:[0.90086234,-0.15933226,0.17252006], key:"frenchlime" },
+    crate::nearest::Node{ lab:[0.8943956,-0.18150751,0.17676596], key:"springfrost" },
+    crate::nearest::Node{ lab:[0.8866904,-0.18789291,0.14014867], key:"screamingreen" },
+    crate::nearest::Node{ lab:[0.89065707,-0.1898203,0.18399782], key:"chartreuseweb" },
+    crate::nearest::Node{ lab:[0.88421834,-0.14282906,0.17102946], key:"greenlizard" },
+    crate::nearest::Node{ lab:[0.8880557,-0.06925072,0.1548013], key:"maximumgreenyellow" },
+    crate::nearest::Node{ lab:[0.972323,-0.05635208,0.16178836], key:"laserlemon" },
+    crate::nearest::Node{ lab:[0.94219154,-0.06647505,0.12378842], key:"mindaro" },
+    crate::nearest::Node{ lab:[0.9128293,-0.13609613,0.09871494], key:"mintgreen" },
+    crate::nearest::Node{ lab:[0.95485103,-0.06554036,0.18413043], key:"maximumyellow" },
+    crate::nearest::Node{ lab:[0.972323,-0.05635208,0.16178836], key:"unmellowyellow" },
+    crate::nearest::Node{ lab:[0.9359678,-0.053039894,0.117406525], key:"keylime" },
+    crate::nearest::Node{ lab:[0.93504834,-0.050148096,0.1916157], key:"canaryyellow" },
+    crate::nearest::Node{ lab:[0.93359166,-0.119870834,0.1897967], key:"arcticlime" },
+    crate::nearest::Node{ lab:[0.9307076,-0.1247976,0.19151096], key:"electriclime" },
+    crate::nearest::Node{ lab:[0.92248356,-0.13744457,0.18996166], key:"limecolorwheel" },
+    crate::nearest::Node{ lab:[0.93202084,-0.12280894,0.19175865], key:"volt" },
+    crate::nearest::Node{ lab:[0.9312855,-0.051039606,0.19085613], key:"aureolin" },
+    crate::nearest::Node{ lab:[0.9167306,-0.06603685,0.18711463], key:"xanthic" },
+    crate::nearest::Node{ lab:[0.9514512,-0.060853295,0.19508065], key:"lemon" },
+    crate::nearest::Node{ lab:[0.96637905,-0.07354742,0.19826493], key:"lemonglacier" },
+    crate::nearest::Node{ lab:[0.9661926,-0.06710487,0.18822882], key:"yellowryb" },
+    crate::nearest::Node{ lab:[0.9679827,-0.07136908,0.19856976], key:"yellow" },
+    crate::nearest::Node{ lab:[0.9514512,-0.060853295,0.19508065], key:"yellowsunshine" },
+    crate::nearest::Node{ lab:[0.93504834,-0.050148096,0.1916157], key:"yellowprocess" },
+    crate::nearest::Node{ lab:[0.89241236,-0.012106699,0.14489496], key:"naplesyellow" },
+    crate::nearest::Node{ lab:[0.9264093,-0.018049283,0.12247107], key:"yellowcrayola" },
+    crate::nearest::Node{ lab:[0.9022801,-0.009653909,0.11939091], key:"mellowyellow" },
+    crate::nearest::Node{ lab:[0.89004815,-0.016247185,0.11146374], key:"flax" },
+    crate::nearest::Node{ lab:[0.91348904,-0.024851901,0.10912827], key:"khakix11lightkhaki" },
+    crate::nearest::Node{ lab:[0.9022801,-0.009653909,0.11939091], key:"jasmine" },
+    crate::nearest::Node{ lab:[0.9193431,-0.008144359,0.07554775], key:"vanilla" },
+    crate::nearest::Node{ lab:[0.9193431,-0.008144359,0.07554775], key:"mediumchampagne" },
+    crate::nearest::Node{ lab:[0.92938066,-0.017003464,0.057478182], key:"springgreencrayola" },
+    crate::nearest::Node{ lab:[0.97926265,-0.037237216,0.11197589], key:"lemonyellowcrayola" },
+    crate::nearest::Node{ lab:[0.92938066,-0.017003464,0.057478182], key:"palespringbud" },
+    crate::nearest::Node{ lab:[0.9783526,-0.039475273,0.118012846], key:"canary" },
+    crate::nearest::Node{ lab:[0.93118674,0.0003660179,0.06769225], key:"bananamania" },
+    crate::nearest::Node{ lab:[0.93565625,-0.0046725795,0.058380373], key:"lemonmeringue" },
+    crate::nearest::Node{ lab:[0.8984579,-0.009655347,0.1510146], key:"mustard" },
+    crate::nearest::Node{ lab:[0.89241236,-0.012106699,0.14489496], key:"royalyellow" },
+    crate::nearest::Node{ lab:[0.8887507,-0.018341359,0.18181774], key:"schoolbusyellow" },
+    crate::nearest::Node{ lab:[0.8867711,-0.016925177,0.18139815], key:"goldwebgolden" },
+    crate::nearest::Node{ lab:[0.89470756,-0.022573857,0.18307999], key:"sizzlingsunrise" },
+    crate::nearest::Node{ lab:[0.89207566,-0.0049795937,0.14544499], key:"dandelion" },
+    crate::nearest::Node{ lab:[0.89241236,-0.012106699,0.14489496], key:"stildegrainyellow" },
+    crate::nearest::Node{ lab:[0.89873695,-0.030196218,0.15694562], key:"minionyellow" },
+    crate::nearest::Node{ lab:[0.9293905,-0.0370071,0.15626031], key:"maize" },
+    crate::nearest::Node{ lab:[0.9293905,-0.0370071,0.15626031], key:"corn" },
+    crate::nearest::Node{ lab:[0.9268999,-0.044725,0.18989323], key:"middleyellow" },
+    crate::nearest::Node{ lab:[0.902691,-0.028179092,0.1847709], key:"goldenyellow" },
+    crate::nearest::Node{ lab:[0.90177155,-0.029324861,0.18459512], key:"yellowpantone" },
+    crate::nearest::Node{ lab:[0.7140696,0.17250608,0.036838844], key:"wildwatermelon" },
+    crate::nearest::Node{ lab:[0.76643074,0.12728803,0.01271726], key:"pinksherbet" },
+    crate::nearest::Node{ lab:[0.698138,0.14361249,-0.09250931], key:"frenchmauve" },
+    crate::nearest::Node{ lab:[0.6736623,0.1356534,-0.023611246], key:"wildorchid" },
+    crate::nearest::Node{ lab:[0.69326174,0.040960018,-0.03699323], key:"glossygrape" },
+    crate::nearest::Node{ lab:[0.6844445,0.0931691,-0.111064225], key:"lavenderfloral" },
+    crate::nearest::Node{ lab:[0.66397166,0.003445274,-0.03899274], key:"coolgrey" },
+    crate::nearest::Node{ lab:[0.67811614,0.07401617,-0.06390462], key:"africanviolet" },
+    crate::nearest::Node{ lab:[0.6744777,0.072788835,-0.028602812], key:"operamauve" },
+    crate::nearest::Node{ lab:[0.6623933,0.13984187,-0.04062725], key:"superpink" },
+    crate::nearest::Node{ lab:[0.6735677,0.13246967,-0.04435135], key:"skymagenta" },
+    crate::nearest::Node{ lab:[0.7633212,0.066960715,-0.06720673], key:"wisteria" },
+    crate::nearest::Node{ lab:[0.7284389,0.016754646,-0.064161815], key:"bluebell" },
+    crate::nearest::Node{ lab:[0.7651713,0.020376336,-0.08006431], key:"maximumbluepurple" },
+    crate::nearest::Node{ lab:[0.75855666,0.05664183,-0.03788465], key:"lilac" },
+    crate::nearest::Node{ lab:[0.7142962,0.11934733,-0.032862008], key:"middlepurple" },
+    crate::nearest::Node{ lab:[0.76056707,0.110637166,-0.10104263], key:"brightlilac" },
+    crate::nearest::Node{ lab:[0.70472443,0.031904373,-0.016921934], key:"lilacluster" },
+    crate::nearest::Node{ lab:[0.66338,0.064493924,-0.005430606], key:"englishlavender" },
+    crate::nearest::Node{ lab:[0.700749,0.026972217,-0.017099082], key:"heliotropegray" },
+    crate::nearest::Node{ lab:[0.6889641,0.0030050627,-0.023417532], key:"manatee" },
+    crate::nearest::Node{ lab:[0.6927437,0.051944137,0.017446116], key:"rosybrown" },
+    crate::nearest::Node{ lab:[0.6655192,0.07563499,0.025135413], key:"oldrose" },
+    crate::nearest::Node{ lab:[0.7002119,0.08560339,0.004783719], key:"puce" },
+    crate::nearest::Node{ lab:[0.7476649,0.110525824,-0.002628669], key:"charmpink" },
+    crate::nearest::Node{ lab:[0.7188058,0.04440322,0.014683069], key:"tuscany" },
+    crate::nearest::Node{ lab:[0.73684347,0.0026859678,-0.0051191566], key:"silvermetallic" },
+    crate::nearest::Node{ lab:[0.7107582,0.10201471,0.014892337], key:"shimmeringblush" },
+    crate::nearest::Node{ lab:[0.76216114,0.12369014,0.008001647], key:"vanillaice" },
+    crate::nearest::Node{ lab:[0.7648209,0.14360544,0.0040836255], key:"ticklemepink" },
+    crate::nearest::Node{ lab:[0.68630165,0.21055643,-0.026757622], key:"magentacrayolabrokenanchor" },
+    crate::nearest::Node{ lab:[0.7021318,0.15488695,-0.094151884], key:"orchid" },
+    crate::nearest::Node{ lab:[0.6663994,0.26918855,-0.08223402], key:"shockingpink" },
+    crate::nearest::Node{ lab:[0.6902502,0.25588375,-0.088693656], key:"razzledazzlerose" },
+    crate::nearest::Node{ lab:[0.68136525,0.26822296,-0.097508684], key:"hotmagentabrokenanchor" },
+    crate::nearest::Node{ lab:[0.66573995,0.25480652,-0.032398026], key:"persianrose" },
+    crate::nearest::Node{ lab:[0.70167387,0.2745663,-0.16915606], key:"magenta" },
+    crate::nearest::Node{ lab:[0.70167387,0.2745663,-0.16915606], key:"fuchsia" },
+    crate::nearest::Node{ lab:[0.7282974,0.195155,-0.027445627], key:"hotpink" },
+    crate::nearest::Node{ lab:[0.7618985,0.15646526,-0.10079778], key:"violetweb" },
+    crate::nearest::Node{ lab:[0.7323573,0.16144742,-0.14556235], key:"heliotrope" },
+    crate::nearest::Node{ lab:[0.74968994,0.1590992,-0.03097481], key:"persianpink" },
+    crate::nearest::Node{ lab:[0.73369676,0.20502734,-0.063169196], key:"rosepink" },
+    crate::nearest::Node{ lab:[0.7144365,0.23358999,-0.09765068], key:"purplepizzazzbrokenanchor" },
+    crate::nearest::Node{ lab:[0.684866,0.14759658,-0.017059375], key:"thulianpink" },
+    crate::nearest::Node{ lab:[0.68193305,0.20607096,-0.0005714924], key:"violetred" },
+    crate::nearest::Node{ lab:[0.684866,0.14759658,-0.017059375], key:"liseranpurple" },
+    crate::nearest::Node{ lab:[0.684866,0.14759658,-0.017059375], key:"chinapink" },
+    crate::nearest::Node{ lab:[0.6803698,0.19479689,-0.013262354], key:"violetredperbang" },
+    crate::nearest::Node{ lab:[0.67290276,0.23190081,-0.0026917544], key:"frenchfuchsia" },
+    crate::nearest::Node{ lab:[0.67277384,0.22933911,-0.021465752], key:"rosebonbon" },
+    crate::nearest::Node{ lab:[0.7140696,0.17250608,0.036838844], key:"ultrared" },
+    crate::nearest::Node{ lab:[0.7185917,0.1447325,0.008025611], key:"paledogwood" },
+    crate::nearest::Node{ lab:[0.71580106,0.17124389,-0.0032591792], key:"cyclamen" },
+    crate::nearest::Node{ lab:[0.7185917,0.1447325,0.008025611], key:"rosepompadour" },
+    crate::nearest::Node{ lab:[0.72216,0.18233037,0.003799145], key:"frenchpink" },
+    crate::nearest::Node{ lab:[0.68508184,0.23487574,-0.025171544], key:"wildstrawberry" },
+    crate::nearest::Node{ lab:[0.8332929,0.036385518,-0.024576474], key:"thistle" },
+    crate::nearest::Node{ lab:[0.9157164,0.035046633,0.0070626573], key:"palepink" },
+    crate::nearest::Node{ lab:[0.8717591,0.034152664,-0.0047209333], key:"queenpink" },
+    crate::nearest::Node{ lab:[0.7684009,0.018758887,0.001534112], key:"blackshadows" },
+    crate::nearest::Node{ lab:[0.8621799,0.018030245,-0.06811222], key:"periwinkle" },
+    crate::nearest::Node{ lab:[0.8621799,0.018030245,-0.06811222], key:"lavenderblue" },
+    crate::nearest::Node{ lab:[0.8219339,0.0060352273,-0.01700964], key:"lavendergray" },
+    crate::nearest::Node{ lab:[0.85445416,0.019898443,-0.021093613], key:"languidlavender" },
+    crate::nearest::Node{ lab:[0.8332929,0.036385518,-0.024576474], key:"lightpurple" },
+    crate::nearest::Node{ lab:[0.9309023,0.0073649315,-0.025915211], key:"lavenderweb" },
+    crate::nearest::Node{ lab:[0.9889369,0.005096968,0.0015824084], key:"snow" },
+    crate::nearest::Node{ lab:[0.98111874,0.0025899603,-0.008898664], key:"ghostwhite" },
+    crate::nearest::Node{ lab:[0.9571188,0.002602273,0.0063585676], key:"isabelline" },
+    crate::nearest::Node{ lab:[0.96833485,0.017342905,-0.0014863464], key:"lavenderblush" },
+    crate::nearest::Node{ lab:[0.9467418,0.0281663,-0.019111315], key:"palepurplepantone" },
+    crate::nearest::Node{ lab:[0.93423027,0.0070579113,0.03666718], key:"champagne" },
+    crate::nearest::Node{ lab:[0.9099956,0.015688717,0.024486162], key:"champagnepink" },
+    crate::nearest::Node{ lab:[0.8909418,0.0028541442,0.02472366], key:"bone" },
+    crate::nearest::Node{ lab:[0.8137107,0.0077162283,0.00962583], key:"silvercrayola" },
+    crate::nearest::Node{ lab:[0.89691275,0.014077313,0.033708297], key:"almond" },
+    crate::nearest::Node{ lab:[0.9208803,0.028734919,0.035510074], key:"unbleachedsilk" },
+    crate::nearest::Node{ lab:[0.7693954,0.023889232,0.009094688], key:"silverpink" },
+    crate::nearest::Node{ lab:[0.97601813,0.007638632,0.012033104], key:"seashell" },
+    crate::nearest::Node{ lab:[0.9723414,0.002528669,0.021410508], key:"oldlace" },
+    crate::nearest::Node{ lab:[0.96023846,0.006529202,0.015857963], key:"linen" },
+    crate::nearest::Node{ lab:[0.93432355,0.0038684292,0.024771003], key:"magnolia" },
+    crate::nearest::Node{ lab:[0.9466915,0.007930012,0.03005429], key:"antiquewhite" },
+    crate::nearest::Node{ lab:[0.9400117,0.027195733,0.012844467], key:"mistyrose" },
+    crate::nearest::Node{ lab:[0.8433153,0.063412085,-0.0032532096], key:"cameopink" },
+    crate::nearest::Node{ lab:[0.8064714,0.053379804,-0.027835254], key:"pinklavender" },
+    crate::nearest::Node{ lab:[0.7862447,0.107568346,-0.006762033], key:"amaranthpink" },
+    crate::nearest::Node{ lab:[0.77755064,0.09834497,-0.045560323], key:"orchidcrayola" },
+    crate::nearest::Node{ lab:[0.78328353,0.08990804,-0.05940838], key:"plumweb" },
+    crate::nearest::Node{ lab:[0.78318053,0.09487713,-0.021202574], key:"kobi" },
+    crate::nearest::Node{ lab:[0.7664454,0.20180194,-0.12808326], key:"ultrapink" },
+    crate::nearest::Node{ lab:[0.7664454,0.20180194,-0.12808326], key:"shockingpinkcrayola" },
+    crate::nearest::Node{ lab:[0.8647448,0.0840641,-0.012812415], key:"cottoncandy" },
+    crate::nearest::Node{ lab:[0.93232346,0.044309434,-0.018053962], key:"pinklace" },
+    crate::nearest::Node{ lab:[0.8273472,0.079052724,-0.08904227], key:"mauve" },
+    crate::nearest::Node{ lab:[0.92350745,0.044793837,-0.006574205], key:"mimipink" },
+    crate::nearest::Node{ lab:[0.82377934,0.09054218,-0.0046847765], key:"nadeshikopink" },
+    crate::nearest::Node{ lab:[0.8225801,0.11225218,-0.010473429], key:"carnationpink" },
+    crate::nearest::Node{ lab:[0.85014665,0.06402474,-0.0015391957], key:"orchidpink" },
+    crate::nearest::Node{ lab:[0.77849925,0.13165261,0.022238977], key:"salmonpink" },
+    crate::nearest::Node{ lab:[0.8473878,0.08471583,0.013548875], key:"lightpink" },
+    crate::nearest::Node{ lab:[0.7723809,0.1055729,0.011838402], key:"mauvelous" },
+    crate::nearest::Node{ lab:[0.7749498,0.0637269,0.023047427], key:"pastelpink" },
+    crate::nearest::Node{ lab:[0.7814978,0.1355992,0.007625718], key:"schausspink" },
+    crate::nearest::Node{ lab:[0.7814978,0.1355992,0.007625718], key:"bakermillerpink" },
+    crate::nearest::Node{ lab:[0.8545352,0.06088945,0.021783961], key:"spanishpink" },
+    crate::nearest::Node{ lab:[0.8581668,0.05449537,0.01805668], key:"babypink" },
+    crate::nearest::Node{ lab:[0.9264804,0.03716367,-0.00066804775], key:"piggypink" },
+    crate::nearest::Node{ lab:[0.8581668,0.05449537,0.01805668], key:"tearose" },
+    crate::nearest::Node{ lab:[0.85022604,0.08463264,0.0091755], key:"cherryblossompink" },
+    crate::nearest::Node{ lab:[0.8677384,0.07298037,0.009071449], key:"pink" },
+    crate::nearest::Node{ lab:[0.7600997,0.011206419,0.06803199], key:"lightfrenchbeige" },
+    crate::nearest::Node{ lab:[0.72301257,0.1205353,0.14644846], key:"heatwave" },
+    crate::nearest::Node{ lab:[0.6661058,0.06317778,0.11536712], key:"bronze" },
+    crate::nearest::Node{ lab:[0.7112805,0.025158571,0.074079186], key:"desert" },
+    crate::nearest::Node{ lab:[0.7084761,0.07860492,0.07643827], key:"coppercrayola" },
+    crate::nearest::Node{ lab:[0.7112805,0.025158571,0.074079186], key:"camel" },
+    crate::nearest::Node{ lab:[0.68693006,0.057047997,0.071690366], key:"antiquebrass" },
+    crate::nearest::Node{ lab:[0.7014031,0.068677716,0.09016683], key:"rawsienna" },
+    crate::nearest::Node{ lab:[0.71024734,0.12044315,0.10866842], key:"mandarin" },
+    crate::nearest::Node{ lab:[0.69765884,0.096113764,0.040836107], key:"newyorkpink" },
+    crate::nearest::Node{ lab:[0.71563435,0.06206681,0.0960094], key:"persianorange" },
+    crate::nearest::Node{ lab:[0.7112805,0.025158571,0.074079186], key:"woodbrown" },
+    crate::nearest::Node{ lab:[0.7112805,0.025158571,0.074079186], key:"fallow" },
+    crate::nearest::Node{ lab:[0.73712116,0.053461716,0.081375346], key:"tancrayola" },
+    crate::nearest::Node{ lab:[0.73085624,0.08924476,0.06974092], key:"middlered" },
+    crate::nearest::Node{ lab:[0.75073624,0.08360267,0.0686568], key:"darksalmon" },
+    crate::nearest::Node{ lab:[0.7289524,0.0076393806,0.13033117], key:"satinsheengold" },
+    crate::nearest::Node{ lab:[0.720404,0.086626016,0.13183147], key:"cadmiumorange" },
+    crate::nearest::Node{ lab:[0.70207286,0.075132914,0.14266525], key:"fulvous" },
+    crate::nearest::Node{ lab:[0.7266282,0.0056879483,0.14179838], key:"lemoncurry" },
+    crate::nearest::Node{ lab:[0.7126682,0.043130405,0.14517687], key:"harvestgold" },
+    crate::nearest::Node{ lab:[0.7221033,0.08984127,0.14659877], key:"tangerine" },
+    crate::nearest::Node{ lab:[0.71930474,0.10471536,0.13548344], key:"princetonorange" },
+    crate::nearest::Node{ lab:[0.75054425,0.094165646,0.15236416], key:"darkorange" },
+    crate::nearest::Node{ lab:[0.7515723,0.015389061,0.14612558], key:"goldenrod" },
+    crate::nearest::Node{ lab:[0.7367027,0.06897664,0.14170894], key:"carrotorange" },
+    crate::nearest::Node{ lab:[0.7431975,0.04078475,0.14892617], key:"gamboge" },
+    crate::nearest::Node{ lab:[0.7303931,0.1133142,0.14803626], key:"orange" },
+    crate::nearest::Node{ lab:[0.73818547,0.11972956,0.12085545], key:"mangotango" },
+    crate::nearest::Node{ lab:[0.67843205,0.18610941,0.096804045], key:"redorange" },
+    crate::nearest::Node{ lab:[0.6847989,0.13687181,0.04115753], key:"tangopink" },
+    crate::nearest::Node{ lab:[0.6655157,0.18864262,0.09575712], key:"tartorange" },
+    crate::nearest::Node{ lab:[0.67738074,0.121221475,0.07853273], key:"terracotta" },
+    crate::nearest::Node{ lab:[0.6847989,0.13687181,0.04115753], key:"candypink" },
+    crate::nearest::Node{ lab:[0.67843205,0.18610941,0.096804045], key:"orangeredcrayola" },
+    crate::nearest::Node{ lab:[0.6687332,0.18879274,0.08304843], key:"strawberry" },
+    crate::nearest::Node{ lab:[0.66450083,0.21463048,0.060297042], key:"neonfuchsia" },
+    crate::nearest::Node{ lab:[0.7382093,0.12966357,0.06384306], key:"coralpink" },
+    crate::nearest::Node{ lab:[0.7382093,0.12966357,0.06384306], key:"congopink" },
+    crate::nearest::Node{ lab:[0.72464097,0.12856539,0.049425825], key:"lightcoral" },
+    crate::nearest::Node{ lab:[0.688548,0.12193472,0.09325795], key:"burntsienna" },
+    crate::nearest::Node{ lab:[0.73500234,0.13369869,0.071269915], key:"salmon" },
+    crate::nearest::Node{ lab:[0.6860612,0.19903857,0.053310435], key:"fieryrose" },
+    crate::nearest::Node{ lab:[0.699229,0.14867465,0.13298854], key:"orangered" },
+    crate::nearest::Node{ lab:[0.67790157,0.16789891,0.1080908], key:"orangesoda" },
+    crate::nearest::Node{ lab:[0.6791711,0.1659046,0.13698436], key:"orangepantone" },
+    crate::nearest::Node{ lab:[0.6962193,0.1652304,0.104540884], key:"tomato" },
+    crate::nearest::Node{ lab:[0.6970609,0.14686182,0.14085329], key:"safetyorangeblazeorange" },
+    crate::nearest::Node{ lab:[0.66019946,0.18694869,0.13286985], key:"redorangecolorwheel" },
+    crate::nearest::Node{ lab:[0.68393934,0.1718315,0.115283534], key:"portlandorange" },
+    crate::nearest::Node{ lab:[0.71828574,0.13584997,0.12350517], key:"orangecrayola" },
+    crate::nearest::Node{ lab:[0.7164151,0.12967265,0.1397271], key:"pumpkin" },
+    crate::nearest::Node{ lab:[0.73511285,0.12822507,0.10853774], key:"coral" },
+    crate::nearest::Node{ lab:[0.7201215,0.12339523,0.1458261], key:"safetyorange" },
+    crate::nearest::Node{ lab:[0.699229,0.14867465,0.13298854], key:"redorangecrayola" },
+    crate::nearest::Node{ lab:[0.71055007,0.15107597,0.1063064], key:"outrageousorange" },
+    crate::nearest::Node{ lab:[0.860051,0.028716203,0.045561172], key:"desertsand" },
+    crate::nearest::Node{ lab:[0.89487654,0.019288117,0.07257394], key:"deepchampagne" },
+    crate::nearest::Node{ lab:[0.91640216,0.015881488,0.07125776], key:"navajowhite" },
+    crate::nearest::Node{ lab:[0.78618664,0.016927637,0.061535213], key:"tan" },
+    crate::nearest::Node{ lab:[0.9074287,0.002742358,0.05039456], key:"dutchwhite" },
+    crate::nearest::Node{ lab:[0.7653756,0.008314246,0.046966955], key:"khakiweb" },
+    crate::nearest::Node{ lab:[0.9088334,0.0074598812,0.061044525], key:"wheat" },
+    crate::nearest::Node{ lab:[0.904809,0.025361622,0.061396483], key:"lightorange" },
+    crate::nearest::Node{ lab:[0.91125286,0.026585272,0.053788204], key:"peachpuff" },
+    crate::nearest::Node{ lab:[0.9296221,0.010123965,0.066774465], key:"moccasin" },
+    crate::nearest::Node{ lab:[0.94843936,0.009296485,0.043962296], key:"blanchedalmond" },
+    crate::nearest::Node{ lab:[0.95807993,0.006622367,0.037681177], key:"papayawhip" },
+    crate::nearest::Node{ lab:[0.9313596,0.00825444,0.068757325], key:"peach" },
+    crate::nearest::Node{ lab:[0.91640216,0.015881488,0.07125776], key:"skincolor" },
+    crate::nearest::Node{ lab:[0.9328562,0.016026122,0.048885465], key:"bisque" },
+    crate::nearest::Node{ lab:[0.83630663,0.017021189,0.17047165], key:"mango" },
+    crate::nearest::Node{ lab:[0.80584687,0.022451883,0.1520538], key:"xanthous" },
+    crate::nearest::Node{ lab:[0.8201524,0.02046482,0.14024878], key:"maximumyellowred" },
+    crate::nearest::Node{ lab:[0.8045399,0.022220576,0.07461971], key:"burlywood" },
+    crate::nearest::Node{ lab:[0.82660586,0.011252065,0.16305774], key:"orangeyellow" },
+    crate::nearest::Node{ lab:[0.83088475,0.02518924,0.16952825], key:"selectiveyellow" },
+    crate::nearest::Node{ lab:[0.8245174,0.023109153,0.0784332], key:"goldcrayola" },
+    crate::nearest::Node{ lab:[0.8402875,0.017799418,0.17152889], key:"amber" },
+    crate::nearest::Node{ lab:[0.84995246,0.0110475775,0.17210446], key:"mikadoyellow" },
+    crate::nearest::Node{ lab:[0.86657447,0.003782378,0.16461195], key:"sunglow" },
+    crate::nearest::Node{ lab:[0.84286094,0.009862665,0.1721396], key:"goldenpoppy" },
+    crate::nearest::Node{ lab:[0.89487654,0.019288117,0.07257394], key:"tuscan" },
+    crate::nearest::Node{ lab:[0.89487654,0.019288117,0.07257394], key:"sunset" },
+    crate::nearest::Node{ lab:[0.86273986,0.034961432,0.10310193], key:"buff" },
+    crate::nearest::Node{ lab:[0.7965247,0.09789727,0.06694144], key:"vividtangerine" },
+    crate::nearest::Node{ lab:[0.7788884,0.095769055,0.10115609], key:"atomictangerine" },
+    crate::nearest::Node{ lab:[0.793755,0.09214708,0.08421498], key:"lightsalmon" },
+    crate::nearest::Node{ lab:[0.7777602,0.0446473,0.06251762], key:"tumbleweed" },
+    crate::nearest::Node{ lab:[0.78075063,0.043206107,0.09228042], key:"fawn" },
+    crate::nearest::Node{ lab:[0.7683444,0.103368156,0.1027316], key:"strawberryblonde" },
+    crate::nearest::Node{ lab:[0.77793616,0.11870248,0.052575927], key:"monalisa" },
+    crate::nearest::Node{ lab:[0.8282082,0.045801796,0.099680215], key:"mellowapricot" },
+    crate::nearest::Node{ lab:[0.8785091,0.04111445,0.066770226], key:"peachcrayola" },
+    crate::nearest::Node{ lab:[0.8830676,0.037236545,0.052193355], key:"apricot" },
+    crate::nearest::Node{ lab:[0.8022507,0.042420834,0.093048565], key:"middleyellowred" },
+    crate::nearest::Node{ lab:[0.84734154,0.05190368,0.08835415], key:"macaroniandcheese" },
+    crate::nearest::Node{ lab:[0.8483642,0.06984275,0.041983474], key:"melon" },
+    crate::nearest::Node{ lab:[0.78399736,0.06401243,0.10958705], key:"sandybrown" },
+    crate::nearest::Node{ lab:[0.78221047,0.06565023,0.15914555], key:"orangepeel" },
+    crate::nearest::Node{ lab:[0.7770901,0.03037559,0.11668011], key:"sunray" },
+    crate::nearest::Node{ lab:[0.7713706,0.035076894,0.11487248], key:"indianyellow" },
+    crate::nearest::Node{ lab:[0.7636338,0.03925725,0.14814325], key:"marigold" },
+    crate::nearest::Node{ lab:[0.76532155,0.08100089,0.15476282], key:"yelloworangecolorwheel" },
+    crate::nearest::Node{ lab:[0.7736857,0.08078091,0.14148766], key:"deepsaffron" },
+    crate::nearest::Node{ lab:[0.8113704,0.052045215,0.14220832], key:"yelloworange" },
+    crate::nearest::Node{ lab:[0.81607425,0.037098594,0.16637383], key:"chineseyellow" },
+    crate::nearest::Node{ lab:[0.8021717,0.051269643,0.15785295], key:"brightyellowcrayola" },
+    crate::nearest::Node{ lab:[0.7926884,0.0566112,0.16138455], key:"orangeweb" },
+    crate::nearest::Node{ lab:[0.80445933,0.060983986,0.11599947], key:"rajah" },
+];
@@ -0,0 +1,906 @@
+//  ***  AUTO-GENERATED  – DO NOT EDIT BY HAND  ***
+
+/// CSS Color Module Level 4 named colors.
+pub static CSS_COLORS: phf::Map<&'static str, crate::Color> = phf::phf_map! {
+    "aliceblue" => crate::Color{ name:std::borrow::Cow::Borrowed("aliceblue"), hex:std::borrow::Cow::Borrowed("#F0F8FF"), rgb:(240,248,255), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "antiquewhite" => crate::Color{ name:std::borrow::Cow::Borrowed("antiquewhite"), hex:std::borrow::Cow::Borrowed("#FAEBD7"), rgb:(250,235,215), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "aqua" => crate::Color{ name:std::borrow::Cow::Borrowed("aqua"), hex:std::borrow::Cow::Borrowed("#00FFFF"), rgb:(0,255,255), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "aquamarine" => crate::Color{ name:std::borrow::Cow::Borrowed("aquamarine"), hex:std::borrow::Cow::Borrowed("#7FFFD4"), rgb:(127,255,212), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "azure" => crate::Color{ name:std::borrow::Cow::Borrowed("azure"), hex:std::borrow::Cow::Borrowed("#F0FFFF"), rgb:(240,255,255), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "beige" => crate::Color{ name:std::borrow::Cow::Borrowed("beige"), hex:std::borrow::Cow::Borrowed("#F5F5DC"), rgb:(245,245,220), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "bisque" => crate::Color{ name:std::borrow::Cow::Borrowed("bisque"), hex:std::borrow::Cow::Borrowed("#FFE4C4"), rgb:(255,228,196), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "black" => crate::Color{ name:std::borrow::Cow::Borrowed("black"), hex:std::borrow::Cow::Borrowed("#000000"), rgb:(0,0,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "blanchedalmond" => crate::Color{ name:std::borrow::Cow::Borrowed("blanchedalmond"), hex:std::borrow::Cow::Borrowed("#FFEBCD"), rgb:(255,235,205), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "blue" => crate::Color{ name:std::borrow::Cow::Borrowed("blue"), hex:std::borrow::Cow::Borrowed("#0000FF"), rgb:(0,0,255), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "blueviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("blueviolet"), hex:std::borrow::Cow::Borrowed("#8A2BE2"), rgb:(138,43,226), aliases:&["bluevoilet"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "brown" => crate::Color{ name:std::borrow::Cow::Borrowed("brown"), hex:std::borrow::Cow::Borrowed("#A52A2A"), rgb:(165,42,42), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "burlywood" => crate::Color{ name:std::borrow::Cow::Borrowed("burlywood"), hex:std::borrow::Cow::Borrowed("#DEB887"), rgb:(222,184,135), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "cadetblue" => crate::Color{ name:std::borrow::Cow::Borrowed("cadetblue"), hex:std::borrow::Cow::Borrowed("#5F9EA0"), rgb:(95,158,160), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "chartreuse" => crate::Color{ name:std::borrow::Cow::Borrowed("chartreuse"), hex:std::borrow::Cow::Borrowed("#7FFF00"), rgb:(127,255,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "chocolate" => crate::Color{ name:std::borrow::Cow::Borrowed("chocolate"), hex:std::borrow::Cow::Borrowed("#D2691E"), rgb:(210,105,30), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "coral" => crate::Color{ name:std::borrow::Cow::Borrowed("coral"), hex:std::borrow::Cow::Borrowed("#FF7F50"), rgb:(255,127,80), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "cornflowerblue" => crate::Color{ name:std::borrow::Cow::Borrowed("cornflowerblue"), hex:std::borrow::Cow::Borrowed("#6495ED"), rgb:(100,149,237), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "cornsilk" => crate::Color{ name:std::borrow::Cow::Borrowed("cornsilk"), hex:std::borrow::Cow::Borrowed("#FFF8DC"), rgb:(255,248,220), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "crimson" => crate::Color{ name:std::borrow::Cow::Borrowed("crimson"), hex:std::borrow::Cow::Borrowed("#DC143C"), rgb:(220,20,60), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "cyan" => crate::Color{ name:std::borrow::Cow::Borrowed("cyan"), hex:std::borrow::Cow::Borrowed("#00FFFF"), rgb:(0,255,255), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkblue" => crate::Color{ name:std::borrow::Cow::Borrowed("darkblue"), hex:std::borrow::Cow::Borrowed("#00008B"), rgb:(0,0,139), aliases:&["dkblue"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkcyan" => crate::Color{ name:std::borrow::Cow::Borrowed("darkcyan"), hex:std::borrow::Cow::Borrowed("#008B8B"), rgb:(0,139,139), aliases:&["dkcyan"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkgoldenrod" => crate::Color{ name:std::borrow::Cow::Borrowed("darkgoldenrod"), hex:std::borrow::Cow::Borrowed("#B8860B"), rgb:(184,134,11), aliases:&["dkgoldenrod"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkgray" => crate::Color{ name:std::borrow::Cow::Borrowed("darkgray"), hex:std::borrow::Cow::Borrowed("#A9A9A9"), rgb:(169,169,169), aliases:&["dkgray", "dkgrey"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("darkgreen"), hex:std::borrow::Cow::Borrowed("#006400"), rgb:(0,100,0), aliases:&["dkgreen"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("darkgrey"), hex:std::borrow::Cow::Borrowed("#A9A9A9"), rgb:(169,169,169), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkkhaki" => crate::Color{ name:std::borrow::Cow::Borrowed("darkkhaki"), hex:std::borrow::Cow::Borrowed("#BDB76B"), rgb:(189,183,107), aliases:&["dkkhaki"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkmagenta" => crate::Color{ name:std::borrow::Cow::Borrowed("darkmagenta"), hex:std::borrow::Cow::Borrowed("#8B008B"), rgb:(139,0,139), aliases:&["darkmegenta", "dkmagenta", "dkmegenta"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkolivegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("darkolivegreen"), hex:std::borrow::Cow::Borrowed("#556B2F"), rgb:(85,107,47), aliases:&["dkolivegreen"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkorange" => crate::Color{ name:std::borrow::Cow::Borrowed("darkorange"), hex:std::borrow::Cow::Borrowed("#FF8C00"), rgb:(255,140,0), aliases:&["dkorange"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkorchid" => crate::Color{ name:std::borrow::Cow::Borrowed("darkorchid"), hex:std::borrow::Cow::Borrowed("#9932CC"), rgb:(153,50,204), aliases:&["dkorchid"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkred" => crate::Color{ name:std::borrow::Cow::Borrowed("darkred"), hex:std::borrow::Cow::Borrowed("#8B0000"), rgb:(139,0,0), aliases:&["dkred"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darksalmon" => crate::Color{ name:std::borrow::Cow::Borrowed("darksalmon"), hex:std::borrow::Cow::Borrowed("#E9967A"), rgb:(233,150,122), aliases:&["dksalmon"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkseagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("darkseagreen"), hex:std::borrow::Cow::Borrowed("#8FBC8F"), rgb:(143,188,143), aliases:&["dkseagreen"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkslateblue" => crate::Color{ name:std::borrow::Cow::Borrowed("darkslateblue"), hex:std::borrow::Cow::Borrowed("#483D8B"), rgb:(72,61,139), aliases:&["dkslateblue"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkslategray" => crate::Color{ name:std::borrow::Cow::Borrowed("darkslategray"), hex:std::borrow::Cow::Borrowed("#2F4F4F"), rgb:(47,79,79), aliases:&["dkslategray", "dkslategrey"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkslategrey" => crate::Color{ name:std::borrow::Cow::Borrowed("darkslategrey"), hex:std::borrow::Cow::Borrowed("#2F4F4F"), rgb:(47,79,79), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkturquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("darkturquoise"), hex:std::borrow::Cow::Borrowed("#00CED1"), rgb:(0,206,209), aliases:&["darkturqoise", "darkturquise", "dkturquoise", "dkturqoise", "dkturquise"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("darkviolet"), hex:std::borrow::Cow::Borrowed("#9400D3"), rgb:(148,0,211), aliases:&["darkvoilet", "dkviolet", "dkvoilet"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "deeppink" => crate::Color{ name:std::borrow::Cow::Borrowed("deeppink"), hex:std::borrow::Cow::Borrowed("#FF1493"), rgb:(255,20,147), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "deepskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("deepskyblue"), hex:std::borrow::Cow::Borrowed("#00BFFF"), rgb:(0,191,255), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "dimgray" => crate::Color{ name:std::borrow::Cow::Borrowed("dimgray"), hex:std::borrow::Cow::Borrowed("#696969"), rgb:(105,105,105), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "dimgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("dimgrey"), hex:std::borrow::Cow::Borrowed("#696969"), rgb:(105,105,105), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "dodgerblue" => crate::Color{ name:std::borrow::Cow::Borrowed("dodgerblue"), hex:std::borrow::Cow::Borrowed("#1E90FF"), rgb:(30,144,255), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "firebrick" => crate::Color{ name:std::borrow::Cow::Borrowed("firebrick"), hex:std::borrow::Cow::Borrowed("#B22222"), rgb:(178,34,34), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "floralwhite" => crate::Color{ name:std::borrow::Cow::Borrowed("floralwhite"), hex:std::borrow::Cow::Borrowed("#FFFAF0"), rgb:(255,250,240), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "forestgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("forestgreen"), hex:std::borrow::Cow::Borrowed("#228B22"), rgb:(34,139,34), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "fuchsia" => crate::Color{ name:std::borrow::Cow::Borrowed("fuchsia"), hex:std::borrow::Cow::Borrowed("#FF00FF"), rgb:(255,0,255), aliases:&["fuschia", "fushia"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "gainsboro" => crate::Color{ name:std::borrow::Cow::Borrowed("gainsboro"), hex:std::borrow::Cow::Borrowed("#DCDCDC"), rgb:(220,220,220), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "ghostwhite" => crate::Color{ name:std::borrow::Cow::Borrowed("ghostwhite"), hex:std::borrow::Cow::Borrowed("#F8F8FF"), rgb:(248,248,255), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "gold" => crate::Color{ name:std::borrow::Cow::Borrowed("gold"), hex:std::borrow::Cow::Borrowed("#FFD700"), rgb:(255,215,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "goldenrod" => crate::Color{ name:std::borrow::Cow::Borrowed("goldenrod"), hex:std::borrow::Cow::Borrowed("#DAA520"), rgb:(218,165,32), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "gray" => crate::Color{ name:std::borrow::Cow::Borrowed("gray"), hex:std::borrow::Cow::Borrowed("#808080"), rgb:(128,128,128), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "green" => crate::Color{ name:std::borrow::Cow::Borrowed("green"), hex:std::borrow::Cow::Borrowed("#008000"), rgb:(0,128,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "greenyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("greenyellow"), hex:std::borrow::Cow::Borrowed("#ADFF2F"), rgb:(173,255,47), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "grey" => crate::Color{ name:std::borrow::Cow::Borrowed("grey"), hex:std::borrow::Cow::Borrowed("#808080"), rgb:(128,128,128), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "honeydew" => crate::Color{ name:std::borrow::Cow::Borrowed("honeydew"), hex:std::borrow::Cow::Borrowed("#F0FFF0"), rgb:(240,255,240), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "hotpink" => crate::Color{ name:std::borrow::Cow::Borrowed("hotpink"), hex:std::borrow::Cow::Borrowed("#FF69B4"), rgb:(255,105,180), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "indianred" => crate::Color{ name:std::borrow::Cow::Borrowed("indianred"), hex:std::borrow::Cow::Borrowed("#CD5C5C"), rgb:(205,92,92), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "indigo" => crate::Color{ name:std::borrow::Cow::Borrowed("indigo"), hex:std::borrow::Cow::Borrowed("#4B0082"), rgb:(75,0,130), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "ivory" => crate::Color{ name:std::borrow::Cow::Borrowed("ivory"), hex:std::borrow::Cow::Borrowed("#FFFFF0"), rgb:(255,255,240), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "khaki" => crate::Color{ name:std::borrow::Cow::Borrowed("khaki"), hex:std::borrow::Cow::Borrowed("#F0E68C"), rgb:(240,230,140), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lavender" => crate::Color{ name:std::borrow::Cow::Borrowed("lavender"), hex:std::borrow::Cow::Borrowed("#E6E6FA"), rgb:(230,230,250), aliases:&["lavendar"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lavenderblush" => crate::Color{ name:std::borrow::Cow::Borrowed("lavenderblush"), hex:std::borrow::Cow::Borrowed("#FFF0F5"), rgb:(255,240,245), aliases:&["lavendarblush"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lawngreen" => crate::Color{ name:std::borrow::Cow::Borrowed("lawngreen"), hex:std::borrow::Cow::Borrowed("#7CFC00"), rgb:(124,252,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lemonchiffon" => crate::Color{ name:std::borrow::Cow::Borrowed("lemonchiffon"), hex:std::borrow::Cow::Borrowed("#FFFACD"), rgb:(255,250,205), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightblue" => crate::Color{ name:std::borrow::Cow::Borrowed("lightblue"), hex:std::borrow::Cow::Borrowed("#ADD8E6"), rgb:(173,216,230), aliases:&["ltblue"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightcoral" => crate::Color{ name:std::borrow::Cow::Borrowed("lightcoral"), hex:std::borrow::Cow::Borrowed("#F08080"), rgb:(240,128,128), aliases:&["ltcoral"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightcyan" => crate::Color{ name:std::borrow::Cow::Borrowed("lightcyan"), hex:std::borrow::Cow::Borrowed("#E0FFFF"), rgb:(224,255,255), aliases:&["ltcyan"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightgoldenrodyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("lightgoldenrodyellow"), hex:std::borrow::Cow::Borrowed("#FAFAD2"), rgb:(250,250,210), aliases:&["ltgoldenrodyellow"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightgray" => crate::Color{ name:std::borrow::Cow::Borrowed("lightgray"), hex:std::borrow::Cow::Borrowed("#D3D3D3"), rgb:(211,211,211), aliases:&["ltgray", "ltgrey"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("lightgreen"), hex:std::borrow::Cow::Borrowed("#90EE90"), rgb:(144,238,144), aliases:&["ltgreen"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("lightgrey"), hex:std::borrow::Cow::Borrowed("#D3D3D3"), rgb:(211,211,211), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightpink" => crate::Color{ name:std::borrow::Cow::Borrowed("lightpink"), hex:std::borrow::Cow::Borrowed("#FFB6C1"), rgb:(255,182,193), aliases:&["ltpink"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightsalmon" => crate::Color{ name:std::borrow::Cow::Borrowed("lightsalmon"), hex:std::borrow::Cow::Borrowed("#FFA07A"), rgb:(255,160,122), aliases:&["ltsalmon"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightseagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("lightseagreen"), hex:std::borrow::Cow::Borrowed("#20B2AA"), rgb:(32,178,170), aliases:&["ltseagreen"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("lightskyblue"), hex:std::borrow::Cow::Borrowed("#87CEFA"), rgb:(135,206,250), aliases:&["ltskyblue"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightslategray" => crate::Color{ name:std::borrow::Cow::Borrowed("lightslategray"), hex:std::borrow::Cow::Borrowed("#778899"), rgb:(119,136,153), aliases:&["ltslategray", "ltslategrey"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightslategrey" => crate::Color{ name:std::borrow::Cow::Borrowed("lightslategrey"), hex:std::borrow::Cow::Borrowed("#778899"), rgb:(119,136,153), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightsteelblue" => crate::Color{ name:std::borrow::Cow::Borrowed("lightsteelblue"), hex:std::borrow::Cow::Borrowed("#B0C4DE"), rgb:(176,196,222), aliases:&["ltsteelblue"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("lightyellow"), hex:std::borrow::Cow::Borrowed("#FFFFE0"), rgb:(255,255,224), aliases:&["ltyellow"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lime" => crate::Color{ name:std::borrow::Cow::Borrowed("lime"), hex:std::borrow::Cow::Borrowed("#00FF00"), rgb:(0,255,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "limegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("limegreen"), hex:std::borrow::Cow::Borrowed("#32CD32"), rgb:(50,205,50), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "linen" => crate::Color{ name:std::borrow::Cow::Borrowed("linen"), hex:std::borrow::Cow::Borrowed("#FAF0E6"), rgb:(250,240,230), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "magenta" => crate::Color{ name:std::borrow::Cow::Borrowed("magenta"), hex:std::borrow::Cow::Borrowed("#FF00FF"), rgb:(255,0,255), aliases:&["megenta"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "maroon" => crate::Color{ name:std::borrow::Cow::Borrowed("maroon"), hex:std::borrow::Cow::Borrowed("#800000"), rgb:(128,0,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mediumaquamarine" => crate::Color{ name:std::borrow::Cow::Borrowed("mediumaquamarine"), hex:std::borrow::Cow::Borrowed("#66CDAA"), rgb:(102,205,170), aliases:&["medaquamarine"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mediumblue" => crate::Color{ name:std::borrow::Cow::Borrowed("mediumblue"), hex:std::borrow::Cow::Borrowed("#0000CD"), rgb:(0,0,205), aliases:&["medblue"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mediumorchid" => crate::Color{ name:std::borrow::Cow::Borrowed("mediumorchid"), hex:std::borrow::Cow::Borrowed("#BA55D3"), rgb:(186,85,211), aliases:&["medorchid"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mediumpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("mediumpurple"), hex:std::borrow::Cow::Borrowed("#9370DB"), rgb:(147,112,219), aliases:&["medpurple"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mediumseagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("mediumseagreen"), hex:std::borrow::Cow::Borrowed("#3CB371"), rgb:(60,179,113), aliases:&["medseagreen"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mediumslateblue" => crate::Color{ name:std::borrow::Cow::Borrowed("mediumslateblue"), hex:std::borrow::Cow::Borrowed("#7B68EE"), rgb:(123,104,238), aliases:&["medslateblue"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mediumspringgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("mediumspringgreen"), hex:std::borrow::Cow::Borrowed("#00FA9A"), rgb:(0,250,154), aliases:&["medspringgreen"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mediumturquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("mediumturquoise"), hex:std::borrow::Cow::Borrowed("#48D1CC"), rgb:(72,209,204), aliases:&["mediumturqoise", "mediumturquise", "medturquoise", "medturqoise", "medturquise"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mediumvioletred" => crate::Color{ name:std::borrow::Cow::Borrowed("mediumvioletred"), hex:std::borrow::Cow::Borrowed("#C71585"), rgb:(199,21,133), aliases:&["mediumvoiletred", "medvioletred", "medvoiletred"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "midnightblue" => crate::Color{ name:std::borrow::Cow::Borrowed("midnightblue"), hex:std::borrow::Cow::Borrowed("#191970"), rgb:(25,25,112), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mintcream" => crate::Color{ name:std::borrow::Cow::Borrowed("mintcream"), hex:std::borrow::Cow::Borrowed("#F5FFFA"), rgb:(245,255,250), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mistyrose" => crate::Color{ name:std::borrow::Cow::Borrowed("mistyrose"), hex:std::borrow::Cow::Borrowed("#FFE4E1"), rgb:(255,228,225), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "moccasin" => crate::Color{ name:std::borrow::Cow::Borrowed("moccasin"), hex:std::borrow::Cow::Borrowed("#FFE4B5"), rgb:(255,228,181), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "navajowhite" => crate::Color{ name:std::borrow::Cow::Borrowed("navajowhite"), hex:std::borrow::Cow::Borrowed("#FFDEAD"), rgb:(255,222,173), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "navy" => crate::Color{ name:std::borrow::Cow::Borrowed("navy"), hex:std::borrow::Cow::Borrowed("#000080"), rgb:(0,0,128), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "oldlace" => crate::Color{ name:std::borrow::Cow::Borrowed("oldlace"), hex:std::borrow::Cow::Borrowed("#FDF5E6"), rgb:(253,245,230), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "olive" => crate::Color{ name:std::borrow::Cow::Borrowed("olive"), hex:std::borrow::Cow::Borrowed("#808000"), rgb:(128,128,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "olivedrab" => crate::Color{ name:std::borrow::Cow::Borrowed("olivedrab"), hex:std::borrow::Cow::Borrowed("#6B8E23"), rgb:(107,142,35), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "orange" => crate::Color{ name:std::borrow::Cow::Borrowed("orange"), hex:std::borrow::Cow::Borrowed("#FFA500"), rgb:(255,165,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "orangered" => crate::Color{ name:std::borrow::Cow::Borrowed("orangered"), hex:std::borrow::Cow::Borrowed("#FF4500"), rgb:(255,69,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "orchid" => crate::Color{ name:std::borrow::Cow::Borrowed("orchid"), hex:std::borrow::Cow::Borrowed("#DA70D6"), rgb:(218,112,214), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "palegoldenrod" => crate::Color{ name:std::borrow::Cow::Borrowed("palegoldenrod"), hex:std::borrow::Cow::Borrowed("#EEE8AA"), rgb:(238,232,170), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "palegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("palegreen"), hex:std::borrow::Cow::Borrowed("#98FB98"), rgb:(152,251,152), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "paleturquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("paleturquoise"), hex:std::borrow::Cow::Borrowed("#AFEEEE"), rgb:(175,238,238), aliases:&["paleturqoise", "paleturquise"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "palevioletred" => crate::Color{ name:std::borrow::Cow::Borrowed("palevioletred"), hex:std::borrow::Cow::Borrowed("#DB7093"), rgb:(219,112,147), aliases:&["palevoiletred"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "papayawhip" => crate::Color{ name:std::borrow::Cow::Borrowed("papayawhip"), hex:std::borrow::Cow::Borrowed("#FFEFD5"), rgb:(255,239,213), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "peachpuff" => crate::Color{ name:std::borrow::Cow::Borrowed("peachpuff"), hex:std::borrow::Cow::Borrowed("#FFDAB9"), rgb:(255,218,185), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "peru" => crate::Color{ name:std::borrow::Cow::Borrowed("peru"), hex:std::borrow::Cow::Borrowed("#CD853F"), rgb:(205,133,63), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "pink" => crate::Color{ name:std::borrow::Cow::Borrowed("pink"), hex:std::borrow::Cow::Borrowed("#FFC0CB"), rgb:(255,192,203), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "plum" => crate::Color{ name:std::borrow::Cow::Borrowed("plum"), hex:std::borrow::Cow::Borrowed("#DDA0DD"), rgb:(221,160,221), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "powderblue" => crate::Color{ name:std::borrow::Cow::Borrowed("powderblue"), hex:std::borrow::Cow::Borrowed("#B0E0E6"), rgb:(176,224,230), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "purple" => crate::Color{ name:std::borrow::Cow::Borrowed("purple"), hex:std::borrow::Cow::Borrowed("#800080"), rgb:(128,0,128), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "rebeccapurple" => crate::Color{ name:std::borrow::Cow::Borrowed("rebeccapurple"), hex:std::borrow::Cow::Borrowed("#663399"), rgb:(102,51,153), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "red" => crate::Color{ name:std::borrow::Cow::Borrowed("red"), hex:std::borrow::Cow::Borrowed("#FF0000"), rgb:(255,0,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "rosybrown" => crate::Color{ name:std::borrow::Cow::Borrowed("rosybrown"), hex:std::borrow::Cow::Borrowed("#BC8F8F"), rgb:(188,143,143), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "royalblue" => crate::Color{ name:std::borrow::Cow::Borrowed("royalblue"), hex:std::borrow::Cow::Borrowed("#4169E1"), rgb:(65,105,225), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "saddlebrown" => crate::Color{ name:std::borrow::Cow::Borrowed("saddlebrown"), hex:std::borrow::Cow::Borrowed("#8B4513"), rgb:(139,69,19), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "salmon" => crate::Color{ name:std::borrow::Cow::Borrowed("salmon"), hex:std::borrow::Cow::Borrowed("#FA8072"), rgb:(250,128,114), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "sandybrown" => crate::Color{ name:std::borrow::Cow::Borrowed("sandybrown"), hex:std::borrow::Cow::Borrowed("#F4A460"), rgb:(244,164,96), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "seagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("seagreen"), hex:std::borrow::Cow::Borrowed("#2E8B57"), rgb:(46,139,87), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "seashell" => crate::Color{ name:std::borrow::Cow::Borrowed("seashell"), hex:std::borrow::Cow::Borrowed("#FFF5EE"), rgb:(255,245,238), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "sienna" => crate::Color{ name:std::borrow::Cow::Borrowed("sienna"), hex:std::borrow::Cow::Borrowed("#A0522D"), rgb:(160,82,45), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "silver" => crate::Color{ name:std::borrow::Cow::Borrowed("silver"), hex:std::borrow::Cow::Borrowed("#C0C0C0"), rgb:(192,192,192), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "skyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("skyblue"), hex:std::borrow::Cow::Borrowed("#87CEEB"), rgb:(135,206,235), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "slateblue" => crate::Color{ name:std::borrow::Cow::Borrowed("slateblue"), hex:std::borrow::Cow::Borrowed("#6A5ACD"), rgb:(106,90,205), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "slategray" => crate::Color{ name:std::borrow::Cow::Borrowed("slategray"), hex:std::borrow::Cow::Borrowed("#708090"), rgb:(112,128,144), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "slategrey" => crate::Color{ name:std::borrow::Cow::Borrowed("slategrey"), hex:std::borrow::Cow::Borrowed("#708090"), rgb:(112,128,144), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "snow" => crate::Color{ name:std::borrow::Cow::Borrowed("snow"), hex:std::borrow::Cow::Borrowed("#FFFAFA"), rgb:(255,250,250), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "springgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("springgreen"), hex:std::borrow::Cow::Borrowed("#00FF7F"), rgb:(0,255,127), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "steelblue" => crate::Color{ name:std::borrow::Cow::Borrowed("steelblue"), hex:std::borrow::Cow::Borrowed("#4682B4"), rgb:(70,130,180), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "tan" => crate::Color{ name:std::borrow::Cow::Borrowed("tan"), hex:std::borrow::Cow::Borrowed("#D2B48C"), rgb:(210,180,140), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "teal" => crate::Color{ name:std::borrow::Cow::Borrowed("teal"), hex:std::borrow::Cow::Borrowed("#008080"), rgb:(0,128,128), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "thistle" => crate::Color{ name:std::borrow::Cow::Borrowed("thistle"), hex:std::borrow::Cow::Borrowed("#D8BFD8"), rgb:(216,191,216), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "tomato" => crate::Color{ name:std::borrow::Cow::Borrowed("tomato"), hex:std::borrow::Cow::Borrowed("#FF6347"), rgb:(255,99,71), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "turquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("turquoise"), hex:std::borrow::Cow::Borrowed("#40E0D0"), rgb:(64,224,208), aliases:&["turqoise", "turquise"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "violet" => crate::Color{ name:std::borrow::Cow::Borrowed("violet"), hex:std::borrow::Cow::Borrowed("#EE82EE"), rgb:(238,130,238), aliases:&["voilet"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "wheat" => crate::Color{ name:std::borrow::Cow::Borrowed("wheat"), hex:std::borrow::Cow::Borrowed("#F5DEB3"), rgb:(245,222,179), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "white" => crate::Color{ name:std::borrow::Cow::Borrowed("white"), hex:std::borrow::Cow::Borrowed("#FFFFFF"), rgb:(255,255,255), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "whitesmoke" => crate::Color{ name:std::borrow::Cow::Borrowed("whitesmoke"), hex:std::borrow::Cow::Borrowed("#F5F5F5"), rgb:(245,245,245), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "yellow" => crate::Color{ name:std::borrow::Cow::Borrowed("yellow"), hex:std::borrow::Cow::Borrowed("#FFFF00"), rgb:(255,255,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "yellowgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("yellowgreen"), hex:std::borrow::Cow::Borrowed("#9ACD32"), rgb:(154,205,50), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+};
+
+/// Alternate spellings of `CSS_COLORS` keys, mapped to the key they stand for.
+pub(crate) static CSS_COLORS_ALIASES: phf::Map<&'static str, &'static str> = phf::phf_map! {
+    "bluevoilet" => "blueviolet",
+    "dkblue" => "darkblue",
+    "dkcyan" => "darkcyan",
+    "dkgoldenrod" => "darkgoldenrod",
+    "dkgray" => "darkgray",
+    "dkgrey" => "darkgray",
+    "dkgreen" => "darkgreen",
+    "dkkhaki" => "darkkhaki",
+    "darkmegenta" => "darkmagenta",
+    "dkmagenta" => "darkmagenta",
+    "dkmegenta" => "darkmagenta",
+    "dkolivegreen" => "darkolivegreen",
+    "dkorange" => "darkorange",
+    "dkorchid" => "darkorchid",
+    "dkred" => "darkred",
+    "dksalmon" => "darksalmon",
+    "dkseagreen" => "darkseagreen",
+    "dkslateblue" => "darkslateblue",
+    "dkslategray" => "darkslategray",
+    "dkslategrey" => "darkslategray",
+    "darkturqoise" => "darkturquoise",
+    "darkturquise" => "darkturquoise",
+    "dkturquoise" => "darkturquoise",
+    "dkturqoise" => "darkturquoise",
+    "dkturquise" => "darkturquoise",
+    "darkvoilet" => "darkviolet",
+    "dkviolet" => "darkviolet",
+    "dkvoilet" => "darkviolet",
+    "fuschia" => "fuchsia",
+    "fushia" => "fuchsia",
+    "lavendar" => "lavender",
+    "lavendarblush" => "lavenderblush",
+    "ltblue" => "lightblue",
+    "ltcoral" => "lightcoral",
+    "ltcyan" => "lightcyan",
+    "ltgoldenrodyellow" => "lightgoldenrodyellow",
+    "ltgray" => "lightgray",
+    "ltgrey" => "lightgray",
+    "ltgreen" => "lightgreen",
+    "ltpink" => "lightpink",
+    "ltsalmon" => "lightsalmon",
+    "ltseagreen" => "lightseagreen",
+    "ltskyblue" => "lightskyblue",
+    "ltslategray" => "lightslategray",
+    "ltslategrey" => "lightslategray",
+    "ltsteelblue" => "lightsteelblue",
+    "ltyellow" => "lightyellow",
+    "megenta" => "magenta",
+    "medaquamarine" => "mediumaquamarine",
+    "medblue" => "mediumblue",
+    "medorchid" => "mediumorchid",
+    "medpurple" => "mediumpurple",
+    "medseagreen" => "mediumseagreen",
+    "medslateblue" => "mediumslateblue",
+    "medspringgreen" => "mediumspringgreen",
+    "mediumturqoise" => "mediumturquoise",
+    "mediumturquise" => "mediumturquoise",
+    "medturquoise" => "mediumturquoise",
+    "medturqoise" => "mediumturquoise",
+    "medturquise" => "mediumturquoise",
+    "mediumvoiletred" => "mediumvioletred",
+    "medvioletred" => "mediumvioletred",
+    "medvoiletred" => "mediumvioletred",
+    "paleturqoise" => "paleturquoise",
+    "paleturquise" => "paleturquoise",
+    "palevoiletred" => "palevioletred",
+    "turqoise" => "turquoise",
+    "turquise" => "turquoise",
+    "voilet" => "violet",
+};
+
+/// `CSS_COLORS` keys sharing a base name, for names that aren't simply their own key.
+pub(crate) static CSS_COLORS_VARIANTS: phf::Map<&'static str, &'static [&'static str]> = phf::phf_map! {
+};
+
+/// `CSS_COLORS` keys ordered by display name, case-insensitively.
+pub(crate) static CSS_COLORS_BY_NAME: [&str; 148] = [
+    "aliceblue",
+    "antiquewhite",
+    "aqua",
+    "aquamarine",
+    "azure",
+    "beige",
+    "bisque",
+    "black",
+    "blanchedalmond",
+    "blue",
+    "blueviolet",
+    "brown",
+    "burlywood",
+    "cadetblue",
+    "chartreuse",
+    "chocolate",
+    "coral",
+    "cornflowerblue",
+    "cornsilk",
+    "crimson",
+    "cyan",
+    "darkblue",
+    "darkcyan",
+    "darkgoldenrod",
+    "darkgray",
+    "darkgreen",
+    "darkgrey",
+    "darkkhaki",
+    "darkmagenta",
+    "darkolivegreen",
+    "darkorange",
+    "darkorchid",
+    "darkred",
+    "darksalmon",
+    "darkseagreen",
+    "darkslateblue",
+    "darkslategray",
+    "darkslategrey",
+    "darkturquoise",
+    "darkviolet",
+    "deeppink",
+    "deepskyblue",
+    "dimgray",
+    "dimgrey",
+    "dodgerblue",
+    "firebrick",
+    "floralwhite",
+    "forestgreen",
+    "fuchsia",
+    "gainsboro",
+    "ghostwhite",
+    "gold",
+    "goldenrod",
+    "gray",
+    "green",
+    "greenyellow",
+    "grey",
+    "honeydew",
+    "hotpink",
+    "indianred",
+    "indigo",
+    "ivory",
+    "khaki",
+    "lavender",
+    "lavenderblush",
+    "lawngreen",
+    "lemonchiffon",
+    "lightblue",
+    "lightcoral",
+    "lightcyan",
+    "lightgoldenrodyellow",
+    "lightgray",
+    "lightgreen",
+    "lightgrey",
+    "lightpink",
+    "lightsalmon",
+    "lightseagreen",
+    "lightskyblue",
+    "lightslategray",
+    "lightslategrey",
+    "lightsteelblue",
+    "lightyellow",
+    "lime",
+    "limegreen",
+    "linen",
+    "magenta",
+    "maroon",
+    "mediumaquamarine",
+    "mediumblue",
+    "mediumorchid",
+    "mediumpurple",
+    "mediumseagreen",
+    "mediumslateblue",
+    "mediumspringgreen",
+    "mediumturquoise",
+    "mediumvioletred",
+    "midnightblue",
+    "mintcream",
+    "mistyrose",
+    "moccasin",
+    "navajowhite",
+    "navy",
+    "oldlace",
+    "olive",
+    "olivedrab",
+    "orange",
+    "orangered",
+    "orchid",
+    "palegoldenrod",
+    "palegreen",
+    "paleturquoise",
+    "palevioletred",
+    "papayawhip",
+    "peachpuff",
+    "peru",
+    "pink",
+    "plum",
+    "powderblue",
+    "purple",
+    "rebeccapurple",
+    "red",
+    "rosybrown",
+    "royalblue",
+    "saddlebrown",
+    "salmon",
+    "sandybrown",
+    "seagreen",
+    "seashell",
+    "sienna",
+    "silver",
+    "skyblue",
+    "slateblue",
+    "slategray",
+    "slategrey",
+    "snow",
+    "springgreen",
+    "steelblue",
+    "tan",
+    "teal",
+    "thistle",
+    "tomato",
+    "turquoise",
+    "violet",
+    "wheat",
+    "white",
+    "whitesmoke",
+    "yellow",
+    "yellowgreen",
+];
+
+/// `CSS_COLORS` keys in sorted order, for prefix search.
+pub(crate) static CSS_COLORS_KEYS: [&str; 148] = [
+    "aliceblue",
+    "antiquewhite",
+    "aqua",
+    "aquamarine",
+    "azure",
+    "beige",
+    "bisque",
+    "black",
+    "blanchedalmond",
+    "blue",
+    "blueviolet",
+    "brown",
+    "burlywood",
+    "cadetblue",
+    "chartreuse",
+    "chocolate",
+    "coral",
+    "cornflowerblue",
+    "cornsilk",
+    "crimson",
+    "cyan",
+    "darkblue",
+    "darkcyan",
+    "darkgoldenrod",
+    "darkgray",
+    "darkgreen",
+    "darkgrey",
+    "darkkhaki",
+    "darkmagenta",
+    "darkolivegreen",
+    "darkorange",
+    "darkorchid",
+    "darkred",
+    "darksalmon",
+    "darkseagreen",
+    "darkslateblue",
+    "darkslategray",
+    "darkslategrey",
+    "darkturquoise",
+    "darkviolet",
+    "deeppink",
+    "deepskyblue",
+    "dimgray",
+    "dimgrey",
+    "dodgerblue",
+    "firebrick",
+    "floralwhite",
+    "forestgreen",
+    "fuchsia",
+    "gainsboro",
+    "ghostwhite",
+    "gold",
+    "goldenrod",
+    "gray",
+    "green",
+    "greenyellow",
+    "grey",
+    "honeydew",
+    "hotpink",
+    "indianred",
+    "indigo",
+    "ivory",
+    "khaki",
+    "lavender",
+    "lavenderblush",
+    "lawngreen",
+    "lemonchiffon",
+    "lightblue",
+    "lightcoral",
+    "lightcyan",
+    "lightgoldenrodyellow",
+    "lightgray",
+    "lightgreen",
+    "lightgrey",
+    "lightpink",
+    "lightsalmon",
+    "lightseagreen",
+    "lightskyblue",
+    "lightslategray",
+    "lightslategrey",
+    "lightsteelblue",
+    "lightyellow",
+    "lime",
+    "limegreen",
+    "linen",
+    "magenta",
+    "maroon",
+    "mediumaquamarine",
+    "mediumblue",
+    "mediumorchid",
+    "mediumpurple",
+    "mediumseagreen",
+    "mediumslateblue",
+    "mediumspringgreen",
+    "mediumturquoise",
+    "mediumvioletred",
+    "midnightblue",
+    "mintcream",
+    "mistyrose",
+    "moccasin",
+    "navajowhite",
+    "navy",
+    "oldlace",
+    "olive",
+    "olivedrab",
+    "orange",
+    "orangered",
+    "orchid",
+    "palegoldenrod",
+    "palegreen",
+    "paleturquoise",
+    "palevioletred",
+    "papayawhip",
+    "peachpuff",
+    "peru",
+    "pink",
+    "plum",
+    "powderblue",
+    "purple",
+    "rebeccapurple",
+    "red",
+    "rosybrown",
+    "royalblue",
+    "saddlebrown",
+    "salmon",
+    "sandybrown",
+    "seagreen",
+    "seashell",
+    "sienna",
+    "silver",
+    "skyblue",
+    "slateblue",
+    "slategray",
+    "slategrey",
+    "snow",
+    "springgreen",
+    "steelblue",
+    "tan",
+    "teal",
+    "thistle",
+    "tomato",
+    "turquoise",
+    "violet",
+    "wheat",
+    "white",
+    "whitesmoke",
+    "yellow",
+    "yellowgreen",
+];
+
+/// Every word of every display name, sorted, with the keys (in display-name order) using it.
+pub(crate) static CSS_COLORS_WORDS: [(&str, &[&str]); 148] = [
+    ("aliceblue", &["aliceblue"]),
+    ("antiquewhite", &["antiquewhite"]),
+    ("aqua", &["aqua"]),
+    ("aquamarine", &["aquamarine"]),
+    ("azure", &["azure"]),
+    ("beige", &["beige"]),
+    ("bisque", &["bisque"]),
+    ("black", &["black"]),
+    ("blanchedalmond", &["blanchedalmond"]),
+    ("blue", &["blue"]),
+    ("blueviolet", &["blueviolet"]),
+    ("brown", &["brown"]),
+    ("burlywood", &["burlywood"]),
+    ("cadetblue", &["cadetblue"]),
+    ("chartreuse", &["chartreuse"]),
+    ("chocolate", &["chocolate"]),
+    ("coral", &["coral"]),
+    ("cornflowerblue", &["cornflowerblue"]),
+    ("cornsilk", &["cornsilk"]),
+    ("crimson", &["crimson"]),
+    ("cyan", &["cyan"]),
+    ("darkblue", &["darkblue"]),
+    ("darkcyan", &["darkcyan"]),
+    ("darkgoldenrod", &["darkgoldenrod"]),
+    ("darkgray", &["darkgray"]),
+    ("darkgreen", &["darkgreen"]),
+    ("darkgrey", &["darkgrey"]),
+    ("darkkhaki", &["darkkhaki"]),
+    ("darkmagenta", &["darkmagenta"]),
+    ("darkolivegreen", &["darkolivegreen"]),
+    ("darkorange", &["darkorange"]),
+    ("darkorchid", &["darkorchid"]),
+    ("darkred", &["darkred"]),
+    ("darksalmon", &["darksalmon"]),
+    ("darkseagreen", &["darkseagreen"]),
+    ("darkslateblue", &["darkslateblue"]),
+    ("darkslategray", &["darkslategray"]),
+    ("darkslategrey", &["darkslategrey"]),
+    ("darkturquoise", &["darkturquoise"]),
+    ("darkviolet", &["darkviolet"]),
+    ("deeppink", &["deeppink"]),
+    ("deepskyblue", &["deepskyblue"]),
+    ("dimgray", &["dimgray"]),
+    ("dimgrey", &["dimgrey"]),
+    ("dodgerblue", &["dodgerblue"]),
+    ("firebrick", &["firebrick"]),
+    ("floralwhite", &["floralwhite"]),
+    ("forestgreen", &["forestgreen"]),
+    ("fuchsia", &["fuchsia"]),
+    ("gainsboro", &["gainsboro"]),
+    ("ghostwhite", &["ghostwhite"]),
+    ("gold", &["gold"]),
+    ("goldenrod", &["goldenrod"]),
+    ("gray", &["gray"]),
+    ("green", &["green"]),
+    ("greenyellow", &["greenyellow"]),
+    ("grey", &["grey"]),
+    ("honeydew", &["honeydew"]),
+    ("hotpink", &["hotpink"]),
+    ("indianred", &["indianred"]),
+    ("indigo", &["indigo"]),
+    ("ivory", &["ivory"]),
+    ("khaki", &["khaki"]),
+    ("lavender", &["lavender"]),
+    ("lavenderblush", &["lavenderblush"]),
+    ("lawngreen", &["lawngreen"]),
+    ("lemonchiffon", &["lemonchiffon"]),
+    ("lightblue", &["lightblue"]),
+    ("lightcoral", &["lightcoral"]),
+    ("lightcyan", &["lightcyan"]),
+    ("lightgoldenrodyellow", &["lightgoldenrodyellow"]),
+    ("lightgray", &["lightgray"]),
+    ("lightgreen", &["lightgreen"]),
+    ("lightgrey", &["lightgrey"]),
+    ("lightpink", &["lightpink"]),
+    ("lightsalmon", &["lightsalmon"]),
+    ("lightseagreen", &["lightseagreen"]),
+    ("lightskyblue", &["lightskyblue"]),
+    ("lightslategray", &["lightslategray"]),
+    ("lightslategrey", &["lightslategrey"]),
+    ("lightsteelblue", &["lightsteelblue"]),
+    ("lightyellow", &["lightyellow"]),
+    ("lime", &["lime"]),
+    ("limegreen", &["limegreen"]),
+    ("linen", &["linen"]),
+    ("magenta", &["magenta"]),
+    ("maroon", &["maroon"]),
+    ("mediumaquamarine", &["mediumaquamarine"]),
+    ("mediumblue", &["mediumblue"]),
+    ("mediumorchid", &["mediumorchid"]),
+    ("mediumpurple", &["mediumpurple"]),
+    ("mediumseagreen", &["mediumseagreen"]),
+    ("mediumslateblue", &["mediumslateblue"]),
+    ("mediumspringgreen", &["mediumspringgreen"]),
+    ("mediumturquoise", &["mediumturquoise"]),
+    ("mediumvioletred", &["mediumvioletred"]),
+    ("midnightblue", &["midnightblue"]),
+    ("mintcream", &["mintcream"]),
+    ("mistyrose", &["mistyrose"]),
+    ("moccasin", &["moccasin"]),
+    ("navajowhite", &["navajowhite"]),
+    ("navy", &["navy"]),
+    ("oldlace", &["oldlace"]),
+    ("olive", &["olive"]),
+    ("olivedrab", &["olivedrab"]),
+    ("orange", &["orange"]),
+    ("orangered", &["orangered"]),
+    ("orchid", &["orchid"]),
+    ("palegoldenrod", &["palegoldenrod"]),
+    ("palegreen", &["palegreen"]),
+    ("paleturquoise", &["paleturquoise"]),
+    ("palevioletred", &["palevioletred"]),
+    ("papayawhip", &["papayawhip"]),
+    ("peachpuff", &["peachpuff"]),
+    ("peru", &["peru"]),
+    ("pink", &["pink"]),
+    ("plum", &["plum"]),
+    ("powderblue", &["powderblue"]),
+    ("purple", &["purple"]),
+    ("rebeccapurple", &["rebeccapurple"]),
+    ("red", &["red"]),
+    ("rosybrown", &["rosybrown"]),
+    ("royalblue", &["royalblue"]),
+    ("saddlebrown", &["saddlebrown"]),
+    ("salmon", &["salmon"]),
+    ("sandybrown", &["sandybrown"]),
+    ("seagreen", &["seagreen"]),
+    ("seashell", &["seashell"]),
+    ("sienna", &["sienna"]),
+    ("silver", &["silver"]),
+    ("skyblue", &["skyblue"]),
+    ("slateblue", &["slateblue"]),
+    ("slategray", &["slategray"]),
+    ("slategrey", &["slategrey"]),
+    ("snow", &["snow"]),
+    ("springgreen", &["springgreen"]),
+    ("steelblue", &["steelblue"]),
+    ("tan", &["tan"]),
+    ("teal", &["teal"]),
+    ("thistle", &["thistle"]),
+    ("tomato", &["tomato"]),
+    ("turquoise", &["turquoise"]),
+    ("violet", &["violet"]),
+    ("wheat", &["wheat"]),
+    ("white", &["white"]),
+    ("whitesmoke", &["whitesmoke"]),
+    ("yellow", &["yellow"]),
+    ("yellowgreen", &["yellowgreen"]),
+];
+
+/// `CSS_COLORS` keys of each hue family, in `Family` order, each in display-name order.
+pub(crate) static CSS_COLORS_FAMILIES: [&[&str]; 10] = [
+    &["crimson", "darksalmon", "firebrick", "indianred", "lightcoral", "orangered", "red", "rosybrown", "salmon", "tomato"],
+    &["bisque", "burlywood", "coral", "darkorange", "lightsalmon", "orange", "peachpuff", "peru", "sandybrown", "tan"],
+    &["blanchedalmond", "cornsilk", "darkgoldenrod", "darkkhaki", "gold", "goldenrod", "khaki", "lemonchiffon", "lightgoldenrodyellow", "lightyellow", "moccasin", "navajowhite", "olive", "palegoldenrod", "papayawhip", "wheat", "yellow"],
+    &["aquamarine", "chartreuse", "darkgreen", "darkolivegreen", "darkseagreen", "forestgreen", "green", "greenyellow", "lawngreen", "lightgreen", "lime", "limegreen", "mediumaquamarine", "mediumseagreen", "mediumspringgreen", "olivedrab", "palegreen", "seagreen", "springgreen", "yellowgreen"],
+    &["aqua", "cadetblue", "cyan", "darkcyan", "darkslategray", "darkslategrey", "darkturquoise", "lightblue", "lightseagreen", "mediumturquoise", "paleturquoise", "powderblue", "skyblue", "teal", "turquoise"],
+    &["blue", "cornflowerblue", "darkblue", "darkslateblue", "deepskyblue", "dodgerblue", "lightskyblue", "lightsteelblue", "mediumblue", "mediumslateblue", "midnightblue", "navy", "royalblue", "slateblue", "steelblue"],
+    &["blueviolet", "darkmagenta", "darkorchid", "darkviolet", "fuchsia", "indigo", "magenta", "mediumorchid", "mediumpurple", "orchid", "plum", "purple", "rebeccapurple", "thistle", "violet"],
+    &["deeppink", "hotpink", "lightpink", "mediumvioletred", "palevioletred", "pink"],
+    &["brown", "chocolate", "darkred", "maroon", "saddlebrown", "sienna"],
+    &["aliceblue", "antiquewhite", "azure", "beige", "black", "darkgray", "darkgrey", "dimgray", "dimgrey", "floralwhite", "gainsboro", "ghostwhite", "gray", "grey", "honeydew", "ivory", "lavender", "lavenderblush", "lightcyan", "lightgray", "lightgrey", "lightslategray", "lightslategrey", "linen", "mintcream", "mistyrose", "oldlace", "seashell", "silver", "slategray", "slategrey", "snow", "white", "whitesmoke"],
+];
+
+/// `CSS_COLORS_FAMILIES` split by `Lightness` and then `Chroma` band, each in display-name order.
+pub(crate) static CSS_COLORS_BANDS: [[[&[&str]; 3]; 3]; 10] = [
+    [
+        [&[], &[], &[]],
+        [&["rosybrown"], &["indianred", "lightcoral", "salmon"], &["crimson", "firebrick", "orangered", "red", "tomato"]],
+        [&[], &["darksalmon"], &[]],
+    ],
+    [
+        [&[], &[], &[]],
+        [&[], &["peru"], &["coral"]],
+        [&["bisque", "burlywood", "peachpuff", "tan"], &["lightsalmon", "sandybrown"], &["darkorange", "orange"]],
+    ],
+    [
+        [&[], &[], &[]],
+        [&[], &["darkgoldenrod", "olive"], &[]],
+        [&["blanchedalmond", "cornsilk", "lemonchiffon", "lightgoldenrodyellow", "lightyellow", "moccasin", "navajowhite", "palegoldenrod", "papayawhip", "wheat"], &["darkkhaki", "goldenrod", "khaki"], &["gold", "yellow"]],
+    ],
+    [
+        [&[], &["darkgreen"], &[]],
+        [&[], &["darkolivegreen", "mediumseagreen", "olivedrab", "seagreen"], &["forestgreen", "green", "limegreen"]],
+        [&["darkseagreen"], &["aquamarine", "lightgreen", "mediumaquamarine"], &["chartreuse", "greenyellow", "lawngreen", "lime", "mediumspringgreen", "palegreen", "springgreen", "yellowgreen"]],
+    ],
+    [
+        [&["darkslategray", "darkslategrey"], &[], &[]],
+        [&["cadetblue"], &["darkcyan", "lightseagreen", "teal"], &[]],
+        [&["lightblue", "paleturquoise", "powderblue"], &["aqua", "cyan", "darkturquoise", "mediumturquoise", "skyblue", "turquoise"], &[]],
+    ],
+    [
+        [&[], &["darkslateblue", "midnightblue"], &["darkblue", "mediumblue", "navy"]],
+        [&[], &["cornflowerblue", "steelblue"], &["blue", "dodgerblue", "mediumslateblue", "royalblue", "slateblue"]],
+        [&["lightsteelblue"], &["deepskyblue", "lightskyblue"], &[]],
+    ],
+    [
+        [&[], &[], &["darkmagenta", "indigo", "purple", "rebeccapurple"]],
+        [&[], &["mediumpurple"], &["blueviolet", "darkorchid", "darkviolet", "fuchsia", "magenta", "mediumorchid", "orchid"]],
+        [&["thistle"], &["plum"], &["violet"]],
+    ],
+    [
+        [&[], &[], &[]],
+        [&[], &["palevioletred"], &["deeppink", "hotpink", "mediumvioletred"]],
+        [&["pink"], &["lightpink"], &[]],
+    ],
+    [
+        [&[], &["maroon"], &["darkred"]],
+        [&[], &["brown", "chocolate", "saddlebrown", "sienna"], &[]],
+        [&[], &[], &[]],
+    ],
+    [
+        [&["black"], &[], &[]],
+        [&["darkgray", "darkgrey", "dimgray", "dimgrey", "gray", "grey", "lightslategray", "lightslategrey", "slategray", "slategrey"], &[], &[]],
+        [&["aliceblue", "antiquewhite", "azure", "beige", "floralwhite", "gainsboro", "ghostwhite", "honeydew", "ivory", "lavender", "lavenderblush", "lightcyan", "lightgray", "lightgrey", "linen", "mintcream", "mistyrose", "oldlace", "seashell", "silver", "snow", "white", "whitesmoke"], &[], &[]],
+    ],
+];
+
+/// `CSS_COLORS` keys laid out as a k-d tree over OKLab coordinates.
+pub(crate) static CSS_COLORS_NEAREST: [crate::nearest::Node; 148] = [
+    crate::nearest::Node{ lab:[0.7508648,-0.06507693,0.046025883], key:"darkseagreen" },
+    crate::nearest::Node{ lab:[0.5435672,0.045877244,-0.16496573], key:"slateblue" },
+    crate::nearest::Node{ lab:[0.40296292,-0.03630598,-0.01024461], key:"darkslategray" },
+    crate::nearest::Node{ lab:[0.5880009,-0.040817074,-0.090565845], key:"steelblue" },
+    crate::nearest::Node{ lab:[0.28782427,-0.02066731,-0.19836867], key:"darkblue" },
+    crate::nearest::Node{ lab:[0.57652205,-0.095159926,-0.025087172], key:"darkcyan" },
+    crate::nearest::Node{ lab:[0.45201373,-0.032456983,-0.31152815], key:"blue" },
+    crate::nearest::Node{ lab:[0.38345328,-0.02753398,-0.26427624], key:"mediumblue" },
+    crate::nearest::Node{ lab:[0.5431226,-0.089647055,-0.023633804], key:"teal" },
+    crate::nearest::Node{ lab:[0.28811887,0.006927588,-0.14345874], key:"midnightblue" },
+    crate::nearest::Node{ lab:[0.5598482,-0.011820611,-0.18786201], key:"royalblue" },
+    crate::nearest::Node{ lab:[0.27114984,-0.019469997,-0.18687664], key:"navy" },
+    crate::nearest::Node{ lab:[0.4143428,0.034491476,-0.11997692], key:"darkslateblue" },
+    crate::nearest::Node{ lab:[0.61902016,-0.011989233,-0.030206343], key:"lightslategray" },
+    crate::nearest::Node{ lab:[0.65768147,-0.061722323,-0.020410107], key:"cadetblue" },
+    crate::nearest::Node{ lab:[0.674622,-0.021289019,-0.1397445], key:"cornflowerblue" },
+    crate::nearest::Node{ lab:[0.65200555,-0.05493326,-0.18200985], key:"dodgerblue" },
+    crate::nearest::Node{ lab:[0.691201,-0.11281922,-0.01779147], key:"lightseagreen" },
+    crate::nearest::Node{ lab:[0.59250414,-0.01141009,-0.028742729], key:"slategray" },
+    crate::nearest::Node{ lab:[0.61902016,-0.011989233,-0.030206343], key:"lightslategrey" },
+    crate::nearest::Node{ lab:[0.59250414,-0.01141009,-0.028742729], key:"slategrey" },
+    crate::nearest::Node{ lab:[0.5806646,-0.04281223,0.1191162], key:"olive" },
+    crate::nearest::Node{ lab:[0.4955208,-0.05289934,0.072315395], key:"darkolivegreen" },
+    crate::nearest::Node{ lab:[0.5578046,-0.1346051,0.10182646], key:"forestgreen" },
+    crate::nearest::Node{ lab:[0.5685264,-0.107546546,0.05026605], key:"seagreen" },
+    crate::nearest::Node{ lab:[0.43601784,-0.11769909,0.0903289], key:"darkgreen" },
+    crate::nearest::Node{ lab:[0.51975185,-0.14030233,0.107675895], key:"green" },
+    crate::nearest::Node{ lab:[0.52080655,4.216083e-11,1.9412495e-08], key:"dimgray" },
+    crate::nearest::Node{ lab:[0.40296292,-0.03630598,-0.01024461], key:"darkslategrey" },
+    crate::nearest::Node{ lab:[0.0,0.0,0.0], key:"black" },
+    crate::nearest::Node{ lab:[0.52080655,4.216083e-11,1.9412495e-08], key:"dimgrey" },
+    crate::nearest::Node{ lab:[0.5998708,4.8561544e-11,2.235953e-08], key:"grey" },
+    crate::nearest::Node{ lab:[0.59948385,-0.081376776,0.11069002], key:"olivedrab" },
+    crate::nearest::Node{ lab:[0.68404204,-0.13052309,0.060866643], key:"mediumseagreen" },
+    crate::nearest::Node{ lab:[0.5998708,4.8561544e-11,2.235953e-08], key:"gray" },
+    crate::nearest::Node{ lab:[0.74187446,-0.18220717,0.13812841], key:"limegreen" },
+    crate::nearest::Node{ lab:[0.73480856,5.948486e-11,2.7389188e-08], key:"darkgrey" },
+    crate::nearest::Node{ lab:[0.73480856,5.948486e-11,2.7389188e-08], key:"darkgray" },
+    crate::nearest::Node{ lab:[0.65206957,0.01937692,0.13077164], key:"darkgoldenrod" },
+    crate::nearest::Node{ lab:[0.72464097,0.12856539,0.049425825], key:"lightcoral" },
+    crate::nearest::Node{ lab:[0.62557954,0.15330534,-0.132207], key:"mediumorchid" },
+    crate::nearest::Node{ lab:[0.54111457,0.15060078,-0.1701666], key:"darkorchid" },
+    crate::nearest::Node{ lab:[0.33898208,0.094161525,-0.15255125], key:"indigo" },
+    crate::nearest::Node{ lab:[0.6044729,0.051809892,-0.18683998], key:"mediumslateblue" },
+    crate::nearest::Node{ lab:[0.53376496,0.13031808,-0.21370523], key:"blueviolet" },
+    crate::nearest::Node{ lab:[0.4402718,0.08817676,-0.13386434], key:"rebeccapurple" },
+    crate::nearest::Node{ lab:[0.42091367,0.16470431,-0.10147178], key:"purple" },
+    crate::nearest::Node{ lab:[0.51490974,0.16689838,-0.20023905], key:"darkviolet" },
+    crate::nearest::Node{ lab:[0.44679788,0.17483285,-0.10771182], key:"darkmagenta" },
+    crate::nearest::Node{ lab:[0.55336696,0.21807168,-0.03968207], key:"mediumvioletred" },
+    crate::nearest::Node{ lab:[0.7282974,0.195155,-0.027445627], key:"hotpink" },
+    crate::nearest::Node{ lab:[0.67792284,0.1382069,0.001642491], key:"palevioletred" },
+    crate::nearest::Node{ lab:[0.7021318,0.15488695,-0.094151884], key:"orchid" },
+    crate::nearest::Node{ lab:[0.62691426,0.07093668,-0.14121489], key:"mediumpurple" },
+    crate::nearest::Node{ lab:[0.6927437,0.051944137,0.017446116], key:"rosybrown" },
+    crate::nearest::Node{ lab:[0.70167387,0.2745663,-0.16915606], key:"magenta" },
+    crate::nearest::Node{ lab:[0.70167387,0.2745663,-0.16915606], key:"fuchsia" },
+    crate::nearest::Node{ lab:[0.654935,0.26096505,-0.0139288595], key:"deeppink" },
+    crate::nearest::Node{ lab:[0.6279554,0.22486307,0.1258463], key:"red" },
+    crate::nearest::Node{ lab:[0.39985687,0.14318381,0.08013389], key:"darkred" },
+    crate::nearest::Node{ lab:[0.52648216,0.081961505,0.08083752], key:"sienna" },
+    crate::nearest::Node{ lab:[0.61544085,0.1334389,0.05453257], key:"indianred" },
+    crate::nearest::Node{ lab:[0.3766921,0.13488878,0.07549152], key:"maroon" },
+    crate::nearest::Node{ lab:[0.4707835,0.07080872,0.08696012], key:"saddlebrown" },
+    crate::nearest::Node{ lab:[0.5711893,0.20843765,0.07622524], key:"crimson" },
+    crate::nearest::Node{ lab:[0.48061255,0.14402948,0.068890296], key:"brown" },
+    crate::nearest::Node{ lab:[0.49677107,0.1603672,0.081059314], key:"firebrick" },
+    crate::nearest::Node{ lab:[0.73511285,0.12822507,0.10853774], key:"coral" },
+    crate::nearest::Node{ lab:[0.6343984,0.09907391,0.11919316], key:"chocolate" },
+    crate::nearest::Node{ lab:[0.75073624,0.08360267,0.0686568], key:"darksalmon" },
+    crate::nearest::Node{ lab:[0.67819256,0.057283256,0.10856277], key:"peru" },
+    crate::nearest::Node{ lab:[0.75054425,0.094165646,0.15236416], key:"darkorange" },
+    crate::nearest::Node{ lab:[0.6962193,0.1652304,0.104540884], key:"tomato" },
+    crate::nearest::Node{ lab:[0.73500234,0.13369869,0.071269915], key:"salmon" },
+    crate::nearest::Node{ lab:[0.66019946,0.18694869,0.13286985], key:"orangered" },
+    crate::nearest::Node{ lab:[0.9959763,-0.0056533646,0.018783517], key:"ivory" },
+    crate::nearest::Node{ lab:[0.9635736,-0.009584834,0.031351846], key:"beige" },
+    crate::nearest::Node{ lab:[0.8750832,-0.045226052,-0.021795848], key:"powderblue" },
+    crate::nearest::Node{ lab:[0.75535,-0.0952156,-0.12030096], key:"deepskyblue" },
+    crate::nearest::Node{ lab:[0.8223342,-0.13022855,-0.011597303], key:"turquoise" },
+    crate::nearest::Node{ lab:[0.7868021,-0.11386521,-0.023286536], key:"mediumturquoise" },
+    crate::nearest::Node{ lab:[0.7719288,-0.12593356,-0.03764616], key:"darkturquoise" },
+    crate::nearest::Node{ lab:[0.77668625,-0.107762344,0.0212899], key:"mediumaquamarine" },
+    crate::nearest::Node{ lab:[0.81362313,-0.011055388,-0.04135573], key:"lightsteelblue" },
+    crate::nearest::Node{ lab:[0.8206188,-0.051824216,-0.07905587], key:"lightskyblue" },
+    crate::nearest::Node{ lab:[0.81481725,-0.057156272,-0.05868004], key:"skyblue" },
+    crate::nearest::Node{ lab:[0.8562331,-0.037679408,-0.031231297], key:"lightblue" },
+    crate::nearest::Node{ lab:[0.9778582,-0.030687958,-0.009171604], key:"lightcyan" },
+    crate::nearest::Node{ lab:[0.90690863,-0.06075319,-0.01752655], key:"paleturquoise" },
+    crate::nearest::Node{ lab:[0.9053992,-0.14944394,-0.039398156], key:"cyan" },
+    crate::nearest::Node{ lab:[0.9053992,-0.14944394,-0.039398156], key:"aqua" },
+    crate::nearest::Node{ lab:[0.91499454,-0.12798631,0.024896637], key:"aquamarine" },
+    crate::nearest::Node{ lab:[0.991172,-0.011939205,0.003240988], key:"mintcream" },
+    crate::nearest::Node{ lab:[0.98895067,-0.015041059,-0.0045705023], key:"azure" },
+    crate::nearest::Node{ lab:[0.9848418,-0.020746188,0.014322255], key:"honeydew" },
+    crate::nearest::Node{ lab:[0.8902625,-0.19050698,0.18392423], key:"chartreuse" },
+    crate::nearest::Node{ lab:[0.86800325,-0.126184,0.09138165], key:"lightgreen" },
+    crate::nearest::Node{ lab:[0.86643964,-0.23388757,0.17949848], key:"lime" },
+    crate::nearest::Node{ lab:[0.8749299,-0.20581263,0.11397103], key:"springgreen" },
+    crate::nearest::Node{ lab:[0.86680585,-0.19017537,0.081097126], key:"mediumspringgreen" },
+    crate::nearest::Node{ lab:[0.88175315,-0.18977894,0.18217745], key:"lawngreen" },
+    crate::nearest::Node{ lab:[0.78485227,-0.10964247,0.14744212], key:"yellowgreen" },
+    crate::nearest::Node{ lab:[0.76747406,-0.02457125,0.094910465], key:"darkkhaki" },
+    crate::nearest::Node{ lab:[0.8867711,-0.016925177,0.18139815], key:"gold" },
+    crate::nearest::Node{ lab:[0.9210476,-0.018201202,0.07770318], key:"palegoldenrod" },
+    crate::nearest::Node{ lab:[0.91304886,-0.1501201,0.17879699], key:"greenyellow" },
+    crate::nearest::Node{ lab:[0.91348904,-0.024851901,0.10912827], key:"khaki" },
+    crate::nearest::Node{ lab:[0.9035428,-0.1315452,0.09527285], key:"palegreen" },
+    crate::nearest::Node{ lab:[0.9679827,-0.07136908,0.19856976], key:"yellow" },
+    crate::nearest::Node{ lab:[0.9750065,-0.015435947,0.049487032], key:"lightgoldenrodyellow" },
+    crate::nearest::Node{ lab:[0.99200743,-0.011841024,0.038462907], key:"lightyellow" },
+    crate::nearest::Node{ lab:[0.9778096,-0.012259016,0.056911167], key:"lemonchiffon" },
+    crate::nearest::Node{ lab:[0.98623276,0.0013425166,0.014156463], key:"floralwhite" },
+    crate::nearest::Node{ lab:[0.9309023,0.0073649315,-0.025915211], key:"lavender" },
+    crate::nearest::Node{ lab:[0.8332929,0.036385518,-0.024576474], key:"thistle" },
+    crate::nearest::Node{ lab:[0.8668631,7.01752e-11,3.2311373e-08], key:"lightgrey" },
+    crate::nearest::Node{ lab:[0.8668631,7.01752e-11,3.2311373e-08], key:"lightgray" },
+    crate::nearest::Node{ lab:[0.80779624,6.539341e-11,3.010972e-08], key:"silver" },
+    crate::nearest::Node{ lab:[0.8944904,7.2411466e-11,3.3341152e-08], key:"gainsboro" },
+    crate::nearest::Node{ lab:[0.8677384,0.07298037,0.009071449], key:"pink" },
+    crate::nearest::Node{ lab:[0.78328353,0.08990804,-0.05940838], key:"plum" },
+    crate::nearest::Node{ lab:[0.7618985,0.15646526,-0.10079778], key:"violet" },
+    crate::nearest::Node{ lab:[0.8473878,0.08471583,0.013548875], key:"lightpink" },
+    crate::nearest::Node{ lab:[0.9889369,0.005096968,0.0015824084], key:"snow" },
+    crate::nearest::Node{ lab:[0.97015077,7.8536455e-11,3.616131e-08], key:"whitesmoke" },
+    crate::nearest::Node{ lab:[0.98111874,0.0025899603,-0.008898664], key:"ghostwhite" },
+    crate::nearest::Node{ lab:[0.97514284,-0.005500548,-0.011404169], key:"aliceblue" },
+    crate::nearest::Node{ lab:[1.0,8.0952856e-11,3.7273907e-08], key:"white" },
+    crate::nearest::Node{ lab:[0.97601813,0.007638632,0.012033104], key:"seashell" },
+    crate::nearest::Node{ lab:[0.96833485,0.017342905,-0.0014863464], key:"lavenderblush" },
+    crate::nearest::Node{ lab:[0.9400117,0.027195733,0.012844467], key:"mistyrose" },
+    crate::nearest::Node{ lab:[0.91640216,0.015881488,0.07125776], key:"navajowhite" },
+    crate::nearest::Node{ lab:[0.91125286,0.026585272,0.053788204], key:"peachpuff" },
+    crate::nearest::Node{ lab:[0.8045399,0.022220576,0.07461971], key:"burlywood" },
+    crate::nearest::Node{ lab:[0.9088334,0.0074598812,0.061044525], key:"wheat" },
+    crate::nearest::Node{ lab:[0.78618664,0.016927637,0.061535213], key:"tan" },
+    crate::nearest::Node{ lab:[0.7515723,0.015389061,0.14612558], key:"goldenrod" },
+    crate::nearest::Node{ lab:[0.78399736,0.06401243,0.10958705], key:"sandybrown" },
+    crate::nearest::Node{ lab:[0.793755,0.09214708,0.08421498], key:"lightsalmon" },
+    crate::nearest::Node{ lab:[0.7926884,0.0566112,0.16138455], key:"orange" },
+    crate::nearest::Node{ lab:[0.9466915,0.007930012,0.03005429], key:"antiquewhite" },
+    crate::nearest::Node{ lab:[0.9773007,-0.003531187,0.03708985], key:"cornsilk" },
+    crate::nearest::Node{ lab:[0.9723414,0.002528669,0.021410508], key:"oldlace" },
+    crate::nearest::Node{ lab:[0.96023846,0.006529202,0.015857963], key:"linen" },
+    crate::nearest::Node{ lab:[0.95807993,0.006622367,0.037681177], key:"papayawhip" },
+    crate::nearest::Node{ lab:[0.9328562,0.016026122,0.048885465], key:"bisque" },
+    crate::nearest::Node{ lab:[0.94843936,0.009296485,0.043962296], key:"blanchedalmond" },
+    crate::nearest::Node{ lab:[0.9296221,0.010123965,0.066774465], key:"moccasin" },
+];
@@ -0,0 +1,1221 @@
+//  ***  AUTO-GENERATED  – DO NOT EDIT BY HAND  ***
+
+/// CIE Lab and OKLab coordinates of every built-in color, by `0xRRGGBB`.
+pub(crate) static LAB: phf::Map<u32, ([f32; 3], [f32; 3])> = phf::phf_map! {
+    0x000000u32 => ([0.0,0.0,0.0], [0.0,0.0,0.0]),
+    0x000080u32 => ([12.974283,47.50693,-64.69935], [0.27114984,-0.019469997,-0.18687664]),
+    0x00008Bu32 => ([14.756065,50.428383,-68.67805], [0.28782427,-0.02066731,-0.19836867]),
+    0x00009Cu32 => ([17.460398,54.86247,-74.716805], [0.3131322,-0.022484554,-0.21581091]),
+    0x0000CDu32 => ([24.974703,67.183105,-91.496185], [0.38345328,-0.02753398,-0.26427624]),
+    0x0000EEu32 => ([29.843288,75.16576,-102.3677], [0.42901492,-0.030805549,-0.29567736]),
+    0x0000FFu32 => ([32.300873,79.19527,-107.85547], [0.45201373,-0.032456983,-0.31152815]),
+    0x000F89u32 => ([16.243437,44.356876,-64.93546], [0.29710805,-0.019077092,-0.18805243]),
+    0x0014A8u32 => ([21.30913,51.888866,-75.61008], [0.34408522,-0.02220885,-0.21893746]),
+    0x0018A8u32 => ([21.872288,50.199898,-74.67098], [0.34797248,-0.021986865,-0.21633586]),
+    0x002147u32 => ([12.876042,5.8066473,-27.03032], [0.25046423,-0.02171748,-0.077873394]),
+    0x002366u32 => ([16.31153,18.724543,-42.334404], [0.2852834,-0.019172631,-0.12275449]),
+    0x002387u32 => ([19.846962,32.506474,-57.693367], [0.32184708,-0.019507008,-0.1674787]),
+    0x003153u32 => ([19.312258,-0.41365024,-24.882635], [0.30350265,-0.0317874,-0.07113183]),
+    0x0033AAu32 => ([27.489225,35.60448,-66.562096], [0.38865826,-0.023929207,-0.19322073]),
+    0x00356Bu32 => ([22.329002,7.637102,-35.805496], [0.33242992,-0.028868334,-0.10314908]),
+    0x004040u32 => ([23.79259,-17.861065,-5.2488465], [0.33635125,-0.05551767,-0.014636217]),
+    0x0040FFu32 => ([39.387566,58.068054,-96.04576], [0.50108993,-0.030174272,-0.278721]),
+    0x00416Au32 => ([26.218369,-1.2076516,-28.934752], [0.36260617,-0.03859134,-0.082649484]),
+    0x004225u32 => ([23.769768,-27.176893,12.434727], [0.3335927,-0.07312978,0.0310697]),
+    0x004242u32 => ([24.606516,-18.226398,-5.356208], [0.34323105,-0.05665324,-0.014935588]),
+    0x0048BAu32 => ([34.353584,27.938438,-64.79939], [0.444041,-0.030220311,-0.18785344]),
+    0x004953u32 => ([27.816397,-15.766384,-11.622212], [0.37153354,-0.055529796,-0.032468844]),
+    0x004B49u32 => ([28.14877,-20.55826,-4.6457815], [0.37295556,-0.06275077,-0.012993567]),
+    0x004F98u32 => ([33.68639,9.1042795,-45.581367], [0.43062758,-0.0378965,-0.13125166]),
+    0x00573Fu32 => ([32.20188,-29.533401,7.5977674], [0.40521407,-0.08120031,0.01937932]),
+    0x006400u32 => ([36.202755,-43.369106,41.861946], [0.43601784,-0.11769909,0.0903289]),
+    0x006600u32 => ([36.93399,-43.976604,42.448334], [0.44212538,-0.119347766,0.09159419]),
+    0x0067A5u32 => ([41.8704,-1.1352185,-40.257072], [0.4972232,-0.052467912,-0.11504004]),
+    0x00688Bu32 => ([40.838676,-12.061393,-26.376736], [0.48461756,-0.061905652,-0.0745256]),
+    0x006B3Cu32 => ([39.31161,-38.52099,19.062077], [0.46377486,-0.10342975,0.04732856]),
+    0x006DB0u32 => ([44.33466,-0.4537357,-42.80204], [0.518656,-0.054106902,-0.12238164]),
+    0x0070B8u32 => ([45.700085,0.87794894,-45.286186], [0.53087467,-0.05426367,-0.12961079]),
+    0x0072BBu32 => ([46.47404,0.8324175,-45.7907], [0.53751355,-0.05498865,-0.13104953]),
+    0x007474u32 => ([43.87378,-26.874586,-7.8976583], [0.5060897,-0.083534464,-0.022022331]),
+    0x00755Eu32 => ([43.55134,-34.136772,4.809478], [0.5012615,-0.0957087,0.012173908]),
+    0x007BA7u32 => ([48.203312,-12.321161,-31.442532], [0.54783934,-0.0685352,-0.08896469]),
+    0x007BB8u32 => ([49.03884,-5.8029304,-39.985622], [0.5572282,-0.062885724,-0.11385769]),
+    0x007F5Cu32 => ([46.909748,-38.952766,10.746559], [0.5287483,-0.10681185,0.027390629]),
+    0x007F66u32 => ([47.15409,-36.273945,5.2356787], [0.5315672,-0.10163518,0.013262868]),
+    0x007FFFu32 => ([54.446045,19.41268,-71.35487], [0.6130819,-0.050091464,-0.20595838]),
+    0x008000u32 => ([46.22791,-51.697823,49.901226], [0.51975185,-0.14030233,0.107675895]),
+    0x008080u32 => ([48.255013,-28.841122,-8.475566], [0.5431226,-0.089647055,-0.023633804]),
+    0x00868Bu32 => ([50.58565,-28.178013,-11.429804], [0.56332743,-0.090323925,-0.031835053]),
+    0x0087BDu32 => ([52.9505,-10.878088,-36.69327], [0.58913153,-0.071183756,-0.10405663]),
+    0x008B00u32 => ([50.054634,-54.876995,52.969913], [0.5517141,-0.14893027,0.11429746]),
+    0x008B45u32 => ([50.563023,-48.4801,28.515034], [0.55758286,-0.12978213,0.0694756]),
+    0x008B8Bu32 => ([52.206394,-30.614716,-8.996773], [0.57652205,-0.095159926,-0.025087172]),
+    0x009150u32 => ([52.761875,-48.566204,25.412924], [0.5763808,-0.13023515,0.06276425]),
+    0x0093AFu32 => ([56.049065,-22.52035,-23.883518], [0.6119837,-0.086897485,-0.06696618]),
+    0x009698u32 => ([56.166298,-31.727308,-10.558275], [0.61018914,-0.09967032,-0.029419594]),
+    0x009ACDu32 => ([59.450302,-15.576054,-35.56632], [0.64344674,-0.08170728,-0.10053112]),
+    0x009B7Du32 => ([56.99951,-41.93918,6.0712757], [0.61443317,-0.11749915,0.015380972]),
+    0x009E60u32 => ([57.363747,-50.058815,22.759644], [0.6154014,-0.13472831,0.056894]),
+    0x009EDBu32 => ([61.27844,-12.6883335,-40.513527], [0.66012114,-0.08028149,-0.11484271]),
+    0x009F6Bu32 => ([57.900696,-48.02793,17.437769], [0.62052613,-0.13029069,0.044132046]),
+    0x00A550u32 => ([59.423527,-55.486225,33.86581], [0.63166857,-0.14850532,0.08209099]),
+    0x00A693u32 => ([61.113445,-40.536503,-0.39367214], [0.6501063,-0.117277466,-0.0017412826]),
+    0x00A877u32 => ([61.076855,-48.69444,15.181439], [0.6475634,-0.1328872,0.038606852]),
+    0x00AB66u32 => ([61.726418,-53.532825,25.312517], [0.65187,-0.14391176,0.063087925]),
+    0x00AD43u32 => ([61.904526,-59.970547,43.148045], [0.6517998,-0.16072145,0.10161455]),
+    0x00B2EEu32 => ([68.0293,-16.726885,-40.395184], [0.7168135,-0.09033469,-0.114239275]),
+    0x00B7EBu32 => ([69.37185,-20.411278,-36.668175], [0.7271483,-0.09551729,-0.10340074]),
+    0x00BFFFu32 => ([72.54764,-17.648428,-42.539345], [0.75535,-0.0952156,-0.12030096]),
+    0x00C5CDu32 => ([72.45388,-37.151787,-15.611042], [0.7484203,-0.11957514,-0.043481637]),
+    0x00CC99u32 => ([73.20913,-54.45831,13.657189], [0.7499994,-0.14987393,0.034840956]),
+    0x00CCCCu32 => ([74.535774,-40.63735,-11.942133], [0.76526356,-0.12631334,-0.0333002]),
+    0x00CCFFu32 => ([76.316,-24.345041,-36.67454], [0.7855614,-0.10589181,-0.103225954]),
+    0x00CD00u32 => ([72.001144,-73.109764,70.56906], [0.73502,-0.19841203,0.15227255]),
+    0x00CD66u32 => ([72.64093,-65.04264,39.325436], [0.74240494,-0.17408928,0.095453866]),
+    0x00CDCDu32 => ([74.86782,-40.78639,-11.985932], [0.7680703,-0.12677662,-0.033422332]),
+    0x00CED1u32 => ([75.291565,-40.035767,-13.511468], [0.7719288,-0.12593356,-0.03764616]),
+    0x00E5EEu32 => ([83.02421,-41.658165,-17.375076], [0.8378374,-0.13396254,-0.048394755]),
+    0x00EE00u32 => ([82.45738,-81.796616,78.954025], [0.8223545,-0.2219872,0.17036547]),
+    0x00EE76u32 => ([83.1544,-72.99947,44.67925], [0.83039993,-0.19537657,0.10825914]),
+    0x00EEEEu32 => ([85.66467,-45.6326,-13.410092], [0.85933185,-0.14184012,-0.03739355]),
+    0x00F5FFu32 => ([88.21886,-43.717537,-18.47814], [0.88182646,-0.14080492,-0.05146767]),
+    0x00FA9Au32 => ([87.33957,-70.682274,32.46598], [0.86680585,-0.19017537,0.081097126]),
+    0x00FF00u32 => ([87.73552,-86.181595,83.18662], [0.86643964,-0.23388757,0.17949848]),
+    0x00FF40u32 => ([87.91417,-83.864655,71.914795], [0.86849844,-0.22611961,0.16185355]),
+    0x00FF7Fu32 => ([88.471085,-76.89857,47.031643], [0.8749299,-0.20581263,0.11397103]),
+    0x00FFBFu32 => ([89.52308,-64.6757,16.676388], [0.8870849,-0.1778066,0.042535026]),
+    0x00FFCDu32 => ([89.82413,-61.375973,9.895326], [0.89055794,-0.17143585,0.025142089]),
+    0x00FFEFu32 => ([90.66488,-52.56765,-6.4959116], [0.90023583,-0.1562836,-0.018469727]),
+    0x00FFFEu32 => ([91.08562,-48.365204,-13.653875], [0.9050652,-0.14986442,-0.038085785]),
+    0x00FFFFu32 => ([91.11475,-48.078888,-14.128985], [0.9053992,-0.14944394,-0.039398156]),
+    0x010203u32 => ([0.50984263,-0.12237652,-0.47058746], [0.082309134,-0.0039260634,-0.007010753]),
+    0x010B13u32 => ([2.6448536,-1.1707282,-4.870673], [0.14188524,-0.015076642,-0.022130191]),
+    0x014421u32 => ([24.482275,-28.746483,15.867457], [0.3393065,-0.077049494,0.038993586]),
+    0x01796Fu32 => ([45.397953,-30.838274,-2.644226], [0.51802874,-0.09083574,-0.0076601785]),
+    0x030303u32 => ([0.8225244,0.0,0.0], [0.096925825,7.846439e-12,3.6128043e-09]),
+    0x043927u32 => ([20.43379,-21.837584,6.600431], [0.30646956,-0.059831064,0.016817749]),
+    0x050505u32 => ([1.370874,0.0,0.0], [0.11491828,9.302954e-12,4.283453e-09]),
+    0x080808u32 => ([2.1933985,0.0,0.0], [0.13440923,1.0880803e-11,5.0099573e-09]),
+    0x0A0A0Au32 => ([2.741748,0.0,0.0], [0.14478795,1.1720944e-11,5.3968128e-09]),
+    0x0A7E8Cu32 => ([48.107018,-23.28731,-15.76136], [0.5435608,-0.08046707,-0.043971732]),
+    0x0ABAB5u32 => ([68.39969,-39.119915,-8.546666], [0.7130614,-0.11916665,-0.02391171]),
+    0x0BDA51u32 => ([76.56122,-71.53427,53.65174], [0.774386,-0.19197132,0.12536094]),
+    0x0D0D0Du32 => ([3.6355119,0.0,0.0], [0.1590664,1.2876852e-11,5.929026e-09]),
+    0x0F0F0Fu32 => ([4.3150043,0.0,0.0], [0.16841613,1.3633747e-11,6.277527e-09]),
+    0x0F52BAu32 => ([37.26314,21.780903,-60.040817], [0.46653616,-0.03058691,-0.17361465]),
+    0x100C08u32 => ([3.5286493,0.7244339,2.0132673], [0.15796071,0.004105644,0.010066425]),
+    0x104E8Bu32 => ([32.750233,5.256548,-39.15279], [0.4212653,-0.034868326,-0.11225423]),
+    0x1164B4u32 => ([42.04339,7.597724,-48.79418], [0.5020138,-0.042558204,-0.14011306]),
+    0x121212u32 => ([5.4638886,1.3877788e-14,0.0], [0.1822037,1.4749882e-11,6.791444e-09]),
+    0x123524u32 => ([19.233143,-17.589674,7.005201], [0.29755905,-0.048239984,0.017845135]),
+    0x126180u32 => ([38.262672,-10.999248,-23.714306], [0.46294564,-0.054965604,-0.06683501]),
+    0x130A8Fu32 => ([17.171928,48.453526,-67.128555], [0.30774397,-0.0041636038,-0.19408944]),
+    0x138808u32 => ([49.208054,-52.19517,50.853706], [0.5452053,-0.14199805,0.11067057]),
+    0x141414u32 => ([6.3189282,1.3877788e-14,0.0], [0.1912513,1.5482338e-11,7.1286834e-09]),
+    0x1560BDu32 => ([41.529984,14.034348,-54.87276], [0.5001138,-0.036728613,-0.15806355]),
+    0x171717u32 => ([7.7395563,2.7755576e-14,-1.110223e-14], [0.20462653,1.6565069e-11,7.62723e-09]),
+    0x177245u32 => ([42.22481,-37.0397,17.762087], [0.4892612,-0.0999982,0.044448875]),
+    0x18453Bu32 => ([26.057472,-18.264145,1.4554518], [0.35595354,-0.05229121,0.0035747373]),
+    0x1874CDu32 => ([48.375458,7.7260923,-53.08224], [0.5565391,-0.046916146,-0.15233076]),
+    0x189BCCu32 => ([59.884274,-15.528683,-34.31604], [0.6472885,-0.079240024,-0.09684141]),
+    0x191970u32 => ([15.8589525,31.718258,-49.573303], [0.28811887,0.006927588,-0.14345874]),
+    0x195905u32 => ([32.617443,-35.632015,37.49307], [0.40746307,-0.09783534,0.08179743]),
+    0x1974D2u32 => ([48.69754,9.733283,-55.41501], [0.5600732,-0.045571312,-0.15918685]),
+    0x1A1A1Au32 => ([9.263234,1.3877788e-14,0.0], [0.2177865,1.7630564e-11,8.117754e-09]),
+    0x1A2421u32 => ([13.176135,-5.231349,0.5574997], [0.24958095,-0.015185025,0.0013979138]),
+    0x1B1B1Bu32 => ([9.766934,0.0,0.0], [0.22212875,1.7982005e-11,8.279606e-09]),
+    0x1C05B3u32 => ([22.337029,59.822876,-80.49997], [0.3578636,-0.0030052206,-0.23251452]),
+    0x1C1C1Cu32 => ([10.268185,1.3877788e-14,0.0], [0.22644986,1.833185e-11,8.440671e-09]),
+    0x1C39BBu32 => ([31.255396,39.314613,-70.45965], [0.42302522,-0.012576002,-0.20427106]),
+    0x1C86EEu32 => ([55.551933,9.513648,-60.14889], [0.618918,-0.051894158,-0.17270933]),
+    0x1CA9C9u32 => ([64.02969,-23.709976,-26.22048], [0.68028927,-0.09203423,-0.07344979]),
+    0x1CAC78u32 => ([62.59752,-48.386654,16.768963], [0.6607896,-0.13185017,0.042578448]),
+    0x1D2951u32 => ([17.650036,9.09671,-26.12362], [0.29335928,-0.0014218224,-0.07447428]),
+    0x1DACD6u32 => ([65.45137,-20.803396,-31.225546], [0.6934368,-0.089559786,-0.08776085]),
+    0x1E90FFu32 => ([59.38027,9.968255,-63.38596], [0.65200555,-0.05493326,-0.18200985]),
+    0x1F005Eu32 => ([9.79368,39.189545,-47.717445], [0.24107769,0.034536637,-0.13743797]),
+    0x1F1F1Fu32 => ([11.7579155,1.3877788e-14,0.0], [0.23929238,1.9371407e-11,8.919362e-09]),
+    0x1F75FEu32 => ([52.02705,26.342379,-74.71291], [0.5953018,-0.037794735,-0.21589367]),
+    0x20B2AAu32 => ([65.78641,-37.508133,-6.329303], [0.691201,-0.11281922,-0.01779147]),
+    0x212121u32 => ([12.74001,1.3877788e-14,-5.551115e-15], [0.2477587,2.0056831e-11,9.2349355e-09]),
+    0x214FC6u32 => ([37.82788,29.59985,-66.11406], [0.47494522,-0.019576382,-0.19130133]),
+    0x2243B6u32 => ([33.416935,31.463995,-63.944332], [0.43802926,-0.012482282,-0.18508701]),
+    0x228B22u32 => ([50.59354,-49.58485,45.019466], [0.5578046,-0.1346051,0.10182646]),
+    0x23297Au32 => ([21.38763,26.175829,-46.891148], [0.33287644,0.0075079906,-0.13535666]),
+    0x242124u32 => ([13.170144,2.1373487,-1.5082788], [0.25227332,0.005876713,-0.0039959615]),
+    0x242424u32 => ([14.197679,2.7755576e-14,0.0], [0.2603248,2.1074004e-11,9.7033235e-09]),
+    0x262626u32 => ([15.15972,2.7755576e-14,0.0], [0.2686183,2.1745453e-11,1.0012453e-08]),
+    0x26619Cu32 => ([40.18229,2.0429814,-37.55277], [0.48421347,-0.036113318,-0.10696092]),
+    0x27408Bu32 => ([29.316206,17.495941,-44.640045], [0.39700583,-0.007619295,-0.12835258]),
+    0x292929u32 => ([16.589066,0.0,0.0], [0.28094023,2.2742891e-11,1.047174e-08]),
+    0x299617u32 => ([54.39126,-52.75133,52.25889], [0.5895765,-0.14405198,0.11533459]),
+    0x29AB87u32 => ([62.79791,-42.997837,8.7955475], [0.664073,-0.1197322,0.022456335]),
+    0x2A2F23u32 => ([18.569086,-4.9159813,7.0016737], [0.2962453,-0.01305195,0.017888166]),
+    0x2A3439u32 => ([20.994143,-2.8377612,-4.5477147], [0.3177972,-0.0105383415,-0.012357463]),
+    0x2A52BEu32 => ([38.276768,25.405378,-60.703773], [0.4771607,-0.015532671,-0.17523603]),
+    0x2B2B2Bu32 => ([17.533285,2.7755576e-14,-1.110223e-14], [0.28908005,2.3401864e-11,1.07751434e-08]),
+    0x2C1608u32 => ([10.006728,9.568223,11.888429], [0.22775845,0.026612895,0.03369998]),
+    0x2D383Au32 => ([22.624743,-4.032821,-2.7732673], [0.3314289,-0.01311514,-0.0075227194]),
+    0x2D5DA1u32 => ([39.51181,7.5133395,-41.58683], [0.4806092,-0.026286589,-0.11876569]),
+    0x2D68C4u32 => ([44.86206,13.713369,-53.53134], [0.5289219,-0.029368643,-0.15368661]),
+    0x2E2787u32 => ([22.93383,33.725716,-52.53267], [0.34994093,0.021825057,-0.15157267]),
+    0x2E2D88u32 => ([24.433678,30.276342,-50.700535], [0.3610752,0.016851597,-0.14619601]),
+    0x2E2E2Eu32 => ([18.937323,0.0,0.0], [0.30118382,2.4381636e-11,1.1226297e-08]),
+    0x2E5090u32 => ([34.66379,9.761649,-39.090084], [0.4398917,-0.016073884,-0.111626536]),
+    0x2E5894u32 => ([37.29483,6.0173726,-37.320297], [0.4610331,-0.023472438,-0.10630949]),
+    0x2E8B57u32 => ([51.534466,-39.71345,20.053988], [0.5685264,-0.107546546,0.05026605]),
+    0x2F4F4Fu32 => ([31.255627,-11.718019,-3.7230852], [0.40296292,-0.03630598,-0.01024461]),
+    0x301934u32 => ([13.056385,16.955322,-13.106769], [0.25741762,0.045345835,-0.03606172]),
+    0x303030u32 => ([19.865534,2.7755576e-14,-1.110223e-14], [0.30918562,2.5029367e-11,1.1524556e-08]),
+    0x30B21Au32 => ([63.78871,-60.418438,60.030716], [0.6681099,-0.16496858,0.13199042]),
+    0x30BA8Fu32 => ([67.81946,-46.42972,11.527609], [0.70622337,-0.12848857,0.029459594]),
+    0x30BFBFu32 => ([70.6287,-35.81923,-10.668559], [0.733372,-0.111287944,-0.029683698]),
+    0x317873u32 => ([46.07361,-23.328413,-3.9293373], [0.52646476,-0.07022214,-0.0110018095]),
+    0x318CE7u32 => ([57.247803,4.9214454,-53.52173], [0.6319969,-0.049637515,-0.15294132]),
+    0x319177u32 => ([54.275215,-34.3936,5.6750646], [0.59352696,-0.09676951,0.014446005]),
+    0x32127Au32 => ([17.989996,41.38918,-52.426247], [0.31199443,0.04633266,-0.1509656]),
+    0x32174Du32 => ([14.550537,25.647257,-28.264658], [0.27447447,0.053369313,-0.080149785]),
+    0x324AB2u32 => ([35.57598,27.115793,-58.022587], [0.45494774,-0.0033475277,-0.16732341]),
+    0x32CD32u32 => ([72.60734,-67.12481,61.442074], [0.74187446,-0.18220717,0.13812841]),
+    0x333333u32 => ([21.24673,2.7755576e-14,0.0], [0.32109252,2.599343e-11,1.1968373e-08]),
+    0x333399u32 => ([27.767027,33.161667,-55.73013], [0.3909911,0.016645685,-0.16077578]),
+    0x33CC33u32 => ([72.31315,-66.59625,60.82416], [0.7394971,-0.18077563,0.13696559]),
+    0x343434u32 => ([21.704275,0.0,0.0], [0.32503685,2.6312758e-11,1.2115394e-08]),
+    0x353839u32 => ([23.27279,-1.0412217,-1.0677003], [0.33815894,-0.0034603637,-0.0028636598]),
+    0x355E3Bu32 => ([36.133682,-22.79163,15.690557], [0.44148088,-0.062085684,0.03930367]),
+    0x363636u32 => ([22.615238,0.0,0.0], [0.33288997,2.6948443e-11,1.240811e-08]),
+    0x36454Fu32 => ([28.393007,-3.2490764,-7.95713], [0.3813769,-0.013836242,-0.021753376]),
+    0x36648Bu32 => ([40.835716,-3.2685428,-26.287218], [0.48817736,-0.03283669,-0.07390359]),
+    0x36747Du32 => ([45.297012,-17.142462,-11.034523], [0.5218066,-0.057446405,-0.030506535]),
+    0x367588u32 => ([46.020504,-14.246222,-16.489847], [0.528952,-0.053914614,-0.045816984]),
+    0x383838u32 => ([23.520859,2.7755576e-14,0.0], [0.34069705,2.7580382e-11,1.269911e-08]),
+    0x39A78Eu32 => ([62.04828,-37.11055,3.861945], [0.6594793,-0.10550399,0.009653714]),
+    0x39FF14u32 => ([88.178696,-82.09002,82.00019], [0.87143195,-0.22389737,0.1779002]),
+    0x3A5FCDu32 => ([43.494045,24.4723,-60.901844], [0.52178997,-0.01132121,-0.17532149]),
+    0x3AB09Eu32 => ([65.29252,-36.843166,-0.34098753], [0.6873873,-0.10703216,-0.0015293075]),
+    0x3B3B3Bu32 => ([24.869669,0.0,0.0], [0.35232472,2.852174e-11,1.313252e-08]),
+    0x3B3C36u32 => ([25.038313,-1.7450471,3.5640016], [0.35314614,-0.0042595845,0.009302186]),
+    0x3B7A57u32 => ([46.360397,-29.01074,13.085834], [0.52738965,-0.0795097,0.033300303]),
+    0x3C1414u32 => ([12.681782,19.721758,9.91469], [0.2548405,0.058515843,0.024430417]),
+    0x3C341Fu32 => ([22.000765,-0.04541838,14.581893], [0.3276122,0.00034479235,0.03571493]),
+    0x3C69E7u32 => ([48.048225,27.811522,-68.305664], [0.5622477,-0.015460981,-0.1969046]),
+    0x3CB371u32 => ([65.27234,-48.215878,24.29237], [0.68404204,-0.13052309,0.060866643]),
+    0x3D0C02u32 => ([10.989933,23.224466,16.206297], [0.24152951,0.06453652,0.045546647]),
+    0x3D2B1Fu32 => ([19.30342,6.467511,11.075209], [0.30676293,0.01945686,0.02755986]),
+    0x3D3D3Du32 => ([25.76271,2.7755576e-14,0.0], [0.36002338,2.9144964e-11,1.3419478e-08]),
+    0x3DDC84u32 => ([78.36964,-60.088474,31.778042], [0.79303676,-0.16201735,0.07902775]),
+    0x3E8EDEu32 => ([57.696472,2.0296211,-47.77033], [0.6349673,-0.046411768,-0.13590764]),
+    0x3EB489u32 => ([66.11401,-43.166332,12.446088], [0.69266343,-0.119237795,0.03182192]),
+    0x3F00FFu32 => ([34.551582,79.98647,-104.039314], [0.47320974,0.018847827,-0.3002088]),
+    0x3FFF00u32 => ([88.244064,-81.6041,83.80704], [0.8721586,-0.22311376,0.18055767]),
+    0x404040u32 => ([27.093414,0.0,0.0], [0.37149495,3.0073527e-11,1.3847068e-08]),
+    0x40826Du32 => ([49.688107,-26.472485,4.947111], [0.55673677,-0.07479696,0.012646176]),
+    0x40E0D0u32 => ([81.26562,-44.07575,-4.026482], [0.8223342,-0.13022855,-0.011597303]),
+    0x4166F5u32 => ([48.533825,35.26021,-75.35537], [0.56970316,-0.008748506,-0.21757187]),
+    0x4169E1u32 => ([47.831806,26.27173,-65.26215], [0.5598482,-0.011820611,-0.18786201]),
+    0x424242u32 => ([27.974855,2.7755576e-14,0.0], [0.3790936,3.0688813e-11,1.41303e-08]),
+    0x43302Eu32 => ([21.960836,8.310578,4.662357], [0.33040577,0.025451811,0.012161781]),
+    0x436B95u32 => ([44.057655,-1.276475,-27.201977], [0.51676697,-0.026658848,-0.07632402]),
+    0x436EEEu32 => ([50.117588,28.034103,-68.86397], [0.58020926,-0.0130960485,-0.19834393]),
+    0x43B3AEu32 => ([66.89109,-32.40814,-6.8335586], [0.7025225,-0.09848188,-0.01905639]),
+    0x43CD80u32 => ([73.81246,-54.26638,27.82109], [0.75562465,-0.1467403,0.06956232]),
+    0x444C38u32 => ([31.074825,-7.403975,10.850834], [0.40317076,-0.019738507,0.027571265]),
+    0x454545u32 => ([29.288818,0.0,0.0], [0.39042085,3.1605663e-11,1.455251e-08]),
+    0x458B00u32 => ([51.53041,-42.449684,54.764553], [0.5681456,-0.11911728,0.117351025]),
+    0x458B74u32 => ([52.939304,-27.922935,5.5724964], [0.5842524,-0.07875323,0.014262554]),
+    0x4666FFu32 => ([49.525066,39.30729,-79.2927], [0.58007413,-0.0044031376,-0.22903168]),
+    0x4682B4u32 => ([52.46657,-4.0714846,-32.190907], [0.5880009,-0.040817074,-0.090565845]),
+    0x470659u32 => ([16.460629,40.517185,-33.16399], [0.2982503,0.100242205,-0.09361181]),
+    0x473C8Bu32 => ([30.485144,26.462027,-42.641], [0.41156593,0.03437831,-0.121655814]),
+    0x474747u32 => ([30.15951,0.0,0.0], [0.3979268,3.221326e-11,1.4832287e-08]),
+    0x47ABCCu32 => ([65.61106,-18.845299,-25.380726], [0.6958523,-0.07548052,-0.0708166]),
+    0x483C32u32 => ([26.273426,3.4192522,8.134479], [0.36572334,0.011185313,0.020846242]),
+    0x483D8Bu32 => ([30.82907,26.055157,-42.08227], [0.4143428,0.034491476,-0.11997692]),
+    0x4876FFu32 => ([53.550022,29.705112,-72.73822], [0.6104151,-0.013900383,-0.20952773]),
+    0x48D1CCu32 => ([76.88213,-37.354244,-8.353113], [0.7868021,-0.11386521,-0.023286536]),
+    0x49796Bu32 => ([47.173668,-19.81728,2.551651], [0.53736436,-0.056823704,0.0064525916]),
+    0x4A0000u32 => ([12.326289,32.766468,19.35921], [0.2569091,0.09199598,0.05148624]),
+    0x4A412Au32 => ([27.855238,-0.047922075,15.457861], [0.37808514,0.00063998747,0.038168896]),
+    0x4A4A4Au32 => ([31.45792,0.0,0.0], [0.40912,3.3119563e-11,1.5249501e-08]),
+    0x4A646Cu32 => ([40.658653,-7.4885325,-7.637077], [0.48552307,-0.026028452,-0.02085409]),
+    0x4A708Bu32 => ([45.507694,-5.6820145,-18.978048], [0.52777094,-0.030021546,-0.052656073]),
+    0x4B0082u32 => ([20.470022,51.68918,-53.312717], [0.33898208,0.094161525,-0.15255125]),
+    0x4B3621u32 => ([24.462677,6.4099126,16.810024], [0.35120362,0.018561773,0.040717855]),
+    0x4B6F44u32 => ([43.18485,-22.018772,19.972115], [0.5025247,-0.06008194,0.04963196]),
+    0x4C2882u32 => ([25.760695,36.981865,-44.654438], [0.37617615,0.06594622,-0.12741888]),
+    0x4C516Du32 => ([35.053963,5.1277394,-16.747988], [0.44197366,0.00524548,-0.046222944]),
+    0x4C9141u32 => ([54.205128,-38.17565,35.4408], [0.59227365,-0.10432899,0.084491976]),
+    0x4CBB17u32 => ([67.38023,-57.583107,64.11509], [0.6998742,-0.15910152,0.1399122]),
+    0x4D4D4Du32 => ([32.74751,0.0,0.0], [0.42023715,3.4019287e-11,1.566388e-08]),
+    0x4D8C57u32 => ([52.972977,-32.246216,22.168844], [0.58340377,-0.08773143,0.055298593]),
+    0x4E5180u32 => ([36.10521,11.294829,-26.882215], [0.45343462,0.013392697,-0.075289175]),
+    0x4EEE94u32 => ([84.43439,-61.16985,31.76192], [0.8448486,-0.16531892,0.079314955]),
+    0x4F4F4Fu32 => ([33.602505,2.7755576e-14,0.0], [0.4276078,3.4616088e-11,1.5938614e-08]),
+    0x4F7942u32 => ([46.618275,-25.913273,25.589891], [0.5308536,-0.07091865,0.06253602]),
+    0x4F94CDu32 => ([59.203876,-4.5126987,-35.838688], [0.6458245,-0.045729555,-0.10088461]),
+    0x5072A7u32 => ([47.661793,3.3865418,-32.13322], [0.5496273,-0.017551322,-0.090355046]),
+    0x507D2Au32 => ([47.67452,-30.358252,39.1352], [0.53863937,-0.08484943,0.09008631]),
+    0x50C878u32 => ([72.47408,-51.24862,30.257654], [0.74513996,-0.1386491,0.075160146]),
+    0x512888u32 => ([26.86132,39.683887,-46.593224], [0.38696495,0.07249728,-0.13293596]),
+    0x525252u32 => ([34.87815,0.0,1.110223e-14], [0.43860474,3.5506265e-11,1.6348514e-08]),
+    0x5271FFu32 => ([52.755383,34.07204,-74.007126], [0.6056804,-0.001415847,-0.21313308]),
+    0x528B8Bu32 => ([54.156715,-18.647861,-5.8828683], [0.59777963,-0.05779736,-0.016208105]),
+    0x536878u32 => ([42.90672,-3.7232258,-11.489851], [0.5062731,-0.01725702,-0.03145141]),
+    0x53868Bu32 => ([52.677734,-15.689055,-8.079189], [0.5860673,-0.050523356,-0.022182563]),
+    0x543D37u32 => ([28.109732,9.11654,7.6374702], [0.38370585,0.027974896,0.019667504]),
+    0x545454u32 => ([35.724148,2.7755576e-14,0.0], [0.44589785,3.609671e-11,1.6620355e-08]),
+    0x545AA7u32 => ([41.195286,19.316654,-42.338818], [0.50046796,0.016253447,-0.120216206]),
+    0x54626Fu32 => ([40.844543,-2.1525397,-9.120688], [0.48913512,-0.01084922,-0.02483063]),
+    0x548B54u32 => ([52.93813,-30.052162,23.847599], [0.5838624,-0.081874035,0.05916482]),
+    0x54FF9Fu32 => ([89.8152,-64.47037,33.424175], [0.8901033,-0.17423931,0.083472]),
+    0x551A8Bu32 => ([25.36449,47.690083,-50.8795], [0.3783602,0.091318995,-0.14529027]),
+    0x555555u32 => ([36.14585,2.7755576e-14,0.0], [0.4495332,3.639089e-11,1.6755859e-08]),
+    0x555D50u32 => ([38.444336,-5.86621,6.415341], [0.467214,-0.015665704,0.016632438]),
+    0x556B2Fu32 => ([42.23383,-18.829433,30.599873], [0.4955208,-0.05289934,0.072315395]),
+    0x563C5Cu32 => ([29.369526,17.800127,-14.610725], [0.3981867,0.047390956,-0.039854538]),
+    0x56887Du32 => ([53.022587,-19.475317,0.5823086], [0.58785,-0.056760583,0.0012374569]),
+    0x56A0D3u32 => ([63.195457,-8.01502,-32.951687], [0.6789742,-0.05159262,-0.092394896]),
+    0x575757u32 => ([36.98671,0.0,1.110223e-14], [0.45678198,3.697781e-11,1.702605e-08]),
+    0x58427Cu32 => ([32.75861,22.770056,-29.608446], [0.42956173,0.04768349,-0.082961634]),
+    0x59260Bu32 => ([22.068857,21.661087,27.435364], [0.33629897,0.059254173,0.059607234]),
+    0x5946B2u32 => ([37.33216,36.33001,-55.04599], [0.47495806,0.04643322,-0.1575407]),
+    0x595959u32 => ([37.82425,0.0,1.110223e-14], [0.46400213,3.7562425e-11,1.7295173e-08]),
+    0x5B3256u32 => ([27.234516,24.5612,-13.932428], [0.3826312,0.06971168,-0.037755158]),
+    0x5C5C5Cu32 => ([39.074493,0.0,0.0], [0.4747801,3.8434866e-11,1.7696909e-08]),
+    0x5CACEEu32 => ([67.98513,-5.0037413,-40.34114], [0.7212357,-0.051460147,-0.11359343]),
+    0x5D3954u32 => ([29.142769,20.612743,-9.688391], [0.3973361,0.059441973,-0.025935315]),
+    0x5D478Bu32 => ([35.39571,25.242655,-34.66343], [0.45334104,0.049142104,-0.0975723]),
+    0x5DA493u32 => ([62.49387,-26.508718,1.8606975], [0.66698045,-0.07652271,0.004523079]),
+    0x5DADECu32 => ([68.21833,-6.011199,-38.88236], [0.7228985,-0.052397456,-0.10935344]),
+    0x5E5E5Eu32 => ([39.904053,2.7755576e-14,0.0], [0.48193148,3.9013764e-11,1.796347e-08]),
+    0x5E8C31u32 => ([53.338223,-31.410782,42.3559], [0.5870827,-0.088048816,0.09758879]),
+    0x5F9EA0u32 => ([61.153835,-19.67614,-7.419834], [0.65768147,-0.061722323,-0.020410107]),
+    0x5FA778u32 => ([62.865185,-33.27889,17.426128], [0.6681455,-0.091143936,0.044290643]),
+    0x6050DCu32 => ([43.346695,44.581264,-69.62934], [0.53033704,0.04266501,-0.20016871]),
+    0x607B8Bu32 => ([50.116585,-5.688305,-11.846121], [0.5676768,-0.023193972,-0.0324073]),
+    0x6082B6u32 => ([53.844944,2.4053366,-31.093079], [0.6025983,-0.017434837,-0.08702596]),
+    0x612086u32 => ([27.561619,46.284687,-44.1065], [0.39621332,0.10467277,-0.12499041]),
+    0x614051u32 => ([31.353006,17.257318,-4.3722687], [0.414942,0.050991345,-0.011420752]),
+    0x616161u32 => ([41.142666,2.7755576e-14,-1.110223e-14], [0.4926092,3.987813e-11,1.8361469e-08]),
+    0x635147u32 => ([35.970085,5.77226,8.690862], [0.45020232,0.018337728,0.022400396]),
+    0x636363u32 => ([41.964687,0.0,0.0], [0.49969557,4.0451864e-11,1.8625606e-08]),
+    0x63B8FFu32 => ([72.32987,-4.99815,-42.750454], [0.7586417,-0.05376588,-0.12040324]),
+    0x644117u32 => ([30.764713,10.96708,30.73488], [0.40718612,0.028374806,0.06867024]),
+    0x6495EDu32 => ([61.92725,9.340373,-49.297096], [0.674622,-0.021289019,-0.1397445]),
+    0x65000Bu32 => ([19.1882,40.86429,24.936628], [0.31928962,0.117446855,0.05426743]),
+    0x654321u32 => ([31.557785,10.873552,26.188091], [0.41400516,0.029658241,0.060763396]),
+    0x660000u32 => ([19.330202,40.86825,29.654917], [0.32043204,0.114742756,0.06421664]),
+    0x66023Cu32 => ([20.808218,43.872837,-5.9826837], [0.33543,0.13539821,-0.015278784]),
+    0x663399u32 => ([32.902805,42.88651,-47.14913], [0.4402718,0.08817676,-0.13386434]),
+    0x664228u32 => ([31.52004,12.551545,22.048998], [0.41432676,0.03572349,0.052545823]),
+    0x665D1Eu32 => ([39.151703,-4.644106,36.090683], [0.47386557,-0.015976302,0.08134933]),
+    0x666666u32 => ([43.19229,5.551115e-14,0.0], [0.51027834,4.1308457e-11,1.9020069e-08]),
+    0x668B8Bu32 => ([55.23021,-12.779686,-4.174517], [0.60923177,-0.039535813,-0.011428846]),
+    0x6699CCu32 => ([61.62482,-2.8167667,-31.430965], [0.66757596,-0.03303534,-0.08785054]),
+    0x66CD00u32 => ([73.8598,-57.389137,72.82981], [0.7557282,-0.16074371,0.15612002]),
+    0x66CDAAu32 => ([75.69208,-38.332497,8.309622], [0.77668625,-0.107762344,0.0212899]),
+    0x66DDAAu32 => ([80.42527,-45.386604,15.020024], [0.8151864,-0.12546168,0.03844431]),
+    0x66FF66u32 => ([89.53397,-69.16643,59.813248], [0.8866904,-0.18789291,0.14014867]),
+    0x673147u32 => ([28.208975,26.994028,-2.4060388], [0.39177614,0.08174951,-0.0059852907]),
+    0x674846u32 => ([33.90105,12.942325,6.4737797], [0.43510556,0.03947364,0.016864384]),
+    0x674C47u32 => ([35.01849,10.618361,7.4196215], [0.4438403,0.03259182,0.019237798]),
+    0x676767u32 => ([43.600075,0.0,0.0], [0.5137937,4.1593312e-11,1.91511e-08]),
+    0x679267u32 => ([56.506004,-23.702394,18.216202], [0.6166685,-0.06469219,0.04608136]),
+    0x68228Bu32 => ([29.289728,48.00889,-44.3729], [0.41182733,0.11155728,-0.12553596]),
+    0x682860u32 => ([27.588123,36.40449,-19.75932], [0.39094284,0.10446695,-0.053939547]),
+    0x683068u32 => ([29.555262,33.746853,-21.858965], [0.4067351,0.09379674,-0.060027637]),
+    0x68838Bu32 => ([52.99255,-7.6952806,-7.481477], [0.5917844,-0.026281085,-0.020352568]),
+    0x6959CDu32 => ([45.01936,36.43025,-58.288494], [0.54100865,0.04573151,-0.16652025]),
+    0x696969u32 => ([44.41356,0.0,-2.220446e-14], [0.52080655,4.216083e-11,1.9412495e-08]),
+    0x698B22u32 => ([53.582508,-27.6572,48.97637], [0.59046817,-0.07978732,0.109068125]),
+    0x698B69u32 => ([54.54105,-19.02638,14.406445], [0.60131794,-0.051982213,0.036761448]),
+    0x6A0DADu32 => ([30.325878,62.029953,-63.41463], [0.42849085,0.11846329,-0.18124227]),
+    0x6A5ACDu32 => ([45.33699,36.045197,-57.7715], [0.5435672,0.045877244,-0.16496573]),
+    0x6A5DFFu32 => ([49.63206,49.424694,-79.029526], [0.5864626,0.040664844,-0.2274512]),
+    0x6B4423u32 => ([32.660683,13.269121,26.525248], [0.42440858,0.036613084,0.061594125]),
+    0x6B6B6Bu32 => ([45.224316,0.0,0.0], [0.52779585,4.2726406e-11,1.9673013e-08]),
+    0x6B8E23u32 => ([54.650475,-28.224148,49.693764], [0.59948385,-0.081376776,0.11069002]),
+    0x6C2E1Fu32 => ([27.412153,26.46255,22.813839], [0.38426822,0.07590022,0.052840367]),
+    0x6C3082u32 => ([31.610497,40.422825,-35.015892], [0.4277668,0.10124331,-0.098065645]),
+    0x6C541Eu32 => ([37.156807,3.6381629,34.20385], [0.4596131,0.007395991,0.077135384]),
+    0x6C7B8Bu32 => ([50.94118,-1.7973557,-10.522935], [0.5763011,-0.010385493,-0.028618427]),
+    0x6CA0DCu32 => ([64.560524,-0.6013596,-35.742466], [0.69366586,-0.031026315,-0.1001605]),
+    0x6CA6CDu32 => ([65.6452,-8.34374,-25.758339], [0.7002306,-0.042998232,-0.0715454]),
+    0x6D9BC3u32 => ([62.196747,-4.8499403,-25.47939], [0.671872,-0.0321679,-0.070735864]),
+    0x6E6E6Eu32 => ([46.43545,0.0,0.0], [0.5382367,4.3571674e-11,2.0062183e-08]),
+    0x6E7B8Bu32 => ([51.08817,-1.1249483,-10.287308], [0.5778302,-0.008180779,-0.027947452]),
+    0x6E8B3Du32 => ([54.15084,-23.422937,37.841007], [0.59668326,-0.06591139,0.08905967]),
+    0x6EAEA1u32 => ([66.64148,-23.526922,0.18846786], [0.7037532,-0.06878816,0.00012153621]),
+    0x6F4E37u32 => ([36.18467,10.86721,19.097643], [0.45392776,0.03204077,0.046858396]),
+    0x701C1Cu32 => ([24.826565,36.62553,21.86611], [0.36609405,0.10653839,0.049997386]),
+    0x702670u32 => ([29.415668,42.618813,-27.11004], [0.4096806,0.118739024,-0.07487456]),
+    0x702963u32 => ([29.2401,38.87345,-19.033798], [0.40618414,0.1131309,-0.051716957]),
+    0x703642u32 => ([30.585102,26.967505,4.5079756], [0.41208646,0.08186605,0.0119350385]),
+    0x704214u32 => ([32.68345,15.97976,34.673782], [0.42558694,0.041795943,0.07563908]),
+    0x707070u32 => ([47.23962,0.0,0.0], [0.5451691,4.4132975e-11,2.0320584e-08]),
+    0x708090u32 => ([52.835976,-2.141087,-10.570761], [0.59250414,-0.01141009,-0.028742729]),
+    0x71A6D2u32 => ([66.086136,-5.7030807,-27.827965], [0.7050169,-0.036936592,-0.07737939]),
+    0x722F37u32 => ([29.125084,30.358698,9.721909], [0.40077865,0.091534294,0.02467889]),
+    0x727472u32 => ([48.61475,-1.1660967,0.8363407], [0.5565899,-0.0032007808,0.0021979334]),
+    0x72A0C1u32 => ([63.80992,-6.881362,-21.864302], [0.68508023,-0.03455203,-0.060433626]),
+    0x733380u32 => ([33.07385,40.34766,-31.38157], [0.44016927,0.10622621,-0.08728785]),
+    0x737373u32 => ([48.441105,5.551115e-14,0.0], [0.55552673,4.497136e-11,2.0706652e-08]),
+    0x738678u32 => ([54.080944,-9.797021,5.236199], [0.600552,-0.027264245,0.013613609]),
+    0x73C2FBu32 => ([75.57042,-8.578605,-35.508354], [0.78544587,-0.053845476,-0.099283025]),
+    0x74C365u32 => ([72.01521,-42.514896,39.58148], [0.74419236,-0.11621824,0.095758386]),
+    0x757575u32 => ([49.238987,0.0,0.0], [0.56240505,4.5528192e-11,2.0963034e-08]),
+    0x764374u32 => ([36.228077,30.029034,-18.932024], [0.46241993,0.08391852,-0.051565595]),
+    0x766EC8u32 => ([50.889366,25.375376,-45.954372], [0.5866375,0.036019497,-0.1298641]),
+    0x76D7EAu32 => ([80.95563,-23.59066,-18.166935], [0.8266558,-0.080890864,-0.05015651]),
+    0x76EE00u32 => ([84.48304,-64.629425,81.41815], [0.8449301,-0.18087514,0.17455946]),
+    0x76EEC6u32 => ([86.54139,-43.16252,9.08226], [0.86847925,-0.12140722,0.023258306]),
+    0x777696u32 => ([50.781525,7.4997745,-17.134361], [0.5784959,0.013615323,-0.046870608]),
+    0x778899u32 => ([55.91705,-2.2458873,-11.107735], [0.61902016,-0.011989233,-0.030206343]),
+    0x778BA5u32 => ([57.17604,-1.0127056,-16.101505], [0.6302811,-0.011736662,-0.044097234]),
+    0x77B5FEu32 => ([72.31127,0.24293017,-42.178265], [0.76067,-0.03547555,-0.118509814]),
+    0x78184Au32 => ([27.133877,44.54718,-5.6734896], [0.39002565,0.13681537,-0.014432625]),
+    0x7851A9u32 => ([42.37293,34.718117,-41.451828], [0.5176225,0.07373137,-0.11666214]),
+    0x787878u32 => ([50.431267,0.0,0.0], [0.57268333,4.6360582e-11,2.1346146e-08]),
+    0x79443Bu32 => ([35.034187,21.76342,15.359437], [0.44820476,0.064785026,0.038219366]),
+    0x796878u32 => ([46.07185,9.814912,-6.419162], [0.53885436,0.02720747,-0.017094225]),
+    0x79CDCDu32 => ([77.36073,-25.44388,-8.005383], [0.7952578,-0.07887135,-0.022066372]),
+    0x7A378Bu32 => ([35.467953,42.539616,-34.327347], [0.46178827,0.11036024,-0.09568766]),
+    0x7A67EEu32 => ([51.849415,41.450912,-65.896965], [0.6019964,0.051651165,-0.1883475]),
+    0x7A7A7Au32 => ([51.223152,0.0,0.0], [0.5795099,4.691303e-11,2.1600599e-08]),
+    0x7A8B8Bu32 => ([56.552013,-6.1105123,-2.0765467], [0.6231373,-0.018857569,-0.005642317]),
+    0x7AC5CDu32 => ([75.12442,-21.200083,-11.331968], [0.7774605,-0.06860061,-0.031131519]),
+    0x7B1113u32 => ([25.742147,43.55243,29.150799], [0.37672463,0.12488483,0.06229811]),
+    0x7B3F00u32 => ([33.491962,22.287815,43.797195], [0.43490916,0.05731574,0.088258676]),
+    0x7B68EEu32 => ([52.15715,41.074867,-65.39569], [0.6044729,0.051809892,-0.18683998]),
+    0x7BB661u32 => ([68.408806,-34.931183,37.17172], [0.71561813,-0.095898986,0.09009619]),
+    0x7C0A02u32 => ([25.282345,45.053314,36.675873], [0.3733034,0.12632202,0.07355378]),
+    0x7C4848u32 => ([36.72127,22.177588,9.57468], [0.46297687,0.067019776,0.024600165]),
+    0x7CB9E8u32 => ([72.76519,-7.213791,-29.551563], [0.7619825,-0.04263658,-0.08215846]),
+    0x7CCD7Cu32 => ([75.70055,-41.0901,32.73461], [0.7762888,-0.11192439,0.08101421]),
+    0x7CFC00u32 => ([88.876915,-67.8576,84.95936], [0.88175315,-0.18977894,0.18217745]),
+    0x7D26CDu32 => ([38.117855,64.9634,-69.417885], [0.49642208,0.121555,-0.19840966]),
+    0x7D7D7Du32 => ([52.40663,0.0,0.0], [0.5897123,4.7739146e-11,2.1980883e-08]),
+    0x7E5E60u32 => ([43.19095,13.363029,3.9431608], [0.515365,0.04066686,0.0104827555]),
+    0x7EC0EEu32 => ([75.01781,-8.825489,-29.32503], [0.780784,-0.04711986,-0.081490085]),
+    0x7ED4E6u32 => ([80.310974,-21.354818,-16.9742], [0.8219718,-0.073177464,-0.046766132]),
+    0x7F00FFu32 => ([40.787106,83.1011,-93.49862], [0.52935565,0.11738072,-0.26854175]),
+    0x7F1734u32 => ([27.773483,44.806633,10.252238], [0.39509264,0.13574535,0.025694022]),
+    0x7F7F7Fu32 => ([53.192776,0.0,0.0], [0.5964894,4.8287374e-11,2.2233493e-08]),
+    0x7FFF00u32 => ([89.87314,-68.067726,85.78693], [0.8902625,-0.19050698,0.18392423]),
+    0x7FFFD4u32 => ([92.034904,-45.52077,9.720063], [0.91499454,-0.12798631,0.024896637]),
+    0x800000u32 => ([25.533325,48.04372,38.055717], [0.3766921,0.13488878,0.07549152]),
+    0x800020u32 => ([25.845482,48.893406,21.294643], [0.3799746,0.14435318,0.048641592]),
+    0x800080u32 => ([29.78378,58.929775,-36.489487], [0.42091367,0.16470431,-0.10147178]),
+    0x801818u32 => ([27.598158,43.247723,28.380735], [0.39257485,0.124400415,0.06195262]),
+    0x80461Bu32 => ([36.079014,21.305853,35.412033], [0.45687208,0.057495005,0.078043036]),
+    0x807532u32 => ([48.936222,-4.750636,37.72264], [0.55817705,-0.015493545,0.087216645]),
+    0x808000u32 => ([51.86852,-12.933197,56.678097], [0.5806646,-0.04281223,0.1191162]),
+    0x808080u32 => ([53.585014,0.0,0.0], [0.5998708,4.8561544e-11,2.235953e-08]),
+    0x80FF00u32 => ([89.90895,-67.78732,85.83032], [0.89065707,-0.1898203,0.18399782]),
+    0x81613Cu32 => ([43.57058,8.186473,26.000547], [0.5165877,0.023432001,0.062624946]),
+    0x826644u32 => ([45.17376,6.52368,23.45767], [0.5298028,0.01938623,0.057372816]),
+    0x828282u32 => ([54.367836,5.551115e-14,0.0], [0.60661924,4.9107718e-11,2.261107e-08]),
+    0x836FFFu32 => ([55.49603,43.5776,-69.415245], [0.63430214,0.054095298,-0.19840683]),
+    0x838996u32 => ([56.99592,0.55538934,-7.6738014], [0.6294332,-0.0014821948,-0.020678453]),
+    0x838B83u32 => ([57.0063,-4.516604,3.2665303], [0.62769604,-0.012387319,0.008547659]),
+    0x838B8Bu32 => ([57.22564,-2.9155953,-1.0097357], [0.63015157,-0.008986197,-0.0027333114]),
+    0x841617u32 => ([28.226475,45.07401,29.896086], [0.39871502,0.12943153,0.06454199]),
+    0x841B2Du32 => ([29.106924,44.578125,16.895529], [0.40630656,0.13294236,0.04059843]),
+    0x8470FFu32 => ([55.799793,43.205997,-68.92043], [0.6367466,0.054251764,-0.19691874]),
+    0x848482u32 => ([55.0927,-0.39282158,1.076906], [0.6127261,-0.0008421151,0.0028453534]),
+    0x856088u32 => ([45.81204,22.282148,-16.293795], [0.5416511,0.060808484,-0.04414741]),
+    0x856D4Du32 => ([47.572334,4.7961125,21.438902], [0.54984975,0.014987691,0.053097285]),
+    0x85754Eu32 => ([49.80933,0.29296497,23.793957], [0.56748897,0.0019030448,0.058612257]),
+    0x858585u32 => ([55.538006,0.0,0.0], [0.61670697,4.9924342e-11,2.2987077e-08]),
+    0x8601AFu32 => ([34.366848,67.77955,-57.823555], [0.46559456,0.1606632,-0.16384731]),
+    0x86608Eu32 => ([46.160328,23.887909,-19.341892], [0.54533833,0.06382957,-0.052701373]),
+    0x86A1A9u32 => ([64.50128,-7.554646,-7.268537], [0.69105965,-0.025575008,-0.019716434]),
+    0x87421Fu32 => ([36.26583,26.845644,33.884464], [0.46062005,0.074275814,0.07534047]),
+    0x878787u32 => ([56.315464,5.551115e-14,0.0], [0.62340915,5.046652e-11,2.3236897e-08]),
+    0x87CEEBu32 => ([79.20804,-14.834012,-21.275524], [0.81481725,-0.057156272,-0.05868004]),
+    0x87CEFAu32 => ([79.72405,-10.825371,-28.500797], [0.8206188,-0.051824216,-0.07905587]),
+    0x87CEFFu32 => ([79.90419,-9.45541,-30.888014], [0.82264006,-0.050129265,-0.085837685]),
+    0x87FF2Au32 => ([90.24601,-64.91497,80.84927], [0.8943956,-0.18150751,0.17676596]),
+    0x880085u32 => ([31.643524,61.106358,-36.561783], [0.43781623,0.17240374,-0.10146111]),
+    0x8806CEu32 => ([37.5196,73.29115,-70.91784], [0.49584103,0.15090184,-0.20225547]),
+    0x882D17u32 => ([32.19922,37.71187,34.086246], [0.42986467,0.105995625,0.07335905]),
+    0x893843u32 => ([35.10855,35.61497,10.8086405], [0.4544233,0.10746113,0.027459968]),
+    0x893F45u32 => ([36.5628,32.15639,11.543453], [0.46554133,0.09683965,0.029292548]),
+    0x8968CDu32 => ([51.412663,35.184986,-47.876823], [0.5955492,0.067665964,-0.1350069]),
+    0x89CFF0u32 => ([79.746605,-13.548473,-23.121527], [0.81990635,-0.05486208,-0.063845955]),
+    0x8A2BE2u32 => ([42.188614,69.85011,-74.76366], [0.53376496,0.13031808,-0.21370523]),
+    0x8A3324u32 => ([33.77202,36.182182,28.500975], [0.44285735,0.1036415,0.06470267]),
+    0x8A496Bu32 => ([39.96612,32.03878,-7.388455], [0.4951845,0.095909074,-0.01928946]),
+    0x8A795Du32 => ([51.688366,2.0932326,17.788813], [0.58434325,0.007900103,0.044875946]),
+    0x8A7F80u32 => ([54.175964,4.355334,0.9665798], [0.60661703,0.013225741,0.0026215918]),
+    0x8A8A8Au32 => ([57.477757,0.0,0.0], [0.63342893,5.1278093e-11,2.3610372e-08]),
+    0x8A9A5Bu32 => ([61.018925,-16.280037,31.167624], [0.65825135,-0.044853345,0.07610483]),
+    0x8B0000u32 => ([28.08743,50.998184,41.289383], [0.39985687,0.14318381,0.08013389]),
+    0x8B008Bu32 => ([32.599266,62.553677,-38.733418], [0.44679788,0.17483285,-0.10771182]),
+    0x8B0A50u32 => ([30.12327,53.437286,-4.7945147], [0.41952026,0.164882,-0.01194501]),
+    0x8B1A1Au32 => ([30.165133,46.165752,30.569414], [0.4158355,0.13265726,0.06641371]),
+    0x8B1C62u32 => ([32.262844,51.337692,-13.324745], [0.4373559,0.15648307,-0.035171498]),
+    0x8B2252u32 => ([32.35407,47.47859,-2.8001387], [0.43603435,0.1457624,-0.0067519387]),
+    0x8B2323u32 => ([31.378098,43.453835,26.427607], [0.4252157,0.12611888,0.05991149]),
+    0x8B2500u32 => ([31.377474,41.70893,43.808426], [0.42432198,0.114615396,0.08546014]),
+    0x8B3626u32 => ([34.53515,35.193817,28.23331], [0.44903964,0.10086437,0.064476825]),
+    0x8B3A3Au32 => ([35.63815,34.534367,17.29608], [0.45843133,0.102621205,0.042429518]),
+    0x8B3A62u32 => ([36.739,39.008934,-6.559252], [0.47028607,0.11825439,-0.016910905]),
+    0x8B3E2Fu32 => ([36.253002,31.611649,24.879229], [0.46245888,0.091527864,0.058573905]),
+    0x8B4500u32 => ([37.38271,26.025469,47.43037], [0.469857,0.067558736,0.09528921]),
+    0x8B4513u32 => ([37.468403,26.439123,40.984188], [0.4707835,0.07080872,0.08696012]),
+    0x8B4726u32 => ([38.085735,26.130465,32.23451], [0.4760418,0.07299125,0.07313289]),
+    0x8B475Du32 => ([39.218502,31.444965,0.2499507], [0.48834527,0.09540408,0.0010699424]),
+    0x8B4789u32 => ([40.900013,38.73096,-24.402676], [0.5064539,0.10826524,-0.06676247]),
+    0x8B4C39u32 => ([39.5029,24.634209,22.907196], [0.48776054,0.071755536,0.05521808]),
+    0x8B5742u32 => ([42.306736,19.111784,21.131533], [0.5098221,0.05620653,0.05179587]),
+    0x8B5A00u32 => ([42.463356,13.955247,50.641655], [0.50910914,0.032353424,0.103693455]),
+    0x8B5A2Bu32 => ([42.72764,15.491398,34.695652], [0.5120029,0.04183085,0.07934129]),
+    0x8B5F65u32 => ([45.100243,18.836323,3.8501472], [0.5339498,0.057117853,0.010298147]),
+    0x8B636Cu32 => ([46.306,17.587732,1.3655515], [0.54387563,0.053052094,0.0038379387]),
+    0x8B6508u32 => ([45.379013,7.691094,50.695408], [0.5319594,0.014957871,0.106074095]),
+    0x8B668Bu32 => ([48.078312,21.48464,-14.575909], [0.5608322,0.059349135,-0.039320562]),
+    0x8B6914u32 => ([46.502834,5.65858,48.400394], [0.5409162,0.010079277,0.103645064]),
+    0x8B6969u32 => ([47.76802,13.584351,5.2759476], [0.5548978,0.041482266,0.013928598]),
+    0x8B72BEu32 => ([53.21267,26.06932,-36.43393], [0.60704064,0.054143667,-0.10164556]),
+    0x8B7355u32 => ([49.985737,4.913476,20.128897], [0.5707023,0.015680756,0.05025606]),
+    0x8B7500u32 => ([49.752235,-1.6549633,55.634125], [0.56632847,-0.011969941,0.11586064]),
+    0x8B7765u32 => ([51.407726,4.812232,12.778306], [0.58293104,0.015972449,0.032766562]),
+    0x8B795Eu32 => ([51.80575,2.653681,17.387655], [0.5855615,0.009550085,0.0439265]),
+    0x8B7B8Bu32 => ([53.513058,9.172869,-6.4108167], [0.6027434,0.025248317,-0.017063685]),
+    0x8B7D6Bu32 => ([53.18725,2.2859476,11.772952], [0.5973325,0.008652725,0.030307405]),
+    0x8B7D7Bu32 => ([53.58547,4.976591,3.0330467], [0.6017655,0.015550135,0.008052539]),
+    0x8B7E66u32 => ([53.352337,0.9501771,14.892705], [0.5982654,0.0048094625,0.037975695]),
+    0x8B814Cu32 => ([53.719604,-4.106071,30.02111], [0.5996245,-0.011341041,0.07265714]),
+    0x8B8378u32 => ([55.152367,1.0152591,7.0259356], [0.6137918,0.0045657926,0.018341005]),
+    0x8B8386u32 => ([55.525654,3.575872,-0.5357231], [0.617952,0.010480625,-0.0013674488]),
+    0x8B8589u32 => ([56.15806,3.013981,-1.3447667], [0.62318724,0.008548593,-0.003530075]),
+    0x8B864Eu32 => ([55.164326,-6.659522,30.749577], [0.61117095,-0.018509518,0.07442535]),
+    0x8B8680u32 => ([56.18555,0.7406314,3.8904665], [0.6225857,0.0032093322,0.010241239]),
+    0x8B8682u32 => ([56.23862,1.1099982,2.8137002], [0.62317777,0.004057066,0.0074336072]),
+    0x8B8878u32 => ([56.53896,-1.8091689,8.998386], [0.6247029,-0.0034092057,0.02335487]),
+    0x8B8970u32 => ([56.63455,-3.6979957,13.709005], [0.6248515,-0.008507609,0.03513749]),
+    0x8B8989u32 => ([57.25665,0.7402095,0.2619634], [0.63180345,0.0022778457,0.0007060218]),
+    0x8B8B00u32 => ([56.042114,-13.728528,60.163532], [0.6163727,-0.045444977,0.12644129]),
+    0x8B8B7Au32 => ([57.423927,-3.143339,9.107689], [0.63183963,-0.007263154,0.023628818]),
+    0x8B8B83u32 => ([57.64862,-1.5264382,4.275759], [0.63435227,-0.0033723156,0.011219934]),
+    0x8BA8B7u32 => ([67.211365,-6.5534244,-11.0035925], [0.71474874,-0.02469291,-0.029934056]),
+    0x8C8C8Cu32 => ([58.25007,5.551115e-14,0.0], [0.64008677,5.181694e-11,2.3858536e-08]),
+    0x8C92ACu32 => ([60.87998,3.4363577,-14.339322], [0.66397166,0.003445274,-0.03899274]),
+    0x8CBED6u32 => ([74.35341,-10.546777,-17.281216], [0.7746906,-0.040988285,-0.04741452]),
+    0x8D4E85u32 => ([42.468822,34.802246,-19.520863], [0.5181426,0.09897636,-0.052930396]),
+    0x8DA399u32 => ([65.08673,-9.721761,2.6028926], [0.6954205,-0.0278135,0.006738201]),
+    0x8DB6CDu32 => ([71.910194,-8.236074,-16.057953], [0.7545331,-0.033119705,-0.04396445]),
+    0x8DD9CCu32 => ([81.645645,-26.356848,-1.0497962], [0.83201855,-0.07770111,-0.0032345878]),
+    0x8DEEEEu32 => ([88.439384,-28.549414,-8.979531], [0.88959485,-0.088499345,-0.024752988]),
+    0x8E3A59u32 => ([36.92783,38.85138,-0.5202697], [0.47167856,0.11842778,-0.00086675846]),
+    0x8E4585u32 => ([40.737183,39.98107,-22.183016], [0.50553507,0.11410801,-0.060364846]),
+    0x8EE5EEu32 => ([85.98664,-23.941137,-12.628581], [0.8700574,-0.077376,-0.03469788]),
+    0x8F00FFu32 => ([42.853424,84.37682,-90.01664], [0.54763174,0.14169216,-0.25796884]),
+    0x8F8F8Fu32 => ([59.40479,0.0,2.220446e-14], [0.6500413,5.262285e-11,2.4229578e-08]),
+    0x8FBC8Fu32 => ([72.08693,-23.819565,18.038876], [0.7508648,-0.06507693,0.046025883]),
+    0x8FD400u32 => ([77.83106,-46.5048,76.71593], [0.79333663,-0.13411617,0.16353215]),
+    0x905D5Du32 => ([45.010143,20.88358,8.649793], [0.53392476,0.063379966,0.022473192]),
+    0x90EE90u32 => ([86.54868,-46.32779,36.951508], [0.86800325,-0.126184,0.09138165]),
+    0x912CEEu32 => ([44.225075,73.136765,-78.199974], [0.5529085,0.1358553,-0.22356771]),
+    0x913831u32 => ([36.120247,37.15376,23.725708], [0.46353638,0.10851562,0.05605875]),
+    0x914E75u32 => ([42.389618,33.373653,-9.822033], [0.516651,0.09923692,-0.025897818]),
+    0x915C83u32 => ([46.060387,27.899286,-12.78333], [0.54605293,0.080591016,-0.034178115]),
+    0x915F6Du32 => ([46.054432,22.4422,0.48306784], [0.54362655,0.06767031,0.0015843842]),
+    0x919191u32 => ([60.172146,5.551115e-14,2.220446e-14], [0.65665644,5.3158145e-11,2.447615e-08]),
+    0x91A3B0u32 => ([66.018974,-3.499955,-8.84433], [0.70565987,-0.014236823,-0.023932874]),
+    0x922B3Eu32 => ([34.370476,44.323242,13.268042], [0.4515809,0.13349308,0.033071913]),
+    0x9370DBu32 => ([54.975327,36.802002,-50.08981], [0.62691426,0.07093668,-0.14121489]),
+    0x93C572u32 => ([74.41291,-31.20845,36.413715], [0.76856756,-0.08565976,0.08907123]),
+    0x93CCEAu32 => ([79.22977,-10.992664,-20.650303], [0.81649417,-0.044827566,-0.05680809]),
+    0x9400D3u32 => ([39.57997,76.326485,-70.36751], [0.51490974,0.16689838,-0.20023905]),
+    0x949494u32 => ([61.319584,0.0,0.0], [0.66654813,5.3958782e-11,2.4844853e-08]),
+    0x954535u32 => ([39.41798,32.317577,25.523247], [0.49000335,0.09375322,0.060424317]),
+    0x960018u32 => ([30.762466,54.340244,33.137615], [0.42434382,0.15669264,0.07014515]),
+    0x963D7Fu32 => ([40.171574,45.420208,-19.280027], [0.50293416,0.13382334,-0.051940832]),
+    0x964B00u32 => ([40.438187,27.494148,50.14312], [0.4967423,0.07136499,0.10074232]),
+    0x967117u32 => ([49.926052,6.3166595,50.861553], [0.5706673,0.011667329,0.10903065]),
+    0x9678B6u32 => ([55.37451,23.997305,-28.393385], [0.6247793,0.056816958,-0.078307614]),
+    0x969696u32 => ([62.082176,0.0,2.220446e-14], [0.6731222,5.449119e-11,2.5089895e-08]),
+    0x96C8A2u32 => ([76.31432,-23.987362,13.795788], [0.7871764,-0.066141725,0.035492998]),
+    0x96CDCDu32 => ([78.78324,-17.622702,-5.741212], [0.810449,-0.05452667,-0.015725845]),
+    0x979AAAu32 => ([63.82973,2.186632,-8.698343], [0.6889641,0.0030050627,-0.023417532]),
+    0x97FFFFu32 => ([94.01938,-30.187605,-9.490921], [0.937082,-0.09357936,-0.026164567]),
+    0x987456u32 => ([51.65432,10.050046,22.019667], [0.5869905,0.030348035,0.054652587]),
+    0x98817Bu32 => ([55.893394,7.7758,6.512873], [0.6227219,0.024410238,0.017088247]),
+    0x989898u32 => ([62.842915,5.551115e-14,0.0], [0.6796803,5.5022154e-11,2.533434e-08]),
+    0x98F5FFu32 => ([91.32372,-25.160559,-13.5026655], [0.91559553,-0.08147634,-0.037101988]),
+    0x98FB98u32 => ([90.75011,-48.296627,38.530235], [0.9035428,-0.1315452,0.09527285]),
+    0x98FF98u32 => ([91.890976,-49.975792,40.012165], [0.9128293,-0.13609613,0.09871494]),
+    0x9932CCu32 => ([43.38027,65.15734,-60.09903], [0.54111457,0.15060078,-0.1701666]),
+    0x996515u32 => ([47.150635,14.535009,49.29623], [0.54973835,0.03504063,0.104863055]),
+    0x996666u32 => ([48.554882,20.552498,8.408901], [0.5643525,0.0624514,0.021916663]),
+    0x9966CCu32 => ([52.547737,40.312992,-45.401775], [0.6076205,0.09024343,-0.12734418]),
+    0x997A8Du32 => ([54.673424,15.319136,-5.706081], [0.6151462,0.044277,-0.015028676]),
+    0x999999u32 => ([63.222595,0.0,0.0], [0.6829534,5.5286997e-11,2.5456341e-08]),
+    0x9A32CDu32 => ([43.59475,65.545074,-60.3232], [0.5431449,0.15170917,-0.170792]),
+    0x9AB973u32 => ([71.42267,-22.691345,32.139988], [0.74568194,-0.062101237,0.07925277]),
+    0x9AC0CDu32 => ([75.43653,-9.710169,-10.686992], [0.7844699,-0.033826698,-0.029082797]),
+    0x9ACD32u32 => ([76.53479,-37.99102,66.58981], [0.78485227,-0.10964247,0.14744212]),
+    0x9AFF9Au32 => ([92.00739,-49.101143,39.20013], [0.9141096,-0.13373195,0.09688558]),
+    0x9B111Eu32 => ([32.841675,53.233204,32.02583], [0.4417714,0.15386587,0.06949228]),
+    0x9B30FFu32 => ([47.36938,76.93344,-82.53384], [0.5817672,0.14207394,-0.23599249]),
+    0x9BC4E2u32 => ([77.24397,-6.5735564,-19.318607], [0.80110127,-0.030285722,-0.05297335]),
+    0x9BCD9Bu32 => ([77.87315,-26.025326,19.743248], [0.7999691,-0.07109455,0.050324704]),
+    0x9C2542u32 => ([35.56818,50.039925,12.632657], [0.46428013,0.1512787,0.03159574]),
+    0x9C51B6u32 => ([47.405605,47.46559,-40.835617], [0.5666131,0.12066933,-0.113862045]),
+    0x9C7C38u32 => ([53.781456,4.488698,40.952568], [0.60325426,0.010078094,0.09422343]),
+    0x9C9C9Cu32 => ([64.358925,0.0,0.0], [0.6927493,5.6079974e-11,2.5821475e-08]),
+    0x9D2933u32 => ([35.97315,47.938614,22.78154], [0.4666701,0.1415133,0.053767174]),
+    0x9E1B32u32 => ([34.568565,52.641376,21.698954], [0.45655653,0.15621136,0.051157773]),
+    0x9E5E6Fu32 => ([47.524715,28.32356,1.6175597], [0.5586189,0.08573352,0.0046147397]),
+    0x9E9E9Eu32 => ([65.11424,5.551115e-14,0.0], [0.6992607,5.6607108e-11,2.606418e-08]),
+    0x9EFD38u32 => ([90.69012,-56.40959,78.13559], [0.90086234,-0.15933226,0.17252006]),
+    0x9F00C5u32 => ([40.251217,75.20523,-61.061687], [0.5195411,0.18530074,-0.1725538]),
+    0x9F00FFu32 => ([45.069553,85.85492,-86.28866], [0.5671712,0.16477573,-0.24661]),
+    0x9F1D35u32 => ([35.01919,52.55301,20.389122], [0.46042374,0.15645063,0.048543185]),
+    0x9F2B68u32 => ([38.020115,52.468678,-8.079375], [0.48706886,0.16062917,-0.02075928]),
+    0x9F4576u32 => ([42.666965,43.004505,-9.814139], [0.5230566,0.12972657,-0.025658568]),
+    0x9F79EEu32 => ([59.17632,39.6148,-53.96188], [0.66430587,0.07579208,-0.15222797]),
+    0x9F8170u32 => ([56.329113,8.904559,13.56565], [0.6268912,0.028066466,0.034806788]),
+    0x9FA91Fu32 => ([66.40201,-19.610922,63.11207], [0.7036615,-0.060952857,0.13672987]),
+    0x9FB6CDu32 => ([73.04572,-2.8472025,-14.23539], [0.76642185,-0.015361351,-0.03873922]),
+    0xA020F0u32 => ([45.357014,78.74019,-77.39429], [0.565501,0.16262309,-0.22063299]),
+    0xA0522Du32 => ([43.79772,29.318764,35.637856], [0.52648216,0.081961505,0.08083752]),
+    0xA0D6B4u32 => ([81.21509,-24.259523,11.214196], [0.8292749,-0.06747754,0.028956167]),
+    0xA17A74u32 => ([54.821915,14.275508,9.170041], [0.6159505,0.043846063,0.023871617]),
+    0xA1A1A1u32 => ([66.24395,0.0,0.0], [0.7089995,5.7395422e-11,2.6427184e-08]),
+    0xA1CAF1u32 => ([79.70926,-4.406219,-23.594725], [0.8231182,-0.027018836,-0.064911544]),
+    0xA2006Du32 => ([35.57053,63.02703,-14.907356], [0.470976,0.19360247,-0.039208665]),
+    0xA2A2D0u32 => ([68.07652,9.794279,-23.39684], [0.7284389,0.016754646,-0.064161815]),
+    0xA2ADD0u32 => ([70.95509,3.6820388,-19.014372], [0.75087583,0.00127862,-0.051928114]),
+    0xA2B5CDu32 => ([72.98162,-1.3721105,-14.311285], [0.7664364,-0.010912662,-0.038913358]),
+    0xA2CD5Au32 => ([77.32962,-32.088146,51.78117], [0.7935365,-0.09046981,0.12130145]),
+    0xA3A3A3u32 => ([66.99493,5.551115e-14,-2.220446e-14], [0.71547353,5.7919614e-11,2.6668495e-08]),
+    0xA3C1ADu32 => ([75.41587,-14.057184,6.7037907], [0.7829122,-0.03929016,0.01741479]),
+    0xA45A52u32 => ([46.73097,29.405548,18.058723], [0.5519774,0.0877183,0.045064848]),
+    0xA4D3EEu32 => ([82.19176,-9.036331,-18.215181], [0.8428369,-0.036707148,-0.049881805]),
+    0xA4DDEDu32 => ([84.85256,-14.422834,-13.684858], [0.86381805,-0.049678788,-0.037397824]),
+    0xA50021u32 => ([34.21216,58.44057,32.327168], [0.4557328,0.16979656,0.07024997]),
+    0xA50B5Eu32 => ([36.07693,60.825695,-4.488069], [0.47384584,0.18773521,-0.011031611]),
+    0xA52A2Au32 => ([37.52433,49.688004,30.541035], [0.48061255,0.14402948,0.068890296]),
+    0xA55353u32 => ([45.374943,33.69458,15.648409], [0.5420161,0.10101044,0.03935302]),
+    0xA57164u32 => ([52.653316,18.88474,15.521592], [0.59897864,0.057168227,0.039448358]),
+    0xA63A79u32 => ([42.02286,50.676365,-12.5242195], [0.52086556,0.15351781,-0.032913964]),
+    0xA67B5Bu32 => ([55.102222,12.583184,24.008389], [0.6176574,0.03760138,0.059379533]),
+    0xA6A6A6u32 => ([68.11823,0.0,0.0], [0.72515714,5.87036e-11,2.7029442e-08]),
+    0xA75502u32 => ([45.24564,29.36351,53.8383], [0.5388764,0.076233365,0.10867899]),
+    0xA7F432u32 => ([88.517204,-49.964565,77.93108], [0.88421834,-0.14282906,0.17102946]),
+    0xA7FC00u32 => ([90.704445,-54.309177,87.16734], [0.9017266,-0.15614162,0.1859131]),
+    0xA81C07u32 => ([36.313686,54.006622,46.99873], [0.47179124,0.15114202,0.09183609]),
+    0xA83731u32 => ([40.048683,45.908073,29.432405], [0.500819,0.13323906,0.06772583]),
+    0xA8A8A8u32 => ([68.86502,0.0,0.0], [0.731595,5.9224625e-11,2.7269405e-08]),
+    0xA8C3BCu32 => ([76.67011,-10.496748,0.44561118], [0.79496133,-0.030834874,0.0010199478]),
+    0xA8E4A0u32 => ([85.17388,-31.834301,27.238647], [0.8609429,-0.086786166,0.06866777]),
+    0xA91101u32 => ([35.673687,56.5747,49.170383], [0.46733496,0.15830497,0.09332969]),
+    0xA9A9A9u32 => ([69.23779,0.0,0.0], [0.73480856,5.948486e-11,2.7389188e-08]),
+    0xA9BA9Du32 => ([73.53703,-11.223278,12.7521515], [0.76780564,-0.0299782,0.032927066]),
+    0xAA381Eu32 => ([40.360348,45.39547,40.46517], [0.50316536,0.12762032,0.08662559]),
+    0xAA98A9u32 => ([64.85716,9.744392,-6.4373555], [0.700749,0.026972217,-0.017099082]),
+    0xAAA9ADu32 => ([69.42615,1.1159723,-1.9290161], [0.73684347,0.0026859678,-0.0051191566]),
+    0xAAF0D1u32 => ([89.61177,-28.113737,8.045471], [0.90020925,-0.07939158,0.02075705]),
+    0xAB274Fu32 => ([39.027508,54.81228,9.534747], [0.49612674,0.16682085,0.024403075]),
+    0xAB4B52u32 => ([44.66095,39.99985,15.435967], [0.5383891,0.1200822,0.03875357]),
+    0xAB4E52u32 => ([45.24438,38.4865,16.228485], [0.5427934,0.115341954,0.040644564]),
+    0xAB82FFu32 => ([63.21317,41.879986,-56.842075], [0.7000409,0.080385715,-0.1603432]),
+    0xAB92B3u32 => ([63.73003,15.430263,-13.740722], [0.69326174,0.040960018,-0.03699323]),
+    0xABABABu32 => ([69.98213,0.0,0.0], [0.74122524,6.000411e-11,2.7628362e-08]),
+    0xACACACu32 => ([70.3537,5.551115e-14,0.0], [0.7444284,6.0263516e-11,2.7747756e-08]),
+    0xACACE6u32 => ([72.22086,12.434264,-29.04987], [0.7651713,0.020376336,-0.08006431]),
+    0xACBF60u32 => ([74.11082,-20.861156,45.34989], [0.76957154,-0.059050318,0.10775094]),
+    0xACE1AFu32 => ([84.850914,-26.617886,19.05778], [0.85987616,-0.07284497,0.048754267]),
+    0xACE5EEu32 => ([87.47569,-15.99785,-10.250352], [0.8859068,-0.052159514,-0.027962636]),
+    0xAD4379u32 => ([44.632305,48.831944,-8.4782], [0.54237807,0.14838313,-0.021899726]),
+    0xAD6F69u32 => ([53.248142,23.859556,13.5756235], [0.6060415,0.072160415,0.03475517]),
+    0xADADADu32 => ([70.72486,5.551115e-14,0.0], [0.7476281,6.0522864e-11,2.786702e-08]),
+    0xADD8E6u32 => ([83.813515,-10.889042,-11.476056], [0.8562331,-0.037679408,-0.031231297]),
+    0xADFF2Fu32 => ([91.956955,-52.483963,81.87017], [0.91304886,-0.1501201,0.17879699]),
+    0xAE2029u32 => ([38.187283,55.531185,32.372894], [0.48870045,0.16116302,0.072035216]),
+    0xAE98AAu32 => ([65.24557,11.360487,-6.3839], [0.70472443,0.031904373,-0.016921934]),
+    0xAEEEEEu32 => ([90.00729,-19.918169,-6.483836], [0.9063425,-0.061631925,-0.017762616]),
+    0xAF4035u32 => ([42.82237,44.616505,30.654259], [0.5241671,0.129172,0.07077586]),
+    0xAF6E4Du32 => ([52.73296,22.353905,29.060345], [0.60088897,0.064687744,0.070020996]),
+    0xAFDBF5u32 => ([85.183945,-8.438956,-17.353617], [0.8688809,-0.03411867,-0.047426894]),
+    0xAFEEEEu32 => ([90.06065,-19.635435,-6.3989987], [0.90690863,-0.06075319,-0.01752655]),
+    0xB03060u32 => ([41.516594,54.702007,2.46524], [0.51770574,0.16756217,0.0069583473]),
+    0xB0B0B0u32 => ([71.83598,5.551115e-14,0.0], [0.75720674,6.129802e-11,2.8224054e-08]),
+    0xB0BF1Au32 => ([73.91153,-23.386866,71.15013], [0.7671261,-0.07245521,0.1526088]),
+    0xB0C4DEu32 => ([78.45199,-1.2793018,-15.210757], [0.81362313,-0.011055388,-0.04135573]),
+    0xB0E0E6u32 => ([86.132965,-14.090363,-8.006895], [0.8750832,-0.045226052,-0.021795848]),
+    0xB0E2FFu32 => ([87.407875,-9.431158,-19.263824], [0.887641,-0.03845864,-0.05275574]),
+    0xB22222u32 => ([39.11544,55.914516,37.64703], [0.49677107,0.1603672,0.081059314]),
+    0xB23AEEu32 => ([50.293045,73.671616,-68.11946], [0.6046069,0.16946268,-0.19296487]),
+    0xB284BEu32 => ([61.382195,27.898989,-23.456934], [0.67811614,0.07401617,-0.06390462]),
+    0xB2BEB5u32 => ([75.84027,-5.8405724,3.1151683], [0.78956074,-0.016317919,0.008143105]),
+    0xB2DFEEu32 => ([86.21212,-11.227559,-12.097617], [0.87677443,-0.03902575,-0.032934643]),
+    0xB2EC5Du32 => ([87.117,-39.956085,61.761505], [0.8752925,-0.11292277,0.14272448]),
+    0xB2FFFFu32 => ([95.24627,-23.363705,-7.537401], [0.9502116,-0.07232947,-0.020683033]),
+    0xB31B1Bu32 => ([38.63549,57.77608,41.050125], [0.4933732,0.1647346,0.085735574]),
+    0xB3446Cu32 => ([45.361973,48.72096,0.67323005], [0.54833454,0.14877672,0.0023458095]),
+    0xB3B3B3u32 => ([72.9436,0.0,0.0], [0.7667552,6.207107e-11,2.8579963e-08]),
+    0xB3EE3Au32 => ([87.503136,-42.701897,74.97406], [0.87781817,-0.12330499,0.16570996]),
+    0xB452CDu32 => ([52.00268,57.80147,-46.635933], [0.6109126,0.1496776,-0.13011053]),
+    0xB48395u32 => ([59.984978,21.622992,-2.1687493], [0.66338,0.064493924,-0.005430606]),
+    0xB4CDCDu32 => ([80.61249,-8.357618,-2.8374465], [0.8297055,-0.025793977,-0.0077112643]),
+    0xB4EEB4u32 => ([88.99164,-29.34736,22.276268], [0.89463717,-0.080166236,0.05676233]),
+    0xB53389u32 => ([44.231583,59.688854,-18.72005], [0.54399014,0.18028972,-0.049848933]),
+    0xB57EDCu32 => ([61.579407,38.523544,-39.994785], [0.6844445,0.0931691,-0.111064225]),
+    0xB5B35Cu32 => ([71.44418,-11.47996,44.27626], [0.7498437,-0.033294104,0.10494136]),
+    0xB5B5B5u32 => ([73.68011,5.551115e-14,0.0], [0.77310437,6.2584715e-11,2.881662e-08]),
+    0xB7410Eu32 => ([44.062157,45.752487,51.11778], [0.53509915,0.12540387,0.10340163]),
+    0xB768A2u32 => ([54.634644,39.712074,-17.656841], [0.62484664,0.11550581,-0.047335852]),
+    0xB784A7u32 => ([61.098614,25.218142,-10.77337], [0.6744777,0.072788835,-0.028602812]),
+    0xB86D29u32 => ([53.26445,24.498203,48.495655], [0.6061719,0.06484345,0.106216796]),
+    0xB87333u32 => ([54.753803,21.64875,45.39668], [0.6179532,0.0578433,0.10181905]),
+    0xB8860Bu32 => ([59.219574,9.859737,62.73321], [0.65206957,0.01937692,0.13077164]),
+    0xB8B8B8u32 => ([74.78203,5.551115e-14,-2.220446e-14], [0.7826037,6.33541e-11,2.9170698e-08]),
+    0xB94E48u32 => ([47.278122,42.84531,25.31915], [0.5619146,0.12609582,0.060914993]),
+    0xB9D3EEu32 => ([83.48186,-2.9255753,-16.1441], [0.85634136,-0.016541759,-0.043940056]),
+    0xB9D9EBu32 => ([84.96439,-6.717985,-12.330705], [0.86772346,-0.02561598,-0.033479523]),
+    0xBA160Cu32 => ([39.650032,60.482628,49.160854], [0.5031413,0.17027196,0.096513115]),
+    0xBA55D3u32 => ([53.643345,59.06286,-47.40423], [0.62557954,0.15330534,-0.132207]),
+    0xBABABAu32 => ([75.51479,5.551115e-14,0.0], [0.78892064,6.3865246e-11,2.9406154e-08]),
+    0xBBB477u32 => ([72.58769,-6.7012057,32.040733], [0.76135266,-0.017523883,0.07899401]),
+    0xBBFFFFu32 => ([95.70683,-20.897602,-6.804936], [0.95510685,-0.06466132,-0.018641125]),
+    0xBC8F8Fu32 => ([63.60677,17.010828,6.608826], [0.6927437,0.051944137,0.017446116]),
+    0xBCB88Au32 => ([74.06653,-5.9767485,23.946161], [0.77433413,-0.01458821,0.060391244]),
+    0xBCD2EEu32 => ([83.419685,-1.4914814,-16.21772], [0.85635716,-0.012214361,-0.04410883]),
+    0xBCD4E6u32 => ([83.69994,-4.2742953,-11.545975], [0.8577632,-0.01780857,-0.031273633]),
+    0xBCEE68u32 => ([88.37092,-36.24257,58.608135], [0.88730097,-0.102280386,0.13700965]),
+    0xBD33A4u32 => ([46.80136,65.40829,-30.85563], [0.5691313,0.19205646,-0.08404446]),
+    0xBDB76Bu32 => ([73.38153,-8.791057,39.29272], [0.76747406,-0.02457125,0.094910465]),
+    0xBDBDBDu32 => ([76.6112,0.0,0.0], [0.7983724,6.463069e-11,2.9758459e-08]),
+    0xBDDA57u32 => ([82.726654,-27.991482,59.65352], [0.84146273,-0.08088439,0.1373768]),
+    0xBE4F62u32 => ([48.788193,46.29189,11.89073], [0.57655615,0.14006111,0.030461883]),
+    0xBEBEBEu32 => ([76.975945,5.551115e-14,0.0], [0.8015167,6.4884986e-11,2.987566e-08]),
+    0xBED3E5u32 => ([83.543396,-3.340817,-11.233787], [0.85677326,-0.014817796,-0.030396534]),
+    0xBF00FFu32 => ([49.85513,89.375465,-78.262184], [0.6092981,0.20670143,-0.22209285]),
+    0xBF3EFFu32 => ([53.752964,77.91841,-71.92318], [0.6363712,0.17930016,-0.20374893]),
+    0xBFAFB2u32 => ([72.85963,6.24984,0.5439168], [0.7684009,0.018758887,0.001534112]),
+    0xBFBFBFu32 => ([77.34033,0.0,0.0], [0.804658,6.513967e-11,2.9992748e-08]),
+    0xBFC1C2u32 => ([77.94066,-0.50559765,-0.74469674], [0.80963826,-0.0017398313,-0.0019868773]),
+    0xBFEFFFu32 => ([91.69739,-11.821815,-12.737132], [0.92383033,-0.04108929,-0.03467519]),
+    0xBFFF00u32 => ([92.83695,-46.875576,89.36025], [0.92248356,-0.13744457,0.18996166]),
+    0xC0362Cu32 => ([44.27388,54.070625,38.265144], [0.54039615,0.15499839,0.08437138]),
+    0xC04000u32 => ([45.462727,49.21142,56.681477], [0.5485056,0.13415419,0.11062811]),
+    0xC0448Fu32 => ([48.667633,56.94621,-15.400515], [0.58076805,0.17215285,-0.040650208]),
+    0xC08081u32 => ([60.096863,24.899355,9.632333], [0.6655192,0.07563499,0.025135413]),
+    0xC09999u32 => ([66.740654,14.517656,5.5385065], [0.7188058,0.04440322,0.014683069]),
+    0xC0C0C0u32 => ([77.70436,0.0,0.0], [0.80779624,6.539341e-11,3.010972e-08]),
+    0xC0FF3Eu32 => ([93.046455,-45.03824,79.24285], [0.9248172,-0.13010156,0.17498943]),
+    0xC154C1u32 => ([53.524467,57.979877,-37.22506], [0.6237304,0.16134061,-0.10252075]),
+    0xC19A6Bu32 => ([66.14471,8.363557,30.153646], [0.7112805,0.025158571,0.074079186]),
+    0xC1CDC1u32 => ([81.20914,-6.306044,4.5633807], [0.8356812,-0.017294098,0.011937526]),
+    0xC1CDCDu32 => ([81.515114,-4.0685205,-1.4078671], [0.83910716,-0.012540363,-0.0038116644]),
+    0xC1FFC1u32 => ([94.613495,-30.963215,23.505188], [0.94252616,-0.08457955,0.059890226]),
+    0xC21E56u32 => ([42.87117,63.98485,11.143339], [0.53301275,0.19484068,0.028302046]),
+    0xC2B280u32 => ([72.80567,-1.7522308,27.677004], [0.7650061,-0.0029296859,0.06904687]),
+    0xC2C2C2u32 => ([78.43137,0.0,0.0], [0.81406355,6.590106e-11,3.034333e-08]),
+    0xC30B4Eu32 => ([41.83491,66.74195,14.795879], [0.52516824,0.20236064,0.0366942]),
+    0xC32148u32 => ([43.002934,62.664593,20.13148], [0.53334314,0.1879744,0.04876054]),
+    0xC3B091u32 => ([72.69237,1.9605563,18.38924], [0.7653756,0.008314246,0.046966955]),
+    0xC3CDE6u32 => ([82.36666,1.6045965,-13.489045], [0.8485028,-0.0011151101,-0.036492337]),
+    0xC40233u32 => ([41.248398,66.541756,31.020145], [0.51965606,0.19565533,0.070051745]),
+    0xC41E3Au32 => ([42.72084,62.89033,28.483034], [0.5308306,0.18553981,0.06584733]),
+    0xC46210u32 => ([52.409332,34.73003,57.44143], [0.60265964,0.09162328,0.11796342]),
+    0xC4AEADu32 => ([72.90868,7.744047,3.411828], [0.7693954,0.023889232,0.009094688]),
+    0xC4C3D0u32 => ([79.225235,2.8000667,-6.3637795], [0.8219339,0.0060352273,-0.01700964]),
+    0xC4C4C4u32 => ([79.15698,5.551115e-14,0.0], [0.8203188,6.640721e-11,3.0576487e-08]),
+    0xC54B8Cu32 => ([50.449654,55.048508,-10.833739], [0.5951153,0.16716394,-0.028155832]),
+    0xC5B358u32 => ([72.77043,-5.126752,48.1204], [0.76352763,-0.01646215,0.11264559]),
+    0xC5CBE1u32 => ([81.84806,2.2674212,-11.579348], [0.84430397,0.0019523985,-0.03122727]),
+    0xC5E384u32 => ([86.24293,-24.909851,43.086163], [0.8726998,-0.06886237,0.104780324]),
+    0xC6E2FFu32 => ([88.74141,-3.115981,-17.122852], [0.90160024,-0.017608464,-0.046611745]),
+    0xC71585u32 => ([44.764477,70.992195,-15.173358], [0.55336696,0.21807168,-0.03968207]),
+    0xC72C48u32 => ([44.820133,60.843853,22.651234], [0.5481551,0.18155994,0.054443844]),
+    0xC7C7C7u32 => ([80.24282,0.0,0.0], [0.8296795,6.7164774e-11,3.0925396e-08]),
+    0xC80815u32 => ([42.01455,66.14846,48.53599], [0.5258339,0.18784505,0.09718028]),
+    0xC84186u32 => ([49.231953,59.306236,-9.014826], [0.58639574,0.18116602,-0.023143014]),
+    0xC8509Bu32 => ([52.269726,55.74306,-16.937164], [0.6112648,0.16741587,-0.044911876]),
+    0xC88A65u32 => ([62.841564,19.420298,29.279224], [0.68693006,0.057047997,0.071690366]),
+    0xC8A2C8u32 => ([71.07097,20.539923,-14.129262], [0.75855666,0.05664183,-0.03788465]),
+    0xC8AD7Fu32 => ([72.027245,3.1936026,27.270857], [0.7600997,0.011206419,0.06803199]),
+    0xC95A49u32 => ([52.07694,42.96197,31.301332], [0.603224,0.124929294,0.074023925]),
+    0xC9A0DCu32 => ([71.36932,25.88782,-24.674316], [0.7633212,0.066960715,-0.06720673]),
+    0xC9C0BBu32 => ([78.28934,2.2703786,3.6392307], [0.8137107,0.0077162283,0.00962583]),
+    0xC9C9C9u32 => ([80.96501,5.551115e-14,0.0], [0.83590525,6.7668926e-11,3.1157455e-08]),
+    0xCA1F7Bu32 => ([45.591297,68.87495,-7.7394404], [0.5591887,0.21219687,-0.019550174]),
+    0xCAE00Du32 => ([84.943794,-28.577993,82.597946], [0.86048573,-0.088260636,0.17524326]),
+    0xCAE1FFu32 => ([88.7455,-1.401664,-17.090551], [0.9022968,-0.012352899,-0.046478692]),
+    0xCAFF70u32 => ([93.988785,-38.046032,61.765076], [0.93511015,-0.107393235,0.14438587]),
+    0xCB410Bu32 => ([47.62816,52.707542,56.05007], [0.5685533,0.1449517,0.11148068]),
+    0xCB4154u32 => ([48.409996,55.533012,20.190958], [0.5769047,0.16625935,0.049606152]),
+    0xCB6D51u32 => ([56.317,34.602943,32.115143], [0.63647336,0.100233085,0.07662549]),
+    0xCBA135u32 => ([68.34693,4.8279934,59.004734], [0.7289524,0.0076393806,0.13033117]),
+    0xCC3333u32 => ([46.23946,59.118706,37.11398], [0.5593959,0.17093518,0.082863495]),
+    0xCC3336u32 => ([46.276134,59.23975,35.433193], [0.5597858,0.17195657,0.07990361]),
+    0xCC33CCu32 => ([51.343437,74.75496,-46.892605], [0.6131073,0.20863299,-0.13000724]),
+    0xCC474Bu32 => ([49.31848,52.821148,26.792318], [0.58349097,0.15591414,0.06402495]),
+    0xCC5500u32 => ([51.07852,44.154354,60.773228], [0.5948405,0.118258566,0.12027619]),
+    0xCC7722u32 => ([58.17753,27.331236,56.82304], [0.64956224,0.071006596,0.12109882]),
+    0xCC8899u32 => ([63.955475,28.348568,1.6727797], [0.7002119,0.08560339,0.004783719]),
+    0xCCA01Du32 => ([68.07139,4.973856,66.68986], [0.7266282,0.0056879483,0.14179838]),
+    0xCCCCCCu32 => ([82.045784,5.551115e-14,0.0], [0.84522223,6.8423045e-11,3.1504737e-08]),
+    0xCCCCFFu32 => ([83.56996,10.298546,-24.910103], [0.8621799,0.018030245,-0.06811222]),
+    0xCCFF00u32 => ([93.605156,-41.949596,90.28058], [0.9307076,-0.1247976,0.19151096]),
+    0xCD0000u32 => ([42.735382,67.94222,57.01002], [0.53270847,0.19075634,0.10675821]),
+    0xCD00CDu32 => ([48.74627,83.337006,-51.602516], [0.59524554,0.23292069,-0.14349884]),
+    0xCD1076u32 => ([45.164883,71.534065,-5.2329674], [0.5565759,0.22079502,-0.0128544485]),
+    0xCD2626u32 => ([44.95228,62.76069,43.03216], [0.5497453,0.17963804,0.0917285]),
+    0xCD2990u32 => ([47.72735,69.49856,-17.230146], [0.57822925,0.2122898,-0.045384336]),
+    0xCD3278u32 => ([47.77576,64.425285,-2.6005821], [0.57583094,0.19795083,-0.0060192803]),
+    0xCD3333u32 => ([46.42933,59.421177,37.37734], [0.5611505,0.17176974,0.08337059]),
+    0xCD3700u32 => ([46.596706,57.0047,58.581726], [0.56139565,0.15708405,0.11300612]),
+    0xCD4F39u32 => ([50.533134,48.704407,38.605053], [0.59212214,0.13936974,0.08738763]),
+    0xCD5555u32 => ([51.988422,47.623077,24.369349], [0.6044245,0.14115185,0.05932767]),
+    0xCD5700u32 => ([51.60761,43.58057,61.153057], [0.59917116,0.11648875,0.121180944]),
+    0xCD5B45u32 => ([52.832176,43.710663,34.623325], [0.6099874,0.12611261,0.08073549]),
+    0xCD5C5Cu32 => ([53.39327,44.825134,22.11487], [0.61544085,0.1334389,0.05453257]),
+    0xCD607Eu32 => ([54.920692,46.17198,4.20849], [0.6294865,0.14044964,0.011458373]),
+    0xCD6090u32 => ([55.441532,48.360695,-5.6198096], [0.6351045,0.14665842,-0.0142133]),
+    0xCD6600u32 => ([54.53665,36.122147,62.840954], [0.62150526,0.0942904,0.1259902]),
+    0xCD661Du32 => ([54.629284,36.560932,55.673702], [0.6225055,0.097825624,0.116935566]),
+    0xCD6839u32 => ([55.25428,36.543926,43.823017], [0.6279623,0.10192827,0.098654106]),
+    0xCD6889u32 => ([56.76982,43.492348,0.43357083], [0.6443997,0.13207494,0.0017032273]),
+    0xCD69C9u32 => ([59.251743,52.721615,-32.72585], [0.6704608,0.147781,-0.089537114]),
+    0xCD7054u32 => ([57.27886,34.034092,31.705614], [0.64454734,0.09877471,0.075936854]),
+    0xCD7F32u32 => ([60.23933,24.01181,52.33042], [0.6661058,0.06317778,0.11536712]),
+    0xCD8162u32 => ([61.275402,26.085655,29.022354], [0.6759614,0.076468565,0.07086481]),
+    0xCD8500u32 => ([61.44298,19.606207,67.17507], [0.67480046,0.0460279,0.13740693]),
+    0xCD853Fu32 => ([61.753048,21.390818,47.91863], [0.67819256,0.057283256,0.10856277]),
+    0xCD8C95u32 => ([64.888336,25.971508,5.3007617], [0.70727897,0.078754276,0.0141663635]),
+    0xCD919Eu32 => ([66.2899,24.539814,2.2542934], [0.7188266,0.074101746,0.006261731]),
+    0xCD950Cu32 => ([65.3591,11.044661,68.12903], [0.7054299,0.021949882,0.14178589]),
+    0xCD96CDu32 => ([68.8963,29.73871,-20.13564], [0.74356294,0.08217101,-0.054364786]),
+    0xCD9B1Du32 => ([66.909195,8.107645,65.78178], [0.71773446,0.0145856915,0.13970761]),
+    0xCD9B9Bu32 => ([68.5985,18.632444,7.261734], [0.73639446,0.056879513,0.019155286]),
+    0xCDAA7Du32 => ([71.69742,6.5799656,27.988369], [0.75850093,0.020813059,0.06963525]),
+    0xCDAD00u32 => ([71.46484,-1.9355947,74.024185], [0.7534343,-0.015193077,0.15413137]),
+    0xCDAF95u32 => ([73.42196,6.926119,17.367622], [0.7735085,0.022764863,0.044489972]),
+    0xCDB38Bu32 => ([74.24358,3.457378,23.896065], [0.77930444,0.012437764,0.060252823]),
+    0xCDB5CDu32 => ([76.33557,12.815471,-8.94683], [0.8008813,0.035278972,-0.023826094]),
+    0xCDB79Eu32 => ([75.6471,3.916061,15.593471], [0.7915711,0.014045617,0.0401459]),
+    0xCDB7B5u32 => ([76.18136,7.499454,3.880031], [0.7975154,0.02326429,0.010318194]),
+    0xCDBA96u32 => ([76.25277,1.1922857,20.740475], [0.79578996,0.006169395,0.052771825]),
+    0xCDBE70u32 => ([76.664795,-5.397276,41.150856], [0.79698926,-0.015161156,0.0992163]),
+    0xCDC0B0u32 => ([78.3528,1.8822944,9.704471], [0.81413007,0.0076777157,0.025317756]),
+    0xCDC1C5u32 => ([79.127914,4.906167,-0.4958699], [0.8219236,0.014452491,-0.0012409706]),
+    0xCDC5BFu32 => ([79.99558,1.7313372,3.9957075], [0.8282182,0.0062085157,0.010553264]),
+    0xCDC673u32 => ([78.81354,-9.226916,42.32032], [0.81415284,-0.025918249,0.10202853]),
+    0xCDC8B1u32 => ([80.43889,-2.1696184,12.133304], [0.830616,-0.003827372,0.03148308]),
+    0xCDC9A5u32 => ([80.44283,-4.5375824,18.531868], [0.82980406,-0.010213281,0.047467917]),
+    0xCDC9C9u32 => ([81.27487,1.3807025,0.48951373], [0.8390999,0.0042482666,0.0013187922]),
+    0xCDCD00u32 => ([79.97795,-18.289803,80.15274], [0.82116127,-0.060543977,0.16845113]),
+    0xCDCDB4u32 => ([81.80403,-4.2954216,12.468022], [0.841601,-0.009947961,0.032326695]),
+    0xCDCDC1u32 => ([82.104935,-2.1284273,5.9704747], [0.844966,-0.004711405,0.015659759]),
+    0xCE2029u32 => ([44.684547,64.53645,41.205524], [0.5482022,0.18572602,0.08868106]),
+    0xCE4676u32 => ([50.466774,57.357582,2.5631938], [0.5958121,0.17536923,0.007286676]),
+    0xCEFF00u32 => ([93.72823,-41.178837,90.4278], [0.93202084,-0.12280894,0.19175865]),
+    0xCF1020u32 => ([43.861607,67.2894,45.320824], [0.5422226,0.19254248,0.09413338]),
+    0xCF3476u32 => ([48.299793,64.144455,-0.5695671], [0.5801425,0.19695488,-0.0007371235]),
+    0xCF6BA9u32 => ([58.64618,47.076477,-15.326433], [0.6623933,0.13984187,-0.04062725]),
+    0xCF71AFu32 => ([60.04416,44.953045,-16.656084], [0.6735677,0.13246967,-0.04435135]),
+    0xCFB53Bu32 => ([73.95239,-3.7560763,62.314102], [0.77422416,-0.016499305,0.13833281]),
+    0xCFCFCFu32 => ([83.12358,0.0,0.0], [0.85451365,6.917539e-11,3.1851062e-08]),
+    0xD02090u32 => ([47.570675,72.23075,-17.419933], [0.57809794,0.22113267,-0.04583584]),
+    0xD0417Eu32 => ([50.333153,60.70966,-2.4136226], [0.5962308,0.18602851,-0.005565246]),
+    0xD0F0C0u32 => ([91.47589,-18.968176,19.8036], [0.91969395,-0.051175892,0.050747965]),
+    0xD0FF14u32 => ([93.87438,-40.203903,88.97429], [0.93359166,-0.119870834,0.1897967]),
+    0xD10047u32 => ([44.25111,70.72266,22.928997], [0.5474139,0.21187654,0.05464594]),
+    0xD10056u32 => ([44.560436,71.55272,13.951272], [0.55067515,0.2175604,0.034856066]),
+    0xD15FEEu32 => ([59.811184,65.27147,-52.610664], [0.6814634,0.16894074,-0.14683022]),
+    0xD1D1D1u32 => ([83.84049,0.0,0.0], [0.8606939,6.967582e-11,3.2081424e-08]),
+    0xD1E231u32 => ([86.09131,-25.820028,76.999275], [0.87127584,-0.07927928,0.16766147]),
+    0xD1EEEEu32 => ([92.076744,-9.424251,-3.1986363], [0.92813194,-0.029086463,-0.008693361]),
+    0xD2691Eu32 => ([55.98812,37.047977,56.74133], [0.6343984,0.09907391,0.11919316]),
+    0xD2B48Cu32 => ([74.9751,5.018318,24.428057], [0.78618664,0.016927637,0.061535213]),
+    0xD3AF37u32 => ([72.75065,0.9419807,62.788006], [0.7655302,-0.0037478162,0.13842352]),
+    0xD3D3D3u32 => ([84.556114,5.551115e-14,0.0], [0.8668631,7.01752e-11,3.2311373e-08]),
+    0xD40000u32 => ([44.232384,69.673874,58.463047], [0.5462857,0.19561817,0.109479174]),
+    0xD44500u32 => ([49.804756,54.042774,60.848675], [0.58779675,0.14764543,0.11850935]),
+    0xD470A2u32 => ([60.065228,45.152695,-9.093367], [0.6736623,0.1356534,-0.023611246]),
+    0xD473D4u32 => ([62.519035,51.727478,-33.783722], [0.698138,0.14361249,-0.09250931]),
+    0xD4AF37u32 => ([72.848434,1.375333,62.916096], [0.7665278,-0.0025638866,0.13865352]),
+    0xD4D4D4u32 => ([84.91345,5.551115e-14,0.0], [0.86994356,7.042433e-11,3.2426197e-08]),
+    0xD65282u32 => ([53.968197,56.03402,0.6339801], [0.625444,0.17114109,0.002332848]),
+    0xD68A59u32 => ([64.3194,24.138813,37.965958], [0.7014031,0.068677716,0.09016683]),
+    0xD6CADDu32 => ([82.78449,7.606119,-7.90827], [0.85445416,0.019898443,-0.021093613]),
+    0xD6D6D6u32 => ([85.627174,5.551115e-14,-2.220446e-14], [0.8760963,7.0922546e-11,3.2655535e-08]),
+    0xD70040u32 => ([45.38646,71.81454,28.954315], [0.55749923,0.21295086,0.0669858]),
+    0xD70751u32 => ([45.90107,72.04105,19.09704], [0.5622573,0.21738389,0.046571]),
+    0xD74894u32 => ([53.19176,62.469,-11.175464], [0.6218636,0.19049318,-0.028923895]),
+    0xD7837Fu32 => ([63.519157,31.84758,15.975879], [0.69765884,0.096113764,0.040836107]),
+    0xD891EFu32 => ([70.23393,42.47495,-36.716644], [0.76056707,0.110637166,-0.10104263]),
+    0xD8B2D1u32 => ([76.705635,18.939018,-10.465366], [0.8064714,0.053379804,-0.027835254]),
+    0xD8BFD8u32 => ([80.07764,13.21773,-9.229448], [0.8332929,0.036385518,-0.024576474]),
+    0xD9004Cu32 => ([46.01015,72.84233,22.422869], [0.5634484,0.21867189,0.053760085]),
+    0xD92121u32 => ([46.911293,67.106186,48.279587], [0.5683348,0.1910856,0.10008968]),
+    0xD9381Eu32 => ([49.101204,60.81766,51.52895], [0.5845523,0.17098334,0.10631744]),
+    0xD982B5u32 => ([65.006065,40.352383,-12.458219], [0.7142962,0.11934733,-0.032862008]),
+    0xD98695u32 => ([64.94158,33.649693,5.5602994], [0.7107582,0.10201471,0.014892337]),
+    0xD99058u32 => ([66.06244,22.064543,40.68934], [0.71563435,0.06206681,0.0960094]),
+    0xD99A6Cu32 => ([68.713005,18.32782,33.43268], [0.73712116,0.053461716,0.081375346]),
+    0xD9D9D9u32 => ([86.69542,0.0,2.220446e-14], [0.8853053,7.166784e-11,3.2998788e-08]),
+    0xD9E650u32 => ([87.93233,-23.012955,68.63171], [0.8880557,-0.06925072,0.1548013]),
+    0xDA1884u32 => ([48.545,74.63986,-8.49934], [0.587096,0.23022005,-0.021486588]),
+    0xDA2C43u32 => ([48.40417,65.9278,30.962461], [0.58093977,0.19426128,0.07180953]),
+    0xDA3287u32 => ([50.614998,69.28067,-7.221409], [0.60249317,0.21295787,-0.018159619]),
+    0xDA70D6u32 => ([62.80242,55.28335,-34.406746], [0.7021318,0.15488695,-0.094151884]),
+    0xDA8A67u32 => ([65.01294,26.898705,31.408823], [0.7084761,0.07860492,0.07643827]),
+    0xDA9100u32 => ([65.87155,18.772629,70.854645], [0.7126682,0.043130405,0.14517687]),
+    0xDAA520u32 => ([70.81678,8.518486,68.764626], [0.7515723,0.015389061,0.14612558]),
+    0xDB2D43u32 => ([48.69149,65.939156,31.356285], [0.583409,0.19416402,0.072637744]),
+    0xDB7093u32 => ([60.56659,45.517036,0.39978197], [0.67792284,0.1382069,0.001642491]),
+    0xDBD7D2u32 => ([86.16333,0.48347542,2.9446099], [0.8809152,0.002270903,0.0077844053]),
+    0xDBDBDBu32 => ([87.40604,5.551115e-14,0.0], [0.89143133,7.216411e-11,3.322713e-08]),
+    0xDC143Cu32 => ([47.033386,70.919205,33.596107], [0.5711893,0.20843765,0.07622524]),
+    0xDCDCDCu32 => ([87.76089,5.551115e-14,0.0], [0.8944904,7.2411466e-11,3.3341152e-08]),
+    0xDDA0DDu32 => ([73.3735,32.531353,-21.986998], [0.78328353,0.08990804,-0.05940838]),
+    0xDE3163u32 => ([50.251163,67.531494,14.165287], [0.59789073,0.2049457,0.035737816]),
+    0xDE5285u32 => ([55.339188,58.81347,1.0101047], [0.6383903,0.17973304,0.003331822]),
+    0xDE5D83u32 => ([57.07476,53.799294,4.700745], [0.65114945,0.16391937,0.012765149]),
+    0xDE6FA1u32 => ([61.19749,48.816307,-6.6870136], [0.684866,0.14759658,-0.017059375]),
+    0xDEA5A4u32 => ([72.97188,20.877024,8.7703705], [0.7749498,0.0637269,0.023047427]),
+    0xDEAA88u32 => ([73.58317,14.625205,24.893675], [0.7777602,0.0446473,0.06251762]),
+    0xDEB887u32 => ([77.0176,7.0464034,30.018799], [0.8045399,0.022220576,0.07461971]),
+    0xDECC9Cu32 => ([82.44124,-0.90971565,26.24646], [0.848379,0.00010763084,0.06616671]),
+    0xDEDEDEu32 => ([88.46968,0.0,0.0], [0.90060073,7.290624e-11,3.356891e-08]),
+    0xDF00FFu32 => ([54.979603,93.54723,-69.70556], [0.6544557,0.24305098,-0.19600451]),
+    0xDF73FFu32 => ([65.88159,62.73162,-52.255703], [0.7323573,0.16144742,-0.14556235]),
+    0xE0115Fu32 => ([48.35718,74.04148,14.062856], [0.58436847,0.22521165,0.035304293]),
+    0xE066FFu32 => ([63.81265,68.91604,-55.531006], [0.71752864,0.17836118,-0.15499185]),
+    0xE0B0FFu32 => ([78.53092,31.4987,-32.483765], [0.8273472,0.079052724,-0.08904227]),
+    0xE0E0E0u32 => ([89.17728,0.0,0.0], [0.9067007,7.340012e-11,3.3796276e-08]),
+    0xE0EEE0u32 => ([92.74136,-7.1534166,5.1775093], [0.9347844,-0.01961765,0.013542785]),
+    0xE0EEEEu32 => ([93.08837,-4.6144657,-1.5963889], [0.9386699,-0.01422337,-0.0043222858]),
+    0xE0FFFFu32 => ([97.86775,-9.943113,-3.3745673], [0.9778582,-0.030687958,-0.009171604]),
+    0xE12C2Cu32 => ([49.48835,67.0242,45.60983], [0.5904966,0.19200198,0.09763226]),
+    0xE1CA7Au32 => ([81.65014,-2.5303245,42.729126], [0.8409967,-0.006969507,0.10316134]),
+    0xE2062Cu32 => ([47.602917,73.26234,43.81679], [0.5769067,0.21161732,0.09355546]),
+    0xE23D28u32 => ([51.453167,62.143852,49.57813], [0.60535246,0.17588018,0.104889214]),
+    0xE25822u32 => ([55.345623,51.34249,55.68675], [0.63446414,0.14165314,0.116478436]),
+    0xE2725Bu32 => ([60.747253,41.590984,32.7983], [0.67738074,0.121221475,0.07853273]),
+    0xE29CD2u32 => ([72.6279,34.345776,-17.03292], [0.77755064,0.09834497,-0.045560323]),
+    0xE30B5Du32 => ([48.71476,75.203545,15.882711], [0.5878832,0.22826861,0.039510995]),
+    0xE3256Bu32 => ([50.3561,72.34232,9.580984], [0.60096836,0.22103779,0.02475591]),
+    0xE32636u32 => ([49.47957,69.31413,40.234585], [0.59144425,0.2010608,0.08883291]),
+    0xE34234u32 => ([52.37346,61.07774,44.416813], [0.61289644,0.17466073,0.097392306]),
+    0xE3A857u32 => ([72.909,13.279685,49.521667], [0.7713706,0.035076894,0.11487248]),
+    0xE3AB57u32 => ([73.64048,11.682802,50.359924], [0.7770901,0.03037559,0.11668011]),
+    0xE3DAC9u32 => ([87.33471,0.22278936,9.462364], [0.8909418,0.0028541442,0.02472366]),
+    0xE3E3E3u32 => ([90.23645,5.551115e-14,0.0], [0.91583145,7.4139195e-11,3.413662e-08]),
+    0xE3F988u32 => ([94.25232,-23.699543,51.575253], [0.94219154,-0.06647505,0.12378842]),
+    0xE40078u32 => ([49.317238,78.15014,0.17436944], [0.5948825,0.24100037,0.0012714873]),
+    0xE4007Cu32 => ([49.437943,78.467896,-2.0912633], [0.59615153,0.2422193,-0.00456126]),
+    0xE4717Au32 => ([61.404697,45.41194,16.163837], [0.6847989,0.13687181,0.04115753]),
+    0xE48400u32 => ([64.15995,29.94298,70.36819], [0.70207286,0.075132914,0.14266525]),
+    0xE49B0Fu32 => ([69.45041,17.887474,71.81957], [0.7431975,0.04078475,0.14892617]),
+    0xE4D00Au32 => ([82.71557,-9.842377,82.10606], [0.8476691,-0.0378065,0.17242062]),
+    0xE4D96Fu32 => ([85.63916,-9.955793,52.97644], [0.8727635,-0.029674098,0.12492052]),
+    0xE58E73u32 => ([67.45961,30.1884,28.286543], [0.73085624,0.08924476,0.06974092]),
+    0xE5AA70u32 => ([73.91966,14.994502,38.205322], [0.78075063,0.043206107,0.09228042]),
+    0xE5E4E2u32 => ([90.61347,-0.018434456,1.0917485], [0.9190806,0.0002754717,0.0028923708]),
+    0xE5E5E5u32 => ([90.9411,0.0,0.0], [0.921906,7.463086e-11,3.436304e-08]),
+    0xE60026u32 => ([48.217175,74.562065,47.916973], [0.5826792,0.2140876,0.09982712]),
+    0xE63E62u32 => ([53.11646,65.713394,18.921345], [0.6216562,0.19801016,0.04693353]),
+    0xE68FACu32 => ([69.07482,36.658287,-1.1916348], [0.7476649,0.110525824,-0.002628669]),
+    0xE6BE8Au32 => ([79.32048,7.384226,31.625587], [0.8245174,0.023109153,0.0784332]),
+    0xE6E6FAu32 => ([91.82764,3.7088158,-9.661404], [0.9309023,0.0073649315,-0.025915211]),
+    0xE79FC4u32 => ([73.39756,32.117115,-8.118479], [0.78318053,0.09487713,-0.021202574]),
+    0xE86100u32 => ([57.75881,48.966625,66.87065], [0.6542429,0.13125299,0.13227418]),
+    0xE8CCD7u32 => ([84.611664,11.616941,-1.8441206], [0.8717591,0.034152664,-0.0047209333]),
+    0xE8E8E8u32 => ([91.9959,5.551115e-14,-2.220446e-14], [0.93099916,7.5367046e-11,3.4701976e-08]),
+    0xE8F48Cu32 => ([93.34213,-18.993156,48.62583], [0.9359678,-0.053039894,0.117406525]),
+    0xE936A7u32 => ([54.81883,75.274574,-19.615864], [0.64173555,0.22941428,-0.051787503]),
+    0xE97451u32 => ([62.007294,42.576847,40.023335], [0.688548,0.12193472,0.09325795]),
+    0xE9967Au32 => ([69.85493,28.170292,27.710531], [0.75073624,0.08360267,0.0686568]),
+    0xE9D66Bu32 => ([85.240295,-6.740607,54.480682], [0.87046516,-0.021160008,0.12771575]),
+    0xE9FFDBu32 => ([97.61491,-13.446175,14.809207], [0.9745583,-0.03594397,0.03831461]),
+    0xEAA221u32 => ([71.855934,17.050972,70.07433], [0.7636338,0.03925725,0.14814325]),
+    0xEBEBEBu32 => ([93.048134,5.551115e-14,0.0], [0.9400701,7.6101125e-11,3.5040088e-08]),
+    0xEC5800u32 => ([56.894997,54.40278,66.76], [0.648952,0.1473968,0.13101345]),
+    0xECB176u32 => ([76.4281,14.650615,38.416454], [0.8022507,0.042420834,0.093048565]),
+    0xECEBBDu32 => ([92.09783,-7.035853,22.514563], [0.92938066,-0.017003464,0.057478182]),
+    0xED1C24u32 => ([50.647007,73.34413,51.611034], [0.60301495,0.20914295,0.1065032]),
+    0xED2939u32 => ([51.711494,71.42514,41.538097], [0.61150044,0.20717202,0.09175999]),
+    0xED7A9Bu32 => ([65.19415,47.64355,2.848151], [0.7185917,0.1447325,0.008025611]),
+    0xED872Du32 => ([66.15496,32.8553,61.458378], [0.720404,0.086626016,0.13183147]),
+    0xED9121u32 => ([68.29063,27.310352,67.33874], [0.7367027,0.06897664,0.14170894]),
+    0xEDC9AFu32 => ([83.37609,8.86581,17.722807], [0.860051,0.028716203,0.045561172]),
+    0xEDEDEDu32 => ([93.748215,0.0,0.0], [0.9461053,7.659001e-11,3.5265042e-08]),
+    0xEE0000u32 => ([49.71428,76.01507,63.783913], [0.59600455,0.21342187,0.11944315]),
+    0xEE00EEu32 => ([56.439373,93.23905,-57.73389], [0.6659722,0.26059616,-0.16054927]),
+    0xEE1289u32 => ([52.307697,80.236176,-5.5039663], [0.6217096,0.24768505,-0.0134547185]),
+    0xEE204Du32 => ([51.552483,74.287766,29.74622], [0.6115422,0.2205732,0.06972633]),
+    0xEE2C2Cu32 => ([52.02167,70.614586,48.92765], [0.61373323,0.20188539,0.103681125]),
+    0xEE30A7u32 => ([55.158768,77.98184,-19.017813], [0.64581424,0.23833886,-0.050052892]),
+    0xEE3A8Cu32 => ([55.17489,72.55017,-3.0580156], [0.64292175,0.22297367,-0.007118094]),
+    0xEE3B3Bu32 => ([53.62577,66.976295,42.566933], [0.626114,0.19337378,0.094459645]),
+    0xEE4000u32 => ([53.864407,64.24843,65.464165], [0.62682915,0.17718615,0.12615736]),
+    0xEE5C42u32 => ([58.25259,54.858395,43.888855], [0.6610147,0.15675204,0.0989371]),
+    0xEE6363u32 => ([59.892826,53.63295,27.563345], [0.67487997,0.15888228,0.06699892]),
+    0xEE6A50u32 => ([60.840908,49.207672,39.354366], [0.68111503,0.14176923,0.09147514]),
+    0xEE6AA7u32 => ([62.741463,57.06968,-7.590261], [0.7016037,0.17325602,-0.019340584]),
+    0xEE7600u32 => ([62.62293,41.15216,70.13286], [0.693096,0.10767896,0.14047521]),
+    0xEE7621u32 => ([62.715233,41.585598,62.890953], [0.6940921,0.111212455,0.13142805]),
+    0xEE7942u32 => ([63.547268,41.198853,49.680775], [0.7011981,0.11471035,0.11143313]),
+    0xEE799Fu32 => ([65.2454,48.95364,0.6648277], [0.719611,0.14869082,0.0023766248]),
+    0xEE7AE9u32 => ([68.00698,59.36422,-36.68461], [0.74869144,0.16653825,-0.10036906]),
+    0xEE8262u32 => ([65.769516,38.52752,35.61849], [0.7194409,0.111774914,0.08521936]),
+    0xEE82EEu32 => ([69.69502,56.357788,-36.812153], [0.7618985,0.15646526,-0.10079778]),
+    0xEE9572u32 => ([70.09823,29.924547,32.480133], [0.7534667,0.087692365,0.079255685]),
+    0xEE9A00u32 => ([70.37909,22.544289,74.98287], [0.7529151,0.053253636,0.15329263]),
+    0xEE9A49u32 => ([70.7132,24.456476,54.057682], [0.7565688,0.065416105,0.12213488]),
+    0xEEA2ADu32 => ([74.21331,29.620523,5.827988], [0.7890729,0.08980463,0.015589232]),
+    0xEEA9B8u32 => ([76.078766,27.446077,2.4939728], [0.80433047,0.08287363,0.006932123]),
+    0xEEAD0Eu32 => ([74.8867,12.631723,76.39178], [0.788146,0.025265712,0.15874428]),
+    0xEEAEEEu32 => ([78.82399,33.65101,-22.770866], [0.83069426,0.09298825,-0.06149542]),
+    0xEEB422u32 => ([76.64086,9.281378,73.87592], [0.8020601,0.016810458,0.15661752]),
+    0xEEB4B4u32 => ([78.52962,21.02836,8.204241], [0.82292056,0.064187296,0.021635972]),
+    0xEEC591u32 => ([81.922554,7.636402,31.548574], [0.8470451,0.024028432,0.0784312]),
+    0xEEC900u32 => ([81.81206,-2.0740845,82.7877], [0.8425985,-0.016742054,0.17236921]),
+    0xEECBADu32 => ([83.91747,7.9247003,19.548553], [0.86436486,0.025975686,0.05006125]),
+    0xEECFA1u32 => ([84.67768,4.2993646,26.895266], [0.8695717,0.015130016,0.06777667]),
+    0xEED202u32 => ([84.109276,-6.637163,84.23912], [0.86080337,-0.029440835,0.1759889]),
+    0xEED2EEu32 => ([87.21419,14.54041,-10.1476965], [0.8953218,0.04002898,-0.02702832]),
+    0xEED5B7u32 => ([86.59579,4.0889,18.011663], [0.8860274,0.014879805,0.046317797]),
+    0xEED5D2u32 => ([87.202225,8.164274,4.6422286], [0.8927757,0.025417171,0.012327614]),
+    0xEED8AEu32 => ([87.16615,1.3098083,23.51054], [0.8899226,0.0068216873,0.0597799]),
+    0xEED9C4u32 => ([87.86698,3.9053974,12.971857], [0.89691275,0.014077313,0.033708297]),
+    0xEEDC82u32 => ([87.47343,-5.7592244,46.27327], [0.89004815,-0.016247185,0.11146374]),
+    0xEEDD82u32 => ([87.728645,-6.2646832,46.59034], [0.8920685,-0.017707465,0.11217303]),
+    0xEEDFCCu32 => ([89.53926,2.0291626,11.152838], [0.9106255,0.0084033925,0.02907968]),
+    0xEEE0E5u32 => ([90.38877,5.6241937,-0.7241196], [0.91927123,0.01652253,-0.001836036]),
+    0xEEE5DEu32 => ([91.45555,1.8498452,4.491269], [0.927058,0.0066934703,0.011860541]),
+    0xEEE600u32 => ([89.28591,-16.590023,88.14754], [0.9019865,-0.05707731,0.18493222]),
+    0xEEE685u32 => ([90.07475,-10.490599,48.041134], [0.9107944,-0.029606732,0.11560869]),
+    0xEEE8AAu32 => ([91.14062,-7.3519564,30.97192], [0.9210476,-0.018201202,0.07770318]),
+    0xEEE8CDu32 => ([91.81862,-2.4228323,13.868838], [0.9286304,-0.00423662,0.035967942]),
+    0xEEE9BFu32 => ([91.78211,-5.015931,21.069616], [0.9273899,-0.01124498,0.053932402]),
+    0xEEE9E9u32 => ([92.723976,1.6791196,0.5956412], [0.9379122,0.0051662507,0.0016045223]),
+    0xEEED09u32 => ([91.12827,-19.909967,88.80336], [0.9167306,-0.06603685,0.18711463]),
+    0xEEEE00u32 => ([91.38199,-20.462984,89.67643], [0.91873103,-0.067737766,0.18846639]),
+    0xEEEED1u32 => ([93.42001,-4.8422995,14.063029], [0.9415432,-0.011222289,0.036455274]),
+    0xEEEEE0u32 => ([93.757195,-2.4134798,6.772998], [0.945314,-0.0053455317,0.017762167]),
+    0xEF98AAu32 => ([72.03527,34.85596,4.358206], [0.7723809,0.1055729,0.011838402]),
+    0xEFBBCCu32 => ([80.87888,21.2661,-1.34707], [0.8433153,0.063412085,-0.0032532096]),
+    0xEFCC00u32 => ([82.66708,-3.177861,83.43544], [0.8495819,-0.019866327,0.17382948]),
+    0xEFDFBBu32 => ([89.25914,-0.13191439,19.64739], [0.9074287,0.002742358,0.05039456]),
+    0xF0599Cu32 => ([59.95477,63.7658,-5.3503275], [0.6803698,0.19479689,-0.013262354]),
+    0xF08080u32 => ([66.155136,42.806458,19.554623], [0.72464097,0.12856539,0.049425825]),
+    0xF0E68Cu32 => ([90.32763,-9.013776,44.980377], [0.91348904,-0.024851901,0.10912827]),
+    0xF0EAD6u32 => ([92.67462,-1.3227699,10.431808], [0.9364041,-0.0014792025,0.027220368]),
+    0xF0F0F0u32 => ([94.79625,0.0,0.0], [0.95514005,7.732098e-11,3.56018e-08]),
+    0xF0F8FFu32 => ([97.17878,-1.347921,-4.262767], [0.97514284,-0.005500548,-0.011404169]),
+    0xF0FFF0u32 => ([98.56564,-7.564964,5.475645], [0.9848418,-0.020746188,0.014322255]),
+    0xF0FFFFu32 => ([98.93259,-4.879724,-1.6880422], [0.98895067,-0.015041059,-0.0045705023]),
+    0xF19CBBu32 => ([73.589355,35.811745,-2.7502627], [0.7862447,0.107568346,-0.006762033]),
+    0xF1B42Fu32 => ([77.011284,10.915284,70.6113], [0.80584687,0.022451883,0.1520538]),
+    0xF1DDCFu32 => ([89.35521,4.5958023,9.351032], [0.9099956,0.015688717,0.024486162]),
+    0xF1E9D2u32 => ([92.41225,-1.0940124,12.146295], [0.9342337,-0.0005681052,0.031605452]),
+    0xF2003Cu32 => ([50.928417,78.01734,39.243015], [0.6075652,0.2282293,0.087175235]),
+    0xF28500u32 => ([66.2555,35.162636,72.61429], [0.7221033,0.08984127,0.14659877]),
+    0xF2BA49u32 => ([78.73962,9.249765,62.641647], [0.8201524,0.02046482,0.14024878]),
+    0xF2BDCDu32 => ([81.664825,21.421099,-0.70077515], [0.85014665,0.06402474,-0.0015391957]),
+    0xF2C649u32 => ([81.70107,3.0062678,65.66994], [0.8434354,0.0024134587,0.1466686]),
+    0xF2E8D7u32 => ([92.35248,0.55693424,9.472102], [0.93432355,0.0038684292,0.024771003]),
+    0xF2F2F2u32 => ([95.49356,5.551115e-14,0.0], [0.96115136,7.780798e-11,3.5825867e-08]),
+    0xF37A48u32 => ([64.519196,42.89429,47.963802], [0.71024734,0.12044315,0.10866842]),
+    0xF38FA9u32 => ([70.571014,40.823757,2.855729], [0.76216114,0.12369014,0.008001647]),
+    0xF3E5ABu32 => ([90.795555,-3.8361902,30.065943], [0.9193431,-0.008144359,0.07554775]),
+    0xF400A1u32 => ([53.82436,85.060265,-17.419054], [0.63741416,0.2618854,-0.045464214]),
+    0xF4A460u32 => ([73.953,23.021935,46.791096], [0.78399736,0.06401243,0.10958705]),
+    0xF4C2C2u32 => ([82.760635,17.820986,6.814756], [0.8581668,0.05449537,0.01805668]),
+    0xF4C430u32 => ([81.252075,3.884004,74.146614], [0.83987963,0.002247191,0.15978712]),
+    0xF4CA16u32 => ([82.66485,0.20999475,81.25305], [0.85075617,-0.009873688,0.17078537]),
+    0xF4F0ECu32 => ([94.99629,0.64227605,2.4010005], [0.9571188,0.002602273,0.0063585676]),
+    0xF56FA1u32 => ([64.44923,56.250614,-1.5215969], [0.71580106,0.17124389,-0.0032591792]),
+    0xF58025u32 => ([65.73875,39.410957,64.420746], [0.71930474,0.10471536,0.13548344]),
+    0xF5BD1Fu32 => ([79.561745,7.512434,77.21903], [0.82660586,0.011252065,0.16305774]),
+    0xF5DEB3u32 => ([89.35111,1.5087593,24.007895], [0.9088334,0.0074598812,0.061044525]),
+    0xF5E050u32 => ([88.59257,-8.606917,70.01747], [0.89873695,-0.030196218,0.15694562]),
+    0xF5F5DCu32 => ([95.948944,-4.1939793,12.049176], [0.9635736,-0.009584834,0.031351846]),
+    0xF5F5F5u32 => ([96.53749,0.0,2.220446e-14], [0.97015077,7.8536455e-11,3.616131e-08]),
+    0xF5FFFAu32 => ([99.15648,-4.162721,1.2465645], [0.991172,-0.011939205,0.003240988]),
+    0xF653A6u32 => ([60.36952,68.941315,-10.425764], [0.68630165,0.21055643,-0.026757622]),
+    0xF6ADC6u32 => ([78.20391,30.224361,-1.9364327], [0.82377934,0.09054218,-0.0046847765]),
+    0xF6EABEu32 => ([92.64402,-2.7476158,22.875927], [0.93565625,-0.0046725795,0.058380373]),
+    0xF75394u32 => ([59.9807,67.31026,-0.53048456], [0.68193305,0.20607096,-0.0005714924]),
+    0xF77FBEu32 => ([68.513855,52.977856,-11.870896], [0.74968994,0.1590992,-0.03097481]),
+    0xF78FA7u32 => ([71.01654,41.97383,4.6779566], [0.76643074,0.12728803,0.01271726]),
+    0xF7BFBEu32 => ([82.246895,19.913378,8.258341], [0.8545352,0.06088945,0.021783961]),
+    0xF7E7CEu32 => ([92.301796,1.4442331,14.133222], [0.93423027,0.0070579113,0.03666718]),
+    0xF7F7F7u32 => ([97.2321,5.551115e-14,0.0], [0.9761388,7.902107e-11,3.6384506e-08]),
+    0xF88379u32 => ([67.70423,43.580017,25.74494], [0.7382093,0.12966357,0.06384306]),
+    0xF8B878u32 => ([79.383064,15.967738,41.37531], [0.8282082,0.045801796,0.099680215]),
+    0xF8D568u32 => ([86.278694,-0.3333905,57.40563], [0.88170266,-0.003920474,0.13340665]),
+    0xF8DE7Eu32 => ([88.78362,-3.1568177,50.020382], [0.9022801,-0.009653909,0.11939091]),
+    0xF8F8FFu32 => ([97.757256,1.2474599,-3.3455095], [0.98111874,0.0025899603,-0.008898664]),
+    0xF9429Eu32 => ([58.516182,74.71753,-8.492854], [0.67277384,0.22933911,-0.021465752]),
+    0xFA5053u32 => ([58.685593,64.44008,35.55244], [0.6687332,0.18879274,0.08304843]),
+    0xFA5B3Du32 => ([60.020817,59.134876,48.980453], [0.67790157,0.16789891,0.1080908]),
+    0xFA8072u32 => ([67.262146,45.22245,29.092161], [0.73500234,0.13369869,0.071269915]),
+    0xFAD6A5u32 => ([87.55059,5.7547603,28.88079], [0.89487654,0.019288117,0.07257394]),
+    0xFADA5Eu32 => ([87.619125,-2.7221463,63.39453], [0.89241236,-0.012106699,0.14489496]),
+    0xFADADDu32 => ([89.71439,11.539915,2.6136367], [0.9157164,0.035046633,0.0070626573]),
+    0xFAE6FAu32 => ([93.37238,10.240181,-7.201627], [0.9467418,0.0281663,-0.019111315]),
+    0xFAE7B5u32 => ([92.04836,-0.9546448,26.736282], [0.93118674,0.0003660179,0.06769225]),
+    0xFAEBD7u32 => ([93.73103,1.8371707,11.526061], [0.9466915,0.007930012,0.03005429]),
+    0xFAF0E6u32 => ([95.31136,1.6765826,6.022014], [0.96023846,0.006529202,0.015857963]),
+    0xFAFA37u32 => ([95.56861,-20.306963,84.93927], [0.95485103,-0.06554036,0.18413043]),
+    0xFAFAD2u32 => ([97.3689,-6.4828115,19.23758], [0.9750065,-0.015435947,0.049487032]),
+    0xFAFAFAu32 => ([98.272026,5.551115e-14,0.0], [0.98510367,7.9746876e-11,3.6718664e-08]),
+    0xFB4D46u32 => ([58.285534,65.248215,42.36995], [0.6655157,0.18864262,0.09575712]),
+    0xFBAB60u32 => ([76.36415,22.18223,49.821884], [0.80445933,0.060983986,0.11599947]),
+    0xFBCEB1u32 => ([85.9202,11.768298,20.40056], [0.8830676,0.037236545,0.052193355]),
+    0xFBEC5Du32 => ([92.261246,-11.370563,68.8324], [0.9293905,-0.0370071,0.15626031]),
+    0xFC0FC0u32 => ([56.9253,88.833336,-30.629328], [0.6663994,0.26918855,-0.08223402]),
+    0xFC6C85u32 => ([64.25568,56.997486,14.358025], [0.7140696,0.17250608,0.036838844]),
+    0xFC89ACu32 => ([70.57207,47.34337,1.3228234], [0.7648209,0.14360544,0.0040836255]),
+    0xFCC200u32 => ([81.44588,7.5440416,83.17365], [0.84286094,0.009862665,0.1721396]),
+    0xFCE883u32 => ([91.706604,-6.1565967,51.267147], [0.9264093,-0.018049283,0.12247107]),
+    0xFCFCFCu32 => ([98.96399,5.551115e-14,0.0], [0.9910689,8.02296e-11,3.694101e-08]),
+    0xFD3A4Au32 => ([56.49283,72.12989,38.156757], [0.65297973,0.21102782,0.08721516]),
+    0xFD3F92u32 => ([58.52404,75.48533,-1.3727418], [0.67290276,0.23190081,-0.0026917544]),
+    0xFD6C9Eu32 => ([65.02562,59.79816,1.174501], [0.72216,0.18233037,0.003799145]),
+    0xFDBE02u32 => ([80.58073,10.06062,82.4304], [0.83630663,0.017021189,0.17047165]),
+    0xFDDDE6u32 => ([90.92103,12.484236,-0.3228485], [0.9264804,0.03716367,-0.00066804775]),
+    0xFDEE00u32 => ([92.58936,-14.210988,91.1017], [0.9312855,-0.051039606,0.19085613]),
+    0xFDF5E6u32 => ([96.77982,0.16995656,8.16619], [0.9723414,0.002528669,0.021410508]),
+    0xFDFF00u32 => ([96.98494,-22.37952,94.302086], [0.96637905,-0.07354742,0.19826493]),
+    0xFE2712u32 => ([54.625946,75.529434,63.216812], [0.63802433,0.21208858,0.12350716]),
+    0xFE28A2u32 => ([57.270325,82.77309,-12.611306], [0.66573995,0.25480652,-0.032398026]),
+    0xFE4164u32 => ([57.822983,71.67721,24.78249], [0.66450083,0.21463048,0.060297042]),
+    0xFE4EDAu32 => ([62.998108,79.28217,-35.958263], [0.7144365,0.23358999,-0.09765068]),
+    0xFEBAADu32 => ([81.40567,22.85173,16.277636], [0.8483642,0.06984275,0.041983474]),
+    0xFED85Du32 => ([87.472626,-0.12265338,63.76979], [0.89207566,-0.0049795937,0.14544499]),
+    0xFED8B1u32 => ([88.6183,7.7105803,24.16519], [0.904809,0.025361622,0.061396483]),
+    0xFEDF00u32 => ([88.85047,-6.379071,88.456856], [0.90177155,-0.029324861,0.18459512]),
+    0xFEFE33u32 => ([96.899635,-20.707026,87.230125], [0.9661926,-0.06710487,0.18822882]),
+    0xFEFEFAu32 => ([99.55619,-0.6953609,1.9075975], [0.99592274,-0.0014921264,0.005039103]),
+    0xFF0000u32 => ([53.237114,80.09011,67.20326], [0.6279554,0.22486307,0.1258463]),
+    0xFF007Cu32 => ([54.781693,84.25028,6.324116], [0.6442221,0.25879532,0.016827684]),
+    0xFF007Fu32 => ([54.861633,84.46207,4.6354046], [0.64506364,0.25977802,0.012628676]),
+    0xFF0090u32 => ([55.35683,85.76669,-4.812195], [0.65027076,0.2649197,-0.011569724]),
+    0xFF00FFu32 => ([60.32273,98.23744,-60.82891], [0.70167387,0.2745663,-0.16915606]),
+    0xFF1493u32 => ([55.957954,84.538185,-5.7051697], [0.654935,0.26096505,-0.0139288595]),
+    0xFF1DCEu32 => ([58.600697,89.51879,-36.01278], [0.68136525,0.26822296,-0.097508684]),
+    0xFF2400u32 => ([54.580288,76.22096,67.69206], [0.63788545,0.21288706,0.12801369]),
+    0xFF3030u32 => ([55.676037,74.38212,51.514496], [0.64669484,0.2126673,0.10919122]),
+    0xFF33CCu32 => ([59.880264,85.304115,-32.876682], [0.6902502,0.25588375,-0.088693656]),
+    0xFF34B3u32 => ([58.950348,82.18311,-19.950626], [0.6802419,0.25121078,-0.05249582]),
+    0xFF355Eu32 => ([56.70137,74.977,26.87951], [0.65618056,0.22400112,0.064596444]),
+    0xFF3800u32 => ([56.179913,71.68374,68.34095], [0.64976555,0.19891308,0.13060151]),
+    0xFF3855u32 => ([56.82899,73.72924,32.314926], [0.6566428,0.21818313,0.075901814]),
+    0xFF3E96u32 => ([58.891647,76.64992,-3.1391828], [0.67662543,0.23559928,-0.0072812247]),
+    0xFF4040u32 => ([57.367195,70.54686,44.818287], [0.6597478,0.20369229,0.09947552]),
+    0xFF43A4u32 => ([59.850853,76.54134,-9.887572], [0.68508184,0.23487574,-0.025171544]),
+    0xFF4500u32 => ([57.578564,67.779015,68.95933], [0.66019946,0.18694869,0.13286985]),
+    0xFF5349u32 => ([59.82647,64.39892,42.671772], [0.67843205,0.18610941,0.096804045]),
+    0xFF5470u32 => ([60.58165,66.22955,21.489582], [0.6860612,0.19903857,0.053310435]),
+    0xFF5800u32 => ([60.107677,60.864582,70.17851], [0.6791711,0.1659046,0.13698436]),
+    0xFF5A36u32 => ([60.642128,60.95815,53.35261], [0.68393934,0.1718315,0.115283534]),
+    0xFF6347u32 => ([62.204315,57.847107,46.41782], [0.6962193,0.1652304,0.104540884]),
+    0xFF66CCu32 => ([65.846176,68.58771,-23.66998], [0.73369676,0.20502734,-0.063169196]),
+    0xFF6700u32 => ([62.477207,54.554855,71.4185], [0.6970609,0.14686182,0.14085329]),
+    0xFF681Fu32 => ([62.72904,54.453762,64.67557], [0.699229,0.14867465,0.13298854]),
+    0xFF69B4u32 => ([65.48431,64.23715,-10.649883], [0.7282974,0.195155,-0.027445627]),
+    0xFF6A6Au32 => ([63.845516,56.742577,29.241962], [0.71015275,0.16803843,0.071007974]),
+    0xFF6E4Au32 => ([64.08767,53.10874,46.957626], [0.71055007,0.15107597,0.1063064]),
+    0xFF6EB4u32 => ([66.297905,62.05768,-9.434235], [0.73434347,0.18842535,-0.024205914]),
+    0xFF6FFFu32 => ([69.36364,72.506676,-46.486107], [0.7664454,0.20180194,-0.12808326]),
+    0xFF7256u32 => ([64.93861,51.871452,41.62162], [0.7174506,0.1493888,0.096676536]),
+    0xFF7518u32 => ([65.003716,48.3692,68.42283], [0.7164151,0.12967265,0.1397271]),
+    0xFF7538u32 => ([65.17744,49.1373,56.859737], [0.71828574,0.13584997,0.12350517]),
+    0xFF7800u32 => ([65.5115,46.70777,73.11721], [0.7201215,0.12339523,0.1458261]),
+    0xFF7A00u32 => ([65.890396,45.74592,73.336845], [0.72301257,0.1205353,0.14644846]),
+    0xFF7F00u32 => ([66.856255,43.311924,73.9035], [0.7303931,0.1133142,0.14803626]),
+    0xFF7F24u32 => ([66.95267,43.7649,66.333664], [0.7314336,0.117006354,0.1385842]),
+    0xFF7F50u32 => ([67.29286,45.349476,47.492134], [0.73511285,0.12822507,0.10853774]),
+    0xFF8243u32 => ([67.74975,43.268684,54.381016], [0.73818547,0.11972956,0.12085545]),
+    0xFF8247u32 => ([67.78658,43.442795,52.50972], [0.73858446,0.12090257,0.11768213]),
+    0xFF82ABu32 => ([69.589325,51.669563,0.49913886], [0.7581307,0.1569345,0.0019803778]),
+    0xFF83FAu32 => ([72.49318,62.686184,-38.861885], [0.78874564,0.17575535,-0.106350206]),
+    0xFF8C00u32 => ([69.48324,36.819885,75.48959], [0.75054425,0.094165646,0.15236416]),
+    0xFF8C69u32 => ([70.20328,40.409695,37.88802], [0.7583671,0.11712107,0.09054666]),
+    0xFF91A4u32 => ([72.35789,43.40377,8.404336], [0.77849925,0.13165261,0.022238977]),
+    0xFF91AFu32 => ([72.63344,44.72661,2.69465], [0.7814978,0.1355992,0.007625718]),
+    0xFF9361u32 => ([71.55049,36.30944,43.742992], [0.7683444,0.103368156,0.1027316]),
+    0xFF948Eu32 => ([72.4921,39.50231,20.763142], [0.77793616,0.11870248,0.052575927]),
+    0xFF9505u32 => ([71.39777,32.261517,76.0507], [0.76532155,0.08100089,0.15476282]),
+    0xFF9933u32 => ([72.41815,31.020336,65.72954], [0.7736857,0.08078091,0.14148766]),
+    0xFF9966u32 => ([72.894905,33.56106,42.78282], [0.7788884,0.095769055,0.10115609]),
+    0xFF9F00u32 => ([73.58386,27.04965,78.07641], [0.78221047,0.06565023,0.15914555]),
+    0xFFA07Au32 => ([74.70455,31.473017,34.547485], [0.793755,0.09214708,0.08421498]),
+    0xFFA089u32 => ([74.95742,32.831314,26.832888], [0.7965247,0.09789727,0.06694144]),
+    0xFFA500u32 => ([74.93391,23.926893,78.95302], [0.7926884,0.0566112,0.16138455]),
+    0xFFA54Fu32 => ([75.2877,25.94832,56.816383], [0.7965569,0.06947922,0.12840745]),
+    0xFFA6C9u32 => ([77.728195,37.45551,-4.155756], [0.8225801,0.11225218,-0.010473429]),
+    0xFFAA1Du32 => ([76.13109,21.63952,75.46299], [0.8021717,0.051269643,0.15785295]),
+    0xFFAE42u32 => ([77.237755,20.642265,64.456436], [0.8113704,0.052045215,0.14220832]),
+    0xFFAEB9u32 => ([79.06262,31.060038,6.4932995], [0.83142525,0.09419538,0.017330954]),
+    0xFFB200u32 => ([77.93594,17.143719,80.93978], [0.81607425,0.037098594,0.16637383]),
+    0xFFB5C5u32 => ([80.928,29.047798,2.694372], [0.8467503,0.087720804,0.0074791647]),
+    0xFFB6C1u32 => ([81.05366,27.960371,5.0345116], [0.8473878,0.08471583,0.013548875]),
+    0xFFB7C5u32 => ([81.37956,27.998909,3.34936], [0.85022604,0.08463264,0.0091755]),
+    0xFFB90Fu32 => ([79.610855,13.619883,80.49633], [0.8292328,0.027475648,0.16715148]),
+    0xFFBA00u32 => ([79.8297,12.975611,82.21676], [0.83088475,0.02518924,0.16952825]),
+    0xFFBBFFu32 => ([83.97734,35.288834,-23.885138], [0.87576056,0.09751094,-0.064497784]),
+    0xFFBCD9u32 => ([83.03982,28.360233,-4.977062], [0.8647448,0.0840641,-0.012812415]),
+    0xFFBD88u32 => ([81.53005,17.583643,36.027596], [0.84734154,0.05190368,0.08835415]),
+    0xFFBF00u32 => ([81.029175,10.378695,83.03406], [0.8402875,0.017799418,0.17152889]),
+    0xFFC0CBu32 => ([83.58574,24.14168,3.3246627], [0.8677384,0.07298037,0.009071449]),
+    0xFFC125u32 => ([81.585175,9.819002,77.877235], [0.84488195,0.017809005,0.16505587]),
+    0xFFC1C1u32 => ([83.5748,22.189438,8.658865], [0.8668558,0.067730226,0.022833867]),
+    0xFFC40Cu32 => ([82.25417,7.8853908,82.735344], [0.84995246,0.0110475775,0.17210446]),
+    0xFFC680u32 => ([83.55204,12.154592,42.701756], [0.86273986,0.034961432,0.10310193]),
+    0xFFCBA4u32 => ([85.32889,13.250461,26.47337], [0.8785091,0.04111445,0.066770226]),
+    0xFFCC33u32 => ([84.32205,4.516338,76.35395], [0.86657447,0.003782378,0.16461195]),
+    0xFFD300u32 => ([85.93159,0.09880815,86.43422], [0.87888414,-0.011234227,0.17972594]),
+    0xFFD39Bu32 => ([87.103035,8.08741,33.522194], [0.891874,0.025389407,0.08327556]),
+    0xFFD700u32 => ([86.92946,-1.9302442,87.13677], [0.8867711,-0.016925177,0.18139815]),
+    0xFFD800u32 => ([87.17972,-2.4358706,87.31347], [0.8887507,-0.018341359,0.18181774]),
+    0xFFDAB9u32 => ([89.34938,8.082339,21.02214], [0.91125286,0.026585272,0.053788204]),
+    0xFFDAE9u32 => ([90.45358,15.225748,-2.5615656], [0.92350745,0.044793837,-0.006574205]),
+    0xFFDB00u32 => ([87.93234,-3.9486716,87.84605], [0.89470756,-0.022573857,0.18307999]),
+    0xFFDB58u32 => ([88.27058,-1.5246061,66.75756], [0.8984579,-0.009655347,0.1510146]),
+    0xFFDDCAu32 => ([90.431206,8.872393,13.663231], [0.9208803,0.028734919,0.035510074]),
+    0xFFDDF4u32 => ([91.462524,15.52418,-6.845649], [0.93232346,0.044309434,-0.018053962]),
+    0xFFDEADu32 => ([90.10068,4.5067954,28.272156], [0.91640216,0.015881488,0.07125776]),
+    0xFFDF00u32 => ([88.93994,-5.9559007,88.56172], [0.902691,-0.028179092,0.1847709]),
+    0xFFE1FFu32 => ([92.72084,15.377763,-10.731117], [0.94311273,0.0423346,-0.028583469]),
+    0xFFE4B5u32 => ([91.72259,2.436295,26.35986], [0.9296221,0.010123965,0.066774465]),
+    0xFFE4C4u32 => ([92.01292,4.4284053,19.011833], [0.9328562,0.016026122,0.048885465]),
+    0xFFE4E1u32 => ([92.65598,8.745844,4.8352714], [0.9400117,0.027195733,0.012844467]),
+    0xFFE5B4u32 => ([91.95117,1.8001539,27.185698], [0.9313596,0.00825444,0.068757325]),
+    0xFFE7BAu32 => ([92.55633,1.5530868,24.929361], [0.93648344,0.007675813,0.06336308]),
+    0xFFEB00u32 => ([91.9882,-11.905541,90.74423], [0.9268999,-0.044725,0.18989323]),
+    0xFFEBCDu32 => ([93.919846,2.1280425,17.02606], [0.94843936,0.009296485,0.043962296]),
+    0xFFEC8Bu32 => ([93.06522,-6.242134,49.154713], [0.9380866,-0.01768766,0.11828971]),
+    0xFFEF00u32 => ([93.0119,-13.863389,91.48262], [0.93504834,-0.050148096,0.1916157]),
+    0xFFEFD5u32 => ([95.07573,1.2689354,14.525372], [0.95807993,0.006622367,0.037681177]),
+    0xFFEFDBu32 => ([95.20645,2.1769395,11.612499], [0.9595379,0.008950289,0.030285917]),
+    0xFFF0F5u32 => ([96.06857,5.886949,-0.59397703], [0.96833485,0.017342905,-0.0014863464]),
+    0xFFF44Fu32 => ([94.53717,-14.414195,76.61987], [0.94796807,-0.0472757,0.17040591]),
+    0xFFF5EEu32 => ([97.12127,2.1614761,4.553984], [0.97601813,0.007638632,0.012033104]),
+    0xFFF68Fu32 => ([95.64537,-10.818701,50.433456], [0.9587041,-0.030513296,0.12140254]),
+    0xFFF700u32 => ([95.06929,-17.739113,92.97418], [0.9514512,-0.060853295,0.19508065]),
+    0xFFF8DCu32 => ([97.45543,-2.2191923,14.293623], [0.9773007,-0.003531187,0.03708985]),
+    0xFFF8E7u32 => ([97.6983,-0.49713805,8.989984], [0.98001367,0.0007264414,0.023535365]),
+    0xFFFACDu32 => ([97.64787,-5.42891,22.234165], [0.9778096,-0.012259016,0.056911167]),
+    0xFFFAF0u32 => ([98.40153,-0.03719076,5.376169], [0.98623276,0.0013425166,0.014156463]),
+    0xFFFAFAu32 => ([98.64383,1.6565475,0.5873776], [0.9889369,0.005096968,0.0015824084]),
+    0xFFFDD0u32 => ([98.458046,-6.494806,21.83273], [0.98440266,-0.015316982,0.055935122]),
+    0xFFFF00u32 => ([97.13856,-21.55997,94.48384], [0.9679827,-0.07136908,0.19856976]),
+    0xFFFF66u32 => ([97.527115,-18.445343,70.90306], [0.972323,-0.05635208,0.16178836]),
+    0xFFFF99u32 => ([98.06569,-14.232666,48.664738], [0.9783526,-0.039475273,0.118012846]),
+    0xFFFF9Fu32 => ([98.14692,-13.607417,45.87139], [0.97926265,-0.037237216,0.11197589]),
+    0xFFFFE0u32 => ([99.28491,-5.108653,14.837992], [0.99200743,-0.011841024,0.038462907]),
+    0xFFFFF0u32 => ([99.63981,-2.552057,7.162729], [0.9959763,-0.0056533646,0.018783517]),
+    0xFFFFFFu32 => ([1e+02,0.0,0.0], [1.0,8.0952856e-11,3.7273907e-08]),
+};
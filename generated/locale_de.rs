@@ -0,0 +1,165 @@
+//  ***  AUTO-GENERATED  – DO NOT EDIT BY HAND  ***
+
+/// German color names, canonicalized, mapped to the key of the English name.
+pub(crate) static LOCALE_DE: phf::Map<&'static str, &'static str> = phf::phf_map! {
+    "schwarz" => "black",
+    "weiss" => "white",
+    "rot" => "red",
+    "grun" => "green",
+    "blau" => "blue",
+    "gelb" => "yellow",
+    "orange" => "orange",
+    "lila" => "purple",
+    "purpur" => "purple",
+    "rosa" => "pink",
+    "pink" => "pink",
+    "braun" => "brown",
+    "grau" => "grayweb",
+    "cyan" => "cyan",
+    "zyan" => "cyan",
+    "magenta" => "magenta",
+    "violett" => "violet",
+    "turkis" => "turquoise",
+    "gold" => "goldmetallic",
+    "silber" => "silver",
+    "beige" => "beige",
+    "khaki" => "khakiweb",
+    "marineblau" => "navyblue",
+    "navyblau" => "navyblue",
+    "himmelblau" => "skyblue",
+    "hellblau" => "lightblue",
+    "oliv" => "olive",
+    "olivgrun" => "olive",
+    "kastanienbraun" => "maroonweb",
+    "karmesinrot" => "crimson",
+    "karmesin" => "crimson",
+    "scharlachrot" => "scarlet",
+    "scharlach" => "scarlet",
+    "indigo" => "indigo",
+    "elfenbein" => "ivory",
+    "cremeweiss" => "cream",
+    "creme" => "cream",
+    "korallenrot" => "coral",
+    "koralle" => "coral",
+    "lachsrosa" => "salmon",
+    "lachs" => "salmon",
+    "zitronengelb" => "lemon",
+    "limettengrun" => "limecolorwheel",
+    "limette" => "limecolorwheel",
+    "minzgrun" => "mint",
+    "petrol" => "teal",
+    "blaugrun" => "teal",
+    "azurblau" => "azure",
+    "azur" => "azure",
+    "ultramarin" => "ultramarine",
+    "zinnoberrot" => "vermilion",
+    "zinnober" => "vermilion",
+    "ocker" => "ochre",
+    "burgunderrot" => "burgundy",
+    "bordeaux" => "burgundy",
+    "smaragdgrun" => "emerald",
+    "smaragd" => "emerald",
+    "rubinrot" => "ruby",
+    "rubin" => "ruby",
+    "saphirblau" => "sapphire",
+    "saphir" => "sapphire",
+    "bernstein" => "amber",
+    "aprikose" => "apricot",
+    "pfirsich" => "peach",
+    "pflaume" => "plum",
+    "rosenrot" => "rose",
+    "lavendel" => "lavenderfloral",
+    "flieder" => "lilac",
+    "glyzinie" => "wisteria",
+    "mauve" => "mauve",
+    "aquamarin" => "aquamarine",
+    "rostrot" => "rust",
+    "rost" => "rust",
+    "kupfer" => "copper",
+    "bronze" => "bronze",
+    "sepia" => "sepia",
+    "anthrazit" => "charcoal",
+    "schokoladenbraun" => "chocolatetraditional",
+    "schokolade" => "chocolatetraditional",
+    "mandarine" => "tangerine",
+    "weinrot" => "wine",
+    "coelinblau" => "cerulean",
+    "preussischblau" => "prussianblue",
+    "berlinerblau" => "prussianblue",
+    "fuchsie" => "fuchsia",
+    "karminrot" => "carmine",
+    "karmin" => "carmine",
+    "kirschblutenrosa" => "cherryblossompink",
+    "hellbraun" => "tan",
+};
+
+/// German display names, by key of the English name.
+pub(crate) static LOCALE_DE_NAMES: phf::Map<&'static str, &'static str> = phf::phf_map! {
+    "black" => "Schwarz",
+    "white" => "Weiß",
+    "red" => "Rot",
+    "green" => "Grün",
+    "blue" => "Blau",
+    "yellow" => "Gelb",
+    "orange" => "Orange",
+    "purple" => "Lila",
+    "pink" => "Rosa",
+    "brown" => "Braun",
+    "grayweb" => "Grau",
+    "cyan" => "Cyan",
+    "magenta" => "Magenta",
+    "violet" => "Violett",
+    "turquoise" => "Türkis",
+    "goldmetallic" => "Gold",
+    "silver" => "Silber",
+    "beige" => "Beige",
+    "khakiweb" => "Khaki",
+    "navyblue" => "Marineblau",
+    "skyblue" => "Himmelblau",
+    "lightblue" => "Hellblau",
+    "olive" => "Oliv",
+    "maroonweb" => "Kastanienbraun",
+    "crimson" => "Karmesinrot",
+    "scarlet" => "Scharlachrot",
+    "indigo" => "Indigo",
+    "ivory" => "Elfenbein",
+    "cream" => "Cremeweiß",
+    "coral" => "Korallenrot",
+    "salmon" => "Lachsrosa",
+    "lemon" => "Zitronengelb",
+    "limecolorwheel" => "Limettengrün",
+    "mint" => "Minzgrün",
+    "teal" => "Petrol",
+    "azure" => "Azurblau",
+    "ultramarine" => "Ultramarin",
+    "vermilion" => "Zinnoberrot",
+    "ochre" => "Ocker",
+    "burgundy" => "Burgunderrot",
+    "emerald" => "Smaragdgrün",
+    "ruby" => "Rubinrot",
+    "sapphire" => "Saphirblau",
+    "amber" => "Bernstein",
+    "apricot" => "Aprikose",
+    "peach" => "Pfirsich",
+    "plum" => "Pflaume",
+    "rose" => "Rosenrot",
+    "lavenderfloral" => "Lavendel",
+    "lilac" => "Flieder",
+    "wisteria" => "Glyzinie",
+    "mauve" => "Mauve",
+    "aquamarine" => "Aquamarin",
+    "rust" => "Rostrot",
+    "copper" => "Kupfer",
+    "bronze" => "Bronze",
+    "sepia" => "Sepia",
+    "charcoal" => "Anthrazit",
+    "chocolatetraditional" => "Schokoladenbraun",
+    "tangerine" => "Mandarine",
+    "wine" => "Weinrot",
+    "cerulean" => "Coelinblau",
+    "prussianblue" => "Preußischblau",
+    "fuchsia" => "Fuchsie",
+    "carmine" => "Karminrot",
+    "cherryblossompink" => "Kirschblütenrosa",
+    "tan" => "Hellbraun",
+};
@@ -0,0 +1,153 @@
+//  ***  AUTO-GENERATED  – DO NOT EDIT BY HAND  ***
+
+/// French color names, canonicalized, mapped to the key of the English name.
+pub(crate) static LOCALE_FR: phf::Map<&'static str, &'static str> = phf::phf_map! {
+    "noir" => "black",
+    "blanc" => "white",
+    "rouge" => "red",
+    "vert" => "green",
+    "bleu" => "blue",
+    "jaune" => "yellow",
+    "orange" => "orange",
+    "violet" => "purple",
+    "pourpre" => "purple",
+    "rose" => "pink",
+    "marron" => "brown",
+    "brun" => "brown",
+    "gris" => "grayweb",
+    "cyan" => "cyan",
+    "magenta" => "magenta",
+    "turquoise" => "turquoise",
+    "or" => "goldmetallic",
+    "dore" => "goldmetallic",
+    "argent" => "silver",
+    "argente" => "silver",
+    "beige" => "beige",
+    "kaki" => "khakiweb",
+    "bleumarine" => "navyblue",
+    "bleuciel" => "skyblue",
+    "bleuclair" => "lightblue",
+    "vertolive" => "olive",
+    "olive" => "olive",
+    "bordeauxfonce" => "maroonweb",
+    "cramoisi" => "crimson",
+    "ecarlate" => "scarlet",
+    "indigo" => "indigo",
+    "ivoire" => "ivory",
+    "creme" => "cream",
+    "corail" => "coral",
+    "saumon" => "salmon",
+    "jaunecitron" => "lemon",
+    "citron" => "lemon",
+    "vertcitron" => "limecolorwheel",
+    "vertmenthe" => "mint",
+    "menthe" => "mint",
+    "bleucanard" => "teal",
+    "sarcelle" => "teal",
+    "bleuazur" => "azure",
+    "azur" => "azure",
+    "bleuoutremer" => "ultramarine",
+    "outremer" => "ultramarine",
+    "vermillon" => "vermilion",
+    "ocre" => "ochre",
+    "bordeaux" => "burgundy",
+    "vertemeraude" => "emerald",
+    "emeraude" => "emerald",
+    "rubis" => "ruby",
+    "bleusaphir" => "sapphire",
+    "saphir" => "sapphire",
+    "ambre" => "amber",
+    "abricot" => "apricot",
+    "peche" => "peach",
+    "prune" => "plum",
+    "lavande" => "lavenderfloral",
+    "lilas" => "lilac",
+    "glycine" => "wisteria",
+    "mauve" => "mauve",
+    "aiguemarine" => "aquamarine",
+    "rouille" => "rust",
+    "cuivre" => "copper",
+    "bronze" => "bronze",
+    "sepia" => "sepia",
+    "anthracite" => "charcoal",
+    "charbon" => "charcoal",
+    "chocolat" => "chocolatetraditional",
+    "mandarine" => "tangerine",
+    "liedevin" => "wine",
+    "bleuceruleen" => "cerulean",
+    "ceruleen" => "cerulean",
+    "bleudeprusse" => "prussianblue",
+    "fuchsia" => "fuchsia",
+    "carmin" => "carmine",
+    "rosesakura" => "cherryblossompink",
+    "brunclair" => "tan",
+};
+
+/// French display names, by key of the English name.
+pub(crate) static LOCALE_FR_NAMES: phf::Map<&'static str, &'static str> = phf::phf_map! {
+    "black" => "Noir",
+    "white" => "Blanc",
+    "red" => "Rouge",
+    "green" => "Vert",
+    "blue" => "Bleu",
+    "yellow" => "Jaune",
+    "orange" => "Orange",
+    "purple" => "Violet",
+    "pink" => "Rose",
+    "brown" => "Marron",
+    "grayweb" => "Gris",
+    "cyan" => "Cyan",
+    "magenta" => "Magenta",
+    "turquoise" => "Turquoise",
+    "goldmetallic" => "Or",
+    "silver" => "Argent",
+    "beige" => "Beige",
+    "khakiweb" => "Kaki",
+    "navyblue" => "Bleu marine",
+    "skyblue" => "Bleu ciel",
+    "lightblue" => "Bleu clair",
+    "olive" => "Vert olive",
+    "maroonweb" => "Bordeaux foncé",
+    "crimson" => "Cramoisi",
+    "scarlet" => "Écarlate",
+    "indigo" => "Indigo",
+    "ivory" => "Ivoire",
+    "cream" => "Crème",
+    "coral" => "Corail",
+    "salmon" => "Saumon",
+    "lemon" => "Jaune citron",
+    "limecolorwheel" => "Vert citron",
+    "mint" => "Vert menthe",
+    "teal" => "Bleu canard",
+    "azure" => "Bleu azur",
+    "ultramarine" => "Bleu outremer",
+    "vermilion" => "Vermillon",
+    "ochre" => "Ocre",
+    "burgundy" => "Bordeaux",
+    "emerald" => "Vert émeraude",
+    "ruby" => "Rubis",
+    "sapphire" => "Bleu saphir",
+    "amber" => "Ambre",
+    "apricot" => "Abricot",
+    "peach" => "Pêche",
+    "plum" => "Prune",
+    "lavenderfloral" => "Lavande",
+    "lilac" => "Lilas",
+    "wisteria" => "Glycine",
+    "mauve" => "Mauve",
+    "aquamarine" => "Aigue-marine",
+    "rust" => "Rouille",
+    "copper" => "Cuivre",
+    "bronze" => "Bronze",
+    "sepia" => "Sépia",
+    "charcoal" => "Anthracite",
+    "chocolatetraditional" => "Chocolat",
+    "tangerine" => "Mandarine",
+    "wine" => "Lie-de-vin",
+    "cerulean" => "Bleu céruléen",
+    "prussianblue" => "Bleu de Prusse",
+    "fuchsia" => "Fuchsia",
+    "carmine" => "Carmin",
+    "cherryblossompink" => "Rose sakura",
+    "tan" => "Brun clair",
+};
@@ -0,0 +1,162 @@
+//  ***  AUTO-GENERATED  – DO NOT EDIT BY HAND  ***
+
+/// Japanese color names, canonicalized, mapped to the key of the English name.
+pub(crate) static LOCALE_JA: phf::Map<&'static str, &'static str> = phf::phf_map! {
+    "黒" => "black",
+    "黒色" => "black",
+    "白" => "white",
+    "白色" => "white",
+    "赤" => "red",
+    "赤色" => "red",
+    "緑" => "green",
+    "緑色" => "green",
+    "青" => "blue",
+    "青色" => "blue",
+    "黄色" => "yellow",
+    "橙色" => "orange",
+    "オレンシ" => "orange",
+    "紫" => "purple",
+    "紫色" => "purple",
+    "ヒンク" => "pink",
+    "桃色" => "pink",
+    "茶色" => "brown",
+    "灰色" => "grayweb",
+    "クレー" => "grayweb",
+    "シアン" => "cyan",
+    "マセンタ" => "magenta",
+    "菫色" => "violet",
+    "ハイオレット" => "violet",
+    "ターコイス" => "turquoise",
+    "金色" => "goldmetallic",
+    "銀色" => "silver",
+    "ヘーシュ" => "beige",
+    "カーキ" => "khakiweb",
+    "紺色" => "navyblue",
+    "ネイヒー" => "navyblue",
+    "空色" => "skyblue",
+    "スカイフルー" => "skyblue",
+    "水色" => "lightblue",
+    "オリーフ" => "olive",
+    "栗色" => "maroonweb",
+    "マルーン" => "maroonweb",
+    "深紅" => "crimson",
+    "クリムソン" => "crimson",
+    "緋色" => "scarlet",
+    "スカーレット" => "scarlet",
+    "藍色" => "indigo",
+    "インティコ" => "indigo",
+    "象牙色" => "ivory",
+    "アイホリー" => "ivory",
+    "クリーム色" => "cream",
+    "珊瑚色" => "coral",
+    "コーラル" => "coral",
+    "サーモンヒンク" => "salmon",
+    "レモン色" => "lemon",
+    "ライム" => "limecolorwheel",
+    "ミントクリーン" => "mint",
+    "ティール" => "teal",
+    "紺碧" => "azure",
+    "群青色" => "ultramarine",
+    "ウルトラマリン" => "ultramarine",
+    "朱色" => "vermilion",
+    "黄土色" => "ochre",
+    "ハーカンティー" => "burgundy",
+    "エメラルトクリーン" => "emerald",
+    "ルヒー" => "ruby",
+    "サファイア" => "sapphire",
+    "琥珀色" => "amber",
+    "アフリコット" => "apricot",
+    "ヒーチ" => "peach",
+    "フラム" => "plum",
+    "ラヘンター" => "lavenderfloral",
+    "ライラック" => "lilac",
+    "藤色" => "wisteria",
+    "モーフ" => "mauve",
+    "アクアマリン" => "aquamarine",
+    "錆色" => "rust",
+    "銅色" => "copper",
+    "フロンス" => "bronze",
+    "セヒア" => "sepia",
+    "チャコールクレー" => "charcoal",
+    "チョコレート色" => "chocolatetraditional",
+    "タンシェリン" => "tangerine",
+    "ワインレット" => "wine",
+    "セルリアンフルー" => "cerulean",
+    "紺青" => "prussianblue",
+    "フルシアンフルー" => "prussianblue",
+    "フクシア" => "fuchsia",
+    "洋紅色" => "carmine",
+    "カーマイン" => "carmine",
+    "桜色" => "cherryblossompink",
+    "タン" => "tan",
+};
+
+/// Japanese display names, by key of the English name.
+pub(crate) static LOCALE_JA_NAMES: phf::Map<&'static str, &'static str> = phf::phf_map! {
+    "black" => "黒",
+    "white" => "白",
+    "red" => "赤",
+    "green" => "緑",
+    "blue" => "青",
+    "yellow" => "黄色",
+    "orange" => "橙色",
+    "purple" => "紫",
+    "pink" => "ピンク",
+    "brown" => "茶色",
+    "grayweb" => "灰色",
+    "cyan" => "シアン",
+    "magenta" => "マゼンタ",
+    "violet" => "菫色",
+    "turquoise" => "ターコイズ",
+    "goldmetallic" => "金色",
+    "silver" => "銀色",
+    "beige" => "ベージュ",
+    "khakiweb" => "カーキ",
+    "navyblue" => "紺色",
+    "skyblue" => "空色",
+    "lightblue" => "水色",
+    "olive" => "オリーブ",
+    "maroonweb" => "栗色",
+    "crimson" => "深紅",
+    "scarlet" => "緋色",
+    "indigo" => "藍色",
+    "ivory" => "象牙色",
+    "cream" => "クリーム色",
+    "coral" => "珊瑚色",
+    "salmon" => "サーモンピンク",
+    "lemon" => "レモン色",
+    "limecolorwheel" => "ライム",
+    "mint" => "ミントグリーン",
+    "teal" => "ティール",
+    "azure" => "紺碧",
+    "ultramarine" => "群青色",
+    "vermilion" => "朱色",
+    "ochre" => "黄土色",
+    "burgundy" => "バーガンディー",
+    "emerald" => "エメラルドグリーン",
+    "ruby" => "ルビー",
+    "sapphire" => "サファイア",
+    "amber" => "琥珀色",
+    "apricot" => "アプリコット",
+    "peach" => "ピーチ",
+    "plum" => "プラム",
+    "lavenderfloral" => "ラベンダー",
+    "lilac" => "ライラック",
+    "wisteria" => "藤色",
+    "mauve" => "モーブ",
+    "aquamarine" => "アクアマリン",
+    "rust" => "錆色",
+    "copper" => "銅色",
+    "bronze" => "ブロンズ",
+    "sepia" => "セピア",
+    "charcoal" => "チャコールグレー",
+    "chocolatetraditional" => "チョコレート色",
+    "tangerine" => "タンジェリン",
+    "wine" => "ワインレッド",
+    "cerulean" => "セルリアンブルー",
+    "prussianblue" => "紺青",
+    "fuchsia" => "フクシア",
+    "carmine" => "洋紅色",
+    "cherryblossompink" => "桜色",
+    "tan" => "タン",
+};
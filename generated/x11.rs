@@ -0,0 +1,3518 @@
+//  ***  AUTO-GENERATED  – DO NOT EDIT BY HAND  ***
+
+/// Colors from the X11 `rgb.txt` database.
+pub static X11_COLORS: phf::Map<&'static str, crate::Color> = phf::phf_map! {
+    "snow" => crate::Color{ name:std::borrow::Cow::Borrowed("snow"), hex:std::borrow::Cow::Borrowed("#FFFAFA"), rgb:(255,250,250), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "ghostwhite" => crate::Color{ name:std::borrow::Cow::Borrowed("ghost white"), hex:std::borrow::Cow::Borrowed("#F8F8FF"), rgb:(248,248,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "whitesmoke" => crate::Color{ name:std::borrow::Cow::Borrowed("white smoke"), hex:std::borrow::Cow::Borrowed("#F5F5F5"), rgb:(245,245,245), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gainsboro" => crate::Color{ name:std::borrow::Cow::Borrowed("gainsboro"), hex:std::borrow::Cow::Borrowed("#DCDCDC"), rgb:(220,220,220), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "floralwhite" => crate::Color{ name:std::borrow::Cow::Borrowed("floral white"), hex:std::borrow::Cow::Borrowed("#FFFAF0"), rgb:(255,250,240), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "oldlace" => crate::Color{ name:std::borrow::Cow::Borrowed("old lace"), hex:std::borrow::Cow::Borrowed("#FDF5E6"), rgb:(253,245,230), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "linen" => crate::Color{ name:std::borrow::Cow::Borrowed("linen"), hex:std::borrow::Cow::Borrowed("#FAF0E6"), rgb:(250,240,230), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "antiquewhite" => crate::Color{ name:std::borrow::Cow::Borrowed("antique white"), hex:std::borrow::Cow::Borrowed("#FAEBD7"), rgb:(250,235,215), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "papayawhip" => crate::Color{ name:std::borrow::Cow::Borrowed("papaya whip"), hex:std::borrow::Cow::Borrowed("#FFEFD5"), rgb:(255,239,213), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "blanchedalmond" => crate::Color{ name:std::borrow::Cow::Borrowed("blanched almond"), hex:std::borrow::Cow::Borrowed("#FFEBCD"), rgb:(255,235,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "bisque" => crate::Color{ name:std::borrow::Cow::Borrowed("bisque"), hex:std::borrow::Cow::Borrowed("#FFE4C4"), rgb:(255,228,196), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "peachpuff" => crate::Color{ name:std::borrow::Cow::Borrowed("peach puff"), hex:std::borrow::Cow::Borrowed("#FFDAB9"), rgb:(255,218,185), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "navajowhite" => crate::Color{ name:std::borrow::Cow::Borrowed("navajo white"), hex:std::borrow::Cow::Borrowed("#FFDEAD"), rgb:(255,222,173), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "moccasin" => crate::Color{ name:std::borrow::Cow::Borrowed("moccasin"), hex:std::borrow::Cow::Borrowed("#FFE4B5"), rgb:(255,228,181), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cornsilk" => crate::Color{ name:std::borrow::Cow::Borrowed("cornsilk"), hex:std::borrow::Cow::Borrowed("#FFF8DC"), rgb:(255,248,220), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "ivory" => crate::Color{ name:std::borrow::Cow::Borrowed("ivory"), hex:std::borrow::Cow::Borrowed("#FFFFF0"), rgb:(255,255,240), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lemonchiffon" => crate::Color{ name:std::borrow::Cow::Borrowed("lemon chiffon"), hex:std::borrow::Cow::Borrowed("#FFFACD"), rgb:(255,250,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "seashell" => crate::Color{ name:std::borrow::Cow::Borrowed("seashell"), hex:std::borrow::Cow::Borrowed("#FFF5EE"), rgb:(255,245,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "honeydew" => crate::Color{ name:std::borrow::Cow::Borrowed("honeydew"), hex:std::borrow::Cow::Borrowed("#F0FFF0"), rgb:(240,255,240), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mintcream" => crate::Color{ name:std::borrow::Cow::Borrowed("mint cream"), hex:std::borrow::Cow::Borrowed("#F5FFFA"), rgb:(245,255,250), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "azure" => crate::Color{ name:std::borrow::Cow::Borrowed("azure"), hex:std::borrow::Cow::Borrowed("#F0FFFF"), rgb:(240,255,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "aliceblue" => crate::Color{ name:std::borrow::Cow::Borrowed("alice blue"), hex:std::borrow::Cow::Borrowed("#F0F8FF"), rgb:(240,248,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lavender" => crate::Color{ name:std::borrow::Cow::Borrowed("lavender"), hex:std::borrow::Cow::Borrowed("#E6E6FA"), rgb:(230,230,250), aliases:&["lavendar"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lavenderblush" => crate::Color{ name:std::borrow::Cow::Borrowed("lavender blush"), hex:std::borrow::Cow::Borrowed("#FFF0F5"), rgb:(255,240,245), aliases:&["lavendarblush"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mistyrose" => crate::Color{ name:std::borrow::Cow::Borrowed("misty rose"), hex:std::borrow::Cow::Borrowed("#FFE4E1"), rgb:(255,228,225), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "white" => crate::Color{ name:std::borrow::Cow::Borrowed("white"), hex:std::borrow::Cow::Borrowed("#FFFFFF"), rgb:(255,255,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "black" => crate::Color{ name:std::borrow::Cow::Borrowed("black"), hex:std::borrow::Cow::Borrowed("#000000"), rgb:(0,0,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkslategray" => crate::Color{ name:std::borrow::Cow::Borrowed("dark slate gray"), hex:std::borrow::Cow::Borrowed("#2F4F4F"), rgb:(47,79,79), aliases:&["dkslategray", "dkslategrey"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkslategrey" => crate::Color{ name:std::borrow::Cow::Borrowed("dark slate grey"), hex:std::borrow::Cow::Borrowed("#2F4F4F"), rgb:(47,79,79), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "dimgray" => crate::Color{ name:std::borrow::Cow::Borrowed("dim gray"), hex:std::borrow::Cow::Borrowed("#696969"), rgb:(105,105,105), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "dimgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("dim grey"), hex:std::borrow::Cow::Borrowed("#696969"), rgb:(105,105,105), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slategray" => crate::Color{ name:std::borrow::Cow::Borrowed("slate gray"), hex:std::borrow::Cow::Borrowed("#708090"), rgb:(112,128,144), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slategrey" => crate::Color{ name:std::borrow::Cow::Borrowed("slate grey"), hex:std::borrow::Cow::Borrowed("#708090"), rgb:(112,128,144), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightslategray" => crate::Color{ name:std::borrow::Cow::Borrowed("light slate gray"), hex:std::borrow::Cow::Borrowed("#778899"), rgb:(119,136,153), aliases:&["ltslategray", "ltslategrey"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightslategrey" => crate::Color{ name:std::borrow::Cow::Borrowed("light slate grey"), hex:std::borrow::Cow::Borrowed("#778899"), rgb:(119,136,153), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray" => crate::Color{ name:std::borrow::Cow::Borrowed("gray"), hex:std::borrow::Cow::Borrowed("#BEBEBE"), rgb:(190,190,190), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey" => crate::Color{ name:std::borrow::Cow::Borrowed("grey"), hex:std::borrow::Cow::Borrowed("#BEBEBE"), rgb:(190,190,190), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("light grey"), hex:std::borrow::Cow::Borrowed("#D3D3D3"), rgb:(211,211,211), aliases:&["ltgrey", "ltgray"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightgray" => crate::Color{ name:std::borrow::Cow::Borrowed("light gray"), hex:std::borrow::Cow::Borrowed("#D3D3D3"), rgb:(211,211,211), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "midnightblue" => crate::Color{ name:std::borrow::Cow::Borrowed("midnight blue"), hex:std::borrow::Cow::Borrowed("#191970"), rgb:(25,25,112), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "navy" => crate::Color{ name:std::borrow::Cow::Borrowed("navy"), hex:std::borrow::Cow::Borrowed("#000080"), rgb:(0,0,128), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "navyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("navy blue"), hex:std::borrow::Cow::Borrowed("#000080"), rgb:(0,0,128), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cornflowerblue" => crate::Color{ name:std::borrow::Cow::Borrowed("cornflower blue"), hex:std::borrow::Cow::Borrowed("#6495ED"), rgb:(100,149,237), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkslateblue" => crate::Color{ name:std::borrow::Cow::Borrowed("dark slate blue"), hex:std::borrow::Cow::Borrowed("#483D8B"), rgb:(72,61,139), aliases:&["dkslateblue"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slateblue" => crate::Color{ name:std::borrow::Cow::Borrowed("slate blue"), hex:std::borrow::Cow::Borrowed("#6A5ACD"), rgb:(106,90,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumslateblue" => crate::Color{ name:std::borrow::Cow::Borrowed("medium slate blue"), hex:std::borrow::Cow::Borrowed("#7B68EE"), rgb:(123,104,238), aliases:&["medslateblue"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightslateblue" => crate::Color{ name:std::borrow::Cow::Borrowed("light slate blue"), hex:std::borrow::Cow::Borrowed("#8470FF"), rgb:(132,112,255), aliases:&["ltslateblue"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumblue" => crate::Color{ name:std::borrow::Cow::Borrowed("medium blue"), hex:std::borrow::Cow::Borrowed("#0000CD"), rgb:(0,0,205), aliases:&["medblue"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "royalblue" => crate::Color{ name:std::borrow::Cow::Borrowed("royal blue"), hex:std::borrow::Cow::Borrowed("#4169E1"), rgb:(65,105,225), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "blue" => crate::Color{ name:std::borrow::Cow::Borrowed("blue"), hex:std::borrow::Cow::Borrowed("#0000FF"), rgb:(0,0,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "dodgerblue" => crate::Color{ name:std::borrow::Cow::Borrowed("dodger blue"), hex:std::borrow::Cow::Borrowed("#1E90FF"), rgb:(30,144,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "deepskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("deep sky blue"), hex:std::borrow::Cow::Borrowed("#00BFFF"), rgb:(0,191,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "skyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("sky blue"), hex:std::borrow::Cow::Borrowed("#87CEEB"), rgb:(135,206,235), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("light sky blue"), hex:std::borrow::Cow::Borrowed("#87CEFA"), rgb:(135,206,250), aliases:&["ltskyblue"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "steelblue" => crate::Color{ name:std::borrow::Cow::Borrowed("steel blue"), hex:std::borrow::Cow::Borrowed("#4682B4"), rgb:(70,130,180), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightsteelblue" => crate::Color{ name:std::borrow::Cow::Borrowed("light steel blue"), hex:std::borrow::Cow::Borrowed("#B0C4DE"), rgb:(176,196,222), aliases:&["ltsteelblue"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightblue" => crate::Color{ name:std::borrow::Cow::Borrowed("light blue"), hex:std::borrow::Cow::Borrowed("#ADD8E6"), rgb:(173,216,230), aliases:&["ltblue"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "powderblue" => crate::Color{ name:std::borrow::Cow::Borrowed("powder blue"), hex:std::borrow::Cow::Borrowed("#B0E0E6"), rgb:(176,224,230), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "paleturquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("pale turquoise"), hex:std::borrow::Cow::Borrowed("#AFEEEE"), rgb:(175,238,238), aliases:&["paleturqoise", "paleturquise"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkturquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("dark turquoise"), hex:std::borrow::Cow::Borrowed("#00CED1"), rgb:(0,206,209), aliases:&["darkturqoise", "darkturquise", "dkturquoise", "dkturqoise", "dkturquise"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumturquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("medium turquoise"), hex:std::borrow::Cow::Borrowed("#48D1CC"), rgb:(72,209,204), aliases:&["mediumturqoise", "mediumturquise", "medturquoise", "medturqoise", "medturquise"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "turquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("turquoise"), hex:std::borrow::Cow::Borrowed("#40E0D0"), rgb:(64,224,208), aliases:&["turqoise", "turquise"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cyan" => crate::Color{ name:std::borrow::Cow::Borrowed("cyan"), hex:std::borrow::Cow::Borrowed("#00FFFF"), rgb:(0,255,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightcyan" => crate::Color{ name:std::borrow::Cow::Borrowed("light cyan"), hex:std::borrow::Cow::Borrowed("#E0FFFF"), rgb:(224,255,255), aliases:&["ltcyan"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cadetblue" => crate::Color{ name:std::borrow::Cow::Borrowed("cadet blue"), hex:std::borrow::Cow::Borrowed("#5F9EA0"), rgb:(95,158,160), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumaquamarine" => crate::Color{ name:std::borrow::Cow::Borrowed("medium aquamarine"), hex:std::borrow::Cow::Borrowed("#66CDAA"), rgb:(102,205,170), aliases:&["medaquamarine"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "aquamarine" => crate::Color{ name:std::borrow::Cow::Borrowed("aquamarine"), hex:std::borrow::Cow::Borrowed("#7FFFD4"), rgb:(127,255,212), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("dark green"), hex:std::borrow::Cow::Borrowed("#006400"), rgb:(0,100,0), aliases:&["dkgreen"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkolivegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("dark olive green"), hex:std::borrow::Cow::Borrowed("#556B2F"), rgb:(85,107,47), aliases:&["dkolivegreen"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkseagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("dark sea green"), hex:std::borrow::Cow::Borrowed("#8FBC8F"), rgb:(143,188,143), aliases:&["dkseagreen"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "seagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("sea green"), hex:std::borrow::Cow::Borrowed("#2E8B57"), rgb:(46,139,87), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumseagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("medium sea green"), hex:std::borrow::Cow::Borrowed("#3CB371"), rgb:(60,179,113), aliases:&["medseagreen"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightseagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("light sea green"), hex:std::borrow::Cow::Borrowed("#20B2AA"), rgb:(32,178,170), aliases:&["ltseagreen"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("pale green"), hex:std::borrow::Cow::Borrowed("#98FB98"), rgb:(152,251,152), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "springgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("spring green"), hex:std::borrow::Cow::Borrowed("#00FF7F"), rgb:(0,255,127), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lawngreen" => crate::Color{ name:std::borrow::Cow::Borrowed("lawn green"), hex:std::borrow::Cow::Borrowed("#7CFC00"), rgb:(124,252,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "green" => crate::Color{ name:std::borrow::Cow::Borrowed("green"), hex:std::borrow::Cow::Borrowed("#00FF00"), rgb:(0,255,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "chartreuse" => crate::Color{ name:std::borrow::Cow::Borrowed("chartreuse"), hex:std::borrow::Cow::Borrowed("#7FFF00"), rgb:(127,255,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumspringgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("medium spring green"), hex:std::borrow::Cow::Borrowed("#00FA9A"), rgb:(0,250,154), aliases:&["medspringgreen"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "greenyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("green yellow"), hex:std::borrow::Cow::Borrowed("#ADFF2F"), rgb:(173,255,47), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "limegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("lime green"), hex:std::borrow::Cow::Borrowed("#32CD32"), rgb:(50,205,50), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "yellowgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("yellow green"), hex:std::borrow::Cow::Borrowed("#9ACD32"), rgb:(154,205,50), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "forestgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("forest green"), hex:std::borrow::Cow::Borrowed("#228B22"), rgb:(34,139,34), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "olivedrab" => crate::Color{ name:std::borrow::Cow::Borrowed("olive drab"), hex:std::borrow::Cow::Borrowed("#6B8E23"), rgb:(107,142,35), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkkhaki" => crate::Color{ name:std::borrow::Cow::Borrowed("dark khaki"), hex:std::borrow::Cow::Borrowed("#BDB76B"), rgb:(189,183,107), aliases:&["dkkhaki"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "khaki" => crate::Color{ name:std::borrow::Cow::Borrowed("khaki"), hex:std::borrow::Cow::Borrowed("#F0E68C"), rgb:(240,230,140), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palegoldenrod" => crate::Color{ name:std::borrow::Cow::Borrowed("pale goldenrod"), hex:std::borrow::Cow::Borrowed("#EEE8AA"), rgb:(238,232,170), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightgoldenrodyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("light goldenrod yellow"), hex:std::borrow::Cow::Borrowed("#FAFAD2"), rgb:(250,250,210), aliases:&["ltgoldenrodyellow"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("light yellow"), hex:std::borrow::Cow::Borrowed("#FFFFE0"), rgb:(255,255,224), aliases:&["ltyellow"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "yellow" => crate::Color{ name:std::borrow::Cow::Borrowed("yellow"), hex:std::borrow::Cow::Borrowed("#FFFF00"), rgb:(255,255,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gold" => crate::Color{ name:std::borrow::Cow::Borrowed("gold"), hex:std::borrow::Cow::Borrowed("#FFD700"), rgb:(255,215,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightgoldenrod" => crate::Color{ name:std::borrow::Cow::Borrowed("light goldenrod"), hex:std::borrow::Cow::Borrowed("#EEDD82"), rgb:(238,221,130), aliases:&["ltgoldenrod"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "goldenrod" => crate::Color{ name:std::borrow::Cow::Borrowed("goldenrod"), hex:std::borrow::Cow::Borrowed("#DAA520"), rgb:(218,165,32), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkgoldenrod" => crate::Color{ name:std::borrow::Cow::Borrowed("dark goldenrod"), hex:std::borrow::Cow::Borrowed("#B8860B"), rgb:(184,134,11), aliases:&["dkgoldenrod"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "rosybrown" => crate::Color{ name:std::borrow::Cow::Borrowed("rosy brown"), hex:std::borrow::Cow::Borrowed("#BC8F8F"), rgb:(188,143,143), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "indianred" => crate::Color{ name:std::borrow::Cow::Borrowed("indian red"), hex:std::borrow::Cow::Borrowed("#CD5C5C"), rgb:(205,92,92), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "saddlebrown" => crate::Color{ name:std::borrow::Cow::Borrowed("saddle brown"), hex:std::borrow::Cow::Borrowed("#8B4513"), rgb:(139,69,19), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "sienna" => crate::Color{ name:std::borrow::Cow::Borrowed("sienna"), hex:std::borrow::Cow::Borrowed("#A0522D"), rgb:(160,82,45), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "peru" => crate::Color{ name:std::borrow::Cow::Borrowed("peru"), hex:std::borrow::Cow::Borrowed("#CD853F"), rgb:(205,133,63), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "burlywood" => crate::Color{ name:std::borrow::Cow::Borrowed("burlywood"), hex:std::borrow::Cow::Borrowed("#DEB887"), rgb:(222,184,135), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "beige" => crate::Color{ name:std::borrow::Cow::Borrowed("beige"), hex:std::borrow::Cow::Borrowed("#F5F5DC"), rgb:(245,245,220), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "wheat" => crate::Color{ name:std::borrow::Cow::Borrowed("wheat"), hex:std::borrow::Cow::Borrowed("#F5DEB3"), rgb:(245,222,179), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "sandybrown" => crate::Color{ name:std::borrow::Cow::Borrowed("sandy brown"), hex:std::borrow::Cow::Borrowed("#F4A460"), rgb:(244,164,96), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "tan" => crate::Color{ name:std::borrow::Cow::Borrowed("tan"), hex:std::borrow::Cow::Borrowed("#D2B48C"), rgb:(210,180,140), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "chocolate" => crate::Color{ name:std::borrow::Cow::Borrowed("chocolate"), hex:std::borrow::Cow::Borrowed("#D2691E"), rgb:(210,105,30), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "firebrick" => crate::Color{ name:std::borrow::Cow::Borrowed("firebrick"), hex:std::borrow::Cow::Borrowed("#B22222"), rgb:(178,34,34), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "brown" => crate::Color{ name:std::borrow::Cow::Borrowed("brown"), hex:std::borrow::Cow::Borrowed("#A52A2A"), rgb:(165,42,42), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darksalmon" => crate::Color{ name:std::borrow::Cow::Borrowed("dark salmon"), hex:std::borrow::Cow::Borrowed("#E9967A"), rgb:(233,150,122), aliases:&["dksalmon"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "salmon" => crate::Color{ name:std::borrow::Cow::Borrowed("salmon"), hex:std::borrow::Cow::Borrowed("#FA8072"), rgb:(250,128,114), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightsalmon" => crate::Color{ name:std::borrow::Cow::Borrowed("light salmon"), hex:std::borrow::Cow::Borrowed("#FFA07A"), rgb:(255,160,122), aliases:&["ltsalmon"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orange" => crate::Color{ name:std::borrow::Cow::Borrowed("orange"), hex:std::borrow::Cow::Borrowed("#FFA500"), rgb:(255,165,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkorange" => crate::Color{ name:std::borrow::Cow::Borrowed("dark orange"), hex:std::borrow::Cow::Borrowed("#FF8C00"), rgb:(255,140,0), aliases:&["dkorange"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "coral" => crate::Color{ name:std::borrow::Cow::Borrowed("coral"), hex:std::borrow::Cow::Borrowed("#FF7F50"), rgb:(255,127,80), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightcoral" => crate::Color{ name:std::borrow::Cow::Borrowed("light coral"), hex:std::borrow::Cow::Borrowed("#F08080"), rgb:(240,128,128), aliases:&["ltcoral"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "tomato" => crate::Color{ name:std::borrow::Cow::Borrowed("tomato"), hex:std::borrow::Cow::Borrowed("#FF6347"), rgb:(255,99,71), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orangered" => crate::Color{ name:std::borrow::Cow::Borrowed("orange red"), hex:std::borrow::Cow::Borrowed("#FF4500"), rgb:(255,69,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "red" => crate::Color{ name:std::borrow::Cow::Borrowed("red"), hex:std::borrow::Cow::Borrowed("#FF0000"), rgb:(255,0,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "hotpink" => crate::Color{ name:std::borrow::Cow::Borrowed("hot pink"), hex:std::borrow::Cow::Borrowed("#FF69B4"), rgb:(255,105,180), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "deeppink" => crate::Color{ name:std::borrow::Cow::Borrowed("deep pink"), hex:std::borrow::Cow::Borrowed("#FF1493"), rgb:(255,20,147), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "pink" => crate::Color{ name:std::borrow::Cow::Borrowed("pink"), hex:std::borrow::Cow::Borrowed("#FFC0CB"), rgb:(255,192,203), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightpink" => crate::Color{ name:std::borrow::Cow::Borrowed("light pink"), hex:std::borrow::Cow::Borrowed("#FFB6C1"), rgb:(255,182,193), aliases:&["ltpink"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palevioletred" => crate::Color{ name:std::borrow::Cow::Borrowed("pale violet red"), hex:std::borrow::Cow::Borrowed("#DB7093"), rgb:(219,112,147), aliases:&["palevoiletred"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "maroon" => crate::Color{ name:std::borrow::Cow::Borrowed("maroon"), hex:std::borrow::Cow::Borrowed("#B03060"), rgb:(176,48,96), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumvioletred" => crate::Color{ name:std::borrow::Cow::Borrowed("medium violet red"), hex:std::borrow::Cow::Borrowed("#C71585"), rgb:(199,21,133), aliases:&["mediumvoiletred", "medvioletred", "medvoiletred"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "violetred" => crate::Color{ name:std::borrow::Cow::Borrowed("violet red"), hex:std::borrow::Cow::Borrowed("#D02090"), rgb:(208,32,144), aliases:&["voiletred"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "magenta" => crate::Color{ name:std::borrow::Cow::Borrowed("magenta"), hex:std::borrow::Cow::Borrowed("#FF00FF"), rgb:(255,0,255), aliases:&["megenta"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "violet" => crate::Color{ name:std::borrow::Cow::Borrowed("violet"), hex:std::borrow::Cow::Borrowed("#EE82EE"), rgb:(238,130,238), aliases:&["voilet"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "plum" => crate::Color{ name:std::borrow::Cow::Borrowed("plum"), hex:std::borrow::Cow::Borrowed("#DDA0DD"), rgb:(221,160,221), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orchid" => crate::Color{ name:std::borrow::Cow::Borrowed("orchid"), hex:std::borrow::Cow::Borrowed("#DA70D6"), rgb:(218,112,214), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumorchid" => crate::Color{ name:std::borrow::Cow::Borrowed("medium orchid"), hex:std::borrow::Cow::Borrowed("#BA55D3"), rgb:(186,85,211), aliases:&["medorchid"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkorchid" => crate::Color{ name:std::borrow::Cow::Borrowed("dark orchid"), hex:std::borrow::Cow::Borrowed("#9932CC"), rgb:(153,50,204), aliases:&["dkorchid"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("dark violet"), hex:std::borrow::Cow::Borrowed("#9400D3"), rgb:(148,0,211), aliases:&["darkvoilet", "dkviolet", "dkvoilet"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "blueviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("blue violet"), hex:std::borrow::Cow::Borrowed("#8A2BE2"), rgb:(138,43,226), aliases:&["bluevoilet"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "purple" => crate::Color{ name:std::borrow::Cow::Borrowed("purple"), hex:std::borrow::Cow::Borrowed("#A020F0"), rgb:(160,32,240), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("medium purple"), hex:std::borrow::Cow::Borrowed("#9370DB"), rgb:(147,112,219), aliases:&["medpurple"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "thistle" => crate::Color{ name:std::borrow::Cow::Borrowed("thistle"), hex:std::borrow::Cow::Borrowed("#D8BFD8"), rgb:(216,191,216), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "snow1" => crate::Color{ name:std::borrow::Cow::Borrowed("snow1"), hex:std::borrow::Cow::Borrowed("#FFFAFA"), rgb:(255,250,250), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "snow2" => crate::Color{ name:std::borrow::Cow::Borrowed("snow2"), hex:std::borrow::Cow::Borrowed("#EEE9E9"), rgb:(238,233,233), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "snow3" => crate::Color{ name:std::borrow::Cow::Borrowed("snow3"), hex:std::borrow::Cow::Borrowed("#CDC9C9"), rgb:(205,201,201), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "snow4" => crate::Color{ name:std::borrow::Cow::Borrowed("snow4"), hex:std::borrow::Cow::Borrowed("#8B8989"), rgb:(139,137,137), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "seashell1" => crate::Color{ name:std::borrow::Cow::Borrowed("seashell1"), hex:std::borrow::Cow::Borrowed("#FFF5EE"), rgb:(255,245,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "seashell2" => crate::Color{ name:std::borrow::Cow::Borrowed("seashell2"), hex:std::borrow::Cow::Borrowed("#EEE5DE"), rgb:(238,229,222), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "seashell3" => crate::Color{ name:std::borrow::Cow::Borrowed("seashell3"), hex:std::borrow::Cow::Borrowed("#CDC5BF"), rgb:(205,197,191), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "seashell4" => crate::Color{ name:std::borrow::Cow::Borrowed("seashell4"), hex:std::borrow::Cow::Borrowed("#8B8682"), rgb:(139,134,130), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "antiquewhite1" => crate::Color{ name:std::borrow::Cow::Borrowed("AntiqueWhite1"), hex:std::borrow::Cow::Borrowed("#FFEFDB"), rgb:(255,239,219), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "antiquewhite2" => crate::Color{ name:std::borrow::Cow::Borrowed("AntiqueWhite2"), hex:std::borrow::Cow::Borrowed("#EEDFCC"), rgb:(238,223,204), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "antiquewhite3" => crate::Color{ name:std::borrow::Cow::Borrowed("AntiqueWhite3"), hex:std::borrow::Cow::Borrowed("#CDC0B0"), rgb:(205,192,176), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "antiquewhite4" => crate::Color{ name:std::borrow::Cow::Borrowed("AntiqueWhite4"), hex:std::borrow::Cow::Borrowed("#8B8378"), rgb:(139,131,120), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "bisque1" => crate::Color{ name:std::borrow::Cow::Borrowed("bisque1"), hex:std::borrow::Cow::Borrowed("#FFE4C4"), rgb:(255,228,196), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "bisque2" => crate::Color{ name:std::borrow::Cow::Borrowed("bisque2"), hex:std::borrow::Cow::Borrowed("#EED5B7"), rgb:(238,213,183), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "bisque3" => crate::Color{ name:std::borrow::Cow::Borrowed("bisque3"), hex:std::borrow::Cow::Borrowed("#CDB79E"), rgb:(205,183,158), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "bisque4" => crate::Color{ name:std::borrow::Cow::Borrowed("bisque4"), hex:std::borrow::Cow::Borrowed("#8B7D6B"), rgb:(139,125,107), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "peachpuff1" => crate::Color{ name:std::borrow::Cow::Borrowed("PeachPuff1"), hex:std::borrow::Cow::Borrowed("#FFDAB9"), rgb:(255,218,185), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "peachpuff2" => crate::Color{ name:std::borrow::Cow::Borrowed("PeachPuff2"), hex:std::borrow::Cow::Borrowed("#EECBAD"), rgb:(238,203,173), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "peachpuff3" => crate::Color{ name:std::borrow::Cow::Borrowed("PeachPuff3"), hex:std::borrow::Cow::Borrowed("#CDAF95"), rgb:(205,175,149), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "peachpuff4" => crate::Color{ name:std::borrow::Cow::Borrowed("PeachPuff4"), hex:std::borrow::Cow::Borrowed("#8B7765"), rgb:(139,119,101), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "navajowhite1" => crate::Color{ name:std::borrow::Cow::Borrowed("NavajoWhite1"), hex:std::borrow::Cow::Borrowed("#FFDEAD"), rgb:(255,222,173), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "navajowhite2" => crate::Color{ name:std::borrow::Cow::Borrowed("NavajoWhite2"), hex:std::borrow::Cow::Borrowed("#EECFA1"), rgb:(238,207,161), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "navajowhite3" => crate::Color{ name:std::borrow::Cow::Borrowed("NavajoWhite3"), hex:std::borrow::Cow::Borrowed("#CDB38B"), rgb:(205,179,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "navajowhite4" => crate::Color{ name:std::borrow::Cow::Borrowed("NavajoWhite4"), hex:std::borrow::Cow::Borrowed("#8B795E"), rgb:(139,121,94), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lemonchiffon1" => crate::Color{ name:std::borrow::Cow::Borrowed("LemonChiffon1"), hex:std::borrow::Cow::Borrowed("#FFFACD"), rgb:(255,250,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lemonchiffon2" => crate::Color{ name:std::borrow::Cow::Borrowed("LemonChiffon2"), hex:std::borrow::Cow::Borrowed("#EEE9BF"), rgb:(238,233,191), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lemonchiffon3" => crate::Color{ name:std::borrow::Cow::Borrowed("LemonChiffon3"), hex:std::borrow::Cow::Borrowed("#CDC9A5"), rgb:(205,201,165), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lemonchiffon4" => crate::Color{ name:std::borrow::Cow::Borrowed("LemonChiffon4"), hex:std::borrow::Cow::Borrowed("#8B8970"), rgb:(139,137,112), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cornsilk1" => crate::Color{ name:std::borrow::Cow::Borrowed("cornsilk1"), hex:std::borrow::Cow::Borrowed("#FFF8DC"), rgb:(255,248,220), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cornsilk2" => crate::Color{ name:std::borrow::Cow::Borrowed("cornsilk2"), hex:std::borrow::Cow::Borrowed("#EEE8CD"), rgb:(238,232,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cornsilk3" => crate::Color{ name:std::borrow::Cow::Borrowed("cornsilk3"), hex:std::borrow::Cow::Borrowed("#CDC8B1"), rgb:(205,200,177), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cornsilk4" => crate::Color{ name:std::borrow::Cow::Borrowed("cornsilk4"), hex:std::borrow::Cow::Borrowed("#8B8878"), rgb:(139,136,120), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "ivory1" => crate::Color{ name:std::borrow::Cow::Borrowed("ivory1"), hex:std::borrow::Cow::Borrowed("#FFFFF0"), rgb:(255,255,240), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "ivory2" => crate::Color{ name:std::borrow::Cow::Borrowed("ivory2"), hex:std::borrow::Cow::Borrowed("#EEEEE0"), rgb:(238,238,224), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "ivory3" => crate::Color{ name:std::borrow::Cow::Borrowed("ivory3"), hex:std::borrow::Cow::Borrowed("#CDCDC1"), rgb:(205,205,193), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "ivory4" => crate::Color{ name:std::borrow::Cow::Borrowed("ivory4"), hex:std::borrow::Cow::Borrowed("#8B8B83"), rgb:(139,139,131), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "honeydew1" => crate::Color{ name:std::borrow::Cow::Borrowed("honeydew1"), hex:std::borrow::Cow::Borrowed("#F0FFF0"), rgb:(240,255,240), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "honeydew2" => crate::Color{ name:std::borrow::Cow::Borrowed("honeydew2"), hex:std::borrow::Cow::Borrowed("#E0EEE0"), rgb:(224,238,224), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "honeydew3" => crate::Color{ name:std::borrow::Cow::Borrowed("honeydew3"), hex:std::borrow::Cow::Borrowed("#C1CDC1"), rgb:(193,205,193), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "honeydew4" => crate::Color{ name:std::borrow::Cow::Borrowed("honeydew4"), hex:std::borrow::Cow::Borrowed("#838B83"), rgb:(131,139,131), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lavenderblush1" => crate::Color{ name:std::borrow::Cow::Borrowed("LavenderBlush1"), hex:std::borrow::Cow::Borrowed("#FFF0F5"), rgb:(255,240,245), aliases:&["lavendarblush1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lavenderblush2" => crate::Color{ name:std::borrow::Cow::Borrowed("LavenderBlush2"), hex:std::borrow::Cow::Borrowed("#EEE0E5"), rgb:(238,224,229), aliases:&["lavendarblush2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lavenderblush3" => crate::Color{ name:std::borrow::Cow::Borrowed("LavenderBlush3"), hex:std::borrow::Cow::Borrowed("#CDC1C5"), rgb:(205,193,197), aliases:&["lavendarblush3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lavenderblush4" => crate::Color{ name:std::borrow::Cow::Borrowed("LavenderBlush4"), hex:std::borrow::Cow::Borrowed("#8B8386"), rgb:(139,131,134), aliases:&["lavendarblush4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mistyrose1" => crate::Color{ name:std::borrow::Cow::Borrowed("MistyRose1"), hex:std::borrow::Cow::Borrowed("#FFE4E1"), rgb:(255,228,225), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mistyrose2" => crate::Color{ name:std::borrow::Cow::Borrowed("MistyRose2"), hex:std::borrow::Cow::Borrowed("#EED5D2"), rgb:(238,213,210), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mistyrose3" => crate::Color{ name:std::borrow::Cow::Borrowed("MistyRose3"), hex:std::borrow::Cow::Borrowed("#CDB7B5"), rgb:(205,183,181), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mistyrose4" => crate::Color{ name:std::borrow::Cow::Borrowed("MistyRose4"), hex:std::borrow::Cow::Borrowed("#8B7D7B"), rgb:(139,125,123), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "azure1" => crate::Color{ name:std::borrow::Cow::Borrowed("azure1"), hex:std::borrow::Cow::Borrowed("#F0FFFF"), rgb:(240,255,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "azure2" => crate::Color{ name:std::borrow::Cow::Borrowed("azure2"), hex:std::borrow::Cow::Borrowed("#E0EEEE"), rgb:(224,238,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "azure3" => crate::Color{ name:std::borrow::Cow::Borrowed("azure3"), hex:std::borrow::Cow::Borrowed("#C1CDCD"), rgb:(193,205,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "azure4" => crate::Color{ name:std::borrow::Cow::Borrowed("azure4"), hex:std::borrow::Cow::Borrowed("#838B8B"), rgb:(131,139,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slateblue1" => crate::Color{ name:std::borrow::Cow::Borrowed("SlateBlue1"), hex:std::borrow::Cow::Borrowed("#836FFF"), rgb:(131,111,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slateblue2" => crate::Color{ name:std::borrow::Cow::Borrowed("SlateBlue2"), hex:std::borrow::Cow::Borrowed("#7A67EE"), rgb:(122,103,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slateblue3" => crate::Color{ name:std::borrow::Cow::Borrowed("SlateBlue3"), hex:std::borrow::Cow::Borrowed("#6959CD"), rgb:(105,89,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slateblue4" => crate::Color{ name:std::borrow::Cow::Borrowed("SlateBlue4"), hex:std::borrow::Cow::Borrowed("#473C8B"), rgb:(71,60,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "royalblue1" => crate::Color{ name:std::borrow::Cow::Borrowed("RoyalBlue1"), hex:std::borrow::Cow::Borrowed("#4876FF"), rgb:(72,118,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "royalblue2" => crate::Color{ name:std::borrow::Cow::Borrowed("RoyalBlue2"), hex:std::borrow::Cow::Borrowed("#436EEE"), rgb:(67,110,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "royalblue3" => crate::Color{ name:std::borrow::Cow::Borrowed("RoyalBlue3"), hex:std::borrow::Cow::Borrowed("#3A5FCD"), rgb:(58,95,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "royalblue4" => crate::Color{ name:std::borrow::Cow::Borrowed("RoyalBlue4"), hex:std::borrow::Cow::Borrowed("#27408B"), rgb:(39,64,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "blue1" => crate::Color{ name:std::borrow::Cow::Borrowed("blue1"), hex:std::borrow::Cow::Borrowed("#0000FF"), rgb:(0,0,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "blue2" => crate::Color{ name:std::borrow::Cow::Borrowed("blue2"), hex:std::borrow::Cow::Borrowed("#0000EE"), rgb:(0,0,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "blue3" => crate::Color{ name:std::borrow::Cow::Borrowed("blue3"), hex:std::borrow::Cow::Borrowed("#0000CD"), rgb:(0,0,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "blue4" => crate::Color{ name:std::borrow::Cow::Borrowed("blue4"), hex:std::borrow::Cow::Borrowed("#00008B"), rgb:(0,0,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "dodgerblue1" => crate::Color{ name:std::borrow::Cow::Borrowed("DodgerBlue1"), hex:std::borrow::Cow::Borrowed("#1E90FF"), rgb:(30,144,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "dodgerblue2" => crate::Color{ name:std::borrow::Cow::Borrowed("DodgerBlue2"), hex:std::borrow::Cow::Borrowed("#1C86EE"), rgb:(28,134,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "dodgerblue3" => crate::Color{ name:std::borrow::Cow::Borrowed("DodgerBlue3"), hex:std::borrow::Cow::Borrowed("#1874CD"), rgb:(24,116,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "dodgerblue4" => crate::Color{ name:std::borrow::Cow::Borrowed("DodgerBlue4"), hex:std::borrow::Cow::Borrowed("#104E8B"), rgb:(16,78,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "steelblue1" => crate::Color{ name:std::borrow::Cow::Borrowed("SteelBlue1"), hex:std::borrow::Cow::Borrowed("#63B8FF"), rgb:(99,184,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "steelblue2" => crate::Color{ name:std::borrow::Cow::Borrowed("SteelBlue2"), hex:std::borrow::Cow::Borrowed("#5CACEE"), rgb:(92,172,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "steelblue3" => crate::Color{ name:std::borrow::Cow::Borrowed("SteelBlue3"), hex:std::borrow::Cow::Borrowed("#4F94CD"), rgb:(79,148,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "steelblue4" => crate::Color{ name:std::borrow::Cow::Borrowed("SteelBlue4"), hex:std::borrow::Cow::Borrowed("#36648B"), rgb:(54,100,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "deepskyblue1" => crate::Color{ name:std::borrow::Cow::Borrowed("DeepSkyBlue1"), hex:std::borrow::Cow::Borrowed("#00BFFF"), rgb:(0,191,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "deepskyblue2" => crate::Color{ name:std::borrow::Cow::Borrowed("DeepSkyBlue2"), hex:std::borrow::Cow::Borrowed("#00B2EE"), rgb:(0,178,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "deepskyblue3" => crate::Color{ name:std::borrow::Cow::Borrowed("DeepSkyBlue3"), hex:std::borrow::Cow::Borrowed("#009ACD"), rgb:(0,154,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "deepskyblue4" => crate::Color{ name:std::borrow::Cow::Borrowed("DeepSkyBlue4"), hex:std::borrow::Cow::Borrowed("#00688B"), rgb:(0,104,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "skyblue1" => crate::Color{ name:std::borrow::Cow::Borrowed("SkyBlue1"), hex:std::borrow::Cow::Borrowed("#87CEFF"), rgb:(135,206,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "skyblue2" => crate::Color{ name:std::borrow::Cow::Borrowed("SkyBlue2"), hex:std::borrow::Cow::Borrowed("#7EC0EE"), rgb:(126,192,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "skyblue3" => crate::Color{ name:std::borrow::Cow::Borrowed("SkyBlue3"), hex:std::borrow::Cow::Borrowed("#6CA6CD"), rgb:(108,166,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "skyblue4" => crate::Color{ name:std::borrow::Cow::Borrowed("SkyBlue4"), hex:std::borrow::Cow::Borrowed("#4A708B"), rgb:(74,112,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightskyblue1" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSkyBlue1"), hex:std::borrow::Cow::Borrowed("#B0E2FF"), rgb:(176,226,255), aliases:&["ltskyblue1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightskyblue2" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSkyBlue2"), hex:std::borrow::Cow::Borrowed("#A4D3EE"), rgb:(164,211,238), aliases:&["ltskyblue2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightskyblue3" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSkyBlue3"), hex:std::borrow::Cow::Borrowed("#8DB6CD"), rgb:(141,182,205), aliases:&["ltskyblue3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightskyblue4" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSkyBlue4"), hex:std::borrow::Cow::Borrowed("#607B8B"), rgb:(96,123,139), aliases:&["ltskyblue4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slategray1" => crate::Color{ name:std::borrow::Cow::Borrowed("SlateGray1"), hex:std::borrow::Cow::Borrowed("#C6E2FF"), rgb:(198,226,255), aliases:&["slategrey1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slategray2" => crate::Color{ name:std::borrow::Cow::Borrowed("SlateGray2"), hex:std::borrow::Cow::Borrowed("#B9D3EE"), rgb:(185,211,238), aliases:&["slategrey2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slategray3" => crate::Color{ name:std::borrow::Cow::Borrowed("SlateGray3"), hex:std::borrow::Cow::Borrowed("#9FB6CD"), rgb:(159,182,205), aliases:&["slategrey3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slategray4" => crate::Color{ name:std::borrow::Cow::Borrowed("SlateGray4"), hex:std::borrow::Cow::Borrowed("#6C7B8B"), rgb:(108,123,139), aliases:&["slategrey4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightsteelblue1" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSteelBlue1"), hex:std::borrow::Cow::Borrowed("#CAE1FF"), rgb:(202,225,255), aliases:&["ltsteelblue1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightsteelblue2" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSteelBlue2"), hex:std::borrow::Cow::Borrowed("#BCD2EE"), rgb:(188,210,238), aliases:&["ltsteelblue2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightsteelblue3" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSteelBlue3"), hex:std::borrow::Cow::Borrowed("#A2B5CD"), rgb:(162,181,205), aliases:&["ltsteelblue3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightsteelblue4" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSteelBlue4"), hex:std::borrow::Cow::Borrowed("#6E7B8B"), rgb:(110,123,139), aliases:&["ltsteelblue4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightblue1" => crate::Color{ name:std::borrow::Cow::Borrowed("LightBlue1"), hex:std::borrow::Cow::Borrowed("#BFEFFF"), rgb:(191,239,255), aliases:&["ltblue1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightblue2" => crate::Color{ name:std::borrow::Cow::Borrowed("LightBlue2"), hex:std::borrow::Cow::Borrowed("#B2DFEE"), rgb:(178,223,238), aliases:&["ltblue2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightblue3" => crate::Color{ name:std::borrow::Cow::Borrowed("LightBlue3"), hex:std::borrow::Cow::Borrowed("#9AC0CD"), rgb:(154,192,205), aliases:&["ltblue3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightblue4" => crate::Color{ name:std::borrow::Cow::Borrowed("LightBlue4"), hex:std::borrow::Cow::Borrowed("#68838B"), rgb:(104,131,139), aliases:&["ltblue4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightcyan1" => crate::Color{ name:std::borrow::Cow::Borrowed("LightCyan1"), hex:std::borrow::Cow::Borrowed("#E0FFFF"), rgb:(224,255,255), aliases:&["ltcyan1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightcyan2" => crate::Color{ name:std::borrow::Cow::Borrowed("LightCyan2"), hex:std::borrow::Cow::Borrowed("#D1EEEE"), rgb:(209,238,238), aliases:&["ltcyan2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightcyan3" => crate::Color{ name:std::borrow::Cow::Borrowed("LightCyan3"), hex:std::borrow::Cow::Borrowed("#B4CDCD"), rgb:(180,205,205), aliases:&["ltcyan3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightcyan4" => crate::Color{ name:std::borrow::Cow::Borrowed("LightCyan4"), hex:std::borrow::Cow::Borrowed("#7A8B8B"), rgb:(122,139,139), aliases:&["ltcyan4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "paleturquoise1" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleTurquoise1"), hex:std::borrow::Cow::Borrowed("#BBFFFF"), rgb:(187,255,255), aliases:&["paleturqoise1", "paleturquise1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "paleturquoise2" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleTurquoise2"), hex:std::borrow::Cow::Borrowed("#AEEEEE"), rgb:(174,238,238), aliases:&["paleturqoise2", "paleturquise2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "paleturquoise3" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleTurquoise3"), hex:std::borrow::Cow::Borrowed("#96CDCD"), rgb:(150,205,205), aliases:&["paleturqoise3", "paleturquise3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "paleturquoise4" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleTurquoise4"), hex:std::borrow::Cow::Borrowed("#668B8B"), rgb:(102,139,139), aliases:&["paleturqoise4", "paleturquise4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cadetblue1" => crate::Color{ name:std::borrow::Cow::Borrowed("CadetBlue1"), hex:std::borrow::Cow::Borrowed("#98F5FF"), rgb:(152,245,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cadetblue2" => crate::Color{ name:std::borrow::Cow::Borrowed("CadetBlue2"), hex:std::borrow::Cow::Borrowed("#8EE5EE"), rgb:(142,229,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cadetblue3" => crate::Color{ name:std::borrow::Cow::Borrowed("CadetBlue3"), hex:std::borrow::Cow::Borrowed("#7AC5CD"), rgb:(122,197,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cadetblue4" => crate::Color{ name:std::borrow::Cow::Borrowed("CadetBlue4"), hex:std::borrow::Cow::Borrowed("#53868B"), rgb:(83,134,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "turquoise1" => crate::Color{ name:std::borrow::Cow::Borrowed("turquoise1"), hex:std::borrow::Cow::Borrowed("#00F5FF"), rgb:(0,245,255), aliases:&["turqoise1", "turquise1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "turquoise2" => crate::Color{ name:std::borrow::Cow::Borrowed("turquoise2"), hex:std::borrow::Cow::Borrowed("#00E5EE"), rgb:(0,229,238), aliases:&["turqoise2", "turquise2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "turquoise3" => crate::Color{ name:std::borrow::Cow::Borrowed("turquoise3"), hex:std::borrow::Cow::Borrowed("#00C5CD"), rgb:(0,197,205), aliases:&["turqoise3", "turquise3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "turquoise4" => crate::Color{ name:std::borrow::Cow::Borrowed("turquoise4"), hex:std::borrow::Cow::Borrowed("#00868B"), rgb:(0,134,139), aliases:&["turqoise4", "turquise4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cyan1" => crate::Color{ name:std::borrow::Cow::Borrowed("cyan1"), hex:std::borrow::Cow::Borrowed("#00FFFF"), rgb:(0,255,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cyan2" => crate::Color{ name:std::borrow::Cow::Borrowed("cyan2"), hex:std::borrow::Cow::Borrowed("#00EEEE"), rgb:(0,238,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cyan3" => crate::Color{ name:std::borrow::Cow::Borrowed("cyan3"), hex:std::borrow::Cow::Borrowed("#00CDCD"), rgb:(0,205,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cyan4" => crate::Color{ name:std::borrow::Cow::Borrowed("cyan4"), hex:std::borrow::Cow::Borrowed("#008B8B"), rgb:(0,139,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkslategray1" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkSlateGray1"), hex:std::borrow::Cow::Borrowed("#97FFFF"), rgb:(151,255,255), aliases:&["darkslategrey1", "dkslategray1", "dkslategrey1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkslategray2" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkSlateGray2"), hex:std::borrow::Cow::Borrowed("#8DEEEE"), rgb:(141,238,238), aliases:&["darkslategrey2", "dkslategray2", "dkslategrey2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkslategray3" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkSlateGray3"), hex:std::borrow::Cow::Borrowed("#79CDCD"), rgb:(121,205,205), aliases:&["darkslategrey3", "dkslategray3", "dkslategrey3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkslategray4" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkSlateGray4"), hex:std::borrow::Cow::Borrowed("#528B8B"), rgb:(82,139,139), aliases:&["darkslategrey4", "dkslategray4", "dkslategrey4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "aquamarine1" => crate::Color{ name:std::borrow::Cow::Borrowed("aquamarine1"), hex:std::borrow::Cow::Borrowed("#7FFFD4"), rgb:(127,255,212), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "aquamarine2" => crate::Color{ name:std::borrow::Cow::Borrowed("aquamarine2"), hex:std::borrow::Cow::Borrowed("#76EEC6"), rgb:(118,238,198), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "aquamarine3" => crate::Color{ name:std::borrow::Cow::Borrowed("aquamarine3"), hex:std::borrow::Cow::Borrowed("#66CDAA"), rgb:(102,205,170), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "aquamarine4" => crate::Color{ name:std::borrow::Cow::Borrowed("aquamarine4"), hex:std::borrow::Cow::Borrowed("#458B74"), rgb:(69,139,116), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkseagreen1" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkSeaGreen1"), hex:std::borrow::Cow::Borrowed("#C1FFC1"), rgb:(193,255,193), aliases:&["dkseagreen1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkseagreen2" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkSeaGreen2"), hex:std::borrow::Cow::Borrowed("#B4EEB4"), rgb:(180,238,180), aliases:&["dkseagreen2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkseagreen3" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkSeaGreen3"), hex:std::borrow::Cow::Borrowed("#9BCD9B"), rgb:(155,205,155), aliases:&["dkseagreen3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkseagreen4" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkSeaGreen4"), hex:std::borrow::Cow::Borrowed("#698B69"), rgb:(105,139,105), aliases:&["dkseagreen4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "seagreen1" => crate::Color{ name:std::borrow::Cow::Borrowed("SeaGreen1"), hex:std::borrow::Cow::Borrowed("#54FF9F"), rgb:(84,255,159), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "seagreen2" => crate::Color{ name:std::borrow::Cow::Borrowed("SeaGreen2"), hex:std::borrow::Cow::Borrowed("#4EEE94"), rgb:(78,238,148), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "seagreen3" => crate::Color{ name:std::borrow::Cow::Borrowed("SeaGreen3"), hex:std::borrow::Cow::Borrowed("#43CD80"), rgb:(67,205,128), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "seagreen4" => crate::Color{ name:std::borrow::Cow::Borrowed("SeaGreen4"), hex:std::borrow::Cow::Borrowed("#2E8B57"), rgb:(46,139,87), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palegreen1" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleGreen1"), hex:std::borrow::Cow::Borrowed("#9AFF9A"), rgb:(154,255,154), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palegreen2" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleGreen2"), hex:std::borrow::Cow::Borrowed("#90EE90"), rgb:(144,238,144), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palegreen3" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleGreen3"), hex:std::borrow::Cow::Borrowed("#7CCD7C"), rgb:(124,205,124), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palegreen4" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleGreen4"), hex:std::borrow::Cow::Borrowed("#548B54"), rgb:(84,139,84), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "springgreen1" => crate::Color{ name:std::borrow::Cow::Borrowed("SpringGreen1"), hex:std::borrow::Cow::Borrowed("#00FF7F"), rgb:(0,255,127), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "springgreen2" => crate::Color{ name:std::borrow::Cow::Borrowed("SpringGreen2"), hex:std::borrow::Cow::Borrowed("#00EE76"), rgb:(0,238,118), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "springgreen3" => crate::Color{ name:std::borrow::Cow::Borrowed("SpringGreen3"), hex:std::borrow::Cow::Borrowed("#00CD66"), rgb:(0,205,102), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "springgreen4" => crate::Color{ name:std::borrow::Cow::Borrowed("SpringGreen4"), hex:std::borrow::Cow::Borrowed("#008B45"), rgb:(0,139,69), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "green1" => crate::Color{ name:std::borrow::Cow::Borrowed("green1"), hex:std::borrow::Cow::Borrowed("#00FF00"), rgb:(0,255,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "green2" => crate::Color{ name:std::borrow::Cow::Borrowed("green2"), hex:std::borrow::Cow::Borrowed("#00EE00"), rgb:(0,238,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "green3" => crate::Color{ name:std::borrow::Cow::Borrowed("green3"), hex:std::borrow::Cow::Borrowed("#00CD00"), rgb:(0,205,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "green4" => crate::Color{ name:std::borrow::Cow::Borrowed("green4"), hex:std::borrow::Cow::Borrowed("#008B00"), rgb:(0,139,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "chartreuse1" => crate::Color{ name:std::borrow::Cow::Borrowed("chartreuse1"), hex:std::borrow::Cow::Borrowed("#7FFF00"), rgb:(127,255,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "chartreuse2" => crate::Color{ name:std::borrow::Cow::Borrowed("chartreuse2"), hex:std::borrow::Cow::Borrowed("#76EE00"), rgb:(118,238,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "chartreuse3" => crate::Color{ name:std::borrow::Cow::Borrowed("chartreuse3"), hex:std::borrow::Cow::Borrowed("#66CD00"), rgb:(102,205,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "chartreuse4" => crate::Color{ name:std::borrow::Cow::Borrowed("chartreuse4"), hex:std::borrow::Cow::Borrowed("#458B00"), rgb:(69,139,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "olivedrab1" => crate::Color{ name:std::borrow::Cow::Borrowed("OliveDrab1"), hex:std::borrow::Cow::Borrowed("#C0FF3E"), rgb:(192,255,62), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "olivedrab2" => crate::Color{ name:std::borrow::Cow::Borrowed("OliveDrab2"), hex:std::borrow::Cow::Borrowed("#B3EE3A"), rgb:(179,238,58), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "olivedrab3" => crate::Color{ name:std::borrow::Cow::Borrowed("OliveDrab3"), hex:std::borrow::Cow::Borrowed("#9ACD32"), rgb:(154,205,50), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "olivedrab4" => crate::Color{ name:std::borrow::Cow::Borrowed("OliveDrab4"), hex:std::borrow::Cow::Borrowed("#698B22"), rgb:(105,139,34), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkolivegreen1" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOliveGreen1"), hex:std::borrow::Cow::Borrowed("#CAFF70"), rgb:(202,255,112), aliases:&["dkolivegreen1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkolivegreen2" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOliveGreen2"), hex:std::borrow::Cow::Borrowed("#BCEE68"), rgb:(188,238,104), aliases:&["dkolivegreen2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkolivegreen3" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOliveGreen3"), hex:std::borrow::Cow::Borrowed("#A2CD5A"), rgb:(162,205,90), aliases:&["dkolivegreen3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkolivegreen4" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOliveGreen4"), hex:std::borrow::Cow::Borrowed("#6E8B3D"), rgb:(110,139,61), aliases:&["dkolivegreen4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "khaki1" => crate::Color{ name:std::borrow::Cow::Borrowed("khaki1"), hex:std::borrow::Cow::Borrowed("#FFF68F"), rgb:(255,246,143), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "khaki2" => crate::Color{ name:std::borrow::Cow::Borrowed("khaki2"), hex:std::borrow::Cow::Borrowed("#EEE685"), rgb:(238,230,133), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "khaki3" => crate::Color{ name:std::borrow::Cow::Borrowed("khaki3"), hex:std::borrow::Cow::Borrowed("#CDC673"), rgb:(205,198,115), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "khaki4" => crate::Color{ name:std::borrow::Cow::Borrowed("khaki4"), hex:std::borrow::Cow::Borrowed("#8B864E"), rgb:(139,134,78), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightgoldenrod1" => crate::Color{ name:std::borrow::Cow::Borrowed("LightGoldenrod1"), hex:std::borrow::Cow::Borrowed("#FFEC8B"), rgb:(255,236,139), aliases:&["ltgoldenrod1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightgoldenrod2" => crate::Color{ name:std::borrow::Cow::Borrowed("LightGoldenrod2"), hex:std::borrow::Cow::Borrowed("#EEDC82"), rgb:(238,220,130), aliases:&["ltgoldenrod2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightgoldenrod3" => crate::Color{ name:std::borrow::Cow::Borrowed("LightGoldenrod3"), hex:std::borrow::Cow::Borrowed("#CDBE70"), rgb:(205,190,112), aliases:&["ltgoldenrod3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightgoldenrod4" => crate::Color{ name:std::borrow::Cow::Borrowed("LightGoldenrod4"), hex:std::borrow::Cow::Borrowed("#8B814C"), rgb:(139,129,76), aliases:&["ltgoldenrod4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightyellow1" => crate::Color{ name:std::borrow::Cow::Borrowed("LightYellow1"), hex:std::borrow::Cow::Borrowed("#FFFFE0"), rgb:(255,255,224), aliases:&["ltyellow1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightyellow2" => crate::Color{ name:std::borrow::Cow::Borrowed("LightYellow2"), hex:std::borrow::Cow::Borrowed("#EEEED1"), rgb:(238,238,209), aliases:&["ltyellow2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightyellow3" => crate::Color{ name:std::borrow::Cow::Borrowed("LightYellow3"), hex:std::borrow::Cow::Borrowed("#CDCDB4"), rgb:(205,205,180), aliases:&["ltyellow3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightyellow4" => crate::Color{ name:std::borrow::Cow::Borrowed("LightYellow4"), hex:std::borrow::Cow::Borrowed("#8B8B7A"), rgb:(139,139,122), aliases:&["ltyellow4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "yellow1" => crate::Color{ name:std::borrow::Cow::Borrowed("yellow1"), hex:std::borrow::Cow::Borrowed("#FFFF00"), rgb:(255,255,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "yellow2" => crate::Color{ name:std::borrow::Cow::Borrowed("yellow2"), hex:std::borrow::Cow::Borrowed("#EEEE00"), rgb:(238,238,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "yellow3" => crate::Color{ name:std::borrow::Cow::Borrowed("yellow3"), hex:std::borrow::Cow::Borrowed("#CDCD00"), rgb:(205,205,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "yellow4" => crate::Color{ name:std::borrow::Cow::Borrowed("yellow4"), hex:std::borrow::Cow::Borrowed("#8B8B00"), rgb:(139,139,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gold1" => crate::Color{ name:std::borrow::Cow::Borrowed("gold1"), hex:std::borrow::Cow::Borrowed("#FFD700"), rgb:(255,215,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gold2" => crate::Color{ name:std::borrow::Cow::Borrowed("gold2"), hex:std::borrow::Cow::Borrowed("#EEC900"), rgb:(238,201,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gold3" => crate::Color{ name:std::borrow::Cow::Borrowed("gold3"), hex:std::borrow::Cow::Borrowed("#CDAD00"), rgb:(205,173,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gold4" => crate::Color{ name:std::borrow::Cow::Borrowed("gold4"), hex:std::borrow::Cow::Borrowed("#8B7500"), rgb:(139,117,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "goldenrod1" => crate::Color{ name:std::borrow::Cow::Borrowed("goldenrod1"), hex:std::borrow::Cow::Borrowed("#FFC125"), rgb:(255,193,37), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "goldenrod2" => crate::Color{ name:std::borrow::Cow::Borrowed("goldenrod2"), hex:std::borrow::Cow::Borrowed("#EEB422"), rgb:(238,180,34), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "goldenrod3" => crate::Color{ name:std::borrow::Cow::Borrowed("goldenrod3"), hex:std::borrow::Cow::Borrowed("#CD9B1D"), rgb:(205,155,29), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "goldenrod4" => crate::Color{ name:std::borrow::Cow::Borrowed("goldenrod4"), hex:std::borrow::Cow::Borrowed("#8B6914"), rgb:(139,105,20), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkgoldenrod1" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkGoldenrod1"), hex:std::borrow::Cow::Borrowed("#FFB90F"), rgb:(255,185,15), aliases:&["dkgoldenrod1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkgoldenrod2" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkGoldenrod2"), hex:std::borrow::Cow::Borrowed("#EEAD0E"), rgb:(238,173,14), aliases:&["dkgoldenrod2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkgoldenrod3" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkGoldenrod3"), hex:std::borrow::Cow::Borrowed("#CD950C"), rgb:(205,149,12), aliases:&["dkgoldenrod3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkgoldenrod4" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkGoldenrod4"), hex:std::borrow::Cow::Borrowed("#8B6508"), rgb:(139,101,8), aliases:&["dkgoldenrod4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "rosybrown1" => crate::Color{ name:std::borrow::Cow::Borrowed("RosyBrown1"), hex:std::borrow::Cow::Borrowed("#FFC1C1"), rgb:(255,193,193), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "rosybrown2" => crate::Color{ name:std::borrow::Cow::Borrowed("RosyBrown2"), hex:std::borrow::Cow::Borrowed("#EEB4B4"), rgb:(238,180,180), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "rosybrown3" => crate::Color{ name:std::borrow::Cow::Borrowed("RosyBrown3"), hex:std::borrow::Cow::Borrowed("#CD9B9B"), rgb:(205,155,155), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "rosybrown4" => crate::Color{ name:std::borrow::Cow::Borrowed("RosyBrown4"), hex:std::borrow::Cow::Borrowed("#8B6969"), rgb:(139,105,105), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "indianred1" => crate::Color{ name:std::borrow::Cow::Borrowed("IndianRed1"), hex:std::borrow::Cow::Borrowed("#FF6A6A"), rgb:(255,106,106), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "indianred2" => crate::Color{ name:std::borrow::Cow::Borrowed("IndianRed2"), hex:std::borrow::Cow::Borrowed("#EE6363"), rgb:(238,99,99), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "indianred3" => crate::Color{ name:std::borrow::Cow::Borrowed("IndianRed3"), hex:std::borrow::Cow::Borrowed("#CD5555"), rgb:(205,85,85), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "indianred4" => crate::Color{ name:std::borrow::Cow::Borrowed("IndianRed4"), hex:std::borrow::Cow::Borrowed("#8B3A3A"), rgb:(139,58,58), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "sienna1" => crate::Color{ name:std::borrow::Cow::Borrowed("sienna1"), hex:std::borrow::Cow::Borrowed("#FF8247"), rgb:(255,130,71), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "sienna2" => crate::Color{ name:std::borrow::Cow::Borrowed("sienna2"), hex:std::borrow::Cow::Borrowed("#EE7942"), rgb:(238,121,66), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "sienna3" => crate::Color{ name:std::borrow::Cow::Borrowed("sienna3"), hex:std::borrow::Cow::Borrowed("#CD6839"), rgb:(205,104,57), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "sienna4" => crate::Color{ name:std::borrow::Cow::Borrowed("sienna4"), hex:std::borrow::Cow::Borrowed("#8B4726"), rgb:(139,71,38), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "burlywood1" => crate::Color{ name:std::borrow::Cow::Borrowed("burlywood1"), hex:std::borrow::Cow::Borrowed("#FFD39B"), rgb:(255,211,155), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "burlywood2" => crate::Color{ name:std::borrow::Cow::Borrowed("burlywood2"), hex:std::borrow::Cow::Borrowed("#EEC591"), rgb:(238,197,145), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "burlywood3" => crate::Color{ name:std::borrow::Cow::Borrowed("burlywood3"), hex:std::borrow::Cow::Borrowed("#CDAA7D"), rgb:(205,170,125), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "burlywood4" => crate::Color{ name:std::borrow::Cow::Borrowed("burlywood4"), hex:std::borrow::Cow::Borrowed("#8B7355"), rgb:(139,115,85), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "wheat1" => crate::Color{ name:std::borrow::Cow::Borrowed("wheat1"), hex:std::borrow::Cow::Borrowed("#FFE7BA"), rgb:(255,231,186), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "wheat2" => crate::Color{ name:std::borrow::Cow::Borrowed("wheat2"), hex:std::borrow::Cow::Borrowed("#EED8AE"), rgb:(238,216,174), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "wheat3" => crate::Color{ name:std::borrow::Cow::Borrowed("wheat3"), hex:std::borrow::Cow::Borrowed("#CDBA96"), rgb:(205,186,150), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "wheat4" => crate::Color{ name:std::borrow::Cow::Borrowed("wheat4"), hex:std::borrow::Cow::Borrowed("#8B7E66"), rgb:(139,126,102), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "tan1" => crate::Color{ name:std::borrow::Cow::Borrowed("tan1"), hex:std::borrow::Cow::Borrowed("#FFA54F"), rgb:(255,165,79), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "tan2" => crate::Color{ name:std::borrow::Cow::Borrowed("tan2"), hex:std::borrow::Cow::Borrowed("#EE9A49"), rgb:(238,154,73), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "tan3" => crate::Color{ name:std::borrow::Cow::Borrowed("tan3"), hex:std::borrow::Cow::Borrowed("#CD853F"), rgb:(205,133,63), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "tan4" => crate::Color{ name:std::borrow::Cow::Borrowed("tan4"), hex:std::borrow::Cow::Borrowed("#8B5A2B"), rgb:(139,90,43), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "chocolate1" => crate::Color{ name:std::borrow::Cow::Borrowed("chocolate1"), hex:std::borrow::Cow::Borrowed("#FF7F24"), rgb:(255,127,36), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "chocolate2" => crate::Color{ name:std::borrow::Cow::Borrowed("chocolate2"), hex:std::borrow::Cow::Borrowed("#EE7621"), rgb:(238,118,33), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "chocolate3" => crate::Color{ name:std::borrow::Cow::Borrowed("chocolate3"), hex:std::borrow::Cow::Borrowed("#CD661D"), rgb:(205,102,29), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "chocolate4" => crate::Color{ name:std::borrow::Cow::Borrowed("chocolate4"), hex:std::borrow::Cow::Borrowed("#8B4513"), rgb:(139,69,19), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "firebrick1" => crate::Color{ name:std::borrow::Cow::Borrowed("firebrick1"), hex:std::borrow::Cow::Borrowed("#FF3030"), rgb:(255,48,48), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "firebrick2" => crate::Color{ name:std::borrow::Cow::Borrowed("firebrick2"), hex:std::borrow::Cow::Borrowed("#EE2C2C"), rgb:(238,44,44), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "firebrick3" => crate::Color{ name:std::borrow::Cow::Borrowed("firebrick3"), hex:std::borrow::Cow::Borrowed("#CD2626"), rgb:(205,38,38), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "firebrick4" => crate::Color{ name:std::borrow::Cow::Borrowed("firebrick4"), hex:std::borrow::Cow::Borrowed("#8B1A1A"), rgb:(139,26,26), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "brown1" => crate::Color{ name:std::borrow::Cow::Borrowed("brown1"), hex:std::borrow::Cow::Borrowed("#FF4040"), rgb:(255,64,64), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "brown2" => crate::Color{ name:std::borrow::Cow::Borrowed("brown2"), hex:std::borrow::Cow::Borrowed("#EE3B3B"), rgb:(238,59,59), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "brown3" => crate::Color{ name:std::borrow::Cow::Borrowed("brown3"), hex:std::borrow::Cow::Borrowed("#CD3333"), rgb:(205,51,51), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "brown4" => crate::Color{ name:std::borrow::Cow::Borrowed("brown4"), hex:std::borrow::Cow::Borrowed("#8B2323"), rgb:(139,35,35), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "salmon1" => crate::Color{ name:std::borrow::Cow::Borrowed("salmon1"), hex:std::borrow::Cow::Borrowed("#FF8C69"), rgb:(255,140,105), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "salmon2" => crate::Color{ name:std::borrow::Cow::Borrowed("salmon2"), hex:std::borrow::Cow::Borrowed("#EE8262"), rgb:(238,130,98), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "salmon3" => crate::Color{ name:std::borrow::Cow::Borrowed("salmon3"), hex:std::borrow::Cow::Borrowed("#CD7054"), rgb:(205,112,84), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "salmon4" => crate::Color{ name:std::borrow::Cow::Borrowed("salmon4"), hex:std::borrow::Cow::Borrowed("#8B4C39"), rgb:(139,76,57), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightsalmon1" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSalmon1"), hex:std::borrow::Cow::Borrowed("#FFA07A"), rgb:(255,160,122), aliases:&["ltsalmon1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightsalmon2" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSalmon2"), hex:std::borrow::Cow::Borrowed("#EE9572"), rgb:(238,149,114), aliases:&["ltsalmon2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightsalmon3" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSalmon3"), hex:std::borrow::Cow::Borrowed("#CD8162"), rgb:(205,129,98), aliases:&["ltsalmon3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightsalmon4" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSalmon4"), hex:std::borrow::Cow::Borrowed("#8B5742"), rgb:(139,87,66), aliases:&["ltsalmon4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orange1" => crate::Color{ name:std::borrow::Cow::Borrowed("orange1"), hex:std::borrow::Cow::Borrowed("#FFA500"), rgb:(255,165,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orange2" => crate::Color{ name:std::borrow::Cow::Borrowed("orange2"), hex:std::borrow::Cow::Borrowed("#EE9A00"), rgb:(238,154,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orange3" => crate::Color{ name:std::borrow::Cow::Borrowed("orange3"), hex:std::borrow::Cow::Borrowed("#CD8500"), rgb:(205,133,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orange4" => crate::Color{ name:std::borrow::Cow::Borrowed("orange4"), hex:std::borrow::Cow::Borrowed("#8B5A00"), rgb:(139,90,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkorange1" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOrange1"), hex:std::borrow::Cow::Borrowed("#FF7F00"), rgb:(255,127,0), aliases:&["dkorange1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkorange2" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOrange2"), hex:std::borrow::Cow::Borrowed("#EE7600"), rgb:(238,118,0), aliases:&["dkorange2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkorange3" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOrange3"), hex:std::borrow::Cow::Borrowed("#CD6600"), rgb:(205,102,0), aliases:&["dkorange3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkorange4" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOrange4"), hex:std::borrow::Cow::Borrowed("#8B4500"), rgb:(139,69,0), aliases:&["dkorange4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "coral1" => crate::Color{ name:std::borrow::Cow::Borrowed("coral1"), hex:std::borrow::Cow::Borrowed("#FF7256"), rgb:(255,114,86), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "coral2" => crate::Color{ name:std::borrow::Cow::Borrowed("coral2"), hex:std::borrow::Cow::Borrowed("#EE6A50"), rgb:(238,106,80), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "coral3" => crate::Color{ name:std::borrow::Cow::Borrowed("coral3"), hex:std::borrow::Cow::Borrowed("#CD5B45"), rgb:(205,91,69), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "coral4" => crate::Color{ name:std::borrow::Cow::Borrowed("coral4"), hex:std::borrow::Cow::Borrowed("#8B3E2F"), rgb:(139,62,47), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "tomato1" => crate::Color{ name:std::borrow::Cow::Borrowed("tomato1"), hex:std::borrow::Cow::Borrowed("#FF6347"), rgb:(255,99,71), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "tomato2" => crate::Color{ name:std::borrow::Cow::Borrowed("tomato2"), hex:std::borrow::Cow::Borrowed("#EE5C42"), rgb:(238,92,66), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "tomato3" => crate::Color{ name:std::borrow::Cow::Borrowed("tomato3"), hex:std::borrow::Cow::Borrowed("#CD4F39"), rgb:(205,79,57), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "tomato4" => crate::Color{ name:std::borrow::Cow::Borrowed("tomato4"), hex:std::borrow::Cow::Borrowed("#8B3626"), rgb:(139,54,38), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orangered1" => crate::Color{ name:std::borrow::Cow::Borrowed("OrangeRed1"), hex:std::borrow::Cow::Borrowed("#FF4500"), rgb:(255,69,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orangered2" => crate::Color{ name:std::borrow::Cow::Borrowed("OrangeRed2"), hex:std::borrow::Cow::Borrowed("#EE4000"), rgb:(238,64,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orangered3" => crate::Color{ name:std::borrow::Cow::Borrowed("OrangeRed3"), hex:std::borrow::Cow::Borrowed("#CD3700"), rgb:(205,55,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orangered4" => crate::Color{ name:std::borrow::Cow::Borrowed("OrangeRed4"), hex:std::borrow::Cow::Borrowed("#8B2500"), rgb:(139,37,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "red1" => crate::Color{ name:std::borrow::Cow::Borrowed("red1"), hex:std::borrow::Cow::Borrowed("#FF0000"), rgb:(255,0,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "red2" => crate::Color{ name:std::borrow::Cow::Borrowed("red2"), hex:std::borrow::Cow::Borrowed("#EE0000"), rgb:(238,0,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "red3" => crate::Color{ name:std::borrow::Cow::Borrowed("red3"), hex:std::borrow::Cow::Borrowed("#CD0000"), rgb:(205,0,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "red4" => crate::Color{ name:std::borrow::Cow::Borrowed("red4"), hex:std::borrow::Cow::Borrowed("#8B0000"), rgb:(139,0,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "debianred" => crate::Color{ name:std::borrow::Cow::Borrowed("DebianRed"), hex:std::borrow::Cow::Borrowed("#D70751"), rgb:(215,7,81), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "deeppink1" => crate::Color{ name:std::borrow::Cow::Borrowed("DeepPink1"), hex:std::borrow::Cow::Borrowed("#FF1493"), rgb:(255,20,147), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "deeppink2" => crate::Color{ name:std::borrow::Cow::Borrowed("DeepPink2"), hex:std::borrow::Cow::Borrowed("#EE1289"), rgb:(238,18,137), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "deeppink3" => crate::Color{ name:std::borrow::Cow::Borrowed("DeepPink3"), hex:std::borrow::Cow::Borrowed("#CD1076"), rgb:(205,16,118), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "deeppink4" => crate::Color{ name:std::borrow::Cow::Borrowed("DeepPink4"), hex:std::borrow::Cow::Borrowed("#8B0A50"), rgb:(139,10,80), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "hotpink1" => crate::Color{ name:std::borrow::Cow::Borrowed("HotPink1"), hex:std::borrow::Cow::Borrowed("#FF6EB4"), rgb:(255,110,180), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "hotpink2" => crate::Color{ name:std::borrow::Cow::Borrowed("HotPink2"), hex:std::borrow::Cow::Borrowed("#EE6AA7"), rgb:(238,106,167), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "hotpink3" => crate::Color{ name:std::borrow::Cow::Borrowed("HotPink3"), hex:std::borrow::Cow::Borrowed("#CD6090"), rgb:(205,96,144), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "hotpink4" => crate::Color{ name:std::borrow::Cow::Borrowed("HotPink4"), hex:std::borrow::Cow::Borrowed("#8B3A62"), rgb:(139,58,98), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "pink1" => crate::Color{ name:std::borrow::Cow::Borrowed("pink1"), hex:std::borrow::Cow::Borrowed("#FFB5C5"), rgb:(255,181,197), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "pink2" => crate::Color{ name:std::borrow::Cow::Borrowed("pink2"), hex:std::borrow::Cow::Borrowed("#EEA9B8"), rgb:(238,169,184), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "pink3" => crate::Color{ name:std::borrow::Cow::Borrowed("pink3"), hex:std::borrow::Cow::Borrowed("#CD919E"), rgb:(205,145,158), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "pink4" => crate::Color{ name:std::borrow::Cow::Borrowed("pink4"), hex:std::borrow::Cow::Borrowed("#8B636C"), rgb:(139,99,108), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightpink1" => crate::Color{ name:std::borrow::Cow::Borrowed("LightPink1"), hex:std::borrow::Cow::Borrowed("#FFAEB9"), rgb:(255,174,185), aliases:&["ltpink1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightpink2" => crate::Color{ name:std::borrow::Cow::Borrowed("LightPink2"), hex:std::borrow::Cow::Borrowed("#EEA2AD"), rgb:(238,162,173), aliases:&["ltpink2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightpink3" => crate::Color{ name:std::borrow::Cow::Borrowed("LightPink3"), hex:std::borrow::Cow::Borrowed("#CD8C95"), rgb:(205,140,149), aliases:&["ltpink3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightpink4" => crate::Color{ name:std::borrow::Cow::Borrowed("LightPink4"), hex:std::borrow::Cow::Borrowed("#8B5F65"), rgb:(139,95,101), aliases:&["ltpink4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palevioletred1" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleVioletRed1"), hex:std::borrow::Cow::Borrowed("#FF82AB"), rgb:(255,130,171), aliases:&["palevoiletred1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palevioletred2" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleVioletRed2"), hex:std::borrow::Cow::Borrowed("#EE799F"), rgb:(238,121,159), aliases:&["palevoiletred2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palevioletred3" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleVioletRed3"), hex:std::borrow::Cow::Borrowed("#CD6889"), rgb:(205,104,137), aliases:&["palevoiletred3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palevioletred4" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleVioletRed4"), hex:std::borrow::Cow::Borrowed("#8B475D"), rgb:(139,71,93), aliases:&["palevoiletred4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "maroon1" => crate::Color{ name:std::borrow::Cow::Borrowed("maroon1"), hex:std::borrow::Cow::Borrowed("#FF34B3"), rgb:(255,52,179), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "maroon2" => crate::Color{ name:std::borrow::Cow::Borrowed("maroon2"), hex:std::borrow::Cow::Borrowed("#EE30A7"), rgb:(238,48,167), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "maroon3" => crate::Color{ name:std::borrow::Cow::Borrowed("maroon3"), hex:std::borrow::Cow::Borrowed("#CD2990"), rgb:(205,41,144), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "maroon4" => crate::Color{ name:std::borrow::Cow::Borrowed("maroon4"), hex:std::borrow::Cow::Borrowed("#8B1C62"), rgb:(139,28,98), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "violetred1" => crate::Color{ name:std::borrow::Cow::Borrowed("VioletRed1"), hex:std::borrow::Cow::Borrowed("#FF3E96"), rgb:(255,62,150), aliases:&["voiletred1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "violetred2" => crate::Color{ name:std::borrow::Cow::Borrowed("VioletRed2"), hex:std::borrow::Cow::Borrowed("#EE3A8C"), rgb:(238,58,140), aliases:&["voiletred2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "violetred3" => crate::Color{ name:std::borrow::Cow::Borrowed("VioletRed3"), hex:std::borrow::Cow::Borrowed("#CD3278"), rgb:(205,50,120), aliases:&["voiletred3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "violetred4" => crate::Color{ name:std::borrow::Cow::Borrowed("VioletRed4"), hex:std::borrow::Cow::Borrowed("#8B2252"), rgb:(139,34,82), aliases:&["voiletred4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "magenta1" => crate::Color{ name:std::borrow::Cow::Borrowed("magenta1"), hex:std::borrow::Cow::Borrowed("#FF00FF"), rgb:(255,0,255), aliases:&["megenta1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "magenta2" => crate::Color{ name:std::borrow::Cow::Borrowed("magenta2"), hex:std::borrow::Cow::Borrowed("#EE00EE"), rgb:(238,0,238), aliases:&["megenta2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "magenta3" => crate::Color{ name:std::borrow::Cow::Borrowed("magenta3"), hex:std::borrow::Cow::Borrowed("#CD00CD"), rgb:(205,0,205), aliases:&["megenta3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "magenta4" => crate::Color{ name:std::borrow::Cow::Borrowed("magenta4"), hex:std::borrow::Cow::Borrowed("#8B008B"), rgb:(139,0,139), aliases:&["megenta4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orchid1" => crate::Color{ name:std::borrow::Cow::Borrowed("orchid1"), hex:std::borrow::Cow::Borrowed("#FF83FA"), rgb:(255,131,250), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orchid2" => crate::Color{ name:std::borrow::Cow::Borrowed("orchid2"), hex:std::borrow::Cow::Borrowed("#EE7AE9"), rgb:(238,122,233), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orchid3" => crate::Color{ name:std::borrow::Cow::Borrowed("orchid3"), hex:std::borrow::Cow::Borrowed("#CD69C9"), rgb:(205,105,201), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orchid4" => crate::Color{ name:std::borrow::Cow::Borrowed("orchid4"), hex:std::borrow::Cow::Borrowed("#8B4789"), rgb:(139,71,137), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "plum1" => crate::Color{ name:std::borrow::Cow::Borrowed("plum1"), hex:std::borrow::Cow::Borrowed("#FFBBFF"), rgb:(255,187,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "plum2" => crate::Color{ name:std::borrow::Cow::Borrowed("plum2"), hex:std::borrow::Cow::Borrowed("#EEAEEE"), rgb:(238,174,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "plum3" => crate::Color{ name:std::borrow::Cow::Borrowed("plum3"), hex:std::borrow::Cow::Borrowed("#CD96CD"), rgb:(205,150,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "plum4" => crate::Color{ name:std::borrow::Cow::Borrowed("plum4"), hex:std::borrow::Cow::Borrowed("#8B668B"), rgb:(139,102,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumorchid1" => crate::Color{ name:std::borrow::Cow::Borrowed("MediumOrchid1"), hex:std::borrow::Cow::Borrowed("#E066FF"), rgb:(224,102,255), aliases:&["medorchid1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumorchid2" => crate::Color{ name:std::borrow::Cow::Borrowed("MediumOrchid2"), hex:std::borrow::Cow::Borrowed("#D15FEE"), rgb:(209,95,238), aliases:&["medorchid2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumorchid3" => crate::Color{ name:std::borrow::Cow::Borrowed("MediumOrchid3"), hex:std::borrow::Cow::Borrowed("#B452CD"), rgb:(180,82,205), aliases:&["medorchid3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumorchid4" => crate::Color{ name:std::borrow::Cow::Borrowed("MediumOrchid4"), hex:std::borrow::Cow::Borrowed("#7A378B"), rgb:(122,55,139), aliases:&["medorchid4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkorchid1" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOrchid1"), hex:std::borrow::Cow::Borrowed("#BF3EFF"), rgb:(191,62,255), aliases:&["dkorchid1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkorchid2" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOrchid2"), hex:std::borrow::Cow::Borrowed("#B23AEE"), rgb:(178,58,238), aliases:&["dkorchid2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkorchid3" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOrchid3"), hex:std::borrow::Cow::Borrowed("#9A32CD"), rgb:(154,50,205), aliases:&["dkorchid3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkorchid4" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOrchid4"), hex:std::borrow::Cow::Borrowed("#68228B"), rgb:(104,34,139), aliases:&["dkorchid4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "purple1" => crate::Color{ name:std::borrow::Cow::Borrowed("purple1"), hex:std::borrow::Cow::Borrowed("#9B30FF"), rgb:(155,48,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "purple2" => crate::Color{ name:std::borrow::Cow::Borrowed("purple2"), hex:std::borrow::Cow::Borrowed("#912CEE"), rgb:(145,44,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "purple3" => crate::Color{ name:std::borrow::Cow::Borrowed("purple3"), hex:std::borrow::Cow::Borrowed("#7D26CD"), rgb:(125,38,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "purple4" => crate::Color{ name:std::borrow::Cow::Borrowed("purple4"), hex:std::borrow::Cow::Borrowed("#551A8B"), rgb:(85,26,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumpurple1" => crate::Color{ name:std::borrow::Cow::Borrowed("MediumPurple1"), hex:std::borrow::Cow::Borrowed("#AB82FF"), rgb:(171,130,255), aliases:&["medpurple1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumpurple2" => crate::Color{ name:std::borrow::Cow::Borrowed("MediumPurple2"), hex:std::borrow::Cow::Borrowed("#9F79EE"), rgb:(159,121,238), aliases:&["medpurple2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumpurple3" => crate::Color{ name:std::borrow::Cow::Borrowed("MediumPurple3"), hex:std::borrow::Cow::Borrowed("#8968CD"), rgb:(137,104,205), aliases:&["medpurple3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumpurple4" => crate::Color{ name:std::borrow::Cow::Borrowed("MediumPurple4"), hex:std::borrow::Cow::Borrowed("#5D478B"), rgb:(93,71,139), aliases:&["medpurple4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "thistle1" => crate::Color{ name:std::borrow::Cow::Borrowed("thistle1"), hex:std::borrow::Cow::Borrowed("#FFE1FF"), rgb:(255,225,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "thistle2" => crate::Color{ name:std::borrow::Cow::Borrowed("thistle2"), hex:std::borrow::Cow::Borrowed("#EED2EE"), rgb:(238,210,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "thistle3" => crate::Color{ name:std::borrow::Cow::Borrowed("thistle3"), hex:std::borrow::Cow::Borrowed("#CDB5CD"), rgb:(205,181,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "thistle4" => crate::Color{ name:std::borrow::Cow::Borrowed("thistle4"), hex:std::borrow::Cow::Borrowed("#8B7B8B"), rgb:(139,123,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray0" => crate::Color{ name:std::borrow::Cow::Borrowed("gray0"), hex:std::borrow::Cow::Borrowed("#000000"), rgb:(0,0,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey0" => crate::Color{ name:std::borrow::Cow::Borrowed("grey0"), hex:std::borrow::Cow::Borrowed("#000000"), rgb:(0,0,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray1" => crate::Color{ name:std::borrow::Cow::Borrowed("gray1"), hex:std::borrow::Cow::Borrowed("#030303"), rgb:(3,3,3), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey1" => crate::Color{ name:std::borrow::Cow::Borrowed("grey1"), hex:std::borrow::Cow::Borrowed("#030303"), rgb:(3,3,3), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray2" => crate::Color{ name:std::borrow::Cow::Borrowed("gray2"), hex:std::borrow::Cow::Borrowed("#050505"), rgb:(5,5,5), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey2" => crate::Color{ name:std::borrow::Cow::Borrowed("grey2"), hex:std::borrow::Cow::Borrowed("#050505"), rgb:(5,5,5), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray3" => crate::Color{ name:std::borrow::Cow::Borrowed("gray3"), hex:std::borrow::Cow::Borrowed("#080808"), rgb:(8,8,8), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey3" => crate::Color{ name:std::borrow::Cow::Borrowed("grey3"), hex:std::borrow::Cow::Borrowed("#080808"), rgb:(8,8,8), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray4" => crate::Color{ name:std::borrow::Cow::Borrowed("gray4"), hex:std::borrow::Cow::Borrowed("#0A0A0A"), rgb:(10,10,10), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey4" => crate::Color{ name:std::borrow::Cow::Borrowed("grey4"), hex:std::borrow::Cow::Borrowed("#0A0A0A"), rgb:(10,10,10), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray5" => crate::Color{ name:std::borrow::Cow::Borrowed("gray5"), hex:std::borrow::Cow::Borrowed("#0D0D0D"), rgb:(13,13,13), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey5" => crate::Color{ name:std::borrow::Cow::Borrowed("grey5"), hex:std::borrow::Cow::Borrowed("#0D0D0D"), rgb:(13,13,13), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray6" => crate::Color{ name:std::borrow::Cow::Borrowed("gray6"), hex:std::borrow::Cow::Borrowed("#0F0F0F"), rgb:(15,15,15), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey6" => crate::Color{ name:std::borrow::Cow::Borrowed("grey6"), hex:std::borrow::Cow::Borrowed("#0F0F0F"), rgb:(15,15,15), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray7" => crate::Color{ name:std::borrow::Cow::Borrowed("gray7"), hex:std::borrow::Cow::Borrowed("#121212"), rgb:(18,18,18), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey7" => crate::Color{ name:std::borrow::Cow::Borrowed("grey7"), hex:std::borrow::Cow::Borrowed("#121212"), rgb:(18,18,18), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray8" => crate::Color{ name:std::borrow::Cow::Borrowed("gray8"), hex:std::borrow::Cow::Borrowed("#141414"), rgb:(20,20,20), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey8" => crate::Color{ name:std::borrow::Cow::Borrowed("grey8"), hex:std::borrow::Cow::Borrowed("#141414"), rgb:(20,20,20), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray9" => crate::Color{ name:std::borrow::Cow::Borrowed("gray9"), hex:std::borrow::Cow::Borrowed("#171717"), rgb:(23,23,23), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey9" => crate::Color{ name:std::borrow::Cow::Borrowed("grey9"), hex:std::borrow::Cow::Borrowed("#171717"), rgb:(23,23,23), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray10" => crate::Color{ name:std::borrow::Cow::Borrowed("gray10"), hex:std::borrow::Cow::Borrowed("#1A1A1A"), rgb:(26,26,26), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey10" => crate::Color{ name:std::borrow::Cow::Borrowed("grey10"), hex:std::borrow::Cow::Borrowed("#1A1A1A"), rgb:(26,26,26), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray11" => crate::Color{ name:std::borrow::Cow::Borrowed("gray11"), hex:std::borrow::Cow::Borrowed("#1C1C1C"), rgb:(28,28,28), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey11" => crate::Color{ name:std::borrow::Cow::Borrowed("grey11"), hex:std::borrow::Cow::Borrowed("#1C1C1C"), rgb:(28,28,28), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray12" => crate::Color{ name:std::borrow::Cow::Borrowed("gray12"), hex:std::borrow::Cow::Borrowed("#1F1F1F"), rgb:(31,31,31), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey12" => crate::Color{ name:std::borrow::Cow::Borrowed("grey12"), hex:std::borrow::Cow::Borrowed("#1F1F1F"), rgb:(31,31,31), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray13" => crate::Color{ name:std::borrow::Cow::Borrowed("gray13"), hex:std::borrow::Cow::Borrowed("#212121"), rgb:(33,33,33), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey13" => crate::Color{ name:std::borrow::Cow::Borrowed("grey13"), hex:std::borrow::Cow::Borrowed("#212121"), rgb:(33,33,33), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray14" => crate::Color{ name:std::borrow::Cow::Borrowed("gray14"), hex:std::borrow::Cow::Borrowed("#242424"), rgb:(36,36,36), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey14" => crate::Color{ name:std::borrow::Cow::Borrowed("grey14"), hex:std::borrow::Cow::Borrowed("#242424"), rgb:(36,36,36), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray15" => crate::Color{ name:std::borrow::Cow::Borrowed("gray15"), hex:std::borrow::Cow::Borrowed("#262626"), rgb:(38,38,38), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey15" => crate::Color{ name:std::borrow::Cow::Borrowed("grey15"), hex:std::borrow::Cow::Borrowed("#262626"), rgb:(38,38,38), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray16" => crate::Color{ name:std::borrow::Cow::Borrowed("gray16"), hex:std::borrow::Cow::Borrowed("#292929"), rgb:(41,41,41), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey16" => crate::Color{ name:std::borrow::Cow::Borrowed("grey16"), hex:std::borrow::Cow::Borrowed("#292929"), rgb:(41,41,41), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray17" => crate::Color{ name:std::borrow::Cow::Borrowed("gray17"), hex:std::borrow::Cow::Borrowed("#2B2B2B"), rgb:(43,43,43), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey17" => crate::Color{ name:std::borrow::Cow::Borrowed("grey17"), hex:std::borrow::Cow::Borrowed("#2B2B2B"), rgb:(43,43,43), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray18" => crate::Color{ name:std::borrow::Cow::Borrowed("gray18"), hex:std::borrow::Cow::Borrowed("#2E2E2E"), rgb:(46,46,46), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey18" => crate::Color{ name:std::borrow::Cow::Borrowed("grey18"), hex:std::borrow::Cow::Borrowed("#2E2E2E"), rgb:(46,46,46), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray19" => crate::Color{ name:std::borrow::Cow::Borrowed("gray19"), hex:std::borrow::Cow::Borrowed("#303030"), rgb:(48,48,48), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey19" => crate::Color{ name:std::borrow::Cow::Borrowed("grey19"), hex:std::borrow::Cow::Borrowed("#303030"), rgb:(48,48,48), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray20" => crate::Color{ name:std::borrow::Cow::Borrowed("gray20"), hex:std::borrow::Cow::Borrowed("#333333"), rgb:(51,51,51), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey20" => crate::Color{ name:std::borrow::Cow::Borrowed("grey20"), hex:std::borrow::Cow::Borrowed("#333333"), rgb:(51,51,51), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray21" => crate::Color{ name:std::borrow::Cow::Borrowed("gray21"), hex:std::borrow::Cow::Borrowed("#363636"), rgb:(54,54,54), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey21" => crate::Color{ name:std::borrow::Cow::Borrowed("grey21"), hex:std::borrow::Cow::Borrowed("#363636"), rgb:(54,54,54), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray22" => crate::Color{ name:std::borrow::Cow::Borrowed("gray22"), hex:std::borrow::Cow::Borrowed("#383838"), rgb:(56,56,56), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey22" => crate::Color{ name:std::borrow::Cow::Borrowed("grey22"), hex:std::borrow::Cow::Borrowed("#383838"), rgb:(56,56,56), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray23" => crate::Color{ name:std::borrow::Cow::Borrowed("gray23"), hex:std::borrow::Cow::Borrowed("#3B3B3B"), rgb:(59,59,59), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey23" => crate::Color{ name:std::borrow::Cow::Borrowed("grey23"), hex:std::borrow::Cow::Borrowed("#3B3B3B"), rgb:(59,59,59), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray24" => crate::Color{ name:std::borrow::Cow::Borrowed("gray24"), hex:std::borrow::Cow::Borrowed("#3D3D3D"), rgb:(61,61,61), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey24" => crate::Color{ name:std::borrow::Cow::Borrowed("grey24"), hex:std::borrow::Cow::Borrowed("#3D3D3D"), rgb:(61,61,61), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray25" => crate::Color{ name:std::borrow::Cow::Borrowed("gray25"), hex:std::borrow::Cow::Borrowed("#404040"), rgb:(64,64,64), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey25" => crate::Color{ name:std::borrow::Cow::Borrowed("grey25"), hex:std::borrow::Cow::Borrowed("#404040"), rgb:(64,64,64), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray26" => crate::Color{ name:std::borrow::Cow::Borrowed("gray26"), hex:std::borrow::Cow::Borrowed("#424242"), rgb:(66,66,66), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey26" => crate::Color{ name:std::borrow::Cow::Borrowed("grey26"), hex:std::borrow::Cow::Borrowed("#424242"), rgb:(66,66,66), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray27" => crate::Color{ name:std::borrow::Cow::Borrowed("gray27"), hex:std::borrow::Cow::Borrowed("#454545"), rgb:(69,69,69), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey27" => crate::Color{ name:std::borrow::Cow::Borrowed("grey27"), hex:std::borrow::Cow::Borrowed("#454545"), rgb:(69,69,69), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray28" => crate::Color{ name:std::borrow::Cow::Borrowed("gray28"), hex:std::borrow::Cow::Borrowed("#474747"), rgb:(71,71,71), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey28" => crate::Color{ name:std::borrow::Cow::Borrowed("grey28"), hex:std::borrow::Cow::Borrowed("#474747"), rgb:(71,71,71), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray29" => crate::Color{ name:std::borrow::Cow::Borrowed("gray29"), hex:std::borrow::Cow::Borrowed("#4A4A4A"), rgb:(74,74,74), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey29" => crate::Color{ name:std::borrow::Cow::Borrowed("grey29"), hex:std::borrow::Cow::Borrowed("#4A4A4A"), rgb:(74,74,74), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray30" => crate::Color{ name:std::borrow::Cow::Borrowed("gray30"), hex:std::borrow::Cow::Borrowed("#4D4D4D"), rgb:(77,77,77), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey30" => crate::Color{ name:std::borrow::Cow::Borrowed("grey30"), hex:std::borrow::Cow::Borrowed("#4D4D4D"), rgb:(77,77,77), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray31" => crate::Color{ name:std::borrow::Cow::Borrowed("gray31"), hex:std::borrow::Cow::Borrowed("#4F4F4F"), rgb:(79,79,79), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey31" => crate::Color{ name:std::borrow::Cow::Borrowed("grey31"), hex:std::borrow::Cow::Borrowed("#4F4F4F"), rgb:(79,79,79), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray32" => crate::Color{ name:std::borrow::Cow::Borrowed("gray32"), hex:std::borrow::Cow::Borrowed("#525252"), rgb:(82,82,82), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey32" => crate::Color{ name:std::borrow::Cow::Borrowed("grey32"), hex:std::borrow::Cow::Borrowed("#525252"), rgb:(82,82,82), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray33" => crate::Color{ name:std::borrow::Cow::Borrowed("gray33"), hex:std::borrow::Cow::Borrowed("#545454"), rgb:(84,84,84), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey33" => crate::Color{ name:std::borrow::Cow::Borrowed("grey33"), hex:std::borrow::Cow::Borrowed("#545454"), rgb:(84,84,84), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray34" => crate::Color{ name:std::borrow::Cow::Borrowed("gray34"), hex:std::borrow::Cow::Borrowed("#575757"), rgb:(87,87,87), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey34" => crate::Color{ name:std::borrow::Cow::Borrowed("grey34"), hex:std::borrow::Cow::Borrowed("#575757"), rgb:(87,87,87), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray35" => crate::Color{ name:std::borrow::Cow::Borrowed("gray35"), hex:std::borrow::Cow::Borrowed("#595959"), rgb:(89,89,89), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey35" => crate::Color{ name:std::borrow::Cow::Borrowed("grey35"), hex:std::borrow::Cow::Borrowed("#595959"), rgb:(89,89,89), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray36" => crate::Color{ name:std::borrow::Cow::Borrowed("gray36"), hex:std::borrow::Cow::Borrowed("#5C5C5C"), rgb:(92,92,92), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey36" => crate::Color{ name:std::borrow::Cow::Borrowed("grey36"), hex:std::borrow::Cow::Borrowed("#5C5C5C"), rgb:(92,92,92), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray37" => crate::Color{ name:std::borrow::Cow::Borrowed("gray37"), hex:std::borrow::Cow::Borrowed("#5E5E5E"), rgb:(94,94,94), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey37" => crate::Color{ name:std::borrow::Cow::Borrowed("grey37"), hex:std::borrow::Cow::Borrowed("#5E5E5E"), rgb:(94,94,94), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray38" => crate::Color{ name:std::borrow::Cow::Borrowed("gray38"), hex:std::borrow::Cow::Borrowed("#616161"), rgb:(97,97,97), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey38" => crate::Color{ name:std::borrow::Cow::Borrowed("grey38"), hex:std::borrow::Cow::Borrowed("#616161"), rgb:(97,97,97), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray39" => crate::Color{ name:std::borrow::Cow::Borrowed("gray39"), hex:std::borrow::Cow::Borrowed("#636363"), rgb:(99,99,99), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey39" => crate::Color{ name:std::borrow::Cow::Borrowed("grey39"), hex:std::borrow::Cow::Borrowed("#636363"), rgb:(99,99,99), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray40" => crate::Color{ name:std::borrow::Cow::Borrowed("gray40"), hex:std::borrow::Cow::Borrowed("#666666"), rgb:(102,102,102), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey40" => crate::Color{ name:std::borrow::Cow::Borrowed("grey40"), hex:std::borrow::Cow::Borrowed("#666666"), rgb:(102,102,102), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray41" => crate::Color{ name:std::borrow::Cow::Borrowed("gray41"), hex:std::borrow::Cow::Borrowed("#696969"), rgb:(105,105,105), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey41" => crate::Color{ name:std::borrow::Cow::Borrowed("grey41"), hex:std::borrow::Cow::Borrowed("#696969"), rgb:(105,105,105), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray42" => crate::Color{ name:std::borrow::Cow::Borrowed("gray42"), hex:std::borrow::Cow::Borrowed("#6B6B6B"), rgb:(107,107,107), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey42" => crate::Color{ name:std::borrow::Cow::Borrowed("grey42"), hex:std::borrow::Cow::Borrowed("#6B6B6B"), rgb:(107,107,107), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray43" => crate::Color{ name:std::borrow::Cow::Borrowed("gray43"), hex:std::borrow::Cow::Borrowed("#6E6E6E"), rgb:(110,110,110), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey43" => crate::Color{ name:std::borrow::Cow::Borrowed("grey43"), hex:std::borrow::Cow::Borrowed("#6E6E6E"), rgb:(110,110,110), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray44" => crate::Color{ name:std::borrow::Cow::Borrowed("gray44"), hex:std::borrow::Cow::Borrowed("#707070"), rgb:(112,112,112), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey44" => crate::Color{ name:std::borrow::Cow::Borrowed("grey44"), hex:std::borrow::Cow::Borrowed("#707070"), rgb:(112,112,112), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray45" => crate::Color{ name:std::borrow::Cow::Borrowed("gray45"), hex:std::borrow::Cow::Borrowed("#737373"), rgb:(115,115,115), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey45" => crate::Color{ name:std::borrow::Cow::Borrowed("grey45"), hex:std::borrow::Cow::Borrowed("#737373"), rgb:(115,115,115), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray46" => crate::Color{ name:std::borrow::Cow::Borrowed("gray46"), hex:std::borrow::Cow::Borrowed("#757575"), rgb:(117,117,117), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey46" => crate::Color{ name:std::borrow::Cow::Borrowed("grey46"), hex:std::borrow::Cow::Borrowed("#757575"), rgb:(117,117,117), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray47" => crate::Color{ name:std::borrow::Cow::Borrowed("gray47"), hex:std::borrow::Cow::Borrowed("#787878"), rgb:(120,120,120), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey47" => crate::Color{ name:std::borrow::Cow::Borrowed("grey47"), hex:std::borrow::Cow::Borrowed("#787878"), rgb:(120,120,120), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray48" => crate::Color{ name:std::borrow::Cow::Borrowed("gray48"), hex:std::borrow::Cow::Borrowed("#7A7A7A"), rgb:(122,122,122), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey48" => crate::Color{ name:std::borrow::Cow::Borrowed("grey48"), hex:std::borrow::Cow::Borrowed("#7A7A7A"), rgb:(122,122,122), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray49" => crate::Color{ name:std::borrow::Cow::Borrowed("gray49"), hex:std::borrow::Cow::Borrowed("#7D7D7D"), rgb:(125,125,125), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey49" => crate::Color{ name:std::borrow::Cow::Borrowed("grey49"), hex:std::borrow::Cow::Borrowed("#7D7D7D"), rgb:(125,125,125), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray50" => crate::Color{ name:std::borrow::Cow::Borrowed("gray50"), hex:std::borrow::Cow::Borrowed("#7F7F7F"), rgb:(127,127,127), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey50" => crate::Color{ name:std::borrow::Cow::Borrowed("grey50"), hex:std::borrow::Cow::Borrowed("#7F7F7F"), rgb:(127,127,127), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray51" => crate::Color{ name:std::borrow::Cow::Borrowed("gray51"), hex:std::borrow::Cow::Borrowed("#828282"), rgb:(130,130,130), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey51" => crate::Color{ name:std::borrow::Cow::Borrowed("grey51"), hex:std::borrow::Cow::Borrowed("#828282"), rgb:(130,130,130), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray52" => crate::Color{ name:std::borrow::Cow::Borrowed("gray52"), hex:std::borrow::Cow::Borrowed("#858585"), rgb:(133,133,133), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey52" => crate::Color{ name:std::borrow::Cow::Borrowed("grey52"), hex:std::borrow::Cow::Borrowed("#858585"), rgb:(133,133,133), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray53" => crate::Color{ name:std::borrow::Cow::Borrowed("gray53"), hex:std::borrow::Cow::Borrowed("#878787"), rgb:(135,135,135), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey53" => crate::Color{ name:std::borrow::Cow::Borrowed("grey53"), hex:std::borrow::Cow::Borrowed("#878787"), rgb:(135,135,135), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray54" => crate::Color{ name:std::borrow::Cow::Borrowed("gray54"), hex:std::borrow::Cow::Borrowed("#8A8A8A"), rgb:(138,138,138), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey54" => crate::Color{ name:std::borrow::Cow::Borrowed("grey54"), hex:std::borrow::Cow::Borrowed("#8A8A8A"), rgb:(138,138,138), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray55" => crate::Color{ name:std::borrow::Cow::Borrowed("gray55"), hex:std::borrow::Cow::Borrowed("#8C8C8C"), rgb:(140,140,140), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey55" => crate::Color{ name:std::borrow::Cow::Borrowed("grey55"), hex:std::borrow::Cow::Borrowed("#8C8C8C"), rgb:(140,140,140), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray56" => crate::Color{ name:std::borrow::Cow::Borrowed("gray56"), hex:std::borrow::Cow::Borrowed("#8F8F8F"), rgb:(143,143,143), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey56" => crate::Color{ name:std::borrow::Cow::Borrowed("grey56"), hex:std::borrow::Cow::Borrowed("#8F8F8F"), rgb:(143,143,143), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray57" => crate::Color{ name:std::borrow::Cow::Borrowed("gray57"), hex:std::borrow::Cow::Borrowed("#919191"), rgb:(145,145,145), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey57" => crate::Color{ name:std::borrow::Cow::Borrowed("grey57"), hex:std::borrow::Cow::Borrowed("#919191"), rgb:(145,145,145), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray58" => crate::Color{ name:std::borrow::Cow::Borrowed("gray58"), hex:std::borrow::Cow::Borrowed("#949494"), rgb:(148,148,148), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey58" => crate::Color{ name:std::borrow::Cow::Borrowed("grey58"), hex:std::borrow::Cow::Borrowed("#949494"), rgb:(148,148,148), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray59" => crate::Color{ name:std::borrow::Cow::Borrowed("gray59"), hex:std::borrow::Cow::Borrowed("#969696"), rgb:(150,150,150), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey59" => crate::Color{ name:std::borrow::Cow::Borrowed("grey59"), hex:std::borrow::Cow::Borrowed("#969696"), rgb:(150,150,150), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray60" => crate::Color{ name:std::borrow::Cow::Borrowed("gray60"), hex:std::borrow::Cow::Borrowed("#999999"), rgb:(153,153,153), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey60" => crate::Color{ name:std::borrow::Cow::Borrowed("grey60"), hex:std::borrow::Cow::Borrowed("#999999"), rgb:(153,153,153), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray61" => crate::Color{ name:std::borrow::Cow::Borrowed("gray61"), hex:std::borrow::Cow::Borrowed("#9C9C9C"), rgb:(156,156,156), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey61" => crate::Color{ name:std::borrow::Cow::Borrowed("grey61"), hex:std::borrow::Cow::Borrowed("#9C9C9C"), rgb:(156,156,156), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray62" => crate::Color{ name:std::borrow::Cow::Borrowed("gray62"), hex:std::borrow::Cow::Borrowed("#9E9E9E"), rgb:(158,158,158), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey62" => crate::Color{ name:std::borrow::Cow::Borrowed("grey62"), hex:std::borrow::Cow::Borrowed("#9E9E9E"), rgb:(158,158,158), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray63" => crate::Color{ name:std::borrow::Cow::Borrowed("gray63"), hex:std::borrow::Cow::Borrowed("#A1A1A1"), rgb:(161,161,161), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey63" => crate::Color{ name:std::borrow::Cow::Borrowed("grey63"), hex:std::borrow::Cow::Borrowed("#A1A1A1"), rgb:(161,161,161), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray64" => crate::Color{ name:std::borrow::Cow::Borrowed("gray64"), hex:std::borrow::Cow::Borrowed("#A3A3A3"), rgb:(163,163,163), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey64" => crate::Color{ name:std::borrow::Cow::Borrowed("grey64"), hex:std::borrow::Cow::Borrowed("#A3A3A3"), rgb:(163,163,163), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray65" => crate::Color{ name:std::borrow::Cow::Borrowed("gray65"), hex:std::borrow::Cow::Borrowed("#A6A6A6"), rgb:(166,166,166), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey65" => crate::Color{ name:std::borrow::Cow::Borrowed("grey65"), hex:std::borrow::Cow::Borrowed("#A6A6A6"), rgb:(166,166,166), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray66" => crate::Color{ name:std::borrow::Cow::Borrowed("gray66"), hex:std::borrow::Cow::Borrowed("#A8A8A8"), rgb:(168,168,168), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey66" => crate::Color{ name:std::borrow::Cow::Borrowed("grey66"), hex:std::borrow::Cow::Borrowed("#A8A8A8"), rgb:(168,168,168), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray67" => crate::Color{ name:std::borrow::Cow::Borrowed("gray67"), hex:std::borrow::Cow::Borrowed("#ABABAB"), rgb:(171,171,171), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey67" => crate::Color{ name:std::borrow::Cow::Borrowed("grey67"), hex:std::borrow::Cow::Borrowed("#ABABAB"), rgb:(171,171,171), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray68" => crate::Color{ name:std::borrow::Cow::Borrowed("gray68"), hex:std::borrow::Cow::Borrowed("#ADADAD"), rgb:(173,173,173), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey68" => crate::Color{ name:std::borrow::Cow::Borrowed("grey68"), hex:std::borrow::Cow::Borrowed("#ADADAD"), rgb:(173,173,173), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray69" => crate::Color{ name:std::borrow::Cow::Borrowed("gray69"), hex:std::borrow::Cow::Borrowed("#B0B0B0"), rgb:(176,176,176), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey69" => crate::Color{ name:std::borrow::Cow::Borrowed("grey69"), hex:std::borrow::Cow::Borrowed("#B0B0B0"), rgb:(176,176,176), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray70" => crate::Color{ name:std::borrow::Cow::Borrowed("gray70"), hex:std::borrow::Cow::Borrowed("#B3B3B3"), rgb:(179,179,179), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey70" => crate::Color{ name:std::borrow::Cow::Borrowed("grey70"), hex:std::borrow::Cow::Borrowed("#B3B3B3"), rgb:(179,179,179), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray71" => crate::Color{ name:std::borrow::Cow::Borrowed("gray71"), hex:std::borrow::Cow::Borrowed("#B5B5B5"), rgb:(181,181,181), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey71" => crate::Color{ name:std::borrow::Cow::Borrowed("grey71"), hex:std::borrow::Cow::Borrowed("#B5B5B5"), rgb:(181,181,181), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray72" => crate::Color{ name:std::borrow::Cow::Borrowed("gray72"), hex:std::borrow::Cow::Borrowed("#B8B8B8"), rgb:(184,184,184), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey72" => crate::Color{ name:std::borrow::Cow::Borrowed("grey72"), hex:std::borrow::Cow::Borrowed("#B8B8B8"), rgb:(184,184,184), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray73" => crate::Color{ name:std::borrow::Cow::Borrowed("gray73"), hex:std::borrow::Cow::Borrowed("#BABABA"), rgb:(186,186,186), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey73" => crate::Color{ name:std::borrow::Cow::Borrowed("grey73"), hex:std::borrow::Cow::Borrowed("#BABABA"), rgb:(186,186,186), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray74" => crate::Color{ name:std::borrow::Cow::Borrowed("gray74"), hex:std::borrow::Cow::Borrowed("#BDBDBD"), rgb:(189,189,189), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey74" => crate::Color{ name:std::borrow::Cow::Borrowed("grey74"), hex:std::borrow::Cow::Borrowed("#BDBDBD"), rgb:(189,189,189), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray75" => crate::Color{ name:std::borrow::Cow::Borrowed("gray75"), hex:std::borrow::Cow::Borrowed("#BFBFBF"), rgb:(191,191,191), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey75" => crate::Color{ name:std::borrow::Cow::Borrowed("grey75"), hex:std::borrow::Cow::Borrowed("#BFBFBF"), rgb:(191,191,191), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray76" => crate::Color{ name:std::borrow::Cow::Borrowed("gray76"), hex:std::borrow::Cow::Borrowed("#C2C2C2"), rgb:(194,194,194), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey76" => crate::Color{ name:std::borrow::Cow::Borrowed("grey76"), hex:std::borrow::Cow::Borrowed("#C2C2C2"), rgb:(194,194,194), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray77" => crate::Color{ name:std::borrow::Cow::Borrowed("gray77"), hex:std::borrow::Cow::Borrowed("#C4C4C4"), rgb:(196,196,196), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey77" => crate::Color{ name:std::borrow::Cow::Borrowed("grey77"), hex:std::borrow::Cow::Borrowed("#C4C4C4"), rgb:(196,196,196), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray78" => crate::Color{ name:std::borrow::Cow::Borrowed("gray78"), hex:std::borrow::Cow::Borrowed("#C7C7C7"), rgb:(199,199,199), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey78" => crate::Color{ name:std::borrow::Cow::Borrowed("grey78"), hex:std::borrow::Cow::Borrowed("#C7C7C7"), rgb:(199,199,199), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray79" => crate::Color{ name:std::borrow::Cow::Borrowed("gray79"), hex:std::borrow::Cow::Borrowed("#C9C9C9"), rgb:(201,201,201), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey79" => crate::Color{ name:std::borrow::Cow::Borrowed("grey79"), hex:std::borrow::Cow::Borrowed("#C9C9C9"), rgb:(201,201,201), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray80" => crate::Color{ name:std::borrow::Cow::Borrowed("gray80"), hex:std::borrow::Cow::Borrowed("#CCCCCC"), rgb:(204,204,204), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey80" => crate::Color{ name:std::borrow::Cow::Borrowed("grey80"), hex:std::borrow::Cow::Borrowed("#CCCCCC"), rgb:(204,204,204), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray81" => crate::Color{ name:std::borrow::Cow::Borrowed("gray81"), hex:std::borrow::Cow::Borrowed("#CFCFCF"), rgb:(207,207,207), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey81" => crate::Color{ name:std::borrow::Cow::Borrowed("grey81"), hex:std::borrow::Cow::Borrowed("#CFCFCF"), rgb:(207,207,207), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray82" => crate::Color{ name:std::borrow::Cow::Borrowed("gray82"), hex:std::borrow::Cow::Borrowed("#D1D1D1"), rgb:(209,209,209), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey82" => crate::Color{ name:std::borrow::Cow::Borrowed("grey82"), hex:std::borrow::Cow::Borrowed("#D1D1D1"), rgb:(209,209,209), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray83" => crate::Color{ name:std::borrow::Cow::Borrowed("gray83"), hex:std::borrow::Cow::Borrowed("#D4D4D4"), rgb:(212,212,212), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey83" => crate::Color{ name:std::borrow::Cow::Borrowed("grey83"), hex:std::borrow::Cow::Borrowed("#D4D4D4"), rgb:(212,212,212), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray84" => crate::Color{ name:std::borrow::Cow::Borrowed("gray84"), hex:std::borrow::Cow::Borrowed("#D6D6D6"), rgb:(214,214,214), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey84" => crate::Color{ name:std::borrow::Cow::Borrowed("grey84"), hex:std::borrow::Cow::Borrowed("#D6D6D6"), rgb:(214,214,214), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray85" => crate::Color{ name:std::borrow::Cow::Borrowed("gray85"), hex:std::borrow::Cow::Borrowed("#D9D9D9"), rgb:(217,217,217), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey85" => crate::Color{ name:std::borrow::Cow::Borrowed("grey85"), hex:std::borrow::Cow::Borrowed("#D9D9D9"), rgb:(217,217,217), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray86" => crate::Color{ name:std::borrow::Cow::Borrowed("gray86"), hex:std::borrow::Cow::Borrowed("#DBDBDB"), rgb:(219,219,219), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey86" => crate::Color{ name:std::borrow::Cow::Borrowed("grey86"), hex:std::borrow::Cow::Borrowed("#DBDBDB"), rgb:(219,219,219), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray87" => crate::Color{ name:std::borrow::Cow::Borrowed("gray87"), hex:std::borrow::Cow::Borrowed("#DEDEDE"), rgb:(222,222,222), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey87" => crate::Color{ name:std::borrow::Cow::Borrowed("grey87"), hex:std::borrow::Cow::Borrowed("#DEDEDE"), rgb:(222,222,222), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray88" => crate::Color{ name:std::borrow::Cow::Borrowed("gray88"), hex:std::borrow::Cow::Borrowed("#E0E0E0"), rgb:(224,224,224), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey88" => crate::Color{ name:std::borrow::Cow::Borrowed("grey88"), hex:std::borrow::Cow::Borrowed("#E0E0E0"), rgb:(224,224,224), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray89" => crate::Color{ name:std::borrow::Cow::Borrowed("gray89"), hex:std::borrow::Cow::Borrowed("#E3E3E3"), rgb:(227,227,227), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey89" => crate::Color{ name:std::borrow::Cow::Borrowed("grey89"), hex:std::borrow::Cow::Borrowed("#E3E3E3"), rgb:(227,227,227), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray90" => crate::Color{ name:std::borrow::Cow::Borrowed("gray90"), hex:std::borrow::Cow::Borrowed("#E5E5E5"), rgb:(229,229,229), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey90" => crate::Color{ name:std::borrow::Cow::Borrowed("grey90"), hex:std::borrow::Cow::Borrowed("#E5E5E5"), rgb:(229,229,229), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray91" => crate::Color{ name:std::borrow::Cow::Borrowed("gray91"), hex:std::borrow::Cow::Borrowed("#E8E8E8"), rgb:(232,232,232), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey91" => crate::Color{ name:std::borrow::Cow::Borrowed("grey91"), hex:std::borrow::Cow::Borrowed("#E8E8E8"), rgb:(232,232,232), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray92" => crate::Color{ name:std::borrow::Cow::Borrowed("gray92"), hex:std::borrow::Cow::Borrowed("#EBEBEB"), rgb:(235,235,235), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey92" => crate::Color{ name:std::borrow::Cow::Borrowed("grey92"), hex:std::borrow::Cow::Borrowed("#EBEBEB"), rgb:(235,235,235), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray93" => crate::Color{ name:std::borrow::Cow::Borrowed("gray93"), hex:std::borrow::Cow::Borrowed("#EDEDED"), rgb:(237,237,237), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey93" => crate::Color{ name:std::borrow::Cow::Borrowed("grey93"), hex:std::borrow::Cow::Borrowed("#EDEDED"), rgb:(237,237,237), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray94" => crate::Color{ name:std::borrow::Cow::Borrowed("gray94"), hex:std::borrow::Cow::Borrowed("#F0F0F0"), rgb:(240,240,240), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey94" => crate::Color{ name:std::borrow::Cow::Borrowed("grey94"), hex:std::borrow::Cow::Borrowed("#F0F0F0"), rgb:(240,240,240), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray95" => crate::Color{ name:std::borrow::Cow::Borrowed("gray95"), hex:std::borrow::Cow::Borrowed("#F2F2F2"), rgb:(242,242,242), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey95" => crate::Color{ name:std::borrow::Cow::Borrowed("grey95"), hex:std::borrow::Cow::Borrowed("#F2F2F2"), rgb:(242,242,242), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray96" => crate::Color{ name:std::borrow::Cow::Borrowed("gray96"), hex:std::borrow::Cow::Borrowed("#F5F5F5"), rgb:(245,245,245), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey96" => crate::Color{ name:std::borrow::Cow::Borrowed("grey96"), hex:std::borrow::Cow::Borrowed("#F5F5F5"), rgb:(245,245,245), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray97" => crate::Color{ name:std::borrow::Cow::Borrowed("gray97"), hex:std::borrow::Cow::Borrowed("#F7F7F7"), rgb:(247,247,247), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey97" => crate::Color{ name:std::borrow::Cow::Borrowed("grey97"), hex:std::borrow::Cow::Borrowed("#F7F7F7"), rgb:(247,247,247), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray98" => crate::Color{ name:std::borrow::Cow::Borrowed("gray98"), hex:std::borrow::Cow::Borrowed("#FAFAFA"), rgb:(250,250,250), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey98" => crate::Color{ name:std::borrow::Cow::Borrowed("grey98"), hex:std::borrow::Cow::Borrowed("#FAFAFA"), rgb:(250,250,250), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray99" => crate::Color{ name:std::borrow::Cow::Borrowed("gray99"), hex:std::borrow::Cow::Borrowed("#FCFCFC"), rgb:(252,252,252), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey99" => crate::Color{ name:std::borrow::Cow::Borrowed("grey99"), hex:std::borrow::Cow::Borrowed("#FCFCFC"), rgb:(252,252,252), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray100" => crate::Color{ name:std::borrow::Cow::Borrowed("gray100"), hex:std::borrow::Cow::Borrowed("#FFFFFF"), rgb:(255,255,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey100" => crate::Color{ name:std::borrow::Cow::Borrowed("grey100"), hex:std::borrow::Cow::Borrowed("#FFFFFF"), rgb:(255,255,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("dark grey"), hex:std::borrow::Cow::Borrowed("#A9A9A9"), rgb:(169,169,169), aliases:&["dkgrey", "dkgray"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkgray" => crate::Color{ name:std::borrow::Cow::Borrowed("dark gray"), hex:std::borrow::Cow::Borrowed("#A9A9A9"), rgb:(169,169,169), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkblue" => crate::Color{ name:std::borrow::Cow::Borrowed("dark blue"), hex:std::borrow::Cow::Borrowed("#00008B"), rgb:(0,0,139), aliases:&["dkblue"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkcyan" => crate::Color{ name:std::borrow::Cow::Borrowed("dark cyan"), hex:std::borrow::Cow::Borrowed("#008B8B"), rgb:(0,139,139), aliases:&["dkcyan"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkmagenta" => crate::Color{ name:std::borrow::Cow::Borrowed("dark magenta"), hex:std::borrow::Cow::Borrowed("#8B008B"), rgb:(139,0,139), aliases:&["darkmegenta", "dkmagenta", "dkmegenta"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkred" => crate::Color{ name:std::borrow::Cow::Borrowed("dark red"), hex:std::borrow::Cow::Borrowed("#8B0000"), rgb:(139,0,0), aliases:&["dkred"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("light green"), hex:std::borrow::Cow::Borrowed("#90EE90"), rgb:(144,238,144), aliases:&["ltgreen"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+};
+
+/// Alternate spellings of `X11_COLORS` keys, mapped to the key they stand for.
+pub(crate) static X11_COLORS_ALIASES: phf::Map<&'static str, &'static str> = phf::phf_map! {
+    "lavendar" => "lavender",
+    "lavendarblush" => "lavenderblush",
+    "dkslategray" => "darkslategray",
+    "dkslategrey" => "darkslategray",
+    "ltslategray" => "lightslategray",
+    "ltslategrey" => "lightslategray",
+    "ltgrey" => "lightgrey",
+    "ltgray" => "lightgrey",
+    "dkslateblue" => "darkslateblue",
+    "medslateblue" => "mediumslateblue",
+    "ltslateblue" => "lightslateblue",
+    "medblue" => "mediumblue",
+    "ltskyblue" => "lightskyblue",
+    "ltsteelblue" => "lightsteelblue",
+    "ltblue" => "lightblue",
+    "paleturqoise" => "paleturquoise",
+    "paleturquise" => "paleturquoise",
+    "darkturqoise" => "darkturquoise",
+    "darkturquise" => "darkturquoise",
+    "dkturquoise" => "darkturquoise",
+    "dkturqoise" => "darkturquoise",
+    "dkturquise" => "darkturquoise",
+    "mediumturqoise" => "mediumturquoise",
+    "mediumturquise" => "mediumturquoise",
+    "medturquoise" => "mediumturquoise",
+    "medturqoise" => "mediumturquoise",
+    "medturquise" => "mediumturquoise",
+    "turqoise" => "turquoise",
+    "turquise" => "turquoise",
+    "ltcyan" => "lightcyan",
+    "medaquamarine" => "mediumaquamarine",
+    "dkgreen" => "darkgreen",
+    "dkolivegreen" => "darkolivegreen",
+    "dkseagreen" => "darkseagreen",
+    "medseagreen" => "mediumseagreen",
+    "ltseagreen" => "lightseagreen",
+    "medspringgreen" => "mediumspringgreen",
+    "dkkhaki" => "darkkhaki",
+    "ltgoldenrodyellow" => "lightgoldenrodyellow",
+    "ltyellow" => "lightyellow",
+    "ltgoldenrod" => "lightgoldenrod",
+    "dkgoldenrod" => "darkgoldenrod",
+    "dksalmon" => "darksalmon",
+    "ltsalmon" => "lightsalmon",
+    "dkorange" => "darkorange",
+    "ltcoral" => "lightcoral",
+    "ltpink" => "lightpink",
+    "palevoiletred" => "palevioletred",
+    "mediumvoiletred" => "mediumvioletred",
+    "medvioletred" => "mediumvioletred",
+    "medvoiletred" => "mediumvioletred",
+    "voiletred" => "violetred",
+    "megenta" => "magenta",
+    "voilet" => "violet",
+    "medorchid" => "mediumorchid",
+    "dkorchid" => "darkorchid",
+    "darkvoilet" => "darkviolet",
+    "dkviolet" => "darkviolet",
+    "dkvoilet" => "darkviolet",
+    "bluevoilet" => "blueviolet",
+    "medpurple" => "mediumpurple",
+    "lavendarblush1" => "lavenderblush1",
+    "lavendarblush2" => "lavenderblush2",
+    "lavendarblush3" => "lavenderblush3",
+    "lavendarblush4" => "lavenderblush4",
+    "ltskyblue1" => "lightskyblue1",
+    "ltskyblue2" => "lightskyblue2",
+    "ltskyblue3" => "lightskyblue3",
+    "ltskyblue4" => "lightskyblue4",
+    "slategrey1" => "slategray1",
+    "slategrey2" => "slategray2",
+    "slategrey3" => "slategray3",
+    "slategrey4" => "slategray4",
+    "ltsteelblue1" => "lightsteelblue1",
+    "ltsteelblue2" => "lightsteelblue2",
+    "ltsteelblue3" => "lightsteelblue3",
+    "ltsteelblue4" => "lightsteelblue4",
+    "ltblue1" => "lightblue1",
+    "ltblue2" => "lightblue2",
+    "ltblue3" => "lightblue3",
+    "ltblue4" => "lightblue4",
+    "ltcyan1" => "lightcyan1",
+    "ltcyan2" => "lightcyan2",
+    "ltcyan3" => "lightcyan3",
+    "ltcyan4" => "lightcyan4",
+    "paleturqoise1" => "paleturquoise1",
+    "paleturquise1" => "paleturquoise1",
+    "paleturqoise2" => "paleturquoise2",
+    "paleturquise2" => "paleturquoise2",
+    "paleturqoise3" => "paleturquoise3",
+    "paleturquise3" => "paleturquoise3",
+    "paleturqoise4" => "paleturquoise4",
+    "paleturquise4" => "paleturquoise4",
+    "turqoise1" => "turquoise1",
+    "turquise1" => "turquoise1",
+    "turqoise2" => "turquoise2",
+    "turquise2" => "turquoise2",
+    "turqoise3" => "turquoise3",
+    "turquise3" => "turquoise3",
+    "turqoise4" => "turquoise4",
+    "turquise4" => "turquoise4",
+    "darkslategrey1" => "darkslategray1",
+    "dkslategray1" => "darkslategray1",
+    "dkslategrey1" => "darkslategray1",
+    "darkslategrey2" => "darkslategray2",
+    "dkslategray2" => "darkslategray2",
+    "dkslategrey2" => "darkslategray2",
+    "darkslategrey3" => "darkslategray3",
+    "dkslategray3" => "darkslategray3",
+    "dkslategrey3" => "darkslategray3",
+    "darkslategrey4" => "darkslategray4",
+    "dkslategray4" => "darkslategray4",
+    "dkslategrey4" => "darkslategray4",
+    "dkseagreen1" => "darkseagreen1",
+    "dkseagreen2" => "darkseagreen2",
+    "dkseagreen3" => "darkseagreen3",
+    "dkseagreen4" => "darkseagreen4",
+    "dkolivegreen1" => "darkolivegreen1",
+    "dkolivegreen2" => "darkolivegreen2",
+    "dkolivegreen3" => "darkolivegreen3",
+    "dkolivegreen4" => "darkolivegreen4",
+    "ltgoldenrod1" => "lightgoldenrod1",
+    "ltgoldenrod2" => "lightgoldenrod2",
+    "ltgoldenrod3" => "lightgoldenrod3",
+    "ltgoldenrod4" => "lightgoldenrod4",
+    "ltyellow1" => "lightyellow1",
+    "ltyellow2" => "lightyellow2",
+    "ltyellow3" => "lightyellow3",
+    "ltyellow4" => "lightyellow4",
+    "dkgoldenrod1" => "darkgoldenrod1",
+    "dkgoldenrod2" => "darkgoldenrod2",
+    "dkgoldenrod3" => "darkgoldenrod3",
+    "dkgoldenrod4" => "darkgoldenrod4",
+    "ltsalmon1" => "lightsalmon1",
+    "ltsalmon2" => "lightsalmon2",
+    "ltsalmon3" => "lightsalmon3",
+    "ltsalmon4" => "lightsalmon4",
+    "dkorange1" => "darkorange1",
+    "dkorange2" => "darkorange2",
+    "dkorange3" => "darkorange3",
+    "dkorange4" => "darkorange4",
+    "ltpink1" => "lightpink1",
+    "ltpink2" => "lightpink2",
+    "ltpink3" => "lightpink3",
+    "ltpink4" => "lightpink4",
+    "palevoiletred1" => "palevioletred1",
+    "palevoiletred2" => "palevioletred2",
+    "palevoiletred3" => "palevioletred3",
+    "palevoiletred4" => "palevioletred4",
+    "voiletred1" => "violetred1",
+    "voiletred2" => "violetred2",
+    "voiletred3" => "violetred3",
+    "voiletred4" => "violetred4",
+    "megenta1" => "magenta1",
+    "megenta2" => "magenta2",
+    "megenta3" => "magenta3",
+    "megenta4" => "magenta4",
+    "medorchid1" => "mediumorchid1",
+    "medorchid2" => "mediumorchid2",
+    "medorchid3" => "mediumorchid3",
+    "medorchid4" => "mediumorchid4",
+    "dkorchid1" => "darkorchid1",
+    "dkorchid2" => "darkorchid2",
+    "dkorchid3" => "darkorchid3",
+    "dkorchid4" => "darkorchid4",
+    "medpurple1" => "mediumpurple1",
+    "medpurple2" => "mediumpurple2",
+    "medpurple3" => "mediumpurple3",
+    "medpurple4" => "mediumpurple4",
+    "dkgrey" => "darkgrey",
+    "dkgray" => "darkgrey",
+    "dkblue" => "darkblue",
+    "dkcyan" => "darkcyan",
+    "darkmegenta" => "darkmagenta",
+    "dkmagenta" => "darkmagenta",
+    "dkmegenta" => "darkmagenta",
+    "dkred" => "darkred",
+    "ltgreen" => "lightgreen",
+};
+
+/// `X11_COLORS` keys sharing a base name, for names that aren't simply their own key.
+pub(crate) static X11_COLORS_VARIANTS: phf::Map<&'static str, &'static [&'static str]> = phf::phf_map! {
+};
+
+/// `X11_COLORS` keys ordered by display name, case-insensitively.
+pub(crate) static X11_COLORS_BY_NAME: [&str; 658] = [
+    "aliceblue",
+    "antiquewhite",
+    "antiquewhite1",
+    "antiquewhite2",
+    "antiquewhite3",
+    "antiquewhite4",
+    "aquamarine",
+    "aquamarine1",
+    "aquamarine2",
+    "aquamarine3",
+    "aquamarine4",
+    "azure",
+    "azure1",
+    "azure2",
+    "azure3",
+    "azure4",
+    "beige",
+    "bisque",
+    "bisque1",
+    "bisque2",
+    "bisque3",
+    "bisque4",
+    "black",
+    "blanchedalmond",
+    "blue",
+    "blueviolet",
+    "blue1",
+    "blue2",
+    "blue3",
+    "blue4",
+    "brown",
+    "brown1",
+    "brown2",
+    "brown3",
+    "brown4",
+    "burlywood",
+    "burlywood1",
+    "burlywood2",
+    "burlywood3",
+    "burlywood4",
+    "cadetblue",
+    "cadetblue1",
+    "cadetblue2",
+    "cadetblue3",
+    "cadetblue4",
+    "chartreuse",
+    "chartreuse1",
+    "chartreuse2",
+    "chartreuse3",
+    "chartreuse4",
+    "chocolate",
+    "chocolate1",
+    "chocolate2",
+    "chocolate3",
+    "chocolate4",
+    "coral",
+    "coral1",
+    "coral2",
+    "coral3",
+    "coral4",
+    "cornflowerblue",
+    "cornsilk",
+    "cornsilk1",
+    "cornsilk2",
+    "cornsilk3",
+    "cornsilk4",
+    "cyan",
+    "cyan1",
+    "cyan2",
+    "cyan3",
+    "cyan4",
+    "darkblue",
+    "darkcyan",
+    "darkgoldenrod",
+    "darkgray",
+    "darkgreen",
+    "darkgrey",
+    "darkkhaki",
+    "darkmagenta",
+    "darkolivegreen",
+    "darkorange",
+    "darkorchid",
+    "darkred",
+    "darksalmon",
+    "darkseagreen",
+    "darkslateblue",
+    "darkslategray",
+    "darkslategrey",
+    "darkturquoise",
+    "darkviolet",
+    "darkgoldenrod1",
+    "darkgoldenrod2",
+    "darkgoldenrod3",
+    "darkgoldenrod4",
+    "darkolivegreen1",
+    "darkolivegreen2",
+    "darkolivegreen3",
+    "darkolivegreen4",
+    "darkorange1",
+    "darkorange2",
+    "darkorange3",
+    "darkorange4",
+    "darkorchid1",
+    "darkorchid2",
+    "darkorchid3",
+    "darkorchid4",
+    "darkseagreen1",
+    "darkseagreen2",
+    "darkseagreen3",
+    "darkseagreen4",
+    "darkslategray1",
+    "darkslategray2",
+    "darkslategray3",
+    "darkslategray4",
+    "debianred",
+    "deeppink",
+    "deepskyblue",
+    "deeppink1",
+    "deeppink2",
+    "deeppink3",
+    "deeppink4",
+    "deepskyblue1",
+    "deepskyblue2",
+    "deepskyblue3",
+    "deepskyblue4",
+    "dimgray",
+    "dimgrey",
+    "dodgerblue",
+    "dodgerblue1",
+    "dodgerblue2",
+    "dodgerblue3",
+    "dodgerblue4",
+    "firebrick",
+    "firebrick1",
+    "firebrick2",
+    "firebrick3",
+    "firebrick4",
+    "floralwhite",
+    "forestgreen",
+    "gainsboro",
+    "ghostwhite",
+    "gold",
+    "gold1",
+    "gold2",
+    "gold3",
+    "gold4",
+    "goldenrod",
+    "goldenrod1",
+    "goldenrod2",
+    "goldenrod3",
+    "goldenrod4",
+    "gray",
+    "gray0",
+    "gray1",
+    "gray10",
+    "gray100",
+    "gray11",
+    "gray12",
+    "gray13",
+    "gray14",
+    "gray15",
+    "gray16",
+    "gray17",
+    "gray18",
+    "gray19",
+    "gray2",
+    "gray20",
+    "gray21",
+    "gray22",
+    "gray23",
+    "gray24",
+    "gray25",
+    "gray26",
+    "gray27",
+    "gray28",
+    "gray29",
+    "gray3",
+    "gray30",
+    "gray31",
+    "gray32",
+    "gray33",
+    "gray34",
+    "gray35",
+    "gray36",
+    "gray37",
+    "gray38",
+    "gray39",
+    "gray4",
+    "gray40",
+    "gray41",
+    "gray42",
+    "gray43",
+    "gray44",
+    "gray45",
+    "gray46",
+    "gray47",
+    "gray48",
+    "gray49",
+    "gray5",
+    "gray50",
+    "gray51",
+    "gray52",
+    "gray53",
+    "gray54",
+    "gray55",
+    "gray56",
+    "gray57",
+    "gray58",
+    "gray59",
+    "gray6",
+    "gray60",
+    "gray61",
+    "gray62",
+    "gray63",
+    "gray64",
+    "gray65",
+    "gray66",
+    "gray67",
+    "gray68",
+    "gray69",
+    "gray7",
+    "gray70",
+    "gray71",
+    "gray72",
+    "gray73",
+    "gray74",
+    "gray75",
+    "gray76",
+    "gray77",
+    "gray78",
+    "gray79",
+    "gray8",
+    "gray80",
+    "gray81",
+    "gray82",
+    "gray83",
+    "gray84",
+    "gray85",
+    "gray86",
+    "gray87",
+    "gray88",
+    "gray89",
+    "gray9",
+    "gray90",
+    "gray91",
+    "gray92",
+    "gray93",
+    "gray94",
+    "gray95",
+    "gray96",
+    "gray97",
+    "gray98",
+    "gray99",
+    "green",
+    "greenyellow",
+    "green1",
+    "green2",
+    "green3",
+    "green4",
+    "grey",
+    "grey0",
+    "grey1",
+    "grey10",
+    "grey100",
+    "grey11",
+    "grey12",
+    "grey13",
+    "grey14",
+    "grey15",
+    "grey16",
+    "grey17",
+    "grey18",
+    "grey19",
+    "grey2",
+    "grey20",
+    "grey21",
+    "grey22",
+    "grey23",
+    "grey24",
+    "grey25",
+    "grey26",
+    "grey27",
+    "grey28",
+    "grey29",
+    "grey3",
+    "grey30",
+    "grey31",
+    "grey32",
+    "grey33",
+    "grey34",
+    "grey35",
+    "grey36",
+    "grey37",
+    "grey38",
+    "grey39",
+    "grey4",
+    "grey40",
+    "grey41",
+    "grey42",
+    "grey43",
+    "grey44",
+    "grey45",
+    "grey46",
+    "grey47",
+    "grey48",
+    "grey49",
+    "grey5",
+    "grey50",
+    "grey51",
+    "grey52",
+    "grey53",
+    "grey54",
+    "grey55",
+    "grey56",
+    "grey57",
+    "grey58",
+    "grey59",
+    "grey6",
+    "grey60",
+    "grey61",
+    "grey62",
+    "grey63",
+    "grey64",
+    "grey65",
+    "grey66",
+    "grey67",
+    "grey68",
+    "grey69",
+    "grey7",
+    "grey70",
+    "grey71",
+    "grey72",
+    "grey73",
+    "grey74",
+    "grey75",
+    "grey76",
+    "grey77",
+    "grey78",
+    "grey79",
+    "grey8",
+    "grey80",
+    "grey81",
+    "grey82",
+    "grey83",
+    "grey84",
+    "grey85",
+    "grey86",
+    "grey87",
+    "grey88",
+    "grey89",
+    "grey9",
+    "grey90",
+    "grey91",
+    "grey92",
+    "grey93",
+    "grey94",
+    "grey95",
+    "grey96",
+    "grey97",
+    "grey98",
+    "grey99",
+    "honeydew",
+    "honeydew1",
+    "honeydew2",
+    "honeydew3",
+    "honeydew4",
+    "hotpink",
+    "hotpink1",
+    "hotpink2",
+    "hotpink3",
+    "hotpink4",
+    "indianred",
+    "indianred1",
+    "indianred2",
+    "indianred3",
+    "indianred4",
+    "ivory",
+    "ivory1",
+    "ivory2",
+    "ivory3",
+    "ivory4",
+    "khaki",
+    "khaki1",
+    "khaki2",
+    "khaki3",
+    "khaki4",
+    "lavender",
+    "lavenderblush",
+    "lavenderblush1",
+    "lavenderblush2",
+    "lavenderblush3",
+    "lavenderblush4",
+    "lawngreen",
+    "lemonchiffon",
+    "lemonchiffon1",
+    "lemonchiffon2",
+    "lemonchiffon3",
+    "lemonchiffon4",
+    "lightblue",
+    "lightcoral",
+    "lightcyan",
+    "lightgoldenrod",
+    "lightgoldenrodyellow",
+    "lightgray",
+    "lightgreen",
+    "lightgrey",
+    "lightpink",
+    "lightsalmon",
+    "lightseagreen",
+    "lightskyblue",
+    "lightslateblue",
+    "lightslategray",
+    "lightslategrey",
+    "lightsteelblue",
+    "lightyellow",
+    "lightblue1",
+    "lightblue2",
+    "lightblue3",
+    "lightblue4",
+    "lightcyan1",
+    "lightcyan2",
+    "lightcyan3",
+    "lightcyan4",
+    "lightgoldenrod1",
+    "lightgoldenrod2",
+    "lightgoldenrod3",
+    "lightgoldenrod4",
+    "lightpink1",
+    "lightpink2",
+    "lightpink3",
+    "lightpink4",
+    "lightsalmon1",
+    "lightsalmon2",
+    "lightsalmon3",
+    "lightsalmon4",
+    "lightskyblue1",
+    "lightskyblue2",
+    "lightskyblue3",
+    "lightskyblue4",
+    "lightsteelblue1",
+    "lightsteelblue2",
+    "lightsteelblue3",
+    "lightsteelblue4",
+    "lightyellow1",
+    "lightyellow2",
+    "lightyellow3",
+    "lightyellow4",
+    "limegreen",
+    "linen",
+    "magenta",
+    "magenta1",
+    "magenta2",
+    "magenta3",
+    "magenta4",
+    "maroon",
+    "maroon1",
+    "maroon2",
+    "maroon3",
+    "maroon4",
+    "mediumaquamarine",
+    "mediumblue",
+    "mediumorchid",
+    "mediumpurple",
+    "mediumseagreen",
+    "mediumslateblue",
+    "mediumspringgreen",
+    "mediumturquoise",
+    "mediumvioletred",
+    "mediumorchid1",
+    "mediumorchid2",
+    "mediumorchid3",
+    "mediumorchid4",
+    "mediumpurple1",
+    "mediumpurple2",
+    "mediumpurple3",
+    "mediumpurple4",
+    "midnightblue",
+    "mintcream",
+    "mistyrose",
+    "mistyrose1",
+    "mistyrose2",
+    "mistyrose3",
+    "mistyrose4",
+    "moccasin",
+    "navajowhite",
+    "navajowhite1",
+    "navajowhite2",
+    "navajowhite3",
+    "navajowhite4",
+    "navy",
+    "navyblue",
+    "oldlace",
+    "olivedrab",
+    "olivedrab1",
+    "olivedrab2",
+    "olivedrab3",
+    "olivedrab4",
+    "orange",
+    "orangered",
+    "orange1",
+    "orange2",
+    "orange3",
+    "orange4",
+    "orangered1",
+    "orangered2",
+    "orangered3",
+    "orangered4",
+    "orchid",
+    "orchid1",
+    "orchid2",
+    "orchid3",
+    "orchid4",
+    "palegoldenrod",
+    "palegreen",
+    "paleturquoise",
+    "palevioletred",
+    "palegreen1",
+    "palegreen2",
+    "palegreen3",
+    "palegreen4",
+    "paleturquoise1",
+    "paleturquoise2",
+    "paleturquoise3",
+    "paleturquoise4",
+    "palevioletred1",
+    "palevioletred2",
+    "palevioletred3",
+    "palevioletred4",
+    "papayawhip",
+    "peachpuff",
+    "peachpuff1",
+    "peachpuff2",
+    "peachpuff3",
+    "peachpuff4",
+    "peru",
+    "pink",
+    "pink1",
+    "pink2",
+    "pink3",
+    "pink4",
+    "plum",
+    "plum1",
+    "plum2",
+    "plum3",
+    "plum4",
+    "powderblue",
+    "purple",
+    "purple1",
+    "purple2",
+    "purple3",
+    "purple4",
+    "red",
+    "red1",
+    "red2",
+    "red3",
+    "red4",
+    "rosybrown",
+    "rosybrown1",
+    "rosybrown2",
+    "rosybrown3",
+    "rosybrown4",
+    "royalblue",
+    "royalblue1",
+    "royalblue2",
+    "royalblue3",
+    "royalblue4",
+    "saddlebrown",
+    "salmon",
+    "salmon1",
+    "salmon2",
+    "salmon3",
+    "salmon4",
+    "sandybrown",
+    "seagreen",
+    "seagreen1",
+    "seagreen2",
+    "seagreen3",
+    "seagreen4",
+    "seashell",
+    "seashell1",
+    "seashell2",
+    "seashell3",
+    "seashell4",
+    "sienna",
+    "sienna1",
+    "sienna2",
+    "sienna3",
+    "sienna4",
+    "skyblue",
+    "skyblue1",
+    "skyblue2",
+    "skyblue3",
+    "skyblue4",
+    "slateblue",
+    "slategray",
+    "slategrey",
+    "slateblue1",
+    "slateblue2",
+    "slateblue3",
+    "slateblue4",
+    "slategray1",
+    "slategray2",
+    "slategray3",
+    "slategray4",
+    "snow",
+    "snow1",
+    "snow2",
+    "snow3",
+    "snow4",
+    "springgreen",
+    "springgreen1",
+    "springgreen2",
+    "springgreen3",
+    "springgreen4",
+    "steelblue",
+    "steelblue1",
+    "steelblue2",
+    "steelblue3",
+    "steelblue4",
+    "tan",
+    "tan1",
+    "tan2",
+    "tan3",
+    "tan4",
+    "thistle",
+    "thistle1",
+    "thistle2",
+    "thistle3",
+    "thistle4",
+    "tomato",
+    "tomato1",
+    "tomato2",
+    "tomato3",
+    "tomato4",
+    "turquoise",
+    "turquoise1",
+    "turquoise2",
+    "turquoise3",
+    "turquoise4",
+    "violet",
+    "violetred",
+    "violetred1",
+    "violetred2",
+    "violetred3",
+    "violetred4",
+    "wheat",
+    "wheat1",
+    "wheat2",
+    "wheat3",
+    "wheat4",
+    "white",
+    "whitesmoke",
+    "yellow",
+    "yellowgreen",
+    "yellow1",
+    "yellow2",
+    "yellow3",
+    "yellow4",
+];
+
+/// `X11_COLORS` keys in sorted order, for prefix search.
+pub(crate) static X11_COLORS_KEYS: [&str; 658] = [
+    "aliceblue",
+    "antiquewhite",
+    "antiquewhite1",
+    "antiquewhite2",
+    "antiquewhite3",
+    "antiquewhite4",
+    "aquamarine",
+    "aquamarine1",
+    "aquamarine2",
+    "aquamarine3",
+    "aquamarine4",
+    "azure",
+    "azure1",
+    "azure2",
+    "azure3",
+    "azure4",
+    "beige",
+    "bisque",
+    "bisque1",
+    "bisque2",
+    "bisque3",
+    "bisque4",
+    "black",
+    "blanchedalmond",
+    "blue",
+    "blue1",
+    "blue2",
+    "blue3",
+    "blue4",
+    "blueviolet",
+    "brown",
+    "brown1",
+    "brown2",
+    "brown3",
+    "brown4",
+    "burlywood",
+    "burlywood1",
+    "burlywood2",
+    "burlywood3",
+    "burlywood4",
+    "cadetblue",
+    "cadetblue1",
+    "cadetblue2",
+    "cadetblue3",
+    "cadetblue4",
+    "chartreuse",
+    "chartreuse1",
+    "chartreuse2",
+    "chartreuse3",
+    "chartreuse4",
+    "chocolate",
+    "chocolate1",
+    "chocolate2",
+    "chocolate3",
+    "chocolate4",
+    "coral",
+    "coral1",
+    "coral2",
+    "coral3",
+    "coral4",
+    "cornflowerblue",
+    "cornsilk",
+    "cornsilk1",
+    "cornsilk2",
+    "cornsilk3",
+    "cornsilk4",
+    "cyan",
+    "cyan1",
+    "cyan2",
+    "cyan3",
+    "cyan4",
+    "darkblue",
+    "darkcyan",
+    "darkgoldenrod",
+    "darkgoldenrod1",
+    "darkgoldenrod2",
+    "darkgoldenrod3",
+    "darkgoldenrod4",
+    "darkgray",
+    "darkgreen",
+    "darkgrey",
+    "darkkhaki",
+    "darkmagenta",
+    "darkolivegreen",
+    "darkolivegreen1",
+    "darkolivegreen2",
+    "darkolivegreen3",
+    "darkolivegreen4",
+    "darkorange",
+    "darkorange1",
+    "darkorange2",
+    "darkorange3",
+    "darkorange4",
+    "darkorchid",
+    "darkorchid1",
+    "darkorchid2",
+    "darkorchid3",
+    "darkorchid4",
+    "darkred",
+    "darksalmon",
+    "darkseagreen",
+    "darkseagreen1",
+    "darkseagreen2",
+    "darkseagreen3",
+    "darkseagreen4",
+    "darkslateblue",
+    "darkslategray",
+    "darkslategray1",
+    "darkslategray2",
+    "darkslategray3",
+    "darkslategray4",
+    "darkslategrey",
+    "darkturquoise",
+    "darkviolet",
+    "debianred",
+    "deeppink",
+    "deeppink1",
+    "deeppink2",
+    "deeppink3",
+    "deeppink4",
+    "deepskyblue",
+    "deepskyblue1",
+    "deepskyblue2",
+    "deepskyblue3",
+    "deepskyblue4",
+    "dimgray",
+    "dimgrey",
+    "dodgerblue",
+    "dodgerblue1",
+    "dodgerblue2",
+    "dodgerblue3",
+    "dodgerblue4",
+    "firebrick",
+    "firebrick1",
+    "firebrick2",
+    "firebrick3",
+    "firebrick4",
+    "floralwhite",
+    "forestgreen",
+    "gainsboro",
+    "ghostwhite",
+    "gold",
+    "gold1",
+    "gold2",
+    "gold3",
+    "gold4",
+    "goldenrod",
+    "goldenrod1",
+    "goldenrod2",
+    "goldenrod3",
+    "goldenrod4",
+    "gray",
+    "gray0",
+    "gray1",
+    "gray10",
+    "gray100",
+    "gray11",
+    "gray12",
+    "gray13",
+    "gray14",
+    "gray15",
+    "gray16",
+    "gray17",
+    "gray18",
+    "gray19",
+    "gray2",
+    "gray20",
+    "gray21",
+    "gray22",
+    "gray23",
+    "gray24",
+    "gray25",
+    "gray26",
+    "gray27",
+    "gray28",
+    "gray29",
+    "gray3",
+    "gray30",
+    "gray31",
+    "gray32",
+    "gray33",
+    "gray34",
+    "gray35",
+    "gray36",
+    "gray37",
+    "gray38",
+    "gray39",
+    "gray4",
+    "gray40",
+    "gray41",
+    "gray42",
+    "gray43",
+    "gray44",
+    "gray45",
+    "gray46",
+    "gray47",
+    "gray48",
+    "gray49",
+    "gray5",
+    "gray50",
+    "gray51",
+    "gray52",
+    "gray53",
+    "gray54",
+    "gray55",
+    "gray56",
+    "gray57",
+    "gray58",
+    "gray59",
+    "gray6",
+    "gray60",
+    "gray61",
+    "gray62",
+    "gray63",
+    "gray64",
+    "gray65",
+    "gray66",
+    "gray67",
+    "gray68",
+    "gray69",
+    "gray7",
+    "gray70",
+    "gray71",
+    "gray72",
+    "gray73",
+    "gray74",
+    "gray75",
+    "gray76",
+    "gray77",
+    "gray78",
+    "gray79",
+    "gray8",
+    "gray80",
+    "gray81",
+    "gray82",
+    "gray83",
+    "gray84",
+    "gray85",
+    "gray86",
+    "gray87",
+    "gray88",
+    "gray89",
+    "gray9",
+    "gray90",
+    "gray91",
+    "gray92",
+    "gray93",
+    "gray94",
+    "gray95",
+    "gray96",
+    "gray97",
+    "gray98",
+    "gray99",
+    "green",
+    "green1",
+    "green2",
+    "green3",
+    "green4",
+    "greenyellow",
+    "grey",
+    "grey0",
+    "grey1",
+    "grey10",
+    "grey100",
+    "grey11",
+    "grey12",
+    "grey13",
+    "grey14",
+    "grey15",
+    "grey16",
+    "grey17",
+    "grey18",
+    "grey19",
+    "grey2",
+    "grey20",
+    "grey21",
+    "grey22",
+    "grey23",
+    "grey24",
+    "grey25",
+    "grey26",
+    "grey27",
+    "grey28",
+    "grey29",
+    "grey3",
+    "grey30",
+    "grey31",
+    "grey32",
+    "grey33",
+    "grey34",
+    "grey35",
+    "grey36",
+    "grey37",
+    "grey38",
+    "grey39",
+    "grey4",
+    "grey40",
+    "grey41",
+    "grey42",
+    "grey43",
+    "grey44",
+    "grey45",
+    "grey46",
+    "grey47",
+    "grey48",
+    "grey49",
+    "grey5",
+    "grey50",
+    "grey51",
+    "grey52",
+    "grey53",
+    "grey54",
+    "grey55",
+    "grey56",
+    "grey57",
+    "grey58",
+    "grey59",
+    "grey6",
+    "grey60",
+    "grey61",
+    "grey62",
+    "grey63",
+    "grey64",
+    "grey65",
+    "grey66",
+    "grey67",
+    "grey68",
+    "grey69",
+    "grey7",
+    "grey70",
+    "grey71",
+    "grey72",
+    "grey73",
+    "grey74",
+    "grey75",
+    "grey76",
+    "grey77",
+    "grey78",
+    "grey79",
+    "grey8",
+    "grey80",
+    "grey81",
+    "grey82",
+    "grey83",
+    "grey84",
+    "grey85",
+    "grey86",
+    "grey87",
+    "grey88",
+    "grey89",
+    "grey9",
+    "grey90",
+    "grey91",
+    "grey92",
+    "grey93",
+    "grey94",
+    "grey95",
+    "grey96",
+    "grey97",
+    "grey98",
+    "grey99",
+    "honeydew",
+    "honeydew1",
+    "honeydew2",
+    "honeydew3",
+    "honeydew4",
+    "hotpink",
+    "hotpink1",
+    "hotpink2",
+    "hotpink3",
+    "hotpink4",
+    "indianred",
+    "indianred1",
+    "indianred2",
+    "indianred3",
+    "indianred4",
+    "ivory",
+    "ivory1",
+    "ivory2",
+    "ivory3",
+    "ivory4",
+    "khaki",
+    "khaki1",
+    "khaki2",
+    "khaki3",
+    "khaki4",
+    "lavender",
+    "lavenderblush",
+    "lavenderblush1",
+    "lavenderblush2",
+    "lavenderblush3",
+    "lavenderblush4",
+    "lawngreen",
+    "lemonchiffon",
+    "lemonchiffon1",
+    "lemonchiffon2",
+    "lemonchiffon3",
+    "lemonchiffon4",
+    "lightblue",
+    "lightblue1",
+    "lightblue2",
+    "lightblue3",
+    "lightblue4",
+    "lightcoral",
+    "lightcyan",
+    "lightcyan1",
+    "lightcyan2",
+    "lightcyan3",
+    "lightcyan4",
+    "lightgoldenrod",
+    "lightgoldenrod1",
+    "lightgoldenrod2",
+    "lightgoldenrod3",
+    "lightgoldenrod4",
+    "lightgoldenrodyellow",
+    "lightgray",
+    "lightgreen",
+    "lightgrey",
+    "lightpink",
+    "lightpink1",
+    "lightpink2",
+    "lightpink3",
+    "lightpink4",
+    "lightsalmon",
+    "lightsalmon1",
+    "lightsalmon2",
+    "lightsalmon3",
+    "lightsalmon4",
+    "lightseagreen",
+    "lightskyblue",
+    "lightskyblue1",
+    "lightskyblue2",
+    "lightskyblue3",
+    "lightskyblue4",
+    "lightslateblue",
+    "lightslategray",
+    "lightslategrey",
+    "lightsteelblue",
+    "lightsteelblue1",
+    "lightsteelblue2",
+    "lightsteelblue3",
+    "lightsteelblue4",
+    "lightyellow",
+    "lightyellow1",
+    "lightyellow2",
+    "lightyellow3",
+    "lightyellow4",
+    "limegreen",
+    "linen",
+    "magenta",
+    "magenta1",
+    "magenta2",
+    "magenta3",
+    "magenta4",
+    "maroon",
+    "maroon1",
+    "maroon2",
+    "maroon3",
+    "maroon4",
+    "mediumaquamarine",
+    "mediumblue",
+    "mediumorchid",
+    "mediumorchid1",
+    "mediumorchid2",
+    "mediumorchid3",
+    "mediumorchid4",
+    "mediumpurple",
+    "mediumpurple1",
+    "mediumpurple2",
+    "mediumpurple3",
+    "mediumpurple4",
+    "mediumseagreen",
+    "mediumslateblue",
+    "mediumspringgreen",
+    "mediumturquoise",
+    "mediumvioletred",
+    "midnightblue",
+    "mintcream",
+    "mistyrose",
+    "mistyrose1",
+    "mistyrose2",
+    "mistyrose3",
+    "mistyrose4",
+    "moccasin",
+    "navajowhite",
+    "navajowhite1",
+    "navajowhite2",
+    "navajowhite3",
+    "navajowhite4",
+    "navy",
+    "navyblue",
+    "oldlace",
+    "olivedrab",
+    "olivedrab1",
+    "olivedrab2",
+    "olivedrab3",
+    "olivedrab4",
+    "orange",
+    "orange1",
+    "orange2",
+    "orange3",
+    "orange4",
+    "orangered",
+    "orangered1",
+    "orangered2",
+    "orangered3",
+    "orangered4",
+    "orchid",
+    "orchid1",
+    "orchid2",
+    "orchid3",
+    "orchid4",
+    "palegoldenrod",
+    "palegreen",
+    "palegreen1",
+    "palegreen2",
+    "palegreen3",
+    "palegreen4",
+    "paleturquoise",
+    "paleturquoise1",
+    "paleturquoise2",
+    "paleturquoise3",
+    "paleturquoise4",
+    "palevioletred",
+    "palevioletred1",
+    "palevioletred2",
+    "palevioletred3",
+    "palevioletred4",
+    "papayawhip",
+    "peachpuff",
+    "peachpuff1",
+    "peachpuff2",
+    "peachpuff3",
+    "peachpuff4",
+    "peru",
+    "pink",
+    "pink1",
+    "pink2",
+    "pink3",
+    "pink4",
+    "plum",
+    "plum1",
+    "plum2",
+    "plum3",
+    "plum4",
+    "powderblue",
+    "purple",
+    "purple1",
+    "purple2",
+    "purple3",
+    "purple4",
+    "red",
+    "red1",
+    "red2",
+    "red3",
+    "red4",
+    "rosybrown",
+    "rosybrown1",
+    "rosybrown2",
+    "rosybrown3",
+    "rosybrown4",
+    "royalblue",
+    "royalblue1",
+    "royalblue2",
+    "royalblue3",
+    "royalblue4",
+    "saddlebrown",
+    "salmon",
+    "salmon1",
+    "salmon2",
+    "salmon3",
+    "salmon4",
+    "sandybrown",
+    "seagreen",
+    "seagreen1",
+    "seagreen2",
+    "seagreen3",
+    "seagreen4",
+    "seashell",
+    "seashell1",
+    "seashell2",
+    "seashell3",
+    "seashell4",
+    "sienna",
+    "sienna1",
+    "sienna2",
+    "sienna3",
+    "sienna4",
+    "skyblue",
+    "skyblue1",
+    "skyblue2",
+    "skyblue3",
+    "skyblue4",
+    "slateblue",
+    "slateblue1",
+    "slateblue2",
+    "slateblue3",
+    "slateblue4",
+    "slategray",
+    "slategray1",
+    "slategray2",
+    "slategray3",
+    "slategray4",
+    "slategrey",
+    "snow",
+    "snow1",
+    "snow2",
+    "snow3",
+    "snow4",
+    "springgreen",
+    "springgreen1",
+    "springgreen2",
+    "springgreen3",
+    "springgreen4",
+    "steelblue",
+    "steelblue1",
+    "steelblue2",
+    "steelblue3",
+    "steelblue4",
+    "tan",
+    "tan1",
+    "tan2",
+    "tan3",
+    "tan4",
+    "thistle",
+    "thistle1",
+    "thistle2",
+    "thistle3",
+    "thistle4",
+    "tomato",
+    "tomato1",
+    "tomato2",
+    "tomato3",
+    "tomato4",
+    "turquoise",
+    "turquoise1",
+    "turquoise2",
+    "turquoise3",
+    "turquoise4",
+    "violet",
+    "violetred",
+    "violetred1",
+    "violetred2",
+    "violetred3",
+    "violetred4",
+    "wheat",
+    "wheat1",
+    "wheat2",
+    "wheat3",
+    "wheat4",
+    "white",
+    "whitesmoke",
+    "yellow",
+    "yellow1",
+    "yellow2",
+    "yellow3",
+    "yellow4",
+    "yellowgreen",
+];
+
+/// Every word of every display name, sorted, with the keys (in display-name order) using it.
+pub(crate) static X11_COLORS_WORDS: [(&str, &[&str]); 611] = [
+    ("alice", &["aliceblue"]),
+    ("almond", &["blanchedalmond"]),
+    ("antique", &["antiquewhite"]),
+    ("antiquewhite1", &["antiquewhite1"]),
+    ("antiquewhite2", &["antiquewhite2"]),
+    ("antiquewhite3", &["antiquewhite3"]),
+    ("antiquewhite4", &["antiquewhite4"]),
+    ("aquamarine", &["aquamarine", "mediumaquamarine"]),
+    ("aquamarine1", &["aquamarine1"]),
+    ("aquamarine2", &["aquamarine2"]),
+    ("aquamarine3", &["aquamarine3"]),
+    ("aquamarine4", &["aquamarine4"]),
+    ("azure", &["azure"]),
+    ("azure1", &["azure1"]),
+    ("azure2", &["azure2"]),
+    ("azure3", &["azure3"]),
+    ("azure4", &["azure4"]),
+    ("beige", &["beige"]),
+    ("bisque", &["bisque"]),
+    ("bisque1", &["bisque1"]),
+    ("bisque2", &["bisque2"]),
+    ("bisque3", &["bisque3"]),
+    ("bisque4", &["bisque4"]),
+    ("black", &["black"]),
+    ("blanched", &["blanchedalmond"]),
+    ("blue", &["aliceblue", "blue", "blueviolet", "cadetblue", "cornflowerblue", "darkblue", "darkslateblue", "deepskyblue", "dodgerblue", "lightblue", "lightskyblue", "lightslateblue", "lightsteelblue", "mediumblue", "mediumslateblue", "midnightblue", "navyblue", "powderblue", "royalblue", "skyblue", "slateblue", "steelblue"]),
+    ("blue1", &["blue1"]),
+    ("blue2", &["blue2"]),
+    ("blue3", &["blue3"]),
+    ("blue4", &["blue4"]),
+    ("blush", &["lavenderblush"]),
+    ("brown", &["brown", "rosybrown", "saddlebrown", "sandybrown"]),
+    ("brown1", &["brown1"]),
+    ("brown2", &["brown2"]),
+    ("brown3", &["brown3"]),
+    ("brown4", &["brown4"]),
+    ("burlywood", &["burlywood"]),
+    ("burlywood1", &["burlywood1"]),
+    ("burlywood2", &["burlywood2"]),
+    ("burlywood3", &["burlywood3"]),
+    ("burlywood4", &["burlywood4"]),
+    ("cadet", &["cadetblue"]),
+    ("cadetblue1", &["cadetblue1"]),
+    ("cadetblue2", &["cadetblue2"]),
+    ("cadetblue3", &["cadetblue3"]),
+    ("cadetblue4", &["cadetblue4"]),
+    ("chartreuse", &["chartreuse"]),
+    ("chartreuse1", &["chartreuse1"]),
+    ("chartreuse2", &["chartreuse2"]),
+    ("chartreuse3", &["chartreuse3"]),
+    ("chartreuse4", &["chartreuse4"]),
+    ("chiffon", &["lemonchiffon"]),
+    ("chocolate", &["chocolate"]),
+    ("chocolate1", &["chocolate1"]),
+    ("chocolate2", &["chocolate2"]),
+    ("chocolate3", &["chocolate3"]),
+    ("chocolate4", &["chocolate4"]),
+    ("coral", &["coral", "lightcoral"]),
+    ("coral1", &["coral1"]),
+    ("coral2", &["coral2"]),
+    ("coral3", &["coral3"]),
+    ("coral4", &["coral4"]),
+    ("cornflower", &["cornflowerblue"]),
+    ("cornsilk", &["cornsilk"]),
+    ("cornsilk1", &["cornsilk1"]),
+    ("cornsilk2", &["cornsilk2"]),
+    ("cornsilk3", &["cornsilk3"]),
+    ("cornsilk4", &["cornsilk4"]),
+    ("cream", &["mintcream"]),
+    ("cyan", &["cyan", "darkcyan", "lightcyan"]),
+    ("cyan1", &["cyan1"]),
+    ("cyan2", &["cyan2"]),
+    ("cyan3", &["cyan3"]),
+    ("cyan4", &["cyan4"]),
+    ("dark", &["darkblue", "darkcyan", "darkgoldenrod", "darkgray", "darkgreen", "darkgrey", "darkkhaki", "darkmagenta", "darkolivegreen", "darkorange", "darkorchid", "darkred", "darksalmon", "darkseagreen", "darkslateblue", "darkslategray", "darkslategrey", "darkturquoise", "darkviolet"]),
+    ("darkgoldenrod1", &["darkgoldenrod1"]),
+    ("darkgoldenrod2", &["darkgoldenrod2"]),
+    ("darkgoldenrod3", &["darkgoldenrod3"]),
+    ("darkgoldenrod4", &["darkgoldenrod4"]),
+    ("darkolivegreen1", &["darkolivegreen1"]),
+    ("darkolivegreen2", &["darkolivegreen2"]),
+    ("darkolivegreen3", &["darkolivegreen3"]),
+    ("darkolivegreen4", &["darkolivegreen4"]),
+    ("darkorange1", &["darkorange1"]),
+    ("darkorange2", &["darkorange2"]),
+    ("darkorange3", &["darkorange3"]),
+    ("darkorange4", &["darkorange4"]),
+    ("darkorchid1", &["darkorchid1"]),
+    ("darkorchid2", &["darkorchid2"]),
+    ("darkorchid3", &["darkorchid3"]),
+    ("darkorchid4", &["darkorchid4"]),
+    ("darkseagreen1", &["darkseagreen1"]),
+    ("darkseagreen2", &["darkseagreen2"]),
+    ("darkseagreen3", &["darkseagreen3"]),
+    ("darkseagreen4", &["darkseagreen4"]),
+    ("darkslategray1", &["darkslategray1"]),
+    ("darkslategray2", &["darkslategray2"]),
+    ("darkslategray3", &["darkslategray3"]),
+    ("darkslategray4", &["darkslategray4"]),
+    ("debianred", &["debianred"]),
+    ("deep", &["deeppink", "deepskyblue"]),
+    ("deeppink1", &["deeppink1"]),
+    ("deeppink2", &["deeppink2"]),
+    ("deeppink3", &["deeppink3"]),
+    ("deeppink4", &["deeppink4"]),
+    ("deepskyblue1", &["deepskyblue1"]),
+    ("deepskyblue2", &["deepskyblue2"]),
+    ("deepskyblue3", &["deepskyblue3"]),
+    ("deepskyblue4", &["deepskyblue4"]),
+    ("dim", &["dimgray", "dimgrey"]),
+    ("dodger", &["dodgerblue"]),
+    ("dodgerblue1", &["dodgerblue1"]),
+    ("dodgerblue2", &["dodgerblue2"]),
+    ("dodgerblue3", &["dodgerblue3"]),
+    ("dodgerblue4", &["dodgerblue4"]),
+    ("drab", &["olivedrab"]),
+    ("firebrick", &["firebrick"]),
+    ("firebrick1", &["firebrick1"]),
+    ("firebrick2", &["firebrick2"]),
+    ("firebrick3", &["firebrick3"]),
+    ("firebrick4", &["firebrick4"]),
+    ("floral", &["floralwhite"]),
+    ("forest", &["forestgreen"]),
+    ("gainsboro", &["gainsboro"]),
+    ("ghost", &["ghostwhite"]),
+    ("gold", &["gold"]),
+    ("gold1", &["gold1"]),
+    ("gold2", &["gold2"]),
+    ("gold3", &["gold3"]),
+    ("gold4", &["gold4"]),
+    ("goldenrod", &["darkgoldenrod", "goldenrod", "lightgoldenrod", "lightgoldenrodyellow", "palegoldenrod"]),
+    ("goldenrod1", &["goldenrod1"]),
+    ("goldenrod2", &["goldenrod2"]),
+    ("goldenrod3", &["goldenrod3"]),
+    ("goldenrod4", &["goldenrod4"]),
+    ("gray", &["darkgray", "darkslategray", "dimgray", "gray", "lightgray", "lightslategray", "slategray"]),
+    ("gray0", &["gray0"]),
+    ("gray1", &["gray1"]),
+    ("gray10", &["gray10"]),
+    ("gray100", &["gray100"]),
+    ("gray11", &["gray11"]),
+    ("gray12", &["gray12"]),
+    ("gray13", &["gray13"]),
+    ("gray14", &["gray14"]),
+    ("gray15", &["gray15"]),
+    ("gray16", &["gray16"]),
+    ("gray17", &["gray17"]),
+    ("gray18", &["gray18"]),
+    ("gray19", &["gray19"]),
+    ("gray2", &["gray2"]),
+    ("gray20", &["gray20"]),
+    ("gray21", &["gray21"]),
+    ("gray22", &["gray22"]),
+    ("gray23", &["gray23"]),
+    ("gray24", &["gray24"]),
+    ("gray25", &["gray25"]),
+    ("gray26", &["gray26"]),
+    ("gray27", &["gray27"]),
+    ("gray28", &["gray28"]),
+    ("gray29", &["gray29"]),
+    ("gray3", &["gray3"]),
+    ("gray30", &["gray30"]),
+    ("gray31", &["gray31"]),
+    ("gray32", &["gray32"]),
+    ("gray33", &["gray33"]),
+    ("gray34", &["gray34"]),
+    ("gray35", &["gray35"]),
+    ("gray36", &["gray36"]),
+    ("gray37", &["gray37"]),
+    ("gray38", &["gray38"]),
+    ("gray39", &["gray39"]),
+    ("gray4", &["gray4"]),
+    ("gray40", &["gray40"]),
+    ("gray41", &["gray41"]),
+    ("gray42", &["gray42"]),
+    ("gray43", &["gray43"]),
+    ("gray44", &["gray44"]),
+    ("gray45", &["gray45"]),
+    ("gray46", &["gray46"]),
+    ("gray47", &["gray47"]),
+    ("gray48", &["gray48"]),
+    ("gray49", &["gray49"]),
+    ("gray5", &["gray5"]),
+    ("gray50", &["gray50"]),
+    ("gray51", &["gray51"]),
+    ("gray52", &["gray52"]),
+    ("gray53", &["gray53"]),
+    ("gray54", &["gray54"]),
+    ("gray55", &["gray55"]),
+    ("gray56", &["gray56"]),
+    ("gray57", &["gray57"]),
+    ("gray58", &["gray58"]),
+    ("gray59", &["gray59"]),
+    ("gray6", &["gray6"]),
+    ("gray60", &["gray60"]),
+    ("gray61", &["gray61"]),
+    ("gray62", &["gray62"]),
+    ("gray63", &["gray63"]),
+    ("gray64", &["gray64"]),
+    ("gray65", &["gray65"]),
+    ("gray66", &["gray66"]),
+    ("gray67", &["gray67"]),
+    ("gray68", &["gray68"]),
+    ("gray69", &["gray69"]),
+    ("gray7", &["gray7"]),
+    ("gray70", &["gray70"]),
+    ("gray71", &["gray71"]),
+    ("gray72", &["gray72"]),
+    ("gray73", &["gray73"]),
+    ("gray74", &["gray74"]),
+    ("gray75", &["gray75"]),
+    ("gray76", &["gray76"]),
+    ("gray77", &["gray77"]),
+    ("gray78", &["gray78"]),
+    ("gray79", &["gray79"]),
+    ("gray8", &["gray8"]),
+    ("gray80", &["gray80"]),
+    ("gray81", &["gray81"]),
+    ("gray82", &["gray82"]),
+    ("gray83", &["gray83"]),
+    ("gray84", &["gray84"]),
+    ("gray85", &["gray85"]),
+    ("gray86", &["gray86"]),
+    ("gray87", &["gray87"]),
+    ("gray88", &["gray88"]),
+    ("gray89", &["gray89"]),
+    ("gray9", &["gray9"]),
+    ("gray90", &["gray90"]),
+    ("gray91", &["gray91"]),
+    ("gray92", &["gray92"]),
+    ("gray93", &["gray93"]),
+    ("gray94", &["gray94"]),
+    ("gray95", &["gray95"]),
+    ("gray96", &["gray96"]),
+    ("gray97", &["gray97"]),
+    ("gray98", &["gray98"]),
+    ("gray99", &["gray99"]),
+    ("green", &["darkgreen", "darkolivegreen", "darkseagreen", "forestgreen", "green", "greenyellow", "lawngreen", "lightgreen", "lightseagreen", "limegreen", "mediumseagreen", "mediumspringgreen", "palegreen", "seagreen", "springgreen", "yellowgreen"]),
+    ("green1", &["green1"]),
+    ("green2", &["green2"]),
+    ("green3", &["green3"]),
+    ("green4", &["green4"]),
+    ("grey", &["darkgrey", "darkslategrey", "dimgrey", "grey", "lightgrey", "lightslategrey", "slategrey"]),
+    ("grey0", &["grey0"]),
+    ("grey1", &["grey1"]),
+    ("grey10", &["grey10"]),
+    ("grey100", &["grey100"]),
+    ("grey11", &["grey11"]),
+    ("grey12", &["grey12"]),
+    ("grey13", &["grey13"]),
+    ("grey14", &["grey14"]),
+    ("grey15", &["grey15"]),
+    ("grey16", &["grey16"]),
+    ("grey17", &["grey17"]),
+    ("grey18", &["grey18"]),
+    ("grey19", &["grey19"]),
+    ("grey2", &["grey2"]),
+    ("grey20", &["grey20"]),
+    ("grey21", &["grey21"]),
+    ("grey22", &["grey22"]),
+    ("grey23", &["grey23"]),
+    ("grey24", &["grey24"]),
+    ("grey25", &["grey25"]),
+    ("grey26", &["grey26"]),
+    ("grey27", &["grey27"]),
+    ("grey28", &["grey28"]),
+    ("grey29", &["grey29"]),
+    ("grey3", &["grey3"]),
+    ("grey30", &["grey30"]),
+    ("grey31", &["grey31"]),
+    ("grey32", &["grey32"]),
+    ("grey33", &["grey33"]),
+    ("grey34", &["grey34"]),
+    ("grey35", &["grey35"]),
+    ("grey36", &["grey36"]),
+    ("grey37", &["grey37"]),
+    ("grey38", &["grey38"]),
+    ("grey39", &["grey39"]),
+    ("grey4", &["grey4"]),
+    ("grey40", &["grey40"]),
+    ("grey41", &["grey41"]),
+    ("grey42", &["grey42"]),
+    ("grey43", &["grey43"]),
+    ("grey44", &["grey44"]),
+    ("grey45", &["grey45"]),
+    ("grey46", &["grey46"]),
+    ("grey47", &["grey47"]),
+    ("grey48", &["grey48"]),
+    ("grey49", &["grey49"]),
+    ("grey5", &["grey5"]),
+    ("grey50", &["grey50"]),
+    ("grey51", &["grey51"]),
+    ("grey52", &["grey52"]),
+    ("grey53", &["grey53"]),
+    ("grey54", &["grey54"]),
+    ("grey55", &["grey55"]),
+    ("grey56", &["grey56"]),
+    ("grey57", &["grey57"]),
+    ("grey58", &["grey58"]),
+    ("grey59", &["grey59"]),
+    ("grey6", &["grey6"]),
+    ("grey60", &["grey60"]),
+    ("grey61", &["grey61"]),
+    ("grey62", &["grey62"]),
+    ("grey63", &["grey63"]),
+    ("grey64", &["grey64"]),
+    ("grey65", &["grey65"]),
+    ("grey66", &["grey66"]),
+    ("grey67", &["grey67"]),
+    ("grey68", &["grey68"]),
+    ("grey69", &["grey69"]),
+    ("grey7", &["grey7"]),
+    ("grey70", &["grey70"]),
+    ("grey71", &["grey71"]),
+    ("grey72", &["grey72"]),
+    ("grey73", &["grey73"]),
+    ("grey74", &["grey74"]),
+    ("grey75", &["grey75"]),
+    ("grey76", &["grey76"]),
+    ("grey77", &["grey77"]),
+    ("grey78", &["grey78"]),
+    ("grey79", &["grey79"]),
+    ("grey8", &["grey8"]),
+    ("grey80", &["grey80"]),
+    ("grey81", &["grey81"]),
+    ("grey82", &["grey82"]),
+    ("grey83", &["grey83"]),
+    ("grey84", &["grey84"]),
+    ("grey85", &["grey85"]),
+    ("grey86", &["grey86"]),
+    ("grey87", &["grey87"]),
+    ("grey88", &["grey88"]),
+    ("grey89", &["grey89"]),
+    ("grey9", &["grey9"]),
+    ("grey90", &["grey90"]),
+    ("grey91", &["grey91"]),
+    ("grey92", &["grey92"]),
+    ("grey93", &["grey93"]),
+    ("grey94", &["grey94"]),
+    ("grey95", &["grey95"]),
+    ("grey96", &["grey96"]),
+    ("grey97", &["grey97"]),
+    ("grey98", &["grey98"]),
+    ("grey99", &["grey99"]),
+    ("honeydew", &["honeydew"]),
+    ("honeydew1", &["honeydew1"]),
+    ("honeydew2", &["honeydew2"]),
+    ("honeydew3", &["honeydew3"]),
+    ("honeydew4", &["honeydew4"]),
+    ("hot", &["hotpink"]),
+    ("hotpink1", &["hotpink1"]),
+    ("hotpink2", &["hotpink2"]),
+    ("hotpink3", &["hotpink3"]),
+    ("hotpink4", &["hotpink4"]),
+    ("indian", &["indianred"]),
+    ("indianred1", &["indianred1"]),
+    ("indianred2", &["indianred2"]),
+    ("indianred3", &["indianred3"]),
+    ("indianred4", &["indianred4"]),
+    ("ivory", &["ivory"]),
+    ("ivory1", &["ivory1"]),
+    ("ivory2", &["ivory2"]),
+    ("ivory3", &["ivory3"]),
+    ("ivory4", &["ivory4"]),
+    ("khaki", &["darkkhaki", "khaki"]),
+    ("khaki1", &["khaki1"]),
+    ("khaki2", &["khaki2"]),
+    ("khaki3", &["khaki3"]),
+    ("khaki4", &["khaki4"]),
+    ("lace", &["oldlace"]),
+    ("lavender", &["lavender", "lavenderblush"]),
+    ("lavenderblush1", &["lavenderblush1"]),
+    ("lavenderblush2", &["lavenderblush2"]),
+    ("lavenderblush3", &["lavenderblush3"]),
+    ("lavenderblush4", &["lavenderblush4"]),
+    ("lawn", &["lawngreen"]),
+    ("lemon", &["lemonchiffon"]),
+    ("lemonchiffon1", &["lemonchiffon1"]),
+    ("lemonchiffon2", &["lemonchiffon2"]),
+    ("lemonchiffon3", &["lemonchiffon3"]),
+    ("lemonchiffon4", &["lemonchiffon4"]),
+    ("light", &["lightblue", "lightcoral", "lightcyan", "lightgoldenrod", "lightgoldenrodyellow", "lightgray", "lightgreen", "lightgrey", "lightpink", "lightsalmon", "lightseagreen", "lightskyblue", "lightslateblue", "lightslategray", "lightslategrey", "lightsteelblue", "lightyellow"]),
+    ("lightblue1", &["lightblue1"]),
+    ("lightblue2", &["lightblue2"]),
+    ("lightblue3", &["lightblue3"]),
+    ("lightblue4", &["lightblue4"]),
+    ("lightcyan1", &["lightcyan1"]),
+    ("lightcyan2", &["lightcyan2"]),
+    ("lightcyan3", &["lightcyan3"]),
+    ("lightcyan4", &["lightcyan4"]),
+    ("lightgoldenrod1", &["lightgoldenrod1"]),
+    ("lightgoldenrod2", &["lightgoldenrod2"]),
+    ("lightgoldenrod3", &["lightgoldenrod3"]),
+    ("lightgoldenrod4", &["lightgoldenrod4"]),
+    ("lightpink1", &["lightpink1"]),
+    ("lightpink2", &["lightpink2"]),
+    ("lightpink3", &["lightpink3"]),
+    ("lightpink4", &["lightpink4"]),
+    ("lightsalmon1", &["lightsalmon1"]),
+    ("lightsalmon2", &["lightsalmon2"]),
+    ("lightsalmon3", &["lightsalmon3"]),
+    ("lightsalmon4", &["lightsalmon4"]),
+    ("lightskyblue1", &["lightskyblue1"]),
+    ("lightskyblue2", &["lightskyblue2"]),
+    ("lightskyblue3", &["lightskyblue3"]),
+    ("lightskyblue4", &["lightskyblue4"]),
+    ("lightsteelblue1", &["lightsteelblue1"]),
+    ("lightsteelblue2", &["lightsteelblue2"]),
+    ("lightsteelblue3", &["lightsteelblue3"]),
+    ("lightsteelblue4", &["lightsteelblue4"]),
+    ("lightyellow1", &["lightyellow1"]),
+    ("lightyellow2", &["lightyellow2"]),
+    ("lightyellow3", &["lightyellow3"]),
+    ("lightyellow4", &["lightyellow4"]),
+    ("lime", &["limegreen"]),
+    ("linen", &["linen"]),
+    ("magenta", &["darkmagenta", "magenta"]),
+    ("magenta1", &["magenta1"]),
+    ("magenta2", &["magenta2"]),
+    ("magenta3", &["magenta3"]),
+    ("magenta4", &["magenta4"]),
+    ("maroon", &["maroon"]),
+    ("maroon1", &["maroon1"]),
+    ("maroon2", &["maroon2"]),
+    ("maroon3", &["maroon3"]),
+    ("maroon4", &["maroon4"]),
+    ("medium", &["mediumaquamarine", "mediumblue", "mediumorchid", "mediumpurple", "mediumseagreen", "mediumslateblue", "mediumspringgreen", "mediumturquoise", "mediumvioletred"]),
+    ("mediumorchid1", &["mediumorchid1"]),
+    ("mediumorchid2", &["mediumorchid2"]),
+    ("mediumorchid3", &["mediumorchid3"]),
+    ("mediumorchid4", &["mediumorchid4"]),
+    ("mediumpurple1", &["mediumpurple1"]),
+    ("mediumpurple2", &["mediumpurple2"]),
+    ("mediumpurple3", &["mediumpurple3"]),
+    ("mediumpurple4", &["mediumpurple4"]),
+    ("midnight", &["midnightblue"]),
+    ("mint", &["mintcream"]),
+    ("misty", &["mistyrose"]),
+    ("mistyrose1", &["mistyrose1"]),
+    ("mistyrose2", &["mistyrose2"]),
+    ("mistyrose3", &["mistyrose3"]),
+    ("mistyrose4", &["mistyrose4"]),
+    ("moccasin", &["moccasin"]),
+    ("navajo", &["navajowhite"]),
+    ("navajowhite1", &["navajowhite1"]),
+    ("navajowhite2", &["navajowhite2"]),
+    ("navajowhite3", &["navajowhite3"]),
+    ("navajowhite4", &["navajowhite4"]),
+    ("navy", &["navy", "navyblue"]),
+    ("old", &["oldlace"]),
+    ("olive", &["darkolivegreen", "olivedrab"]),
+    ("olivedrab1", &["olivedrab1"]),
+    ("olivedrab2", &["olivedrab2"]),
+    ("olivedrab3", &["olivedrab3"]),
+    ("olivedrab4", &["olivedrab4"]),
+    ("orange", &["darkorange", "orange", "orangered"]),
+    ("orange1", &["orange1"]),
+    ("orange2", &["orange2"]),
+    ("orange3", &["orange3"]),
+    ("orange4", &["orange4"]),
+    ("orangered1", &["orangered1"]),
+    ("orangered2", &["orangered2"]),
+    ("orangered3", &["orangered3"]),
+    ("orangered4", &["orangered4"]),
+    ("orchid", &["darkorchid", "mediumorchid", "orchid"]),
+    ("orchid1", &["orchid1"]),
+    ("orchid2", &["orchid2"]),
+    ("orchid3", &["orchid3"]),
+    ("orchid4", &["orchid4"]),
+    ("pale", &["palegoldenrod", "palegreen", "paleturquoise", "palevioletred"]),
+    ("palegreen1", &["palegreen1"]),
+    ("palegreen2", &["palegreen2"]),
+    ("palegreen3", &["palegreen3"]),
+    ("palegreen4", &["palegreen4"]),
+    ("paleturquoise1", &["paleturquoise1"]),
+    ("paleturquoise2", &["paleturquoise2"]),
+    ("paleturquoise3", &["paleturquoise3"]),
+    ("paleturquoise4", &["paleturquoise4"]),
+    ("palevioletred1", &["palevioletred1"]),
+    ("palevioletred2", &["palevioletred2"]),
+    ("palevioletred3", &["palevioletred3"]),
+    ("palevioletred4", &["palevioletred4"]),
+    ("papaya", &["papayawhip"]),
+    ("peach", &["peachpuff"]),
+    ("peachpuff1", &["peachpuff1"]),
+    ("peachpuff2", &["peachpuff2"]),
+    ("peachpuff3", &["peachpuff3"]),
+    ("peachpuff4", &["peachpuff4"]),
+    ("peru", &["peru"]),
+    ("pink", &["deeppink", "hotpink", "lightpink", "pink"]),
+    ("pink1", &["pink1"]),
+    ("pink2", &["pink2"]),
+    ("pink3", &["pink3"]),
+    ("pink4", &["pink4"]),
+    ("plum", &["plum"]),
+    ("plum1", &["plum1"]),
+    ("plum2", &["plum2"]),
+    ("plum3", &["plum3"]),
+    ("plum4", &["plum4"]),
+    ("powder", &["powderblue"]),
+    ("puff", &["peachpuff"]),
+    ("purple", &["mediumpurple", "purple"]),
+    ("purple1", &["purple1"]),
+    ("purple2", &["purple2"]),
+    ("purple3", &["purple3"]),
+    ("purple4", &["purple4"]),
+    ("red", &["darkred", "indianred", "mediumvioletred", "orangered", "palevioletred", "red", "violetred"]),
+    ("red1", &["red1"]),
+    ("red2", &["red2"]),
+    ("red3", &["red3"]),
+    ("red4", &["red4"]),
+    ("rose", &["mistyrose"]),
+    ("rosy", &["rosybrown"]),
+    ("rosybrown1", &["rosybrown1"]),
+    ("rosybrown2", &["rosybrown2"]),
+    ("rosybrown3", &["rosybrown3"]),
+    ("rosybrown4", &["rosybrown4"]),
+    ("royal", &["royalblue"]),
+    ("royalblue1", &["royalblue1"]),
+    ("royalblue2", &["royalblue2"]),
+    ("royalblue3", &["royalblue3"]),
+    ("royalblue4", &["royalblue4"]),
+    ("saddle", &["saddlebrown"]),
+    ("salmon", &["darksalmon", "lightsalmon", "salmon"]),
+    ("salmon1", &["salmon1"]),
+    ("salmon2", &["salmon2"]),
+    ("salmon3", &["salmon3"]),
+    ("salmon4", &["salmon4"]),
+    ("sandy", &["sandybrown"]),
+    ("sea", &["darkseagreen", "lightseagreen", "mediumseagreen", "seagreen"]),
+    ("seagreen1", &["seagreen1"]),
+    ("seagreen2", &["seagreen2"]),
+    ("seagreen3", &["seagreen3"]),
+    ("seagreen4", &["seagreen4"]),
+    ("seashell", &["seashell"]),
+    ("seashell1", &["seashell1"]),
+    ("seashell2", &["seashell2"]),
+    ("seashell3", &["seashell3"]),
+    ("seashell4", &["seashell4"]),
+    ("sienna", &["sienna"]),
+    ("sienna1", &["sienna1"]),
+    ("sienna2", &["sienna2"]),
+    ("sienna3", &["sienna3"]),
+    ("sienna4", &["sienna4"]),
+    ("sky", &["deepskyblue", "lightskyblue", "skyblue"]),
+    ("skyblue1", &["skyblue1"]),
+    ("skyblue2", &["skyblue2"]),
+    ("skyblue3", &["skyblue3"]),
+    ("skyblue4", &["skyblue4"]),
+    ("slate", &["darkslateblue", "darkslategray", "darkslategrey", "lightslateblue", "lightslategray", "lightslategrey", "mediumslateblue", "slateblue", "slategray", "slategrey"]),
+    ("slateblue1", &["slateblue1"]),
+    ("slateblue2", &["slateblue2"]),
+    ("slateblue3", &["slateblue3"]),
+    ("slateblue4", &["slateblue4"]),
+    ("slategray1", &["slategray1"]),
+    ("slategray2", &["slategray2"]),
+    ("slategray3", &["slategray3"]),
+    ("slategray4", &["slategray4"]),
+    ("smoke", &["whitesmoke"]),
+    ("snow", &["snow"]),
+    ("snow1", &["snow1"]),
+    ("snow2", &["snow2"]),
+    ("snow3", &["snow3"]),
+    ("snow4", &["snow4"]),
+    ("spring", &["mediumspringgreen", "springgreen"]),
+    ("springgreen1", &["springgreen1"]),
+    ("springgreen2", &["springgreen2"]),
+    ("springgreen3", &["springgreen3"]),
+    ("springgreen4", &["springgreen4"]),
+    ("steel", &["lightsteelblue", "steelblue"]),
+    ("steelblue1", &["steelblue1"]),
+    ("steelblue2", &["steelblue2"]),
+    ("steelblue3", &["steelblue3"]),
+    ("steelblue4", &["steelblue4"]),
+    ("tan", &["tan"]),
+    ("tan1", &["tan1"]),
+    ("tan2", &["tan2"]),
+    ("tan3", &["tan3"]),
+    ("tan4", &["tan4"]),
+    ("thistle", &["thistle"]),
+    ("thistle1", &["thistle1"]),
+    ("thistle2", &["thistle2"]),
+    ("thistle3", &["thistle3"]),
+    ("thistle4", &["thistle4"]),
+    ("tomato", &["tomato"]),
+    ("tomato1", &["tomato1"]),
+    ("tomato2", &["tomato2"]),
+    ("tomato3", &["tomato3"]),
+    ("tomato4", &["tomato4"]),
+    ("turquoise", &["darkturquoise", "mediumturquoise", "paleturquoise", "turquoise"]),
+    ("turquoise1", &["turquoise1"]),
+    ("turquoise2", &["turquoise2"]),
+    ("turquoise3", &["turquoise3"]),
+    ("turquoise4", &["turquoise4"]),
+    ("violet", &["blueviolet", "darkviolet", "mediumvioletred", "palevioletred", "violet", "violetred"]),
+    ("violetred1", &["violetred1"]),
+    ("violetred2", &["violetred2"]),
+    ("violetred3", &["violetred3"]),
+    ("violetred4", &["violetred4"]),
+    ("wheat", &["wheat"]),
+    ("wheat1", &["wheat1"]),
+    ("wheat2", &["wheat2"]),
+    ("wheat3", &["wheat3"]),
+    ("wheat4", &["wheat4"]),
+    ("whip", &["papayawhip"]),
+    ("white", &["antiquewhite", "floralwhite", "ghostwhite", "navajowhite", "white", "whitesmoke"]),
+    ("yellow", &["greenyellow", "lightgoldenrodyellow", "lightyellow", "yellow", "yellowgreen"]),
+    ("yellow1", &["yellow1"]),
+    ("yellow2", &["yellow2"]),
+    ("yellow3", &["yellow3"]),
+    ("yellow4", &["yellow4"]),
+];
+
+/// `X11_COLORS` keys of each hue family, in `Family` order, each in display-name order.
+pub(crate) static X11_COLORS_FAMILIES: [&[&str]; 10] = [
+    &["brown1", "brown2", "brown3", "coral1", "coral2", "coral3", "darksalmon", "firebrick", "firebrick1", "firebrick2", "firebrick3", "indianred", "indianred1", "indianred2", "indianred3", "lightcoral", "orangered", "orangered1", "orangered2", "orangered3", "palevioletred4", "red", "red1", "red2", "red3", "rosybrown", "rosybrown3", "rosybrown4", "salmon", "salmon1", "salmon2", "salmon3", "tomato", "tomato1", "tomato2", "tomato3"],
+    &["bisque", "bisque1", "bisque2", "bisque3", "burlywood", "burlywood1", "burlywood2", "burlywood3", "chocolate1", "chocolate2", "coral", "darkorange", "darkorange1", "darkorange2", "lightsalmon", "lightsalmon1", "lightsalmon2", "lightsalmon3", "orange", "orange1", "orange2", "orange3", "peachpuff", "peachpuff1", "peachpuff2", "peachpuff3", "peru", "sandybrown", "sienna1", "sienna2", "tan", "tan1", "tan2", "tan3"],
+    &["blanchedalmond", "cornsilk", "cornsilk1", "cornsilk2", "darkgoldenrod", "darkkhaki", "darkgoldenrod1", "darkgoldenrod2", "darkgoldenrod3", "gold", "gold1", "gold2", "gold3", "goldenrod", "goldenrod1", "goldenrod2", "goldenrod3", "khaki", "khaki1", "khaki2", "khaki3", "khaki4", "lemonchiffon", "lemonchiffon1", "lemonchiffon2", "lemonchiffon3", "lemonchiffon4", "lightgoldenrod", "lightgoldenrodyellow", "lightyellow", "lightgoldenrod1", "lightgoldenrod2", "lightgoldenrod3", "lightyellow1", "lightyellow2", "moccasin", "navajowhite", "navajowhite1", "navajowhite2", "navajowhite3", "palegoldenrod", "papayawhip", "wheat", "wheat1", "wheat2", "wheat3", "yellow", "yellow1", "yellow2", "yellow3", "yellow4"],
+    &["aquamarine", "aquamarine1", "aquamarine2", "aquamarine3", "aquamarine4", "chartreuse", "chartreuse1", "chartreuse2", "chartreuse3", "chartreuse4", "darkgreen", "darkolivegreen", "darkseagreen", "darkolivegreen1", "darkolivegreen2", "darkolivegreen3", "darkolivegreen4", "darkseagreen1", "darkseagreen2", "darkseagreen3", "darkseagreen4", "forestgreen", "green", "greenyellow", "green1", "green2", "green3", "green4", "lawngreen", "lightgreen", "limegreen", "mediumaquamarine", "mediumseagreen", "mediumspringgreen", "olivedrab", "olivedrab1", "olivedrab2", "olivedrab3", "olivedrab4", "palegreen", "palegreen1", "palegreen2", "palegreen3", "palegreen4", "seagreen", "seagreen1", "seagreen2", "seagreen3", "seagreen4", "springgreen", "springgreen1", "springgreen2", "springgreen3", "springgreen4", "yellowgreen"],
+    &["cadetblue", "cadetblue1", "cadetblue2", "cadetblue3", "cadetblue4", "cyan", "cyan1", "cyan2", "cyan3", "cyan4", "darkcyan", "darkslategray", "darkslategrey", "darkturquoise", "darkslategray1", "darkslategray2", "darkslategray3", "darkslategray4", "lightblue", "lightseagreen", "lightblue1", "lightblue2", "lightblue3", "mediumturquoise", "paleturquoise", "paleturquoise1", "paleturquoise2", "paleturquoise3", "paleturquoise4", "powderblue", "skyblue", "turquoise", "turquoise1", "turquoise2", "turquoise3", "turquoise4"],
+    &["blue", "blue1", "blue2", "blue3", "blue4", "cornflowerblue", "darkblue", "darkslateblue", "deepskyblue", "deepskyblue1", "deepskyblue2", "deepskyblue3", "deepskyblue4", "dodgerblue", "dodgerblue1", "dodgerblue2", "dodgerblue3", "dodgerblue4", "lightskyblue", "lightslateblue", "lightsteelblue", "lightskyblue1", "lightskyblue2", "lightskyblue3", "lightskyblue4", "lightsteelblue1", "lightsteelblue2", "lightsteelblue3", "mediumblue", "mediumslateblue", "midnightblue", "navy", "navyblue", "royalblue", "royalblue1", "royalblue2", "royalblue3", "royalblue4", "skyblue1", "skyblue2", "skyblue3", "skyblue4", "slateblue", "slateblue1", "slateblue2", "slateblue3", "slateblue4", "slategray1", "slategray2", "slategray3", "steelblue", "steelblue1", "steelblue2", "steelblue3", "steelblue4"],
+    &["blueviolet", "darkmagenta", "darkorchid", "darkviolet", "darkorchid1", "darkorchid2", "darkorchid3", "darkorchid4", "deeppink4", "hotpink4", "magenta", "magenta1", "magenta2", "magenta3", "magenta4", "maroon4", "mediumorchid", "mediumpurple", "mediumorchid1", "mediumorchid2", "mediumorchid3", "mediumorchid4", "mediumpurple1", "mediumpurple2", "mediumpurple3", "mediumpurple4", "orchid", "orchid1", "orchid2", "orchid3", "orchid4", "plum", "plum1", "plum2", "plum3", "plum4", "purple", "purple1", "purple2", "purple3", "purple4", "thistle", "thistle1", "thistle2", "thistle3", "violet", "violetred4"],
+    &["debianred", "deeppink", "deeppink1", "deeppink2", "deeppink3", "hotpink", "hotpink1", "hotpink2", "hotpink3", "lightpink", "lightpink1", "lightpink2", "lightpink3", "lightpink4", "maroon", "maroon1", "maroon2", "maroon3", "mediumvioletred", "palevioletred", "palevioletred1", "palevioletred2", "palevioletred3", "pink", "pink1", "pink2", "pink3", "pink4", "rosybrown1", "rosybrown2", "violetred", "violetred1", "violetred2", "violetred3"],
+    &["brown", "brown4", "burlywood4", "chocolate", "chocolate3", "chocolate4", "coral4", "darkred", "darkgoldenrod4", "darkorange3", "darkorange4", "firebrick4", "gold4", "goldenrod4", "indianred4", "lightgoldenrod4", "lightsalmon4", "navajowhite4", "orange4", "orangered4", "peachpuff4", "red4", "saddlebrown", "salmon4", "sienna", "sienna3", "sienna4", "tan4", "tomato4", "wheat4"],
+    &["aliceblue", "antiquewhite", "antiquewhite1", "antiquewhite2", "antiquewhite3", "antiquewhite4", "azure", "azure1", "azure2", "azure3", "azure4", "beige", "bisque4", "black", "cornsilk3", "cornsilk4", "darkgray", "darkgrey", "dimgray", "dimgrey", "floralwhite", "gainsboro", "ghostwhite", "gray", "gray0", "gray1", "gray10", "gray100", "gray11", "gray12", "gray13", "gray14", "gray15", "gray16", "gray17", "gray18", "gray19", "gray2", "gray20", "gray21", "gray22", "gray23", "gray24", "gray25", "gray26", "gray27", "gray28", "gray29", "gray3", "gray30", "gray31", "gray32", "gray33", "gray34", "gray35", "gray36", "gray37", "gray38", "gray39", "gray4", "gray40", "gray41", "gray42", "gray43", "gray44", "gray45", "gray46", "gray47", "gray48", "gray49", "gray5", "gray50", "gray51", "gray52", "gray53", "gray54", "gray55", "gray56", "gray57", "gray58", "gray59", "gray6", "gray60", "gray61", "gray62", "gray63", "gray64", "gray65", "gray66", "gray67", "gray68", "gray69", "gray7", "gray70", "gray71", "gray72", "gray73", "gray74", "gray75", "gray76", "gray77", "gray78", "gray79", "gray8", "gray80", "gray81", "gray82", "gray83", "gray84", "gray85", "gray86", "gray87", "gray88", "gray89", "gray9", "gray90", "gray91", "gray92", "gray93", "gray94", "gray95", "gray96", "gray97", "gray98", "gray99", "grey", "grey0", "grey1", "grey10", "grey100", "grey11", "grey12", "grey13", "grey14", "grey15", "grey16", "grey17", "grey18", "grey19", "grey2", "grey20", "grey21", "grey22", "grey23", "grey24", "grey25", "grey26", "grey27", "grey28", "grey29", "grey3", "grey30", "grey31", "grey32", "grey33", "grey34", "grey35", "grey36", "grey37", "grey38", "grey39", "grey4", "grey40", "grey41", "grey42", "grey43", "grey44", "grey45", "grey46", "grey47", "grey48", "grey49", "grey5", "grey50", "grey51", "grey52", "grey53", "grey54", "grey55", "grey56", "grey57", "grey58", "grey59", "grey6", "grey60", "grey61", "grey62", "grey63", "grey64", "grey65", "grey66", "grey67", "grey68", "grey69", "grey7", "grey70", "grey71", "grey72", "grey73", "grey74", "grey75", "grey76", "grey77", "grey78", "grey79", "grey8", "grey80", "grey81", "grey82", "grey83", "grey84", "grey85", "grey86", "grey87", "grey88", "grey89", "grey9", "grey90", "grey91", "grey92", "grey93", "grey94", "grey95", "grey96", "grey97", "grey98", "grey99", "honeydew", "honeydew1", "honeydew2", "honeydew3", "honeydew4", "ivory", "ivory1", "ivory2", "ivory3", "ivory4", "lavender", "lavenderblush", "lavenderblush1", "lavenderblush2", "lavenderblush3", "lavenderblush4", "lightcyan", "lightgray", "lightgrey", "lightslategray", "lightslategrey", "lightblue4", "lightcyan1", "lightcyan2", "lightcyan3", "lightcyan4", "lightsteelblue4", "lightyellow3", "lightyellow4", "linen", "mintcream", "mistyrose", "mistyrose1", "mistyrose2", "mistyrose3", "mistyrose4", "oldlace", "seashell", "seashell1", "seashell2", "seashell3", "seashell4", "slategray", "slategrey", "slategray4", "snow", "snow1", "snow2", "snow3", "snow4", "thistle4", "white", "whitesmoke"],
+];
+
+/// `X11_COLORS_FAMILIES` split by `Lightness` and then `Chroma` band, each in display-name order.
+pub(crate) static X11_COLORS_BANDS: [[[&[&str]; 3]; 3]; 10] = [
+    [
+        [&[], &[], &[]],
+        [&["rosybrown", "rosybrown3", "rosybrown4"], &["coral3", "indianred", "indianred3", "lightcoral", "palevioletred4", "salmon", "salmon2", "salmon3"], &["brown1", "brown2", "brown3", "coral1", "coral2", "firebrick", "firebrick1", "firebrick2", "firebrick3", "indianred1", "indianred2", "orangered", "orangered1", "orangered2", "orangered3", "red", "red1", "red2", "red3", "tomato", "tomato1", "tomato2", "tomato3"]],
+        [&[], &["darksalmon", "salmon1"], &[]],
+    ],
+    [
+        [&[], &[], &[]],
+        [&[], &["lightsalmon3", "orange3", "peru", "sienna2", "tan3"], &["chocolate1", "chocolate2", "coral", "darkorange1", "darkorange2", "sienna1"]],
+        [&["bisque", "bisque1", "bisque2", "bisque3", "burlywood", "burlywood3", "peachpuff", "peachpuff1", "peachpuff2", "peachpuff3", "tan"], &["burlywood1", "burlywood2", "lightsalmon", "lightsalmon1", "lightsalmon2", "sandybrown", "tan1", "tan2"], &["darkorange", "orange", "orange1", "orange2"]],
+    ],
+    [
+        [&[], &[], &[]],
+        [&["khaki4", "lemonchiffon4"], &["darkgoldenrod", "darkgoldenrod3", "goldenrod3", "yellow4"], &[]],
+        [&["blanchedalmond", "cornsilk", "cornsilk1", "cornsilk2", "lemonchiffon", "lemonchiffon1", "lemonchiffon2", "lemonchiffon3", "lightgoldenrodyellow", "lightyellow", "lightyellow1", "lightyellow2", "moccasin", "navajowhite", "navajowhite1", "navajowhite2", "navajowhite3", "palegoldenrod", "papayawhip", "wheat", "wheat1", "wheat2", "wheat3"], &["darkkhaki", "gold3", "goldenrod", "goldenrod2", "khaki", "khaki1", "khaki2", "khaki3", "lightgoldenrod", "lightgoldenrod1", "lightgoldenrod2", "lightgoldenrod3"], &["darkgoldenrod1", "darkgoldenrod2", "gold", "gold1", "gold2", "goldenrod1", "yellow", "yellow1", "yellow2", "yellow3"]],
+    ],
+    [
+        [&[], &["darkgreen"], &[]],
+        [&["darkseagreen4"], &["aquamarine4", "darkolivegreen", "darkolivegreen4", "mediumseagreen", "olivedrab", "olivedrab4", "palegreen4", "seagreen", "seagreen4", "springgreen4"], &["chartreuse4", "forestgreen", "green3", "green4", "limegreen", "springgreen3"]],
+        [&["darkseagreen"], &["aquamarine", "aquamarine1", "aquamarine2", "aquamarine3", "darkolivegreen3", "darkseagreen1", "darkseagreen2", "darkseagreen3", "lightgreen", "mediumaquamarine", "palegreen2", "palegreen3"], &["chartreuse", "chartreuse1", "chartreuse2", "chartreuse3", "darkolivegreen1", "darkolivegreen2", "green", "greenyellow", "green1", "green2", "lawngreen", "mediumspringgreen", "olivedrab1", "olivedrab2", "olivedrab3", "palegreen", "palegreen1", "seagreen1", "seagreen2", "seagreen3", "springgreen", "springgreen1", "springgreen2", "yellowgreen"]],
+    ],
+    [
+        [&["darkslategray", "darkslategrey"], &[], &[]],
+        [&["cadetblue", "cadetblue4", "darkslategray4", "paleturquoise4"], &["cyan4", "darkcyan", "lightseagreen", "turquoise3", "turquoise4"], &[]],
+        [&["cadetblue3", "lightblue", "lightblue1", "lightblue2", "lightblue3", "paleturquoise", "paleturquoise1", "paleturquoise2", "paleturquoise3", "powderblue"], &["cadetblue1", "cadetblue2", "cyan", "cyan1", "cyan2", "cyan3", "darkturquoise", "darkslategray1", "darkslategray2", "darkslategray3", "mediumturquoise", "skyblue", "turquoise", "turquoise1", "turquoise2"], &[]],
+    ],
+    [
+        [&[], &["darkslateblue", "dodgerblue4", "midnightblue", "royalblue4", "slateblue4"], &["blue2", "blue3", "blue4", "darkblue", "mediumblue", "navy", "navyblue"]],
+        [&["lightskyblue4", "skyblue4"], &["cornflowerblue", "deepskyblue2", "deepskyblue3", "deepskyblue4", "dodgerblue3", "skyblue3", "steelblue", "steelblue2", "steelblue3", "steelblue4"], &["blue", "blue1", "dodgerblue", "dodgerblue1", "dodgerblue2", "lightslateblue", "mediumslateblue", "royalblue", "royalblue1", "royalblue2", "royalblue3", "slateblue", "slateblue1", "slateblue2", "slateblue3"]],
+        [&["lightsteelblue", "lightskyblue1", "lightskyblue2", "lightskyblue3", "lightsteelblue1", "lightsteelblue2", "lightsteelblue3", "slategray1", "slategray2", "slategray3"], &["deepskyblue", "deepskyblue1", "lightskyblue", "skyblue1", "skyblue2", "steelblue1"], &[]],
+    ],
+    [
+        [&[], &["violetred4"], &["darkmagenta", "darkorchid4", "deeppink4", "magenta4", "maroon4", "purple4"]],
+        [&["plum4"], &["hotpink4", "mediumpurple", "mediumorchid4", "mediumpurple3", "mediumpurple4", "orchid4", "plum3"], &["blueviolet", "darkorchid", "darkviolet", "darkorchid1", "darkorchid2", "darkorchid3", "magenta", "magenta1", "magenta2", "magenta3", "mediumorchid", "mediumorchid1", "mediumorchid2", "mediumorchid3", "mediumpurple1", "mediumpurple2", "orchid", "orchid2", "orchid3", "purple", "purple1", "purple2", "purple3"]],
+        [&["thistle", "thistle1", "thistle2", "thistle3"], &["plum", "plum1", "plum2"], &["orchid1", "violet"]],
+    ],
+    [
+        [&[], &[], &[]],
+        [&["lightpink4", "pink3", "pink4"], &["hotpink3", "lightpink3", "palevioletred", "palevioletred2", "palevioletred3"], &["debianred", "deeppink", "deeppink1", "deeppink2", "deeppink3", "hotpink", "hotpink1", "hotpink2", "maroon", "maroon1", "maroon2", "maroon3", "mediumvioletred", "violetred", "violetred1", "violetred2", "violetred3"]],
+        [&["pink", "rosybrown1", "rosybrown2"], &["lightpink", "lightpink1", "lightpink2", "palevioletred1", "pink1", "pink2"], &[]],
+    ],
+    [
+        [&[], &["brown4", "firebrick4", "orangered4", "tomato4"], &["darkred", "red4"]],
+        [&["burlywood4", "lightgoldenrod4", "lightsalmon4", "navajowhite4", "peachpuff4", "wheat4"], &["brown", "chocolate", "chocolate3", "chocolate4", "coral4", "darkgoldenrod4", "darkorange3", "darkorange4", "gold4", "goldenrod4", "indianred4", "orange4", "saddlebrown", "salmon4", "sienna", "sienna3", "sienna4", "tan4"], &[]],
+        [&[], &[], &[]],
+    ],
+    [
+        [&["black", "gray0", "gray1", "gray10", "gray11", "gray12", "gray13", "gray14", "gray15", "gray16", "gray17", "gray18", "gray19", "gray2", "gray20", "gray21", "gray22", "gray23", "gray24", "gray25", "gray26", "gray27", "gray28", "gray29", "gray3", "gray30", "gray31", "gray32", "gray33", "gray4", "gray5", "gray6", "gray7", "gray8", "gray9", "grey0", "grey1", "grey10", "grey11", "grey12", "grey13", "grey14", "grey15", "grey16", "grey17", "grey18", "grey19", "grey2", "grey20", "grey21", "grey22", "grey23", "grey24", "grey25", "grey26", "grey27", "grey28", "grey29", "grey3", "grey30", "grey31", "grey32", "grey33", "grey4", "grey5", "grey6", "grey7", "grey8", "grey9"], &[], &[]],
+        [&["antiquewhite4", "azure4", "bisque4", "cornsilk4", "darkgray", "darkgrey", "dimgray", "dimgrey", "gray34", "gray35", "gray36", "gray37", "gray38", "gray39", "gray40", "gray41", "gray42", "gray43", "gray44", "gray45", "gray46", "gray47", "gray48", "gray49", "gray50", "gray51", "gray52", "gray53", "gray54", "gray55", "gray56", "gray57", "gray58", "gray59", "gray60", "gray61", "gray62", "gray63", "gray64", "gray65", "gray66", "gray67", "gray68", "grey34", "grey35", "grey36", "grey37", "grey38", "grey39", "grey40", "grey41", "grey42", "grey43", "grey44", "grey45", "grey46", "grey47", "grey48", "grey49", "grey50", "grey51", "grey52", "grey53", "grey54", "grey55", "grey56", "grey57", "grey58", "grey59", "grey60", "grey61", "grey62", "grey63", "grey64", "grey65", "grey66", "grey67", "grey68", "honeydew4", "ivory4", "lavenderblush4", "lightslategray", "lightslategrey", "lightblue4", "lightcyan4", "lightsteelblue4", "lightyellow4", "mistyrose4", "seashell4", "slategray", "slategrey", "slategray4", "snow4", "thistle4"], &[], &[]],
+        [&["aliceblue", "antiquewhite", "antiquewhite1", "antiquewhite2", "antiquewhite3", "azure", "azure1", "azure2", "azure3", "beige", "cornsilk3", "floralwhite", "gainsboro", "ghostwhite", "gray", "gray100", "gray69", "gray70", "gray71", "gray72", "gray73", "gray74", "gray75", "gray76", "gray77", "gray78", "gray79", "gray80", "gray81", "gray82", "gray83", "gray84", "gray85", "gray86", "gray87", "gray88", "gray89", "gray90", "gray91", "gray92", "gray93", "gray94", "gray95", "gray96", "gray97", "gray98", "gray99", "grey", "grey100", "grey69", "grey70", "grey71", "grey72", "grey73", "grey74", "grey75", "grey76", "grey77", "grey78", "grey79", "grey80", "grey81", "grey82", "grey83", "grey84", "grey85", "grey86", "grey87", "grey88", "grey89", "grey90", "grey91", "grey92", "grey93", "grey94", "grey95", "grey96", "grey97", "grey98", "grey99", "honeydew", "honeydew1", "honeydew2", "honeydew3", "ivory", "ivory1", "ivory2", "ivory3", "lavender", "lavenderblush", "lavenderblush1", "lavenderblush2", "lavenderblush3", "lightcyan", "lightgray", "lightgrey", "lightcyan1", "lightcyan2", "lightcyan3", "lightyellow3", "linen", "mintcream", "mistyrose", "mistyrose1", "mistyrose2", "mistyrose3", "oldlace", "seashell", "seashell1", "seashell2", "seashell3", "snow", "snow1", "snow2", "snow3", "white", "whitesmoke"], &[], &[]],
+    ],
+];
+
+/// `X11_COLORS` keys laid out as a k-d tree over OKLab coordinates.
+pub(crate) static X11_COLORS_NEAREST: [crate::nearest::Node; 658] = [
+    crate::nearest::Node{ lab:[0.7002306,-0.042998232,-0.0715454], key:"skyblue3" },
+    crate::nearest::Node{ lab:[0.55552673,4.497136e-11,2.0706652e-08], key:"grey45" },
+    crate::nearest::Node{ lab:[0.28908005,2.3401864e-11,1.07751434e-08], key:"grey17" },
+    crate::nearest::Node{ lab:[0.38345328,-0.02753398,-0.26427624], key:"mediumblue" },
+    crate::nearest::Node{ lab:[0.1822037,1.4749882e-11,6.791444e-09], key:"gray7" },
+    crate::nearest::Node{ lab:[0.11491828,9.302954e-12,4.283453e-09], key:"gray2" },
+    crate::nearest::Node{ lab:[0.27114984,-0.019469997,-0.18687664], key:"navy" },
+    crate::nearest::Node{ lab:[0.0,0.0,0.0], key:"grey0" },
+    crate::nearest::Node{ lab:[0.0,0.0,0.0], key:"gray0" },
+    crate::nearest::Node{ lab:[0.0,0.0,0.0], key:"black" },
+    crate::nearest::Node{ lab:[0.096925825,7.846439e-12,3.6128043e-09], key:"grey1" },
+    crate::nearest::Node{ lab:[0.096925825,7.846439e-12,3.6128043e-09], key:"gray1" },
+    crate::nearest::Node{ lab:[0.28782427,-0.02066731,-0.19836867], key:"darkblue" },
+    crate::nearest::Node{ lab:[0.28782427,-0.02066731,-0.19836867], key:"blue4" },
+    crate::nearest::Node{ lab:[0.38345328,-0.02753398,-0.26427624], key:"blue3" },
+    crate::nearest::Node{ lab:[0.27114984,-0.019469997,-0.18687664], key:"navyblue" },
+    crate::nearest::Node{ lab:[0.14478795,1.1720944e-11,5.3968128e-09], key:"grey4" },
+    crate::nearest::Node{ lab:[0.13440923,1.0880803e-11,5.0099573e-09], key:"grey3" },
+    crate::nearest::Node{ lab:[0.13440923,1.0880803e-11,5.0099573e-09], key:"gray3" },
+    crate::nearest::Node{ lab:[0.11491828,9.302954e-12,4.283453e-09], key:"grey2" },
+    crate::nearest::Node{ lab:[0.14478795,1.1720944e-11,5.3968128e-09], key:"gray4" },
+    crate::nearest::Node{ lab:[0.16841613,1.3633747e-11,6.277527e-09], key:"gray6" },
+    crate::nearest::Node{ lab:[0.1590664,1.2876852e-11,5.929026e-09], key:"grey5" },
+    crate::nearest::Node{ lab:[0.1590664,1.2876852e-11,5.929026e-09], key:"gray5" },
+    crate::nearest::Node{ lab:[0.16841613,1.3633747e-11,6.277527e-09], key:"grey6" },
+    crate::nearest::Node{ lab:[0.23929238,1.9371407e-11,8.919362e-09], key:"grey12" },
+    crate::nearest::Node{ lab:[0.2177865,1.7630564e-11,8.117754e-09], key:"gray10" },
+    crate::nearest::Node{ lab:[0.1912513,1.5482338e-11,7.1286834e-09], key:"grey8" },
+    crate::nearest::Node{ lab:[0.1912513,1.5482338e-11,7.1286834e-09], key:"gray8" },
+    crate::nearest::Node{ lab:[0.1822037,1.4749882e-11,6.791444e-09], key:"grey7" },
+    crate::nearest::Node{ lab:[0.20462653,1.6565069e-11,7.62723e-09], key:"grey9" },
+    crate::nearest::Node{ lab:[0.20462653,1.6565069e-11,7.62723e-09], key:"gray9" },
+    crate::nearest::Node{ lab:[0.22644986,1.833185e-11,8.440671e-09], key:"grey11" },
+    crate::nearest::Node{ lab:[0.22644986,1.833185e-11,8.440671e-09], key:"gray11" },
+    crate::nearest::Node{ lab:[0.2177865,1.7630564e-11,8.117754e-09], key:"grey10" },
+    crate::nearest::Node{ lab:[0.23929238,1.9371407e-11,8.919362e-09], key:"gray12" },
+    crate::nearest::Node{ lab:[0.2686183,2.1745453e-11,1.0012453e-08], key:"gray15" },
+    crate::nearest::Node{ lab:[0.2603248,2.1074004e-11,9.7033235e-09], key:"gray14" },
+    crate::nearest::Node{ lab:[0.2477587,2.0056831e-11,9.2349355e-09], key:"grey13" },
+    crate::nearest::Node{ lab:[0.2477587,2.0056831e-11,9.2349355e-09], key:"gray13" },
+    crate::nearest::Node{ lab:[0.2603248,2.1074004e-11,9.7033235e-09], key:"grey14" },
+    crate::nearest::Node{ lab:[0.28094023,2.2742891e-11,1.047174e-08], key:"grey16" },
+    crate::nearest::Node{ lab:[0.28094023,2.2742891e-11,1.047174e-08], key:"gray16" },
+    crate::nearest::Node{ lab:[0.2686183,2.1745453e-11,1.0012453e-08], key:"grey15" },
+    crate::nearest::Node{ lab:[0.28908005,2.3401864e-11,1.07751434e-08], key:"gray17" },
+    crate::nearest::Node{ lab:[0.45201373,-0.032456983,-0.31152815], key:"blue" },
+    crate::nearest::Node{ lab:[0.56332743,-0.090323925,-0.031835053], key:"turquoise4" },
+    crate::nearest::Node{ lab:[0.618918,-0.051894158,-0.17270933], key:"dodgerblue2" },
+    crate::nearest::Node{ lab:[0.5880009,-0.040817074,-0.090565845], key:"steelblue" },
+    crate::nearest::Node{ lab:[0.48461756,-0.061905652,-0.0745256], key:"deepskyblue4" },
+    crate::nearest::Node{ lab:[0.5565391,-0.046916146,-0.15233076], key:"dodgerblue3" },
+    crate::nearest::Node{ lab:[0.48817736,-0.03283669,-0.07390359], key:"steelblue4" },
+    crate::nearest::Node{ lab:[0.4212653,-0.034868326,-0.11225423], key:"dodgerblue4" },
+    crate::nearest::Node{ lab:[0.65200555,-0.05493326,-0.18200985], key:"dodgerblue1" },
+    crate::nearest::Node{ lab:[0.64344674,-0.08170728,-0.10053112], key:"deepskyblue3" },
+    crate::nearest::Node{ lab:[0.65200555,-0.05493326,-0.18200985], key:"dodgerblue" },
+    crate::nearest::Node{ lab:[0.6458245,-0.045729555,-0.10088461], key:"steelblue3" },
+    crate::nearest::Node{ lab:[0.5860673,-0.050523356,-0.022182563], key:"cadetblue4" },
+    crate::nearest::Node{ lab:[0.40296292,-0.03630598,-0.01024461], key:"darkslategray" },
+    crate::nearest::Node{ lab:[0.57652205,-0.095159926,-0.025087172], key:"darkcyan" },
+    crate::nearest::Node{ lab:[0.57652205,-0.095159926,-0.025087172], key:"cyan4" },
+    crate::nearest::Node{ lab:[0.40296292,-0.03630598,-0.01024461], key:"darkslategrey" },
+    crate::nearest::Node{ lab:[0.59777963,-0.05779736,-0.016208105], key:"darkslategray4" },
+    crate::nearest::Node{ lab:[0.691201,-0.11281922,-0.01779147], key:"lightseagreen" },
+    crate::nearest::Node{ lab:[0.65768147,-0.061722323,-0.020410107], key:"cadetblue" },
+    crate::nearest::Node{ lab:[0.60923177,-0.039535813,-0.011428846], key:"paleturquoise4" },
+    crate::nearest::Node{ lab:[0.5676768,-0.023193972,-0.0324073], key:"lightskyblue4" },
+    crate::nearest::Node{ lab:[0.52777094,-0.030021546,-0.052656073], key:"skyblue4" },
+    crate::nearest::Node{ lab:[0.52178997,-0.01132121,-0.17532149], key:"royalblue3" },
+    crate::nearest::Node{ lab:[0.42901492,-0.030805549,-0.29567736], key:"blue2" },
+    crate::nearest::Node{ lab:[0.45201373,-0.032456983,-0.31152815], key:"blue1" },
+    crate::nearest::Node{ lab:[0.39700583,-0.007619295,-0.12835258], key:"royalblue4" },
+    crate::nearest::Node{ lab:[0.58020926,-0.0130960485,-0.19834393], key:"royalblue2" },
+    crate::nearest::Node{ lab:[0.674622,-0.021289019,-0.1397445], key:"cornflowerblue" },
+    crate::nearest::Node{ lab:[0.6104151,-0.013900383,-0.20952773], key:"royalblue1" },
+    crate::nearest::Node{ lab:[0.5598482,-0.011820611,-0.18786201], key:"royalblue" },
+    crate::nearest::Node{ lab:[0.59250414,-0.01141009,-0.028742729], key:"slategrey" },
+    crate::nearest::Node{ lab:[0.5763011,-0.010385493,-0.028618427], key:"slategray4" },
+    crate::nearest::Node{ lab:[0.5917844,-0.026281085,-0.020352568], key:"lightblue4" },
+    crate::nearest::Node{ lab:[0.59250414,-0.01141009,-0.028742729], key:"slategray" },
+    crate::nearest::Node{ lab:[0.5778302,-0.008180779,-0.027947452], key:"lightsteelblue4" },
+    crate::nearest::Node{ lab:[0.61902016,-0.011989233,-0.030206343], key:"lightslategrey" },
+    crate::nearest::Node{ lab:[0.6231373,-0.018857569,-0.005642317], key:"lightcyan4" },
+    crate::nearest::Node{ lab:[0.61902016,-0.011989233,-0.030206343], key:"lightslategray" },
+    crate::nearest::Node{ lab:[0.63015157,-0.008986197,-0.0027333114], key:"azure4" },
+    crate::nearest::Node{ lab:[0.48193148,3.9013764e-11,1.796347e-08], key:"grey37" },
+    crate::nearest::Node{ lab:[0.39042085,3.1605663e-11,1.455251e-08], key:"grey27" },
+    crate::nearest::Node{ lab:[0.35232472,2.852174e-11,1.313252e-08], key:"gray23" },
+    crate::nearest::Node{ lab:[0.32109252,2.599343e-11,1.1968373e-08], key:"grey20" },
+    crate::nearest::Node{ lab:[0.30918562,2.5029367e-11,1.1524556e-08], key:"gray19" },
+    crate::nearest::Node{ lab:[0.30118382,2.4381636e-11,1.1226297e-08], key:"grey18" },
+    crate::nearest::Node{ lab:[0.30118382,2.4381636e-11,1.1226297e-08], key:"gray18" },
+    crate::nearest::Node{ lab:[0.32109252,2.599343e-11,1.1968373e-08], key:"gray20" },
+    crate::nearest::Node{ lab:[0.30918562,2.5029367e-11,1.1524556e-08], key:"grey19" },
+    crate::nearest::Node{ lab:[0.34069705,2.7580382e-11,1.269911e-08], key:"gray22" },
+    crate::nearest::Node{ lab:[0.33288997,2.6948443e-11,1.240811e-08], key:"grey21" },
+    crate::nearest::Node{ lab:[0.33288997,2.6948443e-11,1.240811e-08], key:"gray21" },
+    crate::nearest::Node{ lab:[0.34069705,2.7580382e-11,1.269911e-08], key:"grey22" },
+    crate::nearest::Node{ lab:[0.37149495,3.0073527e-11,1.3847068e-08], key:"grey25" },
+    crate::nearest::Node{ lab:[0.36002338,2.9144964e-11,1.3419478e-08], key:"grey24" },
+    crate::nearest::Node{ lab:[0.36002338,2.9144964e-11,1.3419478e-08], key:"gray24" },
+    crate::nearest::Node{ lab:[0.35232472,2.852174e-11,1.313252e-08], key:"grey23" },
+    crate::nearest::Node{ lab:[0.37149495,3.0073527e-11,1.3847068e-08], key:"gray25" },
+    crate::nearest::Node{ lab:[0.3790936,3.0688813e-11,1.41303e-08], key:"grey26" },
+    crate::nearest::Node{ lab:[0.43601784,-0.11769909,0.0903289], key:"darkgreen" },
+    crate::nearest::Node{ lab:[0.3790936,3.0688813e-11,1.41303e-08], key:"gray26" },
+    crate::nearest::Node{ lab:[0.39042085,3.1605663e-11,1.455251e-08], key:"gray27" },
+    crate::nearest::Node{ lab:[0.43860474,3.5506265e-11,1.6348514e-08], key:"grey32" },
+    crate::nearest::Node{ lab:[0.42023715,3.4019287e-11,1.566388e-08], key:"gray30" },
+    crate::nearest::Node{ lab:[0.40912,3.3119563e-11,1.5249501e-08], key:"gray29" },
+    crate::nearest::Node{ lab:[0.3979268,3.221326e-11,1.4832287e-08], key:"grey28" },
+    crate::nearest::Node{ lab:[0.3979268,3.221326e-11,1.4832287e-08], key:"gray28" },
+    crate::nearest::Node{ lab:[0.40912,3.3119563e-11,1.5249501e-08], key:"grey29" },
+    crate::nearest::Node{ lab:[0.4276078,3.4616088e-11,1.5938614e-08], key:"grey31" },
+    crate::nearest::Node{ lab:[0.4276078,3.4616088e-11,1.5938614e-08], key:"gray31" },
+    crate::nearest::Node{ lab:[0.42023715,3.4019287e-11,1.566388e-08], key:"grey30" },
+    crate::nearest::Node{ lab:[0.43860474,3.5506265e-11,1.6348514e-08], key:"gray32" },
+    crate::nearest::Node{ lab:[0.46400213,3.7562425e-11,1.7295173e-08], key:"gray35" },
+    crate::nearest::Node{ lab:[0.45678198,3.697781e-11,1.702605e-08], key:"gray34" },
+    crate::nearest::Node{ lab:[0.44589785,3.609671e-11,1.6620355e-08], key:"grey33" },
+    crate::nearest::Node{ lab:[0.44589785,3.609671e-11,1.6620355e-08], key:"gray33" },
+    crate::nearest::Node{ lab:[0.45678198,3.697781e-11,1.702605e-08], key:"grey34" },
+    crate::nearest::Node{ lab:[0.4747801,3.8434866e-11,1.7696909e-08], key:"grey36" },
+    crate::nearest::Node{ lab:[0.4747801,3.8434866e-11,1.7696909e-08], key:"gray36" },
+    crate::nearest::Node{ lab:[0.46400213,3.7562425e-11,1.7295173e-08], key:"grey35" },
+    crate::nearest::Node{ lab:[0.48193148,3.9013764e-11,1.796347e-08], key:"gray37" },
+    crate::nearest::Node{ lab:[0.63183963,-0.007263154,0.023628818], key:"lightyellow4" },
+    crate::nearest::Node{ lab:[0.5996245,-0.011341041,0.07265714], key:"lightgoldenrod4" },
+    crate::nearest::Node{ lab:[0.5842524,-0.07875323,0.014262554], key:"aquamarine4" },
+    crate::nearest::Node{ lab:[0.5685264,-0.107546546,0.05026605], key:"seagreen4" },
+    crate::nearest::Node{ lab:[0.55758286,-0.12978213,0.0694756], key:"springgreen4" },
+    crate::nearest::Node{ lab:[0.5685264,-0.107546546,0.05026605], key:"seagreen" },
+    crate::nearest::Node{ lab:[0.4955208,-0.05289934,0.072315395], key:"darkolivegreen" },
+    crate::nearest::Node{ lab:[0.5838624,-0.081874035,0.05916482], key:"palegreen4" },
+    crate::nearest::Node{ lab:[0.62769604,-0.012387319,0.008547659], key:"honeydew4" },
+    crate::nearest::Node{ lab:[0.68404204,-0.13052309,0.060866643], key:"mediumseagreen" },
+    crate::nearest::Node{ lab:[0.60131794,-0.051982213,0.036761448], key:"darkseagreen4" },
+    crate::nearest::Node{ lab:[0.6248515,-0.008507609,0.03513749], key:"lemonchiffon4" },
+    crate::nearest::Node{ lab:[0.59046817,-0.07978732,0.109068125], key:"olivedrab4" },
+    crate::nearest::Node{ lab:[0.5681456,-0.11911728,0.117351025], key:"chartreuse4" },
+    crate::nearest::Node{ lab:[0.5517141,-0.14893027,0.11429746], key:"green4" },
+    crate::nearest::Node{ lab:[0.5578046,-0.1346051,0.10182646], key:"forestgreen" },
+    crate::nearest::Node{ lab:[0.56632847,-0.011969941,0.11586064], key:"gold4" },
+    crate::nearest::Node{ lab:[0.6163727,-0.045444977,0.12644129], key:"yellow4" },
+    crate::nearest::Node{ lab:[0.59948385,-0.081376776,0.11069002], key:"olivedrab" },
+    crate::nearest::Node{ lab:[0.59668326,-0.06591139,0.08905967], key:"darkolivegreen4" },
+    crate::nearest::Node{ lab:[0.61117095,-0.018509518,0.07442535], key:"khaki4" },
+    crate::nearest::Node{ lab:[0.52080655,4.216083e-11,1.9412495e-08], key:"grey41" },
+    crate::nearest::Node{ lab:[0.51027834,4.1308457e-11,1.9020069e-08], key:"gray40" },
+    crate::nearest::Node{ lab:[0.49969557,4.0451864e-11,1.8625606e-08], key:"gray39" },
+    crate::nearest::Node{ lab:[0.4926092,3.987813e-11,1.8361469e-08], key:"grey38" },
+    crate::nearest::Node{ lab:[0.4926092,3.987813e-11,1.8361469e-08], key:"gray38" },
+    crate::nearest::Node{ lab:[0.49969557,4.0451864e-11,1.8625606e-08], key:"grey39" },
+    crate::nearest::Node{ lab:[0.52080655,4.216083e-11,1.9412495e-08], key:"dimgrey" },
+    crate::nearest::Node{ lab:[0.52080655,4.216083e-11,1.9412495e-08], key:"dimgray" },
+    crate::nearest::Node{ lab:[0.51027834,4.1308457e-11,1.9020069e-08], key:"grey40" },
+    crate::nearest::Node{ lab:[0.52080655,4.216083e-11,1.9412495e-08], key:"gray41" },
+    crate::nearest::Node{ lab:[0.5451691,4.4132975e-11,2.0320584e-08], key:"gray44" },
+    crate::nearest::Node{ lab:[0.5382367,4.3571674e-11,2.0062183e-08], key:"gray43" },
+    crate::nearest::Node{ lab:[0.52779585,4.2726406e-11,1.9673013e-08], key:"grey42" },
+    crate::nearest::Node{ lab:[0.52779585,4.2726406e-11,1.9673013e-08], key:"gray42" },
+    crate::nearest::Node{ lab:[0.5382367,4.3571674e-11,2.0062183e-08], key:"grey43" },
+    crate::nearest::Node{ lab:[0.5451691,4.4132975e-11,2.0320584e-08], key:"grey44" },
+    crate::nearest::Node{ lab:[0.6247029,-0.0034092057,0.02335487], key:"cornsilk4" },
+    crate::nearest::Node{ lab:[0.63435227,-0.0033723156,0.011219934], key:"ivory4" },
+    crate::nearest::Node{ lab:[0.55552673,4.497136e-11,2.0706652e-08], key:"gray45" },
+    crate::nearest::Node{ lab:[0.6731222,5.449119e-11,2.5089895e-08], key:"gray59" },
+    crate::nearest::Node{ lab:[0.5964894,4.8287374e-11,2.2233493e-08], key:"grey50" },
+    crate::nearest::Node{ lab:[0.41182733,0.11155728,-0.12553596], key:"darkorchid4" },
+    crate::nearest::Node{ lab:[0.5608322,0.059349135,-0.039320562], key:"plum4" },
+    crate::nearest::Node{ lab:[0.46178827,0.11036024,-0.09568766], key:"mediumorchid4" },
+    crate::nearest::Node{ lab:[0.4143428,0.034491476,-0.11997692], key:"darkslateblue" },
+    crate::nearest::Node{ lab:[0.41156593,0.03437831,-0.121655814], key:"slateblue4" },
+    crate::nearest::Node{ lab:[0.28811887,0.006927588,-0.14345874], key:"midnightblue" },
+    crate::nearest::Node{ lab:[0.45334104,0.049142104,-0.0975723], key:"mediumpurple4" },
+    crate::nearest::Node{ lab:[0.3783602,0.091318995,-0.14529027], key:"purple4" },
+    crate::nearest::Node{ lab:[0.5955492,0.067665964,-0.1350069], key:"mediumpurple3" },
+    crate::nearest::Node{ lab:[0.5435672,0.045877244,-0.16496573], key:"slateblue" },
+    crate::nearest::Node{ lab:[0.54100865,0.04573151,-0.16652025], key:"slateblue3" },
+    crate::nearest::Node{ lab:[0.5064539,0.10826524,-0.06676247], key:"orchid4" },
+    crate::nearest::Node{ lab:[0.5795099,4.691303e-11,2.1600599e-08], key:"gray48" },
+    crate::nearest::Node{ lab:[0.57268333,4.6360582e-11,2.1346146e-08], key:"gray47" },
+    crate::nearest::Node{ lab:[0.56240505,4.5528192e-11,2.0963034e-08], key:"grey46" },
+    crate::nearest::Node{ lab:[0.56240505,4.5528192e-11,2.0963034e-08], key:"gray46" },
+    crate::nearest::Node{ lab:[0.57268333,4.6360582e-11,2.1346146e-08], key:"grey47" },
+    crate::nearest::Node{ lab:[0.5897123,4.7739146e-11,2.1980883e-08], key:"grey49" },
+    crate::nearest::Node{ lab:[0.5897123,4.7739146e-11,2.1980883e-08], key:"gray49" },
+    crate::nearest::Node{ lab:[0.5795099,4.691303e-11,2.1600599e-08], key:"grey48" },
+    crate::nearest::Node{ lab:[0.5964894,4.8287374e-11,2.2233493e-08], key:"gray50" },
+    crate::nearest::Node{ lab:[0.44679788,0.17483285,-0.10771182], key:"magenta4" },
+    crate::nearest::Node{ lab:[0.5431449,0.15170917,-0.170792], key:"darkorchid3" },
+    crate::nearest::Node{ lab:[0.54111457,0.15060078,-0.1701666], key:"darkorchid" },
+    crate::nearest::Node{ lab:[0.49642208,0.121555,-0.19840966], key:"purple3" },
+    crate::nearest::Node{ lab:[0.53376496,0.13031808,-0.21370523], key:"blueviolet" },
+    crate::nearest::Node{ lab:[0.44679788,0.17483285,-0.10771182], key:"darkmagenta" },
+    crate::nearest::Node{ lab:[0.51490974,0.16689838,-0.20023905], key:"darkviolet" },
+    crate::nearest::Node{ lab:[0.565501,0.16262309,-0.22063299], key:"purple" },
+    crate::nearest::Node{ lab:[0.5529085,0.1358553,-0.22356771], key:"purple2" },
+    crate::nearest::Node{ lab:[0.5817672,0.14207394,-0.23599249], key:"purple1" },
+    crate::nearest::Node{ lab:[0.59524554,0.23292069,-0.14349884], key:"magenta3" },
+    crate::nearest::Node{ lab:[0.55336696,0.21807168,-0.03968207], key:"mediumvioletred" },
+    crate::nearest::Node{ lab:[0.4373559,0.15648307,-0.035171498], key:"maroon4" },
+    crate::nearest::Node{ lab:[0.43603435,0.1457624,-0.0067519387], key:"violetred4" },
+    crate::nearest::Node{ lab:[0.47028607,0.11825439,-0.016910905], key:"hotpink4" },
+    crate::nearest::Node{ lab:[0.41952026,0.164882,-0.01194501], key:"deeppink4" },
+    crate::nearest::Node{ lab:[0.5565759,0.22079502,-0.0128544485], key:"deeppink3" },
+    crate::nearest::Node{ lab:[0.57583094,0.19795083,-0.0060192803], key:"violetred3" },
+    crate::nearest::Node{ lab:[0.57822925,0.2122898,-0.045384336], key:"maroon3" },
+    crate::nearest::Node{ lab:[0.57809794,0.22113267,-0.04583584], key:"violetred" },
+    crate::nearest::Node{ lab:[0.63430214,0.054095298,-0.19840683], key:"slateblue1" },
+    crate::nearest::Node{ lab:[0.63342893,5.1278093e-11,2.3610372e-08], key:"gray54" },
+    crate::nearest::Node{ lab:[0.61670697,4.9924342e-11,2.2987077e-08], key:"gray52" },
+    crate::nearest::Node{ lab:[0.6027434,0.025248317,-0.017063685], key:"thistle4" },
+    crate::nearest::Node{ lab:[0.60661924,4.9107718e-11,2.261107e-08], key:"grey51" },
+    crate::nearest::Node{ lab:[0.60661924,4.9107718e-11,2.261107e-08], key:"gray51" },
+    crate::nearest::Node{ lab:[0.6044729,0.051809892,-0.18683998], key:"mediumslateblue" },
+    crate::nearest::Node{ lab:[0.6019964,0.051651165,-0.1883475], key:"slateblue2" },
+    crate::nearest::Node{ lab:[0.62340915,5.046652e-11,2.3236897e-08], key:"grey53" },
+    crate::nearest::Node{ lab:[0.62340915,5.046652e-11,2.3236897e-08], key:"gray53" },
+    crate::nearest::Node{ lab:[0.61670697,4.9924342e-11,2.2987077e-08], key:"grey52" },
+    crate::nearest::Node{ lab:[0.617952,0.010480625,-0.0013674488], key:"lavenderblush4" },
+    crate::nearest::Node{ lab:[0.6500413,5.262285e-11,2.4229578e-08], key:"grey56" },
+    crate::nearest::Node{ lab:[0.64008677,5.181694e-11,2.3858536e-08], key:"grey55" },
+    crate::nearest::Node{ lab:[0.64008677,5.181694e-11,2.3858536e-08], key:"gray55" },
+    crate::nearest::Node{ lab:[0.63342893,5.1278093e-11,2.3610372e-08], key:"grey54" },
+    crate::nearest::Node{ lab:[0.6500413,5.262285e-11,2.4229578e-08], key:"gray56" },
+    crate::nearest::Node{ lab:[0.66654813,5.3958782e-11,2.4844853e-08], key:"gray58" },
+    crate::nearest::Node{ lab:[0.65665644,5.3158145e-11,2.447615e-08], key:"grey57" },
+    crate::nearest::Node{ lab:[0.65665644,5.3158145e-11,2.447615e-08], key:"gray57" },
+    crate::nearest::Node{ lab:[0.66654813,5.3958782e-11,2.4844853e-08], key:"grey58" },
+    crate::nearest::Node{ lab:[0.6109126,0.1496776,-0.13011053], key:"mediumorchid3" },
+    crate::nearest::Node{ lab:[0.6367466,0.054251764,-0.19691874], key:"lightslateblue" },
+    crate::nearest::Node{ lab:[0.6046069,0.16946268,-0.19296487], key:"darkorchid2" },
+    crate::nearest::Node{ lab:[0.62557954,0.15330534,-0.132207], key:"mediumorchid" },
+    crate::nearest::Node{ lab:[0.62691426,0.07093668,-0.14121489], key:"mediumpurple" },
+    crate::nearest::Node{ lab:[0.6363712,0.17930016,-0.20374893], key:"darkorchid1" },
+    crate::nearest::Node{ lab:[0.6814634,0.16894074,-0.14683022], key:"mediumorchid2" },
+    crate::nearest::Node{ lab:[0.66430587,0.07579208,-0.15222797], key:"mediumpurple2" },
+    crate::nearest::Node{ lab:[0.7000409,0.080385715,-0.1603432], key:"mediumpurple1" },
+    crate::nearest::Node{ lab:[0.6659722,0.26059616,-0.16054927], key:"magenta2" },
+    crate::nearest::Node{ lab:[0.654935,0.26096505,-0.0139288595], key:"deeppink" },
+    crate::nearest::Node{ lab:[0.64581424,0.23833886,-0.050052892], key:"maroon2" },
+    crate::nearest::Node{ lab:[0.64292175,0.22297367,-0.007118094], key:"violetred2" },
+    crate::nearest::Node{ lab:[0.6351045,0.14665842,-0.0142133], key:"hotpink3" },
+    crate::nearest::Node{ lab:[0.6217096,0.24768505,-0.0134547185], key:"deeppink2" },
+    crate::nearest::Node{ lab:[0.6802419,0.25121078,-0.05249582], key:"maroon1" },
+    crate::nearest::Node{ lab:[0.67662543,0.23559928,-0.0072812247], key:"violetred1" },
+    crate::nearest::Node{ lab:[0.6704608,0.147781,-0.089537114], key:"orchid3" },
+    crate::nearest::Node{ lab:[0.654935,0.26096505,-0.0139288595], key:"deeppink1" },
+    crate::nearest::Node{ lab:[0.6099874,0.12611261,0.08073549], key:"coral3" },
+    crate::nearest::Node{ lab:[0.46245888,0.091527864,0.058573905], key:"coral4" },
+    crate::nearest::Node{ lab:[0.48776054,0.071755536,0.05521808], key:"salmon4" },
+    crate::nearest::Node{ lab:[0.58293104,0.015972449,0.032766562], key:"peachpuff4" },
+    crate::nearest::Node{ lab:[0.54387563,0.053052094,0.0038379387], key:"pink4" },
+    crate::nearest::Node{ lab:[0.5707023,0.015680756,0.05025606], key:"burlywood4" },
+    crate::nearest::Node{ lab:[0.5548978,0.041482266,0.013928598], key:"rosybrown4" },
+    crate::nearest::Node{ lab:[0.5098221,0.05620653,0.05179587], key:"lightsalmon4" },
+    crate::nearest::Node{ lab:[0.5339498,0.057117853,0.010298147], key:"lightpink4" },
+    crate::nearest::Node{ lab:[0.5855615,0.009550085,0.0439265], key:"navajowhite4" },
+    crate::nearest::Node{ lab:[0.5982654,0.0048094625,0.037975695], key:"wheat4" },
+    crate::nearest::Node{ lab:[0.5973325,0.008652725,0.030307405], key:"bisque4" },
+    crate::nearest::Node{ lab:[0.6017655,0.015550135,0.008052539], key:"mistyrose4" },
+    crate::nearest::Node{ lab:[0.50910914,0.032353424,0.103693455], key:"orange4" },
+    crate::nearest::Node{ lab:[0.4707835,0.07080872,0.08696012], key:"saddlebrown" },
+    crate::nearest::Node{ lab:[0.469857,0.067558736,0.09528921], key:"darkorange4" },
+    crate::nearest::Node{ lab:[0.4707835,0.07080872,0.08696012], key:"chocolate4" },
+    crate::nearest::Node{ lab:[0.4760418,0.07299125,0.07313289], key:"sienna4" },
+    crate::nearest::Node{ lab:[0.5120029,0.04183085,0.07934129], key:"tan4" },
+    crate::nearest::Node{ lab:[0.5319594,0.014957871,0.106074095], key:"darkgoldenrod4" },
+    crate::nearest::Node{ lab:[0.5409162,0.010079277,0.103645064], key:"goldenrod4" },
+    crate::nearest::Node{ lab:[0.52648216,0.081961505,0.08083752], key:"sienna" },
+    crate::nearest::Node{ lab:[0.39985687,0.14318381,0.08013389], key:"darkred" },
+    crate::nearest::Node{ lab:[0.48061255,0.14402948,0.068890296], key:"brown" },
+    crate::nearest::Node{ lab:[0.4252157,0.12611888,0.05991149], key:"brown4" },
+    crate::nearest::Node{ lab:[0.44903964,0.10086437,0.064476825], key:"tomato4" },
+    crate::nearest::Node{ lab:[0.45843133,0.102621205,0.042429518], key:"indianred4" },
+    crate::nearest::Node{ lab:[0.4158355,0.13265726,0.06641371], key:"firebrick4" },
+    crate::nearest::Node{ lab:[0.51770574,0.16756217,0.0069583473], key:"maroon" },
+    crate::nearest::Node{ lab:[0.6044245,0.14115185,0.05932767], key:"indianred3" },
+    crate::nearest::Node{ lab:[0.48834527,0.09540408,0.0010699424], key:"palevioletred4" },
+    crate::nearest::Node{ lab:[0.5622573,0.21738389,0.046571], key:"debianred" },
+    crate::nearest::Node{ lab:[0.5497453,0.17963804,0.0917285], key:"firebrick3" },
+    crate::nearest::Node{ lab:[0.49677107,0.1603672,0.081059314], key:"firebrick" },
+    crate::nearest::Node{ lab:[0.42432198,0.114615396,0.08546014], key:"orangered4" },
+    crate::nearest::Node{ lab:[0.39985687,0.14318381,0.08013389], key:"red4" },
+    crate::nearest::Node{ lab:[0.53270847,0.19075634,0.10675821], key:"red3" },
+    crate::nearest::Node{ lab:[0.5611505,0.17176974,0.08337059], key:"brown3" },
+    crate::nearest::Node{ lab:[0.56139565,0.15708405,0.11300612], key:"orangered3" },
+    crate::nearest::Node{ lab:[0.59212214,0.13936974,0.08738763], key:"tomato3" },
+    crate::nearest::Node{ lab:[0.59600455,0.21342187,0.11944315], key:"red2" },
+    crate::nearest::Node{ lab:[0.6279623,0.10192827,0.098654106], key:"sienna3" },
+    crate::nearest::Node{ lab:[0.6137918,0.0045657926,0.018341005], key:"antiquewhite4" },
+    crate::nearest::Node{ lab:[0.6927437,0.051944137,0.017446116], key:"rosybrown" },
+    crate::nearest::Node{ lab:[0.6829534,5.5286997e-11,2.5456341e-08], key:"grey60" },
+    crate::nearest::Node{ lab:[0.6829534,5.5286997e-11,2.5456341e-08], key:"gray60" },
+    crate::nearest::Node{ lab:[0.6731222,5.449119e-11,2.5089895e-08], key:"grey59" },
+    crate::nearest::Node{ lab:[0.62317777,0.004057066,0.0074336072], key:"seashell4" },
+    crate::nearest::Node{ lab:[0.63180345,0.0022778457,0.0007060218], key:"snow4" },
+    crate::nearest::Node{ lab:[0.6992607,5.6607108e-11,2.606418e-08], key:"gray62" },
+    crate::nearest::Node{ lab:[0.6927493,5.6079974e-11,2.5821475e-08], key:"grey61" },
+    crate::nearest::Node{ lab:[0.6927493,5.6079974e-11,2.5821475e-08], key:"gray61" },
+    crate::nearest::Node{ lab:[0.6992607,5.6607108e-11,2.606418e-08], key:"grey62" },
+    crate::nearest::Node{ lab:[0.65206957,0.01937692,0.13077164], key:"darkgoldenrod" },
+    crate::nearest::Node{ lab:[0.64454734,0.09877471,0.075936854], key:"salmon3" },
+    crate::nearest::Node{ lab:[0.62150526,0.0942904,0.1259902], key:"darkorange3" },
+    crate::nearest::Node{ lab:[0.6225055,0.097825624,0.116935566], key:"chocolate3" },
+    crate::nearest::Node{ lab:[0.6343984,0.09907391,0.11919316], key:"chocolate" },
+    crate::nearest::Node{ lab:[0.67819256,0.057283256,0.10856277], key:"tan3" },
+    crate::nearest::Node{ lab:[0.67480046,0.0460279,0.13740693], key:"orange3" },
+    crate::nearest::Node{ lab:[0.67819256,0.057283256,0.10856277], key:"peru" },
+    crate::nearest::Node{ lab:[0.6759614,0.076468565,0.07086481], key:"lightsalmon3" },
+    crate::nearest::Node{ lab:[0.6962193,0.1652304,0.104540884], key:"tomato" },
+    crate::nearest::Node{ lab:[0.6597478,0.20369229,0.09947552], key:"brown1" },
+    crate::nearest::Node{ lab:[0.626114,0.19337378,0.094459645], key:"brown2" },
+    crate::nearest::Node{ lab:[0.61544085,0.1334389,0.05453257], key:"indianred" },
+    crate::nearest::Node{ lab:[0.6443997,0.13207494,0.0017032273], key:"palevioletred3" },
+    crate::nearest::Node{ lab:[0.61373323,0.20188539,0.103681125], key:"firebrick2" },
+    crate::nearest::Node{ lab:[0.6610147,0.15675204,0.0989371], key:"tomato2" },
+    crate::nearest::Node{ lab:[0.68111503,0.14176923,0.09147514], key:"coral2" },
+    crate::nearest::Node{ lab:[0.67792284,0.1382069,0.001642491], key:"palevioletred" },
+    crate::nearest::Node{ lab:[0.67487997,0.15888228,0.06699892], key:"indianred2" },
+    crate::nearest::Node{ lab:[0.66019946,0.18694869,0.13286985], key:"orangered" },
+    crate::nearest::Node{ lab:[0.6279554,0.22486307,0.1258463], key:"red" },
+    crate::nearest::Node{ lab:[0.62682915,0.17718615,0.12615736], key:"orangered2" },
+    crate::nearest::Node{ lab:[0.64669484,0.2126673,0.10919122], key:"firebrick1" },
+    crate::nearest::Node{ lab:[0.6279554,0.22486307,0.1258463], key:"red1" },
+    crate::nearest::Node{ lab:[0.6962193,0.1652304,0.104540884], key:"tomato1" },
+    crate::nearest::Node{ lab:[0.693096,0.10767896,0.14047521], key:"darkorange2" },
+    crate::nearest::Node{ lab:[0.6940921,0.111212455,0.13142805], key:"chocolate2" },
+    crate::nearest::Node{ lab:[0.66019946,0.18694869,0.13286985], key:"orangered1" },
+    crate::nearest::Node{ lab:[0.81406355,6.590106e-11,3.034333e-08], key:"gray76" },
+    crate::nearest::Node{ lab:[0.804658,6.513967e-11,2.9992748e-08], key:"grey75" },
+    crate::nearest::Node{ lab:[0.7983724,6.463069e-11,2.9758459e-08], key:"grey74" },
+    crate::nearest::Node{ lab:[0.72515714,5.87036e-11,2.7029442e-08], key:"gray65" },
+    crate::nearest::Node{ lab:[0.7719288,-0.12593356,-0.03764616], key:"darkturquoise" },
+    crate::nearest::Node{ lab:[0.75535,-0.0952156,-0.12030096], key:"deepskyblue1" },
+    crate::nearest::Node{ lab:[0.7168135,-0.09033469,-0.114239275], key:"deepskyblue2" },
+    crate::nearest::Node{ lab:[0.7484203,-0.11957514,-0.043481637], key:"turquoise3" },
+    crate::nearest::Node{ lab:[0.75535,-0.0952156,-0.12030096], key:"deepskyblue" },
+    crate::nearest::Node{ lab:[0.7545331,-0.033119705,-0.04396445], key:"lightskyblue3" },
+    crate::nearest::Node{ lab:[0.7212357,-0.051460147,-0.11359343], key:"steelblue2" },
+    crate::nearest::Node{ lab:[0.76642185,-0.015361351,-0.03873922], key:"slategray3" },
+    crate::nearest::Node{ lab:[0.780784,-0.04711986,-0.081490085], key:"skyblue2" },
+    crate::nearest::Node{ lab:[0.7586417,-0.05376588,-0.12040324], key:"steelblue1" },
+    crate::nearest::Node{ lab:[0.7664364,-0.010912662,-0.038913358], key:"lightsteelblue3" },
+    crate::nearest::Node{ lab:[0.7680703,-0.12677662,-0.033422332], key:"cyan3" },
+    crate::nearest::Node{ lab:[0.71547353,5.7919614e-11,2.6668495e-08], key:"gray64" },
+    crate::nearest::Node{ lab:[0.7089995,5.7395422e-11,2.6427184e-08], key:"grey63" },
+    crate::nearest::Node{ lab:[0.7089995,5.7395422e-11,2.6427184e-08], key:"gray63" },
+    crate::nearest::Node{ lab:[0.71547353,5.7919614e-11,2.6668495e-08], key:"grey64" },
+    crate::nearest::Node{ lab:[0.7774605,-0.06860061,-0.031131519], key:"cadetblue3" },
+    crate::nearest::Node{ lab:[0.7952578,-0.07887135,-0.022066372], key:"darkslategray3" },
+    crate::nearest::Node{ lab:[0.7868021,-0.11386521,-0.023286536], key:"mediumturquoise" },
+    crate::nearest::Node{ lab:[0.7844699,-0.033826698,-0.029082797], key:"lightblue3" },
+    crate::nearest::Node{ lab:[0.75720674,6.129802e-11,2.8224054e-08], key:"grey69" },
+    crate::nearest::Node{ lab:[0.74122524,6.000411e-11,2.7628362e-08], key:"gray67" },
+    crate::nearest::Node{ lab:[0.731595,5.9224625e-11,2.7269405e-08], key:"grey66" },
+    crate::nearest::Node{ lab:[0.731595,5.9224625e-11,2.7269405e-08], key:"gray66" },
+    crate::nearest::Node{ lab:[0.72515714,5.87036e-11,2.7029442e-08], key:"grey65" },
+    crate::nearest::Node{ lab:[0.73480856,5.948486e-11,2.7389188e-08], key:"darkgrey" },
+    crate::nearest::Node{ lab:[0.73480856,5.948486e-11,2.7389188e-08], key:"darkgray" },
+    crate::nearest::Node{ lab:[0.7476281,6.0522864e-11,2.786702e-08], key:"grey68" },
+    crate::nearest::Node{ lab:[0.7476281,6.0522864e-11,2.786702e-08], key:"gray68" },
+    crate::nearest::Node{ lab:[0.74122524,6.000411e-11,2.7628362e-08], key:"grey67" },
+    crate::nearest::Node{ lab:[0.75720674,6.129802e-11,2.8224054e-08], key:"gray69" },
+    crate::nearest::Node{ lab:[0.7826037,6.33541e-11,2.9170698e-08], key:"gray72" },
+    crate::nearest::Node{ lab:[0.77310437,6.2584715e-11,2.881662e-08], key:"gray71" },
+    crate::nearest::Node{ lab:[0.7667552,6.207107e-11,2.8579963e-08], key:"grey70" },
+    crate::nearest::Node{ lab:[0.7667552,6.207107e-11,2.8579963e-08], key:"gray70" },
+    crate::nearest::Node{ lab:[0.77310437,6.2584715e-11,2.881662e-08], key:"grey71" },
+    crate::nearest::Node{ lab:[0.78892064,6.3865246e-11,2.9406154e-08], key:"grey73" },
+    crate::nearest::Node{ lab:[0.78892064,6.3865246e-11,2.9406154e-08], key:"gray73" },
+    crate::nearest::Node{ lab:[0.7826037,6.33541e-11,2.9170698e-08], key:"grey72" },
+    crate::nearest::Node{ lab:[0.7983724,6.463069e-11,2.9758459e-08], key:"gray74" },
+    crate::nearest::Node{ lab:[0.887641,-0.03845864,-0.05275574], key:"lightskyblue1" },
+    crate::nearest::Node{ lab:[0.92383033,-0.04108929,-0.03467519], key:"lightblue1" },
+    crate::nearest::Node{ lab:[0.8700574,-0.077376,-0.03469788], key:"cadetblue2" },
+    crate::nearest::Node{ lab:[0.81481725,-0.057156272,-0.05868004], key:"skyblue" },
+    crate::nearest::Node{ lab:[0.85933185,-0.14184012,-0.03739355], key:"cyan2" },
+    crate::nearest::Node{ lab:[0.8378374,-0.13396254,-0.048394755], key:"turquoise2" },
+    crate::nearest::Node{ lab:[0.8206188,-0.051824216,-0.07905587], key:"lightskyblue" },
+    crate::nearest::Node{ lab:[0.82264006,-0.050129265,-0.085837685], key:"skyblue1" },
+    crate::nearest::Node{ lab:[0.88182646,-0.14080492,-0.05146767], key:"turquoise1" },
+    crate::nearest::Node{ lab:[0.9053992,-0.14944394,-0.039398156], key:"cyan1" },
+    crate::nearest::Node{ lab:[0.9053992,-0.14944394,-0.039398156], key:"cyan" },
+    crate::nearest::Node{ lab:[0.91559553,-0.08147634,-0.037101988], key:"cadetblue1" },
+    crate::nearest::Node{ lab:[0.88959485,-0.088499345,-0.024752988], key:"darkslategray2" },
+    crate::nearest::Node{ lab:[0.8750832,-0.045226052,-0.021795848], key:"powderblue" },
+    crate::nearest::Node{ lab:[0.8223342,-0.13022855,-0.011597303], key:"turquoise" },
+    crate::nearest::Node{ lab:[0.810449,-0.05452667,-0.015725845], key:"paleturquoise3" },
+    crate::nearest::Node{ lab:[0.87677443,-0.03902575,-0.032934643], key:"lightblue2" },
+    crate::nearest::Node{ lab:[0.9063425,-0.061631925,-0.017762616], key:"paleturquoise2" },
+    crate::nearest::Node{ lab:[0.95510685,-0.06466132,-0.018641125], key:"paleturquoise1" },
+    crate::nearest::Node{ lab:[0.937082,-0.09357936,-0.026164567], key:"darkslategray1" },
+    crate::nearest::Node{ lab:[0.90690863,-0.06075319,-0.01752655], key:"paleturquoise" },
+    crate::nearest::Node{ lab:[0.9778582,-0.030687958,-0.009171604], key:"lightcyan1" },
+    crate::nearest::Node{ lab:[0.85635716,-0.012214361,-0.04410883], key:"lightsteelblue2" },
+    crate::nearest::Node{ lab:[0.85634136,-0.016541759,-0.043940056], key:"slategray2" },
+    crate::nearest::Node{ lab:[0.8562331,-0.037679408,-0.031231297], key:"lightblue" },
+    crate::nearest::Node{ lab:[0.8428369,-0.036707148,-0.049881805], key:"lightskyblue2" },
+    crate::nearest::Node{ lab:[0.81362313,-0.011055388,-0.04135573], key:"lightsteelblue" },
+    crate::nearest::Node{ lab:[0.9022968,-0.012352899,-0.046478692], key:"lightsteelblue1" },
+    crate::nearest::Node{ lab:[0.9778582,-0.030687958,-0.009171604], key:"lightcyan" },
+    crate::nearest::Node{ lab:[0.90160024,-0.017608464,-0.046611745], key:"slategray1" },
+    crate::nearest::Node{ lab:[0.97514284,-0.005500548,-0.011404169], key:"aliceblue" },
+    crate::nearest::Node{ lab:[0.83910716,-0.012540363,-0.0038116644], key:"azure3" },
+    crate::nearest::Node{ lab:[0.8015167,6.4884986e-11,2.987566e-08], key:"grey" },
+    crate::nearest::Node{ lab:[0.8015167,6.4884986e-11,2.987566e-08], key:"gray" },
+    crate::nearest::Node{ lab:[0.8297055,-0.025793977,-0.0077112643], key:"lightcyan3" },
+    crate::nearest::Node{ lab:[0.804658,6.513967e-11,2.9992748e-08], key:"gray75" },
+    crate::nearest::Node{ lab:[0.98895067,-0.015041059,-0.0045705023], key:"azure1" },
+    crate::nearest::Node{ lab:[0.98895067,-0.015041059,-0.0045705023], key:"azure" },
+    crate::nearest::Node{ lab:[0.92813194,-0.029086463,-0.008693361], key:"lightcyan2" },
+    crate::nearest::Node{ lab:[0.9386699,-0.01422337,-0.0043222858], key:"azure2" },
+    crate::nearest::Node{ lab:[0.88730097,-0.102280386,0.13700965], key:"darkolivegreen2" },
+    crate::nearest::Node{ lab:[0.78485227,-0.10964247,0.14744212], key:"olivedrab3" },
+    crate::nearest::Node{ lab:[0.8749299,-0.20581263,0.11397103], key:"springgreen1" },
+    crate::nearest::Node{ lab:[0.86680585,-0.19017537,0.081097126], key:"mediumspringgreen" },
+    crate::nearest::Node{ lab:[0.8448486,-0.16531892,0.079314955], key:"seagreen2" },
+    crate::nearest::Node{ lab:[0.83039993,-0.19537657,0.10825914], key:"springgreen2" },
+    crate::nearest::Node{ lab:[0.74240494,-0.17408928,0.095453866], key:"springgreen3" },
+    crate::nearest::Node{ lab:[0.7762888,-0.11192439,0.08101421], key:"palegreen3" },
+    crate::nearest::Node{ lab:[0.75562465,-0.1467403,0.06956232], key:"seagreen3" },
+    crate::nearest::Node{ lab:[0.86800325,-0.126184,0.09138165], key:"palegreen2" },
+    crate::nearest::Node{ lab:[0.8749299,-0.20581263,0.11397103], key:"springgreen" },
+    crate::nearest::Node{ lab:[0.86800325,-0.126184,0.09138165], key:"lightgreen" },
+    crate::nearest::Node{ lab:[0.86847925,-0.12140722,0.023258306], key:"aquamarine2" },
+    crate::nearest::Node{ lab:[0.8449301,-0.18087514,0.17455946], key:"chartreuse2" },
+    crate::nearest::Node{ lab:[0.74187446,-0.18220717,0.13812841], key:"limegreen" },
+    crate::nearest::Node{ lab:[0.8223545,-0.2219872,0.17036547], key:"green2" },
+    crate::nearest::Node{ lab:[0.73502,-0.19841203,0.15227255], key:"green3" },
+    crate::nearest::Node{ lab:[0.7557282,-0.16074371,0.15612002], key:"chartreuse3" },
+    crate::nearest::Node{ lab:[0.88175315,-0.18977894,0.18217745], key:"lawngreen" },
+    crate::nearest::Node{ lab:[0.86643964,-0.23388757,0.17949848], key:"green1" },
+    crate::nearest::Node{ lab:[0.86643964,-0.23388757,0.17949848], key:"green" },
+    crate::nearest::Node{ lab:[0.87781817,-0.12330499,0.16570996], key:"olivedrab2" },
+    crate::nearest::Node{ lab:[0.76747406,-0.02457125,0.094910465], key:"darkkhaki" },
+    crate::nearest::Node{ lab:[0.82980406,-0.010213281,0.047467917], key:"lemonchiffon3" },
+    crate::nearest::Node{ lab:[0.7999691,-0.07109455,0.050324704], key:"darkseagreen3" },
+    crate::nearest::Node{ lab:[0.77668625,-0.107762344,0.0212899], key:"mediumaquamarine" },
+    crate::nearest::Node{ lab:[0.77668625,-0.107762344,0.0212899], key:"aquamarine3" },
+    crate::nearest::Node{ lab:[0.7508648,-0.06507693,0.046025883], key:"darkseagreen" },
+    crate::nearest::Node{ lab:[0.844966,-0.004711405,0.015659759], key:"ivory3" },
+    crate::nearest::Node{ lab:[0.841601,-0.009947961,0.032326695], key:"lightyellow3" },
+    crate::nearest::Node{ lab:[0.8356812,-0.017294098,0.011937526], key:"honeydew3" },
+    crate::nearest::Node{ lab:[0.830616,-0.003827372,0.03148308], key:"cornsilk3" },
+    crate::nearest::Node{ lab:[0.81415284,-0.025918249,0.10202853], key:"khaki3" },
+    crate::nearest::Node{ lab:[0.7534343,-0.015193077,0.15413137], key:"gold3" },
+    crate::nearest::Node{ lab:[0.78485227,-0.10964247,0.14744212], key:"yellowgreen" },
+    crate::nearest::Node{ lab:[0.7935365,-0.09046981,0.12130145], key:"darkolivegreen3" },
+    crate::nearest::Node{ lab:[0.79698926,-0.015161156,0.0992163], key:"lightgoldenrod3" },
+    crate::nearest::Node{ lab:[0.8867711,-0.016925177,0.18139815], key:"gold1" },
+    crate::nearest::Node{ lab:[0.8867711,-0.016925177,0.18139815], key:"gold" },
+    crate::nearest::Node{ lab:[0.82116127,-0.060543977,0.16845113], key:"yellow3" },
+    crate::nearest::Node{ lab:[0.8425985,-0.016742054,0.17236921], key:"gold2" },
+    crate::nearest::Node{ lab:[0.9347844,-0.01961765,0.013542785], key:"honeydew2" },
+    crate::nearest::Node{ lab:[0.9107944,-0.029606732,0.11560869], key:"khaki2" },
+    crate::nearest::Node{ lab:[0.91499454,-0.12798631,0.024896637], key:"aquamarine" },
+    crate::nearest::Node{ lab:[0.9035428,-0.1315452,0.09527285], key:"palegreen" },
+    crate::nearest::Node{ lab:[0.9141096,-0.13373195,0.09688558], key:"palegreen1" },
+    crate::nearest::Node{ lab:[0.8901033,-0.17423931,0.083472], key:"seagreen1" },
+    crate::nearest::Node{ lab:[0.91348904,-0.024851901,0.10912827], key:"khaki" },
+    crate::nearest::Node{ lab:[0.89463717,-0.080166236,0.05676233], key:"darkseagreen2" },
+    crate::nearest::Node{ lab:[0.9848418,-0.020746188,0.014322255], key:"honeydew" },
+    crate::nearest::Node{ lab:[0.94252616,-0.08457955,0.059890226], key:"darkseagreen1" },
+    crate::nearest::Node{ lab:[0.91499454,-0.12798631,0.024896637], key:"aquamarine1" },
+    crate::nearest::Node{ lab:[0.9848418,-0.020746188,0.014322255], key:"honeydew1" },
+    crate::nearest::Node{ lab:[0.9248172,-0.13010156,0.17498943], key:"olivedrab1" },
+    crate::nearest::Node{ lab:[0.91304886,-0.1501201,0.17879699], key:"greenyellow" },
+    crate::nearest::Node{ lab:[0.8902625,-0.19050698,0.18392423], key:"chartreuse1" },
+    crate::nearest::Node{ lab:[0.8902625,-0.19050698,0.18392423], key:"chartreuse" },
+    crate::nearest::Node{ lab:[0.91873103,-0.067737766,0.18846639], key:"yellow2" },
+    crate::nearest::Node{ lab:[0.9679827,-0.07136908,0.19856976], key:"yellow1" },
+    crate::nearest::Node{ lab:[0.9679827,-0.07136908,0.19856976], key:"yellow" },
+    crate::nearest::Node{ lab:[0.93511015,-0.107393235,0.14438587], key:"darkolivegreen1" },
+    crate::nearest::Node{ lab:[0.9587041,-0.030513296,0.12140254], key:"khaki1" },
+    crate::nearest::Node{ lab:[0.99200743,-0.011841024,0.038462907], key:"lightyellow" },
+    crate::nearest::Node{ lab:[0.9773007,-0.003531187,0.03708985], key:"cornsilk" },
+    crate::nearest::Node{ lab:[0.945314,-0.0053455317,0.017762167], key:"ivory2" },
+    crate::nearest::Node{ lab:[0.9415432,-0.011222289,0.036455274], key:"lightyellow2" },
+    crate::nearest::Node{ lab:[0.9635736,-0.009584834,0.031351846], key:"beige" },
+    crate::nearest::Node{ lab:[0.9286304,-0.00423662,0.035967942], key:"cornsilk2" },
+    crate::nearest::Node{ lab:[0.9959763,-0.0056533646,0.018783517], key:"ivory1" },
+    crate::nearest::Node{ lab:[0.9959763,-0.0056533646,0.018783517], key:"ivory" },
+    crate::nearest::Node{ lab:[0.991172,-0.011939205,0.003240988], key:"mintcream" },
+    crate::nearest::Node{ lab:[0.9773007,-0.003531187,0.03708985], key:"cornsilk1" },
+    crate::nearest::Node{ lab:[0.9380866,-0.01768766,0.11828971], key:"lightgoldenrod1" },
+    crate::nearest::Node{ lab:[0.89004815,-0.016247185,0.11146374], key:"lightgoldenrod2" },
+    crate::nearest::Node{ lab:[0.8920685,-0.017707465,0.11217303], key:"lightgoldenrod" },
+    crate::nearest::Node{ lab:[0.9210476,-0.018201202,0.07770318], key:"palegoldenrod" },
+    crate::nearest::Node{ lab:[0.9273899,-0.01124498,0.053932402], key:"lemonchiffon2" },
+    crate::nearest::Node{ lab:[0.9778096,-0.012259016,0.056911167], key:"lemonchiffon1" },
+    crate::nearest::Node{ lab:[0.9778096,-0.012259016,0.056911167], key:"lemonchiffon" },
+    crate::nearest::Node{ lab:[0.9750065,-0.015435947,0.049487032], key:"lightgoldenrodyellow" },
+    crate::nearest::Node{ lab:[0.99200743,-0.011841024,0.038462907], key:"lightyellow1" },
+    crate::nearest::Node{ lab:[0.9379122,0.0051662507,0.0016045223], key:"snow2" },
+    crate::nearest::Node{ lab:[0.89143133,7.216411e-11,3.322713e-08], key:"grey86" },
+    crate::nearest::Node{ lab:[0.8853053,7.166784e-11,3.2998788e-08], key:"grey85" },
+    crate::nearest::Node{ lab:[0.85451365,6.917539e-11,3.1851062e-08], key:"grey81" },
+    crate::nearest::Node{ lab:[0.83590525,6.7668926e-11,3.1157455e-08], key:"gray79" },
+    crate::nearest::Node{ lab:[0.8203188,6.640721e-11,3.0576487e-08], key:"grey77" },
+    crate::nearest::Node{ lab:[0.8203188,6.640721e-11,3.0576487e-08], key:"gray77" },
+    crate::nearest::Node{ lab:[0.81406355,6.590106e-11,3.034333e-08], key:"grey76" },
+    crate::nearest::Node{ lab:[0.8296795,6.7164774e-11,3.0925396e-08], key:"grey78" },
+    crate::nearest::Node{ lab:[0.8296795,6.7164774e-11,3.0925396e-08], key:"gray78" },
+    crate::nearest::Node{ lab:[0.84522223,6.8423045e-11,3.1504737e-08], key:"grey80" },
+    crate::nearest::Node{ lab:[0.84522223,6.8423045e-11,3.1504737e-08], key:"gray80" },
+    crate::nearest::Node{ lab:[0.83590525,6.7668926e-11,3.1157455e-08], key:"grey79" },
+    crate::nearest::Node{ lab:[0.85451365,6.917539e-11,3.1851062e-08], key:"gray81" },
+    crate::nearest::Node{ lab:[0.86994356,7.042433e-11,3.2426197e-08], key:"gray83" },
+    crate::nearest::Node{ lab:[0.8668631,7.01752e-11,3.2311373e-08], key:"lightgray" },
+    crate::nearest::Node{ lab:[0.8606939,6.967582e-11,3.2081424e-08], key:"grey82" },
+    crate::nearest::Node{ lab:[0.8606939,6.967582e-11,3.2081424e-08], key:"gray82" },
+    crate::nearest::Node{ lab:[0.8668631,7.01752e-11,3.2311373e-08], key:"lightgrey" },
+    crate::nearest::Node{ lab:[0.8760963,7.0922546e-11,3.2655535e-08], key:"grey84" },
+    crate::nearest::Node{ lab:[0.8760963,7.0922546e-11,3.2655535e-08], key:"gray84" },
+    crate::nearest::Node{ lab:[0.86994356,7.042433e-11,3.2426197e-08], key:"grey83" },
+    crate::nearest::Node{ lab:[0.8853053,7.166784e-11,3.2998788e-08], key:"gray85" },
+    crate::nearest::Node{ lab:[0.78328353,0.08990804,-0.05940838], key:"plum" },
+    crate::nearest::Node{ lab:[0.74869144,0.16653825,-0.10036906], key:"orchid2" },
+    crate::nearest::Node{ lab:[0.70167387,0.2745663,-0.16915606], key:"magenta" },
+    crate::nearest::Node{ lab:[0.7021318,0.15488695,-0.094151884], key:"orchid" },
+    crate::nearest::Node{ lab:[0.71752864,0.17836118,-0.15499185], key:"mediumorchid1" },
+    crate::nearest::Node{ lab:[0.70167387,0.2745663,-0.16915606], key:"magenta1" },
+    crate::nearest::Node{ lab:[0.7618985,0.15646526,-0.10079778], key:"violet" },
+    crate::nearest::Node{ lab:[0.83069426,0.09298825,-0.06149542], key:"plum2" },
+    crate::nearest::Node{ lab:[0.87576056,0.09751094,-0.064497784], key:"plum1" },
+    crate::nearest::Node{ lab:[0.78874564,0.17575535,-0.106350206], key:"orchid1" },
+    crate::nearest::Node{ lab:[0.8008813,0.035278972,-0.023826094], key:"thistle3" },
+    crate::nearest::Node{ lab:[0.73434347,0.18842535,-0.024205914], key:"hotpink1" },
+    crate::nearest::Node{ lab:[0.7016037,0.17325602,-0.019340584], key:"hotpink2" },
+    crate::nearest::Node{ lab:[0.74356294,0.08217101,-0.054364786], key:"plum3" },
+    crate::nearest::Node{ lab:[0.7282974,0.195155,-0.027445627], key:"hotpink" },
+    crate::nearest::Node{ lab:[0.8219236,0.014452491,-0.0012409706], key:"lavenderblush3" },
+    crate::nearest::Node{ lab:[0.8390999,0.0042482666,0.0013187922], key:"snow3" },
+    crate::nearest::Node{ lab:[0.89143133,7.216411e-11,3.322713e-08], key:"gray86" },
+    crate::nearest::Node{ lab:[0.8332929,0.036385518,-0.024576474], key:"thistle" },
+    crate::nearest::Node{ lab:[0.97015077,7.8536455e-11,3.616131e-08], key:"grey96" },
+    crate::nearest::Node{ lab:[0.93099916,7.5367046e-11,3.4701976e-08], key:"grey91" },
+    crate::nearest::Node{ lab:[0.91583145,7.4139195e-11,3.413662e-08], key:"gray89" },
+    crate::nearest::Node{ lab:[0.90060073,7.290624e-11,3.356891e-08], key:"grey87" },
+    crate::nearest::Node{ lab:[0.90060073,7.290624e-11,3.356891e-08], key:"gray87" },
+    crate::nearest::Node{ lab:[0.8944904,7.2411466e-11,3.3341152e-08], key:"gainsboro" },
+    crate::nearest::Node{ lab:[0.9067007,7.340012e-11,3.3796276e-08], key:"grey88" },
+    crate::nearest::Node{ lab:[0.9067007,7.340012e-11,3.3796276e-08], key:"gray88" },
+    crate::nearest::Node{ lab:[0.921906,7.463086e-11,3.436304e-08], key:"grey90" },
+    crate::nearest::Node{ lab:[0.921906,7.463086e-11,3.436304e-08], key:"gray90" },
+    crate::nearest::Node{ lab:[0.91583145,7.4139195e-11,3.413662e-08], key:"grey89" },
+    crate::nearest::Node{ lab:[0.93099916,7.5367046e-11,3.4701976e-08], key:"gray91" },
+    crate::nearest::Node{ lab:[0.95514005,7.732098e-11,3.56018e-08], key:"gray94" },
+    crate::nearest::Node{ lab:[0.9461053,7.659001e-11,3.5265042e-08], key:"gray93" },
+    crate::nearest::Node{ lab:[0.9400701,7.6101125e-11,3.5040088e-08], key:"grey92" },
+    crate::nearest::Node{ lab:[0.9400701,7.6101125e-11,3.5040088e-08], key:"gray92" },
+    crate::nearest::Node{ lab:[0.9461053,7.659001e-11,3.5265042e-08], key:"grey93" },
+    crate::nearest::Node{ lab:[0.96115136,7.780798e-11,3.5825867e-08], key:"grey95" },
+    crate::nearest::Node{ lab:[0.96115136,7.780798e-11,3.5825867e-08], key:"gray95" },
+    crate::nearest::Node{ lab:[0.95514005,7.732098e-11,3.56018e-08], key:"grey94" },
+    crate::nearest::Node{ lab:[0.97015077,7.8536455e-11,3.616131e-08], key:"gray96" },
+    crate::nearest::Node{ lab:[0.9761388,7.902107e-11,3.6384506e-08], key:"grey97" },
+    crate::nearest::Node{ lab:[0.96833485,0.017342905,-0.0014863464], key:"lavenderblush" },
+    crate::nearest::Node{ lab:[0.8953218,0.04002898,-0.02702832], key:"thistle2" },
+    crate::nearest::Node{ lab:[0.91927123,0.01652253,-0.001836036], key:"lavenderblush2" },
+    crate::nearest::Node{ lab:[0.9309023,0.0073649315,-0.025915211], key:"lavender" },
+    crate::nearest::Node{ lab:[0.94311273,0.0423346,-0.028583469], key:"thistle1" },
+    crate::nearest::Node{ lab:[0.98111874,0.0025899603,-0.008898664], key:"ghostwhite" },
+    crate::nearest::Node{ lab:[0.9761388,7.902107e-11,3.6384506e-08], key:"gray97" },
+    crate::nearest::Node{ lab:[0.97015077,7.8536455e-11,3.616131e-08], key:"whitesmoke" },
+    crate::nearest::Node{ lab:[0.96833485,0.017342905,-0.0014863464], key:"lavenderblush1" },
+    crate::nearest::Node{ lab:[0.9910689,8.02296e-11,3.694101e-08], key:"gray99" },
+    crate::nearest::Node{ lab:[0.9889369,0.005096968,0.0015824084], key:"snow" },
+    crate::nearest::Node{ lab:[0.98510367,7.9746876e-11,3.6718664e-08], key:"grey98" },
+    crate::nearest::Node{ lab:[0.98510367,7.9746876e-11,3.6718664e-08], key:"gray98" },
+    crate::nearest::Node{ lab:[0.9889369,0.005096968,0.0015824084], key:"snow1" },
+    crate::nearest::Node{ lab:[1.0,8.0952856e-11,3.7273907e-08], key:"grey100" },
+    crate::nearest::Node{ lab:[1.0,8.0952856e-11,3.7273907e-08], key:"gray100" },
+    crate::nearest::Node{ lab:[0.9910689,8.02296e-11,3.694101e-08], key:"grey99" },
+    crate::nearest::Node{ lab:[1.0,8.0952856e-11,3.7273907e-08], key:"white" },
+    crate::nearest::Node{ lab:[0.80433047,0.08287363,0.006932123], key:"pink2" },
+    crate::nearest::Node{ lab:[0.70727897,0.078754276,0.0141663635], key:"lightpink3" },
+    crate::nearest::Node{ lab:[0.7565688,0.065416105,0.12213488], key:"tan2" },
+    crate::nearest::Node{ lab:[0.78399736,0.06401243,0.10958705], key:"sandybrown" },
+    crate::nearest::Node{ lab:[0.7735085,0.022764863,0.044489972], key:"peachpuff3" },
+    crate::nearest::Node{ lab:[0.75850093,0.020813059,0.06963525], key:"burlywood3" },
+    crate::nearest::Node{ lab:[0.77930444,0.012437764,0.060252823], key:"navajowhite3" },
+    crate::nearest::Node{ lab:[0.73639446,0.056879513,0.019155286], key:"rosybrown3" },
+    crate::nearest::Node{ lab:[0.7188266,0.074101746,0.006261731], key:"pink3" },
+    crate::nearest::Node{ lab:[0.78618664,0.016927637,0.061535213], key:"tan" },
+    crate::nearest::Node{ lab:[0.79578996,0.006169395,0.052771825], key:"wheat3" },
+    crate::nearest::Node{ lab:[0.7915711,0.014045617,0.0401459], key:"bisque3" },
+    crate::nearest::Node{ lab:[0.7975154,0.02326429,0.010318194], key:"mistyrose3" },
+    crate::nearest::Node{ lab:[0.788146,0.025265712,0.15874428], key:"darkgoldenrod2" },
+    crate::nearest::Node{ lab:[0.7054299,0.021949882,0.14178589], key:"darkgoldenrod3" },
+    crate::nearest::Node{ lab:[0.7515723,0.015389061,0.14612558], key:"goldenrod" },
+    crate::nearest::Node{ lab:[0.71773446,0.0145856915,0.13970761], key:"goldenrod3" },
+    crate::nearest::Node{ lab:[0.7529151,0.053253636,0.15329263], key:"orange2" },
+    crate::nearest::Node{ lab:[0.7926884,0.0566112,0.16138455], key:"orange1" },
+    crate::nearest::Node{ lab:[0.7926884,0.0566112,0.16138455], key:"orange" },
+    crate::nearest::Node{ lab:[0.8020601,0.016810458,0.15661752], key:"goldenrod2" },
+    crate::nearest::Node{ lab:[0.7965569,0.06947922,0.12840745], key:"tan1" },
+    crate::nearest::Node{ lab:[0.793755,0.09214708,0.08421498], key:"lightsalmon1" },
+    crate::nearest::Node{ lab:[0.75073624,0.08360267,0.0686568], key:"darksalmon" },
+    crate::nearest::Node{ lab:[0.719611,0.14869082,0.0023766248], key:"palevioletred2" },
+    crate::nearest::Node{ lab:[0.73500234,0.13369869,0.071269915], key:"salmon" },
+    crate::nearest::Node{ lab:[0.72464097,0.12856539,0.049425825], key:"lightcoral" },
+    crate::nearest::Node{ lab:[0.71015275,0.16803843,0.071007974], key:"indianred1" },
+    crate::nearest::Node{ lab:[0.793755,0.09214708,0.08421498], key:"lightsalmon" },
+    crate::nearest::Node{ lab:[0.7534667,0.087692365,0.079255685], key:"lightsalmon2" },
+    crate::nearest::Node{ lab:[0.7890729,0.08980463,0.015589232], key:"lightpink2" },
+    crate::nearest::Node{ lab:[0.7581307,0.1569345,0.0019803778], key:"palevioletred1" },
+    crate::nearest::Node{ lab:[0.7314336,0.117006354,0.1385842], key:"chocolate1" },
+    crate::nearest::Node{ lab:[0.7011981,0.11471035,0.11143313], key:"sienna2" },
+    crate::nearest::Node{ lab:[0.7303931,0.1133142,0.14803626], key:"darkorange1" },
+    crate::nearest::Node{ lab:[0.7194409,0.111774914,0.08521936], key:"salmon2" },
+    crate::nearest::Node{ lab:[0.7174506,0.1493888,0.096676536], key:"coral1" },
+    crate::nearest::Node{ lab:[0.73858446,0.12090257,0.11768213], key:"sienna1" },
+    crate::nearest::Node{ lab:[0.75054425,0.094165646,0.15236416], key:"darkorange" },
+    crate::nearest::Node{ lab:[0.7583671,0.11712107,0.09054666], key:"salmon1" },
+    crate::nearest::Node{ lab:[0.73511285,0.12822507,0.10853774], key:"coral" },
+    crate::nearest::Node{ lab:[0.91640216,0.015881488,0.07125776], key:"navajowhite1" },
+    crate::nearest::Node{ lab:[0.9595379,0.008950289,0.030285917], key:"antiquewhite1" },
+    crate::nearest::Node{ lab:[0.96023846,0.006529202,0.015857963], key:"linen" },
+    crate::nearest::Node{ lab:[0.81413007,0.0076777157,0.025317756], key:"antiquewhite3" },
+    crate::nearest::Node{ lab:[0.927058,0.0066934703,0.011860541], key:"seashell2" },
+    crate::nearest::Node{ lab:[0.8282182,0.0062085157,0.010553264], key:"seashell3" },
+    crate::nearest::Node{ lab:[0.9466915,0.007930012,0.03005429], key:"antiquewhite" },
+    crate::nearest::Node{ lab:[0.9106255,0.0084033925,0.02907968], key:"antiquewhite2" },
+    crate::nearest::Node{ lab:[0.97601813,0.007638632,0.012033104], key:"seashell" },
+    crate::nearest::Node{ lab:[0.9723414,0.002528669,0.021410508], key:"oldlace" },
+    crate::nearest::Node{ lab:[0.98623276,0.0013425166,0.014156463], key:"floralwhite" },
+    crate::nearest::Node{ lab:[0.97601813,0.007638632,0.012033104], key:"seashell1" },
+    crate::nearest::Node{ lab:[0.91640216,0.015881488,0.07125776], key:"navajowhite" },
+    crate::nearest::Node{ lab:[0.8860274,0.014879805,0.046317797], key:"bisque2" },
+    crate::nearest::Node{ lab:[0.9088334,0.0074598812,0.061044525], key:"wheat" },
+    crate::nearest::Node{ lab:[0.8899226,0.0068216873,0.0597799], key:"wheat2" },
+    crate::nearest::Node{ lab:[0.8695717,0.015130016,0.06777667], key:"navajowhite2" },
+    crate::nearest::Node{ lab:[0.94843936,0.009296485,0.043962296], key:"blanchedalmond" },
+    crate::nearest::Node{ lab:[0.93648344,0.007675813,0.06336308], key:"wheat1" },
+    crate::nearest::Node{ lab:[0.95807993,0.006622367,0.037681177], key:"papayawhip" },
+    crate::nearest::Node{ lab:[0.9296221,0.010123965,0.066774465], key:"moccasin" },
+    crate::nearest::Node{ lab:[0.9328562,0.016026122,0.048885465], key:"bisque" },
+    crate::nearest::Node{ lab:[0.8668558,0.067730226,0.022833867], key:"rosybrown1" },
+    crate::nearest::Node{ lab:[0.8467503,0.087720804,0.0074791647], key:"pink1" },
+    crate::nearest::Node{ lab:[0.82292056,0.064187296,0.021635972], key:"rosybrown2" },
+    crate::nearest::Node{ lab:[0.8473878,0.08471583,0.013548875], key:"lightpink" },
+    crate::nearest::Node{ lab:[0.83142525,0.09419538,0.017330954], key:"lightpink1" },
+    crate::nearest::Node{ lab:[0.9400117,0.027195733,0.012844467], key:"mistyrose1" },
+    crate::nearest::Node{ lab:[0.9400117,0.027195733,0.012844467], key:"mistyrose" },
+    crate::nearest::Node{ lab:[0.8927757,0.025417171,0.012327614], key:"mistyrose2" },
+    crate::nearest::Node{ lab:[0.8677384,0.07298037,0.009071449], key:"pink" },
+    crate::nearest::Node{ lab:[0.86436486,0.025975686,0.05006125], key:"peachpuff2" },
+    crate::nearest::Node{ lab:[0.8470451,0.024028432,0.0784312], key:"burlywood2" },
+    crate::nearest::Node{ lab:[0.84488195,0.017809005,0.16505587], key:"goldenrod1" },
+    crate::nearest::Node{ lab:[0.8045399,0.022220576,0.07461971], key:"burlywood" },
+    crate::nearest::Node{ lab:[0.8292328,0.027475648,0.16715148], key:"darkgoldenrod1" },
+    crate::nearest::Node{ lab:[0.91125286,0.026585272,0.053788204], key:"peachpuff" },
+    crate::nearest::Node{ lab:[0.891874,0.025389407,0.08327556], key:"burlywood1" },
+    crate::nearest::Node{ lab:[0.9328562,0.016026122,0.048885465], key:"bisque1" },
+    crate::nearest::Node{ lab:[0.91125286,0.026585272,0.053788204], key:"peachpuff1" },
+];
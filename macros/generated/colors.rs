@@ -0,0 +1,1057 @@
+//  ***  AUTO-GENERATED  – DO NOT EDIT BY HAND  ***
+
+/// Colors from Wikipedia's "List of colors" pages.
+pub static COLORS: phf::Map<&'static str, crate::Color> = phf::phf_map! {
+    "absolutezero" => crate::Color{ name:std::borrow::Cow::Borrowed("Absolute Zero"), hex:std::borrow::Cow::Borrowed("#0048BA"), rgb:(0,72,186), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "acidgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Acid green"), hex:std::borrow::Cow::Borrowed("#B0BF1A"), rgb:(176,191,26), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "aero" => crate::Color{ name:std::borrow::Cow::Borrowed("Aero"), hex:std::borrow::Cow::Borrowed("#7CB9E8"), rgb:(124,185,232), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "africanviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("African violet"), hex:std::borrow::Cow::Borrowed("#B284BE"), rgb:(178,132,190), aliases:&["africanvoilet"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "airsuperiorityblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Air superiority blue"), hex:std::borrow::Cow::Borrowed("#72A0C1"), rgb:(114,160,193), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "aliceblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Alice blue"), hex:std::borrow::Cow::Borrowed("#F0F8FF"), rgb:(240,248,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "alizarin" => crate::Color{ name:std::borrow::Cow::Borrowed("Alizarin"), hex:std::borrow::Cow::Borrowed("#DB2D43"), rgb:(219,45,67), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "alloyorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Alloy orange"), hex:std::borrow::Cow::Borrowed("#C46210"), rgb:(196,98,16), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "almond" => crate::Color{ name:std::borrow::Cow::Borrowed("Almond"), hex:std::borrow::Cow::Borrowed("#EED9C4"), rgb:(238,217,196), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "amaranthdeeppurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Amaranth deep purple"), hex:std::borrow::Cow::Borrowed("#9F2B68"), rgb:(159,43,104), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "amaranthpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Amaranth pink"), hex:std::borrow::Cow::Borrowed("#F19CBB"), rgb:(241,156,187), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "amaranthpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Amaranth purple"), hex:std::borrow::Cow::Borrowed("#AB274F"), rgb:(171,39,79), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "amazon" => crate::Color{ name:std::borrow::Cow::Borrowed("Amazon"), hex:std::borrow::Cow::Borrowed("#3B7A57"), rgb:(59,122,87), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "amber" => crate::Color{ name:std::borrow::Cow::Borrowed("Amber"), hex:std::borrow::Cow::Borrowed("#FFBF00"), rgb:(255,191,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "amethyst" => crate::Color{ name:std::borrow::Cow::Borrowed("Amethyst"), hex:std::borrow::Cow::Borrowed("#9966CC"), rgb:(153,102,204), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "androidgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Android green"), hex:std::borrow::Cow::Borrowed("#3DDC84"), rgb:(61,220,132), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "antiquebrass" => crate::Color{ name:std::borrow::Cow::Borrowed("Antique brass"), hex:std::borrow::Cow::Borrowed("#C88A65"), rgb:(200,138,101), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "antiquebronze" => crate::Color{ name:std::borrow::Cow::Borrowed("Antique bronze"), hex:std::borrow::Cow::Borrowed("#665D1E"), rgb:(102,93,30), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "antiquefuchsia" => crate::Color{ name:std::borrow::Cow::Borrowed("Antique fuchsia"), hex:std::borrow::Cow::Borrowed("#915C83"), rgb:(145,92,131), aliases:&["antiquefuschia", "antiquefushia"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "antiqueruby" => crate::Color{ name:std::borrow::Cow::Borrowed("Antique ruby"), hex:std::borrow::Cow::Borrowed("#841B2D"), rgb:(132,27,45), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "antiquewhite" => crate::Color{ name:std::borrow::Cow::Borrowed("Antique white"), hex:std::borrow::Cow::Borrowed("#FAEBD7"), rgb:(250,235,215), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "apricot" => crate::Color{ name:std::borrow::Cow::Borrowed("Apricot"), hex:std::borrow::Cow::Borrowed("#FBCEB1"), rgb:(251,206,177), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "aqua" => crate::Color{ name:std::borrow::Cow::Borrowed("Aqua"), hex:std::borrow::Cow::Borrowed("#00FFFF"), rgb:(0,255,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "aquamarine" => crate::Color{ name:std::borrow::Cow::Borrowed("Aquamarine"), hex:std::borrow::Cow::Borrowed("#7FFFD4"), rgb:(127,255,212), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "arcticlime" => crate::Color{ name:std::borrow::Cow::Borrowed("Arctic lime"), hex:std::borrow::Cow::Borrowed("#D0FF14"), rgb:(208,255,20), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "artichokegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Artichoke green"), hex:std::borrow::Cow::Borrowed("#4B6F44"), rgb:(75,111,68), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "arylideyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Arylide yellow"), hex:std::borrow::Cow::Borrowed("#E9D66B"), rgb:(233,214,107), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "ashgray" => crate::Color{ name:std::borrow::Cow::Borrowed("Ash gray"), hex:std::borrow::Cow::Borrowed("#B2BEB5"), rgb:(178,190,181), aliases:&["ashgrey"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "atomictangerine" => crate::Color{ name:std::borrow::Cow::Borrowed("Atomic tangerine"), hex:std::borrow::Cow::Borrowed("#FF9966"), rgb:(255,153,102), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "aureolin" => crate::Color{ name:std::borrow::Cow::Borrowed("Aureolin"), hex:std::borrow::Cow::Borrowed("#FDEE00"), rgb:(253,238,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "azure" => crate::Color{ name:std::borrow::Cow::Borrowed("Azure"), hex:std::borrow::Cow::Borrowed("#007FFF"), rgb:(0,127,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "azurex11webcolor" => crate::Color{ name:std::borrow::Cow::Borrowed("Azure (X11/web color)"), hex:std::borrow::Cow::Borrowed("#F0FFFF"), rgb:(240,255,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("X11/web color") } },
+    "babyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Baby blue"), hex:std::borrow::Cow::Borrowed("#89CFF0"), rgb:(137,207,240), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "babyblueeyes" => crate::Color{ name:std::borrow::Cow::Borrowed("Baby blue eyes"), hex:std::borrow::Cow::Borrowed("#A1CAF1"), rgb:(161,202,241), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "babypink" => crate::Color{ name:std::borrow::Cow::Borrowed("Baby pink"), hex:std::borrow::Cow::Borrowed("#F4C2C2"), rgb:(244,194,194), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "babypowder" => crate::Color{ name:std::borrow::Cow::Borrowed("Baby powder"), hex:std::borrow::Cow::Borrowed("#FEFEFA"), rgb:(254,254,250), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bakermillerpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Baker-Miller pink"), hex:std::borrow::Cow::Borrowed("#FF91AF"), rgb:(255,145,175), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bananamania" => crate::Color{ name:std::borrow::Cow::Borrowed("Banana Mania"), hex:std::borrow::Cow::Borrowed("#FAE7B5"), rgb:(250,231,181), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "barbiepink" => crate::Color{ name:std::borrow::Cow::Borrowed("Barbie Pink"), hex:std::borrow::Cow::Borrowed("#DA1884"), rgb:(218,24,132), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "barnred" => crate::Color{ name:std::borrow::Cow::Borrowed("Barn red"), hex:std::borrow::Cow::Borrowed("#7C0A02"), rgb:(124,10,2), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "battleshipgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("Battleship grey"), hex:std::borrow::Cow::Borrowed("#848482"), rgb:(132,132,130), aliases:&["battleshipgray"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "beaublue" => crate::Color{ name:std::borrow::Cow::Borrowed("Beau blue"), hex:std::borrow::Cow::Borrowed("#BCD4E6"), rgb:(188,212,230), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "beaver" => crate::Color{ name:std::borrow::Cow::Borrowed("Beaver"), hex:std::borrow::Cow::Borrowed("#9F8170"), rgb:(159,129,112), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "beige" => crate::Color{ name:std::borrow::Cow::Borrowed("Beige"), hex:std::borrow::Cow::Borrowed("#F5F5DC"), rgb:(245,245,220), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bdazzledblue" => crate::Color{ name:std::borrow::Cow::Borrowed("B'dazzled blue"), hex:std::borrow::Cow::Borrowed("#2E5894"), rgb:(46,88,148), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bigdiporuby" => crate::Color{ name:std::borrow::Cow::Borrowed("Big dip o’ruby"), hex:std::borrow::Cow::Borrowed("#9C2542"), rgb:(156,37,66), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bisque" => crate::Color{ name:std::borrow::Cow::Borrowed("Bisque"), hex:std::borrow::Cow::Borrowed("#FFE4C4"), rgb:(255,228,196), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bistre" => crate::Color{ name:std::borrow::Cow::Borrowed("Bistre"), hex:std::borrow::Cow::Borrowed("#3D2B1F"), rgb:(61,43,31), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bistrebrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Bistre brown"), hex:std::borrow::Cow::Borrowed("#967117"), rgb:(150,113,23), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bitterlemon" => crate::Color{ name:std::borrow::Cow::Borrowed("Bitter lemon"), hex:std::borrow::Cow::Borrowed("#CAE00D"), rgb:(202,224,13), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "black" => crate::Color{ name:std::borrow::Cow::Borrowed("Black"), hex:std::borrow::Cow::Borrowed("#000000"), rgb:(0,0,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blackbean" => crate::Color{ name:std::borrow::Cow::Borrowed("Black bean"), hex:std::borrow::Cow::Borrowed("#3D0C02"), rgb:(61,12,2), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blackcoral" => crate::Color{ name:std::borrow::Cow::Borrowed("Black coral"), hex:std::borrow::Cow::Borrowed("#54626F"), rgb:(84,98,111), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blackolive" => crate::Color{ name:std::borrow::Cow::Borrowed("Black olive"), hex:std::borrow::Cow::Borrowed("#3B3C36"), rgb:(59,60,54), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blackshadows" => crate::Color{ name:std::borrow::Cow::Borrowed("Black Shadows"), hex:std::borrow::Cow::Borrowed("#BFAFB2"), rgb:(191,175,178), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blanchedalmond" => crate::Color{ name:std::borrow::Cow::Borrowed("Blanched almond"), hex:std::borrow::Cow::Borrowed("#FFEBCD"), rgb:(255,235,205), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blastoffbronze" => crate::Color{ name:std::borrow::Cow::Borrowed("Blast-off bronze"), hex:std::borrow::Cow::Borrowed("#A57164"), rgb:(165,113,100), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bleudefrance" => crate::Color{ name:std::borrow::Cow::Borrowed("Bleu de France"), hex:std::borrow::Cow::Borrowed("#318CE7"), rgb:(49,140,231), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blizzardblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Blizzard blue"), hex:std::borrow::Cow::Borrowed("#ACE5EE"), rgb:(172,229,238), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bloodred" => crate::Color{ name:std::borrow::Cow::Borrowed("Blood red"), hex:std::borrow::Cow::Borrowed("#660000"), rgb:(102,0,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blue" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue"), hex:std::borrow::Cow::Borrowed("#0000FF"), rgb:(0,0,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bluecrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue (Crayola)"), hex:std::borrow::Cow::Borrowed("#1F75FE"), rgb:(31,117,254), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("Crayola") } },
+    "bluemunsell" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue (Munsell)"), hex:std::borrow::Cow::Borrowed("#0093AF"), rgb:(0,147,175), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("Munsell") } },
+    "bluencs" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue (NCS)"), hex:std::borrow::Cow::Borrowed("#0087BD"), rgb:(0,135,189), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("NCS") } },
+    "bluepantone" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue (Pantone)"), hex:std::borrow::Cow::Borrowed("#0018A8"), rgb:(0,24,168), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("Pantone") } },
+    "bluepigment" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue (pigment)"), hex:std::borrow::Cow::Borrowed("#333399"), rgb:(51,51,153), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("pigment") } },
+    "bluebell" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue bell"), hex:std::borrow::Cow::Borrowed("#A2A2D0"), rgb:(162,162,208), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bluegraycrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue-gray(Crayola)"), hex:std::borrow::Cow::Borrowed("#6699CC"), rgb:(102,153,204), aliases:&["bluegreycrayola"], source:crate::Source{ list:"List of colors: A–F", variant:Some("Crayola") } },
+    "bluejeans" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue jeans"), hex:std::borrow::Cow::Borrowed("#5DADEC"), rgb:(93,173,236), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bluesapphire" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue sapphire"), hex:std::borrow::Cow::Borrowed("#126180"), rgb:(18,97,128), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blueviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue-violet"), hex:std::borrow::Cow::Borrowed("#8A2BE2"), rgb:(138,43,226), aliases:&["bluevoilet"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blueyonder" => crate::Color{ name:std::borrow::Cow::Borrowed("Blue yonder"), hex:std::borrow::Cow::Borrowed("#5072A7"), rgb:(80,114,167), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bluetiful" => crate::Color{ name:std::borrow::Cow::Borrowed("Bluetiful"), hex:std::borrow::Cow::Borrowed("#3C69E7"), rgb:(60,105,231), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "blush" => crate::Color{ name:std::borrow::Cow::Borrowed("Blush"), hex:std::borrow::Cow::Borrowed("#DE5D83"), rgb:(222,93,131), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bole" => crate::Color{ name:std::borrow::Cow::Borrowed("Bole"), hex:std::borrow::Cow::Borrowed("#79443B"), rgb:(121,68,59), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bone" => crate::Color{ name:std::borrow::Cow::Borrowed("Bone"), hex:std::borrow::Cow::Borrowed("#E3DAC9"), rgb:(227,218,201), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "brickred" => crate::Color{ name:std::borrow::Cow::Borrowed("Brick red"), hex:std::borrow::Cow::Borrowed("#CB4154"), rgb:(203,65,84), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "brightlilac" => crate::Color{ name:std::borrow::Cow::Borrowed("Bright lilac"), hex:std::borrow::Cow::Borrowed("#D891EF"), rgb:(216,145,239), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "brightyellowcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Bright yellow (Crayola)"), hex:std::borrow::Cow::Borrowed("#FFAA1D"), rgb:(255,170,29), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("Crayola") } },
+    "britishracinggreen" => crate::Color{ name:std::borrow::Cow::Borrowed("British racing green"), hex:std::borrow::Cow::Borrowed("#004225"), rgb:(0,66,37), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "bronze" => crate::Color{ name:std::borrow::Cow::Borrowed("Bronze"), hex:std::borrow::Cow::Borrowed("#CD7F32"), rgb:(205,127,50), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "brown" => crate::Color{ name:std::borrow::Cow::Borrowed("Brown"), hex:std::borrow::Cow::Borrowed("#964B00"), rgb:(150,75,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "brownsugar" => crate::Color{ name:std::borrow::Cow::Borrowed("Brown sugar"), hex:std::borrow::Cow::Borrowed("#AF6E4D"), rgb:(175,110,77), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "budgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Bud green"), hex:std::borrow::Cow::Borrowed("#7BB661"), rgb:(123,182,97), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "buff" => crate::Color{ name:std::borrow::Cow::Borrowed("Buff"), hex:std::borrow::Cow::Borrowed("#FFC680"), rgb:(255,198,128), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "burgundy" => crate::Color{ name:std::borrow::Cow::Borrowed("Burgundy"), hex:std::borrow::Cow::Borrowed("#800020"), rgb:(128,0,32), aliases:&["burgandy"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "burlywood" => crate::Color{ name:std::borrow::Cow::Borrowed("Burlywood"), hex:std::borrow::Cow::Borrowed("#DEB887"), rgb:(222,184,135), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "burnishedbrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Burnished brown"), hex:std::borrow::Cow::Borrowed("#A17A74"), rgb:(161,122,116), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "burntorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Burnt orange"), hex:std::borrow::Cow::Borrowed("#CC5500"), rgb:(204,85,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "burntsienna" => crate::Color{ name:std::borrow::Cow::Borrowed("Burnt sienna"), hex:std::borrow::Cow::Borrowed("#E97451"), rgb:(233,116,81), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "burntumber" => crate::Color{ name:std::borrow::Cow::Borrowed("Burnt umber"), hex:std::borrow::Cow::Borrowed("#8A3324"), rgb:(138,51,36), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "byzantine" => crate::Color{ name:std::borrow::Cow::Borrowed("Byzantine"), hex:std::borrow::Cow::Borrowed("#BD33A4"), rgb:(189,51,164), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "byzantium" => crate::Color{ name:std::borrow::Cow::Borrowed("Byzantium"), hex:std::borrow::Cow::Borrowed("#702963"), rgb:(112,41,99), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cadetblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Cadet blue"), hex:std::borrow::Cow::Borrowed("#5F9EA0"), rgb:(95,158,160), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cadetgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("Cadet grey"), hex:std::borrow::Cow::Borrowed("#91A3B0"), rgb:(145,163,176), aliases:&["cadetgray"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cadmiumgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Cadmium green"), hex:std::borrow::Cow::Borrowed("#006B3C"), rgb:(0,107,60), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cadmiumorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Cadmium orange"), hex:std::borrow::Cow::Borrowed("#ED872D"), rgb:(237,135,45), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cafeaulait" => crate::Color{ name:std::borrow::Cow::Borrowed("Café au lait"), hex:std::borrow::Cow::Borrowed("#A67B5B"), rgb:(166,123,91), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cafenoir" => crate::Color{ name:std::borrow::Cow::Borrowed("Café noir"), hex:std::borrow::Cow::Borrowed("#4B3621"), rgb:(75,54,33), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cambridgeblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Cambridge blue"), hex:std::borrow::Cow::Borrowed("#A3C1AD"), rgb:(163,193,173), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "camel" => crate::Color{ name:std::borrow::Cow::Borrowed("Camel"), hex:std::borrow::Cow::Borrowed("#C19A6B"), rgb:(193,154,107), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cameopink" => crate::Color{ name:std::borrow::Cow::Borrowed("Cameo pink"), hex:std::borrow::Cow::Borrowed("#EFBBCC"), rgb:(239,187,204), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "canary" => crate::Color{ name:std::borrow::Cow::Borrowed("Canary"), hex:std::borrow::Cow::Borrowed("#FFFF99"), rgb:(255,255,153), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "canaryyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Canary yellow"), hex:std::borrow::Cow::Borrowed("#FFEF00"), rgb:(255,239,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "candypink" => crate::Color{ name:std::borrow::Cow::Borrowed("Candy pink"), hex:std::borrow::Cow::Borrowed("#E4717A"), rgb:(228,113,122), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cardinal" => crate::Color{ name:std::borrow::Cow::Borrowed("Cardinal"), hex:std::borrow::Cow::Borrowed("#C41E3A"), rgb:(196,30,58), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "caribbeangreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Caribbean green"), hex:std::borrow::Cow::Borrowed("#00CC99"), rgb:(0,204,153), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "carmine" => crate::Color{ name:std::borrow::Cow::Borrowed("Carmine"), hex:std::borrow::Cow::Borrowed("#960018"), rgb:(150,0,24), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "carminemp" => crate::Color{ name:std::borrow::Cow::Borrowed("Carmine (M&P)"), hex:std::borrow::Cow::Borrowed("#D70040"), rgb:(215,0,64), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("M&P") } },
+    "carnationpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Carnation pink"), hex:std::borrow::Cow::Borrowed("#FFA6C9"), rgb:(255,166,201), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "carnelian" => crate::Color{ name:std::borrow::Cow::Borrowed("Carnelian"), hex:std::borrow::Cow::Borrowed("#B31B1B"), rgb:(179,27,27), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "carolinablue" => crate::Color{ name:std::borrow::Cow::Borrowed("Carolina blue"), hex:std::borrow::Cow::Borrowed("#56A0D3"), rgb:(86,160,211), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "carrotorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Carrot orange"), hex:std::borrow::Cow::Borrowed("#ED9121"), rgb:(237,145,33), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "catawba" => crate::Color{ name:std::borrow::Cow::Borrowed("Catawba"), hex:std::borrow::Cow::Borrowed("#703642"), rgb:(112,54,66), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cedarchest" => crate::Color{ name:std::borrow::Cow::Borrowed("Cedar Chest"), hex:std::borrow::Cow::Borrowed("#C95A49"), rgb:(201,90,73), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "celadon" => crate::Color{ name:std::borrow::Cow::Borrowed("Celadon"), hex:std::borrow::Cow::Borrowed("#ACE1AF"), rgb:(172,225,175), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "celeste" => crate::Color{ name:std::borrow::Cow::Borrowed("Celeste"), hex:std::borrow::Cow::Borrowed("#B2FFFF"), rgb:(178,255,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cerise" => crate::Color{ name:std::borrow::Cow::Borrowed("Cerise"), hex:std::borrow::Cow::Borrowed("#DE3163"), rgb:(222,49,99), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cerulean" => crate::Color{ name:std::borrow::Cow::Borrowed("Cerulean"), hex:std::borrow::Cow::Borrowed("#007BA7"), rgb:(0,123,167), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "ceruleanblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Cerulean blue"), hex:std::borrow::Cow::Borrowed("#2A52BE"), rgb:(42,82,190), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "ceruleanfrost" => crate::Color{ name:std::borrow::Cow::Borrowed("Cerulean frost"), hex:std::borrow::Cow::Borrowed("#6D9BC3"), rgb:(109,155,195), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "ceruleancrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Cerulean (Crayola)"), hex:std::borrow::Cow::Borrowed("#1DACD6"), rgb:(29,172,214), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("Crayola") } },
+    "ceruleanrgb" => crate::Color{ name:std::borrow::Cow::Borrowed("Cerulean (RGB)"), hex:std::borrow::Cow::Borrowed("#0040FF"), rgb:(0,64,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("RGB") } },
+    "champagne" => crate::Color{ name:std::borrow::Cow::Borrowed("Champagne"), hex:std::borrow::Cow::Borrowed("#F7E7CE"), rgb:(247,231,206), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "champagnepink" => crate::Color{ name:std::borrow::Cow::Borrowed("Champagne pink"), hex:std::borrow::Cow::Borrowed("#F1DDCF"), rgb:(241,221,207), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "charcoal" => crate::Color{ name:std::borrow::Cow::Borrowed("Charcoal"), hex:std::borrow::Cow::Borrowed("#36454F"), rgb:(54,69,79), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "charmpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Charm pink"), hex:std::borrow::Cow::Borrowed("#E68FAC"), rgb:(230,143,172), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "chartreuseweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Chartreuse (web)"), hex:std::borrow::Cow::Borrowed("#80FF00"), rgb:(128,255,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("web") } },
+    "cherryblossompink" => crate::Color{ name:std::borrow::Cow::Borrowed("Cherry blossom pink"), hex:std::borrow::Cow::Borrowed("#FFB7C5"), rgb:(255,183,197), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "chestnut" => crate::Color{ name:std::borrow::Cow::Borrowed("Chestnut"), hex:std::borrow::Cow::Borrowed("#954535"), rgb:(149,69,53), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "chilired" => crate::Color{ name:std::borrow::Cow::Borrowed("Chili red"), hex:std::borrow::Cow::Borrowed("#E23D28"), rgb:(226,61,40), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "chinapink" => crate::Color{ name:std::borrow::Cow::Borrowed("China pink"), hex:std::borrow::Cow::Borrowed("#DE6FA1"), rgb:(222,111,161), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "chinesered" => crate::Color{ name:std::borrow::Cow::Borrowed("Chinese red"), hex:std::borrow::Cow::Borrowed("#AA381E"), rgb:(170,56,30), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "chineseviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("Chinese violet"), hex:std::borrow::Cow::Borrowed("#856088"), rgb:(133,96,136), aliases:&["chinesevoilet"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "chineseyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Chinese yellow"), hex:std::borrow::Cow::Borrowed("#FFB200"), rgb:(255,178,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "chocolatetraditional" => crate::Color{ name:std::borrow::Cow::Borrowed("Chocolate (traditional)"), hex:std::borrow::Cow::Borrowed("#7B3F00"), rgb:(123,63,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("traditional") } },
+    "chocolateweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Chocolate (web)"), hex:std::borrow::Cow::Borrowed("#D2691E"), rgb:(210,105,30), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("web") } },
+    "cinereous" => crate::Color{ name:std::borrow::Cow::Borrowed("Cinereous"), hex:std::borrow::Cow::Borrowed("#98817B"), rgb:(152,129,123), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cinnabar" => crate::Color{ name:std::borrow::Cow::Borrowed("Cinnabar"), hex:std::borrow::Cow::Borrowed("#E34234"), rgb:(227,66,52), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cinnamonsatin" => crate::Color{ name:std::borrow::Cow::Borrowed("Cinnamon Satin"), hex:std::borrow::Cow::Borrowed("#CD607E"), rgb:(205,96,126), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "citrine" => crate::Color{ name:std::borrow::Cow::Borrowed("Citrine"), hex:std::borrow::Cow::Borrowed("#E4D00A"), rgb:(228,208,10), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "citron" => crate::Color{ name:std::borrow::Cow::Borrowed("Citron"), hex:std::borrow::Cow::Borrowed("#9FA91F"), rgb:(159,169,31), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "claret" => crate::Color{ name:std::borrow::Cow::Borrowed("Claret"), hex:std::borrow::Cow::Borrowed("#7F1734"), rgb:(127,23,52), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "coffee" => crate::Color{ name:std::borrow::Cow::Borrowed("Coffee"), hex:std::borrow::Cow::Borrowed("#6F4E37"), rgb:(111,78,55), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "columbiablue" => crate::Color{ name:std::borrow::Cow::Borrowed("Columbia Blue"), hex:std::borrow::Cow::Borrowed("#B9D9EB"), rgb:(185,217,235), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "congopink" => crate::Color{ name:std::borrow::Cow::Borrowed("Congo pink"), hex:std::borrow::Cow::Borrowed("#F88379"), rgb:(248,131,121), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "coolgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("Cool grey"), hex:std::borrow::Cow::Borrowed("#8C92AC"), rgb:(140,146,172), aliases:&["coolgray"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "copper" => crate::Color{ name:std::borrow::Cow::Borrowed("Copper"), hex:std::borrow::Cow::Borrowed("#B87333"), rgb:(184,115,51), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "coppercrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Copper (Crayola)"), hex:std::borrow::Cow::Borrowed("#DA8A67"), rgb:(218,138,103), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("Crayola") } },
+    "copperpenny" => crate::Color{ name:std::borrow::Cow::Borrowed("Copper penny"), hex:std::borrow::Cow::Borrowed("#AD6F69"), rgb:(173,111,105), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "copperred" => crate::Color{ name:std::borrow::Cow::Borrowed("Copper red"), hex:std::borrow::Cow::Borrowed("#CB6D51"), rgb:(203,109,81), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "copperrose" => crate::Color{ name:std::borrow::Cow::Borrowed("Copper rose"), hex:std::borrow::Cow::Borrowed("#996666"), rgb:(153,102,102), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "coquelicot" => crate::Color{ name:std::borrow::Cow::Borrowed("Coquelicot"), hex:std::borrow::Cow::Borrowed("#FF3800"), rgb:(255,56,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "coral" => crate::Color{ name:std::borrow::Cow::Borrowed("Coral"), hex:std::borrow::Cow::Borrowed("#FF7F50"), rgb:(255,127,80), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "coralpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Coral pink"), hex:std::borrow::Cow::Borrowed("#F88379"), rgb:(248,131,121), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cordovan" => crate::Color{ name:std::borrow::Cow::Borrowed("Cordovan"), hex:std::borrow::Cow::Borrowed("#893F45"), rgb:(137,63,69), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "corn" => crate::Color{ name:std::borrow::Cow::Borrowed("Corn"), hex:std::borrow::Cow::Borrowed("#FBEC5D"), rgb:(251,236,93), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cornflowerblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Cornflower blue"), hex:std::borrow::Cow::Borrowed("#6495ED"), rgb:(100,149,237), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cornsilk" => crate::Color{ name:std::borrow::Cow::Borrowed("Cornsilk"), hex:std::borrow::Cow::Borrowed("#FFF8DC"), rgb:(255,248,220), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cosmiccobalt" => crate::Color{ name:std::borrow::Cow::Borrowed("Cosmic cobalt"), hex:std::borrow::Cow::Borrowed("#2E2D88"), rgb:(46,45,136), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cosmiclatte" => crate::Color{ name:std::borrow::Cow::Borrowed("Cosmic latte"), hex:std::borrow::Cow::Borrowed("#FFF8E7"), rgb:(255,248,231), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "coyotebrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Coyote brown"), hex:std::borrow::Cow::Borrowed("#81613C"), rgb:(129,97,60), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cottoncandy" => crate::Color{ name:std::borrow::Cow::Borrowed("Cotton candy"), hex:std::borrow::Cow::Borrowed("#FFBCD9"), rgb:(255,188,217), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cream" => crate::Color{ name:std::borrow::Cow::Borrowed("Cream"), hex:std::borrow::Cow::Borrowed("#FFFDD0"), rgb:(255,253,208), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "crimson" => crate::Color{ name:std::borrow::Cow::Borrowed("Crimson"), hex:std::borrow::Cow::Borrowed("#DC143C"), rgb:(220,20,60), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "crimsonua" => crate::Color{ name:std::borrow::Cow::Borrowed("Crimson (UA)"), hex:std::borrow::Cow::Borrowed("#9E1B32"), rgb:(158,27,50), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("UA") } },
+    "culturedpearl" => crate::Color{ name:std::borrow::Cow::Borrowed("Cultured Pearl"), hex:std::borrow::Cow::Borrowed("#F5F5F5"), rgb:(245,245,245), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cyan" => crate::Color{ name:std::borrow::Cow::Borrowed("Cyan"), hex:std::borrow::Cow::Borrowed("#00FFFF"), rgb:(0,255,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cyanprocess" => crate::Color{ name:std::borrow::Cow::Borrowed("Cyan (process)"), hex:std::borrow::Cow::Borrowed("#00B7EB"), rgb:(0,183,235), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("process") } },
+    "cybergrape" => crate::Color{ name:std::borrow::Cow::Borrowed("Cyber grape"), hex:std::borrow::Cow::Borrowed("#58427C"), rgb:(88,66,124), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cyberyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Cyber yellow"), hex:std::borrow::Cow::Borrowed("#FFD300"), rgb:(255,211,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "cyclamen" => crate::Color{ name:std::borrow::Cow::Borrowed("Cyclamen"), hex:std::borrow::Cow::Borrowed("#F56FA1"), rgb:(245,111,161), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "dandelion" => crate::Color{ name:std::borrow::Cow::Borrowed("Dandelion"), hex:std::borrow::Cow::Borrowed("#FED85D"), rgb:(254,216,93), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkbrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark brown"), hex:std::borrow::Cow::Borrowed("#654321"), rgb:(101,67,33), aliases:&["dkbrown"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkbyzantium" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark byzantium"), hex:std::borrow::Cow::Borrowed("#5D3954"), rgb:(93,57,84), aliases:&["dkbyzantium"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkcyan" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark cyan"), hex:std::borrow::Cow::Borrowed("#008B8B"), rgb:(0,139,139), aliases:&["dkcyan"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkelectricblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark electric blue"), hex:std::borrow::Cow::Borrowed("#536878"), rgb:(83,104,120), aliases:&["dkelectricblue"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkgoldenrod" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark goldenrod"), hex:std::borrow::Cow::Borrowed("#B8860B"), rgb:(184,134,11), aliases:&["dkgoldenrod"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkgreenx11" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark green (X11)"), hex:std::borrow::Cow::Borrowed("#006400"), rgb:(0,100,0), aliases:&["dkgreenx11"], source:crate::Source{ list:"List of colors: A–F", variant:Some("X11") } },
+    "darkjunglegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark jungle green"), hex:std::borrow::Cow::Borrowed("#1A2421"), rgb:(26,36,33), aliases:&["dkjunglegreen"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkkhaki" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark khaki"), hex:std::borrow::Cow::Borrowed("#BDB76B"), rgb:(189,183,107), aliases:&["dkkhaki"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darklava" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark lava"), hex:std::borrow::Cow::Borrowed("#483C32"), rgb:(72,60,50), aliases:&["dklava"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkliverhorses" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark liver (horses)"), hex:std::borrow::Cow::Borrowed("#543D37"), rgb:(84,61,55), aliases:&["dkliverhorses"], source:crate::Source{ list:"List of colors: A–F", variant:Some("horses") } },
+    "darkmagenta" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark magenta"), hex:std::borrow::Cow::Borrowed("#8B008B"), rgb:(139,0,139), aliases:&["darkmegenta", "dkmagenta", "dkmegenta"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkolivegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark olive green"), hex:std::borrow::Cow::Borrowed("#556B2F"), rgb:(85,107,47), aliases:&["dkolivegreen"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark orange"), hex:std::borrow::Cow::Borrowed("#FF8C00"), rgb:(255,140,0), aliases:&["dkorange"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkorchid" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark orchid"), hex:std::borrow::Cow::Borrowed("#9932CC"), rgb:(153,50,204), aliases:&["dkorchid"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark purple"), hex:std::borrow::Cow::Borrowed("#301934"), rgb:(48,25,52), aliases:&["dkpurple"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkred" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark red"), hex:std::borrow::Cow::Borrowed("#8B0000"), rgb:(139,0,0), aliases:&["dkred"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darksalmon" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark salmon"), hex:std::borrow::Cow::Borrowed("#E9967A"), rgb:(233,150,122), aliases:&["dksalmon"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkseagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark sea green"), hex:std::borrow::Cow::Borrowed("#8FBC8F"), rgb:(143,188,143), aliases:&["dkseagreen"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darksienna" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark sienna"), hex:std::borrow::Cow::Borrowed("#3C1414"), rgb:(60,20,20), aliases:&["dksienna"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark sky blue"), hex:std::borrow::Cow::Borrowed("#8CBED6"), rgb:(140,190,214), aliases:&["dkskyblue"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkslateblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark slate blue"), hex:std::borrow::Cow::Borrowed("#483D8B"), rgb:(72,61,139), aliases:&["dkslateblue"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkslategray" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark slate gray"), hex:std::borrow::Cow::Borrowed("#2F4F4F"), rgb:(47,79,79), aliases:&["darkslategrey", "dkslategray", "dkslategrey"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkspringgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark spring green"), hex:std::borrow::Cow::Borrowed("#177245"), rgb:(23,114,69), aliases:&["dkspringgreen"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkturquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark turquoise"), hex:std::borrow::Cow::Borrowed("#00CED1"), rgb:(0,206,209), aliases:&["darkturqoise", "darkturquise", "dkturquoise", "dkturqoise", "dkturquise"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "darkviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("Dark violet"), hex:std::borrow::Cow::Borrowed("#9400D3"), rgb:(148,0,211), aliases:&["darkvoilet", "dkviolet", "dkvoilet"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "davysgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("Davy's grey"), hex:std::borrow::Cow::Borrowed("#555555"), rgb:(85,85,85), aliases:&["davysgray"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "deepcerise" => crate::Color{ name:std::borrow::Cow::Borrowed("Deep cerise"), hex:std::borrow::Cow::Borrowed("#DA3287"), rgb:(218,50,135), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "deepchampagne" => crate::Color{ name:std::borrow::Cow::Borrowed("Deep champagne"), hex:std::borrow::Cow::Borrowed("#FAD6A5"), rgb:(250,214,165), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "deepchestnut" => crate::Color{ name:std::borrow::Cow::Borrowed("Deep chestnut"), hex:std::borrow::Cow::Borrowed("#B94E48"), rgb:(185,78,72), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "deepjunglegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Deep jungle green"), hex:std::borrow::Cow::Borrowed("#004B49"), rgb:(0,75,73), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "deeppink" => crate::Color{ name:std::borrow::Cow::Borrowed("Deep pink"), hex:std::borrow::Cow::Borrowed("#FF1493"), rgb:(255,20,147), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "deepsaffron" => crate::Color{ name:std::borrow::Cow::Borrowed("Deep saffron"), hex:std::borrow::Cow::Borrowed("#FF9933"), rgb:(255,153,51), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "deepskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Deep sky blue"), hex:std::borrow::Cow::Borrowed("#00BFFF"), rgb:(0,191,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "deepspacesparkle" => crate::Color{ name:std::borrow::Cow::Borrowed("Deep Space Sparkle"), hex:std::borrow::Cow::Borrowed("#4A646C"), rgb:(74,100,108), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "deeptaupe" => crate::Color{ name:std::borrow::Cow::Borrowed("Deep taupe"), hex:std::borrow::Cow::Borrowed("#7E5E60"), rgb:(126,94,96), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "denim" => crate::Color{ name:std::borrow::Cow::Borrowed("Denim"), hex:std::borrow::Cow::Borrowed("#1560BD"), rgb:(21,96,189), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "denimblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Denim blue"), hex:std::borrow::Cow::Borrowed("#2243B6"), rgb:(34,67,182), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "desert" => crate::Color{ name:std::borrow::Cow::Borrowed("Desert"), hex:std::borrow::Cow::Borrowed("#C19A6B"), rgb:(193,154,107), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "desertsand" => crate::Color{ name:std::borrow::Cow::Borrowed("Desert sand"), hex:std::borrow::Cow::Borrowed("#EDC9AF"), rgb:(237,201,175), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "dimgray" => crate::Color{ name:std::borrow::Cow::Borrowed("Dim gray"), hex:std::borrow::Cow::Borrowed("#696969"), rgb:(105,105,105), aliases:&["dimgrey"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "dodgerblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Dodger blue"), hex:std::borrow::Cow::Borrowed("#1E90FF"), rgb:(30,144,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "drabdarkbrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Drab dark brown"), hex:std::borrow::Cow::Borrowed("#4A412A"), rgb:(74,65,42), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "dukeblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Duke blue"), hex:std::borrow::Cow::Borrowed("#00009C"), rgb:(0,0,156), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "dutchwhite" => crate::Color{ name:std::borrow::Cow::Borrowed("Dutch white"), hex:std::borrow::Cow::Borrowed("#EFDFBB"), rgb:(239,223,187), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "ebony" => crate::Color{ name:std::borrow::Cow::Borrowed("Ebony"), hex:std::borrow::Cow::Borrowed("#555D50"), rgb:(85,93,80), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "ecru" => crate::Color{ name:std::borrow::Cow::Borrowed("Ecru"), hex:std::borrow::Cow::Borrowed("#C2B280"), rgb:(194,178,128), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "eerieblack" => crate::Color{ name:std::borrow::Cow::Borrowed("Eerie black"), hex:std::borrow::Cow::Borrowed("#1B1B1B"), rgb:(27,27,27), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "eggplant" => crate::Color{ name:std::borrow::Cow::Borrowed("Eggplant"), hex:std::borrow::Cow::Borrowed("#614051"), rgb:(97,64,81), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "eggshell" => crate::Color{ name:std::borrow::Cow::Borrowed("Eggshell"), hex:std::borrow::Cow::Borrowed("#F0EAD6"), rgb:(240,234,214), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "electriclime" => crate::Color{ name:std::borrow::Cow::Borrowed("Electric lime"), hex:std::borrow::Cow::Borrowed("#CCFF00"), rgb:(204,255,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "electricpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Electric purple"), hex:std::borrow::Cow::Borrowed("#BF00FF"), rgb:(191,0,255), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "electricviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("Electric violet"), hex:std::borrow::Cow::Borrowed("#8F00FF"), rgb:(143,0,255), aliases:&["electricvoilet"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "emerald" => crate::Color{ name:std::borrow::Cow::Borrowed("Emerald"), hex:std::borrow::Cow::Borrowed("#50C878"), rgb:(80,200,120), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "eminence" => crate::Color{ name:std::borrow::Cow::Borrowed("Eminence"), hex:std::borrow::Cow::Borrowed("#6C3082"), rgb:(108,48,130), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "englishlavender" => crate::Color{ name:std::borrow::Cow::Borrowed("English lavender"), hex:std::borrow::Cow::Borrowed("#B48395"), rgb:(180,131,149), aliases:&["englishlavendar"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "englishred" => crate::Color{ name:std::borrow::Cow::Borrowed("English red"), hex:std::borrow::Cow::Borrowed("#AB4B52"), rgb:(171,75,82), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "englishvermillion" => crate::Color{ name:std::borrow::Cow::Borrowed("English vermillion"), hex:std::borrow::Cow::Borrowed("#CC474B"), rgb:(204,71,75), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "englishviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("English violet"), hex:std::borrow::Cow::Borrowed("#563C5C"), rgb:(86,60,92), aliases:&["englishvoilet"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "erin" => crate::Color{ name:std::borrow::Cow::Borrowed("Erin"), hex:std::borrow::Cow::Borrowed("#00FF40"), rgb:(0,255,64), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "etonblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Eton blue"), hex:std::borrow::Cow::Borrowed("#96C8A2"), rgb:(150,200,162), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "fallow" => crate::Color{ name:std::borrow::Cow::Borrowed("Fallow"), hex:std::borrow::Cow::Borrowed("#C19A6B"), rgb:(193,154,107), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "falured" => crate::Color{ name:std::borrow::Cow::Borrowed("Falu red"), hex:std::borrow::Cow::Borrowed("#801818"), rgb:(128,24,24), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "fandango" => crate::Color{ name:std::borrow::Cow::Borrowed("Fandango"), hex:std::borrow::Cow::Borrowed("#B53389"), rgb:(181,51,137), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "fandangopink" => crate::Color{ name:std::borrow::Cow::Borrowed("Fandango pink"), hex:std::borrow::Cow::Borrowed("#DE5285"), rgb:(222,82,133), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "fawn" => crate::Color{ name:std::borrow::Cow::Borrowed("Fawn"), hex:std::borrow::Cow::Borrowed("#E5AA70"), rgb:(229,170,112), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "ferngreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Fern green"), hex:std::borrow::Cow::Borrowed("#4F7942"), rgb:(79,121,66), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "fielddrab" => crate::Color{ name:std::borrow::Cow::Borrowed("Field drab"), hex:std::borrow::Cow::Borrowed("#6C541E"), rgb:(108,84,30), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "fieryrose" => crate::Color{ name:std::borrow::Cow::Borrowed("Fiery rose"), hex:std::borrow::Cow::Borrowed("#FF5470"), rgb:(255,84,112), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "finn" => crate::Color{ name:std::borrow::Cow::Borrowed("Finn"), hex:std::borrow::Cow::Borrowed("#683068"), rgb:(104,48,104), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "firebrick" => crate::Color{ name:std::borrow::Cow::Borrowed("Firebrick"), hex:std::borrow::Cow::Borrowed("#B22222"), rgb:(178,34,34), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "fireenginered" => crate::Color{ name:std::borrow::Cow::Borrowed("Fire engine red"), hex:std::borrow::Cow::Borrowed("#CE2029"), rgb:(206,32,41), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "flame" => crate::Color{ name:std::borrow::Cow::Borrowed("Flame"), hex:std::borrow::Cow::Borrowed("#E25822"), rgb:(226,88,34), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "flax" => crate::Color{ name:std::borrow::Cow::Borrowed("Flax"), hex:std::borrow::Cow::Borrowed("#EEDC82"), rgb:(238,220,130), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "flirt" => crate::Color{ name:std::borrow::Cow::Borrowed("Flirt"), hex:std::borrow::Cow::Borrowed("#A2006D"), rgb:(162,0,109), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "floralwhite" => crate::Color{ name:std::borrow::Cow::Borrowed("Floral white"), hex:std::borrow::Cow::Borrowed("#FFFAF0"), rgb:(255,250,240), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "forestgreenweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Forest green (web)"), hex:std::borrow::Cow::Borrowed("#228B22"), rgb:(34,139,34), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:Some("web") } },
+    "frenchbeige" => crate::Color{ name:std::borrow::Cow::Borrowed("French beige"), hex:std::borrow::Cow::Borrowed("#A67B5B"), rgb:(166,123,91), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frenchbistre" => crate::Color{ name:std::borrow::Cow::Borrowed("French bistre"), hex:std::borrow::Cow::Borrowed("#856D4D"), rgb:(133,109,77), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frenchblue" => crate::Color{ name:std::borrow::Cow::Borrowed("French blue"), hex:std::borrow::Cow::Borrowed("#0072BB"), rgb:(0,114,187), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frenchfuchsia" => crate::Color{ name:std::borrow::Cow::Borrowed("French fuchsia"), hex:std::borrow::Cow::Borrowed("#FD3F92"), rgb:(253,63,146), aliases:&["frenchfuschia", "frenchfushia"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frenchlilac" => crate::Color{ name:std::borrow::Cow::Borrowed("French lilac"), hex:std::borrow::Cow::Borrowed("#86608E"), rgb:(134,96,142), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frenchlime" => crate::Color{ name:std::borrow::Cow::Borrowed("French lime"), hex:std::borrow::Cow::Borrowed("#9EFD38"), rgb:(158,253,56), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frenchmauve" => crate::Color{ name:std::borrow::Cow::Borrowed("French mauve"), hex:std::borrow::Cow::Borrowed("#D473D4"), rgb:(212,115,212), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frenchpink" => crate::Color{ name:std::borrow::Cow::Borrowed("French pink"), hex:std::borrow::Cow::Borrowed("#FD6C9E"), rgb:(253,108,158), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frenchraspberry" => crate::Color{ name:std::borrow::Cow::Borrowed("French raspberry"), hex:std::borrow::Cow::Borrowed("#C72C48"), rgb:(199,44,72), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frenchskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("French sky blue"), hex:std::borrow::Cow::Borrowed("#77B5FE"), rgb:(119,181,254), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frenchviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("French violet"), hex:std::borrow::Cow::Borrowed("#8806CE"), rgb:(136,6,206), aliases:&["frenchvoilet"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "frostbite" => crate::Color{ name:std::borrow::Cow::Borrowed("Frostbite"), hex:std::borrow::Cow::Borrowed("#E936A7"), rgb:(233,54,167), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "fuchsia" => crate::Color{ name:std::borrow::Cow::Borrowed("Fuchsia"), hex:std::borrow::Cow::Borrowed("#FF00FF"), rgb:(255,0,255), aliases:&["fuschia", "fushia"], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "fuchsiacrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Fuchsia (Crayola)"), hex:std::borrow::Cow::Borrowed("#C154C1"), rgb:(193,84,193), aliases:&["fuschiacrayola", "fushiacrayola"], source:crate::Source{ list:"List of colors: A–F", variant:Some("Crayola") } },
+    "fulvous" => crate::Color{ name:std::borrow::Cow::Borrowed("Fulvous"), hex:std::borrow::Cow::Borrowed("#E48400"), rgb:(228,132,0), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "fuzzywuzzy" => crate::Color{ name:std::borrow::Cow::Borrowed("Fuzzy Wuzzy"), hex:std::borrow::Cow::Borrowed("#87421F"), rgb:(135,66,31), aliases:&[], source:crate::Source{ list:"List of colors: A–F", variant:None } },
+    "gainsboro" => crate::Color{ name:std::borrow::Cow::Borrowed("Gainsboro"), hex:std::borrow::Cow::Borrowed("#DCDCDC"), rgb:(220,220,220), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "gamboge" => crate::Color{ name:std::borrow::Cow::Borrowed("Gamboge"), hex:std::borrow::Cow::Borrowed("#E49B0F"), rgb:(228,155,15), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "genericviridian" => crate::Color{ name:std::borrow::Cow::Borrowed("Generic viridian"), hex:std::borrow::Cow::Borrowed("#007F66"), rgb:(0,127,102), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "ghostwhite" => crate::Color{ name:std::borrow::Cow::Borrowed("Ghost white"), hex:std::borrow::Cow::Borrowed("#F8F8FF"), rgb:(248,248,255), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "glaucous" => crate::Color{ name:std::borrow::Cow::Borrowed("Glaucous"), hex:std::borrow::Cow::Borrowed("#6082B6"), rgb:(96,130,182), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "glossygrape" => crate::Color{ name:std::borrow::Cow::Borrowed("Glossy grape"), hex:std::borrow::Cow::Borrowed("#AB92B3"), rgb:(171,146,179), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "gogreen" => crate::Color{ name:std::borrow::Cow::Borrowed("GO green"), hex:std::borrow::Cow::Borrowed("#00AB66"), rgb:(0,171,102), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "goldmetallic" => crate::Color{ name:std::borrow::Cow::Borrowed("Gold (metallic)"), hex:std::borrow::Cow::Borrowed("#D4AF37"), rgb:(212,175,55), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("metallic") } },
+    "goldwebgolden" => crate::Color{ name:std::borrow::Cow::Borrowed("Gold (web) (Golden)"), hex:std::borrow::Cow::Borrowed("#FFD700"), rgb:(255,215,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("web, Golden") } },
+    "goldcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Gold (Crayola)"), hex:std::borrow::Cow::Borrowed("#E6BE8A"), rgb:(230,190,138), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("Crayola") } },
+    "goldfusion" => crate::Color{ name:std::borrow::Cow::Borrowed("Gold Fusion"), hex:std::borrow::Cow::Borrowed("#85754E"), rgb:(133,117,78), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "goldenbrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Golden brown"), hex:std::borrow::Cow::Borrowed("#996515"), rgb:(153,101,21), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "goldenpoppy" => crate::Color{ name:std::borrow::Cow::Borrowed("Golden poppy"), hex:std::borrow::Cow::Borrowed("#FCC200"), rgb:(252,194,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "goldenyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Golden yellow"), hex:std::borrow::Cow::Borrowed("#FFDF00"), rgb:(255,223,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "goldenrod" => crate::Color{ name:std::borrow::Cow::Borrowed("Goldenrod"), hex:std::borrow::Cow::Borrowed("#DAA520"), rgb:(218,165,32), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "gothamgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Gotham green"), hex:std::borrow::Cow::Borrowed("#00573F"), rgb:(0,87,63), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "granitegray" => crate::Color{ name:std::borrow::Cow::Borrowed("Granite gray"), hex:std::borrow::Cow::Borrowed("#676767"), rgb:(103,103,103), aliases:&["granitegrey"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "grannysmithapple" => crate::Color{ name:std::borrow::Cow::Borrowed("Granny Smith apple"), hex:std::borrow::Cow::Borrowed("#A8E4A0"), rgb:(168,228,160), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "grayweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Gray (web)"), hex:std::borrow::Cow::Borrowed("#808080"), rgb:(128,128,128), aliases:&["greyweb"], source:crate::Source{ list:"List of colors: G–M", variant:Some("web") } },
+    "grayx11gray" => crate::Color{ name:std::borrow::Cow::Borrowed("Gray (X11 gray)"), hex:std::borrow::Cow::Borrowed("#BEBEBE"), rgb:(190,190,190), aliases:&["greyx11grey"], source:crate::Source{ list:"List of colors: G–M", variant:Some("X11 gray") } },
+    "green" => crate::Color{ name:std::borrow::Cow::Borrowed("Green"), hex:std::borrow::Cow::Borrowed("#00FF00"), rgb:(0,255,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "greencrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Green (Crayola)"), hex:std::borrow::Cow::Borrowed("#1CAC78"), rgb:(28,172,120), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("Crayola") } },
+    "greenweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Green (web)"), hex:std::borrow::Cow::Borrowed("#008000"), rgb:(0,128,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("web") } },
+    "greenmunsell" => crate::Color{ name:std::borrow::Cow::Borrowed("Green (Munsell)"), hex:std::borrow::Cow::Borrowed("#00A877"), rgb:(0,168,119), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("Munsell") } },
+    "greenncs" => crate::Color{ name:std::borrow::Cow::Borrowed("Green (NCS)"), hex:std::borrow::Cow::Borrowed("#009F6B"), rgb:(0,159,107), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("NCS") } },
+    "greenpantone" => crate::Color{ name:std::borrow::Cow::Borrowed("Green (Pantone)"), hex:std::borrow::Cow::Borrowed("#00AD43"), rgb:(0,173,67), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("Pantone") } },
+    "greenpigment" => crate::Color{ name:std::borrow::Cow::Borrowed("Green (pigment)"), hex:std::borrow::Cow::Borrowed("#00A550"), rgb:(0,165,80), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("pigment") } },
+    "greenblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Green-blue"), hex:std::borrow::Cow::Borrowed("#1164B4"), rgb:(17,100,180), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "greenlizard" => crate::Color{ name:std::borrow::Cow::Borrowed("Green Lizard"), hex:std::borrow::Cow::Borrowed("#A7F432"), rgb:(167,244,50), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "greensheen" => crate::Color{ name:std::borrow::Cow::Borrowed("Green Sheen"), hex:std::borrow::Cow::Borrowed("#6EAEA1"), rgb:(110,174,161), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "gunmetal" => crate::Color{ name:std::borrow::Cow::Borrowed("Gunmetal"), hex:std::borrow::Cow::Borrowed("#2A3439"), rgb:(42,52,57), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "hansayellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Hansa yellow"), hex:std::borrow::Cow::Borrowed("#E9D66B"), rgb:(233,214,107), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "harlequin" => crate::Color{ name:std::borrow::Cow::Borrowed("Harlequin"), hex:std::borrow::Cow::Borrowed("#3FFF00"), rgb:(63,255,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "harvestgold" => crate::Color{ name:std::borrow::Cow::Borrowed("Harvest gold"), hex:std::borrow::Cow::Borrowed("#DA9100"), rgb:(218,145,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "heatwave" => crate::Color{ name:std::borrow::Cow::Borrowed("Heat Wave"), hex:std::borrow::Cow::Borrowed("#FF7A00"), rgb:(255,122,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "heliotrope" => crate::Color{ name:std::borrow::Cow::Borrowed("Heliotrope"), hex:std::borrow::Cow::Borrowed("#DF73FF"), rgb:(223,115,255), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "heliotropegray" => crate::Color{ name:std::borrow::Cow::Borrowed("Heliotrope gray"), hex:std::borrow::Cow::Borrowed("#AA98A9"), rgb:(170,152,169), aliases:&["heliotropegrey"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "hollywoodcerise" => crate::Color{ name:std::borrow::Cow::Borrowed("Hollywood cerise"), hex:std::borrow::Cow::Borrowed("#F400A1"), rgb:(244,0,161), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "honolulublue" => crate::Color{ name:std::borrow::Cow::Borrowed("Honolulu blue"), hex:std::borrow::Cow::Borrowed("#006DB0"), rgb:(0,109,176), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "hookersgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Hooker's green"), hex:std::borrow::Cow::Borrowed("#49796B"), rgb:(73,121,107), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "hotmagentabrokenanchor" => crate::Color{ name:std::borrow::Cow::Borrowed("Hot magenta[broken anchor]"), hex:std::borrow::Cow::Borrowed("#FF1DCE"), rgb:(255,29,206), aliases:&["hotmegentabrokenanchor"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "hotpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Hot pink"), hex:std::borrow::Cow::Borrowed("#FF69B4"), rgb:(255,105,180), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "huntergreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Hunter green"), hex:std::borrow::Cow::Borrowed("#355E3B"), rgb:(53,94,59), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "iceberg" => crate::Color{ name:std::borrow::Cow::Borrowed("Iceberg"), hex:std::borrow::Cow::Borrowed("#71A6D2"), rgb:(113,166,210), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "illuminatingemerald" => crate::Color{ name:std::borrow::Cow::Borrowed("Illuminating emerald"), hex:std::borrow::Cow::Borrowed("#319177"), rgb:(49,145,119), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "imperialred" => crate::Color{ name:std::borrow::Cow::Borrowed("Imperial red"), hex:std::borrow::Cow::Borrowed("#ED2939"), rgb:(237,41,57), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "inchworm" => crate::Color{ name:std::borrow::Cow::Borrowed("Inchworm"), hex:std::borrow::Cow::Borrowed("#B2EC5D"), rgb:(178,236,93), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "independence" => crate::Color{ name:std::borrow::Cow::Borrowed("Independence"), hex:std::borrow::Cow::Borrowed("#4C516D"), rgb:(76,81,109), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "indiagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("India green"), hex:std::borrow::Cow::Borrowed("#138808"), rgb:(19,136,8), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "indianred" => crate::Color{ name:std::borrow::Cow::Borrowed("Indian red"), hex:std::borrow::Cow::Borrowed("#CD5C5C"), rgb:(205,92,92), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "indianyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Indian yellow"), hex:std::borrow::Cow::Borrowed("#E3A857"), rgb:(227,168,87), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "indigo" => crate::Color{ name:std::borrow::Cow::Borrowed("Indigo"), hex:std::borrow::Cow::Borrowed("#6A5DFF"), rgb:(106,93,255), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "indigodye" => crate::Color{ name:std::borrow::Cow::Borrowed("Indigo dye"), hex:std::borrow::Cow::Borrowed("#00416A"), rgb:(0,65,106), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "internationalkleinblue" => crate::Color{ name:std::borrow::Cow::Borrowed("International Klein Blue"), hex:std::borrow::Cow::Borrowed("#130A8F"), rgb:(19,10,143), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "internationalorangeengineering" => crate::Color{ name:std::borrow::Cow::Borrowed("International orange (engineering)"), hex:std::borrow::Cow::Borrowed("#BA160C"), rgb:(186,22,12), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("engineering") } },
+    "internationalorangegoldengatebridge" => crate::Color{ name:std::borrow::Cow::Borrowed("International orange (Golden Gate Bridge)"), hex:std::borrow::Cow::Borrowed("#C0362C"), rgb:(192,54,44), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("Golden Gate Bridge") } },
+    "irresistible" => crate::Color{ name:std::borrow::Cow::Borrowed("Irresistible"), hex:std::borrow::Cow::Borrowed("#B3446C"), rgb:(179,68,108), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "isabelline" => crate::Color{ name:std::borrow::Cow::Borrowed("Isabelline"), hex:std::borrow::Cow::Borrowed("#F4F0EC"), rgb:(244,240,236), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "italianskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Italian sky blue"), hex:std::borrow::Cow::Borrowed("#B2FFFF"), rgb:(178,255,255), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "ivory" => crate::Color{ name:std::borrow::Cow::Borrowed("Ivory"), hex:std::borrow::Cow::Borrowed("#FFFFF0"), rgb:(255,255,240), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "japanesecarmine" => crate::Color{ name:std::borrow::Cow::Borrowed("Japanese carmine"), hex:std::borrow::Cow::Borrowed("#9D2933"), rgb:(157,41,51), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "japaneseviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("Japanese violet"), hex:std::borrow::Cow::Borrowed("#5B3256"), rgb:(91,50,86), aliases:&["japanesevoilet"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "jasmine" => crate::Color{ name:std::borrow::Cow::Borrowed("Jasmine"), hex:std::borrow::Cow::Borrowed("#F8DE7E"), rgb:(248,222,126), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "jazzberryjam" => crate::Color{ name:std::borrow::Cow::Borrowed("Jazzberry jam"), hex:std::borrow::Cow::Borrowed("#A50B5E"), rgb:(165,11,94), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "jet" => crate::Color{ name:std::borrow::Cow::Borrowed("Jet"), hex:std::borrow::Cow::Borrowed("#343434"), rgb:(52,52,52), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "jonquil" => crate::Color{ name:std::borrow::Cow::Borrowed("Jonquil"), hex:std::borrow::Cow::Borrowed("#F4CA16"), rgb:(244,202,22), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "junebud" => crate::Color{ name:std::borrow::Cow::Borrowed("June bud"), hex:std::borrow::Cow::Borrowed("#BDDA57"), rgb:(189,218,87), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "junglegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Jungle green"), hex:std::borrow::Cow::Borrowed("#29AB87"), rgb:(41,171,135), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "kellygreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Kelly green"), hex:std::borrow::Cow::Borrowed("#4CBB17"), rgb:(76,187,23), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "keppel" => crate::Color{ name:std::borrow::Cow::Borrowed("Keppel"), hex:std::borrow::Cow::Borrowed("#3AB09E"), rgb:(58,176,158), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "keylime" => crate::Color{ name:std::borrow::Cow::Borrowed("Key lime"), hex:std::borrow::Cow::Borrowed("#E8F48C"), rgb:(232,244,140), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "khakiweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Khaki (web)"), hex:std::borrow::Cow::Borrowed("#C3B091"), rgb:(195,176,145), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("web") } },
+    "khakix11lightkhaki" => crate::Color{ name:std::borrow::Cow::Borrowed("Khaki (X11) (Light khaki)"), hex:std::borrow::Cow::Borrowed("#F0E68C"), rgb:(240,230,140), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("X11, Light khaki") } },
+    "kobe" => crate::Color{ name:std::borrow::Cow::Borrowed("Kobe"), hex:std::borrow::Cow::Borrowed("#882D17"), rgb:(136,45,23), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "kobi" => crate::Color{ name:std::borrow::Cow::Borrowed("Kobi"), hex:std::borrow::Cow::Borrowed("#E79FC4"), rgb:(231,159,196), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "kobicha" => crate::Color{ name:std::borrow::Cow::Borrowed("Kobicha"), hex:std::borrow::Cow::Borrowed("#6B4423"), rgb:(107,68,35), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "ksupurple" => crate::Color{ name:std::borrow::Cow::Borrowed("KSU purple"), hex:std::borrow::Cow::Borrowed("#512888"), rgb:(81,40,136), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "languidlavender" => crate::Color{ name:std::borrow::Cow::Borrowed("Languid lavender"), hex:std::borrow::Cow::Borrowed("#D6CADD"), rgb:(214,202,221), aliases:&["languidlavendar"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lapislazuli" => crate::Color{ name:std::borrow::Cow::Borrowed("Lapis lazuli"), hex:std::borrow::Cow::Borrowed("#26619C"), rgb:(38,97,156), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "laserlemon" => crate::Color{ name:std::borrow::Cow::Borrowed("Laser lemon"), hex:std::borrow::Cow::Borrowed("#FFFF66"), rgb:(255,255,102), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "laurelgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Laurel green"), hex:std::borrow::Cow::Borrowed("#A9BA9D"), rgb:(169,186,157), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lava" => crate::Color{ name:std::borrow::Cow::Borrowed("Lava"), hex:std::borrow::Cow::Borrowed("#CF1020"), rgb:(207,16,32), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lavenderfloral" => crate::Color{ name:std::borrow::Cow::Borrowed("Lavender (floral)"), hex:std::borrow::Cow::Borrowed("#B57EDC"), rgb:(181,126,220), aliases:&["lavendarfloral"], source:crate::Source{ list:"List of colors: G–M", variant:Some("floral") } },
+    "lavenderweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Lavender (web)"), hex:std::borrow::Cow::Borrowed("#E6E6FA"), rgb:(230,230,250), aliases:&["lavendarweb"], source:crate::Source{ list:"List of colors: G–M", variant:Some("web") } },
+    "lavenderblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Lavender blue"), hex:std::borrow::Cow::Borrowed("#CCCCFF"), rgb:(204,204,255), aliases:&["lavendarblue"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lavenderblush" => crate::Color{ name:std::borrow::Cow::Borrowed("Lavender blush"), hex:std::borrow::Cow::Borrowed("#FFF0F5"), rgb:(255,240,245), aliases:&["lavendarblush"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lavendergray" => crate::Color{ name:std::borrow::Cow::Borrowed("Lavender gray"), hex:std::borrow::Cow::Borrowed("#C4C3D0"), rgb:(196,195,208), aliases:&["lavendargray", "lavendergrey", "lavendargrey"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lawngreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Lawn green"), hex:std::borrow::Cow::Borrowed("#7CFC00"), rgb:(124,252,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lemon" => crate::Color{ name:std::borrow::Cow::Borrowed("Lemon"), hex:std::borrow::Cow::Borrowed("#FFF700"), rgb:(255,247,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lemonchiffon" => crate::Color{ name:std::borrow::Cow::Borrowed("Lemon chiffon"), hex:std::borrow::Cow::Borrowed("#FFFACD"), rgb:(255,250,205), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lemoncurry" => crate::Color{ name:std::borrow::Cow::Borrowed("Lemon curry"), hex:std::borrow::Cow::Borrowed("#CCA01D"), rgb:(204,160,29), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lemonglacier" => crate::Color{ name:std::borrow::Cow::Borrowed("Lemon glacier"), hex:std::borrow::Cow::Borrowed("#FDFF00"), rgb:(253,255,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lemonmeringue" => crate::Color{ name:std::borrow::Cow::Borrowed("Lemon meringue"), hex:std::borrow::Cow::Borrowed("#F6EABE"), rgb:(246,234,190), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lemonyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Lemon yellow"), hex:std::borrow::Cow::Borrowed("#FFF44F"), rgb:(255,244,79), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lemonyellowcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Lemon yellow (Crayola)"), hex:std::borrow::Cow::Borrowed("#FFFF9F"), rgb:(255,255,159), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("Crayola") } },
+    "liberty" => crate::Color{ name:std::borrow::Cow::Borrowed("Liberty"), hex:std::borrow::Cow::Borrowed("#545AA7"), rgb:(84,90,167), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Light blue"), hex:std::borrow::Cow::Borrowed("#ADD8E6"), rgb:(173,216,230), aliases:&["ltblue"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightcoral" => crate::Color{ name:std::borrow::Cow::Borrowed("Light coral"), hex:std::borrow::Cow::Borrowed("#F08080"), rgb:(240,128,128), aliases:&["ltcoral"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightcornflowerblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Light cornflower blue"), hex:std::borrow::Cow::Borrowed("#93CCEA"), rgb:(147,204,234), aliases:&["ltcornflowerblue"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightcyan" => crate::Color{ name:std::borrow::Cow::Borrowed("Light cyan"), hex:std::borrow::Cow::Borrowed("#E0FFFF"), rgb:(224,255,255), aliases:&["ltcyan"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightfrenchbeige" => crate::Color{ name:std::borrow::Cow::Borrowed("Light French beige"), hex:std::borrow::Cow::Borrowed("#C8AD7F"), rgb:(200,173,127), aliases:&["ltfrenchbeige"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightgoldenrodyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Light goldenrod yellow"), hex:std::borrow::Cow::Borrowed("#FAFAD2"), rgb:(250,250,210), aliases:&["ltgoldenrodyellow"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightgray" => crate::Color{ name:std::borrow::Cow::Borrowed("Light gray"), hex:std::borrow::Cow::Borrowed("#D3D3D3"), rgb:(211,211,211), aliases:&["lightgrey", "ltgray", "ltgrey"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Light green"), hex:std::borrow::Cow::Borrowed("#90EE90"), rgb:(144,238,144), aliases:&["ltgreen"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Light orange"), hex:std::borrow::Cow::Borrowed("#FED8B1"), rgb:(254,216,177), aliases:&["ltorange"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightperiwinkle" => crate::Color{ name:std::borrow::Cow::Borrowed("Light periwinkle"), hex:std::borrow::Cow::Borrowed("#C5CBE1"), rgb:(197,203,225), aliases:&["ltperiwinkle"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Light pink"), hex:std::borrow::Cow::Borrowed("#FFB6C1"), rgb:(255,182,193), aliases:&["ltpink"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Light purple"), hex:std::borrow::Cow::Borrowed("#D8BFD8"), rgb:(216,191,216), aliases:&["ltpurple"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightsalmon" => crate::Color{ name:std::borrow::Cow::Borrowed("Light salmon"), hex:std::borrow::Cow::Borrowed("#FFA07A"), rgb:(255,160,122), aliases:&["ltsalmon"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightseagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Light sea green"), hex:std::borrow::Cow::Borrowed("#20B2AA"), rgb:(32,178,170), aliases:&["ltseagreen"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Light sky blue"), hex:std::borrow::Cow::Borrowed("#87CEFA"), rgb:(135,206,250), aliases:&["ltskyblue"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightslategray" => crate::Color{ name:std::borrow::Cow::Borrowed("Light slate gray"), hex:std::borrow::Cow::Borrowed("#778899"), rgb:(119,136,153), aliases:&["lightslategrey", "ltslategray", "ltslategrey"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightsteelblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Light steel blue"), hex:std::borrow::Cow::Borrowed("#B0C4DE"), rgb:(176,196,222), aliases:&["ltsteelblue"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lightyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Light yellow"), hex:std::borrow::Cow::Borrowed("#FFFFE0"), rgb:(255,255,224), aliases:&["ltyellow"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lilac" => crate::Color{ name:std::borrow::Cow::Borrowed("Lilac"), hex:std::borrow::Cow::Borrowed("#C8A2C8"), rgb:(200,162,200), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lilacluster" => crate::Color{ name:std::borrow::Cow::Borrowed("Lilac Luster"), hex:std::borrow::Cow::Borrowed("#AE98AA"), rgb:(174,152,170), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "limecolorwheel" => crate::Color{ name:std::borrow::Cow::Borrowed("Lime (color wheel)"), hex:std::borrow::Cow::Borrowed("#BFFF00"), rgb:(191,255,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("color wheel") } },
+    "limewebx11green" => crate::Color{ name:std::borrow::Cow::Borrowed("Lime (web) (X11 green)"), hex:std::borrow::Cow::Borrowed("#00FF00"), rgb:(0,255,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("web, X11 green") } },
+    "limegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Lime green"), hex:std::borrow::Cow::Borrowed("#32CD32"), rgb:(50,205,50), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lincolngreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Lincoln green"), hex:std::borrow::Cow::Borrowed("#195905"), rgb:(25,89,5), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "linen" => crate::Color{ name:std::borrow::Cow::Borrowed("Linen"), hex:std::borrow::Cow::Borrowed("#FAF0E6"), rgb:(250,240,230), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "lion" => crate::Color{ name:std::borrow::Cow::Borrowed("Lion"), hex:std::borrow::Cow::Borrowed("#DECC9C"), rgb:(222,204,156), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "liseranpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Liseran purple"), hex:std::borrow::Cow::Borrowed("#DE6FA1"), rgb:(222,111,161), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "littleboyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Little boy blue"), hex:std::borrow::Cow::Borrowed("#6CA0DC"), rgb:(108,160,220), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "liver" => crate::Color{ name:std::borrow::Cow::Borrowed("Liver"), hex:std::borrow::Cow::Borrowed("#674C47"), rgb:(103,76,71), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "liverdogs" => crate::Color{ name:std::borrow::Cow::Borrowed("Liver (dogs)"), hex:std::borrow::Cow::Borrowed("#B86D29"), rgb:(184,109,41), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("dogs") } },
+    "liverorgan" => crate::Color{ name:std::borrow::Cow::Borrowed("Liver (organ)"), hex:std::borrow::Cow::Borrowed("#6C2E1F"), rgb:(108,46,31), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("organ") } },
+    "liverchestnut" => crate::Color{ name:std::borrow::Cow::Borrowed("Liver chestnut"), hex:std::borrow::Cow::Borrowed("#987456"), rgb:(152,116,86), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "livid" => crate::Color{ name:std::borrow::Cow::Borrowed("Livid"), hex:std::borrow::Cow::Borrowed("#6699CC"), rgb:(102,153,204), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "macaroniandcheese" => crate::Color{ name:std::borrow::Cow::Borrowed("Macaroni and Cheese"), hex:std::borrow::Cow::Borrowed("#FFBD88"), rgb:(255,189,136), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "madderlake" => crate::Color{ name:std::borrow::Cow::Borrowed("Madder Lake"), hex:std::borrow::Cow::Borrowed("#CC3336"), rgb:(204,51,54), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "magenta" => crate::Color{ name:std::borrow::Cow::Borrowed("Magenta"), hex:std::borrow::Cow::Borrowed("#FF00FF"), rgb:(255,0,255), aliases:&["megenta"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "magentacrayolabrokenanchor" => crate::Color{ name:std::borrow::Cow::Borrowed("Magenta (Crayola)[broken anchor]"), hex:std::borrow::Cow::Borrowed("#F653A6"), rgb:(246,83,166), aliases:&["megentacrayolabrokenanchor"], source:crate::Source{ list:"List of colors: G–M", variant:Some("Crayola") } },
+    "magentadye" => crate::Color{ name:std::borrow::Cow::Borrowed("Magenta (dye)"), hex:std::borrow::Cow::Borrowed("#CA1F7B"), rgb:(202,31,123), aliases:&["megentadye"], source:crate::Source{ list:"List of colors: G–M", variant:Some("dye") } },
+    "magentapantone" => crate::Color{ name:std::borrow::Cow::Borrowed("Magenta (Pantone)"), hex:std::borrow::Cow::Borrowed("#D0417E"), rgb:(208,65,126), aliases:&["megentapantone"], source:crate::Source{ list:"List of colors: G–M", variant:Some("Pantone") } },
+    "magentaprocess" => crate::Color{ name:std::borrow::Cow::Borrowed("Magenta (process)"), hex:std::borrow::Cow::Borrowed("#FF0090"), rgb:(255,0,144), aliases:&["megentaprocess"], source:crate::Source{ list:"List of colors: G–M", variant:Some("process") } },
+    "magentahaze" => crate::Color{ name:std::borrow::Cow::Borrowed("Magenta haze"), hex:std::borrow::Cow::Borrowed("#9F4576"), rgb:(159,69,118), aliases:&["megentahaze"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "magicmint" => crate::Color{ name:std::borrow::Cow::Borrowed("Magic mint"), hex:std::borrow::Cow::Borrowed("#AAF0D1"), rgb:(170,240,209), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "magnolia" => crate::Color{ name:std::borrow::Cow::Borrowed("Magnolia"), hex:std::borrow::Cow::Borrowed("#F2E8D7"), rgb:(242,232,215), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mahogany" => crate::Color{ name:std::borrow::Cow::Borrowed("Mahogany"), hex:std::borrow::Cow::Borrowed("#C04000"), rgb:(192,64,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maize" => crate::Color{ name:std::borrow::Cow::Borrowed("Maize"), hex:std::borrow::Cow::Borrowed("#FBEC5D"), rgb:(251,236,93), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maizecrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Maize (Crayola)"), hex:std::borrow::Cow::Borrowed("#F2C649"), rgb:(242,198,73), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("Crayola") } },
+    "majorelleblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Majorelle blue"), hex:std::borrow::Cow::Borrowed("#6050DC"), rgb:(96,80,220), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "malachite" => crate::Color{ name:std::borrow::Cow::Borrowed("Malachite"), hex:std::borrow::Cow::Borrowed("#0BDA51"), rgb:(11,218,81), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "manatee" => crate::Color{ name:std::borrow::Cow::Borrowed("Manatee"), hex:std::borrow::Cow::Borrowed("#979AAA"), rgb:(151,154,170), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mandarin" => crate::Color{ name:std::borrow::Cow::Borrowed("Mandarin"), hex:std::borrow::Cow::Borrowed("#F37A48"), rgb:(243,122,72), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mango" => crate::Color{ name:std::borrow::Cow::Borrowed("Mango"), hex:std::borrow::Cow::Borrowed("#FDBE02"), rgb:(253,190,2), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mangotango" => crate::Color{ name:std::borrow::Cow::Borrowed("Mango Tango"), hex:std::borrow::Cow::Borrowed("#FF8243"), rgb:(255,130,67), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mantis" => crate::Color{ name:std::borrow::Cow::Borrowed("Mantis"), hex:std::borrow::Cow::Borrowed("#74C365"), rgb:(116,195,101), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mardigras" => crate::Color{ name:std::borrow::Cow::Borrowed("Mardi Gras"), hex:std::borrow::Cow::Borrowed("#880085"), rgb:(136,0,133), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "marigold" => crate::Color{ name:std::borrow::Cow::Borrowed("Marigold"), hex:std::borrow::Cow::Borrowed("#EAA221"), rgb:(234,162,33), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "marooncrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Maroon (Crayola)"), hex:std::borrow::Cow::Borrowed("#C32148"), rgb:(195,33,72), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("Crayola") } },
+    "maroonweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Maroon (web)"), hex:std::borrow::Cow::Borrowed("#800000"), rgb:(128,0,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("web") } },
+    "maroonx11" => crate::Color{ name:std::borrow::Cow::Borrowed("Maroon (X11)"), hex:std::borrow::Cow::Borrowed("#B03060"), rgb:(176,48,96), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("X11") } },
+    "mauve" => crate::Color{ name:std::borrow::Cow::Borrowed("Mauve"), hex:std::borrow::Cow::Borrowed("#E0B0FF"), rgb:(224,176,255), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mauvetaupe" => crate::Color{ name:std::borrow::Cow::Borrowed("Mauve taupe"), hex:std::borrow::Cow::Borrowed("#915F6D"), rgb:(145,95,109), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mauvelous" => crate::Color{ name:std::borrow::Cow::Borrowed("Mauvelous"), hex:std::borrow::Cow::Borrowed("#EF98AA"), rgb:(239,152,170), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maximumblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Maximum blue"), hex:std::borrow::Cow::Borrowed("#47ABCC"), rgb:(71,171,204), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maximumbluegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Maximum blue green"), hex:std::borrow::Cow::Borrowed("#30BFBF"), rgb:(48,191,191), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maximumbluepurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Maximum blue purple"), hex:std::borrow::Cow::Borrowed("#ACACE6"), rgb:(172,172,230), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maximumgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Maximum green"), hex:std::borrow::Cow::Borrowed("#5E8C31"), rgb:(94,140,49), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maximumgreenyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Maximum green yellow"), hex:std::borrow::Cow::Borrowed("#D9E650"), rgb:(217,230,80), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maximumpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Maximum purple"), hex:std::borrow::Cow::Borrowed("#733380"), rgb:(115,51,128), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maximumred" => crate::Color{ name:std::borrow::Cow::Borrowed("Maximum red"), hex:std::borrow::Cow::Borrowed("#D92121"), rgb:(217,33,33), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maximumredpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Maximum red purple"), hex:std::borrow::Cow::Borrowed("#A63A79"), rgb:(166,58,121), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maximumyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Maximum yellow"), hex:std::borrow::Cow::Borrowed("#FAFA37"), rgb:(250,250,55), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maximumyellowred" => crate::Color{ name:std::borrow::Cow::Borrowed("Maximum yellow red"), hex:std::borrow::Cow::Borrowed("#F2BA49"), rgb:(242,186,73), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "maygreen" => crate::Color{ name:std::borrow::Cow::Borrowed("May green"), hex:std::borrow::Cow::Borrowed("#4C9141"), rgb:(76,145,65), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mayablue" => crate::Color{ name:std::borrow::Cow::Borrowed("Maya blue"), hex:std::borrow::Cow::Borrowed("#73C2FB"), rgb:(115,194,251), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumaquamarine" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium aquamarine"), hex:std::borrow::Cow::Borrowed("#66DDAA"), rgb:(102,221,170), aliases:&["medaquamarine"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium blue"), hex:std::borrow::Cow::Borrowed("#0000CD"), rgb:(0,0,205), aliases:&["medblue"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumcandyapplered" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium candy apple red"), hex:std::borrow::Cow::Borrowed("#E2062C"), rgb:(226,6,44), aliases:&["medcandyapplered"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumcarmine" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium carmine"), hex:std::borrow::Cow::Borrowed("#AF4035"), rgb:(175,64,53), aliases:&["medcarmine"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumchampagne" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium champagne"), hex:std::borrow::Cow::Borrowed("#F3E5AB"), rgb:(243,229,171), aliases:&["medchampagne"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumorchid" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium orchid"), hex:std::borrow::Cow::Borrowed("#BA55D3"), rgb:(186,85,211), aliases:&["medorchid"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium purple"), hex:std::borrow::Cow::Borrowed("#9370DB"), rgb:(147,112,219), aliases:&["medpurple"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumseagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium sea green"), hex:std::borrow::Cow::Borrowed("#3CB371"), rgb:(60,179,113), aliases:&["medseagreen"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumslateblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium slate blue"), hex:std::borrow::Cow::Borrowed("#7B68EE"), rgb:(123,104,238), aliases:&["medslateblue"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumspringgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium spring green"), hex:std::borrow::Cow::Borrowed("#00FA9A"), rgb:(0,250,154), aliases:&["medspringgreen"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumturquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium turquoise"), hex:std::borrow::Cow::Borrowed("#48D1CC"), rgb:(72,209,204), aliases:&["mediumturqoise", "mediumturquise", "medturquoise", "medturqoise", "medturquise"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mediumvioletred" => crate::Color{ name:std::borrow::Cow::Borrowed("Medium violet-red"), hex:std::borrow::Cow::Borrowed("#C71585"), rgb:(199,21,133), aliases:&["mediumvoiletred", "medvioletred", "medvoiletred"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mellowapricot" => crate::Color{ name:std::borrow::Cow::Borrowed("Mellow apricot"), hex:std::borrow::Cow::Borrowed("#F8B878"), rgb:(248,184,120), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mellowyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Mellow yellow"), hex:std::borrow::Cow::Borrowed("#F8DE7E"), rgb:(248,222,126), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "melon" => crate::Color{ name:std::borrow::Cow::Borrowed("Melon"), hex:std::borrow::Cow::Borrowed("#FEBAAD"), rgb:(254,186,173), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "metallicgold" => crate::Color{ name:std::borrow::Cow::Borrowed("Metallic gold"), hex:std::borrow::Cow::Borrowed("#D3AF37"), rgb:(211,175,55), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "metallicseaweed" => crate::Color{ name:std::borrow::Cow::Borrowed("Metallic Seaweed"), hex:std::borrow::Cow::Borrowed("#0A7E8C"), rgb:(10,126,140), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "metallicsunburst" => crate::Color{ name:std::borrow::Cow::Borrowed("Metallic Sunburst"), hex:std::borrow::Cow::Borrowed("#9C7C38"), rgb:(156,124,56), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mexicanpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Mexican pink"), hex:std::borrow::Cow::Borrowed("#E4007C"), rgb:(228,0,124), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middleblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle blue"), hex:std::borrow::Cow::Borrowed("#7ED4E6"), rgb:(126,212,230), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middlebluegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle blue green"), hex:std::borrow::Cow::Borrowed("#8DD9CC"), rgb:(141,217,204), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middlebluepurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle blue purple"), hex:std::borrow::Cow::Borrowed("#8B72BE"), rgb:(139,114,190), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middlegrey" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle grey"), hex:std::borrow::Cow::Borrowed("#8B8680"), rgb:(139,134,128), aliases:&["middlegray"], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middlegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle green"), hex:std::borrow::Cow::Borrowed("#4D8C57"), rgb:(77,140,87), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middlegreenyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle green yellow"), hex:std::borrow::Cow::Borrowed("#ACBF60"), rgb:(172,191,96), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middlepurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle purple"), hex:std::borrow::Cow::Borrowed("#D982B5"), rgb:(217,130,181), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middlered" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle red"), hex:std::borrow::Cow::Borrowed("#E58E73"), rgb:(229,142,115), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middleredpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle red purple"), hex:std::borrow::Cow::Borrowed("#A55353"), rgb:(165,83,83), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middleyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle yellow"), hex:std::borrow::Cow::Borrowed("#FFEB00"), rgb:(255,235,0), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "middleyellowred" => crate::Color{ name:std::borrow::Cow::Borrowed("Middle yellow red"), hex:std::borrow::Cow::Borrowed("#ECB176"), rgb:(236,177,118), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "midnight" => crate::Color{ name:std::borrow::Cow::Borrowed("Midnight"), hex:std::borrow::Cow::Borrowed("#702670"), rgb:(112,38,112), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "midnightblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Midnight blue"), hex:std::borrow::Cow::Borrowed("#191970"), rgb:(25,25,112), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "midnightgreeneaglegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Midnight green (eagle green)"), hex:std::borrow::Cow::Borrowed("#004953"), rgb:(0,73,83), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("eagle green") } },
+    "mikadoyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Mikado yellow"), hex:std::borrow::Cow::Borrowed("#FFC40C"), rgb:(255,196,12), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mimipink" => crate::Color{ name:std::borrow::Cow::Borrowed("Mimi pink"), hex:std::borrow::Cow::Borrowed("#FFDAE9"), rgb:(255,218,233), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mindaro" => crate::Color{ name:std::borrow::Cow::Borrowed("Mindaro"), hex:std::borrow::Cow::Borrowed("#E3F988"), rgb:(227,249,136), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "ming" => crate::Color{ name:std::borrow::Cow::Borrowed("Ming"), hex:std::borrow::Cow::Borrowed("#36747D"), rgb:(54,116,125), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "minionyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Minion yellow"), hex:std::borrow::Cow::Borrowed("#F5E050"), rgb:(245,224,80), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mint" => crate::Color{ name:std::borrow::Cow::Borrowed("Mint"), hex:std::borrow::Cow::Borrowed("#3EB489"), rgb:(62,180,137), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mintcream" => crate::Color{ name:std::borrow::Cow::Borrowed("Mint cream"), hex:std::borrow::Cow::Borrowed("#F5FFFA"), rgb:(245,255,250), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mintgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Mint green"), hex:std::borrow::Cow::Borrowed("#98FF98"), rgb:(152,255,152), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mistymoss" => crate::Color{ name:std::borrow::Cow::Borrowed("Misty moss"), hex:std::borrow::Cow::Borrowed("#BBB477"), rgb:(187,180,119), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mistyrose" => crate::Color{ name:std::borrow::Cow::Borrowed("Misty rose"), hex:std::borrow::Cow::Borrowed("#FFE4E1"), rgb:(255,228,225), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "moccasin" => crate::Color{ name:std::borrow::Cow::Borrowed("Moccasin"), hex:std::borrow::Cow::Borrowed("#FFE4B5"), rgb:(255,228,181), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "modebeige" => crate::Color{ name:std::borrow::Cow::Borrowed("Mode beige"), hex:std::borrow::Cow::Borrowed("#967117"), rgb:(150,113,23), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "monalisa" => crate::Color{ name:std::borrow::Cow::Borrowed("Mona Lisa"), hex:std::borrow::Cow::Borrowed("#FF948E"), rgb:(255,148,142), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "morningblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Morning blue"), hex:std::borrow::Cow::Borrowed("#8DA399"), rgb:(141,163,153), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mossgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Moss green"), hex:std::borrow::Cow::Borrowed("#8A9A5B"), rgb:(138,154,91), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mountainmeadow" => crate::Color{ name:std::borrow::Cow::Borrowed("Mountain Meadow"), hex:std::borrow::Cow::Borrowed("#30BA8F"), rgb:(48,186,143), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mountbattenpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Mountbatten pink"), hex:std::borrow::Cow::Borrowed("#997A8D"), rgb:(153,122,141), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "msugreen" => crate::Color{ name:std::borrow::Cow::Borrowed("MSU green"), hex:std::borrow::Cow::Borrowed("#18453B"), rgb:(24,69,59), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mulberry" => crate::Color{ name:std::borrow::Cow::Borrowed("Mulberry"), hex:std::borrow::Cow::Borrowed("#C54B8C"), rgb:(197,75,140), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mulberrycrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Mulberry (Crayola)"), hex:std::borrow::Cow::Borrowed("#C8509B"), rgb:(200,80,155), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:Some("Crayola") } },
+    "mustard" => crate::Color{ name:std::borrow::Cow::Borrowed("Mustard"), hex:std::borrow::Cow::Borrowed("#FFDB58"), rgb:(255,219,88), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "myrtlegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Myrtle green"), hex:std::borrow::Cow::Borrowed("#317873"), rgb:(49,120,115), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mystic" => crate::Color{ name:std::borrow::Cow::Borrowed("Mystic"), hex:std::borrow::Cow::Borrowed("#D65282"), rgb:(214,82,130), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "mysticmaroon" => crate::Color{ name:std::borrow::Cow::Borrowed("Mystic maroon"), hex:std::borrow::Cow::Borrowed("#AD4379"), rgb:(173,67,121), aliases:&[], source:crate::Source{ list:"List of colors: G–M", variant:None } },
+    "nadeshikopink" => crate::Color{ name:std::borrow::Cow::Borrowed("Nadeshiko pink"), hex:std::borrow::Cow::Borrowed("#F6ADC6"), rgb:(246,173,198), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "naplesyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Naples yellow"), hex:std::borrow::Cow::Borrowed("#FADA5E"), rgb:(250,218,94), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "navajowhite" => crate::Color{ name:std::borrow::Cow::Borrowed("Navajo white"), hex:std::borrow::Cow::Borrowed("#FFDEAD"), rgb:(255,222,173), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "navyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Navy blue"), hex:std::borrow::Cow::Borrowed("#000080"), rgb:(0,0,128), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "navybluecrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Navy blue (Crayola)"), hex:std::borrow::Cow::Borrowed("#1974D2"), rgb:(25,116,210), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "neonblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Neon blue"), hex:std::borrow::Cow::Borrowed("#4666FF"), rgb:(70,102,255), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "neongreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Neon green"), hex:std::borrow::Cow::Borrowed("#39FF14"), rgb:(57,255,20), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "neonfuchsia" => crate::Color{ name:std::borrow::Cow::Borrowed("Neon fuchsia"), hex:std::borrow::Cow::Borrowed("#FE4164"), rgb:(254,65,100), aliases:&["neonfuschia", "neonfushia"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "newcar" => crate::Color{ name:std::borrow::Cow::Borrowed("New Car"), hex:std::borrow::Cow::Borrowed("#214FC6"), rgb:(33,79,198), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "newyorkpink" => crate::Color{ name:std::borrow::Cow::Borrowed("New York pink"), hex:std::borrow::Cow::Borrowed("#D7837F"), rgb:(215,131,127), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "nickel" => crate::Color{ name:std::borrow::Cow::Borrowed("Nickel"), hex:std::borrow::Cow::Borrowed("#727472"), rgb:(114,116,114), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "nonphotoblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Non-photo blue"), hex:std::borrow::Cow::Borrowed("#A4DDED"), rgb:(164,221,237), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "nyanza" => crate::Color{ name:std::borrow::Cow::Borrowed("Nyanza"), hex:std::borrow::Cow::Borrowed("#E9FFDB"), rgb:(233,255,219), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "ochre" => crate::Color{ name:std::borrow::Cow::Borrowed("Ochre"), hex:std::borrow::Cow::Borrowed("#CC7722"), rgb:(204,119,34), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "oldburgundy" => crate::Color{ name:std::borrow::Cow::Borrowed("Old burgundy"), hex:std::borrow::Cow::Borrowed("#43302E"), rgb:(67,48,46), aliases:&["oldburgandy"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "oldgold" => crate::Color{ name:std::borrow::Cow::Borrowed("Old gold"), hex:std::borrow::Cow::Borrowed("#CFB53B"), rgb:(207,181,59), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "oldlace" => crate::Color{ name:std::borrow::Cow::Borrowed("Old lace"), hex:std::borrow::Cow::Borrowed("#FDF5E6"), rgb:(253,245,230), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "oldlavender" => crate::Color{ name:std::borrow::Cow::Borrowed("Old lavender"), hex:std::borrow::Cow::Borrowed("#796878"), rgb:(121,104,120), aliases:&["oldlavendar"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "oldmauve" => crate::Color{ name:std::borrow::Cow::Borrowed("Old mauve"), hex:std::borrow::Cow::Borrowed("#673147"), rgb:(103,49,71), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "oldrose" => crate::Color{ name:std::borrow::Cow::Borrowed("Old rose"), hex:std::borrow::Cow::Borrowed("#C08081"), rgb:(192,128,129), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "oldsilver" => crate::Color{ name:std::borrow::Cow::Borrowed("Old silver"), hex:std::borrow::Cow::Borrowed("#848482"), rgb:(132,132,130), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "olive" => crate::Color{ name:std::borrow::Cow::Borrowed("Olive"), hex:std::borrow::Cow::Borrowed("#808000"), rgb:(128,128,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "olivedrab3" => crate::Color{ name:std::borrow::Cow::Borrowed("Olive Drab (#3)"), hex:std::borrow::Cow::Borrowed("#6B8E23"), rgb:(107,142,35), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("#3") } },
+    "olivedrab7" => crate::Color{ name:std::borrow::Cow::Borrowed("Olive Drab #7"), hex:std::borrow::Cow::Borrowed("#3C341F"), rgb:(60,52,31), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("#7") } },
+    "olivegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Olive green"), hex:std::borrow::Cow::Borrowed("#B5B35C"), rgb:(181,179,92), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "olivine" => crate::Color{ name:std::borrow::Cow::Borrowed("Olivine"), hex:std::borrow::Cow::Borrowed("#9AB973"), rgb:(154,185,115), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "onyx" => crate::Color{ name:std::borrow::Cow::Borrowed("Onyx"), hex:std::borrow::Cow::Borrowed("#353839"), rgb:(53,56,57), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "opal" => crate::Color{ name:std::borrow::Cow::Borrowed("Opal"), hex:std::borrow::Cow::Borrowed("#A8C3BC"), rgb:(168,195,188), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "operamauve" => crate::Color{ name:std::borrow::Cow::Borrowed("Opera mauve"), hex:std::borrow::Cow::Borrowed("#B784A7"), rgb:(183,132,167), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "orange" => crate::Color{ name:std::borrow::Cow::Borrowed("Orange"), hex:std::borrow::Cow::Borrowed("#FF7F00"), rgb:(255,127,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "orangecrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Orange (Crayola)"), hex:std::borrow::Cow::Borrowed("#FF7538"), rgb:(255,117,56), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "orangepantone" => crate::Color{ name:std::borrow::Cow::Borrowed("Orange (Pantone)"), hex:std::borrow::Cow::Borrowed("#FF5800"), rgb:(255,88,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Pantone") } },
+    "orangeweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Orange (web)"), hex:std::borrow::Cow::Borrowed("#FFA500"), rgb:(255,165,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("web") } },
+    "orangepeel" => crate::Color{ name:std::borrow::Cow::Borrowed("Orange peel"), hex:std::borrow::Cow::Borrowed("#FF9F00"), rgb:(255,159,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "orangered" => crate::Color{ name:std::borrow::Cow::Borrowed("Orange-red"), hex:std::borrow::Cow::Borrowed("#FF681F"), rgb:(255,104,31), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "orangeredcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Orange-red (Crayola)"), hex:std::borrow::Cow::Borrowed("#FF5349"), rgb:(255,83,73), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "orangesoda" => crate::Color{ name:std::borrow::Cow::Borrowed("Orange soda"), hex:std::borrow::Cow::Borrowed("#FA5B3D"), rgb:(250,91,61), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "orangeyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Orange-yellow"), hex:std::borrow::Cow::Borrowed("#F5BD1F"), rgb:(245,189,31), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "orangeyellowcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Orange-yellow (Crayola)"), hex:std::borrow::Cow::Borrowed("#F8D568"), rgb:(248,213,104), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "orchid" => crate::Color{ name:std::borrow::Cow::Borrowed("Orchid"), hex:std::borrow::Cow::Borrowed("#DA70D6"), rgb:(218,112,214), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "orchidpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Orchid pink"), hex:std::borrow::Cow::Borrowed("#F2BDCD"), rgb:(242,189,205), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "orchidcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Orchid (Crayola)"), hex:std::borrow::Cow::Borrowed("#E29CD2"), rgb:(226,156,210), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "outerspacecrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Outer space (Crayola)"), hex:std::borrow::Cow::Borrowed("#2D383A"), rgb:(45,56,58), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "outrageousorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Outrageous Orange"), hex:std::borrow::Cow::Borrowed("#FF6E4A"), rgb:(255,110,74), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "oxblood" => crate::Color{ name:std::borrow::Cow::Borrowed("Oxblood"), hex:std::borrow::Cow::Borrowed("#4A0000"), rgb:(74,0,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "oxfordblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Oxford blue"), hex:std::borrow::Cow::Borrowed("#002147"), rgb:(0,33,71), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "oucrimsonred" => crate::Color{ name:std::borrow::Cow::Borrowed("OU Crimson red"), hex:std::borrow::Cow::Borrowed("#841617"), rgb:(132,22,23), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pacificblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Pacific blue"), hex:std::borrow::Cow::Borrowed("#1CA9C9"), rgb:(28,169,201), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pakistangreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Pakistan green"), hex:std::borrow::Cow::Borrowed("#006600"), rgb:(0,102,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "palatinatepurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Palatinate purple"), hex:std::borrow::Cow::Borrowed("#682860"), rgb:(104,40,96), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "paleaqua" => crate::Color{ name:std::borrow::Cow::Borrowed("Pale aqua"), hex:std::borrow::Cow::Borrowed("#BED3E5"), rgb:(190,211,229), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "palecerulean" => crate::Color{ name:std::borrow::Cow::Borrowed("Pale cerulean"), hex:std::borrow::Cow::Borrowed("#9BC4E2"), rgb:(155,196,226), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "paledogwood" => crate::Color{ name:std::borrow::Cow::Borrowed("Pale Dogwood"), hex:std::borrow::Cow::Borrowed("#ED7A9B"), rgb:(237,122,155), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "palepink" => crate::Color{ name:std::borrow::Cow::Borrowed("Pale pink"), hex:std::borrow::Cow::Borrowed("#FADADD"), rgb:(250,218,221), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "palepurplepantone" => crate::Color{ name:std::borrow::Cow::Borrowed("Pale purple (Pantone)"), hex:std::borrow::Cow::Borrowed("#FAE6FA"), rgb:(250,230,250), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Pantone") } },
+    "palespringbud" => crate::Color{ name:std::borrow::Cow::Borrowed("Pale spring bud"), hex:std::borrow::Cow::Borrowed("#ECEBBD"), rgb:(236,235,189), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pansypurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Pansy purple"), hex:std::borrow::Cow::Borrowed("#78184A"), rgb:(120,24,74), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "paoloveronesegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Paolo Veronese green"), hex:std::borrow::Cow::Borrowed("#009B7D"), rgb:(0,155,125), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "papayawhip" => crate::Color{ name:std::borrow::Cow::Borrowed("Papaya whip"), hex:std::borrow::Cow::Borrowed("#FFEFD5"), rgb:(255,239,213), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "paradisepink" => crate::Color{ name:std::borrow::Cow::Borrowed("Paradise pink"), hex:std::borrow::Cow::Borrowed("#E63E62"), rgb:(230,62,98), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "parchment" => crate::Color{ name:std::borrow::Cow::Borrowed("Parchment"), hex:std::borrow::Cow::Borrowed("#F1E9D2"), rgb:(241,233,210), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "parisgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Paris Green"), hex:std::borrow::Cow::Borrowed("#50C878"), rgb:(80,200,120), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pastelpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Pastel pink"), hex:std::borrow::Cow::Borrowed("#DEA5A4"), rgb:(222,165,164), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "patriarch" => crate::Color{ name:std::borrow::Cow::Borrowed("Patriarch"), hex:std::borrow::Cow::Borrowed("#800080"), rgb:(128,0,128), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "paua" => crate::Color{ name:std::borrow::Cow::Borrowed("Paua"), hex:std::borrow::Cow::Borrowed("#1F005E"), rgb:(31,0,94), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "paynesgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("Payne's grey"), hex:std::borrow::Cow::Borrowed("#536878"), rgb:(83,104,120), aliases:&["paynesgray"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "peach" => crate::Color{ name:std::borrow::Cow::Borrowed("Peach"), hex:std::borrow::Cow::Borrowed("#FFE5B4"), rgb:(255,229,180), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "peachcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Peach (Crayola)"), hex:std::borrow::Cow::Borrowed("#FFCBA4"), rgb:(255,203,164), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "peachpuff" => crate::Color{ name:std::borrow::Cow::Borrowed("Peach puff"), hex:std::borrow::Cow::Borrowed("#FFDAB9"), rgb:(255,218,185), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pear" => crate::Color{ name:std::borrow::Cow::Borrowed("Pear"), hex:std::borrow::Cow::Borrowed("#D1E231"), rgb:(209,226,49), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pearlypurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Pearly purple"), hex:std::borrow::Cow::Borrowed("#B768A2"), rgb:(183,104,162), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "periwinkle" => crate::Color{ name:std::borrow::Cow::Borrowed("Periwinkle"), hex:std::borrow::Cow::Borrowed("#CCCCFF"), rgb:(204,204,255), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "periwinklecrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Periwinkle (Crayola)"), hex:std::borrow::Cow::Borrowed("#C3CDE6"), rgb:(195,205,230), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "permanentgeraniumlake" => crate::Color{ name:std::borrow::Cow::Borrowed("Permanent Geranium Lake"), hex:std::borrow::Cow::Borrowed("#E12C2C"), rgb:(225,44,44), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "persianblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Persian blue"), hex:std::borrow::Cow::Borrowed("#1C39BB"), rgb:(28,57,187), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "persiangreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Persian green"), hex:std::borrow::Cow::Borrowed("#00A693"), rgb:(0,166,147), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "persianindigo" => crate::Color{ name:std::borrow::Cow::Borrowed("Persian indigo"), hex:std::borrow::Cow::Borrowed("#32127A"), rgb:(50,18,122), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "persianorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Persian orange"), hex:std::borrow::Cow::Borrowed("#D99058"), rgb:(217,144,88), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "persianpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Persian pink"), hex:std::borrow::Cow::Borrowed("#F77FBE"), rgb:(247,127,190), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "persianplum" => crate::Color{ name:std::borrow::Cow::Borrowed("Persian plum"), hex:std::borrow::Cow::Borrowed("#701C1C"), rgb:(112,28,28), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "persianred" => crate::Color{ name:std::borrow::Cow::Borrowed("Persian red"), hex:std::borrow::Cow::Borrowed("#CC3333"), rgb:(204,51,51), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "persianrose" => crate::Color{ name:std::borrow::Cow::Borrowed("Persian rose"), hex:std::borrow::Cow::Borrowed("#FE28A2"), rgb:(254,40,162), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "persimmon" => crate::Color{ name:std::borrow::Cow::Borrowed("Persimmon"), hex:std::borrow::Cow::Borrowed("#EC5800"), rgb:(236,88,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "petunia" => crate::Color{ name:std::borrow::Cow::Borrowed("Petunia"), hex:std::borrow::Cow::Borrowed("#470659"), rgb:(71,6,89), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pewterblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Pewter Blue"), hex:std::borrow::Cow::Borrowed("#8BA8B7"), rgb:(139,168,183), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "phlox" => crate::Color{ name:std::borrow::Cow::Borrowed("Phlox"), hex:std::borrow::Cow::Borrowed("#DF00FF"), rgb:(223,0,255), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "phthaloblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Phthalo blue"), hex:std::borrow::Cow::Borrowed("#000F89"), rgb:(0,15,137), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "phthalogreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Phthalo green"), hex:std::borrow::Cow::Borrowed("#123524"), rgb:(18,53,36), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "picoteeblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Picotee blue"), hex:std::borrow::Cow::Borrowed("#2E2787"), rgb:(46,39,135), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pictorialcarmine" => crate::Color{ name:std::borrow::Cow::Borrowed("Pictorial carmine"), hex:std::borrow::Cow::Borrowed("#C30B4E"), rgb:(195,11,78), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "piggypink" => crate::Color{ name:std::borrow::Cow::Borrowed("Piggy pink"), hex:std::borrow::Cow::Borrowed("#FDDDE6"), rgb:(253,221,230), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pinegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Pine green"), hex:std::borrow::Cow::Borrowed("#01796F"), rgb:(1,121,111), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pinegreen2" => crate::Color{ name:std::borrow::Cow::Borrowed("Pine green"), hex:std::borrow::Cow::Borrowed("#2A2F23"), rgb:(42,47,35), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pink" => crate::Color{ name:std::borrow::Cow::Borrowed("Pink"), hex:std::borrow::Cow::Borrowed("#FFC0CB"), rgb:(255,192,203), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pinkpantone" => crate::Color{ name:std::borrow::Cow::Borrowed("Pink (Pantone)"), hex:std::borrow::Cow::Borrowed("#D74894"), rgb:(215,72,148), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Pantone") } },
+    "pinklace" => crate::Color{ name:std::borrow::Cow::Borrowed("Pink lace"), hex:std::borrow::Cow::Borrowed("#FFDDF4"), rgb:(255,221,244), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pinklavender" => crate::Color{ name:std::borrow::Cow::Borrowed("Pink lavender"), hex:std::borrow::Cow::Borrowed("#D8B2D1"), rgb:(216,178,209), aliases:&["pinklavendar"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pinksherbet" => crate::Color{ name:std::borrow::Cow::Borrowed("Pink Sherbet"), hex:std::borrow::Cow::Borrowed("#F78FA7"), rgb:(247,143,167), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pistachio" => crate::Color{ name:std::borrow::Cow::Borrowed("Pistachio"), hex:std::borrow::Cow::Borrowed("#93C572"), rgb:(147,197,114), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "platinum" => crate::Color{ name:std::borrow::Cow::Borrowed("Platinum"), hex:std::borrow::Cow::Borrowed("#E5E4E2"), rgb:(229,228,226), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "plum" => crate::Color{ name:std::borrow::Cow::Borrowed("Plum"), hex:std::borrow::Cow::Borrowed("#8E4585"), rgb:(142,69,133), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "plumweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Plum (web)"), hex:std::borrow::Cow::Borrowed("#DDA0DD"), rgb:(221,160,221), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("web") } },
+    "plumppurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Plump Purple"), hex:std::borrow::Cow::Borrowed("#5946B2"), rgb:(89,70,178), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "polishedpine" => crate::Color{ name:std::borrow::Cow::Borrowed("Polished Pine"), hex:std::borrow::Cow::Borrowed("#5DA493"), rgb:(93,164,147), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pompandpower" => crate::Color{ name:std::borrow::Cow::Borrowed("Pomp and Power"), hex:std::borrow::Cow::Borrowed("#86608E"), rgb:(134,96,142), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "popstar" => crate::Color{ name:std::borrow::Cow::Borrowed("Popstar"), hex:std::borrow::Cow::Borrowed("#BE4F62"), rgb:(190,79,98), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "portlandorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Portland Orange"), hex:std::borrow::Cow::Borrowed("#FF5A36"), rgb:(255,90,54), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "powderblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Powder blue"), hex:std::borrow::Cow::Borrowed("#B0E0E6"), rgb:(176,224,230), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "prairiegold" => crate::Color{ name:std::borrow::Cow::Borrowed("Prairie gold"), hex:std::borrow::Cow::Borrowed("#E1CA7A"), rgb:(225,202,122), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "princetonorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Princeton orange"), hex:std::borrow::Cow::Borrowed("#F58025"), rgb:(245,128,37), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "prune" => crate::Color{ name:std::borrow::Cow::Borrowed("Prune"), hex:std::borrow::Cow::Borrowed("#701C1C"), rgb:(112,28,28), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "prussianblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Prussian blue"), hex:std::borrow::Cow::Borrowed("#003153"), rgb:(0,49,83), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "psychedelicpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Psychedelic purple"), hex:std::borrow::Cow::Borrowed("#DF00FF"), rgb:(223,0,255), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "puce" => crate::Color{ name:std::borrow::Cow::Borrowed("Puce"), hex:std::borrow::Cow::Borrowed("#CC8899"), rgb:(204,136,153), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "pullmanbrownupsbrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Pullman Brown (UPS Brown)"), hex:std::borrow::Cow::Borrowed("#644117"), rgb:(100,65,23), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("UPS Brown") } },
+    "pumpkin" => crate::Color{ name:std::borrow::Cow::Borrowed("Pumpkin"), hex:std::borrow::Cow::Borrowed("#FF7518"), rgb:(255,117,24), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "purple" => crate::Color{ name:std::borrow::Cow::Borrowed("Purple"), hex:std::borrow::Cow::Borrowed("#6A0DAD"), rgb:(106,13,173), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "purpleweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Purple (web)"), hex:std::borrow::Cow::Borrowed("#800080"), rgb:(128,0,128), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("web") } },
+    "purplemunsell" => crate::Color{ name:std::borrow::Cow::Borrowed("Purple (Munsell)"), hex:std::borrow::Cow::Borrowed("#9F00C5"), rgb:(159,0,197), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Munsell") } },
+    "purplex11" => crate::Color{ name:std::borrow::Cow::Borrowed("Purple (X11)"), hex:std::borrow::Cow::Borrowed("#A020F0"), rgb:(160,32,240), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("X11") } },
+    "purplemountainmajesty" => crate::Color{ name:std::borrow::Cow::Borrowed("Purple mountain majesty"), hex:std::borrow::Cow::Borrowed("#9678B6"), rgb:(150,120,182), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "purplenavy" => crate::Color{ name:std::borrow::Cow::Borrowed("Purple navy"), hex:std::borrow::Cow::Borrowed("#4E5180"), rgb:(78,81,128), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "purplepizzazzbrokenanchor" => crate::Color{ name:std::borrow::Cow::Borrowed("Purple pizzazz[broken anchor]"), hex:std::borrow::Cow::Borrowed("#FE4EDA"), rgb:(254,78,218), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "purpleplum" => crate::Color{ name:std::borrow::Cow::Borrowed("Purple Plum"), hex:std::borrow::Cow::Borrowed("#9C51B6"), rgb:(156,81,182), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "queenblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Queen blue"), hex:std::borrow::Cow::Borrowed("#436B95"), rgb:(67,107,149), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "queenpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Queen pink"), hex:std::borrow::Cow::Borrowed("#E8CCD7"), rgb:(232,204,215), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "quicksilver" => crate::Color{ name:std::borrow::Cow::Borrowed("Quick Silver"), hex:std::borrow::Cow::Borrowed("#A6A6A6"), rgb:(166,166,166), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "quinacridonemagenta" => crate::Color{ name:std::borrow::Cow::Borrowed("Quinacridone magenta"), hex:std::borrow::Cow::Borrowed("#8E3A59"), rgb:(142,58,89), aliases:&["quinacridonemegenta"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "radicalred" => crate::Color{ name:std::borrow::Cow::Borrowed("Radical Red"), hex:std::borrow::Cow::Borrowed("#FF355E"), rgb:(255,53,94), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "raisinblack" => crate::Color{ name:std::borrow::Cow::Borrowed("Raisin black"), hex:std::borrow::Cow::Borrowed("#242124"), rgb:(36,33,36), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rajah" => crate::Color{ name:std::borrow::Cow::Borrowed("Rajah"), hex:std::borrow::Cow::Borrowed("#FBAB60"), rgb:(251,171,96), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "raspberry" => crate::Color{ name:std::borrow::Cow::Borrowed("Raspberry"), hex:std::borrow::Cow::Borrowed("#E30B5D"), rgb:(227,11,93), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "raspberryglace" => crate::Color{ name:std::borrow::Cow::Borrowed("Raspberry glacé"), hex:std::borrow::Cow::Borrowed("#915F6D"), rgb:(145,95,109), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "raspberryrose" => crate::Color{ name:std::borrow::Cow::Borrowed("Raspberry rose"), hex:std::borrow::Cow::Borrowed("#B3446C"), rgb:(179,68,108), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rawsienna" => crate::Color{ name:std::borrow::Cow::Borrowed("Raw sienna"), hex:std::borrow::Cow::Borrowed("#D68A59"), rgb:(214,138,89), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rawumber" => crate::Color{ name:std::borrow::Cow::Borrowed("Raw umber"), hex:std::borrow::Cow::Borrowed("#826644"), rgb:(130,102,68), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "razzledazzlerose" => crate::Color{ name:std::borrow::Cow::Borrowed("Razzle dazzle rose"), hex:std::borrow::Cow::Borrowed("#FF33CC"), rgb:(255,51,204), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "razzmatazz" => crate::Color{ name:std::borrow::Cow::Borrowed("Razzmatazz"), hex:std::borrow::Cow::Borrowed("#E3256B"), rgb:(227,37,107), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "razzmicberry" => crate::Color{ name:std::borrow::Cow::Borrowed("Razzmic Berry"), hex:std::borrow::Cow::Borrowed("#8D4E85"), rgb:(141,78,133), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rebeccapurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Rebecca Purple"), hex:std::borrow::Cow::Borrowed("#663399"), rgb:(102,51,153), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "red" => crate::Color{ name:std::borrow::Cow::Borrowed("Red"), hex:std::borrow::Cow::Borrowed("#FF0000"), rgb:(255,0,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "redcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Red (Crayola)"), hex:std::borrow::Cow::Borrowed("#EE204D"), rgb:(238,32,77), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "redmunsell" => crate::Color{ name:std::borrow::Cow::Borrowed("Red (Munsell)"), hex:std::borrow::Cow::Borrowed("#F2003C"), rgb:(242,0,60), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Munsell") } },
+    "redncs" => crate::Color{ name:std::borrow::Cow::Borrowed("Red (NCS)"), hex:std::borrow::Cow::Borrowed("#C40233"), rgb:(196,2,51), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("NCS") } },
+    "redpantone" => crate::Color{ name:std::borrow::Cow::Borrowed("Red (Pantone)"), hex:std::borrow::Cow::Borrowed("#ED2939"), rgb:(237,41,57), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Pantone") } },
+    "redpigment" => crate::Color{ name:std::borrow::Cow::Borrowed("Red (pigment)"), hex:std::borrow::Cow::Borrowed("#ED1C24"), rgb:(237,28,36), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("pigment") } },
+    "redryb" => crate::Color{ name:std::borrow::Cow::Borrowed("Red (RYB)"), hex:std::borrow::Cow::Borrowed("#FE2712"), rgb:(254,39,18), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("RYB") } },
+    "redorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Red-orange"), hex:std::borrow::Cow::Borrowed("#FF5349"), rgb:(255,83,73), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "redochre2" => crate::Color{ name:std::borrow::Cow::Borrowed("Red ochre[2]"), hex:std::borrow::Cow::Borrowed("#913831"), rgb:(145,56,49), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "redorangecrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Red-orange (Crayola)"), hex:std::borrow::Cow::Borrowed("#FF681F"), rgb:(255,104,31), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "redorangecolorwheel" => crate::Color{ name:std::borrow::Cow::Borrowed("Red-orange (Color wheel)"), hex:std::borrow::Cow::Borrowed("#FF4500"), rgb:(255,69,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Color wheel") } },
+    "redpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Red-purple"), hex:std::borrow::Cow::Borrowed("#E40078"), rgb:(228,0,120), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "redsalsa" => crate::Color{ name:std::borrow::Cow::Borrowed("Red Salsa"), hex:std::borrow::Cow::Borrowed("#FD3A4A"), rgb:(253,58,74), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "redviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("Red-violet"), hex:std::borrow::Cow::Borrowed("#C71585"), rgb:(199,21,133), aliases:&["redvoilet"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "redvioletcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Red-violet (Crayola)"), hex:std::borrow::Cow::Borrowed("#C0448F"), rgb:(192,68,143), aliases:&["redvoiletcrayola"], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "redvioletcolorwheel" => crate::Color{ name:std::borrow::Cow::Borrowed("Red-violet (Color wheel)"), hex:std::borrow::Cow::Borrowed("#922B3E"), rgb:(146,43,62), aliases:&["redvoiletcolorwheel"], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Color wheel") } },
+    "redwood" => crate::Color{ name:std::borrow::Cow::Borrowed("Redwood"), hex:std::borrow::Cow::Borrowed("#A45A52"), rgb:(164,90,82), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "resolutionblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Resolution blue"), hex:std::borrow::Cow::Borrowed("#002387"), rgb:(0,35,135), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rhythm" => crate::Color{ name:std::borrow::Cow::Borrowed("Rhythm"), hex:std::borrow::Cow::Borrowed("#777696"), rgb:(119,118,150), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "richblack" => crate::Color{ name:std::borrow::Cow::Borrowed("Rich black"), hex:std::borrow::Cow::Borrowed("#004040"), rgb:(0,64,64), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "richblackfogra29" => crate::Color{ name:std::borrow::Cow::Borrowed("Rich black (FOGRA29)"), hex:std::borrow::Cow::Borrowed("#010B13"), rgb:(1,11,19), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("FOGRA29") } },
+    "richblackfogra39" => crate::Color{ name:std::borrow::Cow::Borrowed("Rich black (FOGRA39)"), hex:std::borrow::Cow::Borrowed("#010203"), rgb:(1,2,3), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("FOGRA39") } },
+    "riflegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Rifle green"), hex:std::borrow::Cow::Borrowed("#444C38"), rgb:(68,76,56), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "robineggblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Robin egg blue"), hex:std::borrow::Cow::Borrowed("#00CCCC"), rgb:(0,204,204), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rocketmetallic" => crate::Color{ name:std::borrow::Cow::Borrowed("Rocket metallic"), hex:std::borrow::Cow::Borrowed("#8A7F80"), rgb:(138,127,128), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rojospanishred" => crate::Color{ name:std::borrow::Cow::Borrowed("Rojo Spanish red"), hex:std::borrow::Cow::Borrowed("#A91101"), rgb:(169,17,1), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "romansilver" => crate::Color{ name:std::borrow::Cow::Borrowed("Roman silver"), hex:std::borrow::Cow::Borrowed("#838996"), rgb:(131,137,150), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rose" => crate::Color{ name:std::borrow::Cow::Borrowed("Rose"), hex:std::borrow::Cow::Borrowed("#FF007F"), rgb:(255,0,127), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rosebonbon" => crate::Color{ name:std::borrow::Cow::Borrowed("Rose bonbon"), hex:std::borrow::Cow::Borrowed("#F9429E"), rgb:(249,66,158), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rosedust" => crate::Color{ name:std::borrow::Cow::Borrowed("Rose Dust"), hex:std::borrow::Cow::Borrowed("#9E5E6F"), rgb:(158,94,111), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "roseebony" => crate::Color{ name:std::borrow::Cow::Borrowed("Rose ebony"), hex:std::borrow::Cow::Borrowed("#674846"), rgb:(103,72,70), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rosemadder" => crate::Color{ name:std::borrow::Cow::Borrowed("Rose madder"), hex:std::borrow::Cow::Borrowed("#E32636"), rgb:(227,38,54), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rosepink" => crate::Color{ name:std::borrow::Cow::Borrowed("Rose pink"), hex:std::borrow::Cow::Borrowed("#FF66CC"), rgb:(255,102,204), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rosepompadour" => crate::Color{ name:std::borrow::Cow::Borrowed("Rose Pompadour"), hex:std::borrow::Cow::Borrowed("#ED7A9B"), rgb:(237,122,155), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rosered" => crate::Color{ name:std::borrow::Cow::Borrowed("Rose red"), hex:std::borrow::Cow::Borrowed("#C21E56"), rgb:(194,30,86), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rosetaupe" => crate::Color{ name:std::borrow::Cow::Borrowed("Rose taupe"), hex:std::borrow::Cow::Borrowed("#905D5D"), rgb:(144,93,93), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rosevale" => crate::Color{ name:std::borrow::Cow::Borrowed("Rose vale"), hex:std::borrow::Cow::Borrowed("#AB4E52"), rgb:(171,78,82), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rosewood" => crate::Color{ name:std::borrow::Cow::Borrowed("Rosewood"), hex:std::borrow::Cow::Borrowed("#65000B"), rgb:(101,0,11), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rossocorsa" => crate::Color{ name:std::borrow::Cow::Borrowed("Rosso corsa"), hex:std::borrow::Cow::Borrowed("#D40000"), rgb:(212,0,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rosybrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Rosy brown"), hex:std::borrow::Cow::Borrowed("#BC8F8F"), rgb:(188,143,143), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "royalbluedark" => crate::Color{ name:std::borrow::Cow::Borrowed("Royal blue (dark)"), hex:std::borrow::Cow::Borrowed("#002366"), rgb:(0,35,102), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("dark") } },
+    "royalbluelight" => crate::Color{ name:std::borrow::Cow::Borrowed("Royal blue (light)"), hex:std::borrow::Cow::Borrowed("#4169E1"), rgb:(65,105,225), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("light") } },
+    "royalpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Royal purple"), hex:std::borrow::Cow::Borrowed("#7851A9"), rgb:(120,81,169), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "royalyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Royal yellow"), hex:std::borrow::Cow::Borrowed("#FADA5E"), rgb:(250,218,94), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "ruber" => crate::Color{ name:std::borrow::Cow::Borrowed("Ruber"), hex:std::borrow::Cow::Borrowed("#CE4676"), rgb:(206,70,118), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rubinered" => crate::Color{ name:std::borrow::Cow::Borrowed("Rubine red"), hex:std::borrow::Cow::Borrowed("#D10056"), rgb:(209,0,86), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "ruby" => crate::Color{ name:std::borrow::Cow::Borrowed("Ruby"), hex:std::borrow::Cow::Borrowed("#E0115F"), rgb:(224,17,95), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rubyred" => crate::Color{ name:std::borrow::Cow::Borrowed("Ruby red"), hex:std::borrow::Cow::Borrowed("#9B111E"), rgb:(155,17,30), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rufous" => crate::Color{ name:std::borrow::Cow::Borrowed("Rufous"), hex:std::borrow::Cow::Borrowed("#A81C07"), rgb:(168,28,7), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "russet" => crate::Color{ name:std::borrow::Cow::Borrowed("Russet"), hex:std::borrow::Cow::Borrowed("#80461B"), rgb:(128,70,27), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "russiangreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Russian green"), hex:std::borrow::Cow::Borrowed("#679267"), rgb:(103,146,103), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "russianviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("Russian violet"), hex:std::borrow::Cow::Borrowed("#32174D"), rgb:(50,23,77), aliases:&["russianvoilet"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rust" => crate::Color{ name:std::borrow::Cow::Borrowed("Rust"), hex:std::borrow::Cow::Borrowed("#B7410E"), rgb:(183,65,14), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "rustyred" => crate::Color{ name:std::borrow::Cow::Borrowed("Rusty red"), hex:std::borrow::Cow::Borrowed("#DA2C43"), rgb:(218,44,67), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sacramentostategreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Sacramento State green"), hex:std::borrow::Cow::Borrowed("#043927"), rgb:(4,57,39), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "saddlebrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Saddle brown"), hex:std::borrow::Cow::Borrowed("#8B4513"), rgb:(139,69,19), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "safetyorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Safety orange"), hex:std::borrow::Cow::Borrowed("#FF7800"), rgb:(255,120,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "safetyorangeblazeorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Safety orange (blaze orange)"), hex:std::borrow::Cow::Borrowed("#FF6700"), rgb:(255,103,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("blaze orange") } },
+    "safetyyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Safety yellow"), hex:std::borrow::Cow::Borrowed("#EED202"), rgb:(238,210,2), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "saffron" => crate::Color{ name:std::borrow::Cow::Borrowed("Saffron"), hex:std::borrow::Cow::Borrowed("#F4C430"), rgb:(244,196,48), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sage" => crate::Color{ name:std::borrow::Cow::Borrowed("Sage"), hex:std::borrow::Cow::Borrowed("#BCB88A"), rgb:(188,184,138), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "stpatricksblue" => crate::Color{ name:std::borrow::Cow::Borrowed("St. Patrick's blue"), hex:std::borrow::Cow::Borrowed("#23297A"), rgb:(35,41,122), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "salmon" => crate::Color{ name:std::borrow::Cow::Borrowed("Salmon"), hex:std::borrow::Cow::Borrowed("#FA8072"), rgb:(250,128,114), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "salmonpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Salmon pink"), hex:std::borrow::Cow::Borrowed("#FF91A4"), rgb:(255,145,164), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sand" => crate::Color{ name:std::borrow::Cow::Borrowed("Sand"), hex:std::borrow::Cow::Borrowed("#C2B280"), rgb:(194,178,128), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sanddune" => crate::Color{ name:std::borrow::Cow::Borrowed("Sand dune"), hex:std::borrow::Cow::Borrowed("#967117"), rgb:(150,113,23), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sandybrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Sandy brown"), hex:std::borrow::Cow::Borrowed("#F4A460"), rgb:(244,164,96), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sapgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Sap green"), hex:std::borrow::Cow::Borrowed("#507D2A"), rgb:(80,125,42), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sapphire" => crate::Color{ name:std::borrow::Cow::Borrowed("Sapphire"), hex:std::borrow::Cow::Borrowed("#0F52BA"), rgb:(15,82,186), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sapphireblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Sapphire blue"), hex:std::borrow::Cow::Borrowed("#0067A5"), rgb:(0,103,165), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sapphirecrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Sapphire (Crayola)"), hex:std::borrow::Cow::Borrowed("#2D5DA1"), rgb:(45,93,161), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "satinsheengold" => crate::Color{ name:std::borrow::Cow::Borrowed("Satin sheen gold"), hex:std::borrow::Cow::Borrowed("#CBA135"), rgb:(203,161,53), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "scarlet" => crate::Color{ name:std::borrow::Cow::Borrowed("Scarlet"), hex:std::borrow::Cow::Borrowed("#FF2400"), rgb:(255,36,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "schausspink" => crate::Color{ name:std::borrow::Cow::Borrowed("Schauss pink"), hex:std::borrow::Cow::Borrowed("#FF91AF"), rgb:(255,145,175), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "schoolbusyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("School bus yellow"), hex:std::borrow::Cow::Borrowed("#FFD800"), rgb:(255,216,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "screamingreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Screamin' Green"), hex:std::borrow::Cow::Borrowed("#66FF66"), rgb:(102,255,102), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "seagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Sea green"), hex:std::borrow::Cow::Borrowed("#2E8B57"), rgb:(46,139,87), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "seagreencrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Sea green (Crayola)"), hex:std::borrow::Cow::Borrowed("#00FFCD"), rgb:(0,255,205), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "seance" => crate::Color{ name:std::borrow::Cow::Borrowed("Seance"), hex:std::borrow::Cow::Borrowed("#612086"), rgb:(97,32,134), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sealbrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Seal brown"), hex:std::borrow::Cow::Borrowed("#59260B"), rgb:(89,38,11), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "seashell" => crate::Color{ name:std::borrow::Cow::Borrowed("Seashell"), hex:std::borrow::Cow::Borrowed("#FFF5EE"), rgb:(255,245,238), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "secret" => crate::Color{ name:std::borrow::Cow::Borrowed("Secret"), hex:std::borrow::Cow::Borrowed("#764374"), rgb:(118,67,116), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "selectiveyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Selective yellow"), hex:std::borrow::Cow::Borrowed("#FFBA00"), rgb:(255,186,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sepia" => crate::Color{ name:std::borrow::Cow::Borrowed("Sepia"), hex:std::borrow::Cow::Borrowed("#704214"), rgb:(112,66,20), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "shadow" => crate::Color{ name:std::borrow::Cow::Borrowed("Shadow"), hex:std::borrow::Cow::Borrowed("#8A795D"), rgb:(138,121,93), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "shadowblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Shadow blue"), hex:std::borrow::Cow::Borrowed("#778BA5"), rgb:(119,139,165), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "shamrockgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Shamrock green"), hex:std::borrow::Cow::Borrowed("#009E60"), rgb:(0,158,96), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sheengreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Sheen green"), hex:std::borrow::Cow::Borrowed("#8FD400"), rgb:(143,212,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "shimmeringblush" => crate::Color{ name:std::borrow::Cow::Borrowed("Shimmering Blush"), hex:std::borrow::Cow::Borrowed("#D98695"), rgb:(217,134,149), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "shinyshamrock" => crate::Color{ name:std::borrow::Cow::Borrowed("Shiny Shamrock"), hex:std::borrow::Cow::Borrowed("#5FA778"), rgb:(95,167,120), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "shockingpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Shocking pink"), hex:std::borrow::Cow::Borrowed("#FC0FC0"), rgb:(252,15,192), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "shockingpinkcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Shocking pink (Crayola)"), hex:std::borrow::Cow::Borrowed("#FF6FFF"), rgb:(255,111,255), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "sienna" => crate::Color{ name:std::borrow::Cow::Borrowed("Sienna"), hex:std::borrow::Cow::Borrowed("#882D17"), rgb:(136,45,23), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "silver" => crate::Color{ name:std::borrow::Cow::Borrowed("Silver"), hex:std::borrow::Cow::Borrowed("#C0C0C0"), rgb:(192,192,192), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "silvercrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Silver (Crayola)"), hex:std::borrow::Cow::Borrowed("#C9C0BB"), rgb:(201,192,187), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "silvermetallic" => crate::Color{ name:std::borrow::Cow::Borrowed("Silver (Metallic)"), hex:std::borrow::Cow::Borrowed("#AAA9AD"), rgb:(170,169,173), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Metallic") } },
+    "silverchalice" => crate::Color{ name:std::borrow::Cow::Borrowed("Silver chalice"), hex:std::borrow::Cow::Borrowed("#ACACAC"), rgb:(172,172,172), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "silverpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Silver pink"), hex:std::borrow::Cow::Borrowed("#C4AEAD"), rgb:(196,174,173), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "silversand" => crate::Color{ name:std::borrow::Cow::Borrowed("Silver sand"), hex:std::borrow::Cow::Borrowed("#BFC1C2"), rgb:(191,193,194), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sinopia" => crate::Color{ name:std::borrow::Cow::Borrowed("Sinopia"), hex:std::borrow::Cow::Borrowed("#CB410B"), rgb:(203,65,11), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sizzlingred" => crate::Color{ name:std::borrow::Cow::Borrowed("Sizzling Red"), hex:std::borrow::Cow::Borrowed("#FF3855"), rgb:(255,56,85), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sizzlingsunrise" => crate::Color{ name:std::borrow::Cow::Borrowed("Sizzling Sunrise"), hex:std::borrow::Cow::Borrowed("#FFDB00"), rgb:(255,219,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "skobeloff" => crate::Color{ name:std::borrow::Cow::Borrowed("Skobeloff"), hex:std::borrow::Cow::Borrowed("#007474"), rgb:(0,116,116), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "skincolor" => crate::Color{ name:std::borrow::Cow::Borrowed("Skin color"), hex:std::borrow::Cow::Borrowed("#FFDEAD"), rgb:(255,222,173), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "skyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Sky blue"), hex:std::borrow::Cow::Borrowed("#87CEEB"), rgb:(135,206,235), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "skybluecrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Sky blue (Crayola)"), hex:std::borrow::Cow::Borrowed("#76D7EA"), rgb:(118,215,234), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "skymagenta" => crate::Color{ name:std::borrow::Cow::Borrowed("Sky magenta"), hex:std::borrow::Cow::Borrowed("#CF71AF"), rgb:(207,113,175), aliases:&["skymegenta"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "slateblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Slate blue"), hex:std::borrow::Cow::Borrowed("#6A5ACD"), rgb:(106,90,205), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "slategray" => crate::Color{ name:std::borrow::Cow::Borrowed("Slate gray"), hex:std::borrow::Cow::Borrowed("#708090"), rgb:(112,128,144), aliases:&["slategrey"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "slimygreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Slimy green"), hex:std::borrow::Cow::Borrowed("#299617"), rgb:(41,150,23), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "smitten" => crate::Color{ name:std::borrow::Cow::Borrowed("Smitten"), hex:std::borrow::Cow::Borrowed("#C84186"), rgb:(200,65,134), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "smokyblack" => crate::Color{ name:std::borrow::Cow::Borrowed("Smoky black"), hex:std::borrow::Cow::Borrowed("#100C08"), rgb:(16,12,8), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "snow" => crate::Color{ name:std::borrow::Cow::Borrowed("Snow"), hex:std::borrow::Cow::Borrowed("#FFFAFA"), rgb:(255,250,250), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "solidpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Solid pink"), hex:std::borrow::Cow::Borrowed("#893843"), rgb:(137,56,67), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sonicsilver" => crate::Color{ name:std::borrow::Cow::Borrowed("Sonic silver"), hex:std::borrow::Cow::Borrowed("#757575"), rgb:(117,117,117), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spacecadet" => crate::Color{ name:std::borrow::Cow::Borrowed("Space cadet"), hex:std::borrow::Cow::Borrowed("#1D2951"), rgb:(29,41,81), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishbistre" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish bistre"), hex:std::borrow::Cow::Borrowed("#807532"), rgb:(128,117,50), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish blue"), hex:std::borrow::Cow::Borrowed("#0070B8"), rgb:(0,112,184), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishcarmine" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish carmine"), hex:std::borrow::Cow::Borrowed("#D10047"), rgb:(209,0,71), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishgray" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish gray"), hex:std::borrow::Cow::Borrowed("#989898"), rgb:(152,152,152), aliases:&["spanishgrey"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish green"), hex:std::borrow::Cow::Borrowed("#009150"), rgb:(0,145,80), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish orange"), hex:std::borrow::Cow::Borrowed("#E86100"), rgb:(232,97,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish pink"), hex:std::borrow::Cow::Borrowed("#F7BFBE"), rgb:(247,191,190), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishred" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish red"), hex:std::borrow::Cow::Borrowed("#E60026"), rgb:(230,0,38), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish sky blue"), hex:std::borrow::Cow::Borrowed("#00FFFE"), rgb:(0,255,254), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish violet"), hex:std::borrow::Cow::Borrowed("#4C2882"), rgb:(76,40,130), aliases:&["spanishvoilet"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "spanishviridian" => crate::Color{ name:std::borrow::Cow::Borrowed("Spanish viridian"), hex:std::borrow::Cow::Borrowed("#007F5C"), rgb:(0,127,92), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "springbud" => crate::Color{ name:std::borrow::Cow::Borrowed("Spring bud"), hex:std::borrow::Cow::Borrowed("#A7FC00"), rgb:(167,252,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "springfrost" => crate::Color{ name:std::borrow::Cow::Borrowed("Spring Frost"), hex:std::borrow::Cow::Borrowed("#87FF2A"), rgb:(135,255,42), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "springgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Spring green"), hex:std::borrow::Cow::Borrowed("#00FF7F"), rgb:(0,255,127), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "springgreencrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Spring green (Crayola)"), hex:std::borrow::Cow::Borrowed("#ECEBBD"), rgb:(236,235,189), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "starcommandblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Star command blue"), hex:std::borrow::Cow::Borrowed("#007BB8"), rgb:(0,123,184), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "steelblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Steel blue"), hex:std::borrow::Cow::Borrowed("#4682B4"), rgb:(70,130,180), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "steelpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Steel pink"), hex:std::borrow::Cow::Borrowed("#CC33CC"), rgb:(204,51,204), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "stildegrainyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Stil de grain yellow"), hex:std::borrow::Cow::Borrowed("#FADA5E"), rgb:(250,218,94), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "straw" => crate::Color{ name:std::borrow::Cow::Borrowed("Straw"), hex:std::borrow::Cow::Borrowed("#E4D96F"), rgb:(228,217,111), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "strawberry" => crate::Color{ name:std::borrow::Cow::Borrowed("Strawberry"), hex:std::borrow::Cow::Borrowed("#FA5053"), rgb:(250,80,83), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "strawberryblonde" => crate::Color{ name:std::borrow::Cow::Borrowed("Strawberry Blonde"), hex:std::borrow::Cow::Borrowed("#FF9361"), rgb:(255,147,97), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "stronglimegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Strong Lime Green"), hex:std::borrow::Cow::Borrowed("#33CC33"), rgb:(51,204,51), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sugarplum" => crate::Color{ name:std::borrow::Cow::Borrowed("Sugar Plum"), hex:std::borrow::Cow::Borrowed("#914E75"), rgb:(145,78,117), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sunglow" => crate::Color{ name:std::borrow::Cow::Borrowed("Sunglow"), hex:std::borrow::Cow::Borrowed("#FFCC33"), rgb:(255,204,51), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sunray" => crate::Color{ name:std::borrow::Cow::Borrowed("Sunray"), hex:std::borrow::Cow::Borrowed("#E3AB57"), rgb:(227,171,87), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sunset" => crate::Color{ name:std::borrow::Cow::Borrowed("Sunset"), hex:std::borrow::Cow::Borrowed("#FAD6A5"), rgb:(250,214,165), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "superpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Super pink"), hex:std::borrow::Cow::Borrowed("#CF6BA9"), rgb:(207,107,169), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "sweetbrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Sweet Brown"), hex:std::borrow::Cow::Borrowed("#A83731"), rgb:(168,55,49), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "syracuseorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Syracuse Orange"), hex:std::borrow::Cow::Borrowed("#D44500"), rgb:(212,69,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tan" => crate::Color{ name:std::borrow::Cow::Borrowed("Tan"), hex:std::borrow::Cow::Borrowed("#D2B48C"), rgb:(210,180,140), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tancrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Tan (Crayola)"), hex:std::borrow::Cow::Borrowed("#D99A6C"), rgb:(217,154,108), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "tangerine" => crate::Color{ name:std::borrow::Cow::Borrowed("Tangerine"), hex:std::borrow::Cow::Borrowed("#F28500"), rgb:(242,133,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tangopink" => crate::Color{ name:std::borrow::Cow::Borrowed("Tango pink"), hex:std::borrow::Cow::Borrowed("#E4717A"), rgb:(228,113,122), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tartorange" => crate::Color{ name:std::borrow::Cow::Borrowed("Tart Orange"), hex:std::borrow::Cow::Borrowed("#FB4D46"), rgb:(251,77,70), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "taupe" => crate::Color{ name:std::borrow::Cow::Borrowed("Taupe"), hex:std::borrow::Cow::Borrowed("#483C32"), rgb:(72,60,50), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "taupegray" => crate::Color{ name:std::borrow::Cow::Borrowed("Taupe gray"), hex:std::borrow::Cow::Borrowed("#8B8589"), rgb:(139,133,137), aliases:&["taupegrey"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "teagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Tea green"), hex:std::borrow::Cow::Borrowed("#D0F0C0"), rgb:(208,240,192), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tearose" => crate::Color{ name:std::borrow::Cow::Borrowed("Tea rose"), hex:std::borrow::Cow::Borrowed("#F4C2C2"), rgb:(244,194,194), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "teal" => crate::Color{ name:std::borrow::Cow::Borrowed("Teal"), hex:std::borrow::Cow::Borrowed("#008080"), rgb:(0,128,128), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tealblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Teal blue"), hex:std::borrow::Cow::Borrowed("#367588"), rgb:(54,117,136), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "technobotanica" => crate::Color{ name:std::borrow::Cow::Borrowed("Technobotanica"), hex:std::borrow::Cow::Borrowed("#00FFBF"), rgb:(0,255,191), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "telemagenta" => crate::Color{ name:std::borrow::Cow::Borrowed("Telemagenta"), hex:std::borrow::Cow::Borrowed("#CF3476"), rgb:(207,52,118), aliases:&["telemegenta"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tennetawny" => crate::Color{ name:std::borrow::Cow::Borrowed("Tenné(tawny)"), hex:std::borrow::Cow::Borrowed("#CD5700"), rgb:(205,87,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("tawny") } },
+    "terracotta" => crate::Color{ name:std::borrow::Cow::Borrowed("Terra cotta"), hex:std::borrow::Cow::Borrowed("#E2725B"), rgb:(226,114,91), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "thistle" => crate::Color{ name:std::borrow::Cow::Borrowed("Thistle"), hex:std::borrow::Cow::Borrowed("#D8BFD8"), rgb:(216,191,216), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "thulianpink" => crate::Color{ name:std::borrow::Cow::Borrowed("Thulian pink"), hex:std::borrow::Cow::Borrowed("#DE6FA1"), rgb:(222,111,161), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "ticklemepink" => crate::Color{ name:std::borrow::Cow::Borrowed("Tickle Me Pink"), hex:std::borrow::Cow::Borrowed("#FC89AC"), rgb:(252,137,172), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tiffanyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Tiffany Blue"), hex:std::borrow::Cow::Borrowed("#0ABAB5"), rgb:(10,186,181), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "timberwolf" => crate::Color{ name:std::borrow::Cow::Borrowed("Timberwolf"), hex:std::borrow::Cow::Borrowed("#DBD7D2"), rgb:(219,215,210), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "titaniumyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Titanium yellow"), hex:std::borrow::Cow::Borrowed("#EEE600"), rgb:(238,230,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tomato" => crate::Color{ name:std::borrow::Cow::Borrowed("Tomato"), hex:std::borrow::Cow::Borrowed("#FF6347"), rgb:(255,99,71), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tourmaline" => crate::Color{ name:std::borrow::Cow::Borrowed("Tourmaline"), hex:std::borrow::Cow::Borrowed("#86A1A9"), rgb:(134,161,169), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tropicalrainforest" => crate::Color{ name:std::borrow::Cow::Borrowed("Tropical rainforest"), hex:std::borrow::Cow::Borrowed("#00755E"), rgb:(0,117,94), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "trueblue" => crate::Color{ name:std::borrow::Cow::Borrowed("True Blue"), hex:std::borrow::Cow::Borrowed("#2D68C4"), rgb:(45,104,196), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "trypanblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Trypan Blue"), hex:std::borrow::Cow::Borrowed("#1C05B3"), rgb:(28,5,179), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tuftsblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Tufts blue"), hex:std::borrow::Cow::Borrowed("#3E8EDE"), rgb:(62,142,222), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tumbleweed" => crate::Color{ name:std::borrow::Cow::Borrowed("Tumbleweed"), hex:std::borrow::Cow::Borrowed("#DEAA88"), rgb:(222,170,136), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "turquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("Turquoise"), hex:std::borrow::Cow::Borrowed("#40E0D0"), rgb:(64,224,208), aliases:&["turqoise", "turquise"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "turquoiseblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Turquoise blue"), hex:std::borrow::Cow::Borrowed("#00FFEF"), rgb:(0,255,239), aliases:&["turqoiseblue", "turquiseblue"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "turquoisegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Turquoise green"), hex:std::borrow::Cow::Borrowed("#A0D6B4"), rgb:(160,214,180), aliases:&["turqoisegreen", "turquisegreen"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "turtlegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Turtle green"), hex:std::borrow::Cow::Borrowed("#8A9A5B"), rgb:(138,154,91), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tuscan" => crate::Color{ name:std::borrow::Cow::Borrowed("Tuscan"), hex:std::borrow::Cow::Borrowed("#FAD6A5"), rgb:(250,214,165), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tuscanbrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Tuscan brown"), hex:std::borrow::Cow::Borrowed("#6F4E37"), rgb:(111,78,55), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tuscanred" => crate::Color{ name:std::borrow::Cow::Borrowed("Tuscan red"), hex:std::borrow::Cow::Borrowed("#7C4848"), rgb:(124,72,72), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tuscantan" => crate::Color{ name:std::borrow::Cow::Borrowed("Tuscan tan"), hex:std::borrow::Cow::Borrowed("#A67B5B"), rgb:(166,123,91), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tuscany" => crate::Color{ name:std::borrow::Cow::Borrowed("Tuscany"), hex:std::borrow::Cow::Borrowed("#C09999"), rgb:(192,153,153), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "twilightlavender" => crate::Color{ name:std::borrow::Cow::Borrowed("Twilight lavender"), hex:std::borrow::Cow::Borrowed("#8A496B"), rgb:(138,73,107), aliases:&["twilightlavendar"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "tyrianpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("Tyrian purple"), hex:std::borrow::Cow::Borrowed("#66023C"), rgb:(102,2,60), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "uablue" => crate::Color{ name:std::borrow::Cow::Borrowed("UA blue"), hex:std::borrow::Cow::Borrowed("#0033AA"), rgb:(0,51,170), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "uared" => crate::Color{ name:std::borrow::Cow::Borrowed("UA red"), hex:std::borrow::Cow::Borrowed("#D9004C"), rgb:(217,0,76), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "ultramarine" => crate::Color{ name:std::borrow::Cow::Borrowed("Ultramarine"), hex:std::borrow::Cow::Borrowed("#3F00FF"), rgb:(63,0,255), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "ultramarineblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Ultramarine blue"), hex:std::borrow::Cow::Borrowed("#4166F5"), rgb:(65,102,245), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "ultrapink" => crate::Color{ name:std::borrow::Cow::Borrowed("Ultra pink"), hex:std::borrow::Cow::Borrowed("#FF6FFF"), rgb:(255,111,255), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "ultrared" => crate::Color{ name:std::borrow::Cow::Borrowed("Ultra red"), hex:std::borrow::Cow::Borrowed("#FC6C85"), rgb:(252,108,133), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "umber" => crate::Color{ name:std::borrow::Cow::Borrowed("Umber"), hex:std::borrow::Cow::Borrowed("#635147"), rgb:(99,81,71), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "unbleachedsilk" => crate::Color{ name:std::borrow::Cow::Borrowed("Unbleached silk"), hex:std::borrow::Cow::Borrowed("#FFDDCA"), rgb:(255,221,202), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "unitednationsblue" => crate::Color{ name:std::borrow::Cow::Borrowed("United Nations blue"), hex:std::borrow::Cow::Borrowed("#009EDB"), rgb:(0,158,219), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "universityofpennsylvaniared" => crate::Color{ name:std::borrow::Cow::Borrowed("University of Pennsylvania red"), hex:std::borrow::Cow::Borrowed("#A50021"), rgb:(165,0,33), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "unmellowyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Unmellow yellow"), hex:std::borrow::Cow::Borrowed("#FFFF66"), rgb:(255,255,102), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "upforestgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("UP Forest green"), hex:std::borrow::Cow::Borrowed("#014421"), rgb:(1,68,33), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "upmaroon" => crate::Color{ name:std::borrow::Cow::Borrowed("UP maroon"), hex:std::borrow::Cow::Borrowed("#7B1113"), rgb:(123,17,19), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "upsdellred" => crate::Color{ name:std::borrow::Cow::Borrowed("Upsdell red"), hex:std::borrow::Cow::Borrowed("#AE2029"), rgb:(174,32,41), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "uranianblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Uranian blue"), hex:std::borrow::Cow::Borrowed("#AFDBF5"), rgb:(175,219,245), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "usafablue" => crate::Color{ name:std::borrow::Cow::Borrowed("USAFA blue"), hex:std::borrow::Cow::Borrowed("#004F98"), rgb:(0,79,152), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vandykebrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Van Dyke brown"), hex:std::borrow::Cow::Borrowed("#664228"), rgb:(102,66,40), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vanilla" => crate::Color{ name:std::borrow::Cow::Borrowed("Vanilla"), hex:std::borrow::Cow::Borrowed("#F3E5AB"), rgb:(243,229,171), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vanillaice" => crate::Color{ name:std::borrow::Cow::Borrowed("Vanilla ice"), hex:std::borrow::Cow::Borrowed("#F38FA9"), rgb:(243,143,169), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vantgblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Vantg blue"), hex:std::borrow::Cow::Borrowed("#5271FF"), rgb:(82,113,255), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vegasgold" => crate::Color{ name:std::borrow::Cow::Borrowed("Vegas gold"), hex:std::borrow::Cow::Borrowed("#C5B358"), rgb:(197,179,88), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "venetianred" => crate::Color{ name:std::borrow::Cow::Borrowed("Venetian red"), hex:std::borrow::Cow::Borrowed("#C80815"), rgb:(200,8,21), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "verdigris" => crate::Color{ name:std::borrow::Cow::Borrowed("Verdigris"), hex:std::borrow::Cow::Borrowed("#43B3AE"), rgb:(67,179,174), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vermilion" => crate::Color{ name:std::borrow::Cow::Borrowed("Vermilion"), hex:std::borrow::Cow::Borrowed("#E34234"), rgb:(227,66,52), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vermilion2" => crate::Color{ name:std::borrow::Cow::Borrowed("Vermilion"), hex:std::borrow::Cow::Borrowed("#D9381E"), rgb:(217,56,30), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "veronica" => crate::Color{ name:std::borrow::Cow::Borrowed("Veronica"), hex:std::borrow::Cow::Borrowed("#A020F0"), rgb:(160,32,240), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "violet" => crate::Color{ name:std::borrow::Cow::Borrowed("Violet"), hex:std::borrow::Cow::Borrowed("#8F00FF"), rgb:(143,0,255), aliases:&["voilet"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "violetcolorwheel" => crate::Color{ name:std::borrow::Cow::Borrowed("Violet (color wheel)"), hex:std::borrow::Cow::Borrowed("#7F00FF"), rgb:(127,0,255), aliases:&["voiletcolorwheel"], source:crate::Source{ list:"List of colors: N–Z", variant:Some("color wheel") } },
+    "violetcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Violet (crayola)"), hex:std::borrow::Cow::Borrowed("#963D7F"), rgb:(150,61,127), aliases:&["voiletcrayola"], source:crate::Source{ list:"List of colors: N–Z", variant:Some("crayola") } },
+    "violetryb" => crate::Color{ name:std::borrow::Cow::Borrowed("Violet (RYB)"), hex:std::borrow::Cow::Borrowed("#8601AF"), rgb:(134,1,175), aliases:&["voiletryb"], source:crate::Source{ list:"List of colors: N–Z", variant:Some("RYB") } },
+    "violetweb" => crate::Color{ name:std::borrow::Cow::Borrowed("Violet (web)"), hex:std::borrow::Cow::Borrowed("#EE82EE"), rgb:(238,130,238), aliases:&["voiletweb"], source:crate::Source{ list:"List of colors: N–Z", variant:Some("web") } },
+    "violetblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Violet-blue"), hex:std::borrow::Cow::Borrowed("#324AB2"), rgb:(50,74,178), aliases:&["voiletblue"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "violetbluecrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Violet-blue (Crayola)"), hex:std::borrow::Cow::Borrowed("#766EC8"), rgb:(118,110,200), aliases:&["voiletbluecrayola"], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "violetred" => crate::Color{ name:std::borrow::Cow::Borrowed("Violet-red"), hex:std::borrow::Cow::Borrowed("#F75394"), rgb:(247,83,148), aliases:&["voiletred"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "violetredperbang" => crate::Color{ name:std::borrow::Cow::Borrowed("Violet-red(PerBang)"), hex:std::borrow::Cow::Borrowed("#F0599C"), rgb:(240,89,156), aliases:&["voiletredperbang"], source:crate::Source{ list:"List of colors: N–Z", variant:Some("PerBang") } },
+    "viridian" => crate::Color{ name:std::borrow::Cow::Borrowed("Viridian"), hex:std::borrow::Cow::Borrowed("#40826D"), rgb:(64,130,109), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "viridiangreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Viridian green"), hex:std::borrow::Cow::Borrowed("#009698"), rgb:(0,150,152), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vividburgundy" => crate::Color{ name:std::borrow::Cow::Borrowed("Vivid burgundy"), hex:std::borrow::Cow::Borrowed("#9F1D35"), rgb:(159,29,53), aliases:&["vividburgandy"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vividskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Vivid sky blue"), hex:std::borrow::Cow::Borrowed("#00CCFF"), rgb:(0,204,255), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vividtangerine" => crate::Color{ name:std::borrow::Cow::Borrowed("Vivid tangerine"), hex:std::borrow::Cow::Borrowed("#FFA089"), rgb:(255,160,137), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "vividviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("Vivid violet"), hex:std::borrow::Cow::Borrowed("#9F00FF"), rgb:(159,0,255), aliases:&["vividvoilet"], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "volt" => crate::Color{ name:std::borrow::Cow::Borrowed("Volt"), hex:std::borrow::Cow::Borrowed("#CEFF00"), rgb:(206,255,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "warmblack" => crate::Color{ name:std::borrow::Cow::Borrowed("Warm black"), hex:std::borrow::Cow::Borrowed("#004242"), rgb:(0,66,66), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "weezyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Weezy Blue"), hex:std::borrow::Cow::Borrowed("#189BCC"), rgb:(24,155,204), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "wheat" => crate::Color{ name:std::borrow::Cow::Borrowed("Wheat"), hex:std::borrow::Cow::Borrowed("#F5DEB3"), rgb:(245,222,179), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "white" => crate::Color{ name:std::borrow::Cow::Borrowed("White"), hex:std::borrow::Cow::Borrowed("#FFFFFF"), rgb:(255,255,255), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "wildblueyonder" => crate::Color{ name:std::borrow::Cow::Borrowed("Wild blue yonder"), hex:std::borrow::Cow::Borrowed("#A2ADD0"), rgb:(162,173,208), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "wildorchid" => crate::Color{ name:std::borrow::Cow::Borrowed("Wild orchid"), hex:std::borrow::Cow::Borrowed("#D470A2"), rgb:(212,112,162), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "wildstrawberry" => crate::Color{ name:std::borrow::Cow::Borrowed("Wild Strawberry"), hex:std::borrow::Cow::Borrowed("#FF43A4"), rgb:(255,67,164), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "wildwatermelon" => crate::Color{ name:std::borrow::Cow::Borrowed("Wild watermelon"), hex:std::borrow::Cow::Borrowed("#FC6C85"), rgb:(252,108,133), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "windsortan" => crate::Color{ name:std::borrow::Cow::Borrowed("Windsor tan"), hex:std::borrow::Cow::Borrowed("#A75502"), rgb:(167,85,2), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "wine" => crate::Color{ name:std::borrow::Cow::Borrowed("Wine"), hex:std::borrow::Cow::Borrowed("#722F37"), rgb:(114,47,55), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "winedregs" => crate::Color{ name:std::borrow::Cow::Borrowed("Wine dregs"), hex:std::borrow::Cow::Borrowed("#673147"), rgb:(103,49,71), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "wintersky" => crate::Color{ name:std::borrow::Cow::Borrowed("Winter Sky"), hex:std::borrow::Cow::Borrowed("#FF007C"), rgb:(255,0,124), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "wintergreendream" => crate::Color{ name:std::borrow::Cow::Borrowed("Wintergreen Dream"), hex:std::borrow::Cow::Borrowed("#56887D"), rgb:(86,136,125), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "wisteria" => crate::Color{ name:std::borrow::Cow::Borrowed("Wisteria"), hex:std::borrow::Cow::Borrowed("#C9A0DC"), rgb:(201,160,220), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "woodbrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Wood brown"), hex:std::borrow::Cow::Borrowed("#C19A6B"), rgb:(193,154,107), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "xanadu" => crate::Color{ name:std::borrow::Cow::Borrowed("Xanadu"), hex:std::borrow::Cow::Borrowed("#738678"), rgb:(115,134,120), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "xanthic" => crate::Color{ name:std::borrow::Cow::Borrowed("Xanthic"), hex:std::borrow::Cow::Borrowed("#EEED09"), rgb:(238,237,9), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "xanthous" => crate::Color{ name:std::borrow::Cow::Borrowed("Xanthous"), hex:std::borrow::Cow::Borrowed("#F1B42F"), rgb:(241,180,47), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "yaleblue" => crate::Color{ name:std::borrow::Cow::Borrowed("Yale Blue"), hex:std::borrow::Cow::Borrowed("#00356B"), rgb:(0,53,107), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "yellow" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow"), hex:std::borrow::Cow::Borrowed("#FFFF00"), rgb:(255,255,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "yellowcrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow (Crayola)"), hex:std::borrow::Cow::Borrowed("#FCE883"), rgb:(252,232,131), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "yellowmunsell" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow (Munsell)"), hex:std::borrow::Cow::Borrowed("#EFCC00"), rgb:(239,204,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Munsell") } },
+    "yellowncs" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow (NCS)"), hex:std::borrow::Cow::Borrowed("#FFD300"), rgb:(255,211,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("NCS") } },
+    "yellowpantone" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow (Pantone)"), hex:std::borrow::Cow::Borrowed("#FEDF00"), rgb:(254,223,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Pantone") } },
+    "yellowprocess" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow (process)"), hex:std::borrow::Cow::Borrowed("#FFEF00"), rgb:(255,239,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("process") } },
+    "yellowryb" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow (RYB)"), hex:std::borrow::Cow::Borrowed("#FEFE33"), rgb:(254,254,51), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("RYB") } },
+    "yellowgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow-green"), hex:std::borrow::Cow::Borrowed("#9ACD32"), rgb:(154,205,50), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "yellowgreencrayola" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow-green (Crayola)"), hex:std::borrow::Cow::Borrowed("#C5E384"), rgb:(197,227,132), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Crayola") } },
+    "yellowgreencolorwheel" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow-green (Color Wheel)"), hex:std::borrow::Cow::Borrowed("#30B21A"), rgb:(48,178,26), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Color Wheel") } },
+    "yelloworange" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow Orange"), hex:std::borrow::Cow::Borrowed("#FFAE42"), rgb:(255,174,66), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "yelloworangecolorwheel" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow Orange (Color Wheel)"), hex:std::borrow::Cow::Borrowed("#FF9505"), rgb:(255,149,5), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:Some("Color Wheel") } },
+    "yellowsunshine" => crate::Color{ name:std::borrow::Cow::Borrowed("Yellow Sunshine"), hex:std::borrow::Cow::Borrowed("#FFF700"), rgb:(255,247,0), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "yinmnblue" => crate::Color{ name:std::borrow::Cow::Borrowed("YInMn Blue"), hex:std::borrow::Cow::Borrowed("#2E5090"), rgb:(46,80,144), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "zaffre" => crate::Color{ name:std::borrow::Cow::Borrowed("Zaffre"), hex:std::borrow::Cow::Borrowed("#0014A8"), rgb:(0,20,168), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "zinnwalditebrown" => crate::Color{ name:std::borrow::Cow::Borrowed("Zinnwaldite brown"), hex:std::borrow::Cow::Borrowed("#2C1608"), rgb:(44,22,8), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+    "zomp" => crate::Color{ name:std::borrow::Cow::Borrowed("Zomp"), hex:std::borrow::Cow::Borrowed("#39A78E"), rgb:(57,167,142), aliases:&[], source:crate::Source{ list:"List of colors: N–Z", variant:None } },
+};
+
+/// Alternate spellings of `COLORS` keys, mapped to the key they stand for.
+pub(crate) static COLORS_ALIASES: phf::Map<&'static str, &'static str> = phf::phf_map! {
+    "africanvoilet" => "africanviolet",
+    "antiquefuschia" => "antiquefuchsia",
+    "antiquefushia" => "antiquefuchsia",
+    "ashgrey" => "ashgray",
+    "battleshipgray" => "battleshipgrey",
+    "bluegreycrayola" => "bluegraycrayola",
+    "bluevoilet" => "blueviolet",
+    "burgandy" => "burgundy",
+    "cadetgray" => "cadetgrey",
+    "chinesevoilet" => "chineseviolet",
+    "coolgray" => "coolgrey",
+    "dkbrown" => "darkbrown",
+    "dkbyzantium" => "darkbyzantium",
+    "dkcyan" => "darkcyan",
+    "dkelectricblue" => "darkelectricblue",
+    "dkgoldenrod" => "darkgoldenrod",
+    "dkgreenx11" => "darkgreenx11",
+    "dkjunglegreen" => "darkjunglegreen",
+    "dkkhaki" => "darkkhaki",
+    "dklava" => "darklava",
+    "dkliverhorses" => "darkliverhorses",
+    "darkmegenta" => "darkmagenta",
+    "dkmagenta" => "darkmagenta",
+    "dkmegenta" => "darkmagenta",
+    "dkolivegreen" => "darkolivegreen",
+    "dkorange" => "darkorange",
+    "dkorchid" => "darkorchid",
+    "dkpurple" => "darkpurple",
+    "dkred" => "darkred",
+    "dksalmon" => "darksalmon",
+    "dkseagreen" => "darkseagreen",
+    "dksienna" => "darksienna",
+    "dkskyblue" => "darkskyblue",
+    "dkslateblue" => "darkslateblue",
+    "darkslategrey" => "darkslategray",
+    "dkslategray" => "darkslategray",
+    "dkslategrey" => "darkslategray",
+    "dkspringgreen" => "darkspringgreen",
+    "darkturqoise" => "darkturquoise",
+    "darkturquise" => "darkturquoise",
+    "dkturquoise" => "darkturquoise",
+    "dkturqoise" => "darkturquoise",
+    "dkturquise" => "darkturquoise",
+    "darkvoilet" => "darkviolet",
+    "dkviolet" => "darkviolet",
+    "dkvoilet" => "darkviolet",
+    "davysgray" => "davysgrey",
+    "dimgrey" => "dimgray",
+    "electricvoilet" => "electricviolet",
+    "englishlavendar" => "englishlavender",
+    "englishvoilet" => "englishviolet",
+    "frenchfuschia" => "frenchfuchsia",
+    "frenchfushia" => "frenchfuchsia",
+    "frenchvoilet" => "frenchviolet",
+    "fuschia" => "fuchsia",
+    "fushia" => "fuchsia",
+    "fuschiacrayola" => "fuchsiacrayola",
+    "fushiacrayola" => "fuchsiacrayola",
+    "granitegrey" => "granitegray",
+    "greyweb" => "grayweb",
+    "greyx11grey" => "grayx11gray",
+    "heliotropegrey" => "heliotropegray",
+    "hotmegentabrokenanchor" => "hotmagentabrokenanchor",
+    "japanesevoilet" => "japaneseviolet",
+    "languidlavendar" => "languidlavender",
+    "lavendarfloral" => "lavenderfloral",
+    "lavendarweb" => "lavenderweb",
+    "lavendarblue" => "lavenderblue",
+    "lavendarblush" => "lavenderblush",
+    "lavendargray" => "lavendergray",
+    "lavendergrey" => "lavendergray",
+    "lavendargrey" => "lavendergray",
+    "ltblue" => "lightblue",
+    "ltcoral" => "lightcoral",
+    "ltcornflowerblue" => "lightcornflowerblue",
+    "ltcyan" => "lightcyan",
+    "ltfrenchbeige" => "lightfrenchbeige",
+    "ltgoldenrodyellow" => "lightgoldenrodyellow",
+    "lightgrey" => "lightgray",
+    "ltgray" => "lightgray",
+    "ltgrey" => "lightgray",
+    "ltgreen" => "lightgreen",
+    "ltorange" => "lightorange",
+    "ltperiwinkle" => "lightperiwinkle",
+    "ltpink" => "lightpink",
+    "ltpurple" => "lightpurple",
+    "ltsalmon" => "lightsalmon",
+    "ltseagreen" => "lightseagreen",
+    "ltskyblue" => "lightskyblue",
+    "lightslategrey" => "lightslategray",
+    "ltslategray" => "lightslategray",
+    "ltslategrey" => "lightslategray",
+    "ltsteelblue" => "lightsteelblue",
+    "ltyellow" => "lightyellow",
+    "megenta" => "magenta",
+    "megentacrayolabrokenanchor" => "magentacrayolabrokenanchor",
+    "megentadye" => "magentadye",
+    "megentapantone" => "magentapantone",
+    "megentaprocess" => "magentaprocess",
+    "megentahaze" => "magentahaze",
+    "medaquamarine" => "mediumaquamarine",
+    "medblue" => "mediumblue",
+    "medcandyapplered" => "mediumcandyapplered",
+    "medcarmine" => "mediumcarmine",
+    "medchampagne" => "mediumchampagne",
+    "medorchid" => "mediumorchid",
+    "medpurple" => "mediumpurple",
+    "medseagreen" => "mediumseagreen",
+    "medslateblue" => "mediumslateblue",
+    "medspringgreen" => "mediumspringgreen",
+    "mediumturqoise" => "mediumturquoise",
+    "mediumturquise" => "mediumturquoise",
+    "medturquoise" => "mediumturquoise",
+    "medturqoise" => "mediumturquoise",
+    "medturquise" => "mediumturquoise",
+    "mediumvoiletred" => "mediumvioletred",
+    "medvioletred" => "mediumvioletred",
+    "medvoiletred" => "mediumvioletred",
+    "middlegray" => "middlegrey",
+    "neonfuschia" => "neonfuchsia",
+    "neonfushia" => "neonfuchsia",
+    "oldburgandy" => "oldburgundy",
+    "oldlavendar" => "oldlavender",
+    "paynesgray" => "paynesgrey",
+    "pinklavendar" => "pinklavender",
+    "quinacridonemegenta" => "quinacridonemagenta",
+    "redvoilet" => "redviolet",
+    "redvoiletcrayola" => "redvioletcrayola",
+    "redvoiletcolorwheel" => "redvioletcolorwheel",
+    "russianvoilet" => "russianviolet",
+    "skymegenta" => "skymagenta",
+    "slategrey" => "slategray",
+    "spanishgrey" => "spanishgray",
+    "spanishvoilet" => "spanishviolet",
+    "taupegrey" => "taupegray",
+    "telemegenta" => "telemagenta",
+    "turqoise" => "turquoise",
+    "turquise" => "turquoise",
+    "turqoiseblue" => "turquoiseblue",
+    "turquiseblue" => "turquoiseblue",
+    "turqoisegreen" => "turquoisegreen",
+    "turquisegreen" => "turquoisegreen",
+    "twilightlavendar" => "twilightlavender",
+    "voilet" => "violet",
+    "voiletcolorwheel" => "violetcolorwheel",
+    "voiletcrayola" => "violetcrayola",
+    "voiletryb" => "violetryb",
+    "voiletweb" => "violetweb",
+    "voiletblue" => "violetblue",
+    "voiletbluecrayola" => "violetbluecrayola",
+    "voiletred" => "violetred",
+    "voiletredperbang" => "violetredperbang",
+    "vividburgandy" => "vividburgundy",
+    "vividvoilet" => "vividviolet",
+};
@@ -0,0 +1,226 @@
+//  ***  AUTO-GENERATED  – DO NOT EDIT BY HAND  ***
+
+/// CSS Color Module Level 4 named colors.
+pub static CSS_COLORS: phf::Map<&'static str, crate::Color> = phf::phf_map! {
+    "aliceblue" => crate::Color{ name:std::borrow::Cow::Borrowed("aliceblue"), hex:std::borrow::Cow::Borrowed("#F0F8FF"), rgb:(240,248,255), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "antiquewhite" => crate::Color{ name:std::borrow::Cow::Borrowed("antiquewhite"), hex:std::borrow::Cow::Borrowed("#FAEBD7"), rgb:(250,235,215), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "aqua" => crate::Color{ name:std::borrow::Cow::Borrowed("aqua"), hex:std::borrow::Cow::Borrowed("#00FFFF"), rgb:(0,255,255), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "aquamarine" => crate::Color{ name:std::borrow::Cow::Borrowed("aquamarine"), hex:std::borrow::Cow::Borrowed("#7FFFD4"), rgb:(127,255,212), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "azure" => crate::Color{ name:std::borrow::Cow::Borrowed("azure"), hex:std::borrow::Cow::Borrowed("#F0FFFF"), rgb:(240,255,255), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "beige" => crate::Color{ name:std::borrow::Cow::Borrowed("beige"), hex:std::borrow::Cow::Borrowed("#F5F5DC"), rgb:(245,245,220), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "bisque" => crate::Color{ name:std::borrow::Cow::Borrowed("bisque"), hex:std::borrow::Cow::Borrowed("#FFE4C4"), rgb:(255,228,196), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "black" => crate::Color{ name:std::borrow::Cow::Borrowed("black"), hex:std::borrow::Cow::Borrowed("#000000"), rgb:(0,0,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "blanchedalmond" => crate::Color{ name:std::borrow::Cow::Borrowed("blanchedalmond"), hex:std::borrow::Cow::Borrowed("#FFEBCD"), rgb:(255,235,205), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "blue" => crate::Color{ name:std::borrow::Cow::Borrowed("blue"), hex:std::borrow::Cow::Borrowed("#0000FF"), rgb:(0,0,255), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "blueviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("blueviolet"), hex:std::borrow::Cow::Borrowed("#8A2BE2"), rgb:(138,43,226), aliases:&["bluevoilet"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "brown" => crate::Color{ name:std::borrow::Cow::Borrowed("brown"), hex:std::borrow::Cow::Borrowed("#A52A2A"), rgb:(165,42,42), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "burlywood" => crate::Color{ name:std::borrow::Cow::Borrowed("burlywood"), hex:std::borrow::Cow::Borrowed("#DEB887"), rgb:(222,184,135), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "cadetblue" => crate::Color{ name:std::borrow::Cow::Borrowed("cadetblue"), hex:std::borrow::Cow::Borrowed("#5F9EA0"), rgb:(95,158,160), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "chartreuse" => crate::Color{ name:std::borrow::Cow::Borrowed("chartreuse"), hex:std::borrow::Cow::Borrowed("#7FFF00"), rgb:(127,255,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "chocolate" => crate::Color{ name:std::borrow::Cow::Borrowed("chocolate"), hex:std::borrow::Cow::Borrowed("#D2691E"), rgb:(210,105,30), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "coral" => crate::Color{ name:std::borrow::Cow::Borrowed("coral"), hex:std::borrow::Cow::Borrowed("#FF7F50"), rgb:(255,127,80), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "cornflowerblue" => crate::Color{ name:std::borrow::Cow::Borrowed("cornflowerblue"), hex:std::borrow::Cow::Borrowed("#6495ED"), rgb:(100,149,237), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "cornsilk" => crate::Color{ name:std::borrow::Cow::Borrowed("cornsilk"), hex:std::borrow::Cow::Borrowed("#FFF8DC"), rgb:(255,248,220), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "crimson" => crate::Color{ name:std::borrow::Cow::Borrowed("crimson"), hex:std::borrow::Cow::Borrowed("#DC143C"), rgb:(220,20,60), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "cyan" => crate::Color{ name:std::borrow::Cow::Borrowed("cyan"), hex:std::borrow::Cow::Borrowed("#00FFFF"), rgb:(0,255,255), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkblue" => crate::Color{ name:std::borrow::Cow::Borrowed("darkblue"), hex:std::borrow::Cow::Borrowed("#00008B"), rgb:(0,0,139), aliases:&["dkblue"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkcyan" => crate::Color{ name:std::borrow::Cow::Borrowed("darkcyan"), hex:std::borrow::Cow::Borrowed("#008B8B"), rgb:(0,139,139), aliases:&["dkcyan"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkgoldenrod" => crate::Color{ name:std::borrow::Cow::Borrowed("darkgoldenrod"), hex:std::borrow::Cow::Borrowed("#B8860B"), rgb:(184,134,11), aliases:&["dkgoldenrod"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkgray" => crate::Color{ name:std::borrow::Cow::Borrowed("darkgray"), hex:std::borrow::Cow::Borrowed("#A9A9A9"), rgb:(169,169,169), aliases:&["dkgray", "dkgrey"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("darkgreen"), hex:std::borrow::Cow::Borrowed("#006400"), rgb:(0,100,0), aliases:&["dkgreen"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("darkgrey"), hex:std::borrow::Cow::Borrowed("#A9A9A9"), rgb:(169,169,169), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkkhaki" => crate::Color{ name:std::borrow::Cow::Borrowed("darkkhaki"), hex:std::borrow::Cow::Borrowed("#BDB76B"), rgb:(189,183,107), aliases:&["dkkhaki"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkmagenta" => crate::Color{ name:std::borrow::Cow::Borrowed("darkmagenta"), hex:std::borrow::Cow::Borrowed("#8B008B"), rgb:(139,0,139), aliases:&["darkmegenta", "dkmagenta", "dkmegenta"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkolivegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("darkolivegreen"), hex:std::borrow::Cow::Borrowed("#556B2F"), rgb:(85,107,47), aliases:&["dkolivegreen"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkorange" => crate::Color{ name:std::borrow::Cow::Borrowed("darkorange"), hex:std::borrow::Cow::Borrowed("#FF8C00"), rgb:(255,140,0), aliases:&["dkorange"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkorchid" => crate::Color{ name:std::borrow::Cow::Borrowed("darkorchid"), hex:std::borrow::Cow::Borrowed("#9932CC"), rgb:(153,50,204), aliases:&["dkorchid"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkred" => crate::Color{ name:std::borrow::Cow::Borrowed("darkred"), hex:std::borrow::Cow::Borrowed("#8B0000"), rgb:(139,0,0), aliases:&["dkred"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darksalmon" => crate::Color{ name:std::borrow::Cow::Borrowed("darksalmon"), hex:std::borrow::Cow::Borrowed("#E9967A"), rgb:(233,150,122), aliases:&["dksalmon"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkseagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("darkseagreen"), hex:std::borrow::Cow::Borrowed("#8FBC8F"), rgb:(143,188,143), aliases:&["dkseagreen"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkslateblue" => crate::Color{ name:std::borrow::Cow::Borrowed("darkslateblue"), hex:std::borrow::Cow::Borrowed("#483D8B"), rgb:(72,61,139), aliases:&["dkslateblue"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkslategray" => crate::Color{ name:std::borrow::Cow::Borrowed("darkslategray"), hex:std::borrow::Cow::Borrowed("#2F4F4F"), rgb:(47,79,79), aliases:&["dkslategray", "dkslategrey"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkslategrey" => crate::Color{ name:std::borrow::Cow::Borrowed("darkslategrey"), hex:std::borrow::Cow::Borrowed("#2F4F4F"), rgb:(47,79,79), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkturquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("darkturquoise"), hex:std::borrow::Cow::Borrowed("#00CED1"), rgb:(0,206,209), aliases:&["darkturqoise", "darkturquise", "dkturquoise", "dkturqoise", "dkturquise"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "darkviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("darkviolet"), hex:std::borrow::Cow::Borrowed("#9400D3"), rgb:(148,0,211), aliases:&["darkvoilet", "dkviolet", "dkvoilet"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "deeppink" => crate::Color{ name:std::borrow::Cow::Borrowed("deeppink"), hex:std::borrow::Cow::Borrowed("#FF1493"), rgb:(255,20,147), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "deepskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("deepskyblue"), hex:std::borrow::Cow::Borrowed("#00BFFF"), rgb:(0,191,255), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "dimgray" => crate::Color{ name:std::borrow::Cow::Borrowed("dimgray"), hex:std::borrow::Cow::Borrowed("#696969"), rgb:(105,105,105), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "dimgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("dimgrey"), hex:std::borrow::Cow::Borrowed("#696969"), rgb:(105,105,105), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "dodgerblue" => crate::Color{ name:std::borrow::Cow::Borrowed("dodgerblue"), hex:std::borrow::Cow::Borrowed("#1E90FF"), rgb:(30,144,255), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "firebrick" => crate::Color{ name:std::borrow::Cow::Borrowed("firebrick"), hex:std::borrow::Cow::Borrowed("#B22222"), rgb:(178,34,34), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "floralwhite" => crate::Color{ name:std::borrow::Cow::Borrowed("floralwhite"), hex:std::borrow::Cow::Borrowed("#FFFAF0"), rgb:(255,250,240), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "forestgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("forestgreen"), hex:std::borrow::Cow::Borrowed("#228B22"), rgb:(34,139,34), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "fuchsia" => crate::Color{ name:std::borrow::Cow::Borrowed("fuchsia"), hex:std::borrow::Cow::Borrowed("#FF00FF"), rgb:(255,0,255), aliases:&["fuschia", "fushia"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "gainsboro" => crate::Color{ name:std::borrow::Cow::Borrowed("gainsboro"), hex:std::borrow::Cow::Borrowed("#DCDCDC"), rgb:(220,220,220), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "ghostwhite" => crate::Color{ name:std::borrow::Cow::Borrowed("ghostwhite"), hex:std::borrow::Cow::Borrowed("#F8F8FF"), rgb:(248,248,255), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "gold" => crate::Color{ name:std::borrow::Cow::Borrowed("gold"), hex:std::borrow::Cow::Borrowed("#FFD700"), rgb:(255,215,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "goldenrod" => crate::Color{ name:std::borrow::Cow::Borrowed("goldenrod"), hex:std::borrow::Cow::Borrowed("#DAA520"), rgb:(218,165,32), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "gray" => crate::Color{ name:std::borrow::Cow::Borrowed("gray"), hex:std::borrow::Cow::Borrowed("#808080"), rgb:(128,128,128), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "green" => crate::Color{ name:std::borrow::Cow::Borrowed("green"), hex:std::borrow::Cow::Borrowed("#008000"), rgb:(0,128,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "greenyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("greenyellow"), hex:std::borrow::Cow::Borrowed("#ADFF2F"), rgb:(173,255,47), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "grey" => crate::Color{ name:std::borrow::Cow::Borrowed("grey"), hex:std::borrow::Cow::Borrowed("#808080"), rgb:(128,128,128), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "honeydew" => crate::Color{ name:std::borrow::Cow::Borrowed("honeydew"), hex:std::borrow::Cow::Borrowed("#F0FFF0"), rgb:(240,255,240), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "hotpink" => crate::Color{ name:std::borrow::Cow::Borrowed("hotpink"), hex:std::borrow::Cow::Borrowed("#FF69B4"), rgb:(255,105,180), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "indianred" => crate::Color{ name:std::borrow::Cow::Borrowed("indianred"), hex:std::borrow::Cow::Borrowed("#CD5C5C"), rgb:(205,92,92), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "indigo" => crate::Color{ name:std::borrow::Cow::Borrowed("indigo"), hex:std::borrow::Cow::Borrowed("#4B0082"), rgb:(75,0,130), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "ivory" => crate::Color{ name:std::borrow::Cow::Borrowed("ivory"), hex:std::borrow::Cow::Borrowed("#FFFFF0"), rgb:(255,255,240), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "khaki" => crate::Color{ name:std::borrow::Cow::Borrowed("khaki"), hex:std::borrow::Cow::Borrowed("#F0E68C"), rgb:(240,230,140), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lavender" => crate::Color{ name:std::borrow::Cow::Borrowed("lavender"), hex:std::borrow::Cow::Borrowed("#E6E6FA"), rgb:(230,230,250), aliases:&["lavendar"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lavenderblush" => crate::Color{ name:std::borrow::Cow::Borrowed("lavenderblush"), hex:std::borrow::Cow::Borrowed("#FFF0F5"), rgb:(255,240,245), aliases:&["lavendarblush"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lawngreen" => crate::Color{ name:std::borrow::Cow::Borrowed("lawngreen"), hex:std::borrow::Cow::Borrowed("#7CFC00"), rgb:(124,252,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lemonchiffon" => crate::Color{ name:std::borrow::Cow::Borrowed("lemonchiffon"), hex:std::borrow::Cow::Borrowed("#FFFACD"), rgb:(255,250,205), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightblue" => crate::Color{ name:std::borrow::Cow::Borrowed("lightblue"), hex:std::borrow::Cow::Borrowed("#ADD8E6"), rgb:(173,216,230), aliases:&["ltblue"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightcoral" => crate::Color{ name:std::borrow::Cow::Borrowed("lightcoral"), hex:std::borrow::Cow::Borrowed("#F08080"), rgb:(240,128,128), aliases:&["ltcoral"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightcyan" => crate::Color{ name:std::borrow::Cow::Borrowed("lightcyan"), hex:std::borrow::Cow::Borrowed("#E0FFFF"), rgb:(224,255,255), aliases:&["ltcyan"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightgoldenrodyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("lightgoldenrodyellow"), hex:std::borrow::Cow::Borrowed("#FAFAD2"), rgb:(250,250,210), aliases:&["ltgoldenrodyellow"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightgray" => crate::Color{ name:std::borrow::Cow::Borrowed("lightgray"), hex:std::borrow::Cow::Borrowed("#D3D3D3"), rgb:(211,211,211), aliases:&["ltgray", "ltgrey"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("lightgreen"), hex:std::borrow::Cow::Borrowed("#90EE90"), rgb:(144,238,144), aliases:&["ltgreen"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("lightgrey"), hex:std::borrow::Cow::Borrowed("#D3D3D3"), rgb:(211,211,211), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightpink" => crate::Color{ name:std::borrow::Cow::Borrowed("lightpink"), hex:std::borrow::Cow::Borrowed("#FFB6C1"), rgb:(255,182,193), aliases:&["ltpink"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightsalmon" => crate::Color{ name:std::borrow::Cow::Borrowed("lightsalmon"), hex:std::borrow::Cow::Borrowed("#FFA07A"), rgb:(255,160,122), aliases:&["ltsalmon"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightseagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("lightseagreen"), hex:std::borrow::Cow::Borrowed("#20B2AA"), rgb:(32,178,170), aliases:&["ltseagreen"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("lightskyblue"), hex:std::borrow::Cow::Borrowed("#87CEFA"), rgb:(135,206,250), aliases:&["ltskyblue"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightslategray" => crate::Color{ name:std::borrow::Cow::Borrowed("lightslategray"), hex:std::borrow::Cow::Borrowed("#778899"), rgb:(119,136,153), aliases:&["ltslategray", "ltslategrey"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightslategrey" => crate::Color{ name:std::borrow::Cow::Borrowed("lightslategrey"), hex:std::borrow::Cow::Borrowed("#778899"), rgb:(119,136,153), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightsteelblue" => crate::Color{ name:std::borrow::Cow::Borrowed("lightsteelblue"), hex:std::borrow::Cow::Borrowed("#B0C4DE"), rgb:(176,196,222), aliases:&["ltsteelblue"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lightyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("lightyellow"), hex:std::borrow::Cow::Borrowed("#FFFFE0"), rgb:(255,255,224), aliases:&["ltyellow"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "lime" => crate::Color{ name:std::borrow::Cow::Borrowed("lime"), hex:std::borrow::Cow::Borrowed("#00FF00"), rgb:(0,255,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "limegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("limegreen"), hex:std::borrow::Cow::Borrowed("#32CD32"), rgb:(50,205,50), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "linen" => crate::Color{ name:std::borrow::Cow::Borrowed("linen"), hex:std::borrow::Cow::Borrowed("#FAF0E6"), rgb:(250,240,230), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "magenta" => crate::Color{ name:std::borrow::Cow::Borrowed("magenta"), hex:std::borrow::Cow::Borrowed("#FF00FF"), rgb:(255,0,255), aliases:&["megenta"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "maroon" => crate::Color{ name:std::borrow::Cow::Borrowed("maroon"), hex:std::borrow::Cow::Borrowed("#800000"), rgb:(128,0,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mediumaquamarine" => crate::Color{ name:std::borrow::Cow::Borrowed("mediumaquamarine"), hex:std::borrow::Cow::Borrowed("#66CDAA"), rgb:(102,205,170), aliases:&["medaquamarine"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mediumblue" => crate::Color{ name:std::borrow::Cow::Borrowed("mediumblue"), hex:std::borrow::Cow::Borrowed("#0000CD"), rgb:(0,0,205), aliases:&["medblue"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mediumorchid" => crate::Color{ name:std::borrow::Cow::Borrowed("mediumorchid"), hex:std::borrow::Cow::Borrowed("#BA55D3"), rgb:(186,85,211), aliases:&["medorchid"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mediumpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("mediumpurple"), hex:std::borrow::Cow::Borrowed("#9370DB"), rgb:(147,112,219), aliases:&["medpurple"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mediumseagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("mediumseagreen"), hex:std::borrow::Cow::Borrowed("#3CB371"), rgb:(60,179,113), aliases:&["medseagreen"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mediumslateblue" => crate::Color{ name:std::borrow::Cow::Borrowed("mediumslateblue"), hex:std::borrow::Cow::Borrowed("#7B68EE"), rgb:(123,104,238), aliases:&["medslateblue"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mediumspringgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("mediumspringgreen"), hex:std::borrow::Cow::Borrowed("#00FA9A"), rgb:(0,250,154), aliases:&["medspringgreen"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mediumturquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("mediumturquoise"), hex:std::borrow::Cow::Borrowed("#48D1CC"), rgb:(72,209,204), aliases:&["mediumturqoise", "mediumturquise", "medturquoise", "medturqoise", "medturquise"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mediumvioletred" => crate::Color{ name:std::borrow::Cow::Borrowed("mediumvioletred"), hex:std::borrow::Cow::Borrowed("#C71585"), rgb:(199,21,133), aliases:&["mediumvoiletred", "medvioletred", "medvoiletred"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "midnightblue" => crate::Color{ name:std::borrow::Cow::Borrowed("midnightblue"), hex:std::borrow::Cow::Borrowed("#191970"), rgb:(25,25,112), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mintcream" => crate::Color{ name:std::borrow::Cow::Borrowed("mintcream"), hex:std::borrow::Cow::Borrowed("#F5FFFA"), rgb:(245,255,250), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "mistyrose" => crate::Color{ name:std::borrow::Cow::Borrowed("mistyrose"), hex:std::borrow::Cow::Borrowed("#FFE4E1"), rgb:(255,228,225), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "moccasin" => crate::Color{ name:std::borrow::Cow::Borrowed("moccasin"), hex:std::borrow::Cow::Borrowed("#FFE4B5"), rgb:(255,228,181), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "navajowhite" => crate::Color{ name:std::borrow::Cow::Borrowed("navajowhite"), hex:std::borrow::Cow::Borrowed("#FFDEAD"), rgb:(255,222,173), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "navy" => crate::Color{ name:std::borrow::Cow::Borrowed("navy"), hex:std::borrow::Cow::Borrowed("#000080"), rgb:(0,0,128), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "oldlace" => crate::Color{ name:std::borrow::Cow::Borrowed("oldlace"), hex:std::borrow::Cow::Borrowed("#FDF5E6"), rgb:(253,245,230), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "olive" => crate::Color{ name:std::borrow::Cow::Borrowed("olive"), hex:std::borrow::Cow::Borrowed("#808000"), rgb:(128,128,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "olivedrab" => crate::Color{ name:std::borrow::Cow::Borrowed("olivedrab"), hex:std::borrow::Cow::Borrowed("#6B8E23"), rgb:(107,142,35), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "orange" => crate::Color{ name:std::borrow::Cow::Borrowed("orange"), hex:std::borrow::Cow::Borrowed("#FFA500"), rgb:(255,165,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "orangered" => crate::Color{ name:std::borrow::Cow::Borrowed("orangered"), hex:std::borrow::Cow::Borrowed("#FF4500"), rgb:(255,69,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "orchid" => crate::Color{ name:std::borrow::Cow::Borrowed("orchid"), hex:std::borrow::Cow::Borrowed("#DA70D6"), rgb:(218,112,214), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "palegoldenrod" => crate::Color{ name:std::borrow::Cow::Borrowed("palegoldenrod"), hex:std::borrow::Cow::Borrowed("#EEE8AA"), rgb:(238,232,170), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "palegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("palegreen"), hex:std::borrow::Cow::Borrowed("#98FB98"), rgb:(152,251,152), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "paleturquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("paleturquoise"), hex:std::borrow::Cow::Borrowed("#AFEEEE"), rgb:(175,238,238), aliases:&["paleturqoise", "paleturquise"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "palevioletred" => crate::Color{ name:std::borrow::Cow::Borrowed("palevioletred"), hex:std::borrow::Cow::Borrowed("#DB7093"), rgb:(219,112,147), aliases:&["palevoiletred"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "papayawhip" => crate::Color{ name:std::borrow::Cow::Borrowed("papayawhip"), hex:std::borrow::Cow::Borrowed("#FFEFD5"), rgb:(255,239,213), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "peachpuff" => crate::Color{ name:std::borrow::Cow::Borrowed("peachpuff"), hex:std::borrow::Cow::Borrowed("#FFDAB9"), rgb:(255,218,185), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "peru" => crate::Color{ name:std::borrow::Cow::Borrowed("peru"), hex:std::borrow::Cow::Borrowed("#CD853F"), rgb:(205,133,63), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "pink" => crate::Color{ name:std::borrow::Cow::Borrowed("pink"), hex:std::borrow::Cow::Borrowed("#FFC0CB"), rgb:(255,192,203), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "plum" => crate::Color{ name:std::borrow::Cow::Borrowed("plum"), hex:std::borrow::Cow::Borrowed("#DDA0DD"), rgb:(221,160,221), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "powderblue" => crate::Color{ name:std::borrow::Cow::Borrowed("powderblue"), hex:std::borrow::Cow::Borrowed("#B0E0E6"), rgb:(176,224,230), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "purple" => crate::Color{ name:std::borrow::Cow::Borrowed("purple"), hex:std::borrow::Cow::Borrowed("#800080"), rgb:(128,0,128), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "rebeccapurple" => crate::Color{ name:std::borrow::Cow::Borrowed("rebeccapurple"), hex:std::borrow::Cow::Borrowed("#663399"), rgb:(102,51,153), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "red" => crate::Color{ name:std::borrow::Cow::Borrowed("red"), hex:std::borrow::Cow::Borrowed("#FF0000"), rgb:(255,0,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "rosybrown" => crate::Color{ name:std::borrow::Cow::Borrowed("rosybrown"), hex:std::borrow::Cow::Borrowed("#BC8F8F"), rgb:(188,143,143), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "royalblue" => crate::Color{ name:std::borrow::Cow::Borrowed("royalblue"), hex:std::borrow::Cow::Borrowed("#4169E1"), rgb:(65,105,225), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "saddlebrown" => crate::Color{ name:std::borrow::Cow::Borrowed("saddlebrown"), hex:std::borrow::Cow::Borrowed("#8B4513"), rgb:(139,69,19), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "salmon" => crate::Color{ name:std::borrow::Cow::Borrowed("salmon"), hex:std::borrow::Cow::Borrowed("#FA8072"), rgb:(250,128,114), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "sandybrown" => crate::Color{ name:std::borrow::Cow::Borrowed("sandybrown"), hex:std::borrow::Cow::Borrowed("#F4A460"), rgb:(244,164,96), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "seagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("seagreen"), hex:std::borrow::Cow::Borrowed("#2E8B57"), rgb:(46,139,87), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "seashell" => crate::Color{ name:std::borrow::Cow::Borrowed("seashell"), hex:std::borrow::Cow::Borrowed("#FFF5EE"), rgb:(255,245,238), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "sienna" => crate::Color{ name:std::borrow::Cow::Borrowed("sienna"), hex:std::borrow::Cow::Borrowed("#A0522D"), rgb:(160,82,45), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "silver" => crate::Color{ name:std::borrow::Cow::Borrowed("silver"), hex:std::borrow::Cow::Borrowed("#C0C0C0"), rgb:(192,192,192), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "skyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("skyblue"), hex:std::borrow::Cow::Borrowed("#87CEEB"), rgb:(135,206,235), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "slateblue" => crate::Color{ name:std::borrow::Cow::Borrowed("slateblue"), hex:std::borrow::Cow::Borrowed("#6A5ACD"), rgb:(106,90,205), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "slategray" => crate::Color{ name:std::borrow::Cow::Borrowed("slategray"), hex:std::borrow::Cow::Borrowed("#708090"), rgb:(112,128,144), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "slategrey" => crate::Color{ name:std::borrow::Cow::Borrowed("slategrey"), hex:std::borrow::Cow::Borrowed("#708090"), rgb:(112,128,144), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "snow" => crate::Color{ name:std::borrow::Cow::Borrowed("snow"), hex:std::borrow::Cow::Borrowed("#FFFAFA"), rgb:(255,250,250), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "springgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("springgreen"), hex:std::borrow::Cow::Borrowed("#00FF7F"), rgb:(0,255,127), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "steelblue" => crate::Color{ name:std::borrow::Cow::Borrowed("steelblue"), hex:std::borrow::Cow::Borrowed("#4682B4"), rgb:(70,130,180), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "tan" => crate::Color{ name:std::borrow::Cow::Borrowed("tan"), hex:std::borrow::Cow::Borrowed("#D2B48C"), rgb:(210,180,140), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "teal" => crate::Color{ name:std::borrow::Cow::Borrowed("teal"), hex:std::borrow::Cow::Borrowed("#008080"), rgb:(0,128,128), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "thistle" => crate::Color{ name:std::borrow::Cow::Borrowed("thistle"), hex:std::borrow::Cow::Borrowed("#D8BFD8"), rgb:(216,191,216), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "tomato" => crate::Color{ name:std::borrow::Cow::Borrowed("tomato"), hex:std::borrow::Cow::Borrowed("#FF6347"), rgb:(255,99,71), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "turquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("turquoise"), hex:std::borrow::Cow::Borrowed("#40E0D0"), rgb:(64,224,208), aliases:&["turqoise", "turquise"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "violet" => crate::Color{ name:std::borrow::Cow::Borrowed("violet"), hex:std::borrow::Cow::Borrowed("#EE82EE"), rgb:(238,130,238), aliases:&["voilet"], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "wheat" => crate::Color{ name:std::borrow::Cow::Borrowed("wheat"), hex:std::borrow::Cow::Borrowed("#F5DEB3"), rgb:(245,222,179), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "white" => crate::Color{ name:std::borrow::Cow::Borrowed("white"), hex:std::borrow::Cow::Borrowed("#FFFFFF"), rgb:(255,255,255), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "whitesmoke" => crate::Color{ name:std::borrow::Cow::Borrowed("whitesmoke"), hex:std::borrow::Cow::Borrowed("#F5F5F5"), rgb:(245,245,245), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "yellow" => crate::Color{ name:std::borrow::Cow::Borrowed("yellow"), hex:std::borrow::Cow::Borrowed("#FFFF00"), rgb:(255,255,0), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+    "yellowgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("yellowgreen"), hex:std::borrow::Cow::Borrowed("#9ACD32"), rgb:(154,205,50), aliases:&[], source:crate::Source{ list:"CSS Color Module Level 4", variant:None } },
+};
+
+/// Alternate spellings of `CSS_COLORS` keys, mapped to the key they stand for.
+pub(crate) static CSS_COLORS_ALIASES: phf::Map<&'static str, &'static str> = phf::phf_map! {
+    "bluevoilet" => "blueviolet",
+    "dkblue" => "darkblue",
+    "dkcyan" => "darkcyan",
+    "dkgoldenrod" => "darkgoldenrod",
+    "dkgray" => "darkgray",
+    "dkgrey" => "darkgray",
+    "dkgreen" => "darkgreen",
+    "dkkhaki" => "darkkhaki",
+    "darkmegenta" => "darkmagenta",
+    "dkmagenta" => "darkmagenta",
+    "dkmegenta" => "darkmagenta",
+    "dkolivegreen" => "darkolivegreen",
+    "dkorange" => "darkorange",
+    "dkorchid" => "darkorchid",
+    "dkred" => "darkred",
+    "dksalmon" => "darksalmon",
+    "dkseagreen" => "darkseagreen",
+    "dkslateblue" => "darkslateblue",
+    "dkslategray" => "darkslategray",
+    "dkslategrey" => "darkslategray",
+    "darkturqoise" => "darkturquoise",
+    "darkturquise" => "darkturquoise",
+    "dkturquoise" => "darkturquoise",
+    "dkturqoise" => "darkturquoise",
+    "dkturquise" => "darkturquoise",
+    "darkvoilet" => "darkviolet",
+    "dkviolet" => "darkviolet",
+    "dkvoilet" => "darkviolet",
+    "fuschia" => "fuchsia",
+    "fushia" => "fuchsia",
+    "lavendar" => "lavender",
+    "lavendarblush" => "lavenderblush",
+    "ltblue" => "lightblue",
+    "ltcoral" => "lightcoral",
+    "ltcyan" => "lightcyan",
+    "ltgoldenrodyellow" => "lightgoldenrodyellow",
+    "ltgray" => "lightgray",
+    "ltgrey" => "lightgray",
+    "ltgreen" => "lightgreen",
+    "ltpink" => "lightpink",
+    "ltsalmon" => "lightsalmon",
+    "ltseagreen" => "lightseagreen",
+    "ltskyblue" => "lightskyblue",
+    "ltslategray" => "lightslategray",
+    "ltslategrey" => "lightslategray",
+    "ltsteelblue" => "lightsteelblue",
+    "ltyellow" => "lightyellow",
+    "megenta" => "magenta",
+    "medaquamarine" => "mediumaquamarine",
+    "medblue" => "mediumblue",
+    "medorchid" => "mediumorchid",
+    "medpurple" => "mediumpurple",
+    "medseagreen" => "mediumseagreen",
+    "medslateblue" => "mediumslateblue",
+    "medspringgreen" => "mediumspringgreen",
+    "mediumturqoise" => "mediumturquoise",
+    "mediumturquise" => "mediumturquoise",
+    "medturquoise" => "mediumturquoise",
+    "medturqoise" => "mediumturquoise",
+    "medturquise" => "mediumturquoise",
+    "mediumvoiletred" => "mediumvioletred",
+    "medvioletred" => "mediumvioletred",
+    "medvoiletred" => "mediumvioletred",
+    "paleturqoise" => "paleturquoise",
+    "paleturquise" => "paleturquoise",
+    "palevoiletred" => "palevioletred",
+    "turqoise" => "turquoise",
+    "turquise" => "turquoise",
+    "voilet" => "violet",
+};
@@ -0,0 +1,845 @@
+//  ***  AUTO-GENERATED  – DO NOT EDIT BY HAND  ***
+
+/// Colors from the X11 `rgb.txt` database.
+pub static X11_COLORS: phf::Map<&'static str, crate::Color> = phf::phf_map! {
+    "snow" => crate::Color{ name:std::borrow::Cow::Borrowed("snow"), hex:std::borrow::Cow::Borrowed("#FFFAFA"), rgb:(255,250,250), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "ghostwhite" => crate::Color{ name:std::borrow::Cow::Borrowed("ghost white"), hex:std::borrow::Cow::Borrowed("#F8F8FF"), rgb:(248,248,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "whitesmoke" => crate::Color{ name:std::borrow::Cow::Borrowed("white smoke"), hex:std::borrow::Cow::Borrowed("#F5F5F5"), rgb:(245,245,245), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gainsboro" => crate::Color{ name:std::borrow::Cow::Borrowed("gainsboro"), hex:std::borrow::Cow::Borrowed("#DCDCDC"), rgb:(220,220,220), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "floralwhite" => crate::Color{ name:std::borrow::Cow::Borrowed("floral white"), hex:std::borrow::Cow::Borrowed("#FFFAF0"), rgb:(255,250,240), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "oldlace" => crate::Color{ name:std::borrow::Cow::Borrowed("old lace"), hex:std::borrow::Cow::Borrowed("#FDF5E6"), rgb:(253,245,230), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "linen" => crate::Color{ name:std::borrow::Cow::Borrowed("linen"), hex:std::borrow::Cow::Borrowed("#FAF0E6"), rgb:(250,240,230), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "antiquewhite" => crate::Color{ name:std::borrow::Cow::Borrowed("antique white"), hex:std::borrow::Cow::Borrowed("#FAEBD7"), rgb:(250,235,215), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "papayawhip" => crate::Color{ name:std::borrow::Cow::Borrowed("papaya whip"), hex:std::borrow::Cow::Borrowed("#FFEFD5"), rgb:(255,239,213), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "blanchedalmond" => crate::Color{ name:std::borrow::Cow::Borrowed("blanched almond"), hex:std::borrow::Cow::Borrowed("#FFEBCD"), rgb:(255,235,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "bisque" => crate::Color{ name:std::borrow::Cow::Borrowed("bisque"), hex:std::borrow::Cow::Borrowed("#FFE4C4"), rgb:(255,228,196), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "peachpuff" => crate::Color{ name:std::borrow::Cow::Borrowed("peach puff"), hex:std::borrow::Cow::Borrowed("#FFDAB9"), rgb:(255,218,185), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "navajowhite" => crate::Color{ name:std::borrow::Cow::Borrowed("navajo white"), hex:std::borrow::Cow::Borrowed("#FFDEAD"), rgb:(255,222,173), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "moccasin" => crate::Color{ name:std::borrow::Cow::Borrowed("moccasin"), hex:std::borrow::Cow::Borrowed("#FFE4B5"), rgb:(255,228,181), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cornsilk" => crate::Color{ name:std::borrow::Cow::Borrowed("cornsilk"), hex:std::borrow::Cow::Borrowed("#FFF8DC"), rgb:(255,248,220), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "ivory" => crate::Color{ name:std::borrow::Cow::Borrowed("ivory"), hex:std::borrow::Cow::Borrowed("#FFFFF0"), rgb:(255,255,240), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lemonchiffon" => crate::Color{ name:std::borrow::Cow::Borrowed("lemon chiffon"), hex:std::borrow::Cow::Borrowed("#FFFACD"), rgb:(255,250,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "seashell" => crate::Color{ name:std::borrow::Cow::Borrowed("seashell"), hex:std::borrow::Cow::Borrowed("#FFF5EE"), rgb:(255,245,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "honeydew" => crate::Color{ name:std::borrow::Cow::Borrowed("honeydew"), hex:std::borrow::Cow::Borrowed("#F0FFF0"), rgb:(240,255,240), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mintcream" => crate::Color{ name:std::borrow::Cow::Borrowed("mint cream"), hex:std::borrow::Cow::Borrowed("#F5FFFA"), rgb:(245,255,250), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "azure" => crate::Color{ name:std::borrow::Cow::Borrowed("azure"), hex:std::borrow::Cow::Borrowed("#F0FFFF"), rgb:(240,255,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "aliceblue" => crate::Color{ name:std::borrow::Cow::Borrowed("alice blue"), hex:std::borrow::Cow::Borrowed("#F0F8FF"), rgb:(240,248,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lavender" => crate::Color{ name:std::borrow::Cow::Borrowed("lavender"), hex:std::borrow::Cow::Borrowed("#E6E6FA"), rgb:(230,230,250), aliases:&["lavendar"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lavenderblush" => crate::Color{ name:std::borrow::Cow::Borrowed("lavender blush"), hex:std::borrow::Cow::Borrowed("#FFF0F5"), rgb:(255,240,245), aliases:&["lavendarblush"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mistyrose" => crate::Color{ name:std::borrow::Cow::Borrowed("misty rose"), hex:std::borrow::Cow::Borrowed("#FFE4E1"), rgb:(255,228,225), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "white" => crate::Color{ name:std::borrow::Cow::Borrowed("white"), hex:std::borrow::Cow::Borrowed("#FFFFFF"), rgb:(255,255,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "black" => crate::Color{ name:std::borrow::Cow::Borrowed("black"), hex:std::borrow::Cow::Borrowed("#000000"), rgb:(0,0,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkslategray" => crate::Color{ name:std::borrow::Cow::Borrowed("dark slate gray"), hex:std::borrow::Cow::Borrowed("#2F4F4F"), rgb:(47,79,79), aliases:&["dkslategray", "dkslategrey"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkslategrey" => crate::Color{ name:std::borrow::Cow::Borrowed("dark slate grey"), hex:std::borrow::Cow::Borrowed("#2F4F4F"), rgb:(47,79,79), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "dimgray" => crate::Color{ name:std::borrow::Cow::Borrowed("dim gray"), hex:std::borrow::Cow::Borrowed("#696969"), rgb:(105,105,105), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "dimgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("dim grey"), hex:std::borrow::Cow::Borrowed("#696969"), rgb:(105,105,105), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slategray" => crate::Color{ name:std::borrow::Cow::Borrowed("slate gray"), hex:std::borrow::Cow::Borrowed("#708090"), rgb:(112,128,144), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slategrey" => crate::Color{ name:std::borrow::Cow::Borrowed("slate grey"), hex:std::borrow::Cow::Borrowed("#708090"), rgb:(112,128,144), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightslategray" => crate::Color{ name:std::borrow::Cow::Borrowed("light slate gray"), hex:std::borrow::Cow::Borrowed("#778899"), rgb:(119,136,153), aliases:&["ltslategray", "ltslategrey"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightslategrey" => crate::Color{ name:std::borrow::Cow::Borrowed("light slate grey"), hex:std::borrow::Cow::Borrowed("#778899"), rgb:(119,136,153), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray" => crate::Color{ name:std::borrow::Cow::Borrowed("gray"), hex:std::borrow::Cow::Borrowed("#BEBEBE"), rgb:(190,190,190), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey" => crate::Color{ name:std::borrow::Cow::Borrowed("grey"), hex:std::borrow::Cow::Borrowed("#BEBEBE"), rgb:(190,190,190), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("light grey"), hex:std::borrow::Cow::Borrowed("#D3D3D3"), rgb:(211,211,211), aliases:&["ltgrey", "ltgray"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightgray" => crate::Color{ name:std::borrow::Cow::Borrowed("light gray"), hex:std::borrow::Cow::Borrowed("#D3D3D3"), rgb:(211,211,211), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "midnightblue" => crate::Color{ name:std::borrow::Cow::Borrowed("midnight blue"), hex:std::borrow::Cow::Borrowed("#191970"), rgb:(25,25,112), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "navy" => crate::Color{ name:std::borrow::Cow::Borrowed("navy"), hex:std::borrow::Cow::Borrowed("#000080"), rgb:(0,0,128), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "navyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("navy blue"), hex:std::borrow::Cow::Borrowed("#000080"), rgb:(0,0,128), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cornflowerblue" => crate::Color{ name:std::borrow::Cow::Borrowed("cornflower blue"), hex:std::borrow::Cow::Borrowed("#6495ED"), rgb:(100,149,237), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkslateblue" => crate::Color{ name:std::borrow::Cow::Borrowed("dark slate blue"), hex:std::borrow::Cow::Borrowed("#483D8B"), rgb:(72,61,139), aliases:&["dkslateblue"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slateblue" => crate::Color{ name:std::borrow::Cow::Borrowed("slate blue"), hex:std::borrow::Cow::Borrowed("#6A5ACD"), rgb:(106,90,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumslateblue" => crate::Color{ name:std::borrow::Cow::Borrowed("medium slate blue"), hex:std::borrow::Cow::Borrowed("#7B68EE"), rgb:(123,104,238), aliases:&["medslateblue"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightslateblue" => crate::Color{ name:std::borrow::Cow::Borrowed("light slate blue"), hex:std::borrow::Cow::Borrowed("#8470FF"), rgb:(132,112,255), aliases:&["ltslateblue"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumblue" => crate::Color{ name:std::borrow::Cow::Borrowed("medium blue"), hex:std::borrow::Cow::Borrowed("#0000CD"), rgb:(0,0,205), aliases:&["medblue"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "royalblue" => crate::Color{ name:std::borrow::Cow::Borrowed("royal blue"), hex:std::borrow::Cow::Borrowed("#4169E1"), rgb:(65,105,225), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "blue" => crate::Color{ name:std::borrow::Cow::Borrowed("blue"), hex:std::borrow::Cow::Borrowed("#0000FF"), rgb:(0,0,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "dodgerblue" => crate::Color{ name:std::borrow::Cow::Borrowed("dodger blue"), hex:std::borrow::Cow::Borrowed("#1E90FF"), rgb:(30,144,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "deepskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("deep sky blue"), hex:std::borrow::Cow::Borrowed("#00BFFF"), rgb:(0,191,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "skyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("sky blue"), hex:std::borrow::Cow::Borrowed("#87CEEB"), rgb:(135,206,235), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightskyblue" => crate::Color{ name:std::borrow::Cow::Borrowed("light sky blue"), hex:std::borrow::Cow::Borrowed("#87CEFA"), rgb:(135,206,250), aliases:&["ltskyblue"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "steelblue" => crate::Color{ name:std::borrow::Cow::Borrowed("steel blue"), hex:std::borrow::Cow::Borrowed("#4682B4"), rgb:(70,130,180), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightsteelblue" => crate::Color{ name:std::borrow::Cow::Borrowed("light steel blue"), hex:std::borrow::Cow::Borrowed("#B0C4DE"), rgb:(176,196,222), aliases:&["ltsteelblue"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightblue" => crate::Color{ name:std::borrow::Cow::Borrowed("light blue"), hex:std::borrow::Cow::Borrowed("#ADD8E6"), rgb:(173,216,230), aliases:&["ltblue"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "powderblue" => crate::Color{ name:std::borrow::Cow::Borrowed("powder blue"), hex:std::borrow::Cow::Borrowed("#B0E0E6"), rgb:(176,224,230), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "paleturquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("pale turquoise"), hex:std::borrow::Cow::Borrowed("#AFEEEE"), rgb:(175,238,238), aliases:&["paleturqoise", "paleturquise"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkturquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("dark turquoise"), hex:std::borrow::Cow::Borrowed("#00CED1"), rgb:(0,206,209), aliases:&["darkturqoise", "darkturquise", "dkturquoise", "dkturqoise", "dkturquise"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumturquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("medium turquoise"), hex:std::borrow::Cow::Borrowed("#48D1CC"), rgb:(72,209,204), aliases:&["mediumturqoise", "mediumturquise", "medturquoise", "medturqoise", "medturquise"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "turquoise" => crate::Color{ name:std::borrow::Cow::Borrowed("turquoise"), hex:std::borrow::Cow::Borrowed("#40E0D0"), rgb:(64,224,208), aliases:&["turqoise", "turquise"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cyan" => crate::Color{ name:std::borrow::Cow::Borrowed("cyan"), hex:std::borrow::Cow::Borrowed("#00FFFF"), rgb:(0,255,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightcyan" => crate::Color{ name:std::borrow::Cow::Borrowed("light cyan"), hex:std::borrow::Cow::Borrowed("#E0FFFF"), rgb:(224,255,255), aliases:&["ltcyan"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cadetblue" => crate::Color{ name:std::borrow::Cow::Borrowed("cadet blue"), hex:std::borrow::Cow::Borrowed("#5F9EA0"), rgb:(95,158,160), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumaquamarine" => crate::Color{ name:std::borrow::Cow::Borrowed("medium aquamarine"), hex:std::borrow::Cow::Borrowed("#66CDAA"), rgb:(102,205,170), aliases:&["medaquamarine"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "aquamarine" => crate::Color{ name:std::borrow::Cow::Borrowed("aquamarine"), hex:std::borrow::Cow::Borrowed("#7FFFD4"), rgb:(127,255,212), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("dark green"), hex:std::borrow::Cow::Borrowed("#006400"), rgb:(0,100,0), aliases:&["dkgreen"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkolivegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("dark olive green"), hex:std::borrow::Cow::Borrowed("#556B2F"), rgb:(85,107,47), aliases:&["dkolivegreen"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkseagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("dark sea green"), hex:std::borrow::Cow::Borrowed("#8FBC8F"), rgb:(143,188,143), aliases:&["dkseagreen"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "seagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("sea green"), hex:std::borrow::Cow::Borrowed("#2E8B57"), rgb:(46,139,87), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumseagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("medium sea green"), hex:std::borrow::Cow::Borrowed("#3CB371"), rgb:(60,179,113), aliases:&["medseagreen"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightseagreen" => crate::Color{ name:std::borrow::Cow::Borrowed("light sea green"), hex:std::borrow::Cow::Borrowed("#20B2AA"), rgb:(32,178,170), aliases:&["ltseagreen"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("pale green"), hex:std::borrow::Cow::Borrowed("#98FB98"), rgb:(152,251,152), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "springgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("spring green"), hex:std::borrow::Cow::Borrowed("#00FF7F"), rgb:(0,255,127), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lawngreen" => crate::Color{ name:std::borrow::Cow::Borrowed("lawn green"), hex:std::borrow::Cow::Borrowed("#7CFC00"), rgb:(124,252,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "green" => crate::Color{ name:std::borrow::Cow::Borrowed("green"), hex:std::borrow::Cow::Borrowed("#00FF00"), rgb:(0,255,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "chartreuse" => crate::Color{ name:std::borrow::Cow::Borrowed("chartreuse"), hex:std::borrow::Cow::Borrowed("#7FFF00"), rgb:(127,255,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumspringgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("medium spring green"), hex:std::borrow::Cow::Borrowed("#00FA9A"), rgb:(0,250,154), aliases:&["medspringgreen"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "greenyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("green yellow"), hex:std::borrow::Cow::Borrowed("#ADFF2F"), rgb:(173,255,47), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "limegreen" => crate::Color{ name:std::borrow::Cow::Borrowed("lime green"), hex:std::borrow::Cow::Borrowed("#32CD32"), rgb:(50,205,50), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "yellowgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("yellow green"), hex:std::borrow::Cow::Borrowed("#9ACD32"), rgb:(154,205,50), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "forestgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("forest green"), hex:std::borrow::Cow::Borrowed("#228B22"), rgb:(34,139,34), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "olivedrab" => crate::Color{ name:std::borrow::Cow::Borrowed("olive drab"), hex:std::borrow::Cow::Borrowed("#6B8E23"), rgb:(107,142,35), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkkhaki" => crate::Color{ name:std::borrow::Cow::Borrowed("dark khaki"), hex:std::borrow::Cow::Borrowed("#BDB76B"), rgb:(189,183,107), aliases:&["dkkhaki"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "khaki" => crate::Color{ name:std::borrow::Cow::Borrowed("khaki"), hex:std::borrow::Cow::Borrowed("#F0E68C"), rgb:(240,230,140), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palegoldenrod" => crate::Color{ name:std::borrow::Cow::Borrowed("pale goldenrod"), hex:std::borrow::Cow::Borrowed("#EEE8AA"), rgb:(238,232,170), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightgoldenrodyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("light goldenrod yellow"), hex:std::borrow::Cow::Borrowed("#FAFAD2"), rgb:(250,250,210), aliases:&["ltgoldenrodyellow"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightyellow" => crate::Color{ name:std::borrow::Cow::Borrowed("light yellow"), hex:std::borrow::Cow::Borrowed("#FFFFE0"), rgb:(255,255,224), aliases:&["ltyellow"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "yellow" => crate::Color{ name:std::borrow::Cow::Borrowed("yellow"), hex:std::borrow::Cow::Borrowed("#FFFF00"), rgb:(255,255,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gold" => crate::Color{ name:std::borrow::Cow::Borrowed("gold"), hex:std::borrow::Cow::Borrowed("#FFD700"), rgb:(255,215,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightgoldenrod" => crate::Color{ name:std::borrow::Cow::Borrowed("light goldenrod"), hex:std::borrow::Cow::Borrowed("#EEDD82"), rgb:(238,221,130), aliases:&["ltgoldenrod"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "goldenrod" => crate::Color{ name:std::borrow::Cow::Borrowed("goldenrod"), hex:std::borrow::Cow::Borrowed("#DAA520"), rgb:(218,165,32), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkgoldenrod" => crate::Color{ name:std::borrow::Cow::Borrowed("dark goldenrod"), hex:std::borrow::Cow::Borrowed("#B8860B"), rgb:(184,134,11), aliases:&["dkgoldenrod"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "rosybrown" => crate::Color{ name:std::borrow::Cow::Borrowed("rosy brown"), hex:std::borrow::Cow::Borrowed("#BC8F8F"), rgb:(188,143,143), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "indianred" => crate::Color{ name:std::borrow::Cow::Borrowed("indian red"), hex:std::borrow::Cow::Borrowed("#CD5C5C"), rgb:(205,92,92), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "saddlebrown" => crate::Color{ name:std::borrow::Cow::Borrowed("saddle brown"), hex:std::borrow::Cow::Borrowed("#8B4513"), rgb:(139,69,19), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "sienna" => crate::Color{ name:std::borrow::Cow::Borrowed("sienna"), hex:std::borrow::Cow::Borrowed("#A0522D"), rgb:(160,82,45), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "peru" => crate::Color{ name:std::borrow::Cow::Borrowed("peru"), hex:std::borrow::Cow::Borrowed("#CD853F"), rgb:(205,133,63), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "burlywood" => crate::Color{ name:std::borrow::Cow::Borrowed("burlywood"), hex:std::borrow::Cow::Borrowed("#DEB887"), rgb:(222,184,135), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "beige" => crate::Color{ name:std::borrow::Cow::Borrowed("beige"), hex:std::borrow::Cow::Borrowed("#F5F5DC"), rgb:(245,245,220), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "wheat" => crate::Color{ name:std::borrow::Cow::Borrowed("wheat"), hex:std::borrow::Cow::Borrowed("#F5DEB3"), rgb:(245,222,179), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "sandybrown" => crate::Color{ name:std::borrow::Cow::Borrowed("sandy brown"), hex:std::borrow::Cow::Borrowed("#F4A460"), rgb:(244,164,96), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "tan" => crate::Color{ name:std::borrow::Cow::Borrowed("tan"), hex:std::borrow::Cow::Borrowed("#D2B48C"), rgb:(210,180,140), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "chocolate" => crate::Color{ name:std::borrow::Cow::Borrowed("chocolate"), hex:std::borrow::Cow::Borrowed("#D2691E"), rgb:(210,105,30), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "firebrick" => crate::Color{ name:std::borrow::Cow::Borrowed("firebrick"), hex:std::borrow::Cow::Borrowed("#B22222"), rgb:(178,34,34), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "brown" => crate::Color{ name:std::borrow::Cow::Borrowed("brown"), hex:std::borrow::Cow::Borrowed("#A52A2A"), rgb:(165,42,42), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darksalmon" => crate::Color{ name:std::borrow::Cow::Borrowed("dark salmon"), hex:std::borrow::Cow::Borrowed("#E9967A"), rgb:(233,150,122), aliases:&["dksalmon"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "salmon" => crate::Color{ name:std::borrow::Cow::Borrowed("salmon"), hex:std::borrow::Cow::Borrowed("#FA8072"), rgb:(250,128,114), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightsalmon" => crate::Color{ name:std::borrow::Cow::Borrowed("light salmon"), hex:std::borrow::Cow::Borrowed("#FFA07A"), rgb:(255,160,122), aliases:&["ltsalmon"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orange" => crate::Color{ name:std::borrow::Cow::Borrowed("orange"), hex:std::borrow::Cow::Borrowed("#FFA500"), rgb:(255,165,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkorange" => crate::Color{ name:std::borrow::Cow::Borrowed("dark orange"), hex:std::borrow::Cow::Borrowed("#FF8C00"), rgb:(255,140,0), aliases:&["dkorange"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "coral" => crate::Color{ name:std::borrow::Cow::Borrowed("coral"), hex:std::borrow::Cow::Borrowed("#FF7F50"), rgb:(255,127,80), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightcoral" => crate::Color{ name:std::borrow::Cow::Borrowed("light coral"), hex:std::borrow::Cow::Borrowed("#F08080"), rgb:(240,128,128), aliases:&["ltcoral"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "tomato" => crate::Color{ name:std::borrow::Cow::Borrowed("tomato"), hex:std::borrow::Cow::Borrowed("#FF6347"), rgb:(255,99,71), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orangered" => crate::Color{ name:std::borrow::Cow::Borrowed("orange red"), hex:std::borrow::Cow::Borrowed("#FF4500"), rgb:(255,69,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "red" => crate::Color{ name:std::borrow::Cow::Borrowed("red"), hex:std::borrow::Cow::Borrowed("#FF0000"), rgb:(255,0,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "hotpink" => crate::Color{ name:std::borrow::Cow::Borrowed("hot pink"), hex:std::borrow::Cow::Borrowed("#FF69B4"), rgb:(255,105,180), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "deeppink" => crate::Color{ name:std::borrow::Cow::Borrowed("deep pink"), hex:std::borrow::Cow::Borrowed("#FF1493"), rgb:(255,20,147), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "pink" => crate::Color{ name:std::borrow::Cow::Borrowed("pink"), hex:std::borrow::Cow::Borrowed("#FFC0CB"), rgb:(255,192,203), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightpink" => crate::Color{ name:std::borrow::Cow::Borrowed("light pink"), hex:std::borrow::Cow::Borrowed("#FFB6C1"), rgb:(255,182,193), aliases:&["ltpink"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palevioletred" => crate::Color{ name:std::borrow::Cow::Borrowed("pale violet red"), hex:std::borrow::Cow::Borrowed("#DB7093"), rgb:(219,112,147), aliases:&["palevoiletred"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "maroon" => crate::Color{ name:std::borrow::Cow::Borrowed("maroon"), hex:std::borrow::Cow::Borrowed("#B03060"), rgb:(176,48,96), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumvioletred" => crate::Color{ name:std::borrow::Cow::Borrowed("medium violet red"), hex:std::borrow::Cow::Borrowed("#C71585"), rgb:(199,21,133), aliases:&["mediumvoiletred", "medvioletred", "medvoiletred"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "violetred" => crate::Color{ name:std::borrow::Cow::Borrowed("violet red"), hex:std::borrow::Cow::Borrowed("#D02090"), rgb:(208,32,144), aliases:&["voiletred"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "magenta" => crate::Color{ name:std::borrow::Cow::Borrowed("magenta"), hex:std::borrow::Cow::Borrowed("#FF00FF"), rgb:(255,0,255), aliases:&["megenta"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "violet" => crate::Color{ name:std::borrow::Cow::Borrowed("violet"), hex:std::borrow::Cow::Borrowed("#EE82EE"), rgb:(238,130,238), aliases:&["voilet"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "plum" => crate::Color{ name:std::borrow::Cow::Borrowed("plum"), hex:std::borrow::Cow::Borrowed("#DDA0DD"), rgb:(221,160,221), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orchid" => crate::Color{ name:std::borrow::Cow::Borrowed("orchid"), hex:std::borrow::Cow::Borrowed("#DA70D6"), rgb:(218,112,214), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumorchid" => crate::Color{ name:std::borrow::Cow::Borrowed("medium orchid"), hex:std::borrow::Cow::Borrowed("#BA55D3"), rgb:(186,85,211), aliases:&["medorchid"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkorchid" => crate::Color{ name:std::borrow::Cow::Borrowed("dark orchid"), hex:std::borrow::Cow::Borrowed("#9932CC"), rgb:(153,50,204), aliases:&["dkorchid"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("dark violet"), hex:std::borrow::Cow::Borrowed("#9400D3"), rgb:(148,0,211), aliases:&["darkvoilet", "dkviolet", "dkvoilet"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "blueviolet" => crate::Color{ name:std::borrow::Cow::Borrowed("blue violet"), hex:std::borrow::Cow::Borrowed("#8A2BE2"), rgb:(138,43,226), aliases:&["bluevoilet"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "purple" => crate::Color{ name:std::borrow::Cow::Borrowed("purple"), hex:std::borrow::Cow::Borrowed("#A020F0"), rgb:(160,32,240), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumpurple" => crate::Color{ name:std::borrow::Cow::Borrowed("medium purple"), hex:std::borrow::Cow::Borrowed("#9370DB"), rgb:(147,112,219), aliases:&["medpurple"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "thistle" => crate::Color{ name:std::borrow::Cow::Borrowed("thistle"), hex:std::borrow::Cow::Borrowed("#D8BFD8"), rgb:(216,191,216), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "snow1" => crate::Color{ name:std::borrow::Cow::Borrowed("snow1"), hex:std::borrow::Cow::Borrowed("#FFFAFA"), rgb:(255,250,250), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "snow2" => crate::Color{ name:std::borrow::Cow::Borrowed("snow2"), hex:std::borrow::Cow::Borrowed("#EEE9E9"), rgb:(238,233,233), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "snow3" => crate::Color{ name:std::borrow::Cow::Borrowed("snow3"), hex:std::borrow::Cow::Borrowed("#CDC9C9"), rgb:(205,201,201), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "snow4" => crate::Color{ name:std::borrow::Cow::Borrowed("snow4"), hex:std::borrow::Cow::Borrowed("#8B8989"), rgb:(139,137,137), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "seashell1" => crate::Color{ name:std::borrow::Cow::Borrowed("seashell1"), hex:std::borrow::Cow::Borrowed("#FFF5EE"), rgb:(255,245,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "seashell2" => crate::Color{ name:std::borrow::Cow::Borrowed("seashell2"), hex:std::borrow::Cow::Borrowed("#EEE5DE"), rgb:(238,229,222), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "seashell3" => crate::Color{ name:std::borrow::Cow::Borrowed("seashell3"), hex:std::borrow::Cow::Borrowed("#CDC5BF"), rgb:(205,197,191), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "seashell4" => crate::Color{ name:std::borrow::Cow::Borrowed("seashell4"), hex:std::borrow::Cow::Borrowed("#8B8682"), rgb:(139,134,130), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "antiquewhite1" => crate::Color{ name:std::borrow::Cow::Borrowed("AntiqueWhite1"), hex:std::borrow::Cow::Borrowed("#FFEFDB"), rgb:(255,239,219), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "antiquewhite2" => crate::Color{ name:std::borrow::Cow::Borrowed("AntiqueWhite2"), hex:std::borrow::Cow::Borrowed("#EEDFCC"), rgb:(238,223,204), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "antiquewhite3" => crate::Color{ name:std::borrow::Cow::Borrowed("AntiqueWhite3"), hex:std::borrow::Cow::Borrowed("#CDC0B0"), rgb:(205,192,176), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "antiquewhite4" => crate::Color{ name:std::borrow::Cow::Borrowed("AntiqueWhite4"), hex:std::borrow::Cow::Borrowed("#8B8378"), rgb:(139,131,120), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "bisque1" => crate::Color{ name:std::borrow::Cow::Borrowed("bisque1"), hex:std::borrow::Cow::Borrowed("#FFE4C4"), rgb:(255,228,196), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "bisque2" => crate::Color{ name:std::borrow::Cow::Borrowed("bisque2"), hex:std::borrow::Cow::Borrowed("#EED5B7"), rgb:(238,213,183), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "bisque3" => crate::Color{ name:std::borrow::Cow::Borrowed("bisque3"), hex:std::borrow::Cow::Borrowed("#CDB79E"), rgb:(205,183,158), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "bisque4" => crate::Color{ name:std::borrow::Cow::Borrowed("bisque4"), hex:std::borrow::Cow::Borrowed("#8B7D6B"), rgb:(139,125,107), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "peachpuff1" => crate::Color{ name:std::borrow::Cow::Borrowed("PeachPuff1"), hex:std::borrow::Cow::Borrowed("#FFDAB9"), rgb:(255,218,185), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "peachpuff2" => crate::Color{ name:std::borrow::Cow::Borrowed("PeachPuff2"), hex:std::borrow::Cow::Borrowed("#EECBAD"), rgb:(238,203,173), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "peachpuff3" => crate::Color{ name:std::borrow::Cow::Borrowed("PeachPuff3"), hex:std::borrow::Cow::Borrowed("#CDAF95"), rgb:(205,175,149), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "peachpuff4" => crate::Color{ name:std::borrow::Cow::Borrowed("PeachPuff4"), hex:std::borrow::Cow::Borrowed("#8B7765"), rgb:(139,119,101), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "navajowhite1" => crate::Color{ name:std::borrow::Cow::Borrowed("NavajoWhite1"), hex:std::borrow::Cow::Borrowed("#FFDEAD"), rgb:(255,222,173), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "navajowhite2" => crate::Color{ name:std::borrow::Cow::Borrowed("NavajoWhite2"), hex:std::borrow::Cow::Borrowed("#EECFA1"), rgb:(238,207,161), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "navajowhite3" => crate::Color{ name:std::borrow::Cow::Borrowed("NavajoWhite3"), hex:std::borrow::Cow::Borrowed("#CDB38B"), rgb:(205,179,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "navajowhite4" => crate::Color{ name:std::borrow::Cow::Borrowed("NavajoWhite4"), hex:std::borrow::Cow::Borrowed("#8B795E"), rgb:(139,121,94), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lemonchiffon1" => crate::Color{ name:std::borrow::Cow::Borrowed("LemonChiffon1"), hex:std::borrow::Cow::Borrowed("#FFFACD"), rgb:(255,250,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lemonchiffon2" => crate::Color{ name:std::borrow::Cow::Borrowed("LemonChiffon2"), hex:std::borrow::Cow::Borrowed("#EEE9BF"), rgb:(238,233,191), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lemonchiffon3" => crate::Color{ name:std::borrow::Cow::Borrowed("LemonChiffon3"), hex:std::borrow::Cow::Borrowed("#CDC9A5"), rgb:(205,201,165), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lemonchiffon4" => crate::Color{ name:std::borrow::Cow::Borrowed("LemonChiffon4"), hex:std::borrow::Cow::Borrowed("#8B8970"), rgb:(139,137,112), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cornsilk1" => crate::Color{ name:std::borrow::Cow::Borrowed("cornsilk1"), hex:std::borrow::Cow::Borrowed("#FFF8DC"), rgb:(255,248,220), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cornsilk2" => crate::Color{ name:std::borrow::Cow::Borrowed("cornsilk2"), hex:std::borrow::Cow::Borrowed("#EEE8CD"), rgb:(238,232,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cornsilk3" => crate::Color{ name:std::borrow::Cow::Borrowed("cornsilk3"), hex:std::borrow::Cow::Borrowed("#CDC8B1"), rgb:(205,200,177), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cornsilk4" => crate::Color{ name:std::borrow::Cow::Borrowed("cornsilk4"), hex:std::borrow::Cow::Borrowed("#8B8878"), rgb:(139,136,120), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "ivory1" => crate::Color{ name:std::borrow::Cow::Borrowed("ivory1"), hex:std::borrow::Cow::Borrowed("#FFFFF0"), rgb:(255,255,240), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "ivory2" => crate::Color{ name:std::borrow::Cow::Borrowed("ivory2"), hex:std::borrow::Cow::Borrowed("#EEEEE0"), rgb:(238,238,224), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "ivory3" => crate::Color{ name:std::borrow::Cow::Borrowed("ivory3"), hex:std::borrow::Cow::Borrowed("#CDCDC1"), rgb:(205,205,193), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "ivory4" => crate::Color{ name:std::borrow::Cow::Borrowed("ivory4"), hex:std::borrow::Cow::Borrowed("#8B8B83"), rgb:(139,139,131), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "honeydew1" => crate::Color{ name:std::borrow::Cow::Borrowed("honeydew1"), hex:std::borrow::Cow::Borrowed("#F0FFF0"), rgb:(240,255,240), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "honeydew2" => crate::Color{ name:std::borrow::Cow::Borrowed("honeydew2"), hex:std::borrow::Cow::Borrowed("#E0EEE0"), rgb:(224,238,224), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "honeydew3" => crate::Color{ name:std::borrow::Cow::Borrowed("honeydew3"), hex:std::borrow::Cow::Borrowed("#C1CDC1"), rgb:(193,205,193), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "honeydew4" => crate::Color{ name:std::borrow::Cow::Borrowed("honeydew4"), hex:std::borrow::Cow::Borrowed("#838B83"), rgb:(131,139,131), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lavenderblush1" => crate::Color{ name:std::borrow::Cow::Borrowed("LavenderBlush1"), hex:std::borrow::Cow::Borrowed("#FFF0F5"), rgb:(255,240,245), aliases:&["lavendarblush1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lavenderblush2" => crate::Color{ name:std::borrow::Cow::Borrowed("LavenderBlush2"), hex:std::borrow::Cow::Borrowed("#EEE0E5"), rgb:(238,224,229), aliases:&["lavendarblush2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lavenderblush3" => crate::Color{ name:std::borrow::Cow::Borrowed("LavenderBlush3"), hex:std::borrow::Cow::Borrowed("#CDC1C5"), rgb:(205,193,197), aliases:&["lavendarblush3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lavenderblush4" => crate::Color{ name:std::borrow::Cow::Borrowed("LavenderBlush4"), hex:std::borrow::Cow::Borrowed("#8B8386"), rgb:(139,131,134), aliases:&["lavendarblush4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mistyrose1" => crate::Color{ name:std::borrow::Cow::Borrowed("MistyRose1"), hex:std::borrow::Cow::Borrowed("#FFE4E1"), rgb:(255,228,225), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mistyrose2" => crate::Color{ name:std::borrow::Cow::Borrowed("MistyRose2"), hex:std::borrow::Cow::Borrowed("#EED5D2"), rgb:(238,213,210), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mistyrose3" => crate::Color{ name:std::borrow::Cow::Borrowed("MistyRose3"), hex:std::borrow::Cow::Borrowed("#CDB7B5"), rgb:(205,183,181), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mistyrose4" => crate::Color{ name:std::borrow::Cow::Borrowed("MistyRose4"), hex:std::borrow::Cow::Borrowed("#8B7D7B"), rgb:(139,125,123), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "azure1" => crate::Color{ name:std::borrow::Cow::Borrowed("azure1"), hex:std::borrow::Cow::Borrowed("#F0FFFF"), rgb:(240,255,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "azure2" => crate::Color{ name:std::borrow::Cow::Borrowed("azure2"), hex:std::borrow::Cow::Borrowed("#E0EEEE"), rgb:(224,238,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "azure3" => crate::Color{ name:std::borrow::Cow::Borrowed("azure3"), hex:std::borrow::Cow::Borrowed("#C1CDCD"), rgb:(193,205,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "azure4" => crate::Color{ name:std::borrow::Cow::Borrowed("azure4"), hex:std::borrow::Cow::Borrowed("#838B8B"), rgb:(131,139,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slateblue1" => crate::Color{ name:std::borrow::Cow::Borrowed("SlateBlue1"), hex:std::borrow::Cow::Borrowed("#836FFF"), rgb:(131,111,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slateblue2" => crate::Color{ name:std::borrow::Cow::Borrowed("SlateBlue2"), hex:std::borrow::Cow::Borrowed("#7A67EE"), rgb:(122,103,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slateblue3" => crate::Color{ name:std::borrow::Cow::Borrowed("SlateBlue3"), hex:std::borrow::Cow::Borrowed("#6959CD"), rgb:(105,89,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slateblue4" => crate::Color{ name:std::borrow::Cow::Borrowed("SlateBlue4"), hex:std::borrow::Cow::Borrowed("#473C8B"), rgb:(71,60,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "royalblue1" => crate::Color{ name:std::borrow::Cow::Borrowed("RoyalBlue1"), hex:std::borrow::Cow::Borrowed("#4876FF"), rgb:(72,118,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "royalblue2" => crate::Color{ name:std::borrow::Cow::Borrowed("RoyalBlue2"), hex:std::borrow::Cow::Borrowed("#436EEE"), rgb:(67,110,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "royalblue3" => crate::Color{ name:std::borrow::Cow::Borrowed("RoyalBlue3"), hex:std::borrow::Cow::Borrowed("#3A5FCD"), rgb:(58,95,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "royalblue4" => crate::Color{ name:std::borrow::Cow::Borrowed("RoyalBlue4"), hex:std::borrow::Cow::Borrowed("#27408B"), rgb:(39,64,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "blue1" => crate::Color{ name:std::borrow::Cow::Borrowed("blue1"), hex:std::borrow::Cow::Borrowed("#0000FF"), rgb:(0,0,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "blue2" => crate::Color{ name:std::borrow::Cow::Borrowed("blue2"), hex:std::borrow::Cow::Borrowed("#0000EE"), rgb:(0,0,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "blue3" => crate::Color{ name:std::borrow::Cow::Borrowed("blue3"), hex:std::borrow::Cow::Borrowed("#0000CD"), rgb:(0,0,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "blue4" => crate::Color{ name:std::borrow::Cow::Borrowed("blue4"), hex:std::borrow::Cow::Borrowed("#00008B"), rgb:(0,0,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "dodgerblue1" => crate::Color{ name:std::borrow::Cow::Borrowed("DodgerBlue1"), hex:std::borrow::Cow::Borrowed("#1E90FF"), rgb:(30,144,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "dodgerblue2" => crate::Color{ name:std::borrow::Cow::Borrowed("DodgerBlue2"), hex:std::borrow::Cow::Borrowed("#1C86EE"), rgb:(28,134,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "dodgerblue3" => crate::Color{ name:std::borrow::Cow::Borrowed("DodgerBlue3"), hex:std::borrow::Cow::Borrowed("#1874CD"), rgb:(24,116,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "dodgerblue4" => crate::Color{ name:std::borrow::Cow::Borrowed("DodgerBlue4"), hex:std::borrow::Cow::Borrowed("#104E8B"), rgb:(16,78,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "steelblue1" => crate::Color{ name:std::borrow::Cow::Borrowed("SteelBlue1"), hex:std::borrow::Cow::Borrowed("#63B8FF"), rgb:(99,184,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "steelblue2" => crate::Color{ name:std::borrow::Cow::Borrowed("SteelBlue2"), hex:std::borrow::Cow::Borrowed("#5CACEE"), rgb:(92,172,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "steelblue3" => crate::Color{ name:std::borrow::Cow::Borrowed("SteelBlue3"), hex:std::borrow::Cow::Borrowed("#4F94CD"), rgb:(79,148,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "steelblue4" => crate::Color{ name:std::borrow::Cow::Borrowed("SteelBlue4"), hex:std::borrow::Cow::Borrowed("#36648B"), rgb:(54,100,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "deepskyblue1" => crate::Color{ name:std::borrow::Cow::Borrowed("DeepSkyBlue1"), hex:std::borrow::Cow::Borrowed("#00BFFF"), rgb:(0,191,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "deepskyblue2" => crate::Color{ name:std::borrow::Cow::Borrowed("DeepSkyBlue2"), hex:std::borrow::Cow::Borrowed("#00B2EE"), rgb:(0,178,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "deepskyblue3" => crate::Color{ name:std::borrow::Cow::Borrowed("DeepSkyBlue3"), hex:std::borrow::Cow::Borrowed("#009ACD"), rgb:(0,154,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "deepskyblue4" => crate::Color{ name:std::borrow::Cow::Borrowed("DeepSkyBlue4"), hex:std::borrow::Cow::Borrowed("#00688B"), rgb:(0,104,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "skyblue1" => crate::Color{ name:std::borrow::Cow::Borrowed("SkyBlue1"), hex:std::borrow::Cow::Borrowed("#87CEFF"), rgb:(135,206,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "skyblue2" => crate::Color{ name:std::borrow::Cow::Borrowed("SkyBlue2"), hex:std::borrow::Cow::Borrowed("#7EC0EE"), rgb:(126,192,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "skyblue3" => crate::Color{ name:std::borrow::Cow::Borrowed("SkyBlue3"), hex:std::borrow::Cow::Borrowed("#6CA6CD"), rgb:(108,166,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "skyblue4" => crate::Color{ name:std::borrow::Cow::Borrowed("SkyBlue4"), hex:std::borrow::Cow::Borrowed("#4A708B"), rgb:(74,112,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightskyblue1" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSkyBlue1"), hex:std::borrow::Cow::Borrowed("#B0E2FF"), rgb:(176,226,255), aliases:&["ltskyblue1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightskyblue2" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSkyBlue2"), hex:std::borrow::Cow::Borrowed("#A4D3EE"), rgb:(164,211,238), aliases:&["ltskyblue2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightskyblue3" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSkyBlue3"), hex:std::borrow::Cow::Borrowed("#8DB6CD"), rgb:(141,182,205), aliases:&["ltskyblue3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightskyblue4" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSkyBlue4"), hex:std::borrow::Cow::Borrowed("#607B8B"), rgb:(96,123,139), aliases:&["ltskyblue4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slategray1" => crate::Color{ name:std::borrow::Cow::Borrowed("SlateGray1"), hex:std::borrow::Cow::Borrowed("#C6E2FF"), rgb:(198,226,255), aliases:&["slategrey1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slategray2" => crate::Color{ name:std::borrow::Cow::Borrowed("SlateGray2"), hex:std::borrow::Cow::Borrowed("#B9D3EE"), rgb:(185,211,238), aliases:&["slategrey2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slategray3" => crate::Color{ name:std::borrow::Cow::Borrowed("SlateGray3"), hex:std::borrow::Cow::Borrowed("#9FB6CD"), rgb:(159,182,205), aliases:&["slategrey3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "slategray4" => crate::Color{ name:std::borrow::Cow::Borrowed("SlateGray4"), hex:std::borrow::Cow::Borrowed("#6C7B8B"), rgb:(108,123,139), aliases:&["slategrey4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightsteelblue1" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSteelBlue1"), hex:std::borrow::Cow::Borrowed("#CAE1FF"), rgb:(202,225,255), aliases:&["ltsteelblue1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightsteelblue2" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSteelBlue2"), hex:std::borrow::Cow::Borrowed("#BCD2EE"), rgb:(188,210,238), aliases:&["ltsteelblue2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightsteelblue3" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSteelBlue3"), hex:std::borrow::Cow::Borrowed("#A2B5CD"), rgb:(162,181,205), aliases:&["ltsteelblue3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightsteelblue4" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSteelBlue4"), hex:std::borrow::Cow::Borrowed("#6E7B8B"), rgb:(110,123,139), aliases:&["ltsteelblue4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightblue1" => crate::Color{ name:std::borrow::Cow::Borrowed("LightBlue1"), hex:std::borrow::Cow::Borrowed("#BFEFFF"), rgb:(191,239,255), aliases:&["ltblue1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightblue2" => crate::Color{ name:std::borrow::Cow::Borrowed("LightBlue2"), hex:std::borrow::Cow::Borrowed("#B2DFEE"), rgb:(178,223,238), aliases:&["ltblue2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightblue3" => crate::Color{ name:std::borrow::Cow::Borrowed("LightBlue3"), hex:std::borrow::Cow::Borrowed("#9AC0CD"), rgb:(154,192,205), aliases:&["ltblue3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightblue4" => crate::Color{ name:std::borrow::Cow::Borrowed("LightBlue4"), hex:std::borrow::Cow::Borrowed("#68838B"), rgb:(104,131,139), aliases:&["ltblue4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightcyan1" => crate::Color{ name:std::borrow::Cow::Borrowed("LightCyan1"), hex:std::borrow::Cow::Borrowed("#E0FFFF"), rgb:(224,255,255), aliases:&["ltcyan1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightcyan2" => crate::Color{ name:std::borrow::Cow::Borrowed("LightCyan2"), hex:std::borrow::Cow::Borrowed("#D1EEEE"), rgb:(209,238,238), aliases:&["ltcyan2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightcyan3" => crate::Color{ name:std::borrow::Cow::Borrowed("LightCyan3"), hex:std::borrow::Cow::Borrowed("#B4CDCD"), rgb:(180,205,205), aliases:&["ltcyan3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightcyan4" => crate::Color{ name:std::borrow::Cow::Borrowed("LightCyan4"), hex:std::borrow::Cow::Borrowed("#7A8B8B"), rgb:(122,139,139), aliases:&["ltcyan4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "paleturquoise1" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleTurquoise1"), hex:std::borrow::Cow::Borrowed("#BBFFFF"), rgb:(187,255,255), aliases:&["paleturqoise1", "paleturquise1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "paleturquoise2" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleTurquoise2"), hex:std::borrow::Cow::Borrowed("#AEEEEE"), rgb:(174,238,238), aliases:&["paleturqoise2", "paleturquise2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "paleturquoise3" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleTurquoise3"), hex:std::borrow::Cow::Borrowed("#96CDCD"), rgb:(150,205,205), aliases:&["paleturqoise3", "paleturquise3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "paleturquoise4" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleTurquoise4"), hex:std::borrow::Cow::Borrowed("#668B8B"), rgb:(102,139,139), aliases:&["paleturqoise4", "paleturquise4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cadetblue1" => crate::Color{ name:std::borrow::Cow::Borrowed("CadetBlue1"), hex:std::borrow::Cow::Borrowed("#98F5FF"), rgb:(152,245,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cadetblue2" => crate::Color{ name:std::borrow::Cow::Borrowed("CadetBlue2"), hex:std::borrow::Cow::Borrowed("#8EE5EE"), rgb:(142,229,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cadetblue3" => crate::Color{ name:std::borrow::Cow::Borrowed("CadetBlue3"), hex:std::borrow::Cow::Borrowed("#7AC5CD"), rgb:(122,197,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cadetblue4" => crate::Color{ name:std::borrow::Cow::Borrowed("CadetBlue4"), hex:std::borrow::Cow::Borrowed("#53868B"), rgb:(83,134,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "turquoise1" => crate::Color{ name:std::borrow::Cow::Borrowed("turquoise1"), hex:std::borrow::Cow::Borrowed("#00F5FF"), rgb:(0,245,255), aliases:&["turqoise1", "turquise1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "turquoise2" => crate::Color{ name:std::borrow::Cow::Borrowed("turquoise2"), hex:std::borrow::Cow::Borrowed("#00E5EE"), rgb:(0,229,238), aliases:&["turqoise2", "turquise2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "turquoise3" => crate::Color{ name:std::borrow::Cow::Borrowed("turquoise3"), hex:std::borrow::Cow::Borrowed("#00C5CD"), rgb:(0,197,205), aliases:&["turqoise3", "turquise3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "turquoise4" => crate::Color{ name:std::borrow::Cow::Borrowed("turquoise4"), hex:std::borrow::Cow::Borrowed("#00868B"), rgb:(0,134,139), aliases:&["turqoise4", "turquise4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cyan1" => crate::Color{ name:std::borrow::Cow::Borrowed("cyan1"), hex:std::borrow::Cow::Borrowed("#00FFFF"), rgb:(0,255,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cyan2" => crate::Color{ name:std::borrow::Cow::Borrowed("cyan2"), hex:std::borrow::Cow::Borrowed("#00EEEE"), rgb:(0,238,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cyan3" => crate::Color{ name:std::borrow::Cow::Borrowed("cyan3"), hex:std::borrow::Cow::Borrowed("#00CDCD"), rgb:(0,205,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "cyan4" => crate::Color{ name:std::borrow::Cow::Borrowed("cyan4"), hex:std::borrow::Cow::Borrowed("#008B8B"), rgb:(0,139,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkslategray1" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkSlateGray1"), hex:std::borrow::Cow::Borrowed("#97FFFF"), rgb:(151,255,255), aliases:&["darkslategrey1", "dkslategray1", "dkslategrey1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkslategray2" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkSlateGray2"), hex:std::borrow::Cow::Borrowed("#8DEEEE"), rgb:(141,238,238), aliases:&["darkslategrey2", "dkslategray2", "dkslategrey2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkslategray3" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkSlateGray3"), hex:std::borrow::Cow::Borrowed("#79CDCD"), rgb:(121,205,205), aliases:&["darkslategrey3", "dkslategray3", "dkslategrey3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkslategray4" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkSlateGray4"), hex:std::borrow::Cow::Borrowed("#528B8B"), rgb:(82,139,139), aliases:&["darkslategrey4", "dkslategray4", "dkslategrey4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "aquamarine1" => crate::Color{ name:std::borrow::Cow::Borrowed("aquamarine1"), hex:std::borrow::Cow::Borrowed("#7FFFD4"), rgb:(127,255,212), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "aquamarine2" => crate::Color{ name:std::borrow::Cow::Borrowed("aquamarine2"), hex:std::borrow::Cow::Borrowed("#76EEC6"), rgb:(118,238,198), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "aquamarine3" => crate::Color{ name:std::borrow::Cow::Borrowed("aquamarine3"), hex:std::borrow::Cow::Borrowed("#66CDAA"), rgb:(102,205,170), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "aquamarine4" => crate::Color{ name:std::borrow::Cow::Borrowed("aquamarine4"), hex:std::borrow::Cow::Borrowed("#458B74"), rgb:(69,139,116), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkseagreen1" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkSeaGreen1"), hex:std::borrow::Cow::Borrowed("#C1FFC1"), rgb:(193,255,193), aliases:&["dkseagreen1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkseagreen2" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkSeaGreen2"), hex:std::borrow::Cow::Borrowed("#B4EEB4"), rgb:(180,238,180), aliases:&["dkseagreen2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkseagreen3" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkSeaGreen3"), hex:std::borrow::Cow::Borrowed("#9BCD9B"), rgb:(155,205,155), aliases:&["dkseagreen3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkseagreen4" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkSeaGreen4"), hex:std::borrow::Cow::Borrowed("#698B69"), rgb:(105,139,105), aliases:&["dkseagreen4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "seagreen1" => crate::Color{ name:std::borrow::Cow::Borrowed("SeaGreen1"), hex:std::borrow::Cow::Borrowed("#54FF9F"), rgb:(84,255,159), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "seagreen2" => crate::Color{ name:std::borrow::Cow::Borrowed("SeaGreen2"), hex:std::borrow::Cow::Borrowed("#4EEE94"), rgb:(78,238,148), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "seagreen3" => crate::Color{ name:std::borrow::Cow::Borrowed("SeaGreen3"), hex:std::borrow::Cow::Borrowed("#43CD80"), rgb:(67,205,128), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "seagreen4" => crate::Color{ name:std::borrow::Cow::Borrowed("SeaGreen4"), hex:std::borrow::Cow::Borrowed("#2E8B57"), rgb:(46,139,87), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palegreen1" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleGreen1"), hex:std::borrow::Cow::Borrowed("#9AFF9A"), rgb:(154,255,154), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palegreen2" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleGreen2"), hex:std::borrow::Cow::Borrowed("#90EE90"), rgb:(144,238,144), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palegreen3" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleGreen3"), hex:std::borrow::Cow::Borrowed("#7CCD7C"), rgb:(124,205,124), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palegreen4" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleGreen4"), hex:std::borrow::Cow::Borrowed("#548B54"), rgb:(84,139,84), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "springgreen1" => crate::Color{ name:std::borrow::Cow::Borrowed("SpringGreen1"), hex:std::borrow::Cow::Borrowed("#00FF7F"), rgb:(0,255,127), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "springgreen2" => crate::Color{ name:std::borrow::Cow::Borrowed("SpringGreen2"), hex:std::borrow::Cow::Borrowed("#00EE76"), rgb:(0,238,118), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "springgreen3" => crate::Color{ name:std::borrow::Cow::Borrowed("SpringGreen3"), hex:std::borrow::Cow::Borrowed("#00CD66"), rgb:(0,205,102), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "springgreen4" => crate::Color{ name:std::borrow::Cow::Borrowed("SpringGreen4"), hex:std::borrow::Cow::Borrowed("#008B45"), rgb:(0,139,69), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "green1" => crate::Color{ name:std::borrow::Cow::Borrowed("green1"), hex:std::borrow::Cow::Borrowed("#00FF00"), rgb:(0,255,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "green2" => crate::Color{ name:std::borrow::Cow::Borrowed("green2"), hex:std::borrow::Cow::Borrowed("#00EE00"), rgb:(0,238,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "green3" => crate::Color{ name:std::borrow::Cow::Borrowed("green3"), hex:std::borrow::Cow::Borrowed("#00CD00"), rgb:(0,205,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "green4" => crate::Color{ name:std::borrow::Cow::Borrowed("green4"), hex:std::borrow::Cow::Borrowed("#008B00"), rgb:(0,139,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "chartreuse1" => crate::Color{ name:std::borrow::Cow::Borrowed("chartreuse1"), hex:std::borrow::Cow::Borrowed("#7FFF00"), rgb:(127,255,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "chartreuse2" => crate::Color{ name:std::borrow::Cow::Borrowed("chartreuse2"), hex:std::borrow::Cow::Borrowed("#76EE00"), rgb:(118,238,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "chartreuse3" => crate::Color{ name:std::borrow::Cow::Borrowed("chartreuse3"), hex:std::borrow::Cow::Borrowed("#66CD00"), rgb:(102,205,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "chartreuse4" => crate::Color{ name:std::borrow::Cow::Borrowed("chartreuse4"), hex:std::borrow::Cow::Borrowed("#458B00"), rgb:(69,139,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "olivedrab1" => crate::Color{ name:std::borrow::Cow::Borrowed("OliveDrab1"), hex:std::borrow::Cow::Borrowed("#C0FF3E"), rgb:(192,255,62), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "olivedrab2" => crate::Color{ name:std::borrow::Cow::Borrowed("OliveDrab2"), hex:std::borrow::Cow::Borrowed("#B3EE3A"), rgb:(179,238,58), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "olivedrab3" => crate::Color{ name:std::borrow::Cow::Borrowed("OliveDrab3"), hex:std::borrow::Cow::Borrowed("#9ACD32"), rgb:(154,205,50), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "olivedrab4" => crate::Color{ name:std::borrow::Cow::Borrowed("OliveDrab4"), hex:std::borrow::Cow::Borrowed("#698B22"), rgb:(105,139,34), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkolivegreen1" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOliveGreen1"), hex:std::borrow::Cow::Borrowed("#CAFF70"), rgb:(202,255,112), aliases:&["dkolivegreen1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkolivegreen2" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOliveGreen2"), hex:std::borrow::Cow::Borrowed("#BCEE68"), rgb:(188,238,104), aliases:&["dkolivegreen2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkolivegreen3" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOliveGreen3"), hex:std::borrow::Cow::Borrowed("#A2CD5A"), rgb:(162,205,90), aliases:&["dkolivegreen3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkolivegreen4" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOliveGreen4"), hex:std::borrow::Cow::Borrowed("#6E8B3D"), rgb:(110,139,61), aliases:&["dkolivegreen4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "khaki1" => crate::Color{ name:std::borrow::Cow::Borrowed("khaki1"), hex:std::borrow::Cow::Borrowed("#FFF68F"), rgb:(255,246,143), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "khaki2" => crate::Color{ name:std::borrow::Cow::Borrowed("khaki2"), hex:std::borrow::Cow::Borrowed("#EEE685"), rgb:(238,230,133), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "khaki3" => crate::Color{ name:std::borrow::Cow::Borrowed("khaki3"), hex:std::borrow::Cow::Borrowed("#CDC673"), rgb:(205,198,115), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "khaki4" => crate::Color{ name:std::borrow::Cow::Borrowed("khaki4"), hex:std::borrow::Cow::Borrowed("#8B864E"), rgb:(139,134,78), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightgoldenrod1" => crate::Color{ name:std::borrow::Cow::Borrowed("LightGoldenrod1"), hex:std::borrow::Cow::Borrowed("#FFEC8B"), rgb:(255,236,139), aliases:&["ltgoldenrod1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightgoldenrod2" => crate::Color{ name:std::borrow::Cow::Borrowed("LightGoldenrod2"), hex:std::borrow::Cow::Borrowed("#EEDC82"), rgb:(238,220,130), aliases:&["ltgoldenrod2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightgoldenrod3" => crate::Color{ name:std::borrow::Cow::Borrowed("LightGoldenrod3"), hex:std::borrow::Cow::Borrowed("#CDBE70"), rgb:(205,190,112), aliases:&["ltgoldenrod3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightgoldenrod4" => crate::Color{ name:std::borrow::Cow::Borrowed("LightGoldenrod4"), hex:std::borrow::Cow::Borrowed("#8B814C"), rgb:(139,129,76), aliases:&["ltgoldenrod4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightyellow1" => crate::Color{ name:std::borrow::Cow::Borrowed("LightYellow1"), hex:std::borrow::Cow::Borrowed("#FFFFE0"), rgb:(255,255,224), aliases:&["ltyellow1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightyellow2" => crate::Color{ name:std::borrow::Cow::Borrowed("LightYellow2"), hex:std::borrow::Cow::Borrowed("#EEEED1"), rgb:(238,238,209), aliases:&["ltyellow2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightyellow3" => crate::Color{ name:std::borrow::Cow::Borrowed("LightYellow3"), hex:std::borrow::Cow::Borrowed("#CDCDB4"), rgb:(205,205,180), aliases:&["ltyellow3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightyellow4" => crate::Color{ name:std::borrow::Cow::Borrowed("LightYellow4"), hex:std::borrow::Cow::Borrowed("#8B8B7A"), rgb:(139,139,122), aliases:&["ltyellow4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "yellow1" => crate::Color{ name:std::borrow::Cow::Borrowed("yellow1"), hex:std::borrow::Cow::Borrowed("#FFFF00"), rgb:(255,255,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "yellow2" => crate::Color{ name:std::borrow::Cow::Borrowed("yellow2"), hex:std::borrow::Cow::Borrowed("#EEEE00"), rgb:(238,238,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "yellow3" => crate::Color{ name:std::borrow::Cow::Borrowed("yellow3"), hex:std::borrow::Cow::Borrowed("#CDCD00"), rgb:(205,205,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "yellow4" => crate::Color{ name:std::borrow::Cow::Borrowed("yellow4"), hex:std::borrow::Cow::Borrowed("#8B8B00"), rgb:(139,139,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gold1" => crate::Color{ name:std::borrow::Cow::Borrowed("gold1"), hex:std::borrow::Cow::Borrowed("#FFD700"), rgb:(255,215,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gold2" => crate::Color{ name:std::borrow::Cow::Borrowed("gold2"), hex:std::borrow::Cow::Borrowed("#EEC900"), rgb:(238,201,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gold3" => crate::Color{ name:std::borrow::Cow::Borrowed("gold3"), hex:std::borrow::Cow::Borrowed("#CDAD00"), rgb:(205,173,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gold4" => crate::Color{ name:std::borrow::Cow::Borrowed("gold4"), hex:std::borrow::Cow::Borrowed("#8B7500"), rgb:(139,117,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "goldenrod1" => crate::Color{ name:std::borrow::Cow::Borrowed("goldenrod1"), hex:std::borrow::Cow::Borrowed("#FFC125"), rgb:(255,193,37), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "goldenrod2" => crate::Color{ name:std::borrow::Cow::Borrowed("goldenrod2"), hex:std::borrow::Cow::Borrowed("#EEB422"), rgb:(238,180,34), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "goldenrod3" => crate::Color{ name:std::borrow::Cow::Borrowed("goldenrod3"), hex:std::borrow::Cow::Borrowed("#CD9B1D"), rgb:(205,155,29), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "goldenrod4" => crate::Color{ name:std::borrow::Cow::Borrowed("goldenrod4"), hex:std::borrow::Cow::Borrowed("#8B6914"), rgb:(139,105,20), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkgoldenrod1" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkGoldenrod1"), hex:std::borrow::Cow::Borrowed("#FFB90F"), rgb:(255,185,15), aliases:&["dkgoldenrod1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkgoldenrod2" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkGoldenrod2"), hex:std::borrow::Cow::Borrowed("#EEAD0E"), rgb:(238,173,14), aliases:&["dkgoldenrod2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkgoldenrod3" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkGoldenrod3"), hex:std::borrow::Cow::Borrowed("#CD950C"), rgb:(205,149,12), aliases:&["dkgoldenrod3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkgoldenrod4" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkGoldenrod4"), hex:std::borrow::Cow::Borrowed("#8B6508"), rgb:(139,101,8), aliases:&["dkgoldenrod4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "rosybrown1" => crate::Color{ name:std::borrow::Cow::Borrowed("RosyBrown1"), hex:std::borrow::Cow::Borrowed("#FFC1C1"), rgb:(255,193,193), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "rosybrown2" => crate::Color{ name:std::borrow::Cow::Borrowed("RosyBrown2"), hex:std::borrow::Cow::Borrowed("#EEB4B4"), rgb:(238,180,180), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "rosybrown3" => crate::Color{ name:std::borrow::Cow::Borrowed("RosyBrown3"), hex:std::borrow::Cow::Borrowed("#CD9B9B"), rgb:(205,155,155), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "rosybrown4" => crate::Color{ name:std::borrow::Cow::Borrowed("RosyBrown4"), hex:std::borrow::Cow::Borrowed("#8B6969"), rgb:(139,105,105), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "indianred1" => crate::Color{ name:std::borrow::Cow::Borrowed("IndianRed1"), hex:std::borrow::Cow::Borrowed("#FF6A6A"), rgb:(255,106,106), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "indianred2" => crate::Color{ name:std::borrow::Cow::Borrowed("IndianRed2"), hex:std::borrow::Cow::Borrowed("#EE6363"), rgb:(238,99,99), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "indianred3" => crate::Color{ name:std::borrow::Cow::Borrowed("IndianRed3"), hex:std::borrow::Cow::Borrowed("#CD5555"), rgb:(205,85,85), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "indianred4" => crate::Color{ name:std::borrow::Cow::Borrowed("IndianRed4"), hex:std::borrow::Cow::Borrowed("#8B3A3A"), rgb:(139,58,58), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "sienna1" => crate::Color{ name:std::borrow::Cow::Borrowed("sienna1"), hex:std::borrow::Cow::Borrowed("#FF8247"), rgb:(255,130,71), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "sienna2" => crate::Color{ name:std::borrow::Cow::Borrowed("sienna2"), hex:std::borrow::Cow::Borrowed("#EE7942"), rgb:(238,121,66), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "sienna3" => crate::Color{ name:std::borrow::Cow::Borrowed("sienna3"), hex:std::borrow::Cow::Borrowed("#CD6839"), rgb:(205,104,57), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "sienna4" => crate::Color{ name:std::borrow::Cow::Borrowed("sienna4"), hex:std::borrow::Cow::Borrowed("#8B4726"), rgb:(139,71,38), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "burlywood1" => crate::Color{ name:std::borrow::Cow::Borrowed("burlywood1"), hex:std::borrow::Cow::Borrowed("#FFD39B"), rgb:(255,211,155), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "burlywood2" => crate::Color{ name:std::borrow::Cow::Borrowed("burlywood2"), hex:std::borrow::Cow::Borrowed("#EEC591"), rgb:(238,197,145), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "burlywood3" => crate::Color{ name:std::borrow::Cow::Borrowed("burlywood3"), hex:std::borrow::Cow::Borrowed("#CDAA7D"), rgb:(205,170,125), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "burlywood4" => crate::Color{ name:std::borrow::Cow::Borrowed("burlywood4"), hex:std::borrow::Cow::Borrowed("#8B7355"), rgb:(139,115,85), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "wheat1" => crate::Color{ name:std::borrow::Cow::Borrowed("wheat1"), hex:std::borrow::Cow::Borrowed("#FFE7BA"), rgb:(255,231,186), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "wheat2" => crate::Color{ name:std::borrow::Cow::Borrowed("wheat2"), hex:std::borrow::Cow::Borrowed("#EED8AE"), rgb:(238,216,174), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "wheat3" => crate::Color{ name:std::borrow::Cow::Borrowed("wheat3"), hex:std::borrow::Cow::Borrowed("#CDBA96"), rgb:(205,186,150), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "wheat4" => crate::Color{ name:std::borrow::Cow::Borrowed("wheat4"), hex:std::borrow::Cow::Borrowed("#8B7E66"), rgb:(139,126,102), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "tan1" => crate::Color{ name:std::borrow::Cow::Borrowed("tan1"), hex:std::borrow::Cow::Borrowed("#FFA54F"), rgb:(255,165,79), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "tan2" => crate::Color{ name:std::borrow::Cow::Borrowed("tan2"), hex:std::borrow::Cow::Borrowed("#EE9A49"), rgb:(238,154,73), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "tan3" => crate::Color{ name:std::borrow::Cow::Borrowed("tan3"), hex:std::borrow::Cow::Borrowed("#CD853F"), rgb:(205,133,63), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "tan4" => crate::Color{ name:std::borrow::Cow::Borrowed("tan4"), hex:std::borrow::Cow::Borrowed("#8B5A2B"), rgb:(139,90,43), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "chocolate1" => crate::Color{ name:std::borrow::Cow::Borrowed("chocolate1"), hex:std::borrow::Cow::Borrowed("#FF7F24"), rgb:(255,127,36), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "chocolate2" => crate::Color{ name:std::borrow::Cow::Borrowed("chocolate2"), hex:std::borrow::Cow::Borrowed("#EE7621"), rgb:(238,118,33), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "chocolate3" => crate::Color{ name:std::borrow::Cow::Borrowed("chocolate3"), hex:std::borrow::Cow::Borrowed("#CD661D"), rgb:(205,102,29), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "chocolate4" => crate::Color{ name:std::borrow::Cow::Borrowed("chocolate4"), hex:std::borrow::Cow::Borrowed("#8B4513"), rgb:(139,69,19), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "firebrick1" => crate::Color{ name:std::borrow::Cow::Borrowed("firebrick1"), hex:std::borrow::Cow::Borrowed("#FF3030"), rgb:(255,48,48), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "firebrick2" => crate::Color{ name:std::borrow::Cow::Borrowed("firebrick2"), hex:std::borrow::Cow::Borrowed("#EE2C2C"), rgb:(238,44,44), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "firebrick3" => crate::Color{ name:std::borrow::Cow::Borrowed("firebrick3"), hex:std::borrow::Cow::Borrowed("#CD2626"), rgb:(205,38,38), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "firebrick4" => crate::Color{ name:std::borrow::Cow::Borrowed("firebrick4"), hex:std::borrow::Cow::Borrowed("#8B1A1A"), rgb:(139,26,26), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "brown1" => crate::Color{ name:std::borrow::Cow::Borrowed("brown1"), hex:std::borrow::Cow::Borrowed("#FF4040"), rgb:(255,64,64), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "brown2" => crate::Color{ name:std::borrow::Cow::Borrowed("brown2"), hex:std::borrow::Cow::Borrowed("#EE3B3B"), rgb:(238,59,59), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "brown3" => crate::Color{ name:std::borrow::Cow::Borrowed("brown3"), hex:std::borrow::Cow::Borrowed("#CD3333"), rgb:(205,51,51), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "brown4" => crate::Color{ name:std::borrow::Cow::Borrowed("brown4"), hex:std::borrow::Cow::Borrowed("#8B2323"), rgb:(139,35,35), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "salmon1" => crate::Color{ name:std::borrow::Cow::Borrowed("salmon1"), hex:std::borrow::Cow::Borrowed("#FF8C69"), rgb:(255,140,105), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "salmon2" => crate::Color{ name:std::borrow::Cow::Borrowed("salmon2"), hex:std::borrow::Cow::Borrowed("#EE8262"), rgb:(238,130,98), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "salmon3" => crate::Color{ name:std::borrow::Cow::Borrowed("salmon3"), hex:std::borrow::Cow::Borrowed("#CD7054"), rgb:(205,112,84), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "salmon4" => crate::Color{ name:std::borrow::Cow::Borrowed("salmon4"), hex:std::borrow::Cow::Borrowed("#8B4C39"), rgb:(139,76,57), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightsalmon1" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSalmon1"), hex:std::borrow::Cow::Borrowed("#FFA07A"), rgb:(255,160,122), aliases:&["ltsalmon1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightsalmon2" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSalmon2"), hex:std::borrow::Cow::Borrowed("#EE9572"), rgb:(238,149,114), aliases:&["ltsalmon2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightsalmon3" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSalmon3"), hex:std::borrow::Cow::Borrowed("#CD8162"), rgb:(205,129,98), aliases:&["ltsalmon3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightsalmon4" => crate::Color{ name:std::borrow::Cow::Borrowed("LightSalmon4"), hex:std::borrow::Cow::Borrowed("#8B5742"), rgb:(139,87,66), aliases:&["ltsalmon4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orange1" => crate::Color{ name:std::borrow::Cow::Borrowed("orange1"), hex:std::borrow::Cow::Borrowed("#FFA500"), rgb:(255,165,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orange2" => crate::Color{ name:std::borrow::Cow::Borrowed("orange2"), hex:std::borrow::Cow::Borrowed("#EE9A00"), rgb:(238,154,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orange3" => crate::Color{ name:std::borrow::Cow::Borrowed("orange3"), hex:std::borrow::Cow::Borrowed("#CD8500"), rgb:(205,133,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orange4" => crate::Color{ name:std::borrow::Cow::Borrowed("orange4"), hex:std::borrow::Cow::Borrowed("#8B5A00"), rgb:(139,90,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkorange1" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOrange1"), hex:std::borrow::Cow::Borrowed("#FF7F00"), rgb:(255,127,0), aliases:&["dkorange1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkorange2" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOrange2"), hex:std::borrow::Cow::Borrowed("#EE7600"), rgb:(238,118,0), aliases:&["dkorange2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkorange3" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOrange3"), hex:std::borrow::Cow::Borrowed("#CD6600"), rgb:(205,102,0), aliases:&["dkorange3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkorange4" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOrange4"), hex:std::borrow::Cow::Borrowed("#8B4500"), rgb:(139,69,0), aliases:&["dkorange4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "coral1" => crate::Color{ name:std::borrow::Cow::Borrowed("coral1"), hex:std::borrow::Cow::Borrowed("#FF7256"), rgb:(255,114,86), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "coral2" => crate::Color{ name:std::borrow::Cow::Borrowed("coral2"), hex:std::borrow::Cow::Borrowed("#EE6A50"), rgb:(238,106,80), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "coral3" => crate::Color{ name:std::borrow::Cow::Borrowed("coral3"), hex:std::borrow::Cow::Borrowed("#CD5B45"), rgb:(205,91,69), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "coral4" => crate::Color{ name:std::borrow::Cow::Borrowed("coral4"), hex:std::borrow::Cow::Borrowed("#8B3E2F"), rgb:(139,62,47), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "tomato1" => crate::Color{ name:std::borrow::Cow::Borrowed("tomato1"), hex:std::borrow::Cow::Borrowed("#FF6347"), rgb:(255,99,71), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "tomato2" => crate::Color{ name:std::borrow::Cow::Borrowed("tomato2"), hex:std::borrow::Cow::Borrowed("#EE5C42"), rgb:(238,92,66), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "tomato3" => crate::Color{ name:std::borrow::Cow::Borrowed("tomato3"), hex:std::borrow::Cow::Borrowed("#CD4F39"), rgb:(205,79,57), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "tomato4" => crate::Color{ name:std::borrow::Cow::Borrowed("tomato4"), hex:std::borrow::Cow::Borrowed("#8B3626"), rgb:(139,54,38), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orangered1" => crate::Color{ name:std::borrow::Cow::Borrowed("OrangeRed1"), hex:std::borrow::Cow::Borrowed("#FF4500"), rgb:(255,69,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orangered2" => crate::Color{ name:std::borrow::Cow::Borrowed("OrangeRed2"), hex:std::borrow::Cow::Borrowed("#EE4000"), rgb:(238,64,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orangered3" => crate::Color{ name:std::borrow::Cow::Borrowed("OrangeRed3"), hex:std::borrow::Cow::Borrowed("#CD3700"), rgb:(205,55,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orangered4" => crate::Color{ name:std::borrow::Cow::Borrowed("OrangeRed4"), hex:std::borrow::Cow::Borrowed("#8B2500"), rgb:(139,37,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "red1" => crate::Color{ name:std::borrow::Cow::Borrowed("red1"), hex:std::borrow::Cow::Borrowed("#FF0000"), rgb:(255,0,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "red2" => crate::Color{ name:std::borrow::Cow::Borrowed("red2"), hex:std::borrow::Cow::Borrowed("#EE0000"), rgb:(238,0,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "red3" => crate::Color{ name:std::borrow::Cow::Borrowed("red3"), hex:std::borrow::Cow::Borrowed("#CD0000"), rgb:(205,0,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "red4" => crate::Color{ name:std::borrow::Cow::Borrowed("red4"), hex:std::borrow::Cow::Borrowed("#8B0000"), rgb:(139,0,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "debianred" => crate::Color{ name:std::borrow::Cow::Borrowed("DebianRed"), hex:std::borrow::Cow::Borrowed("#D70751"), rgb:(215,7,81), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "deeppink1" => crate::Color{ name:std::borrow::Cow::Borrowed("DeepPink1"), hex:std::borrow::Cow::Borrowed("#FF1493"), rgb:(255,20,147), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "deeppink2" => crate::Color{ name:std::borrow::Cow::Borrowed("DeepPink2"), hex:std::borrow::Cow::Borrowed("#EE1289"), rgb:(238,18,137), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "deeppink3" => crate::Color{ name:std::borrow::Cow::Borrowed("DeepPink3"), hex:std::borrow::Cow::Borrowed("#CD1076"), rgb:(205,16,118), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "deeppink4" => crate::Color{ name:std::borrow::Cow::Borrowed("DeepPink4"), hex:std::borrow::Cow::Borrowed("#8B0A50"), rgb:(139,10,80), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "hotpink1" => crate::Color{ name:std::borrow::Cow::Borrowed("HotPink1"), hex:std::borrow::Cow::Borrowed("#FF6EB4"), rgb:(255,110,180), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "hotpink2" => crate::Color{ name:std::borrow::Cow::Borrowed("HotPink2"), hex:std::borrow::Cow::Borrowed("#EE6AA7"), rgb:(238,106,167), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "hotpink3" => crate::Color{ name:std::borrow::Cow::Borrowed("HotPink3"), hex:std::borrow::Cow::Borrowed("#CD6090"), rgb:(205,96,144), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "hotpink4" => crate::Color{ name:std::borrow::Cow::Borrowed("HotPink4"), hex:std::borrow::Cow::Borrowed("#8B3A62"), rgb:(139,58,98), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "pink1" => crate::Color{ name:std::borrow::Cow::Borrowed("pink1"), hex:std::borrow::Cow::Borrowed("#FFB5C5"), rgb:(255,181,197), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "pink2" => crate::Color{ name:std::borrow::Cow::Borrowed("pink2"), hex:std::borrow::Cow::Borrowed("#EEA9B8"), rgb:(238,169,184), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "pink3" => crate::Color{ name:std::borrow::Cow::Borrowed("pink3"), hex:std::borrow::Cow::Borrowed("#CD919E"), rgb:(205,145,158), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "pink4" => crate::Color{ name:std::borrow::Cow::Borrowed("pink4"), hex:std::borrow::Cow::Borrowed("#8B636C"), rgb:(139,99,108), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightpink1" => crate::Color{ name:std::borrow::Cow::Borrowed("LightPink1"), hex:std::borrow::Cow::Borrowed("#FFAEB9"), rgb:(255,174,185), aliases:&["ltpink1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightpink2" => crate::Color{ name:std::borrow::Cow::Borrowed("LightPink2"), hex:std::borrow::Cow::Borrowed("#EEA2AD"), rgb:(238,162,173), aliases:&["ltpink2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightpink3" => crate::Color{ name:std::borrow::Cow::Borrowed("LightPink3"), hex:std::borrow::Cow::Borrowed("#CD8C95"), rgb:(205,140,149), aliases:&["ltpink3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightpink4" => crate::Color{ name:std::borrow::Cow::Borrowed("LightPink4"), hex:std::borrow::Cow::Borrowed("#8B5F65"), rgb:(139,95,101), aliases:&["ltpink4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palevioletred1" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleVioletRed1"), hex:std::borrow::Cow::Borrowed("#FF82AB"), rgb:(255,130,171), aliases:&["palevoiletred1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palevioletred2" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleVioletRed2"), hex:std::borrow::Cow::Borrowed("#EE799F"), rgb:(238,121,159), aliases:&["palevoiletred2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palevioletred3" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleVioletRed3"), hex:std::borrow::Cow::Borrowed("#CD6889"), rgb:(205,104,137), aliases:&["palevoiletred3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "palevioletred4" => crate::Color{ name:std::borrow::Cow::Borrowed("PaleVioletRed4"), hex:std::borrow::Cow::Borrowed("#8B475D"), rgb:(139,71,93), aliases:&["palevoiletred4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "maroon1" => crate::Color{ name:std::borrow::Cow::Borrowed("maroon1"), hex:std::borrow::Cow::Borrowed("#FF34B3"), rgb:(255,52,179), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "maroon2" => crate::Color{ name:std::borrow::Cow::Borrowed("maroon2"), hex:std::borrow::Cow::Borrowed("#EE30A7"), rgb:(238,48,167), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "maroon3" => crate::Color{ name:std::borrow::Cow::Borrowed("maroon3"), hex:std::borrow::Cow::Borrowed("#CD2990"), rgb:(205,41,144), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "maroon4" => crate::Color{ name:std::borrow::Cow::Borrowed("maroon4"), hex:std::borrow::Cow::Borrowed("#8B1C62"), rgb:(139,28,98), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "violetred1" => crate::Color{ name:std::borrow::Cow::Borrowed("VioletRed1"), hex:std::borrow::Cow::Borrowed("#FF3E96"), rgb:(255,62,150), aliases:&["voiletred1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "violetred2" => crate::Color{ name:std::borrow::Cow::Borrowed("VioletRed2"), hex:std::borrow::Cow::Borrowed("#EE3A8C"), rgb:(238,58,140), aliases:&["voiletred2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "violetred3" => crate::Color{ name:std::borrow::Cow::Borrowed("VioletRed3"), hex:std::borrow::Cow::Borrowed("#CD3278"), rgb:(205,50,120), aliases:&["voiletred3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "violetred4" => crate::Color{ name:std::borrow::Cow::Borrowed("VioletRed4"), hex:std::borrow::Cow::Borrowed("#8B2252"), rgb:(139,34,82), aliases:&["voiletred4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "magenta1" => crate::Color{ name:std::borrow::Cow::Borrowed("magenta1"), hex:std::borrow::Cow::Borrowed("#FF00FF"), rgb:(255,0,255), aliases:&["megenta1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "magenta2" => crate::Color{ name:std::borrow::Cow::Borrowed("magenta2"), hex:std::borrow::Cow::Borrowed("#EE00EE"), rgb:(238,0,238), aliases:&["megenta2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "magenta3" => crate::Color{ name:std::borrow::Cow::Borrowed("magenta3"), hex:std::borrow::Cow::Borrowed("#CD00CD"), rgb:(205,0,205), aliases:&["megenta3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "magenta4" => crate::Color{ name:std::borrow::Cow::Borrowed("magenta4"), hex:std::borrow::Cow::Borrowed("#8B008B"), rgb:(139,0,139), aliases:&["megenta4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orchid1" => crate::Color{ name:std::borrow::Cow::Borrowed("orchid1"), hex:std::borrow::Cow::Borrowed("#FF83FA"), rgb:(255,131,250), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orchid2" => crate::Color{ name:std::borrow::Cow::Borrowed("orchid2"), hex:std::borrow::Cow::Borrowed("#EE7AE9"), rgb:(238,122,233), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orchid3" => crate::Color{ name:std::borrow::Cow::Borrowed("orchid3"), hex:std::borrow::Cow::Borrowed("#CD69C9"), rgb:(205,105,201), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "orchid4" => crate::Color{ name:std::borrow::Cow::Borrowed("orchid4"), hex:std::borrow::Cow::Borrowed("#8B4789"), rgb:(139,71,137), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "plum1" => crate::Color{ name:std::borrow::Cow::Borrowed("plum1"), hex:std::borrow::Cow::Borrowed("#FFBBFF"), rgb:(255,187,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "plum2" => crate::Color{ name:std::borrow::Cow::Borrowed("plum2"), hex:std::borrow::Cow::Borrowed("#EEAEEE"), rgb:(238,174,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "plum3" => crate::Color{ name:std::borrow::Cow::Borrowed("plum3"), hex:std::borrow::Cow::Borrowed("#CD96CD"), rgb:(205,150,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "plum4" => crate::Color{ name:std::borrow::Cow::Borrowed("plum4"), hex:std::borrow::Cow::Borrowed("#8B668B"), rgb:(139,102,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumorchid1" => crate::Color{ name:std::borrow::Cow::Borrowed("MediumOrchid1"), hex:std::borrow::Cow::Borrowed("#E066FF"), rgb:(224,102,255), aliases:&["medorchid1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumorchid2" => crate::Color{ name:std::borrow::Cow::Borrowed("MediumOrchid2"), hex:std::borrow::Cow::Borrowed("#D15FEE"), rgb:(209,95,238), aliases:&["medorchid2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumorchid3" => crate::Color{ name:std::borrow::Cow::Borrowed("MediumOrchid3"), hex:std::borrow::Cow::Borrowed("#B452CD"), rgb:(180,82,205), aliases:&["medorchid3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumorchid4" => crate::Color{ name:std::borrow::Cow::Borrowed("MediumOrchid4"), hex:std::borrow::Cow::Borrowed("#7A378B"), rgb:(122,55,139), aliases:&["medorchid4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkorchid1" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOrchid1"), hex:std::borrow::Cow::Borrowed("#BF3EFF"), rgb:(191,62,255), aliases:&["dkorchid1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkorchid2" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOrchid2"), hex:std::borrow::Cow::Borrowed("#B23AEE"), rgb:(178,58,238), aliases:&["dkorchid2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkorchid3" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOrchid3"), hex:std::borrow::Cow::Borrowed("#9A32CD"), rgb:(154,50,205), aliases:&["dkorchid3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkorchid4" => crate::Color{ name:std::borrow::Cow::Borrowed("DarkOrchid4"), hex:std::borrow::Cow::Borrowed("#68228B"), rgb:(104,34,139), aliases:&["dkorchid4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "purple1" => crate::Color{ name:std::borrow::Cow::Borrowed("purple1"), hex:std::borrow::Cow::Borrowed("#9B30FF"), rgb:(155,48,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "purple2" => crate::Color{ name:std::borrow::Cow::Borrowed("purple2"), hex:std::borrow::Cow::Borrowed("#912CEE"), rgb:(145,44,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "purple3" => crate::Color{ name:std::borrow::Cow::Borrowed("purple3"), hex:std::borrow::Cow::Borrowed("#7D26CD"), rgb:(125,38,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "purple4" => crate::Color{ name:std::borrow::Cow::Borrowed("purple4"), hex:std::borrow::Cow::Borrowed("#551A8B"), rgb:(85,26,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumpurple1" => crate::Color{ name:std::borrow::Cow::Borrowed("MediumPurple1"), hex:std::borrow::Cow::Borrowed("#AB82FF"), rgb:(171,130,255), aliases:&["medpurple1"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumpurple2" => crate::Color{ name:std::borrow::Cow::Borrowed("MediumPurple2"), hex:std::borrow::Cow::Borrowed("#9F79EE"), rgb:(159,121,238), aliases:&["medpurple2"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumpurple3" => crate::Color{ name:std::borrow::Cow::Borrowed("MediumPurple3"), hex:std::borrow::Cow::Borrowed("#8968CD"), rgb:(137,104,205), aliases:&["medpurple3"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "mediumpurple4" => crate::Color{ name:std::borrow::Cow::Borrowed("MediumPurple4"), hex:std::borrow::Cow::Borrowed("#5D478B"), rgb:(93,71,139), aliases:&["medpurple4"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "thistle1" => crate::Color{ name:std::borrow::Cow::Borrowed("thistle1"), hex:std::borrow::Cow::Borrowed("#FFE1FF"), rgb:(255,225,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "thistle2" => crate::Color{ name:std::borrow::Cow::Borrowed("thistle2"), hex:std::borrow::Cow::Borrowed("#EED2EE"), rgb:(238,210,238), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "thistle3" => crate::Color{ name:std::borrow::Cow::Borrowed("thistle3"), hex:std::borrow::Cow::Borrowed("#CDB5CD"), rgb:(205,181,205), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "thistle4" => crate::Color{ name:std::borrow::Cow::Borrowed("thistle4"), hex:std::borrow::Cow::Borrowed("#8B7B8B"), rgb:(139,123,139), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray0" => crate::Color{ name:std::borrow::Cow::Borrowed("gray0"), hex:std::borrow::Cow::Borrowed("#000000"), rgb:(0,0,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey0" => crate::Color{ name:std::borrow::Cow::Borrowed("grey0"), hex:std::borrow::Cow::Borrowed("#000000"), rgb:(0,0,0), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray1" => crate::Color{ name:std::borrow::Cow::Borrowed("gray1"), hex:std::borrow::Cow::Borrowed("#030303"), rgb:(3,3,3), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey1" => crate::Color{ name:std::borrow::Cow::Borrowed("grey1"), hex:std::borrow::Cow::Borrowed("#030303"), rgb:(3,3,3), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray2" => crate::Color{ name:std::borrow::Cow::Borrowed("gray2"), hex:std::borrow::Cow::Borrowed("#050505"), rgb:(5,5,5), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey2" => crate::Color{ name:std::borrow::Cow::Borrowed("grey2"), hex:std::borrow::Cow::Borrowed("#050505"), rgb:(5,5,5), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray3" => crate::Color{ name:std::borrow::Cow::Borrowed("gray3"), hex:std::borrow::Cow::Borrowed("#080808"), rgb:(8,8,8), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey3" => crate::Color{ name:std::borrow::Cow::Borrowed("grey3"), hex:std::borrow::Cow::Borrowed("#080808"), rgb:(8,8,8), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray4" => crate::Color{ name:std::borrow::Cow::Borrowed("gray4"), hex:std::borrow::Cow::Borrowed("#0A0A0A"), rgb:(10,10,10), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey4" => crate::Color{ name:std::borrow::Cow::Borrowed("grey4"), hex:std::borrow::Cow::Borrowed("#0A0A0A"), rgb:(10,10,10), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray5" => crate::Color{ name:std::borrow::Cow::Borrowed("gray5"), hex:std::borrow::Cow::Borrowed("#0D0D0D"), rgb:(13,13,13), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey5" => crate::Color{ name:std::borrow::Cow::Borrowed("grey5"), hex:std::borrow::Cow::Borrowed("#0D0D0D"), rgb:(13,13,13), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray6" => crate::Color{ name:std::borrow::Cow::Borrowed("gray6"), hex:std::borrow::Cow::Borrowed("#0F0F0F"), rgb:(15,15,15), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey6" => crate::Color{ name:std::borrow::Cow::Borrowed("grey6"), hex:std::borrow::Cow::Borrowed("#0F0F0F"), rgb:(15,15,15), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray7" => crate::Color{ name:std::borrow::Cow::Borrowed("gray7"), hex:std::borrow::Cow::Borrowed("#121212"), rgb:(18,18,18), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey7" => crate::Color{ name:std::borrow::Cow::Borrowed("grey7"), hex:std::borrow::Cow::Borrowed("#121212"), rgb:(18,18,18), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray8" => crate::Color{ name:std::borrow::Cow::Borrowed("gray8"), hex:std::borrow::Cow::Borrowed("#141414"), rgb:(20,20,20), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey8" => crate::Color{ name:std::borrow::Cow::Borrowed("grey8"), hex:std::borrow::Cow::Borrowed("#141414"), rgb:(20,20,20), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray9" => crate::Color{ name:std::borrow::Cow::Borrowed("gray9"), hex:std::borrow::Cow::Borrowed("#171717"), rgb:(23,23,23), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey9" => crate::Color{ name:std::borrow::Cow::Borrowed("grey9"), hex:std::borrow::Cow::Borrowed("#171717"), rgb:(23,23,23), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray10" => crate::Color{ name:std::borrow::Cow::Borrowed("gray10"), hex:std::borrow::Cow::Borrowed("#1A1A1A"), rgb:(26,26,26), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey10" => crate::Color{ name:std::borrow::Cow::Borrowed("grey10"), hex:std::borrow::Cow::Borrowed("#1A1A1A"), rgb:(26,26,26), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray11" => crate::Color{ name:std::borrow::Cow::Borrowed("gray11"), hex:std::borrow::Cow::Borrowed("#1C1C1C"), rgb:(28,28,28), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey11" => crate::Color{ name:std::borrow::Cow::Borrowed("grey11"), hex:std::borrow::Cow::Borrowed("#1C1C1C"), rgb:(28,28,28), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray12" => crate::Color{ name:std::borrow::Cow::Borrowed("gray12"), hex:std::borrow::Cow::Borrowed("#1F1F1F"), rgb:(31,31,31), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey12" => crate::Color{ name:std::borrow::Cow::Borrowed("grey12"), hex:std::borrow::Cow::Borrowed("#1F1F1F"), rgb:(31,31,31), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray13" => crate::Color{ name:std::borrow::Cow::Borrowed("gray13"), hex:std::borrow::Cow::Borrowed("#212121"), rgb:(33,33,33), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey13" => crate::Color{ name:std::borrow::Cow::Borrowed("grey13"), hex:std::borrow::Cow::Borrowed("#212121"), rgb:(33,33,33), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray14" => crate::Color{ name:std::borrow::Cow::Borrowed("gray14"), hex:std::borrow::Cow::Borrowed("#242424"), rgb:(36,36,36), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey14" => crate::Color{ name:std::borrow::Cow::Borrowed("grey14"), hex:std::borrow::Cow::Borrowed("#242424"), rgb:(36,36,36), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray15" => crate::Color{ name:std::borrow::Cow::Borrowed("gray15"), hex:std::borrow::Cow::Borrowed("#262626"), rgb:(38,38,38), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey15" => crate::Color{ name:std::borrow::Cow::Borrowed("grey15"), hex:std::borrow::Cow::Borrowed("#262626"), rgb:(38,38,38), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray16" => crate::Color{ name:std::borrow::Cow::Borrowed("gray16"), hex:std::borrow::Cow::Borrowed("#292929"), rgb:(41,41,41), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey16" => crate::Color{ name:std::borrow::Cow::Borrowed("grey16"), hex:std::borrow::Cow::Borrowed("#292929"), rgb:(41,41,41), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray17" => crate::Color{ name:std::borrow::Cow::Borrowed("gray17"), hex:std::borrow::Cow::Borrowed("#2B2B2B"), rgb:(43,43,43), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey17" => crate::Color{ name:std::borrow::Cow::Borrowed("grey17"), hex:std::borrow::Cow::Borrowed("#2B2B2B"), rgb:(43,43,43), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray18" => crate::Color{ name:std::borrow::Cow::Borrowed("gray18"), hex:std::borrow::Cow::Borrowed("#2E2E2E"), rgb:(46,46,46), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey18" => crate::Color{ name:std::borrow::Cow::Borrowed("grey18"), hex:std::borrow::Cow::Borrowed("#2E2E2E"), rgb:(46,46,46), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray19" => crate::Color{ name:std::borrow::Cow::Borrowed("gray19"), hex:std::borrow::Cow::Borrowed("#303030"), rgb:(48,48,48), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey19" => crate::Color{ name:std::borrow::Cow::Borrowed("grey19"), hex:std::borrow::Cow::Borrowed("#303030"), rgb:(48,48,48), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray20" => crate::Color{ name:std::borrow::Cow::Borrowed("gray20"), hex:std::borrow::Cow::Borrowed("#333333"), rgb:(51,51,51), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey20" => crate::Color{ name:std::borrow::Cow::Borrowed("grey20"), hex:std::borrow::Cow::Borrowed("#333333"), rgb:(51,51,51), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray21" => crate::Color{ name:std::borrow::Cow::Borrowed("gray21"), hex:std::borrow::Cow::Borrowed("#363636"), rgb:(54,54,54), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey21" => crate::Color{ name:std::borrow::Cow::Borrowed("grey21"), hex:std::borrow::Cow::Borrowed("#363636"), rgb:(54,54,54), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray22" => crate::Color{ name:std::borrow::Cow::Borrowed("gray22"), hex:std::borrow::Cow::Borrowed("#383838"), rgb:(56,56,56), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey22" => crate::Color{ name:std::borrow::Cow::Borrowed("grey22"), hex:std::borrow::Cow::Borrowed("#383838"), rgb:(56,56,56), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray23" => crate::Color{ name:std::borrow::Cow::Borrowed("gray23"), hex:std::borrow::Cow::Borrowed("#3B3B3B"), rgb:(59,59,59), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey23" => crate::Color{ name:std::borrow::Cow::Borrowed("grey23"), hex:std::borrow::Cow::Borrowed("#3B3B3B"), rgb:(59,59,59), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray24" => crate::Color{ name:std::borrow::Cow::Borrowed("gray24"), hex:std::borrow::Cow::Borrowed("#3D3D3D"), rgb:(61,61,61), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey24" => crate::Color{ name:std::borrow::Cow::Borrowed("grey24"), hex:std::borrow::Cow::Borrowed("#3D3D3D"), rgb:(61,61,61), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray25" => crate::Color{ name:std::borrow::Cow::Borrowed("gray25"), hex:std::borrow::Cow::Borrowed("#404040"), rgb:(64,64,64), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey25" => crate::Color{ name:std::borrow::Cow::Borrowed("grey25"), hex:std::borrow::Cow::Borrowed("#404040"), rgb:(64,64,64), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray26" => crate::Color{ name:std::borrow::Cow::Borrowed("gray26"), hex:std::borrow::Cow::Borrowed("#424242"), rgb:(66,66,66), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey26" => crate::Color{ name:std::borrow::Cow::Borrowed("grey26"), hex:std::borrow::Cow::Borrowed("#424242"), rgb:(66,66,66), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray27" => crate::Color{ name:std::borrow::Cow::Borrowed("gray27"), hex:std::borrow::Cow::Borrowed("#454545"), rgb:(69,69,69), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey27" => crate::Color{ name:std::borrow::Cow::Borrowed("grey27"), hex:std::borrow::Cow::Borrowed("#454545"), rgb:(69,69,69), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray28" => crate::Color{ name:std::borrow::Cow::Borrowed("gray28"), hex:std::borrow::Cow::Borrowed("#474747"), rgb:(71,71,71), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey28" => crate::Color{ name:std::borrow::Cow::Borrowed("grey28"), hex:std::borrow::Cow::Borrowed("#474747"), rgb:(71,71,71), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray29" => crate::Color{ name:std::borrow::Cow::Borrowed("gray29"), hex:std::borrow::Cow::Borrowed("#4A4A4A"), rgb:(74,74,74), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey29" => crate::Color{ name:std::borrow::Cow::Borrowed("grey29"), hex:std::borrow::Cow::Borrowed("#4A4A4A"), rgb:(74,74,74), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray30" => crate::Color{ name:std::borrow::Cow::Borrowed("gray30"), hex:std::borrow::Cow::Borrowed("#4D4D4D"), rgb:(77,77,77), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey30" => crate::Color{ name:std::borrow::Cow::Borrowed("grey30"), hex:std::borrow::Cow::Borrowed("#4D4D4D"), rgb:(77,77,77), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray31" => crate::Color{ name:std::borrow::Cow::Borrowed("gray31"), hex:std::borrow::Cow::Borrowed("#4F4F4F"), rgb:(79,79,79), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey31" => crate::Color{ name:std::borrow::Cow::Borrowed("grey31"), hex:std::borrow::Cow::Borrowed("#4F4F4F"), rgb:(79,79,79), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray32" => crate::Color{ name:std::borrow::Cow::Borrowed("gray32"), hex:std::borrow::Cow::Borrowed("#525252"), rgb:(82,82,82), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey32" => crate::Color{ name:std::borrow::Cow::Borrowed("grey32"), hex:std::borrow::Cow::Borrowed("#525252"), rgb:(82,82,82), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray33" => crate::Color{ name:std::borrow::Cow::Borrowed("gray33"), hex:std::borrow::Cow::Borrowed("#545454"), rgb:(84,84,84), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey33" => crate::Color{ name:std::borrow::Cow::Borrowed("grey33"), hex:std::borrow::Cow::Borrowed("#545454"), rgb:(84,84,84), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray34" => crate::Color{ name:std::borrow::Cow::Borrowed("gray34"), hex:std::borrow::Cow::Borrowed("#575757"), rgb:(87,87,87), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey34" => crate::Color{ name:std::borrow::Cow::Borrowed("grey34"), hex:std::borrow::Cow::Borrowed("#575757"), rgb:(87,87,87), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray35" => crate::Color{ name:std::borrow::Cow::Borrowed("gray35"), hex:std::borrow::Cow::Borrowed("#595959"), rgb:(89,89,89), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey35" => crate::Color{ name:std::borrow::Cow::Borrowed("grey35"), hex:std::borrow::Cow::Borrowed("#595959"), rgb:(89,89,89), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray36" => crate::Color{ name:std::borrow::Cow::Borrowed("gray36"), hex:std::borrow::Cow::Borrowed("#5C5C5C"), rgb:(92,92,92), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey36" => crate::Color{ name:std::borrow::Cow::Borrowed("grey36"), hex:std::borrow::Cow::Borrowed("#5C5C5C"), rgb:(92,92,92), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray37" => crate::Color{ name:std::borrow::Cow::Borrowed("gray37"), hex:std::borrow::Cow::Borrowed("#5E5E5E"), rgb:(94,94,94), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey37" => crate::Color{ name:std::borrow::Cow::Borrowed("grey37"), hex:std::borrow::Cow::Borrowed("#5E5E5E"), rgb:(94,94,94), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray38" => crate::Color{ name:std::borrow::Cow::Borrowed("gray38"), hex:std::borrow::Cow::Borrowed("#616161"), rgb:(97,97,97), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey38" => crate::Color{ name:std::borrow::Cow::Borrowed("grey38"), hex:std::borrow::Cow::Borrowed("#616161"), rgb:(97,97,97), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray39" => crate::Color{ name:std::borrow::Cow::Borrowed("gray39"), hex:std::borrow::Cow::Borrowed("#636363"), rgb:(99,99,99), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey39" => crate::Color{ name:std::borrow::Cow::Borrowed("grey39"), hex:std::borrow::Cow::Borrowed("#636363"), rgb:(99,99,99), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray40" => crate::Color{ name:std::borrow::Cow::Borrowed("gray40"), hex:std::borrow::Cow::Borrowed("#666666"), rgb:(102,102,102), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey40" => crate::Color{ name:std::borrow::Cow::Borrowed("grey40"), hex:std::borrow::Cow::Borrowed("#666666"), rgb:(102,102,102), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray41" => crate::Color{ name:std::borrow::Cow::Borrowed("gray41"), hex:std::borrow::Cow::Borrowed("#696969"), rgb:(105,105,105), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey41" => crate::Color{ name:std::borrow::Cow::Borrowed("grey41"), hex:std::borrow::Cow::Borrowed("#696969"), rgb:(105,105,105), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray42" => crate::Color{ name:std::borrow::Cow::Borrowed("gray42"), hex:std::borrow::Cow::Borrowed("#6B6B6B"), rgb:(107,107,107), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey42" => crate::Color{ name:std::borrow::Cow::Borrowed("grey42"), hex:std::borrow::Cow::Borrowed("#6B6B6B"), rgb:(107,107,107), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray43" => crate::Color{ name:std::borrow::Cow::Borrowed("gray43"), hex:std::borrow::Cow::Borrowed("#6E6E6E"), rgb:(110,110,110), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey43" => crate::Color{ name:std::borrow::Cow::Borrowed("grey43"), hex:std::borrow::Cow::Borrowed("#6E6E6E"), rgb:(110,110,110), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray44" => crate::Color{ name:std::borrow::Cow::Borrowed("gray44"), hex:std::borrow::Cow::Borrowed("#707070"), rgb:(112,112,112), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey44" => crate::Color{ name:std::borrow::Cow::Borrowed("grey44"), hex:std::borrow::Cow::Borrowed("#707070"), rgb:(112,112,112), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray45" => crate::Color{ name:std::borrow::Cow::Borrowed("gray45"), hex:std::borrow::Cow::Borrowed("#737373"), rgb:(115,115,115), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey45" => crate::Color{ name:std::borrow::Cow::Borrowed("grey45"), hex:std::borrow::Cow::Borrowed("#737373"), rgb:(115,115,115), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray46" => crate::Color{ name:std::borrow::Cow::Borrowed("gray46"), hex:std::borrow::Cow::Borrowed("#757575"), rgb:(117,117,117), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey46" => crate::Color{ name:std::borrow::Cow::Borrowed("grey46"), hex:std::borrow::Cow::Borrowed("#757575"), rgb:(117,117,117), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray47" => crate::Color{ name:std::borrow::Cow::Borrowed("gray47"), hex:std::borrow::Cow::Borrowed("#787878"), rgb:(120,120,120), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey47" => crate::Color{ name:std::borrow::Cow::Borrowed("grey47"), hex:std::borrow::Cow::Borrowed("#787878"), rgb:(120,120,120), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray48" => crate::Color{ name:std::borrow::Cow::Borrowed("gray48"), hex:std::borrow::Cow::Borrowed("#7A7A7A"), rgb:(122,122,122), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey48" => crate::Color{ name:std::borrow::Cow::Borrowed("grey48"), hex:std::borrow::Cow::Borrowed("#7A7A7A"), rgb:(122,122,122), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray49" => crate::Color{ name:std::borrow::Cow::Borrowed("gray49"), hex:std::borrow::Cow::Borrowed("#7D7D7D"), rgb:(125,125,125), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey49" => crate::Color{ name:std::borrow::Cow::Borrowed("grey49"), hex:std::borrow::Cow::Borrowed("#7D7D7D"), rgb:(125,125,125), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray50" => crate::Color{ name:std::borrow::Cow::Borrowed("gray50"), hex:std::borrow::Cow::Borrowed("#7F7F7F"), rgb:(127,127,127), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey50" => crate::Color{ name:std::borrow::Cow::Borrowed("grey50"), hex:std::borrow::Cow::Borrowed("#7F7F7F"), rgb:(127,127,127), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray51" => crate::Color{ name:std::borrow::Cow::Borrowed("gray51"), hex:std::borrow::Cow::Borrowed("#828282"), rgb:(130,130,130), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey51" => crate::Color{ name:std::borrow::Cow::Borrowed("grey51"), hex:std::borrow::Cow::Borrowed("#828282"), rgb:(130,130,130), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray52" => crate::Color{ name:std::borrow::Cow::Borrowed("gray52"), hex:std::borrow::Cow::Borrowed("#858585"), rgb:(133,133,133), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey52" => crate::Color{ name:std::borrow::Cow::Borrowed("grey52"), hex:std::borrow::Cow::Borrowed("#858585"), rgb:(133,133,133), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray53" => crate::Color{ name:std::borrow::Cow::Borrowed("gray53"), hex:std::borrow::Cow::Borrowed("#878787"), rgb:(135,135,135), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey53" => crate::Color{ name:std::borrow::Cow::Borrowed("grey53"), hex:std::borrow::Cow::Borrowed("#878787"), rgb:(135,135,135), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray54" => crate::Color{ name:std::borrow::Cow::Borrowed("gray54"), hex:std::borrow::Cow::Borrowed("#8A8A8A"), rgb:(138,138,138), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey54" => crate::Color{ name:std::borrow::Cow::Borrowed("grey54"), hex:std::borrow::Cow::Borrowed("#8A8A8A"), rgb:(138,138,138), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray55" => crate::Color{ name:std::borrow::Cow::Borrowed("gray55"), hex:std::borrow::Cow::Borrowed("#8C8C8C"), rgb:(140,140,140), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey55" => crate::Color{ name:std::borrow::Cow::Borrowed("grey55"), hex:std::borrow::Cow::Borrowed("#8C8C8C"), rgb:(140,140,140), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray56" => crate::Color{ name:std::borrow::Cow::Borrowed("gray56"), hex:std::borrow::Cow::Borrowed("#8F8F8F"), rgb:(143,143,143), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey56" => crate::Color{ name:std::borrow::Cow::Borrowed("grey56"), hex:std::borrow::Cow::Borrowed("#8F8F8F"), rgb:(143,143,143), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray57" => crate::Color{ name:std::borrow::Cow::Borrowed("gray57"), hex:std::borrow::Cow::Borrowed("#919191"), rgb:(145,145,145), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey57" => crate::Color{ name:std::borrow::Cow::Borrowed("grey57"), hex:std::borrow::Cow::Borrowed("#919191"), rgb:(145,145,145), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray58" => crate::Color{ name:std::borrow::Cow::Borrowed("gray58"), hex:std::borrow::Cow::Borrowed("#949494"), rgb:(148,148,148), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey58" => crate::Color{ name:std::borrow::Cow::Borrowed("grey58"), hex:std::borrow::Cow::Borrowed("#949494"), rgb:(148,148,148), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray59" => crate::Color{ name:std::borrow::Cow::Borrowed("gray59"), hex:std::borrow::Cow::Borrowed("#969696"), rgb:(150,150,150), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey59" => crate::Color{ name:std::borrow::Cow::Borrowed("grey59"), hex:std::borrow::Cow::Borrowed("#969696"), rgb:(150,150,150), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray60" => crate::Color{ name:std::borrow::Cow::Borrowed("gray60"), hex:std::borrow::Cow::Borrowed("#999999"), rgb:(153,153,153), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey60" => crate::Color{ name:std::borrow::Cow::Borrowed("grey60"), hex:std::borrow::Cow::Borrowed("#999999"), rgb:(153,153,153), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray61" => crate::Color{ name:std::borrow::Cow::Borrowed("gray61"), hex:std::borrow::Cow::Borrowed("#9C9C9C"), rgb:(156,156,156), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey61" => crate::Color{ name:std::borrow::Cow::Borrowed("grey61"), hex:std::borrow::Cow::Borrowed("#9C9C9C"), rgb:(156,156,156), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray62" => crate::Color{ name:std::borrow::Cow::Borrowed("gray62"), hex:std::borrow::Cow::Borrowed("#9E9E9E"), rgb:(158,158,158), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey62" => crate::Color{ name:std::borrow::Cow::Borrowed("grey62"), hex:std::borrow::Cow::Borrowed("#9E9E9E"), rgb:(158,158,158), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray63" => crate::Color{ name:std::borrow::Cow::Borrowed("gray63"), hex:std::borrow::Cow::Borrowed("#A1A1A1"), rgb:(161,161,161), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey63" => crate::Color{ name:std::borrow::Cow::Borrowed("grey63"), hex:std::borrow::Cow::Borrowed("#A1A1A1"), rgb:(161,161,161), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray64" => crate::Color{ name:std::borrow::Cow::Borrowed("gray64"), hex:std::borrow::Cow::Borrowed("#A3A3A3"), rgb:(163,163,163), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey64" => crate::Color{ name:std::borrow::Cow::Borrowed("grey64"), hex:std::borrow::Cow::Borrowed("#A3A3A3"), rgb:(163,163,163), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray65" => crate::Color{ name:std::borrow::Cow::Borrowed("gray65"), hex:std::borrow::Cow::Borrowed("#A6A6A6"), rgb:(166,166,166), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey65" => crate::Color{ name:std::borrow::Cow::Borrowed("grey65"), hex:std::borrow::Cow::Borrowed("#A6A6A6"), rgb:(166,166,166), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray66" => crate::Color{ name:std::borrow::Cow::Borrowed("gray66"), hex:std::borrow::Cow::Borrowed("#A8A8A8"), rgb:(168,168,168), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey66" => crate::Color{ name:std::borrow::Cow::Borrowed("grey66"), hex:std::borrow::Cow::Borrowed("#A8A8A8"), rgb:(168,168,168), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray67" => crate::Color{ name:std::borrow::Cow::Borrowed("gray67"), hex:std::borrow::Cow::Borrowed("#ABABAB"), rgb:(171,171,171), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey67" => crate::Color{ name:std::borrow::Cow::Borrowed("grey67"), hex:std::borrow::Cow::Borrowed("#ABABAB"), rgb:(171,171,171), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray68" => crate::Color{ name:std::borrow::Cow::Borrowed("gray68"), hex:std::borrow::Cow::Borrowed("#ADADAD"), rgb:(173,173,173), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey68" => crate::Color{ name:std::borrow::Cow::Borrowed("grey68"), hex:std::borrow::Cow::Borrowed("#ADADAD"), rgb:(173,173,173), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray69" => crate::Color{ name:std::borrow::Cow::Borrowed("gray69"), hex:std::borrow::Cow::Borrowed("#B0B0B0"), rgb:(176,176,176), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey69" => crate::Color{ name:std::borrow::Cow::Borrowed("grey69"), hex:std::borrow::Cow::Borrowed("#B0B0B0"), rgb:(176,176,176), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray70" => crate::Color{ name:std::borrow::Cow::Borrowed("gray70"), hex:std::borrow::Cow::Borrowed("#B3B3B3"), rgb:(179,179,179), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey70" => crate::Color{ name:std::borrow::Cow::Borrowed("grey70"), hex:std::borrow::Cow::Borrowed("#B3B3B3"), rgb:(179,179,179), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray71" => crate::Color{ name:std::borrow::Cow::Borrowed("gray71"), hex:std::borrow::Cow::Borrowed("#B5B5B5"), rgb:(181,181,181), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey71" => crate::Color{ name:std::borrow::Cow::Borrowed("grey71"), hex:std::borrow::Cow::Borrowed("#B5B5B5"), rgb:(181,181,181), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray72" => crate::Color{ name:std::borrow::Cow::Borrowed("gray72"), hex:std::borrow::Cow::Borrowed("#B8B8B8"), rgb:(184,184,184), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey72" => crate::Color{ name:std::borrow::Cow::Borrowed("grey72"), hex:std::borrow::Cow::Borrowed("#B8B8B8"), rgb:(184,184,184), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray73" => crate::Color{ name:std::borrow::Cow::Borrowed("gray73"), hex:std::borrow::Cow::Borrowed("#BABABA"), rgb:(186,186,186), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey73" => crate::Color{ name:std::borrow::Cow::Borrowed("grey73"), hex:std::borrow::Cow::Borrowed("#BABABA"), rgb:(186,186,186), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray74" => crate::Color{ name:std::borrow::Cow::Borrowed("gray74"), hex:std::borrow::Cow::Borrowed("#BDBDBD"), rgb:(189,189,189), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey74" => crate::Color{ name:std::borrow::Cow::Borrowed("grey74"), hex:std::borrow::Cow::Borrowed("#BDBDBD"), rgb:(189,189,189), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray75" => crate::Color{ name:std::borrow::Cow::Borrowed("gray75"), hex:std::borrow::Cow::Borrowed("#BFBFBF"), rgb:(191,191,191), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey75" => crate::Color{ name:std::borrow::Cow::Borrowed("grey75"), hex:std::borrow::Cow::Borrowed("#BFBFBF"), rgb:(191,191,191), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray76" => crate::Color{ name:std::borrow::Cow::Borrowed("gray76"), hex:std::borrow::Cow::Borrowed("#C2C2C2"), rgb:(194,194,194), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey76" => crate::Color{ name:std::borrow::Cow::Borrowed("grey76"), hex:std::borrow::Cow::Borrowed("#C2C2C2"), rgb:(194,194,194), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray77" => crate::Color{ name:std::borrow::Cow::Borrowed("gray77"), hex:std::borrow::Cow::Borrowed("#C4C4C4"), rgb:(196,196,196), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey77" => crate::Color{ name:std::borrow::Cow::Borrowed("grey77"), hex:std::borrow::Cow::Borrowed("#C4C4C4"), rgb:(196,196,196), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray78" => crate::Color{ name:std::borrow::Cow::Borrowed("gray78"), hex:std::borrow::Cow::Borrowed("#C7C7C7"), rgb:(199,199,199), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey78" => crate::Color{ name:std::borrow::Cow::Borrowed("grey78"), hex:std::borrow::Cow::Borrowed("#C7C7C7"), rgb:(199,199,199), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray79" => crate::Color{ name:std::borrow::Cow::Borrowed("gray79"), hex:std::borrow::Cow::Borrowed("#C9C9C9"), rgb:(201,201,201), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey79" => crate::Color{ name:std::borrow::Cow::Borrowed("grey79"), hex:std::borrow::Cow::Borrowed("#C9C9C9"), rgb:(201,201,201), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray80" => crate::Color{ name:std::borrow::Cow::Borrowed("gray80"), hex:std::borrow::Cow::Borrowed("#CCCCCC"), rgb:(204,204,204), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey80" => crate::Color{ name:std::borrow::Cow::Borrowed("grey80"), hex:std::borrow::Cow::Borrowed("#CCCCCC"), rgb:(204,204,204), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray81" => crate::Color{ name:std::borrow::Cow::Borrowed("gray81"), hex:std::borrow::Cow::Borrowed("#CFCFCF"), rgb:(207,207,207), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey81" => crate::Color{ name:std::borrow::Cow::Borrowed("grey81"), hex:std::borrow::Cow::Borrowed("#CFCFCF"), rgb:(207,207,207), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray82" => crate::Color{ name:std::borrow::Cow::Borrowed("gray82"), hex:std::borrow::Cow::Borrowed("#D1D1D1"), rgb:(209,209,209), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey82" => crate::Color{ name:std::borrow::Cow::Borrowed("grey82"), hex:std::borrow::Cow::Borrowed("#D1D1D1"), rgb:(209,209,209), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray83" => crate::Color{ name:std::borrow::Cow::Borrowed("gray83"), hex:std::borrow::Cow::Borrowed("#D4D4D4"), rgb:(212,212,212), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey83" => crate::Color{ name:std::borrow::Cow::Borrowed("grey83"), hex:std::borrow::Cow::Borrowed("#D4D4D4"), rgb:(212,212,212), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray84" => crate::Color{ name:std::borrow::Cow::Borrowed("gray84"), hex:std::borrow::Cow::Borrowed("#D6D6D6"), rgb:(214,214,214), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey84" => crate::Color{ name:std::borrow::Cow::Borrowed("grey84"), hex:std::borrow::Cow::Borrowed("#D6D6D6"), rgb:(214,214,214), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray85" => crate::Color{ name:std::borrow::Cow::Borrowed("gray85"), hex:std::borrow::Cow::Borrowed("#D9D9D9"), rgb:(217,217,217), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey85" => crate::Color{ name:std::borrow::Cow::Borrowed("grey85"), hex:std::borrow::Cow::Borrowed("#D9D9D9"), rgb:(217,217,217), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray86" => crate::Color{ name:std::borrow::Cow::Borrowed("gray86"), hex:std::borrow::Cow::Borrowed("#DBDBDB"), rgb:(219,219,219), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey86" => crate::Color{ name:std::borrow::Cow::Borrowed("grey86"), hex:std::borrow::Cow::Borrowed("#DBDBDB"), rgb:(219,219,219), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray87" => crate::Color{ name:std::borrow::Cow::Borrowed("gray87"), hex:std::borrow::Cow::Borrowed("#DEDEDE"), rgb:(222,222,222), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey87" => crate::Color{ name:std::borrow::Cow::Borrowed("grey87"), hex:std::borrow::Cow::Borrowed("#DEDEDE"), rgb:(222,222,222), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray88" => crate::Color{ name:std::borrow::Cow::Borrowed("gray88"), hex:std::borrow::Cow::Borrowed("#E0E0E0"), rgb:(224,224,224), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey88" => crate::Color{ name:std::borrow::Cow::Borrowed("grey88"), hex:std::borrow::Cow::Borrowed("#E0E0E0"), rgb:(224,224,224), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray89" => crate::Color{ name:std::borrow::Cow::Borrowed("gray89"), hex:std::borrow::Cow::Borrowed("#E3E3E3"), rgb:(227,227,227), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey89" => crate::Color{ name:std::borrow::Cow::Borrowed("grey89"), hex:std::borrow::Cow::Borrowed("#E3E3E3"), rgb:(227,227,227), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray90" => crate::Color{ name:std::borrow::Cow::Borrowed("gray90"), hex:std::borrow::Cow::Borrowed("#E5E5E5"), rgb:(229,229,229), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey90" => crate::Color{ name:std::borrow::Cow::Borrowed("grey90"), hex:std::borrow::Cow::Borrowed("#E5E5E5"), rgb:(229,229,229), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray91" => crate::Color{ name:std::borrow::Cow::Borrowed("gray91"), hex:std::borrow::Cow::Borrowed("#E8E8E8"), rgb:(232,232,232), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey91" => crate::Color{ name:std::borrow::Cow::Borrowed("grey91"), hex:std::borrow::Cow::Borrowed("#E8E8E8"), rgb:(232,232,232), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray92" => crate::Color{ name:std::borrow::Cow::Borrowed("gray92"), hex:std::borrow::Cow::Borrowed("#EBEBEB"), rgb:(235,235,235), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey92" => crate::Color{ name:std::borrow::Cow::Borrowed("grey92"), hex:std::borrow::Cow::Borrowed("#EBEBEB"), rgb:(235,235,235), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray93" => crate::Color{ name:std::borrow::Cow::Borrowed("gray93"), hex:std::borrow::Cow::Borrowed("#EDEDED"), rgb:(237,237,237), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey93" => crate::Color{ name:std::borrow::Cow::Borrowed("grey93"), hex:std::borrow::Cow::Borrowed("#EDEDED"), rgb:(237,237,237), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray94" => crate::Color{ name:std::borrow::Cow::Borrowed("gray94"), hex:std::borrow::Cow::Borrowed("#F0F0F0"), rgb:(240,240,240), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey94" => crate::Color{ name:std::borrow::Cow::Borrowed("grey94"), hex:std::borrow::Cow::Borrowed("#F0F0F0"), rgb:(240,240,240), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray95" => crate::Color{ name:std::borrow::Cow::Borrowed("gray95"), hex:std::borrow::Cow::Borrowed("#F2F2F2"), rgb:(242,242,242), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey95" => crate::Color{ name:std::borrow::Cow::Borrowed("grey95"), hex:std::borrow::Cow::Borrowed("#F2F2F2"), rgb:(242,242,242), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray96" => crate::Color{ name:std::borrow::Cow::Borrowed("gray96"), hex:std::borrow::Cow::Borrowed("#F5F5F5"), rgb:(245,245,245), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey96" => crate::Color{ name:std::borrow::Cow::Borrowed("grey96"), hex:std::borrow::Cow::Borrowed("#F5F5F5"), rgb:(245,245,245), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray97" => crate::Color{ name:std::borrow::Cow::Borrowed("gray97"), hex:std::borrow::Cow::Borrowed("#F7F7F7"), rgb:(247,247,247), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey97" => crate::Color{ name:std::borrow::Cow::Borrowed("grey97"), hex:std::borrow::Cow::Borrowed("#F7F7F7"), rgb:(247,247,247), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray98" => crate::Color{ name:std::borrow::Cow::Borrowed("gray98"), hex:std::borrow::Cow::Borrowed("#FAFAFA"), rgb:(250,250,250), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey98" => crate::Color{ name:std::borrow::Cow::Borrowed("grey98"), hex:std::borrow::Cow::Borrowed("#FAFAFA"), rgb:(250,250,250), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray99" => crate::Color{ name:std::borrow::Cow::Borrowed("gray99"), hex:std::borrow::Cow::Borrowed("#FCFCFC"), rgb:(252,252,252), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey99" => crate::Color{ name:std::borrow::Cow::Borrowed("grey99"), hex:std::borrow::Cow::Borrowed("#FCFCFC"), rgb:(252,252,252), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "gray100" => crate::Color{ name:std::borrow::Cow::Borrowed("gray100"), hex:std::borrow::Cow::Borrowed("#FFFFFF"), rgb:(255,255,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "grey100" => crate::Color{ name:std::borrow::Cow::Borrowed("grey100"), hex:std::borrow::Cow::Borrowed("#FFFFFF"), rgb:(255,255,255), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkgrey" => crate::Color{ name:std::borrow::Cow::Borrowed("dark grey"), hex:std::borrow::Cow::Borrowed("#A9A9A9"), rgb:(169,169,169), aliases:&["dkgrey", "dkgray"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkgray" => crate::Color{ name:std::borrow::Cow::Borrowed("dark gray"), hex:std::borrow::Cow::Borrowed("#A9A9A9"), rgb:(169,169,169), aliases:&[], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkblue" => crate::Color{ name:std::borrow::Cow::Borrowed("dark blue"), hex:std::borrow::Cow::Borrowed("#00008B"), rgb:(0,0,139), aliases:&["dkblue"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkcyan" => crate::Color{ name:std::borrow::Cow::Borrowed("dark cyan"), hex:std::borrow::Cow::Borrowed("#008B8B"), rgb:(0,139,139), aliases:&["dkcyan"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkmagenta" => crate::Color{ name:std::borrow::Cow::Borrowed("dark magenta"), hex:std::borrow::Cow::Borrowed("#8B008B"), rgb:(139,0,139), aliases:&["darkmegenta", "dkmagenta", "dkmegenta"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "darkred" => crate::Color{ name:std::borrow::Cow::Borrowed("dark red"), hex:std::borrow::Cow::Borrowed("#8B0000"), rgb:(139,0,0), aliases:&["dkred"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+    "lightgreen" => crate::Color{ name:std::borrow::Cow::Borrowed("light green"), hex:std::borrow::Cow::Borrowed("#90EE90"), rgb:(144,238,144), aliases:&["ltgreen"], source:crate::Source{ list:"X11 rgb.txt", variant:None } },
+};
+
+/// Alternate spellings of `X11_COLORS` keys, mapped to the key they stand for.
+pub(crate) static X11_COLORS_ALIASES: phf::Map<&'static str, &'static str> = phf::phf_map! {
+    "lavendar" => "lavender",
+    "lavendarblush" => "lavenderblush",
+    "dkslategray" => "darkslategray",
+    "dkslategrey" => "darkslategray",
+    "ltslategray" => "lightslategray",
+    "ltslategrey" => "lightslategray",
+    "ltgrey" => "lightgrey",
+    "ltgray" => "lightgrey",
+    "dkslateblue" => "darkslateblue",
+    "medslateblue" => "mediumslateblue",
+    "ltslateblue" => "lightslateblue",
+    "medblue" => "mediumblue",
+    "ltskyblue" => "lightskyblue",
+    "ltsteelblue" => "lightsteelblue",
+    "ltblue" => "lightblue",
+    "paleturqoise" => "paleturquoise",
+    "paleturquise" => "paleturquoise",
+    "darkturqoise" => "darkturquoise",
+    "darkturquise" => "darkturquoise",
+    "dkturquoise" => "darkturquoise",
+    "dkturqoise" => "darkturquoise",
+    "dkturquise" => "darkturquoise",
+    "mediumturqoise" => "mediumturquoise",
+    "mediumturquise" => "mediumturquoise",
+    "medturquoise" => "mediumturquoise",
+    "medturqoise" => "mediumturquoise",
+    "medturquise" => "mediumturquoise",
+    "turqoise" => "turquoise",
+    "turquise" => "turquoise",
+    "ltcyan" => "lightcyan",
+    "medaquamarine" => "mediumaquamarine",
+    "dkgreen" => "darkgreen",
+    "dkolivegreen" => "darkolivegreen",
+    "dkseagreen" => "darkseagreen",
+    "medseagreen" => "mediumseagreen",
+    "ltseagreen" => "lightseagreen",
+    "medspringgreen" => "mediumspringgreen",
+    "dkkhaki" => "darkkhaki",
+    "ltgoldenrodyellow" => "lightgoldenrodyellow",
+    "ltyellow" => "lightyellow",
+    "ltgoldenrod" => "lightgoldenrod",
+    "dkgoldenrod" => "darkgoldenrod",
+    "dksalmon" => "darksalmon",
+    "ltsalmon" => "lightsalmon",
+    "dkorange" => "darkorange",
+    "ltcoral" => "lightcoral",
+    "ltpink" => "lightpink",
+    "palevoiletred" => "palevioletred",
+    "mediumvoiletred" => "mediumvioletred",
+    "medvioletred" => "mediumvioletred",
+    "medvoiletred" => "mediumvioletred",
+    "voiletred" => "violetred",
+    "megenta" => "magenta",
+    "voilet" => "violet",
+    "medorchid" => "mediumorchid",
+    "dkorchid" => "darkorchid",
+    "darkvoilet" => "darkviolet",
+    "dkviolet" => "darkviolet",
+    "dkvoilet" => "darkviolet",
+    "bluevoilet" => "blueviolet",
+    "medpurple" => "mediumpurple",
+    "lavendarblush1" => "lavenderblush1",
+    "lavendarblush2" => "lavenderblush2",
+    "lavendarblush3" => "lavenderblush3",
+    "lavendarblush4" => "lavenderblush4",
+    "ltskyblue1" => "lightskyblue1",
+    "ltskyblue2" => "lightskyblue2",
+    "ltskyblue3" => "lightskyblue3",
+    "ltskyblue4" => "lightskyblue4",
+    "slategrey1" => "slategray1",
+    "slategrey2" => "slategray2",
+    "slategrey3" => "slategray3",
+    "slategrey4" => "slategray4",
+    "ltsteelblue1" => "lightsteelblue1",
+    "ltsteelblue2" => "lightsteelblue2",
+    "ltsteelblue3" => "lightsteelblue3",
+    "ltsteelblue4" => "lightsteelblue4",
+    "ltblue1" => "lightblue1",
+    "ltblue2" => "lightblue2",
+    "ltblue3" => "lightblue3",
+    "ltblue4" => "lightblue4",
+    "ltcyan1" => "lightcyan1",
+    "ltcyan2" => "lightcyan2",
+    "ltcyan3" => "lightcyan3",
+    "ltcyan4" => "lightcyan4",
+    "paleturqoise1" => "paleturquoise1",
+    "paleturquise1" => "paleturquoise1",
+    "paleturqoise2" => "paleturquoise2",
+    "paleturquise2" => "paleturquoise2",
+    "paleturqoise3" => "paleturquoise3",
+    "paleturquise3" => "paleturquoise3",
+    "paleturqoise4" => "paleturquoise4",
+    "paleturquise4" => "paleturquoise4",
+    "turqoise1" => "turquoise1",
+    "turquise1" => "turquoise1",
+    "turqoise2" => "turquoise2",
+    "turquise2" => "turquoise2",
+    "turqoise3" => "turquoise3",
+    "turquise3" => "turquoise3",
+    "turqoise4" => "turquoise4",
+    "turquise4" => "turquoise4",
+    "darkslategrey1" => "darkslategray1",
+    "dkslategray1" => "darkslategray1",
+    "dkslategrey1" => "darkslategray1",
+    "darkslategrey2" => "darkslategray2",
+    "dkslategray2" => "darkslategray2",
+    "dkslategrey2" => "darkslategray2",
+    "darkslategrey3" => "darkslategray3",
+    "dkslategray3" => "darkslategray3",
+    "dkslategrey3" => "darkslategray3",
+    "darkslategrey4" => "darkslategray4",
+    "dkslategray4" => "darkslategray4",
+    "dkslategrey4" => "darkslategray4",
+    "dkseagreen1" => "darkseagreen1",
+    "dkseagreen2" => "darkseagreen2",
+    "dkseagreen3" => "darkseagreen3",
+    "dkseagreen4" => "darkseagreen4",
+    "dkolivegreen1" => "darkolivegreen1",
+    "dkolivegreen2" => "darkolivegreen2",
+    "dkolivegreen3" => "darkolivegreen3",
+    "dkolivegreen4" => "darkolivegreen4",
+    "ltgoldenrod1" => "lightgoldenrod1",
+    "ltgoldenrod2" => "lightgoldenrod2",
+    "ltgoldenrod3" => "lightgoldenrod3",
+    "ltgoldenrod4" => "lightgoldenrod4",
+    "ltyellow1" => "lightyellow1",
+    "ltyellow2" => "lightyellow2",
+    "ltyellow3" => "lightyellow3",
+    "ltyellow4" => "lightyellow4",
+    "dkgoldenrod1" => "darkgoldenrod1",
+    "dkgoldenrod2" => "darkgoldenrod2",
+    "dkgoldenrod3" => "darkgoldenrod3",
+    "dkgoldenrod4" => "darkgoldenrod4",
+    "ltsalmon1" => "lightsalmon1",
+    "ltsalmon2" => "lightsalmon2",
+    "ltsalmon3" => "lightsalmon3",
+    "ltsalmon4" => "lightsalmon4",
+    "dkorange1" => "darkorange1",
+    "dkorange2" => "darkorange2",
+    "dkorange3" => "darkorange3",
+    "dkorange4" => "darkorange4",
+    "ltpink1" => "lightpink1",
+    "ltpink2" => "lightpink2",
+    "ltpink3" => "lightpink3",
+    "ltpink4" => "lightpink4",
+    "palevoiletred1" => "palevioletred1",
+    "palevoiletred2" => "palevioletred2",
+    "palevoiletred3" => "palevioletred3",
+    "palevoiletred4" => "palevioletred4",
+    "voiletred1" => "violetred1",
+    "voiletred2" => "violetred2",
+    "voiletred3" => "violetred3",
+    "voiletred4" => "violetred4",
+    "megenta1" => "magenta1",
+    "megenta2" => "magenta2",
+    "megenta3" => "magenta3",
+    "megenta4" => "magenta4",
+    "medorchid1" => "mediumorchid1",
+    "medorchid2" => "mediumorchid2",
+    "medorchid3" => "mediumorchid3",
+    "medorchid4" => "mediumorchid4",
+    "dkorchid1" => "darkorchid1",
+    "dkorchid2" => "darkorchid2",
+    "dkorchid3" => "darkorchid3",
+    "dkorchid4" => "darkorchid4",
+    "medpurple1" => "mediumpurple1",
+    "medpurple2" => "mediumpurple2",
+    "medpurple3" => "mediumpurple3",
+    "medpurple4" => "mediumpurple4",
+    "dkgrey" => "darkgrey",
+    "dkgray" => "darkgrey",
+    "dkblue" => "darkblue",
+    "dkcyan" => "darkcyan",
+    "darkmegenta" => "darkmagenta",
+    "dkmagenta" => "darkmagenta",
+    "dkmegenta" => "darkmagenta",
+    "dkred" => "darkred",
+    "ltgreen" => "lightgreen",
+};
@@ -0,0 +1,276 @@
+//! Procedural macros behind `pigment::color!` and `pigment::hex!`.
+//!
+//! Don't depend on this crate directly: use the macros through `pigment`,
+//! which passes its own path in and enables the same color tables here as
+//! there. The tables are generated next to `pigment`'s own, from the same
+//! data, by tools/scrape.py.
+
+use std::borrow::Cow;
+
+use pigment_names::{canonical, score, words};
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+// The pigment types the generated tables are written in terms of
+
+pub(crate) struct Color {
+    name: Cow<'static, str>,
+    hex: Cow<'static, str>,
+    rgb: (u8, u8, u8),
+    aliases: &'static [&'static str],
+    source: Source,
+}
+
+pub(crate) struct Source {
+    list: &'static str,
+    variant: Option<&'static str>,
+}
+
+mod tables {
+    #[cfg(feature = "wikipedia")]
+    include!(concat!(env!("CARGO_MANIFEST_DIR"), "/generated/colors.rs"));
+    #[cfg(feature = "css-names")]
+    include!(concat!(env!("CARGO_MANIFEST_DIR"), "/generated/css.rs"));
+    #[cfg(feature = "x11")]
+    include!(concat!(env!("CARGO_MANIFEST_DIR"), "/generated/x11.rs"));
+}
+
+type Table = (
+    &'static phf::Map<&'static str, Color>,
+    &'static phf::Map<&'static str, &'static str>,
+);
+
+/// The enabled tables with their aliases, in the order `pigment::color` searches them.
+static TABLES: &[Table] = &[
+    #[cfg(feature = "wikipedia")]
+    (&tables::COLORS, &tables::COLORS_ALIASES),
+    #[cfg(feature = "css-names")]
+    (&tables::CSS_COLORS, &tables::CSS_COLORS_ALIASES),
+    #[cfg(feature = "x11")]
+    (&tables::X11_COLORS, &tables::X11_COLORS_ALIASES),
+];
+
+/// How many names an error message suggests at most.
+const SUGGEST_LIMIT: usize = 5;
+
+/// Minimum similarity for a name to be suggested.
+const SUGGEST_THRESHOLD: f64 = 0.6;
+
+/// `color!($crate, "name")`: the built-in color called `name`, as a constant.
+#[proc_macro]
+pub fn color(input: TokenStream) -> TokenStream {
+    expand(input, "color", |name| {
+        let key = canonical(name);
+        let Some(c) = builtin(&key) else {
+            return Err(unknown_color(name));
+        };
+        Ok(format!(
+            "({}, {}, ({}, {}, {}), &[{}], {}, {})",
+            string(&c.name),
+            string(&c.hex),
+            c.rgb.0,
+            c.rgb.1,
+            c.rgb.2,
+            c.aliases
+                .iter()
+                .map(|a| string(a))
+                .collect::<Vec<_>>()
+                .join(", "),
+            string(c.source.list),
+            c.source
+                .variant
+                .map_or("None".into(), |v| format!("Some({})", string(v))),
+        ))
+    })
+}
+
+/// `hex!($crate, "#RRGGBB")`: the `Rgb` value of a hex code, as a constant.
+#[proc_macro]
+pub fn hex(input: TokenStream) -> TokenStream {
+    expand(input, "hex", |code| {
+        let (r, g, b) = parse_hex(code)?;
+        Ok(format!("(({r}, {g}, {b}))"))
+    })
+}
+
+// Split `$crate, "literal"` apart, run `f` on the string, and pass the
+// arguments it returns to `$crate::__private::constructor` in a const block
+fn expand(
+    input: TokenStream,
+    constructor: &str,
+    f: impl FnOnce(&str) -> Result<String, String>,
+) -> TokenStream {
+    let mut tokens: Vec<TokenTree> = input.into_iter().collect();
+    let Some(comma) = tokens
+        .iter()
+        .position(|t| matches!(t, TokenTree::Punct(p) if p.as_char() == ','))
+    else {
+        return error("expected a string literal", Span::call_site());
+    };
+    let arg: Vec<TokenTree> = tokens.split_off(comma + 1);
+    let krate = &tokens[..comma];
+
+    let Some((value, span)) = string_literal(arg) else {
+        return error("expected a string literal", Span::call_site());
+    };
+    let args = match f(&value) {
+        Ok(args) => args,
+        Err(message) => return error(&message, span),
+    };
+
+    let mut call: TokenStream = krate.iter().cloned().collect();
+    call.extend(
+        format!("::__private::{constructor}")
+            .parse::<TokenStream>()
+            .unwrap(),
+    );
+    call.extend(args.parse::<TokenStream>().unwrap());
+    let block = TokenTree::Group(Group::new(Delimiter::Brace, call));
+    [TokenTree::Ident(Ident::new("const", span)), block]
+        .into_iter()
+        .collect()
+}
+
+// The value of a (possibly macro-wrapped) string literal, and its span
+fn string_literal(tokens: Vec<TokenTree>) -> Option<(String, Span)> {
+    let mut tokens = tokens.into_iter();
+    let token = tokens.next()?;
+    if tokens.next().is_some() {
+        return None;
+    }
+    match token {
+        TokenTree::Group(g) if g.delimiter() == Delimiter::None => {
+            string_literal(g.stream().into_iter().collect())
+        }
+        TokenTree::Literal(lit) => unquote(&lit.to_string()).map(|s| (s, lit.span())),
+        _ => None,
+    }
+}
+
+// The contents of a string literal as written in source
+fn unquote(lit: &str) -> Option<String> {
+    if let Some(raw) = lit.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let raw = raw.get(hashes + 1..raw.len() - hashes - 1)?;
+        return Some(raw.to_string());
+    }
+
+    let inner = lit.strip_prefix('"')?.strip_suffix('"')?;
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => out.push('\n'),
+            'r' => out.push('\r'),
+            't' => out.push('\t'),
+            '0' => out.push('\0'),
+            'x' => {
+                let code: String = chars.by_ref().take(2).collect();
+                out.push(u8::from_str_radix(&code, 16).ok()? as char);
+            }
+            'u' => {
+                let code: String = chars.by_ref().skip(1).take_while(|&c| c != '}').collect();
+                out.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+            }
+            c => out.push(c),
+        }
+    }
+    Some(out)
+}
+
+// `compile_error!("message")`, reported at `span`
+fn error(message: &str, span: Span) -> TokenStream {
+    let mut literal = Literal::string(message);
+    literal.set_span(span);
+    let mut bang = Punct::new('!', Spacing::Alone);
+    bang.set_span(span);
+    let mut args = Group::new(
+        Delimiter::Parenthesis,
+        TokenStream::from(TokenTree::Literal(literal)),
+    );
+    args.set_span(span);
+    [
+        TokenTree::Ident(Ident::new("compile_error", span)),
+        TokenTree::Punct(bang),
+        TokenTree::Group(args),
+    ]
+    .into_iter()
+    .collect()
+}
+
+fn string(s: &str) -> String {
+    format!("{s:?}")
+}
+
+// Each table in turn, by primary name and then by alias, like `pigment::color`
+fn builtin(key: &str) -> Option<&'static Color> {
+    TABLES.iter().find_map(|(colors, aliases)| {
+        colors
+            .get(key)
+            .or_else(|| aliases.get(key).map(|key| &colors[*key]))
+    })
+}
+
+fn unknown_color(name: &str) -> String {
+    let mut message = format!("unknown color name {name:?}");
+    let hints = suggest(name);
+    if !hints.is_empty() {
+        let hints: Vec<String> = hints.iter().map(|name| format!("{name:?}")).collect();
+        message.push_str(&format!("; did you mean {}?", hints.join(", ")));
+    }
+    message
+}
+
+// "Did you mean" names for `query`, scored like `pigment::suggest`
+fn suggest(query: &str) -> Vec<&'static str> {
+    let key: Vec<char> = canonical(query).chars().collect();
+    if key.is_empty() {
+        return Vec::new();
+    }
+    let words = words(query);
+
+    let mut scored: Vec<(f64, &'static str)> = TABLES
+        .iter()
+        .flat_map(|(colors, _)| colors.entries())
+        .map(|(candidate, c)| (score(&key, &words, candidate, &c.name), c.name.as_ref()))
+        .filter(|&(score, _)| score >= SUGGEST_THRESHOLD)
+        .collect();
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0).then_with(|| a.1.cmp(b.1)));
+
+    let mut names: Vec<&'static str> = Vec::new();
+    for (_, name) in scored {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names.truncate(SUGGEST_LIMIT);
+    names
+}
+
+// Same rules as `Ansi::from_hex`, with a reason when they aren't met
+fn parse_hex(code: &str) -> Result<(u8, u8, u8), String> {
+    let digits = code.strip_prefix('#').unwrap_or(code);
+    if let Some(bad) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(format!(
+            "invalid hex color {code:?}: {bad:?} is not a hex digit"
+        ));
+    }
+    let channel = |i: usize, width: usize| {
+        let value = u8::from_str_radix(&digits[i * width..(i + 1) * width], 16).unwrap();
+        if width == 1 { value * 17 } else { value }
+    };
+    match digits.len() {
+        3 => Ok((channel(0, 1), channel(1, 1), channel(2, 1))),
+        6 => Ok((channel(0, 2), channel(1, 2), channel(2, 2))),
+        4 | 8 => Err(format!(
+            "invalid hex color {code:?}: `hex!` makes an opaque `Rgb`, so it \
+             takes no alpha; use `Rgba::from_hex` for a translucent color"
+        )),
+        n => Err(format!(
+            "invalid hex color {code:?}: expected 3 or 6 hex digits, found {n}"
+        )),
+    }
+}
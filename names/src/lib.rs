@@ -0,0 +1,150 @@
+//! Color-name matching shared by `pigment` and `pigment-macros`.
+//!
+//! Don't depend on this crate directly: it exists so that `pigment::color`
+//! and `pigment::color!` canonicalize and score names with the very same
+//! code, and its API follows whatever those two need.
+
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+/// Lookup key for a name: lowercase letters and digits only, with accents
+/// and ligatures folded to plain letters ("Café" → "cafe", "Blåbær" →
+/// "blabaer"). Must stay in sync with `canonical` in tools/scrape.py.
+pub fn canonical(s: &str) -> String {
+    let mut key = String::with_capacity(s.len());
+    for c in s.nfkd().flat_map(char::to_lowercase) {
+        match c {
+            'ß' => key.push_str("ss"),
+            'æ' => key.push_str("ae"),
+            'œ' => key.push_str("oe"),
+            'ø' => key.push('o'),
+            'đ' | 'ð' => key.push('d'),
+            'ł' => key.push('l'),
+            'þ' => key.push_str("th"),
+            c if c.is_alphanumeric() && !is_combining_mark(c) => key.push(c),
+            _ => {}
+        }
+    }
+    key
+}
+
+/// Words of a name, each canonicalized the same way as whole keys.
+pub fn words(s: &str) -> Vec<String> {
+    s.split(|c: char| !c.is_alphanumeric())
+        .map(canonical)
+        .filter(|w| !w.is_empty())
+        .collect()
+}
+
+/// Combined similarity between a query and one table entry, in `0.0..=1.0`.
+///
+/// `query` is the canonical query as chars and `query_words` its
+/// [`words`]; `key` and `name` are the entry's key and display name. The
+/// edit-distance similarity of query and key is nudged upwards by how many
+/// whole words query and name share.
+pub fn score(query: &[char], query_words: &[String], key: &str, name: &str) -> f64 {
+    let key: Vec<char> = key.chars().collect();
+    let longest = query.len().max(key.len());
+    let similarity = 1.0 - edit_distance(query, &key) as f64 / longest as f64;
+    let overlap = overlap(query_words, &words(name));
+    similarity + (1.0 - similarity) * overlap / 2.0
+}
+
+// Jaccard index of two word lists
+fn overlap(a: &[String], b: &[String]) -> f64 {
+    let shared = a.iter().filter(|w| b.contains(w)).count();
+    let union = a.len() + b.len() - shared;
+    if union == 0 {
+        0.0
+    } else {
+        shared as f64 / union as f64
+    }
+}
+
+/// Optimal string alignment distance: Levenshtein plus adjacent
+/// transpositions.
+pub fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut prev2 = vec![0; b.len() + 1];
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        curr[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                curr[j] = curr[j].min(prev2[j - 2] + 1);
+            }
+        }
+        std::mem::swap(&mut prev2, &mut prev);
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn distance(a: &str, b: &str) -> usize {
+        let a: Vec<char> = a.chars().collect();
+        let b: Vec<char> = b.chars().collect();
+        edit_distance(&a, &b)
+    }
+
+    mod edit_distance {
+        use super::*;
+
+        #[test]
+        fn test_identical_and_empty() {
+            assert_eq!(distance("azure", "azure"), 0);
+            assert_eq!(distance("", ""), 0);
+            assert_eq!(distance("", "red"), 3);
+            assert_eq!(distance("red", ""), 3);
+        }
+
+        #[test]
+        fn test_single_edits() {
+            assert_eq!(distance("aquamrine", "aquamarine"), 1); // insertion
+            assert_eq!(distance("reed", "red"), 1); // deletion
+            assert_eq!(distance("rad", "red"), 1); // substitution
+        }
+
+        #[test]
+        fn test_transposition_counts_once() {
+            assert_eq!(distance("azuer", "azure"), 1);
+            assert_eq!(distance("lbue", "blue"), 1);
+        }
+    }
+
+    mod scoring {
+        use super::*;
+
+        fn query(s: &str) -> (Vec<char>, Vec<String>) {
+            (canonical(s).chars().collect(), words(s))
+        }
+
+        #[test]
+        fn test_words() {
+            assert_eq!(words("Blue (Crayola)"), ["blue", "crayola"]);
+            assert_eq!(words("Café au lait"), ["cafe", "au", "lait"]);
+            assert!(words(" - ").is_empty());
+        }
+
+        #[test]
+        fn test_exact_match_scores_one() {
+            let (key, words) = query("Alice Blue");
+            assert_eq!(score(&key, &words, "aliceblue", "Alice blue"), 1.0);
+        }
+
+        #[test]
+        fn test_shared_words_boost_score() {
+            let (key, spaced) = query("alice green");
+            let (_, joined) = query("alicegreen");
+            let with_word = score(&key, &spaced, "aliceblue", "Alice blue");
+            let without = score(&key, &joined, "aliceblue", "Alice blue");
+            assert!(with_word > without);
+        }
+    }
+}
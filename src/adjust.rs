@@ -0,0 +1,308 @@
+//! Deriving new colors from existing ones: lighter, darker, more or less
+//! colorful, rotated, inverted, gray, or mixed with another.
+//!
+//! Every operation works in OKLCH, so equal steps look equal whatever the
+//! hue: darkening yellow and blue by the same amount takes the same visible
+//! step, which HSL can't promise. Results outside the sRGB gamut keep their
+//! lightness and hue and lose chroma until they fit.
+//!
+//! The operations are on [`Rgb`], and on [`Color`] and [`Ansi`] too. A
+//! derived color has no name, so `Color` returns an `Rgb`, while `Ansi`
+//! returns an `Ansi` ready for [`fg`](Ansi::fg) and [`bg`](Ansi::bg).
+
+use crate::ansi::Ansi;
+use crate::gradient::{HueArc, blend_oklch};
+use crate::{Color, Oklch, Rgb};
+
+impl Rgb {
+    /// This color with `amount` added to its OKLCH lightness, which runs
+    /// from 0 (black) to 1 (white).
+    ///
+    /// ```
+    /// use pigment::Rgb;
+    ///
+    /// let base = Rgb::new(0, 72, 186);
+    /// let hover = base.lighten(0.1);
+    /// assert!(hover.oklch().lightness() > base.oklch().lightness());
+    /// assert_eq!(base.lighten(1.0), Rgb::new(255, 255, 255));
+    /// ```
+    pub fn lighten(&self, amount: f32) -> Rgb {
+        self.adjust(|c| Oklch::new(c.l + amount, c.chroma, c.hue))
+    }
+
+    /// This color with `amount` taken from its OKLCH lightness; the
+    /// opposite of [`lighten`](Self::lighten).
+    pub fn darken(&self, amount: f32) -> Rgb {
+        self.lighten(-amount)
+    }
+
+    /// This color with its chroma scaled up by `amount`, so `0.2` is 20%
+    /// more colorful. Grays stay gray.
+    pub fn saturate(&self, amount: f32) -> Rgb {
+        self.adjust(|c| Oklch::new(c.l, c.chroma * (1.0 + amount), c.hue))
+    }
+
+    /// This color with its chroma scaled down by `amount`, so `1.0` is gray.
+    pub fn desaturate(&self, amount: f32) -> Rgb {
+        self.saturate(-amount.min(1.0))
+    }
+
+    /// This color with its hue turned by `degrees`, keeping lightness and
+    /// chroma.
+    pub fn rotate_hue(&self, degrees: f32) -> Rgb {
+        self.adjust(|c| Oklch::new(c.l, c.chroma, c.hue + degrees))
+    }
+
+    /// The perceptual opposite of this color: lightness mirrored and hue
+    /// turned halfway round, so black and white swap and blue turns orange.
+    pub fn invert(&self) -> Rgb {
+        self.adjust(|c| Oklch::new(1.0 - c.l, c.chroma, c.hue + 180.0))
+    }
+
+    /// The gray with this color's lightness.
+    pub fn grayscale(&self) -> Rgb {
+        self.adjust(|c| Oklch::new(c.l, 0.0, 0.0))
+    }
+
+    /// The color `t` of the way from this color to `other`, along the
+    /// shorter way round the hue circle; `t` is clamped to `0.0..=1.0`.
+    ///
+    /// ```
+    /// use pigment::Rgb;
+    ///
+    /// let black = Rgb::new(0, 0, 0);
+    /// let white = Rgb::new(255, 255, 255);
+    /// assert_eq!(black.mix(white, 0.0), black);
+    /// assert_eq!(black.mix(white, 1.0), white);
+    /// let gray = black.mix(white, 0.5);
+    /// assert_eq!(gray.r(), gray.g());
+    /// assert_eq!(gray.g(), gray.b());
+    /// ```
+    pub fn mix(&self, other: impl Into<Rgb>, t: f32) -> Rgb {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        blend_oklch(self.oklch(), other.into().oklch(), t, HueArc::Shorter)
+    }
+
+    /// This color in OKLCH.
+    #[inline]
+    pub fn oklch(&self) -> Oklch {
+        Oklch::from(*self)
+    }
+
+    fn adjust(&self, f: impl FnOnce(Oklch) -> Oklch) -> Rgb {
+        f(self.oklch()).to_rgb_in_gamut()
+    }
+}
+
+// The `Rgb` operations on another color type, returning `$output`
+macro_rules! adjustments {
+    ($model:ty => $output:ty) => {
+        impl $model {
+            /// See [`Rgb::lighten`].
+            pub fn lighten(&self, amount: f32) -> $output {
+                Rgb::from(self).lighten(amount).into()
+            }
+
+            /// See [`Rgb::darken`].
+            pub fn darken(&self, amount: f32) -> $output {
+                Rgb::from(self).darken(amount).into()
+            }
+
+            /// See [`Rgb::saturate`].
+            pub fn saturate(&self, amount: f32) -> $output {
+                Rgb::from(self).saturate(amount).into()
+            }
+
+            /// See [`Rgb::desaturate`].
+            pub fn desaturate(&self, amount: f32) -> $output {
+                Rgb::from(self).desaturate(amount).into()
+            }
+
+            /// See [`Rgb::rotate_hue`].
+            pub fn rotate_hue(&self, degrees: f32) -> $output {
+                Rgb::from(self).rotate_hue(degrees).into()
+            }
+
+            /// See [`Rgb::invert`].
+            pub fn invert(&self) -> $output {
+                Rgb::from(self).invert().into()
+            }
+
+            /// See [`Rgb::grayscale`].
+            pub fn grayscale(&self) -> $output {
+                Rgb::from(self).grayscale().into()
+            }
+
+            /// See [`Rgb::mix`].
+            pub fn mix(&self, other: impl Into<Rgb>, t: f32) -> $output {
+                Rgb::from(self).mix(other, t).into()
+            }
+        }
+    };
+}
+
+adjustments!(Color => Rgb);
+adjustments!(Ansi => Ansi);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> impl Iterator<Item = Rgb> {
+        (0..=255).step_by(17).flat_map(|r| {
+            (0..=255).step_by(17).flat_map(move |g| {
+                (0..=255)
+                    .step_by(17)
+                    .map(move |b| Rgb::new(r as u8, g as u8, b as u8))
+            })
+        })
+    }
+
+    mod lightness {
+        use super::*;
+
+        #[test]
+        fn test_zero_changes_nothing() {
+            for c in grid() {
+                assert_eq!(c.lighten(0.0), c, "{c:?}");
+                assert_eq!(c.saturate(0.0), c, "{c:?}");
+                assert_eq!(c.rotate_hue(0.0), c, "{c:?}");
+                assert_eq!(c.mix(c, 0.5), c, "{c:?}");
+            }
+        }
+
+        #[test]
+        fn test_lighten_and_darken() {
+            let c = Rgb::new(0, 72, 186);
+            let l = c.oklch().lightness();
+            assert!((c.lighten(0.1).oklch().lightness() - (l + 0.1)).abs() < 0.01);
+            assert!((c.darken(0.1).oklch().lightness() - (l - 0.1)).abs() < 0.01);
+            assert_eq!(c.darken(1.0), Rgb::new(0, 0, 0));
+            assert_eq!(c.lighten(1.0), Rgb::new(255, 255, 255));
+        }
+
+        #[test]
+        fn test_hue_is_kept_out_of_gamut() {
+            // Lightening pure blue clips; the hue must not drift toward purple
+            let blue = Rgb::new(0, 0, 255);
+            let light = blue.lighten(0.2).oklch();
+            assert!((light.hue() - blue.oklch().hue()).abs() < 1.0, "{light:?}");
+        }
+    }
+
+    mod chroma {
+        use super::*;
+
+        #[test]
+        fn test_saturate_and_desaturate() {
+            let c = Rgb::new(0x66, 0x99, 0x88);
+            let chroma = c.oklch().chroma();
+            assert!(c.saturate(0.5).oklch().chroma() > chroma);
+            assert!(c.desaturate(0.5).oklch().chroma() < chroma);
+            let gray = c.desaturate(1.0);
+            assert_eq!(gray.r, gray.g);
+            assert_eq!(gray.g, gray.b);
+            assert_eq!(c.desaturate(2.0), gray);
+        }
+
+        #[test]
+        fn test_grays_stay_gray() {
+            let gray = Rgb::new(128, 128, 128);
+            assert_eq!(gray.saturate(1.0), gray);
+            assert_eq!(gray.grayscale(), gray);
+        }
+
+        #[test]
+        fn test_grayscale_keeps_lightness() {
+            for c in grid() {
+                let g = c.grayscale();
+                assert_eq!(g.r, g.g, "{c:?}");
+                assert_eq!(g.g, g.b, "{c:?}");
+                assert!((g.oklch().lightness() - c.oklch().lightness()).abs() < 0.01);
+            }
+        }
+    }
+
+    mod hue {
+        use super::*;
+
+        #[test]
+        fn test_rotate_hue() {
+            let c = Rgb::new(0x66, 0x99, 0x88);
+            assert_eq!(c.rotate_hue(360.0), c);
+            // Two trips through 8 bits may each round once
+            let back = c.rotate_hue(-90.0).rotate_hue(90.0);
+            assert!(
+                back.r.abs_diff(c.r) <= 1 && back.g.abs_diff(c.g) <= 1 && back.b.abs_diff(c.b) <= 1
+            );
+            let turned = c.rotate_hue(120.0).oklch();
+            let expected = (c.oklch().hue() + 120.0) % 360.0;
+            assert!((turned.hue() - expected).abs() < 1.0);
+        }
+
+        #[test]
+        fn test_invert() {
+            let (black, white) = (Rgb::new(0, 0, 0), Rgb::new(255, 255, 255));
+            assert_eq!(black.invert(), white);
+            assert_eq!(white.invert(), black);
+            let blue = Rgb::new(0, 72, 186);
+            let hue = blue.invert().oklch().hue();
+            assert!((hue - (blue.oklch().hue() - 180.0)).abs() < 1.0);
+        }
+    }
+
+    mod mixing {
+        use super::*;
+
+        #[test]
+        fn test_mix() {
+            let red = Rgb::new(255, 0, 0);
+            let blue = Rgb::new(0, 0, 255);
+            assert_eq!(red.mix(blue, 0.0), red);
+            assert_eq!(red.mix(blue, 1.0), blue);
+            assert_eq!(red.mix(blue, -1.0), red);
+            assert_eq!(red.mix(blue, 2.0), blue);
+            // Red to blue the short way passes through purple, not green
+            let mid = red.mix(blue, 0.5);
+            assert!(mid.g < mid.r && mid.g < mid.b, "{mid:?}");
+        }
+
+        #[test]
+        fn test_mixing_with_gray_keeps_hue() {
+            let red = Rgb::new(255, 0, 0);
+            let white = Rgb::new(255, 255, 255);
+            let pink = red.mix(white, 0.5).oklch();
+            assert!((pink.hue() - red.oklch().hue()).abs() < 2.0, "{pink:?}");
+        }
+    }
+
+    mod other_types {
+        use super::*;
+
+        #[test]
+        fn test_ansi() {
+            let ansi = Ansi::rgb(0, 72, 186);
+            assert_eq!(
+                ansi.lighten(0.1).get_rgb(),
+                Rgb::new(0, 72, 186).lighten(0.1).tuple()
+            );
+            assert_eq!(
+                ansi.invert().get_rgb(),
+                Rgb::new(0, 72, 186).invert().tuple()
+            );
+            assert_eq!(ansi.mix(Rgb::new(0, 0, 0), 1.0).get_rgb(), (0, 0, 0));
+        }
+
+        #[test]
+        #[cfg(feature = "wikipedia")]
+        fn test_named_colors() {
+            let zero = crate::color("Absolute Zero").unwrap();
+            assert_eq!(zero.darken(0.05), Rgb::from(&zero).darken(0.05));
+            assert_eq!(
+                zero.mix(crate::color("White").unwrap(), 1.0),
+                Rgb::new(255, 255, 255)
+            );
+            assert_eq!(zero.rotate_hue(0.0), Rgb::from(zero));
+        }
+    }
+}
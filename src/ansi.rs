@@ -1,3 +1,12 @@
+//! Truecolor ANSI escape sequences.
+//!
+//! [`Ansi::from_hex`] and [`Ansi::from_rgb_str`] only say whether their
+//! input was valid. `"…".parse::<Ansi>()` takes every color syntax
+//! [`parse`](crate::parse()) does, rejects components out of range like
+//! [`parse_strict`](crate::parse_strict), and its
+//! [`ParseColorError`](crate::ParseColorError) says what is wrong and
+//! where.
+
 #[derive(Debug, Clone, Copy)]
 pub struct Ansi {
     pub(crate) rgb: (u8, u8, u8),
@@ -62,6 +71,13 @@ impl Ansi {
     /// let purple = Ansi::from_hex("#800080FF").unwrap();
     /// assert_eq!(purple.fg(), "\x1b[38;2;128;0;128m");
     /// ```
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        // `#RGBA` isn't one of the formats above, though `parse` takes it
+        if hex.strip_prefix('#').unwrap_or(hex).len() == 4 {
+            return None;
+        }
+        crate::parse::parse_hex(hex).ok().map(|c| c.rgb().into())
+    }
 
     /// Creates a new Ansi instance from an RGB color code string
     ///
@@ -92,6 +108,7 @@ impl Ansi {
     /// - Percentage values (e.g., "100%, 0%, 0%")
     /// - Negative values (e.g., "-255, 0, 0")
     /// - Values greater than 255 (e.g., "256, 0, 0")
+    /// - A translucent alpha (e.g., "rgb(255 0 0 / 0.5)"); see [`crate::Rgba::from_rgb_str`]
     ///
     /// # Examples
     ///
@@ -118,100 +135,12 @@ impl Ansi {
     /// let cyan = Ansi::from_rgb_str("  rgb  (  0  ,  255  ,  255  )  ").unwrap();
     /// assert_eq!(cyan.fg(), "\x1b[38;2;0;255;255m");
     /// ```
-    pub fn from_hex(hex: &str) -> Option<Self> {
-        // Check for invalid input with multiple # symbols
-        if hex.matches('#').count() > 1 {
-            return None;
-        }
-
-        // Remove # if present
-        let hex = hex.trim_start_matches('#');
-
-        // Check if the hex string contains only valid hex characters (0-9, A-F, a-f)
-        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
-            return None;
-        }
-
-        // Handle different hex formats
-        let (r, g, b) = match hex.len() {
-            // Full hex code (e.g., "FF0000")
-            6 => {
-                let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
-                let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
-                let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
-                (r, g, b)
-            },
-            // Short hex code (e.g., "F00")
-            3 => {
-                let r = u8::from_str_radix(&hex[0..1].repeat(2), 16).ok()?;
-                let g = u8::from_str_radix(&hex[1..2].repeat(2), 16).ok()?;
-                let b = u8::from_str_radix(&hex[2..3].repeat(2), 16).ok()?;
-                (r, g, b)
-            },
-            // 8-digit hex code with alpha (e.g., "FF0000FF")
-            // We'll ignore the alpha channel (last 2 digits)
-            8 => {
-                let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
-                let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
-                let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
-                // Alpha channel (hex[6..8]) is ignored
-                (r, g, b)
-            },
-            // Invalid hex code
-            _ => return None,
-        };
-
-        Some(Self { rgb: (r, g, b) })
-    }
-
     pub fn from_rgb_str(rgb_str: &str) -> Option<Self> {
-        // Handle empty string
-        if rgb_str.trim().is_empty() {
-            return None;
-        }
-
-        // Trim the input string
-        let rgb_str = rgb_str.trim();
-
-        // Try to parse as CSS-style RGB: "rgb(255, 0, 0)" or "rgb(255,0,0)"
-        if rgb_str.to_lowercase().trim().starts_with("rgb") {
-            // Find the opening and closing parentheses
-            let open_paren = rgb_str.find('(')?;
-            let close_paren = rgb_str.rfind(')')?;
-
-            // Make sure the closing parenthesis comes after the opening one
-            if close_paren <= open_paren {
-                return None;
-            }
-
-            // Extract the content inside the parentheses
-            let content = &rgb_str[open_paren + 1..close_paren].trim();
-            return Self::parse_rgb_components(content);
-        }
-
-        // Try to parse as comma-separated or space-separated values
-        Self::parse_rgb_components(rgb_str)
-    }
-
-    // Helper method to parse RGB components from a string
-    fn parse_rgb_components(s: &str) -> Option<Self> {
-        // First, normalize the string by replacing commas with spaces
-        let normalized = s.replace(',', " ");
-
-        // Split by whitespace
-        let components: Vec<&str> = normalized.split_whitespace().collect();
-
-        // Check if we have exactly 3 components
-        if components.len() != 3 {
-            return None;
-        }
-
-        // Parse each component as a u8
-        let r = components[0].parse::<u8>().ok()?;
-        let g = components[1].parse::<u8>().ok()?;
-        let b = components[2].parse::<u8>().ok()?;
-
-        Some(Self { rgb: (r, g, b) })
+        // Opaque like `Rgb::from_hex`: a translucent alpha is refused, not dropped
+        crate::parse::parse_rgb(rgb_str)
+            .ok()
+            .filter(crate::Rgba::is_opaque)
+            .map(|c| c.rgb().into())
     }
 
     // Color methods
@@ -301,6 +230,11 @@ impl Ansi {
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::needless_range_loop,
+    clippy::single_char_add_str,
+    clippy::useless_format
+)]
 mod tests {
     use super::*;
 
@@ -1,23 +1,56 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Color {
-    pub(crate) name: &'static str,
-    pub(crate) hex: &'static str,
+    // Borrowed for the built-in palettes, owned for registered colors
+    pub(crate) name: Cow<'static, str>,
+    pub(crate) hex: Cow<'static, str>,
     pub(crate) rgb: (u8, u8, u8),
+    pub(crate) aliases: &'static [&'static str],
+    pub(crate) source: Source,
 }
 
 impl Color {
     #[inline]
-    pub fn name(&self) -> &'static str {
-        self.name
+    pub fn name(&self) -> &str {
+        &self.name
     }
+    /// The hex code as listed, uppercase `#RRGGBB`; formatting the color
+    /// with `{}` or `{:x}` gives it in lowercase instead.
     #[inline]
-    pub fn hex(&self) -> &'static str {
-        self.hex
+    pub fn hex(&self) -> &str {
+        &self.hex
     }
     #[inline]
     pub fn rgb(&self) -> (u8, u8, u8) {
         self.rgb
     }
+    /// Alternate lookup keys for this color, in canonical form.
+    ///
+    /// These are spelling variants ("grey" for "gray"), abbreviations ("dk",
+    /// "lt", "med") and common misspellings ("fuschia") that
+    /// [`color`](crate::color) resolves to this entry.
+    #[inline]
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+    /// Which list this color comes from, and under which variant label.
+    #[inline]
+    pub fn source(&self) -> Source {
+        self.source
+    }
+    /// This color's name in `locale`, if it has been translated.
+    ///
+    /// ```
+    /// # #[cfg(feature = "locale-fr")] {
+    /// use pigment::Locale;
+    ///
+    /// let sky = pigment::color("Sky blue").unwrap();
+    /// assert_eq!(sky.localized_name(Locale::Fr), Some("Bleu ciel"));
+    /// # }
+    /// ```
+    #[cfg(any(feature = "locale-de", feature = "locale-fr", feature = "locale-ja"))]
+    pub fn localized_name(&self, locale: crate::Locale) -> Option<&'static str> {
+        crate::locale::localized_name(self, locale)
+    }
 
     pub fn ansi(&self) -> Ansi {
         Ansi { rgb: self.rgb }
@@ -107,11 +140,13 @@ impl From<Color> for rgb::Rgb<u8> {
 #[cfg(feature = "color-rs")]
 impl From<Color> for color::Rgba8 {
     fn from(c: Color) -> Self {
-        let (r, g, b) = c.rgb;
-        color::Rgba8 { r, g, b, a: 255 } // Full opacity
+        crate::Rgba::from(c).into() // Named colors are opaque
     }
 }
 
+use std::borrow::Cow;
+
+use crate::Source;
 use crate::ansi::Ansi;
 
 #[cfg(test)]
@@ -120,7 +155,16 @@ mod tests {
 
     // Helper function to create test colors
     fn create_test_color(name: &'static str, hex: &'static str, rgb: (u8, u8, u8)) -> Color {
-        Color { name, hex, rgb }
+        Color {
+            name: Cow::Borrowed(name),
+            hex: Cow::Borrowed(hex),
+            rgb,
+            aliases: &[],
+            source: Source {
+                list: "test",
+                variant: None,
+            },
+        }
     }
 
     mod getters {
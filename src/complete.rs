@@ -0,0 +1,197 @@
+//! "As you type" completion over color names.
+//!
+//! [`complete`] matches the start of a whole name, [`containing`] matches
+//! words anywhere in it. Both use the sorted indexes `tools/scrape.py`
+//! generates for every palette, so neither scans the table.
+
+use crate::iter::Colors;
+use crate::palette::{Palette, Table};
+use crate::{Color, canonical};
+
+/// Colors whose name starts with `prefix`, in key order.
+///
+/// The prefix is canonicalized like any other lookup, so "dark sl",
+/// "Dark-Sl" and "darksl" are all the same query. An empty prefix matches
+/// every color.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "wikipedia")] {
+/// let names: Vec<_> = pigment::complete("dark sl").map(|c| c.name().to_owned()).collect();
+/// assert_eq!(names, ["Dark slate blue", "Dark slate gray"]);
+/// # }
+/// ```
+pub fn complete(prefix: &str) -> Colors {
+    Palette::default().complete(prefix)
+}
+
+/// Colors with a word in their name starting with each word of `query`.
+///
+/// Every word of the query has to match the start of some word of the
+/// name, so "slate" finds *Dark slate gray* and *Slate blue*, and
+/// "dark sla" narrows that to the dark ones. Results are in display-name
+/// order, like [`all`](crate::all). A query without any words matches
+/// nothing.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "wikipedia")] {
+/// let names: Vec<_> = pigment::containing("slate gr").iter().map(|c| c.name().to_owned()).collect();
+/// assert_eq!(names, ["Dark slate gray", "Light slate gray", "Slate gray"]);
+/// # }
+/// ```
+pub fn containing(query: &str) -> Vec<Color> {
+    Palette::default().containing(query)
+}
+
+pub(crate) fn complete_in(table: &'static Table, prefix: &str) -> Colors {
+    let prefix = canonical(prefix);
+    let start = table.keys.partition_point(|key| *key < prefix.as_str());
+    let len = table.keys[start..].partition_point(|key| key.starts_with(&prefix));
+    Colors::new(table, &table.keys[start..start + len])
+}
+
+pub(crate) fn containing_in(table: &'static Table, query: &str) -> Vec<Color> {
+    let mut keys: Option<Vec<&'static str>> = None;
+    for word in query.split(|c: char| !c.is_alphanumeric()) {
+        let word = canonical(word);
+        if word.is_empty() {
+            continue;
+        }
+        let mut matching = word_matches(table, &word);
+        if let Some(previous) = keys {
+            matching.retain(|key| previous.binary_search(key).is_ok());
+        }
+        keys = Some(matching);
+    }
+
+    let mut colors: Vec<Color> = keys
+        .unwrap_or_default()
+        .into_iter()
+        .map(|key| table.colors[key].clone())
+        .collect();
+    colors.sort_by_cached_key(|c| c.name.to_lowercase());
+    colors
+}
+
+// Keys of every color with a word starting with `word`, without repeats
+fn word_matches(table: &'static Table, word: &str) -> Vec<&'static str> {
+    let start = table.words.partition_point(|(w, _)| *w < word);
+    let mut keys: Vec<&'static str> = table.words[start..]
+        .iter()
+        .take_while(|(w, _)| w.starts_with(word))
+        .flat_map(|(_, keys)| keys.iter().copied())
+        .collect();
+    keys.sort_unstable();
+    keys.dedup();
+    keys
+}
+
+#[cfg(all(test, feature = "wikipedia"))]
+mod tests {
+    use super::*;
+    use crate::{COLORS, COLORS_KEYS, COLORS_WORDS};
+
+    fn names(colors: impl IntoIterator<Item = Color>) -> Vec<String> {
+        colors.into_iter().map(|c| c.name().to_owned()).collect()
+    }
+
+    mod indexes {
+        use super::*;
+
+        #[test]
+        fn test_keys_sorted_and_complete() {
+            assert_eq!(COLORS_KEYS.len(), COLORS.len());
+            assert!(COLORS_KEYS.windows(2).all(|w| w[0] < w[1]));
+            assert!(COLORS_KEYS.iter().all(|key| COLORS.contains_key(key)));
+        }
+
+        #[test]
+        fn test_words_sorted_and_point_at_names() {
+            assert!(COLORS_WORDS.windows(2).all(|w| w[0].0 < w[1].0));
+            for (word, keys) in COLORS_WORDS.iter() {
+                for key in keys.iter() {
+                    assert!(canonical(&COLORS[key].name).contains(word), "{word} {key}");
+                }
+            }
+        }
+    }
+
+    mod complete_function {
+        use super::*;
+
+        #[test]
+        fn test_prefix_forgiveness() {
+            let expected = names(complete("darksl"));
+            assert_eq!(expected, ["Dark slate blue", "Dark slate gray"]);
+            assert_eq!(names(complete("Dark-Sl")), expected);
+            assert_eq!(names(complete("DARK_SL")), expected);
+        }
+
+        #[test]
+        fn test_every_result_has_prefix() {
+            for prefix in ["a", "bl", "cer", "medium", "z"] {
+                let found = complete(prefix);
+                assert!(found.len() > 0, "{prefix}");
+                for c in found {
+                    assert!(canonical(c.name()).starts_with(prefix), "{}", c.name());
+                }
+            }
+        }
+
+        #[test]
+        fn test_full_name_and_misses() {
+            assert_eq!(names(complete("Zomp")), ["Zomp"]);
+            assert_eq!(complete("zzz").len(), 0);
+            assert_eq!(complete("").len(), COLORS.len());
+        }
+    }
+
+    mod containing_function {
+        use super::*;
+
+        #[test]
+        fn test_single_word() {
+            let found = names(containing("slate"));
+            assert!(found.iter().any(|name| name == "Slate blue"));
+            assert!(found.iter().any(|name| name == "Dark slate gray"));
+            assert!(found.iter().any(|name| name == "Light slate gray"));
+            assert!(found.iter().all(|n| n.to_lowercase().contains("slate")));
+        }
+
+        #[test]
+        fn test_word_prefixes_and_case() {
+            assert_eq!(names(containing("Slat")), names(containing("slate")));
+        }
+
+        #[test]
+        fn test_all_words_must_match() {
+            assert_eq!(
+                names(containing("slate gray")),
+                ["Dark slate gray", "Light slate gray", "Slate gray"]
+            );
+            assert_eq!(
+                names(containing("gray, slate")),
+                names(containing("slate gray"))
+            );
+            assert!(containing("slate crayola").is_empty());
+        }
+
+        #[test]
+        fn test_ordered_by_name() {
+            let found = containing("blue");
+            assert!(found.len() > 10);
+            let lower: Vec<_> = found.iter().map(|c| c.name().to_lowercase()).collect();
+            assert!(lower.windows(2).all(|w| w[0] <= w[1]));
+        }
+
+        #[test]
+        fn test_no_words() {
+            assert!(containing("").is_empty());
+            assert!(containing(" -_ ").is_empty());
+            assert!(containing("qqqq").is_empty());
+        }
+    }
+}
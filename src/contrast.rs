@@ -0,0 +1,408 @@
+//! Readability of text on a background: WCAG 2 contrast and APCA.
+//!
+//! [WCAG 2](https://www.w3.org/TR/WCAG21/#contrast-minimum) compares the
+//! relative luminance of two colors as a ratio from 1:1 to 21:1, and sets
+//! the thresholds that accessibility audits check, summed up by
+//! [`WcagLevel`]. APCA, the candidate method for WCAG 3, models perception
+//! more closely and tells text from background: its Lc value is positive
+//! for dark text on a light background and negative the other way round.
+//!
+//! [`Color::best_foreground`] picks the most readable of several text
+//! colors, and [`Rgb::with_contrast`] nudges a color's lightness until it
+//! reaches a ratio.
+
+use std::fmt;
+
+use crate::lab::linearize;
+use crate::{Color, Rgb};
+
+/// How a contrast ratio fares against the WCAG 2 text thresholds, from
+/// worst to best.
+///
+/// Large text is at least 18pt, or 14pt bold. The AAA threshold for large
+/// text equals the AA one for normal text, so it has no level of its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WcagLevel {
+    /// Below 3:1, not enough for any text.
+    Fail,
+    /// At least 3:1: AA for large text only.
+    AaLarge,
+    /// At least 4.5:1: AA for all text, and AAA for large text.
+    Aa,
+    /// At least 7:1: AAA for all text.
+    Aaa,
+}
+
+impl WcagLevel {
+    /// The level a contrast ratio reaches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pigment::WcagLevel;
+    ///
+    /// assert_eq!(WcagLevel::from_ratio(4.5), WcagLevel::Aa);
+    /// assert_eq!(WcagLevel::from_ratio(4.49), WcagLevel::AaLarge);
+    /// assert!(WcagLevel::from_ratio(21.0) >= WcagLevel::Aa);
+    /// ```
+    pub fn from_ratio(ratio: f32) -> WcagLevel {
+        [WcagLevel::Aaa, WcagLevel::Aa, WcagLevel::AaLarge]
+            .into_iter()
+            .find(|level| ratio >= level.min_ratio())
+            .unwrap_or(WcagLevel::Fail)
+    }
+
+    /// The smallest contrast ratio that reaches this level.
+    pub fn min_ratio(self) -> f32 {
+        match self {
+            WcagLevel::Fail => 1.0,
+            WcagLevel::AaLarge => 3.0,
+            WcagLevel::Aa => 4.5,
+            WcagLevel::Aaa => 7.0,
+        }
+    }
+
+    /// A short name, such as "AA Large".
+    pub fn name(self) -> &'static str {
+        match self {
+            WcagLevel::Fail => "Fail",
+            WcagLevel::AaLarge => "AA Large",
+            WcagLevel::Aa => "AA",
+            WcagLevel::Aaa => "AAA",
+        }
+    }
+}
+
+impl fmt::Display for WcagLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+// APCA-W3 0.0.98G-4g constants
+const APCA_COEFFICIENTS: [f64; 3] = [0.2126729, 0.7151522, 0.0721750];
+const APCA_TRC: f64 = 2.4;
+const APCA_NORM_BG: f64 = 0.56;
+const APCA_NORM_TEXT: f64 = 0.57;
+const APCA_REV_BG: f64 = 0.65;
+const APCA_REV_TEXT: f64 = 0.62;
+const APCA_BLACK_THRESHOLD: f64 = 0.022;
+const APCA_BLACK_CLAMP: f64 = 1.414;
+const APCA_SCALE: f64 = 1.14;
+const APCA_OFFSET: f64 = 0.027;
+const APCA_DELTA_Y_MIN: f64 = 0.0005;
+const APCA_LOW_CLIP: f64 = 0.1;
+
+impl Rgb {
+    /// The WCAG relative luminance, from 0 for black to 1 for white.
+    pub fn luminance(&self) -> f32 {
+        relative_luminance(*self) as f32
+    }
+
+    /// The WCAG 2 contrast ratio between this color and `other`, from 1
+    /// for identical luminances to 21 for black and white. The order of
+    /// the two colors doesn't matter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pigment::{Rgb, WcagLevel};
+    ///
+    /// let white = Rgb::new(255, 255, 255);
+    /// assert_eq!(Rgb::new(0, 0, 0).contrast_ratio(white), 21.0);
+    ///
+    /// let gray = Rgb::new(0x76, 0x76, 0x76);
+    /// assert_eq!(gray.wcag_level(white), WcagLevel::Aa);
+    /// ```
+    pub fn contrast_ratio(&self, other: impl Into<Rgb>) -> f32 {
+        let (a, b) = (relative_luminance(*self), relative_luminance(other.into()));
+        ((a.max(b) + 0.05) / (a.min(b) + 0.05)) as f32
+    }
+
+    /// The [`WcagLevel`] of the contrast between this color and `other`.
+    pub fn wcag_level(&self, other: impl Into<Rgb>) -> WcagLevel {
+        WcagLevel::from_ratio(self.contrast_ratio(other))
+    }
+
+    /// The APCA lightness contrast Lc of this color as text on
+    /// `background`, roughly -108 to 106.
+    ///
+    /// Positive values are dark text on a light background, negative ones
+    /// light text on a dark background. Lc 75 is the usual minimum for body
+    /// text, 60 for larger text and 45 for headlines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pigment::Rgb;
+    ///
+    /// let (black, white) = (Rgb::new(0, 0, 0), Rgb::new(255, 255, 255));
+    /// assert_eq!(black.apca(white).round(), 106.0);
+    /// assert_eq!(white.apca(black).round(), -108.0);
+    /// ```
+    pub fn apca(&self, background: impl Into<Rgb>) -> f32 {
+        let text = apca_luminance(*self);
+        let background = apca_luminance(background.into());
+        if (background - text).abs() < APCA_DELTA_Y_MIN {
+            return 0.0;
+        }
+        let lc = if background > text {
+            let sapc = (background.powf(APCA_NORM_BG) - text.powf(APCA_NORM_TEXT)) * APCA_SCALE;
+            if sapc < APCA_LOW_CLIP {
+                0.0
+            } else {
+                sapc - APCA_OFFSET
+            }
+        } else {
+            let sapc = (background.powf(APCA_REV_BG) - text.powf(APCA_REV_TEXT)) * APCA_SCALE;
+            if sapc > -APCA_LOW_CLIP {
+                0.0
+            } else {
+                sapc + APCA_OFFSET
+            }
+        };
+        (lc * 100.0) as f32
+    }
+
+    /// This color with its OKLCH lightness changed as little as possible to
+    /// reach a WCAG contrast `ratio` against `background`, keeping its hue.
+    ///
+    /// Returns the color unchanged if it already reaches the ratio, and
+    /// `None` if neither lightening nor darkening can reach it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pigment::Rgb;
+    ///
+    /// let white = Rgb::new(255, 255, 255);
+    /// let link = Rgb::new(0x66, 0x99, 0xFF).with_contrast(white, 4.5).unwrap();
+    /// assert!(link.contrast_ratio(white) >= 4.5);
+    /// assert!(Rgb::new(0x66, 0x99, 0xFF).with_contrast(white, 22.0).is_none());
+    /// ```
+    pub fn with_contrast(&self, background: impl Into<Rgb>, ratio: f32) -> Option<Rgb> {
+        let background = background.into();
+        if self.contrast_ratio(background) >= ratio {
+            return Some(*self);
+        }
+        // The smallest lightness change in each direction that reaches the
+        // ratio; black and white are the furthest either can go
+        let mut best: Option<(f32, Rgb)> = None;
+        for direction in [-1.0, 1.0] {
+            let shifted = |amount: f32| self.lighten(direction * amount);
+            if shifted(1.0).contrast_ratio(background) < ratio {
+                continue;
+            }
+            let (mut low, mut high) = (0.0, 1.0);
+            for _ in 0..20 {
+                let mid = (low + high) / 2.0;
+                if shifted(mid).contrast_ratio(background) >= ratio {
+                    high = mid;
+                } else {
+                    low = mid;
+                }
+            }
+            if best.is_none_or(|(amount, _)| high < amount) {
+                best = Some((high, shifted(high)));
+            }
+        }
+        best.map(|(_, c)| c)
+    }
+}
+
+fn relative_luminance(c: Rgb) -> f64 {
+    let [r, g, b] = linearize(c.tuple());
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+// Screen luminance as APCA estimates it, with soft-clamped blacks
+fn apca_luminance(c: Rgb) -> f64 {
+    let y: f64 = [c.r, c.g, c.b]
+        .into_iter()
+        .zip(APCA_COEFFICIENTS)
+        .map(|(c, k)| (c as f64 / 255.0).powf(APCA_TRC) * k)
+        .sum();
+    if y < APCA_BLACK_THRESHOLD {
+        y + (APCA_BLACK_THRESHOLD - y).powf(APCA_BLACK_CLAMP)
+    } else {
+        y
+    }
+}
+
+impl Color {
+    /// See [`Rgb::luminance`].
+    pub fn luminance(&self) -> f32 {
+        Rgb::from(self).luminance()
+    }
+
+    /// See [`Rgb::contrast_ratio`].
+    pub fn contrast_ratio(&self, other: impl Into<Rgb>) -> f32 {
+        Rgb::from(self).contrast_ratio(other)
+    }
+
+    /// See [`Rgb::wcag_level`].
+    pub fn wcag_level(&self, other: impl Into<Rgb>) -> WcagLevel {
+        Rgb::from(self).wcag_level(other)
+    }
+
+    /// See [`Rgb::apca`].
+    pub fn apca(&self, background: impl Into<Rgb>) -> f32 {
+        Rgb::from(self).apca(background)
+    }
+
+    /// See [`Rgb::with_contrast`].
+    pub fn with_contrast(&self, background: impl Into<Rgb>, ratio: f32) -> Option<Rgb> {
+        Rgb::from(self).with_contrast(background, ratio)
+    }
+
+    /// The candidate with the highest WCAG contrast against this color as
+    /// a background, or `None` if there are no candidates. Ties go to the
+    /// earlier candidate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "wikipedia")] {
+    /// use pigment::{color, WcagLevel};
+    ///
+    /// let background = color("Absolute Zero").unwrap();
+    /// let text = background
+    ///     .best_foreground([color("Black").unwrap(), color("White").unwrap()])
+    ///     .unwrap();
+    /// assert_eq!(text.name(), "White");
+    /// assert!(background.wcag_level(text) >= WcagLevel::Aa);
+    /// # }
+    /// ```
+    pub fn best_foreground<C>(&self, candidates: impl IntoIterator<Item = C>) -> Option<C>
+    where
+        C: Into<Rgb> + Clone,
+    {
+        let background = Rgb::from(self);
+        let mut best: Option<(f32, C)> = None;
+        for candidate in candidates {
+            let ratio = background.contrast_ratio(candidate.clone());
+            if best.as_ref().is_none_or(|(other, _)| ratio > *other) {
+                best = Some((ratio, candidate));
+            }
+        }
+        best.map(|(_, c)| c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLACK: Rgb = Rgb::new(0, 0, 0);
+    const WHITE: Rgb = Rgb::new(255, 255, 255);
+
+    mod wcag {
+        use super::*;
+
+        #[test]
+        fn test_luminance() {
+            assert_eq!(BLACK.luminance(), 0.0);
+            assert!((WHITE.luminance() - 1.0).abs() < 1e-6);
+            assert!((Rgb::new(255, 0, 0).luminance() - 0.2126).abs() < 1e-4);
+        }
+
+        #[test]
+        fn test_contrast_ratio() {
+            assert_eq!(BLACK.contrast_ratio(WHITE), 21.0);
+            assert_eq!(WHITE.contrast_ratio(BLACK), 21.0);
+            assert_eq!(WHITE.contrast_ratio(WHITE), 1.0);
+            // Reference values from the WebAIM contrast checker
+            let ratio = Rgb::new(0x76, 0x76, 0x76).contrast_ratio(WHITE);
+            assert!((ratio - 4.54).abs() < 0.01, "{ratio}");
+            let ratio = Rgb::new(0, 0, 255).contrast_ratio(WHITE);
+            assert!((ratio - 8.59).abs() < 0.01, "{ratio}");
+        }
+
+        #[test]
+        fn test_levels() {
+            assert_eq!(WcagLevel::from_ratio(1.0), WcagLevel::Fail);
+            assert_eq!(WcagLevel::from_ratio(3.0), WcagLevel::AaLarge);
+            assert_eq!(WcagLevel::from_ratio(4.5), WcagLevel::Aa);
+            assert_eq!(WcagLevel::from_ratio(7.0), WcagLevel::Aaa);
+            assert_eq!(WcagLevel::from_ratio(f32::NAN), WcagLevel::Fail);
+            assert!(WcagLevel::Aaa > WcagLevel::Aa);
+            assert_eq!(BLACK.wcag_level(WHITE), WcagLevel::Aaa);
+            assert_eq!(WcagLevel::AaLarge.to_string(), "AA Large");
+        }
+    }
+
+    mod apca {
+        use super::*;
+
+        #[test]
+        fn test_reference_values() {
+            // From the APCA-W3 0.0.98G-4g reference implementation
+            assert!((BLACK.apca(WHITE) - 106.04).abs() < 0.01);
+            assert!((WHITE.apca(BLACK) - -107.88).abs() < 0.01);
+            let lc = Rgb::new(0x88, 0x88, 0x88).apca(WHITE);
+            assert!((lc - 63.06).abs() < 0.01, "{lc}");
+            let lc = WHITE.apca(Rgb::new(0x88, 0x88, 0x88));
+            assert!((lc - -68.54).abs() < 0.01, "{lc}");
+        }
+
+        #[test]
+        fn test_low_contrast_clips_to_zero() {
+            assert_eq!(WHITE.apca(WHITE), 0.0);
+            assert_eq!(Rgb::new(250, 250, 250).apca(WHITE), 0.0);
+        }
+    }
+
+    mod picking {
+        use super::*;
+
+        #[test]
+        fn test_with_contrast() {
+            let blue = Rgb::new(0x66, 0x99, 0xFF);
+            for background in [WHITE, BLACK, Rgb::new(0x30, 0x30, 0x60)] {
+                for ratio in [3.0, 4.5, 7.0] {
+                    let Some(c) = blue.with_contrast(background, ratio) else {
+                        continue;
+                    };
+                    assert!(
+                        c.contrast_ratio(background) >= ratio,
+                        "{background:?} {ratio}"
+                    );
+                }
+            }
+            assert_eq!(blue.with_contrast(BLACK, 3.0), Some(blue));
+            assert_eq!(blue.with_contrast(WHITE, 22.0), None);
+        }
+
+        #[test]
+        fn test_with_contrast_keeps_hue() {
+            let blue = Rgb::new(0x66, 0x99, 0xFF);
+            let darker = blue.with_contrast(WHITE, 7.0).unwrap();
+            let (a, b) = (blue.oklch().hue(), darker.oklch().hue());
+            assert!((a - b).abs() < 3.0, "{a} {b}");
+            assert!(darker.oklch().lightness() < blue.oklch().lightness());
+        }
+
+        #[test]
+        fn test_with_contrast_picks_smaller_change() {
+            // Mid gray can reach 3:1 against mid-dark gray either way; going
+            // lighter is the shorter trip
+            let background = Rgb::new(0x50, 0x50, 0x50);
+            let c = Rgb::new(0x70, 0x70, 0x70)
+                .with_contrast(background, 3.0)
+                .unwrap();
+            assert!(c.luminance() > background.luminance());
+        }
+
+        #[test]
+        #[cfg(feature = "wikipedia")]
+        fn test_best_foreground() {
+            let black = crate::color("Black").unwrap();
+            let white = crate::color("White").unwrap();
+            let yellow = crate::color("Yellow").unwrap();
+            assert_eq!(yellow.best_foreground([&white, &black]), Some(&black));
+            assert_eq!(black.best_foreground([&yellow, &white]), Some(&white));
+            assert_eq!(black.best_foreground(Vec::<Color>::new()), None);
+            assert_eq!(black.best_foreground([WHITE, WHITE]), Some(WHITE));
+        }
+    }
+}
@@ -0,0 +1,656 @@
+//! Writing colors back out as CSS.
+//!
+//! Every CSS color type implements [`Display`](fmt::Display) in its own
+//! notation, so `hsl(270 50% 40%)` parsed into an [`Hsl`] prints as it was
+//! written. [`Rgb`] and [`Rgba`] print as hex codes and also implement
+//! [`LowerHex`](fmt::LowerHex) and [`UpperHex`](fmt::UpperHex), and so do
+//! [`Color`] and [`Ansi`] through their `Rgb`; `css()` picks any other
+//! notation with a [`CssFormat`].
+//!
+//! The formatter flags work as for numbers: `{:#x}` adds the `#`, `{:#}`
+//! shortens `#rrggbb` to `#rgb` where it can, `{:.3}` writes components
+//! with exactly three decimals instead of trimming them, and width, fill
+//! and alignment pad the whole color. Alphas follow CSS, with the fewest
+//! decimals that still round-trip to the same 8-bit value.
+//!
+//! Lab and LCh are written as CSS `lab()` and `lch()`, which are relative
+//! to the D50 white point, so they're adapted from the D65 values here.
+
+use std::borrow::Cow;
+use std::fmt::{self, Alignment, Write};
+
+use crate::ansi::Ansi;
+#[cfg(feature = "css-names")]
+use crate::palette::Palette;
+use crate::{Color, Hsl, Hwb, Lab, Lch, Oklab, Oklch, ParsedColor, Rgb, Rgba};
+
+/// A CSS notation to write a color in, for [`Rgba::css`] and friends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CssFormat {
+    /// `#rrggbb`, or `#rrggbbaa` when translucent.
+    #[default]
+    Hex,
+    /// `#rgb` or `#rgba` when every digit is doubled, else like [`Hex`](Self::Hex).
+    ShortHex,
+    /// `rgb(r g b)`, or `rgb(r g b / a)` when translucent.
+    Rgb,
+    /// `hsl(h s% l%)`, with the alpha as for [`Rgb`](Self::Rgb).
+    Hsl,
+    /// `oklch(l c h)`, with the alpha as for [`Rgb`](Self::Rgb).
+    Oklch,
+    /// The CSS keyword with exactly this value, such as `rebeccapurple`
+    /// or `transparent`; [`ShortHex`](Self::ShortHex) when there is none.
+    /// Keywords other than `transparent` need the `css-names` feature, and
+    /// one that [`parse`](crate::parse()) would read as another palette's
+    /// color, such as `azure` next to Wikipedia's Azure, is written as hex
+    /// too.
+    Name,
+}
+
+/// A color ready to be written in a [`CssFormat`]; see [`Rgba::css`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Css {
+    color: Rgba,
+    format: CssFormat,
+}
+
+impl Rgba {
+    /// This color in CSS `format`, to print or turn into a `String`.
+    ///
+    /// ```
+    /// use pigment::{CssFormat, Rgba};
+    ///
+    /// let glass = Rgba::new(0x66, 0x33, 0x99, 128);
+    /// assert_eq!(glass.css(CssFormat::Hex).to_string(), "#66339980");
+    /// assert_eq!(glass.css(CssFormat::Rgb).to_string(), "rgb(102 51 153 / 0.5)");
+    /// assert_eq!(glass.css(CssFormat::Hsl).to_string(), "hsl(270 50% 40% / 0.5)");
+    /// assert_eq!(format!("{:.1}", glass.css(CssFormat::Oklch)), "oklch(0.4 0.2 303.4 / 0.5)");
+    /// ```
+    #[inline]
+    pub fn css(&self, format: CssFormat) -> Css {
+        Css {
+            color: *self,
+            format,
+        }
+    }
+}
+
+impl Rgb {
+    /// This color in CSS `format`, to print or turn into a `String`.
+    ///
+    /// ```
+    /// # #[cfg(feature = "css-names")] {
+    /// use pigment::{CssFormat, Rgb};
+    ///
+    /// let red = Rgb::new(255, 0, 0);
+    /// assert_eq!(red.css(CssFormat::ShortHex).to_string(), "#f00");
+    /// assert_eq!(red.css(CssFormat::Rgb).to_string(), "rgb(255 0 0)");
+    /// assert_eq!(red.css(CssFormat::Name).to_string(), "red");
+    /// assert_eq!(Rgb::new(0, 72, 186).css(CssFormat::Name).to_string(), "#0048ba");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn css(&self, format: CssFormat) -> Css {
+        self.with_alpha(u8::MAX).css(format)
+    }
+}
+
+impl ParsedColor {
+    /// This color in CSS `format`, to print or turn into a `String`.
+    ///
+    /// To write it back in the notation it was parsed from, use its
+    /// [`Display`](fmt::Display) implementation instead.
+    #[inline]
+    pub fn css(&self, format: CssFormat) -> Css {
+        self.rgba().css(format)
+    }
+}
+
+impl fmt::Display for Css {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = self.color;
+        match self.format {
+            CssFormat::Hex => pad(f, &hex(c, false, true)),
+            CssFormat::ShortHex => pad(f, &hex(c, true, true)),
+            CssFormat::Rgb => function(
+                f,
+                "rgb",
+                [
+                    (c.r as f64, 0, ""),
+                    (c.g as f64, 0, ""),
+                    (c.b as f64, 0, ""),
+                ],
+                c.a,
+            ),
+            CssFormat::Hsl => hsl(f, Hsl::from(c.rgb()), c.a),
+            CssFormat::Oklch => oklch(f, Oklch::from(c.rgb()), c.a),
+            CssFormat::Name => match keyword(c) {
+                Some(keyword) => pad(f, &keyword),
+                None => pad(f, &hex(c, true, true)),
+            },
+        }
+    }
+}
+
+/// `#rrggbb`; `{:#}` shortens it to `#rgb` when possible.
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.with_alpha(u8::MAX).fmt(f)
+    }
+}
+
+/// `#rrggbbaa`, or `#rrggbb` when opaque; `{:#}` shortens it to `#rgba`
+/// or `#rgb` when possible.
+impl fmt::Display for Rgba {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad(f, &hex(*self, f.alternate(), true))
+    }
+}
+
+/// `rrggbb`, and `{:#x}` for `#rrggbb`.
+impl fmt::LowerHex for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.with_alpha(u8::MAX), f)
+    }
+}
+
+/// `RRGGBB`, and `{:#X}` for `#RRGGBB`.
+impl fmt::UpperHex for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.with_alpha(u8::MAX), f)
+    }
+}
+
+/// `rrggbbaa`, or `rrggbb` when opaque, and `{:#x}` with a `#`.
+impl fmt::LowerHex for Rgba {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad(f, &hex(*self, false, f.alternate()))
+    }
+}
+
+/// `RRGGBBAA`, or `RRGGBB` when opaque, and `{:#X}` with a `#`.
+impl fmt::UpperHex for Rgba {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad(f, &hex(*self, false, f.alternate()).to_ascii_uppercase())
+    }
+}
+
+/// `#rrggbb` like [`Rgb`], so lowercase, while [`hex`](Color::hex) keeps
+/// the uppercase of the color tables. Use [`name`](Color::name) for the
+/// name.
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&Rgb::from(self), f)
+    }
+}
+
+/// `rrggbb`, and `{:#x}` for `#rrggbb`.
+impl fmt::LowerHex for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&Rgb::from(self), f)
+    }
+}
+
+/// `RRGGBB`, and `{:#X}` for `#RRGGBB`.
+impl fmt::UpperHex for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&Rgb::from(self), f)
+    }
+}
+
+/// `#rrggbb` like [`Rgb`]; the escape sequences are [`fg`](Ansi::fg) and
+/// [`bg`](Ansi::bg).
+impl fmt::Display for Ansi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&Rgb::from(self), f)
+    }
+}
+
+/// `rrggbb`, and `{:#x}` for `#rrggbb`.
+impl fmt::LowerHex for Ansi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&Rgb::from(self), f)
+    }
+}
+
+/// `RRGGBB`, and `{:#X}` for `#RRGGBB`.
+impl fmt::UpperHex for Ansi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&Rgb::from(self), f)
+    }
+}
+
+/// `hsl(h s% l%)`.
+impl fmt::Display for Hsl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        hsl(f, *self, u8::MAX)
+    }
+}
+
+/// `hwb(h w% b%)`.
+impl fmt::Display for Hwb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        hwb(f, *self, u8::MAX)
+    }
+}
+
+/// `lab(l a b)`, adapted to D50 like CSS.
+impl fmt::Display for Lab {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        lab(f, *self, u8::MAX)
+    }
+}
+
+/// `lch(l c h)`, adapted to D50 like CSS.
+impl fmt::Display for Lch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        lch(f, *self, u8::MAX)
+    }
+}
+
+/// `oklab(l a b)`.
+impl fmt::Display for Oklab {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        oklab(f, *self, u8::MAX)
+    }
+}
+
+/// `oklch(l c h)`.
+impl fmt::Display for Oklch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        oklch(f, *self, u8::MAX)
+    }
+}
+
+/// The notation the color was parsed from: the name for a named color,
+/// a hex code for `rgb()` and the other sRGB forms, and the function
+/// otherwise, with the alpha when translucent.
+///
+/// ```
+/// # #[cfg(feature = "wikipedia")] {
+/// let c = pigment::parse("HSLA(270, 50%, 40%, 0.5)").unwrap();
+/// assert_eq!(c.to_string(), "hsl(270 50% 40% / 0.5)");
+/// assert_eq!(pigment::parse("absolute_zero").unwrap().to_string(), "Absolute Zero");
+/// # }
+/// ```
+impl fmt::Display for ParsedColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ParsedColor::Named(ref c) => pad(f, &c.name),
+            ParsedColor::Rgb(c) => c.fmt(f),
+            ParsedColor::Hsl(c, a) => hsl(f, c, a),
+            ParsedColor::Hwb(c, a) => hwb(f, c, a),
+            ParsedColor::Lab(c, a) => lab(f, c, a),
+            ParsedColor::Lch(c, a) => lch(f, c, a),
+            ParsedColor::Oklab(c, a) => oklab(f, c, a),
+            ParsedColor::Oklch(c, a) => oklch(f, c, a),
+        }
+    }
+}
+
+// The CSS keyword with exactly the value `c`
+fn keyword(c: Rgba) -> Option<Cow<'static, str>> {
+    if c == Rgba::new(0, 0, 0, 0) {
+        return Some(Cow::Borrowed("transparent"));
+    }
+    // The CSS table's names are its keywords; one that `parse` reads as
+    // another palette's color wouldn't round-trip
+    #[cfg(feature = "css-names")]
+    if c.a == u8::MAX {
+        let rgb = c.rgb().tuple();
+        let named = Palette::Css.nearest(rgb);
+        if named.rgb == rgb && crate::color(&named.name).is_some_and(|c| c.rgb == rgb) {
+            return Some(named.name);
+        }
+    }
+    None
+}
+
+// Components are a value, its default number of decimals, and a unit
+
+fn hsl(f: &mut fmt::Formatter<'_>, c: Hsl, alpha: u8) -> fmt::Result {
+    let [h, s, l] = [c.hue, c.saturation * 100.0, c.lightness * 100.0].map(f64::from);
+    function(f, "hsl", [(h, 2, ""), (s, 2, "%"), (l, 2, "%")], alpha)
+}
+
+fn hwb(f: &mut fmt::Formatter<'_>, c: Hwb, alpha: u8) -> fmt::Result {
+    let [h, w, b] = [c.hue, c.whiteness * 100.0, c.blackness * 100.0].map(f64::from);
+    function(f, "hwb", [(h, 2, ""), (w, 2, "%"), (b, 2, "%")], alpha)
+}
+
+fn lab(f: &mut fmt::Formatter<'_>, c: Lab, alpha: u8) -> fmt::Result {
+    let [l, a, b] = c.to_d50();
+    function(f, "lab", [(l, 2, ""), (a, 2, ""), (b, 2, "")], alpha)
+}
+
+fn lch(f: &mut fmt::Formatter<'_>, c: Lch, alpha: u8) -> fmt::Result {
+    let [l, chroma, hue] = c.to_d50();
+    let hue = hue.rem_euclid(360.0);
+    function(f, "lch", [(l, 2, ""), (chroma, 2, ""), (hue, 2, "")], alpha)
+}
+
+fn oklab(f: &mut fmt::Formatter<'_>, c: Oklab, alpha: u8) -> fmt::Result {
+    let [l, a, b] = [c.l, c.a, c.b].map(f64::from);
+    function(f, "oklab", [(l, 5, ""), (a, 5, ""), (b, 5, "")], alpha)
+}
+
+fn oklch(f: &mut fmt::Formatter<'_>, c: Oklch, alpha: u8) -> fmt::Result {
+    let [l, chroma, hue] = [c.l, c.chroma, c.hue].map(f64::from);
+    function(
+        f,
+        "oklch",
+        [(l, 5, ""), (chroma, 5, ""), (hue, 3, "")],
+        alpha,
+    )
+}
+
+// `name(x y z)` or `name(x y z / alpha)`, with the formatter's precision
+// for the components if it has one
+fn function(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    components: [(f64, usize, &str); 3],
+    alpha: u8,
+) -> fmt::Result {
+    let mut css = format!("{name}(");
+    for (i, (value, decimals, unit)) in components.into_iter().enumerate() {
+        if i > 0 {
+            css.push(' ');
+        }
+        match f.precision() {
+            Some(precision) => write!(css, "{}", fixed(value, precision))?,
+            None => css.push_str(&number(value, decimals)),
+        }
+        css.push_str(unit);
+    }
+    if alpha != u8::MAX {
+        write!(css, " / {}", alpha_number(alpha))?;
+    }
+    css.push(')');
+    pad(f, &css)
+}
+
+// `#rrggbb[aa]`, shortened to `#rgb[a]` if asked and possible
+fn hex(c: Rgba, short: bool, hash: bool) -> String {
+    let mut channels = vec![c.r, c.g, c.b];
+    if c.a != u8::MAX {
+        channels.push(c.a);
+    }
+    let mut out = String::with_capacity(9);
+    if hash {
+        out.push('#');
+    }
+    if short && channels.iter().all(|&x| x % 17 == 0) {
+        for x in channels {
+            write!(out, "{:x}", x / 17).unwrap();
+        }
+    } else {
+        for x in channels {
+            write!(out, "{x:02x}").unwrap();
+        }
+    }
+    out
+}
+
+// `value` with at most `decimals` decimals, trailing zeros trimmed
+fn number(value: f64, decimals: usize) -> String {
+    let s = format!("{value:.decimals$}");
+    let s = if s.contains('.') {
+        s.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &s
+    };
+    if s == "-0" { "0".into() } else { s.into() }
+}
+
+// `value` with exactly `decimals` decimals
+fn fixed(value: f64, decimals: usize) -> String {
+    let s = format!("{value:.decimals$}");
+    match s.strip_prefix('-') {
+        Some(rest) if rest.bytes().all(|b| b == b'0' || b == b'.') => rest.into(),
+        _ => s,
+    }
+}
+
+// The shortest of two or three decimals that rounds back to `alpha`, as
+// CSS serializes alphas
+fn alpha_number(alpha: u8) -> String {
+    let x = alpha as f64 / 255.0;
+    let two = (x * 100.0).round() / 100.0;
+    if (two * 255.0).round() as u8 == alpha {
+        number(two, 2)
+    } else {
+        number(x, 3)
+    }
+}
+
+// `Formatter::pad` without its string truncation, since precision means
+// decimals here
+fn pad(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    let len = s.chars().count();
+    let Some(padding) = f.width().and_then(|width| width.checked_sub(len)) else {
+        return f.write_str(s);
+    };
+    let (before, after) = match f.align() {
+        Some(Alignment::Right) => (padding, 0),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Left) | None => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(s)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod hex {
+        use super::*;
+
+        #[test]
+        fn test_display() {
+            assert_eq!(Rgb::new(0, 72, 186).to_string(), "#0048ba");
+            assert_eq!(Rgba::new(0, 72, 186, 128).to_string(), "#0048ba80");
+            assert_eq!(Rgba::new(0, 72, 186, 255).to_string(), "#0048ba");
+            assert_eq!(format!("{:#}", Rgb::new(255, 0, 0)), "#f00");
+            assert_eq!(format!("{:#}", Rgba::new(255, 0, 0, 0x88)), "#f008");
+            assert_eq!(format!("{:#}", Rgb::new(255, 0, 1)), "#ff0001");
+        }
+
+        #[test]
+        fn test_hex_flags() {
+            let c = Rgb::new(0, 72, 186);
+            assert_eq!(format!("{c:x}"), "0048ba");
+            assert_eq!(format!("{c:#x}"), "#0048ba");
+            assert_eq!(format!("{c:X}"), "0048BA");
+            assert_eq!(format!("{c:#X}"), "#0048BA");
+            assert_eq!(format!("{:#X}", c.with_alpha(0)), "#0048BA00");
+        }
+
+        #[test]
+        fn test_color_and_ansi() {
+            let ansi = Ansi::rgb(0, 72, 186);
+            assert_eq!(ansi.to_string(), "#0048ba");
+            assert_eq!(format!("{ansi:#x} {ansi:X}"), "#0048ba 0048BA");
+            assert_eq!(format!("{:#}", Ansi::rgb(255, 0, 0)), "#f00");
+        }
+
+        #[test]
+        #[cfg(feature = "wikipedia")]
+        fn test_color_is_lowercase_unlike_hex() {
+            let zero = crate::color("Absolute Zero").unwrap();
+            assert_eq!(zero.hex(), "#0048BA");
+            assert_eq!(zero.to_string(), "#0048ba");
+            assert_eq!(format!("{zero:#x}"), "#0048ba");
+            assert_eq!(format!("{zero:#X}"), zero.hex());
+            assert_eq!(format!("[{zero:>9}]"), "[  #0048ba]");
+        }
+
+        #[test]
+        fn test_padding() {
+            let c = Rgb::new(255, 0, 0);
+            assert_eq!(format!("[{c:>9}]"), "[  #ff0000]");
+            assert_eq!(format!("[{c:-<9}]"), "[#ff0000--]");
+            assert_eq!(format!("[{c:^#6}]"), "[ #f00 ]");
+            assert_eq!(format!("[{c:3}]"), "[#ff0000]");
+        }
+    }
+
+    mod functions {
+        use super::*;
+
+        #[test]
+        fn test_css_formats() {
+            let c = Rgb::new(0x66, 0x33, 0x99);
+            assert_eq!(c.css(CssFormat::Hex).to_string(), "#663399");
+            assert_eq!(c.css(CssFormat::ShortHex).to_string(), "#639");
+            assert_eq!(c.css(CssFormat::Rgb).to_string(), "rgb(102 51 153)");
+            assert_eq!(c.css(CssFormat::Hsl).to_string(), "hsl(270 50% 40%)");
+            assert_eq!(
+                c.css(CssFormat::Oklch).to_string(),
+                "oklch(0.44027 0.1603 303.373)"
+            );
+        }
+
+        #[test]
+        fn test_alpha() {
+            let c = |a| Rgba::new(255, 0, 0, a).css(CssFormat::Rgb).to_string();
+            assert_eq!(c(0), "rgb(255 0 0 / 0)");
+            assert_eq!(c(128), "rgb(255 0 0 / 0.5)");
+            assert_eq!(c(64), "rgb(255 0 0 / 0.25)");
+            // 0.01 rounds to 3, so 2/255 needs a third decimal
+            assert_eq!(c(2), "rgb(255 0 0 / 0.008)");
+            for a in 0..=255 {
+                let out = c(a);
+                assert_eq!(crate::parse(&out).unwrap().alpha(), a, "{out}");
+            }
+        }
+
+        #[test]
+        fn test_precision() {
+            let hsl = Hsl::new(270.0, 0.5, 0.4);
+            assert_eq!(format!("{hsl:.1}"), "hsl(270.0 50.0% 40.0%)");
+            assert_eq!(format!("{hsl:.0}"), "hsl(270 50% 40%)");
+            assert_eq!(
+                format!("{:.2}", Oklch::new(0.5, 0.0, 0.0)),
+                "oklch(0.50 0.00 0.00)"
+            );
+            assert_eq!(
+                format!("{:>20}", Hwb::new(0.0, 0.0, 0.0)),
+                "        hwb(0 0% 0%)"
+            );
+        }
+
+        #[test]
+        fn test_lab_is_written_in_d50() {
+            // The CSS reference value for sRGB red
+            assert_eq!(
+                Lab::from(Rgb::new(255, 0, 0)).to_string(),
+                "lab(54.29 80.8 69.89)"
+            );
+            assert_eq!(
+                Lch::from(Rgb::new(255, 0, 0)).to_string(),
+                "lch(54.29 106.84 40.86)"
+            );
+            assert_eq!(
+                Lab::from(Rgb::new(255, 255, 255)).to_string(),
+                "lab(100 0 0)"
+            );
+        }
+    }
+
+    mod names {
+        use super::*;
+
+        fn name(c: Rgba) -> String {
+            c.css(CssFormat::Name).to_string()
+        }
+
+        #[test]
+        fn test_transparent() {
+            assert_eq!(name(Rgba::new(0, 0, 0, 0)), "transparent");
+            assert_eq!(name(Rgba::new(255, 0, 0, 0)), "#f000");
+        }
+
+        #[test]
+        #[cfg(feature = "css-names")]
+        fn test_css_keywords_only() {
+            assert_eq!(name(Rgba::new(0x66, 0x33, 0x99, 255)), "rebeccapurple");
+            assert_eq!(name(Rgba::new(0xF5, 0xF5, 0xF5, 255)), "whitesmoke");
+            assert_eq!(name(Rgba::new(0, 72, 186, 255)), "#0048ba");
+            assert_eq!(name(Rgba::new(0x66, 0x33, 0x99, 128)), "#66339980");
+        }
+
+        #[test]
+        #[cfg(all(feature = "css-names", feature = "wikipedia"))]
+        fn test_shadowed_keywords_are_hex() {
+            assert_eq!(name(Rgba::new(0xF0, 0xFF, 0xFF, 255)), "#f0ffff");
+        }
+
+        #[test]
+        #[cfg(not(feature = "css-names"))]
+        fn test_hex_without_css_names() {
+            assert_eq!(name(Rgba::new(255, 0, 0, 255)), "#f00");
+        }
+
+        #[test]
+        #[cfg(feature = "css-names")]
+        fn test_keywords_parse_strictly() {
+            for c in Palette::Css.all() {
+                let css = name(Rgba::from(&c));
+                let parsed = crate::parse_strict(&css).unwrap();
+                assert_eq!(parsed.rgb(), Rgb::from(&c), "{css}");
+            }
+        }
+    }
+
+    mod round_trip {
+        use super::*;
+
+        #[test]
+        fn test_parsed_colors_print_as_written() {
+            for css in [
+                "#0048ba",
+                "#0048ba80",
+                "hsl(270 50% 40%)",
+                "hsl(270 50% 40% / 0.5)",
+                "hwb(270 20% 40%)",
+                "lab(54.29 80.8 69.89)",
+                "lch(54.29 106.84 40.85 / 0.25)",
+                "oklab(0.628 0.2249 0.1258)",
+                "oklch(0.628 0.2577 29.23)",
+            ] {
+                assert_eq!(crate::parse(css).unwrap().to_string(), css);
+            }
+        }
+
+        #[test]
+        fn test_every_format_parses_back() {
+            for r in (0..=255).step_by(15) {
+                for g in (0..=255).step_by(15) {
+                    for b in (0..=255).step_by(15) {
+                        let c = Rgb::new(r as u8, g as u8, b as u8);
+                        for format in [
+                            CssFormat::Hex,
+                            CssFormat::ShortHex,
+                            CssFormat::Rgb,
+                            CssFormat::Hsl,
+                            CssFormat::Oklch,
+                            CssFormat::Name,
+                        ] {
+                            let css = c.css(format).to_string();
+                            assert_eq!(crate::parse(&css).unwrap().rgb(), c, "{css}");
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
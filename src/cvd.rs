@@ -0,0 +1,460 @@
+//! How colors look with color vision deficiency, and which ones run together.
+//!
+//! [`Rgb::simulate`] shows a color as someone with a [`Deficiency`] sees it,
+//! using one of three published [`CvdModel`]s. [`confusions`] goes through a
+//! set of colors, such as status colors, and reports the pairs that tell
+//! apart with normal vision but not under some deficiency.
+//!
+//! The simulations work in linear RGB with the matrices published by
+//! [libDaltonLens](https://daltonlens.org) for Brettel and Viénot, and by
+//! Machado et al. at full severity. Distances are Euclidean in OKLab, as
+//! for [`nearest`](crate::nearest).
+
+use std::fmt;
+
+use crate::lab::{encode, linearize, oklab_from_linear};
+use crate::{Color, Rgb};
+
+/// A kind of color vision deficiency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Deficiency {
+    /// No working L (red) cones: reds look dark and merge with greens.
+    Protanopia,
+    /// No working M (green) cones, the most common: reds and greens merge.
+    Deuteranopia,
+    /// No working S (blue) cones: blues merge with greens, yellows with
+    /// pinks.
+    Tritanopia,
+    /// No working cones of any kind: only lightness is left.
+    Achromatopsia,
+}
+
+impl Deficiency {
+    /// Every deficiency, in declaration order.
+    pub const ALL: [Deficiency; 4] = [
+        Deficiency::Protanopia,
+        Deficiency::Deuteranopia,
+        Deficiency::Tritanopia,
+        Deficiency::Achromatopsia,
+    ];
+
+    /// The deficiency's name, such as "Deuteranopia".
+    pub fn name(self) -> &'static str {
+        match self {
+            Deficiency::Protanopia => "Protanopia",
+            Deficiency::Deuteranopia => "Deuteranopia",
+            Deficiency::Tritanopia => "Tritanopia",
+            Deficiency::Achromatopsia => "Achromatopsia",
+        }
+    }
+}
+
+impl fmt::Display for Deficiency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A published method of simulating dichromacy. All three agree on
+/// [`Achromatopsia`](Deficiency::Achromatopsia), which keeps luminance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CvdModel {
+    /// Brettel, Viénot and Mollon (1997): two half-planes per deficiency.
+    /// Accurate for all three dichromacies.
+    #[default]
+    Brettel,
+    /// Viénot, Brettel and Mollon (1999): a single plane. Accurate for
+    /// protanopia and deuteranopia and fast, but poor for tritanopia.
+    Vienot,
+    /// Machado, Oliveira and Fernandes (2009) at full severity.
+    Machado,
+}
+
+type Matrix = [[f64; 3]; 3];
+
+// Brettel: (matrix on the side of the normal, other matrix, normal)
+const BRETTEL_PROTAN: (Matrix, Matrix, [f64; 3]) = (
+    [
+        [0.14980, 1.19548, -0.34528],
+        [0.10764, 0.84864, 0.04372],
+        [0.00384, -0.00540, 1.00156],
+    ],
+    [
+        [0.14570, 1.16172, -0.30742],
+        [0.10816, 0.85291, 0.03892],
+        [0.00386, -0.00524, 1.00139],
+    ],
+    [0.00048, 0.00393, -0.00441],
+);
+const BRETTEL_DEUTAN: (Matrix, Matrix, [f64; 3]) = (
+    [
+        [0.36477, 0.86381, -0.22858],
+        [0.26294, 0.64245, 0.09462],
+        [-0.02006, 0.02728, 0.99278],
+    ],
+    [
+        [0.37298, 0.88166, -0.25464],
+        [0.25954, 0.63506, 0.10540],
+        [-0.01980, 0.02784, 0.99196],
+    ],
+    [-0.00281, -0.00611, 0.00892],
+);
+const BRETTEL_TRITAN: (Matrix, Matrix, [f64; 3]) = (
+    [
+        [1.01277, 0.13548, -0.14826],
+        [-0.01243, 0.86812, 0.14431],
+        [0.07589, 0.80500, 0.11911],
+    ],
+    [
+        [0.93678, 0.18979, -0.12657],
+        [0.06154, 0.81526, 0.12320],
+        [-0.37562, 1.12767, 0.24796],
+    ],
+    [0.03901, -0.02788, -0.01113],
+);
+
+const VIENOT_PROTAN: Matrix = [
+    [0.11238, 0.88762, 0.00000],
+    [0.11238, 0.88762, 0.00000],
+    [0.00401, -0.00401, 1.00000],
+];
+const VIENOT_DEUTAN: Matrix = [
+    [0.29275, 0.70725, 0.00000],
+    [0.29275, 0.70725, 0.00000],
+    [-0.02234, 0.02234, 1.00000],
+];
+const VIENOT_TRITAN: Matrix = [
+    [1.00000, 0.14461, -0.14461],
+    [0.00000, 0.85924, 0.14076],
+    [0.00000, 0.85924, 0.14076],
+];
+
+const MACHADO_PROTAN: Matrix = [
+    [0.152286, 1.052583, -0.204868],
+    [0.114503, 0.786281, 0.099216],
+    [-0.003882, -0.048116, 1.051998],
+];
+const MACHADO_DEUTAN: Matrix = [
+    [0.367322, 0.860646, -0.227968],
+    [0.280085, 0.672501, 0.047413],
+    [-0.011820, 0.042940, 0.968881],
+];
+const MACHADO_TRITAN: Matrix = [
+    [1.255528, -0.076749, -0.178779],
+    [-0.078411, 0.930809, 0.147602],
+    [0.004733, 0.691367, 0.303900],
+];
+
+/// The default OKLab distance below which [`confusions`] calls two colors
+/// indistinguishable, a little over the smallest difference most people
+/// notice side by side.
+pub const MIN_DISTANCE: f32 = 0.04;
+
+impl Rgb {
+    /// This color as seen with `deficiency`, simulated with the default
+    /// [`CvdModel`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pigment::{Deficiency, Rgb};
+    ///
+    /// let red = Rgb::new(255, 0, 0);
+    /// let green = Rgb::new(0, 128, 0);
+    /// // Far apart with normal vision, close together without M cones
+    /// let r = red.simulate(Deficiency::Deuteranopia);
+    /// let g = green.simulate(Deficiency::Deuteranopia);
+    /// assert!(r.r().abs_diff(g.r()) < 80 && r.b().abs_diff(g.b()) < 30);
+    ///
+    /// let gray = red.simulate(Deficiency::Achromatopsia);
+    /// assert_eq!(gray.r(), gray.g());
+    /// assert_eq!(gray.g(), gray.b());
+    /// ```
+    pub fn simulate(&self, deficiency: Deficiency) -> Rgb {
+        self.simulate_with(deficiency, CvdModel::default())
+    }
+
+    /// This color as seen with `deficiency`, simulated with `model`.
+    pub fn simulate_with(&self, deficiency: Deficiency, model: CvdModel) -> Rgb {
+        let linear = linearize(self.tuple());
+        let brettel = |(h1, h2, normal): (Matrix, Matrix, [f64; 3])| {
+            let side: f64 = (0..3).map(|i| linear[i] * normal[i]).sum();
+            apply(if side >= 0.0 { &h1 } else { &h2 }, linear)
+        };
+        encode(match (deficiency, model) {
+            (Deficiency::Achromatopsia, _) => {
+                let [r, g, b] = linear;
+                [0.2126 * r + 0.7152 * g + 0.0722 * b; 3]
+            }
+            (Deficiency::Protanopia, CvdModel::Brettel) => brettel(BRETTEL_PROTAN),
+            (Deficiency::Deuteranopia, CvdModel::Brettel) => brettel(BRETTEL_DEUTAN),
+            (Deficiency::Tritanopia, CvdModel::Brettel) => brettel(BRETTEL_TRITAN),
+            (Deficiency::Protanopia, CvdModel::Vienot) => apply(&VIENOT_PROTAN, linear),
+            (Deficiency::Deuteranopia, CvdModel::Vienot) => apply(&VIENOT_DEUTAN, linear),
+            (Deficiency::Tritanopia, CvdModel::Vienot) => apply(&VIENOT_TRITAN, linear),
+            (Deficiency::Protanopia, CvdModel::Machado) => apply(&MACHADO_PROTAN, linear),
+            (Deficiency::Deuteranopia, CvdModel::Machado) => apply(&MACHADO_DEUTAN, linear),
+            (Deficiency::Tritanopia, CvdModel::Machado) => apply(&MACHADO_TRITAN, linear),
+        })
+    }
+}
+
+impl Color {
+    /// See [`Rgb::simulate`].
+    pub fn simulate(&self, deficiency: Deficiency) -> Rgb {
+        Rgb::from(self).simulate(deficiency)
+    }
+
+    /// See [`Rgb::simulate_with`].
+    pub fn simulate_with(&self, deficiency: Deficiency, model: CvdModel) -> Rgb {
+        Rgb::from(self).simulate_with(deficiency, model)
+    }
+}
+
+fn apply(m: &Matrix, [r, g, b]: [f64; 3]) -> [f64; 3] {
+    m.map(|row| row[0] * r + row[1] * g + row[2] * b)
+}
+
+/// Two colors of a set that look alike under one deficiency.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Confusion<C> {
+    pub(crate) deficiency: Deficiency,
+    pub(crate) indices: (usize, usize),
+    pub(crate) colors: (C, C),
+    pub(crate) distance: f32,
+}
+
+impl<C> Confusion<C> {
+    /// The deficiency under which the colors look alike.
+    #[inline]
+    pub fn deficiency(&self) -> Deficiency {
+        self.deficiency
+    }
+
+    /// The two colors, in the order they came in.
+    #[inline]
+    pub fn colors(&self) -> (&C, &C) {
+        (&self.colors.0, &self.colors.1)
+    }
+
+    /// The positions of the two colors in the checked set.
+    #[inline]
+    pub fn indices(&self) -> (usize, usize) {
+        self.indices
+    }
+
+    /// The OKLab distance between the two simulated colors.
+    #[inline]
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+}
+
+/// The pairs of `colors` that are distinguishable with normal vision but
+/// closer than [`MIN_DISTANCE`] under some deficiency, simulated with the
+/// default [`CvdModel`].
+///
+/// Confusions come grouped by deficiency in [`Deficiency::ALL`] order, and
+/// within each in the order of the pairs.
+///
+/// # Examples
+///
+/// ```
+/// use pigment::{Deficiency, color, confusions};
+///
+/// let status = [
+///     color("Red").unwrap(),
+///     color("Green").unwrap(),
+///     color("Blue").unwrap(),
+/// ];
+/// for c in confusions(&status) {
+///     let (a, b) = c.colors();
+///     println!("{}: {} and {}", c.deficiency(), a.name(), b.name());
+/// }
+/// ```
+pub fn confusions<C: Into<Rgb> + Clone>(colors: &[C]) -> Vec<Confusion<C>> {
+    confusions_with(colors, CvdModel::default(), MIN_DISTANCE)
+}
+
+/// Like [`confusions`], simulating with `model` and calling colors closer
+/// than `min_distance` in OKLab indistinguishable.
+pub fn confusions_with<C: Into<Rgb> + Clone>(
+    colors: &[C],
+    model: CvdModel,
+    min_distance: f32,
+) -> Vec<Confusion<C>> {
+    let rgb: Vec<Rgb> = colors.iter().map(|c| c.clone().into()).collect();
+    let oklab = |c: Rgb| oklab_from_linear(linearize(c.tuple()));
+    let normal: Vec<[f64; 3]> = rgb.iter().map(|&c| oklab(c)).collect();
+    let mut found = Vec::new();
+    for deficiency in Deficiency::ALL {
+        let seen: Vec<[f64; 3]> = rgb
+            .iter()
+            .map(|c| oklab(c.simulate_with(deficiency, model)))
+            .collect();
+        for i in 0..colors.len() {
+            for j in i + 1..colors.len() {
+                let apart = distance(&seen[i], &seen[j]);
+                if apart < min_distance && distance(&normal[i], &normal[j]) >= min_distance {
+                    found.push(Confusion {
+                        deficiency,
+                        indices: (i, j),
+                        colors: (colors[i].clone(), colors[j].clone()),
+                        distance: apart,
+                    });
+                }
+            }
+        }
+    }
+    found
+}
+
+fn distance(a: &[f64; 3], b: &[f64; 3]) -> f32 {
+    (0..3).map(|i| (a[i] - b[i]).powi(2)).sum::<f64>().sqrt() as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODELS: [CvdModel; 3] = [CvdModel::Brettel, CvdModel::Vienot, CvdModel::Machado];
+
+    mod simulation {
+        use super::*;
+
+        #[test]
+        fn test_neutrals_are_kept() {
+            for model in MODELS {
+                for deficiency in Deficiency::ALL {
+                    for v in [0, 128, 255] {
+                        let gray = Rgb::new(v, v, v);
+                        let seen = gray.simulate_with(deficiency, model);
+                        assert!(
+                            seen.r.abs_diff(v) <= 1
+                                && seen.g.abs_diff(v) <= 1
+                                && seen.b.abs_diff(v) <= 1,
+                            "{deficiency} {model:?} {seen:?}"
+                        );
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_achromatopsia_is_gray() {
+            for model in MODELS {
+                let gray = Rgb::new(0, 72, 186).simulate_with(Deficiency::Achromatopsia, model);
+                assert_eq!(gray.r, gray.g);
+                assert_eq!(gray.g, gray.b);
+            }
+        }
+
+        #[test]
+        fn test_dichromacy_is_a_projection() {
+            // Seeing a simulated color again changes nothing
+            for deficiency in [
+                Deficiency::Protanopia,
+                Deficiency::Deuteranopia,
+                Deficiency::Tritanopia,
+            ] {
+                for model in [CvdModel::Brettel, CvdModel::Vienot] {
+                    for c in [
+                        Rgb::new(0x80, 0x60, 0x50),
+                        Rgb::new(0x66, 0x99, 0x88),
+                        Rgb::new(40, 200, 90),
+                    ] {
+                        let once = c.simulate_with(deficiency, model);
+                        let twice = once.simulate_with(deficiency, model);
+                        assert!(
+                            once.r.abs_diff(twice.r) <= 2
+                                && once.g.abs_diff(twice.g) <= 2
+                                && once.b.abs_diff(twice.b) <= 2,
+                            "{deficiency} {model:?} {once:?} {twice:?}"
+                        );
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_red_and_green_merge() {
+            let red = Rgb::new(0xE0, 0x40, 0x30);
+            let green = Rgb::new(0x70, 0x80, 0x30);
+            let oklab = |c: Rgb| oklab_from_linear(linearize(c.tuple()));
+            assert!(distance(&oklab(red), &oklab(green)) > 0.1);
+            for deficiency in [Deficiency::Protanopia, Deficiency::Deuteranopia] {
+                let (r, g) = (red.simulate(deficiency), green.simulate(deficiency));
+                let close = distance(&oklab(r), &oklab(g));
+                assert!(close < 0.1, "{deficiency} {close}");
+            }
+        }
+
+        #[test]
+        fn test_names() {
+            assert_eq!(Deficiency::Tritanopia.to_string(), "Tritanopia");
+        }
+    }
+
+    mod checker {
+        use super::*;
+
+        #[test]
+        fn test_simulated_pairs_are_confused() {
+            let purple = Rgb::new(0x99, 0x44, 0xCC);
+            for deficiency in [
+                Deficiency::Protanopia,
+                Deficiency::Deuteranopia,
+                Deficiency::Tritanopia,
+            ] {
+                let seen = purple.simulate_with(deficiency, CvdModel::Vienot);
+                let found = confusions_with(&[purple, seen], CvdModel::Vienot, MIN_DISTANCE);
+                assert!(
+                    found
+                        .iter()
+                        .any(|c| c.deficiency() == deficiency && c.indices() == (0, 1)),
+                    "{deficiency} {found:?}"
+                );
+            }
+        }
+
+        #[test]
+        fn test_distinct_set_is_clear() {
+            let set = [Rgb::new(0, 0, 0), Rgb::new(255, 255, 255)];
+            assert!(confusions(&set).is_empty());
+        }
+
+        #[test]
+        fn test_same_colors_are_not_reported() {
+            // Already alike with normal vision, so no deficiency makes them so
+            let red = Rgb::new(255, 0, 0);
+            assert!(confusions(&[red, red]).is_empty());
+        }
+
+        #[test]
+        fn test_grays_under_achromatopsia() {
+            let blue = Rgb::new(0, 0, 255);
+            let gray = blue.simulate(Deficiency::Achromatopsia);
+            let found = confusions(&[blue, gray]);
+            let c = found.last().unwrap();
+            assert_eq!(c.deficiency(), Deficiency::Achromatopsia);
+            assert_eq!(c.colors(), (&blue, &gray));
+            assert!(c.distance() < MIN_DISTANCE);
+        }
+
+        #[test]
+        #[cfg(feature = "wikipedia")]
+        fn test_named_colors() {
+            let red = crate::color("Red").unwrap();
+            assert_eq!(
+                red.simulate(Deficiency::Deuteranopia),
+                Rgb::from(&red).simulate(Deficiency::Deuteranopia)
+            );
+            let found = confusions(&[red, crate::color("Blue").unwrap()]);
+            assert!(
+                found
+                    .iter()
+                    .all(|c| c.deficiency() != Deficiency::Deuteranopia)
+            );
+        }
+    }
+}
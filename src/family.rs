@@ -0,0 +1,323 @@
+//! Grouping colors by hue, lightness and chroma.
+//!
+//! Colors are classified from their OKLCH coordinates, the polar form of
+//! OKLab. `tools/scrape.py` runs the same rules over every palette to
+//! generate the family and band lists, and [`Color::family`],
+//! [`Color::lightness`] and [`Color::chroma`] run them at runtime, so
+//! registered colors are classified too.
+
+use crate::Color;
+use crate::iter::Colors;
+use crate::nearest::oklab_f64;
+use crate::palette::{Palette, Table};
+
+/// A hue family, the everyday name for a group of similar colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Family {
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Cyan,
+    Blue,
+    Purple,
+    Pink,
+    /// Dark oranges and yellows, from tan to chocolate.
+    Brown,
+    /// Whites, grays and blacks, and anything too muted to have a hue.
+    Gray,
+}
+
+impl Family {
+    /// Every family, in hue order with the brown and gray families last.
+    pub const ALL: [Family; 10] = [
+        Family::Red,
+        Family::Orange,
+        Family::Yellow,
+        Family::Green,
+        Family::Cyan,
+        Family::Blue,
+        Family::Purple,
+        Family::Pink,
+        Family::Brown,
+        Family::Gray,
+    ];
+
+    /// Lowercase English name of the family, e.g. `"blue"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Family::Red => "red",
+            Family::Orange => "orange",
+            Family::Yellow => "yellow",
+            Family::Green => "green",
+            Family::Cyan => "cyan",
+            Family::Blue => "blue",
+            Family::Purple => "purple",
+            Family::Pink => "pink",
+            Family::Brown => "brown",
+            Family::Gray => "gray",
+        }
+    }
+}
+
+/// How light a color is, by OKLCH lightness.
+///
+/// Bands are ordered from dark to light, so `lightness <= Lightness::Medium`
+/// means "not light".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Lightness {
+    /// Lightness below 0.45.
+    Dark,
+    /// Lightness from 0.45 up to 0.75.
+    Medium,
+    /// Lightness of 0.75 and above.
+    Light,
+}
+
+impl Lightness {
+    /// Every band, from dark to light.
+    pub const ALL: [Lightness; 3] = [Lightness::Dark, Lightness::Medium, Lightness::Light];
+}
+
+/// How colorful a color is, by OKLCH chroma.
+///
+/// Bands are ordered from muted to vivid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Chroma {
+    /// Chroma below 0.08: grays and grayish tones.
+    Muted,
+    /// Chroma from 0.08 up to 0.16.
+    Moderate,
+    /// Chroma of 0.16 and above.
+    Vivid,
+}
+
+impl Chroma {
+    /// Every band, from muted to vivid.
+    pub const ALL: [Chroma; 3] = [Chroma::Muted, Chroma::Moderate, Chroma::Vivid];
+}
+
+/// Colors of hue `family`, in display-name order.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "wikipedia")] {
+/// use pigment::Family;
+///
+/// let blues = pigment::family(Family::Blue);
+/// assert!(blues.len() > 50);
+/// assert!(blues.map(|c| c.name().to_owned()).any(|name| name == "Absolute Zero"));
+/// # }
+/// ```
+pub fn family(family: Family) -> Colors {
+    Palette::default().family(family)
+}
+
+/// Colors of hue `family` in one lightness and chroma band, in display-name
+/// order.
+///
+/// The bands are worked out when the tables are generated, so this is as
+/// cheap as [`family`]. To span several bands, chain the calls or filter
+/// [`family`] on [`Color::lightness`] and [`Color::chroma`], whose bands
+/// are ordered.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "wikipedia")] {
+/// use pigment::{Chroma, Family, Lightness, family_in_band};
+///
+/// // Pastels: light, muted pinks
+/// let pastels: Vec<_> = family_in_band(Family::Pink, Lightness::Light, Chroma::Muted).collect();
+/// assert!(pastels.iter().all(|c| c.lightness() == Lightness::Light));
+///
+/// // Every green that isn't light, whatever its chroma
+/// let mut greens = pigment::family(Family::Green).filter(|c| c.lightness() < Lightness::Light);
+/// assert!(greens.any(|c| c.name() == "Forest green (web)"));
+/// # }
+/// ```
+pub fn family_in_band(family: Family, lightness: Lightness, chroma: Chroma) -> Colors {
+    Palette::default().family_in_band(family, lightness, chroma)
+}
+
+pub(crate) fn family_in(table: &'static Table, family: Family) -> Colors {
+    Colors::new(table, table.families[family as usize])
+}
+
+impl Color {
+    /// The hue family this color belongs to.
+    ///
+    /// ```
+    /// # #[cfg(feature = "wikipedia")] {
+    /// use pigment::Family;
+    ///
+    /// assert_eq!(pigment::color("Navy blue").unwrap().family(), Family::Blue);
+    /// assert_eq!(pigment::color("Chocolate (web)").unwrap().family(), Family::Brown);
+    /// # }
+    /// ```
+    pub fn family(&self) -> Family {
+        classify(self.rgb)
+    }
+
+    /// Whether this color is dark, medium or light.
+    // Must stay in sync with `bands` in tools/scrape.py
+    pub fn lightness(&self) -> Lightness {
+        match oklch(self.rgb)[0] {
+            l if l < 0.45 => Lightness::Dark,
+            l if l < 0.75 => Lightness::Medium,
+            _ => Lightness::Light,
+        }
+    }
+
+    /// Whether this color is muted, moderately colorful or vivid.
+    // Must stay in sync with `bands` in tools/scrape.py
+    pub fn chroma(&self) -> Chroma {
+        match oklch(self.rgb)[1] {
+            c if c < 0.08 => Chroma::Muted,
+            c if c < 0.16 => Chroma::Moderate,
+            _ => Chroma::Vivid,
+        }
+    }
+}
+
+// Lightness, chroma and hue in degrees
+fn oklch(rgb: (u8, u8, u8)) -> [f64; 3] {
+    let [l, a, b] = oklab_f64(rgb);
+    [l, a.hypot(b), b.atan2(a).to_degrees().rem_euclid(360.0)]
+}
+
+// Must stay in sync with `family` in tools/scrape.py
+pub(crate) fn classify(rgb: (u8, u8, u8)) -> Family {
+    let [l, c, h] = oklch(rgb);
+    if c < 0.035 {
+        return Family::Gray;
+    }
+    match h {
+        // Magenta to crimson: pink when light, purple or red when dark
+        h if !(15.0..340.0).contains(&h) => match (l >= 0.5, h >= 340.0) {
+            (true, _) => Family::Pink,
+            (false, true) => Family::Purple,
+            (false, false) => Family::Red,
+        },
+        // Brick reds darken into browns, and lighten into salmon pinks
+        h if h < 40.0 && l < 0.5 && c < 0.17 => Family::Brown,
+        h if h < 40.0 && l >= 0.78 => Family::Pink,
+        h if h < 40.0 => Family::Red,
+        // Dark oranges and yellows read as browns and olives
+        h if h < 100.0 && l < 0.65 => Family::Brown,
+        h if h < 75.0 => Family::Orange,
+        h if h < 120.0 => Family::Yellow,
+        h if h < 175.0 => Family::Green,
+        h if h < 230.0 => Family::Cyan,
+        h if h < 290.0 => Family::Blue,
+        _ => Family::Purple,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rgb(hex: u32) -> (u8, u8, u8) {
+        ((hex >> 16) as u8, (hex >> 8) as u8, hex as u8)
+    }
+
+    mod classification {
+        use super::*;
+
+        #[test]
+        fn test_generated_lists_agree() {
+            for &palette in Palette::ALL {
+                let mut total = 0;
+                for family in Family::ALL {
+                    for c in palette.family(family) {
+                        assert_eq!(c.family(), family, "{palette:?} {}", c.name());
+                        total += 1;
+                    }
+                }
+                assert_eq!(total, palette.len(), "{palette:?}");
+            }
+        }
+
+        #[test]
+        fn test_samples() {
+            let cases = [
+                (0xFF0000, Family::Red),
+                (0xDC143C, Family::Red),
+                (0xFFA500, Family::Orange),
+                (0xFFD700, Family::Yellow),
+                (0x228B22, Family::Green),
+                (0x00CED1, Family::Cyan),
+                (0x000080, Family::Blue),
+                (0x663399, Family::Purple),
+                (0xFF69B4, Family::Pink),
+                (0x8B4513, Family::Brown),
+                (0x808080, Family::Gray),
+                (0xFFFFFF, Family::Gray),
+                (0x000000, Family::Gray),
+            ];
+            for (hex, family) in cases {
+                assert_eq!(classify(rgb(hex)), family, "{hex:06X}");
+            }
+        }
+
+        #[test]
+        fn test_names() {
+            assert_eq!(Family::ALL.map(Family::name).len(), 10);
+            assert_eq!(Family::Gray.name(), "gray");
+        }
+    }
+
+    mod bands {
+        use super::*;
+
+        fn color(hex: u32) -> Color {
+            Palette::default().nearest(rgb(hex))
+        }
+
+        #[test]
+        fn test_lightness() {
+            assert_eq!(color(0x000000).lightness(), Lightness::Dark);
+            assert_eq!(color(0x808080).lightness(), Lightness::Medium);
+            assert_eq!(color(0xFFFFFF).lightness(), Lightness::Light);
+        }
+
+        #[test]
+        fn test_chroma() {
+            assert_eq!(color(0x808080).chroma(), Chroma::Muted);
+            assert_eq!(color(0xFF0000).chroma(), Chroma::Vivid);
+        }
+
+        #[test]
+        fn test_generated_bands_agree() {
+            for &palette in Palette::ALL {
+                for family in Family::ALL {
+                    let mut total = 0;
+                    for lightness in Lightness::ALL {
+                        for chroma in Chroma::ALL {
+                            for c in palette.family_in_band(family, lightness, chroma) {
+                                let name = c.name();
+                                assert_eq!(c.family(), family, "{palette:?} {name}");
+                                assert_eq!(c.lightness(), lightness, "{palette:?} {name}");
+                                assert_eq!(c.chroma(), chroma, "{palette:?} {name}");
+                                total += 1;
+                            }
+                        }
+                    }
+                    assert_eq!(
+                        total,
+                        palette.family(family).len(),
+                        "{palette:?} {family:?}"
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn test_bands_are_ordered() {
+            assert!(Lightness::Dark < Lightness::Medium && Lightness::Medium < Lightness::Light);
+            assert!(Chroma::Muted < Chroma::Moderate && Chroma::Moderate < Chroma::Vivid);
+        }
+    }
+}
@@ -0,0 +1,349 @@
+//! Smooth ramps between colors, for progress bars, heatmaps and the like.
+//!
+//! A [`Gradient`] runs from position 0 to 1 through any number of color
+//! stops. Between two stops it blends in the chosen [`Interpolation`]
+//! space: OKLab by default, which keeps the middle of a ramp from going
+//! muddy or dark the way plain sRGB blending does.
+
+use crate::ansi::Ansi;
+use crate::lab::{encode, linear_from_oklab, linearize, oklab_from_linear};
+use crate::{Oklch, Rgb};
+
+/// Chroma below which a stop counts as gray, so its meaningless hue is
+/// replaced by its neighbor's when blending in OKLCH.
+const ACHROMATIC: f32 = 1e-4;
+
+/// The space a [`Gradient`] blends its stops in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Interpolation {
+    /// Gamma-encoded sRGB, as CSS gradients did before Color Level 4.
+    Srgb,
+    /// Linear-light RGB, which blends like mixing light.
+    LinearRgb,
+    /// OKLab: perceptually even steps without hue detours.
+    #[default]
+    Oklab,
+    /// OKLCH, keeping colors vivid by going around the hue circle.
+    Oklch(HueArc),
+}
+
+/// Which way round the hue circle [`Interpolation::Oklch`] goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HueArc {
+    /// The shorter way: red to blue passes through purple.
+    #[default]
+    Shorter,
+    /// The longer way: red to blue passes through yellow and green.
+    Longer,
+}
+
+/// A color ramp through two or more stops.
+///
+/// # Examples
+///
+/// ```
+/// use pigment::{Gradient, HueArc, Interpolation, Rgb};
+///
+/// let heat = Gradient::new(Rgb::new(0, 0, 255), Rgb::new(255, 0, 0))
+///     .stop(0.5, Rgb::new(255, 255, 255));
+/// assert_eq!(heat.sample(0.5).get_rgb(), (255, 255, 255));
+///
+/// // A ten-cell bar, ready for `fg()` and `bg()`
+/// let bar: String = heat.steps(10).iter().map(|c| c.bg() + " ").collect();
+/// # let _ = bar;
+///
+/// let rainbow = Gradient::new(Rgb::new(255, 0, 0), Rgb::new(0, 0, 255))
+///     .with_interpolation(Interpolation::Oklch(HueArc::Longer));
+/// let (r, g, b) = rainbow.sample(0.5).get_rgb();
+/// assert!(g > r && g > b);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    pub(crate) stops: Vec<(f32, Rgb)>,
+    pub(crate) interpolation: Interpolation,
+}
+
+impl Gradient {
+    /// A gradient from `start` at position 0 to `end` at position 1.
+    pub fn new(start: impl Into<Rgb>, end: impl Into<Rgb>) -> Self {
+        Gradient {
+            stops: vec![(0.0, start.into()), (1.0, end.into())],
+            interpolation: Interpolation::default(),
+        }
+    }
+
+    /// A gradient through `colors`, spaced evenly from 0 to 1, or `None`
+    /// if there are no colors. A single color makes a flat gradient.
+    pub fn even<C: Into<Rgb>>(colors: impl IntoIterator<Item = C>) -> Option<Self> {
+        let colors: Vec<Rgb> = colors.into_iter().map(Into::into).collect();
+        let last = colors.len().checked_sub(1)?;
+        let position = |i: usize| {
+            if last == 0 {
+                0.0
+            } else {
+                i as f32 / last as f32
+            }
+        };
+        let stops = colors
+            .into_iter()
+            .enumerate()
+            .map(|(i, c)| (position(i), c))
+            .collect();
+        Some(Gradient {
+            stops,
+            interpolation: Interpolation::default(),
+        })
+    }
+
+    /// This gradient with another stop at `position`, clamped to
+    /// `0.0..=1.0`.
+    ///
+    /// A stop at the same position as an existing one goes after it, so
+    /// two stops at one position make a hard edge.
+    pub fn stop(mut self, position: f32, color: impl Into<Rgb>) -> Self {
+        let position = if position.is_nan() {
+            0.0
+        } else {
+            position.clamp(0.0, 1.0)
+        };
+        let at = self.stops.partition_point(|&(p, _)| p <= position);
+        self.stops.insert(at, (position, color.into()));
+        self
+    }
+
+    /// This gradient blending in `interpolation` instead of OKLab.
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// The stops as positions and colors, in order.
+    #[inline]
+    pub fn stops(&self) -> &[(f32, Rgb)] {
+        &self.stops
+    }
+
+    /// The space the stops are blended in.
+    #[inline]
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// The color at position `t`, clamped to `0.0..=1.0`. Before the first
+    /// stop and after the last, the gradient holds their colors.
+    pub fn sample(&self, t: f32) -> Ansi {
+        let (r, g, b) = self.sample_rgb(t).tuple();
+        Ansi::rgb(r, g, b)
+    }
+
+    /// `n` colors evenly spaced from position 0 to 1, both included.
+    pub fn steps(&self, n: usize) -> Vec<Ansi> {
+        match n {
+            0 => Vec::new(),
+            1 => vec![self.sample(0.0)],
+            _ => (0..n)
+                .map(|i| self.sample(i as f32 / (n - 1) as f32))
+                .collect(),
+        }
+    }
+
+    fn sample_rgb(&self, t: f32) -> Rgb {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        // The first stop past `t`; the one before it starts the segment
+        let next = self.stops.partition_point(|&(p, _)| p <= t);
+        if next == 0 {
+            return self.stops[0].1;
+        }
+        if next == self.stops.len() {
+            return self.stops[next - 1].1;
+        }
+        let (p0, from) = self.stops[next - 1];
+        let (p1, to) = self.stops[next];
+        blend(self.interpolation, from, to, (t - p0) / (p1 - p0))
+    }
+}
+
+/// The color `t` of the way from `from` to `to` in `space`.
+fn blend(space: Interpolation, from: Rgb, to: Rgb, t: f32) -> Rgb {
+    let t = t as f64;
+    let lerp = |a: [f64; 3], b: [f64; 3]| [0, 1, 2].map(|i| a[i] + (b[i] - a[i]) * t);
+    match space {
+        Interpolation::Srgb => {
+            let channels = |c: Rgb| [c.r, c.g, c.b].map(f64::from);
+            let [r, g, b] = lerp(channels(from), channels(to)).map(|c| c.round() as u8);
+            Rgb::new(r, g, b)
+        }
+        Interpolation::LinearRgb => encode(lerp(linearize(from.tuple()), linearize(to.tuple()))),
+        Interpolation::Oklab => {
+            let oklab = |c: Rgb| oklab_from_linear(linearize(c.tuple()));
+            encode(linear_from_oklab(lerp(oklab(from), oklab(to))))
+        }
+        Interpolation::Oklch(arc) => blend_oklch(from.oklch(), to.oklch(), t as f32, arc),
+    }
+}
+
+/// The OKLCH color `t` of the way from `from` to `to`, going `arc` round
+/// the hue circle, mapped into sRGB.
+pub(crate) fn blend_oklch(from: Oklch, to: Oklch, t: f32, arc: HueArc) -> Rgb {
+    let hue = match (from.chroma < ACHROMATIC, to.chroma < ACHROMATIC) {
+        (true, false) => to.hue,
+        (false, true) | (true, true) => from.hue,
+        (false, false) => {
+            let shorter = (to.hue - from.hue + 180.0).rem_euclid(360.0) - 180.0;
+            let delta = match arc {
+                HueArc::Shorter => shorter,
+                HueArc::Longer if shorter > 0.0 => shorter - 360.0,
+                HueArc::Longer => shorter + 360.0,
+            };
+            from.hue + delta * t
+        }
+    };
+    let lerp = |a: f32, b: f32| a + (b - a) * t;
+    Oklch::new(lerp(from.l, to.l), lerp(from.chroma, to.chroma), hue).to_rgb_in_gamut()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgb = Rgb::new(255, 0, 0);
+    const BLUE: Rgb = Rgb::new(0, 0, 255);
+    const SPACES: [Interpolation; 5] = [
+        Interpolation::Srgb,
+        Interpolation::LinearRgb,
+        Interpolation::Oklab,
+        Interpolation::Oklch(HueArc::Shorter),
+        Interpolation::Oklch(HueArc::Longer),
+    ];
+
+    mod stops {
+        use super::*;
+
+        #[test]
+        fn test_endpoints() {
+            for space in SPACES {
+                let g = Gradient::new(RED, BLUE).with_interpolation(space);
+                assert_eq!(g.interpolation(), space);
+                assert_eq!(g.sample(0.0).get_rgb(), RED.tuple(), "{space:?}");
+                assert_eq!(g.sample(1.0).get_rgb(), BLUE.tuple(), "{space:?}");
+                assert_eq!(g.sample(-1.0).get_rgb(), RED.tuple(), "{space:?}");
+                assert_eq!(g.sample(2.0).get_rgb(), BLUE.tuple(), "{space:?}");
+                assert_eq!(g.sample(f32::NAN).get_rgb(), RED.tuple(), "{space:?}");
+            }
+        }
+
+        #[test]
+        fn test_middle_stop() {
+            let white = Rgb::new(255, 255, 255);
+            let g = Gradient::new(RED, BLUE).stop(0.5, white);
+            assert_eq!(g.stops().len(), 3);
+            assert_eq!(g.stops()[1], (0.5, white));
+            assert_eq!(g.sample(0.5).get_rgb(), white.tuple());
+            let (r, g_, b) = g.sample(0.25).get_rgb();
+            assert!(r > g_ && r > b);
+        }
+
+        #[test]
+        fn test_hard_edge() {
+            let g = Gradient::new(RED, BLUE)
+                .stop(0.5, RED)
+                .stop(0.5, BLUE)
+                .with_interpolation(Interpolation::Srgb);
+            assert_eq!(g.sample(0.49).get_rgb(), RED.tuple());
+            assert_eq!(g.sample(0.5).get_rgb(), BLUE.tuple());
+        }
+
+        #[test]
+        fn test_stops_are_sorted_and_clamped() {
+            let g = Gradient::new(RED, BLUE).stop(2.0, RED).stop(0.3, BLUE);
+            let positions: Vec<f32> = g.stops().iter().map(|&(p, _)| p).collect();
+            assert_eq!(positions, [0.0, 0.3, 1.0, 1.0]);
+        }
+
+        #[test]
+        fn test_even() {
+            assert!(Gradient::even(Vec::<Rgb>::new()).is_none());
+            let flat = Gradient::even([RED]).unwrap();
+            assert_eq!(flat.sample(0.7).get_rgb(), RED.tuple());
+            let g = Gradient::even([RED, Rgb::new(0, 255, 0), BLUE]).unwrap();
+            let positions: Vec<f32> = g.stops().iter().map(|&(p, _)| p).collect();
+            assert_eq!(positions, [0.0, 0.5, 1.0]);
+        }
+    }
+
+    mod spaces {
+        use super::*;
+
+        #[test]
+        fn test_srgb_midpoint() {
+            let g = Gradient::new(RED, BLUE).with_interpolation(Interpolation::Srgb);
+            assert_eq!(g.sample(0.5).get_rgb(), (128, 0, 128));
+        }
+
+        #[test]
+        fn test_linear_rgb_midpoint_is_brighter() {
+            let black = Rgb::new(0, 0, 0);
+            let white = Rgb::new(255, 255, 255);
+            let g = Gradient::new(black, white).with_interpolation(Interpolation::LinearRgb);
+            assert_eq!(g.sample(0.5).get_rgb(), (188, 188, 188));
+        }
+
+        #[test]
+        fn test_oklab_gray_midpoint() {
+            let black = Rgb::new(0, 0, 0);
+            let white = Rgb::new(255, 255, 255);
+            let (r, g, b) = Gradient::new(black, white).sample(0.5).get_rgb();
+            assert_eq!(r, g);
+            assert_eq!(g, b);
+            let l = Rgb::new(r, g, b).oklch().lightness();
+            assert!((l - 0.5).abs() < 0.01, "{l}");
+        }
+
+        #[test]
+        fn test_hue_arcs() {
+            let shorter =
+                Gradient::new(RED, BLUE).with_interpolation(Interpolation::Oklch(HueArc::Shorter));
+            let (r, g, b) = shorter.sample(0.5).get_rgb();
+            assert!(g < r && g < b, "{:?}", (r, g, b));
+            let longer =
+                Gradient::new(RED, BLUE).with_interpolation(Interpolation::Oklch(HueArc::Longer));
+            let (r, g, b) = longer.sample(0.5).get_rgb();
+            assert!(g > r && g > b, "{:?}", (r, g, b));
+        }
+
+        #[test]
+        fn test_gray_stop_keeps_hue() {
+            let white = Rgb::new(255, 255, 255);
+            let g =
+                Gradient::new(RED, white).with_interpolation(Interpolation::Oklch(HueArc::Shorter));
+            let hue = Rgb::from(g.sample(0.5).get_rgb()).oklch().hue();
+            assert!((hue - RED.oklch().hue()).abs() < 2.0, "{hue}");
+        }
+    }
+
+    mod steps {
+        use super::*;
+
+        #[test]
+        fn test_steps() {
+            let g = Gradient::new(RED, BLUE);
+            assert!(g.steps(0).is_empty());
+            assert_eq!(g.steps(1)[0].get_rgb(), RED.tuple());
+            let steps = g.steps(5);
+            assert_eq!(steps.len(), 5);
+            assert_eq!(steps[0].get_rgb(), RED.tuple());
+            assert_eq!(steps[2].get_rgb(), g.sample(0.5).get_rgb());
+            assert_eq!(steps[4].get_rgb(), BLUE.tuple());
+        }
+
+        #[test]
+        #[cfg(feature = "wikipedia")]
+        fn test_named_colors() {
+            let azure = crate::color("Azure").unwrap();
+            let amber = crate::color("Amber").unwrap();
+            let g = Gradient::new(&azure, &amber);
+            assert_eq!(g.sample(0.0).get_rgb(), azure.rgb);
+            assert_eq!(g.sample(1.0).get_rgb(), amber.rgb);
+        }
+    }
+}
@@ -0,0 +1,272 @@
+//! Color schemes built around one color: complements, triads and the like.
+//!
+//! Each [`Harmony`] turns the hue of a base color by fixed angles, or for
+//! [`Monochromatic`](Harmony::Monochromatic) keeps the hue and steps the
+//! lightness. Like the [adjustments](Rgb::rotate_hue) it builds on, this
+//! happens in OKLCH, so the companions match the base color in lightness
+//! and chroma as the eye sees them rather than only on paper.
+
+use std::fmt;
+
+use crate::palette::Palette;
+use crate::{Color, Rgb};
+
+/// A classic color scheme, for [`Color::harmony`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Harmony {
+    /// The base color and the hue opposite it.
+    Complementary,
+    /// The base color and the two hues either side of its complement,
+    /// 150° and 210° round.
+    SplitComplementary,
+    /// The base color and its neighbors 30° either side.
+    Analogous,
+    /// Three hues evenly spaced, 120° apart.
+    Triadic,
+    /// Two complementary pairs 60° apart, forming a rectangle on the hue
+    /// circle.
+    Tetradic,
+    /// The base hue at five lightnesses: the base color, two darker and
+    /// two lighter. Near black or white, where there is no room on one
+    /// side, the steps go further to the other.
+    Monochromatic,
+}
+
+impl Harmony {
+    /// Every harmony, in declaration order.
+    pub const ALL: [Harmony; 6] = [
+        Harmony::Complementary,
+        Harmony::SplitComplementary,
+        Harmony::Analogous,
+        Harmony::Triadic,
+        Harmony::Tetradic,
+        Harmony::Monochromatic,
+    ];
+
+    /// A human-readable name, such as "Split-complementary".
+    pub fn name(self) -> &'static str {
+        match self {
+            Harmony::Complementary => "Complementary",
+            Harmony::SplitComplementary => "Split-complementary",
+            Harmony::Analogous => "Analogous",
+            Harmony::Triadic => "Triadic",
+            Harmony::Tetradic => "Tetradic",
+            Harmony::Monochromatic => "Monochromatic",
+        }
+    }
+
+    /// The colors of this scheme around `base`, starting with `base`.
+    pub fn apply(self, base: impl Into<Rgb>) -> Vec<Rgb> {
+        let base = base.into();
+        let turns: &[f32] = match self {
+            Harmony::Complementary => &[0.0, 180.0],
+            Harmony::SplitComplementary => &[0.0, 150.0, 210.0],
+            Harmony::Analogous => &[0.0, -30.0, 30.0],
+            Harmony::Triadic => &[0.0, 120.0, 240.0],
+            Harmony::Tetradic => &[0.0, 60.0, 180.0, 240.0],
+            Harmony::Monochromatic => return monochromatic(base),
+        };
+        turns.iter().map(|&turn| base.rotate_hue(turn)).collect()
+    }
+}
+
+// The base color, then four others in order of lightness: the nearest
+// steps of 0.1 either side that the gamut doesn't clip back onto the base
+// or onto each other
+fn monochromatic(base: Rgb) -> Vec<Rgb> {
+    let mut steps: Vec<Rgb> = Vec::with_capacity(4);
+    for step in (1..=10).flat_map(|i| [-0.1 * i as f32, 0.1 * i as f32]) {
+        let c = base.lighten(step);
+        if c != base && !steps.contains(&c) {
+            steps.push(c);
+        }
+        if steps.len() == 4 {
+            break;
+        }
+    }
+    steps.sort_by(|a, b| a.oklch().lightness().total_cmp(&b.oklch().lightness()));
+    std::iter::once(base).chain(steps).collect()
+}
+
+impl fmt::Display for Harmony {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl Color {
+    /// The colors of `harmony` around this one, starting with this color.
+    ///
+    /// The companions are computed, so they rarely have names; see
+    /// [`harmony_named`](Self::harmony_named) for the closest named ones.
+    /// Near black or white, monochromatic steps may coincide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "wikipedia")] {
+    /// use pigment::{Harmony, Rgb};
+    ///
+    /// let zero = pigment::color("Absolute Zero").unwrap();
+    /// let triad = zero.harmony(Harmony::Triadic);
+    /// assert_eq!(triad.len(), 3);
+    /// assert_eq!(triad[0], Rgb::from(zero));
+    /// # }
+    /// ```
+    pub fn harmony(&self, harmony: Harmony) -> Vec<Rgb> {
+        harmony.apply(self)
+    }
+
+    /// The colors of `harmony` around this one: this color itself, then
+    /// its companions, each snapped to the nearest named color in the
+    /// default [`Palette`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "wikipedia")] {
+    /// use pigment::Harmony;
+    ///
+    /// let zero = pigment::color("Absolute Zero").unwrap();
+    /// for c in zero.harmony_named(Harmony::SplitComplementary) {
+    ///     println!("{} {}", c.name(), c.hex());
+    /// }
+    /// # }
+    /// ```
+    pub fn harmony_named(&self, harmony: Harmony) -> Vec<Color> {
+        self.harmony_in(harmony, Palette::default())
+    }
+
+    /// Like [`harmony_named`](Self::harmony_named), snapping the
+    /// companions to the colors of `palette`. The first is still this
+    /// color, whichever palette it comes from.
+    pub fn harmony_in(&self, harmony: Harmony, palette: Palette) -> Vec<Color> {
+        let companions = harmony.apply(self).into_iter().skip(1);
+        std::iter::once(self.clone())
+            .chain(companions.map(|c| palette.nearest(c.tuple())))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hue(c: Rgb) -> f32 {
+        c.oklch().hue()
+    }
+
+    // Distance between two hues the short way round
+    fn apart(a: f32, b: f32) -> f32 {
+        let d = (a - b).rem_euclid(360.0);
+        d.min(360.0 - d)
+    }
+
+    mod schemes {
+        use super::*;
+
+        #[test]
+        fn test_sizes_and_base() {
+            let base = Rgb::new(0x66, 0x99, 0x88);
+            let sizes = [2, 3, 3, 3, 4, 5];
+            for (harmony, size) in Harmony::ALL.into_iter().zip(sizes) {
+                let colors = harmony.apply(base);
+                assert_eq!(colors.len(), size, "{harmony}");
+                assert_eq!(colors[0], base, "{harmony}");
+            }
+        }
+
+        #[test]
+        fn test_hue_angles() {
+            let base = Rgb::new(0x66, 0x99, 0x88);
+            let h = hue(base);
+            let triad = Harmony::Triadic.apply(base);
+            assert!((apart(hue(triad[1]), h) - 120.0).abs() < 2.0);
+            assert!((apart(hue(triad[2]), h) - 120.0).abs() < 2.0);
+            let complement = Harmony::Complementary.apply(base);
+            assert!((apart(hue(complement[1]), h) - 180.0).abs() < 2.0);
+            let analogous = Harmony::Analogous.apply(base);
+            assert!((apart(hue(analogous[1]), h) - 30.0).abs() < 2.0);
+            assert!((apart(hue(analogous[2]), h) - 30.0).abs() < 2.0);
+        }
+
+        #[test]
+        fn test_monochromatic() {
+            let base = Rgb::new(0x66, 0x99, 0x88);
+            let colors = Harmony::Monochromatic.apply(base);
+            let lightness: Vec<f32> = colors.iter().map(|c| c.oklch().lightness()).collect();
+            assert!(lightness[1] < lightness[2] && lightness[2] < lightness[0]);
+            assert!(lightness[0] < lightness[3] && lightness[3] < lightness[4]);
+            for c in &colors[1..] {
+                assert!(apart(hue(*c), hue(base)) < 2.0, "{c:?}");
+            }
+        }
+
+        #[test]
+        fn test_monochromatic_colors_are_distinct() {
+            for base in [
+                Rgb::new(255, 255, 255),
+                Rgb::new(0, 0, 0),
+                Rgb::new(255, 255, 0),
+                Rgb::new(0x66, 0x99, 0x88),
+            ] {
+                let colors = Harmony::Monochromatic.apply(base);
+                assert_eq!(colors.len(), 5, "{base:?}");
+                assert_eq!(colors[0], base);
+                for (i, c) in colors.iter().enumerate() {
+                    assert!(!colors[..i].contains(c), "{base:?}: {colors:?}");
+                }
+            }
+        }
+
+        #[test]
+        fn test_grays_stay_gray() {
+            let gray = Rgb::new(128, 128, 128);
+            for c in Harmony::Triadic.apply(gray) {
+                assert_eq!(c, gray);
+            }
+        }
+
+        #[test]
+        fn test_name() {
+            assert_eq!(
+                Harmony::SplitComplementary.to_string(),
+                "Split-complementary"
+            );
+        }
+    }
+
+    #[cfg(feature = "wikipedia")]
+    mod named {
+        use super::*;
+
+        #[test]
+        fn test_harmony_on_color() {
+            let zero = crate::color("Absolute Zero").unwrap();
+            assert_eq!(
+                zero.harmony(Harmony::Tetradic),
+                Harmony::Tetradic.apply(zero)
+            );
+        }
+
+        #[test]
+        fn test_snapped_to_names() {
+            let zero = crate::color("Absolute Zero").unwrap();
+            let named = zero.harmony_named(Harmony::Complementary);
+            assert_eq!(named.len(), 2);
+            assert_eq!(named[0], zero);
+            let computed = zero.harmony(Harmony::Complementary)[1];
+            assert_eq!(named[1], crate::nearest(computed.tuple()));
+        }
+
+        #[test]
+        #[cfg(feature = "css-names")]
+        fn test_first_is_self_in_any_palette() {
+            let zero = crate::color("Absolute Zero").unwrap();
+            let named = zero.harmony_in(Harmony::Monochromatic, Palette::Css);
+            assert_eq!(named.len(), 5);
+            assert_eq!(named[0], zero);
+            assert_eq!(named[1].source().list(), "CSS Color Module Level 4");
+        }
+    }
+}
@@ -0,0 +1,492 @@
+//! The cylindrical sRGB models designers use: HSL, HSV and HWB.
+//!
+//! All three describe an sRGB color by hue angle plus two amounts, so they
+//! convert to and from [`Rgb`] without leaving the sRGB gamut. Conversions
+//! follow CSS Color Module Level 4, and an 8-bit color survives the round
+//! trip through any of them unchanged.
+//!
+//! Hues are in degrees, normalized to `0.0..360.0`; every other component
+//! is a fraction in `0.0..=1.0` rather than a CSS percentage. Grays have no
+//! hue, and report a hue of zero.
+
+use crate::Color;
+use crate::rgba::{Rgb, rgb_conversions};
+
+/// Hue, saturation and lightness, as in CSS `hsl()`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "wikipedia")] {
+/// use pigment::{Hsl, Rgb};
+///
+/// // CSS `hsl(270 50% 40%)` is rebeccapurple
+/// assert_eq!(Rgb::from(Hsl::new(270.0, 0.5, 0.4)), Rgb::new(0x66, 0x33, 0x99));
+///
+/// // Rotating the hue of a named color
+/// let hsl = pigment::color("Absolute Zero").unwrap().hsl();
+/// let rotated = Hsl::new(hsl.hue() + 180.0, hsl.saturation(), hsl.lightness());
+/// assert_eq!(Rgb::from(rotated), Rgb::new(186, 114, 0));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsl {
+    pub(crate) hue: f32,
+    pub(crate) saturation: f32,
+    pub(crate) lightness: f32,
+}
+
+/// Hue, saturation and value (also known as HSB), as in most color pickers.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsv {
+    pub(crate) hue: f32,
+    pub(crate) saturation: f32,
+    pub(crate) value: f32,
+}
+
+/// Hue, whiteness and blackness, as in CSS `hwb()`.
+///
+/// Whiteness and blackness adding up to more than 1 describe a gray, the
+/// way CSS normalizes them.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hwb {
+    pub(crate) hue: f32,
+    pub(crate) whiteness: f32,
+    pub(crate) blackness: f32,
+}
+
+impl Hsl {
+    /// An HSL color; the hue wraps around and the rest is clamped to `0.0..=1.0`.
+    pub fn new(hue: f32, saturation: f32, lightness: f32) -> Self {
+        Self {
+            hue: normalize_hue(hue),
+            saturation: fraction(saturation),
+            lightness: fraction(lightness),
+        }
+    }
+
+    #[inline]
+    pub fn hue(&self) -> f32 {
+        self.hue
+    }
+    #[inline]
+    pub fn saturation(&self) -> f32 {
+        self.saturation
+    }
+    #[inline]
+    pub fn lightness(&self) -> f32 {
+        self.lightness
+    }
+}
+
+impl Hsv {
+    /// An HSV color; the hue wraps around and the rest is clamped to `0.0..=1.0`.
+    pub fn new(hue: f32, saturation: f32, value: f32) -> Self {
+        Self {
+            hue: normalize_hue(hue),
+            saturation: fraction(saturation),
+            value: fraction(value),
+        }
+    }
+
+    #[inline]
+    pub fn hue(&self) -> f32 {
+        self.hue
+    }
+    #[inline]
+    pub fn saturation(&self) -> f32 {
+        self.saturation
+    }
+    #[inline]
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+}
+
+impl Hwb {
+    /// An HWB color; the hue wraps around and the rest is clamped to `0.0..=1.0`.
+    pub fn new(hue: f32, whiteness: f32, blackness: f32) -> Self {
+        Self {
+            hue: normalize_hue(hue),
+            whiteness: fraction(whiteness),
+            blackness: fraction(blackness),
+        }
+    }
+
+    #[inline]
+    pub fn hue(&self) -> f32 {
+        self.hue
+    }
+    #[inline]
+    pub fn whiteness(&self) -> f32 {
+        self.whiteness
+    }
+    #[inline]
+    pub fn blackness(&self) -> f32 {
+        self.blackness
+    }
+}
+
+impl Color {
+    /// This color in HSL.
+    ///
+    /// ```
+    /// # #[cfg(feature = "wikipedia")] {
+    /// let hsl = pigment::color("Absolute Zero").unwrap().hsl();
+    /// assert_eq!(hsl.hue().round(), 217.0);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn hsl(&self) -> Hsl {
+        Hsl::from(self)
+    }
+
+    /// This color in HSV.
+    #[inline]
+    pub fn hsv(&self) -> Hsv {
+        Hsv::from(self)
+    }
+
+    /// This color in HWB.
+    #[inline]
+    pub fn hwb(&self) -> Hwb {
+        Hwb::from(self)
+    }
+}
+
+impl From<Rgb> for Hsl {
+    fn from(c: Rgb) -> Self {
+        let (hue, max, min) = hue_max_min(c);
+        let lightness = (max + min) / 2.0;
+        let saturation = if max == min || lightness == 0.0 || lightness == 1.0 {
+            0.0
+        } else {
+            (max - lightness) / lightness.min(1.0 - lightness)
+        };
+        Self::new(hue, saturation, lightness)
+    }
+}
+
+impl From<Hsl> for Rgb {
+    fn from(c: Hsl) -> Self {
+        let a = c.saturation * c.lightness.min(1.0 - c.lightness);
+        let f = |n: f32| {
+            let k = (n + c.hue / 30.0) % 12.0;
+            c.lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+        from_fractions(f(0.0), f(8.0), f(4.0))
+    }
+}
+
+impl From<Rgb> for Hsv {
+    fn from(c: Rgb) -> Self {
+        let (hue, max, min) = hue_max_min(c);
+        let saturation = if max == 0.0 { 0.0 } else { (max - min) / max };
+        Self::new(hue, saturation, max)
+    }
+}
+
+impl From<Hsv> for Rgb {
+    fn from(c: Hsv) -> Self {
+        let [r, g, b] = hsv_fractions(c.hue, c.saturation, c.value);
+        from_fractions(r, g, b)
+    }
+}
+
+impl From<Rgb> for Hwb {
+    fn from(c: Rgb) -> Self {
+        let (hue, max, min) = hue_max_min(c);
+        Self::new(hue, min, 1.0 - max)
+    }
+}
+
+impl From<Hwb> for Rgb {
+    fn from(c: Hwb) -> Self {
+        let (white, black) = (c.whiteness, c.blackness);
+        if white + black >= 1.0 {
+            let gray = white / (white + black);
+            return from_fractions(gray, gray, gray);
+        }
+        // The fully saturated hue, scaled down and lifted by the white
+        let [r, g, b] = hsv_fractions(c.hue, 1.0, 1.0).map(|x| x * (1.0 - white - black) + white);
+        from_fractions(r, g, b)
+    }
+}
+
+// Between the models directly, without rounding to 8 bits on the way
+
+impl From<Hsl> for Hsv {
+    fn from(c: Hsl) -> Self {
+        let value = c.lightness + c.saturation * c.lightness.min(1.0 - c.lightness);
+        let saturation = if value == 0.0 {
+            0.0
+        } else {
+            2.0 * (1.0 - c.lightness / value)
+        };
+        Self::new(c.hue, saturation, value)
+    }
+}
+
+impl From<Hsv> for Hsl {
+    fn from(c: Hsv) -> Self {
+        let lightness = c.value * (1.0 - c.saturation / 2.0);
+        let saturation = if lightness == 0.0 || lightness == 1.0 {
+            0.0
+        } else {
+            (c.value - lightness) / lightness.min(1.0 - lightness)
+        };
+        Self::new(c.hue, saturation, lightness)
+    }
+}
+
+impl From<Hsv> for Hwb {
+    fn from(c: Hsv) -> Self {
+        Self::new(c.hue, (1.0 - c.saturation) * c.value, 1.0 - c.value)
+    }
+}
+
+impl From<Hwb> for Hsv {
+    fn from(c: Hwb) -> Self {
+        let (white, black) = (c.whiteness, c.blackness);
+        if white + black >= 1.0 {
+            return Self::new(c.hue, 0.0, white / (white + black));
+        }
+        let value = 1.0 - black;
+        Self::new(c.hue, 1.0 - white / value, value)
+    }
+}
+
+impl From<Hsl> for Hwb {
+    fn from(c: Hsl) -> Self {
+        Hsv::from(c).into()
+    }
+}
+
+impl From<Hwb> for Hsl {
+    fn from(c: Hwb) -> Self {
+        Hsv::from(c).into()
+    }
+}
+
+rgb_conversions!(Hsl);
+rgb_conversions!(Hsv);
+rgb_conversions!(Hwb);
+
+// Hue in degrees (zero for grays) and the largest and smallest channel
+fn hue_max_min(c: Rgb) -> (f32, f32, f32) {
+    let [r, g, b] = [c.r, c.g, c.b].map(|channel| channel as f32 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let d = max - min;
+    let hue = if d == 0.0 {
+        0.0
+    } else if max == r {
+        (g - b) / d * 60.0
+    } else if max == g {
+        ((b - r) / d + 2.0) * 60.0
+    } else {
+        ((r - g) / d + 4.0) * 60.0
+    };
+    (hue, max, min)
+}
+
+fn hsv_fractions(hue: f32, saturation: f32, value: f32) -> [f32; 3] {
+    let f = |n: f32| {
+        let k = (n + hue / 60.0) % 6.0;
+        value - value * saturation * k.min(4.0 - k).clamp(0.0, 1.0)
+    };
+    [f(5.0), f(3.0), f(1.0)]
+}
+
+fn from_fractions(r: f32, g: f32, b: f32) -> Rgb {
+    let channel = |x: f32| (x * 255.0).round().clamp(0.0, 255.0) as u8;
+    Rgb::new(channel(r), channel(g), channel(b))
+}
+
+pub(crate) fn normalize_hue(hue: f32) -> f32 {
+    if hue.is_finite() {
+        // `rem_euclid` can round up to exactly 360 for tiny negative hues
+        let hue = hue.rem_euclid(360.0);
+        if hue < 360.0 { hue } else { 0.0 }
+    } else {
+        0.0
+    }
+}
+
+fn fraction(x: f32) -> f32 {
+    if x.is_nan() { 0.0 } else { x.clamp(0.0, 1.0) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rgb(hex: u32) -> Rgb {
+        Rgb::new((hex >> 16) as u8, (hex >> 8) as u8, hex as u8)
+    }
+
+    mod css_reference {
+        use super::*;
+
+        #[test]
+        fn test_hsl() {
+            // Examples from CSS Color 4 and the CSS named colors
+            let cases = [
+                ((0.0, 1.0, 0.5), 0xFF0000),
+                ((120.0, 1.0, 0.25), 0x008000),
+                ((240.0, 1.0, 0.5), 0x0000FF),
+                ((60.0, 1.0, 0.5), 0xFFFF00),
+                ((270.0, 0.5, 0.4), 0x663399),
+                ((0.0, 0.0, 0.5), 0x808080),
+                ((0.0, 0.0, 1.0), 0xFFFFFF),
+                ((120.0, 0.734, 0.749), 0x90EE90),
+            ];
+            for ((h, s, l), hex) in cases {
+                assert_eq!(Rgb::from(Hsl::new(h, s, l)), rgb(hex), "hsl({h} {s} {l})");
+            }
+        }
+
+        #[test]
+        fn test_hwb() {
+            let cases = [
+                ((0.0, 0.0, 0.0), 0xFF0000),
+                ((120.0, 0.0, 0.5), 0x008000),
+                ((0.0, 1.0, 0.0), 0xFFFFFF),
+                ((0.0, 0.0, 1.0), 0x000000),
+                ((0.0, 0.6, 0.6), 0x808080),
+                ((270.0, 0.2, 0.4), 0x663399),
+            ];
+            for ((h, w, b), hex) in cases {
+                assert_eq!(Rgb::from(Hwb::new(h, w, b)), rgb(hex), "hwb({h} {w} {b})");
+            }
+        }
+
+        #[test]
+        fn test_hsv() {
+            let cases = [
+                ((0.0, 1.0, 1.0), 0xFF0000),
+                ((120.0, 1.0, 0.5), 0x008000),
+                ((270.0, 2.0 / 3.0, 0.6), 0x663399),
+                ((0.0, 0.0, 0.0), 0x000000),
+            ];
+            for ((h, s, v), hex) in cases {
+                assert_eq!(Rgb::from(Hsv::new(h, s, v)), rgb(hex), "hsv({h} {s} {v})");
+            }
+        }
+
+        #[test]
+        fn test_from_rgb() {
+            let hsl = Hsl::from(rgb(0x663399));
+            assert!((hsl.hue() - 270.0).abs() < 1e-3);
+            assert!((hsl.saturation() - 0.5).abs() < 1e-3);
+            assert!((hsl.lightness() - 0.4).abs() < 1e-3);
+
+            let hwb = Hwb::from(rgb(0x663399));
+            assert!((hwb.whiteness() - 0.2).abs() < 1e-3);
+            assert!((hwb.blackness() - 0.4).abs() < 1e-3);
+
+            let hsv = Hsv::from(rgb(0xFF0000));
+            assert_eq!((hsv.hue(), hsv.saturation(), hsv.value()), (0.0, 1.0, 1.0));
+        }
+    }
+
+    mod round_trip {
+        use super::*;
+
+        #[test]
+        fn test_every_model_is_lossless() {
+            for r in (0..=255).step_by(5) {
+                for g in (0..=255).step_by(5) {
+                    for b in (0..=255).step_by(5) {
+                        let c = Rgb::new(r as u8, g as u8, b as u8);
+                        assert_eq!(Rgb::from(Hsl::from(c)), c, "hsl {c:?}");
+                        assert_eq!(Rgb::from(Hsv::from(c)), c, "hsv {c:?}");
+                        assert_eq!(Rgb::from(Hwb::from(c)), c, "hwb {c:?}");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_tuples_and_ansi() {
+            let hsl = Hsl::from((0x66, 0x33, 0x99));
+            assert_eq!(<(u8, u8, u8)>::from(hsl), (0x66, 0x33, 0x99));
+            let ansi = crate::ansi::Ansi::from(Hwb::from(hsl));
+            assert_eq!(ansi.get_rgb(), (0x66, 0x33, 0x99));
+            assert_eq!(Hsv::from(ansi), Hsv::from(rgb(0x663399)));
+        }
+
+        #[test]
+        #[cfg(feature = "wikipedia")]
+        fn test_named_colors() {
+            for c in crate::all() {
+                assert_eq!(Rgb::from(c.hsl()), Rgb::from(&c), "{}", c.name());
+                assert_eq!(Rgb::from(c.hsv()), Rgb::from(&c), "{}", c.name());
+                assert_eq!(Rgb::from(c.hwb()), Rgb::from(&c), "{}", c.name());
+            }
+        }
+    }
+
+    mod between_models {
+        use super::*;
+
+        fn close(a: [f32; 3], b: [f32; 3]) -> bool {
+            a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-4)
+        }
+
+        #[test]
+        fn test_agree_with_rgb_path() {
+            for hex in [
+                0x663399, 0x0048BA, 0xFF0000, 0x7FD87F, 0x808080, 0x000000, 0xFFFFFF,
+            ] {
+                let c = rgb(hex);
+                let hsl = Hsl::from(c);
+                assert_eq!(Rgb::from(Hsv::from(hsl)), c, "{hex:06X}");
+                assert_eq!(Rgb::from(Hwb::from(hsl)), c, "{hex:06X}");
+                assert_eq!(Rgb::from(Hsl::from(Hwb::from(c))), c, "{hex:06X}");
+                assert_eq!(Rgb::from(Hsv::from(Hwb::from(c))), c, "{hex:06X}");
+            }
+        }
+
+        #[test]
+        fn test_css_hwb_normalization() {
+            // hwb(0 60% 60%) is the gray 60 / (60 + 60)
+            let hsv = Hsv::from(Hwb::new(0.0, 0.6, 0.6));
+            assert!(close([hsv.hue, hsv.saturation, hsv.value], [0.0, 0.0, 0.5]));
+            let hsl = Hsl::from(Hsv::new(120.0, 1.0, 0.5));
+            assert!(close(
+                [hsl.hue, hsl.saturation, hsl.lightness],
+                [120.0, 1.0, 0.25]
+            ));
+        }
+    }
+
+    mod normalization {
+        use super::*;
+
+        #[test]
+        fn test_hue_wraps() {
+            assert_eq!(Hsl::new(360.0, 1.0, 0.5).hue(), 0.0);
+            assert_eq!(Hsl::new(-90.0, 1.0, 0.5).hue(), 270.0);
+            assert_eq!(Hsv::new(725.0, 1.0, 1.0).hue(), 5.0);
+            assert_eq!(Hwb::new(f32::NAN, 0.0, 0.0).hue(), 0.0);
+            assert!(Hsl::new(-1e-9, 1.0, 0.5).hue() < 360.0);
+        }
+
+        #[test]
+        fn test_fractions_clamp() {
+            let hsl = Hsl::new(0.0, 1.5, -0.5);
+            assert_eq!((hsl.saturation(), hsl.lightness()), (1.0, 0.0));
+            assert_eq!(Hwb::new(0.0, f32::NAN, 2.0).whiteness(), 0.0);
+        }
+
+        #[test]
+        fn test_grays_have_no_hue() {
+            for c in [rgb(0x000000), rgb(0x777777), rgb(0xFFFFFF)] {
+                assert_eq!(Hsl::from(c).hue(), 0.0);
+                assert_eq!(Hsl::from(c).saturation(), 0.0);
+                assert_eq!(Hsv::from(c).saturation(), 0.0);
+            }
+        }
+    }
+}
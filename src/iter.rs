@@ -0,0 +1,252 @@
+//! Enumerating the color table.
+//!
+//! Everything here walks the table in one stable order: alphabetically by
+//! display name, ignoring case.
+
+use std::iter::FusedIterator;
+
+use crate::Color;
+use crate::palette::{Palette, Table};
+
+/// Iterator over named colors, in display-name order.
+///
+/// Returned by [`all`], [`section`] and [`starting_with`], and by the
+/// [`Palette`] methods of the same names.
+#[derive(Debug, Clone)]
+pub struct Colors {
+    colors: &'static phf::Map<&'static str, Color>,
+    keys: std::slice::Iter<'static, &'static str>,
+}
+
+impl Colors {
+    pub(crate) fn new(table: &'static Table, keys: &'static [&'static str]) -> Self {
+        Self {
+            colors: table.colors,
+            keys: keys.iter(),
+        }
+    }
+}
+
+impl Iterator for Colors {
+    type Item = Color;
+
+    #[inline]
+    fn next(&mut self) -> Option<Color> {
+        self.keys.next().map(|key| self.colors[key].clone())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Colors {
+    #[inline]
+    fn next_back(&mut self) -> Option<Color> {
+        self.keys.next_back().map(|key| self.colors[key].clone())
+    }
+}
+
+impl ExactSizeIterator for Colors {}
+
+impl FusedIterator for Colors {}
+
+/// The three alphabetical ranges the source data is split into.
+///
+/// These match Wikipedia's "List of colors: A–F", "G–M" and "N–Z" pages
+/// that `tools/scrape.py` reads the table from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Section {
+    AToF,
+    GToM,
+    NToZ,
+}
+
+impl Section {
+    /// Every section, in alphabetical order.
+    pub const ALL: [Section; 3] = [Section::AToF, Section::GToM, Section::NToZ];
+
+    /// The section a name starting with `letter` belongs to, if any.
+    ///
+    /// ```
+    /// use pigment::Section;
+    ///
+    /// assert_eq!(Section::of('b'), Some(Section::AToF));
+    /// assert_eq!(Section::of('M'), Some(Section::GToM));
+    /// assert_eq!(Section::of('7'), None);
+    /// ```
+    pub fn of(letter: char) -> Option<Section> {
+        match letter.to_ascii_lowercase() {
+            'a'..='f' => Some(Section::AToF),
+            'g'..='m' => Some(Section::GToM),
+            'n'..='z' => Some(Section::NToZ),
+            _ => None,
+        }
+    }
+
+    /// First and last letter covered by this section, in lowercase.
+    pub fn letters(self) -> (char, char) {
+        match self {
+            Section::AToF => ('a', 'f'),
+            Section::GToM => ('g', 'm'),
+            Section::NToZ => ('n', 'z'),
+        }
+    }
+}
+
+/// Every named color, alphabetically by display name (ignoring case).
+///
+/// Like the other crate-level functions this walks the default palette,
+/// which is Wikipedia's unless that feature is disabled; see
+/// [`Palette::default`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "wikipedia")] {
+/// let mut colors = pigment::all();
+/// assert_eq!(colors.len(), pigment::len());
+/// assert_eq!(colors.next().unwrap().name(), "Absolute Zero");
+/// # }
+/// ```
+pub fn all() -> Colors {
+    Palette::default().all()
+}
+
+/// Number of named colors in the table.
+#[inline]
+pub fn len() -> usize {
+    Palette::default().len()
+}
+
+/// The colors whose display name falls in `section`, in [`all`] order.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "wikipedia")] {
+/// use pigment::{Section, section};
+///
+/// assert!(section(Section::GToM).all(|c| ('G'..='M').contains(&c.name().chars().next().unwrap())));
+/// # }
+/// ```
+pub fn section(section: Section) -> Colors {
+    Palette::default().section(section)
+}
+
+/// The colors whose display name starts with `letter`, in [`all`] order.
+///
+/// Matching ignores case; letters outside `a`–`z` match nothing.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "wikipedia")] {
+/// let z: Vec<_> = pigment::starting_with('z').map(|c| c.name().to_owned()).collect();
+/// assert_eq!(z, ["Zaffre", "Zinnwaldite brown", "Zomp"]);
+/// # }
+/// ```
+pub fn starting_with(letter: char) -> Colors {
+    Palette::default().starting_with(letter)
+}
+
+pub(crate) fn section_in(table: &'static Table, section: Section) -> Colors {
+    let (first, last) = section.letters();
+    letters(table, first, last)
+}
+
+pub(crate) fn starting_with_in(table: &'static Table, letter: char) -> Colors {
+    if !letter.is_ascii_alphabetic() {
+        return Colors::new(table, &[]);
+    }
+    let letter = letter.to_ascii_lowercase();
+    letters(table, letter, letter)
+}
+
+// All colors of `table` whose lowercase initial lies in `first..=last`
+fn letters(table: &'static Table, first: char, last: char) -> Colors {
+    let initial = |key: &&str| {
+        let name = &table.colors[*key].name;
+        name.chars().next().map_or('\0', |c| c.to_ascii_lowercase())
+    };
+    let start = table.by_name.partition_point(|key| initial(key) < first);
+    let end = table.by_name.partition_point(|key| initial(key) <= last);
+    Colors::new(table, &table.by_name[start..end])
+}
+
+#[cfg(all(test, feature = "wikipedia"))]
+mod tests {
+    use super::*;
+    use crate::COLORS;
+
+    mod all_function {
+        use super::*;
+
+        #[test]
+        fn test_visits_every_color_once() {
+            let mut names: Vec<_> = all().map(|c| c.hex().to_owned() + c.name()).collect();
+            assert_eq!(names.len(), COLORS.len());
+            names.sort();
+            names.dedup();
+            assert_eq!(names.len(), COLORS.len());
+        }
+
+        #[test]
+        fn test_ordered_by_display_name() {
+            let names: Vec<_> = all().map(|c| c.name().to_lowercase()).collect();
+            assert!(names.windows(2).all(|w| w[0] <= w[1]));
+            assert_eq!(all().next_back().unwrap().name(), "Zomp");
+        }
+
+        #[test]
+        fn test_len_and_exact_size() {
+            assert_eq!(len(), COLORS.len());
+            let mut colors = all();
+            assert_eq!(colors.len(), len());
+            colors.next();
+            assert_eq!(colors.len(), len() - 1);
+        }
+    }
+
+    mod sections {
+        use super::*;
+
+        #[test]
+        fn test_section_of() {
+            assert_eq!(Section::of('a'), Some(Section::AToF));
+            assert_eq!(Section::of('F'), Some(Section::AToF));
+            assert_eq!(Section::of('g'), Some(Section::GToM));
+            assert_eq!(Section::of('m'), Some(Section::GToM));
+            assert_eq!(Section::of('N'), Some(Section::NToZ));
+            assert_eq!(Section::of('z'), Some(Section::NToZ));
+            assert_eq!(Section::of('é'), None);
+            assert_eq!(Section::of(' '), None);
+        }
+
+        #[test]
+        fn test_sections_partition_the_table() {
+            let total: usize = Section::ALL.iter().map(|&s| section(s).len()).sum();
+            assert_eq!(total, len());
+
+            for s in Section::ALL {
+                for c in section(s) {
+                    let initial = c.name().chars().next().unwrap();
+                    assert_eq!(Section::of(initial), Some(s), "{}", c.name());
+                }
+            }
+        }
+
+        #[test]
+        fn test_starting_with() {
+            assert!(starting_with('q').all(|c| c.name().starts_with('Q')));
+            assert_eq!(starting_with('a').len(), starting_with('A').len());
+            assert!(starting_with('a').len() > 0);
+            assert_eq!(starting_with('1').len(), 0);
+            assert_eq!(starting_with('ß').len(), 0);
+
+            let by_letter: usize = ('a'..='z').map(|l| starting_with(l).len()).sum();
+            assert_eq!(by_letter, len());
+        }
+    }
+}
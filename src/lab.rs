@@ -0,0 +1,811 @@
+//! Device-independent color spaces: CIE XYZ, CIE Lab and LCh, and OKLab
+//! and OKLCH.
+//!
+//! Unlike the cylindrical sRGB models in [`Hsl`](crate::Hsl), these
+//! describe colors as the eye sees them, so distances and gradients in Lab
+//! and OKLab look even. Conversions decode the sRGB gamma curve first and
+//! use the D65 white point and matrices of CSS Color Module Level 4. Colors
+//! outside the sRGB gamut are clipped when converted back to [`Rgb`].
+//!
+//! CSS `lab()` and `lch()` are relative to the D50 white point instead;
+//! the Lab and LCh here are D65, like OKLab. Lab lightness runs from 0 to
+//! 100 and OKLab lightness from 0 to 1. Hues are in degrees, normalized to
+//! `0.0..360.0`, and are zero for colors without chroma.
+//!
+//! With the `precomputed` feature, the Lab and OKLab coordinates of every
+//! built-in color are generated ahead of time, and [`Color::lab`] and
+//! [`Color::oklab`] look them up instead of computing them.
+
+use crate::Color;
+use crate::hsl::normalize_hue;
+use crate::rgba::{Rgb, rgb_conversions};
+
+/// CIE XYZ relative to the D65 white point, with white at `y` = 1.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Xyz {
+    pub(crate) x: f32,
+    pub(crate) y: f32,
+    pub(crate) z: f32,
+}
+
+/// CIE Lab (L\*a\*b\*) relative to the D65 white point.
+///
+/// # Examples
+///
+/// ```
+/// use pigment::{Lab, Rgb};
+///
+/// let lab = Lab::from(Rgb::new(255, 255, 255));
+/// assert_eq!(lab.lightness().round(), 100.0);
+/// assert_eq!(Rgb::from(Lab::new(100.0, 0.0, 0.0)), Rgb::new(255, 255, 255));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Lab {
+    pub(crate) l: f32,
+    pub(crate) a: f32,
+    pub(crate) b: f32,
+}
+
+/// CIE LCh, the polar form of [`Lab`]: lightness, chroma and hue.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Lch {
+    pub(crate) l: f32,
+    pub(crate) chroma: f32,
+    pub(crate) hue: f32,
+}
+
+/// OKLab, a perceptual space designed for even lightness and hue.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Oklab {
+    pub(crate) l: f32,
+    pub(crate) a: f32,
+    pub(crate) b: f32,
+}
+
+/// OKLCH, the polar form of [`Oklab`]: lightness, chroma and hue.
+///
+/// # Examples
+///
+/// ```
+/// use pigment::{Oklch, Rgb};
+///
+/// let red = Oklch::from(Rgb::new(255, 0, 0));
+/// assert_eq!(red.hue().round(), 29.0);
+///
+/// // The same lightness and chroma, a third of the way around
+/// let turned = Oklch::new(red.lightness(), 0.1, red.hue() + 120.0);
+/// assert_eq!(Rgb::from(turned), Rgb::new(90, 153, 103));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Oklch {
+    pub(crate) l: f32,
+    pub(crate) chroma: f32,
+    pub(crate) hue: f32,
+}
+
+impl Xyz {
+    /// An XYZ color; not clamped, since XYZ has no fixed range.
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Self {
+            x: finite(x),
+            y: finite(y),
+            z: finite(z),
+        }
+    }
+
+    #[inline]
+    pub fn x(&self) -> f32 {
+        self.x
+    }
+    #[inline]
+    pub fn y(&self) -> f32 {
+        self.y
+    }
+    #[inline]
+    pub fn z(&self) -> f32 {
+        self.z
+    }
+}
+
+impl Lab {
+    /// A Lab color; the lightness is clamped to `0.0..=100.0`.
+    pub fn new(lightness: f32, a: f32, b: f32) -> Self {
+        Self {
+            l: finite(lightness).clamp(0.0, 100.0),
+            a: finite(a),
+            b: finite(b),
+        }
+    }
+
+    #[inline]
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+    #[inline]
+    pub fn a(&self) -> f32 {
+        self.a
+    }
+    #[inline]
+    pub fn b(&self) -> f32 {
+        self.b
+    }
+}
+
+impl Lch {
+    /// An LCh color; the lightness is clamped to `0.0..=100.0`, the chroma
+    /// to zero or more, and the hue wraps around.
+    pub fn new(lightness: f32, chroma: f32, hue: f32) -> Self {
+        Self {
+            l: finite(lightness).clamp(0.0, 100.0),
+            chroma: finite(chroma).max(0.0),
+            hue: normalize_hue(hue),
+        }
+    }
+
+    #[inline]
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+    #[inline]
+    pub fn chroma(&self) -> f32 {
+        self.chroma
+    }
+    #[inline]
+    pub fn hue(&self) -> f32 {
+        self.hue
+    }
+}
+
+impl Oklab {
+    /// An OKLab color; the lightness is clamped to `0.0..=1.0`.
+    pub fn new(lightness: f32, a: f32, b: f32) -> Self {
+        Self {
+            l: finite(lightness).clamp(0.0, 1.0),
+            a: finite(a),
+            b: finite(b),
+        }
+    }
+
+    #[inline]
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+    #[inline]
+    pub fn a(&self) -> f32 {
+        self.a
+    }
+    #[inline]
+    pub fn b(&self) -> f32 {
+        self.b
+    }
+}
+
+impl Oklch {
+    /// An OKLCH color; the lightness is clamped to `0.0..=1.0`, the chroma
+    /// to zero or more, and the hue wraps around.
+    pub fn new(lightness: f32, chroma: f32, hue: f32) -> Self {
+        Self {
+            l: finite(lightness).clamp(0.0, 1.0),
+            chroma: finite(chroma).max(0.0),
+            hue: normalize_hue(hue),
+        }
+    }
+
+    #[inline]
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+    #[inline]
+    pub fn chroma(&self) -> f32 {
+        self.chroma
+    }
+    #[inline]
+    pub fn hue(&self) -> f32 {
+        self.hue
+    }
+}
+
+impl Color {
+    /// This color in CIE XYZ.
+    #[inline]
+    pub fn xyz(&self) -> Xyz {
+        Xyz::from(self)
+    }
+
+    /// This color in CIE Lab.
+    ///
+    /// ```
+    /// # #[cfg(feature = "wikipedia")] {
+    /// let lab = pigment::color("Absolute Zero").unwrap().lab();
+    /// assert_eq!(lab.lightness().round(), 34.0);
+    /// # }
+    /// ```
+    pub fn lab(&self) -> Lab {
+        #[cfg(feature = "precomputed")]
+        if let Some(&([l, a, b], _)) = precomputed(self.rgb) {
+            return Lab { l, a, b };
+        }
+        Lab::from(self)
+    }
+
+    /// This color in CIE LCh.
+    #[inline]
+    pub fn lch(&self) -> Lch {
+        self.lab().into()
+    }
+
+    /// This color in OKLab.
+    pub fn oklab(&self) -> Oklab {
+        #[cfg(feature = "precomputed")]
+        if let Some(&(_, [l, a, b])) = precomputed(self.rgb) {
+            return Oklab { l, a, b };
+        }
+        Oklab::from(self)
+    }
+
+    /// This color in OKLCH.
+    #[inline]
+    pub fn oklch(&self) -> Oklch {
+        self.oklab().into()
+    }
+}
+
+// The generated coordinates of a built-in color; registered colors and
+// anything else not in a built-in palette are missing
+#[cfg(feature = "precomputed")]
+fn precomputed((r, g, b): (u8, u8, u8)) -> Option<&'static ([f32; 3], [f32; 3])> {
+    crate::LAB.get(&(u32::from(r) << 16 | u32::from(g) << 8 | u32::from(b)))
+}
+
+impl From<Rgb> for Xyz {
+    fn from(c: Rgb) -> Self {
+        let [x, y, z] = xyz_from_linear(linearize(c.tuple()));
+        Self::new(x as f32, y as f32, z as f32)
+    }
+}
+
+impl From<Xyz> for Rgb {
+    fn from(c: Xyz) -> Self {
+        encode(linear_from_xyz(c.array()))
+    }
+}
+
+impl From<Rgb> for Lab {
+    fn from(c: Rgb) -> Self {
+        let [l, a, b] = lab_from_xyz(xyz_from_linear(linearize(c.tuple())), D65);
+        Self::new(l as f32, a as f32, b as f32)
+    }
+}
+
+impl From<Lab> for Rgb {
+    fn from(c: Lab) -> Self {
+        encode(linear_from_xyz(xyz_from_lab(c.array(), D65)))
+    }
+}
+
+impl From<Rgb> for Lch {
+    fn from(c: Rgb) -> Self {
+        Lab::from(c).into()
+    }
+}
+
+impl From<Lch> for Rgb {
+    fn from(c: Lch) -> Self {
+        Lab::from(c).into()
+    }
+}
+
+impl From<Rgb> for Oklab {
+    fn from(c: Rgb) -> Self {
+        let [l, a, b] = oklab_from_linear(linearize(c.tuple()));
+        Self::new(l as f32, a as f32, b as f32)
+    }
+}
+
+impl From<Oklab> for Rgb {
+    fn from(c: Oklab) -> Self {
+        encode(linear_from_oklab(c.array()))
+    }
+}
+
+impl From<Rgb> for Oklch {
+    fn from(c: Rgb) -> Self {
+        Oklab::from(c).into()
+    }
+}
+
+impl From<Oklch> for Rgb {
+    fn from(c: Oklch) -> Self {
+        Oklab::from(c).into()
+    }
+}
+
+// Between the spaces directly, without rounding to 8 bits on the way
+
+impl From<Xyz> for Lab {
+    fn from(c: Xyz) -> Self {
+        let [l, a, b] = lab_from_xyz(c.array(), D65);
+        Self::new(l as f32, a as f32, b as f32)
+    }
+}
+
+impl From<Lab> for Xyz {
+    fn from(c: Lab) -> Self {
+        let [x, y, z] = xyz_from_lab(c.array(), D65);
+        Self::new(x as f32, y as f32, z as f32)
+    }
+}
+
+impl From<Lab> for Lch {
+    fn from(c: Lab) -> Self {
+        let [l, chroma, hue] = polar(c.array(), LCH_ACHROMATIC);
+        Self::new(l as f32, chroma as f32, hue as f32)
+    }
+}
+
+impl From<Lch> for Lab {
+    fn from(c: Lch) -> Self {
+        let [l, a, b] = rectangular(c.array());
+        Self::new(l as f32, a as f32, b as f32)
+    }
+}
+
+impl From<Xyz> for Oklab {
+    fn from(c: Xyz) -> Self {
+        let [l, a, b] = oklab_from_linear(linear_from_xyz(c.array()));
+        Self::new(l as f32, a as f32, b as f32)
+    }
+}
+
+impl From<Oklab> for Xyz {
+    fn from(c: Oklab) -> Self {
+        let [x, y, z] = xyz_from_linear(linear_from_oklab(c.array()));
+        Self::new(x as f32, y as f32, z as f32)
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(c: Oklab) -> Self {
+        let [l, chroma, hue] = polar(c.array(), OKLCH_ACHROMATIC);
+        Self::new(l as f32, chroma as f32, hue as f32)
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(c: Oklch) -> Self {
+        let [l, a, b] = rectangular(c.array());
+        Self::new(l as f32, a as f32, b as f32)
+    }
+}
+
+impl Lab {
+    // A CSS `lab()` color, which is relative to D50, adapted to D65
+    pub(crate) fn from_d50(lab: [f64; 3]) -> Self {
+        let [x, y, z] = xyz_from_lab(lab, D50);
+        let xyz = D50_TO_D65.map(|[mx, my, mz]| mx * x + my * y + mz * z);
+        let [l, a, b] = lab_from_xyz(xyz, D65);
+        Self::new(l as f32, a as f32, b as f32)
+    }
+
+    // This color adapted to D50, as CSS `lab()` writes it
+    pub(crate) fn to_d50(self) -> [f64; 3] {
+        let [x, y, z] = xyz_from_lab(self.array(), D65);
+        let xyz = D65_TO_D50.map(|[mx, my, mz]| mx * x + my * y + mz * z);
+        lab_from_xyz(xyz, D50)
+    }
+}
+
+impl Lch {
+    // A CSS `lch()` color, which is relative to D50, adapted to D65
+    pub(crate) fn from_d50(lch: [f64; 3]) -> Self {
+        Lab::from_d50(rectangular(lch)).into()
+    }
+
+    // This color adapted to D50, as CSS `lch()` writes it
+    pub(crate) fn to_d50(self) -> [f64; 3] {
+        polar(Lab::from(self).to_d50(), LCH_ACHROMATIC)
+    }
+}
+
+impl Oklch {
+    // The closest sRGB color with this lightness and hue: the chroma is
+    // reduced until the color fits, like CSS Color 4 gamut mapping, instead
+    // of clipping each channel and shifting the hue
+    pub(crate) fn to_rgb_in_gamut(self) -> Rgb {
+        let [l, chroma, hue] = self.array();
+        let linear = |chroma: f64| linear_from_oklab(rectangular([l, chroma, hue]));
+        let fits = |rgb: [f64; 3]| {
+            rgb.iter()
+                .all(|c| (-GAMUT_TOLERANCE..=1.0 + GAMUT_TOLERANCE).contains(c))
+        };
+        if fits(linear(chroma)) {
+            return encode(linear(chroma));
+        }
+        let (mut low, mut high) = (0.0, chroma);
+        for _ in 0..24 {
+            let mid = (low + high) / 2.0;
+            if fits(linear(mid)) {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        encode(linear(low))
+    }
+}
+
+rgb_conversions!(Xyz);
+rgb_conversions!(Lab);
+rgb_conversions!(Lch);
+rgb_conversions!(Oklab);
+rgb_conversions!(Oklch);
+
+// The components in f64, the precision conversions are computed in
+macro_rules! array {
+    ($($model:ty => $($field:ident),+;)+) => {
+        $(impl $model {
+            fn array(&self) -> [f64; 3] {
+                [$(self.$field as f64),+]
+            }
+        })+
+    };
+}
+
+array! {
+    Xyz => x, y, z;
+    Lab => l, a, b;
+    Lch => l, chroma, hue;
+    Oklab => l, a, b;
+    Oklch => l, chroma, hue;
+}
+
+// How far outside 0–1 a linear channel may stray and still count as in
+// gamut, for the rounding error of colors on the gamut's edge
+const GAMUT_TOLERANCE: f64 = 1e-6;
+
+// Chroma below which a hue is meaningless and reported as zero
+const LCH_ACHROMATIC: f64 = 1e-3;
+const OKLCH_ACHROMATIC: f64 = 1e-5;
+
+// White points, from their chromaticity coordinates
+const D65: [f64; 3] = [0.3127 / 0.3290, 1.0, (1.0 - 0.3127 - 0.3290) / 0.3290];
+const D50: [f64; 3] = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+
+// Bradford chromatic adaptation between D50 and D65, as in CSS Color 4
+const D50_TO_D65: [[f64; 3]; 3] = [
+    [0.955473421488075, -0.02309845494876471, 0.06325924320057072],
+    [
+        -0.0283697093338637,
+        1.0099953980813041,
+        0.021041441191917323,
+    ],
+    [
+        0.012314014864481998,
+        -0.020507649298898964,
+        1.330365926242124,
+    ],
+];
+const D65_TO_D50: [[f64; 3]; 3] = [
+    [
+        1.0479297925449969,
+        0.022946870601609652,
+        -0.05019226628920524,
+    ],
+    [
+        0.02962780877005599,
+        0.9904344267538799,
+        -0.017073799063418826,
+    ],
+    [
+        -0.009243040646204504,
+        0.015055191490298152,
+        0.7518742814281371,
+    ],
+];
+
+// CIE constants for the linear segment of Lab lightness near black
+const EPSILON: f64 = 216.0 / 24389.0;
+const KAPPA: f64 = 24389.0 / 27.0;
+
+// sRGB → linear light; must stay in sync with `linear` in tools/scrape.py
+pub(crate) fn linearize((r, g, b): (u8, u8, u8)) -> [f64; 3] {
+    [r, g, b].map(|c| {
+        let c = c as f64 / 255.0;
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    })
+}
+
+// Linear light → sRGB, clipped to the gamut
+pub(crate) fn encode(linear: [f64; 3]) -> Rgb {
+    let [r, g, b] = linear.map(|c| {
+        let c = if c <= 0.0031308 {
+            12.92 * c
+        } else {
+            1.055 * c.powf(1.0 / 2.4) - 0.055
+        };
+        (c * 255.0).round().clamp(0.0, 255.0) as u8
+    });
+    Rgb::new(r, g, b)
+}
+
+fn xyz_from_linear([r, g, b]: [f64; 3]) -> [f64; 3] {
+    [
+        506752.0 / 1228815.0 * r + 87881.0 / 245763.0 * g + 12673.0 / 70218.0 * b,
+        87098.0 / 409605.0 * r + 175762.0 / 245763.0 * g + 12673.0 / 175545.0 * b,
+        7918.0 / 409605.0 * r + 87881.0 / 737289.0 * g + 1001167.0 / 1053270.0 * b,
+    ]
+}
+
+fn linear_from_xyz([x, y, z]: [f64; 3]) -> [f64; 3] {
+    [
+        12831.0 / 3959.0 * x - 329.0 / 214.0 * y - 1974.0 / 3959.0 * z,
+        -851781.0 / 878810.0 * x + 1648619.0 / 878810.0 * y + 36519.0 / 878810.0 * z,
+        705.0 / 12673.0 * x - 2585.0 / 12673.0 * y + 705.0 / 667.0 * z,
+    ]
+}
+
+// Must stay in sync with `lab` in tools/scrape.py
+fn lab_from_xyz(xyz: [f64; 3], white: [f64; 3]) -> [f64; 3] {
+    let f = |t: f64| {
+        if t > EPSILON {
+            t.cbrt()
+        } else {
+            (KAPPA * t + 16.0) / 116.0
+        }
+    };
+    let [fx, fy, fz] = [0, 1, 2].map(|i| f(xyz[i] / white[i]));
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+fn xyz_from_lab([l, a, b]: [f64; 3], white: [f64; 3]) -> [f64; 3] {
+    let fy = (l + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b / 200.0;
+    let inverse = |f: f64| {
+        if f.powi(3) > EPSILON {
+            f.powi(3)
+        } else {
+            (116.0 * f - 16.0) / KAPPA
+        }
+    };
+    let y = if l > KAPPA * EPSILON {
+        fy.powi(3)
+    } else {
+        l / KAPPA
+    };
+    [inverse(fx) * white[0], y * white[1], inverse(fz) * white[2]]
+}
+
+// Must stay in sync with `oklab` in tools/scrape.py
+pub(crate) fn oklab_from_linear([r, g, b]: [f64; 3]) -> [f64; 3] {
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+    [
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    ]
+}
+
+pub(crate) fn linear_from_oklab([l, a, b]: [f64; 3]) -> [f64; 3] {
+    let l_ = (l + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m_ = (l - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s_ = (l - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+    [
+        4.0767416621 * l_ - 3.3077115913 * m_ + 0.2309699292 * s_,
+        -1.2684380046 * l_ + 2.6097574011 * m_ - 0.3413193965 * s_,
+        -0.0041960863 * l_ - 0.7034186147 * m_ + 1.7076147010 * s_,
+    ]
+}
+
+// Lightness, chroma and hue in degrees
+fn polar([l, a, b]: [f64; 3], achromatic: f64) -> [f64; 3] {
+    let chroma = a.hypot(b);
+    let hue = if chroma < achromatic {
+        0.0
+    } else {
+        b.atan2(a).to_degrees()
+    };
+    [l, chroma, hue]
+}
+
+fn rectangular([l, chroma, hue]: [f64; 3]) -> [f64; 3] {
+    let (sin, cos) = hue.to_radians().sin_cos();
+    [l, chroma * cos, chroma * sin]
+}
+
+fn finite(x: f32) -> f32 {
+    if x.is_finite() { x } else { 0.0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rgb(hex: u32) -> Rgb {
+        Rgb::new((hex >> 16) as u8, (hex >> 8) as u8, hex as u8)
+    }
+
+    fn close(actual: [f32; 3], expected: [f32; 3], tolerance: f32) -> bool {
+        actual
+            .iter()
+            .zip(expected)
+            .all(|(a, e)| (a - e).abs() <= tolerance)
+    }
+
+    mod reference {
+        use super::*;
+
+        #[test]
+        fn test_xyz() {
+            let white = Xyz::from(rgb(0xFFFFFF));
+            assert!(close(
+                [white.x(), white.y(), white.z()],
+                [0.95046, 1.0, 1.08906],
+                1e-4
+            ));
+            let red = Xyz::from(rgb(0xFF0000));
+            assert!(close(
+                [red.x(), red.y(), red.z()],
+                [0.41239, 0.21264, 0.01933],
+                1e-4
+            ));
+        }
+
+        #[test]
+        fn test_lab() {
+            let white = Lab::from(rgb(0xFFFFFF));
+            assert!(close(
+                [white.lightness(), white.a(), white.b()],
+                [100.0, 0.0, 0.0],
+                1e-3
+            ));
+            let red = Lab::from(rgb(0xFF0000));
+            assert!(close(
+                [red.lightness(), red.a(), red.b()],
+                [53.2408, 80.0925, 67.2032],
+                1e-2
+            ));
+            let black = Lab::from(rgb(0x000000));
+            assert_eq!([black.lightness(), black.a(), black.b()], [0.0; 3]);
+        }
+
+        #[test]
+        fn test_oklab() {
+            let red = Oklab::from(rgb(0xFF0000));
+            assert!(close(
+                [red.lightness(), red.a(), red.b()],
+                [0.62796, 0.22486, 0.12585],
+                1e-4
+            ));
+            let white = Oklab::from(rgb(0xFFFFFF));
+            assert!(close(
+                [white.lightness(), white.a(), white.b()],
+                [1.0, 0.0, 0.0],
+                1e-4
+            ));
+        }
+
+        #[test]
+        fn test_polar() {
+            let red = Oklch::from(rgb(0xFF0000));
+            assert!(close(
+                [red.lightness(), red.chroma(), red.hue()],
+                [0.62796, 0.25768, 29.2339],
+                1e-3
+            ));
+            let blue = Lch::from(rgb(0x0000FF));
+            assert!(close(
+                [blue.lightness(), blue.chroma(), blue.hue()],
+                [32.3026, 133.8076, 306.2849],
+                1e-2
+            ));
+        }
+
+        #[test]
+        fn test_grays_have_no_hue() {
+            for hex in [0x000000, 0x808080, 0xFFFFFF] {
+                assert_eq!(Lch::from(rgb(hex)).hue(), 0.0, "{hex:06X}");
+                assert_eq!(Oklch::from(rgb(hex)).hue(), 0.0, "{hex:06X}");
+            }
+        }
+
+        #[test]
+        fn test_out_of_gamut_is_clipped() {
+            assert_eq!(Rgb::from(Oklch::new(0.7, 0.4, 145.0)), Rgb::new(0, 210, 0));
+            assert_eq!(Rgb::from(Lab::new(100.0, 0.0, 0.0)), rgb(0xFFFFFF));
+            assert_eq!(Rgb::from(Oklab::new(2.0, 0.0, 0.0)), rgb(0xFFFFFF));
+        }
+
+        #[test]
+        fn test_new_normalizes() {
+            let c = Oklch::new(f32::NAN, -1.0, -90.0);
+            assert_eq!((c.lightness(), c.chroma(), c.hue()), (0.0, 0.0, 270.0));
+            assert_eq!(Lab::new(120.0, f32::INFINITY, 1.0).lightness(), 100.0);
+            assert_eq!(Lab::new(120.0, f32::INFINITY, 1.0).a(), 0.0);
+        }
+    }
+
+    mod round_trip {
+        use super::*;
+
+        #[test]
+        fn test_every_space_is_lossless() {
+            for r in (0..=255).step_by(5) {
+                for g in (0..=255).step_by(5) {
+                    for b in (0..=255).step_by(5) {
+                        let c = Rgb::new(r as u8, g as u8, b as u8);
+                        assert_eq!(Rgb::from(Xyz::from(c)), c, "xyz {c:?}");
+                        assert_eq!(Rgb::from(Lab::from(c)), c, "lab {c:?}");
+                        assert_eq!(Rgb::from(Lch::from(c)), c, "lch {c:?}");
+                        assert_eq!(Rgb::from(Oklab::from(c)), c, "oklab {c:?}");
+                        assert_eq!(Rgb::from(Oklch::from(c)), c, "oklch {c:?}");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_between_spaces() {
+            let c = rgb(0x663399);
+            assert_eq!(Rgb::from(Oklab::from(Xyz::from(c))), c);
+            assert_eq!(Rgb::from(Xyz::from(Oklab::from(c))), c);
+            assert_eq!(Rgb::from(Lab::from(Lch::from(c))), c);
+            assert_eq!(Rgb::from(Oklab::from(Oklch::from(c))), c);
+        }
+
+        #[test]
+        fn test_tuples_and_ansi() {
+            let lab = Lab::from((0x66, 0x33, 0x99));
+            assert_eq!(<(u8, u8, u8)>::from(lab), (0x66, 0x33, 0x99));
+            let ansi = crate::ansi::Ansi::from(Oklch::from((0x66, 0x33, 0x99)));
+            assert_eq!(ansi.get_rgb(), (0x66, 0x33, 0x99));
+        }
+    }
+
+    #[cfg(any(feature = "wikipedia", feature = "precomputed"))]
+    mod colors {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "wikipedia")]
+        fn test_named_colors() {
+            for c in crate::all() {
+                assert_eq!(Rgb::from(c.lab()), Rgb::from(&c), "{}", c.name());
+                assert_eq!(Rgb::from(c.oklch()), Rgb::from(&c), "{}", c.name());
+            }
+        }
+
+        #[test]
+        #[cfg(feature = "precomputed")]
+        fn test_precomputed_agrees() {
+            for c in crate::Palette::ALL.iter().flat_map(|p| p.all()) {
+                let lab = Lab::from(Rgb::from(&c));
+                let oklab = Oklab::from(Rgb::from(&c));
+                assert!(
+                    close(
+                        [c.lab().l, c.lab().a, c.lab().b],
+                        [lab.l, lab.a, lab.b],
+                        1e-3
+                    ),
+                    "{}",
+                    c.name()
+                );
+                assert!(
+                    close(
+                        [c.oklab().l, c.oklab().a, c.oklab().b],
+                        [oklab.l, oklab.a, oklab.b],
+                        1e-5
+                    ),
+                    "{}",
+                    c.name()
+                );
+            }
+        }
+    }
+}
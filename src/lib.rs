@@ -1,29 +1,159 @@
 //! All the colors of the web, by name – case-/space-/snake-insensitive.
 //!
 //! ```rust
+//! # #[cfg(feature = "wikipedia")] {
 //! use pigment::color;
 //!
 //! let az = color("Absolute Zero").unwrap();
 //! println!("hex = {}", az.hex());          // #0048BA
 //! println!("rgb = {:?}", az.rgb());        // (0, 72, 186)
 //! println!("{}Absolute Zero{}", az.ansi().fg(), pigment::ansi::Ansi::reset());
+//! # }
 //! ```
+mod adjust;
 pub mod ansi;
 mod color;
+mod complete;
+mod contrast;
+mod css;
+mod cvd;
+mod family;
+mod gradient;
+mod harmony;
+mod hsl;
+mod iter;
+mod lab;
+#[cfg(any(feature = "locale-de", feature = "locale-fr", feature = "locale-ja"))]
+mod locale;
+#[cfg(feature = "macros")]
+mod macros;
+mod nearest;
+mod palette;
+mod parse;
+mod registry;
+mod rgba;
+mod search;
+mod source;
 pub use color::Color;
-
+pub use complete::{complete, containing};
+pub use contrast::WcagLevel;
+pub use css::{Css, CssFormat};
+pub use cvd::{Confusion, CvdModel, Deficiency, MIN_DISTANCE, confusions, confusions_with};
+pub use family::{Chroma, Family, Lightness, family, family_in_band};
+pub use gradient::{Gradient, HueArc, Interpolation};
+pub use harmony::Harmony;
+pub use hsl::{Hsl, Hsv, Hwb};
+pub use iter::{Colors, Section, all, len, section, starting_with};
+pub use lab::{Lab, Lch, Oklab, Oklch, Xyz};
+#[cfg(any(feature = "locale-de", feature = "locale-fr", feature = "locale-ja"))]
+pub use locale::{Locale, color_in};
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub use macros::__private;
+pub use nearest::{nearest, nearest_k};
+pub use palette::Palette;
+pub use parse::{ParseColorError, ParseColorErrorKind, ParsedColor, parse, parse_strict};
+pub use registry::{Policy, Registry, RegistryError};
+pub use rgba::{Rgb, Rgba};
+pub use search::{Match, search, suggest};
+pub use source::Source;
+
+#[cfg(not(any(feature = "wikipedia", feature = "css-names", feature = "x11")))]
+compile_error!("pigment needs a color table: enable `wikipedia`, `css-names` or `x11`");
+
+#[cfg(feature = "wikipedia")]
 include!(concat!(env!("CARGO_MANIFEST_DIR"), "/generated/colors.rs"));
-
-fn canonical(s: &str) -> String {
-    s.chars()
-        .filter(|c| c.is_ascii_alphanumeric())
-        .flat_map(|c| c.to_lowercase())
-        .collect()
-}
+#[cfg(feature = "css-names")]
+include!(concat!(env!("CARGO_MANIFEST_DIR"), "/generated/css.rs"));
+#[cfg(feature = "x11")]
+include!(concat!(env!("CARGO_MANIFEST_DIR"), "/generated/x11.rs"));
+#[cfg(feature = "precomputed")]
+include!(concat!(env!("CARGO_MANIFEST_DIR"), "/generated/lab.rs"));
+#[cfg(feature = "locale-de")]
+include!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/generated/locale_de.rs"
+));
+#[cfg(feature = "locale-fr")]
+include!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/generated/locale_fr.rs"
+));
+#[cfg(feature = "locale-ja")]
+include!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/generated/locale_ja.rs"
+));
+
+use pigment_names::canonical;
 
 /// Look up a color by (reasonably forgiving) name.
+///
+/// Every enabled palette is searched, in [`Palette::ALL`] order: the
+/// Wikipedia table, then the CSS keywords, then X11. The first palette that
+/// knows the name wins, either as one of its own names or through an alias:
+/// a spelling variant like "grey", an abbreviation like "dk" or "lt", or a
+/// common misspelling like "fuschia". See [`Color::aliases`].
+///
+/// So with all three enabled "azure" is Wikipedia's `#007FFF`, and "dark
+/// slate grey" is Wikipedia's *Dark slate gray* rather than the CSS keyword
+/// `darkslategrey`; use [`Palette::get`] to ask one palette in particular.
+///
+/// Colors added to the [global registry](Registry::global) are found too,
+/// before or after the built-in ones depending on its [`Policy`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "wikipedia")] {
+/// use pigment::color;
+///
+/// assert_eq!(color("dk slate gray"), color("dark slate gray"));
+/// assert_eq!(color("lt green"), color("light green"));
+/// assert_eq!(color("fuschia").unwrap().name(), "Fuchsia");
+/// # }
+/// ```
 pub fn color(name: &str) -> Option<Color> {
-    COLORS.get(&canonical(name)).copied()
+    Registry::global().color(name)
+}
+
+// The built-in color with canonical name `key`: each enabled palette in
+// turn, by primary key and then by alias
+fn builtin(key: &str) -> Option<Color> {
+    Palette::ALL
+        .iter()
+        .find_map(|palette| palette.primary(key).or_else(|| palette.alias(key)))
+}
+
+/// Every color going by `name`, in every enabled palette.
+///
+/// Where [`color`] picks one entry, this returns all of them: the plain
+/// name and every labelled variant of it, such as *Blue (Crayola)* and
+/// *Blue (Munsell)* for "blue", as well as same-named entries from
+/// different palettes. [`Color::source`] tells them apart. A fully
+/// qualified name like "Blue (Crayola)" finds just that entry.
+///
+/// Results are grouped by palette in [`Palette::ALL`] order, each group in
+/// source order. An unknown name gives an empty `Vec`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "wikipedia")] {
+/// let blues = pigment::color_all("blue");
+/// assert!(blues.len() > 1);
+/// assert!(blues.iter().any(|c| c.source().variant() == Some("Munsell")));
+///
+/// let greens = pigment::color_all("Pine green");
+/// let hexes: Vec<_> = greens.iter().map(|c| c.hex()).collect();
+/// assert_eq!(hexes, ["#01796F", "#2A2F23"]);
+/// # }
+/// ```
+pub fn color_all(name: &str) -> Vec<Color> {
+    Palette::ALL
+        .iter()
+        .flat_map(|palette| palette.get_all(name))
+        .collect()
 }
 
 #[cfg(test)]
@@ -86,12 +216,33 @@ mod tests {
 
         #[test]
         fn test_with_non_ascii_characters() {
-            // Test with non-ASCII characters (should be removed)
-            assert_eq!(canonical("Café au Lait"), "cafaulait");
-            assert_eq!(canonical("Blåbær"), "blbr");
-            assert_eq!(canonical("Grün"), "grn");
-            assert_eq!(canonical("Röd"), "rd");
-            assert_eq!(canonical("Café"), "caf");
+            // Accents are folded away rather than dropped with their letter
+            assert_eq!(canonical("Café au Lait"), "cafeaulait");
+            assert_eq!(canonical("Grün"), "grun");
+            assert_eq!(canonical("Röd"), "rod");
+            assert_eq!(canonical("Café"), "cafe");
+            // Precomposed and decomposed spellings agree
+            assert_eq!(canonical("Cafe\u{301}"), "cafe");
+            assert_eq!(canonical("CAFÉ"), "cafe");
+        }
+
+        #[test]
+        fn test_transliterated_letters() {
+            assert_eq!(canonical("Blåbær"), "blabaer");
+            assert_eq!(canonical("Weiß"), "weiss");
+            assert_eq!(canonical("Grøn"), "gron");
+            assert_eq!(canonical("Œillet"), "oeillet");
+            assert_eq!(canonical("Æblerød"), "aeblerod");
+        }
+
+        #[test]
+        fn test_other_scripts_and_compatibility_forms() {
+            // Letters without an ASCII folding are kept, lowercased
+            assert_eq!(canonical("Синяя"), "синяя");
+            assert_eq!(canonical("青 色"), "青色");
+            // NFKD turns compatibility characters into their plain forms
+            assert_eq!(canonical("ﬁre"), "fire");
+            assert_eq!(canonical("Gray①"), "gray1");
         }
 
         #[test]
@@ -105,6 +256,7 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "wikipedia")]
     mod color_lookup {
         use super::*;
 
@@ -206,8 +358,53 @@ mod tests {
             assert!(color("a_l_i_c_e_b_l_u_e").is_some());
             assert!(color("a.l.i.c.e.b.l.u.e").is_some());
         }
+
+        #[test]
+        fn test_accented_names() {
+            let cafe = color("Café au lait").unwrap();
+            assert_eq!(cafe.hex(), "#A67B5B");
+            assert_eq!(color("Cafe au lait").as_ref(), Some(&cafe));
+            assert_eq!(color("CAFÉ AU LAIT"), Some(cafe));
+            assert_eq!(color("Tenné (tawny)"), color("tenne tawny"));
+        }
+
+        #[test]
+        fn test_aliases() {
+            let slate = color("Dark slate gray").unwrap();
+            assert!(slate.aliases().contains(&"darkslategrey"));
+            assert_eq!(color("Dark Slate Grey").unwrap().rgb(), slate.rgb());
+            assert_eq!(color("dk slate gray"), Some(slate));
+            assert_eq!(color("Fuschia"), color("Fuchsia"));
+            assert_eq!(color("med purple"), color("Medium purple"));
+            assert!(color("Red").unwrap().aliases().is_empty());
+        }
+
+        #[test]
+        fn test_color_all() {
+            let blues = color_all("Blue");
+            assert_eq!(blues[0], color("Blue").unwrap());
+            let variants: Vec<_> = blues.iter().map(|c| c.source().variant()).collect();
+            assert!(variants.contains(&Some("Crayola")));
+            assert!(variants.contains(&Some("Munsell")));
+
+            // Duplicates without a label are all there, in source order
+            let vermilions = color_all("vermilion");
+            assert_eq!(vermilions.len(), 2);
+            assert!(vermilions.iter().all(|c| c.name() == "Vermilion"));
+            assert_ne!(vermilions[0].hex(), vermilions[1].hex());
+
+            // Qualified names, plain names and aliases find a single entry
+            assert_eq!(
+                color_all("Blue (Crayola)"),
+                [color("Blue (Crayola)").unwrap()]
+            );
+            assert_eq!(color_all("Zomp"), [color("Zomp").unwrap()]);
+            assert_eq!(color_all("fuschia")[0], color("Fuchsia").unwrap());
+            assert!(color_all("nope").is_empty());
+        }
     }
 
+    #[cfg(feature = "wikipedia")]
     mod color_lookup_equivalence {
         use super::*;
 
@@ -272,6 +469,7 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "wikipedia")]
     mod integration_tests {
         use super::*;
 
@@ -0,0 +1,232 @@
+//! Color names in other languages.
+//!
+//! Each locale is a generated table of translated names for entries of the
+//! Wikipedia table, compiled in only with its cargo feature: `locale-de`,
+//! `locale-fr` or `locale-ja`, each of which turns on `wikipedia`.
+//! Translated names are canonicalized like English ones, so "bleu ciel",
+//! "Bleu-Ciel" and "BLEU CIEL" all match.
+
+use crate::{Color, Palette, Registry, canonical};
+
+#[cfg(feature = "locale-de")]
+use crate::{LOCALE_DE, LOCALE_DE_NAMES};
+#[cfg(feature = "locale-fr")]
+use crate::{LOCALE_FR, LOCALE_FR_NAMES};
+#[cfg(feature = "locale-ja")]
+use crate::{LOCALE_JA, LOCALE_JA_NAMES};
+
+type Names = phf::Map<&'static str, &'static str>;
+
+/// A language with translated color names.
+///
+/// Only the variants whose cargo feature is enabled exist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// German (feature `locale-de`).
+    #[cfg(feature = "locale-de")]
+    De,
+    /// French (feature `locale-fr`).
+    #[cfg(feature = "locale-fr")]
+    Fr,
+    /// Japanese (feature `locale-ja`).
+    #[cfg(feature = "locale-ja")]
+    Ja,
+}
+
+impl Locale {
+    /// Every enabled locale.
+    pub const ALL: &'static [Locale] = &[
+        #[cfg(feature = "locale-de")]
+        Locale::De,
+        #[cfg(feature = "locale-fr")]
+        Locale::Fr,
+        #[cfg(feature = "locale-ja")]
+        Locale::Ja,
+    ];
+
+    /// ISO 639-1 code of the language, e.g. `"de"`.
+    pub fn code(self) -> &'static str {
+        match self {
+            #[cfg(feature = "locale-de")]
+            Locale::De => "de",
+            #[cfg(feature = "locale-fr")]
+            Locale::Fr => "fr",
+            #[cfg(feature = "locale-ja")]
+            Locale::Ja => "ja",
+        }
+    }
+
+    /// The enabled locale for a language tag such as `"fr"`, `"de-AT"` or
+    /// `"ja_JP"`; only the language part is looked at.
+    ///
+    /// ```
+    /// # #[cfg(feature = "locale-de")] {
+    /// use pigment::Locale;
+    ///
+    /// assert_eq!(Locale::from_code("de-CH"), Some(Locale::De));
+    /// assert_eq!(Locale::from_code("xx"), None);
+    /// # }
+    /// ```
+    pub fn from_code(tag: &str) -> Option<Locale> {
+        let language = tag.split(['-', '_']).next()?;
+        Locale::ALL
+            .iter()
+            .copied()
+            .find(|locale| locale.code().eq_ignore_ascii_case(language))
+    }
+
+    // Translated name → Wikipedia key of the English name, and the reverse
+    fn tables(self) -> (&'static Names, &'static Names) {
+        match self {
+            #[cfg(feature = "locale-de")]
+            Locale::De => (&LOCALE_DE, &LOCALE_DE_NAMES),
+            #[cfg(feature = "locale-fr")]
+            Locale::Fr => (&LOCALE_FR, &LOCALE_FR_NAMES),
+            #[cfg(feature = "locale-ja")]
+            Locale::Ja => (&LOCALE_JA, &LOCALE_JA_NAMES),
+        }
+    }
+}
+
+/// Look up a color by its name in `locale`, falling back to [`color`](crate::color).
+///
+/// Translated names resolve to the Wikipedia entry they translate, or to a
+/// color registered under its English name if the
+/// [global registry](Registry::global) lets registered colors shadow
+/// built-in ones. Anything else, such as an English name, is passed on to
+/// [`color`](crate::color), so a localized app can use this everywhere.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "locale-de", feature = "locale-fr", feature = "locale-ja"))] {
+/// use pigment::{Locale, color, color_in};
+///
+/// assert_eq!(color_in(Locale::De, "Himmelblau"), color("Sky blue"));
+/// assert_eq!(color_in(Locale::Fr, "bleu ciel"), color("Sky blue"));
+/// assert_eq!(color_in(Locale::Ja, "空色"), color("Sky blue"));
+/// assert_eq!(color_in(Locale::De, "Zomp"), color("Zomp"));
+/// # }
+/// ```
+pub fn color_in(locale: Locale, name: &str) -> Option<Color> {
+    let (lookup, _) = locale.tables();
+    match lookup.get(&canonical(name)) {
+        Some(key) => Registry::global().resolve(key, || Palette::Wikipedia.primary(key)),
+        None => crate::color(name),
+    }
+}
+
+// The translation of a color's name, if the color is the Wikipedia entry
+// the translation was written for; a CSS or registered color of the same
+// name has none
+pub(crate) fn localized_name(color: &Color, locale: Locale) -> Option<&'static str> {
+    let (_, names) = locale.tables();
+    let key = canonical(color.name());
+    if Palette::Wikipedia.table().colors.get(&key) != Some(color) {
+        return None;
+    }
+    names.get(&key).copied()
+}
+
+#[cfg(all(test, feature = "wikipedia"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tables_agree() {
+        for &locale in Locale::ALL {
+            let (lookup, names) = locale.tables();
+            for (name, key) in lookup.entries() {
+                assert!(crate::builtin(key).is_some(), "{locale:?} {key}");
+                assert!(names.contains_key(key), "{locale:?} {name}");
+            }
+            for (key, name) in names.entries() {
+                assert_eq!(lookup.get(&canonical(name)), Some(key), "{locale:?} {name}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_code() {
+        for &locale in Locale::ALL {
+            assert_eq!(Locale::from_code(locale.code()), Some(locale));
+            let tag = format!("{}-XX", locale.code().to_uppercase());
+            assert_eq!(Locale::from_code(&tag), Some(locale));
+        }
+        assert_eq!(Locale::from_code(""), None);
+        assert_eq!(Locale::from_code("en"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "locale-de")]
+    fn test_german() {
+        let sky = crate::color("Sky blue").unwrap();
+        assert_eq!(color_in(Locale::De, "Himmelblau").as_ref(), Some(&sky));
+        assert_eq!(color_in(Locale::De, "HIMMEL-BLAU").as_ref(), Some(&sky));
+        assert_eq!(sky.localized_name(Locale::De), Some("Himmelblau"));
+        // Umlauts and ß fold like everywhere else
+        assert_eq!(color_in(Locale::De, "Grun"), crate::color("Green"));
+        assert_eq!(color_in(Locale::De, "weiss"), crate::color("White"));
+        // Alternate names look up, the first one is displayed
+        let teal = color_in(Locale::De, "Blaugrün").unwrap();
+        assert_eq!(teal.localized_name(Locale::De), Some("Petrol"));
+    }
+
+    #[test]
+    #[cfg(feature = "locale-fr")]
+    fn test_french() {
+        assert_eq!(color_in(Locale::Fr, "Bleu ciel"), crate::color("Sky blue"));
+        assert_eq!(color_in(Locale::Fr, "ecarlate"), crate::color("Scarlet"));
+        // "Violet" is French for purple, and wins over the English name
+        assert_eq!(color_in(Locale::Fr, "violet"), crate::color("Purple"));
+        assert_eq!(
+            crate::color("Gray (web)")
+                .unwrap()
+                .localized_name(Locale::Fr),
+            Some("Gris")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "locale-ja")]
+    fn test_japanese() {
+        assert_eq!(color_in(Locale::Ja, "空色"), crate::color("Sky blue"));
+        assert_eq!(
+            color_in(Locale::Ja, "サーモンピンク"),
+            crate::color("Salmon")
+        );
+        assert_eq!(
+            crate::color("Cherry blossom pink")
+                .unwrap()
+                .localized_name(Locale::Ja),
+            Some("桜色")
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "locale-fr", feature = "css-names"))]
+    fn test_translations_belong_to_wikipedia_entries() {
+        let sky = Palette::Wikipedia.get("Sky blue").unwrap();
+        assert_eq!(sky.localized_name(Locale::Fr), Some("Bleu ciel"));
+        // Same name, different table
+        let css_sky = Palette::Css.get("skyblue").unwrap();
+        assert_eq!(css_sky.localized_name(Locale::Fr), None);
+    }
+
+    #[test]
+    fn test_fallback_and_misses() {
+        for &locale in Locale::ALL {
+            assert_eq!(
+                color_in(locale, "Absolute Zero"),
+                crate::color("Absolute Zero")
+            );
+            assert_eq!(color_in(locale, "no such color"), None);
+            assert_eq!(
+                crate::color("Absolute Zero")
+                    .unwrap()
+                    .localized_name(locale),
+                None
+            );
+        }
+    }
+}
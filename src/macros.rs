@@ -0,0 +1,157 @@
+//! Color names and hex codes checked at compile time.
+//!
+//! [`color!`](crate::color!) resolves a name with the same rules as
+//! [`color`](crate::color()) while compiling, so a typo is a compile error
+//! with suggestions instead of a `None` at runtime. Both macros expand to
+//! constants and need the `macros` feature, which is on by default.
+//!
+//! The names checked are those of the built-in palettes enabled when
+//! compiling; colors added to a [`Registry`](crate::Registry) at runtime
+//! aren't known yet, so they still need [`color`](crate::color()).
+
+/// The built-in color called `name`, checked at compile time.
+///
+/// The name is matched exactly like [`color`](crate::color()) matches it,
+/// aliases included, and the result is a constant equal to what
+/// `color(name).unwrap()` returns.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "wikipedia")] {
+/// use pigment::Color;
+///
+/// const AZURE: Color = pigment::color!("Azure");
+/// assert_eq!(AZURE.hex(), "#007FFF");
+/// assert_eq!(pigment::color!("fuschia"), pigment::color("fuschia").unwrap());
+/// # }
+/// ```
+///
+/// Unknown names fail to compile, naming the closest matches:
+///
+/// ```compile_fail
+/// let c = pigment::color!("Azuer"); // unknown color name "Azuer"; did you mean "Azure", …?
+/// ```
+#[macro_export]
+macro_rules! color {
+    ($name:literal) => {
+        $crate::__private::color_macro!($crate, $name)
+    };
+}
+
+/// The [`Rgb`](crate::Rgb) value of a hex code, checked at compile time.
+///
+/// Accepts `#RGB` and `#RRGGBB`, with or without the `#`. An `Rgb` is
+/// opaque, so codes with an alpha digit or pair are refused rather than
+/// having it dropped; use [`Rgba::from_hex`](crate::Rgba::from_hex) for
+/// those.
+///
+/// # Examples
+///
+/// ```
+/// use pigment::Rgb;
+///
+/// const ZERO: Rgb = pigment::hex!("#0048BA");
+/// assert_eq!(ZERO, Rgb::new(0, 72, 186));
+/// assert_eq!(pigment::hex!("f0f").tuple(), (255, 0, 255));
+/// ```
+///
+/// ```compile_fail
+/// let c = pigment::hex!("#0048BG"); // 'G' is not a hex digit
+/// ```
+///
+/// ```compile_fail
+/// let c = pigment::hex!("#0048BA80"); // `hex!` makes an opaque `Rgb`, so it takes no alpha
+/// ```
+#[macro_export]
+macro_rules! hex {
+    ($code:literal) => {
+        $crate::__private::hex_macro!($crate, $code)
+    };
+}
+
+#[doc(hidden)]
+pub mod __private {
+    use std::borrow::Cow;
+
+    use crate::{Color, Rgb, Source};
+
+    pub use pigment_macros::{color as color_macro, hex as hex_macro};
+
+    pub const fn color(
+        name: &'static str,
+        hex: &'static str,
+        rgb: (u8, u8, u8),
+        aliases: &'static [&'static str],
+        list: &'static str,
+        variant: Option<&'static str>,
+    ) -> Color {
+        Color {
+            name: Cow::Borrowed(name),
+            hex: Cow::Borrowed(hex),
+            rgb,
+            aliases,
+            source: Source { list, variant },
+        }
+    }
+
+    pub const fn hex((r, g, b): (u8, u8, u8)) -> Rgb {
+        Rgb::new(r, g, b)
+    }
+}
+
+#[cfg(all(test, feature = "wikipedia"))]
+mod tests {
+    use crate::Color;
+
+    mod color_macro {
+        use super::*;
+
+        #[test]
+        fn test_matches_runtime_lookup() {
+            const ZOMP: Color = crate::color!("Zomp");
+            assert_eq!(Some(ZOMP), crate::color("Zomp"));
+            assert_eq!(
+                Some(crate::color!("Café au lait")),
+                crate::color("cafe au lait")
+            );
+            assert_eq!(
+                Some(crate::color!("ABSOLUTE_ZERO")),
+                crate::color("Absolute Zero")
+            );
+            assert_eq!(
+                Some(crate::color!(r"Gray (web)")),
+                crate::color("Gray (web)")
+            );
+        }
+
+        #[test]
+        fn test_aliases() {
+            assert_eq!(Some(crate::color!("fuschia")), crate::color("Fuchsia"));
+            assert_eq!(crate::color!("fuschia").name(), "Fuchsia");
+        }
+
+        #[test]
+        fn test_source_is_kept() {
+            let c = crate::color!("Gray (web)");
+            assert_eq!(c.source(), crate::color("Gray (web)").unwrap().source());
+            assert_eq!(c.source().variant(), Some("web"));
+        }
+    }
+
+    mod hex_macro {
+        use crate::Rgb;
+
+        #[test]
+        fn test_forms() {
+            assert_eq!(crate::hex!("#0048BA"), Rgb::new(0, 72, 186));
+            assert_eq!(crate::hex!("0048ba"), Rgb::new(0, 72, 186));
+            assert_eq!(crate::hex!("#F00"), Rgb::new(255, 0, 0));
+        }
+
+        #[test]
+        fn test_agrees_with_from_hex() {
+            assert_eq!(Some(crate::hex!("#9AB")), Rgb::from_hex("#9AB"));
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! Reverse lookup: the named color closest to an arbitrary RGB value.
+//!
+//! Distances are Euclidean in OKLab, which tracks perceived difference far
+//! better than RGB distance. The search runs over a k-d tree that
+//! `tools/scrape.py` precomputes for every palette.
+
+use crate::Color;
+use crate::palette::{Palette, Table};
+
+/// One entry of the generated k-d tree.
+///
+/// A subtree is a contiguous slice: its median node first, followed by the
+/// lower half and then the upper half, split on L, a and b in turn.
+#[derive(Debug)]
+pub(crate) struct Node {
+    pub(crate) lab: [f32; 3],
+    pub(crate) key: &'static str,
+}
+
+/// The named color perceptually closest to `rgb`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "wikipedia")] {
+/// use pigment::nearest;
+///
+/// // An exact table entry finds itself
+/// assert_eq!(nearest((0, 72, 186)).name(), "Absolute Zero");
+///
+/// // Anything else finds its closest neighbour
+/// let c = nearest((0x00, 0x49, 0xB9));
+/// assert_eq!(c.name(), "Absolute Zero");
+/// # }
+/// ```
+pub fn nearest(rgb: (u8, u8, u8)) -> Color {
+    Palette::default().nearest(rgb)
+}
+
+/// The `k` named colors perceptually closest to `rgb`, closest first.
+///
+/// Returns fewer than `k` colors only when the table itself is smaller.
+///
+/// # Examples
+///
+/// ```
+/// use pigment::nearest_k;
+///
+/// let three = nearest_k((250, 128, 114), 3);
+/// assert_eq!(three.len(), 3);
+/// assert_eq!(three[0].hex(), "#FA8072"); // Salmon
+/// ```
+pub fn nearest_k(rgb: (u8, u8, u8), k: usize) -> Vec<Color> {
+    Palette::default().nearest_k(rgb, k)
+}
+
+pub(crate) fn nearest_k_in(table: &'static Table, rgb: (u8, u8, u8), k: usize) -> Vec<Color> {
+    let target = oklab(rgb);
+    let mut best = Vec::with_capacity(k + 1);
+    if k > 0 {
+        descend(table.nearest, 0, &target, k, &mut best);
+    }
+    best.into_iter()
+        .map(|(_, key)| table.colors[key].clone())
+        .collect()
+}
+
+// Walk one subtree, keeping `best` sorted by distance and at most `k` long
+fn descend(
+    nodes: &'static [Node],
+    depth: usize,
+    target: &[f32; 3],
+    k: usize,
+    best: &mut Vec<(f32, &'static str)>,
+) {
+    let Some(node) = nodes.first() else {
+        return;
+    };
+    let (lower, upper) = nodes[1..].split_at(nodes.len() / 2);
+
+    let d = distance2(&node.lab, target);
+    if best.len() < k || d < best[best.len() - 1].0 {
+        let at = best.partition_point(|&(other, _)| other <= d);
+        best.insert(at, (d, node.key));
+        best.truncate(k);
+    }
+
+    let axis = depth % 3;
+    let delta = target[axis] - node.lab[axis];
+    let (near, far) = if delta < 0.0 {
+        (lower, upper)
+    } else {
+        (upper, lower)
+    };
+    descend(near, depth + 1, target, k, best);
+    if best.len() < k || delta * delta < best[best.len() - 1].0 {
+        descend(far, depth + 1, target, k, best);
+    }
+}
+
+fn distance2(a: &[f32; 3], b: &[f32; 3]) -> f32 {
+    (a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)
+}
+
+// sRGB → OKLab, rounded to the precision of the generated tree
+pub(crate) fn oklab(rgb: (u8, u8, u8)) -> [f32; 3] {
+    oklab_f64(rgb).map(|x| x as f32)
+}
+
+// sRGB → OKLab
+pub(crate) fn oklab_f64(rgb: (u8, u8, u8)) -> [f64; 3] {
+    crate::lab::oklab_from_linear(crate::lab::linearize(rgb))
+}
+
+#[cfg(all(test, feature = "wikipedia"))]
+mod tests {
+    use super::*;
+    use crate::{COLORS, COLORS_NEAREST};
+
+    // Reference answer: distance to every entry, sorted
+    fn brute_force(rgb: (u8, u8, u8)) -> Vec<f32> {
+        let target = oklab(rgb);
+        let mut all: Vec<f32> = COLORS
+            .values()
+            .map(|c| distance2(&oklab(c.rgb), &target))
+            .collect();
+        all.sort_by(f32::total_cmp);
+        all
+    }
+
+    mod oklab_conversion {
+        use super::*;
+
+        #[test]
+        fn test_reference_values() {
+            let [l, a, b] = oklab((255, 255, 255));
+            assert!((l - 1.0).abs() < 1e-4 && a.abs() < 1e-4 && b.abs() < 1e-4);
+
+            let [l, a, b] = oklab((0, 0, 0));
+            assert!(l.abs() < 1e-6 && a.abs() < 1e-6 && b.abs() < 1e-6);
+
+            // Red in OKLab is (0.62796, 0.22486, 0.12585)
+            let [l, a, b] = oklab((255, 0, 0));
+            assert!((l - 0.62796).abs() < 1e-3);
+            assert!((a - 0.22486).abs() < 1e-3);
+            assert!((b - 0.12585).abs() < 1e-3);
+        }
+
+        #[test]
+        fn test_generated_coordinates_match() {
+            for node in COLORS_NEAREST.iter() {
+                let lab = oklab(COLORS[node.key].rgb);
+                assert!(distance2(&lab, &node.lab) < 1e-8, "{}", node.key);
+            }
+        }
+    }
+
+    mod index {
+        use super::*;
+
+        #[test]
+        fn test_covers_every_color() {
+            assert_eq!(COLORS_NEAREST.len(), COLORS.len());
+            for node in COLORS_NEAREST.iter() {
+                assert!(COLORS.contains_key(node.key));
+            }
+        }
+
+        #[test]
+        fn test_entries_find_themselves() {
+            for (key, c) in COLORS.entries() {
+                let found = nearest(c.rgb);
+                // Some names share a hex value, so only the value has to match
+                assert_eq!(found.rgb, c.rgb, "{key}");
+            }
+        }
+
+        #[test]
+        fn test_matches_brute_force() {
+            for r in (0..=255).step_by(51) {
+                for g in (0..=255).step_by(51) {
+                    for b in (0..=255).step_by(51) {
+                        let rgb = (r as u8, g as u8, b as u8);
+                        let target = oklab(rgb);
+                        let expected = brute_force(rgb);
+                        let found = nearest_k(rgb, 4);
+                        for (c, want) in found.iter().zip(&expected) {
+                            let got = distance2(&oklab(c.rgb), &target);
+                            assert!((got - want).abs() < 1e-6, "{rgb:?}");
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    mod nearest_k_function {
+        use super::*;
+
+        #[test]
+        fn test_sorted_closest_first() {
+            let target = oklab((12, 200, 99));
+            let found = nearest_k((12, 200, 99), 10);
+            assert_eq!(found.len(), 10);
+            let d: Vec<f32> = found
+                .iter()
+                .map(|c| distance2(&oklab(c.rgb), &target))
+                .collect();
+            assert!(d.windows(2).all(|w| w[0] <= w[1]));
+        }
+
+        #[test]
+        fn test_zero_and_oversized_k() {
+            assert!(nearest_k((1, 2, 3), 0).is_empty());
+            assert_eq!(nearest_k((1, 2, 3), 10_000).len(), COLORS.len());
+        }
+
+        #[test]
+        fn test_first_is_nearest() {
+            let rgb = (0x40, 0x80, 0xC0);
+            assert_eq!(nearest_k(rgb, 5)[0].rgb, nearest(rgb).rgb);
+        }
+    }
+}
@@ -0,0 +1,536 @@
+//! Separately selectable named-color tables.
+//!
+//! Color names are not universal: Wikipedia's *Azure* is `#007FFF`, CSS and
+//! X11 `azure` is `#F0FFFF`, and CSS `gray` is darker than X11 `gray`.
+//! [`Palette`] picks which naming scheme a lookup uses.
+//!
+//! Each palette is compiled in only when its cargo feature is enabled:
+//! `wikipedia` (on by default), `css-names` and `x11`. The crate-level
+//! functions such as [`all`](crate::all) use the first enabled palette, see
+//! [`Palette::default`], and [`color`](crate::color) searches all of them.
+
+use crate::family::{Chroma, Family, Lightness};
+use crate::iter::{Colors, Section};
+use crate::nearest::Node;
+use crate::search::Match;
+use crate::{Color, canonical};
+
+#[cfg(feature = "wikipedia")]
+use crate::{
+    COLORS, COLORS_ALIASES, COLORS_BANDS, COLORS_BY_NAME, COLORS_FAMILIES, COLORS_KEYS,
+    COLORS_NEAREST, COLORS_VARIANTS, COLORS_WORDS,
+};
+#[cfg(feature = "css-names")]
+use crate::{
+    CSS_COLORS, CSS_COLORS_ALIASES, CSS_COLORS_BANDS, CSS_COLORS_BY_NAME, CSS_COLORS_FAMILIES,
+    CSS_COLORS_KEYS, CSS_COLORS_NEAREST, CSS_COLORS_VARIANTS, CSS_COLORS_WORDS,
+};
+#[cfg(feature = "x11")]
+use crate::{
+    X11_COLORS, X11_COLORS_ALIASES, X11_COLORS_BANDS, X11_COLORS_BY_NAME, X11_COLORS_FAMILIES,
+    X11_COLORS_KEYS, X11_COLORS_NEAREST, X11_COLORS_VARIANTS, X11_COLORS_WORDS,
+};
+
+/// A generated color table and the indexes `tools/scrape.py` builds for it.
+#[derive(Debug)]
+pub(crate) struct Table {
+    pub(crate) colors: &'static phf::Map<&'static str, Color>,
+    pub(crate) aliases: &'static phf::Map<&'static str, &'static str>,
+    pub(crate) variants: &'static phf::Map<&'static str, &'static [&'static str]>,
+    pub(crate) by_name: &'static [&'static str],
+    pub(crate) keys: &'static [&'static str],
+    pub(crate) words: &'static [(&'static str, &'static [&'static str])],
+    pub(crate) families: &'static [&'static [&'static str]; Family::ALL.len()],
+    pub(crate) bands: &'static [[[&'static [&'static str]; Chroma::ALL.len()]; Lightness::ALL.len()];
+                 Family::ALL.len()],
+    pub(crate) nearest: &'static [Node],
+}
+
+#[cfg(feature = "wikipedia")]
+static WIKIPEDIA: Table = Table {
+    colors: &COLORS,
+    aliases: &COLORS_ALIASES,
+    variants: &COLORS_VARIANTS,
+    by_name: &COLORS_BY_NAME,
+    keys: &COLORS_KEYS,
+    words: &COLORS_WORDS,
+    families: &COLORS_FAMILIES,
+    bands: &COLORS_BANDS,
+    nearest: &COLORS_NEAREST,
+};
+
+#[cfg(feature = "css-names")]
+static CSS: Table = Table {
+    colors: &CSS_COLORS,
+    aliases: &CSS_COLORS_ALIASES,
+    variants: &CSS_COLORS_VARIANTS,
+    by_name: &CSS_COLORS_BY_NAME,
+    keys: &CSS_COLORS_KEYS,
+    words: &CSS_COLORS_WORDS,
+    families: &CSS_COLORS_FAMILIES,
+    bands: &CSS_COLORS_BANDS,
+    nearest: &CSS_COLORS_NEAREST,
+};
+
+#[cfg(feature = "x11")]
+static X11: Table = Table {
+    colors: &X11_COLORS,
+    aliases: &X11_COLORS_ALIASES,
+    variants: &X11_COLORS_VARIANTS,
+    by_name: &X11_COLORS_BY_NAME,
+    keys: &X11_COLORS_KEYS,
+    words: &X11_COLORS_WORDS,
+    families: &X11_COLORS_FAMILIES,
+    bands: &X11_COLORS_BANDS,
+    nearest: &X11_COLORS_NEAREST,
+};
+
+/// A named-color table.
+///
+/// Every palette supports the same lookups as the crate-level functions,
+/// with the same forgiving name matching. Only the variants whose cargo
+/// feature is enabled exist.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "wikipedia", feature = "css-names", feature = "x11"))] {
+/// use pigment::Palette;
+///
+/// assert_eq!(Palette::Wikipedia.get("Azure").unwrap().hex(), "#007FFF");
+/// assert_eq!(Palette::Css.get("azure").unwrap().hex(), "#F0FFFF");
+/// assert_eq!(Palette::Css.get("Rebecca Purple").unwrap().hex(), "#663399");
+/// assert_eq!(Palette::X11.get("Gray").unwrap().hex(), "#BEBEBE");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Palette {
+    /// Wikipedia's "List of colors" pages (feature `wikipedia`).
+    #[cfg(feature = "wikipedia")]
+    Wikipedia,
+    /// The CSS Color Module Level 4 named colors, `aliceblue` … `yellowgreen`
+    /// (feature `css-names`).
+    #[cfg(feature = "css-names")]
+    Css,
+    /// The X11 `rgb.txt` database, including numbered variants like `snow3`
+    /// (feature `x11`).
+    #[cfg(feature = "x11")]
+    X11,
+}
+
+impl Palette {
+    /// Every enabled palette, in the order [`color`](crate::color) searches
+    /// them: Wikipedia, then CSS, then X11.
+    pub const ALL: &'static [Palette] = &[
+        #[cfg(feature = "wikipedia")]
+        Palette::Wikipedia,
+        #[cfg(feature = "css-names")]
+        Palette::Css,
+        #[cfg(feature = "x11")]
+        Palette::X11,
+    ];
+
+    /// Human-readable name of the palette.
+    pub fn name(self) -> &'static str {
+        match self {
+            #[cfg(feature = "wikipedia")]
+            Palette::Wikipedia => "Wikipedia",
+            #[cfg(feature = "css-names")]
+            Palette::Css => "CSS",
+            #[cfg(feature = "x11")]
+            Palette::X11 => "X11",
+        }
+    }
+
+    pub(crate) fn table(self) -> &'static Table {
+        match self {
+            #[cfg(feature = "wikipedia")]
+            Palette::Wikipedia => &WIKIPEDIA,
+            #[cfg(feature = "css-names")]
+            Palette::Css => &CSS,
+            #[cfg(feature = "x11")]
+            Palette::X11 => &X11,
+        }
+    }
+
+    /// Look up a color in this palette by (reasonably forgiving) name.
+    ///
+    /// Names that aren't in the palette are tried as aliases, so "dk grey"
+    /// finds the same color as "dark gray".
+    pub fn get(self, name: &str) -> Option<Color> {
+        let key = canonical(name);
+        self.primary(&key).or_else(|| self.alias(&key))
+    }
+
+    /// Every color in this palette going by `name`; see [`color_all`](crate::color_all).
+    pub fn get_all(self, name: &str) -> Vec<Color> {
+        let key = canonical(name);
+        let table = self.table();
+        match table.variants.get(&key) {
+            Some(keys) => keys.iter().map(|key| table.colors[key].clone()).collect(),
+            None => self.get(name).into_iter().collect(),
+        }
+    }
+
+    // The entry keyed `key` exactly
+    pub(crate) fn primary(self, key: &str) -> Option<Color> {
+        self.table().colors.get(key).cloned()
+    }
+
+    // The entry `key` is an alias of
+    pub(crate) fn alias(self, key: &str) -> Option<Color> {
+        let table = self.table();
+        table
+            .aliases
+            .get(key)
+            .map(|primary| table.colors[primary].clone())
+    }
+
+    /// Number of colors in this palette.
+    #[inline]
+    pub fn len(self) -> usize {
+        self.table().colors.len()
+    }
+
+    /// Whether this palette has no colors at all.
+    #[inline]
+    pub fn is_empty(self) -> bool {
+        self.len() == 0
+    }
+
+    /// Every color in this palette; see [`all`](crate::all).
+    pub fn all(self) -> Colors {
+        Colors::new(self.table(), self.table().by_name)
+    }
+
+    /// The colors in `section` of this palette; see [`section`](crate::section).
+    pub fn section(self, section: Section) -> Colors {
+        crate::iter::section_in(self.table(), section)
+    }
+
+    /// The colors starting with `letter`; see [`starting_with`](crate::starting_with).
+    pub fn starting_with(self, letter: char) -> Colors {
+        crate::iter::starting_with_in(self.table(), letter)
+    }
+
+    /// Colors whose name starts with `prefix`; see [`complete`](crate::complete).
+    pub fn complete(self, prefix: &str) -> Colors {
+        crate::complete::complete_in(self.table(), prefix)
+    }
+
+    /// Colors whose name contains the words in `query`; see [`containing`](crate::containing).
+    pub fn containing(self, query: &str) -> Vec<Color> {
+        crate::complete::containing_in(self.table(), query)
+    }
+
+    /// Best fuzzy matches for `query`; see [`search`](crate::search).
+    pub fn search(self, query: &str, limit: usize) -> Vec<Match> {
+        crate::search::search_in(self.table(), query, limit)
+    }
+
+    /// "Did you mean" candidates for `query`; see [`suggest`](crate::suggest).
+    pub fn suggest(self, query: &str) -> Vec<Color> {
+        crate::search::suggest_in(self.table(), query)
+    }
+
+    /// The colors of hue `family` in this palette; see [`family`](crate::family()).
+    pub fn family(self, family: Family) -> Colors {
+        crate::family::family_in(self.table(), family)
+    }
+
+    /// The colors of hue `family` in this palette with the given lightness
+    /// and chroma; see [`family_in_band`](crate::family_in_band).
+    pub fn family_in_band(self, family: Family, lightness: Lightness, chroma: Chroma) -> Colors {
+        let keys = self.table().bands[family as usize][lightness as usize][chroma as usize];
+        Colors::new(self.table(), keys)
+    }
+
+    /// The color in this palette closest to `rgb`; see [`nearest`](crate::nearest).
+    pub fn nearest(self, rgb: (u8, u8, u8)) -> Color {
+        crate::nearest::nearest_k_in(self.table(), rgb, 1).swap_remove(0)
+    }
+
+    /// The `k` colors in this palette closest to `rgb`; see [`nearest_k`](crate::nearest_k).
+    pub fn nearest_k(self, rgb: (u8, u8, u8), k: usize) -> Vec<Color> {
+        crate::nearest::nearest_k_in(self.table(), rgb, k)
+    }
+}
+
+/// The first enabled palette in [`Palette::ALL`] order: Wikipedia with the
+/// default features.
+///
+/// This is the palette behind [`all`](crate::all), [`search`](crate::search),
+/// [`nearest`](crate::nearest) and the other crate-level functions.
+impl Default for Palette {
+    fn default() -> Self {
+        Palette::ALL[0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod tables {
+        use super::*;
+
+        #[test]
+        fn test_indexes_agree_with_maps() {
+            for &palette in Palette::ALL {
+                let table = palette.table();
+                assert!(!palette.is_empty());
+                assert_eq!(table.by_name.len(), palette.len(), "{palette:?}");
+                assert_eq!(table.keys.len(), palette.len(), "{palette:?}");
+                assert_eq!(table.nearest.len(), palette.len(), "{palette:?}");
+                assert!(table.keys.iter().all(|k| table.colors.contains_key(k)));
+            }
+        }
+
+        #[test]
+        fn test_aliases_agree_with_colors() {
+            for &palette in Palette::ALL {
+                let table = palette.table();
+                for (alias, key) in table.aliases.entries() {
+                    assert!(!table.colors.contains_key(alias), "{alias}");
+                    assert!(table.colors[key].aliases.contains(alias), "{alias}");
+                }
+                let listed: usize = table.colors.values().map(|c| c.aliases.len()).sum();
+                assert_eq!(listed, table.aliases.len(), "{palette:?}");
+            }
+        }
+
+        #[test]
+        fn test_variants_point_at_colors() {
+            for &palette in Palette::ALL {
+                let table = palette.table();
+                for (base, keys) in table.variants.entries() {
+                    assert!(!keys.is_empty(), "{base}");
+                    for key in keys.iter() {
+                        let name = &table.colors[key].name;
+                        assert!(canonical(name).starts_with(base), "{base} {name}");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_default_is_first_enabled() {
+            assert_eq!(Palette::default(), Palette::ALL[0]);
+            #[cfg(feature = "wikipedia")]
+            assert_eq!(Palette::default(), Palette::Wikipedia);
+        }
+
+        #[test]
+        #[cfg(all(feature = "wikipedia", feature = "css-names", feature = "x11"))]
+        fn test_sizes() {
+            assert_eq!(Palette::Css.len(), 148);
+            assert_eq!(Palette::Wikipedia.len(), COLORS.len());
+            assert!(Palette::X11.len() > 500);
+        }
+
+        #[test]
+        #[cfg(all(feature = "wikipedia", feature = "css-names", feature = "x11"))]
+        fn test_names() {
+            assert_eq!(
+                Palette::ALL,
+                [Palette::Wikipedia, Palette::Css, Palette::X11]
+            );
+            assert_eq!(Palette::Wikipedia.name(), "Wikipedia");
+            assert_eq!(Palette::Css.name(), "CSS");
+            assert_eq!(Palette::X11.name(), "X11");
+        }
+    }
+
+    #[cfg(all(feature = "wikipedia", feature = "css-names", feature = "x11"))]
+    mod lookup {
+        use super::*;
+
+        #[test]
+        fn test_palettes_disagree_where_expected() {
+            let hex = |p: Palette, name| p.get(name).unwrap().hex().to_owned();
+
+            assert_eq!(hex(Palette::Wikipedia, "azure"), "#007FFF");
+            assert_eq!(hex(Palette::Css, "azure"), "#F0FFFF");
+            assert_eq!(hex(Palette::X11, "azure"), "#F0FFFF");
+
+            assert_eq!(hex(Palette::Css, "gray"), "#808080");
+            assert_eq!(hex(Palette::X11, "gray"), "#BEBEBE");
+            assert_eq!(hex(Palette::Css, "green"), "#008000");
+            assert_eq!(hex(Palette::X11, "green"), "#00FF00");
+        }
+
+        #[test]
+        fn test_css_keywords() {
+            let rebecca = Palette::Css.get("rebeccapurple").unwrap();
+            assert_eq!(rebecca.name(), "rebeccapurple");
+            assert_eq!(rebecca.rgb(), (102, 51, 153));
+            assert_eq!(
+                Palette::Css.get("grey").unwrap().rgb(),
+                Palette::Css.get("gray").unwrap().rgb()
+            );
+            assert_eq!(
+                Palette::Css.get("darkslategrey").unwrap().rgb(),
+                Palette::Css.get("darkslategray").unwrap().rgb()
+            );
+            assert!(Palette::Css.get("Absolute Zero").is_none());
+        }
+
+        #[test]
+        fn test_x11_spellings_share_an_entry() {
+            assert_eq!(
+                Palette::X11.get("alice blue"),
+                Palette::X11.get("AliceBlue")
+            );
+            assert_eq!(Palette::X11.get("snow3").unwrap().hex(), "#CDC9C9");
+            assert_eq!(Palette::X11.get("grey50").unwrap().rgb(), (127, 127, 127));
+        }
+
+        #[test]
+        fn test_sources() {
+            let source = |p: Palette, name| p.get(name).unwrap().source();
+
+            assert_eq!(
+                source(Palette::Wikipedia, "Absolute Zero").list(),
+                "List of colors: A–F"
+            );
+            assert_eq!(
+                source(Palette::Wikipedia, "Gold (web) (Golden)").variant(),
+                Some("web, Golden")
+            );
+            assert_eq!(
+                source(Palette::Wikipedia, "Olive Drab #7").variant(),
+                Some("#7")
+            );
+            assert_eq!(
+                source(Palette::Wikipedia, "Zomp").list(),
+                "List of colors: N–Z"
+            );
+            assert_eq!(
+                source(Palette::Css, "tomato").list(),
+                "CSS Color Module Level 4"
+            );
+            assert_eq!(source(Palette::X11, "snow3").list(), "X11 rgb.txt");
+            assert_eq!(source(Palette::X11, "snow3").variant(), None);
+        }
+
+        #[test]
+        fn test_color_all_spans_palettes() {
+            let azures = crate::color_all("azure");
+            let lists: Vec<_> = azures.iter().map(|c| c.source().list()).collect();
+            assert_eq!(
+                lists,
+                [
+                    "List of colors: A–F",
+                    "List of colors: A–F",
+                    "CSS Color Module Level 4",
+                    "X11 rgb.txt"
+                ]
+            );
+            assert_eq!(azures[0].hex(), "#007FFF");
+            assert_eq!(azures[1].source().variant(), Some("X11/web color"));
+            assert_eq!(
+                Palette::Css.get_all("azure"),
+                [Palette::Css.get("azure").unwrap()]
+            );
+        }
+
+        #[test]
+        fn test_aliases_per_palette() {
+            assert_eq!(Palette::Css.get("dk grey").unwrap().hex(), "#A9A9A9");
+            assert_eq!(Palette::Css.get("lt green"), Palette::Css.get("lightgreen"));
+            assert_eq!(
+                Palette::X11.get("med blue"),
+                Palette::X11.get("medium blue")
+            );
+            assert_eq!(Palette::Css.get("fushia").unwrap().name(), "fuchsia");
+        }
+
+        #[test]
+        fn test_color_resolves_each_palette_before_the_next() {
+            // "darkslategrey" is a Wikipedia alias but a CSS key; Wikipedia
+            // is searched first, aliases included
+            assert!(
+                Palette::Wikipedia
+                    .get("Dark slate gray")
+                    .unwrap()
+                    .aliases()
+                    .contains(&"darkslategrey")
+            );
+            assert_eq!(
+                crate::color("dark slate grey"),
+                Palette::Wikipedia.get("dark slate gray")
+            );
+            assert_eq!(
+                crate::color("Dark slate grey"),
+                crate::color("Dark slate gray")
+            );
+            assert_eq!(
+                crate::color("dk slate grey"),
+                Palette::Wikipedia.get("dark slate gray")
+            );
+        }
+
+        #[test]
+        fn test_color_searches_in_priority_order() {
+            // Wikipedia wins where it has the name...
+            assert_eq!(crate::color("azure").unwrap().hex(), "#007FFF");
+            assert_eq!(crate::color("Zomp"), Palette::Wikipedia.get("Zomp"));
+            // ...then CSS, then X11
+            assert_eq!(crate::color("whitesmoke"), Palette::Css.get("whitesmoke"));
+            assert_eq!(crate::color("snow3"), Palette::X11.get("snow3"));
+            assert_eq!(crate::color("nope"), None);
+        }
+    }
+
+    #[cfg(all(feature = "css-names", feature = "x11"))]
+    mod per_palette_queries {
+        use super::*;
+
+        #[test]
+        fn test_all_and_sections() {
+            let css: Vec<_> = Palette::Css.all().map(|c| c.name().to_owned()).collect();
+            assert_eq!(css.first().map(String::as_str), Some("aliceblue"));
+            assert_eq!(css.last().map(String::as_str), Some("yellowgreen"));
+            assert_eq!(css.len(), Palette::Css.len());
+            assert!(
+                Palette::Css
+                    .starting_with('r')
+                    .any(|c| c.name() == "rebeccapurple")
+            );
+            let total: usize = Section::ALL
+                .iter()
+                .map(|&s| Palette::X11.section(s).len())
+                .sum();
+            assert_eq!(total, Palette::X11.len());
+        }
+
+        #[test]
+        fn test_complete_and_containing() {
+            let names: Vec<_> = Palette::Css
+                .complete("darkslate")
+                .map(|c| c.name().to_owned())
+                .collect();
+            assert_eq!(names, ["darkslateblue", "darkslategray", "darkslategrey"]);
+            assert!(
+                Palette::X11
+                    .containing("slate")
+                    .iter()
+                    .any(|c| c.name() == "dark slate gray")
+            );
+        }
+
+        #[test]
+        fn test_search_and_nearest() {
+            assert_eq!(
+                Palette::Css.suggest("rebecapurple")[0].name(),
+                "rebeccapurple"
+            );
+            assert_eq!(
+                Palette::Css.search("tomatoe", 1)[0].color().name(),
+                "tomato"
+            );
+            assert_eq!(Palette::Css.nearest((101, 52, 150)).name(), "rebeccapurple");
+            assert_eq!(Palette::X11.nearest_k((255, 250, 250), 1)[0].name(), "snow");
+        }
+    }
+}
@@ -0,0 +1,1141 @@
+//! One parser for every way of writing a color.
+//!
+//! [`parse`] takes color names and the color syntaxes of CSS Color Module
+//! Level 4, and returns a [`ParsedColor`] that remembers which one it was
+//! given, so an `hsl()` stays an [`Hsl`] until it's converted. When the
+//! input isn't a color, the [`ParseColorError`] says what is wrong and
+//! where.
+//!
+//! Parsing follows CSS: function names, units and keywords are
+//! case-insensitive, `none` is zero, and components out of range are
+//! clamped rather than rejected. Both the modern space-separated syntax
+//! (`rgb(255 0 0 / 50%)`) and the legacy comma-separated one
+//! (`rgba(255, 0, 0, 0.5)`) are accepted for `rgb()` and `hsl()`.
+//!
+//! [`parse_strict`] takes the same syntax but reports what CSS would
+//! clamp: a channel of 300, a saturation of 120% or an `rgb()` channel
+//! with a fraction, which an 8-bit channel can't hold, are
+//! [`OutOfRange`](ParseColorErrorKind::OutOfRange) errors instead. The
+//! color types implement [`FromStr`] with it, so
+//! `"hsl(270 50% 40%)".parse::<Rgb>()` works too, and a config value like
+//! `rgb(300, 0, 0)` is an error rather than a quiet red.
+
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::ansi::Ansi;
+use crate::{Color, Hsl, Hsv, Hwb, Lab, Lch, Oklab, Oklch, Rgb, Rgba, Xyz};
+
+/// A parsed color, in the model it was written in.
+///
+/// Every model except [`Named`](ParsedColor::Named) carries an alpha as
+/// well, opaque at 255. CSS `lab()` and `lch()` are relative to the D50
+/// white point and are adapted to the D65 [`Lab`] and [`Lch`] used here.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParsedColor {
+    /// A name from the built-in color tables.
+    Named(Color),
+    /// Hex codes, `rgb()`, `rgba()`, `color(srgb …)` and `transparent`.
+    Rgb(Rgba),
+    /// `hsl()` and `hsla()`.
+    Hsl(Hsl, u8),
+    /// `hwb()`.
+    Hwb(Hwb, u8),
+    /// `lab()`.
+    Lab(Lab, u8),
+    /// `lch()`.
+    Lch(Lch, u8),
+    /// `oklab()`.
+    Oklab(Oklab, u8),
+    /// `oklch()`.
+    Oklch(Oklch, u8),
+}
+
+/// Why a string isn't a color, where, and the string itself.
+///
+/// # Examples
+///
+/// ```
+/// use pigment::ParseColorErrorKind;
+///
+/// let err = pigment::parse("rgb(255 0 zero)").unwrap_err();
+/// assert_eq!(err.kind(), ParseColorErrorKind::InvalidNumber);
+/// assert_eq!(err.offset(), 10);
+/// assert_eq!(err.to_string(), "invalid number \"zero\" at byte 10 of \"rgb(255 0 zero)\"");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError {
+    kind: ParseColorErrorKind,
+    offset: usize,
+    len: usize,
+    input: String,
+}
+
+/// The ways a string can fail to be a color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseColorErrorKind {
+    /// The input is empty or only whitespace.
+    Empty,
+    /// Not a color name, hex code or color function.
+    UnknownColor,
+    /// A character in a hex code that isn't a hex digit, including a
+    /// second `#`.
+    InvalidHexDigit,
+    /// A hex code without 3, 4, 6 or 8 digits.
+    InvalidHexLength,
+    /// A color function without its closing parenthesis.
+    UnclosedFunction,
+    /// Something after the closing parenthesis of a color function.
+    TrailingCharacters,
+    /// `color()` with a color space other than `srgb`.
+    UnsupportedColorSpace,
+    /// A color function without three components, or with anything but a
+    /// single alpha after the `/`.
+    ComponentCount,
+    /// Commas mixed with spaces or `/`, or in a function that only takes
+    /// spaces.
+    InvalidSeparator,
+    /// A component that isn't a number, percentage, angle or `none`.
+    InvalidNumber,
+    /// A percentage or angle where the component doesn't allow one.
+    InvalidUnit,
+    /// A component outside the range its position allows, or an `rgb()`
+    /// channel with a fraction; only [`parse_strict`] reports these.
+    OutOfRange,
+}
+
+impl ParseColorError {
+    #[inline]
+    pub fn kind(&self) -> ParseColorErrorKind {
+        self.kind
+    }
+
+    /// Byte offset into [`input`](Self::input) of the part that is wrong.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The whole input, as given.
+    #[inline]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// The part of the input that is wrong; empty when something is missing.
+    pub fn fragment(&self) -> &str {
+        &self.input[self.offset..self.offset + self.len]
+    }
+}
+
+impl ParseColorErrorKind {
+    fn describe(self) -> &'static str {
+        match self {
+            ParseColorErrorKind::Empty => "empty color",
+            ParseColorErrorKind::UnknownColor => "unknown color",
+            ParseColorErrorKind::InvalidHexDigit => "invalid hex digit",
+            ParseColorErrorKind::InvalidHexLength => "hex code needs 3, 4, 6 or 8 digits, not",
+            ParseColorErrorKind::UnclosedFunction => "missing `)` for",
+            ParseColorErrorKind::TrailingCharacters => "unexpected characters",
+            ParseColorErrorKind::UnsupportedColorSpace => "unsupported color space",
+            ParseColorErrorKind::ComponentCount => "wrong number of components in",
+            ParseColorErrorKind::InvalidSeparator => "unexpected separator",
+            ParseColorErrorKind::InvalidNumber => "invalid number",
+            ParseColorErrorKind::InvalidUnit => "unit not allowed here:",
+            ParseColorErrorKind::OutOfRange => "component out of range",
+        }
+    }
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.kind == ParseColorErrorKind::Empty {
+            return f.write_str(self.kind.describe());
+        }
+        write!(
+            f,
+            "{} {:?} at byte {} of {:?}",
+            self.kind.describe(),
+            self.fragment(),
+            self.offset,
+            self.input
+        )
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+/// Parse a color name, hex code or CSS color function.
+///
+/// Accepts:
+/// - names from the enabled color tables, matched like [`color`](crate::color())
+/// - `#RGB`, `#RGBA`, `#RRGGBB` and `#RRGGBBAA` hex codes, with or without the `#`
+/// - `rgb()` and `rgba()`, with numbers or percentages
+/// - `hsl()`, `hsla()` and `hwb()`, with the hue in degrees or an angle unit
+/// - `lab()`, `lch()`, `oklab()` and `oklch()`
+/// - `color(srgb r g b)`, with channels from 0 to 1
+/// - `transparent`
+///
+/// Names are tried before bare hex codes, so a name that happens to be
+/// valid hex still means the named color. Parentheses must be part of the
+/// name, as in "Blue (Crayola)": `red(` is an unclosed function, not red.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "wikipedia")] {
+/// use pigment::{ParseColorErrorKind, ParsedColor, Rgb, Rgba};
+///
+/// let purple = Rgb::new(0x66, 0x33, 0x99);
+/// for css in [
+///     "#663399",
+///     "rgb(102 51 153)",
+///     "rgba(102, 51, 153, 1)",
+///     "hsl(270deg 50% 40%)",
+///     "hwb(270 20% 40%)",
+///     "color(srgb 0.4 0.2 0.6)",
+/// ] {
+///     assert_eq!(pigment::parse(css).unwrap().rgb(), purple, "{css}");
+/// }
+///
+/// let glass = pigment::parse("rgb(0 72 186 / 50%)").unwrap();
+/// assert_eq!(glass, ParsedColor::Rgb(Rgba::new(0, 72, 186, 128)));
+///
+/// let zero = pigment::parse("Absolute Zero").unwrap();
+/// assert!(matches!(zero, ParsedColor::Named(c) if c.hex() == "#0048BA"));
+///
+/// let err = pigment::parse("#0048BG").unwrap_err();
+/// assert_eq!((err.kind(), err.offset()), (ParseColorErrorKind::InvalidHexDigit, 6));
+/// # }
+/// ```
+pub fn parse(input: &str) -> Result<ParsedColor, ParseColorError> {
+    parse_with(input, false)
+}
+
+/// Like [`parse`], but rejecting components out of range rather than
+/// clamping them.
+///
+/// `rgb()` channels must be whole numbers from 0 to 255 or percentages
+/// from 0% to 100%, alphas from 0 to 1, and saturation, lightness,
+/// whiteness, blackness and chroma within their CSS ranges. Hues may
+/// still go round any number of times.
+///
+/// # Examples
+///
+/// ```
+/// use pigment::{ParseColorErrorKind, Rgb};
+///
+/// assert_eq!(pigment::parse("rgb(300, 0, 0)").unwrap().rgb(), Rgb::new(255, 0, 0));
+///
+/// let err = pigment::parse_strict("rgb(300, 0, 0)").unwrap_err();
+/// assert_eq!((err.kind(), err.fragment()), (ParseColorErrorKind::OutOfRange, "300"));
+/// let err = "rgb(1.5, 0, 0)".parse::<Rgb>().unwrap_err();
+/// assert_eq!(err.to_string(), "component out of range \"1.5\" at byte 4 of \"rgb(1.5, 0, 0)\"");
+/// ```
+pub fn parse_strict(input: &str) -> Result<ParsedColor, ParseColorError> {
+    parse_with(input, true)
+}
+
+fn parse_with(input: &str, strict: bool) -> Result<ParsedColor, ParseColorError> {
+    let p = Parser { input, strict };
+    let s = input.trim();
+    if s.is_empty() {
+        return Err(p.error(ParseColorErrorKind::Empty, s));
+    }
+    if s.starts_with('#') {
+        return p.hex(s).map(ParsedColor::Rgb);
+    }
+    if let Some((name, args)) = function(s) {
+        return p.function(&name, args);
+    }
+    if s.eq_ignore_ascii_case("transparent") {
+        return Ok(ParsedColor::Rgb(Rgba::new(0, 0, 0, 0)));
+    }
+    match p.name(s) {
+        Ok(c) => return Ok(ParsedColor::Named(c)),
+        Err(e) if e.kind != ParseColorErrorKind::UnknownColor => return Err(e),
+        Err(_) => {}
+    }
+    match p.hex(s) {
+        Ok(c) => Ok(ParsedColor::Rgb(c)),
+        // Only something that looks like hex gets a hex error
+        Err(e) if s.chars().all(|c| c.is_ascii_hexdigit()) => Err(e),
+        Err(_) => Err(p.error(ParseColorErrorKind::UnknownColor, s)),
+    }
+}
+
+// The hex code rules of `Rgba::from_hex`, with the reason they aren't met
+pub(crate) fn parse_hex(input: &str) -> Result<Rgba, ParseColorError> {
+    Parser {
+        input,
+        strict: true,
+    }
+    .hex(input)
+}
+
+// The rules of `Rgba::from_rgb_str` and `Ansi::from_rgb_str`: three whole
+// channels from 0 to 255 and an optional alpha, as a fourth value or after
+// a `/`, separated by any mix of commas and spaces, optionally inside
+// `rgb()` or `rgba()`
+pub(crate) fn parse_rgb(input: &str) -> Result<Rgba, ParseColorError> {
+    let p = Parser {
+        input,
+        strict: true,
+    };
+    let s = input.trim();
+    if s.is_empty() {
+        return Err(p.error(ParseColorErrorKind::Empty, s));
+    }
+    let args = match function(s) {
+        Some((name, rest)) if name == "rgb" || name == "rgba" => {
+            let Some(close) = rest.rfind(')') else {
+                let call = &s[..s.len() - rest.len()];
+                return Err(p.error(ParseColorErrorKind::UnclosedFunction, call));
+            };
+            let trailing = &rest[close + 1..];
+            if !trailing.is_empty() {
+                return Err(p.error(ParseColorErrorKind::TrailingCharacters, trailing));
+            }
+            &rest[..close]
+        }
+        Some(_) => return Err(p.error(ParseColorErrorKind::UnknownColor, s)),
+        None => s,
+    };
+    let (channels, alpha) = match args.split_once('/') {
+        Some((channels, alpha)) => (channels, Some(alpha)),
+        None => (args, None),
+    };
+    let mut channels = p.loose(channels)?;
+    let alpha = match alpha {
+        Some(alpha) => match p.loose(alpha)?[..] {
+            [a] => Some(a),
+            _ => return Err(p.error(ParseColorErrorKind::ComponentCount, alpha)),
+        },
+        None if channels.len() == 4 => channels.pop(),
+        None => None,
+    };
+    let [r, g, b] = channels[..] else {
+        return Err(p.error(ParseColorErrorKind::ComponentCount, args));
+    };
+    for c in [r, g, b] {
+        match c.value {
+            Value::Number(_) => {}
+            Value::None => return Err(p.error(ParseColorErrorKind::InvalidNumber, c.token)),
+            _ => return Err(p.error(ParseColorErrorKind::InvalidUnit, c.token)),
+        }
+    }
+    let alpha = match alpha {
+        Some(a) => p.alpha(a)?,
+        None => u8::MAX,
+    };
+    p.rgba([r, g, b], 255.0, alpha)
+}
+
+impl ParsedColor {
+    /// The color as 8-bit sRGB with alpha, clipped to the sRGB gamut.
+    pub fn rgba(&self) -> Rgba {
+        match *self {
+            ParsedColor::Named(ref c) => Rgba::from(c),
+            ParsedColor::Rgb(c) => c,
+            ParsedColor::Hsl(c, a) => Rgb::from(c).with_alpha(a),
+            ParsedColor::Hwb(c, a) => Rgb::from(c).with_alpha(a),
+            ParsedColor::Lab(c, a) => Rgb::from(c).with_alpha(a),
+            ParsedColor::Lch(c, a) => Rgb::from(c).with_alpha(a),
+            ParsedColor::Oklab(c, a) => Rgb::from(c).with_alpha(a),
+            ParsedColor::Oklch(c, a) => Rgb::from(c).with_alpha(a),
+        }
+    }
+
+    /// The color as 8-bit sRGB, ignoring the alpha.
+    #[inline]
+    pub fn rgb(&self) -> Rgb {
+        self.rgba().rgb()
+    }
+
+    /// The alpha, 255 when opaque.
+    pub fn alpha(&self) -> u8 {
+        match *self {
+            ParsedColor::Named(_) => 255,
+            ParsedColor::Rgb(c) => c.a,
+            ParsedColor::Hsl(_, a)
+            | ParsedColor::Hwb(_, a)
+            | ParsedColor::Lab(_, a)
+            | ParsedColor::Lch(_, a)
+            | ParsedColor::Oklab(_, a)
+            | ParsedColor::Oklch(_, a) => a,
+        }
+    }
+}
+
+impl From<ParsedColor> for Rgba {
+    fn from(c: ParsedColor) -> Self {
+        c.rgba()
+    }
+}
+
+/// Drops the alpha.
+impl From<ParsedColor> for Rgb {
+    fn from(c: ParsedColor) -> Self {
+        c.rgb()
+    }
+}
+
+/// Drops the alpha.
+impl From<ParsedColor> for Ansi {
+    fn from(c: ParsedColor) -> Self {
+        c.rgb().into()
+    }
+}
+
+impl FromStr for ParsedColor {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_strict(s)
+    }
+}
+
+/// A built-in color by name, like [`color`](crate::color()); hex codes
+/// and color functions aren't names.
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let p = Parser {
+            input: s,
+            strict: true,
+        };
+        let name = s.trim();
+        if name.is_empty() {
+            return Err(p.error(ParseColorErrorKind::Empty, name));
+        }
+        p.name(name)
+    }
+}
+
+// `FromStr` through `parse_strict`, keeping a color written in the type's own
+// model exact and converting the rest through 8-bit sRGB; the alpha of
+// types without one is dropped
+macro_rules! from_str {
+    ($($model:ident $(=> $variant:ident)?),+) => {
+        $(impl FromStr for $model {
+            type Err = ParseColorError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let parsed = parse_strict(s)?;
+                $(if let ParsedColor::$variant(c, _) = parsed {
+                    return Ok(c);
+                })?
+                Ok(parsed.rgb().into())
+            }
+        })+
+    };
+}
+
+from_str!(Rgb, Ansi, Hsl => Hsl, Hsv, Hwb => Hwb, Xyz, Lab => Lab, Lch => Lch, Oklab => Oklab, Oklch => Oklch);
+
+impl FromStr for Rgba {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_strict(s).map(Rgba::from)
+    }
+}
+
+// A component as written, before it's scaled for its position
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Value {
+    Number(f64),
+    Percent(f64),
+    /// In degrees.
+    Angle(f64),
+    None,
+}
+
+// A value and the token it was parsed from, for errors
+#[derive(Debug, Clone, Copy)]
+struct Component<'a> {
+    value: Value,
+    token: &'a str,
+}
+
+// Split `name(args)` into the lowercase name and everything after the
+// `(`, for the names of color functions only: color names can contain
+// parentheses too
+fn function(s: &str) -> Option<(String, &str)> {
+    let open = s.find('(')?;
+    let name = s[..open].trim_end().to_ascii_lowercase();
+    const FUNCTIONS: &[&str] = &[
+        "rgb", "rgba", "hsl", "hsla", "hwb", "lab", "lch", "oklab", "oklch", "color",
+    ];
+    if !FUNCTIONS.contains(&name.as_str()) {
+        return None;
+    }
+    Some((name, &s[open + 1..]))
+}
+
+// Errors point into `input`; every `&str` they're given is a slice of it
+struct Parser<'a> {
+    input: &'a str,
+    // Report components out of range instead of clamping them
+    strict: bool,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, kind: ParseColorErrorKind, at: &str) -> ParseColorError {
+        let offset = at.as_ptr() as usize - self.input.as_ptr() as usize;
+        ParseColorError {
+            kind,
+            offset,
+            len: at.len(),
+            input: self.input.to_string(),
+        }
+    }
+
+    // A color by name. `canonical` drops punctuation, so parentheses must
+    // be balanced and belong to the name found, as in "Blue (Crayola)",
+    // rather than be a call like `red(`
+    fn name(&self, s: &'a str) -> Result<Color, ParseColorError> {
+        let unknown = || self.error(ParseColorErrorKind::UnknownColor, s);
+        if !s.contains(['(', ')']) {
+            return crate::color(s).ok_or_else(unknown);
+        }
+        let mut depth = 0usize;
+        for c in s.chars() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth = depth.checked_sub(1).ok_or_else(unknown)?,
+                _ => {}
+            }
+        }
+        if depth > 0 {
+            let open = s.rfind('(').unwrap();
+            return Err(self.error(ParseColorErrorKind::UnclosedFunction, &s[..open + 1]));
+        }
+        crate::color(s)
+            .filter(|c| c.name().contains('('))
+            .ok_or_else(unknown)
+    }
+
+    fn hex(&self, s: &'a str) -> Result<Rgba, ParseColorError> {
+        let digits = s.strip_prefix('#').unwrap_or(s);
+        if let Some((i, c)) = digits.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+            let bad = &digits[i..i + c.len_utf8()];
+            return Err(self.error(ParseColorErrorKind::InvalidHexDigit, bad));
+        }
+        let width = match digits.len() {
+            3 | 4 => 1,
+            6 | 8 => 2,
+            _ => return Err(self.error(ParseColorErrorKind::InvalidHexLength, s)),
+        };
+        let channel = |i: usize| {
+            let value = u8::from_str_radix(&digits[i * width..(i + 1) * width], 16).unwrap();
+            if width == 1 { value * 17 } else { value }
+        };
+        let a = if matches!(digits.len(), 4 | 8) {
+            channel(3)
+        } else {
+            u8::MAX
+        };
+        Ok(Rgba::new(channel(0), channel(1), channel(2), a))
+    }
+
+    fn function(&self, name: &str, rest: &'a str) -> Result<ParsedColor, ParseColorError> {
+        let Some(close) = rest.rfind(')') else {
+            // Point at the function name and its `(`
+            let start = self.input.len() - self.input.trim_start().len();
+            let call = &self.input[start..self.input.len() - rest.len()];
+            return Err(self.error(ParseColorErrorKind::UnclosedFunction, call));
+        };
+        let trailing = &rest[close + 1..];
+        if !trailing.is_empty() {
+            return Err(self.error(ParseColorErrorKind::TrailingCharacters, trailing));
+        }
+        let mut args = &rest[..close];
+
+        if name == "color" {
+            // Only the sRGB space, as `color(srgb r g b)`
+            let space = args.split_whitespace().next().unwrap_or(&args[..0]);
+            if !space.eq_ignore_ascii_case("srgb") {
+                return Err(self.error(ParseColorErrorKind::UnsupportedColorSpace, space));
+            }
+            args = &args[args.find(space).unwrap() + space.len()..];
+        }
+
+        let legacy = matches!(name, "rgb" | "rgba" | "hsl" | "hsla");
+        let ([x, y, z], alpha) = if legacy && args.contains(',') {
+            self.commas(args)?
+        } else {
+            self.modern(args)?
+        };
+        let alpha = match alpha {
+            Some(a) => self.alpha(a)?,
+            None => 255,
+        };
+
+        Ok(match name {
+            "rgb" | "rgba" => ParsedColor::Rgb(self.rgba([x, y, z], 255.0, alpha)?),
+            "color" => ParsedColor::Rgb(self.rgba([x, y, z], 1.0, alpha)?),
+            "hsl" | "hsla" => ParsedColor::Hsl(
+                Hsl::new(self.hue(x)?, self.fraction(y)?, self.fraction(z)?),
+                alpha,
+            ),
+            "hwb" => ParsedColor::Hwb(
+                Hwb::new(self.hue(x)?, self.fraction(y)?, self.fraction(z)?),
+                alpha,
+            ),
+            "lab" => ParsedColor::Lab(
+                Lab::from_d50([
+                    self.bounded(x, 100.0, 0.0..=100.0)?,
+                    self.scale(y, 125.0)?,
+                    self.scale(z, 125.0)?,
+                ]),
+                alpha,
+            ),
+            "lch" => ParsedColor::Lch(
+                Lch::from_d50([
+                    self.bounded(x, 100.0, 0.0..=100.0)?,
+                    self.bounded(y, 150.0, 0.0..=f64::INFINITY)?,
+                    self.hue(z)? as f64,
+                ]),
+                alpha,
+            ),
+            "oklab" => ParsedColor::Oklab(
+                Oklab::new(
+                    self.bounded(x, 1.0, 0.0..=1.0)? as f32,
+                    self.scale(y, 0.4)? as f32,
+                    self.scale(z, 0.4)? as f32,
+                ),
+                alpha,
+            ),
+            _ => ParsedColor::Oklch(
+                Oklch::new(
+                    self.bounded(x, 1.0, 0.0..=1.0)? as f32,
+                    self.bounded(y, 0.4, 0.0..=f64::INFINITY)? as f32,
+                    self.hue(z)?,
+                ),
+                alpha,
+            ),
+        })
+    }
+
+    // `a, b, c` or `a, b, c, alpha`
+    #[allow(clippy::type_complexity)]
+    fn commas(
+        &self,
+        args: &'a str,
+    ) -> Result<([Component<'a>; 3], Option<Component<'a>>), ParseColorError> {
+        if let Some(slash) = args.find('/') {
+            return Err(self.error(
+                ParseColorErrorKind::InvalidSeparator,
+                &args[slash..slash + 1],
+            ));
+        }
+        let mut parts = Vec::with_capacity(4);
+        for part in args.split(',') {
+            let token = part.trim();
+            if let Some(space) = token.find(char::is_whitespace) {
+                let space = &token[space..space + 1];
+                return Err(self.error(ParseColorErrorKind::InvalidSeparator, space));
+            }
+            parts.push(self.component(token)?);
+        }
+        match parts[..] {
+            [x, y, z] => Ok(([x, y, z], None)),
+            [x, y, z, a] => Ok(([x, y, z], Some(a))),
+            _ => Err(self.error(ParseColorErrorKind::ComponentCount, args)),
+        }
+    }
+
+    // `a b c` or `a b c / alpha`
+    #[allow(clippy::type_complexity)]
+    fn modern(
+        &self,
+        args: &'a str,
+    ) -> Result<([Component<'a>; 3], Option<Component<'a>>), ParseColorError> {
+        if let Some(comma) = args.find(',') {
+            return Err(self.error(
+                ParseColorErrorKind::InvalidSeparator,
+                &args[comma..comma + 1],
+            ));
+        }
+        let (values, alpha) = match args.split_once('/') {
+            Some((values, alpha)) => (values, Some(alpha)),
+            None => (args, None),
+        };
+        let values = values
+            .split_whitespace()
+            .map(|token| self.component(token))
+            .collect::<Result<Vec<_>, _>>()?;
+        let [x, y, z] = values[..] else {
+            return Err(self.error(ParseColorErrorKind::ComponentCount, args));
+        };
+        let alpha = match alpha {
+            Some(alpha) => match alpha.split_whitespace().collect::<Vec<_>>()[..] {
+                [token] => Some(self.component(token)?),
+                _ => return Err(self.error(ParseColorErrorKind::ComponentCount, alpha)),
+            },
+            None => None,
+        };
+        Ok(([x, y, z], alpha))
+    }
+
+    // Components separated by any mix of commas and spaces
+    fn loose(&self, args: &'a str) -> Result<Vec<Component<'a>>, ParseColorError> {
+        args.split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|token| !token.is_empty())
+            .map(|token| self.component(token))
+            .collect()
+    }
+
+    fn component(&self, token: &'a str) -> Result<Component<'a>, ParseColorError> {
+        let invalid = || self.error(ParseColorErrorKind::InvalidNumber, token);
+        let lower = token.to_ascii_lowercase();
+        let value = if lower == "none" {
+            Value::None
+        } else if let Some(percent) = lower.strip_suffix('%') {
+            Value::Percent(number(percent).ok_or_else(invalid)?)
+        } else if let Some((angle, degrees)) = [
+            // "grad" before "rad", which it ends with
+            ("deg", 1.0),
+            ("grad", 0.9),
+            ("rad", 180.0 / std::f64::consts::PI),
+            ("turn", 360.0),
+        ]
+        .into_iter()
+        .find_map(|(unit, degrees)| Some((lower.strip_suffix(unit)?, degrees)))
+        {
+            Value::Angle(number(angle).ok_or_else(invalid)? * degrees)
+        } else {
+            Value::Number(number(&lower).ok_or_else(invalid)?)
+        };
+        Ok(Component { value, token })
+    }
+
+    // A number as is, or a percentage of `full`
+    fn scale(&self, c: Component, full: f64) -> Result<f64, ParseColorError> {
+        match c.value {
+            Value::Number(n) => Ok(n),
+            Value::Percent(p) => Ok(p / 100.0 * full),
+            Value::None => Ok(0.0),
+            Value::Angle(_) => Err(self.error(ParseColorErrorKind::InvalidUnit, c.token)),
+        }
+    }
+
+    // `scale`, and when strict, within `range`
+    fn bounded(
+        &self,
+        c: Component,
+        full: f64,
+        range: RangeInclusive<f64>,
+    ) -> Result<f64, ParseColorError> {
+        let x = self.scale(c, full)?;
+        if self.strict && !range.contains(&x) {
+            return Err(self.error(ParseColorErrorKind::OutOfRange, c.token));
+        }
+        Ok(x)
+    }
+
+    fn hue(&self, c: Component) -> Result<f32, ParseColorError> {
+        match c.value {
+            Value::Number(degrees) | Value::Angle(degrees) => Ok(degrees as f32),
+            Value::None => Ok(0.0),
+            Value::Percent(_) => Err(self.error(ParseColorErrorKind::InvalidUnit, c.token)),
+        }
+    }
+
+    // Saturation, lightness, whiteness and blackness: percentages, or
+    // numbers on the same 0–100 scale
+    fn fraction(&self, c: Component) -> Result<f32, ParseColorError> {
+        Ok((self.bounded(c, 100.0, 0.0..=100.0)? / 100.0) as f32)
+    }
+
+    fn alpha(&self, c: Component) -> Result<u8, ParseColorError> {
+        Ok((self.bounded(c, 1.0, 0.0..=1.0)?.clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
+
+    // 8-bit channels from values on a scale of 0 to `full`
+    fn rgba(
+        &self,
+        channels: [Component; 3],
+        full: f64,
+        alpha: u8,
+    ) -> Result<Rgba, ParseColorError> {
+        let channel = |c: Component| {
+            let x = self.bounded(c, full, 0.0..=full)? / full * 255.0;
+            // `rgb(127.5 0 0)` is fine CSS, but not an 8-bit channel
+            if self.strict
+                && full == 255.0
+                && matches!(c.value, Value::Number(n) if n.fract() != 0.0)
+            {
+                return Err(self.error(ParseColorErrorKind::OutOfRange, c.token));
+            }
+            Ok(x.round().clamp(0.0, 255.0) as u8)
+        };
+        let [r, g, b] = channels;
+        Ok(Rgba::new(channel(r)?, channel(g)?, channel(b)?, alpha))
+    }
+}
+
+// A CSS number: no `inf`, `nan` or other words Rust would parse
+fn number(s: &str) -> Option<f64> {
+    if !s.starts_with(|c: char| c.is_ascii_digit() || matches!(c, '.' | '+' | '-')) {
+        return None;
+    }
+    s.parse::<f64>().ok().filter(|n| n.is_finite())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rgba(s: &str) -> Option<(u8, u8, u8, u8)> {
+        parse(s).ok().map(|c| {
+            let c = c.rgba();
+            (c.r, c.g, c.b, c.a)
+        })
+    }
+
+    // The kind of error and the part of the input it points at
+    fn error(s: &str) -> (ParseColorErrorKind, &str) {
+        let err = parse(s).unwrap_err();
+        assert_eq!(err.input(), s);
+        (
+            err.kind(),
+            &s[err.offset()..err.offset() + err.fragment().len()],
+        )
+    }
+
+    mod hex {
+        use super::*;
+
+        #[test]
+        fn test_forms() {
+            assert_eq!(rgba("#0048BA"), Some((0, 72, 186, 255)));
+            assert_eq!(rgba("#0048ba80"), Some((0, 72, 186, 128)));
+            assert_eq!(rgba("#F00"), Some((255, 0, 0, 255)));
+            assert_eq!(rgba("#F008"), Some((255, 0, 0, 136)));
+            assert_eq!(rgba("  0048BA  "), Some((0, 72, 186, 255)));
+        }
+
+        #[test]
+        fn test_errors() {
+            use ParseColorErrorKind::*;
+            assert_eq!(error("#0048B"), (InvalidHexLength, "#0048B"));
+            assert_eq!(error("#GGGGGG"), (InvalidHexDigit, "G"));
+            assert_eq!(error("##FF0000"), (InvalidHexDigit, "#"));
+            assert_eq!(error("#FF 000"), (InvalidHexDigit, " "));
+            assert_eq!(error("#FFé000"), (InvalidHexDigit, "é"));
+            assert_eq!(error("  12345  "), (InvalidHexLength, "12345"));
+        }
+    }
+
+    mod rgb {
+        use super::*;
+
+        #[test]
+        fn test_modern_syntax() {
+            assert_eq!(rgba("rgb(255 0 0)"), Some((255, 0, 0, 255)));
+            assert_eq!(rgba("rgb(255 0 0 / 0.5)"), Some((255, 0, 0, 128)));
+            assert_eq!(rgba("rgb(255 0 0/50%)"), Some((255, 0, 0, 128)));
+            assert_eq!(rgba("rgba(100% 50% 0%)"), Some((255, 128, 0, 255)));
+            assert_eq!(rgba("RGB(none 255 none)"), Some((0, 255, 0, 255)));
+            assert_eq!(rgba("rgb(127.5 0 0)"), Some((128, 0, 0, 255)));
+        }
+
+        #[test]
+        fn test_legacy_syntax() {
+            assert_eq!(rgba("rgb(255, 0, 0)"), Some((255, 0, 0, 255)));
+            assert_eq!(rgba("rgba(255, 0, 0, 0.25)"), Some((255, 0, 0, 64)));
+            assert_eq!(
+                rgba("rgba( 0% , 100% , 0% , 100% )"),
+                Some((0, 255, 0, 255))
+            );
+        }
+
+        #[test]
+        fn test_out_of_range_is_clamped() {
+            assert_eq!(rgba("rgb(300 -20 0 / 2)"), Some((255, 0, 0, 255)));
+        }
+
+        #[test]
+        fn test_errors() {
+            use ParseColorErrorKind::*;
+            assert_eq!(error("rgb(255 0)"), (ComponentCount, "255 0"));
+            assert_eq!(error("rgb(255 0 0 0)"), (ComponentCount, "255 0 0 0"));
+            assert_eq!(error("rgb(255, 0 0)"), (InvalidSeparator, " "));
+            assert_eq!(error("rgb(255, 0, 0 / 1)"), (InvalidSeparator, "/"));
+            assert_eq!(error("rgb(255 0 0 / 1 1)"), (ComponentCount, " 1 1"));
+            assert_eq!(error("rgb(255 0 0 / 1 / 1)"), (ComponentCount, " 1 / 1"));
+            assert_eq!(error(" rgb(255 0 0"), (UnclosedFunction, "rgb("));
+            assert_eq!(error("rgb(255 0 0) x"), (TrailingCharacters, " x"));
+            assert_eq!(error("rgb(10deg 0 0)"), (InvalidUnit, "10deg"));
+            assert_eq!(error("rgb(inf 0 0)"), (InvalidNumber, "inf"));
+            assert_eq!(error("rgb(red)"), (InvalidNumber, "red"));
+        }
+    }
+
+    mod cylindrical {
+        use super::*;
+
+        #[test]
+        fn test_hsl() {
+            assert_eq!(rgba("hsl(270 50% 40%)"), Some((0x66, 0x33, 0x99, 255)));
+            assert_eq!(
+                rgba("hsla(270, 50%, 40%, 0.5)"),
+                Some((0x66, 0x33, 0x99, 128))
+            );
+            assert_eq!(rgba("hsl(0.75turn 50 40)"), Some((0x66, 0x33, 0x99, 255)));
+            assert_eq!(rgba("hsl(300grad 50% 40%)"), Some((0x66, 0x33, 0x99, 255)));
+            assert!(matches!(
+                parse("hsl(120 100% 25%)"),
+                Ok(ParsedColor::Hsl(..))
+            ));
+            assert_eq!(
+                error("hsl(50% 50% 40%)"),
+                (ParseColorErrorKind::InvalidUnit, "50%")
+            );
+        }
+
+        #[test]
+        fn test_hwb() {
+            assert_eq!(rgba("hwb(270 20% 40%)"), Some((0x66, 0x33, 0x99, 255)));
+            assert_eq!(rgba("hwb(0 100% 100% / 25%)"), Some((128, 128, 128, 64)));
+            // `hwb()` has no legacy syntax
+            assert_eq!(
+                error("hwb(270, 20%, 40%)"),
+                (ParseColorErrorKind::InvalidSeparator, ",")
+            );
+        }
+    }
+
+    mod lab {
+        use super::*;
+
+        #[test]
+        fn test_lab_and_lch() {
+            // CSS reference values, in D50
+            assert_eq!(rgba("lab(54.29 80.8 69.89)"), Some((255, 0, 0, 255)));
+            assert_eq!(rgba("lch(54.29 106.84 40.85)"), Some((255, 0, 0, 255)));
+            assert_eq!(rgba("lab(100% 0 0)"), Some((255, 255, 255, 255)));
+            assert_eq!(
+                rgba("lch(50% 0 0)").map(|c| c.0 == c.1 && c.1 == c.2),
+                Some(true)
+            );
+        }
+
+        #[test]
+        fn test_oklab_and_oklch() {
+            assert_eq!(rgba("oklab(0.628 0.2249 0.1258)"), Some((255, 0, 0, 255)));
+            assert_eq!(
+                rgba("oklch(62.8% 0.2577 29.23deg / 0.5)"),
+                Some((255, 0, 0, 128))
+            );
+            assert_eq!(rgba("oklab(100% 0% 0%)"), Some((255, 255, 255, 255)));
+            let Ok(ParsedColor::Oklch(c, _)) = parse("oklch(0.5 50% 90)") else {
+                panic!("not oklch");
+            };
+            assert!((c.chroma() - 0.2).abs() < 1e-6);
+        }
+
+        #[test]
+        fn test_d50_is_adapted() {
+            let Ok(ParsedColor::Lab(lab, 255)) = parse("lab(50 0 0)") else {
+                panic!("not lab");
+            };
+            assert!((lab.lightness() - 50.0).abs() < 1e-3);
+            assert!(lab.a().abs() < 1e-3 && lab.b().abs() < 1e-3);
+        }
+    }
+
+    mod color_function {
+        use super::*;
+
+        #[test]
+        fn test_srgb() {
+            use ParseColorErrorKind::*;
+            assert_eq!(rgba("color(srgb 1 0 0)"), Some((255, 0, 0, 255)));
+            assert_eq!(rgba("color(sRGB 0% 100% 0% / 0.5)"), Some((0, 255, 0, 128)));
+            assert_eq!(
+                error("color(display-p3 1 0 0)"),
+                (UnsupportedColorSpace, "display-p3")
+            );
+            assert_eq!(error("color()"), (UnsupportedColorSpace, ""));
+            assert_eq!(error("color(srgb)"), (ComponentCount, ""));
+        }
+    }
+
+    mod names {
+        use super::*;
+
+        #[test]
+        fn test_transparent() {
+            assert_eq!(rgba("Transparent"), Some((0, 0, 0, 0)));
+        }
+
+        #[test]
+        fn test_errors() {
+            use ParseColorErrorKind::*;
+            assert_eq!(error("   "), (Empty, ""));
+            assert_eq!(error(" not a color "), (UnknownColor, "not a color"));
+            assert_eq!(error("foo(1 2 3)"), (UnknownColor, "foo(1 2 3)"));
+        }
+
+        #[test]
+        fn test_parentheses_are_not_dropped() {
+            use ParseColorErrorKind::*;
+            assert_eq!(error("red("), (UnclosedFunction, "red("));
+            assert_eq!(error("red()"), (UnknownColor, "red()"));
+            assert_eq!(error("red)"), (UnknownColor, "red)"));
+            let err = parse_strict("red(").unwrap_err();
+            assert_eq!(err.kind(), UnclosedFunction);
+            assert!("red(".parse::<Color>().is_err());
+        }
+
+        #[test]
+        #[cfg(feature = "wikipedia")]
+        fn test_named_colors() {
+            assert_eq!(
+                parse("absolute_zero").ok(),
+                crate::color("Absolute Zero").map(ParsedColor::Named)
+            );
+            assert_eq!(
+                parse("Gray (web)").ok(),
+                crate::color("Gray (web)").map(ParsedColor::Named)
+            );
+            assert_eq!(rgba("  Azure "), Some((0, 127, 255, 255)));
+        }
+    }
+
+    mod strict {
+        use super::*;
+
+        fn strict(s: &str) -> (ParseColorErrorKind, &str) {
+            let err = parse_strict(s).unwrap_err();
+            (
+                err.kind(),
+                &s[err.offset()..err.offset() + err.fragment().len()],
+            )
+        }
+
+        #[test]
+        fn test_out_of_range_is_reported() {
+            use ParseColorErrorKind::OutOfRange;
+            assert_eq!(strict("rgb(300, 0, 0)"), (OutOfRange, "300"));
+            assert_eq!(strict("rgb(0 -20 0)"), (OutOfRange, "-20"));
+            assert_eq!(strict("rgb(0 0 0 / 2)"), (OutOfRange, "2"));
+            assert_eq!(strict("rgb(0% 0% 101%)"), (OutOfRange, "101%"));
+            assert_eq!(strict("hsl(270 120% 40%)"), (OutOfRange, "120%"));
+            assert_eq!(strict("lch(50 -1 0)"), (OutOfRange, "-1"));
+            assert_eq!(strict("oklab(1.5 0 0)"), (OutOfRange, "1.5"));
+        }
+
+        #[test]
+        fn test_fractional_channels_are_reported() {
+            use ParseColorErrorKind::OutOfRange;
+            assert_eq!(strict("rgb(1.5, 0, 0)"), (OutOfRange, "1.5"));
+            assert!(parse_strict("rgb(50% 0 0)").is_ok());
+            assert!(parse_strict("color(srgb 0.5 0 0)").is_ok());
+        }
+
+        #[test]
+        fn test_in_range_matches_parse() {
+            for css in [
+                "rgb(255 0 0 / 50%)",
+                "hsl(-90 50% 40%)",
+                "oklch(0.5 0.1 400)",
+                "#0048BA80",
+            ] {
+                assert_eq!(parse_strict(css), parse(css), "{css}");
+            }
+        }
+
+        #[test]
+        fn test_rgb_str() {
+            use ParseColorErrorKind::*;
+            let kind = |s| parse_rgb(s).unwrap_err().kind();
+            assert_eq!(parse_rgb("255,,0 0"), Ok(Rgba::new(255, 0, 0, 255)));
+            assert_eq!(
+                parse_rgb(" rgb ( 255 , 0 , 0 ) "),
+                Ok(Rgba::new(255, 0, 0, 255))
+            );
+            assert_eq!(
+                parse_rgb("rgba(255, 0, 0, 0.5)"),
+                Ok(Rgba::new(255, 0, 0, 128))
+            );
+            assert_eq!(
+                parse_rgb("rgb(255 0 0 / 50%)"),
+                Ok(Rgba::new(255, 0, 0, 128))
+            );
+            assert_eq!(kind("rgb(0 0 0 / 1.5)"), OutOfRange);
+            assert_eq!(kind("rgb(0 0 0 / 1 1)"), ComponentCount);
+            assert_eq!(kind("256 0 0"), OutOfRange);
+            assert_eq!(kind("0.5 0 0"), OutOfRange);
+            assert_eq!(kind("100% 0 0"), InvalidUnit);
+            assert_eq!(kind("rgb(0 0 0 0 0)"), ComponentCount);
+            assert_eq!(kind("hsl(0 0 0)"), UnknownColor);
+        }
+    }
+
+    mod from_str {
+        use super::*;
+
+        #[test]
+        fn test_color_types() {
+            assert_eq!("#663399".parse::<Rgb>(), Ok(Rgb::new(0x66, 0x33, 0x99)));
+            assert_eq!("rgb(0 0 0 / 0)".parse::<Rgba>(), Ok(Rgba::new(0, 0, 0, 0)));
+            assert_eq!(
+                "hsl(270 50% 40%)".parse::<Ansi>().map(|a| a.get_rgb()),
+                Ok((0x66, 0x33, 0x99))
+            );
+            assert_eq!(
+                "hsl(270 50% 40%)".parse::<Hsl>(),
+                Ok(Hsl::new(270.0, 0.5, 0.4))
+            );
+            assert_eq!(
+                "#663399".parse::<Hsv>(),
+                Ok(Hsv::from(Rgb::new(0x66, 0x33, 0x99)))
+            );
+            assert_eq!(
+                "oklch(0.5 0.1 200)".parse::<Oklch>(),
+                Ok(Oklch::new(0.5, 0.1, 200.0))
+            );
+            assert!("#663399".parse::<Xyz>().is_ok());
+        }
+
+        #[test]
+        fn test_out_of_range_is_reported() {
+            let err = "rgb(300, 0, 0)".parse::<Rgb>().unwrap_err();
+            assert_eq!(err.kind(), ParseColorErrorKind::OutOfRange);
+            assert!("rgb(1.5, 0, 0)".parse::<Ansi>().is_err());
+            assert!("hsl(0 0% 101%)".parse::<Hsl>().is_err());
+        }
+
+        #[test]
+        fn test_errors_are_reported() {
+            let err = "rgb(1 2)".parse::<Lab>().unwrap_err();
+            assert_eq!(err.kind(), ParseColorErrorKind::ComponentCount);
+            assert_eq!(err.fragment(), "1 2");
+            let err: Box<dyn std::error::Error> = Box::new(err);
+            assert_eq!(
+                err.to_string(),
+                "wrong number of components in \"1 2\" at byte 4 of \"rgb(1 2)\""
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "wikipedia")]
+        fn test_color_names_only() {
+            assert_eq!(
+                "Absolute Zero".parse::<Color>(),
+                Ok(crate::color("Absolute Zero").unwrap())
+            );
+            let err = "#0048BA".parse::<Color>().unwrap_err();
+            assert_eq!(
+                (err.kind(), err.offset()),
+                (ParseColorErrorKind::UnknownColor, 0)
+            );
+        }
+    }
+}
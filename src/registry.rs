@@ -0,0 +1,386 @@
+//! User-defined colors, looked up like the built-in ones.
+//!
+//! A [`Registry`] holds colors added at runtime, such as brand colors, and
+//! layers them over the generated tables: [`Registry::color`] canonicalizes
+//! names exactly like [`color`](crate::color) and consults both. The
+//! [global registry](Registry::global) is consulted by [`color`](crate::color)
+//! itself.
+//!
+//! Built-in colors borrow their names and hex codes from the generated
+//! tables, while registered colors own theirs, so they are freed again by
+//! [`Registry::remove`] or when the registry is dropped.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{PoisonError, RwLock};
+
+use crate::{Color, Rgb, Source, canonical};
+
+/// The [`Source::list`] of every registered color.
+const REGISTRY_LIST: &str = "Registry";
+
+static GLOBAL: Registry = Registry::new();
+
+/// How registered colors relate to built-in colors of the same name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Policy {
+    /// Registered colors win: "Red" can be redefined as the brand red.
+    #[default]
+    Shadow,
+    /// Built-in colors win; registered colors only fill in unknown names.
+    Fallback,
+    /// Registering a name that a built-in color already has is an error.
+    Reject,
+}
+
+/// Why a color could not be registered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryError {
+    /// The name has no letters or digits, so nothing could look it up.
+    EmptyName,
+    /// The hex code is not `#RGB` or `#RRGGBB`. Named colors are opaque, so
+    /// `#RRGGBBAA` is refused rather than losing its alpha.
+    InvalidHex,
+    /// Under [`Policy::Reject`], the name belongs to this built-in color.
+    Builtin(Color),
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::EmptyName => f.write_str("color name has no letters or digits"),
+            RegistryError::InvalidHex => f.write_str("invalid hex color code"),
+            RegistryError::Builtin(c) => write!(f, "\"{}\" is a built-in color", c.name()),
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+#[derive(Debug)]
+struct Inner {
+    policy: Policy,
+    colors: BTreeMap<String, Color>,
+}
+
+/// A thread-safe set of user-defined colors layered over the built-in ones.
+///
+/// # Examples
+///
+/// ```
+/// use pigment::{Policy, Registry};
+///
+/// let brand = Registry::new();
+/// brand.insert("Acme Orange", "#FF6A13").unwrap();
+///
+/// assert_eq!(brand.color("acme-orange").unwrap().hex(), "#FF6A13");
+/// // Built-in colors are still there
+/// assert_eq!(brand.color("Zomp"), pigment::color("Zomp"));
+///
+/// // Shadowing built-ins is allowed by default, and can be ruled out
+/// brand.set_policy(Policy::Reject);
+/// assert!(brand.insert("Red", "#E00000").is_err());
+/// ```
+#[derive(Debug)]
+pub struct Registry {
+    inner: RwLock<Inner>,
+}
+
+impl Registry {
+    /// An empty registry with [`Policy::Shadow`].
+    pub const fn new() -> Self {
+        Self::with_policy(Policy::Shadow)
+    }
+
+    /// An empty registry with the given policy.
+    pub const fn with_policy(policy: Policy) -> Self {
+        Self {
+            inner: RwLock::new(Inner {
+                policy,
+                colors: BTreeMap::new(),
+            }),
+        }
+    }
+
+    /// The process-wide registry that [`color`](crate::color) consults.
+    ///
+    /// ```
+    /// use pigment::Registry;
+    ///
+    /// Registry::global().insert("Initech Blue", "#1B4F9C").unwrap();
+    /// assert_eq!(pigment::color("initech blue").unwrap().hex(), "#1B4F9C");
+    /// ```
+    pub fn global() -> &'static Registry {
+        &GLOBAL
+    }
+
+    /// The current override policy.
+    pub fn policy(&self) -> Policy {
+        self.read(|inner| inner.policy)
+    }
+
+    /// Change the override policy.
+    ///
+    /// Entries already registered are kept, even ones [`Policy::Reject`]
+    /// would have refused.
+    pub fn set_policy(&self, policy: Policy) {
+        self.write(|inner| inner.policy = policy)
+    }
+
+    /// Register `name` as the color `hex`, replacing any earlier entry that
+    /// canonicalizes to the same key.
+    ///
+    /// Returns the registered color.
+    pub fn insert(&self, name: &str, hex: &str) -> Result<Color, RegistryError> {
+        let rgb = Rgb::from_hex(hex).ok_or(RegistryError::InvalidHex)?;
+        self.insert_rgb(name, rgb.tuple())
+    }
+
+    /// Register `name` as the color `rgb`; see [`insert`](Self::insert).
+    pub fn insert_rgb(&self, name: &str, rgb: (u8, u8, u8)) -> Result<Color, RegistryError> {
+        let key = canonical(name);
+        if key.is_empty() {
+            return Err(RegistryError::EmptyName);
+        }
+
+        self.write(|inner| {
+            if inner.policy == Policy::Reject
+                && let Some(builtin) = crate::builtin(&key)
+            {
+                return Err(RegistryError::Builtin(builtin));
+            }
+            let (r, g, b) = rgb;
+            let color = Color {
+                name: Cow::Owned(name.to_owned()),
+                hex: Cow::Owned(format!("#{r:02X}{g:02X}{b:02X}")),
+                rgb,
+                aliases: &[],
+                source: Source {
+                    list: REGISTRY_LIST,
+                    variant: None,
+                },
+            };
+            inner.colors.insert(key, color.clone());
+            Ok(color)
+        })
+    }
+
+    /// Unregister `name`, returning its color if it was registered.
+    pub fn remove(&self, name: &str) -> Option<Color> {
+        let key = canonical(name);
+        self.write(|inner| inner.colors.remove(&key))
+    }
+
+    /// Remove every registered color.
+    pub fn clear(&self) {
+        self.write(|inner| inner.colors.clear())
+    }
+
+    /// The registered color called `name`, ignoring built-in colors.
+    pub fn get(&self, name: &str) -> Option<Color> {
+        let key = canonical(name);
+        self.read(|inner| inner.colors.get(&key).cloned())
+    }
+
+    /// Look up `name` among registered and built-in colors, as the policy
+    /// says.
+    pub fn color(&self, name: &str) -> Option<Color> {
+        let key = canonical(name);
+        self.resolve(&key, || crate::builtin(&key))
+    }
+
+    // The registered color keyed `key` or the built-in one `builtin` finds,
+    // whichever the policy prefers
+    pub(crate) fn resolve(
+        &self,
+        key: &str,
+        builtin: impl FnOnce() -> Option<Color>,
+    ) -> Option<Color> {
+        let (policy, registered) =
+            self.read(|inner| (inner.policy, inner.colors.get(key).cloned()));
+        match policy {
+            Policy::Shadow | Policy::Reject => registered.or_else(builtin),
+            Policy::Fallback => builtin().or(registered),
+        }
+    }
+
+    /// Number of registered colors.
+    pub fn len(&self) -> usize {
+        self.read(|inner| inner.colors.len())
+    }
+
+    /// Whether no colors are registered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Every registered color, ordered by canonical name.
+    pub fn colors(&self) -> Vec<Color> {
+        self.read(|inner| inner.colors.values().cloned().collect())
+    }
+
+    // A panic while holding the lock can't leave `Inner` half-updated, so
+    // poisoning is ignored
+    fn read<T>(&self, f: impl FnOnce(&Inner) -> T) -> T {
+        f(&self.inner.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    fn write<T>(&self, f: impl FnOnce(&mut Inner) -> T) -> T {
+        f(&mut self.inner.write().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(test, feature = "wikipedia"))]
+mod tests {
+    use super::*;
+
+    mod insert {
+        use super::*;
+
+        #[test]
+        fn test_insert_and_get() {
+            let registry = Registry::new();
+            let acme = registry.insert("Acme Orange", "#ff6a13").unwrap();
+            assert_eq!(acme.name(), "Acme Orange");
+            assert_eq!(acme.hex(), "#FF6A13");
+            assert_eq!(acme.rgb(), (255, 106, 19));
+            assert_eq!(acme.source().list(), "Registry");
+            assert_eq!(registry.get("ACME_ORANGE"), Some(acme));
+            assert_eq!(registry.len(), 1);
+        }
+
+        #[test]
+        fn test_invalid_input() {
+            let registry = Registry::new();
+            assert_eq!(
+                registry.insert("Acme", "#GG0000"),
+                Err(RegistryError::InvalidHex)
+            );
+            assert_eq!(
+                registry.insert("Acme", "#FF6A1380"),
+                Err(RegistryError::InvalidHex)
+            );
+            assert_eq!(
+                registry.insert(" - ", "#FF0000"),
+                Err(RegistryError::EmptyName)
+            );
+            assert!(registry.is_empty());
+        }
+
+        #[test]
+        fn test_same_key_replaces() {
+            let registry = Registry::new();
+            registry.insert("Acme Orange", "#FF6A13").unwrap();
+            registry.insert_rgb("acme-orange", (1, 2, 3)).unwrap();
+            assert_eq!(registry.len(), 1);
+            assert_eq!(registry.get("Acme Orange").unwrap().rgb(), (1, 2, 3));
+            assert_eq!(registry.get("Acme Orange").unwrap().name(), "acme-orange");
+        }
+
+        #[test]
+        fn test_remove_and_clear() {
+            let registry = Registry::new();
+            registry.insert("One", "#111").unwrap();
+            registry.insert("Two", "#222").unwrap();
+            assert_eq!(registry.remove("one").unwrap().hex(), "#111111");
+            assert_eq!(registry.remove("one"), None);
+            assert_eq!(registry.colors().len(), 1);
+            registry.clear();
+            assert!(registry.is_empty());
+        }
+    }
+
+    mod policies {
+        use super::*;
+
+        #[test]
+        fn test_shadow() {
+            let registry = Registry::new();
+            assert_eq!(registry.policy(), Policy::Shadow);
+            registry.insert("Red", "#E00000").unwrap();
+            assert_eq!(registry.color("red").unwrap().hex(), "#E00000");
+            assert_eq!(registry.color("Zomp"), crate::color("Zomp"));
+        }
+
+        #[test]
+        fn test_fallback() {
+            let registry = Registry::with_policy(Policy::Fallback);
+            registry.insert("Red", "#E00000").unwrap();
+            registry.insert("Acme Orange", "#FF6A13").unwrap();
+            assert_eq!(registry.color("red").unwrap().hex(), "#FF0000");
+            assert_eq!(registry.color("acme orange").unwrap().hex(), "#FF6A13");
+            // The registered entry is still there, just not preferred
+            assert_eq!(registry.get("red").unwrap().hex(), "#E00000");
+        }
+
+        #[test]
+        fn test_reject() {
+            let registry = Registry::with_policy(Policy::Reject);
+            let red = crate::color("Red").unwrap();
+            assert_eq!(
+                registry.insert("RED", "#E00000"),
+                Err(RegistryError::Builtin(red))
+            );
+            // Aliases count as built-in names too
+            assert!(registry.insert("fuschia", "#E00000").is_err());
+            assert!(registry.insert("Acme Orange", "#FF6A13").is_ok());
+            assert_eq!(registry.len(), 1);
+        }
+
+        #[test]
+        fn test_error_messages() {
+            let red = crate::color("Red").unwrap();
+            assert_eq!(
+                RegistryError::Builtin(red).to_string(),
+                "\"Red\" is a built-in color"
+            );
+            assert_eq!(
+                RegistryError::InvalidHex.to_string(),
+                "invalid hex color code"
+            );
+        }
+    }
+
+    mod sharing {
+        use super::*;
+
+        #[test]
+        fn test_across_threads() {
+            let registry = std::sync::Arc::new(Registry::new());
+            let handles: Vec<_> = (0..4u8)
+                .map(|i| {
+                    let registry = registry.clone();
+                    std::thread::spawn(move || {
+                        registry
+                            .insert_rgb(&format!("Thread {i}"), (i, i, i))
+                            .unwrap();
+                    })
+                })
+                .collect();
+            for handle in handles {
+                handle.join().unwrap();
+            }
+            assert_eq!(registry.len(), 4);
+            assert_eq!(registry.color("thread 3").unwrap().rgb(), (3, 3, 3));
+        }
+
+        #[test]
+        fn test_global_is_consulted_by_color() {
+            // Names unique to this test, since the global registry is shared
+            assert_eq!(crate::color("Pigment Test Teal"), None);
+            Registry::global()
+                .insert("Pigment Test Teal", "#00807F")
+                .unwrap();
+            assert_eq!(crate::color("pigment-test-teal").unwrap().hex(), "#00807F");
+            Registry::global().remove("Pigment Test Teal");
+            assert_eq!(crate::color("Pigment Test Teal"), None);
+        }
+    }
+}
@@ -0,0 +1,783 @@
+//! Plain RGB and RGBA values, named or not.
+//!
+//! [`Color`] is an entry of a color table and [`Ansi`] a terminal style;
+//! [`Rgb`] and [`Rgba`] are just the value, for colors that come from
+//! parsing, arithmetic or another library. Both convert from and into
+//! `Color`, `Ansi` and `(u8, u8, u8)` tuples.
+//!
+//! Terminals have no alpha channel, so an [`Rgba`] becomes [`Ansi`] only by
+//! compositing it over a background with [`Rgba::over`] or
+//! [`Rgba::ansi_over`].
+
+use crate::Color;
+use crate::ansi::Ansi;
+
+/// An 8-bit sRGB color.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "wikipedia")] {
+/// use pigment::Rgb;
+///
+/// let zero = Rgb::new(0, 72, 186);
+/// assert_eq!(Rgb::from_hex("#0048BA"), Some(zero));
+/// assert_eq!(Rgb::from(pigment::color("Absolute Zero").unwrap()), zero);
+/// assert_eq!(zero.tuple(), (0, 72, 186));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb {
+    pub(crate) r: u8,
+    pub(crate) g: u8,
+    pub(crate) b: u8,
+}
+
+/// An 8-bit sRGB color with an alpha channel; `a` is 255 when opaque.
+///
+/// # Examples
+///
+/// ```
+/// use pigment::{Rgb, Rgba};
+///
+/// let glass = Rgb::new(0, 72, 186).with_alpha(128);
+/// assert_eq!(glass, Rgba::new(0, 72, 186, 128));
+/// assert_eq!(glass.rgb(), Rgb::new(0, 72, 186));
+/// assert!(!glass.is_opaque());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgba {
+    pub(crate) r: u8,
+    pub(crate) g: u8,
+    pub(crate) b: u8,
+    pub(crate) a: u8,
+}
+
+impl Rgb {
+    #[inline]
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Parse a `#RGB` or `#RRGGBB` hex code, with or without the `#`.
+    ///
+    /// An `Rgb` is opaque, so `#RGBA` and `#RRGGBBAA` are refused rather
+    /// than having their alpha dropped, as [`hex!`](crate::hex!) and
+    /// [`Registry::insert`](crate::Registry::insert) do; use
+    /// [`Rgba::from_hex`] for those.
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if !matches!(digits.len(), 3 | 6) {
+            return None;
+        }
+        crate::parse::parse_hex(hex).ok().map(|c| c.rgb())
+    }
+
+    #[inline]
+    pub const fn r(&self) -> u8 {
+        self.r
+    }
+    #[inline]
+    pub const fn g(&self) -> u8 {
+        self.g
+    }
+    #[inline]
+    pub const fn b(&self) -> u8 {
+        self.b
+    }
+
+    /// The channels as an `(r, g, b)` tuple, like [`Color::rgb`].
+    #[inline]
+    pub const fn tuple(&self) -> (u8, u8, u8) {
+        (self.r, self.g, self.b)
+    }
+
+    /// This color with alpha `a`.
+    #[inline]
+    pub const fn with_alpha(&self, a: u8) -> Rgba {
+        Rgba::new(self.r, self.g, self.b, a)
+    }
+
+    /// Terminal escape codes for this color.
+    #[inline]
+    pub fn ansi(&self) -> Ansi {
+        Ansi::from(*self)
+    }
+}
+
+impl Rgba {
+    #[inline]
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Parse a `#RGB`, `#RGBA`, `#RRGGBB` or `#RRGGBBAA` hex code, with or
+    /// without the `#`. The forms without alpha are opaque.
+    ///
+    /// ```
+    /// use pigment::Rgba;
+    ///
+    /// assert_eq!(Rgba::from_hex("#0048BA80"), Some(Rgba::new(0, 72, 186, 128)));
+    /// assert_eq!(Rgba::from_hex("#F008"), Some(Rgba::new(255, 0, 0, 136)));
+    /// assert_eq!(Rgba::from_hex("#0048BA"), Some(Rgba::new(0, 72, 186, 255)));
+    /// ```
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        crate::parse::parse_hex(hex).ok()
+    }
+
+    /// Parse CSS-style `rgb()` and `rgba()` values with an optional alpha.
+    ///
+    /// The channels are whole numbers from 0 to 255, separated by commas
+    /// or spaces. The alpha is a number from 0 to 1 or a percentage, given
+    /// as a fourth value or after a `/`. Without an alpha the color is
+    /// opaque. [`Ansi::from_rgb_str`] takes the same forms, opaque ones
+    /// only; [`parse`](crate::parse()) takes percentage channels and every
+    /// other CSS color too.
+    ///
+    /// ```
+    /// use pigment::Rgba;
+    ///
+    /// let half = Rgba::new(255, 0, 0, 128);
+    /// assert_eq!(Rgba::from_rgb_str("rgba(255, 0, 0, 0.5)"), Some(half));
+    /// assert_eq!(Rgba::from_rgb_str("rgb(255 0 0 / 50%)"), Some(half));
+    /// assert_eq!(Rgba::from_rgb_str("255, 0, 0"), Some(Rgba::new(255, 0, 0, 255)));
+    /// assert_eq!(Rgba::from_rgb_str("rgba(255, 0, 0, 1.5)"), None);
+    /// assert_eq!(Rgba::from_rgb_str("rgb(100% 0% 0%)"), None);
+    /// ```
+    pub fn from_rgb_str(rgb_str: &str) -> Option<Self> {
+        crate::parse::parse_rgb(rgb_str).ok()
+    }
+
+    #[inline]
+    pub const fn r(&self) -> u8 {
+        self.r
+    }
+    #[inline]
+    pub const fn g(&self) -> u8 {
+        self.g
+    }
+    #[inline]
+    pub const fn b(&self) -> u8 {
+        self.b
+    }
+    #[inline]
+    pub const fn a(&self) -> u8 {
+        self.a
+    }
+
+    /// The color channels, without the alpha.
+    #[inline]
+    pub const fn rgb(&self) -> Rgb {
+        Rgb::new(self.r, self.g, self.b)
+    }
+
+    /// The channels as an `(r, g, b, a)` tuple.
+    #[inline]
+    pub const fn tuple(&self) -> (u8, u8, u8, u8) {
+        (self.r, self.g, self.b, self.a)
+    }
+
+    /// Whether the alpha is 255.
+    #[inline]
+    pub const fn is_opaque(&self) -> bool {
+        self.a == u8::MAX
+    }
+
+    /// This color painted over an opaque `background`.
+    ///
+    /// Channels are blended in sRGB, the way browsers composite CSS colors.
+    ///
+    /// ```
+    /// use pigment::{Rgb, Rgba};
+    ///
+    /// let shade = Rgba::new(0, 0, 0, 128);
+    /// assert_eq!(shade.over(Rgb::new(255, 255, 255)), Rgb::new(127, 127, 127));
+    /// ```
+    pub fn over(&self, background: Rgb) -> Rgb {
+        let a = u32::from(self.a);
+        let blend =
+            |fg: u8, bg: u8| ((u32::from(fg) * a + u32::from(bg) * (255 - a) + 127) / 255) as u8;
+        Rgb::new(
+            blend(self.r, background.r),
+            blend(self.g, background.g),
+            blend(self.b, background.b),
+        )
+    }
+
+    /// Terminal escape codes for this color painted over `background`; see
+    /// [`over`](Self::over).
+    #[inline]
+    pub fn ansi_over(&self, background: Rgb) -> Ansi {
+        self.over(background).ansi()
+    }
+}
+
+/// Opaque black.
+impl Default for Rgba {
+    fn default() -> Self {
+        Rgb::default().into()
+    }
+}
+
+impl From<(u8, u8, u8)> for Rgb {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Self::new(r, g, b)
+    }
+}
+
+impl From<[u8; 3]> for Rgb {
+    fn from([r, g, b]: [u8; 3]) -> Self {
+        Self::new(r, g, b)
+    }
+}
+
+impl From<Rgb> for (u8, u8, u8) {
+    fn from(c: Rgb) -> Self {
+        c.tuple()
+    }
+}
+
+impl From<Rgb> for [u8; 3] {
+    fn from(c: Rgb) -> Self {
+        [c.r, c.g, c.b]
+    }
+}
+
+impl From<Color> for Rgb {
+    fn from(c: Color) -> Self {
+        Rgb::from(&c)
+    }
+}
+
+impl From<&Color> for Rgb {
+    fn from(c: &Color) -> Self {
+        c.rgb.into()
+    }
+}
+
+impl From<Ansi> for Rgb {
+    fn from(a: Ansi) -> Self {
+        a.rgb.into()
+    }
+}
+
+impl From<&Ansi> for Rgb {
+    fn from(a: &Ansi) -> Self {
+        a.rgb.into()
+    }
+}
+
+impl From<Rgb> for Ansi {
+    fn from(c: Rgb) -> Self {
+        Ansi { rgb: c.tuple() }
+    }
+}
+
+impl From<(u8, u8, u8, u8)> for Rgba {
+    fn from((r, g, b, a): (u8, u8, u8, u8)) -> Self {
+        Self::new(r, g, b, a)
+    }
+}
+
+impl From<[u8; 4]> for Rgba {
+    fn from([r, g, b, a]: [u8; 4]) -> Self {
+        Self::new(r, g, b, a)
+    }
+}
+
+impl From<Rgba> for (u8, u8, u8, u8) {
+    fn from(c: Rgba) -> Self {
+        c.tuple()
+    }
+}
+
+impl From<Rgba> for [u8; 4] {
+    fn from(c: Rgba) -> Self {
+        [c.r, c.g, c.b, c.a]
+    }
+}
+
+/// Fully opaque.
+impl From<Rgb> for Rgba {
+    fn from(c: Rgb) -> Self {
+        c.with_alpha(u8::MAX)
+    }
+}
+
+impl From<Color> for Rgba {
+    fn from(c: Color) -> Self {
+        Rgb::from(c).into()
+    }
+}
+
+impl From<&Color> for Rgba {
+    fn from(c: &Color) -> Self {
+        Rgb::from(c).into()
+    }
+}
+
+// For a color model with `From<Rgb>` and `Into<Rgb>`, the same conversions
+// from and to tuples, `Ansi` and `Color` that `Rgb` has
+macro_rules! rgb_conversions {
+    ($model:ty) => {
+        impl From<(u8, u8, u8)> for $model {
+            fn from(rgb: (u8, u8, u8)) -> Self {
+                $crate::Rgb::from(rgb).into()
+            }
+        }
+
+        impl From<$model> for (u8, u8, u8) {
+            fn from(c: $model) -> Self {
+                $crate::Rgb::from(c).tuple()
+            }
+        }
+
+        impl From<$crate::ansi::Ansi> for $model {
+            fn from(a: $crate::ansi::Ansi) -> Self {
+                $crate::Rgb::from(a).into()
+            }
+        }
+
+        impl From<$model> for $crate::ansi::Ansi {
+            fn from(c: $model) -> Self {
+                $crate::Rgb::from(c).into()
+            }
+        }
+
+        impl From<$crate::Color> for $model {
+            fn from(c: $crate::Color) -> Self {
+                $crate::Rgb::from(c).into()
+            }
+        }
+
+        impl From<&$crate::Color> for $model {
+            fn from(c: &$crate::Color) -> Self {
+                $crate::Rgb::from(c).into()
+            }
+        }
+    };
+}
+pub(crate) use rgb_conversions;
+
+#[cfg(feature = "owo")]
+impl From<Rgb> for owo_colors::Rgb {
+    fn from(c: Rgb) -> Self {
+        owo_colors::Rgb(c.r, c.g, c.b)
+    }
+}
+
+#[cfg(feature = "termcolor")]
+impl From<Rgb> for termcolor::Color {
+    fn from(c: Rgb) -> Self {
+        termcolor::Color::Rgb(c.r, c.g, c.b)
+    }
+}
+
+#[cfg(feature = "colored")]
+impl From<Rgb> for colored::Color {
+    fn from(c: Rgb) -> Self {
+        colored::Color::TrueColor {
+            r: c.r,
+            g: c.g,
+            b: c.b,
+        }
+    }
+}
+
+#[cfg(feature = "anstyle")]
+impl From<Rgb> for anstyle::Color {
+    fn from(c: Rgb) -> Self {
+        anstyle::Color::Rgb(anstyle::RgbColor(c.r, c.g, c.b))
+    }
+}
+
+#[cfg(feature = "nu-ansi-term")]
+impl From<Rgb> for nu_ansi_term::Color {
+    fn from(c: Rgb) -> Self {
+        nu_ansi_term::Color::Rgb(c.r, c.g, c.b)
+    }
+}
+
+#[cfg(feature = "yansi")]
+impl From<Rgb> for yansi::Color {
+    fn from(c: Rgb) -> Self {
+        yansi::Color::Rgb(c.r, c.g, c.b)
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl From<Rgb> for crossterm::style::Color {
+    fn from(c: Rgb) -> Self {
+        crossterm::style::Color::Rgb {
+            r: c.r,
+            g: c.g,
+            b: c.b,
+        }
+    }
+}
+
+#[cfg(feature = "ratatui")]
+impl From<Rgb> for ratatui::style::Color {
+    fn from(c: Rgb) -> Self {
+        ratatui::style::Color::Rgb(c.r, c.g, c.b)
+    }
+}
+
+#[cfg(feature = "palette")]
+impl From<Rgb> for palette::Srgb<u8> {
+    fn from(c: Rgb) -> Self {
+        palette::Srgb::new(c.r, c.g, c.b)
+    }
+}
+
+#[cfg(feature = "rgb")]
+impl From<Rgb> for rgb::Rgb<u8> {
+    fn from(c: Rgb) -> Self {
+        rgb::Rgb {
+            r: c.r,
+            g: c.g,
+            b: c.b,
+        }
+    }
+}
+
+#[cfg(feature = "color-rs")]
+impl From<Rgb> for color::Rgba8 {
+    fn from(c: Rgb) -> Self {
+        Rgba::from(c).into()
+    }
+}
+
+#[cfg(feature = "ratatui")]
+/// Ratatui colors have no alpha: a fully transparent color is the terminal's
+/// default color, anything else keeps its RGB. Use [`Rgba::over`] to blend
+/// it with a known background first.
+impl From<Rgba> for ratatui::style::Color {
+    fn from(c: Rgba) -> Self {
+        if c.a == 0 {
+            ratatui::style::Color::Reset
+        } else {
+            c.rgb().into()
+        }
+    }
+}
+
+#[cfg(feature = "palette")]
+impl From<Rgba> for palette::Srgba<u8> {
+    fn from(c: Rgba) -> Self {
+        palette::Srgba::new(c.r, c.g, c.b, c.a)
+    }
+}
+
+#[cfg(feature = "palette")]
+impl From<palette::Srgba<u8>> for Rgba {
+    fn from(c: palette::Srgba<u8>) -> Self {
+        c.into_components().into()
+    }
+}
+
+#[cfg(feature = "rgb")]
+impl From<Rgba> for rgb::Rgba<u8> {
+    fn from(c: Rgba) -> Self {
+        rgb::Rgba {
+            r: c.r,
+            g: c.g,
+            b: c.b,
+            a: c.a,
+        }
+    }
+}
+
+#[cfg(feature = "rgb")]
+impl From<rgb::Rgba<u8>> for Rgba {
+    fn from(c: rgb::Rgba<u8>) -> Self {
+        Self::new(c.r, c.g, c.b, c.a)
+    }
+}
+
+#[cfg(feature = "color-rs")]
+impl From<Rgba> for color::Rgba8 {
+    fn from(c: Rgba) -> Self {
+        color::Rgba8 {
+            r: c.r,
+            g: c.g,
+            b: c.b,
+            a: c.a,
+        }
+    }
+}
+
+#[cfg(feature = "color-rs")]
+impl From<color::Rgba8> for Rgba {
+    fn from(c: color::Rgba8) -> Self {
+        Self::new(c.r, c.g, c.b, c.a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    mod constructors {
+        use super::*;
+
+        #[test]
+        fn test_new_and_getters() {
+            let c = Rgb::new(1, 2, 3);
+            assert_eq!((c.r(), c.g(), c.b()), (1, 2, 3));
+            let c = Rgba::new(1, 2, 3, 4);
+            assert_eq!((c.r(), c.g(), c.b(), c.a()), (1, 2, 3, 4));
+        }
+
+        #[test]
+        fn test_from_hex() {
+            assert_eq!(Rgb::from_hex("#0048BA"), Some(Rgb::new(0, 72, 186)));
+            assert_eq!(Rgb::from_hex("f0f"), Some(Rgb::new(255, 0, 255)));
+            assert_eq!(Rgb::from_hex("#12345"), None);
+        }
+
+        #[test]
+        fn test_from_hex_refuses_alpha() {
+            assert_eq!(Rgb::from_hex("#F00F"), None);
+            assert_eq!(Rgb::from_hex("#0048BA80"), None);
+            assert_eq!(Rgb::from_hex("0048BAFF"), None);
+            assert_eq!(
+                Rgba::from_hex("#0048BA80"),
+                Some(Rgba::new(0, 72, 186, 128))
+            );
+        }
+
+        #[test]
+        fn test_defaults_are_black() {
+            assert_eq!(Rgb::default(), Rgb::new(0, 0, 0));
+            assert_eq!(Rgba::default(), Rgba::new(0, 0, 0, 255));
+        }
+    }
+
+    mod conversions {
+        use super::*;
+
+        #[test]
+        fn test_tuples_and_arrays() {
+            let c = Rgb::from((10, 20, 30));
+            assert_eq!(c, Rgb::from([10, 20, 30]));
+            assert_eq!(<(u8, u8, u8)>::from(c), (10, 20, 30));
+            assert_eq!(<[u8; 3]>::from(c), [10, 20, 30]);
+
+            let c = Rgba::from((10, 20, 30, 40));
+            assert_eq!(c, Rgba::from([10, 20, 30, 40]));
+            assert_eq!(<(u8, u8, u8, u8)>::from(c), (10, 20, 30, 40));
+            assert_eq!(<[u8; 4]>::from(c), [10, 20, 30, 40]);
+        }
+
+        #[test]
+        fn test_ansi_round_trip() {
+            let c = Rgb::new(18, 52, 86);
+            let ansi = Ansi::from(c);
+            assert_eq!(ansi.fg(), "\x1b[38;2;18;52;86m");
+            assert_eq!(Rgb::from(ansi), c);
+            assert_eq!(c.ansi().bg(), ansi.bg());
+        }
+
+        #[test]
+        #[cfg(feature = "wikipedia")]
+        fn test_from_color() {
+            let zomp = crate::color("Zomp").unwrap();
+            assert_eq!(Rgb::from(&zomp).tuple(), zomp.rgb());
+            assert!(Rgba::from(&zomp).is_opaque());
+            assert_eq!(Rgba::from(&zomp).rgb(), Rgb::from(&zomp));
+        }
+
+        #[test]
+        fn test_alpha() {
+            let c = Rgb::new(1, 2, 3);
+            assert_eq!(Rgba::from(c), c.with_alpha(255));
+            assert!(Rgba::from(c).is_opaque());
+            assert!(!c.with_alpha(0).is_opaque());
+            assert_eq!(c.with_alpha(7).rgb(), c);
+        }
+    }
+
+    mod alpha_parsing {
+        use super::*;
+
+        #[test]
+        fn test_hex_forms() {
+            assert_eq!(Rgba::from_hex("#11223344"), Some(Rgba::new(17, 34, 51, 68)));
+            assert_eq!(Rgba::from_hex("11223344"), Some(Rgba::new(17, 34, 51, 68)));
+            assert_eq!(Rgba::from_hex("#1234"), Some(Rgba::new(17, 34, 51, 68)));
+            assert_eq!(Rgba::from_hex("#123"), Some(Rgba::new(17, 34, 51, 255)));
+            assert_eq!(Rgba::from_hex("#112233"), Some(Rgba::new(17, 34, 51, 255)));
+        }
+
+        #[test]
+        fn test_invalid_hex() {
+            for hex in [
+                "",
+                "#",
+                "#12",
+                "#12345",
+                "#1234567",
+                "##123",
+                "#12G",
+                "#１２３",
+            ] {
+                assert_eq!(Rgba::from_hex(hex), None, "{hex}");
+            }
+        }
+
+        #[test]
+        fn test_rgb_str_forms() {
+            let half = Some(Rgba::new(10, 20, 30, 128));
+            assert_eq!(Rgba::from_rgb_str("rgba(10, 20, 30, 0.5)"), half);
+            assert_eq!(Rgba::from_rgb_str("RGBA(10,20,30,.5)"), half);
+            assert_eq!(Rgba::from_rgb_str("rgb(10 20 30 / 0.5)"), half);
+            assert_eq!(Rgba::from_rgb_str("rgba(10 20 30 / 50%)"), half);
+            assert_eq!(Rgba::from_rgb_str(" 10 20 30 / 50% "), half);
+            assert_eq!(
+                Rgba::from_rgb_str("rgb(10, 20, 30)"),
+                Some(Rgba::new(10, 20, 30, 255))
+            );
+            assert_eq!(
+                Rgba::from_rgb_str("rgba(10, 20, 30, 0)"),
+                Some(Rgba::new(10, 20, 30, 0))
+            );
+        }
+
+        #[test]
+        fn test_invalid_rgb_str() {
+            for s in [
+                "",
+                "rgba()",
+                "rgba(10, 20)",
+                "rgba(10, 20, 30, 0.5, 1)",
+                "rgba(10, 20, 30, 0.5 / 1)",
+                "rgba(10, 20, 30, -0.1)",
+                "rgba(10, 20, 30, 101%)",
+                "rgba(10, 20, 30, nan)",
+                "rgba(10, 20, 300, 1)",
+                "rgb(10 20 30 /)",
+                "rgba(10, 20, 30, 1",
+            ] {
+                assert_eq!(Rgba::from_rgb_str(s), None, "{s}");
+            }
+        }
+
+        #[test]
+        fn test_rgb_str_agrees_with_ansi() {
+            for s in [
+                "rgb(1 2 3)",
+                "1, 2, 3, 1",
+                "rgb(1 2 3 / 100%)",
+                "rgb(1 2 3 / 0.5)",
+            ] {
+                let rgba = Rgba::from_rgb_str(s).filter(Rgba::is_opaque);
+                let ansi = Ansi::from_rgb_str(s).map(Rgb::from);
+                assert_eq!(rgba.map(|c| c.rgb()), ansi, "{s}");
+            }
+            assert!(Ansi::from_rgb_str("rgb(1 2 3 / 0.5)").is_none());
+            assert_eq!(Rgba::from_rgb_str("rgb(100% 0% 0%)"), None);
+        }
+    }
+
+    mod compositing {
+        use super::*;
+
+        #[test]
+        fn test_extremes() {
+            let bg = Rgb::new(10, 200, 30);
+            assert_eq!(Rgba::new(1, 2, 3, 255).over(bg), Rgb::new(1, 2, 3));
+            assert_eq!(Rgba::new(1, 2, 3, 0).over(bg), bg);
+        }
+
+        #[test]
+        fn test_half_and_ansi() {
+            let red = Rgba::new(255, 0, 0, 128);
+            let on_black = red.over(Rgb::new(0, 0, 0));
+            assert_eq!(on_black, Rgb::new(128, 0, 0));
+            assert_eq!(red.ansi_over(Rgb::new(0, 0, 0)).fg(), on_black.ansi().fg());
+            assert_eq!(red.over(Rgb::new(255, 255, 255)), Rgb::new(255, 127, 127));
+        }
+    }
+
+    mod traits {
+        use super::*;
+
+        #[test]
+        fn test_hash_and_eq() {
+            let set: HashSet<Rgb> = [Rgb::new(1, 2, 3), Rgb::new(1, 2, 3), Rgb::new(3, 2, 1)]
+                .into_iter()
+                .collect();
+            assert_eq!(set.len(), 2);
+            // Alpha takes part in equality
+            assert_ne!(Rgba::new(1, 2, 3, 4), Rgba::new(1, 2, 3, 5));
+        }
+    }
+
+    #[cfg(feature = "palette")]
+    mod palette_integration {
+        use super::*;
+
+        #[test]
+        fn test_rgb_to_palette() {
+            let p: palette::Srgb<u8> = Rgb::new(18, 52, 86).into();
+            assert_eq!((p.red, p.green, p.blue), (18, 52, 86));
+        }
+
+        #[test]
+        fn test_rgba_round_trip() {
+            let c = Rgba::new(18, 52, 86, 7);
+            let p: palette::Srgba<u8> = c.into();
+            assert_eq!(p.alpha, 7);
+            assert_eq!(Rgba::from(p), c);
+        }
+    }
+
+    #[cfg(feature = "ratatui")]
+    mod ratatui_integration {
+        use super::*;
+
+        #[test]
+        fn test_rgb_to_ratatui() {
+            let c: ratatui::style::Color = Rgb::new(18, 52, 86).into();
+            assert_eq!(c, ratatui::style::Color::Rgb(18, 52, 86));
+        }
+
+        #[test]
+        fn test_transparent_is_reset() {
+            let c: ratatui::style::Color = Rgba::new(18, 52, 86, 0).into();
+            assert_eq!(c, ratatui::style::Color::Reset);
+            let c: ratatui::style::Color = Rgba::new(18, 52, 86, 1).into();
+            assert_eq!(c, ratatui::style::Color::Rgb(18, 52, 86));
+        }
+    }
+
+    #[cfg(feature = "rgb")]
+    mod rgb_integration {
+        use super::*;
+
+        #[test]
+        fn test_rgba_round_trip() {
+            let c = Rgba::new(18, 52, 86, 7);
+            let other: rgb::Rgba<u8> = c.into();
+            assert_eq!((other.r, other.g, other.b, other.a), (18, 52, 86, 7));
+            assert_eq!(Rgba::from(other), c);
+        }
+    }
+
+    #[cfg(feature = "color-rs")]
+    mod color_rs_integration {
+        use super::*;
+
+        #[test]
+        fn test_rgba_round_trip() {
+            let c = Rgba::new(18, 52, 86, 7);
+            let other: color::Rgba8 = c.into();
+            assert_eq!(other.a, 7);
+            assert_eq!(Rgba::from(other), c);
+            let opaque: color::Rgba8 = Rgb::new(1, 2, 3).into();
+            assert_eq!(opaque.a, 255);
+        }
+    }
+}
@@ -0,0 +1,194 @@
+//! Fuzzy name lookup for when [`color`](crate::color) comes back empty.
+//!
+//! ```rust
+//! # #[cfg(feature = "wikipedia")] {
+//! let best = pigment::suggest("aquamrine");
+//! assert_eq!(best[0].name(), "Aquamarine");
+//! # }
+//! ```
+
+use pigment_names::{score, words};
+
+use crate::palette::{Palette, Table};
+use crate::{Color, canonical};
+
+/// How many colors [`suggest`] offers at most.
+const SUGGEST_LIMIT: usize = 5;
+
+/// Minimum score a color needs before [`suggest`] will offer it.
+const SUGGEST_THRESHOLD: f64 = 0.6;
+
+/// A color returned by [`search`], together with how well it matched.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Match {
+    pub(crate) color: Color,
+    pub(crate) score: f64,
+}
+
+impl Match {
+    /// The color that matched the query.
+    #[inline]
+    pub fn color(&self) -> &Color {
+        &self.color
+    }
+
+    /// Similarity between the query and this color's name, in `0.0..=1.0`.
+    ///
+    /// `1.0` means the query canonicalizes to exactly this color's key.
+    #[inline]
+    pub fn score(&self) -> f64 {
+        self.score
+    }
+}
+
+/// Score every named color against `query` and return the best `limit` matches.
+///
+/// The score is the edit-distance similarity between the canonical forms of
+/// the query and the color's name, nudged upwards by how many whole words the
+/// two share, so that "cornflour blue" still ranks *Cornflower blue* first.
+/// Matches are ordered best first; colors with a score of zero are dropped.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "wikipedia")] {
+/// use pigment::search;
+///
+/// let hits = search("cornflour blue", 3);
+/// assert_eq!(hits[0].color().name(), "Cornflower blue");
+/// assert!(hits[0].score() < 1.0);
+/// # }
+/// ```
+pub fn search(query: &str, limit: usize) -> Vec<Match> {
+    Palette::default().search(query, limit)
+}
+
+/// "Did you mean …?" candidates for a name that [`color`](crate::color) did not know.
+///
+/// Returns up to five colors whose [`search`] score is high enough to be a
+/// plausible typo of `query`, best first. An empty result means nothing was
+/// close enough to be worth suggesting.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "wikipedia")] {
+/// use pigment::{color, suggest};
+///
+/// let name = "Azuer";
+/// if color(name).is_none() {
+///     let hints = suggest(name);
+///     assert!(hints.iter().any(|c| c.name() == "Azure"));
+/// }
+/// # }
+/// ```
+pub fn suggest(query: &str) -> Vec<Color> {
+    Palette::default().suggest(query)
+}
+
+pub(crate) fn search_in(table: &'static Table, query: &str, limit: usize) -> Vec<Match> {
+    let key = canonical(query);
+    if key.is_empty() || limit == 0 {
+        return Vec::new();
+    }
+    let key: Vec<char> = key.chars().collect();
+    let words = words(query);
+
+    let mut matches: Vec<Match> = table
+        .colors
+        .entries()
+        .map(|(candidate, color)| Match {
+            color: color.clone(),
+            score: score(&key, &words, candidate, &color.name),
+        })
+        .filter(|m| m.score > 0.0)
+        .collect();
+
+    matches.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.color.name.cmp(&b.color.name))
+    });
+    matches.truncate(limit);
+    matches
+}
+
+pub(crate) fn suggest_in(table: &'static Table, query: &str) -> Vec<Color> {
+    search_in(table, query, SUGGEST_LIMIT)
+        .into_iter()
+        .filter(|m| m.score >= SUGGEST_THRESHOLD)
+        .map(|m| m.color)
+        .collect()
+}
+
+#[cfg(all(test, feature = "wikipedia"))]
+mod tests {
+    use super::*;
+
+    mod search_function {
+        use super::*;
+
+        #[test]
+        fn test_exact_match_scores_one() {
+            let hits = search("Alice Blue", 1);
+            assert_eq!(hits[0].color(), &crate::color("Alice Blue").unwrap());
+            assert_eq!(hits[0].score(), 1.0);
+        }
+
+        #[test]
+        fn test_typos_rank_intended_color_first() {
+            assert_eq!(search("aquamrine", 1)[0].color().name(), "Aquamarine");
+            assert_eq!(
+                search("cornflour blue", 1)[0].color().name(),
+                "Cornflower blue"
+            );
+            assert_eq!(search("Azuer", 1)[0].color().name(), "Azure");
+        }
+
+        #[test]
+        fn test_results_are_sorted_and_limited() {
+            let hits = search("blue", 10);
+            assert_eq!(hits.len(), 10);
+            assert!(hits.windows(2).all(|w| w[0].score() >= w[1].score()));
+            assert!(hits.iter().all(|m| m.score() > 0.0 && m.score() <= 1.0));
+        }
+
+        #[test]
+        fn test_shared_words_boost_score() {
+            let with_word = search("alice green", 20)
+                .into_iter()
+                .find(|m| m.color().name() == "Alice blue")
+                .unwrap();
+            let without = search("alicegreen", 20)
+                .into_iter()
+                .find(|m| m.color().name() == "Alice blue")
+                .unwrap();
+            assert!(with_word.score() > without.score());
+        }
+
+        #[test]
+        fn test_empty_queries() {
+            assert!(search("", 5).is_empty());
+            assert!(search("  -_ ", 5).is_empty());
+            assert!(search("red", 0).is_empty());
+        }
+    }
+
+    mod suggest_function {
+        use super::*;
+
+        #[test]
+        fn test_suggests_close_names() {
+            let found = suggest("fuschia");
+            let names: Vec<_> = found.iter().map(|c| c.name()).collect();
+            assert!(names.contains(&"Fuchsia"));
+            assert!(names.len() <= SUGGEST_LIMIT);
+        }
+
+        #[test]
+        fn test_nothing_for_gibberish() {
+            assert!(suggest("qqqqqqqqqqqqqqqq").is_empty());
+            assert!(suggest("").is_empty());
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! Where the entries of the color tables come from.
+
+/// Provenance of a named color: the list it was taken from, and the label
+/// telling it apart from other colors of the same name.
+///
+/// Wikipedia in particular lists several colors under one name, such as
+/// *Blue*, *Blue (Crayola)* and *Blue (Munsell)*; [`color_all`](crate::color_all)
+/// returns all of them.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "wikipedia")] {
+/// let crayola = pigment::color("Blue (Crayola)").unwrap();
+/// assert_eq!(crayola.source().list(), "List of colors: A–F");
+/// assert_eq!(crayola.source().variant(), Some("Crayola"));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Source {
+    pub(crate) list: &'static str,
+    pub(crate) variant: Option<&'static str>,
+}
+
+impl Source {
+    /// Title of the source list, e.g. "List of colors: N–Z",
+    /// "CSS Color Module Level 4" or "X11 rgb.txt".
+    #[inline]
+    pub fn list(&self) -> &'static str {
+        self.list
+    }
+
+    /// The qualifier the source gives this color, if any: the parenthesized
+    /// part of "Gold (web) (Golden)" is `"web, Golden"`, and "Olive Drab #7"
+    /// is variant `"#7"`.
+    #[inline]
+    pub fn variant(&self) -> Option<&'static str> {
+        self.variant
+    }
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "wikipedia")]
+
 use pigment::color;
 
 #[test]
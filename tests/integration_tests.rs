@@ -1,21 +1,12 @@
-use pigment::color;
-
-// Helper function to create test colors
-fn create_test_color(name: &'static str, hex: &'static str, rgb: (u8, u8, u8)) -> pigment::Color {
-    // This is a bit of a hack, but we need to create a Color struct for testing
-    // We use the same approach as the tests in the crate
-    #[allow(dead_code)]
-    struct TestColor {
-        name: &'static str,
-        hex: &'static str,
-        rgb: (u8, u8, u8),
-    }
+#![cfg(feature = "wikipedia")]
 
-    let test_color = TestColor { name, hex, rgb };
+use pigment::color;
 
-    // Use transmute to convert our TestColor to a pigment::Color
-    // This is safe because they have the same memory layout
-    unsafe { std::mem::transmute(test_color) }
+// Helper function to create test colors that aren't in the database
+fn create_test_color(name: &str, hex: &str) -> pigment::Color {
+    pigment::Registry::new()
+        .insert(name, hex)
+        .expect("test colors have valid names and hex codes")
 }
 
 // Test a variety of colors with all integrations
@@ -30,12 +21,13 @@ fn test_color_integrations_with_various_colors() {
 
     for color_name in test_color_names {
         if let Some(c) = color(color_name) {
+            #[allow(unused_variables)]
             let expected_rgb = c.rgb();
 
             // Test all integrations with this color
             #[cfg(feature = "owo")]
             {
-                let owo_rgb: owo_colors::Rgb = c.into();
+                let owo_rgb: owo_colors::Rgb = c.clone().into();
                 assert_eq!(owo_rgb.0, expected_rgb.0);
                 assert_eq!(owo_rgb.1, expected_rgb.1);
                 assert_eq!(owo_rgb.2, expected_rgb.2);
@@ -43,7 +35,7 @@ fn test_color_integrations_with_various_colors() {
 
             #[cfg(feature = "termcolor")]
             {
-                let tc: termcolor::Color = c.into();
+                let tc: termcolor::Color = c.clone().into();
                 match tc {
                     termcolor::Color::Rgb(r, g, b) => {
                         assert_eq!(r, expected_rgb.0);
@@ -56,7 +48,7 @@ fn test_color_integrations_with_various_colors() {
 
             #[cfg(feature = "colored")]
             {
-                let colored_c: colored::Color = c.into();
+                let colored_c: colored::Color = c.clone().into();
                 match colored_c {
                     colored::Color::TrueColor { r, g, b } => {
                         assert_eq!(r, expected_rgb.0);
@@ -69,7 +61,7 @@ fn test_color_integrations_with_various_colors() {
 
             #[cfg(feature = "anstyle")]
             {
-                let anstyle_c: anstyle::Color = c.into();
+                let anstyle_c: anstyle::Color = c.clone().into();
                 match anstyle_c {
                     anstyle::Color::Rgb(rgb) => {
                         assert_eq!(rgb.0, expected_rgb.0);
@@ -82,7 +74,7 @@ fn test_color_integrations_with_various_colors() {
 
             #[cfg(feature = "nu-ansi-term")]
             {
-                let nat: nu_ansi_term::Color = c.into();
+                let nat: nu_ansi_term::Color = c.clone().into();
                 match nat {
                     nu_ansi_term::Color::Rgb(r, g, b) => {
                         assert_eq!(r, expected_rgb.0);
@@ -95,7 +87,7 @@ fn test_color_integrations_with_various_colors() {
 
             #[cfg(feature = "yansi")]
             {
-                let yansi_c: yansi::Color = c.into();
+                let yansi_c: yansi::Color = c.clone().into();
                 match yansi_c {
                     yansi::Color::Rgb(r, g, b) => {
                         assert_eq!(r, expected_rgb.0);
@@ -108,7 +100,7 @@ fn test_color_integrations_with_various_colors() {
 
             #[cfg(feature = "crossterm")]
             {
-                let ct: crossterm::style::Color = c.into();
+                let ct: crossterm::style::Color = c.clone().into();
                 match ct {
                     crossterm::style::Color::Rgb { r, g, b } => {
                         assert_eq!(r, expected_rgb.0);
@@ -152,7 +144,7 @@ fn test_hex_color_integrations() {
 
     for (hex, expected_rgb) in test_hex_colors {
         // Create a custom color for testing
-        let c = create_test_color("Custom", hex, expected_rgb);
+        let c = create_test_color("Custom", hex);
 
         // Verify the RGB values
         assert_eq!(c.rgb(), expected_rgb, "RGB values for {} should match", hex);
@@ -160,7 +152,7 @@ fn test_hex_color_integrations() {
         // Test all integrations with this color
         #[cfg(feature = "owo")]
         {
-            let owo_rgb: owo_colors::Rgb = c.into();
+            let owo_rgb: owo_colors::Rgb = c.clone().into();
             assert_eq!(owo_rgb.0, expected_rgb.0);
             assert_eq!(owo_rgb.1, expected_rgb.1);
             assert_eq!(owo_rgb.2, expected_rgb.2);
@@ -168,7 +160,7 @@ fn test_hex_color_integrations() {
 
         #[cfg(feature = "termcolor")]
         {
-            let tc: termcolor::Color = c.into();
+            let tc: termcolor::Color = c.clone().into();
             match tc {
                 termcolor::Color::Rgb(r, g, b) => {
                     assert_eq!(r, expected_rgb.0);
@@ -181,7 +173,7 @@ fn test_hex_color_integrations() {
 
         #[cfg(feature = "colored")]
         {
-            let colored_c: colored::Color = c.into();
+            let colored_c: colored::Color = c.clone().into();
             match colored_c {
                 colored::Color::TrueColor { r, g, b } => {
                     assert_eq!(r, expected_rgb.0);
@@ -194,7 +186,7 @@ fn test_hex_color_integrations() {
 
         #[cfg(feature = "anstyle")]
         {
-            let anstyle_c: anstyle::Color = c.into();
+            let anstyle_c: anstyle::Color = c.clone().into();
             match anstyle_c {
                 anstyle::Color::Rgb(rgb) => {
                     assert_eq!(rgb.0, expected_rgb.0);
@@ -207,7 +199,7 @@ fn test_hex_color_integrations() {
 
         #[cfg(feature = "nu-ansi-term")]
         {
-            let nat: nu_ansi_term::Color = c.into();
+            let nat: nu_ansi_term::Color = c.clone().into();
             match nat {
                 nu_ansi_term::Color::Rgb(r, g, b) => {
                     assert_eq!(r, expected_rgb.0);
@@ -220,7 +212,7 @@ fn test_hex_color_integrations() {
 
         #[cfg(feature = "yansi")]
         {
-            let yansi_c: yansi::Color = c.into();
+            let yansi_c: yansi::Color = c.clone().into();
             match yansi_c {
                 yansi::Color::Rgb(r, g, b) => {
                     assert_eq!(r, expected_rgb.0);
@@ -233,7 +225,7 @@ fn test_hex_color_integrations() {
 
         #[cfg(feature = "crossterm")]
         {
-            let ct: crossterm::style::Color = c.into();
+            let ct: crossterm::style::Color = c.clone().into();
             match ct {
                 crossterm::style::Color::Rgb { r, g, b } => {
                     assert_eq!(r, expected_rgb.0);
@@ -3,58 +3,439 @@
 Scrapes color tables from the three Wikipedia pages and spits
 `generated/colors.rs`, ready to be `include!`-d by the Rust crate.
 
+It also turns the vendored CSS Color 4 keyword list and X11 `rgb.txt`
+under tools/data/ into `generated/css.rs` and `generated/x11.rs`, and the
+translations in tools/data/locales/ into `generated/locale_<code>.rs`.
+
+Besides its map, each file carries the derived tables the crate needs:
+the alias keys from tools/data/aliases.txt, the display-name ordering
+behind `Palette::all`, the sorted key and word indexes behind
+`Palette::complete`, and the OKLab k-d tree behind `Palette::nearest`.
+The maps and aliases alone are written again under macros/generated/
+for `pigment-macros`.
+
 Run:  python tools/scrape.py
-Requires: beautifulsoup4, requests, tqdm
+      python tools/scrape.py --offline   # reuse the committed Wikipedia
+                                         # rows instead of scraping them
+Requires: beautifulsoup4, requests, tqdm (not needed with --offline)
 """
-import re, json, pathlib, textwrap, itertools, requests
-from bs4 import BeautifulSoup
-from tqdm import tqdm
+import math, re, sys, struct, pathlib, unicodedata
 
 PAGES = [
-    "https://en.wikipedia.org/wiki/List_of_colors:_A%E2%80%93F",
-    "https://en.wikipedia.org/wiki/List_of_colors:_G%E2%80%93M",
-    "https://en.wikipedia.org/wiki/List_of_colors:_N%E2%80%93Z",
+    ("https://en.wikipedia.org/wiki/List_of_colors:_A%E2%80%93F", "List of colors: A–F"),
+    ("https://en.wikipedia.org/wiki/List_of_colors:_G%E2%80%93M", "List of colors: G–M"),
+    ("https://en.wikipedia.org/wiki/List_of_colors:_N%E2%80%93Z", "List of colors: N–Z"),
 ]
+CSS_LIST = "CSS Color Module Level 4"
+X11_LIST = "X11 rgb.txt"
+
+COLORS_RS = pathlib.Path("generated/colors.rs")
+DATA = pathlib.Path("tools/data")
+
+FOLD = {"ß": "ss", "æ": "ae", "œ": "oe", "ø": "o", "đ": "d", "ð": "d", "ł": "l", "þ": "th"}
 
 def canonical(name: str) -> str:
-    """lowercase, remove anything that isn’t [a-z0-9]"""
-    return re.sub(r'[^a-z0-9]', '', name.lower())
-
-out = []
-seen_keys = set()
-for url in PAGES:
-    soup = BeautifulSoup(requests.get(url, timeout=30).text, "html.parser")
-    for row in tqdm(soup.select("table.wikitable tr")):
-        cells = row.select("td")
-        if len(cells) < 2:
-            continue
-        name = cells[0].get_text(strip=True)
-        hex_match = re.search(r"#([0-9A-Fa-f]{6})", cells[1].get_text())
-        if not hex_match:
+    """lowercase letters and digits only, accents and ligatures folded.
+
+    Must stay in sync with `canonical` in names/src/lib.rs.
+    """
+    out = []
+    for c in unicodedata.normalize("NFKD", name).lower():
+        if c in FOLD:
+            out.append(FOLD[c])
+        elif c.isalnum() and not unicodedata.category(c).startswith("M"):
+            out.append(c)
+    return "".join(out)
+
+def unique_key(key, seen_keys):
+    """`key`, or `key` with the first free numeric suffix if it is taken."""
+    if key in seen_keys:
+        counter = 2
+        while f"{key}{counter}" in seen_keys:
+            counter += 1
+        key = f"{key}{counter}"
+    seen_keys.add(key)
+    return key
+
+def split_variant(name):
+    """(base name, variant label) of a display name.
+
+    "Gold (web) (Golden)" is ("Gold", "web, Golden"), "Olive Drab #7" is
+    ("Olive Drab", "#7"); footnote markers like "[2]" are dropped.
+    """
+    plain = re.sub(r"\[[^\]]*\]", "", name)
+    labels = re.findall(r"\(([^)]*)\)", plain)
+    base = re.sub(r"\s*\([^)]*\)", "", plain).strip()
+    numbered = re.search(r"\s+(#\d+)$", base)
+    if numbered:
+        labels.append(numbered.group(1))
+        base = base[: numbered.start()]
+    return base, ", ".join(label.strip() for label in labels) or None
+
+def scrape():
+    """Fetch every (key, name, hex, r, g, b, list) row from Wikipedia."""
+    import requests
+    from bs4 import BeautifulSoup
+    from tqdm import tqdm
+
+    out = []
+    seen_keys = set()
+    for url, title in PAGES:
+        soup = BeautifulSoup(requests.get(url, timeout=30).text, "html.parser")
+        for row in tqdm(soup.select("table.wikitable tr")):
+            cells = row.select("td")
+            if len(cells) < 2:
+                continue
+            name = cells[0].get_text(strip=True)
+            hex_match = re.search(r"#([0-9A-Fa-f]{6})", cells[1].get_text())
+            if not hex_match:
+                continue
+            hex_code = "#" + hex_match.group(1).upper()
+            rgb = tuple(int(hex_code[i : i + 2], 16) for i in (1, 3, 5))
+            # Handle duplicate keys by adding a number suffix
+            key = unique_key(canonical(name), seen_keys)
+            out.append((key, name, hex_code, *rgb, title))
+    return out
+
+def load_generated():
+    """Read the rows back out of an existing generated/colors.rs.
+
+    Keys are recomputed from the names, so changes to `canonical` apply.
+    Rows written before sources were recorded get the page their initial
+    belongs on.
+    """
+    cow = r"(?:std::borrow::Cow::Borrowed\()?"
+    row = re.compile(rf'^    "\w+" => crate::Color ?\{{ name: ?{cow}"([^"]*)"\)?, hex: ?{cow}"(#[0-9A-F]{{6}})"')
+    listed = re.compile(r'list: ?"([^"]*)"')
+    out = []
+    seen_keys = set()
+    for line in COLORS_RS.read_text().splitlines():
+        m = row.match(line)
+        if m:
+            name, hex_code = m.groups()
+            key = unique_key(canonical(name), seen_keys)
+            title = listed.search(line)
+            title = title.group(1) if title else PAGES[(name[0].lower() > "f") + (name[0].lower() > "m")][1]
+            out.append((key, name, hex_code, *(int(hex_code[i : i + 2], 16) for i in (1, 3, 5)), title))
+    return out
+
+def load_css():
+    """CSS Color 4 named colors, keyed by the keyword itself."""
+    out = []
+    for line in (DATA / "css-color-4.txt").read_text().splitlines():
+        if line and not line.startswith("#"):
+            name, hex_code = line.split()
+            out.append((canonical(name), name, hex_code, *(int(hex_code[i : i + 2], 16) for i in (1, 3, 5)), CSS_LIST))
+    return out
+
+def load_x11():
+    """X11 rgb.txt; the "alice blue"/"AliceBlue" spellings share one entry."""
+    out = []
+    seen_keys = set()
+    for line in (DATA / "x11-rgb.txt").read_text().splitlines():
+        if not line.strip() or line.startswith("!"):
             continue
-        hex_code = "#" + hex_match.group(1).upper()
-        rgb = tuple(int(hex_code[i : i + 2], 16) for i in (1, 3, 5))
+        r, g, b, *name = line.split()
+        name = " ".join(name)
         key = canonical(name)
-
-        # Handle duplicate keys by adding a number suffix
         if key in seen_keys:
-            counter = 2
-            while f"{key}{counter}" in seen_keys:
-                counter += 1
-            key = f"{key}{counter}"
-
+            continue
         seen_keys.add(key)
-        out.append((key, name, hex_code, *rgb))
-
-# write Rust source
-colors_rs = pathlib.Path("generated/colors.rs")
-colors_rs.parent.mkdir(exist_ok=True)
-with colors_rs.open("w") as f:
-    f.write("///  ***  AUTO-GENERATED  – DO NOT EDIT BY HAND  ***\n")
-    f.write("use phf::{phf_map};\n\n")
-    f.write("pub static COLORS: phf::Map<&'static str, crate::Color> = phf_map! {\n")
-    for key, name, hx, r, g, b in out:
-        f.write(f'    "{key}" => crate::Color{{ name:"{name}", hex:"{hx}", rgb:({r},{g},{b}) }},\n')
+        r, g, b = int(r), int(g), int(b)
+        out.append((key, name, f"#{r:02X}{g:02X}{b:02X}", r, g, b, X11_LIST))
+    return out
+
+def load_alias_rules():
+    """(where, spelling, alternates) rules from tools/data/aliases.txt."""
+    rules = []
+    for line in (DATA / "aliases.txt").read_text().splitlines():
+        if line and not line.startswith("#"):
+            where, spelling, *alternates = line.split()
+            rules.append((where, spelling, alternates))
+    return rules
+
+def aliases(out, rules):
+    """Alternate keys for every row of a table, as {key: [alias, ...]}.
+
+    A variant is dropped when it is a key of the table itself, or when an
+    earlier row already claimed it.
+    """
+    taken = {key for key, *_ in out}
+    result = {}
+    for key, *_ in out:
+        variants = [key]
+        for where, spelling, alternates in rules:
+            for variant in list(variants):
+                for alternate in alternates:
+                    if where == "prefix" and variant.startswith(spelling):
+                        variants.append(alternate + variant[len(spelling) :])
+                    elif where == "any" and spelling in variant:
+                        variants.append(variant.replace(spelling, alternate))
+        result[key] = [v for v in dict.fromkeys(variants[1:]) if v not in taken]
+        taken.update(result[key])
+    return result
+
+# -------- OKLab -------------------------------------------------------------
+# Must stay in sync with `oklab_from_linear` in src/lab.rs.
+
+def oklab(r, g, b):
+    def linear(c):
+        c /= 255
+        return c / 12.92 if c <= 0.04045 else ((c + 0.055) / 1.055) ** 2.4
+
+    r, g, b = linear(r), linear(g), linear(b)
+    l = 0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b
+    m = 0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b
+    s = 0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b
+    l, m, s = l ** (1 / 3), m ** (1 / 3), s ** (1 / 3)
+    return (
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    )
+
+# -------- CIE Lab ----------------------------------------------------------
+# Must stay in sync with `Xyz` and `Lab` in src/lab.rs: CSS Color 4 matrices,
+# D65 white.
+
+D65 = (0.3127 / 0.3290, 1.0, (1.0 - 0.3127 - 0.3290) / 0.3290)
+
+def lab(r, g, b):
+    def linear(c):
+        c /= 255
+        return c / 12.92 if c <= 0.04045 else ((c + 0.055) / 1.055) ** 2.4
+
+    def f(t):
+        return t ** (1 / 3) if t > 216 / 24389 else (24389 / 27 * t + 16) / 116
+
+    r, g, b = linear(r), linear(g), linear(b)
+    x = 506752 / 1228815 * r + 87881 / 245763 * g + 12673 / 70218 * b
+    y = 87098 / 409605 * r + 175762 / 245763 * g + 12673 / 175545 * b
+    z = 7918 / 409605 * r + 87881 / 737289 * g + 1001167 / 1053270 * b
+    fx, fy, fz = f(x / D65[0]), f(y / D65[1]), f(z / D65[2])
+    return (116 * fy - 16, 500 * (fx - fy), 200 * (fy - fz))
+
+def write_lab(path, tables):
+    """Write the Lab and OKLab coordinates of every color in `tables`, by RGB value."""
+    rgbs = sorted({(r, g, b) for table in tables for _, _, _, r, g, b, _ in table})
+    with path.open("w") as f:
+        f.write("//  ***  AUTO-GENERATED  – DO NOT EDIT BY HAND  ***\n\n")
+        f.write("/// CIE Lab and OKLab coordinates of every built-in color, by `0xRRGGBB`.\n")
+        f.write("pub(crate) static LAB: phf::Map<u32, ([f32; 3], [f32; 3])> = phf::phf_map! {\n")
+        for r, g, b in rgbs:
+            cie = ",".join(map(f32, lab(r, g, b)))
+            ok = ",".join(map(f32, oklab(r, g, b)))
+            f.write(f"    0x{r:02X}{g:02X}{b:02X}u32 => ([{cie}], [{ok}]),\n")
+        f.write("};\n")
+    print(f"Wrote {path} with {len(rgbs)} colors")
+
+# -------- families ----------------------------------------------------------
+# Must stay in sync with `classify` in src/family.rs.
+
+FAMILIES = ["red", "orange", "yellow", "green", "cyan", "blue", "purple", "pink", "brown", "gray"]
+
+def family(r, g, b):
+    """The hue family of a color, from its OKLCH coordinates."""
+    l, a, b = oklab(r, g, b)
+    c, h = math.hypot(a, b), math.degrees(math.atan2(b, a)) % 360
+    if c < 0.035:
+        return "gray"
+    if h >= 340 or h < 15:
+        return "pink" if l >= 0.5 else "purple" if h >= 340 else "red"
+    if h < 40:
+        return "brown" if l < 0.5 and c < 0.17 else "pink" if l >= 0.78 else "red"
+    if h < 100 and l < 0.65:
+        return "brown"
+    for family, end in [("orange", 75), ("yellow", 120), ("green", 175), ("cyan", 230), ("blue", 290)]:
+        if h < end:
+            return family
+    return "purple"
+
+# Must stay in sync with `Color::lightness` and `Color::chroma` in src/family.rs.
+
+LIGHTNESS = ["dark", "medium", "light"]
+CHROMA = ["muted", "moderate", "vivid"]
+
+def bands(r, g, b):
+    """The lightness and chroma band of a color, as indexes into `LIGHTNESS` and `CHROMA`."""
+    l, a, b = oklab(r, g, b)
+    c = math.hypot(a, b)
+    return (0 if l < 0.45 else 1 if l < 0.75 else 2, 0 if c < 0.08 else 1 if c < 0.16 else 2)
+
+def words(name):
+    """The canonical form of every word in a display name."""
+    return [w for w in map(canonical, re.split(r"[\W_]+", name)) if w]
+
+def kd_tree(points, depth=0):
+    """Lay (lab, key) points out as an implicit k-d tree in pre-order.
+
+    Each subtree is a contiguous run: its median first, then the lower half,
+    then the upper half, splitting on L, a, b in turn.
+    """
+    if not points:
+        return []
+    axis = depth % 3
+    points = sorted(points, key=lambda p: (p[0][axis], p[1]))
+    mid = len(points) // 2
+    return [points[mid]] + kd_tree(points[:mid], depth + 1) + kd_tree(points[mid + 1 :], depth + 1)
+
+# -------- output ------------------------------------------------------------
+
+def f32(x):
+    """Shortest literal that still round-trips through an f32."""
+    exact = struct.unpack("f", struct.pack("f", x))[0]
+    for digits in range(1, 10):
+        lit = f"{exact:.{digits}g}"
+        if struct.unpack("f", struct.pack("f", float(lit)))[0] == exact:
+            break
+    return lit if any(c in lit for c in ".e") else lit + ".0"
+
+def quoted(items):
+    return ", ".join(f'"{item}"' for item in items)
+
+def optional(item):
+    return f'Some("{item}")' if item is not None else "None"
+
+def write_map(f, table, out, doc, alias):
+    """Write one palette's map, called `table`, and its aliases."""
+    f.write(f"/// {doc}\n")
+    f.write(f"pub static {table}: phf::Map<&'static str, crate::Color> = phf::phf_map! {{\n")
+    for key, name, hx, r, g, b, title in out:
+        source = f'crate::Source{{ list:"{title}", variant:{optional(split_variant(name)[1])} }}'
+        f.write(f'    "{key}" => crate::Color{{ name:std::borrow::Cow::Borrowed("{name}"), hex:std::borrow::Cow::Borrowed("{hx}"), rgb:({r},{g},{b}), aliases:&[{quoted(alias[key])}], source:{source} }},\n')
     f.write("};\n")
 
-print(f"Wrote {colors_rs} with {len(out)} colors")
+    f.write(f"\n/// Alternate spellings of `{table}` keys, mapped to the key they stand for.\n")
+    f.write(f"pub(crate) static {table}_ALIASES: phf::Map<&'static str, &'static str> = phf::phf_map! {{\n")
+    for key, *_ in out:
+        for a in alias[key]:
+            f.write(f'    "{a}" => "{key}",\n')
+    f.write("};\n")
+
+def write(path, table, out, doc, rules):
+    """Write one palette's map, called `table`, and its derived tables.
+
+    The map and its aliases also go to the same path under macros/, which
+    `pigment-macros` includes so that it can be published on its own.
+    """
+    path.parent.mkdir(exist_ok=True)
+    alias = aliases(out, rules)
+    with path.open("w") as f:
+        f.write("//  ***  AUTO-GENERATED  – DO NOT EDIT BY HAND  ***\n\n")
+        write_map(f, table, out, doc, alias)
+        variants = {}
+        for key, name, *_ in out:
+            variants.setdefault(canonical(split_variant(name)[0]), []).append(key)
+        f.write(f"\n/// `{table}` keys sharing a base name, for names that aren't simply their own key.\n")
+        f.write(f"pub(crate) static {table}_VARIANTS: phf::Map<&'static str, &'static [&'static str]> = phf::phf_map! {{\n")
+        for base, keys in variants.items():
+            if keys != [base]:
+                f.write(f'    "{base}" => &[{quoted(keys)}],\n')
+        f.write("};\n")
+
+        by_name = sorted(out, key=lambda row: (row[1].lower(), row[0]))
+        f.write(f"\n/// `{table}` keys ordered by display name, case-insensitively.\n")
+        f.write(f"pub(crate) static {table}_BY_NAME: [&str; {len(by_name)}] = [\n")
+        for key, *_ in by_name:
+            f.write(f'    "{key}",\n')
+        f.write("];\n")
+
+        keys = sorted(key for key, *_ in out)
+        f.write(f"\n/// `{table}` keys in sorted order, for prefix search.\n")
+        f.write(f"pub(crate) static {table}_KEYS: [&str; {len(keys)}] = [\n")
+        for key in keys:
+            f.write(f'    "{key}",\n')
+        f.write("];\n")
+
+        index = {}
+        for key, name, *_ in by_name:
+            for word in dict.fromkeys(words(name)):
+                index.setdefault(word, []).append(key)
+        f.write("\n/// Every word of every display name, sorted, with the keys (in display-name order) using it.\n")
+        f.write(f"pub(crate) static {table}_WORDS: [(&str, &[&str]); {len(index)}] = [\n")
+        for word in sorted(index):
+            f.write(f'    ("{word}", &[{quoted(index[word])}]),\n')
+        f.write("];\n")
+
+        families = {f: [] for f in FAMILIES}
+        for key, _, _, r, g, b, _ in by_name:
+            families[family(r, g, b)].append(key)
+        f.write(f"\n/// `{table}` keys of each hue family, in `Family` order, each in display-name order.\n")
+        f.write(f"pub(crate) static {table}_FAMILIES: [&[&str]; {len(FAMILIES)}] = [\n")
+        for name in FAMILIES:
+            f.write(f"    &[{quoted(families[name])}],\n")
+        f.write("];\n")
+
+        banded = {f: [[[] for _ in CHROMA] for _ in LIGHTNESS] for f in FAMILIES}
+        for key, _, _, r, g, b, _ in by_name:
+            l, c = bands(r, g, b)
+            banded[family(r, g, b)][l][c].append(key)
+        f.write(f"\n/// `{table}_FAMILIES` split by `Lightness` and then `Chroma` band, each in display-name order.\n")
+        f.write(f"pub(crate) static {table}_BANDS: [[[&[&str]; {len(CHROMA)}]; {len(LIGHTNESS)}]; {len(FAMILIES)}] = [\n")
+        for name in FAMILIES:
+            f.write("    [\n")
+            for row in banded[name]:
+                f.write(f"        [{', '.join(f'&[{quoted(keys)}]' for keys in row)}],\n")
+            f.write("    ],\n")
+        f.write("];\n")
+
+        tree = kd_tree([(oklab(r, g, b), key) for key, _, _, r, g, b, _ in out])
+        f.write(f"\n/// `{table}` keys laid out as a k-d tree over OKLab coordinates.\n")
+        f.write(f"pub(crate) static {table}_NEAREST: [crate::nearest::Node; {len(tree)}] = [\n")
+        for (l, a, b), key in tree:
+            f.write(f'    crate::nearest::Node{{ lab:[{f32(l)},{f32(a)},{f32(b)}], key:"{key}" }},\n')
+        f.write("];\n")
+    print(f"Wrote {path} with {len(out)} colors")
+
+    macros = pathlib.Path("macros") / path
+    macros.parent.mkdir(exist_ok=True)
+    with macros.open("w") as f:
+        f.write("//  ***  AUTO-GENERATED  – DO NOT EDIT BY HAND  ***\n\n")
+        write_map(f, table, out, doc, alias)
+    print(f"Wrote {macros}")
+
+LOCALES = {"de": "German", "fr": "French", "ja": "Japanese"}
+
+def write_locale(code, language, table):
+    """Write the name tables for one locale, checked against `table`."""
+    keys = {key for key, *_ in table}
+    lookup, names = {}, {}
+    for line in (DATA / "locales" / f"{code}.txt").read_text().splitlines():
+        if not line or line.startswith("#"):
+            continue
+        english, translated = (part.strip() for part in line.split("=", 1))
+        key = canonical(english)
+        if key not in keys:
+            sys.exit(f"{code}.txt: no color called {english!r}")
+        translated = [name.strip() for name in translated.split(",")]
+        names[key] = translated[0]
+        for name in translated:
+            if lookup.setdefault(canonical(name), key) != key:
+                sys.exit(f"{code}.txt: {name!r} names two colors")
+
+    table = f"LOCALE_{code.upper()}"
+    path = pathlib.Path(f"generated/locale_{code}.rs")
+    with path.open("w") as f:
+        f.write("//  ***  AUTO-GENERATED  – DO NOT EDIT BY HAND  ***\n\n")
+        f.write(f"/// {language} color names, canonicalized, mapped to the key of the English name.\n")
+        f.write(f"pub(crate) static {table}: phf::Map<&'static str, &'static str> = phf::phf_map! {{\n")
+        for name, key in lookup.items():
+            f.write(f'    "{name}" => "{key}",\n')
+        f.write("};\n")
+        f.write(f"\n/// {language} display names, by key of the English name.\n")
+        f.write(f"pub(crate) static {table}_NAMES: phf::Map<&'static str, &'static str> = phf::phf_map! {{\n")
+        for key, name in names.items():
+            f.write(f'    "{key}" => "{name}",\n')
+        f.write("};\n")
+    print(f"Wrote {path} with {len(names)} names")
+
+if __name__ == "__main__":
+    wikipedia = load_generated() if "--offline" in sys.argv[1:] else scrape()
+    css, x11 = load_css(), load_x11()
+    rules = load_alias_rules()
+    write(COLORS_RS, "COLORS", wikipedia, "Colors from Wikipedia's \"List of colors\" pages.", rules)
+    write(pathlib.Path("generated/css.rs"), "CSS_COLORS", css, "CSS Color Module Level 4 named colors.", rules)
+    write(pathlib.Path("generated/x11.rs"), "X11_COLORS", x11, "Colors from the X11 `rgb.txt` database.", rules)
+    write_lab(pathlib.Path("generated/lab.rs"), [wikipedia, css, x11])
+    for code, language in LOCALES.items():
+        write_locale(code, language, wikipedia)